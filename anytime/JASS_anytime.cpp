@@ -22,29 +22,54 @@
 */
 static double rho = 100.0;													///< In the anytime paper rho is the percentage of the collection that should be used as a cap to the number of postings processed.
 static size_t maximum_number_of_postings_to_process = 0;			///< Computed from rho
+static size_t parameter_time_budget_in_ns = 0;						///< The maximum time (in nanoseconds) a query may take (0 is no limit)
+static bool parameter_safe_early_termination = false;				///< Stop each query once the top-k can no longer change
+static size_t parameter_phrase_boost = 0;								///< With a positional index, 0 to remove results without the query's phrases, else the score added per phrase found
 static std::string parameter_queryfilename;							///< Name of file containing the queries
 static size_t parameter_threads = 1;									///< Number of concurrent queries
 static size_t parameter_top_k = 10;										///< Number of results to return
 static size_t accumulator_width = 0;									///< The width (2^accumulator_width) of the accumulator 2-D array (if they are being used).
+static size_t parameter_accumulator_bits = 8;						///< The width (in bits) of each accumulator
 static bool parameter_ascii_query_parser = false;					///< When true use the ASCII pre-casefolded query parser
+static bool parameter_json_query_parser = false;					///< When true each query is a JSON object of term and weight pairs
 static bool parameter_help = false;										///< Print the usage information
-static bool parameter_index_v2 = false;								///< The index is a JASS version 2 index
+static bool parameter_index_v2 = false;								///< The index is a JASS version 2 index (else detect the version)
+static bool parameter_index_v3 = false;								///< The index is a JASS version 3 index (else detect the version)
+static bool parameter_verify_index = false;							///< Check the index checksums when loading the index
+static bool parameter_lazy_index = false;								///< Map the index and read each page on first use (else read it in when it is loaded)
+static std::string parameter_access_pattern = "normal";				///< How the postings are expected to be used (passed to madvise())
+static size_t parameter_prefetch_terms = 0;							///< Read in the postings of this many terms (the longest postings lists) when loading the index
+static bool parameter_lock_index = false;								///< Lock the index into memory
 std::string parameter_accumulator_manager = "2d_heap";	///< Which accumulator manager to use
+static std::string parameter_stemmer;									///< The query stemmer to use (empty means use the index stemmer)
 
 static std::string parameters_errors;									///< Any errors as a result of command line parsing
 static auto parameters = std::make_tuple								///< The  command line parameter block
 	(
 	JASS::commandline::parameter("-?",   "--help",         "                      Print this help.", parameter_help),
 	JASS::commandline::parameter("-h",   "--help",         "                      Print this help.", parameter_help),
-	JASS::commandline::parameter("-2",   "--v2_index",     "                      The index is a JASS v2 index", parameter_index_v2),
-	JASS::commandline::parameter("-I2",  "--v2_index",     "                      The index is a JASS v2 index", parameter_index_v2),
+	JASS::commandline::parameter("-2",   "--v2_index",     "                      The index is a JASS v2 index [default = detect the version from the index]", parameter_index_v2),
+	JASS::commandline::parameter("-I2",  "--v2_index",     "                      The index is a JASS v2 index [default = detect the version from the index]", parameter_index_v2),
+	JASS::commandline::parameter("-3",   "--v3_index",     "                      The index is a JASS v3 index [default = detect the version from the index]", parameter_index_v3),
+	JASS::commandline::parameter("-I3",  "--v3_index",     "                      The index is a JASS v3 index [default = detect the version from the index]", parameter_index_v3),
 	JASS::commandline::parameter("-a",   "--asciiparser",  "                      Use simple query parser (ASCII seperated pre-casefolded tokens)", parameter_ascii_query_parser),
+	JASS::commandline::parameter("-b",   "--bits",         "<8|16|32>             The width (in bits) of each accumulator (heap accumulator managers only) [default = -b8]", parameter_accumulator_bits),
 	JASS::commandline::parameter("-A",   "--accumulators", "<accumulator_manager> Which accumulator manager (2d_heap|1d_heap|simple|blockmax) to use [default = 2d_heap]", parameter_accumulator_manager),
+	JASS::commandline::parameter("-j",   "--jsonparser",   "                      Each query is a JSON object of pre-casefolded terms and their weights, e.g. {\"term\": 1.5}", parameter_json_query_parser),
+	JASS::commandline::parameter("-ma",  "--madvise",      "<pattern>             How the postings will be used, passed to madvise() (normal|random|sequential|willneed) [default = normal]", parameter_access_pattern),
+	JASS::commandline::parameter("-ml",  "--mlock",        "                      Lock the index into memory so that it cannot be paged out (see ulimit -l)", parameter_lock_index),
+	JASS::commandline::parameter("-mp",  "--prefetch",     "<terms>               Read in the postings of this many terms (those with the longest postings lists) when loading the index [default = -mp0]", parameter_prefetch_terms),
+	JASS::commandline::parameter("-m",   "--mmap",         "                      Map the index and read each page on first use (the page cache is shared between processes) [default = read the index in when loading]", parameter_lazy_index),
 	JASS::commandline::parameter("-k",   "--top-k",        "<top-k>               Number of results to return to the user (top-k value) [default = -k10]", parameter_top_k),
+	JASS::commandline::parameter("-P",   "--phraseboost",  "<boost>               With a positional index, add <boost> to the score for each phrase found (0 removes results without the phrases) [default = -P0]", parameter_phrase_boost),
 	JASS::commandline::parameter("-q",   "--queryfile",    "<filename>            Name of file containing a list of queries (1 per line, each line prefixed with query-id)", parameter_queryfilename),
 	JASS::commandline::parameter("-r",   "--rho",          "<integer_percent>     Percent of the collection size to use as max number of postings to process [default = -r100] (overrides -R)", rho),
 	JASS::commandline::parameter("-R",   "--RHO",          "<integer_max>         Max number of postings to process [default is all]", maximum_number_of_postings_to_process),
+	JASS::commandline::parameter("-S",   "--safe",         "                      Stop each query once the top-k can no longer change (safe early termination)", parameter_safe_early_termination),
+	JASS::commandline::parameter("-s",   "--stemmer",      "<stemmer>             Query stemmer (None|Porter|Porter2|Krovetz|S) [default = the stemmer used to build the index]", parameter_stemmer),
+	JASS::commandline::parameter("-T",   "--time",         "<nanoseconds>         Stop each query once it has taken this long [default is no limit]", parameter_time_budget_in_ns),
 	JASS::commandline::parameter("-t",   "--threads",      "<threadcount>         Number of threads to use (one query per thread) [default = -t1]", parameter_threads),
+	JASS::commandline::parameter("-V",   "--verify",       "                      Check the index checksums when loading the index (slower to load)", parameter_verify_index),
 	JASS::commandline::parameter("-w",   "--width",        "<2^w>                 The width of the 2D accumulator array (2^w is used)", accumulator_width)
 	);
 
@@ -145,11 +170,18 @@ static int main_event(int argc, const char *argv[])
 		exit(usage(argv[0]));
 
 	stats.threads = parameter_threads;
+	stats.time_budget_in_ns = parameter_time_budget_in_ns;
+	stats.safe_early_termination = parameter_safe_early_termination;
 
 	/*
 		Set the accumulator manager
 	*/
 	engine.set_accumulator_manager(parameter_accumulator_manager);
+	if (engine.set_accumulator_bits(parameter_accumulator_bits) != JASS_ERROR_OK)
+		{
+		std::cout << "Cannot set the accumulator width to " << parameter_accumulator_bits << " bits (it must be 8, 16, or 32)\n";
+		return 0;
+		}
 
 	/*
 		Set the top-k value
@@ -173,18 +205,43 @@ static int main_event(int argc, const char *argv[])
 	/*
 		Read the index into memory
 	*/
-	if (engine.load_index(parameter_index_v2 ? 2 : 1, "", true) != JASS_ERROR_OK)
+	engine.set_verify_index(parameter_verify_index);
+	if (engine.set_memory_mapping(parameter_lazy_index, parameter_access_pattern, parameter_prefetch_terms, parameter_lock_index) != JASS_ERROR_OK)
 		{
-		std::cout << "Cannot load the index\n";
+		std::cout << "Unknown memory access pattern: " << parameter_access_pattern << "\n";
+		return 0;
+		}
+	size_t index_version = parameter_index_v3 ? 3 : parameter_index_v2 ? 2 : 0;
+	auto loaded = engine.load_index(index_version, "", true);
+	if (loaded != JASS_ERROR_OK)
+		{
+		if (loaded == JASS_ERROR_BAD_INDEX_VERSION)
+			std::cout << "Cannot load the index (it is not a JASS v" << index_version << " index)\n";
+		else if (loaded == JASS_ERROR_CANNOT_LOCK_INDEX)
+			std::cout << "Cannot load the index (it cannot be locked into memory)\n";
+		else
+			std::cout << "Cannot load the index\n";
 		return 0;
 		}
 	stats.number_of_documents = engine.get_document_count();
 
+	/*
+		Set the query stemmer (the default is the one the index was built with)
+	*/
+	if (parameter_stemmer != "")
+		if (engine.set_stemmer(parameter_stemmer) != JASS_ERROR_OK)
+			{
+			std::cout << "Unknown stemmer:" << parameter_stemmer << "\n";
+			return 0;
+			}
+	std::cout << "Query stemmer: " << engine.get_stemmer_name() << "\n";
 
 	/*
 		Set the parser (this will normally be the "regular" query parser, but sometimes the queries contain "weird stuff" and need to be tokenised with spaces as seperators.
 	*/
-	if (parameter_ascii_query_parser)
+	if (parameter_json_query_parser)
+		engine.use_json_parser();
+	else if (parameter_ascii_query_parser)
 		engine.use_ascii_parser();
 	else
 		engine.use_query_parser();
@@ -205,6 +262,11 @@ static int main_event(int argc, const char *argv[])
 			return 0;
 			}
 
+	if (parameter_time_budget_in_ns != 0)
+		engine.set_time_budget_ns(parameter_time_budget_in_ns);
+	engine.set_safe_early_termination(parameter_safe_early_termination);
+	engine.set_phrase_boost(parameter_phrase_boost);
+
 	/*
 		Report the number of postings we're going to process
 	*/
@@ -212,6 +274,10 @@ static int main_event(int argc, const char *argv[])
 		std::cout << "Maximum number of postings to process: " << engine.get_postings_to_process() << "\n";
 	else
 		std::cout << "Maximum number of postings to process: Search to completion\n";
+	if (engine.get_time_budget_ns() != 0)
+		std::cout << "Time budget per query: " << engine.get_time_budget_ns() << " ns\n";
+	if (engine.get_safe_early_termination())
+		std::cout << "Safe early termination\n";
 
 	/*
 		Report the compression scheme used in this index
@@ -252,8 +318,10 @@ static int main_event(int argc, const char *argv[])
 	for (size_t which = 0; which < parameter_threads ; which++)
 		for (const auto &[query_id, result] : output[which])
 			{
-			stats_file << "<id>" << result.query_id << "</id><query>" << result.query << "</query><postings>" << result.postings_processed << "</postings><time_ns>" << result.search_time_in_ns << "</time_ns>\n";
+			stats_file << "<id>" << result.query_id << "</id><query>" << result.query << "</query><postings>" << result.postings_processed << "</postings><time_ns>" << result.search_time_in_ns << "</time_ns><out_of_time>" << result.time_budget_exceeded << "</out_of_time><postings_saved>" << result.postings_saved << "</postings_saved>\n";
 			stats.sum_of_CPU_time_in_ns += result.search_time_in_ns;
+			stats.queries_out_of_time += result.time_budget_exceeded;
+			stats.postings_saved += result.postings_saved;
 			TREC_file << result.results_list;
 			}
 	stats_file << "</JASSv2stats>\n";
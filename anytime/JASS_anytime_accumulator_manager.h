@@ -23,6 +23,28 @@
 
 namespace JASS_anytime_accumulator_manager
 	{
+	/*
+		GET_HEAP_BY_WIDTH()
+		-------------------
+	*/
+	/*!
+		@brief Return a heap-based accumulator manager with accumulators of the given width
+		@tparam ACCUMULATOR_ARRAY The accumulator array template (accumulator_2d or accumulator_simple)
+		@param codex [in] The decompressor that the manager should use
+		@param bits [in] The width of each accumulator in bits (8, 16, or 32)
+		@return An accumulator manager
+	*/
+	template <template <typename ELEMENT, typename = ELEMENT> class ACCUMULATOR_ARRAY>
+	JASS::query *get_heap_by_width(JASS::compress_integer &codex, size_t bits)
+		{
+		if (bits == 32)
+			return new JASS::query_heap<ACCUMULATOR_ARRAY<uint32_t>>(codex);
+		else if (bits == 16)
+			return new JASS::query_heap<ACCUMULATOR_ARRAY<uint16_t>>(codex);
+		else
+			return new JASS::query_heap<ACCUMULATOR_ARRAY<uint8_t>>(codex);
+		}
+
 	/*
 		GET_BY_NAME()
 		-------------
@@ -31,16 +53,22 @@ namespace JASS_anytime_accumulator_manager
 		@brief Return an accumulator manager given its name, which will normally come from the command line parameter parsing
 		@param name [in] The name of the manager to use
 		@param codex [in] The decompressor that the manager should use
+		@param bits [in] The width of each accumulator in bits (8, 16, or 32).  Only the heap managers support widths other than 8.
 		@return An accumulator manager
 	*/
-	JASS::query *get_by_name(const std::string &name, JASS::compress_integer &codex)
+	JASS::query *get_by_name(const std::string &name, JASS::compress_integer &codex, size_t bits = 8)
 		{
 		std::cout << "ACCUMULATOR MANAGER:" << name << "\n";
 
+		if ((name == "simple" || name == "blockmax") && bits != 8)
+			std::cout << "ACCUMULATOR MANAGER " << name << " ONLY SUPPORTS 8-BIT ACCUMULATORS! USING 8 BITS\n";
+		else
+			std::cout << "ACCUMULATOR BITS:" << bits << "\n";
+
 		if (name == "2d_heap")
-			return new JASS::query_heap<JASS::accumulator_2d<JASS::query::ACCUMULATOR_TYPE, JASS::query::MAX_DOCUMENTS>>(codex);
+			return get_heap_by_width<JASS::accumulator_2d>(codex, bits);
 		else if (name == "1d_heap")
-			return new JASS::query_heap<JASS::accumulator_simple<JASS::query::ACCUMULATOR_TYPE, JASS::query::MAX_DOCUMENTS>>(codex);
+			return get_heap_by_width<JASS::accumulator_simple>(codex, bits);
 		else if (name == "simple")
 			return new JASS::query_simple(codex);
 		else if (name == "blockmax")
@@ -48,7 +76,7 @@ namespace JASS_anytime_accumulator_manager
 		else
 			{
 			std::cout << "ACCUMULATOR MANAGER IS UNKNOWN! USING 2d_heap\n";
-			return new JASS::query_heap<JASS::accumulator_2d<JASS::query::ACCUMULATOR_TYPE, JASS::query::MAX_DOCUMENTS>>(codex);
+			return get_heap_by_width<JASS::accumulator_2d>(codex, bits);
 			}
 		}
 	}
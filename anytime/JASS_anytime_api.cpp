@@ -4,8 +4,15 @@
 	Copyright (c) 2021 Andrew Trotman
 	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
 */
+#include <new>
+#include <limits>
+#include <memory>
+#include <iostream>
+#include <filesystem>
+
 #include "timer.h"
 #include "threads.h"
+#include "stem_all.h"
 #include "query_heap.h"
 #include "run_export.h"
 #include "top_k_limit.h"
@@ -14,10 +21,66 @@
 #include "JASS_anytime_api.h"
 #include "JASS_anytime_query.h"
 #include "JASS_anytime_stats.h"
-#include "deserialised_jass_v2.h"
+#include "index_manifest.h"
+#include "deserialised_jass_v3.h"
 #include "JASS_anytime_thread_result.h"
 #include "JASS_anytime_accumulator_manager.h"
 
+/*
+	CLASS PHRASE_CHECKED_RESULTS
+	----------------------------
+*/
+/*!
+	@brief The results list after checking for phrases (and cutting it down to the top-k), in the form run_export() expects.
+*/
+class phrase_checked_results
+	{
+	public:
+		std::vector<JASS::query::docid_rsv_pair> results;		///< The results in order.
+		size_t current;													///< The next result to return.
+
+	public:
+		/*
+			PHRASE_CHECKED_RESULTS::PHRASE_CHECKED_RESULTS()
+			------------------------------------------------
+		*/
+		/*!
+			@brief Constructor
+		*/
+		phrase_checked_results() :
+			current(0)
+			{
+			/* Nothing */
+			}
+
+		/*
+			PHRASE_CHECKED_RESULTS::GET_FIRST()
+			-----------------------------------
+		*/
+		/*!
+			@brief Return the first result.
+			@return The first result, or nullptr if there are no results.
+		*/
+		JASS::query::docid_rsv_pair *get_first(void)
+			{
+			current = 0;
+			return get_next();
+			}
+
+		/*
+			PHRASE_CHECKED_RESULTS::GET_NEXT()
+			----------------------------------
+		*/
+		/*!
+			@brief Return the next result.
+			@return The next result, or nullptr if there are no more results.
+		*/
+		JASS::query::docid_rsv_pair *get_next(void)
+			{
+			return current < results.size() ? &results[current++] : nullptr;
+			}
+	};
+
 /*
 	JASS_ANYTIME_API::ANYTIME_BOOTSTRAP()
 	-------------------------------------
@@ -36,11 +99,17 @@ JASS_anytime_api::JASS_anytime_api()
 	index = nullptr;
 	postings_to_process = (std::numeric_limits<size_t>::max)();
 	relative_postings_to_process = 1;
+	time_budget_in_ns = 0;
+	safe_early_termination = false;
+	verify_index = false;
+	phrase_boost = 0;
 	top_k = 10;
 	which_query_parser = JASS::parser_query::parser_type::query;
 	accumulator_width = 0;
+	accumulator_bits = 8;
 	stats.threads = 1;
 	accumulator_manager = "2d_heap";
+	stemmer_override = "";
 	}
 
 /*
@@ -77,13 +146,73 @@ JASS_anytime_api::thread_data &JASS_anytime_api::get_thread_local_data(size_t th
 			Allocate a JASS query object
 		*/
 		JASS::compress_integer &codex = *index->codex(codex_name, d_ness);
-		initial.jass_query = JASS_anytime_accumulator_manager::get_by_name(accumulator_manager, codex);
-		initial.jass_query->init(index->primary_keys(), index->document_count(), (JASS::query::DOCID_TYPE)top_k, accumulator_width);
+		initial.jass_query = JASS_anytime_accumulator_manager::get_by_name(accumulator_manager, codex, accumulator_bits);
+		size_t candidates = index->has_positions() ? JASS::maths::minimum(top_k * PHRASE_CANDIDATES, (size_t)index->document_count()) : top_k;		// more than top-k are checked for phrases
+		initial.jass_query->init(index->primary_keys(), index->document_count(), (JASS::query::DOCID_TYPE)candidates, accumulator_width);
+
+		/*
+			Allocate the query stemmer
+		*/
+		initial.stemmer.reset(JASS::stem_all::get_by_name(get_stemmer_name()));
 		}
 
 	return initial;
 	}
 
+/*
+	JASS_ANYTIME_API::SET_THREAD_STEMMERS()
+	---------------------------------------
+*/
+void JASS_anytime_api::set_thread_stemmers(void)
+	{
+	std::string name = get_stemmer_name();
+
+	for (auto &[thread_number, local] : thread_local_data)
+		local.stemmer.reset(JASS::stem_all::get_by_name(name));
+	}
+
+/*
+	JASS_ANYTIME_API::SET_STEMMER()
+	-------------------------------
+*/
+JASS_ERROR JASS_anytime_api::set_stemmer(const std::string &name)
+	{
+	if (!JASS::stem_all::is_known(name))
+		return JASS_ERROR_UNKNOWN_STEMMER;
+
+	stemmer_override = name;
+	set_thread_stemmers();
+
+	return JASS_ERROR_OK;
+	}
+
+/*
+	JASS_ANYTIME_API::USE_INDEX_STEMMER()
+	-------------------------------------
+*/
+JASS_ERROR JASS_anytime_api::use_index_stemmer(void)
+	{
+	stemmer_override = "";
+	set_thread_stemmers();
+
+	return JASS_ERROR_OK;
+	}
+
+/*
+	JASS_ANYTIME_API::GET_STEMMER_NAME()
+	------------------------------------
+*/
+std::string JASS_anytime_api::get_stemmer_name(void)
+	{
+	if (stemmer_override != "")
+		return stemmer_override;
+
+	if (index == nullptr)
+		return JASS::stem_all::NO_STEMMER;
+
+	return index->stemmer();
+	}
+
 /*
 	JASS_ANYTIME_API::LOAD_INDEX()
 	------------------------------
@@ -101,6 +230,30 @@ JASS_ERROR JASS_anytime_api::load_index(size_t index_version, const std::string
 	*/
 	try
 		{
+		/*
+			Work out which version the index is, from the manifest if there is one (else from the index itself), and make sure it's the one asked for
+		*/
+		JASS::index_manifest manifest;
+		bool has_manifest = false;
+		size_t detected_version;
+
+		if (index_version == 3)
+			detected_version = std::filesystem::exists(std::filesystem::path(directory) / JASS::serialise_jass_v3::FILENAME) ? 3 : 0;		// a JASS v3 index holds (and checks) its own manifest
+		else
+			{
+			has_manifest = manifest.read(directory);
+			detected_version = has_manifest ? manifest.version : JASS::index_manifest::legacy_version(directory);
+			}
+
+		if (index_version == 0)
+			{
+			if (!has_manifest && detected_version == 0)
+				return JASS_ERROR_BAD_INDEX;				// there is no index in the directory
+			index_version = detected_version;
+			}
+		else if ((has_manifest || detected_version != 0) && detected_version != index_version)
+			return JASS_ERROR_BAD_INDEX_VERSION;
+
 		switch (index_version)
 			{
 			case 1:
@@ -109,23 +262,53 @@ JASS_ERROR JASS_anytime_api::load_index(size_t index_version, const std::string
 			case 2:
 				index = new JASS::deserialised_jass_v2(verbose);
 				break;
+			case 3:
+				index = new JASS::deserialised_jass_v3(verbose);
+				break;
 			default:
 				return JASS_ERROR_BAD_INDEX_VERSION;
 			}
 
-		index->read_index(directory);
+		if (verbose)
+			std::cout << "Index version: " << index_version << (has_manifest || index_version == 3 ? "" : " (no manifest)") << "\n";
+
+		/*
+			Read it and check that it is what the manifest says it is
+		*/
+		index->set_memory_mapping(index_mapping);
+		bool loaded = index->read_index(directory, verify_index) != 0;
+		if (loaded && has_manifest)
+			{
+			std::string codex_name;
+			int32_t d_ness;
+
+			std::unique_ptr<JASS::compress_integer> codex(index->codex(codex_name, d_ness));
+			loaded = index->document_count() == manifest.documents && index->term_count() == manifest.terms && codex_name == manifest.codex && d_ness == manifest.d_ness;
+			}
 
-		if (index->document_count() > JASS::query::MAX_DOCUMENTS)
+		if (!loaded)
 			{
+			bool cannot_lock = index->cannot_lock();
 			delete index;
 			index = nullptr;
-			return JASS_ERROR_TOO_MANY_DOCUMENTS;
+			return cannot_lock ? JASS_ERROR_CANNOT_LOCK_INDEX : JASS_ERROR_BAD_INDEX;
 			}
 
 		/*
-			Set up the accumulators array (and other thread-local data). First the Score-at-a-Time table
+			Set up the accumulators array (and other thread-local data). First the Score-at-a-Time table.  These are sized from the
+			number of documents in the index, so if they can't be allocated then the index is too large.
 		*/
-		get_thread_local_data(0);
+		try
+			{
+			get_thread_local_data(0);
+			}
+		catch (std::bad_alloc &)
+			{
+			thread_local_data.erase(0);
+			delete index;
+			index = nullptr;
+			return JASS_ERROR_TOO_MANY_DOCUMENTS;
+			}
 
 		return JASS_ERROR_OK;
 		}
@@ -135,6 +318,49 @@ JASS_ERROR JASS_anytime_api::load_index(size_t index_version, const std::string
 		}
 	}
 
+/*
+	JASS_ANYTIME_API::SET_VERIFY_INDEX()
+	------------------------------------
+*/
+JASS_ERROR JASS_anytime_api::set_verify_index(bool on)
+	{
+	if (index != nullptr)
+		return JASS_ERROR_INDEX_ALREADY_LOADED;
+
+	verify_index = on;
+
+	return JASS_ERROR_OK;
+	}
+
+/*
+	JASS_ANYTIME_API::SET_MEMORY_MAPPING()
+	--------------------------------------
+*/
+JASS_ERROR JASS_anytime_api::set_memory_mapping(bool lazy, const std::string &access_pattern, size_t prefetch_terms, bool lock)
+	{
+	if (index != nullptr)
+		return JASS_ERROR_INDEX_ALREADY_LOADED;
+
+	JASS::deserialised_jass_v1::memory_mapping how;
+	if (access_pattern == "normal")
+		how.advice = JASS::file::file_read_only::NORMAL;
+	else if (access_pattern == "random")
+		how.advice = JASS::file::file_read_only::RANDOM;
+	else if (access_pattern == "sequential")
+		how.advice = JASS::file::file_read_only::SEQUENTIAL;
+	else if (access_pattern == "willneed")
+		how.advice = JASS::file::file_read_only::WILLNEED;
+	else
+		return JASS_ERROR_UNKNOWN_ACCESS_PATTERN;
+
+	how.lazy = lazy;
+	how.prefetch_terms = prefetch_terms;
+	how.lock = lock;
+	index_mapping = how;
+
+	return JASS_ERROR_OK;
+	}
+
 /*
 	JASS_ANYTIME_API::SET_POSTINGS_TO_PROCESS_PROPORTION()
 	------------------------------------------------------
@@ -171,6 +397,66 @@ JASS_ERROR JASS_anytime_api::set_postings_to_process(size_t count)
 	return JASS_ERROR_OK;
 	}
 
+/*
+	JASS_ANYTIME_API::SET_TIME_BUDGET_NS()
+	--------------------------------------
+*/
+JASS_ERROR JASS_anytime_api::set_time_budget_ns(size_t nanoseconds)
+	{
+	time_budget_in_ns = nanoseconds;
+
+	return JASS_ERROR_OK;
+	}
+
+/*
+	JASS_ANYTIME_API::GET_TIME_BUDGET_NS()
+	--------------------------------------
+*/
+size_t JASS_anytime_api::get_time_budget_ns(void)
+	{
+	return time_budget_in_ns;
+	}
+
+/*
+	JASS_ANYTIME_API::SET_SAFE_EARLY_TERMINATION()
+	----------------------------------------------
+*/
+JASS_ERROR JASS_anytime_api::set_safe_early_termination(bool on)
+	{
+	safe_early_termination = on;
+
+	return JASS_ERROR_OK;
+	}
+
+/*
+	JASS_ANYTIME_API::GET_SAFE_EARLY_TERMINATION()
+	----------------------------------------------
+*/
+bool JASS_anytime_api::get_safe_early_termination(void)
+	{
+	return safe_early_termination;
+	}
+
+/*
+	JASS_ANYTIME_API::SET_PHRASE_BOOST()
+	------------------------------------
+*/
+JASS_ERROR JASS_anytime_api::set_phrase_boost(size_t boost)
+	{
+	phrase_boost = boost;
+
+	return JASS_ERROR_OK;
+	}
+
+/*
+	JASS_ANYTIME_API::GET_PHRASE_BOOST()
+	------------------------------------
+*/
+size_t JASS_anytime_api::get_phrase_boost(void)
+	{
+	return phrase_boost;
+	}
+
 /*
 	JASS_ANYTIME_API::GET_POSTINGS_TO_PROCESS()
 	-------------------------------------------
@@ -189,7 +475,7 @@ JASS_ERROR JASS_anytime_api::set_top_k(size_t k)
 	if (index != nullptr)
 		return JASS_ERROR_INDEX_ALREADY_LOADED;
 
-	if (k > JASS::query::MAX_TOP_K)
+	if (k > get_max_top_k())
 		return JASS_ERROR_TOO_LARGE;
 
 	top_k = k;
@@ -224,7 +510,10 @@ size_t JASS_anytime_api::get_top_k(void)
 */
 JASS::query::DOCID_TYPE JASS_anytime_api::get_max_top_k(void)
 	{
-	return JASS::query::MAX_TOP_K;
+	if (index != nullptr)
+		return index->document_count();
+
+	return (std::numeric_limits<JASS::query::DOCID_TYPE>::max)();
 	}
 
 /*
@@ -236,7 +525,7 @@ JASS_ERROR JASS_anytime_api::get_encoding_scheme(std::string &codex_name, int32_
 	if (index == nullptr)
 		return JASS_ERROR_NO_INDEX;
 
-	index->codex(codex_name, d_ness);
+	std::unique_ptr<JASS::compress_integer> codex(index->codex(codex_name, d_ness));
 
 	return JASS_ERROR_OK;
 	}
@@ -291,6 +580,16 @@ JASS_ERROR JASS_anytime_api::use_query_parser(void)
 	return JASS_ERROR_OK;
 	}
 
+/*
+	JASS_ANYTIME_API::USE_JSON_PARSER()
+	-----------------------------------
+*/
+JASS_ERROR JASS_anytime_api::use_json_parser(void)
+	{
+	which_query_parser = JASS::parser_query::parser_type::json;
+	return JASS_ERROR_OK;
+	}
+
 /*
 	JASS_ANYTIME_API::SET_ACCUMULATOR_WIDTH()
 	-----------------------------------------
@@ -307,6 +606,22 @@ JASS_ERROR JASS_anytime_api::set_accumulator_width(size_t width)
 	return JASS_ERROR_OK;
 	}
 
+/*
+	JASS_ANYTIME_API::SET_ACCUMULATOR_BITS()
+	----------------------------------------
+*/
+JASS_ERROR JASS_anytime_api::set_accumulator_bits(size_t bits)
+	{
+	if (index != nullptr)
+		return JASS_ERROR_INDEX_ALREADY_LOADED;
+
+	if (bits != 8 && bits != 16 && bits != 32)
+		return JASS_ERROR_BAD_ACCUMULATOR_BITS;
+
+	accumulator_bits = bits;
+	return JASS_ERROR_OK;
+	}
+
 /*
 	JASS_ANYTIME_API::SEARCH()
 	--------------------------
@@ -428,7 +743,9 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 		/*
 			Process the query
 		*/
-		local.jass_query->parse(query, which_query_parser);
+		local.jass_query->parse(query, which_query_parser, local.stemmer.get(), &index->stopwords());
+		if (local.jass_query->terms().has_too_many_boolean_terms())
+			std::cerr << "Query " << query_id << " rejected: a boolean query can have no more than " << JASS::query_term_list::max_boolean_terms << " different terms\n";
 
 		/*
 			Parse the query and extract the list of impact segments
@@ -439,6 +756,19 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 		uint32_t smallest_possible_rsv = (std::numeric_limits<decltype(smallest_possible_rsv)>::max)();
 		size_t query_terms_count = local.jass_query->terms().size();
 		uint64_t total_postings_for_query = 0;
+		bool boolean_query = local.jass_query->terms().is_boolean();
+
+		/*
+			Keep a copy of the phrases as the query's terms are discarded before the search starts
+		*/
+		std::vector<std::vector<std::string>> phrases;
+		if (index->has_positions())
+			for (const auto &phrase : local.jass_query->terms().get_phrases())
+				{
+				phrases.push_back(std::vector<std::string>());
+				for (const auto &term : phrase)
+					phrases.back().push_back(std::string(reinterpret_cast<char *>(term.address()), term.size()));
+				}
 //std::cout << "\n";
 		for (const auto &term : local.jass_query->terms())
 			{
@@ -457,8 +787,27 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 			uint32_t term_smallest_impact;
 			uint32_t term_largest_impact;
 			JASS::query::DOCID_TYPE document_frequency;
-			current_segment += index->get_segment_list(current_segment, metadata, term.frequency(), term_smallest_impact, term_largest_impact, document_frequency);
+			auto *first_segment_for_term = current_segment;
+			size_t term_number = &term - local.jass_query->terms().begin();
+			current_segment += index->get_segment_list(current_segment, metadata, term.weight(), term_smallest_impact, term_largest_impact, document_frequency);
+
+			/*
+				For a boolean query note which documents contain the term.  Prohibited terms are only used for this, they are not scored.
+			*/
+			if (boolean_query)
+				{
+				for (auto *segment = first_segment_for_term; segment < current_segment; segment++)
+					local.jass_query->mark_term(term_number, segment->segment_frequency, index->postings() + segment->offset, segment->end - segment->offset);
+				if (term.is_prohibited())
+					{
+					current_segment = first_segment_for_term;
+					continue;
+					}
+				}
+
 			total_postings_for_query += document_frequency;
+			for (auto *segment = first_segment_for_term; segment < current_segment; segment++)
+				segment->query_term = term_number;
 
 			/*
 				Compute the largest and smallest possible rsv values
@@ -467,6 +816,12 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 			smallest_possible_rsv = JASS::maths::minimum(smallest_possible_rsv, (decltype(smallest_possible_rsv))term_smallest_impact);
 			}
 
+		/*
+			For a boolean query work out which documents satisfy the query, only they are scored.
+		*/
+		if (boolean_query)
+			local.jass_query->apply_constraint();
+
 		/*
 			Sort the segments from highest impact to lowest impact
 		*/
@@ -498,12 +853,17 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 			Work out the dynamic impact score scaling factor (if necessary)
 		*/
 		bool scale_rsv_scores = false;
+		const JASS::query::ACCUMULATOR_TYPE max_rsv = local.jass_query->get_max_rsv();
 		largest_possible_rsv_with_overflow = largest_possible_rsv;
-		if (largest_possible_rsv > JASS::query::MAX_RSV)
+		auto scale = [&](JASS::deserialised_jass_v1::segment_header &header)
+			{
+			header.impact = (JASS::query::ACCUMULATOR_TYPE)((double)header.impact / (double)largest_possible_rsv_with_overflow * ((double)max_rsv - query_terms_count) + 1);
+			};
+		if (largest_possible_rsv > max_rsv)
 			{
 			scale_rsv_scores = true;
-			smallest_possible_rsv = (uint32_t)((double)smallest_possible_rsv / (double)largest_possible_rsv * (double)JASS::query::MAX_RSV);
-			largest_possible_rsv = JASS::query::MAX_RSV;
+			smallest_possible_rsv = (uint32_t)((double)smallest_possible_rsv / (double)largest_possible_rsv * (double)max_rsv);
+			largest_possible_rsv = max_rsv;
 
 			/*
 				Check for zeros
@@ -520,14 +880,55 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 		if (relative_postings_to_process != 1)
 			postings_to_process = total_postings_for_query * relative_postings_to_process;
 
+		/*
+			For safe early termination we need to know, before each segment, the largest amount a document's score can still increase by.  A document
+			occurs at most once in each term's postings list, so this is the sum (over the terms) of the largest impact that term has yet to process.
+		*/
+		size_t number_of_segments = current_segment - local.segment_order.get();
+		if (safe_early_termination)
+			{
+			if (scale_rsv_scores)
+				{
+				for (auto *header = local.segment_order.get(); header < current_segment; header++)
+					scale(*header);
+				scale_rsv_scores = false;
+				}
+
+			local.largest_possible_increase.resize(number_of_segments + 1);
+			local.term_largest_remaining_impact.assign(query_terms_count, 0);
+			JASS::query::ACCUMULATOR_TYPE increase = 0;
+			local.largest_possible_increase[number_of_segments] = 0;
+			for (size_t which = number_of_segments; which-- > 0;)
+				{
+				auto &header = local.segment_order[which];
+				increase += header.impact - local.term_largest_remaining_impact[header.query_term];		// the segments are in decreasing impact order so this can't be negative
+				local.term_largest_remaining_impact[header.query_term] = header.impact;
+				local.largest_possible_increase[which] = increase;
+				}
+			}
+
 		/*
 			Process the segments
 		*/
 		size_t postings_processed = 0;
+		size_t postings_saved = 0;
+		size_t segments_processed = 0;
+		bool time_budget_exceeded = false;
 		for (auto *header = local.segment_order.get(); header < current_segment; header++)
 			{
+			/*
+				If we have a time budget then every few segments check to see if we've spent it.  If so then stop.
+			*/
+			if (time_budget_in_ns != 0 && segments_processed % SEGMENTS_PER_TIME_CHECK == 0)
+				if ((size_t)JASS::timer::stop(total_search_time).nanoseconds() >= time_budget_in_ns)
+					{
+					time_budget_exceeded = true;
+					break;
+					}
+			segments_processed++;
+
 			if (scale_rsv_scores)
-				header->impact = (JASS::query::ACCUMULATOR_TYPE)((double)header->impact / (double)largest_possible_rsv_with_overflow * ((double)JASS::query::MAX_RSV - query_terms_count) + 1);
+				scale(*header);
 
 //std::cout << "Process Segment->(" << header->impact << ":" << header->segment_frequency << ")\n";
 			/*
@@ -544,13 +945,64 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 			*/
 			JASS::query::ACCUMULATOR_TYPE impact = header->impact;
 			local.jass_query->decode_and_process(impact, header->segment_frequency, index->postings() + header->offset, header->end - header->offset);
+
+			/*
+				If we're doing safe early termination then stop if the remaining segments can't change the results
+			*/
+			if (safe_early_termination && local.jass_query->is_top_k_settled(local.largest_possible_increase[header - local.segment_order.get() + 1]))
+				{
+				for (auto *unprocessed = header + 1; unprocessed < current_segment; unprocessed++)
+					postings_saved += unprocessed->segment_frequency;
+				break;
+				}
 			}
 
 		/*
 			Finally we have the results list in the heap, now sort it.
 		*/
 		local.jass_query->sort();
-		
+
+		/*
+			With a positional index the search found more than top-k documents (see PHRASE_CANDIDATES).  If the query has phrases then check
+			those documents for them, either removing those without the phrases or boosting those with them, then keep the top-k.
+		*/
+		phrase_checked_results checked;
+		if (index->has_positions())
+			{
+			std::vector<std::vector<JASS::slice>> phrase_terms;
+			for (auto &phrase : phrases)
+				{
+				phrase_terms.push_back(std::vector<JASS::slice>());
+				for (auto &term : phrase)
+					phrase_terms.back().push_back(JASS::slice(const_cast<char *>(term.c_str()), term.size()));
+				}
+
+			for (JASS::query::docid_rsv_pair *document = local.jass_query->get_first(); document != nullptr; document = local.jass_query->get_next())
+				{
+				size_t found = 0;
+				for (const auto &phrase : phrase_terms)
+					found += index->phrase_occurs(phrase, static_cast<uint32_t>(document->document_id));
+
+				if (phrase_boost == 0)
+					{
+					if (found == phrase_terms.size())
+						checked.results.push_back(*document);
+					}
+				else
+					{
+					checked.results.push_back(*document);
+					size_t boosted = (size_t)checked.results.back().rsv + found * phrase_boost;
+					checked.results.back().rsv = (JASS::query::ACCUMULATOR_TYPE)JASS::maths::minimum(boosted, (size_t)local.jass_query->get_max_rsv());
+					}
+				}
+
+			if (phrase_boost != 0)
+				std::stable_sort(checked.results.begin(), checked.results.end(), [](const auto &first, const auto &second){ return first.rsv > second.rsv; });
+
+			if (checked.results.size() > top_k)
+				checked.results.resize(top_k);
+			}
+
 		/*
 			stop the timer
 		*/
@@ -560,11 +1012,14 @@ void JASS_anytime_api::anytime(JASS_anytime_thread_result &output, std::vector<J
 			Serialise the results list (don't time this)
 		*/
 		std::ostringstream results_list;
-		JASS::run_export(JASS::run_export::TREC, results_list, query_id.c_str(), *local.jass_query, "JASSv2", true);
+		if (index->has_positions())
+			JASS::run_export(JASS::run_export::TREC, results_list, query_id.c_str(), checked, "JASSv2", true);
+		else
+			JASS::run_export(JASS::run_export::TREC, results_list, query_id.c_str(), *local.jass_query, "JASSv2", true);
 		/*
 			Store the results (and the time it took)
 		*/
-		output.push_back(query_id, query, results_list.str(), postings_processed, time_taken);
+		output.push_back(query_id, query, results_list.str(), postings_processed, time_taken, time_budget_exceeded, postings_saved);
 
 		/*
 			Re-start the timer
@@ -34,9 +34,14 @@ enum JASS_ERROR
 	JASS_ERROR_OK = 0,						///< Completed successfully without error
 	JASS_ERROR_BAD_INDEX_VERSION,			///< The index version number specified is not supported
 	JASS_ERROR_FAIL,							///< An exception occurred - probably not caused by JASS (might be a C++ RTL exception)
-	JASS_ERROR_TOO_MANY_DOCUMENTS,		///< This index cannot be loaded by this instance of the APIs because it contains more documents than the system-wide maximum
-	JASS_ERROR_TOO_LARGE,					///< top-k is larger than the system-wide maximum top-k value (or the accumulator width is too large)
+	JASS_ERROR_TOO_MANY_DOCUMENTS,		///< This index cannot be loaded by this instance of the APIs because there is not enough memory for accumulators for all its documents
+	JASS_ERROR_TOO_LARGE,					///< top-k is larger than the maximum top-k value (or the accumulator width is too large)
 	JASS_ERROR_INDEX_ALREADY_LOADED,		///< Attempt to load an index when an index has alrady been loaded
+	JASS_ERROR_UNKNOWN_STEMMER,			///< The stemmer is not known to JASS (see JASS::stem_all)
+	JASS_ERROR_BAD_ACCUMULATOR_BITS,		///< The accumulator width (in bits) is not supported (it must be 8, 16, or 32)
+	JASS_ERROR_BAD_INDEX,					///< The index is missing or damaged, or does not match its manifest (CImanifest.txt)
+	JASS_ERROR_UNKNOWN_ACCESS_PATTERN,	///< The memory access pattern is not known (it must be normal, random, sequential, or willneed)
+	JASS_ERROR_CANNOT_LOCK_INDEX,			///< The index was to be locked into memory (see set_memory_mapping()) but could not be (see ulimit -l)
 };
 
 /*
@@ -52,6 +57,8 @@ class JASS_anytime_api
 	private:
 		static constexpr size_t MAX_QUANTUM = 0x0FFF;			///< The maximum number of segments in a query
 		static constexpr size_t MAX_TERMS_PER_QUERY = 1024;	///< The maximum number of terms in a query
+		static constexpr size_t SEGMENTS_PER_TIME_CHECK = 8;	///< When there is a time budget, check the time after processing this many segments
+		static constexpr size_t PHRASE_CANDIDATES = 10;			///< With a positional index, this many times top-k documents are found then checked for the query's phrases
 
 	private:
 		/*
@@ -63,18 +70,28 @@ class JASS_anytime_api
 			public:
 				std::unique_ptr<JASS::deserialised_jass_v1::segment_header[]> segment_order;
 				JASS::query *jass_query;
+				std::unique_ptr<JASS::stem> stemmer;			// stemmers are not thread safe so each thread has its own (nullptr for no stemming)
+				std::vector<JASS::query::ACCUMULATOR_TYPE> largest_possible_increase;		// for safe early termination, the most a score can increase by from each segment onwards
+				std::vector<JASS::query::ACCUMULATOR_TYPE> term_largest_remaining_impact;	// used to compute largest_possible_increase
 			};
 
 	private:
 		JASS::deserialised_jass_v1 *index;							///< The index
 		size_t postings_to_process;									///< The maximunm number of postings to process
 		double relative_postings_to_process;						///< If not 1 then then this is the proportion of this query's postings that should be processed
+		size_t time_budget_in_ns;										///< If not 0 then stop processing a query once it has taken this many nanoseconds
+		bool safe_early_termination;									///< If true then stop processing a query once the top-k can no longer change
+		bool verify_index;												///< If true then check the index checksums when it is loaded
+		JASS::deserialised_jass_v1::memory_mapping index_mapping;	///< How the index files are mapped into memory when it is loaded
+		size_t phrase_boost;												///< With a positional index, 0 to remove results that don't contain the query's phrases, else the score added for each phrase found
 		size_t top_k;														///< The number of documents we want in the results list
 		JASS::parser_query::parser_type which_query_parser;	///< Use the simple ASCII parser or the regular query parser
 		size_t accumulator_width;										///< Width of the accumulator array
+		size_t accumulator_bits;										///< Width (in bits) of each accumulator (8, 16, or 32)
 		JASS_anytime_stats stats;										///< Stats for this "session"
 		std::map<size_t, thread_data> thread_local_data;		///< Data needed by each thread (the accumulators array, etc)
 		std::string accumulator_manager;								///< The name of the accumulator manager
+		std::string stemmer_override;									///< The name of the stemmer to use on queries, or "" to use the stemmer the index was built with
 
 	private:
 		/*
@@ -128,6 +145,15 @@ class JASS_anytime_api
 		*/
 		thread_data &get_thread_local_data(size_t thread_number);
 
+		/*
+			JASS_ANYTIME_API::SET_THREAD_STEMMERS()
+			---------------------------------------
+		*/
+		/*!
+			@brief (Re)create the query stemmer in each thread's local data so that it matches get_stemmer_name()
+		*/
+		void set_thread_stemmers(void);
+
 	public:
 		/*
 			JASS_ANYTIME_API::JASS_ANYTIME_API()
@@ -153,13 +179,46 @@ class JASS_anytime_api
 		*/
 		/*!
          @brief Load a JASS index from the given directory.
-         @param index_version [in] What verison of the index is this - normally 2.
+         @details If the index has a manifest (CImanifest.txt) then the version is taken from it and the index is checked against it, otherwise the
+         version is worked out from the index files.  A JASS v3 index (CIindex.bin) holds its own manifest, and is chosen over a JASS v1 or v2 index in the
+         same directory only if index_version is 3.  If index_version is not 0 and the index is a different version then JASS_ERROR_BAD_INDEX_VERSION is returned.
+         @param index_version [in] What verison of the index is this, 1, 2, 3, or 0 to detect it from the index.
          @param directory[in] The path to the index, default = "."
          @param verbose [in] if true, diagnostics are printed while the index is loading, default = false
          @return JASS_ERROR_OK on success, else an error code.
 		*/
 		JASS_ERROR load_index(size_t index_version, const std::string &directory = "", bool verbose = false);		// verbose prints progress as it loads the index
 
+		/*
+			JASS_ANYTIME_API::SET_VERIFY_INDEX()
+			------------------------------------
+		*/
+		/*!
+         @brief Turn on (or off) checking the index checksums (in CImanifest.txt and CIchecksums.bin) when the index is loaded.
+         @details Must be called before load_index(), which then returns JASS_ERROR_BAD_INDEX if any file or postings list is damaged.  Checking
+         reads each file twice so loading is slower.  Indexes built without checksums are not checked.  By default this is off.
+         @param on [in] true to check the checksums, false not to.
+         @return JASS_ERROR_OK, or JASS_ERROR_INDEX_ALREADY_LOADED if the index has already been loaded.
+		*/
+		JASS_ERROR set_verify_index(bool on);
+
+		/*
+			JASS_ANYTIME_API::SET_MEMORY_MAPPING()
+			--------------------------------------
+		*/
+		/*!
+         @brief Set how the index is mapped into memory when it is loaded.
+         @details By default the whole index is read in when it is loaded.  If lazy then the vocabulary and postings are mapped (read only) but
+         not read, so loading is fast and each page is read from disk the first time a query needs it.  Either way the pages are shared through the
+         page cache with every other process that has the same index loaded.  Must be called before load_index().
+         @param lazy [in] true to read each page on first use, false to read the index in when it is loaded.
+         @param access_pattern [in] How the postings will be used, passed to madvise() (normal, random, sequential, or willneed) [default = normal].
+         @param prefetch_terms [in] Read in the postings of this many terms (those with the longest postings lists) when the index is loaded [default = 0].
+         @param lock [in] Lock the index into memory so that it cannot be paged out (this usually needs privileges or a large ulimit -l), if it cannot be then load_index() returns JASS_ERROR_CANNOT_LOCK_INDEX [default = false].
+         @return JASS_ERROR_OK, JASS_ERROR_UNKNOWN_ACCESS_PATTERN, or JASS_ERROR_INDEX_ALREADY_LOADED if the index has already been loaded.
+		*/
+		JASS_ERROR set_memory_mapping(bool lazy, const std::string &access_pattern = "normal", size_t prefetch_terms = 0, bool lock = false);
+
 		/*
 			JASS_ANYTIME_API::GET_DOCUMENT_COUNT()
 			--------------------------------------
@@ -216,6 +275,39 @@ class JASS_anytime_api
 			accumulator_manager = name;
 			}
 
+		/*
+			JASS_ANYTIME_API::SET_STEMMER()
+			-------------------------------
+		*/
+		/*!
+			@brief Force query-time stemming with the named stemmer, overriding the stemmer the index was built with.
+			@details By default queries are stemmed with the same stemmer that was used to build the index (as recorded in the index).
+			This method overrides that.  Use "None" to disable query-time stemming, and use_index_stemmer() to return to the default.
+			@param name [in] The name of the stemmer (for example, "Porter"), or "None"
+			@return JASS_ERROR_OK, or JASS_ERROR_UNKNOWN_STEMMER if the stemmer is not known.
+		*/
+		JASS_ERROR set_stemmer(const std::string &name);
+
+		/*
+			JASS_ANYTIME_API::USE_INDEX_STEMMER()
+			-------------------------------------
+		*/
+		/*!
+			@brief Stem queries with the same stemmer that was used to build the index (this is the default).
+			@return Always returns JASS_ERROR_OK
+		*/
+		JASS_ERROR use_index_stemmer(void);
+
+		/*
+			JASS_ANYTIME_API::GET_STEMMER_NAME()
+			------------------------------------
+		*/
+		/*!
+			@brief Return the name of the stemmer that will be used on queries.
+			@return The name of the stemmer, or "None" if queries will not be stemmed.
+		*/
+		std::string get_stemmer_name(void);
+
 		/*
 			JASS_ANYTIME_API::SET_POSTINGS_TO_PROCESS_PROPORTION()
 			------------------------------------------------------
@@ -254,6 +346,79 @@ class JASS_anytime_api
 		*/
 		JASS_ERROR set_postings_to_process(size_t count);
 
+		/*
+			JASS_ANYTIME_API::SET_TIME_BUDGET_NS()
+			--------------------------------------
+		*/
+		/*!
+         @brief Set the maximum time a query may take, in nanoseconds.
+         @details An index does not need to be loaded first.  The time is checked every few segments so a query can run slightly over budget.  This can be used along
+         with the postings limits, in which case whichever is reached first stops the search.  A query that is cut short is flagged in JASS_anytime_result::time_budget_exceeded.
+         @param nanoseconds [in] The time budget for each query (0 means no limit, the default)
+         @return JASS_ERROR_OK
+		*/
+		JASS_ERROR set_time_budget_ns(size_t nanoseconds);
+
+		/*
+			JASS_ANYTIME_API::GET_TIME_BUDGET_NS()
+			--------------------------------------
+		*/
+		/*!
+         @brief Return the maximum time a query may take, in nanoseconds.
+         @return The time budget for each query (0 means no limit).
+		*/
+		size_t get_time_budget_ns(void);
+
+		/*
+			JASS_ANYTIME_API::SET_SAFE_EARLY_TERMINATION()
+			----------------------------------------------
+		*/
+		/*!
+         @brief Turn on (or off) safe early termination, stopping a query once the remaining postings can no longer change the top-k or its order.
+         @details An index does not need to be loaded first.  The results are the same as processing all the postings (although the scores might be lower)
+         but fewer postings are processed.  The number of postings not processed is reported in JASS_anytime_result::postings_saved.  Only the heap
+         accumulator managers (2d_heap and 1d_heap) support this; with the others all postings are processed.  By default this is off.
+         @param on [in] true to turn on safe early termination, false to turn it off.
+         @return JASS_ERROR_OK
+		*/
+		JASS_ERROR set_safe_early_termination(bool on);
+
+		/*
+			JASS_ANYTIME_API::GET_SAFE_EARLY_TERMINATION()
+			----------------------------------------------
+		*/
+		/*!
+         @brief Return whether or not safe early termination is being used.
+         @return true if safe early termination is on, else false.
+		*/
+		bool get_safe_early_termination(void);
+
+		/*
+			JASS_ANYTIME_API::SET_PHRASE_BOOST()
+			------------------------------------
+		*/
+		/*!
+         @brief Set how phrases ("new york") in queries are handled.
+         @details Phrases can only be checked if the index was built with positions (JASS_index -P), otherwise they are just terms.  The top
+         PHRASE_CANDIDATES * top-k documents are found as usual then each is checked for the query's phrases.  If boost is 0 then any document that
+         does not contain every phrase is removed from the results list, otherwise boost is added to the score of a document for each phrase it
+         contains and the results list is re-ordered.  The top-k of these are returned.  Documents with the phrases that are not in the top
+         PHRASE_CANDIDATES * top-k are not found, so when filtering fewer than top-k results might still be returned.  By default boost is 0.
+         @param boost [in] 0 to filter on the phrases, else the amount to add to the score for each phrase found.
+         @return JASS_ERROR_OK
+		*/
+		JASS_ERROR set_phrase_boost(size_t boost);
+
+		/*
+			JASS_ANYTIME_API::GET_PHRASE_BOOST()
+			------------------------------------
+		*/
+		/*!
+         @brief Return how phrases are handled (see set_phrase_boost()).
+         @return 0 if results without the phrases are removed, else the score added for each phrase found.
+		*/
+		size_t get_phrase_boost(void);
+
 		/*
 			JASS_ANYTIME_API::GET_POSTINGS_TO_PROCESS()
 			-------------------------------------------
@@ -269,8 +434,8 @@ class JASS_anytime_api
 			---------------------------------
 		*/
 		/*!
-         @brief Return the largest possible top_k value, which might be smaller than the number of documents in the collection
-         @return The maximum top-k value (normally 1000)
+         @brief Return the largest possible top_k value.  The accumulators and heap are sized from the index and top-k when the index is loaded so this is the number of documents in the collection (or the largest document id if no index has been loaded)
+         @return The maximum top-k value
 		*/
 		uint32_t get_max_top_k(void);
 
@@ -306,6 +471,30 @@ class JASS_anytime_api
 		*/
 		JASS_ERROR set_accumulator_width(size_t width);
 
+		/*
+			JASS_ANYTIME_API::SET_ACCUMULATOR_BITS()
+			----------------------------------------
+		*/
+		/*!
+         @brief Set the width (in bits) of each accumulator.  Wider accumulators are slower but can hold larger rsv scores without re-scaling the impacts.
+         @param bits [in] The width of an accumulator, 8, 16, or 32 [default = 8]
+         @return JASS_ERROR_OK, JASS_ERROR_INDEX_ALREADY_LOADED if the index has already been loaded, or JASS_ERROR_BAD_ACCUMULATOR_BITS if bits is not 8, 16, or 32
+		*/
+		JASS_ERROR set_accumulator_bits(size_t bits);
+
+		/*
+			JASS_ANYTIME_API::GET_ACCUMULATOR_BITS()
+			----------------------------------------
+		*/
+		/*!
+         @brief Return the width (in bits) of each accumulator
+         @return The width of an accumulator in bits
+		*/
+		size_t get_accumulator_bits(void)
+			{
+			return accumulator_bits;
+			}
+
 		/*
 			JASS_ANYTIME_API::USE_ASCII_PARSER()
 			------------------------------------
@@ -326,6 +515,17 @@ class JASS_anytime_api
 		*/
 		JASS_ERROR use_query_parser(void);
 
+		/*
+			JASS_ANYTIME_API::USE_JSON_PARSER()
+			-----------------------------------
+		*/
+		/*!
+         @brief Use the query parser for queries that are a JSON object of term and weight pairs such as {"covid": 1.5, "vaccine": 0.75}, as produced by learned sparse query encoders.
+         @details The terms must already be normalised (as with use_ascii_parser()) and each term's impact scores are multiplied by its weight.
+         @return Always returns JASS_ERROR_OK
+		*/
+		JASS_ERROR use_json_parser(void);
+
 		/*
 			JASS_ANYTIME_API::SEARCH()
 			--------------------------
@@ -28,6 +28,8 @@ class JASS_anytime_result
 		std::string results_list;			///< The results list
 		size_t postings_processed;			///< The number of postings processed for this query
 		size_t search_time_in_ns;			///< The time it took to resolve the query
+		bool time_budget_exceeded;			///< True if the search was cut short because the time budget was spent (see JASS_anytime_api::set_time_budget_ns())
+		size_t postings_saved;				///< The number of postings not processed because of safe early termination (see JASS_anytime_api::set_safe_early_termination())
 
 	/*
 		JASS_ANYTIME_RESULT::JASS_ANYTIME_RESULT()
@@ -41,7 +43,9 @@ class JASS_anytime_result
 		query(),
 		results_list(),
 		postings_processed(0),
-		search_time_in_ns(0)
+		search_time_in_ns(0),
+		time_budget_exceeded(false),
+		postings_saved(0)
 		{
 		/* Nothing */
 		}
@@ -57,13 +61,17 @@ class JASS_anytime_result
       @param results_list [in] The results list (normally in TREC format)
       @param postings_processed [in] The numvber of postings processed (that is, <docid, impact> pairs)
       @param search_time_in_ns [in] The time it took to resolve the query
+      @param time_budget_exceeded [in] True if the search was cut short because the time budget was spent
+      @param postings_saved [in] The number of postings not processed because of safe early termination
 	*/
-	JASS_anytime_result(const std::string &query_id, const std::string &query, const std::string &results_list, size_t postings_processed, size_t search_time_in_ns) :
+	JASS_anytime_result(const std::string &query_id, const std::string &query, const std::string &results_list, size_t postings_processed, size_t search_time_in_ns, bool time_budget_exceeded = false, size_t postings_saved = 0) :
 		query_id(query_id),
 		query(query),
 		results_list(results_list),
 		postings_processed(postings_processed),
-		search_time_in_ns(search_time_in_ns)
+		search_time_in_ns(search_time_in_ns),
+		time_budget_exceeded(time_budget_exceeded),
+		postings_saved(postings_saved)
 		{
 		/* Nothing */
 		}
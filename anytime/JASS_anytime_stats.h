@@ -28,6 +28,10 @@ class JASS_anytime_stats
 		size_t wall_time_in_ns;						///< Total wall time to do all the search (in nanoseconds)
 		size_t sum_of_CPU_time_in_ns;				///< Sum of the indivivual thread total timers (multi-threaded can be larger than wall_time_in_ns)
 		size_t total_run_time_in_ns;				///< includes I/O and everything (start main() to end of main()).
+		size_t time_budget_in_ns;					///< The time budget for each query (0 is no limit)
+		size_t queries_out_of_time;				///< The number of queries that were cut short because they spent their time budget
+		bool safe_early_termination;				///< Was safe early termination used?
+		size_t postings_saved;						///< The number of postings not processed because of safe early termination
 
 	public:
 		/*
@@ -43,7 +47,11 @@ class JASS_anytime_stats
 			number_of_queries(0),
 			wall_time_in_ns(0),
 			sum_of_CPU_time_in_ns(0),
-			total_run_time_in_ns(0)
+			total_run_time_in_ns(0),
+			time_budget_in_ns(0),
+			queries_out_of_time(0),
+			safe_early_termination(false),
+			postings_saved(0)
 			{
 			/* Nothing */
 			}
@@ -69,6 +77,13 @@ static inline std::ostream &operator<<(std::ostream &output, JASS_anytime_stats
 	output << "Total CPU wall time searching (sum of threads)   : " << data.sum_of_CPU_time_in_ns << " ns\n";
 	output << "Total time excluding I/O (per query)             : " << data.sum_of_CPU_time_in_ns / ((data.number_of_queries == 0) ? 1 : data.number_of_queries) << " ns\n";
 	output << "Total wall clock run time (inc I/O and search)   : " << data.total_run_time_in_ns << " ns\n";
+	if (data.time_budget_in_ns != 0)
+		{
+		output << "Time budget per query                            : " << data.time_budget_in_ns << " ns\n";
+		output << "Queries that ran out of time                     : " << data.queries_out_of_time << '\n';
+		}
+	if (data.safe_early_termination)
+		output << "Postings saved by safe early termination         : " << data.postings_saved << '\n';
 	output << "-------------------\n";
 	return output;
 	}
@@ -48,10 +48,12 @@ class JASS_anytime_thread_result
          @param results_list [in] The results list (normally in TREC format)
          @param postings_processed [in] The numvber of postings processed (that is, <docid, impact> pairs)
          @param search_time_in_ns [in] The time it took to resolve the query
+         @param time_budget_exceeded [in] True if the search was cut short because the time budget was spent
+         @param postings_saved [in] The number of postings not processed because of safe early termination
 		*/
-		void push_back(const std::string &query_id, const std::string &query, const std::string &results_list, size_t postings_processed, size_t search_time_in_ns)
+		void push_back(const std::string &query_id, const std::string &query, const std::string &results_list, size_t postings_processed, size_t search_time_in_ns, bool time_budget_exceeded = false, size_t postings_saved = 0)
 			{
-			results[query_id] = JASS_anytime_result(query_id, query, results_list, postings_processed, search_time_in_ns);
+			results[query_id] = JASS_anytime_result(query_id, query, results_list, postings_processed, search_time_in_ns, time_budget_exceeded, postings_saved);
 			}
 
 		/*
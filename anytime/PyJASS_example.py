@@ -10,6 +10,8 @@ print("ID:", results.query_id)
 print("query:", results.query)
 print("Postings Processed:", results.postings_processed)
 print("Time (ns):", results.search_time_in_ns)
+print("Out of time:", results.time_budget_exceeded)
+print("Postings Saved:", results.postings_saved)
 print("Results:")
 print(results.results_list)
 
@@ -42,27 +42,26 @@ namespace JASS
 		X.-F. Jia, A. Trotman, R. O'Keefe (2010), Efficient Accumulator Initialisation, Proceedings of the 15th Australasian Document Computing Symposium (ADCS 2010).
 		This implementation differs from that implenentation is so far as the size of the page is alwaya a whole power of 2 and thus the dirty flag can
 		be found with a bit shift rather than a mod.  It also uses dirty flags rather than clean flags as it requires one fewer instruction to check
+		The arrays are allocated by init() so the number of accumulators is set at runtime (normally from the number of documents in the index).
 		@tparam ELEMENT The type of accumulator being used (default is uint16_t)
-		@tparam NUMBER_OF_ACCUMULATORS The maxium number of documents allowed in any index
 	*/
-	template <typename ELEMENT, size_t NUMBER_OF_ACCUMULATORS, typename = typename std::enable_if<std::is_arithmetic<ELEMENT>::value, ELEMENT>::type>
+	template <typename ELEMENT, typename = typename std::enable_if<std::is_arithmetic<ELEMENT>::value, ELEMENT>::type>
 	class accumulator_2d
 		{
 		/*
 			This somewhat bizar line is so that unittest() can see the private members of another instance of the class.
 		*/
-		template<typename A, size_t B, typename C> friend class accumulator_2d;
+		template<typename A, typename B> friend class accumulator_2d;
 
-		private:
-			typedef uint8_t flag_type;
+		public:
+			typedef ELEMENT value_type;			///< The type of each accumulator
 
 		private:
-			static constexpr size_t maximum_number_of_dirty_flags = NUMBER_OF_ACCUMULATORS;
-			static constexpr size_t maximum_number_of_accumulators_allocated = NUMBER_OF_ACCUMULATORS + NUMBER_OF_ACCUMULATORS / 2;			///< The numner of accumulators that were actually allocated (recall that this is a 2D array)
+			typedef uint8_t flag_type;
 
 		private:
-			alignas(__m512i) flag_type dirty_flag[maximum_number_of_dirty_flags];																							///< The dirty flags are kept as bytes for faster lookup
-			alignas(__m512i) ELEMENT accumulator[maximum_number_of_accumulators_allocated];																				///< The accumulators are kept in an array
+			std::vector<flag_type> dirty_flag;					///< The dirty flags are kept as bytes for faster lookup
+			std::vector<ELEMENT> accumulator;					///< The accumulators are kept in an array
 
 			size_t width;												///< Each dirty flag represents this number of accumulators in a "row"
 			uint32_t shift;											///< The amount to shift to get the right dirty flag
@@ -134,10 +133,10 @@ namespace JASS
 				number_of_accumulators_allocated = width * number_of_dirty_flags;
 
 				/*
-					Check we've not gone past the end of the arrays
+					Allocate the arrays
 				*/
-				if (number_of_dirty_flags > maximum_number_of_dirty_flags || number_of_accumulators_allocated > maximum_number_of_accumulators_allocated)
-					throw std::bad_array_new_length();
+				dirty_flag.resize(number_of_dirty_flags);
+				accumulator.resize(number_of_accumulators_allocated);
 
 				/*
 					Clear the dirty flags ready for first use.
@@ -195,7 +194,7 @@ namespace JASS
 
 				if (dirty_flag[flag])
 					{
-					memset(&accumulator[0] + flag * width, 0, width * sizeof(accumulator[0]));
+					memset(accumulator.data() + flag * width, 0, width * sizeof(accumulator[0]));
 					dirty_flag[flag] = 0;
 					}
 
@@ -212,7 +211,7 @@ namespace JASS
 			*/
 			forceinline size_t get_index(ELEMENT *pointer)
 				{
-				return pointer - &accumulator[0];
+				return pointer - accumulator.data();
 				}
 
 			/*
@@ -240,7 +239,7 @@ namespace JASS
 			*/
 			void rewind(void)
 				{
-				::memset(&dirty_flag[0], 0xFF, number_of_dirty_flags * sizeof(dirty_flag[0]));
+				::memset(dirty_flag.data(), 0xFF, number_of_dirty_flags * sizeof(dirty_flag[0]));
 				}
 
 			/*
@@ -290,7 +289,7 @@ namespace JASS
 				/*
 					Allocate an array of 64 accumulators and make sure the width and height are correct
 				*/
-				accumulator_2d<size_t, 64> array;
+				accumulator_2d<size_t> array;
 				array.init(64);
 				JASS_assert(array.width == 8);
 				JASS_assert(array.shift == 3);
@@ -301,7 +300,7 @@ namespace JASS
 				/*
 					Make sure it all works right when there is a single accumulator in the last row
 				*/
-				accumulator_2d<size_t, 65> array_hangover;
+				accumulator_2d<size_t> array_hangover;
 				array_hangover.init(65);
 				JASS_assert(array_hangover.width == 8);
 				JASS_assert(array_hangover.shift == 3);
@@ -312,7 +311,7 @@ namespace JASS
 				/*
 					Make sure it all works right when there is a single accumulator missing from the last row
 				*/
-				accumulator_2d<size_t, 63> array_hangunder;
+				accumulator_2d<size_t> array_hangunder;
 				array_hangunder.init(63);
 				JASS_assert(array_hangunder.width == 4);
 				JASS_assert(array_hangunder.shift == 2);
@@ -323,7 +322,7 @@ namespace JASS
 				/*
 					Make sure it all works right when there is a single accumulator
 				*/
-				accumulator_2d<size_t, 1> array_one;
+				accumulator_2d<size_t> array_one;
 				array_one.init(1);
 				JASS_assert(array_one.width == 1);
 				JASS_assert(array_one.shift == 0);
@@ -35,24 +35,20 @@ namespace JASS
 	*/
 	/*!
 		@brief Store the accumulators in a block-max array as originally used in IOQP.
+		@details The arrays are allocated by init() so the number of accumulators is set at runtime (normally from the number of documents in the index).
 		@tparam ELEMENT The type of accumulator being used (default is uint16_t)
-		@tparam NUMBER_OF_ACCUMULATORS The maxium number of documents allowed in any index
 	*/
-	template <typename ELEMENT, size_t NUMBER_OF_ACCUMULATORS, typename = typename std::enable_if<std::is_arithmetic<ELEMENT>::value, ELEMENT>::type>
+	template <typename ELEMENT, typename = typename std::enable_if<std::is_arithmetic<ELEMENT>::value, ELEMENT>::type>
 	class accumulator_block_max
 		{
 		/*
 			This somewhat bizar line is so that unittest() can see the private members of another instance of the class.
 		*/
-		template<typename A, size_t B, typename C> friend class accumulator_block_max;
-
-		private:
-			static constexpr size_t maximum_number_of_blocks = NUMBER_OF_ACCUMULATORS;
-			static constexpr size_t maximum_number_of_accumulators_allocated = NUMBER_OF_ACCUMULATORS + NUMBER_OF_ACCUMULATORS / 2;			///< The numner of accumulators that were actually allocated (so that the last block is a full block)
+		template<typename A, typename B> friend class accumulator_block_max;
 
 		public:
-			alignas(__m512i) ELEMENT block_max[maximum_number_of_blocks];																						///< The dirty flags are kept as bytes for faster lookup
-			alignas(__m512i) ELEMENT accumulator[maximum_number_of_accumulators_allocated];																///< The accumulators are kept in an array
+			std::vector<ELEMENT> block_max;						///< The largest accumulator in each block
+			std::vector<ELEMENT> accumulator;					///< The accumulators are kept in an array
 
 			uint32_t shift;											///< The amount to shift to get the right dirty flag
 		public:
@@ -126,10 +122,10 @@ namespace JASS
 				number_of_accumulators_allocated = width * number_of_blocks;
 
 				/*
-					Check we've not gone past the end of the arrays
+					Allocate the arrays
 				*/
-				if (number_of_blocks > maximum_number_of_blocks || number_of_accumulators_allocated > maximum_number_of_accumulators_allocated)
-					throw std::bad_array_new_length();
+				block_max.resize(number_of_blocks);
+				accumulator.resize(number_of_accumulators_allocated);
 
 				/*
 					Clear the dirty flags ready for first use.
@@ -141,7 +137,7 @@ namespace JASS
 					there are more accumulators allocated (and accessed) then documents in the collections, so zero the ones that can't get
 					touched here in init() rather than in rewind()
 				*/
-				::memset(accumulator + number_of_accumulators, 0, (number_of_accumulators_allocated - number_of_accumulators) * sizeof(*accumulator));
+				::memset(accumulator.data() + number_of_accumulators, 0, (number_of_accumulators_allocated - number_of_accumulators) * sizeof(accumulator[0]));
 				}
 
 			/*
@@ -201,7 +197,7 @@ namespace JASS
 			*/
 			forceinline size_t get_index(ELEMENT *pointer)
 				{
-				return pointer - &accumulator[0];
+				return pointer - accumulator.data();
 				}
 
 			/*
@@ -230,8 +226,8 @@ namespace JASS
 				/*
 					Initialise the accumulators then initialise the block_max array
 				*/
-				::memset(accumulator, 0, number_of_accumulators * sizeof(*accumulator));
-				::memset(block_max, 0, number_of_blocks * sizeof(*block_max));
+				::memset(accumulator.data(), 0, number_of_accumulators * sizeof(accumulator[0]));
+				::memset(block_max.data(), 0, number_of_blocks * sizeof(block_max[0]));
 				}
 
 			/*
@@ -281,7 +277,7 @@ namespace JASS
 				/*
 					Allocate an array of 64 accumulators and make sure the width and height are correct
 				*/
-				accumulator_block_max<size_t, 64> array;
+				accumulator_block_max<size_t> array;
 				array.init(64);
 				JASS_assert(array.width == 8);
 				JASS_assert(array.shift == 3);
@@ -292,7 +288,7 @@ namespace JASS
 				/*
 					Make sure it all works right when there is a single accumulator in the last row
 				*/
-				accumulator_block_max<size_t, 65> array_hangover;
+				accumulator_block_max<size_t> array_hangover;
 				array_hangover.init(65);
 				JASS_assert(array_hangover.width == 8);
 				JASS_assert(array_hangover.shift == 3);
@@ -303,7 +299,7 @@ namespace JASS
 				/*
 					Make sure it all works right when there is a single accumulator missing from the last row
 				*/
-				accumulator_block_max<size_t, 63> array_hangunder;
+				accumulator_block_max<size_t> array_hangunder;
 				array_hangunder.init(63);
 				JASS_assert(array_hangunder.width == 4);
 				JASS_assert(array_hangunder.shift == 2);
@@ -314,7 +310,7 @@ namespace JASS
 				/*
 					Make sure it all works right when there is a single accumulator
 				*/
-				accumulator_block_max<size_t, 1> array_one;
+				accumulator_block_max<size_t> array_one;
 				array_one.init(1);
 				JASS_assert(array_one.width == 1);
 				JASS_assert(array_one.shift == 0);
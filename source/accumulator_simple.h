@@ -36,19 +36,22 @@ namespace JASS
 	*/
 	/*!
 		@brief Store the accumulators in an array.
+		@details The array is allocated by init() so the number of accumulators is set at runtime (normally from the number of documents in the index).
 		@tparam ELEMENT The type of accumulator being used (default is uint16_t)
-		@tparam NUMBER_OF_ACCUMULATORS The maxium number of documents allowed in any index
 	*/
-	template <typename ELEMENT, size_t NUMBER_OF_ACCUMULATORS, typename = typename std::enable_if<std::is_arithmetic<ELEMENT>::value, ELEMENT>::type>
+	template <typename ELEMENT, typename = typename std::enable_if<std::is_arithmetic<ELEMENT>::value, ELEMENT>::type>
 	class accumulator_simple
 		{
 		/*
 			This somewhat bizar line is so that unittest() can see the private members of another instance of the class.
 		*/
-		template<typename A, size_t B, typename C> friend class accumulator_simple;
+		template<typename A, typename B> friend class accumulator_simple;
+
+		public:
+			typedef ELEMENT value_type;			///< The type of each accumulator
 
 		private:
-			ELEMENT accumulator[NUMBER_OF_ACCUMULATORS];				///< The accumulator array
+			std::vector<ELEMENT> accumulator;							///< The accumulator array
 			size_t number_of_accumulators;								///< The number of accumulators that the user asked for
 
 		public:
@@ -89,6 +92,7 @@ namespace JASS
 			void init(size_t number_of_accumulators, size_t preferred_width = 0)
 				{
 				this->number_of_accumulators = number_of_accumulators;
+				accumulator.resize(number_of_accumulators);
 				rewind();
 				}
 
@@ -131,7 +135,7 @@ namespace JASS
 			*/
 			forceinline size_t get_index(ELEMENT *pointer)
 				{
-				return pointer - &accumulator[0];
+				return pointer - accumulator.data();
 				}
 
 			/*
@@ -159,7 +163,7 @@ namespace JASS
 			*/
 			void rewind(void)
 				{
-				::memset(accumulator, 0, number_of_accumulators * sizeof(*accumulator));
+				::memset(accumulator.data(), 0, number_of_accumulators * sizeof(accumulator[0]));
 				}
 
 			/*
@@ -209,14 +213,14 @@ namespace JASS
 				/*
 					Allocate an array of 64 accumulators
 				*/
-				accumulator_simple<size_t, 64> array;
+				accumulator_simple<size_t> array;
 				array.init(64);
 				unittest_example(array);
 
 				/*
 					Make sure it all works right when there is a single accumulator
 				*/
-				accumulator_simple<size_t, 1> array_one;
+				accumulator_simple<size_t> array_one;
 				array_one.init(1);
 				unittest_example(array_one);
 
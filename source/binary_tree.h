@@ -349,6 +349,29 @@ namespace JASS
 				{
 				return find_and_add(key, nullptr, root);
 				}
+
+			/*
+				BINARY_TREE::FIND()
+				-------------------
+			*/
+			/*!
+				@brief Return a pointer to the element stored for the given key, without adding the key to the tree if it is not already there.
+				@param key [in] They key to find the data for.
+				@return The element associated with the key - or nullptr if the key is not in the tree.
+			*/
+			const ELEMENT *find(const KEY &key) const
+				{
+				node *current = root.load();
+				while (current != nullptr)
+					if (key < current->key)
+						current = current->right.load();
+					else if (current->key < key)
+						current = current->left.load();
+					else
+						return &current->element;
+
+				return nullptr;
+				}
 			
 			/*
 				BINARY_TREE::UNITTEST()
@@ -390,6 +413,15 @@ namespace JASS
 					output << key.first;
 				
 				JASS_assert(output.str() == "9876543210");
+
+				/*
+					Check find() finds what is there and doesn't add what isn't
+				*/
+				JASS_assert(*tree.find(slice("6")) == slice("six"));
+				JASS_assert(tree.find(slice("10")) == nullptr);
+				std::ostringstream unchanged;
+				unchanged << tree;
+				JASS_assert(unchanged.str() == serialised.str());
 				
 				puts("binary_tree::PASSED");
 				}
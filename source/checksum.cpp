@@ -10,6 +10,7 @@
 #include <fstream>
 #include <sstream>
 #include <iomanip>
+#include <vector>
 #include <iostream>
 #include <iterator>
 
@@ -56,6 +57,25 @@ namespace JASS
 		return fletcher_16(start, end);
 		}
 
+	/*
+		CHECKSUM::FLETCHER_16_CONTINUE()
+		--------------------------------
+	*/
+	uint16_t checksum::fletcher_16_continue(uint16_t checksum, const void *address, size_t length)
+		{
+		uint16_t sum_1 = checksum & 0xFF;
+		uint16_t sum_2 = checksum >> 8;
+
+		const uint8_t *end = (const uint8_t *)address + length;
+		for (const uint8_t *current = (const uint8_t *)address; current < end; current++)
+			{
+			sum_1 = (sum_1 + *current) % 255;
+			sum_2 = (sum_2 + sum_1) % 255;
+			}
+
+		return (sum_2 << 8) | sum_1;
+		}
+
 	/*
 		CHECKSUM::FLETCHER_16()
 		-----------------------
@@ -94,7 +114,13 @@ namespace JASS
 	uint16_t checksum::fletcher_16_file(const std::string &filename)
 		{
 		std::ifstream file(filename, std::ios::binary);
-		return fletcher_16(file);
+		std::vector<char> buffer(1024 * 1024);
+		uint16_t checksum = 0;
+
+		while (file.read(buffer.data(), buffer.size()) || file.gcount() != 0)
+			checksum = fletcher_16_continue(checksum, buffer.data(), static_cast<size_t>(file.gcount()));
+
+		return checksum;
 		}
 
 	/*
@@ -142,7 +168,28 @@ namespace JASS
 		std::istringstream stream(unittest_data::ten_documents);
 		checksum = checksum::fletcher_16(stream);
 		JASS_assert(checksum == 0xF7DE);
-		
+
+		/*
+			Check that the checksum can be computed in pieces
+		*/
+		const char *documents = unittest_data::ten_documents.c_str();
+		for (size_t split : {static_cast<size_t>(0), static_cast<size_t>(1), static_cast<size_t>(100), unittest_data::ten_documents.size()})
+			{
+			checksum = checksum::fletcher_16_continue(checksum::fletcher_16(documents, split), documents + split, unittest_data::ten_documents.size() - split);
+			JASS_assert(checksum == 0xF7DE);
+			}
+
+		/*
+			Check the file version
+		*/
+		{
+		std::ofstream file("checksum_unittest.txt", std::ios::binary);
+		file << unittest_data::ten_documents;
+		}
+		checksum = checksum::fletcher_16_file("checksum_unittest.txt");
+		remove("checksum_unittest.txt");
+		JASS_assert(checksum == 0xF7DE);
+
 		/*
 			Passed!
 		*/
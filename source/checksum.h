@@ -57,6 +57,20 @@ namespace JASS
 				@return The Fletcher 16-bit checksum of the 8-bit sequence.
 			*/
 			static uint16_t fletcher_16(const void *data, size_t length);
+
+			/*
+				CHECKSUM::FLETCHER_16_CONTINUE()
+				--------------------------------
+			*/
+			/*!
+				@brief Continue a Fletcher 16-bit checksum over more data, so that data can be checksummed as it is written (or read) in pieces.
+				@details The checksum holds both of Fletcher's sums, so fletcher_16_continue(fletcher_16(a), b) == fletcher_16(a followed by b).
+				@param checksum [in] The checksum of the data before this data (0 if there is none).
+				@param data [in] A pointer to a sequence of bytes of length length to checksum.
+				@param length [in] The number of bytes to checksum.
+				@return The Fletcher 16-bit checksum of the earlier data followed by this data.
+			*/
+			static uint16_t fletcher_16_continue(uint16_t checksum, const void *data, size_t length);
 	
 			/*
 				CHECKSUM::FLETCHER_16()
@@ -88,7 +102,7 @@ namespace JASS
 				----------------------------
 			*/
 			/*!
-				@brief Compute the Fletcher 16-bit checksum of a disk file.
+				@brief Compute the Fletcher 16-bit checksum of a disk file (which is read in blocks).
 				@param filename [in] The path to the file to checksum.
 				@return The Fletcher 16-bit checksum of the file.
 			*/
@@ -6,7 +6,9 @@
 */
 #include <assert.h>
 
+#include <memory>
 #include <vector>
+#include <typeinfo>
 
 #include "compress_integer_all.h"
 #include "compress_integer_none.h"
@@ -77,7 +79,7 @@ namespace JASS
 		COMPRESS_INTEGER_ALL::REPLICATE()
 		---------------------------------
 	*/
-	compress_integer *compress_integer_all::replicate(std::string &shortname)
+	compress_integer *compress_integer_all::replicate(const std::string &shortname)
 		{
 		/*
 			Put the most likley ones first.
@@ -200,6 +202,33 @@ namespace JASS
 				parameters_selected++;					// LCOV_EXCL_LINE		// if the unit test is successful then this should not be called.
 		JASS_assert(parameters_selected == 0);
 		JASS_assert(name(parameters) == compressors[default_compressor].description);
+		JASS_assert(shortname(parameters) == "");
+
+		/*
+			Check the lookups by short name
+		*/
+		parameters[5] = true;
+		JASS_assert(shortname(parameters) == "-cE");
+		JASS_assert(is_known("-cE") && !is_known("-cE2") && !is_known(""));
+		JASS_assert(name_of("-c256") == "Binpack into 256-bit SIMD integers");
+		JASS_assert(name_of("-cUnknown") == "");
+		JASS_assert(shortname_of("Binpack into 256-bit SIMD integers") == "-c256");
+		JASS_assert(shortname_of("Unknown") == "");
+		for (const auto &compressor : compressors)
+			JASS_assert(shortname_of(name_of(compressor.shortname)) == compressor.shortname);
+
+		for (const auto &compressor : compressors)
+			{
+			std::unique_ptr<compress_integer> codex(replicate(compressor.shortname));
+			JASS_assert(codex != nullptr);
+			JASS_assert(is_known(compressor.shortname));
+
+			/*
+				An index records the name of its codex, and the decoder is made from that name
+			*/
+			std::unique_ptr<compress_integer> decoder(get_by_name(name_of(compressor.shortname)));
+			JASS_assert(decoder != nullptr && typeid(*decoder) == typeid(*codex));
+			}
 
 		puts("compress_integer_all::PASSED");
 		}
@@ -86,19 +86,18 @@ namespace JASS
 				return std::tuple_cat(make_commandline<I - 1>(option), std::make_tuple(commandline::parameter(compress_integer_all::compressors[I].shortname, compress_integer_all::compressors[I].longname, compress_integer_all::compressors[I].description, option[I])));
 				}
 
+		public:
 			/*
 				COMPRESS_INTEGER_ALL::REPLICATE()
 				---------------------------------
 			*/
 			/*!
-
 				@brief Turn a static reference to an obect into a dynamically allocated object
-				@param codex [in] one of the objects in compress_integer_all::compressors[]
-				@return A dynamically allocated object of the same type
+				@param shortname [in] The short command line parameter of one of the objects in compress_integer_all::compressors[] (see is_known())
+				@return A dynamically allocated object of the same type (caller to free)
 			*/
-			static compress_integer *replicate(std::string &shortname);
+			static compress_integer *replicate(const std::string &shortname);
 
-		public:
 			/*
 				COMPRESS_INTEGER_ALL::PARAMETERLIST()
 				-------------------------------------
@@ -149,6 +148,78 @@ namespace JASS
 				return compressors[default_compressor].description;
 				}
 
+			/*
+				COMPRESS_INTEGER_ALL::SHORTNAME()
+				---------------------------------
+			*/
+			/*!
+				@brief Get the short command line parameter (e.g. "-cE") of the first selected compressor (according to option).
+				@param option [in] An array (one per compressor) with (preferably) one set to true.
+				@return The short name of the first selected compressor, or "" if none is selected.
+			*/
+			static const std::string shortname(const std::array<bool, compressors_size> &option)
+				{
+				for (size_t which = 0; which < compressors_size; which++)
+					if (option[which])
+						return compressors[which].shortname;
+
+				return "";
+				}
+
+			/*
+				COMPRESS_INTEGER_ALL::IS_KNOWN()
+				--------------------------------
+			*/
+			/*!
+				@brief Is there a compressor with the given short command line parameter?
+				@param shortname [in] The short name of the compressor (e.g. "-cE").
+				@return true if replicate() understands this name, else false.
+			*/
+			static bool is_known(const std::string &shortname)
+				{
+				for (size_t which = 0; which < compressors_size; which++)
+					if (compressors[which].shortname == shortname)
+						return true;
+
+				return false;
+				}
+
+			/*
+				COMPRESS_INTEGER_ALL::NAME_OF()
+				-------------------------------
+			*/
+			/*!
+				@brief Given the short command line parameter of a compressor, return its name.
+				@param shortname [in] The short name of the compressor (e.g. "-cE").
+				@return The name of the compressor, or "" if it is not known.
+			*/
+			static const std::string name_of(const std::string &shortname)
+				{
+				for (size_t which = 0; which < compressors_size; which++)
+					if (compressors[which].shortname == shortname)
+						return compressors[which].description;
+
+				return "";
+				}
+
+			/*
+				COMPRESS_INTEGER_ALL::SHORTNAME_OF()
+				------------------------------------
+			*/
+			/*!
+				@brief Given the name of a compressor, return its short command line parameter (the inverse of name_of()).
+				@param name [in] The name of the compressor.
+				@return The short name of the compressor (e.g. "-cE"), or "" if it is not known.
+			*/
+			static const std::string shortname_of(const std::string &name)
+				{
+				for (size_t which = 0; which < compressors_size; which++)
+					if (compressors[which].description == name)
+						return compressors[which].shortname;
+
+				return "";
+				}
+
 			/*
 				COMPRESS_INTEGER_ALL::GET_BY_NAME()
 				-----------------------------------
@@ -4,11 +4,16 @@
 	Copyright (c) 2017 Andrew Trotman
 	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
 */
+#include <stdio.h>
+#include <string.h>
+
 #include <algorithm>
 #include <filesystem>
 
 #include "file.h"
 #include "slice.h"
+#include "checksum.h"
+#include "index_manifest.h"
 #include "serialise_jass_v1.h"
 #include "compress_integer_all.h"
 #include "deserialised_jass_v1.h"
@@ -88,7 +93,7 @@ namespace JASS
 		/*
 			Read the file of tripples that are the pointers to the terms (and the postings too)
 		*/
-		auto length = file::read_entire_file(vocab_filename, vocabulary_memory);
+		auto length = file::read_entire_file(vocab_filename, vocabulary_memory, !mapping.lazy);
 		if (length == 0)
 			return 0;
 		const uint8_t *vocab;
@@ -97,7 +102,7 @@ namespace JASS
 		/*
 			Read the file of strings that is the vocabulary
 		*/
-		auto bytes = file::read_entire_file(terms_filename, vocabulary_terms_memory);
+		auto bytes = file::read_entire_file(terms_filename, vocabulary_terms_memory, !mapping.lazy);
 		if (bytes == 0)
 			return 0;
 		terms = length / (sizeof(uint64_t) + sizeof(uint64_t) + sizeof(uint64_t));
@@ -149,7 +154,8 @@ namespace JASS
 		/*
 			Read the postings
 		*/
-		auto postings_memory_length = file::read_entire_file(filename, postings_memory);
+		auto postings_memory_length = file::read_entire_file(filename, postings_memory, !mapping.lazy);
+		postings_length = postings_memory.read_entire_file(postings_start);
 
 		/*
 			This can take some time so make some noise when we're finished
@@ -163,6 +169,205 @@ namespace JASS
 		return postings_memory_length;
 		}
 
+	/*
+		DESERIALISED_JASS_V1::READ_STEMMER()
+		------------------------------------
+	*/
+	const std::string &deserialised_jass_v1::read_stemmer(const std::string &filename)
+		{
+		std::string contents;
+
+		/*
+			If the file is missing then we have a legacy index that was not stemmed
+		*/
+		if (std::filesystem::exists(filename))
+			file::read_entire_file(filename, contents);
+
+		return deserialise_stemmer(contents);
+		}
+
+	/*
+		DESERIALISED_JASS_V1::DESERIALISE_STEMMER()
+		-------------------------------------------
+	*/
+	const std::string &deserialised_jass_v1::deserialise_stemmer(const std::string &contents)
+		{
+		stemmer_name = stem_all::NO_STEMMER;
+		if (contents.size() == 0)
+			return stemmer_name;
+
+		/*
+			The file contains the name of the stemmer (perhaps followed by a newline)
+		*/
+		auto end = contents.find_last_not_of(" \t\r\n");
+		if (end != std::string::npos)
+			stemmer_name = contents.substr(0, end + 1);
+
+		if (verbose)
+			std::cout << "Index stemmer: " << stemmer_name << "\n";
+
+		return stemmer_name;
+		}
+
+	/*
+		DESERIALISED_JASS_V1::READ_STOPWORDS()
+		--------------------------------------
+	*/
+	const stop_words &deserialised_jass_v1::read_stopwords(const std::string &filename)
+		{
+		/*
+			If the file is missing then we have a legacy index that was not stopped
+		*/
+		std::string contents;
+		if (std::filesystem::exists(filename))
+			file::read_entire_file(filename, contents);
+
+		return deserialise_stopwords(contents);
+		}
+
+	/*
+		DESERIALISED_JASS_V1::DESERIALISE_STOPWORDS()
+		---------------------------------------------
+	*/
+	const stop_words &deserialised_jass_v1::deserialise_stopwords(const std::string &contents)
+		{
+		stopword_list.clear();
+		stopword_list.add_string(contents);
+
+		if (verbose && stopword_list.size() != 0)
+			std::cout << "Index stop words: " << stopword_list.size() << "\n";
+
+		return stopword_list;
+		}
+
+	/*
+		DESERIALISED_JASS_V1::READ_QUANTIZATION()
+		-----------------------------------------
+	*/
+	bool deserialised_jass_v1::read_quantization(const std::string &filename)
+		{
+		std::string contents;
+
+		/*
+			If the file is missing then we have a legacy index and we don't know how it was quantized
+		*/
+		if (std::filesystem::exists(filename))
+			file::read_entire_file(filename, contents);
+
+		return deserialise_quantization(contents);
+		}
+
+	/*
+		DESERIALISED_JASS_V1::DESERIALISE_QUANTIZATION()
+		------------------------------------------------
+	*/
+	bool deserialised_jass_v1::deserialise_quantization(const std::string &contents)
+		{
+		has_quantization_bounds = false;
+		if (contents.size() == 0)
+			return has_quantization_bounds;
+
+		/*
+			The file starts with the smallest and largest scores separated by whitespace, then the scheme (see quantization_scheme::serialise())
+		*/
+		has_quantization_bounds = quantization_method.deserialise(contents);
+
+		if (verbose && has_quantization_bounds)
+			{
+			const auto &bounds = quantization_method.get_global_bounds();
+			std::cout << "Index quantization bounds: " << bounds.smallest << " " << bounds.largest << "\n";
+			std::cout << "Index quantization: " << quantization_scheme::mapping_name(quantization_method.get_mapping()) << (quantization_method.get_range() == quantization_scheme::PER_TERM ? " per-term" : " global") << " impacts " << quantization_method.get_smallest_impact() << ".." << quantization_method.get_largest_impact() << "\n";
+			}
+
+		return has_quantization_bounds;
+		}
+
+	/*
+		DESERIALISED_JASS_V1::READ_POSITIONS()
+		--------------------------------------
+	*/
+	bool deserialised_jass_v1::read_positions(const std::string &filename)
+		{
+		/*
+			If the file is missing then the index was built without positions
+		*/
+		bool loaded = positional_index.read_index(filename);
+
+		if (verbose && loaded)
+			std::cout << "Index has positions\n";
+
+		return loaded;
+		}
+
+	/*
+		DESERIALISED_JASS_V1::DESERIALISE_POSITIONS()
+		---------------------------------------------
+	*/
+	bool deserialised_jass_v1::deserialise_positions(const uint8_t *memory, size_t length)
+		{
+		bool loaded = positional_index.read_index(memory, length);
+
+		if (verbose && loaded)
+			std::cout << "Index has positions\n";
+
+		return loaded;
+		}
+
+	/*
+		DESERIALISED_JASS_V1::APPLY_MEMORY_MAPPING()
+		--------------------------------------------
+	*/
+	bool deserialised_jass_v1::apply_memory_mapping(const file::file_read_only &postings_file, const std::vector<const file::file_read_only *> &files)
+		{
+		const uint8_t *file_start;
+		postings_file.read_entire_file(file_start);
+		size_t postings_offset = postings_start - file_start;
+
+		if (mapping.advice != file::file_read_only::NORMAL)
+			postings_file.advise(mapping.advice, postings_offset, postings_length);
+
+		/*
+			Prefetch the longest postings lists.  The postings lists are written one after the other so each ends where the next one (in the file) starts.
+		*/
+		if (mapping.prefetch_terms != 0 && vocabulary_list.size() != 0)
+			{
+			std::vector<const uint8_t *> starts;
+			starts.reserve(vocabulary_list.size() + 1);
+			for (const auto &term : vocabulary_list)
+				starts.push_back(term.offset);
+			starts.push_back(postings_start + postings_length);
+			std::sort(starts.begin(), starts.end());
+
+			std::vector<std::pair<size_t, const uint8_t *>> lists;			// (length, start)
+			lists.reserve(vocabulary_list.size());
+			for (size_t which = 0; which < starts.size() - 1; which++)
+				lists.push_back(std::make_pair(starts[which + 1] - starts[which], starts[which]));
+
+			size_t prefetch = (std::min)(mapping.prefetch_terms, lists.size());
+			std::partial_sort(lists.begin(), lists.begin() + prefetch, lists.end(), [](const auto &a, const auto &b){ return a.first > b.first; });
+			for (size_t which = 0; which < prefetch; which++)
+				postings_file.advise(file::file_read_only::WILLNEED, lists[which].second - file_start, lists[which].first);
+
+			if (verbose)
+				std::cout << "Index prefetched the postings of " << prefetch << " terms\n";
+			}
+
+		/*
+			Lock the index into memory
+		*/
+		if (mapping.lock)
+			for (const auto file : files)
+				if (!file->lock())
+					{
+					if (verbose)
+						std::cout << "Cannot lock the index into memory (see ulimit -l)\n";
+					lock_failed = true;
+					return false;
+					}
+
+		return true;
+		}
+
 	/*
 		DESERIALISED_JASS_V1::READ_INDEX_EXPLICIT()
 		-------------------------------------------
@@ -181,11 +386,97 @@ namespace JASS
 		DESERIALISED_JASS_V1::READ_INDEX()
 		----------------------------------
 	*/
-	size_t deserialised_jass_v1::read_index(const std::string &directory)
+	size_t deserialised_jass_v1::read_index(const std::string &directory, bool verify)
 		{
 		std::filesystem::path path = directory;
+		std::vector<std::string> problems;
+
+		/*
+			Check the files are the ones the serialiser wrote before trusting any of the pointers in them
+		*/
+		index_manifest manifest;
+		if (verify && manifest.read(directory))
+			problems = manifest.check_files(directory);
+
+		if (problems.size() == 0)
+			{
+			read_stemmer((path / STEMMER_FILENAME).string());
+			read_stopwords((path / STOPWORDS_FILENAME).string());
+			read_quantization((path / QUANTIZATION_FILENAME).string());
+			read_positions((path / POSITIONS_FILENAME).string());
+
+			if (read_index_explicit((path / PRIMARY_KEY_FILENAME).string(), (path / VOCAB_FILENAME).string(), (path / TERMS_FILENAME).string(), (path / POSTINGS_FILENAME).string()) == 0)
+				return 0;
+
+			if (!apply_memory_mapping(postings_memory, {&primary_key_memory, &vocabulary_memory, &vocabulary_terms_memory, &postings_memory}))
+				return 0;
+
+			if (!verify || !std::filesystem::exists(path / CHECKSUMS_FILENAME))
+				return 1;
+
+			if (verify_postings((path / CHECKSUMS_FILENAME).string(), problems))
+				return 1;
+			}
 
-		return read_index_explicit((path / PRIMARY_KEY_FILENAME).string(), (path / VOCAB_FILENAME).string(), (path / TERMS_FILENAME).string(), (path / POSTINGS_FILENAME).string());
+		if (verbose)
+			for (const auto &problem : problems)
+				std::cout << "Index damaged: " << problem << "\n";
+
+		return 0;
+		}
+
+	/*
+		DESERIALISED_JASS_V1::VERIFY_POSTINGS()
+		---------------------------------------
+	*/
+	bool deserialised_jass_v1::verify_postings(const std::string &checksums_filename, std::vector<std::string> &problems) const
+		{
+		std::string checksums;
+
+		if (!std::filesystem::exists(checksums_filename) || file::read_entire_file(checksums_filename, checksums) == 0)
+			{
+			problems.push_back(checksums_filename + " cannot be read");
+			return false;
+			}
+
+		return verify_postings(reinterpret_cast<const uint8_t *>(checksums.data()), checksums.size(), problems);
+		}
+
+	/*
+		DESERIALISED_JASS_V1::VERIFY_POSTINGS()
+		---------------------------------------
+	*/
+	bool deserialised_jass_v1::verify_postings(const uint8_t *checksums, size_t checksums_length, std::vector<std::string> &problems) const
+		{
+		constexpr size_t record_size = sizeof(uint64_t) + sizeof(uint16_t);
+		size_t problems_on_entry = problems.size();
+
+		if (checksums_length != terms * record_size)
+			{
+			problems.push_back("there is not one postings checksum per term");
+			return false;
+			}
+
+		const uint8_t *postings_base = postings();
+		size_t postings_size = this->postings_size();
+
+		for (size_t term = 0; term < terms; term++)
+			{
+			const auto &entry = vocabulary_list[term];
+			uint64_t length;
+			uint16_t expected;
+
+			memcpy(&length, checksums + term * record_size, sizeof(length));
+			memcpy(&expected, checksums + term * record_size + sizeof(length), sizeof(expected));
+
+			uint64_t offset = entry.offset - postings_base;
+			if (entry.offset < postings_base || offset > postings_size || length > postings_size - offset)
+				problems.push_back("postings list of " + std::string(reinterpret_cast<const char *>(entry.term.address()), entry.term.size()) + " is outside of the postings file");
+			else if (checksum::fletcher_16(postings_base + offset, length) != expected)
+				problems.push_back("postings list of " + std::string(reinterpret_cast<const char *>(entry.term.address()), entry.term.size()) + " does not match its checksum");
+			}
+
+		return problems.size() == problems_on_entry;
 		}
 
 	/*
@@ -194,15 +485,27 @@ namespace JASS
 	*/
 	compress_integer *deserialised_jass_v1::codex(std::string &name, int32_t &d_ness) const
 		{
-		const uint8_t *memory;
+		const uint8_t *memory = postings();
 
-		postings_memory.read_entire_file(memory);
 		if (memory == nullptr)
 			{
 			name = "None";
 			d_ness = 0;
 			return compress_integer_all::get_by_name("None");
 			}
+		else if (memory[0] == serialise_jass_v1::jass_v1_codex::any_codex)
+			{
+			/*
+				The codex's short name follows as a '\0' terminated string.
+			*/
+			const char *shortname = reinterpret_cast<const char *>(memory + 1);
+			const char *end = reinterpret_cast<const char *>(memory + postings_size());
+			const char *terminator = std::find(shortname, end, '\0');
+			if (terminator == end)
+				exit(printf("Unknown index format\n"));
+
+			return serialise_jass_v1::get_compressor(std::string(shortname, terminator), name, d_ness);
+			}
 		else
 			return serialise_jass_v1::get_compressor(static_cast<serialise_jass_v1::jass_v1_codex>(memory[0]), name, d_ness);
 		}
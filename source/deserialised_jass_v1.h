@@ -21,8 +21,12 @@
 #include "query.h"
 #include "slice.h"
 #include "query.h"
+#include "stem_all.h"
+#include "stop_words.h"
 #include "query_term.h"
 #include "compress_integer.h"
+#include "quantization_scheme.h"
+#include "deserialised_positions.h"
 
 namespace JASS
 	{
@@ -35,11 +39,16 @@ namespace JASS
 	*/
 	class deserialised_jass_v1
 		{
-		private:
+		public:
 			static constexpr const char *PRIMARY_KEY_FILENAME = "CIdoclist.bin";
 			static constexpr const char *VOCAB_FILENAME = "CIvocab.bin";
 			static constexpr const char *TERMS_FILENAME = "CIvocab_terms.bin";
 			static constexpr const char *POSTINGS_FILENAME = "CIpostings.bin";
+			static constexpr const char *STEMMER_FILENAME = "CIstemmer.txt";
+			static constexpr const char *STOPWORDS_FILENAME = "CIstopwords.txt";
+			static constexpr const char *QUANTIZATION_FILENAME = "CIquantization.txt";
+			static constexpr const char *POSITIONS_FILENAME = "CIpositions.bin";
+			static constexpr const char *CHECKSUMS_FILENAME = "CIchecksums.bin";
 
 		public:
 			/*
@@ -56,6 +65,7 @@ namespace JASS
 				{
 				public:
 					uint32_t impact;					///< The impact score.  Not a query::ACCUMUMLTOR_TYPE as this can overflow
+					uint32_t query_term;				///< Which term of the query this segment belongs to (not set by get_segment_list(), this is for the search engine's use)
 					uint64_t offset;					///< Offset (within the postings file) of the start of the compressed postings list
 					uint64_t end;						///< Offset (within the postings file) of the end of the compressed postings list
 					query::DOCID_TYPE segment_frequency;			///< The number of document ids in the segment (not end - offset because the postings are compressed)
@@ -144,6 +154,42 @@ namespace JASS
 						}
 				};
 
+			/*
+				CLASS DESERIALISED_JASS_V1::MEMORY_MAPPING
+				------------------------------------------
+			*/
+			/*!
+				@brief How the index files are mapped into memory.
+				@details By default each file is read in as it is loaded.  If lazy then the vocabulary and postings are mapped (read only) but not
+				read, so loading is fast and each page is read from disk the first time a query needs it.  Either way the pages are those of the
+				page cache and so are shared by every process on the machine that has the same index loaded.
+			*/
+			class memory_mapping
+				{
+				public:
+					bool lazy;																///< Read each page on first use rather than when the index is loaded
+					file::file_read_only::access_pattern advice;			///< How the postings are expected to be used (passed to madvise())
+					size_t prefetch_terms;												///< Ask for the postings of this many terms (those with the longest postings lists) to be read in when the index is loaded
+					bool lock;																///< Lock the index into memory so that it cannot be paged out
+
+				public:
+					/*
+						DESERIALISED_JASS_V1::MEMORY_MAPPING::MEMORY_MAPPING()
+						------------------------------------------------------
+					*/
+					/*!
+						@brief Constructor (read everything in when the index is loaded)
+					*/
+					memory_mapping() :
+						lazy(false),
+						advice(file::file_read_only::NORMAL),
+						prefetch_terms(0),
+						lock(false)
+						{
+						/* Nothing */
+						}
+				};
+
 		protected:
 			bool verbose;												///< Should this class produce diagnostics on stdout?
 
@@ -157,6 +203,19 @@ namespace JASS
 			std::vector<metadata> vocabulary_list;				///< The (sorted in alphabetical order) array of vocbulary terms
 
 			file::file_read_only postings_memory;				///< Memory used to store the postings
+			const uint8_t *postings_start;						///< The start of the postings (in postings_memory, or elsewhere if the postings are part of a larger file)
+			size_t postings_length;									///< The length (in bytes) of the postings
+
+			std::string stemmer_name;								///< The name of the stemmer used when indexing (see stem_all)
+			stop_words stopword_list;								///< The stop words dropped when indexing (empty if there was no stopping)
+
+			bool has_quantization_bounds;							///< Were the quantization bounds recorded when the index was built?
+			quantization_scheme quantization_method;			///< How the scores were quantized, and their bounds (if has_quantization_bounds)
+
+			deserialised_positions positional_index;			///< The positions of each term in each document (if the index is positional)
+
+			memory_mapping mapping;									///< How the index files are mapped into memory
+			bool lock_failed;											///< mapping.lock was asked for but the index could not be locked into memory
 
 		protected:
 			/*
@@ -193,6 +252,112 @@ namespace JASS
 			*/
 			virtual size_t read_postings(const std::string &postings_filename = POSTINGS_FILENAME);
 
+			/*
+				DESERIALISED_JASS_V1::READ_STEMMER()
+				------------------------------------
+			*/
+			/*!
+				@brief Read the name of the stemmer used to build the index.
+				@details Indexes built before the stemmer was recorded do not have this file and are assumed to be unstemmed.
+				@param stemmer_filename [in] the name of the file containing the name of the stemmer ("CIstemmer.txt")
+				@return The name of the stemmer (stem_all::NO_STEMMER if the index is not stemmed)
+			*/
+			virtual const std::string &read_stemmer(const std::string &stemmer_filename = STEMMER_FILENAME);
+
+			/*
+				DESERIALISED_JASS_V1::READ_STOPWORDS()
+				--------------------------------------
+			*/
+			/*!
+				@brief Read the stop words that were dropped when building the index.
+				@details Indexes built before the stop words were recorded do not have this file and are assumed to be unstopped.
+				@param stopwords_filename [in] the name of the file containing the stop words ("CIstopwords.txt")
+				@return The stop words (empty if the index is not stopped)
+			*/
+			virtual const stop_words &read_stopwords(const std::string &stopwords_filename = STOPWORDS_FILENAME);
+
+			/*
+				DESERIALISED_JASS_V1::READ_QUANTIZATION()
+				-----------------------------------------
+			*/
+			/*!
+				@brief Read how the index was quantized, including the smallest and largest scores.
+				@details Indexes built before the bounds were recorded do not have this file.
+				@param quantization_filename [in] the name of the file containing the quantization scheme ("CIquantization.txt")
+				@return true if the bounds were read, else false
+			*/
+			virtual bool read_quantization(const std::string &quantization_filename = QUANTIZATION_FILENAME);
+
+			/*
+				DESERIALISED_JASS_V1::READ_POSITIONS()
+				--------------------------------------
+			*/
+			/*!
+				@brief Read the positions of each term in each document.
+				@details Only indexes built with positions have this file.
+				@param positions_filename [in] the name of the file containing the positions ("CIpositions.bin")
+				@return true if the positions were read, else false
+			*/
+			virtual bool read_positions(const std::string &positions_filename = POSITIONS_FILENAME);
+
+			/*
+				DESERIALISED_JASS_V1::DESERIALISE_STEMMER()
+				-------------------------------------------
+			*/
+			/*!
+				@brief Set the name of the stemmer used to build the index from the (already loaded) contents of the stemmer file.
+				@param contents [in] The contents of the file containing the name of the stemmer ("CIstemmer.txt"), empty if the index is not stemmed
+				@return The name of the stemmer (stem_all::NO_STEMMER if the index is not stemmed)
+			*/
+			const std::string &deserialise_stemmer(const std::string &contents);
+
+			/*
+				DESERIALISED_JASS_V1::DESERIALISE_STOPWORDS()
+				---------------------------------------------
+			*/
+			/*!
+				@brief Set the stop words that were dropped when building the index from the (already loaded) contents of the stop words file.
+				@param contents [in] The contents of the file containing the stop words ("CIstopwords.txt"), empty if the index is not stopped
+				@return The stop words (empty if the index is not stopped)
+			*/
+			const stop_words &deserialise_stopwords(const std::string &contents);
+
+			/*
+				DESERIALISED_JASS_V1::DESERIALISE_QUANTIZATION()
+				------------------------------------------------
+			*/
+			/*!
+				@brief Set how the index was quantized from the (already loaded) contents of the quantization file.
+				@param contents [in] The contents of the file containing the quantization scheme ("CIquantization.txt"), empty if it was not recorded
+				@return true if the bounds were read, else false
+			*/
+			bool deserialise_quantization(const std::string &contents);
+
+			/*
+				DESERIALISED_JASS_V1::DESERIALISE_POSITIONS()
+				---------------------------------------------
+			*/
+			/*!
+				@brief Load the positions of each term in each document from the (already loaded) contents of the positions file.
+				@param memory [in] The contents of the file containing the positions ("CIpositions.bin")
+				@param length [in] The length (in bytes) of memory, 0 if the index was built without positions
+				@return true if the positions were read, else false
+			*/
+			bool deserialise_positions(const uint8_t *memory, size_t length);
+
+			/*
+				DESERIALISED_JASS_V1::APPLY_MEMORY_MAPPING()
+				--------------------------------------------
+			*/
+			/*!
+				@brief Pass the madvise() hint for the postings to the kernel, prefetch the postings of the longest postings lists, and lock the files into memory (see mapping).
+				@details Must be called after the index has been loaded.
+				@param postings_file [in] The file that holds the postings (which start at postings())
+				@param files [in] The files that make up the index (which are locked if mapping.lock)
+				@return true on success, false if the index cannot be locked into memory
+			*/
+			bool apply_memory_mapping(const file::file_read_only &postings_file, const std::vector<const file::file_read_only *> &files);
+
 			/*
 				DESERIALISED_JASS_V1::READ_INDEX_EXPLICIT()
 				-------------------------------------------
@@ -219,7 +384,12 @@ namespace JASS
 			explicit deserialised_jass_v1(bool verbose = false) :
 				verbose(verbose),
 				documents(0),
-				terms(0)
+				terms(0),
+				postings_start(nullptr),
+				postings_length(0),
+				stemmer_name(stem_all::NO_STEMMER),
+				has_quantization_bounds(false),
+				lock_failed(false)
 				{
 				/* Nothing */
 				}
@@ -236,16 +406,70 @@ namespace JASS
 				/* Nothing */
 				}
 				
+			/*
+				DESERIALISED_JASS_V1::SET_MEMORY_MAPPING()
+				------------------------------------------
+			*/
+			/*!
+				@brief Set how the index files are mapped into memory (see memory_mapping).  Must be called before read_index().
+				@param how [in] How to map the index.
+			*/
+			void set_memory_mapping(const memory_mapping &how)
+				{
+				mapping = how;
+				}
+
+			/*
+				DESERIALISED_JASS_V1::CANNOT_LOCK()
+				-----------------------------------
+			*/
+			/*!
+				@brief Did read_index() fail because the index could not be locked into memory (see memory_mapping::lock)?
+				@return true if the index was asked to be locked into memory but could not be, else false.
+			*/
+			bool cannot_lock(void) const
+				{
+				return lock_failed;
+				}
+
 			/*
 				DESERIALISED_JASS_V1::READ_INDEX()
 				----------------------------------
 			*/
 			/*!
 				@brief Read a JASS v1 index into memory
+				@details If verify is true then the size and checksum of each index file are checked against the manifest (CImanifest.txt) before
+				loading, and the checksum of each postings list against CIchecksums.bin after loading.  Indexes without those files are not checked.
 				@param directory [in] The directory to search for and index
-				@return 0 on failure, non-zero on success
+				@param verify [in] Should the checksums be checked (default = false)?
+				@return 0 on failure (including a checksum failure, or failing to lock the index into memory when asked to), non-zero on success
+			*/
+			virtual size_t read_index(const std::string &directory = "", bool verify = false);
+
+			/*
+				DESERIALISED_JASS_V1::VERIFY_POSTINGS()
+				---------------------------------------
+			*/
+			/*!
+				@brief Check the length and checksum of each postings list in the (loaded) index against those written by the serialiser.
+				@param checksums_filename [in] The name of the file containing the checksums ("CIchecksums.bin")
+				@param problems [out] A description of each problem found is appended to this.
+				@return true if every postings list is as expected, false if not (or the checksums file cannot be read)
+			*/
+			bool verify_postings(const std::string &checksums_filename, std::vector<std::string> &problems) const;
+
+			/*
+				DESERIALISED_JASS_V1::VERIFY_POSTINGS()
+				---------------------------------------
 			*/
-			size_t read_index(const std::string &directory = "");
+			/*!
+				@brief Check the length and checksum of each postings list in the (loaded) index against those written by the serialiser.
+				@param checksums [in] The checksums (the contents of CIchecksums.bin).
+				@param checksums_length [in] The length (in bytes) of checksums.
+				@param problems [out] A description of each problem found is appended to this.
+				@return true if every postings list is as expected, false if not
+			*/
+			bool verify_postings(const uint8_t *checksums, size_t checksums_length, std::vector<std::string> &problems) const;
 
 			/*
 				DESERIALISED_JASS_V1::CODEX()
@@ -259,6 +483,91 @@ namespace JASS
 			*/
 			compress_integer *codex(std::string &name, int32_t &d_ness) const;
 
+			/*
+				DESERIALISED_JASS_V1::STEMMER()
+				-------------------------------
+			*/
+			/*!
+				@brief Return the name of the stemmer used to build this index (query terms should be stemmed the same way)
+				@return The name of the stemmer (see stem_all), or stem_all::NO_STEMMER if the index is not stemmed
+			*/
+			const std::string &stemmer(void) const
+				{
+				return stemmer_name;
+				}
+
+			/*
+				DESERIALISED_JASS_V1::STOPWORDS()
+				---------------------------------
+			*/
+			/*!
+				@brief Return the stop words that were dropped when building this index (query terms should be stopped the same way)
+				@return The stop words (empty if the index is not stopped)
+			*/
+			const stop_words &stopwords(void) const
+				{
+				return stopword_list;
+				}
+
+			/*
+				DESERIALISED_JASS_V1::QUANTIZATION_BOUNDS()
+				-------------------------------------------
+			*/
+			/*!
+				@brief Return the smallest and largest scores that were used to quantize this index into impacts
+				@param smallest [out] The score that was mapped to the smallest impact
+				@param largest [out] The score that was mapped to the largest impact
+				@return true if the bounds are known, false if the index does not record them
+			*/
+			bool quantization_bounds(double &smallest, double &largest) const
+				{
+				smallest = quantization_method.get_global_bounds().smallest;
+				largest = quantization_method.get_global_bounds().largest;
+				return has_quantization_bounds;
+				}
+
+			/*
+				DESERIALISED_JASS_V1::QUANTIZATION()
+				------------------------------------
+			*/
+			/*!
+				@brief Return how this index was quantized, which can be used to map an impact back to an approximate score (see quantization_scheme::dequantize()).
+				@details Only meaningful if quantization_bounds() returns true.
+				@return The quantization scheme.
+			*/
+			const quantization_scheme &quantization(void) const
+				{
+				return quantization_method;
+				}
+
+			/*
+				DESERIALISED_JASS_V1::HAS_POSITIONS()
+				-------------------------------------
+			*/
+			/*!
+				@brief Was this index built with the positions of each term (and so can it verify phrases)?
+				@return true if phrase_occurs() can be used, else false
+			*/
+			bool has_positions(void) const
+				{
+				return positional_index.is_loaded();
+				}
+
+			/*
+				DESERIALISED_JASS_V1::PHRASE_OCCURS()
+				-------------------------------------
+			*/
+			/*!
+				@brief Does the phrase occur in the document?
+				@param phrase [in] The terms of the phrase, in order
+				@param document_id [in] The document (counting from 0)
+				@return true if the phrase occurs in the document, false if it does not or if the index has no positions
+			*/
+			bool phrase_occurs(const std::vector<slice> &phrase, uint32_t document_id) const
+				{
+				return positional_index.phrase_occurs(phrase, document_id);
+				}
+
 			/*
 				DESERIALISED_JASS_V1::PRIMARY_KEYS()
 				------------------------------------
@@ -282,9 +591,20 @@ namespace JASS
 			*/
 			const uint8_t *postings(void) const
 				{
-				const uint8_t *buffer = nullptr;
-				postings_memory.read_entire_file(buffer);
-				return buffer;
+				return postings_start;
+				}
+
+			/*
+				DESERIALISED_JASS_V1::POSTINGS_SIZE()
+				-------------------------------------
+			*/
+			/*!
+				@brief Return the size of the postings "file"
+				@return The size (in bytes) of the postings "file"
+			*/
+			size_t postings_size(void) const
+				{
+				return postings_length;
 				}
 
 			/*
@@ -300,6 +620,19 @@ namespace JASS
 				return documents;
 				}
 
+			/*
+				DESERIALISED_JASS_V1::TERM_COUNT()
+				----------------------------------
+			*/
+			/*!
+				@brief Return the number of terms in the vocabulary
+				@return the number of terms in the vocabulary
+			*/
+			size_t term_count(void) const
+				{
+				return terms;
+				}
+
 			/*
 				DESERIALISED_JASS_V1::POSTINGS_DETAILS()
 				----------------------------------------
@@ -335,6 +668,30 @@ namespace JASS
 				return false;
 				}
 
+			/*
+				DESERIALISED_JASS_V1::APPLY_QUERY_WEIGHT()
+				------------------------------------------
+			*/
+			/*!
+				@brief Multiply an impact score by a query term weight, rounding to the nearest integer.
+				@details A positive weight never reduces an impact to 0 (so the term still counts) and the result is capped at the largest uint32_t.
+				@param impact [in] The impact score from the index.
+				@param weight [in] The weight of the term in the query.
+				@return The weighted impact score.
+			*/
+			static uint32_t apply_query_weight(uint32_t impact, double weight)
+				{
+				if (weight == 1)
+					return impact;
+
+				double weighted = impact * weight + 0.5;
+				if (weighted >= (double)(std::numeric_limits<uint32_t>::max)())
+					return (std::numeric_limits<uint32_t>::max)();
+				if (weighted < 1)
+					return weight > 0 && impact != 0 ? 1 : 0;
+				return (uint32_t)weighted;
+				}
+
 			/*
 				DESERIALISED_JASS_V1::GET_SEGMENT_LIST()
 				----------------------------------------
@@ -343,11 +700,13 @@ namespace JASS
 				@brief Extract the segment headers and return them in the parameter called segments
 				@param segments [out] The list of segments for the given search term (caller must ensure this ponts to a large enough array)
 				@param metadata [in] The metadata for the given search term
+				@param query_term_weight [in] The weight of the term in the query (see query_term::weight()), the impact scores are multiplied by this
 				@param smallest [out] The largest impact score for this term
 				@param largest [out] The smallest impact score for this term
+				@param document_frequency [out] The number of documents containing the term
 				@return The number of segments extracted and added to the list
 			*/
-			virtual size_t get_segment_list(segment_header *segments, metadata &metadata, size_t query_term_frequency, uint32_t &smallest, uint32_t &largest, query::DOCID_TYPE &document_frequency) const
+			virtual size_t get_segment_list(segment_header *segments, metadata &metadata, double query_term_weight, uint32_t &smallest, uint32_t &largest, query::DOCID_TYPE &document_frequency) const
 				{
 				document_frequency = 0;
 				segment_header *current_segment = segments;
@@ -356,7 +715,7 @@ namespace JASS
 					uint64_t *postings_list = (uint64_t *)metadata.offset;
 					segment_header_on_disk *next_segment_in_postings_list = (segment_header_on_disk *)(postings() + postings_list[segment]);
 
-					current_segment->impact = (uint32_t)(next_segment_in_postings_list->impact * query_term_frequency);
+					current_segment->impact = apply_query_weight(next_segment_in_postings_list->impact, query_term_weight);
 					current_segment->offset = next_segment_in_postings_list->offset;
 					current_segment->end = next_segment_in_postings_list->end;
 					current_segment->segment_frequency = next_segment_in_postings_list->segment_frequency;
@@ -25,7 +25,7 @@ namespace JASS
 		/*
 			Read the file of tripples that are the pointers to the terms (and the postings too)
 		*/
-		auto length = file::read_entire_file(vocab_filename, vocabulary_memory);
+		auto length = file::read_entire_file(vocab_filename, vocabulary_memory, !mapping.lazy);
 		if (length == 0)
 			return 0;
 		const uint8_t *vocab;
@@ -34,7 +34,7 @@ namespace JASS
 		/*
 			Read the file of strings that is the vocabulary
 		*/
-		auto bytes = file::read_entire_file(terms_filename, vocabulary_terms_memory);
+		auto bytes = file::read_entire_file(terms_filename, vocabulary_terms_memory, !mapping.lazy);
 		if (bytes == 0)
 			return 0;
 		const uint8_t *vocab_terms;
@@ -43,6 +43,29 @@ namespace JASS
 		/*
 			Build the vocabulary
 		*/
+		deserialise_vocabulary(vocab, length, vocab_terms);
+
+		/*
+			This can take some time so make some noise when we're finished
+		*/
+		if (verbose)
+			{
+			puts("done");
+			fflush(stdout);
+			}
+
+		/*
+			Return the number of terms in the collection
+		*/
+		return terms;
+		}
+
+	/*
+		DESERIALISED_JASS_V2::DESERIALISE_VOCABULARY()
+		----------------------------------------------
+	*/
+	size_t deserialised_jass_v2::deserialise_vocabulary(const uint8_t *vocab, size_t length, const uint8_t *vocab_terms)
+		{
 		terms = 0;
 		const uint8_t *postings_base = postings();
 		const uint8_t *from = vocab;
@@ -60,18 +83,6 @@ namespace JASS
 			terms++;
 			}
 
-		/*
-			This can take some time so make some noise when we're finished
-		*/
-		if (verbose)
-			{
-			puts("done");
-			fflush(stdout);
-			}
-
-		/*
-			Return the number of terms in the collection
-		*/
 		return terms;
 		}
 
@@ -98,10 +109,36 @@ namespace JASS
 			return 0;					// failed to read the file.
 
 		/*
-			Numnber of documents is stored at the end of the file (as a uint64_t)
+			Build the list of primary keys
 		*/
 		const uint8_t *memory = nullptr;
 		primary_key_memory.read_entire_file(memory);
+		deserialise_primary_keys(memory, bytes);
+
+		/*
+			This can take some time so make some noise when we're finished
+		*/
+		if (verbose)
+			{
+			puts("done");
+			fflush(stdout);
+			}
+
+		/*
+			retrurn the number of documents in the collection
+		*/
+		return documents;
+		}
+
+	/*
+		DESERIALISED_JASS_V2::DESERIALISE_PRIMARY_KEYS()
+		------------------------------------------------
+	*/
+	size_t deserialised_jass_v2::deserialise_primary_keys(const uint8_t *memory, size_t bytes)
+		{
+		/*
+			Numnber of documents is stored at the end of the file (as a uint64_t)
+		*/
 		const uint8_t *end_of_file = memory + bytes - sizeof(uint64_t);
 		documents = (query::DOCID_TYPE)(*(uint64_t *)end_of_file);
 		primary_key_list.reserve(documents);
@@ -116,18 +153,6 @@ namespace JASS
 			if (*from == '\0')
 				primary_key_list.push_back((const char *)from + 1);
 
-		/*
-			This can take some time so make some noise when we're finished
-		*/
-		if (verbose)
-			{
-			puts("done");
-			fflush(stdout);
-			}
-
-		/*
-			retrurn the number of documents in the collection
-		*/
 		return documents;
 		}
 	}
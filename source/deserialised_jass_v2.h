@@ -50,6 +50,31 @@ namespace JASS
 			*/
 			virtual size_t read_primary_keys(const std::string &primary_key_filename = "CIdoclist.bin");
 
+			/*
+				DESERIALISED_JASS_V2::DESERIALISE_VOCABULARY()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Build the vocabulary from the (already loaded) contents of the JASS v2 vocabulary files (the postings must already be loaded)
+				@param vocab [in] The contents of the file containing the vocabulary pointers ("CIvocab.bin")
+				@param length [in] The length (in bytes) of vocab
+				@param vocab_terms [in] The contents of the file containing the vocabulary strings ("CIvocab_terms.bin")
+				@return The number of terms in the collection
+			*/
+			size_t deserialise_vocabulary(const uint8_t *vocab, size_t length, const uint8_t *vocab_terms);
+
+			/*
+				DESERIALISED_JASS_V2::DESERIALISE_PRIMARY_KEYS()
+				------------------------------------------------
+			*/
+			/*!
+				@brief Build the list of primary keys from the (already loaded) contents of the JASS v2 primary key file
+				@param memory [in] The contents of the file containing the primary key list ("CIdoclist.bin")
+				@param bytes [in] The length (in bytes) of memory
+				@return The number of documents in the collection
+			*/
+			size_t deserialise_primary_keys(const uint8_t *memory, size_t bytes);
+
 		public:
 			/*
 				DESERIALISED_JASS_V2::DESERIALISED_JASS_V2()
@@ -120,11 +145,13 @@ namespace JASS
 				@brief Extract the segment headers and return them in the parameter called segments
 				@param segments [out] The list of segments for the given search term (caller must ensure this ponts to a large enough array)
 				@param metadata [in] The metadata for the given search term
+				@param query_term_weight [in] The weight of the term in the query (see query_term::weight()), the impact scores are multiplied by this
 				@param smallest [out] The largest impact score for this term
 				@param largest [out] The smallest impact score for this term
+				@param document_frequency [out] The number of documents containing the term
 				@return The number of segments extracted and added to the list
 			*/
-			virtual size_t get_segment_list(segment_header *segments, metadata &metadata, size_t query_term_frequency, uint32_t &smallest, uint32_t &largest, query::DOCID_TYPE &document_frequency) const
+			virtual size_t get_segment_list(segment_header *segments, metadata &metadata, double query_term_weight, uint32_t &smallest, uint32_t &largest, query::DOCID_TYPE &document_frequency) const
 				{
 				document_frequency = 0;
 				/*
@@ -140,7 +167,7 @@ namespace JASS
 					compress_integer_variable_byte::decompress_into(&current_segment->end , segment_header_pointer);
 					compress_integer_variable_byte::decompress_into(&current_segment->segment_frequency, segment_header_pointer);
 					current_segment->offset += segment_header_pointer - postings();		//v2 index is relative to the segment header
-					current_segment->impact *= query_term_frequency;
+					current_segment->impact = apply_query_weight(current_segment->impact, query_term_weight);
 					current_segment->end += current_segment->offset;					// V2 indexes store length rather than an end pointer
 					document_frequency += current_segment->segment_frequency;
 					current_segment++;
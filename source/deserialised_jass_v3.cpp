@@ -0,0 +1,306 @@
+/*
+	DESERIALISED_JASS_V3.CPP
+	------------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <stdio.h>
+#include <string.h>
+
+#include <memory>
+#include <iostream>
+#include <filesystem>
+
+#include "asserts.h"
+#include "checksum.h"
+#include "unittest_data.h"
+#include "deserialised_jass_v3.h"
+#include "index_manager_positional.h"
+#include "quantization_scheme.h"
+#include "stop_words.h"
+
+namespace JASS
+	{
+	/*
+		DESERIALISED_JASS_V3::READ_SECTIONS()
+		-------------------------------------
+	*/
+	bool deserialised_jass_v3::read_sections(const uint8_t *file, size_t length, std::vector<serialise_jass_v3::section> &sections, std::vector<std::string> &problems, bool verify)
+		{
+		serialise_jass_v3::header head;
+		size_t problems_on_entry = problems.size();
+
+		sections.assign(serialise_jass_v3::LAST_SECTION + 1, serialise_jass_v3::section{0, 0, 0, 0});
+
+		/*
+			Check the header
+		*/
+		if (length < sizeof(head))
+			{
+			problems.push_back("the index is too short to be a JASS v3 index");
+			return false;
+			}
+		memcpy(&head, file, sizeof(head));
+		if (memcmp(head.magic, serialise_jass_v3::MAGIC, sizeof(head.magic)) != 0)
+			{
+			problems.push_back("the index is not a JASS v3 index");
+			return false;
+			}
+		if (head.version != 3)
+			{
+			problems.push_back("the index is version " + std::to_string(head.version) + " but should be version 3");
+			return false;
+			}
+		if (head.sections > (length - sizeof(head)) / sizeof(serialise_jass_v3::section))
+			{
+			problems.push_back("the section table runs off the end of the index");
+			return false;
+			}
+
+		/*
+			Check each section (sections of a kind we don't know are skipped so that later versions can add to the format)
+		*/
+		for (size_t which = 0; which < head.sections; which++)
+			{
+			serialise_jass_v3::section current;
+			memcpy(&current, file + sizeof(head) + which * sizeof(current), sizeof(current));
+
+			if (current.type < serialise_jass_v3::PRIMARY_KEYS || current.type > serialise_jass_v3::LAST_SECTION)
+				continue;
+
+			std::string name = "section " + std::to_string(which) + " (type " + std::to_string(current.type) + ")";
+			if (current.offset % serialise_jass_v3::PAGE_SIZE != 0 || current.offset > length || current.length > length - current.offset)
+				problems.push_back(name + " is not inside the index");
+			else if (verify && current.length != 0 && checksum::fletcher_16(file + current.offset, current.length) != current.checksum)
+				problems.push_back(name + " does not match its checksum");
+			else
+				sections[current.type] = current;
+			}
+
+		return problems.size() == problems_on_entry;
+		}
+
+	/*
+		DESERIALISED_JASS_V3::READ_INDEX()
+		----------------------------------
+	*/
+	size_t deserialised_jass_v3::read_index(const std::string &directory, bool verify)
+		{
+		std::filesystem::path path = directory;
+		std::filesystem::path filename = path / serialise_jass_v3::FILENAME;
+		std::vector<std::string> problems;
+		std::vector<serialise_jass_v3::section> sections;
+
+		/*
+			This can take some time so make some noise when we start
+		*/
+		if (verbose)
+			{
+			printf("Loading index... ");
+			fflush(stdout);
+			}
+
+		/*
+			Map the index into memory and find the sections
+		*/
+		if (!std::filesystem::exists(filename) || file::read_entire_file(filename.string(), index_memory, !mapping.lazy) == 0)
+			return 0;
+
+		const uint8_t *memory;
+		size_t length = index_memory.read_entire_file(memory);
+
+		if (read_sections(memory, length, sections, problems, verify))
+			{
+			const auto &primary_keys = sections[serialise_jass_v3::PRIMARY_KEYS];
+			const auto &vocabulary = sections[serialise_jass_v3::VOCABULARY];
+			const auto &vocabulary_terms = sections[serialise_jass_v3::TERMS];
+			const auto &postings = sections[serialise_jass_v3::POSTINGS];
+			const auto &metadata = sections[serialise_jass_v3::METADATA];
+			const auto &checksums = sections[serialise_jass_v3::CHECKSUMS];
+			const auto &stemmer = sections[serialise_jass_v3::STEMMER];
+			const auto &stopwords = sections[serialise_jass_v3::STOPWORDS];
+			const auto &quantization = sections[serialise_jass_v3::QUANTIZATION];
+			const auto &positions = sections[serialise_jass_v3::POSITIONS];
+
+			if (primary_keys.length < sizeof(uint64_t) || vocabulary.length == 0 || vocabulary_terms.length == 0 || postings.length == 0)
+				problems.push_back("the index is missing a section");
+			else if (!manifest.deserialise(std::string(reinterpret_cast<const char *>(memory + metadata.offset), metadata.length)))
+				problems.push_back("the index has no manifest");
+			else
+				{
+				/*
+					The sections are used in place (a missing section has a length of 0)
+				*/
+				deserialise_stemmer(std::string(reinterpret_cast<const char *>(memory + stemmer.offset), stemmer.length));
+				deserialise_stopwords(std::string(reinterpret_cast<const char *>(memory + stopwords.offset), stopwords.length));
+				deserialise_quantization(std::string(reinterpret_cast<const char *>(memory + quantization.offset), quantization.length));
+				deserialise_positions(memory + positions.offset, positions.length);
+
+				deserialise_primary_keys(memory + primary_keys.offset, primary_keys.length);
+				postings_start = memory + postings.offset;
+				postings_length = postings.length;
+				deserialise_vocabulary(memory + vocabulary.offset, vocabulary.length, memory + vocabulary_terms.offset);
+				if (!apply_memory_mapping(index_memory, {&index_memory}))
+					return 0;				// the index is fine, but it cannot be locked into memory (see cannot_lock())
+
+				/*
+					Check the index is what the manifest says it is
+				*/
+				std::string codex_name;
+				int32_t d_ness;
+				std::unique_ptr<compress_integer> decoder(codex(codex_name, d_ness));
+				if (documents != manifest.documents || terms != manifest.terms || codex_name != manifest.codex || d_ness != manifest.d_ness)
+					problems.push_back("the index does not match its manifest");
+				else if (verify && checksums.length != 0)
+					verify_postings(memory + checksums.offset, checksums.length, problems);
+				}
+			}
+
+		/*
+			This can take some time so make some noise when we're finished
+		*/
+		if (verbose)
+			{
+			puts("done");
+			for (const auto &problem : problems)
+				std::cout << "Index damaged: " << problem << "\n";
+			fflush(stdout);
+			}
+
+		return problems.size() == 0 ? 1 : 0;
+		}
+
+	/*
+		DESERIALISED_JASS_V3::UNITTEST()
+		--------------------------------
+	*/
+	void deserialised_jass_v3::unittest(void)
+		{
+		/*
+			Build an index and serialise it as both JASS v2 and JASS v3
+		*/
+		const std::string positions_filename = "deserialised_jass_v3_positions.bin";
+		index_manager_positional index;
+		index_manager_sequential::unittest_build_index(index, unittest_data::ten_documents);
+		JASS_assert(index.serialise_positions(positions_filename));
+
+		stop_words stopwords;
+		stopwords.add("eleven");
+		quantization_scheme scheme(quantization_scheme::LOGARITHMIC, quantization_scheme::GLOBAL, 8);
+		scheme.get_global_bounds().add(0.5);
+		scheme.get_global_bounds().add(12.25);
+
+		{
+		serialise_jass_v2 serialiser(index.get_highest_document_id());
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		{
+		serialise_jass_v3 serialiser(index.get_highest_document_id());
+		serialiser.add_section(serialise_jass_v3::STEMMER, "Porter\n");
+		serialiser.add_section(serialise_jass_v3::STOPWORDS, stopwords.serialise());
+		serialiser.add_section(serialise_jass_v3::QUANTIZATION, scheme.serialise());
+		serialiser.add_section_file(serialise_jass_v3::POSITIONS, positions_filename);
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		std::filesystem::remove(positions_filename);
+
+		/*
+			Load both and check they are the same
+		*/
+		deserialised_jass_v2 version_2;
+		deserialised_jass_v3 version_3;
+		JASS_assert(version_2.read_index() != 0);
+		JASS_assert(version_3.read_index("", true) != 0);
+		JASS_assert(version_3.get_manifest().version == 3);
+		JASS_assert(version_3.document_count() == 10 && version_3.term_count() == 20);
+		JASS_assert(version_3.primary_keys() == version_2.primary_keys());
+
+		auto term_2 = version_2.begin();
+		for (const auto &term_3 : version_3)
+			{
+			JASS_assert(term_3.term == term_2->term && term_3.impacts == term_2->impacts);
+			JASS_assert(term_3.offset - version_3.postings() == term_2->offset - version_2.postings());
+			++term_2;
+			}
+		JASS_assert(version_3.postings_size() == version_2.postings_size());
+		JASS_assert(memcmp(version_3.postings(), version_2.postings(), version_2.postings_size()) == 0);
+
+		/*
+			Load them again, read on demand with hints, and check they are the same
+		*/
+		memory_mapping on_demand;
+		on_demand.lazy = true;
+		on_demand.advice = file::file_read_only::RANDOM;
+		on_demand.prefetch_terms = 5;
+
+		deserialised_jass_v2 lazy_2;
+		deserialised_jass_v3 lazy_3;
+		lazy_2.set_memory_mapping(on_demand);
+		lazy_3.set_memory_mapping(on_demand);
+		JASS_assert(lazy_2.read_index() != 0);
+		JASS_assert(lazy_3.read_index("", true) != 0);
+		JASS_assert(lazy_2.term_count() == 20 && lazy_3.term_count() == 20);
+		JASS_assert(lazy_2.postings_size() == version_2.postings_size() && memcmp(lazy_2.postings(), version_2.postings(), version_2.postings_size()) == 0);
+		JASS_assert(lazy_3.postings_size() == version_2.postings_size() && memcmp(lazy_3.postings(), version_2.postings(), version_2.postings_size()) == 0);
+
+		/*
+			Damage the index and check that it is found
+		*/
+		std::string original;
+		file::read_entire_file(serialise_jass_v3::FILENAME, original);
+
+		std::string damaged = original;
+		damaged.back() ^= 0x01;
+		file::write_entire_file(serialise_jass_v3::FILENAME, damaged);
+		{
+		deserialised_jass_v3 loaded;
+		JASS_assert(loaded.read_index("", true) == 0);
+		}
+		{
+		deserialised_jass_v3 loaded;
+		JASS_assert(loaded.read_index("", false) != 0);
+		}
+
+		file::write_entire_file(serialise_jass_v3::FILENAME, original.substr(0, original.size() / 2));
+		{
+		deserialised_jass_v3 loaded;
+		JASS_assert(loaded.read_index() == 0);
+		}
+
+		damaged = original;
+		damaged[0] = 'j';
+		file::write_entire_file(serialise_jass_v3::FILENAME, damaged);
+		{
+		deserialised_jass_v3 loaded;
+		JASS_assert(loaded.read_index() == 0);
+		}
+
+		file::write_entire_file(serialise_jass_v3::FILENAME, original);
+
+		/*
+			The index is a single file that can be moved on its own (and its version found without a CImanifest.txt)
+		*/
+		std::error_code error;
+		std::filesystem::create_directory("deserialised_jass_v3_directory", error);
+		std::filesystem::copy_file(serialise_jass_v3::FILENAME, std::filesystem::path("deserialised_jass_v3_directory") / serialise_jass_v3::FILENAME, std::filesystem::copy_options::overwrite_existing, error);
+		JASS_assert(index_manifest::legacy_version("deserialised_jass_v3_directory") == 3);
+		{
+		deserialised_jass_v3 loaded;
+		JASS_assert(loaded.read_index("deserialised_jass_v3_directory", true) != 0 && loaded.document_count() == 10);
+
+		/*
+			The stemmer, stop words, quantization, and positions come from the index file
+		*/
+		JASS_assert(loaded.stemmer() == "Porter");
+		JASS_assert(loaded.stopwords().is_stop_word(slice("eleven")) && !loaded.stopwords().is_stop_word(slice("ten")));
+		JASS_assert(loaded.quantization().serialise() == scheme.serialise());
+		JASS_assert(loaded.has_positions());
+		JASS_assert(loaded.phrase_occurs({slice("ten"), slice("nine")}, 1) && !loaded.phrase_occurs({slice("nine"), slice("ten")}, 1));
+		}
+		std::filesystem::remove_all("deserialised_jass_v3_directory", error);
+
+		puts("deserialised_jass_v3::PASSED");
+		}
+	}
@@ -0,0 +1,113 @@
+/*
+	DESERIALISED_JASS_V3.H
+	----------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Load and deserialise a JASS v3 index (a JASS v2 index in a single file)
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include "index_manifest.h"
+#include "serialise_jass_v3.h"
+#include "deserialised_jass_v2.h"
+
+namespace JASS
+	{
+	/*
+		CLASS DESERIALISED_JASS_V3
+		--------------------------
+	*/
+	/*!
+		@brief Load and deserialise a JASS v3 index (see serialise_jass_v3 for the format).
+		@details The index file is mapped into memory once and each section is used in place.
+	*/
+	class deserialised_jass_v3 : public deserialised_jass_v2
+		{
+		protected:
+			file::file_read_only index_memory;				///< The index file
+			index_manifest manifest;							///< The manifest (from the metadata section)
+
+		public:
+			/*
+				DESERIALISED_JASS_V3::DESERIALISED_JASS_V3()
+				--------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param verbose [in] Should the index reading methods produce messages on stdout?
+			*/
+			explicit deserialised_jass_v3(bool verbose = false) :
+				deserialised_jass_v2(verbose)
+				{
+				/* Nothing */
+				}
+
+			/*
+				DESERIALISED_JASS_V3::~DESERIALISED_JASS_V3()
+				---------------------------------------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~deserialised_jass_v3()
+				{
+				/* Nothing */
+				}
+
+			/*
+				DESERIALISED_JASS_V3::READ_INDEX()
+				----------------------------------
+			*/
+			/*!
+				@brief Read a JASS v3 index into memory
+				@details The counts and codex of the index are checked against its manifest.  If verify is true then the checksum of each section
+				and of each postings list is checked too.
+				@param directory [in] The directory to search for and index
+				@param verify [in] Should the checksums be checked (default = false)?
+				@return 0 on failure (including a checksum failure), non-zero on success
+			*/
+			virtual size_t read_index(const std::string &directory = "", bool verify = false);
+
+			/*
+				DESERIALISED_JASS_V3::GET_MANIFEST()
+				------------------------------------
+			*/
+			/*!
+				@brief Return the manifest of the index (valid once the index has been read)
+				@return The manifest
+			*/
+			const index_manifest &get_manifest(void) const
+				{
+				return manifest;
+				}
+
+			/*
+				DESERIALISED_JASS_V3::READ_SECTIONS()
+				-------------------------------------
+			*/
+			/*!
+				@brief Check the header and section table of a JASS v3 index file and return the sections
+				@param file [in] The contents of the index file.
+				@param length [in] The length (in bytes) of file.
+				@param sections [out] The sections, indexed by serialise_jass_v3::section_type (a length of 0 means the section is missing).
+				@param problems [out] A description of each problem found is appended to this.
+				@param verify [in] Should the checksum of each section be checked?
+				@return true if the header, the section table, and (if verify) the checksums are all valid, else false
+			*/
+			static bool read_sections(const uint8_t *file, size_t length, std::vector<serialise_jass_v3::section> &sections, std::vector<std::string> &problems, bool verify);
+
+			/*
+				DESERIALISED_JASS_V3::UNITTEST()
+				--------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
@@ -0,0 +1,251 @@
+/*
+	DESERIALISED_POSITIONS.CPP
+	--------------------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <string.h>
+
+#include <algorithm>
+#include <filesystem>
+
+#include "file.h"
+#include "asserts.h"
+#include "deserialised_positions.h"
+#include "index_manager_positional.h"
+#include "compress_integer_variable_byte.h"
+
+namespace JASS
+	{
+	/*
+		DESERIALISED_POSITIONS::READ_INDEX()
+		------------------------------------
+	*/
+	bool deserialised_positions::read_index(const std::string &filename)
+		{
+		vocabulary.clear();
+		index.clear();
+		if (!std::filesystem::exists(filename))
+			return false;
+
+		file::read_entire_file(filename, index);
+		return deserialise();
+		}
+
+	/*
+		DESERIALISED_POSITIONS::READ_INDEX()
+		------------------------------------
+	*/
+	bool deserialised_positions::read_index(const uint8_t *memory, size_t length)
+		{
+		vocabulary.clear();
+		index.clear();
+		if (memory != nullptr)
+			index.assign(reinterpret_cast<const char *>(memory), length);
+
+		return deserialise();
+		}
+
+	/*
+		DESERIALISED_POSITIONS::DESERIALISE()
+		-------------------------------------
+	*/
+	bool deserialised_positions::deserialise(void)
+		{
+		if (index.size() < 2 * sizeof(uint64_t))
+			return false;
+
+		/*
+			The file ends with the location of the vocabulary and the number of terms
+		*/
+		uint64_t vocabulary_offset;
+		uint64_t terms;
+		memcpy(&vocabulary_offset, index.data() + index.size() - 2 * sizeof(uint64_t), sizeof(vocabulary_offset));
+		memcpy(&terms, index.data() + index.size() - sizeof(uint64_t), sizeof(terms));
+		if (vocabulary_offset > index.size() - 2 * sizeof(uint64_t))
+			return false;
+
+		/*
+			Load the vocabulary (each term takes at least its length and its offset)
+		*/
+		const uint8_t *current = reinterpret_cast<const uint8_t *>(index.data()) + vocabulary_offset;
+		const uint8_t *end = reinterpret_cast<const uint8_t *>(index.data()) + index.size() - 2 * sizeof(uint64_t);
+		if (terms > static_cast<uint64_t>(end - current) / (sizeof(uint32_t) + sizeof(uint64_t)))
+			return false;
+		vocabulary.reserve(terms);
+		for (uint64_t term = 0; term < terms; term++)
+			{
+			uint32_t length;
+			uint64_t offset;
+
+			if (current + sizeof(length) > end)
+				break;
+			memcpy(&length, current, sizeof(length));
+			current += sizeof(length);
+			if (current + length + sizeof(offset) > end)
+				break;
+			slice string(const_cast<uint8_t *>(current), length);
+			current += length;
+			memcpy(&offset, current, sizeof(offset));
+			current += sizeof(offset);
+
+			vocabulary.push_back(std::make_pair(string, offset));
+			}
+
+		if (vocabulary.size() != terms)
+			{
+			vocabulary.clear();
+			return false;
+			}
+
+		return true;
+		}
+
+	/*
+		DESERIALISED_POSITIONS::FIND()
+		------------------------------
+	*/
+	const uint8_t *deserialised_positions::find(const slice &term) const
+		{
+		auto found = std::lower_bound(vocabulary.begin(), vocabulary.end(), term, [](const std::pair<slice, uint64_t> &entry, const slice &key){ return slice::strict_weak_order_less_than(entry.first, key); });
+		if (found == vocabulary.end() || !(found->first == term))
+			return nullptr;
+
+		return reinterpret_cast<const uint8_t *>(index.data()) + found->second;
+		}
+
+	/*
+		DESERIALISED_POSITIONS::POSITIONS()
+		-----------------------------------
+	*/
+	bool deserialised_positions::positions(std::vector<uint32_t> &into, const slice &term, uint32_t document_id) const
+		{
+		into.clear();
+		const uint8_t *block = find(term);
+		if (block == nullptr)
+			return false;
+
+		/*
+			The blocks are 4-byte aligned so we can look at the document ids and offsets directly
+		*/
+		uint32_t document_frequency = *reinterpret_cast<const uint32_t *>(block);
+		const uint32_t *document_ids = reinterpret_cast<const uint32_t *>(block) + 1;
+		const uint32_t *offsets = document_ids + document_frequency;
+		const uint8_t *encoded = reinterpret_cast<const uint8_t *>(offsets + document_frequency + 1);
+
+		const uint32_t *found = std::lower_bound(document_ids, document_ids + document_frequency, document_id);
+		if (found == document_ids + document_frequency || *found != document_id)
+			return false;
+
+		/*
+			Decode the positions
+		*/
+		size_t which = found - document_ids;
+		const uint8_t *current = encoded + offsets[which];
+		const uint8_t *end = encoded + offsets[which + 1];
+		uint32_t position = 0;
+		while (current < end)
+			{
+			uint32_t gap;
+			compress_integer_variable_byte::decompress_into(&gap, current);
+			position += gap;
+			into.push_back(position);
+			}
+
+		return true;
+		}
+
+	/*
+		DESERIALISED_POSITIONS::PHRASE_OCCURS()
+		---------------------------------------
+	*/
+	bool deserialised_positions::phrase_occurs(const std::vector<slice> &phrase, uint32_t document_id) const
+		{
+		std::vector<uint32_t> starts;
+		std::vector<uint32_t> next;
+
+		if (phrase.empty() || !positions(starts, phrase[0], document_id))
+			return false;
+
+		/*
+			Keep only those starting positions where each of the following terms is in the following position
+		*/
+		for (size_t which = 1; which < phrase.size() && !starts.empty(); which++)
+			{
+			if (!positions(next, phrase[which], document_id))
+				return false;
+			starts.erase(std::remove_if(starts.begin(), starts.end(), [&next, which](uint32_t start){ return !std::binary_search(next.begin(), next.end(), start + static_cast<uint32_t>(which)); }), starts.end());
+			}
+
+		return !starts.empty();
+		}
+
+	/*
+		DESERIALISED_POSITIONS::UNITTEST()
+		----------------------------------
+	*/
+	void deserialised_positions::unittest(void)
+		{
+		const std::string filename = "deserialised_positions_unittest.bin";
+		deserialised_positions positions;
+
+		/*
+			A missing positional index isn't loaded
+		*/
+		JASS_assert(!positions.read_index(filename));
+		JASS_assert(!positions.is_loaded());
+
+		/*
+			Build one: "new york new york" and "york new jersey"
+		*/
+		index_manager_positional indexer;
+		parser::token token;
+		token.type = parser::token::alpha;
+		token.count = 1;
+
+		indexer.begin_document(slice("one"));
+		for (const char *term : {"new", "york", "new", "york"})
+			{
+			token.lexeme = slice(term);
+			indexer.term(token);
+			}
+		indexer.end_document(4);
+		indexer.begin_document(slice("two"));
+		for (const char *term : {"york", "new", "jersey"})
+			{
+			token.lexeme = slice(term);
+			indexer.term(token);
+			}
+		indexer.end_document(3);
+		JASS_assert(indexer.serialise_positions(filename));
+
+		/*
+			Check the phrases
+		*/
+		JASS_assert(positions.read_index(filename));
+		JASS_assert(positions.is_loaded());
+		JASS_assert(positions.phrase_occurs({slice("new"), slice("york")}, 0));
+		JASS_assert(!positions.phrase_occurs({slice("new"), slice("york")}, 1));
+		JASS_assert(positions.phrase_occurs({slice("york"), slice("new")}, 0));
+		JASS_assert(positions.phrase_occurs({slice("york"), slice("new"), slice("jersey")}, 1));
+		JASS_assert(!positions.phrase_occurs({slice("new"), slice("york"), slice("york")}, 0));
+		JASS_assert(!positions.phrase_occurs({slice("new"), slice("boston")}, 0));
+
+		/*
+			The same positions can be read from memory
+		*/
+		std::string contents;
+		file::read_entire_file(filename, contents);
+		deserialised_positions from_memory;
+		JASS_assert(from_memory.read_index(reinterpret_cast<const uint8_t *>(contents.data()), contents.size()));
+		JASS_assert(from_memory.phrase_occurs({slice("york"), slice("new"), slice("jersey")}, 1));
+		JASS_assert(!from_memory.read_index(reinterpret_cast<const uint8_t *>(contents.data()), 3));
+		JASS_assert(!from_memory.is_loaded());
+		contents.back() ^= 0x40;				// an impossible number of terms
+		JASS_assert(!from_memory.read_index(reinterpret_cast<const uint8_t *>(contents.data()), contents.size()));
+
+		std::filesystem::remove(filename);
+
+		puts("deserialised_positions::PASSED");
+		}
+	}
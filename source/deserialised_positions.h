@@ -0,0 +1,135 @@
+/*
+	DESERIALISED_POSITIONS.H
+	------------------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Read a positional index (CIpositions.bin) and use it to verify phrases.
+	@author Andrew Trotman
+	@copyright 2021 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+#include <vector>
+#include <utility>
+
+#include "slice.h"
+
+namespace JASS
+	{
+	/*
+		CLASS DESERIALISED_POSITIONS
+		----------------------------
+	*/
+	/*!
+		@brief Read a positional index (CIpositions.bin) and use it to verify phrases.
+		@details The positions file is written by index_manager_positional::serialise_positions().  It starts with a block for each term, each
+		starting on a 4-byte boundary.  A block is: the document frequency (uint32_t), the document ids in increasing order (uint32_t each, counting
+		from 0), the offset of each document's positions (uint32_t each, document frequency + 1 of them, relative to the end of the offsets), then
+		the positions of the term in each document, D1 and variable byte encoded.  After the blocks is the vocabulary, sorted on the term: for each
+		term the length of the term (uint32_t), the term, and the offset of its block (uint64_t).  The file ends with the offset of the vocabulary
+		(uint64_t) and the number of terms (uint64_t).
+	*/
+	class deserialised_positions
+		{
+		private:
+			std::string index;																	///< The contents of the positions file.
+			std::vector<std::pair<slice, uint64_t>> vocabulary;						///< Each term and the offset of its block, sorted on term.
+
+		private:
+			/*
+				DESERIALISED_POSITIONS::FIND()
+				------------------------------
+			*/
+			/*!
+				@brief Find the block for a term.
+				@param term [in] The term to look for.
+				@return A pointer to the block or nullptr if the term isn't in the positional index.
+			*/
+			const uint8_t *find(const slice &term) const;
+
+			/*
+				DESERIALISED_POSITIONS::DESERIALISE()
+				-------------------------------------
+			*/
+			/*!
+				@brief Build the vocabulary from the (already loaded) contents of the positions file (in index).
+				@return true if the positional index was loaded, false if it is missing or damaged.
+			*/
+			bool deserialise(void);
+
+		public:
+			/*
+				DESERIALISED_POSITIONS::READ_INDEX()
+				------------------------------------
+			*/
+			/*!
+				@brief Read the positional index from disk.
+				@param filename [in] The name of the positions file.
+				@return true if the positional index was loaded, false if it is missing or damaged.
+			*/
+			bool read_index(const std::string &filename);
+
+			/*
+				DESERIALISED_POSITIONS::READ_INDEX()
+				------------------------------------
+			*/
+			/*!
+				@brief Read the positional index from memory (for example, a section of a JASS v3 index).
+				@param memory [in] The contents of a positions file (which are copied).
+				@param length [in] The length (in bytes) of memory.
+				@return true if the positional index was loaded, false if it is missing or damaged.
+			*/
+			bool read_index(const uint8_t *memory, size_t length);
+
+			/*
+				DESERIALISED_POSITIONS::IS_LOADED()
+				-----------------------------------
+			*/
+			/*!
+				@brief Has a positional index been loaded?
+				@return true if a positional index has been loaded, else false.
+			*/
+			bool is_loaded(void) const
+				{
+				return !vocabulary.empty();
+				}
+
+			/*
+				DESERIALISED_POSITIONS::POSITIONS()
+				-----------------------------------
+			*/
+			/*!
+				@brief Get the positions of a term in a document.
+				@param into [out] The positions, in increasing order (empty if the term is not in the document).
+				@param term [in] The term.
+				@param document_id [in] The document (counting from 0).
+				@return true if the term occurs in the document, else false.
+			*/
+			bool positions(std::vector<uint32_t> &into, const slice &term, uint32_t document_id) const;
+
+			/*
+				DESERIALISED_POSITIONS::PHRASE_OCCURS()
+				---------------------------------------
+			*/
+			/*!
+				@brief Does the phrase (the terms in consecutive positions) occur in the document?
+				@param phrase [in] The terms of the phrase, in order.
+				@param document_id [in] The document (counting from 0).
+				@return true if the phrase occurs in the document, else false.
+			*/
+			bool phrase_occurs(const std::vector<slice> &phrase, uint32_t document_id) const;
+
+			/*
+				DESERIALISED_POSITIONS::UNITTEST()
+				----------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
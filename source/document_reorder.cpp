@@ -0,0 +1,674 @@
+/*
+	DOCUMENT_REORDER.CPP
+	--------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <math.h>
+
+#include <numeric>
+#include <sstream>
+#include <algorithm>
+
+#include "asserts.h"
+#include "posting.h"
+#include "document_reorder.h"
+#include "index_manager_sequential.h"
+#include "compress_integer_variable_byte.h"
+
+namespace JASS
+	{
+	/*
+		CLASS PRIMARY_KEY_COLLECTOR
+		---------------------------
+	*/
+	/*!
+		@brief Delegate to collect the primary keys from an index (the postings are ignored).
+	*/
+	class primary_key_collector : public index_manager::delegate
+		{
+		public:
+			std::vector<slice> keys;				///< The primary key of each document (element 0 is the dud document).
+
+		public:
+			/*
+				PRIMARY_KEY_COLLECTOR::PRIMARY_KEY_COLLECTOR()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents [in] The number of documents in the collection.
+			*/
+			explicit primary_key_collector(size_t documents) :
+				index_manager::delegate(documents),
+				keys(documents + 1)
+				{
+				/* Nothing */
+				}
+
+			/*
+				PRIMARY_KEY_COLLECTOR::OPERATOR()()
+				-----------------------------------
+			*/
+			/*!
+				@brief Ignore the postings.
+			*/
+			virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+				{
+				/* Nothing */
+				}
+
+			/*
+				PRIMARY_KEY_COLLECTOR::OPERATOR()()
+				-----------------------------------
+			*/
+			/*!
+				@brief Remember the primary key of the document.
+				@param document_id [in] The internal document identfier.
+				@param primary_key [in] This document's primary key (external document identifier).
+			*/
+			virtual void operator()(size_t document_id, const slice &primary_key)
+				{
+				if (document_id < keys.size())
+					keys[document_id] = primary_key;
+				}
+
+			/*
+				PRIMARY_KEY_COLLECTOR::FINISH()
+				-------------------------------
+			*/
+			/*!
+				@brief Nothing to do.
+			*/
+			virtual void finish(void)
+				{
+				/* Nothing */
+				}
+		};
+
+	/*
+		CLASS FORWARD_INDEX_COLLECTOR
+		-----------------------------
+	*/
+	/*!
+		@brief Delegate to build the forward index (the terms in each document) needed by recursive graph bisection.
+	*/
+	class forward_index_collector : public index_manager::delegate
+		{
+		public:
+			std::vector<std::vector<uint32_t>> terms_in;		///< The terms (as term ids counting from 0) in each document (element 0 is the dud document).
+			uint32_t terms;											///< The number of terms given ids.
+
+		public:
+			/*
+				FORWARD_INDEX_COLLECTOR::FORWARD_INDEX_COLLECTOR()
+				--------------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents [in] The number of documents in the collection.
+			*/
+			explicit forward_index_collector(size_t documents) :
+				index_manager::delegate(documents),
+				terms_in(documents + 1),
+				terms(0)
+				{
+				/* Nothing */
+				}
+
+			/*
+				FORWARD_INDEX_COLLECTOR::OPERATOR()()
+				-------------------------------------
+			*/
+			/*!
+				@brief Add the term to the forward index of each document it occurs in (unless it occurs in only one document).
+				@param term [in] The term name.
+				@param postings [in] The postings lists.
+				@param document_frequency [in] The document frequency of the term
+				@param document_ids [in] An array (of length document_frequency) of document ids.
+				@param term_frequencies [in] An array (of length document_frequency) of term frequencies (corresponding to document_ids).
+			*/
+			virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+				{
+				if (document_frequency < 2)
+					return;
+
+				for (compress_integer::integer *current = document_ids; current < document_ids + document_frequency; current++)
+					terms_in[*current].push_back(terms);
+				terms++;
+				}
+
+			/*
+				FORWARD_INDEX_COLLECTOR::OPERATOR()()
+				-------------------------------------
+			*/
+			/*!
+				@brief Ignore the primary keys.
+			*/
+			virtual void operator()(size_t document_id, const slice &primary_key)
+				{
+				/* Nothing */
+				}
+
+			/*
+				FORWARD_INDEX_COLLECTOR::FINISH()
+				---------------------------------
+			*/
+			/*!
+				@brief Nothing to do.
+			*/
+			virtual void finish(void)
+				{
+				/* Nothing */
+				}
+		};
+
+	/*
+		CLASS RENUMBERER
+		----------------
+	*/
+	/*!
+		@brief Delegate to copy the postings from one index into another while renumbering the documents.
+	*/
+	class renumberer : public index_manager::delegate
+		{
+		public:
+			const std::vector<compress_integer::integer> &new_id;		///< new_id[old document id] is the new document id.
+			index_manager_sequential &into;									///< The index being built.
+			std::vector<slice> keys;											///< The primary keys in the new order (element 0 is the dud document).
+			std::vector<posting> reordered;									///< The postings list being renumbered.
+
+		public:
+			/*
+				RENUMBERER::RENUMBERER()
+				------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents [in] The number of documents in the collection.
+				@param new_id [in] new_id[old document id] is the new document id.
+				@param into [in] The index to build.
+			*/
+			renumberer(size_t documents, const std::vector<compress_integer::integer> &new_id, index_manager_sequential &into) :
+				index_manager::delegate(documents),
+				new_id(new_id),
+				into(into),
+				keys(documents + 1)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RENUMBERER::OPERATOR()()
+				------------------------
+			*/
+			/*!
+				@brief Renumber the postings list then add it to the new index (as a D1-encoded list).
+				@param term [in] The term name.
+				@param postings [in] The postings lists.
+				@param document_frequency [in] The document frequency of the term
+				@param document_ids [in] An array (of length document_frequency) of document ids.
+				@param term_frequencies [in] An array (of length document_frequency) of term frequencies (corresponding to document_ids).
+			*/
+			virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+				{
+				reordered.resize(document_frequency);
+				for (compress_integer::integer which = 0; which < document_frequency; which++)
+					{
+					reordered[which].docid = new_id[document_ids[which]];
+					reordered[which].term_frequency = term_frequencies[which];
+					}
+				std::sort(reordered.begin(), reordered.end(), [](const posting &first, const posting &second){ return first.docid < second.docid; });
+
+				compress_integer::integer previous = 0;
+				for (auto &current : reordered)
+					{
+					compress_integer::integer document_id = current.docid;
+					current.docid -= previous;
+					previous = document_id;
+					}
+
+				parser::token token;
+				token.set(term);
+				into.term(token, reordered);
+				}
+
+			/*
+				RENUMBERER::OPERATOR()()
+				------------------------
+			*/
+			/*!
+				@brief Remember the primary key under the document's new id.
+				@param document_id [in] The internal document identfier.
+				@param primary_key [in] This document's primary key (external document identifier).
+			*/
+			virtual void operator()(size_t document_id, const slice &primary_key)
+				{
+				if (document_id != 0 && document_id < new_id.size())
+					keys[new_id[document_id]] = primary_key;
+				}
+
+			/*
+				RENUMBERER::FINISH()
+				--------------------
+			*/
+			/*!
+				@brief Nothing to do.
+			*/
+			virtual void finish(void)
+				{
+				/* Nothing */
+				}
+		};
+
+	/*
+		CLASS POSTINGS_SIZER
+		--------------------
+	*/
+	/*!
+		@brief Delegate to compute the size of the variable byte encoded d-gaps.
+	*/
+	class postings_sizer : public index_manager::delegate
+		{
+		public:
+			size_t bytes;						///< The size of the d-gaps seen so far.
+
+		public:
+			/*
+				POSTINGS_SIZER::POSTINGS_SIZER()
+				--------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents [in] The number of documents in the collection.
+			*/
+			explicit postings_sizer(size_t documents) :
+				index_manager::delegate(documents),
+				bytes(0)
+				{
+				/* Nothing */
+				}
+
+			/*
+				POSTINGS_SIZER::OPERATOR()()
+				----------------------------
+			*/
+			/*!
+				@brief Add the size of this term's d-gaps to the total.
+				@param term [in] The term name.
+				@param postings [in] The postings lists.
+				@param document_frequency [in] The document frequency of the term
+				@param document_ids [in] An array (of length document_frequency) of document ids.
+				@param term_frequencies [in] An array (of length document_frequency) of term frequencies (corresponding to document_ids).
+			*/
+			virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+				{
+				compress_integer::integer previous = 0;
+				for (compress_integer::integer *current = document_ids; current < document_ids + document_frequency; current++)
+					{
+					bytes += compress_integer_variable_byte::bytes_needed_for(*current - previous);
+					previous = *current;
+					}
+				}
+
+			/*
+				POSTINGS_SIZER::OPERATOR()()
+				----------------------------
+			*/
+			/*!
+				@brief Ignore the primary keys.
+			*/
+			virtual void operator()(size_t document_id, const slice &primary_key)
+				{
+				/* Nothing */
+				}
+
+			/*
+				POSTINGS_SIZER::FINISH()
+				------------------------
+			*/
+			/*!
+				@brief Nothing to do.
+			*/
+			virtual void finish(void)
+				{
+				/* Nothing */
+				}
+		};
+
+	/*
+		CLASS BISECTOR
+		--------------
+	*/
+	/*!
+		@brief Recursive graph bisection of a forward index.
+		@details The cost of storing a term's postings in a partition of n documents, d of which contain the term, is estimated as d * log2(n / (d + 1)).
+		Moving a document from one half to the other changes the cost of each of its terms, and the sum of those changes is the document's gain.
+		The degrees (the number of documents in each half containing each term) are held in arrays that are cleared after each split.
+	*/
+	class bisector
+		{
+		private:
+			const std::vector<std::vector<uint32_t>> &terms_in;	///< The terms in each document.
+			size_t iterations;												///< The largest number of rounds of swapping at each split.
+			size_t smallest_partition;										///< Partitions of this size or less are not split.
+			std::vector<int32_t> left_degree;							///< The number of documents in the left half containing each term.
+			std::vector<int32_t> right_degree;							///< The number of documents in the right half containing each term.
+			std::vector<double> left_to_right;							///< The gain (per term) of moving a document from the left to the right.
+			std::vector<double> right_to_left;							///< The gain (per term) of moving a document from the right to the left.
+			std::vector<uint32_t> partition_terms;						///< The terms in the partition being split.
+			std::vector<std::pair<double, uint32_t>> left_gains;	///< The gain of moving each document in the left half.
+			std::vector<std::pair<double, uint32_t>> right_gains;	///< The gain of moving each document in the right half.
+
+		private:
+			/*
+				BISECTOR::COST()
+				----------------
+			*/
+			/*!
+				@brief The estimated cost of storing the postings of a term.
+				@param degree [in] The number of documents in the partition that contain the term.
+				@param size [in] The number of documents in the partition.
+				@return The cost (in bits).
+			*/
+			static double cost(int32_t degree, double size)
+				{
+				return degree * log2(size / (degree + 1));
+				}
+
+			/*
+				BISECTOR::GAIN()
+				----------------
+			*/
+			/*!
+				@brief The reduction in cost of moving a document containing a term from one half to the other.
+				@param from_degree [in] The degree of the term in the half the document is in.
+				@param from_size [in] The number of documents in that half.
+				@param to_degree [in] The degree of the term in the other half.
+				@param to_size [in] The number of documents in the other half.
+				@return The gain.
+			*/
+			static double gain(int32_t from_degree, double from_size, int32_t to_degree, double to_size)
+				{
+				return cost(from_degree, from_size) + cost(to_degree, to_size) - cost(from_degree - 1, from_size) - cost(to_degree + 1, to_size);
+				}
+
+			/*
+				BISECTOR::COMPUTE_GAINS()
+				-------------------------
+			*/
+			/*!
+				@brief Compute the gain of moving each document in a half to the other half, sorted from largest gain to smallest.
+				@param documents [in] The documents in the half.
+				@param length [in] The number of documents in the half.
+				@param per_term [in] The gain (per term) of moving a document out of this half.
+				@param gains [out] The gain of each document.
+			*/
+			void compute_gains(const uint32_t *documents, size_t length, const std::vector<double> &per_term, std::vector<std::pair<double, uint32_t>> &gains)
+				{
+				gains.clear();
+				for (const uint32_t *document = documents; document < documents + length; document++)
+					{
+					double total = 0;
+					for (uint32_t term : terms_in[*document])
+						total += per_term[term];
+					gains.push_back(std::make_pair(total, *document));
+					}
+				std::sort(gains.begin(), gains.end(), [](const auto &first, const auto &second){ return first.first > second.first || (first.first == second.first && first.second < second.second); });
+				}
+
+		public:
+			/*
+				BISECTOR::BISECTOR()
+				--------------------
+			*/
+			/*!
+				@brief Constructor
+				@param terms_in [in] The terms in each document.
+				@param terms [in] The number of terms.
+				@param iterations [in] The largest number of rounds of swapping at each split.
+				@param smallest_partition [in] Partitions of this size or less are not split.
+			*/
+			bisector(const std::vector<std::vector<uint32_t>> &terms_in, size_t terms, size_t iterations, size_t smallest_partition) :
+				terms_in(terms_in),
+				iterations(iterations),
+				smallest_partition(smallest_partition < 2 ? 2 : smallest_partition),
+				left_degree(terms),
+				right_degree(terms),
+				left_to_right(terms),
+				right_to_left(terms)
+				{
+				/* Nothing */
+				}
+
+			/*
+				BISECTOR::BISECT()
+				------------------
+			*/
+			/*!
+				@brief Reorder the documents so that similar documents are near each other.
+				@param documents [in / out] The documents to reorder.
+				@param length [in] The number of documents.
+			*/
+			void bisect(uint32_t *documents, size_t length)
+				{
+				if (length <= smallest_partition)
+					return;
+
+				size_t left_length = length / 2;
+				size_t right_length = length - left_length;
+				uint32_t *left = documents;
+				uint32_t *right = documents + left_length;
+
+				/*
+					Compute the degrees of the terms in each half
+				*/
+				partition_terms.clear();
+				for (uint32_t *document = documents; document < documents + length; document++)
+					for (uint32_t term : terms_in[*document])
+						{
+						if (left_degree[term] == 0 && right_degree[term] == 0)
+							partition_terms.push_back(term);
+						if (document < right)
+							left_degree[term]++;
+						else
+							right_degree[term]++;
+						}
+
+				/*
+					Swap documents between the halves while that reduces the cost
+				*/
+				for (size_t iteration = 0; iteration < iterations; iteration++)
+					{
+					for (uint32_t term : partition_terms)
+						{
+						if (left_degree[term] != 0)
+							left_to_right[term] = gain(left_degree[term], static_cast<double>(left_length), right_degree[term], static_cast<double>(right_length));
+						if (right_degree[term] != 0)
+							right_to_left[term] = gain(right_degree[term], static_cast<double>(right_length), left_degree[term], static_cast<double>(left_length));
+						}
+
+					compute_gains(left, left_length, left_to_right, left_gains);
+					compute_gains(right, right_length, right_to_left, right_gains);
+
+					size_t swaps = 0;
+					for (; swaps < left_length && swaps < right_length; swaps++)
+						{
+						if (left_gains[swaps].first + right_gains[swaps].first <= 0)
+							break;
+
+						for (uint32_t term : terms_in[left_gains[swaps].second])
+							{
+							left_degree[term]--;
+							right_degree[term]++;
+							}
+						for (uint32_t term : terms_in[right_gains[swaps].second])
+							{
+							right_degree[term]--;
+							left_degree[term]++;
+							}
+						std::swap(left_gains[swaps].second, right_gains[swaps].second);
+						}
+
+					if (swaps == 0)
+						break;
+
+					for (size_t which = 0; which < left_length; which++)
+						left[which] = left_gains[which].second;
+					for (size_t which = 0; which < right_length; which++)
+						right[which] = right_gains[which].second;
+					}
+
+				/*
+					Clean up the degrees so that the arrays can be re-used for the halves
+				*/
+				for (uint32_t term : partition_terms)
+					left_degree[term] = right_degree[term] = 0;
+
+				bisect(left, left_length);
+				bisect(right, right_length);
+				}
+		};
+
+	/*
+		DOCUMENT_REORDER::BY_PRIMARY_KEY()
+		----------------------------------
+	*/
+	std::vector<compress_integer::integer> document_reorder::by_primary_key(index_manager &index)
+		{
+		size_t documents = index.get_highest_document_id();
+		primary_key_collector collector(documents);
+		index.iterate(collector);
+
+		std::vector<compress_integer::integer> order(documents);
+		std::iota(order.begin(), order.end(), 1);
+		std::stable_sort(order.begin(), order.end(), [&collector](compress_integer::integer first, compress_integer::integer second){ return slice::strict_weak_order_less_than(collector.keys[first], collector.keys[second]); });
+
+		std::vector<compress_integer::integer> new_id(documents + 1, 0);
+		for (size_t position = 0; position < order.size(); position++)
+			new_id[order[position]] = static_cast<compress_integer::integer>(position + 1);
+
+		return new_id;
+		}
+
+	/*
+		DOCUMENT_REORDER::BY_BISECTION()
+		--------------------------------
+	*/
+	std::vector<compress_integer::integer> document_reorder::by_bisection(index_manager &index, size_t iterations, size_t smallest_partition)
+		{
+		size_t documents = index.get_highest_document_id();
+		forward_index_collector collector(documents);
+		index.iterate(collector);
+
+		std::vector<uint32_t> order(documents);
+		std::iota(order.begin(), order.end(), 1);
+		bisector(collector.terms_in, collector.terms, iterations, smallest_partition).bisect(order.data(), order.size());
+
+		std::vector<compress_integer::integer> new_id(documents + 1, 0);
+		for (size_t position = 0; position < order.size(); position++)
+			new_id[order[position]] = static_cast<compress_integer::integer>(position + 1);
+
+		return new_id;
+		}
+
+	/*
+		DOCUMENT_REORDER::RENUMBER()
+		----------------------------
+	*/
+	void document_reorder::renumber(index_manager &from, const std::vector<compress_integer::integer> &new_id, index_manager_sequential &into)
+		{
+		size_t documents = from.get_highest_document_id();
+		renumberer callback(documents, new_id, into);
+		from.iterate(callback);
+
+		/*
+			Adding the documents copies their primary keys into the new index
+		*/
+		for (size_t document_id = 1; document_id <= documents; document_id++)
+			into.begin_document(callback.keys[document_id]);
+
+		auto &old_lengths = from.get_document_length_vector();
+		std::vector<compress_integer::integer> new_lengths(old_lengths.size(), 0);
+		for (size_t document_id = 1; document_id < old_lengths.size(); document_id++)
+			new_lengths[new_id[document_id]] = old_lengths[document_id];
+		into.set_document_length_vector(new_lengths);
+		}
+
+	/*
+		DOCUMENT_REORDER::POSTINGS_SIZE()
+		---------------------------------
+	*/
+	size_t document_reorder::postings_size(index_manager &index)
+		{
+		postings_sizer sizer(index.get_highest_document_id());
+		index.iterate(sizer);
+
+		return sizer.bytes;
+		}
+
+	/*
+		DOCUMENT_REORDER::UNITTEST()
+		----------------------------
+	*/
+	void document_reorder::unittest(void)
+		{
+		/*
+			Sorting by primary key (in dictionary order, so "aa" is before "c") gives the same index as indexing in that order
+		*/
+		index_manager_sequential unsorted;
+		index_manager_sequential::unittest_build_index(unsorted, "<DOC><DOCNO>c</DOCNO>one two two</DOC><DOC><DOCNO>a</DOCNO>two three</DOC><DOC><DOCNO>aa</DOCNO>one</DOC>");
+		index_manager_sequential sorted;
+		index_manager_sequential::unittest_build_index(sorted, "<DOC><DOCNO>a</DOCNO>two three</DOC><DOC><DOCNO>aa</DOCNO>one</DOC><DOC><DOCNO>c</DOCNO>one two two</DOC>");
+
+		auto new_id = by_primary_key(unsorted);
+		JASS_assert((new_id == std::vector<compress_integer::integer>{0, 3, 1, 2}));
+
+		index_manager_sequential renumbered;
+		renumber(unsorted, new_id, renumbered);
+
+		std::ostringstream expected_postings;
+		std::ostringstream expected_keys;
+		index_manager_sequential::delegate expected(sorted.get_highest_document_id(), expected_postings, expected_keys);
+		sorted.iterate_sorted(expected);
+
+		std::ostringstream computed_postings;
+		std::ostringstream computed_keys;
+		index_manager_sequential::delegate computed(renumbered.get_highest_document_id(), computed_postings, computed_keys);
+		renumbered.iterate_sorted(computed);
+
+		JASS_assert(computed_postings.str() == expected_postings.str());
+		JASS_assert(computed_keys.str() == expected_keys.str());
+		JASS_assert(renumbered.get_document_length_vector() == sorted.get_document_length_vector());
+
+		/*
+			Bisection brings together the documents with the same vocabulary
+		*/
+		std::string fruit = "apple banana cherry";
+		std::string letters = "xray yankee zulu";
+		std::string collection;
+		size_t document = 0;
+		for (const auto &contents : {fruit, fruit, letters, fruit, letters, letters, fruit, letters})
+			collection += "<DOC><DOCNO>" + std::to_string(++document) + "</DOCNO>" + contents + "</DOC>";
+
+		index_manager_sequential mixed;
+		index_manager_sequential::unittest_build_index(mixed, collection);
+		new_id = by_bisection(mixed, 20, 2);
+
+		std::vector<compress_integer::integer> fruit_ids = {new_id[1], new_id[2], new_id[4], new_id[7]};
+		std::sort(fruit_ids.begin(), fruit_ids.end());
+		JASS_assert(fruit_ids.back() - fruit_ids.front() == 3);			// the four fruit documents are together
+
+		std::vector<compress_integer::integer> permutation(new_id.begin() + 1, new_id.end());
+		std::sort(permutation.begin(), permutation.end());
+		for (size_t which = 0; which < permutation.size(); which++)
+			JASS_assert(permutation[which] == which + 1);
+
+		index_manager_sequential bisected;
+		renumber(mixed, new_id, bisected);
+		JASS_assert(bisected.get_highest_document_id() == mixed.get_highest_document_id());
+		JASS_assert(postings_size(mixed) == 32 && postings_size(bisected) == 32);			// 6 terms in 4 documents and 8 document numbers, each d-gap fits in one byte
+
+		puts("document_reorder::PASSED");
+		}
+	}
@@ -0,0 +1,100 @@
+/*
+	DOCUMENT_REORDER.H
+	------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Re-assign document identifiers so that the postings lists compress better
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <vector>
+
+#include "index_manager.h"
+#include "compress_integer.h"
+
+namespace JASS
+	{
+	class index_manager_sequential;
+
+	/*
+		CLASS DOCUMENT_REORDER
+		----------------------
+	*/
+	/*!
+		@brief Re-assign document identifiers so that the postings lists compress better.
+		@details The indexer numbers the documents in the order it sees them, but the d-gaps (and so the size of the index) depend on the order.
+		An ordering is a vector, new_id, where new_id[old document id] is the new document id (element 0 is the dud document and is unused).  An
+		ordering is computed by by_primary_key() or by_bisection() then applied with renumber(), which builds a new index with the postings,
+		document lengths, and primary keys in the new order.
+
+		Recursive graph bisection is from:  L. Dhulipala, I. Kabiljo, B. Karrer, G. Ottaviano, S. Pupyrev, A. Shalita (2016), Compressing
+		Graphs and Indexes with Recursive Graph Bisection, Proceedings of KDD 2016, pp. 1535-1544
+	*/
+	class document_reorder
+		{
+		public:
+			/*
+				DOCUMENT_REORDER::BY_PRIMARY_KEY()
+				----------------------------------
+			*/
+			/*!
+				@brief Compute the ordering that sorts the documents by primary key (for web documents this is usually the URL, so documents from the same site are together).
+				@param index [in] The index.
+				@return new_id, where new_id[old document id] is the new document id.
+			*/
+			static std::vector<compress_integer::integer> by_primary_key(index_manager &index);
+
+			/*
+				DOCUMENT_REORDER::BY_BISECTION()
+				--------------------------------
+			*/
+			/*!
+				@brief Compute the ordering using recursive graph bisection (BP).
+				@details The documents are split in half and documents are swapped between the halves to reduce the (estimated) cost of storing the d-gaps, then each half
+				is split the same way.  Terms that occur in only one document are ignored as they do not affect the cost.
+				@param index [in] The index.
+				@param iterations [in] The largest number of rounds of swapping at each split.
+				@param smallest_partition [in] Partitions of this many documents (or fewer) are not split.
+				@return new_id, where new_id[old document id] is the new document id.
+			*/
+			static std::vector<compress_integer::integer> by_bisection(index_manager &index, size_t iterations = 20, size_t smallest_partition = 16);
+
+			/*
+				DOCUMENT_REORDER::RENUMBER()
+				----------------------------
+			*/
+			/*!
+				@brief Build a new index by renumbering the documents in an existing index.
+				@details The field lengths and term positions are not copied so this should not be used with fields or positional indexes.
+				@param from [in] The index to renumber.
+				@param new_id [in] The ordering, where new_id[old document id] is the new document id.
+				@param into [out] The renumbered index (which must be empty).  It has its own copy of everything it needs so from can be deleted.
+			*/
+			static void renumber(index_manager &from, const std::vector<compress_integer::integer> &new_id, index_manager_sequential &into);
+
+			/*
+				DOCUMENT_REORDER::POSTINGS_SIZE()
+				---------------------------------
+			*/
+			/*!
+				@brief Return the size of the postings if the d-gaps are variable byte encoded (as a measure of how well the document ids compress).
+				@param index [in] The index.
+				@return The size in bytes.
+			*/
+			static size_t postings_size(index_manager &index);
+
+			/*
+				DOCUMENT_REORDER::UNITTEST()
+				----------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
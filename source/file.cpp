@@ -33,7 +33,7 @@ namespace JASS
 		FILE::FILE_READ_ONLY::OPEN()
 		----------------------------
 	*/
-	size_t file::file_read_only::open(const std::string &filename)
+	size_t file::file_read_only::open(const std::string &filename, bool populate)
 		{
 		#ifdef _MSC_VER
 			hFile = CreateFile(filename.c_str(), GENERIC_READ, 0, NULL, OPEN_EXISTING, FILE_ATTRIBUTE_READONLY, NULL);
@@ -88,8 +88,10 @@ namespace JASS
 			#ifdef __APPLE__
 				file_contents = (uint8_t *)mmap(nullptr, statistics.st_size, PROT_READ, MAP_PRIVATE, reader, 0);
 			#else
-				file_contents = (uint8_t *)mmap(nullptr, statistics.st_size, PROT_READ, MAP_PRIVATE | MAP_POPULATE, reader, 0);
+				file_contents = (uint8_t *)mmap(nullptr, statistics.st_size, PROT_READ, MAP_PRIVATE | (populate ? MAP_POPULATE : 0), reader, 0);
 			#endif
+			if (file_contents == MAP_FAILED)
+				file_contents = nullptr;
 
 			/*
 				Close the file
@@ -108,6 +110,47 @@ namespace JASS
 		#endif
 		}
 
+	/*
+		FILE::FILE_READ_ONLY::ADVISE()
+		------------------------------
+	*/
+	bool file::file_read_only::advise(access_pattern pattern, size_t offset, size_t length) const
+		{
+		if (file_contents == nullptr || offset >= size)
+			return false;
+
+		#ifdef _MSC_VER
+			return false;
+		#else
+			/*
+				madvise() works on whole pages so round the start down and the end up to a page boundary
+			*/
+			size_t page_size = sysconf(_SC_PAGESIZE);
+			size_t end = length > size - offset ? size : offset + length;
+			size_t start = offset - offset % page_size;
+			end = end + (page_size - end % page_size) % page_size;
+
+			int advice = pattern == RANDOM ? MADV_RANDOM : pattern == SEQUENTIAL ? MADV_SEQUENTIAL : pattern == WILLNEED ? MADV_WILLNEED : MADV_NORMAL;
+			return madvise(const_cast<uint8_t *>(reinterpret_cast<const uint8_t *>(file_contents)) + start, end - start, advice) == 0;
+		#endif
+		}
+
+	/*
+		FILE::FILE_READ_ONLY::LOCK()
+		----------------------------
+	*/
+	bool file::file_read_only::lock(void) const
+		{
+		if (file_contents == nullptr)
+			return false;
+
+		#ifdef _MSC_VER
+			return VirtualLock(const_cast<void *>(file_contents), size) != 0;
+		#else
+			return mlock(file_contents, size) == 0;
+		#endif
+		}
+
 	/*
 		FILE::FILE_READ_ONLY::~FILE_READ_ONLY()
 		---------------------------------------
@@ -322,6 +365,41 @@ namespace JASS
 		return file_size < 0 ? 0 : file_size;
 		}
 	
+	/*
+		FILE::SYNC()
+		------------
+	*/
+	bool file::sync(void)
+		{
+		flush();
+		if (fp == nullptr || ::fflush(fp) != 0 || ferror(fp) != 0)
+			return false;
+
+		#ifdef _MSC_VER
+			return _commit(_fileno(fp)) == 0;
+		#else
+			return fsync(fileno(fp)) == 0;
+		#endif
+		}
+
+	/*
+		FILE::SYNC_DIRECTORY()
+		----------------------
+	*/
+	bool file::sync_directory(const std::string &directory)
+		{
+		#ifdef _MSC_VER
+			return true;
+		#else
+			int file_descriptor = open(directory.c_str(), O_RDONLY);
+			if (file_descriptor < 0)
+				return false;
+			bool synced = fsync(file_descriptor) == 0;
+			close(file_descriptor);
+			return synced;
+		#endif
+		}
+
 	/*
 		FILE::MKSTEMP()
 		---------------
@@ -410,6 +488,29 @@ namespace JASS
 		JASS_assert(byte == example_file[5]);
 		JASS_assert(disk_object->tell() == 6);
 
+		/*
+			Check file_read_only, both read in now and read on demand
+		*/
+		{
+		file_read_only populated;
+		JASS_assert(read_entire_file(filename, populated) == example_file.size());
+		file_read_only on_demand;
+		JASS_assert(read_entire_file(filename, on_demand, false) == example_file.size());
+		const uint8_t *contents;
+		on_demand.read_entire_file(contents);
+		JASS_assert(std::string(reinterpret_cast<const char *>(contents), example_file.size()) == example_file);
+		#ifndef _MSC_VER
+			JASS_assert(on_demand.advise(file_read_only::WILLNEED));
+			JASS_assert(on_demand.advise(file_read_only::RANDOM, 5, 3));
+		#endif
+		JASS_assert(!on_demand.advise(file_read_only::SEQUENTIAL, example_file.size()));
+
+		file_read_only missing;
+		JASS_assert(read_entire_file(filename + ".missing", missing, false) == 0);
+		JASS_assert(!missing.advise(file_read_only::WILLNEED));
+		JASS_assert(!missing.lock());
+		}
+
 		/*
 			Clean up
 		*/
@@ -486,6 +587,26 @@ namespace JASS
 		JASS_assert(got == example_file);
 		}
 
+		/*
+			CHECK SYNC (a file opened for reading cannot be written to)
+		*/
+		{
+		auto filename = file::mkstemp("jass");
+		{
+		file tester(filename, "w+b");
+		tester.write(example_file);
+		JASS_assert(tester.sync());
+		}
+		{
+		file tester(filename, "rb");
+		tester.write(example_file);
+		JASS_assert(!tester.sync());
+		}
+		remove(filename.c_str());
+		JASS_assert(sync_directory("."));
+		JASS_assert(!sync_directory(filename));
+		}
+
 		/*
 			Yay, we passed
 		*/
@@ -26,6 +26,7 @@
 #include <string>
 #include <vector>
 #include <memory>
+#include <limits>
 #include <stdexcept>
 
 namespace JASS
@@ -55,6 +56,19 @@ namespace JASS
 			class file_read_only
 				{
 				friend class file;
+				public:
+					/*!
+						@enum access_pattern
+						@brief How the contents of the file are expected to be used (passed to the kernel through madvise()).
+					*/
+					enum access_pattern
+						{
+						NORMAL,					///< No special treatment.
+						RANDOM,					///< Pages are expected to be used in random order (so don't read ahead).
+						SEQUENTIAL,				///< Pages are expected to be used in order (so read ahead aggressively).
+						WILLNEED					///< Pages are expected to be used soon (so read them in now).
+						};
+
 				private:
 #ifdef _MSC_VER
 					HANDLE hFile;							///< The file being mapped
@@ -84,10 +98,37 @@ namespace JASS
 					*/
 					/*!
 						@brief Open and read the file into memory
+						@details The file is mapped read-only so its pages are those of the page cache, which are shared with any other process that has the same file open.
 						@param filename [in] The name of the file to read
+						@param populate [in] If true the whole file is read in now, else each page is read from disk the first time it is used (default = true)
 						@return The size of the file
 					*/
-					size_t open(const std::string &filename);
+					size_t open(const std::string &filename, bool populate = true);
+
+					/*
+						FILE::FILE_READ_ONLY::ADVISE()
+						------------------------------
+					*/
+					/*!
+						@brief Tell the kernel how part of the file is going to be used.
+						@details The range is widened to whole pages.  This is a hint so failure is not an error, but it is reported.
+						@param pattern [in] How the pages are expected to be used.
+						@param offset [in] The start of the range (in bytes from the start of the file) (default = 0).
+						@param length [in] The length of the range in bytes (default = to the end of the file).
+						@return true on success, false if the range is not in the file or the hint is not supported.
+					*/
+					bool advise(access_pattern pattern, size_t offset = 0, size_t length = (std::numeric_limits<size_t>::max)()) const;
+
+					/*
+						FILE::FILE_READ_ONLY::LOCK()
+						----------------------------
+					*/
+					/*!
+						@brief Lock the whole file into memory so that it cannot be paged out (it is unlocked when this object is destroyed).
+						@details This usually needs privileges or a large enough memory lock limit (see ulimit -l).
+						@return true on success, false on failure.
+					*/
+					bool lock(void) const;
 
 					/*
 						FILE::FILE_READ_ONLY::~FILE_READ_ONLY()
@@ -240,11 +281,22 @@ namespace JASS
 						done with blocking I/O then this causes a bottleneck as we wait for the OS
 						to write to disk.
 					*/
-					::fwrite(buffer.get(), 1, buffer_used, fp);
+					if (fp != nullptr)
+						::fwrite(buffer.get(), 1, buffer_used, fp);		// a file that failed to open loses the data (see sync())
 					buffer_used = 0;
 					}
 				}
 
+			/*
+				FILE::SYNC()
+				------------
+			*/
+			/*!
+				@brief Flush the internal buffers and then force the file onto the disk (with fsync()).
+				@return true if everything written so far is on the disk, false if any write failed (or the sync failed).
+			*/
+			bool sync(void);
+
 			/*
 				FILE::READ()
 				------------
@@ -448,15 +500,15 @@ namespace JASS
 				------------------------
 			*/
 			/*!
-				@brief Read the contents of file filename into the std::string into.
-				@details Because into is a string it is naturally '\0' terminated by the C++ std::string class.
+				@brief Map the contents of file filename into memory (read only).
 				@param filename [in] The path of the file to read.
-				@param into [out] The std::string to write into.  This string will be re-sized to the size of the file.
+				@param into [out] The file_read_only to map the file into.
+				@param populate [in] If true the whole file is read in now, else each page is read the first time it is used (default = true).
 				@return The size of the file in bytes
 			*/
-			static size_t read_entire_file(const std::string &filename, file_read_only &into)
+			static size_t read_entire_file(const std::string &filename, file_read_only &into, bool populate = true)
 				{
-				return into.open(filename);
+				return into.open(filename, populate);
 				}
 
 			/*
@@ -497,6 +549,18 @@ namespace JASS
 			*/
 			static bool is_directory(const std::string &filename);
 
+			/*
+				FILE::SYNC_DIRECTORY()
+				----------------------
+			*/
+			/*!
+				@brief Force the directory entries (such as those changed by a rename) onto the disk.
+				@details On Windows directories cannot be synced so this does nothing.
+				@param directory [in] The path of the directory.
+				@return true on success, false on failure.
+			*/
+			static bool sync_directory(const std::string &directory);
+
 			/*
 				FILE::MKSTEMP()
 				---------------
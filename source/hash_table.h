@@ -280,6 +280,25 @@ namespace JASS
 				return (*table[hash].load())[key];
 				}
 
+			/*
+				HASH_TABLE::FIND()
+				------------------
+			*/
+			/*!
+				@brief Return a pointer to the element associated with the key, without creating one if the key is not in the hash table.
+				@param key [in] The key to look up.
+				@return The element associated with the key, or nullptr if there is none.
+			*/
+			const ELEMENT *find(const KEY &key) const
+				{
+				size_t hash = hash_pearson::hash<BITS>(key);
+
+				if (table[hash].load() == nullptr)
+					return nullptr;
+
+				return table[hash].load()->find(key);
+				}
+
 			/*
 				HASH_TABLE::UNITTEST()
 				----------------------
@@ -319,6 +338,15 @@ namespace JASS
 				for (const auto element : map)
 					output << element.first;
 				JASS_assert(output.str() == "0614538729");
+
+				/*
+					Check find() finds what is there and doesn't add what isn't
+				*/
+				JASS_assert(*map.find(slice("6")) == slice("six"));
+				JASS_assert(map.find(slice("10")) == nullptr);
+				std::ostringstream unchanged;
+				unchanged << map;
+				JASS_assert(unchanged.str() == serialised.str());
 	
 				puts("hash_table::PASSED");
 				}
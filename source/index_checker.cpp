@@ -0,0 +1,423 @@
+/*
+	INDEX_CHECKER.CPP
+	-----------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <string.h>
+
+#include <memory>
+#include <algorithm>
+#include <filesystem>
+
+#include "file.h"
+#include "asserts.h"
+#include "index_checker.h"
+#include "unittest_data.h"
+#include "serialise_jass_v1.h"
+#include "serialise_jass_v2.h"
+#include "deserialised_jass_v2.h"
+#include "index_manager_sequential.h"
+
+namespace JASS
+	{
+	/*
+		INDEX_CHECKER::READ_VARIABLE_BYTE()
+		-----------------------------------
+	*/
+	bool index_checker::read_variable_byte(uint64_t &into, const uint8_t *&from, const uint8_t *end)
+		{
+		into = 0;
+		for (size_t bytes = 0; bytes < 10 && from < end; bytes++)
+			{
+			uint8_t byte = *from++;
+			into = (into << 7) | (byte & 0x7F);
+			if (byte & 0x80)
+				return true;
+			}
+
+		return false;
+		}
+
+	/*
+		INDEX_CHECKER::CHECK_VOCABULARY()
+		---------------------------------
+	*/
+	bool index_checker::check_vocabulary(void)
+		{
+		std::filesystem::path path = directory;
+		std::string vocabulary;
+		std::string terms;
+		std::string postings;
+		size_t problems_on_entry = found.count;
+
+		for (const auto &filename : {deserialised_jass_v1::VOCAB_FILENAME, deserialised_jass_v1::TERMS_FILENAME, deserialised_jass_v1::POSTINGS_FILENAME, deserialised_jass_v1::PRIMARY_KEY_FILENAME})
+			if (!std::filesystem::exists(path / filename) || std::filesystem::file_size(path / filename) == 0)
+				found.add(std::string(filename) + " is missing or empty");
+		if (found.count != problems_on_entry)
+			return false;
+
+		file::read_entire_file((path / deserialised_jass_v1::VOCAB_FILENAME).string(), vocabulary);
+		file::read_entire_file((path / deserialised_jass_v1::TERMS_FILENAME).string(), terms);
+		file::read_entire_file((path / deserialised_jass_v1::POSTINGS_FILENAME).string(), postings);
+
+		if (version == 1 && vocabulary.size() % (3 * sizeof(uint64_t)) != 0)
+			found.add(std::string(deserialised_jass_v1::VOCAB_FILENAME) + " is not a whole number of vocabulary entries");
+
+		const uint8_t *from = reinterpret_cast<const uint8_t *>(vocabulary.data());
+		const uint8_t *end = from + vocabulary.size();
+		for (size_t entry = 0; from < end; entry++)
+			{
+			uint64_t term;
+			uint64_t offset;
+			uint64_t impacts;
+
+			if (version == 1)
+				{
+				if (end - from < static_cast<ptrdiff_t>(3 * sizeof(uint64_t)))
+					break;
+				memcpy(&term, from, sizeof(term));
+				memcpy(&offset, from + sizeof(term), sizeof(offset));
+				memcpy(&impacts, from + sizeof(term) + sizeof(offset), sizeof(impacts));
+				from += 3 * sizeof(uint64_t);
+				}
+			else if (!read_variable_byte(term, from, end) || !read_variable_byte(offset, from, end) || !read_variable_byte(impacts, from, end))
+				{
+				found.add("vocabulary entry " + std::to_string(entry) + " is truncated");
+				break;
+				}
+
+			std::string name = "vocabulary entry " + std::to_string(entry);
+			if (term >= terms.size() || terms.find('\0', term) == std::string::npos)
+				found.add(name + " has a bad term offset (" + std::to_string(term) + ")");
+			else
+				name = "term " + std::string(terms.c_str() + term);
+
+			if (impacts == 0)
+				found.add(name + " has no segments");
+			if (offset == 0 || offset >= postings.size())
+				found.add(name + " has a bad postings offset (" + std::to_string(offset) + ")");
+			else if (version == 1 && impacts > (postings.size() - offset) / sizeof(uint64_t))
+				found.add(name + " has a segment table that runs off the end of the postings");
+			}
+
+		return found.count == problems_on_entry;
+		}
+
+	/*
+		INDEX_CHECKER::CHECK_POSTINGS()
+		-------------------------------
+	*/
+	void index_checker::check_postings(deserialised_jass_v1 &index)
+		{
+		std::string codex_name;
+		int32_t d_ness;
+		std::unique_ptr<compress_integer> decoder(index.codex(codex_name, d_ness));
+
+		const uint8_t *postings_base = index.postings();
+		size_t postings_size = index.postings_size();
+		const uint8_t *postings_end = postings_base + postings_size;
+
+		std::vector<compress_integer::integer> decoded(documents + 1024);		// some codexes decode more integers than asked for
+		std::vector<size_t> seen(documents, 0);			// the term (+1) that last saw each document, for spotting duplicates
+
+		size_t term_number = 0;
+		for (const auto &term : index)
+			{
+			std::string name = "term " + std::string(reinterpret_cast<const char *>(term.term.address()), term.term.size());
+			const uint8_t *header = term.offset;
+			term_number++;
+
+			for (uint64_t segment = 0; segment < term.impacts; segment++)
+				{
+				std::string segment_name = name + " segment " + std::to_string(segment);
+				uint64_t impact;
+				uint64_t start;
+				uint64_t end;
+				uint64_t frequency;
+
+				/*
+					Get the segment header
+				*/
+				if (version == 1)
+					{
+					deserialised_jass_v1::segment_header_on_disk on_disk;
+					uint64_t pointer;
+
+					memcpy(&pointer, header + segment * sizeof(uint64_t), sizeof(pointer));
+					if (pointer > postings_size || postings_size - pointer < sizeof(on_disk))
+						{
+						found.add(segment_name + " has a bad header offset (" + std::to_string(pointer) + ")");
+						continue;
+						}
+					memcpy(&on_disk, postings_base + pointer, sizeof(on_disk));
+					impact = on_disk.impact;
+					start = on_disk.offset;
+					end = on_disk.end;
+					frequency = on_disk.segment_frequency;
+					}
+				else
+					{
+					if (!read_variable_byte(impact, header, postings_end) || !read_variable_byte(start, header, postings_end) || !read_variable_byte(end, header, postings_end) || !read_variable_byte(frequency, header, postings_end))
+						{
+						found.add(segment_name + " has a header that runs off the end of the postings");
+						break;
+						}
+					start += header - postings_base;			// relative to the end of the header
+					end += start;									// length rather than end
+					}
+
+				/*
+					Check the header
+				*/
+				if (impact == 0)
+					found.add(segment_name + " has an impact of 0");
+				if (start > end || end > postings_size)
+					{
+					found.add(segment_name + " has bad offsets (" + std::to_string(start) + " to " + std::to_string(end) + ")");
+					continue;
+					}
+				if (frequency == 0 || frequency > documents)
+					{
+					found.add(segment_name + " has a bad document count (" + std::to_string(frequency) + ")");
+					continue;
+					}
+
+				/*
+					Decode and check the document ids (D1 encoded, counting from 0)
+				*/
+				decoder->decode(decoded.data(), frequency, postings_base + start, end - start);
+				uint64_t document_id = 0;
+				for (size_t which = 0; which < frequency; which++)
+					{
+					if (which != 0 && decoded[which] == 0)
+						{
+						found.add(segment_name + " has a d-gap of 0 (non-monotonic) at position " + std::to_string(which));
+						break;
+						}
+					document_id += decoded[which];
+					if (document_id >= documents)
+						{
+						found.add(segment_name + " has document id " + std::to_string(document_id) + " but there are only " + std::to_string(documents) + " documents");
+						break;
+						}
+					if (seen[document_id] == term_number)
+						found.add(segment_name + " has document id " + std::to_string(document_id) + " which is also in another segment");
+					seen[document_id] = term_number;
+					}
+
+				segments++;
+				postings += frequency;
+				}
+			}
+		}
+
+	/*
+		INDEX_CHECKER::CHECK()
+		----------------------
+	*/
+	bool index_checker::check(void)
+		{
+		std::filesystem::path path = directory;
+
+		/*
+			Check the files against the manifest and work out which version of the index we have.  The postings are checked in detail (below) so
+			damage to them does not stop the check, but damage to any of the other files does (as loading the index trusts them).
+		*/
+		bool other_files_damaged = false;
+		if (manifest.read(directory))
+			{
+			version = manifest.version;
+
+			index_manifest postings_only;
+			index_manifest others = manifest;
+			auto postings_details = others.files.find(deserialised_jass_v1::POSTINGS_FILENAME);
+			if (postings_details != others.files.end())
+				{
+				postings_only.files.insert(*postings_details);
+				others.files.erase(postings_details);
+				}
+
+			auto problems = others.check_files(directory);
+			other_files_damaged = problems.size() != 0;
+			found.add(problems);
+			found.add(postings_only.check_files(directory));
+
+			if (manifest.files.size() == 0)
+				notes.push_back("The manifest has no file checksums, the files are not checksummed");
+			else
+				for (const char *filename : {deserialised_jass_v1::STEMMER_FILENAME, deserialised_jass_v1::STOPWORDS_FILENAME, deserialised_jass_v1::QUANTIZATION_FILENAME, deserialised_jass_v1::POSITIONS_FILENAME})
+					if (manifest.files.count(filename) == 0 && std::filesystem::exists(path / filename))
+						notes.push_back(std::string(filename) + " is not in the manifest, it is not checksummed");
+			}
+		else
+			{
+			version = index_manifest::legacy_version(directory);
+			notes.push_back(std::string("There is no manifest (") + index_manifest::FILENAME + "), the files are not checksummed");
+			}
+
+		if (version != 1 && version != 2)
+			{
+			version = 0;
+			return false;
+			}
+
+		/*
+			Check the vocabulary then load the index (loading trusts the files so we stop if they are damaged).
+		*/
+		if (other_files_damaged || !check_vocabulary())
+			return false;
+
+		std::unique_ptr<deserialised_jass_v1> index(version == 1 ? new deserialised_jass_v1(false) : new deserialised_jass_v2(false));
+		if (index->read_index(directory) == 0)
+			{
+			found.add("The index cannot be loaded");
+			return false;
+			}
+		documents = index->document_count();
+		terms = index->term_count();
+
+		/*
+			Check the postings lists against their checksums then check the postings themselves.
+		*/
+		std::filesystem::path checksums = path / deserialised_jass_v1::CHECKSUMS_FILENAME;
+		if (std::filesystem::exists(checksums))
+			{
+			std::vector<std::string> list;
+			index->verify_postings(checksums.string(), list);
+			found.add(list);
+			}
+		else
+			notes.push_back(std::string("There are no postings checksums (") + deserialised_jass_v1::CHECKSUMS_FILENAME + "), the postings lists are not checksummed");
+
+		if (manifest.version != 0 && (documents != manifest.documents || terms != manifest.terms))
+			found.add("The index has " + std::to_string(documents) + " documents and " + std::to_string(terms) + " terms but the manifest says " + std::to_string(manifest.documents) + " and " + std::to_string(manifest.terms));
+
+		check_postings(*index);
+
+		return found.count == 0;
+		}
+
+	/*
+		INDEX_CHECKER::UNITTEST()
+		-------------------------
+	*/
+	void index_checker::unittest(void)
+		{
+		/*
+			Build an index with postings that are not compressed (so that they can be damaged), and check that it is clean
+		*/
+		index_manager_sequential index;
+		index_manager_sequential::unittest_build_index(index, unittest_data::ten_documents);
+		{
+		serialise_jass_v1 serialiser(index.get_highest_document_id(), "-cn", 1);
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+
+		{
+		index_checker clean("");
+		JASS_assert(clean.check());
+		JASS_assert(clean.version == 1 && clean.found.count == 0 && clean.documents == 10 && clean.terms == 20 && clean.postings == 65);
+		}
+
+		std::string postings;
+		file::read_entire_file(deserialised_jass_v1::POSTINGS_FILENAME, postings);
+
+		/*
+			Find the start of the first segment of "one" (which is in document 10) and of "nine" (which has several documents in its segment)
+		*/
+		uint64_t one_segment;
+		uint64_t nine_segment;
+		{
+		deserialised_jass_v1 loaded(false);
+		JASS_assert(loaded.read_index("") != 0);
+		std::vector<deserialised_jass_v1::segment_header> headers(10);
+		uint32_t smallest;
+		uint32_t largest;
+		query::DOCID_TYPE document_frequency;
+		for (const auto &term : loaded)
+			{
+			auto metadata = term;
+			loaded.get_segment_list(headers.data(), metadata, 1, smallest, largest, document_frequency);
+			if (term.term == slice("one"))
+				one_segment = headers[0].offset;
+			if (term.term == slice("nine"))
+				{
+				JASS_assert(headers[0].segment_frequency == 9);
+				nine_segment = headers[0].offset;
+				}
+			}
+		}
+
+		auto has_problem = [](const index_checker &checker, const std::string &problem)
+			{
+			return std::any_of(checker.found.reported.begin(), checker.found.reported.end(), [&](const std::string &reported){return reported.find(problem) != std::string::npos;});
+			};
+
+		/*
+			A document id that is out of range
+		*/
+		std::string damaged = postings;
+		compress_integer::integer gap = 1000;
+		memcpy(&damaged[one_segment], &gap, sizeof(gap));
+		file::write_entire_file(deserialised_jass_v1::POSTINGS_FILENAME, damaged);
+		{
+		index_checker checker("");
+		JASS_assert(!checker.check());
+		JASS_assert(has_problem(checker, "CIpostings.bin"));
+		JASS_assert(has_problem(checker, "term one segment 0 has document id 1000 but there are only 10 documents"));
+		}
+
+		/*
+			A d-gap that is not increasing
+		*/
+		damaged = postings;
+		gap = 0;
+		memcpy(&damaged[nine_segment + 3 * sizeof(gap)], &gap, sizeof(gap));
+		file::write_entire_file(deserialised_jass_v1::POSTINGS_FILENAME, damaged);
+		{
+		index_checker checker("");
+		JASS_assert(!checker.check());
+		JASS_assert(has_problem(checker, "term nine segment 0 has a d-gap of 0 (non-monotonic) at position 3"));
+		}
+
+		/*
+			A truncated postings file (so the vocabulary points past its end)
+		*/
+		file::write_entire_file(deserialised_jass_v1::POSTINGS_FILENAME, postings.substr(0, one_segment));
+		{
+		index_checker checker("", 2);
+		JASS_assert(!checker.check());
+		JASS_assert(has_problem(checker, "CIpostings.bin is"));
+		JASS_assert(has_problem(checker, "has a bad postings offset"));
+		JASS_assert(checker.found.reported.size() == 2 && checker.found.count > 2);
+		}
+
+		/*
+			Without the manifest the structure is still checked
+		*/
+		remove(index_manifest::FILENAME);
+		{
+		index_checker checker("");
+		JASS_assert(!checker.check());
+		JASS_assert(checker.notes.size() == 1 && has_problem(checker, "has a bad postings offset"));
+		}
+
+		file::write_entire_file(deserialised_jass_v1::POSTINGS_FILENAME, postings);
+		{
+		index_checker checker("");
+		JASS_assert(checker.check());
+		}
+
+		/*
+			No index
+		*/
+		{
+		index_checker checker("index_checker_missing_directory");
+		JASS_assert(!checker.check() && checker.version == 0);
+		}
+
+		puts("index_checker::PASSED");
+		}
+	}
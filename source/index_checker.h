@@ -0,0 +1,189 @@
+/*
+	INDEX_CHECKER.H
+	---------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Check a JASS index for damage (the engine of JASS_fsck).
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <stdint.h>
+
+#include <string>
+#include <vector>
+
+#include "index_manifest.h"
+#include "deserialised_jass_v1.h"
+
+namespace JASS
+	{
+	/*
+		CLASS INDEX_CHECKER
+		-------------------
+	*/
+	/*!
+		@brief Check a JASS v1 or v2 index for damage.
+		@details The size and checksum of each index file are checked against the manifest (CImanifest.txt) and the checksum of each postings list
+		against CIchecksums.bin (indexes built before these existed are not checksummed).  Then every vocabulary entry is walked, every segment
+		header is checked to be inside the postings file, and every segment is decoded with the codex recorded in the index and checked for document
+		ids that are out of range, d-gaps that are not increasing, and document ids that are in more than one segment of the same postings list.
+		Damage to the vocabulary stops the check (before the index is loaded) as loading the index trusts the pointers in the vocabulary.
+	*/
+	class index_checker
+		{
+		public:
+			/*
+				CLASS INDEX_CHECKER::PROBLEM_LIST
+				---------------------------------
+			*/
+			/*!
+				@brief The problems found in the index (only the first few of which are kept).
+			*/
+			class problem_list
+				{
+				public:
+					std::vector<std::string> reported;			///< The problems to print.
+					size_t count;										///< The number of problems found.
+					size_t report;										///< The number of problems to keep in reported.
+
+				public:
+					/*
+						INDEX_CHECKER::PROBLEM_LIST::PROBLEM_LIST()
+						-------------------------------------------
+					*/
+					/*!
+						@brief Constructor
+						@param report [in] Keep no more than this many problems (they are all counted).
+					*/
+					explicit problem_list(size_t report) :
+						count(0),
+						report(report)
+						{
+						/* Nothing */
+						}
+
+					/*
+						INDEX_CHECKER::PROBLEM_LIST::ADD()
+						----------------------------------
+					*/
+					/*!
+						@brief Add a problem.
+						@param problem [in] A description of the problem.
+					*/
+					void add(const std::string &problem)
+						{
+						count++;
+						if (reported.size() < report)
+							reported.push_back(problem);
+						}
+
+					/*
+						INDEX_CHECKER::PROBLEM_LIST::ADD()
+						----------------------------------
+					*/
+					/*!
+						@brief Add a list of problems.
+						@param list [in] A description of each problem.
+					*/
+					void add(const std::vector<std::string> &list)
+						{
+						for (const auto &problem : list)
+							add(problem);
+						}
+				};
+
+		private:
+			std::string directory;					///< The directory holding the index.
+			index_manifest manifest;				///< The manifest of the index (version 0 if it doesn't have one).
+
+		public:
+			problem_list found;						///< The problems found by check().
+			std::vector<std::string> notes;		///< Things that could not be checked (such as files that are not checksummed).
+			size_t version;							///< The version of the index (1 or 2), or 0 if there is no JASS v1 or v2 index in the directory.
+			size_t documents;							///< The number of documents in the index (once loaded).
+			size_t terms;								///< The number of terms in the index (once loaded).
+			size_t segments;							///< The number of segments checked.
+			size_t postings;							///< The number of postings checked.
+
+		private:
+			/*
+				INDEX_CHECKER::READ_VARIABLE_BYTE()
+				-----------------------------------
+			*/
+			/*!
+				@brief Decode a variable byte encoded integer (see compress_integer_variable_byte) without reading past the end of the buffer.
+				@param into [out] The decoded integer.
+				@param from [in/out] The start of the encoding, moved to the byte after it.
+				@param end [in] The end of the buffer.
+				@return true on success, false if the encoding is too long or runs off the end of the buffer.
+			*/
+			static bool read_variable_byte(uint64_t &into, const uint8_t *&from, const uint8_t *end);
+
+			/*
+				INDEX_CHECKER::CHECK_VOCABULARY()
+				---------------------------------
+			*/
+			/*!
+				@brief Check that each vocabulary entry points into the vocabulary strings and the postings (before the index is loaded as loading trusts them).
+				@return true if the vocabulary is sound, else false.
+			*/
+			bool check_vocabulary(void);
+
+			/*
+				INDEX_CHECKER::CHECK_POSTINGS()
+				-------------------------------
+			*/
+			/*!
+				@brief Walk every postings list, check each segment header, decode each segment, and check the document ids.
+				@param index [in] The (loaded) index.
+			*/
+			void check_postings(deserialised_jass_v1 &index);
+
+		public:
+			/*
+				INDEX_CHECKER::INDEX_CHECKER()
+				------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param directory [in] The directory holding the index.
+				@param report [in] Keep the descriptions of no more than this many problems (they are all counted).
+			*/
+			explicit index_checker(const std::string &directory, size_t report = 100) :
+				directory(directory),
+				found(report),
+				version(0),
+				documents(0),
+				terms(0),
+				segments(0),
+				postings(0)
+				{
+				/* Nothing */
+				}
+
+			/*
+				INDEX_CHECKER::CHECK()
+				----------------------
+			*/
+			/*!
+				@brief Check the index.
+				@details The problems are in found, and anything that could not be checked is in notes.
+				@return true if the index is clean, false if it is damaged or there is no index (version is 0).
+			*/
+			bool check(void);
+
+			/*
+				INDEX_CHECKER::UNITTEST()
+				-------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
@@ -12,6 +12,10 @@
 */
 #pragma once
 
+#include <string>
+#include <vector>
+#include <algorithm>
+
 #include "parser.h"
 #include "string_cpp.h"
 #include "index_postings.h"
@@ -35,6 +39,15 @@ namespace JASS
 		private:
 			 compress_integer::integer highest_document_id;								///< The highest document_id seen so far (counts from 1).
 			 std::vector<compress_integer::integer> document_length_vector;		///< vector of document lengths.
+			 std::vector<std::string> field_names;											///< The names of the fields (see set_fields()), field n is field_names[n - 1].
+			 std::vector<std::vector<compress_integer::integer>> field_length_vectors;	///< For each field, the length of that field in each document.
+			 std::vector<compress_integer::integer> current_field_lengths;		///< The length of each field in the document being indexed.
+
+		protected:
+			 size_t current_field;																///< The field the next term is in (0 for none, else counting from 1).
+
+		public:
+			 static constexpr char FIELD_SEPARATOR = ':';									///< The separator between field name and term in a field restricted term (title:jaguar).
 
 		public:
 			/*
@@ -169,7 +182,8 @@ namespace JASS
 				@brief Constructor
 			*/
 			index_manager() :
-				highest_document_id(0)				// initialised to 0, this is the number of documents that have (or is) being indexed.
+				highest_document_id(0),				// initialised to 0, this is the number of documents that have (or is) being indexed.
+				current_field(0)
 				{
 				document_length_vector.reserve(1'000'000);
 				document_length_vector.push_back(0);
@@ -219,6 +233,95 @@ namespace JASS
 			virtual void begin_document(const slice &primary_key)
 				{
 				highest_document_id++;
+				current_field = 0;
+				std::fill(current_field_lengths.begin(), current_field_lengths.end(), 0);
+				}
+
+			/*
+				INDEX_MANAGER::SET_FIELDS()
+				---------------------------
+			*/
+			/*!
+				@brief Set the names of the fields (such as the title) to track.  This must be called before any documents are indexed.
+				@details As well as being added to the index, a term in field n (see set_field()) is counted towards the length of that field
+				and is added to the index a second time as the field restricted term "name:term" (for example, "title:jaguar").
+				@param names [in] The names of the fields.  Field n is names[n - 1].
+			*/
+			virtual void set_fields(const std::vector<std::string> &names)
+				{
+				field_names = names;
+				field_length_vectors.assign(names.size(), std::vector<compress_integer::integer>(1, 0));		// document 0 is not used (and has length 0)
+				current_field_lengths.assign(names.size(), 0);
+				}
+
+			/*
+				INDEX_MANAGER::GET_FIELDS()
+				---------------------------
+			*/
+			/*!
+				@brief Return the names of the fields (see set_fields()).
+				@return The field names, field n is at position n - 1.
+			*/
+			const std::vector<std::string> &get_fields(void) const
+				{
+				return field_names;
+				}
+
+			/*
+				INDEX_MANAGER::SET_FIELD()
+				--------------------------
+			*/
+			/*!
+				@brief Tell this object which field the following terms are in.
+				@param field [in] The field (counting from 1, see set_fields()), or 0 for terms that are in no field.
+			*/
+			virtual void set_field(size_t field)
+				{
+				current_field = field;
+				}
+
+			/*
+				INDEX_MANAGER::COUNT_FIELD_TERM()
+				---------------------------------
+			*/
+			/*!
+				@brief Add one to the length of the current field in the current document (if there is a current field).  Call from term().
+			*/
+			void count_field_term(void)
+				{
+				if (current_field != 0)
+					current_field_lengths[current_field - 1]++;
+				}
+
+			/*
+				INDEX_MANAGER::GET_FIELD_LENGTH_VECTOR()
+				----------------------------------------
+			*/
+			/*!
+				@brief Return a reference to the length of the given field in each document.
+				@param field [in] The field (counting from 1).
+				@return The field length vector (indexed by document id). This is only valid for as long as the index_manager object exists.
+			*/
+			std::vector<compress_integer::integer> &get_field_length_vector(size_t field)
+				{
+				return field_length_vectors[field - 1];
+				}
+
+			/*
+				INDEX_MANAGER::GET_FIELD_POSTINGS()
+				-----------------------------------
+			*/
+			/*!
+				@brief Get the document ids and term frequencies of a term in a field (the postings of the field restricted term).
+				@param term [in] The term (without the field name).
+				@param field [in] The field (counting from 1).
+				@param document_ids [out] The document ids in which the term is found in the field.  Valid until the next call.
+				@param term_frequencies [out] The number of times the term is found in the field in each of document_ids.  Valid until the next call.
+				@return The number of documents in which the term is found in the field.
+			*/
+			virtual compress_integer::integer get_field_postings(const slice &term, size_t field, compress_integer::integer *&document_ids, index_postings_impact::impact_type *&term_frequencies)
+				{
+				return 0;
 				}
 			
 			/*
@@ -258,6 +361,8 @@ namespace JASS
 			virtual void end_document(compress_integer::integer document_length)
 				{
 				document_length_vector.push_back(document_length);
+				for (size_t field = 0; field < field_names.size(); field++)
+					field_length_vectors[field].push_back(current_field_lengths[field]);
 				}
 
 			/*
@@ -0,0 +1,198 @@
+/*
+	INDEX_MANAGER_POSITIONAL.CPP
+	----------------------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <vector>
+#include <algorithm>
+#include <filesystem>
+
+#include "file.h"
+#include "asserts.h"
+#include "index_manager_positional.h"
+#include "compress_integer_variable_byte.h"
+
+namespace JASS
+	{
+	/*
+		INDEX_MANAGER_POSITIONAL::POSITIONS_LIST::PUSH_BACK()
+		-----------------------------------------------------
+	*/
+	void index_manager_positional::positions_list::push_back(compress_integer::integer document_id, uint32_t position)
+		{
+		uint8_t encoded[10];
+		uint8_t *end = encoded;
+
+		if (document_id == highest_document)
+			{
+			compress_integer_variable_byte::compress_into(end, static_cast<uint32_t>(0));
+			compress_integer_variable_byte::compress_into(end, position - last_position);
+			}
+		else
+			{
+			compress_integer_variable_byte::compress_into(end, static_cast<uint32_t>(document_id - highest_document));
+			compress_integer_variable_byte::compress_into(end, position);
+			highest_document = document_id;
+			}
+		last_position = position;
+
+		for (uint8_t *byte = encoded; byte < end; byte++)
+			occurrences.push_back(*byte);
+		}
+
+	/*
+		INDEX_MANAGER_POSITIONAL::SERIALISE_POSITIONS()
+		-----------------------------------------------
+	*/
+	bool index_manager_positional::serialise_positions(const std::string &filename)
+		{
+		file out(filename, "w+b");
+		std::vector<std::pair<slice, uint64_t>> vocabulary;
+		std::vector<uint8_t> bytes;
+		std::vector<uint32_t> document_ids;
+		std::vector<uint32_t> offsets;
+		const uint8_t padding[sizeof(uint32_t)] = {};
+
+		for (const auto &[term, list] : positions)
+			{
+			/*
+				Convert the occurrences into document ids and the per-document D1 encoded positions
+			*/
+			bytes.clear();
+			document_ids.clear();
+			offsets.clear();
+
+			std::vector<uint8_t> occurrences;
+			for (const auto byte : list.occurrences)
+				occurrences.push_back(byte);
+			const uint8_t *current = occurrences.data();
+			const uint8_t *end = current + occurrences.size();
+			uint32_t document_id = 0;
+			while (current < end)
+				{
+				uint32_t document_gap;
+				uint32_t position_gap;
+				compress_integer_variable_byte::decompress_into(&document_gap, current);
+				compress_integer_variable_byte::decompress_into(&position_gap, current);
+
+				if (document_gap != 0)
+					{
+					document_id += document_gap;
+					document_ids.push_back(document_id - 1);			// the search engine counts documents from 0
+					offsets.push_back(static_cast<uint32_t>(bytes.size()));
+					}
+
+				uint8_t encoded[5];
+				uint8_t *into = encoded;
+				compress_integer_variable_byte::compress_into(into, position_gap);
+				bytes.insert(bytes.end(), encoded, into);
+				}
+			offsets.push_back(static_cast<uint32_t>(bytes.size()));
+
+			/*
+				Each block starts on a 4-byte boundary so that the reader can use the integers directly
+			*/
+			size_t alignment = out.tell() % sizeof(uint32_t);
+			if (alignment != 0)
+				out.write(padding, sizeof(uint32_t) - alignment);
+			vocabulary.push_back(std::make_pair(term, static_cast<uint64_t>(out.tell())));
+
+			uint32_t document_frequency = static_cast<uint32_t>(document_ids.size());
+			out.write(&document_frequency, sizeof(document_frequency));
+			out.write(document_ids.data(), document_ids.size() * sizeof(document_ids[0]));
+			out.write(offsets.data(), offsets.size() * sizeof(offsets[0]));
+			out.write(bytes.data(), bytes.size());
+			}
+
+		/*
+			Write the vocabulary in term order then the footer
+		*/
+		std::sort(vocabulary.begin(), vocabulary.end(), [](const auto &first, const auto &second) { return slice::strict_weak_order_less_than(first.first, second.first); });
+
+		uint64_t vocabulary_offset = out.tell();
+		for (const auto &[term, offset] : vocabulary)
+			{
+			uint32_t length = static_cast<uint32_t>(term.size());
+			out.write(&length, sizeof(length));
+			out.write(term.address(), term.size());
+			out.write(&offset, sizeof(offset));
+			}
+
+		uint64_t terms = vocabulary.size();
+		out.write(&vocabulary_offset, sizeof(vocabulary_offset));
+		out.write(&terms, sizeof(terms));
+
+		return true;
+		}
+
+	/*
+		INDEX_MANAGER_POSITIONAL::UNITTEST()
+		------------------------------------
+	*/
+	void index_manager_positional::unittest(void)
+		{
+		const std::string filename = "index_manager_positional_unittest.bin";
+		index_manager_positional indexer;
+		parser::token token;
+		token.type = parser::token::alpha;
+		token.count = 1;
+
+		/*
+			Index "a b a" and "b"
+		*/
+		indexer.begin_document(slice("one"));
+		for (const char *term : {"a", "b", "a"})
+			{
+			token.lexeme = slice(term);
+			indexer.term(token);
+			}
+		indexer.end_document(3);
+		indexer.begin_document(slice("two"));
+		token.lexeme = slice("b");
+		indexer.term(token);
+		indexer.end_document(1);
+
+		JASS_assert(indexer.serialise_positions(filename));
+
+		/*
+			Check the file byte for byte
+		*/
+		std::string got;
+		file::read_entire_file(filename, got);
+		std::filesystem::remove(filename);
+
+		/*
+			Blocks are written in hash order so find each from the vocabulary at the end of the file
+		*/
+		uint64_t vocabulary_offset = *reinterpret_cast<const uint64_t *>(got.data() + got.size() - 2 * sizeof(uint64_t));
+		uint64_t terms = *reinterpret_cast<const uint64_t *>(got.data() + got.size() - sizeof(uint64_t));
+		JASS_assert(terms == 2);
+
+		const char *vocabulary = got.data() + vocabulary_offset;
+		JASS_assert(*reinterpret_cast<const uint32_t *>(vocabulary) == 1);
+		JASS_assert(vocabulary[4] == 'a');
+		uint64_t a_offset = *reinterpret_cast<const uint64_t *>(vocabulary + 5);
+		JASS_assert(*reinterpret_cast<const uint32_t *>(vocabulary + 13) == 1);
+		JASS_assert(vocabulary[17] == 'b');
+		uint64_t b_offset = *reinterpret_cast<const uint64_t *>(vocabulary + 18);
+
+		/*
+			"a": df=1, docid 0, offsets {0, 2}, positions 0, 2 (D1 encoded as 0, 2)
+		*/
+		const uint32_t *a = reinterpret_cast<const uint32_t *>(got.data() + a_offset);
+		JASS_assert(a[0] == 1 && a[1] == 0 && a[2] == 0 && a[3] == 2);
+		const uint8_t *a_positions = reinterpret_cast<const uint8_t *>(a + 4);
+		JASS_assert(a_positions[0] == 0x80 && a_positions[1] == 0x82);
+
+		/*
+			"b": df=2, docids 0 and 1, offsets {0, 1, 2}, positions 1 and 0
+		*/
+		const uint32_t *b = reinterpret_cast<const uint32_t *>(got.data() + b_offset);
+		JASS_assert(b[0] == 2 && b[1] == 0 && b[2] == 1 && b[3] == 0 && b[4] == 1 && b[5] == 2);
+		const uint8_t *b_positions = reinterpret_cast<const uint8_t *>(b + 6);
+		JASS_assert(b_positions[0] == 0x81 && b_positions[1] == 0x80);
+
+		puts("index_manager_positional::PASSED");
+		}
+	}
@@ -0,0 +1,171 @@
+/*
+	INDEX_MANAGER_POSITIONAL.H
+	--------------------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Non-thread-safe indexer object that also records the position of each term in each document.
+	@author Andrew Trotman
+	@copyright 2021 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+
+#include "slice.h"
+#include "hash_table.h"
+#include "dynamic_array.h"
+#include "allocator_pool.h"
+#include "index_manager_sequential.h"
+
+namespace JASS
+	{
+	/*
+		CLASS INDEX_MANAGER_POSITIONAL
+		------------------------------
+	*/
+	/*!
+		@brief Non-thread-safe indexer object that also records the position of each term in each document.
+		@details The impact-ordered postings are built exactly as index_manager_sequential builds them.  As well as this, the position (counting from 0)
+		of each term in each document is recorded.  The positions are written to disk with serialise_positions() (see deserialised_positions for
+		the format) so that the search engine can verify phrases.
+	*/
+	class index_manager_positional : public index_manager_sequential
+		{
+		public:
+			static constexpr const char *POSITIONS_FILENAME = "CIpositions.bin";		///< The default name of the positions file.
+
+		private:
+			/*
+				CLASS INDEX_MANAGER_POSITIONAL::POSITIONS_LIST
+				----------------------------------------------
+			*/
+			/*!
+				@brief The positions of a single term in each document it occurs in.
+				@details Each occurrence is stored as a variable byte encoded pair: the difference between this document id and the last (0 for the same
+				document), then the difference between this position and the last in the same document (or the position if the first in the document).
+			*/
+			class positions_list
+				{
+				public:
+					compress_integer::integer highest_document;						///< The last document id seen (counting from 1).
+					uint32_t last_position;													///< The last position seen in highest_document.
+					dynamic_array<uint8_t> occurrences;									///< The variable byte encoded occurrences.
+
+				public:
+					/*
+						INDEX_MANAGER_POSITIONAL::POSITIONS_LIST::POSITIONS_LIST()
+						----------------------------------------------------------
+					*/
+					/*!
+						@brief Constructor.
+						@param memory_pool [in] All allocation is from this allocator.
+					*/
+					positions_list(allocator &memory_pool) :
+						highest_document(0),
+						last_position(0),
+						occurrences(memory_pool, 16, 1.5)
+						{
+						/* Nothing */
+						}
+
+					/*
+						INDEX_MANAGER_POSITIONAL::POSITIONS_LIST::PUSH_BACK()
+						-----------------------------------------------------
+					*/
+					/*!
+						@brief Add an occurrence of the term to the end of the list.
+						@param document_id [in] The document the term occurs in.
+						@param position [in] The position of the term in the document.
+					*/
+					void push_back(compress_integer::integer document_id, uint32_t position);
+				};
+
+		private:
+			allocator_pool position_memory;												///< All memory for the positions is allocated from this allocator.
+			hash_table<slice, positions_list, 24> positions;						///< The positions of each term, keyed on the term.
+			uint32_t position;																///< The position of the next term in the current document.
+
+		public:
+			/*
+				INDEX_MANAGER_POSITIONAL::INDEX_MANAGER_POSITIONAL()
+				----------------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+			*/
+			index_manager_positional() :
+				index_manager_sequential(),
+				positions(position_memory),
+				position(0)
+				{
+				/* Nothing */
+				}
+
+			/*
+				INDEX_MANAGER_POSITIONAL::~INDEX_MANAGER_POSITIONAL()
+				-----------------------------------------------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~index_manager_positional()
+				{
+				/* Nothing */
+				}
+
+			/*
+				INDEX_MANAGER_POSITIONAL::BEGIN_DOCUMENT()
+				------------------------------------------
+			*/
+			/*!
+				@brief Tell this object that you're about to start indexing a new object.
+				@param document_primary_key [in] The document's primary key (or external document identifier).
+			*/
+			virtual void begin_document(const slice &document_primary_key)
+				{
+				index_manager_sequential::begin_document(document_primary_key);
+				position = 0;
+				}
+
+			/*
+				INDEX_MANAGER_POSITIONAL::TERM()
+				--------------------------------
+			*/
+			/*!
+				@brief Hand a new term from the token stream to this object.
+				@param term [in] The term from the token stream.
+			*/
+			virtual void term(const parser::token &term)
+				{
+				index_manager_sequential::term(term);
+				positions[term.lexeme].push_back(get_highest_document_id(), position);
+				position++;
+				}
+
+			using index_manager_sequential::term;
+
+			/*
+				INDEX_MANAGER_POSITIONAL::SERIALISE_POSITIONS()
+				-----------------------------------------------
+			*/
+			/*!
+				@brief Write the positions to disk in the format read by deserialised_positions.
+				@details Document ids are written counting from 0 (as the search engine does).
+				@param filename [in] The name of the file to write.
+				@return true on success, else false.
+			*/
+			bool serialise_positions(const std::string &filename = POSITIONS_FILENAME);
+
+			/*
+				INDEX_MANAGER_POSITIONAL::UNITTEST()
+				------------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
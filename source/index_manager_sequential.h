@@ -14,6 +14,7 @@
 
 #include <vector>
 #include <sstream>
+#include <algorithm>
 
 #include "parser.h"
 #include "posting.h"
@@ -50,6 +51,14 @@ namespace JASS
 			size_t temporary_size;											///< The number of bytes in temporary
 			uint8_t *temporary;												///< Temporary buffer - cannot be used to store anything between calls
 
+			/*
+				These buffers are used for fields
+			*/
+			std::string field_term;												///< The re-used buffer holding the field restricted term (e.g. title:jaguar)
+			std::vector<uint8_t> field_temporary;							///< The re-used temporary buffer used by get_field_postings()
+			std::vector<compress_integer::integer> field_document_ids;	///< The re-used buffer storing the document ids from get_field_postings()
+			std::vector<index_postings_impact::impact_type> field_term_frequencies;	///< The re-used buffer storing the term frequencies from get_field_postings()
+
 		public:
 			/*
 				CLASS INDEX_MANAGER_SEQUENTIAL::DELEGATE
@@ -243,6 +252,69 @@ namespace JASS
 			virtual void term(const parser::token &term)
 				{
 				index[term.lexeme].push_back(get_highest_document_id(), term.count);
+
+				/*
+					If we're in a field then count it and add the field restricted term (e.g. title:jaguar)
+				*/
+				if (current_field != 0)
+					{
+					count_field_term();
+					make_field_term(field_term, current_field, term.lexeme);
+					index[slice(const_cast<char *>(field_term.c_str()), field_term.size())].push_back(get_highest_document_id(), term.count);
+					}
+				}
+
+			/*
+				INDEX_MANAGER_SEQUENTIAL::MAKE_FIELD_TERM()
+				-------------------------------------------
+			*/
+			/*!
+				@brief Construct the field restricted term (e.g. title:jaguar) for a term in a field.
+				@param into [out] The field restricted term.
+				@param field [in] The field (counting from 1).
+				@param term [in] The term.
+			*/
+			void make_field_term(std::string &into, size_t field, const slice &term) const
+				{
+				into = get_fields()[field - 1];
+				into += FIELD_SEPARATOR;
+				into.append(reinterpret_cast<char *>(term.address()), term.size());
+				}
+
+			/*
+				INDEX_MANAGER_SEQUENTIAL::GET_FIELD_POSTINGS()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Get the document ids and term frequencies of a term in a field (the postings of the field restricted term).
+				@param term [in] The term (without the field name).
+				@param field [in] The field (counting from 1).
+				@param document_ids [out] The document ids in which the term is found in the field.  Valid until the next call.
+				@param term_frequencies [out] The number of times the term is found in the field in each of document_ids.  Valid until the next call.
+				@return The number of documents in which the term is found in the field.
+			*/
+			virtual compress_integer::integer get_field_postings(const slice &term, size_t field, compress_integer::integer *&document_ids, index_postings_impact::impact_type *&term_frequencies)
+				{
+				std::string key;
+				make_field_term(key, field, term);
+				const index_postings *postings = index.find(slice(const_cast<char *>(key.c_str()), key.size()));
+				if (postings == nullptr)
+					return 0;
+
+				/*
+					These buffers are seperate from those used by iterate() as this is called (by BM25F) from within iterate()
+				*/
+				size_t documents = get_highest_document_id() + 1;
+				if (field_document_ids.size() < documents)
+					{
+					field_temporary.resize(documents * (sizeof(compress_integer::integer) / 7 + 1));
+					field_document_ids.resize(documents);
+					field_term_frequencies.resize(documents);
+					}
+
+				document_ids = field_document_ids.data();
+				term_frequencies = field_term_frequencies.data();
+				return postings->linearize(field_temporary.data(), field_temporary.size(), document_ids, term_frequencies, documents);
 				}
 
 			/*
@@ -355,6 +427,141 @@ namespace JASS
 					quantizer(callback, ++instance, term);
 				}
 
+			/*
+				INDEX_MANAGER_SEQUENTIAL::ITERATE_SORTED()
+				------------------------------------------
+			*/
+			/*!
+				@brief Iterate over the index calling callback.operator() with each postings list, in term order (see slice::strict_weak_order_less_than).
+				@details This is slower than iterate() because the terms must first be sorted, but the order does not depend on the hash function.
+				@param callback [in] The callback to call.
+			*/
+			virtual void iterate_sorted(index_manager::delegate &callback)
+				{
+				/*
+					Make sure we have allocated the memory necessary for iteration (i.e. to linearize the postings lists).
+				*/
+				make_space();
+
+				/*
+					Sort the terms
+				*/
+				std::vector<std::pair<const slice *, const index_postings *>> terms;
+				for (const auto &[term, postings] : index)
+					terms.push_back(std::pair(&term, &postings));
+				std::sort(terms.begin(), terms.end(), [](const auto &first, const auto &second) { return slice::strict_weak_order_less_than(*first.first, *second.first); });
+
+				/*
+					Iterate over the sorted terms calling the callback function with each term->postings pair.
+				*/
+				for (const auto &[term, postings] : terms)
+					{
+					auto document_frequency = postings->linearize(temporary, temporary_size, document_ids, term_frequencies, get_highest_document_id());
+					callback(*term, *postings, document_frequency, document_ids, term_frequencies);
+					}
+
+				/*
+					Iterate over the primary keys calling the callback function with each docid->key pair.
+				*/
+				size_t instance = 0;
+				callback(instance, slice("-"));
+				for (const auto &key : primary_key)
+					callback(++instance, key);
+				}
+
+			/*
+				INDEX_MANAGER_SEQUENTIAL::MEMORY_USED()
+				---------------------------------------
+			*/
+			/*!
+				@brief Return the number of bytes of memory used by this index so far.
+				@return The number of bytes allocated from the memory pool.
+			*/
+			size_t memory_used(void) const
+				{
+				return memory.size();
+				}
+
+			/*
+				INDEX_MANAGER_SEQUENTIAL::MERGE()
+				---------------------------------
+			*/
+			/*!
+				@brief Combine several partial indexes, each built from a share of the document collection, into this (empty) index.
+				@details Each partial index numbers its documents from 1.  global_document_ids[which][local] is the document id (in this index) of
+				document local in partials[which], and must be increasing in local.  Together the partial indexes must cover all document ids from 1 upwards.
+				The result is the same as if the documents had been indexed into this object one at a time in global document id order.
+				@param partials [in] The partial indexes.
+				@param global_document_ids [in] For each partial index, the mapping from its document ids to document ids in this index (element 0 is unused).
+			*/
+			void merge(const std::vector<index_manager_sequential *> &partials, const std::vector<std::vector<compress_integer::integer>> &global_document_ids)
+				{
+				/*
+					Work out which partial index holds each document, and where its primary key is.
+				*/
+				size_t documents = 0;
+				for (const auto &ids : global_document_ids)
+					documents += ids.size() - 1;
+
+				std::vector<std::vector<const slice *>> keys(partials.size());
+				std::vector<std::pair<size_t, compress_integer::integer>> owner(documents + 1);
+				for (size_t which = 0; which < partials.size(); which++)
+					{
+					for (const auto &key : partials[which]->primary_key)
+						keys[which].push_back(&key);
+					for (compress_integer::integer local = 1; local < global_document_ids[which].size(); local++)
+						owner[global_document_ids[which][local]] = std::pair(which, local);
+					}
+
+				/*
+					Add the documents in global order so that the primary keys and document lengths are in the right places.
+				*/
+				for (size_t document_id = 1; document_id <= documents; document_id++)
+					{
+					const auto &[which, local] = owner[document_id];
+					begin_document(*keys[which][local - 1]);
+					end_document(partials[which]->get_document_length_vector()[local]);
+					}
+
+				/*
+					Merge the postings lists.  Each term is merged when it is first seen, which is in the first partial index that contains it.
+				*/
+				for (auto partial : partials)
+					partial->make_space();
+
+				std::vector<std::pair<compress_integer::integer, index_postings_impact::impact_type>> merged;
+				for (size_t which = 0; which < partials.size(); which++)
+					for (const auto &[term, postings] : partials[which]->index)
+						{
+						bool already_merged = false;
+						for (size_t earlier = 0; earlier < which && !already_merged; earlier++)
+							already_merged = partials[earlier]->index.find(term) != nullptr;
+						if (already_merged)
+							continue;
+
+						/*
+							Collect the postings from this and all later partial indexes and put them in global document id order.
+						*/
+						merged.clear();
+						for (size_t from = which; from < partials.size(); from++)
+							{
+							const index_postings *list = from == which ? &postings : partials[from]->index.find(term);
+							if (list == nullptr)
+								continue;
+
+							index_manager_sequential &part = *partials[from];
+							auto document_frequency = list->linearize(part.temporary, part.temporary_size, part.document_ids, part.term_frequencies, part.get_highest_document_id());
+							for (compress_integer::integer posting = 0; posting < document_frequency; posting++)
+								merged.push_back(std::pair(global_document_ids[from][part.document_ids[posting]], part.term_frequencies[posting]));
+							}
+						std::sort(merged.begin(), merged.end());
+
+						index_postings &into = index[term];
+						for (const auto &[document_id, term_frequency] : merged)
+							into.push_back(document_id, term_frequency);
+						}
+				}
+
 			/*
 				INDEX_MANAGER_SEQUENTIAL::UNITTEST_BUILD_INDEX()
 				------------------------------------------------
@@ -365,7 +572,7 @@ namespace JASS
 				@param index [out] The index once built.
 				@param document_collection [in] The documents to index.
 			*/
-			static void unittest_build_index(index_manager_sequential &index, const std::string &document_collection)
+			static void unittest_build_index(index_manager &index, const std::string &document_collection)
 				{
 				class parser parser;								// We need a parser
 				document document;						// That creates documents
@@ -512,6 +719,60 @@ namespace JASS
 				JASS_assert(postings_result.str() == answer);
 				JASS_assert(primary_key_result.str() == primary_key_answer);
 
+				/*
+					Build the same index as two partial indexes from interleaved runs of documents, and merge them
+				*/
+				index_manager_sequential first_part;
+				index_manager_sequential second_part;
+				unittest_build_index(first_part, unittest_data::ten_document_1 + unittest_data::ten_document_2 + unittest_data::ten_document_3 + unittest_data::ten_document_7 + unittest_data::ten_document_8 + unittest_data::ten_document_9);
+				unittest_build_index(second_part, unittest_data::ten_document_4 + unittest_data::ten_document_5 + unittest_data::ten_document_6 + unittest_data::ten_document_10);
+
+				index_manager_sequential merged;
+				merged.merge({&first_part, &second_part}, {{0, 1, 2, 3, 7, 8, 9}, {0, 4, 5, 6, 10}});
+
+				/*
+					Check it produced the same index as indexing sequentially
+				*/
+				std::ostringstream merged_postings_result;
+				std::ostringstream merged_primary_key_result;
+				delegate merged_callback(10, merged_postings_result, merged_primary_key_result);
+				merged.iterate(merged_callback);
+
+				JASS_assert(merged_postings_result.str() == answer);
+				JASS_assert(merged_primary_key_result.str() == primary_key_answer);
+				JASS_assert(merged.get_document_length_vector() == index.get_document_length_vector());
+				JASS_assert(merged.get_highest_document_id() == index.get_highest_document_id());
+
+				/*
+					Index terms in fields: "jaguar <title>jaguar cars</title>"
+				*/
+				index_manager_sequential fielded;
+				parser::token token;
+				token.type = parser::token::alpha;
+				token.count = 1;
+				fielded.set_fields({"title"});
+				fielded.begin_document(slice("one"));
+				for (const char *term : {"jaguar", "jaguar", "cars"})
+					{
+					token.lexeme = slice(term);
+					fielded.term(token);
+					fielded.set_field(1);
+					}
+				fielded.end_document(3);
+
+				std::ostringstream fielded_result;
+				fielded.text_render(fielded_result);
+				JASS_assert(fielded_result.str().find("jaguar-><1,2>\n") != std::string::npos);
+				JASS_assert(fielded_result.str().find("title:jaguar-><1,1>\n") != std::string::npos);
+				JASS_assert(fielded_result.str().find("title:cars-><1,1>\n") != std::string::npos);
+				JASS_assert(fielded.get_field_length_vector(1) == std::vector<compress_integer::integer>({0, 2}));
+
+				compress_integer::integer *field_document_ids;
+				index_postings_impact::impact_type *field_frequencies;
+				JASS_assert(fielded.get_field_postings(slice("jaguar"), 1, field_document_ids, field_frequencies) == 1);
+				JASS_assert(field_document_ids[0] == 1 && field_frequencies[0] == 1);
+				JASS_assert(fielded.get_field_postings(slice("bus"), 1, field_document_ids, field_frequencies) == 0);
+
 				/*
 					Done
 				*/
@@ -0,0 +1,366 @@
+/*
+	INDEX_MANAGER_SPILL.CPP
+	-----------------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <stdio.h>
+
+#include <set>
+#include <sstream>
+#include <filesystem>
+
+#include "asserts.h"
+#include "unittest_data.h"
+#include "index_manager_spill.h"
+
+namespace JASS
+	{
+	/*
+		INDEX_MANAGER_SPILL::RUN_WRITER::RUN_WRITER()
+		---------------------------------------------
+	*/
+	index_manager_spill::run_writer::run_writer(const std::string &filename, size_t documents, compress_integer::integer first_document_id) :
+		index_manager::delegate(documents),
+		out(filename, "w+b"),
+		first_document_id(first_document_id)
+		{
+		/* Nothing */
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::RUN_WRITER::OPERATOR()()
+		---------------------------------------------
+	*/
+	void index_manager_spill::run_writer::operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+		{
+		/*
+			Convert into document ids in the whole collection then D1 encode and compress.  Variable byte takes at most 5 bytes per integer.
+		*/
+		this->document_ids.resize(document_frequency);
+		for (compress_integer::integer which = 0; which < document_frequency; which++)
+			this->document_ids[which] = document_ids[which] + first_document_id - 1;
+		compress_integer::d1_encode(this->document_ids.data(), this->document_ids.data(), document_frequency);
+
+		compressed.resize(document_frequency * 5 + 16);
+		uint64_t compressed_size = encoder.encode(compressed.data(), compressed.size(), this->document_ids.data(), document_frequency);
+
+		/*
+			Write the postings list
+		*/
+		uint32_t term_length = static_cast<uint32_t>(term.size());
+		uint32_t frequency = static_cast<uint32_t>(document_frequency);
+		out.write(&term_length, sizeof(term_length));
+		out.write(&frequency, sizeof(frequency));
+		out.write(&compressed_size, sizeof(compressed_size));
+		out.write(term.address(), term.size());
+		out.write(compressed.data(), compressed_size);
+		out.write(term_frequencies, sizeof(*term_frequencies) * document_frequency);
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::RUN_READER::RUN_READER()
+		---------------------------------------------
+	*/
+	index_manager_spill::run_reader::run_reader(const std::string &filename) :
+		in(filename, "rb"),
+		at_eof(false)
+		{
+		next();
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::RUN_READER::NEXT()
+		---------------------------------------
+	*/
+	bool index_manager_spill::run_reader::next(void)
+		{
+		uint32_t term_length;
+		uint32_t frequency;
+		uint64_t compressed_size;
+
+		if (in.read(&term_length, sizeof(term_length)) != sizeof(term_length) || in.read(&frequency, sizeof(frequency)) != sizeof(frequency) || in.read(&compressed_size, sizeof(compressed_size)) != sizeof(compressed_size))
+			{
+			at_eof = true;
+			return false;
+			}
+
+		term.resize(term_length);
+		in.read(&term[0], term_length);
+
+		compressed.resize(compressed_size);
+		in.read(compressed.data(), compressed_size);
+		document_ids.resize(frequency + 16);			// the decoder is allowed to over-run
+		decoder.decode(document_ids.data(), frequency, compressed.data(), compressed_size);
+		document_ids.resize(frequency);
+		compress_integer::d1_decode(document_ids.data(), document_ids.data(), frequency);
+
+		term_frequencies.resize(frequency);
+		in.read(term_frequencies.data(), sizeof(term_frequencies[0]) * frequency);
+
+		return true;
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::INDEX_MANAGER_SPILL()
+		------------------------------------------
+	*/
+	index_manager_spill::index_manager_spill(size_t memory_budget, const std::string &run_prefix) :
+		index_manager(),
+		memory_budget(memory_budget),
+		run_prefix(run_prefix),
+		in_memory(std::make_unique<index_manager_sequential>()),
+		documents_on_disk(0),
+		primary_key(memory, 1000, 1.5)
+		{
+		in_memory_empty_size = in_memory->memory_used();
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::~INDEX_MANAGER_SPILL()
+		-------------------------------------------
+	*/
+	index_manager_spill::~index_manager_spill()
+		{
+		std::error_code ignore;
+
+		for (const auto &filename : run_filenames)
+			std::filesystem::remove(filename, ignore);
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::BEGIN_DOCUMENT()
+		-------------------------------------
+	*/
+	void index_manager_spill::begin_document(const slice &document_primary_key)
+		{
+		index_manager::begin_document(document_primary_key);
+		primary_key.push_back(slice(memory, document_primary_key));
+		in_memory->begin_document(document_primary_key);
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::END_DOCUMENT()
+		-----------------------------------
+	*/
+	void index_manager_spill::end_document(compress_integer::integer document_length)
+		{
+		index_manager::end_document(document_length);
+		in_memory->end_document(document_length);
+
+		if (in_memory->memory_used() - in_memory_empty_size >= memory_budget)
+			spill();
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::SPILL()
+		----------------------------
+	*/
+	void index_manager_spill::spill(void)
+		{
+		if (in_memory->get_highest_document_id() == 0)
+			return;
+
+		/*
+			Write the run in term order (so that the runs can be merged)
+		*/
+		std::string filename = run_prefix + std::to_string(run_filenames.size()) + ".tmp";
+		run_filenames.push_back(filename);
+			{
+			run_writer writer(filename, in_memory->get_highest_document_id(), documents_on_disk + 1);
+			in_memory->iterate_sorted(writer);
+			writer.finish();
+			}
+
+		/*
+			Start again with an empty index
+		*/
+		documents_on_disk += in_memory->get_highest_document_id();
+		in_memory = std::make_unique<index_manager_sequential>();
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::MERGE_RUNS()
+		---------------------------------
+	*/
+	void index_manager_spill::merge_runs(index_manager::quantizing_delegate *quantizer, index_manager::delegate &callback)
+		{
+		/*
+			The serialisers keep the term until they finish(), which happens after this method returns, so the terms must outlive it.
+		*/
+		term_memory.rewind();
+
+		std::vector<std::unique_ptr<run_reader>> readers;
+		for (const auto &filename : run_filenames)
+			readers.push_back(std::make_unique<run_reader>(filename));
+
+		allocator_pool postings_memory(1024);
+		index_postings postings(postings_memory);				// the serialisers are passed the linearized lists and so don't need this
+		std::vector<compress_integer::integer> document_ids;
+		std::vector<index_postings_impact::impact_type> term_frequencies;
+
+		while (true)
+			{
+			/*
+				Find the smallest term.  The runs are in increasing document id order so merging a postings list is concatenation.
+			*/
+			const std::string *smallest = nullptr;
+			for (const auto &reader : readers)
+				if (!reader->at_eof && (smallest == nullptr || slice::strict_weak_order_less_than(slice((void *)reader->term.data(), reader->term.size()), slice((void *)smallest->data(), smallest->size()))))
+					smallest = &reader->term;
+
+			if (smallest == nullptr)
+				break;
+
+			std::string term = *smallest;
+			slice term_slice(term_memory, term.data(), term.data() + term.size());
+			document_ids.clear();
+			term_frequencies.clear();
+			for (auto &reader : readers)
+				if (!reader->at_eof && reader->term == term)
+					{
+					document_ids.insert(document_ids.end(), reader->document_ids.begin(), reader->document_ids.end());
+					term_frequencies.insert(term_frequencies.end(), reader->term_frequencies.begin(), reader->term_frequencies.end());
+					reader->next();
+					}
+
+			if (quantizer == nullptr)
+				callback(term_slice, postings, static_cast<compress_integer::integer>(document_ids.size()), document_ids.data(), term_frequencies.data());
+			else
+				(*quantizer)(callback, term_slice, postings, static_cast<compress_integer::integer>(document_ids.size()), document_ids.data(), term_frequencies.data());
+			}
+
+		/*
+			Iterate over the primary keys calling the callback function with each docid->key pair.
+			Note that the search engine counts documents from 1, not from 0.
+		*/
+		size_t instance = 0;
+		if (quantizer == nullptr)
+			{
+			callback(instance, slice("-"));
+			for (const auto &key : primary_key)
+				callback(++instance, key);
+			}
+		else
+			{
+			(*quantizer)(callback, instance, slice("-"));
+			for (const auto &key : primary_key)
+				(*quantizer)(callback, ++instance, key);
+			}
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::ITERATE()
+		------------------------------
+	*/
+	void index_manager_spill::iterate(index_manager::delegate &callback)
+		{
+		if (run_filenames.size() == 0)
+			in_memory->iterate(callback);
+		else
+			{
+			spill();
+			merge_runs(nullptr, callback);
+			}
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::ITERATE()
+		------------------------------
+	*/
+	void index_manager_spill::iterate(index_manager::quantizing_delegate &quantizer, index_manager::delegate &callback)
+		{
+		if (run_filenames.size() == 0)
+			in_memory->iterate(quantizer, callback);
+		else
+			{
+			spill();
+			merge_runs(&quantizer, callback);
+			}
+		}
+
+	/*
+		INDEX_MANAGER_SPILL::UNITTEST()
+		-------------------------------
+	*/
+	void index_manager_spill::unittest(void)
+		{
+		/*
+			Delegate that renders the linearized postings lists (one line each) and the primary keys.
+		*/
+		class render : public index_manager::delegate
+			{
+			public:
+				std::set<std::string> postings;
+				std::set<std::string> terms;
+				std::vector<slice> kept_terms;				// the serialisers keep the terms until they finish()
+				std::ostringstream primary_keys;
+
+			public:
+				render() : index_manager::delegate(10) {}
+
+				virtual void operator()(const slice &term, const index_postings &postings_list, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+					{
+					std::ostringstream line;
+					line << term << "->";
+					for (compress_integer::integer which = 0; which < document_frequency; which++)
+						line << '<' << document_ids[which] << ',' << static_cast<size_t>(term_frequencies[which]) << '>';
+					postings.insert(line.str());
+					terms.insert(std::string((char *)term.address(), term.size()));
+					kept_terms.push_back(term);
+					}
+
+				virtual void operator()(size_t document_id, const slice &primary_key)
+					{
+					primary_keys << document_id << "->" << primary_key << '\n';
+					}
+
+				virtual void finish(void) {}
+			};
+
+		/*
+			Build the index for the standard 10 document collection in memory, and again with a budget so small that every document is spilled
+		*/
+		index_manager_sequential sequential;
+		index_manager_sequential::unittest_build_index(sequential, unittest_data::ten_documents);
+		render sequential_answer;
+		sequential.iterate(sequential_answer);
+
+		index_manager_spill spilled(1, "CIrun_unittest_");
+		index_manager_sequential::unittest_build_index(spilled, unittest_data::ten_documents);
+		JASS_assert(spilled.runs() == 10);
+
+		render spilled_answer;
+		spilled.iterate(spilled_answer);
+
+		JASS_assert(spilled_answer.postings == sequential_answer.postings);
+
+		std::set<std::string> kept_terms;
+		for (const auto &term : spilled_answer.kept_terms)
+			kept_terms.insert(std::string((char *)term.address(), term.size()));
+		JASS_assert(kept_terms == spilled_answer.terms);
+		JASS_assert(spilled_answer.primary_keys.str() == sequential_answer.primary_keys.str());
+		JASS_assert(spilled.get_document_length_vector() == sequential.get_document_length_vector());
+		JASS_assert(spilled.get_highest_document_id() == sequential.get_highest_document_id());
+
+		/*
+			Iterating a second time must give the same answer
+		*/
+		render second_answer;
+		spilled.iterate(second_answer);
+		JASS_assert(second_answer.postings == sequential_answer.postings);
+
+		/*
+			With a large budget nothing is written to disk
+		*/
+		index_manager_spill unspilled(1024 * 1024 * 1024, "CIrun_unittest_");
+		index_manager_sequential::unittest_build_index(unspilled, unittest_data::ten_documents);
+		JASS_assert(unspilled.runs() == 0);
+
+		render unspilled_answer;
+		unspilled.iterate(unspilled_answer);
+		JASS_assert(unspilled_answer.postings == sequential_answer.postings);
+
+		puts("index_manager_spill::PASSED");
+		}
+	}
@@ -0,0 +1,286 @@
+/*
+	INDEX_MANAGER_SPILL.H
+	---------------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Non-thread-safe indexer object that keeps within a memory budget by spilling postings to disk.
+	@author Andrew Trotman
+	@copyright 2021 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+#include <vector>
+#include <memory>
+
+#include "file.h"
+#include "dynamic_array.h"
+#include "index_manager.h"
+#include "allocator_pool.h"
+#include "index_manager_sequential.h"
+#include "compress_integer_variable_byte.h"
+
+namespace JASS
+	{
+	/*
+		CLASS INDEX_MANAGER_SPILL
+		-------------------------
+	*/
+	/*!
+		@brief Non-thread-safe indexer object that keeps within a memory budget by spilling postings to disk.
+		@details Documents are indexed into an index_manager_sequential.  When the postings in that index use more memory than the budget, they are
+		written to disk as a "run" (sorted by term) and a new, empty, index_manager_sequential is started.  At iterate() time the runs are merged
+		(a k-way merge) and passed to the callback, so the quantizers and serialisers don't know the difference.  The primary keys and document
+		lengths are kept in memory.  The run files are deleted when this object is destroyed.  If the memory budget is never reached then nothing
+		is written to disk and the behaviour is exactly that of index_manager_sequential.
+	*/
+	class index_manager_spill : public index_manager
+		{
+		private:
+			/*
+				CLASS INDEX_MANAGER_SPILL::RUN_WRITER
+				-------------------------------------
+			*/
+			/*!
+				@brief Delegate that writes the postings lists of an index_manager_sequential to a run file.
+				@details Each postings list is written as: the length of the term (uint32_t), the document frequency (uint32_t), the length of the
+				compressed document ids (uint64_t), the term, the D1 variable-byte encoded document ids, then the term frequencies.
+			*/
+			class run_writer : public index_manager::delegate
+				{
+				private:
+					file out;																			///< The run file.
+					compress_integer::integer first_document_id;								///< Document 1 in the index being written is this document id in the whole collection.
+					std::vector<compress_integer::integer> document_ids;					///< Buffer holding the document ids (in the whole collection) of the current postings list.
+					std::vector<uint8_t> compressed;												///< Buffer holding the compressed document ids.
+					compress_integer_variable_byte encoder;									///< The encoder used to compress the document ids.
+
+				public:
+					/*
+						INDEX_MANAGER_SPILL::RUN_WRITER::RUN_WRITER()
+						---------------------------------------------
+					*/
+					/*!
+						@brief Constructor.
+						@param filename [in] The name of the run file to create.
+						@param documents [in] The number of documents in the index being written.
+						@param first_document_id [in] The document id (in the whole collection) of the first document in the index being written.
+					*/
+					run_writer(const std::string &filename, size_t documents, compress_integer::integer first_document_id);
+
+					/*
+						INDEX_MANAGER_SPILL::RUN_WRITER::OPERATOR()()
+						---------------------------------------------
+					*/
+					/*!
+						@brief Write a postings list to the run file.
+						@param term [in] The term name.
+						@param postings [in] The postings lists.
+						@param document_frequency [in] The document frequency of the term
+						@param document_ids [in] An array (of length document_frequency) of document ids.
+						@param term_frequencies [in] An array (of length document_frequency) of term frequencies (corresponding to document_ids).
+					*/
+					virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies);
+
+					/*
+						INDEX_MANAGER_SPILL::RUN_WRITER::OPERATOR()()
+						---------------------------------------------
+					*/
+					/*!
+						@brief The primary keys are kept in memory so are not written to the run.
+						@param document_id [in] The internal document identfier.
+						@param primary_key [in] This document's primary key (external document identifier).
+					*/
+					virtual void operator()(size_t document_id, const slice &primary_key)
+						{
+						/* Nothing */
+						}
+
+					/*
+						INDEX_MANAGER_SPILL::RUN_WRITER::FINISH()
+						-----------------------------------------
+					*/
+					/*!
+						@brief Flush the run to disk.
+					*/
+					virtual void finish(void)
+						{
+						out.flush();
+						}
+				};
+
+			/*
+				CLASS INDEX_MANAGER_SPILL::RUN_READER
+				-------------------------------------
+			*/
+			/*!
+				@brief Read a run file one postings list at a time.
+			*/
+			class run_reader
+				{
+				private:
+					file in;																				///< The run file.
+					std::vector<uint8_t> compressed;												///< Buffer holding the compressed document ids.
+					compress_integer_variable_byte decoder;									///< The decoder used to decompress the document ids.
+
+				public:
+					std::string term;																	///< The current term.
+					std::vector<compress_integer::integer> document_ids;					///< The document ids of the current postings list.
+					std::vector<index_postings_impact::impact_type> term_frequencies;	///< The term frequencies of the current postings list.
+					bool at_eof;																		///< True once all postings lists have been read.
+
+				public:
+					/*
+						INDEX_MANAGER_SPILL::RUN_READER::RUN_READER()
+						---------------------------------------------
+					*/
+					/*!
+						@brief Constructor.  Open the run and read the first postings list.
+						@param filename [in] The name of the run file.
+					*/
+					explicit run_reader(const std::string &filename);
+
+					/*
+						INDEX_MANAGER_SPILL::RUN_READER::NEXT()
+						---------------------------------------
+					*/
+					/*!
+						@brief Move on to the next postings list in the run.
+						@return false at end of file, else true.
+					*/
+					bool next(void);
+				};
+
+		private:
+			size_t memory_budget;															///< Spill to disk once the postings use more than this many bytes.
+			std::string run_prefix;															///< The run files are called run_prefix followed by a sequence number.
+			std::vector<std::string> run_filenames;										///< The names of the runs written so far.
+			std::unique_ptr<index_manager_sequential> in_memory;						///< The documents indexed since the last spill.
+			size_t in_memory_empty_size;													///< The memory used by in_memory before any documents were added to it.
+			compress_integer::integer documents_on_disk;								///< The number of documents in the runs on disk.
+			allocator_pool memory;															///< Memory for the primary keys.
+			dynamic_array<slice> primary_key;											///< The list of primary keys (i.e. external document identifiers).
+			allocator_pool term_memory;													///< Memory for the terms passed to the callback by merge_runs().
+
+		protected:
+			/*
+				INDEX_MANAGER_SPILL::MERGE_RUNS()
+				---------------------------------
+			*/
+			/*!
+				@brief Merge the runs on disk, calling the callback (through the quantizer if there is one) with each postings list then each primary key.
+				@param quantizer [in] The quantizer to call (or nullptr to call callback directly).
+				@param callback [in] The callback to call.
+			*/
+			void merge_runs(index_manager::quantizing_delegate *quantizer, index_manager::delegate &callback);
+
+		public:
+			/*
+				INDEX_MANAGER_SPILL::INDEX_MANAGER_SPILL()
+				------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param memory_budget [in] Spill postings to disk once they use more than this number of bytes.
+				@param run_prefix [in] The prefix of the filenames used for the runs (which can include a path).
+			*/
+			explicit index_manager_spill(size_t memory_budget, const std::string &run_prefix = "CIrun_");
+
+			/*
+				INDEX_MANAGER_SPILL::~INDEX_MANAGER_SPILL()
+				-------------------------------------------
+			*/
+			/*!
+				@brief Destructor.  Delete the runs from disk.
+			*/
+			virtual ~index_manager_spill();
+
+			/*
+				INDEX_MANAGER_SPILL::BEGIN_DOCUMENT()
+				-------------------------------------
+			*/
+			/*!
+				@brief Tell this object that you're about to start indexing a new object.
+				@param document_primary_key [in] The document's primary key (or external document identifier).
+			*/
+			virtual void begin_document(const slice &document_primary_key);
+
+			/*
+				INDEX_MANAGER_SPILL::TERM()
+				---------------------------
+			*/
+			/*!
+				@brief Hand a new term from the token stream to this object.
+				@param term [in] The term from the token stream.
+			*/
+			virtual void term(const parser::token &term)
+				{
+				in_memory->term(term);
+				}
+
+			/*
+				INDEX_MANAGER_SPILL::END_DOCUMENT()
+				-----------------------------------
+			*/
+			/*!
+				@brief Tell this object that you've finished with the current document.  If the memory budget has been reached then spill to disk.
+				@param document_length [in] The length of the document (in terms).
+			*/
+			virtual void end_document(compress_integer::integer document_length);
+
+			/*
+				INDEX_MANAGER_SPILL::SPILL()
+				----------------------------
+			*/
+			/*!
+				@brief Write the postings of the documents indexed since the last spill to a run on disk.
+			*/
+			void spill(void);
+
+			/*
+				INDEX_MANAGER_SPILL::RUNS()
+				---------------------------
+			*/
+			/*!
+				@brief Return the number of runs written to disk so far.
+				@return The number of runs.
+			*/
+			size_t runs(void) const
+				{
+				return run_filenames.size();
+				}
+
+			/*
+				INDEX_MANAGER_SPILL::ITERATE()
+				------------------------------
+			*/
+			/*!
+				@brief Iterate over the index calling callback.operator() with each postings list.
+				@param callback [in] The callback to call.
+			*/
+			virtual void iterate(index_manager::delegate &callback);
+
+			/*
+				INDEX_MANAGER_SPILL::ITERATE()
+				------------------------------
+			*/
+			/*!
+				@brief Iterate over the index calling callback.operator() with each postings list.
+				@param quantizer [in] The quantizer that will quantize then call the serialiser callback.
+				@param callback [in] The callback that the quantizer should call.
+			*/
+			virtual void iterate(index_manager::quantizing_delegate &quantizer, index_manager::delegate &callback);
+
+			/*
+				INDEX_MANAGER_SPILL::UNITTEST()
+				-------------------------------
+			*/
+			/*!
+				@brief Unit test this class.
+			*/
+			static void unittest(void);
+		};
+	}
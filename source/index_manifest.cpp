@@ -0,0 +1,360 @@
+/*
+	INDEX_MANIFEST.CPP
+	------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <time.h>
+#include <string.h>
+
+#include <sstream>
+#include <filesystem>
+
+#include "file.h"
+#include "asserts.h"
+#include "checksum.h"
+#include "unittest_data.h"
+#include "index_manifest.h"
+#include "serialise_jass_v2.h"
+#include "serialise_jass_v3.h"
+#include "deserialised_jass_v2.h"
+#include "index_manager_sequential.h"
+
+namespace JASS
+	{
+	/*
+		INDEX_MANIFEST::SET_BUILD_TIME()
+		--------------------------------
+	*/
+	void index_manifest::set_build_time(void)
+		{
+		char buffer[32];
+		time_t now = time(nullptr);
+		struct tm utc;
+
+		gmtime_r(&now, &utc);
+		strftime(buffer, sizeof(buffer), "%Y-%m-%dT%H:%M:%SZ", &utc);
+		build_time = buffer;
+		}
+
+	/*
+		INDEX_MANIFEST::ADD_FILE()
+		--------------------------
+	*/
+	bool index_manifest::add_file(const std::string &filename)
+		{
+		if (!std::filesystem::exists(filename))
+			return false;
+
+		files[std::filesystem::path(filename).filename().string()] = file_details{static_cast<uint64_t>(std::filesystem::file_size(filename)), checksum::fletcher_16_file(filename)};
+
+		return true;
+		}
+
+	/*
+		INDEX_MANIFEST::CHECK_FILES()
+		-----------------------------
+	*/
+	std::vector<std::string> index_manifest::check_files(const std::string &directory) const
+		{
+		std::vector<std::string> problems;
+
+		for (const auto &[filename, details] : files)
+			{
+			std::string path = (std::filesystem::path(directory) / filename).string();
+
+			if (!std::filesystem::exists(path))
+				{
+				problems.push_back(filename + " is missing");
+				continue;
+				}
+
+			uint64_t size = std::filesystem::file_size(path);
+			uint16_t sum;
+			if (size != details.size)
+				problems.push_back(filename + " is " + std::to_string(size) + " bytes but should be " + std::to_string(details.size) + " bytes");
+			else if ((sum = checksum::fletcher_16_file(path)) != details.checksum)
+				problems.push_back(filename + " has checksum " + std::to_string(sum) + " but should have " + std::to_string(details.checksum));
+			}
+
+		return problems;
+		}
+
+	/*
+		INDEX_MANIFEST::SERIALISE()
+		---------------------------
+	*/
+	std::string index_manifest::serialise(void) const
+		{
+		std::string result;
+
+		result += "version " + std::to_string(version) + "\n";
+		result += "codex " + codex + "\n";
+		result += "d_ness " + std::to_string(d_ness) + "\n";
+		result += "documents " + std::to_string(documents) + "\n";
+		result += "terms " + std::to_string(terms) + "\n";
+		if (ranking_function != "")
+			result += "ranking_function " + ranking_function + "\n";
+		if (quantization != "")
+			result += "quantization " + quantization + "\n";
+		if (stemmer != "")
+			result += "stemmer " + stemmer + "\n";
+		if (build_time != "")
+			result += "build_time " + build_time + "\n";
+		for (const auto &[filename, details] : files)
+			result += "file " + filename + " " + std::to_string(details.size) + " " + std::to_string(details.checksum) + "\n";
+
+		return result;
+		}
+
+	/*
+		INDEX_MANIFEST::DESERIALISE()
+		-----------------------------
+	*/
+	bool index_manifest::deserialise(const std::string &text)
+		{
+		std::istringstream stream(text);
+		std::string line;
+		index_manifest result;
+		bool has_version = false;
+
+		*this = index_manifest();
+		while (std::getline(stream, line))
+			{
+			if (line.size() != 0 && line.back() == '\r')
+				line.pop_back();
+			if (line.size() == 0)
+				continue;
+
+			/*
+				The keyword is the first word and the value is the rest of the line
+			*/
+			auto space = line.find(' ');
+			std::string keyword = line.substr(0, space);
+			std::string value = space == std::string::npos ? "" : line.substr(space + 1);
+			std::istringstream number(value);
+
+			if (keyword == "version")
+				{
+				if (!(number >> result.version))
+					return false;
+				has_version = true;
+				}
+			else if (keyword == "codex")
+				result.codex = value;
+			else if (keyword == "d_ness")
+				{
+				if (!(number >> result.d_ness))
+					return false;
+				}
+			else if (keyword == "documents")
+				{
+				if (!(number >> result.documents))
+					return false;
+				}
+			else if (keyword == "terms")
+				{
+				if (!(number >> result.terms))
+					return false;
+				}
+			else if (keyword == "ranking_function")
+				result.ranking_function = value;
+			else if (keyword == "quantization")
+				result.quantization = value;
+			else if (keyword == "stemmer")
+				result.stemmer = value;
+			else if (keyword == "build_time")
+				result.build_time = value;
+			else if (keyword == "file")
+				{
+				std::string filename;
+				file_details details;
+				if (!(number >> filename >> details.size >> details.checksum))
+					return false;
+				result.files[filename] = details;
+				}
+			}
+
+		if (!has_version)
+			return false;
+
+		*this = result;
+		return true;
+		}
+
+	/*
+		INDEX_MANIFEST::READ()
+		----------------------
+	*/
+	bool index_manifest::read(const std::string &directory)
+		{
+		std::string filename = (std::filesystem::path(directory) / FILENAME).string();
+		std::string contents;
+
+		if (!std::filesystem::exists(filename) || file::read_entire_file(filename, contents) == 0)
+			{
+			*this = index_manifest();
+			return false;
+			}
+
+		return deserialise(contents);
+		}
+
+	/*
+		INDEX_MANIFEST::LEGACY_VERSION()
+		--------------------------------
+	*/
+	size_t index_manifest::legacy_version(const std::string &directory)
+		{
+		std::string filename = (std::filesystem::path(directory) / "CIdoclist.bin").string();
+		std::string doclist;
+
+		if (!std::filesystem::exists(filename))
+			return std::filesystem::exists(std::filesystem::path(directory) / serialise_jass_v3::FILENAME) ? 3 : 0;
+
+		if (file::read_entire_file(filename, doclist) < sizeof(uint64_t))
+			return 0;
+
+		/*
+			Both versions end with the number of documents.  JASS v1 has a table of offsets (one per document) before that, each pointing (in increasing
+			order) into the primary keys that are before the table.  In JASS v2 the same bytes are the text of the primary keys, which (as integers) are
+			far larger than the file.
+		*/
+		uint64_t documents;
+		memcpy(&documents, doclist.data() + doclist.size() - sizeof(uint64_t), sizeof(documents));
+		if (documents >= doclist.size() / sizeof(uint64_t))
+			return 2;
+
+		size_t table = doclist.size() - (documents + 1) * sizeof(uint64_t);
+		uint64_t previous = 0;
+		for (size_t document = 0; document < documents; document++)
+			{
+			uint64_t offset;
+			memcpy(&offset, doclist.data() + table + document * sizeof(uint64_t), sizeof(offset));
+			if (offset >= table || (document != 0 && offset <= previous))
+				return 2;
+			previous = offset;
+			}
+
+		return 1;
+		}
+
+	/*
+		INDEX_MANIFEST::UNITTEST()
+		--------------------------
+	*/
+	void index_manifest::unittest(void)
+		{
+		/*
+			Check that serialise() and deserialise() round trip
+		*/
+		index_manifest manifest;
+		manifest.version = 2;
+		manifest.codex = "Group Elias Gamma SIMD with Variable Byte";
+		manifest.d_ness = 1;
+		manifest.documents = 10;
+		manifest.terms = 20;
+		manifest.ranking_function = "ATIRE_BM25 k1=0.9 b=0.4";
+		manifest.quantization = "linear global impacts 1 1024";
+		manifest.stemmer = "porter2";
+		manifest.files["CIpostings.bin"] = file_details{1234, 4321};
+		manifest.set_build_time();
+		JASS_assert(manifest.build_time.size() == 20 && manifest.build_time.back() == 'Z');
+
+		index_manifest reread;
+		JASS_assert(reread.deserialise(manifest.serialise()));
+		JASS_assert(reread.serialise() == manifest.serialise());
+		JASS_assert(reread.codex == manifest.codex && reread.ranking_function == manifest.ranking_function);
+		JASS_assert(reread.files.size() == 1 && reread.files["CIpostings.bin"].size == 1234 && reread.files["CIpostings.bin"].checksum == 4321);
+
+		/*
+			Unknown keywords are skipped, but there must be a version and the numbers must be numbers
+		*/
+		JASS_assert(reread.deserialise("version 3\nsomething_new maybe\n") && reread.version == 3);
+		JASS_assert(!reread.deserialise("codex None\n") && reread.version == 0);
+		JASS_assert(!reread.deserialise("version 2\ndocuments many\n") && reread.documents == 0);
+
+		/*
+			The serialisers write the manifest, and legacy_version() can tell the versions apart without it
+		*/
+		index_manager_sequential index;
+		index_manager_sequential::unittest_build_index(index, unittest_data::ten_documents);
+		{
+		serialise_jass_v1 serialiser(index.get_highest_document_id());
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		JASS_assert(legacy_version() == 1);
+		JASS_assert(reread.read() && reread.version == 1 && reread.documents == 10 && reread.terms == 20);
+
+		{
+		serialise_jass_v2 serialiser(index.get_highest_document_id());
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		JASS_assert(legacy_version() == 2);
+		JASS_assert(reread.read() && reread.version == 2 && reread.documents == 10 && reread.terms == 20 && reread.d_ness == 1);
+
+		/*
+			The files and postings lists are checksummed, so damage to them is found
+		*/
+		JASS_assert(reread.files.size() == 5 && reread.check_files().size() == 0);
+		{
+		deserialised_jass_v2 loaded;
+		JASS_assert(loaded.read_index("", true) != 0);
+		}
+
+		std::string postings;
+		file::read_entire_file("CIpostings.bin", postings);
+		std::string damaged = postings;
+		damaged.back() ^= 0x01;
+		file::write_entire_file("CIpostings.bin", damaged);
+
+		JASS_assert(reread.check_files().size() == 1);
+		{
+		deserialised_jass_v2 loaded;
+		JASS_assert(loaded.read_index("", true) == 0);
+		JASS_assert(loaded.read_index("", false) != 0);
+		std::vector<std::string> problems;
+		JASS_assert(!loaded.verify_postings(deserialised_jass_v1::CHECKSUMS_FILENAME, problems) && problems.size() == 1);
+		}
+
+		file::write_entire_file("CIpostings.bin", postings.substr(0, postings.size() - 1));
+		JASS_assert(reread.check_files().size() == 1);
+		file::write_entire_file("CIpostings.bin", postings);
+		JASS_assert(reread.check_files().size() == 0);
+
+		/*
+			The other files that make up the index (the stemmer, stop words, etc.) are checksummed too
+		*/
+		file::write_entire_file("CIstemmer.txt", "Porter2\n");
+		{
+		serialise_jass_v2 serialiser(index.get_highest_document_id());
+		serialiser.add_auxiliary_file("CIstemmer.txt");
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		JASS_assert(reread.read() && reread.files.size() == 6 && reread.files.count("CIstemmer.txt") == 1 && reread.check_files().size() == 0);
+
+		file::write_entire_file("CIstemmer.txt", "Porter\n");
+		JASS_assert(reread.check_files().size() == 1);
+		{
+		deserialised_jass_v2 loaded;
+		JASS_assert(loaded.read_index("", true) == 0);
+		}
+		file::write_entire_file("CIstemmer.txt", "Porter2\n");
+		{
+		deserialised_jass_v2 loaded;
+		JASS_assert(loaded.read_index("", true) != 0 && loaded.stemmer() == "Porter2");
+		}
+		remove("CIstemmer.txt");
+
+		/*
+			No index
+		*/
+		JASS_assert(!reread.read("index_manifest_missing_directory"));
+		JASS_assert(legacy_version("index_manifest_missing_directory") == 0);
+
+		puts("index_manifest::PASSED");
+		}
+	}
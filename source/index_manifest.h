@@ -0,0 +1,169 @@
+/*
+	INDEX_MANIFEST.H
+	----------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief A description of an index (its format, codex, size, and how it was built) that is stored with the index
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <stdint.h>
+
+#include <map>
+#include <string>
+#include <vector>
+
+namespace JASS
+	{
+	/*
+		CLASS INDEX_MANIFEST
+		--------------------
+	*/
+	/*!
+		@brief A description of an index (its format, codex, size, and how it was built) that is stored with the index (in CImanifest.txt).
+		@details The manifest is written by the serialisers (see serialise_jass_v1::finish()) so that the search engine can tell which version of
+		the index it has been given (and check that the index is complete) rather than being told.  It is a text file of one "keyword value" pair per
+		line, where the value is the remainder of the line.  Indexes built before the manifest existed don't have one, legacy_version() can usually
+		tell which version those are.  The manifest also records the size and checksum of each index file so that damaged (e.g. truncated) files can
+		be found before they are loaded (see check_files()).
+	*/
+	class index_manifest
+		{
+		public:
+			static constexpr const char *FILENAME = "CImanifest.txt";		///< The name of the file the manifest is stored in.
+
+			/*
+				CLASS INDEX_MANIFEST::FILE_DETAILS
+				----------------------------------
+			*/
+			/*!
+				@brief The size and checksum of one of the index files.
+			*/
+			class file_details
+				{
+				public:
+					uint64_t size;						///< The size of the file (in bytes).
+					uint16_t checksum;				///< The Fletcher 16-bit checksum of the file (see checksum::fletcher_16()).
+				};
+
+		public:
+			size_t version;							///< The version of the index format (1 = JASS v1, 2 = JASS v2).
+			std::string codex;						///< The name of the codex used to compress the postings (see serialise_jass_v1::get_compressor()).
+			int32_t d_ness;							///< The d-ness of the codex (0 = D0, 1 = D1, etc.).
+			size_t documents;							///< The number of documents in the index.
+			size_t terms;								///< The number of terms in the vocabulary.
+			std::string ranking_function;			///< The ranking function (and its parameters) used to quantize the index ("" if not known).
+			std::string quantization;				///< How the scores were quantized into impacts ("" if not known).
+			std::string stemmer;						///< The stemmer used when indexing ("" if not known).
+			std::string build_time;					///< When the index was built (UTC, in ISO 8601 format).
+			std::map<std::string, file_details> files;	///< The size and checksum of each index file (by filename).
+
+		public:
+			/*
+				INDEX_MANIFEST::INDEX_MANIFEST()
+				--------------------------------
+			*/
+			/*!
+				@brief Constructor
+			*/
+			index_manifest() :
+				version(0),
+				d_ness(0),
+				documents(0),
+				terms(0)
+				{
+				/* Nothing */
+				}
+
+			/*
+				INDEX_MANIFEST::SET_BUILD_TIME()
+				--------------------------------
+			*/
+			/*!
+				@brief Set the build time to now.
+			*/
+			void set_build_time(void);
+
+			/*
+				INDEX_MANIFEST::ADD_FILE()
+				--------------------------
+			*/
+			/*!
+				@brief Record the size and checksum of an index file (which must be complete and flushed to disk).
+				@param filename [in] The name of the file (in the current directory).
+				@return true on success, false if the file cannot be read.
+			*/
+			bool add_file(const std::string &filename);
+
+			/*
+				INDEX_MANIFEST::CHECK_FILES()
+				-----------------------------
+			*/
+			/*!
+				@brief Check that each of the index files recorded in the manifest has the recorded size and checksum.
+				@param directory [in] The directory containing the index ("" for the current directory).
+				@return A description of each problem found (empty if the files are all as expected).
+			*/
+			std::vector<std::string> check_files(const std::string &directory = "") const;
+
+			/*
+				INDEX_MANIFEST::SERIALISE()
+				---------------------------
+			*/
+			/*!
+				@brief Return the manifest as text that can be read back with deserialise().
+				@return The manifest as text.
+			*/
+			std::string serialise(void) const;
+
+			/*
+				INDEX_MANIFEST::DESERIALISE()
+				-----------------------------
+			*/
+			/*!
+				@brief Read a manifest written by serialise().
+				@details Keywords that are not known are skipped so that later versions can add to the manifest.
+				@param text [in] The serialised manifest.
+				@return true on success, false if the text is not a manifest (in which case this object is left as if newly constructed).
+			*/
+			bool deserialise(const std::string &text);
+
+			/*
+				INDEX_MANIFEST::READ()
+				----------------------
+			*/
+			/*!
+				@brief Read the manifest of the index in the given directory.
+				@param directory [in] The directory containing the index ("" for the current directory).
+				@return true on success, false if the index has no manifest or it cannot be read.
+			*/
+			bool read(const std::string &directory = "");
+
+			/*
+				INDEX_MANIFEST::LEGACY_VERSION()
+				--------------------------------
+			*/
+			/*!
+				@brief Work out the version of an index that does not have a manifest (CImanifest.txt).
+				@details JASS v1 indexes end the primary key file (CIdoclist.bin) with a table of offsets to the primary keys, JASS v2 indexes don't.
+				JASS v3 indexes are a single file (CIindex.bin) that holds its own manifest.
+				@param directory [in] The directory containing the index ("" for the current directory).
+				@return The version (1, 2, or 3), or 0 if there is no index in the directory.
+			*/
+			static size_t legacy_version(const std::string &directory = "");
+
+			/*
+				INDEX_MANIFEST::UNITTEST()
+				--------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
@@ -17,6 +17,7 @@
 
 #include <map>
 #include <tuple>
+#include <algorithm>
 #include <sstream>
 #include <iostream>
 
@@ -277,32 +278,30 @@ std::ostream &operator<<(std::ostream &stream, const index_postings &data);
 //if (document_frequency < 10)
 //	std::cout << "DocOrder:" << *this << "\n";
 #endif
-				std::array<compress_integer::integer, index_postings_impact::largest_impact + 1> frequencies = {};			// +1 because it counts from 0.
 //				size_t number_of_postings = 0;
 				index_postings_impact::impact_type highest_impact = 0;
 				index_postings_impact::impact_type lowest_impact = (std::numeric_limits<decltype(lowest_impact)>::max)();
 
 				/*
-					Count up the number of times each impact is seen and compute the highest and lowest impact scores
+					Compute the highest and lowest impact scores
 				*/
 				index_postings_impact::impact_type *end = term_frequencies + document_frequency;
 				for (index_postings_impact::impact_type *current_tf = term_frequencies; current_tf < end; current_tf++)
 					{
-					/*
-						Calculate the maximum and minimum impact scores seen
-					*/
 					if (*current_tf > highest_impact)
 						highest_impact = *current_tf;
 					if (*current_tf < lowest_impact)
 						lowest_impact = *current_tf;
-
-					/*
-						Count the number of times each frequency is seen
-					*/
-					frequencies[*current_tf]++;
 //					number_of_postings++;
 					}
 
+				/*
+					Count the number of times each impact is seen (in the re-used buffer which is zero from lowest_impact to highest_impact)
+				*/
+				compress_integer::integer *frequencies = postings_list.get_frequencies(highest_impact);
+				for (index_postings_impact::impact_type *current_tf = term_frequencies; current_tf < end; current_tf++)
+					frequencies[*current_tf]++;
+
 				/*
 					Count the number of unique impacts
 				*/
@@ -366,6 +365,12 @@ std::ostream &operator<<(std::ostream &stream, const index_postings &data);
 //if (document_frequency < 10)
 //	std::cout << "ImpOrder:" << postings_list << "\n";
 #endif
+
+				/*
+					Leave the re-used buffer all zero for the next postings list (only the impacts that were used need clearing)
+				*/
+				if (document_frequency != 0)
+					std::fill(frequencies + lowest_impact, frequencies + highest_impact + 1, 0);
 				}
 
 			/*
@@ -422,6 +427,34 @@ std::ostream &operator<<(std::ostream &stream, const index_postings &data);
 
 				JASS_assert(strcmp(result.str().c_str(), "<1,2><2,1><173252,1>") == 0);
 
+				/*
+					Impact order two lists with the same re-used buffers, the first with an impact that needs a larger count buffer
+				*/
+				auto render = [](const index_postings_impact &list)
+					{
+					std::ostringstream output;
+					for (const auto &header : list)
+						{
+						output << static_cast<int>(header.impact_score) << ":";
+						for (const auto &document_id : header)
+							output << document_id << " ";
+						}
+					return output.str();
+					};
+
+				index_postings_impact impact_ordered(4, pool);
+				compress_integer::integer first_ids[] = {1, 2, 3};
+				index_postings_impact::impact_type first_impacts[] = {3000, 7, 3000};
+				postings.impact_order(4, impact_ordered, 3, first_ids, first_impacts);
+				JASS_assert(render(impact_ordered) == "7:2 3000:1 3 ");
+				JASS_assert(impact_ordered.frequencies_size == 4096);
+
+				compress_integer::integer second_ids[] = {1, 4};
+				index_postings_impact::impact_type second_impacts[] = {9, 7};
+				postings.impact_order(4, impact_ordered, 2, second_ids, second_impacts);
+				JASS_assert(render(impact_ordered) == "7:4 9:1 ");
+				JASS_assert(std::all_of(impact_ordered.frequencies, impact_ordered.frequencies + impact_ordered.frequencies_size, [](compress_integer::integer count){return count == 0;}));
+
 				puts("index_postings::PASSED");
 				}
 		};
@@ -12,10 +12,13 @@
 */
 #pragma once
 
+#include <string.h>
+
 #include <vector>
 #include <limits>
 #include <sstream>
 
+#include "maths.h"
 #include "allocator.h"
 #include "compress_integer.h"
 
@@ -42,6 +45,7 @@ namespace JASS
 			static constexpr size_t largest_impact = 1024;			///< The largest allowable immpact score (255 is an good value).
 			static constexpr size_t smallest_impact = 1;				///< The smallest allowable impact score (normally 1)
 #endif
+			static constexpr size_t largest_storable_impact = std::numeric_limits<impact_type>::max();		///< The largest impact that can be stored (the quantizer can be asked to use more than largest_impact).
 
 		public:
 			/*
@@ -185,13 +189,16 @@ namespace JASS
 		protected:
 			allocator &memory;							///< All allocation  happens in this arena.
 			size_t number_of_impacts;					///< The number of impact objects in the impacts array.
-			impact impacts[largest_impact + 1];		///< List of impact pointers (the impact header).
+			size_t impacts_size;							///< The number of elements in impacts (grown to hold the number of impacts in the postings list).
+			impact *impacts;								///< List of impact pointers (the impact header).
 			size_t number_of_postings;					///< The length of the postings array measured in size_t.
 			compress_integer::integer *postings;	///< The list of document IDs, strung together for each postings segment.
 			compress_integer::integer *document_ids;					///< The re-used buffer storing decoded document ids - used while impact ordering
 			index_postings_impact::impact_type *term_frequencies;	///< The re-used buffer storing the term frequencies - used while impact ordering
 			size_t temporary_size;											///< The number of bytes in temporary
 			uint8_t *temporary;												///< Temporary buffer - cannot be used to store anything between calls
+			size_t frequencies_size;										///< The number of elements in frequencies (grown to hold the largest impact of the quantization scheme)
+			compress_integer::integer *frequencies;					///< The re-used count of postings with each impact - used while impact ordering (and all zero between calls)
 
 		public:
 			/*
@@ -208,14 +215,40 @@ namespace JASS
 			index_postings_impact(size_t document_count, allocator &memory):
 				memory(memory),
 				number_of_impacts(0),
+				impacts_size(largest_impact + 1),
+				impacts(static_cast<decltype(impacts)>(memory.malloc(impacts_size * sizeof(*impacts)))),
 				number_of_postings(document_count),
-				postings(static_cast<decltype(postings)>(memory.malloc((document_count + largest_impact + 1) * sizeof(*postings)))),			// longest length is total_postings + all impacts + 1
+				postings(static_cast<decltype(postings)>(memory.malloc((document_count + impacts_size) * sizeof(*postings)))),			// longest length is total_postings + all impacts + 1
 				document_ids((decltype(document_ids))memory.malloc(document_count * sizeof(*document_ids))),
 				term_frequencies((decltype(term_frequencies))memory.malloc(document_count * sizeof(*term_frequencies))),
 				temporary_size(document_count * (sizeof(*document_ids) / 7 + 1) * sizeof(*temporary)),
-				temporary((decltype(temporary))memory.malloc(temporary_size))			// enough space to decompress variable-byte encodings
+				temporary((decltype(temporary))memory.malloc(temporary_size)),			// enough space to decompress variable-byte encodings
+				frequencies_size(largest_impact + 1),
+				frequencies((decltype(frequencies))memory.malloc(frequencies_size * sizeof(*frequencies)))
+				{
+				memset(frequencies, 0, frequencies_size * sizeof(*frequencies));
+				}
+
+			/*
+				INDEX_POSTINGS_IMPACT::GET_FREQUENCIES()
+				----------------------------------------
+			*/
+			/*!
+				@brief Return the re-used (all zero) buffer for counting the postings with each impact, large enough to hold highest_impact.
+				@details The buffer grows to the next power of two, which is the largest impact of the quantization scheme (+1) because the
+				scheme has a whole number of bits.  The caller must zero the counts it used before the next call.
+				@param highest_impact [in] The highest impact that will be counted.
+				@return The buffer of counts.
+			*/
+			compress_integer::integer *get_frequencies(impact_type highest_impact)
 				{
-				/* Nothing */
+				if (highest_impact >= frequencies_size)
+					{
+					frequencies_size = static_cast<size_t>(1) << (maths::floor_log2(static_cast<size_t>(highest_impact)) + 1);
+					frequencies = (decltype(frequencies))memory.malloc(frequencies_size * sizeof(*frequencies));
+					memset(frequencies, 0, frequencies_size * sizeof(*frequencies));
+					}
+				return frequencies;
 				}
 
 			/*
@@ -224,11 +257,20 @@ namespace JASS
 			*/
 			/*!
 				@brief Tell this object how many impacts it holds.
-				@details This method should only be called by a method that builds one of these objects.
+				@details This method should only be called by a method that builds one of these objects, and before it calls header().
+				There is room for the default number of impacts (largest_impact), and if there are more (the quantizer was asked
+				for wider impacts, see quantization_scheme) then the headers (and the postings) are re-allocated at the next power of two
+				(so their contents are lost).
 				@param number_of_impacts [in] The number of impact segments this object holds
 			*/
 			void set_impact_count(size_t number_of_impacts)
 				{
+				if (number_of_impacts >= impacts_size)
+					{
+					impacts_size = static_cast<size_t>(1) << (maths::floor_log2(number_of_impacts) + 1);
+					impacts = static_cast<decltype(impacts)>(memory.malloc(impacts_size * sizeof(*impacts)));
+					postings = static_cast<decltype(postings)>(memory.malloc((number_of_postings + impacts_size) * sizeof(*postings)));
+					}
 				this->number_of_impacts = number_of_impacts;
 				}
 
@@ -434,6 +476,15 @@ namespace JASS
 
 				JASS_assert(output.str() == serialised_answer);
 
+				/*
+					Check that the headers start with room for the default number of impacts, and grow when there are more
+				*/
+				JASS_assert(postings.impacts_size == largest_impact + 1);
+				postings.set_impact_count(3000);
+				JASS_assert(postings.impacts_size == 4096);
+				postings.header(2999, 3000, &postings[1], &postings[2]);
+				JASS_assert(postings.end() - postings.begin() == 3000);
+
 				puts("index_postings_impact::PASSED");
 				}
 		};
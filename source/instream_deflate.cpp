@@ -6,7 +6,11 @@
 */
 #include <string.h>
 
+#include <string>
+
 #include "assert.h"
+#include "asserts.h"
+#include "instream_memory.h"
 #include "instream_deflate.h"
 #include "compress_general_zlib.h"
 
@@ -68,6 +72,26 @@ namespace JASS
 
 		if (state == Z_STREAM_END)
 			{
+			/*
+				A gzip file can be several compressed members one after the other (WARC files are usually compressed one record
+				at a time) so if there is more data then reset and decompress the next member.
+			*/
+			if (stream.avail_in <= 0)
+				{
+				stream.avail_in = (uInt)source->fetch(buffer, buffer_length);
+				stream.next_in = buffer;
+				}
+			if (stream.avail_in > 0 && inflateReset(&stream) == Z_OK)
+				{
+				if (stream.avail_out == 0)
+					{
+					bytes_read += document.contents.size();
+					return;			// filled the output buffer and so return bytes read
+					}
+				state = Z_OK;
+				continue;
+				}
+
 			got = document.contents.size() - stream.avail_out;		// number of bytes that were decompressed
 			document.contents.resize(got);
 			bytes_read += got;
@@ -92,6 +116,49 @@ namespace JASS
 	*/
 	void instream_deflate::unittest(void)
 		{
+		/*
+			gzip a string into a single gzip member
+		*/
+		auto gzip = [](const std::string &plaintext)
+			{
+			z_stream deflater = {};
+			JASS_assert(deflateInit2(&deflater, Z_DEFAULT_COMPRESSION, Z_DEFLATED, 15 + 16, 8, Z_DEFAULT_STRATEGY) == Z_OK);		// + 16 for a gzip header
+			std::string compressed(deflateBound(&deflater, (uLong)plaintext.size()), '\0');
+			deflater.next_in = (Bytef *)plaintext.data();
+			deflater.avail_in = (uInt)plaintext.size();
+			deflater.next_out = (Bytef *)&compressed[0];
+			deflater.avail_out = (uInt)compressed.size();
+			JASS_assert(deflate(&deflater, Z_FINISH) == Z_STREAM_END);
+			compressed.resize(deflater.total_out);
+			deflateEnd(&deflater);
+			return compressed;
+			};
+
+		/*
+			A file of two gzip members one after the other (as a WARC file usually is) must decompress to both members
+		*/
+		std::string first = "WARC/1.0\r\nWARC-Type: response\r\n\r\n<html><title>first</title></html>\r\n\r\n";
+		std::string second = "WARC/1.0\r\nWARC-Type: response\r\n\r\n<html><title>second</title></html>\r\n\r\n";
+		std::string two_members = gzip(first) + gzip(second);
+
+		for (size_t block_size : {size_t(7), size_t(1024)})
+			{
+			std::shared_ptr<instream> source(new instream_memory(two_members.data(), two_members.size()));
+			instream_deflate reader(source);
+
+			document document;
+			std::string decompressed;
+			do
+				{
+				document.contents = slice(document.contents_allocator, block_size);
+				reader.read(document);
+				decompressed.append((char *)document.contents.address(), document.contents.size());
+				}
+			while (document.contents.size() == block_size);
+
+			JASS_assert(decompressed == first + second);
+			}
+
 		/*
 			Yay, we passed!
 		*/
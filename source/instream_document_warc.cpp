@@ -13,97 +13,149 @@
 namespace JASS
 	{
 	/*
-		INSTREAM_DOCUMENT_WARC::FIND_STRING()
-		-------------------------------------
+		INSTREAM_DOCUMENT_WARC::READ_LINE()
+		-----------------------------------
 	*/
-	const char *instream_document_warc::find_string(const std::string &string)
+	const char *instream_document_warc::read_line(void)
 		{
-		size_t string_length = string.size();
-
 		/*
-			Do a series of gets()'s until we find the given string
+			Read a '\n' terminated string from the source.
 		*/
-		buffer[string_length] = '\0';		// prevent early accidental termination (due to a ':' left over from a previous iteration).
 		const char *end = buffer.c_str() + buffer.size() - 1;
+		char *into = const_cast<char *>(buffer.c_str() - 1);
 		do
+			{
+			into++;
+			if (source->fetch(into, 1) != 1)
+				return nullptr;		// at EOF
+			}
+		while (*into != '\n' && into < end);
+
+		/*
+			In the case of buffer overflow we read to end of line and truncate.  This shouldn't happen very often,
+			but it does happen because there are some very long WARC-Target-URI in the TREC ClueWeb09 collection
+			in documents such as clueweb09-en0000-05-10880 which as a 1486 character WARC-Target-URI!
+		*/
+		if (into == end && *into != '\n')
+			{
+			char ch;
 			do
-				{
-				/*
-					Read a '\n' terminated string from the source.
-				*/
-				buffer[0] = '\0';
-				char *into = const_cast<char *>(buffer.c_str() - 1);
-				do
-					{
-					into++;
-					if (source->fetch(into, 1) != 1)
-						return nullptr;		// at EOF
-					}
-				while (*into != '\n' && into < end);
-				*into = '\0';
-
-				/*
-					In the case of buffer overflow we read to end of line and start over.  This shouldn't happen very often,
-					but it does happen because there are some very long WARC-Target-URI in the TREC ClueWeb09 collection
-					in documents such as clueweb09-en0000-05-10880 which as a 1486 character WARC-Target-URI!  It also won't
-					affect the string comparison because we're only comparing to the start of the string.
-				*/
-				if (into == end)
-					{
-					do
-						if (source->fetch(into, 1) != 1)
-							return nullptr;		// at EOF
-					while (*into != '\n');
-					continue;		// don't do the string compare because we're out of sync
-					}
-				}
-			while (buffer[string_length] != ':');			// check for the ':' in the right place
-		while (buffer.compare(0, string_length, string) != 0);	// now check for the TREC-ID
+				if (source->fetch(&ch, 1) != 1)
+					break;		// at EOF, but we still have a line to return
+			while (ch != '\n');
+			}
 
-		return buffer.c_str() + string_length + 1;
+		/*
+			Remove the line terminator (WARC files use "\r\n", but some tools write "\n")
+		*/
+		*into = '\0';
+		while (into > buffer.c_str() && *(into - 1) == '\r')
+			*--into = '\0';
+
+		return buffer.c_str();
+		}
+
+	/*
+		INSTREAM_DOCUMENT_WARC::SKIP()
+		------------------------------
+	*/
+	void instream_document_warc::skip(size_t bytes)
+		{
+		while (bytes > 0)
+			{
+			size_t got = source->fetch(&buffer[0], bytes < buffer.size() ? bytes : buffer.size());
+			if (got == 0)
+				return;			// at EOF
+			bytes -= got;
+			}
 		}
 
 	/*
 		INSTREAM_DOCUMENT_WARC::READ()
 		------------------------------
 	*/
+	static const std::string warc_version = "WARC/";						// initialise at program startup
+	static const std::string warc_type = "WARC-Type";						// initialise at program startup
 	static const std::string warc_trec_id = "WARC-TREC-ID";				// initialise at program startup
+	static const std::string warc_target_uri = "WARC-Target-URI";		// initialise at program startup
 	static const std::string content_length = "Content-Length"; 		// initialise at program startup
-	
+
 	void instream_document_warc::read(document &object)
 		{
-		const char *filename;
+		const char *line;
 
-		/*
-			Get and store the filename
-		*/
-		if ((filename = find_string(warc_trec_id)) == NULL)
-			object.primary_key = object.contents = slice();
-		else
+		while (1)
 			{
 			/*
-				Get the document primary key
+				Find the start of the next record
 			*/
-			while (ascii::isspace(*filename))
-				filename++;
-
-			object.primary_key = slice(object.primary_key_allocator, filename, filename + strlen(filename));
+			do
+				if ((line = read_line()) == nullptr)
+					{
+					object.primary_key = object.contents = slice();
+					return;			// at EOF
+					}
+			while (strncmp(line, warc_version.c_str(), warc_version.size()) != 0);
 
 			/*
-				Get and store the document length
+				Read the record header (which is terminated by a blank line) keeping the fields we need
 			*/
-			const char *file_length;
-			if ((file_length = find_string(content_length)) == NULL)
-				object.primary_key = object.contents = slice();
-			else
+			std::string trec_id;
+			std::string target_uri;
+			std::string type;
+			size_t length = 0;
+			while ((line = read_line()) != nullptr && *line != '\0')
 				{
-				size_t length = atoll(file_length);
-				char *document = reinterpret_cast<char *>(object.contents_allocator.malloc(length + 1));
+				const char *colon = strchr(line, ':');
+				if (colon == nullptr)
+					continue;
+
+				std::string field(line, colon);
+				const char *value = colon + 1;
+				while (ascii::isspace(*value))
+					value++;
+
+				const char *value_end = value + strlen(value);
+				while (value_end > value && ascii::isspace(*(value_end - 1)))
+					value_end--;
 
-				source->fetch(document, length);
-				document[length] = '\0';
-				object.contents = slice(document, document + length);
+				if (field == warc_trec_id)
+					trec_id.assign(value, value_end);
+				else if (field == warc_target_uri)
+					target_uri.assign(value, value_end);
+				else if (field == warc_type)
+					type.assign(value, value_end);
+				else if (field == content_length)
+					length = atoll(value);
 				}
+
+			if (line == nullptr)
+				{
+				object.primary_key = object.contents = slice();
+				return;			// at EOF
+				}
+
+			/*
+				Only response records are documents, and we can only index them if they have a primary key and some content.
+			*/
+			const std::string &key = trec_id.size() != 0 ? trec_id : target_uri;
+			if ((type != "" && type != "response") || key.size() == 0 || length == 0)
+				{
+				skip(length);
+				continue;
+				}
+
+			/*
+				Get the document primary key and the document itself
+			*/
+			object.primary_key = slice(object.primary_key_allocator, key.c_str(), key.c_str() + key.size());
+
+			char *document = reinterpret_cast<char *>(object.contents_allocator.malloc(length + 1));
+			length = source->fetch(document, length);
+			document[length] = '\0';
+			object.contents = slice(document, document + length);
+
+			return;
 			}
 		}
 
@@ -166,9 +218,9 @@ namespace JASS
 		/*
 			The correct documents
 		*/
-		const char *first_answer = "\nHTTP/1.1";
+		const char *first_answer = "HTTP/1.1\n";
 		const char *first_key = "clueweb12-0000tw-00-00013";
-		const char *second_answer = "\nHTTP/1.1 200 OK";
+		const char *second_answer = "HTTP/1.1 200 OK\n";
 		const char *second_key = "clueweb12-0000tw-00-00027";
 
 		/*
@@ -194,6 +246,43 @@ namespace JASS
 		getter.read(doc);
 		JASS_assert(doc.isempty());
 
+		/*
+			A CommonCrawl-like WARC file with "\r\n" line endings, request and metadata records, and no WARC-TREC-ID
+		*/
+		std::string crawl_file =
+			"WARC/1.0\r\n"
+			"WARC-Type: request\r\n"
+			"WARC-Target-URI: http://example.com/\r\n"
+			"Content-Length: 18\r\n"
+			"\r\n"
+			"GET / HTTP/1.1\r\n\r\n"
+			"\r\n\r\n"
+			"WARC/1.0\r\n"
+			"WARC-Type: response\r\n"
+			"WARC-Target-URI: http://example.com/\r\n"
+			"Content-Length: 21\r\n"
+			"\r\n"
+			"HTTP/1.1 200 OK\r\n\r\nhi"
+			"\r\n\r\n"
+			"WARC/1.0\r\n"
+			"WARC-Type: metadata\r\n"
+			"WARC-Target-URI: http://example.com/\r\n"
+			"Content-Length: 5\r\n"
+			"\r\n"
+			"x: y\n"
+			"\r\n\r\n";
+		std::shared_ptr<instream> crawl_source(new instream_memory(crawl_file.c_str(), crawl_file.size()));
+		instream_document_warc crawl_getter(crawl_source);
+
+		crawl_getter.read(doc);
+		JASS_assert(doc.primary_key.size() == strlen("http://example.com/"));
+		JASS_assert(strncmp(reinterpret_cast<char *>(doc.primary_key.address()), "http://example.com/", doc.primary_key.size()) == 0);
+		JASS_assert(doc.contents.size() == 21);
+		JASS_assert(strncmp(reinterpret_cast<char *>(doc.contents.address()), "HTTP/1.1 200 OK\r\n\r\nhi", doc.contents.size()) == 0);
+
+		crawl_getter.read(doc);
+		JASS_assert(doc.isempty());
+
 		/*
 			Success
 		*/
@@ -23,6 +23,9 @@ namespace JASS
 	*/
 	/*!
 		@brief Extract documents from a WARC archive
+		@details Only response records are returned as documents, all other records (warcinfo, request, metadata, etc.) are skipped.
+		The primary key is taken from the WARC-TREC-ID (as used in ClueWeb) if present, otherwise from the WARC-Target-URI (as used in CommonCrawl).
+		The document contents is the record's block (the HTTP response, headers and all).
 	*/
 	class instream_document_warc : public instream
 		{
@@ -34,15 +37,25 @@ namespace JASS
 			
 		private:
 			/*
-				INSTREAM_DOCUMENT_WARC::FIND_STRING()
-				-------------------------------------
+				INSTREAM_DOCUMENT_WARC::READ_LINE()
+				-----------------------------------
 			*/
 			/*!
-				@brief read lines from the WARC file until one starting with string is found.
-				@param string [in] the string to look for (at the start of a line)
-				@return a pointer to the remainder of the string
+				@brief read the next line from the WARC file, removing the trailing '\r' and '\n' characters.
+				@details Lines longer than WARC_BUFFER_SIZE are truncated (the remainder of the line is discarded).
+				@return a pointer to the '\0' terminated line (stored in buffer), or nullptr at EOF
 			*/
-			const char *find_string(const std::string &string);
+			const char *read_line(void);
+
+			/*
+				INSTREAM_DOCUMENT_WARC::SKIP()
+				------------------------------
+			*/
+			/*!
+				@brief read and discard bytes from the WARC file.
+				@param bytes [in] the number of bytes to discard
+			*/
+			void skip(size_t bytes);
 
 		public:
 			/*
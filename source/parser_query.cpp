@@ -5,11 +5,15 @@
 	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
 */
 #include <sstream>
+#include <charconv>
 #include <algorithm>
 
 #include "ascii.h"
 #include "unicode.h"
+#include "stop_words.h"
+#include "stem_porter.h"
 #include "parser_query.h"
+#include "index_manager.h"
 #include "allocator_memory.h"
 
 namespace JASS
@@ -18,18 +22,23 @@ namespace JASS
 		PARSER_QUERY::GET_NEXT_TOKEN()
 		------------------------------
 	*/
-	parser_query::token_status parser_query::get_next_token(slice &token)
+	parser_query::token_status parser_query::get_next_token(slice &token, double &weight)
 		{
 		size_t bytes;
 		uint32_t codepoint;
 		uint8_t *start_of_token = buffer_pos;
 
+		weight = 1;
+
 		/*
 			Skipping over all Unicode that isn't alpha-numeric.
 		*/
 		codepoint = unicode::utf8_to_codepoint(current, end_of_query, bytes);
 		while (!unicode::isalnum(codepoint))
 			{
+			token_status operation = get_operator(codepoint, bytes);
+			if (operation != valid_token)
+				return operation;
 			if (bytes == 0 || (current += bytes) >= end_of_query)
 				return eof_token;
 			codepoint = unicode::utf8_to_codepoint(current, end_of_query, bytes);
@@ -40,6 +49,8 @@ namespace JASS
 		*/
 		if (unicode::isalpha(codepoint))
 			{
+			uint8_t *start_of_word = current;
+
 			/*
 				while we have alphabetics, case fold into the token buffer.
 			*/
@@ -64,6 +75,56 @@ namespace JASS
 				codepoint = unicode::utf8_to_codepoint(current, end_of_query, bytes);
 				}
 			while (unicode::isalpha(codepoint));
+
+			/*
+				Upper case AND and OR are operators, not terms
+			*/
+			if (current - start_of_word == 3 && memcmp(start_of_word, "AND", 3) == 0)
+				{
+				buffer_pos = start_of_token;
+				return and_token;
+				}
+			if (current - start_of_word == 2 && memcmp(start_of_word, "OR", 2) == 0)
+				{
+				buffer_pos = start_of_token;
+				return or_token;
+				}
+
+			/*
+				A word followed by a colon then a term is a field restricted term (e.g. title:jaguar)
+			*/
+			if (current + 1 < end_of_query && *current == index_manager::FIELD_SEPARATOR)
+				{
+				size_t next_bytes;
+				uint32_t next = unicode::utf8_to_codepoint(current + 1, end_of_query, next_bytes);
+				if (unicode::isalnum(next))
+					{
+					*buffer_pos++ = index_manager::FIELD_SEPARATOR;
+					current++;
+					codepoint = next;
+					bytes = next_bytes;
+					bool alphabetic = unicode::isalpha(codepoint);
+
+					/*
+						The term is a sequence of alphas or of numerics, case folded into the token buffer
+					*/
+					do
+						{
+						for (const uint32_t *folded = unicode::tocasefold(codepoint); *folded != 0; folded++)
+							{
+							size_t rewrite_bytes = unicode::codepoint_to_utf8(buffer_pos, buffer_end, *folded);						// won't write on overflow
+							buffer_pos += rewrite_bytes;
+							if (rewrite_bytes == 0)
+								return bad_token;	 // LCOV_EXCL_LINE				// can't happen as the character must be a valid alphanumeric and there must be enough room to store it.
+							}
+
+						if ((current += bytes) >= end_of_query || bytes == 0)
+							break;
+						codepoint = unicode::utf8_to_codepoint(current, end_of_query, bytes);
+						}
+					while (alphabetic ? unicode::isalpha(codepoint) : unicode::isdigit(codepoint));
+					}
+				}
 			}
 		/*
 			Unicode Numeric
@@ -102,6 +163,7 @@ namespace JASS
 		*/
 		token = slice(start_of_token, buffer_pos - start_of_token);
 		*buffer_pos++ = '\0';
+		weight = get_weight();
 		return valid_token;
 		}
 
@@ -109,8 +171,10 @@ namespace JASS
 		PARSER_QUERY::GET_NEXT_TOKEN_RAW()
 		----------------------------------
 	*/
-	parser_query::token_status parser_query::get_next_token_raw(slice &token)
+	parser_query::token_status parser_query::get_next_token_raw(slice &token, double &weight)
 		{
+		weight = 1;
+
 		/*
 			Skip over whitespace
 		*/
@@ -131,6 +195,49 @@ namespace JASS
 			current++;
 		uint8_t *end_of_token = current;
 
+		/*
+			Check for operators, which must be whitespace seperated except for + and - which go before the term
+		*/
+		size_t length = end_of_token - start_of_token;
+		if (length == 1 && *start_of_token == '(')
+			return open_token;
+		if (length == 1 && *start_of_token == ')')
+			return close_token;
+		if (*start_of_token == '"')
+			{
+			current = start_of_token + 1;
+			return quote_token;
+			}
+		if (*(end_of_token - 1) == '"')
+			current = --end_of_token;			// the closing quote is returned on the next call
+		if (length == 3 && memcmp(start_of_token, "AND", 3) == 0)
+			return and_token;
+		if (length == 2 && memcmp(start_of_token, "OR", 2) == 0)
+			return or_token;
+		if (length > 1 && (*start_of_token == '+' || *start_of_token == '-'))
+			{
+			current = start_of_token + 1;
+			return *start_of_token == '+' ? must_token : must_not_token;
+			}
+
+		/*
+			Check for a weight (term^weight)
+		*/
+		for (uint8_t *caret = end_of_token - 1; caret > start_of_token; caret--)
+			if (*caret == '^')
+				{
+				current = caret;
+				weight = get_weight();
+				if (current == end_of_token)
+					end_of_token = caret;
+				else
+					{
+					current = end_of_token;
+					weight = 1;
+					}
+				break;
+				}
+
 		/*
 			'\0' terminate then write to the slice
 		*/
@@ -142,6 +249,375 @@ namespace JASS
 		return valid_token;
 		}
 
+	/*
+		PARSER_QUERY::GET_NEXT_TOKEN_JSON()
+		-----------------------------------
+	*/
+	parser_query::token_status parser_query::get_next_token_json(slice &token, double &weight)
+		{
+		/*
+			Find the start of the term
+		*/
+		while (current < end_of_query && *current != '"')
+			current++;
+		if (current >= end_of_query)
+			return eof_token;
+		current++;
+
+		/*
+			Copy the term into the token buffer, removing the escape character from escaped characters
+		*/
+		uint8_t *start_of_token = buffer_pos;
+		while (current < end_of_query && *current != '"')
+			{
+			if (*current == '\\' && current + 1 < end_of_query)
+				current++;
+			*buffer_pos++ = *current++;
+			}
+		token = slice(start_of_token, buffer_pos - start_of_token);
+		*buffer_pos++ = '\0';
+		if (current < end_of_query)
+			current++;
+
+		/*
+			The weight comes after the ':'
+		*/
+		while (current < end_of_query && *current != ':')
+			current++;
+		if (current < end_of_query)
+			current++;
+		while (current < end_of_query && ascii::isspace(*current))
+			current++;
+
+		auto [end_of_weight, error] = std::from_chars(reinterpret_cast<char *>(current), reinterpret_cast<char *>(end_of_query), weight);
+		if (error != std::errc())
+			return bad_token;
+		current = reinterpret_cast<uint8_t *>(const_cast<char *>(end_of_weight));
+
+		return token.size() != 0 && weight > 0 ? valid_token : bad_token;
+		}
+
+	/*
+		PARSER_QUERY::GET_WEIGHT()
+		--------------------------
+	*/
+	double parser_query::get_weight(void)
+		{
+		if (current + 1 >= end_of_query || *current != '^' || !(ascii::isdigit(current[1]) || current[1] == '.'))
+			return 1;
+
+		double weight;
+		auto [end_of_weight, error] = std::from_chars(reinterpret_cast<char *>(current) + 1, reinterpret_cast<char *>(end_of_query), weight, std::chars_format::fixed);
+		if (error != std::errc())
+			return 1;
+
+		current = reinterpret_cast<uint8_t *>(const_cast<char *>(end_of_weight));
+		return weight;
+		}
+
+	/*
+		PARSER_QUERY::GET_OPERATOR()
+		----------------------------
+	*/
+	parser_query::token_status parser_query::get_operator(uint32_t codepoint, size_t bytes)
+		{
+		if (codepoint == '(')
+			{
+			current += bytes;
+			return open_token;
+			}
+		else if (codepoint == ')')
+			{
+			current += bytes;
+			return close_token;
+			}
+		else if (codepoint == '"')
+			{
+			current += bytes;
+			return quote_token;
+			}
+		else if (codepoint == '+' || codepoint == '-')
+			{
+			/*
+				+ and - are only operators at the start of a word and when followed by a term or bracket.
+			*/
+			bool at_start_of_word = current == start_of_query || ascii::isspace(current[-1]) || current[-1] == '(';
+			if (!at_start_of_word || current + bytes >= end_of_query)
+				return valid_token;
+
+			size_t next_bytes;
+			uint32_t next = unicode::utf8_to_codepoint(current + bytes, end_of_query, next_bytes);
+			if (!unicode::isalnum(next) && next != '(')
+				return valid_token;
+
+			current += bytes;
+			return codepoint == '+' ? must_token : must_not_token;
+			}
+
+		return valid_token;
+		}
+
+	/*
+		PARSER_QUERY::EXTRACT_PHRASES()
+		-------------------------------
+	*/
+	void parser_query::extract_phrases(query_term_list &parsed_query)
+		{
+		std::vector<slice> phrase;
+		bool in_phrase = false;
+
+		for (const auto &token : tokens)
+			if (token.type == quote_token)
+				{
+				if (in_phrase && phrase.size() > 1)
+					parsed_query.add_phrase(phrase);
+				phrase.clear();
+				in_phrase = !in_phrase;
+				}
+			else if (in_phrase && token.type == valid_token)
+				phrase.push_back(token.term);
+
+		if (in_phrase && phrase.size() > 1)
+			parsed_query.add_phrase(phrase);
+
+		/*
+			The quotes play no further part in the query
+		*/
+		tokens.erase(std::remove_if(tokens.begin(), tokens.end(), [](const auto &token){ return token.type == quote_token; }), tokens.end());
+		}
+
+	/*
+		PARSER_QUERY::COMBINE()
+		-----------------------
+	*/
+	void parser_query::combine(expression &into, const expression &with, query_term_list::boolean_operator operation)
+		{
+		if (with.empty())
+			return;
+		if (into.empty())
+			into = with;
+		else
+			{
+			into.insert(into.end(), with.begin(), with.end());
+			into.push_back({operation, slice(), 0});
+			}
+		}
+
+	/*
+		PARSER_QUERY::PARSE_UNARY()
+		---------------------------
+	*/
+	parser_query::expression parser_query::parse_unary(query_term_list &parsed_query, bool negated, size_t depth, token_status &modifier)
+		{
+		token_status ignore;
+
+		modifier = valid_token;
+		if (next_token >= tokens.size())
+			return expression();
+
+		const auto &[type, term, weight] = tokens[next_token];
+		switch (type)
+			{
+			case valid_token:
+				next_token++;
+				parsed_query.push_back(term, negated, weight);
+				return expression(1, {query_term_list::boolean_term, term, 0});
+			case must_token:
+				{
+				next_token++;
+				modifier = must_token;
+				return parse_unary(parsed_query, negated, depth, ignore);
+				}
+			case must_not_token:
+				{
+				next_token++;
+				modifier = must_not_token;
+				expression result = parse_unary(parsed_query, !negated, depth, ignore);
+				if (!result.empty())
+					result.push_back({query_term_list::boolean_not, slice(), 0});
+				return result;
+				}
+			case open_token:
+				next_token++;
+				return parse_sequence(parsed_query, negated, depth + 1);
+			default:
+				return expression();		// an operator or close bracket where an operand should be, so there is no operand
+			}
+		}
+
+	/*
+		PARSER_QUERY::PARSE_AND()
+		-------------------------
+	*/
+	parser_query::expression parser_query::parse_and(query_term_list &parsed_query, bool negated, size_t depth, token_status &modifier)
+		{
+		token_status ignore;
+
+		expression result = parse_unary(parsed_query, negated, depth, modifier);
+		while (next_token < tokens.size() && tokens[next_token].type == and_token)
+			{
+			next_token++;
+			combine(result, parse_unary(parsed_query, negated, depth, ignore), query_term_list::boolean_and);
+			modifier = valid_token;
+			}
+
+		return result;
+		}
+
+	/*
+		PARSER_QUERY::PARSE_OR()
+		------------------------
+	*/
+	parser_query::expression parser_query::parse_or(query_term_list &parsed_query, bool negated, size_t depth, token_status &modifier)
+		{
+		token_status ignore;
+
+		expression result = parse_and(parsed_query, negated, depth, modifier);
+		while (next_token < tokens.size() && tokens[next_token].type == or_token)
+			{
+			next_token++;
+			combine(result, parse_and(parsed_query, negated, depth, ignore), query_term_list::boolean_or);
+			modifier = valid_token;
+			}
+
+		return result;
+		}
+
+	/*
+		PARSER_QUERY::PARSE_SEQUENCE()
+		------------------------------
+	*/
+	parser_query::expression parser_query::parse_sequence(query_term_list &parsed_query, bool negated, size_t depth)
+		{
+		expression required;
+		expression optional;
+		expression prohibited;
+
+		while (next_token < tokens.size())
+			{
+			token_status type = tokens[next_token].type;
+			if (type == close_token)
+				{
+				next_token++;
+				if (depth > 0)
+					break;
+				continue;			// unbalanced close bracket so ignore it
+				}
+			if (type == and_token || type == or_token)
+				{
+				next_token++;
+				continue;			// an operator without a left hand operand so ignore it
+				}
+
+			token_status modifier;
+			expression clause = parse_or(parsed_query, negated, depth, modifier);
+			if (modifier == must_token)
+				combine(required, clause, query_term_list::boolean_and);
+			else if (modifier == must_not_token)
+				combine(prohibited, clause, query_term_list::boolean_and);
+			else
+				combine(optional, clause, query_term_list::boolean_or);
+			}
+
+		/*
+			If there are required clauses then the optional clauses are used for scoring only
+		*/
+		expression result = required.empty() ? optional : required;
+		combine(result, prohibited, query_term_list::boolean_and);
+
+		return result;
+		}
+
+	/*
+		PARSER_QUERY::BUILD_QUERY()
+		---------------------------
+	*/
+	void parser_query::build_query(query_term_list &parsed_query)
+		{
+		extract_phrases(parsed_query);
+
+		/*
+			If there are no operators then the query is a bag of words
+		*/
+		if (std::all_of(tokens.begin(), tokens.end(), [](const auto &token){ return token.type == valid_token; }))
+			{
+			for (const auto &token : tokens)
+				parsed_query.push_back(token.term, false, token.weight);
+			return;
+			}
+
+		/*
+			Otherwise build the query tree
+		*/
+		next_token = 0;
+		parsed_query.set_boolean_expression(parse_sequence(parsed_query, false, 0));
+		}
+
+	/*
+		PARSER_QUERY::STEM_TOKEN()
+		--------------------------
+	*/
+	void parser_query::stem_token(slice &token, stem &stemmer)
+		{
+		/*
+			The indexer only stems alphabetic tokens that are longer than 2 bytes, so we must do the same.
+		*/
+		if (token.size() <= 2)
+			return;
+
+		/*
+			The field of a field restricted term (title:jaguar) is not stemmed, the term is
+		*/
+		uint8_t *start_of_field = reinterpret_cast<uint8_t *>(token.address());
+		uint8_t *separator = std::find(start_of_field, start_of_field + token.size(), index_manager::FIELD_SEPARATOR);
+		if (separator != start_of_field + token.size())
+			{
+			slice term(separator + 1, token.size() - (separator + 1 - start_of_field));
+			stem_token(term, stemmer);
+			token = slice(start_of_field, (separator + 1 - start_of_field) + term.size());
+			return;
+			}
+
+		size_t bytes;
+		uint8_t *start_of_token = reinterpret_cast<uint8_t *>(token.address());
+		if (!unicode::isalpha(unicode::utf8_to_codepoint(start_of_token, start_of_token + token.size(), bytes)))
+			return;
+
+		/*
+			The stem is never longer than the term and the term is '\0' terminated in our buffer, so stem in-place.
+		*/
+		size_t length = stemmer.tostem(reinterpret_cast<char *>(start_of_token), reinterpret_cast<char *>(start_of_token), token.size());
+		token = slice(start_of_token, length);
+		}
+
+	/*
+		PARSER_QUERY::PUSH_TERM()
+		-------------------------
+	*/
+	void parser_query::push_term(slice term, double weight, stem *stemmer, const stop_words *stopper)
+		{
+		if (stopper != nullptr)
+			{
+			/*
+				The field of a field restricted term (title:the) is not checked, the term is
+			*/
+			uint8_t *start_of_term = reinterpret_cast<uint8_t *>(term.address());
+			uint8_t *separator = std::find(start_of_term, start_of_term + term.size(), index_manager::FIELD_SEPARATOR);
+			slice word = separator == start_of_term + term.size() ? term : slice(separator + 1, term.size() - (separator + 1 - start_of_term));
+
+			if (stopper->is_stop_word(word))
+				{
+				if (!tokens.empty() && (tokens.back().type == must_token || tokens.back().type == must_not_token))
+					tokens.pop_back();
+				return;
+				}
+			}
+
+		if (stemmer != nullptr)
+			stem_token(term, *stemmer);
+		tokens.push_back({valid_token, term, weight});
+		}
+
 	/*
 		PARSER_QUERY::UNITTEST_TEST_ONE()
 		---------------------------------
@@ -230,6 +706,150 @@ namespace JASS
 			raw_answer << term;
 		JASS_assert(raw_answer.str() == "(.,1)(;,1)(A,1)");
 
+		/*
+			Test stemming with both parsers (numbers and short terms must not be stemmed)
+		*/
+		stem_porter porter;
+		std::ostringstream stemmed_answer;
+		query_term_list *stemmed_tokens = new query_term_list;
+		parser->parse(*stemmed_tokens, std::string("Running CATS 1980s as"), parser_type::query, &porter);
+		for (const auto &term : *stemmed_tokens)
+			stemmed_answer << term;
+		JASS_assert(stemmed_answer.str() == "(s,1)(as,1)(cat,1)(run,1)(1980,1)");
+		delete stemmed_tokens;
+
+		std::ostringstream stemmed_raw_answer;
+		stemmed_tokens = new query_term_list;
+		parser->parse(*stemmed_tokens, std::string("ponies ponies 42s"), parser_type::raw, &porter);
+		for (const auto &term : *stemmed_tokens)
+			stemmed_raw_answer << term;
+		JASS_assert(stemmed_raw_answer.str() == "(42s,1)(poni,2)");
+		delete stemmed_tokens;
+
+		/*
+			Test the boolean operators (the terms are sorted by length then alphabetically so their bits are in that order)
+		*/
+		query_term_list *boolean_tokens = new query_term_list;
+		parser->parse(*boolean_tokens, std::string("Apple +Banana -cherry"));
+		JASS_assert(boolean_tokens->is_boolean());
+		JASS_assert(!boolean_tokens->begin()[0].is_prohibited() && !boolean_tokens->begin()[1].is_prohibited() && boolean_tokens->begin()[2].is_prohibited());
+		JASS_assert(boolean_tokens->matches(0b010) && boolean_tokens->matches(0b011));
+		JASS_assert(!boolean_tokens->matches(0b001) && !boolean_tokens->matches(0b110));
+		delete boolean_tokens;
+
+		boolean_tokens = new query_term_list;
+		parser->parse(*boolean_tokens, std::string("(a OR b) AND c"));
+		JASS_assert(boolean_tokens->matches(0b101) && boolean_tokens->matches(0b110));
+		JASS_assert(!boolean_tokens->matches(0b100) && !boolean_tokens->matches(0b011));
+		delete boolean_tokens;
+
+		boolean_tokens = new query_term_list;
+		parser->parse(*boolean_tokens, std::string("a OR b AND c"));
+		JASS_assert(boolean_tokens->matches(0b001) && boolean_tokens->matches(0b110));
+		JASS_assert(!boolean_tokens->matches(0b010) && !boolean_tokens->matches(0b100));
+		delete boolean_tokens;
+
+		boolean_tokens = new query_term_list;
+		parser->parse(*boolean_tokens, std::string("+a -(b c)"));
+		JASS_assert(boolean_tokens->begin()[1].is_prohibited() && boolean_tokens->begin()[2].is_prohibited());
+		JASS_assert(boolean_tokens->matches(0b001) && !boolean_tokens->matches(0b011) && !boolean_tokens->matches(0b101));
+		delete boolean_tokens;
+
+		boolean_tokens = new query_term_list;
+		parser->parse(*boolean_tokens, std::string("+a -b"), parser_type::raw);
+		JASS_assert(boolean_tokens->matches(0b01) && !boolean_tokens->matches(0b11) && !boolean_tokens->matches(0b10));
+		delete boolean_tokens;
+
+		/*
+			Hyphenated words and lower case and/or are not operators
+		*/
+		got = unittest_test_one(parser, memory, "covid-19 and hope or - dread");
+		JASS_assert(got == "(19,1)(or,1)(and,1)(hope,1)(covid,1)(dread,1)");
+		boolean_tokens = new query_term_list;
+		parser->parse(*boolean_tokens, std::string("covid-19 and hope or - dread"));
+		JASS_assert(!boolean_tokens->is_boolean());
+		delete boolean_tokens;
+
+		/*
+			Test weighted terms with each parser
+		*/
+		query_term_list *weighted_tokens = new query_term_list;
+		parser->parse(*weighted_tokens, std::string("Apple^12 pear^0.5 Apple ^3 x^"));
+		JASS_assert(weighted_tokens->size() == 4);
+		JASS_assert(weighted_tokens->begin()[0].token() == "3" && weighted_tokens->begin()[0].weight() == 1);
+		JASS_assert(weighted_tokens->begin()[1].token() == "x" && weighted_tokens->begin()[1].weight() == 1);
+		JASS_assert(weighted_tokens->begin()[2].token() == "pear" && weighted_tokens->begin()[2].weight() == 0.5);
+		JASS_assert(weighted_tokens->begin()[3].token() == "apple" && weighted_tokens->begin()[3].weight() == 13 && weighted_tokens->begin()[3].frequency() == 2);
+		delete weighted_tokens;
+
+		weighted_tokens = new query_term_list;
+		parser->parse(*weighted_tokens, std::string("a^b^2 c^x d^.25"), parser_type::raw);
+		JASS_assert(weighted_tokens->size() == 3);
+		JASS_assert(weighted_tokens->begin()[0].token() == "d" && weighted_tokens->begin()[0].weight() == 0.25);
+		JASS_assert(weighted_tokens->begin()[1].token() == "a^b" && weighted_tokens->begin()[1].weight() == 2);
+		JASS_assert(weighted_tokens->begin()[2].token() == "c^x" && weighted_tokens->begin()[2].weight() == 1);
+		delete weighted_tokens;
+
+		weighted_tokens = new query_term_list;
+		parser->parse(*weighted_tokens, std::string("{\"covid\": 1.5, \"vac\\\"cine\":3, \"zero\": 0, \"AND\": 2e1}"), parser_type::json);
+		JASS_assert(weighted_tokens->size() == 3 && !weighted_tokens->is_boolean());
+		JASS_assert(weighted_tokens->begin()[0].token() == "AND" && weighted_tokens->begin()[0].weight() == 20);
+		JASS_assert(weighted_tokens->begin()[1].token() == "covid" && weighted_tokens->begin()[1].weight() == 1.5);
+		JASS_assert(weighted_tokens->begin()[2].token() == "vac\"cine" && weighted_tokens->begin()[2].weight() == 3);
+		delete weighted_tokens;
+
+		/*
+			Test phrases with the query and raw parsers (the terms of a phrase are also terms of the query)
+		*/
+		query_term_list *phrase_tokens = new query_term_list;
+		parser->parse(*phrase_tokens, std::string("\"New York\" pizza \"x\" \"hot dog"));
+		JASS_assert(phrase_tokens->size() == 6 && !phrase_tokens->is_boolean());
+		JASS_assert(phrase_tokens->get_phrases().size() == 2);
+		JASS_assert(phrase_tokens->get_phrases()[0].size() == 2 && phrase_tokens->get_phrases()[0][0] == "new" && phrase_tokens->get_phrases()[0][1] == "york");
+		JASS_assert(phrase_tokens->get_phrases()[1].size() == 2 && phrase_tokens->get_phrases()[1][0] == "hot" && phrase_tokens->get_phrases()[1][1] == "dog");
+		delete phrase_tokens;
+
+		phrase_tokens = new query_term_list;
+		parser->parse(*phrase_tokens, std::string("pizza \"new york \" \"one\""), parser_type::raw);
+		JASS_assert(phrase_tokens->size() == 4);
+		JASS_assert(phrase_tokens->get_phrases().size() == 1);
+		JASS_assert(phrase_tokens->get_phrases()[0].size() == 2 && phrase_tokens->get_phrases()[0][0] == "new" && phrase_tokens->get_phrases()[0][1] == "york");
+		delete phrase_tokens;
+
+		/*
+			Test field restricted terms (the field is not stemmed, the term is)
+		*/
+		got = unittest_test_one(parser, memory, "Title:Jaguar body: cars title:2021 :x");
+		JASS_assert(got == "(x,1)(body,1)(cars,1)(title:2021,1)(title:jaguar,1)");
+		query_term_list *field_tokens = new query_term_list;
+		parser->parse(*field_tokens, std::string("title:running"), parser_type::query, &porter);
+		JASS_assert(field_tokens->size() == 1 && field_tokens->begin()[0].token() == "title:run");
+		delete field_tokens;
+
+		/*
+			Test stopping (before stemming), a stopped term takes its + or - operator with it
+		*/
+		stop_words stopper;
+		stopper.add_english();
+		query_term_list *stopped_tokens = new query_term_list;
+		parser->parse(*stopped_tokens, std::string("The Running of the title:the bulls"), parser_type::query, &porter, &stopper);
+		std::ostringstream stopped_answer;
+		for (const auto &term : *stopped_tokens)
+			stopped_answer << term;
+		JASS_assert(stopped_answer.str() == "(run,1)(bull,1)");
+		delete stopped_tokens;
+
+		stopped_tokens = new query_term_list;
+		parser->parse(*stopped_tokens, std::string("+the cats -a dogs"), parser_type::query, nullptr, &stopper);
+		JASS_assert(stopped_tokens->size() == 2 && !stopped_tokens->is_boolean());
+		delete stopped_tokens;
+
+		stopped_tokens = new query_term_list;
+		parser->parse(*stopped_tokens, std::string("+cats -the -dogs"), parser_type::query, nullptr, &stopper);
+		JASS_assert(stopped_tokens->size() == 2 && stopped_tokens->is_boolean());
+		JASS_assert(stopped_tokens->matches(0b01) && !stopped_tokens->matches(0b11) && !stopped_tokens->matches(0b10));
+		delete stopped_tokens;
+
 		delete parser;
 
 		puts("parser_query::PASSED");
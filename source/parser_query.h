@@ -7,19 +7,26 @@
 /*!
 	@file
 	@brief Simple parser for queries
-	@details This parser is a bare-bones parser that generates a list of terms in the query
+	@details This parser is a bare-bones parser that generates a list of terms in the query.  If the query uses any of the boolean
+	operators (+term, -term, AND, OR, and brackets) then it also generates a query tree that documents must satisfy.
 	@author Andrew Trotman
 	@copyright 2017 Andrew Trotman
 */
 
 #pragma once
 
+#include <vector>
+#include <utility>
+
 #include "allocator.h"
 #include "dynamic_array.h"
 #include "query_term_list.h"
 
 namespace JASS
 	{
+	class stem;
+	class stop_words;
+
 	/*
 		CLASS PARSER_QUERY
 		------------------
@@ -38,7 +45,31 @@ namespace JASS
 				{
 				eof_token,								///< At the end of porcessing tokens so not a valid token.
 				bad_token,								///< The token is a bad token (for example, it might have an invalid UTF8 character in it).
-				valid_token								///< The token is a valid token
+				valid_token,							///< The token is a valid token
+				must_token,								///< The + operator (the next term must be in the document).
+				must_not_token,						///< The - operator (the next term must not be in the document).
+				and_token,								///< The AND operator.
+				or_token,								///< The OR operator.
+				open_token,								///< An open bracket.
+				close_token,							///< A close bracket.
+				quote_token								///< A double quote (the start or end of a phrase).
+				};
+
+			typedef std::vector<query_term_list::boolean_node> expression;		///< A (sub-)expression of a boolean query, in postfix order.
+
+			/*
+				CLASS PARSER_QUERY::TOKEN
+				-------------------------
+			*/
+			/*!
+				@brief A token (term or operator) from the query.
+			*/
+			class token
+				{
+				public:
+					token_status type;					///< The type of token (valid_token for a term).
+					slice term;								///< If a term then the term.
+					double weight;							///< If a term then its weight (see query_term::weight()).
 				};
 
 		public:
@@ -49,16 +80,20 @@ namespace JASS
 			enum parser_type
 				{
 				query,									///< Unicode parsing with case folding and alphanumeric seperation
-				raw										///< ASCII parsing asuming only whitespace seperates tokens
+				raw,										///< ASCII parsing asuming only whitespace seperates tokens
+				json										///< A JSON object of term and weight pairs such as {"covid": 1.5, "vaccine": 0.75} (as generated by learned sparse query encoders)
 				};
 
 
 		private:
 			allocator &memory;						///< All memory associated with the query.
+			uint8_t *start_of_query;				///< The start of the input query string.
 			uint8_t *current;							///< Currtne locaton (in the input query string) of the parser during parsing.
 			uint8_t *end_of_query;					///< Pointer to the end of the inoput query string.
 			uint8_t *buffer_pos;						///< Loction where the next token will be written during tokenization and normaloisation.
 			uint8_t *buffer_end;						///< End of the normalised token buffer.
+			std::vector<token> tokens;				///< The tokens (terms and operators) of the query.
+			size_t next_token;						///< During building of the query tree, the next token to examine.
 
 		private:
 			/*
@@ -68,8 +103,9 @@ namespace JASS
 			/*!
 				@brief Return the next parsed token from the source query, this parser does case folding and sp on
 				@param token [in] a slice of the token.
+				@param weight [out] the weight of the token (1 unless given as token^weight).
 			*/
-			token_status get_next_token(slice &token);
+			token_status get_next_token(slice &token, double &weight);
 
 			/*
 				PARSER_QUERY::GET_NEXT_TOKEN_RAW()
@@ -78,8 +114,159 @@ namespace JASS
 			/*!
 				@brief Return the next parsed token from the source query, this parser assumes the tokens are already normalised (case folded, puncutation removed, and so on).  Seperators are whitespace.
 				@param token [in] a slice of the token.
+				@param weight [out] the weight of the token (1 unless given as token^weight).
 			*/
-			token_status get_next_token_raw(slice &token);
+			token_status get_next_token_raw(slice &token, double &weight);
+
+			/*
+				PARSER_QUERY::GET_NEXT_TOKEN_JSON()
+				-----------------------------------
+			*/
+			/*!
+				@brief Return the next token from a JSON object of "term": weight pairs.  The terms are assumed to be already normalised.  Terms with a weight of 0 (or less) are skipped.
+				@param token [in] a slice of the token.
+				@param weight [out] the weight of the token.
+			*/
+			token_status get_next_token_json(slice &token, double &weight);
+
+			/*
+				PARSER_QUERY::GET_WEIGHT()
+				--------------------------
+			*/
+			/*!
+				@brief If the query is at a ^weight (for example, ^12 or ^0.5) then move past it and return the weight, else return 1.
+				@return The weight.
+			*/
+			double get_weight(void);
+
+			/*
+				PARSER_QUERY::GET_OPERATOR()
+				----------------------------
+			*/
+			/*!
+				@brief If the query is at a boolean operator then return it (and move past it).
+				@details The + and - operators must be at the start of a word (so "covid-19" is not "covid NOT 19") and AND and OR must be upper case.
+				@param codepoint [in] The codepoint at the current location in the query.
+				@param bytes [in] The length (in bytes) of codepoint.
+				@return The operator or valid_token if not at an operator.
+			*/
+			token_status get_operator(uint32_t codepoint, size_t bytes);
+
+			/*
+				PARSER_QUERY::BUILD_QUERY()
+				---------------------------
+			*/
+			/*!
+				@brief Turn the tokens into the query (and, if there are any boolean operators, the query tree).
+				@param parsed_query [out] The parsed query.
+			*/
+			void build_query(query_term_list &parsed_query);
+
+			/*
+				PARSER_QUERY::EXTRACT_PHRASES()
+				-------------------------------
+			*/
+			/*!
+				@brief Find the phrases (terms between double quotes) in the tokens, add them to the query, and remove the quotes from the tokens.
+				@details The terms of a phrase remain in the query as ordinary terms.  An unterminated phrase runs to the end of the query, and a
+				"phrase" of fewer than two terms is just a term.
+				@param parsed_query [out] The query to add the phrases to.
+			*/
+			void extract_phrases(query_term_list &parsed_query);
+
+			/*
+				PARSER_QUERY::COMBINE()
+				-----------------------
+			*/
+			/*!
+				@brief Combine two sub-expressions with an operator (an empty sub-expression is ignored).
+				@param into [in, out] The left hand operand, and the result.
+				@param with [in] The right hand operand.
+				@param operation [in] The operator.
+			*/
+			static void combine(expression &into, const expression &with, query_term_list::boolean_operator operation);
+
+			/*
+				PARSER_QUERY::PARSE_SEQUENCE()
+				------------------------------
+			*/
+			/*!
+				@brief Parse a sequence of clauses (each possibly with a + or - before it) up to the matching close bracket (or end of query).
+				@details If any clause is required (+) then the optional clauses do not affect which documents match, otherwise at least one optional clause must match.
+				No prohibited (-) clause may match.
+				@param parsed_query [out] The parsed query (the terms are added to this).
+				@param negated [in] Is this sequence inside a negation?
+				@param depth [in] The bracket depth.
+				@return The sequence as a postfix expression.
+			*/
+			expression parse_sequence(query_term_list &parsed_query, bool negated, size_t depth);
+
+			/*
+				PARSER_QUERY::PARSE_OR()
+				------------------------
+			*/
+			/*!
+				@brief Parse a list of operands seperated by OR.
+				@param parsed_query [out] The parsed query (the terms are added to this).
+				@param negated [in] Is this expression inside a negation?
+				@param depth [in] The bracket depth.
+				@param modifier [out] If there is only one operand then its modifier (must_token or must_not_token), else valid_token.
+				@return The postfix expression.
+			*/
+			expression parse_or(query_term_list &parsed_query, bool negated, size_t depth, token_status &modifier);
+
+			/*
+				PARSER_QUERY::PARSE_AND()
+				-------------------------
+			*/
+			/*!
+				@brief Parse a list of operands seperated by AND.
+				@param parsed_query [out] The parsed query (the terms are added to this).
+				@param negated [in] Is this expression inside a negation?
+				@param depth [in] The bracket depth.
+				@param modifier [out] If there is only one operand then its modifier (must_token or must_not_token), else valid_token.
+				@return The postfix expression.
+			*/
+			expression parse_and(query_term_list &parsed_query, bool negated, size_t depth, token_status &modifier);
+
+			/*
+				PARSER_QUERY::PARSE_UNARY()
+				---------------------------
+			*/
+			/*!
+				@brief Parse a term or bracketed sub-expression, possibly preceded by + or -.
+				@param parsed_query [out] The parsed query (the terms are added to this).
+				@param negated [in] Is this operand inside a negation?
+				@param depth [in] The bracket depth.
+				@param modifier [out] must_token or must_not_token if the operand is preceded by + or -, else valid_token.
+				@return The postfix expression.
+			*/
+			expression parse_unary(query_term_list &parsed_query, bool negated, size_t depth, token_status &modifier);
+
+			/*
+				PARSER_QUERY::STEM_TOKEN()
+				--------------------------
+			*/
+			/*!
+				@brief Stem the token in-place, but only if the indexer would have stemmed it (alphabetic tokens longer than 2 bytes).
+				@param token [in, out] a slice of the token, which is changed to be the stem.
+				@param stemmer [in] the stemmer to use.
+			*/
+			void stem_token(slice &token, stem &stemmer);
+
+			/*
+				PARSER_QUERY::PUSH_TERM()
+				-------------------------
+			*/
+			/*!
+				@brief Add a term to the end of the list of tokens, stemming it first if needed.
+				@details Stop words are not added, and nor is any + or - operator immediately before them (as the operator would otherwise apply to the next term).
+				@param term [in] The term.
+				@param weight [in] The weight of the term.
+				@param stemmer [in] If not nullptr then the term is stemmed with this stemmer.
+				@param stopper [in] If not nullptr then the term is dropped if it is in this list of stop words.
+			*/
+			void push_term(slice term, double weight, stem *stemmer, const stop_words *stopper);
 
 			/*
 				PARSER_QUERY::UNITTEST_TEST_ONE()
@@ -102,10 +289,12 @@ namespace JASS
 			*/
 			parser_query(allocator &memory) :
 				memory(memory),
+                start_of_query(nullptr),
                 current(nullptr),
                 end_of_query(nullptr),
                 buffer_pos(nullptr),
-                buffer_end(nullptr)
+                buffer_end(nullptr),
+                next_token(0)
 				{
 				/* Nothing */
 				}
@@ -115,15 +304,18 @@ namespace JASS
 				---------------------
 			*/
 			/*!
-				@brief parse and return the list of query tokens.
+				@brief parse and return the list of query tokens (and the query tree if the query uses boolean operators).
 				@tparam STRING_TYPE either a std::string or JASS::string (or other string type)
 				@param parsed_query [out] The parsed query once parsed.
 				@param query [in] The query to be parsed.
+				@param which_parser [in] Which parser to use (see parser_type).
+				@param stemmer [in] If not nullptr then each term is stemmed with this stemmer before being added to the query.
+				@param stopper [in] If not nullptr then terms in this list of stop words are dropped from the query (before stemming).
 			*/
 			template <typename STRING_TYPE>
-			void parse(query_term_list &parsed_query, const STRING_TYPE &query, parser_type which_parser = parser_type::query)
+			void parse(query_term_list &parsed_query, const STRING_TYPE &query, parser_type which_parser = parser_type::query, stem *stemmer = nullptr, const stop_words *stopper = nullptr)
 				{
-				current = (uint8_t *)(const_cast<char *>(query.c_str()));							// get a pointer to the start of the query string
+				start_of_query = current = (uint8_t *)(const_cast<char *>(query.c_str()));							// get a pointer to the start of the query string
 				end_of_query = current + query.size();			// get a pointer to the end of the query string
 
 				/*
@@ -136,26 +328,39 @@ namespace JASS
 				buffer_end = buffer_pos + worse_case_normalised_query_length;
 
 				/*
-					Parse the query to get all of the search terms
+					Parse the query to get all of the search terms (and operators)
 				*/
 				slice term;												// Each term as returned by the parser.
+				double weight;											// The weight of each term.
 				token_status status;
+				tokens.clear();
 				if (which_parser == parser_type::query)
 					{
-					while ((status = get_next_token(term)) != eof_token)		// get the next token
+					while ((status = get_next_token(term, weight)) != eof_token)		// get the next token
+						if (status == valid_token)
+							push_term(term, weight, stemmer, stopper);
+						else if (status != bad_token)
+							tokens.push_back({status, slice(), 0});
+					}
+				else if (which_parser == parser_type::raw)
+					{
+					while ((status = get_next_token_raw(term, weight)) != eof_token)		// get the next token
 						if (status == valid_token)
-							parsed_query.push_back(term);
+							push_term(term, weight, stemmer, stopper);
+						else if (status != bad_token)
+							tokens.push_back({status, slice(), 0});
 					}
-				else	// (which_parser == parser_type::raw)
+				else	// (which_parser == parser_type::json)
 					{
-					while ((status = get_next_token_raw(term)) != eof_token)		// get the next token
+					while ((status = get_next_token_json(term, weight)) != eof_token)		// get the next token
 						if (status == valid_token)
-							parsed_query.push_back(term);
+							push_term(term, weight, stemmer, stopper);
 					}
 
 				/*
-					Now unique the terms in the query and increment the occurence accounts appropriately.
+					Turn the tokens into a query then unique the terms in the query and increment the occurence accounts appropriately.
 				*/
+				build_query(parsed_query);
 				parsed_query.sort_unique();
 				}
 
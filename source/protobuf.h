@@ -9,12 +9,15 @@
 	@author Andrew Trotman
 	@copyright 2019 Andrew Trotman
 
-	@brief Hand crafted methods to read protocol buffer (protobuf) encoded files.
+	@brief Hand crafted methods to read and write protocol buffer (protobuf) encoded files.
 	@details For details of the encoding see: https://developers.google.com/protocol-buffers/docs/encoding
 */
 #pragma once
 
 #include <stdint.h>
+
+#include <string>
+
 #include "slice.h"
 
 namespace JASS
@@ -24,7 +27,7 @@ namespace JASS
 		--------------
 	*/
 	/*!
-		@brief Functions to read a protobuf buffer
+		@brief Functions to read and write a protobuf buffer
 		@details For details of the encoding see: https://developers.google.com/protocol-buffers/docs/encoding
 		This class is written to be standalone from JASSv2 so that others can use it without including all of JASSv2
 	*/
@@ -264,5 +267,92 @@ namespace JASS
 
 				return encoding >> 3;
 				}
+
+			/*
+				PROTOBUF::PUT_UINT64_T()
+				------------------------
+			*/
+			/*!
+				@brief Encode and write an unsigned 64-bit VARINT integer (int32 and int64 fields are also written this way when not negative).
+				@param stream [in, out] The stream to append to.
+				@param value [in] The integer to write.
+			*/
+			static void put_uint64_t(std::string &stream, uint64_t value)
+				{
+				while (value >= 0x80)
+					{
+					stream.push_back(static_cast<char>((value & 0x7F) | 0x80));
+					value >>= 7;
+					}
+				stream.push_back(static_cast<char>(value));
+				}
+
+			/*
+				PROTOBUF::PUT_BLOB()
+				--------------------
+			*/
+			/*!
+				@brief Encode and write a blob or string (including an embedded message), the length then the data.
+				@param stream [in, out] The stream to append to.
+				@param data [in] The blob.
+				@param length [in] The length of the blob in bytes.
+			*/
+			static void put_blob(std::string &stream, const void *data, size_t length)
+				{
+				put_uint64_t(stream, length);
+				stream.append(reinterpret_cast<const char *>(data), length);
+				}
+
+			/*
+				PROTOBUF::PUT_64_T()
+				--------------------
+			*/
+			/*!
+				@brief Encode and write a 64-bit number (in little-endian byte order).
+				@param stream [in, out] The stream to append to.
+				@param value [in] The number to write.
+			*/
+			static void put_64_t(std::string &stream, uint64_t value)
+				{
+				for (size_t byte = 0; byte < 8; byte++)
+					stream.push_back(static_cast<char>((value >> (byte * 8)) & 0xFF));
+				}
+
+			/*
+				PROTOBUF::PUT_DOUBLE()
+				----------------------
+			*/
+			/*!
+				@brief Encode and write a double.
+				@param stream [in, out] The stream to append to.
+				@param value [in] The number to write.
+			*/
+			static void put_double(std::string &stream, double value)
+				{
+				union
+					{
+					uint64_t byte_sequence;
+					double number;
+					} answer;
+
+				answer.number = value;
+				put_64_t(stream, answer.byte_sequence);
+				}
+
+			/*
+				PROTOBUF::PUT_TYPE_AND_FIELD()
+				------------------------------
+			*/
+			/*!
+				@brief Write the key of a field, its field number and its type.
+				@details As with get_type_and_field(), the field number must be less than 16 so that the key fits in one byte.
+				@param stream [in, out] The stream to append to.
+				@param type [in] The type of the field.
+				@param field [in] The number of the field.
+			*/
+			static void put_type_and_field(std::string &stream, wire_type type, uint8_t field)
+				{
+				stream.push_back(static_cast<char>((field << 3) | type));
+				}
 		} ;
 	}
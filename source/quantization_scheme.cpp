@@ -0,0 +1,435 @@
+/*
+	QUANTIZATION_SCHEME.CPP
+	-----------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <math.h>
+#include <stdio.h>
+
+#include <sstream>
+#include <algorithm>
+#include <string_view>
+
+#include "asserts.h"
+#include "quantization_scheme.h"
+
+namespace JASS
+	{
+	/*
+		APPEND()
+		--------
+	*/
+	/*!
+		@brief Append a space and then a double (at full precision) to a string.
+		@param into [in / out] The string.
+		@param value [in] The value.
+	*/
+	static void append(std::string &into, double value)
+		{
+		char buffer[32];
+
+		snprintf(buffer, sizeof(buffer), " %.17g", value);
+		into += buffer;
+		}
+
+	/*
+		APPEND_BOUNDARIES()
+		-------------------
+	*/
+	/*!
+		@brief Append the number of boundaries and then each boundary to a string.
+		@param into [in / out] The string.
+		@param boundaries [in] The boundaries.
+	*/
+	static void append_boundaries(std::string &into, const std::vector<double> &boundaries)
+		{
+		into += " " + std::to_string(boundaries.size());
+		for (double boundary : boundaries)
+			append(into, boundary);
+		}
+
+	/*
+		READ_BOUNDARIES()
+		-----------------
+	*/
+	/*!
+		@brief Read the number of boundaries and then each boundary from a stream.
+		@param from [in] The stream.
+		@param boundaries [out] The boundaries.
+		@return true on success, else false.
+	*/
+	static bool read_boundaries(std::istream &from, std::vector<double> &boundaries)
+		{
+		size_t count;
+
+		if (!(from >> count))
+			return false;
+
+		boundaries.resize(count);
+		for (auto &boundary : boundaries)
+			if (!(from >> boundary))
+				return false;
+
+		return true;
+		}
+
+	/*
+		QUANTIZATION_SCHEME::GET_BOUNDS()
+		---------------------------------
+	*/
+	quantization_scheme::bounds &quantization_scheme::get_bounds(const slice &term)
+		{
+		if (range == GLOBAL)
+			return global;
+
+		std::string_view key(reinterpret_cast<const char *>(term.address()), term.size());
+		auto found = term_bounds.find(key);
+		if (found != term_bounds.end())
+			return found->second;
+
+		return term_bounds.emplace(std::string(key), bounds()).first->second;
+		}
+
+	/*
+		QUANTIZATION_SCHEME::GET_BOUNDS()
+		---------------------------------
+	*/
+	const quantization_scheme::bounds &quantization_scheme::get_bounds(const slice &term) const
+		{
+		if (range == GLOBAL)
+			return global;
+
+		auto found = term_bounds.find(std::string_view(reinterpret_cast<const char *>(term.address()), term.size()));
+
+		return found == term_bounds.end() ? global : found->second;
+		}
+
+	/*
+		QUANTIZATION_SCHEME::WIDEN()
+		----------------------------
+	*/
+	void quantization_scheme::widen(const quantization_scheme &other)
+		{
+		global.add(other.global.smallest);
+		global.add(other.global.largest);
+
+		for (const auto &[term, term_range] : other.term_bounds)
+			{
+			auto &into = term_bounds[term];
+			into.add(term_range.smallest);
+			into.add(term_range.largest);
+			}
+		}
+
+	/*
+		QUANTIZATION_SCHEME::COMPUTE_BOUNDARIES()
+		-----------------------------------------
+	*/
+	void quantization_scheme::compute_boundaries(bounds &into, std::vector<double> &scores) const
+		{
+		into.boundaries.clear();
+		if (scores.size() == 0)
+			return;
+
+		std::sort(scores.begin(), scores.end());
+		into.add(scores.front());
+		into.add(scores.back());
+
+		/*
+			The boundary of each impact is the score at its share of the sorted scores, ties mean that several impacts can have the same boundary
+			and as only one of them can be used, the duplicates are removed.
+		*/
+		size_t impacts = largest_impact - smallest_impact + 1;
+		for (size_t which = 0; which < impacts; which++)
+			into.boundaries.push_back(scores[which * scores.size() / impacts]);
+
+		into.boundaries.erase(std::unique(into.boundaries.begin(), into.boundaries.end()), into.boundaries.end());
+		}
+
+	/*
+		QUANTIZATION_SCHEME::QUANTIZE_NON_LINEAR()
+		------------------------------------------
+	*/
+	index_postings_impact::impact_type quantization_scheme::quantize_non_linear(double score, const bounds &within) const
+		{
+		size_t bucket;
+
+		if (mapping == EQUAL_FREQUENCY)
+			{
+			/*
+				The bucket is the last one whose boundary is no larger than the score
+			*/
+			auto found = std::upper_bound(within.boundaries.begin(), within.boundaries.end(), score);
+			bucket = found == within.boundaries.begin() ? 0 : found - within.boundaries.begin() - 1;
+			}
+		else
+			{
+			/*
+				LOGARITHMIC: bucket = floor(log(1 + x * range) / log(1 + range) * range) where x is the position of the score in the range (0..1)
+			*/
+			double impact_range = static_cast<double>(largest_impact - smallest_impact);
+			double position = (score - within.smallest) / (within.largest - within.smallest);
+			if (position < 0)
+				position = 0;
+			bucket = static_cast<size_t>(log1p(position * impact_range) / log1p(impact_range) * impact_range);
+			}
+
+		return static_cast<index_postings_impact::impact_type>((std::min)(bucket + smallest_impact, largest_impact));
+		}
+
+	/*
+		QUANTIZATION_SCHEME::DEQUANTIZE()
+		---------------------------------
+	*/
+	double quantization_scheme::dequantize(size_t impact, const bounds &within) const
+		{
+		double impact_range = static_cast<double>(largest_impact - smallest_impact);
+		size_t bucket = impact < smallest_impact ? 0 : impact - smallest_impact;
+
+		if (within.largest <= within.smallest)
+			return within.largest;
+
+		if (mapping == EQUAL_FREQUENCY)
+			{
+			if (bucket >= within.boundaries.size())
+				return within.largest;
+			if (bucket + 1 == within.boundaries.size())
+				return (within.boundaries[bucket] + within.largest) / 2;
+			return (within.boundaries[bucket] + within.boundaries[bucket + 1]) / 2;
+			}
+
+		/*
+			Only the largest score is quantized into the largest impact, otherwise take the middle of the bucket
+		*/
+		if (bucket >= largest_impact - smallest_impact)
+			return within.largest;
+
+		double position = (bucket + 0.5) / impact_range;
+		if (mapping == LOGARITHMIC)
+			position = expm1(position * log1p(impact_range)) / impact_range;
+
+		return within.smallest + position * (within.largest - within.smallest);
+		}
+
+	/*
+		QUANTIZATION_SCHEME::SERIALISE()
+		--------------------------------
+	*/
+	std::string quantization_scheme::serialise(void) const
+		{
+		std::string result;
+
+		append(result, global.smallest);
+		append(result, global.largest);
+		result.erase(0, 1);				// the leading space
+		result += "\n";
+
+		result += std::string("mapping ") + mapping_name(mapping) + "\n";
+		result += std::string("range ") + (range == GLOBAL ? "global" : "term") + "\n";
+		result += "impacts " + std::to_string(smallest_impact) + " " + std::to_string(largest_impact) + "\n";
+
+		if (mapping == EQUAL_FREQUENCY && range == GLOBAL)
+			{
+			result += "boundaries";
+			append_boundaries(result, global.boundaries);
+			result += "\n";
+			}
+
+		for (const auto &[term, term_range] : term_bounds)
+			{
+			result += "term " + term;
+			append(result, term_range.smallest);
+			append(result, term_range.largest);
+			append_boundaries(result, term_range.boundaries);
+			result += "\n";
+			}
+
+		return result;
+		}
+
+	/*
+		QUANTIZATION_SCHEME::DESERIALISE()
+		----------------------------------
+	*/
+	bool quantization_scheme::deserialise(const std::string &text)
+		{
+		std::istringstream stream(text);
+		std::string keyword;
+
+		*this = quantization_scheme();
+		if (!(stream >> global.smallest >> global.largest))
+			return false;
+
+		while (stream >> keyword)
+			{
+			if (keyword == "mapping")
+				{
+				std::string name;
+				if (!(stream >> name) || !mapping_by_name(name, mapping))
+					return false;
+				}
+			else if (keyword == "range")
+				{
+				std::string name;
+				if (!(stream >> name) || (name != "global" && name != "term"))
+					return false;
+				range = name == "global" ? GLOBAL : PER_TERM;
+				}
+			else if (keyword == "impacts")
+				{
+				if (!(stream >> smallest_impact >> largest_impact) || smallest_impact > largest_impact)
+					return false;
+				}
+			else if (keyword == "boundaries")
+				{
+				if (!read_boundaries(stream, global.boundaries))
+					return false;
+				}
+			else if (keyword == "term")
+				{
+				std::string term;
+				bounds term_range;
+				if (!(stream >> term >> term_range.smallest >> term_range.largest) || !read_boundaries(stream, term_range.boundaries))
+					return false;
+				term_bounds[term] = term_range;
+				}
+			else
+				return false;
+			}
+
+		return true;
+		}
+
+	/*
+		QUANTIZATION_SCHEME::MAPPING_NAME()
+		-----------------------------------
+	*/
+	const char *quantization_scheme::mapping_name(mapping_type mapping)
+		{
+		switch (mapping)
+			{
+			case LOGARITHMIC:
+				return "log";
+			case EQUAL_FREQUENCY:
+				return "equal_frequency";
+			default:
+				return "linear";
+			}
+		}
+
+	/*
+		QUANTIZATION_SCHEME::MAPPING_BY_NAME()
+		--------------------------------------
+	*/
+	bool quantization_scheme::mapping_by_name(const std::string &name, mapping_type &mapping)
+		{
+		for (auto candidate : {LINEAR, LOGARITHMIC, EQUAL_FREQUENCY})
+			if (name == mapping_name(candidate))
+				{
+				mapping = candidate;
+				return true;
+				}
+
+		return false;
+		}
+
+	/*
+		QUANTIZATION_SCHEME::UNITTEST()
+		-------------------------------
+	*/
+	void quantization_scheme::unittest(void)
+		{
+		/*
+			The default is the original linear quantization onto 1..index_postings_impact::largest_impact
+		*/
+		quantization_scheme linear;
+		linear.get_bounds(slice("term")).add(0.0);
+		linear.get_bounds(slice("term")).add(10.0);
+		JASS_assert(&linear.get_bounds(slice("other")) == &linear.get_global_bounds());
+		JASS_assert(linear.quantize(0.0, linear.get_global_bounds()) == index_postings_impact::smallest_impact);
+		JASS_assert(linear.quantize(10.0, linear.get_global_bounds()) == index_postings_impact::largest_impact);
+		JASS_assert(linear.quantize(5.0, linear.get_global_bounds()) == static_cast<index_postings_impact::impact_type>(0.5 * (index_postings_impact::largest_impact - index_postings_impact::smallest_impact)) + index_postings_impact::smallest_impact);
+
+		/*
+			8 bits is 1..255, and an impact maps back to (about) the score it came from
+		*/
+		quantization_scheme eight_bits(LINEAR, GLOBAL, 8);
+		eight_bits.get_global_bounds().add(0.0);
+		eight_bits.get_global_bounds().add(10.0);
+		JASS_assert(eight_bits.get_largest_impact() == 255);
+		JASS_assert(eight_bits.quantize(10.0, eight_bits.get_global_bounds()) == 255);
+		JASS_assert(fabs(eight_bits.dequantize(eight_bits.quantize(3.3, eight_bits.get_global_bounds()), eight_bits.get_global_bounds()) - 3.3) < 10.0 / 254);
+		JASS_assert(eight_bits.dequantize(255, eight_bits.get_global_bounds()) == 10.0);
+
+		/*
+			Logarithmic gives more impacts to the low scores, and is still monotonic
+		*/
+		quantization_scheme logarithmic(LOGARITHMIC, GLOBAL, 8);
+		logarithmic.get_global_bounds().add(0.0);
+		logarithmic.get_global_bounds().add(10.0);
+		JASS_assert(logarithmic.quantize(0.0, logarithmic.get_global_bounds()) == 1);
+		JASS_assert(logarithmic.quantize(10.0, logarithmic.get_global_bounds()) == 255);
+		JASS_assert(logarithmic.quantize(1.0, logarithmic.get_global_bounds()) > eight_bits.quantize(1.0, eight_bits.get_global_bounds()));
+		JASS_assert(logarithmic.quantize(1.0, logarithmic.get_global_bounds()) < logarithmic.quantize(1.1, logarithmic.get_global_bounds()));
+		JASS_assert(fabs(logarithmic.dequantize(logarithmic.quantize(1.0, logarithmic.get_global_bounds()), logarithmic.get_global_bounds()) - 1.0) < 0.05);
+
+		/*
+			Equal frequency puts the same number of scores into each impact
+		*/
+		quantization_scheme equal(EQUAL_FREQUENCY, PER_TERM, 2);
+		std::vector<double> scores = {8, 7, 6, 5, 4, 3};
+		equal.compute_boundaries(equal.get_bounds(slice("term")), scores);
+		const auto &term = equal.get_bounds(slice("term"));
+		JASS_assert(term.boundaries == std::vector<double>({3, 5, 7}));
+		JASS_assert(equal.quantize(3, term) == 1 && equal.quantize(4, term) == 1);
+		JASS_assert(equal.quantize(5, term) == 2 && equal.quantize(6, term) == 2);
+		JASS_assert(equal.quantize(7, term) == 3 && equal.quantize(8, term) == 3);
+		JASS_assert(equal.quantize(1, term) == 1);
+		JASS_assert(equal.dequantize(2, term) == 6.0);
+		JASS_assert(equal.dequantize(3, term) == 7.5);
+
+		/*
+			The scheme can be written and read back, and the original two-number file is the default scheme
+		*/
+		quantization_scheme reread;
+		JASS_assert(reread.deserialise(equal.serialise()));
+		JASS_assert(reread.serialise() == equal.serialise());
+		JASS_assert(reread.get_mapping() == EQUAL_FREQUENCY && reread.get_range() == PER_TERM && reread.get_largest_impact() == 3);
+		JASS_assert(reread.quantize(6, reread.get_bounds(slice("term"))) == 2);
+
+		JASS_assert(reread.deserialise("0.5 12.25\n"));
+		JASS_assert(reread.get_mapping() == LINEAR && reread.get_range() == GLOBAL && reread.get_largest_impact() == index_postings_impact::largest_impact);
+		JASS_assert(reread.get_global_bounds().smallest == 0.5 && reread.get_global_bounds().largest == 12.25);
+
+		JASS_assert(!reread.deserialise(""));
+		JASS_assert(!reread.deserialise("0 1\nmapping cubic\n"));
+
+		/*
+			Widening covers the bounds of both schemes (for merging indexes)
+		*/
+		quantization_scheme wide(LINEAR, PER_TERM, 8);
+		wide.get_global_bounds().add(2.0);
+		wide.get_global_bounds().add(4.0);
+		wide.get_bounds(slice("term")).add(3.0);
+		quantization_scheme other(LINEAR, PER_TERM, 8);
+		other.get_global_bounds().add(1.0);
+		other.get_global_bounds().add(3.0);
+		other.get_bounds(slice("term")).add(1.5);
+		other.get_bounds(slice("more")).add(2.5);
+		JASS_assert(wide.same_method(other) && !wide.same_method(eight_bits) && !wide.same_method(linear));
+		wide.widen(other);
+		JASS_assert(wide.get_global_bounds().smallest == 1.0 && wide.get_global_bounds().largest == 4.0);
+		JASS_assert(wide.get_bounds(slice("term")).smallest == 1.5 && wide.get_bounds(slice("term")).largest == 3.0);
+		JASS_assert(wide.get_bounds(slice("more")).smallest == 2.5);
+
+		/*
+			Names
+		*/
+		mapping_type mapping;
+		JASS_assert(mapping_by_name("log", mapping) && mapping == LOGARITHMIC);
+		JASS_assert(!mapping_by_name("cubic", mapping));
+
+		puts("quantization_scheme::PASSED");
+		}
+	}
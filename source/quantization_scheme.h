@@ -0,0 +1,392 @@
+/*
+	QUANTIZATION_SCHEME.H
+	---------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief How scores are mapped into impacts (and back again)
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <map>
+#include <limits>
+#include <string>
+#include <vector>
+
+#include "slice.h"
+#include "index_postings_impact.h"
+
+namespace JASS
+	{
+	/*
+		CLASS QUANTIZATION_SCHEME
+		-------------------------
+	*/
+	/*!
+		@brief How scores are mapped into impacts (and back again).
+		@details The scheme is made up of the mapping (linear, logarithmic, or equal-frequency), whether the scores of all terms share one range or
+		each term has its own range, the range of impacts (set by the number of bits), and the bounds of each range.  The indexer fills in the bounds
+		while quantizing and writes the scheme into the index (see serialise()) so that impacts can be mapped back to approximate scores (see dequantize()).
+
+		Linear is uniform quantization (Anh et al., SIGIR 2001).  Logarithmic places the bucket boundaries on a log scale so that the many low scores are
+		spread over more impacts than the few high scores.  Equal-frequency places the boundaries so that each impact holds (about) the same number of postings.
+	*/
+	class quantization_scheme
+		{
+		public:
+			/*
+				ENUM QUANTIZATION_SCHEME::MAPPING_TYPE
+				--------------------------------------
+			*/
+			/*!
+				@enum mapping_type
+				@brief How a score within the range is turned into an impact.
+			*/
+			enum mapping_type
+				{
+				LINEAR,						///< Equal width buckets (uniform quantization)
+				LOGARITHMIC,				///< Buckets of equal width on a log scale
+				EQUAL_FREQUENCY			///< Buckets holding an equal number of postings
+				};
+
+			/*
+				ENUM QUANTIZATION_SCHEME::RANGE_TYPE
+				------------------------------------
+			*/
+			/*!
+				@enum range_type
+				@brief Whether all terms share one range or each term has its own.
+			*/
+			enum range_type
+				{
+				GLOBAL,						///< One range for the whole collection
+				PER_TERM						///< Each term has its own range
+				};
+
+			/*
+				CLASS QUANTIZATION_SCHEME::BOUNDS
+				---------------------------------
+			*/
+			/*!
+				@brief The range of scores that are quantized together.
+			*/
+			class bounds
+				{
+				public:
+					double smallest;								///< The smallest score (which becomes the smallest impact).
+					double largest;								///< The largest score (which becomes the largest impact).
+					std::vector<double> boundaries;			///< For EQUAL_FREQUENCY, the smallest score of each impact (from the smallest impact upwards), else empty.
+
+				public:
+					/*
+						QUANTIZATION_SCHEME::BOUNDS::BOUNDS()
+						-------------------------------------
+					*/
+					/*!
+						@brief Constructor
+					*/
+					bounds() :
+						smallest((std::numeric_limits<double>::max)()),
+						largest(std::numeric_limits<double>::lowest())
+						{
+						/* Nothing */
+						}
+
+					/*
+						QUANTIZATION_SCHEME::BOUNDS::ADD()
+						----------------------------------
+					*/
+					/*!
+						@brief Widen the range to include the given score.
+						@param score [in] The score.
+					*/
+					void add(double score)
+						{
+						if (score < smallest)
+							smallest = score;
+						if (score > largest)
+							largest = score;
+						}
+				};
+
+		public:
+			static constexpr size_t MAX_BITS = 16;			///< The largest number of bits that an impact can be (impacts are stored on disk as uint16_t).
+
+		private:
+			mapping_type mapping;								///< How a score is mapped into an impact.
+			range_type range;										///< Does each term have its own range?
+			size_t smallest_impact;								///< The smallest impact a score can be mapped to.
+			size_t largest_impact;								///< The largest impact a score can be mapped to.
+			bounds global;											///< The collection-wide range (for PER_TERM this is the union of the term ranges).
+			std::map<std::string, bounds, std::less<>> term_bounds;		///< The range of each term (PER_TERM only).
+
+		public:
+			/*
+				QUANTIZATION_SCHEME::QUANTIZATION_SCHEME()
+				------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@details The default is linear quantization of one global range onto the impacts index_postings_impact::smallest_impact to index_postings_impact::largest_impact.
+				@param mapping [in] How a score is mapped into an impact.
+				@param range [in] Does each term have its own range?
+				@param bits [in] The number of bits in an impact (the impacts are 1 to 2^bits - 1), or 0 for index_postings_impact::largest_impact.
+			*/
+			explicit quantization_scheme(mapping_type mapping = LINEAR, range_type range = GLOBAL, size_t bits = 0) :
+				mapping(mapping),
+				range(range),
+				smallest_impact(index_postings_impact::smallest_impact),
+				largest_impact(bits == 0 ? index_postings_impact::largest_impact : (static_cast<size_t>(1) << bits) - 1)
+				{
+				/* Nothing */
+				}
+
+			/*
+				QUANTIZATION_SCHEME::GET_MAPPING()
+				----------------------------------
+			*/
+			/*!
+				@brief Return how a score is mapped into an impact.
+				@return The mapping.
+			*/
+			mapping_type get_mapping(void) const
+				{
+				return mapping;
+				}
+
+			/*
+				QUANTIZATION_SCHEME::GET_RANGE()
+				--------------------------------
+			*/
+			/*!
+				@brief Return whether all terms share one range or each term has its own.
+				@return The range type.
+			*/
+			range_type get_range(void) const
+				{
+				return range;
+				}
+
+			/*
+				QUANTIZATION_SCHEME::GET_SMALLEST_IMPACT()
+				------------------------------------------
+			*/
+			/*!
+				@brief Return the smallest impact a score can be mapped to.
+				@return The smallest impact.
+			*/
+			size_t get_smallest_impact(void) const
+				{
+				return smallest_impact;
+				}
+
+			/*
+				QUANTIZATION_SCHEME::GET_LARGEST_IMPACT()
+				-----------------------------------------
+			*/
+			/*!
+				@brief Return the largest impact a score can be mapped to.
+				@return The largest impact.
+			*/
+			size_t get_largest_impact(void) const
+				{
+				return largest_impact;
+				}
+
+			/*
+				QUANTIZATION_SCHEME::GET_GLOBAL_BOUNDS()
+				----------------------------------------
+			*/
+			/*!
+				@brief Return the collection-wide range of scores.
+				@return The collection-wide bounds.
+			*/
+			bounds &get_global_bounds(void)
+				{
+				return global;
+				}
+
+			/*
+				QUANTIZATION_SCHEME::GET_GLOBAL_BOUNDS()
+				----------------------------------------
+			*/
+			/*!
+				@brief Return the collection-wide range of scores.
+				@return The collection-wide bounds.
+			*/
+			const bounds &get_global_bounds(void) const
+				{
+				return global;
+				}
+
+			/*
+				QUANTIZATION_SCHEME::GET_BOUNDS()
+				---------------------------------
+			*/
+			/*!
+				@brief Return the range that the given term's scores are quantized with, creating it if needed (for PER_TERM).
+				@param term [in] The term.
+				@return The bounds.
+			*/
+			bounds &get_bounds(const slice &term);
+
+			/*
+				QUANTIZATION_SCHEME::GET_BOUNDS()
+				---------------------------------
+			*/
+			/*!
+				@brief Return the range that the given term's scores are quantized with.
+				@param term [in] The term.
+				@return The bounds (the global bounds if the term does not have its own).
+			*/
+			const bounds &get_bounds(const slice &term) const;
+
+			/*
+				QUANTIZATION_SCHEME::SAME_METHOD()
+				----------------------------------
+			*/
+			/*!
+				@brief Does another scheme map scores in the same way as this one (the same mapping, range type, and impacts, but not necessarily the same bounds)?
+				@param other [in] The other scheme.
+				@return true if the methods are the same, else false.
+			*/
+			bool same_method(const quantization_scheme &other) const
+				{
+				return mapping == other.mapping && range == other.range && smallest_impact == other.smallest_impact && largest_impact == other.largest_impact;
+				}
+
+			/*
+				QUANTIZATION_SCHEME::WIDEN()
+				----------------------------
+			*/
+			/*!
+				@brief Widen the bounds of this scheme (both global and per-term) to include those of another scheme.
+				@details The equal-frequency boundaries cannot be combined this way and are left unchanged.
+				@param other [in] The other scheme.
+			*/
+			void widen(const quantization_scheme &other);
+
+			/*
+				QUANTIZATION_SCHEME::COMPUTE_BOUNDARIES()
+				-----------------------------------------
+			*/
+			/*!
+				@brief Compute the equal-frequency boundaries from the given scores (which are sorted).
+				@details The scores can be a sample of the scores, so the range of into is widened to cover them rather than replaced by theirs.
+				@param into [out] The bounds to fill.
+				@param scores [in / out] The scores (which are sorted in place).
+			*/
+			void compute_boundaries(bounds &into, std::vector<double> &scores) const;
+
+			/*
+				QUANTIZATION_SCHEME::QUANTIZE()
+				-------------------------------
+			*/
+			/*!
+				@brief Map a score into an impact.
+				@param score [in] The score.
+				@param within [in] The range the score is quantized in (see get_bounds()).
+				@return The impact.
+			*/
+			index_postings_impact::impact_type quantize(double score, const bounds &within) const
+				{
+				/*
+					This is the expression used by the original (linear) quantizer so existing indexes are re-built exactly
+				*/
+				double impact_range = static_cast<double>(largest_impact - smallest_impact);
+
+				if (within.largest <= within.smallest)
+					return static_cast<index_postings_impact::impact_type>(largest_impact);
+
+				if (mapping == LINEAR)
+					return static_cast<index_postings_impact::impact_type>(((score - within.smallest) / (within.largest - within.smallest)) * impact_range) + static_cast<index_postings_impact::impact_type>(smallest_impact);
+
+				return quantize_non_linear(score, within);
+				}
+
+			/*
+				QUANTIZATION_SCHEME::DEQUANTIZE()
+				---------------------------------
+			*/
+			/*!
+				@brief Map an impact back into the score at the middle of its bucket.
+				@param impact [in] The impact.
+				@param within [in] The range the impact was quantized in (see get_bounds()).
+				@return The approximate score.
+			*/
+			double dequantize(size_t impact, const bounds &within) const;
+
+			/*
+				QUANTIZATION_SCHEME::SERIALISE()
+				--------------------------------
+			*/
+			/*!
+				@brief Return the scheme and its bounds as text that can be read back with deserialise().
+				@details The first line is the smallest and largest global score (as written before the scheme was recorded).  Each remaining line
+				is a keyword followed by values.  Terms are assumed not to contain whitespace.
+				@return The text.
+			*/
+			std::string serialise(void) const;
+
+			/*
+				QUANTIZATION_SCHEME::DESERIALISE()
+				----------------------------------
+			*/
+			/*!
+				@brief Read a scheme written by serialise().
+				@details A file holding only the smallest and largest scores is the default scheme (linear, global).
+				@param text [in] The serialised scheme.
+				@return true on success, false if text cannot be parsed.
+			*/
+			bool deserialise(const std::string &text);
+
+			/*
+				QUANTIZATION_SCHEME::MAPPING_NAME()
+				-----------------------------------
+			*/
+			/*!
+				@brief Return the name of a mapping.
+				@param mapping [in] The mapping.
+				@return The name ("linear", "log", or "equal_frequency").
+			*/
+			static const char *mapping_name(mapping_type mapping);
+
+			/*
+				QUANTIZATION_SCHEME::MAPPING_BY_NAME()
+				--------------------------------------
+			*/
+			/*!
+				@brief Return the mapping with the given name.
+				@param name [in] The name ("linear", "log", or "equal_frequency").
+				@param mapping [out] The mapping.
+				@return true if name is a known mapping, else false.
+			*/
+			static bool mapping_by_name(const std::string &name, mapping_type &mapping);
+
+			/*
+				QUANTIZATION_SCHEME::UNITTEST()
+				-------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+
+		private:
+			/*
+				QUANTIZATION_SCHEME::QUANTIZE_NON_LINEAR()
+				------------------------------------------
+			*/
+			/*!
+				@brief Map a score into an impact using the LOGARITHMIC or EQUAL_FREQUENCY mapping.
+				@param score [in] The score.
+				@param within [in] The range the score is quantized in.
+				@return The impact.
+			*/
+			index_postings_impact::impact_type quantize_non_linear(double score, const bounds &within) const;
+		};
+	}
@@ -14,9 +14,12 @@
 
 #include <math.h>
 
+#include <random>
+#include <vector>
 #include <iostream>
 
 #include "index_manager.h"
+#include "quantization_scheme.h"
 #include "index_manager_sequential.h"
 #include "ranking_function_atire_bm25.h"
 
@@ -37,16 +40,21 @@ namespace JASS
 		so high impact segments are short and low impact scores are long.  The best documents have high impact scores
 		for each query term and so have high result list rsvs are rare.  Uniform quantization also does not require
 		decoding so the cost of ranking is an integer add!
+
+		The number of impacts, whether each term has its own range, and the mapping (linear, logarithmic, equal-frequency)
+		are given by the quantization_scheme, whose bounds are filled in on the first pass.
 	*/
 	template <typename RANKER>
 	class quantize : public index_manager::delegate, public index_manager::quantizing_delegate
 		{
 		private:
-			double largest_rsv;												///< The largest score seen for any document/term pair.
-			double smallest_rsv;												///< The smallest score seen for any document/term pair.
+			quantization_scheme scheme;									///< How the scores are mapped into impacts (and the bounds of each range).
 			std::shared_ptr<RANKER> ranker;								///< The ranker to use for quantization.
 			compress_integer::integer documents_in_collection;		///< The number of documents in the collection.
-			static constexpr double impact_range = index_postings_impact::largest_impact - index_postings_impact::smallest_impact; ///< The number of values in the impact ordering range (normally 255).
+			static constexpr size_t largest_sample = 1 << 20;		///< The most scores kept for computing the EQUAL_FREQUENCY boundaries.
+			std::vector<double> scores;									///< A uniform sample of the scores of the postings (for EQUAL_FREQUENCY, as the boundaries come from the distribution).
+			uint64_t scores_seen;											///< The number of scores that scores is a sample of.
+			std::mt19937_64 random;											///< Choose which scores are in the sample (with a fixed seed so that indexing is repeatable).
 
 		public:
 			/*
@@ -57,17 +65,41 @@ namespace JASS
 				@brief Constructor
 				@param documents [in] The number of documents in the collection.
 				@param ranker [in] The ranking function used for quantization.
+				@param scheme [in] How to map scores into impacts (the default is uniform quantization onto index_postings_impact::smallest_impact..index_postings_impact::largest_impact).
 			*/
-			quantize(size_t documents, std::shared_ptr<RANKER> ranker) :
+			quantize(size_t documents, std::shared_ptr<RANKER> ranker, const quantization_scheme &scheme = quantization_scheme()) :
 				index_manager::delegate(documents),
-				largest_rsv((std::numeric_limits<decltype(largest_rsv)>::min)()),
-				smallest_rsv((std::numeric_limits<decltype(smallest_rsv)>::max)()),
+				scheme(scheme),
 				ranker(ranker),
-				documents_in_collection(static_cast<compress_integer::integer>(documents))
+				documents_in_collection(static_cast<compress_integer::integer>(documents)),
+				scores_seen(0)
 				{
 				/* Nothing. */
 				}
 
+			/*
+				QUANTIZE::SAMPLE()
+				------------------
+			*/
+			/*!
+				@brief Add a score to the sample of scores, keeping at most largest_sample of them (reservoir sampling, Algorithm R).
+				@details Each score seen has the same chance of being in the sample, so the boundaries computed from the sample approximate those
+				of all the scores while memory stays fixed.  When there are no more than largest_sample scores, the sample is all of them.
+				@param score [in] The score of a posting.
+			*/
+			void sample(double score)
+				{
+				scores_seen++;
+				if (scores.size() < largest_sample)
+					scores.push_back(score);
+				else
+					{
+					uint64_t replace = std::uniform_int_distribution<uint64_t>(0, scores_seen - 1)(random);
+					if (replace < largest_sample)
+						scores[replace] = score;
+					}
+				}
+
 			/*
 				QUANTIZE::~QUANTIZE()
 				---------------------
@@ -77,8 +109,8 @@ namespace JASS
 			*/
 			virtual ~quantize()
 				{
-//				std::cout << "RSVmin:" << smallest_rsv << '\n';
-//				std::cout << "RSVmax:" << largest_rsv << '\n';
+//				std::cout << "RSVmin:" << scheme.get_global_bounds().smallest << '\n';
+//				std::cout << "RSVmax:" << scheme.get_global_bounds().largest << '\n';
 				}
 
 			/*
@@ -108,13 +140,26 @@ namespace JASS
 			virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
 				{
 				/*
-					Compute the IDF component
+					Compute the term and IDF components
 				*/
+				uint64_t collection_frequency = 0;
+				for (compress_integer::integer which = 0; which < document_frequency; which++)
+					collection_frequency += term_frequencies[which];
+				ranker->compute_term_component(term, collection_frequency);
 				ranker->compute_idf_component(document_frequency, documents_in_collection);
 
 				/*
 					Compute the document / term score and keep a tally of the smallest and largest (for quantization)
 				*/
+				auto &global = scheme.get_global_bounds();
+				auto &range = scheme.get_bounds(term);
+				bool keep_scores = scheme.get_mapping() == quantization_scheme::EQUAL_FREQUENCY;
+				if (keep_scores && scheme.get_range() == quantization_scheme::PER_TERM)
+					{
+					scores.clear();
+					scores_seen = 0;
+					}
+
 				auto end = document_ids + document_frequency;
 				auto current_tf = term_frequencies;
 				for (compress_integer::integer *current_id = document_ids; current_id < end; current_id++, current_tf++)
@@ -128,11 +173,17 @@ namespace JASS
 					/*
 						Keep a running tally of the largest and smallest rsv we've seen so far
 					*/
-					if (score < smallest_rsv)
-						smallest_rsv = score;
-					if (score > largest_rsv)
-						largest_rsv = score;
+					global.add(score);
+					range.add(score);
+					if (keep_scores)
+						sample(score);
 					}
+
+				/*
+					Each term has its own equal-frequency boundaries, so compute them now
+				*/
+				if (keep_scores && scheme.get_range() == quantization_scheme::PER_TERM)
+					scheme.compute_boundaries(range, scores);
 				}
 
 			/*
@@ -165,13 +216,18 @@ namespace JASS
 			virtual void operator()(index_manager::delegate &writer, const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
 				{
 				/*
-					Compute the IDF component
+					Compute the term and IDF components
 				*/
+				uint64_t collection_frequency = 0;
+				for (compress_integer::integer which = 0; which < document_frequency; which++)
+					collection_frequency += term_frequencies[which];
+				ranker->compute_term_component(term, collection_frequency);
 				ranker->compute_idf_component(document_frequency, documents_in_collection);
 
 				/*
 					Compute the document / term score and quantize it.
 				*/
+				const auto &range = static_cast<const quantization_scheme &>(scheme).get_bounds(term);
 				auto end = document_ids + document_frequency;
 				auto current_tf = term_frequencies;
 				for (compress_integer::integer *current_id = document_ids; current_id < end; current_id++, current_tf++)
@@ -183,12 +239,11 @@ namespace JASS
 					double score = ranker->compute_score(*current_id, *current_tf);
 
 					/*
-						Quantize using uniform quantization, and write back as the new term frequency (which is now an impact score).
-						This uses Uniform Quantiization as defined by Anh et al. in:
+						Quantize (by default using uniform quantization), and write back as the new term frequency (which is now an impact score).
+						Uniform Quantiization is defined by Anh et al. in:
 						Vo Ngoc Anh, Owen de Kretser, and Alistair Moffat. 2001. Vector-space ranking with effective early termination. In Proceedings of the 24th annual international ACM SIGIR conference on Research and development in information retrieval (SIGIR '01). ACM, New York, NY, USA, 35-42. DOI: https://doi.org/10.1145/383952.383957
 					*/
-					index_postings_impact::impact_type impact = static_cast<index_postings_impact::impact_type>(((score - smallest_rsv) / (largest_rsv - smallest_rsv)) * impact_range) + index_postings_impact::smallest_impact;
-					*current_tf = impact;
+					*current_tf = scheme.quantize(score, range);
 					}
 
 				/*
@@ -223,8 +278,41 @@ namespace JASS
 			*/
 			void get_bounds(double &smallest, double &largest)
 				{
-				smallest = smallest_rsv;
-				largest = largest_rsv;
+				smallest = scheme.get_global_bounds().smallest;
+				largest = scheme.get_global_bounds().largest;
+				}
+
+			/*
+				QUANTIZE::GET_SCHEME()
+				----------------------
+			*/
+			/*!
+				@brief Get the quantization scheme along with its bounds (which are complete once complete_scheme() or serialise_index() has been called).
+				@return The quantization scheme.
+			*/
+			const quantization_scheme &get_scheme(void) const
+				{
+				return scheme;
+				}
+
+			/*
+				QUANTIZE::COMPLETE_SCHEME()
+				---------------------------
+			*/
+			/*!
+				@brief Finish the quantization scheme once the first pass (over the index) is over.  Called by serialise_index().
+			*/
+			void complete_scheme(void)
+				{
+				/*
+					The global equal-frequency boundaries need the (sample of the) scores of all terms, so they are computed once the first pass is over
+				*/
+				if (scheme.get_mapping() == quantization_scheme::EQUAL_FREQUENCY && scheme.get_range() == quantization_scheme::GLOBAL && scores.size() != 0)
+					{
+					scheme.compute_boundaries(scheme.get_global_bounds(), scores);
+					scores = std::vector<double>();
+					scores_seen = 0;
+					}
 				}
 
 			/*
@@ -238,6 +326,8 @@ namespace JASS
 			*/
 			void serialise_index(index_manager &index, std::vector<std::unique_ptr<index_manager::delegate>> &serialisers)
 				{
+				complete_scheme();
+
 				for (auto &outputter : serialisers)
 					{
 					index.iterate(*this, *outputter);
@@ -274,6 +364,36 @@ namespace JASS
 				JASS_assert(static_cast<int>(smallest) == 0);
 				JASS_assert(static_cast<int>(largest) == 2);
 
+				/*
+					Each term has its own range, so the largest score of each term is quantized to the largest impact
+				*/
+				quantize<ranking_function_atire_bm25> per_term(index.get_highest_document_id(), ranker, quantization_scheme(quantization_scheme::LINEAR, quantization_scheme::PER_TERM, 8));
+				index.iterate(per_term);
+
+				const auto &scheme = per_term.get_scheme();
+				const auto &global = scheme.get_global_bounds();
+				JASS_assert(global.smallest == smallest && global.largest == largest);
+				JASS_assert(scheme.get_bounds(slice("nine")).largest < largest);
+				JASS_assert(scheme.quantize(scheme.get_bounds(slice("nine")).largest, scheme.get_bounds(slice("nine"))) == 255);
+
+				/*
+					The equal-frequency boundaries come from a fixed size sample of the scores, but the range covers all of them
+				*/
+				quantize<ranking_function_atire_bm25> equal(index.get_highest_document_id(), ranker, quantization_scheme(quantization_scheme::EQUAL_FREQUENCY, quantization_scheme::GLOBAL, 2));
+				for (size_t which = 0; which < 4 * largest_sample; which++)
+					{
+					double score = static_cast<double>(which % 900);
+					equal.scheme.get_global_bounds().add(score);
+					equal.sample(score);
+					}
+				JASS_assert(equal.scores.size() == largest_sample && equal.scores_seen == 4 * largest_sample);
+
+				equal.complete_scheme();
+				const auto &sampled = equal.get_scheme().get_global_bounds();
+				JASS_assert(sampled.smallest == 0 && sampled.largest == 899);
+				JASS_assert(sampled.boundaries.size() == 3 && fabs(sampled.boundaries[1] - 300) < 10 && fabs(sampled.boundaries[2] - 600) < 10);
+				JASS_assert(equal.scores.size() == 0);
+
 				puts("quantize::PASSED");
 				}
 		};
@@ -0,0 +1,149 @@
+/*
+	QUANTIZE_INDEX.CPP
+	------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <sstream>
+
+#include "asserts.h"
+#include "unittest_data.h"
+#include "quantize_index.h"
+#include "serialise_jass_v2.h"
+#include "index_manager_sequential.h"
+#include "ranking_function_dph.h"
+#include "ranking_function_tfidf.h"
+#include "ranking_function_atire_bm25.h"
+#include "ranking_function_lucene_bm25.h"
+#include "ranking_function_lm_dirichlet.h"
+
+namespace JASS
+	{
+	/*
+		QUANTIZE_INDEX::IS_KNOWN()
+		--------------------------
+	*/
+	bool quantize_index::is_known(const std::string &ranking_function)
+		{
+		return ranking_function == "ATIRE_BM25" || ranking_function == "Lucene_BM25" || ranking_function == "LM_Dirichlet" || ranking_function == "DPH" || ranking_function == "TF_IDF";
+		}
+
+	/*
+		QUANTIZE_INDEX::DESCRIBE_RANKING_FUNCTION()
+		-------------------------------------------
+	*/
+	std::string quantize_index::describe_ranking_function(const std::string &ranking_function, double k1, double b, double mu)
+		{
+		std::ostringstream description;
+
+		if (ranking_function == "ATIRE_BM25" || ranking_function == "Lucene_BM25" || ranking_function == "BM25F")
+			description << ranking_function << " k1=" << k1 << " b=" << b;
+		else if (ranking_function == "LM_Dirichlet")
+			description << ranking_function << " mu=" << mu;
+		else
+			description << ranking_function;
+
+		return description.str();
+		}
+
+	/*
+		QUANTIZE_INDEX::DESCRIBE()
+		--------------------------
+	*/
+	void quantize_index::describe(index_manifest &manifest, const std::string &ranking_function, const quantization_scheme *scheme, const std::string &stemmer)
+		{
+		manifest.ranking_function = ranking_function;
+
+		if (scheme == nullptr)
+			manifest.quantization = "none";
+		else
+			manifest.quantization = std::string(quantization_scheme::mapping_name(scheme->get_mapping())) + (scheme->get_range() == quantization_scheme::PER_TERM ? " term" : " global") + " impacts " + std::to_string(scheme->get_smallest_impact()) + " " + std::to_string(scheme->get_largest_impact());
+
+		manifest.stemmer = stemmer;
+		}
+
+	/*
+		QUANTIZE_INDEX::QUANTIZE_AND_SERIALISE()
+		----------------------------------------
+	*/
+	void quantize_index::quantize_and_serialise(const std::string &ranking_function, double k1, double b, double mu, index_manager &index, size_t documents, std::vector<std::unique_ptr<index_manager::delegate>> &exporters, serialise_jass_v3 *jass_v3, bool save_quantization, quantization_scheme &scheme, const stop_watch &timer, nanoseconds &time_to_end_quantization)
+		{
+		auto &document_lengths = index.get_document_length_vector();
+
+		if (ranking_function == "Lucene_BM25")
+			quantize_and_serialise(std::make_shared<ranking_function_lucene_bm25>(k1, b, document_lengths), index, true, documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+		else if (ranking_function == "LM_Dirichlet")
+			quantize_and_serialise(std::make_shared<ranking_function_lm_dirichlet>(mu, document_lengths), index, true, documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+		else if (ranking_function == "DPH")
+			quantize_and_serialise(std::make_shared<ranking_function_dph>(document_lengths), index, true, documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+		else if (ranking_function == "TF_IDF")
+			quantize_and_serialise(std::make_shared<ranking_function_tfidf>(document_lengths), index, true, documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+		else
+			quantize_and_serialise(std::make_shared<ranking_function_atire_bm25>(k1, b, document_lengths), index, true, documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+		}
+
+	/*
+		QUANTIZE_INDEX::UNITTEST()
+		--------------------------
+	*/
+	void quantize_index::unittest(void)
+		{
+		/*
+			The names of the ranking functions
+		*/
+		for (const char *name : {"ATIRE_BM25", "Lucene_BM25", "LM_Dirichlet", "DPH", "TF_IDF"})
+			JASS_assert(is_known(name));
+		JASS_assert(!is_known("BM25F"));
+		JASS_assert(!is_known("Unknown"));
+
+		JASS_assert(describe_ranking_function("ATIRE_BM25", 0.9, 0.4, 1000) == "ATIRE_BM25 k1=0.9 b=0.4");
+		JASS_assert(describe_ranking_function("BM25F", 1.2, 0.75, 1000) == "BM25F k1=1.2 b=0.75");
+		JASS_assert(describe_ranking_function("LM_Dirichlet", 0.9, 0.4, 500) == "LM_Dirichlet mu=500");
+		JASS_assert(describe_ranking_function("DPH", 0.9, 0.4, 1000) == "DPH");
+
+		/*
+			Quantize and write an index with each ranking function, the quantization scheme must be written (before the index) with its bounds
+		*/
+		index_manager_sequential index;
+		index_manager_sequential::unittest_build_index(index, unittest_data::ten_documents);
+
+		for (const char *name : {"ATIRE_BM25", "Lucene_BM25", "LM_Dirichlet", "DPH", "TF_IDF"})
+			{
+			auto timer = timer::start();
+			nanoseconds time_to_end_quantization;
+			quantization_scheme scheme(quantization_scheme::LINEAR, quantization_scheme::GLOBAL, 8);
+
+			auto serialiser = std::make_unique<serialise_jass_v2>(index.get_highest_document_id());
+			describe(serialiser->get_manifest(), describe_ranking_function(name, 0.9, 0.4, 1000), &scheme, "None");
+			serialiser->add_auxiliary_file("CIquantization.txt");
+			std::vector<std::unique_ptr<index_manager::delegate>> exporters;
+			exporters.push_back(std::move(serialiser));
+
+			quantize_and_serialise(name, 0.9, 0.4, 1000, index, index.get_highest_document_id(), exporters, nullptr, true, scheme, timer, time_to_end_quantization);
+			exporters.clear();
+
+			JASS_assert(scheme.get_largest_impact() == 255);
+			JASS_assert(scheme.get_global_bounds().smallest < scheme.get_global_bounds().largest);
+
+			std::string saved;
+			file::read_entire_file("CIquantization.txt", saved);
+			JASS_assert(saved == scheme.serialise());
+
+			index_manifest manifest;
+			JASS_assert(manifest.read());
+			JASS_assert(manifest.version == 2 && manifest.documents == 10);
+			JASS_assert(manifest.ranking_function == describe_ranking_function(name, 0.9, 0.4, 1000));
+			JASS_assert(manifest.quantization == "linear global impacts 1 255");
+			JASS_assert(manifest.check_files().size() == 0);
+			}
+
+		/*
+			Impacts that are not quantized
+		*/
+		index_manifest manifest;
+		describe(manifest, "uniCOIL", nullptr, "Porter");
+		JASS_assert(manifest.ranking_function == "uniCOIL" && manifest.quantization == "none" && manifest.stemmer == "Porter");
+
+		puts("quantize_index::PASSED");
+		}
+	}
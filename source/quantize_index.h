@@ -0,0 +1,176 @@
+/*
+	QUANTIZE_INDEX.H
+	----------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Quantize an in-memory index with a ranking function chosen by name, then serialise it (used by JASS_index and JASS_requantize).
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "file.h"
+#include "timer.h"
+#include "quantize.h"
+#include "quantize_none.h"
+#include "index_manager.h"
+#include "index_manifest.h"
+#include "serialise_jass_v3.h"
+#include "quantization_scheme.h"
+
+namespace JASS
+	{
+	/*
+		CLASS QUANTIZE_INDEX
+		--------------------
+	*/
+	/*!
+		@brief Quantize an in-memory index with a ranking function chosen by name, then serialise it.
+		@details The ranking functions are ATIRE_BM25, Lucene_BM25, LM_Dirichlet, DPH, and TF_IDF (BM25F needs the fields of the index so
+		it is not chosen by name, but quantize_and_serialise() can be called with it directly).  The quantization scheme is written to
+		CIquantization.txt (and given to the JASS v3 serialiser) before the index is written so that its checksum can be recorded in the manifest.
+	*/
+	class quantize_index
+		{
+		public:
+			/*!
+				@typedef stop_watch
+				@brief The type of the timer used to time the quantization (see timer::start()).
+			*/
+			typedef decltype(timer::start()) stop_watch;
+
+			/*!
+				@typedef nanoseconds
+				@brief A time (on a stop_watch) in nanoseconds.
+			*/
+			typedef decltype(timer::stop(timer::start()).nanoseconds()) nanoseconds;
+
+		public:
+			/*
+				QUANTIZE_INDEX::IS_KNOWN()
+				--------------------------
+			*/
+			/*!
+				@brief Is there a ranking function with the given name?
+				@param ranking_function [in] The name of the ranking function (e.g. "ATIRE_BM25").
+				@return true if quantize_and_serialise() understands this name, else false.
+			*/
+			static bool is_known(const std::string &ranking_function);
+
+			/*
+				QUANTIZE_INDEX::DESCRIBE_RANKING_FUNCTION()
+				-------------------------------------------
+			*/
+			/*!
+				@brief Describe a ranking function and its parameters (as recorded in the manifest), e.g. "ATIRE_BM25 k1=0.9 b=0.4".
+				@param ranking_function [in] The name of the ranking function (BM25F and uniCOIL are also described).
+				@param k1 [in] The k1 parameter of BM25 (and BM25F).
+				@param b [in] The b parameter of BM25 (and BM25F).
+				@param mu [in] The mu parameter of LM_Dirichlet.
+				@return The description.
+			*/
+			static std::string describe_ranking_function(const std::string &ranking_function, double k1, double b, double mu);
+
+			/*
+				QUANTIZE_INDEX::DESCRIBE()
+				--------------------------
+			*/
+			/*!
+				@brief Record how the index is being built (the ranking function, quantization, and stemmer) in the manifest that is written with the index.
+				@param manifest [out] The manifest to fill in.
+				@param ranking_function [in] The description of the ranking function (see describe_ranking_function()).
+				@param scheme [in] How the index is being quantized, or nullptr if the impacts are not quantized (they are already impacts).
+				@param stemmer [in] The name of the stemmer ("" if not known).
+			*/
+			static void describe(index_manifest &manifest, const std::string &ranking_function, const quantization_scheme *scheme, const std::string &stemmer);
+
+			/*
+				QUANTIZE_INDEX::QUANTIZE_AND_SERIALISE()
+				----------------------------------------
+			*/
+			/*!
+				@brief Quantize the index using the given ranking function then write it out.
+				@param ranker [in] The ranking function.
+				@param index [in] The index to quantize.
+				@param quantize_scores [in] If false then the index already holds impacts (e.g. uniCOIL) and they are written as they are.
+				@param documents [in] The number of documents in the collection.
+				@param exporters [in] The serialisers to write the index with.
+				@param jass_v3 [in] The JASS v3 serialiser (one of exporters) which is given the quantization scheme, or nullptr if there isn't one.
+				@param save_quantization [in] Write the quantization scheme to CIquantization.txt (before the index is written, so that it can be checksummed).
+				@param scheme [in / out] How to quantize, on return this includes the bounds of the scores.
+				@param timer [in] The timer.
+				@param time_to_end_quantization [out] The time (on timer) at which quantization finished.
+			*/
+			template <typename RANKER>
+			static void quantize_and_serialise(std::shared_ptr<RANKER> ranker, index_manager &index, bool quantize_scores, size_t documents, std::vector<std::unique_ptr<index_manager::delegate>> &exporters, serialise_jass_v3 *jass_v3, bool save_quantization, quantization_scheme &scheme, const stop_watch &timer, nanoseconds &time_to_end_quantization)
+				{
+				std::unique_ptr<quantize<RANKER>> quantizer;
+				if (!quantize_scores)
+					quantizer = std::make_unique<quantize_none<RANKER>>(documents, ranker);
+				else
+					{
+					quantizer = std::make_unique<quantize<RANKER>>(documents, ranker, scheme);
+					index.iterate(*quantizer);
+					}
+
+				time_to_end_quantization = timer::stop(timer).nanoseconds();
+
+				if (exporters.size() != 0)
+					{
+					/*
+						The JASS v3 index holds the quantization scheme so it must have it before it is written
+					*/
+					quantizer->complete_scheme();
+					if (jass_v3 != nullptr && quantize_scores)
+						jass_v3->add_section(serialise_jass_v3::QUANTIZATION, quantizer->get_scheme().serialise());
+
+					/*
+						Record the quantization scheme and bounds so that impacts can be mapped back to scores, and so that indexes can later be merged onto a common scale.
+					*/
+					if (save_quantization)
+						file::write_entire_file("CIquantization.txt", quantizer->get_scheme().serialise());
+
+					quantizer->serialise_index(index, exporters);
+					}
+
+				scheme = quantizer->get_scheme();
+				}
+
+			/*
+				QUANTIZE_INDEX::QUANTIZE_AND_SERIALISE()
+				----------------------------------------
+			*/
+			/*!
+				@brief Quantize the index using the named ranking function then write it out.
+				@param ranking_function [in] The name of the ranking function (see is_known(), ATIRE_BM25 is used if it is not known).
+				@param k1 [in] The k1 parameter of BM25.
+				@param b [in] The b parameter of BM25.
+				@param mu [in] The mu parameter of LM_Dirichlet.
+				@param index [in] The index to quantize.
+				@param documents [in] The number of documents in the collection.
+				@param exporters [in] The serialisers to write the index with.
+				@param jass_v3 [in] The JASS v3 serialiser (one of exporters) which is given the quantization scheme, or nullptr if there isn't one.
+				@param save_quantization [in] Write the quantization scheme to CIquantization.txt (before the index is written, so that it can be checksummed).
+				@param scheme [in / out] How to quantize, on return this includes the bounds of the scores.
+				@param timer [in] The timer.
+				@param time_to_end_quantization [out] The time (on timer) at which quantization finished.
+			*/
+			static void quantize_and_serialise(const std::string &ranking_function, double k1, double b, double mu, index_manager &index, size_t documents, std::vector<std::unique_ptr<index_manager::delegate>> &exporters, serialise_jass_v3 *jass_v3, bool save_quantization, quantization_scheme &scheme, const stop_watch &timer, nanoseconds &time_to_end_quantization);
+
+			/*
+				QUANTIZE_INDEX::UNITTEST()
+				--------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
@@ -13,9 +13,11 @@
 #pragma once
 
 #include <limits>
+#include <algorithm>
 
 #include <immintrin.h>
 
+#include "asserts.h"
 #include "forceinline.h"
 #include "parser_query.h"
 #include "query_term_list.h"
@@ -34,16 +36,10 @@ namespace JASS
 	class query
 		{
 		public:
-//			typedef uint32_t ACCUMULATOR_TYPE;									///< the type of an accumulator (probably a uint16_t)
-//			typedef uint16_t ACCUMULATOR_TYPE;									///< the type of an accumulator (probably a uint16_t)
-			typedef uint8_t ACCUMULATOR_TYPE;									///< the type of an accumulator (probably a uint16_t)
+			typedef uint32_t ACCUMULATOR_TYPE;									///< the type of an rsv passed to and from a query object (the accumulators themselves might be narrower)
+			typedef uint8_t DEFAULT_ACCUMULATOR_TYPE;							///< the type of an accumulator unless otherwise specified (and always for query_simple and query_block_max)
 			typedef uint32_t DOCID_TYPE;										///< the type of a document id (from a compressor)
 
-		public:
-			static constexpr size_t MAX_DOCUMENTS = 200'000'000;					///< the maximum number of documents an index can hold
-			static constexpr size_t MAX_TOP_K = 1'000;							///< the maximum top-k value
-			static constexpr size_t MAX_RSV = (std::numeric_limits<ACCUMULATOR_TYPE>::max)();
-
 		public:
 			/*
 				CLASS QUERY::PRINTER
@@ -86,6 +82,9 @@ namespace JASS
 			query_term_list *parsed_query;											///< The parsed query
 			const std::vector<std::string> *primary_keys;						///< A vector of strings, each the primary key for the document with an id equal to the vector index
 			compress_integer &codex;													///< The decompressor to use.
+			std::vector<uint64_t> term_matches;										///< For boolean queries, bit n of each document's entry is set if it contains term n (after apply_constraint(), non-zero if the document satisfies the query)
+			std::vector<DOCID_TYPE> touched_documents;								///< For boolean queries, the documents whose entry in term_matches is non-zero (only they are evaluated and cleared)
+			bool constrained;																///< True if only documents that satisfy the boolean query may be scored
 
 		public:
 			DOCID_TYPE top_k;																	///< The number of results to track.
@@ -105,6 +104,7 @@ namespace JASS
 				parsed_query(nullptr),
 				primary_keys(nullptr),
 				codex(codex),
+				constrained(false),
 				top_k(0)
 				{
 				/*	 Nothing */
@@ -118,15 +118,15 @@ namespace JASS
 				@brief Initialise the object. MUST be called before first use.
 				@param primary_keys [in] Vector of the document primary keys used to convert from internal document ids to external primary keys.
 				@param documents [in] The number of documents in the collection.
-				@param top_k [in]	The top-k documents to return from the query once executed.
+				@param top_k [in]	The top-k documents to return from the query once executed (at most documents are returned).
 				@param width [in] The width of the 2-d accumulators (if they are being used).
 			*/
 			virtual void init(const std::vector<std::string> &primary_keys, DOCID_TYPE documents = 1024, DOCID_TYPE top_k = 10, size_t width = 7)
 				{
 				this->primary_keys = &primary_keys;
-				this->top_k = top_k;
+				this->top_k = (std::min)(top_k, documents);
 				this->documents = documents;
-				decompress_buffer.resize(64 + (documents * sizeof(DOCID_TYPE) + sizeof(decompress_buffer[0]) - 1) / sizeof(decompress_buffer[0]));			// we add 64 so that decompressors can overflow
+				decompress_buffer.resize(256 + (documents * sizeof(DOCID_TYPE) + sizeof(decompress_buffer[0]) - 1) / sizeof(decompress_buffer[0]));			// we add 256 so that decompressors can overflow (Simple-8b and the 256-bit bitpacker decode up to 240 and 256 integers at a time)
 				rewind(1, 1, 1);
 				}
 
@@ -150,11 +150,72 @@ namespace JASS
 				@brief Take the given query and parse it.
 				@tparam STRING_TYPE Either a std::string or JASS::string.
 				@param query [in] The query to parse.
+				@param which_parser [in] Which parser to use (see parser_query::parser_type).
+				@param stemmer [in] If not nullptr then each query term is stemmed with this stemmer.
+				@param stopper [in] If not nullptr then query terms in this list of stop words are dropped.
 			*/
 			template <typename STRING_TYPE>
-			void parse(const STRING_TYPE &query, parser_query::parser_type which_parser = parser_query::parser_type::query)
+			void parse(const STRING_TYPE &query, parser_query::parser_type which_parser = parser_query::parser_type::query, stem *stemmer = nullptr, const stop_words *stopper = nullptr)
+				{
+				parser.parse(*parsed_query, query, which_parser, stemmer, stopper);
+				constrained = false;
+
+				/*
+					Clear the matches from the previous boolean query (only the documents it touched)
+				*/
+				for (const auto document_id : touched_documents)
+					term_matches[document_id] = 0;
+				touched_documents.clear();
+
+				if (parsed_query->is_boolean() && term_matches.size() != documents)
+					term_matches.assign(documents, 0);
+				}
+
+			/*
+				QUERY::MARK_TERM()
+				------------------
+			*/
+			/*!
+				@brief For a boolean query, decompress a postings segment and record (but do not score) that each of the documents contains the given term.
+				@param term_number [in] The position of the term in the term list (see terms()).
+				@param integers [in] The number of integers that are compressed.
+				@param compressed [in] The compressed sequence.
+				@param compressed_size [in] The length of the compressed sequence.
+				@details A boolean query has no more than query_term_list::max_boolean_terms terms (see query_term_list::has_too_many_boolean_terms()).
+			*/
+			void mark_term(size_t term_number, size_t integers, const void *compressed, size_t compressed_size)
+				{
+				JASS_assert(term_number < query_term_list::max_boolean_terms);
+
+				DOCID_TYPE *buffer = reinterpret_cast<DOCID_TYPE *>(decompress_buffer.data());
+				codex.decode(buffer, integers, compressed, compressed_size);
+
+				uint64_t bit = 1ULL << term_number;
+				DOCID_TYPE id = 0;
+				DOCID_TYPE *end = buffer + integers;
+				for (auto *current = buffer; current < end; current++)
+					{
+					id += *current;
+					if (term_matches[id] == 0)
+						touched_documents.push_back(id);
+					term_matches[id] |= bit;
+					}
+				}
+
+			/*
+				QUERY::APPLY_CONSTRAINT()
+				-------------------------
+			*/
+			/*!
+				@brief For a boolean query, once all the terms have been marked (see mark_term()) work out which documents satisfy the query.
+				From here on only those documents are scored.  This must be called before rewind().  Only the documents that contain at least one
+				of the terms are evaluated (the others have nothing to score them and so are never returned).
+			*/
+			void apply_constraint(void)
 				{
-				parser.parse(*parsed_query, query, which_parser);
+				for (const auto document_id : touched_documents)
+					term_matches[document_id] = parsed_query->matches(term_matches[document_id]) ? 1 : 0;
+				constrained = true;
 				}
 
 			/*
@@ -207,6 +268,56 @@ namespace JASS
 				impact = 0;
 				}
 
+			/*
+				QUERY::GET_MAX_RSV()
+				--------------------
+			*/
+			/*!
+				@brief Return the largest rsv an accumulator can hold before it overflows.
+				@return The largest value that can be stored in an accumulator.
+			*/
+			virtual ACCUMULATOR_TYPE get_max_rsv(void) const = 0;
+
+			/*
+				QUERY::REMOVE_UNMATCHED()
+				-------------------------
+			*/
+			/*!
+				@brief If the query is constrained (see apply_constraint()) then remove the documents that don't satisfy the query from a (d1-decoded) postings list.
+				@param buffer [in, out] The document ids.
+				@param integers [in] The number of document ids in the buffer.
+				@return The number of document ids left in the buffer.
+			*/
+			size_t remove_unmatched(DOCID_TYPE *buffer, size_t integers)
+				{
+				if (!constrained)
+					return integers;
+
+				DOCID_TYPE *into = buffer;
+				DOCID_TYPE *end = buffer + integers;
+				for (auto *current = buffer; current < end; current++)
+					if (term_matches[*current] != 0)
+						*into++ = *current;
+
+				return into - buffer;
+				}
+
+			/*
+				QUERY::IS_TOP_K_SETTLED()
+				-------------------------
+			*/
+			/*!
+				@brief Safe early termination.  Is it the case that no matter what happens to the scores from here on, the top-k (and the order of the top-k) will not change?
+				@details This is used in score-at-a-time processing where the largest amount any document's score can still grow by is known.  An accumulator
+				manager that cannot answer this question returns false (and so processing never stops early).
+				@param largest_possible_increase [in] The largest amount any document's rsv can still increase by.
+				@return true if the top-k (and its order) cannot change, else false.
+			*/
+			virtual bool is_top_k_settled(ACCUMULATOR_TYPE largest_possible_increase)
+				{
+				return false;
+				}
+
 			/*
 				QUERY::SET_IMPACT()
 				-------------------
@@ -31,14 +31,15 @@ namespace JASS
 	class query_block_max : public query
 		{
 		private:
-			typedef pointer_box<ACCUMULATOR_TYPE> accumulator_pointer;
+			typedef DEFAULT_ACCUMULATOR_TYPE ELEMENT;								///< The type of an accumulator
+			typedef pointer_box<ELEMENT> accumulator_pointer;
 
 		private:
-			accumulator_block_max<ACCUMULATOR_TYPE, MAX_DOCUMENTS> accumulators;			///< The accumulators, one per document in the collection
+			accumulator_block_max<ELEMENT> accumulators;										///< The accumulators, one per document in the collection
 			bool sorted;																	///< Has the top-k been generates (false after rewind() true after sort())
 			docid_rsv_pair next_result;												///< A single result, used but get_first() and get_next()
 			DOCID_TYPE next_result_location;											///< Used by get_first() and get_next() to determine which result is next
-			accumulator_pointer accumulator_pointers[MAX_TOP_K];				///< Array of pointers to the top k accumulators
+			std::vector<accumulator_pointer> accumulator_pointers;				///< Array of pointers to the top k accumulators
 			heap<accumulator_pointer> top_results;									///< Heap containing the top-k results
 			DOCID_TYPE needed_for_top_k;												///< The number of results we still need in order to fill the top-k
 
@@ -52,7 +53,8 @@ namespace JASS
 			*/
 			query_block_max(compress_integer &codex) :
 				query(codex),
-				top_results(accumulator_pointers, top_k)
+				accumulator_pointers(1),
+				top_results(accumulator_pointers.data(), top_k)
 				{
 				rewind();
 				}
@@ -84,7 +86,8 @@ namespace JASS
 				{
 				query::init(primary_keys, documents, top_k);
 				accumulators.init(documents, width);
-				top_results.set_top_k(top_k);
+				accumulator_pointers.resize((std::max)(this->top_k, (DOCID_TYPE)1));
+				top_results = heap<accumulator_pointer>(accumulator_pointers.data(), this->top_k);
 				}
 
 			/*
@@ -140,6 +143,19 @@ namespace JASS
 				query::rewind(largest_possible_rsv);
 				}
 
+			/*
+				QUERY_BLOCK_MAX::GET_MAX_RSV()
+				------------------------------
+			*/
+			/*!
+				@brief Return the largest rsv an accumulator can hold before it overflows.
+				@return The largest value that can be stored in an accumulator.
+			*/
+			virtual ACCUMULATOR_TYPE get_max_rsv(void) const
+				{
+				return (std::numeric_limits<ELEMENT>::max)();
+				}
+
 			/*
 				QUERY_BLOCK_MAX::SORT()
 				-----------------------
@@ -160,10 +176,10 @@ namespace JASS
 						Here we scan the block looking for values that need to be inserted into the top-k heap.  If a
 						block max score is less than the bottom of the heap we can skip the block, thus avoiding a full scan
 					*/
-					ACCUMULATOR_TYPE bottom_of_heap = 0;
-					ACCUMULATOR_TYPE *which_accumulator = accumulators.accumulator;
-					ACCUMULATOR_TYPE *which_block = accumulators.block_max;
-					ACCUMULATOR_TYPE *end = accumulators.block_max + accumulators.number_of_blocks;
+					ELEMENT bottom_of_heap = 0;
+					ELEMENT *which_accumulator = accumulators.accumulator.data();
+					ELEMENT *which_block = accumulators.block_max.data();
+					ELEMENT *end = accumulators.block_max.data() + accumulators.number_of_blocks;
 					while (which_block < end)
 						{
 						if (*which_block > bottom_of_heap)
@@ -171,8 +187,8 @@ namespace JASS
 							/*
 								There's a score in this block that's larger than the bottom of the heap, so a potential candidate
 							*/
-							ACCUMULATOR_TYPE *current_accumulator = which_accumulator;
-							ACCUMULATOR_TYPE *end_accumulator = which_accumulator + accumulators.width;
+							ELEMENT *current_accumulator = which_accumulator;
+							ELEMENT *end_accumulator = which_accumulator + accumulators.width;
 							while (current_accumulator < end_accumulator)
 								{
 								if (*current_accumulator > bottom_of_heap)
@@ -214,7 +230,7 @@ namespace JASS
 					/*
 						Now sort the heap array to get the answers in rank order.
 					*/
-					top_k_qsort::sort(accumulator_pointers + needed_for_top_k, top_k - needed_for_top_k, top_k);
+					top_k_qsort::sort(accumulator_pointers.data() + needed_for_top_k, top_k - needed_for_top_k, top_k);
 					sorted = true;
 					}
 				}
@@ -252,6 +268,7 @@ namespace JASS
 					D1-decode inplace with SIMD instructions then process one at a time
 				*/
 				simd::cumulative_sum_256(buffer, integers);
+				integers = remove_unmatched(buffer, integers);
 
 				/*
 					Process the d1-decoded postings list.  We ask the compiler to unroll the loop as it
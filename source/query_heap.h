@@ -33,18 +33,21 @@ namespace JASS
 	class query_heap : public query
 		{
 		private:
-			typedef pointer_box<ACCUMULATOR_TYPE> accumulator_pointer;
+			typedef typename ACCUMULATOR_ARRAY::value_type ELEMENT;			///< The type of an accumulator (which might be narrower than ACCUMULATOR_TYPE)
+			typedef pointer_box<ELEMENT> accumulator_pointer;
 
 		private:
 			ACCUMULATOR_ARRAY accumulators;											///< The accumulators, one per document in the collection
-			DOCID_TYPE needed_for_top_k;												///< The number of results we still need in order to fill the top-k
-			ACCUMULATOR_TYPE zero;														///< Constant zero used for pointer dereferenced comparisons
-			accumulator_pointer accumulator_pointers[MAX_TOP_K];				///< Array of pointers to the top k accumulators
+			DOCID_TYPE heap_size;														///< The heap holds one more than the top-k so that is_top_k_settled() knows the best score outside the top-k
+			DOCID_TYPE needed_for_top_k;												///< The number of results we still need in order to fill the heap
+			ELEMENT zero;																	///< Constant zero used for pointer dereferenced comparisons
+			std::vector<accumulator_pointer> accumulator_pointers;				///< Array of pointers to the top k accumulators
 			heap<accumulator_pointer> top_results;									///< Heap containing the top-k results
 			bool sorted;																	///< has heap and accumulator_pointers been sorted (false after rewind() true after sort())
-			ACCUMULATOR_TYPE top_k_lower_bound;										///< Lowest possible score to enter the top k
+			ELEMENT top_k_lower_bound;													///< Lowest possible score to enter the top k
 			docid_rsv_pair next_result;												///< A single result, used but get_first() and get_next()
 			DOCID_TYPE next_result_location;											///< Used by get_first() and get_next() to determine which result is next
+			std::vector<ELEMENT> settled_scores;										///< Scratch space used by is_top_k_settled()
 
 		public:
 			/*
@@ -56,8 +59,10 @@ namespace JASS
 			*/
 			query_heap(compress_integer &codex) :
 				query(codex),
+				heap_size(1),
 				zero(0),
-				top_results(accumulator_pointers, top_k)
+				accumulator_pointers(1),
+				top_results(accumulator_pointers.data(), heap_size)
 				{
 				rewind();
 				}
@@ -89,7 +94,10 @@ namespace JASS
 				{
 				query::init(primary_keys, documents, top_k);
 				accumulators.init(documents, width);
-				top_results.set_top_k(top_k);
+				heap_size = this->top_k + 1;
+				accumulator_pointers.resize(heap_size);
+				top_results = heap<accumulator_pointer>(accumulator_pointers.data(), heap_size);
+				rewind();
 				}
 
 			/*
@@ -117,10 +125,10 @@ namespace JASS
 			*/
 			virtual docid_rsv_pair *get_next(void)
 				{
-				if (next_result_location >= top_k - needed_for_top_k)
+				if (next_result_location >= (std::min)(top_k, heap_size - needed_for_top_k))
 					return NULL;
 
-				size_t id = accumulators.get_index(accumulator_pointers[top_k - next_result_location - 1].pointer());
+				size_t id = accumulators.get_index(accumulator_pointers[heap_size - next_result_location - 1].pointer());
 				next_result.document_id = id;
 				next_result.primary_key = &((*primary_keys)[id]);
 				next_result.rsv = accumulators.get_value(id);
@@ -143,11 +151,67 @@ namespace JASS
 				zero = 0;
 				accumulator_pointers[0] = &zero;
 				accumulators.rewind();
-				needed_for_top_k = this->top_k;
-				this->top_k_lower_bound = top_k_lower_bound;
+				needed_for_top_k = heap_size;
+				this->top_k_lower_bound = static_cast<ELEMENT>(top_k_lower_bound);
 				query::rewind(largest_possible_rsv);
 				}
 
+			/*
+				QUERY_HEAP::GET_MAX_RSV()
+				-------------------------
+			*/
+			/*!
+				@brief Return the largest rsv an accumulator can hold before it overflows.
+				@return The largest value that can be stored in an accumulator.
+			*/
+			virtual ACCUMULATOR_TYPE get_max_rsv(void) const
+				{
+				return (std::numeric_limits<ELEMENT>::max)();
+				}
+
+			/*
+				QUERY_HEAP::IS_TOP_K_SETTLED()
+				------------------------------
+			*/
+			/*!
+				@brief Safe early termination.  Is it the case that no matter what happens to the scores from here on, the top-k (and the order of the top-k) will not change?
+				@details The top-k is settled when the k-th best score can't be reached by the best score outside the top-k, and each score in the top-k
+				can't be reached by the score below it.  Both tests are strict so that ties (broken on document id) can't change the order either.
+				@param largest_possible_increase [in] The largest amount any document's rsv can still increase by.
+				@return true if the top-k (and its order) cannot change, else false.
+			*/
+			virtual bool is_top_k_settled(ACCUMULATOR_TYPE largest_possible_increase)
+				{
+				/*
+					If fewer than top-k documents have been seen then a document we haven't seen can still enter the top-k
+				*/
+				if (needed_for_top_k > 1)
+					return false;
+
+				/*
+					The heap root is the best score outside the top-k (or there isn't one yet)
+				*/
+				uint64_t best_outside = needed_for_top_k == 0 ? *accumulator_pointers[0] : 0;
+				settled_scores.clear();
+				for (DOCID_TYPE which = 1; which < heap_size; which++)
+					{
+					ELEMENT score = *accumulator_pointers[which];
+					if (score <= best_outside + largest_possible_increase)
+						return false;
+					settled_scores.push_back(score);
+					}
+
+				/*
+					The order can't change if no two adjacent scores are within largest_possible_increase of each other
+				*/
+				std::sort(settled_scores.begin(), settled_scores.end());
+				for (size_t which = 1; which < settled_scores.size(); which++)
+					if (settled_scores[which] <= settled_scores[which - 1] + (uint64_t)largest_possible_increase)
+						return false;
+
+				return true;
+				}
+
 			/*
 				QUERY_HEAP::SORT()
 				------------------
@@ -160,7 +224,7 @@ namespace JASS
 				if (!sorted)
 					{
 //					std::partial_sort(accumulator_pointers + needed_for_top_k, accumulator_pointers + top_k, accumulator_pointers + top_k);
-					top_k_qsort::sort(accumulator_pointers + needed_for_top_k, top_k - needed_for_top_k, top_k);
+					top_k_qsort::sort(accumulator_pointers.data() + needed_for_top_k, heap_size - needed_for_top_k, heap_size);
 					sorted = true;
 					}
 				}
@@ -172,10 +236,11 @@ namespace JASS
 			/*!
 				@brief Add weight to the rsv for document document_id
 				@param document_id [in] which document to increment
-				@param score [in] the amount of weight to add
+				@param rsv [in] the amount of weight to add
 			*/
-			forceinline void add_rsv(DOCID_TYPE document_id, ACCUMULATOR_TYPE score)
+			forceinline void add_rsv(DOCID_TYPE document_id, ACCUMULATOR_TYPE rsv)
 				{
+				ELEMENT score = static_cast<ELEMENT>(rsv);								/* do the arithmetic at the width of the accumulators */
 				accumulator_pointer which = &accumulators[document_id];			/* This will create the accumulator if it doesn't already exist. */
 				*which.pointer() += score;
 				/*
@@ -249,6 +314,7 @@ namespace JASS
 					D1-decode inplace with SIMD instructions then process one at a time
 				*/
 				simd::cumulative_sum_256(buffer, integers);
+				integers = remove_unmatched(buffer, integers);
 
 				/*
 					Process the d1-decoded postings list.  We ask the compiler to unroll the loop as it
@@ -299,6 +365,28 @@ namespace JASS
 					string << "<" << rsv->document_id << "," << (uint32_t)rsv->rsv << ">";
 				JASS_assert(string.str() == "<3,20><1,15>");
 
+				/*
+					Check safe early termination: the top-2 is <3,20><1,15> and the best outside is <2,12>
+				*/
+				query_object->rewind();
+				query_object->add_rsv(2, 10);
+				query_object->add_rsv(3, 20);
+				query_object->add_rsv(2, 2);
+				query_object->add_rsv(1, 1);
+				query_object->add_rsv(1, 14);
+				JASS_assert(query_object->is_top_k_settled(0));
+				JASS_assert(query_object->is_top_k_settled(2));
+				JASS_assert(!query_object->is_top_k_settled(3));
+				JASS_assert(!query_object->is_top_k_settled(5));
+
+				/*
+					Check that the accumulators are the width they claim to be (narrow accumulators wrap)
+				*/
+				query_object->rewind();
+				query_object->add_rsv(2, 200);
+				query_object->add_rsv(2, 200);
+				JASS_assert(query_object->get_first()->rsv == (query_object->get_max_rsv() >= 400 ? 400 : 400 % (query_object->get_max_rsv() + 1)));
+
 				/*
 					Check the parser
 				*/
@@ -315,6 +403,32 @@ namespace JASS
 						JASS_assert(term.token() == "three");
 					}
 
+				/*
+					Check boolean queries: "+one -two" with "one" in documents 1 and 3, and "two" in document 3
+				*/
+				uint8_t postings[16];
+				DOCID_TYPE one[] = {1, 2};
+				DOCID_TYPE two[] = {3};
+				DOCID_TYPE matching[] = {1, 2, 3};
+				query_object->rewind();
+				query_object->parse(std::string("+one -two"));
+				JASS_assert(query_object->terms().is_boolean());
+				query_object->mark_term(0, 2, postings, codex.encode(postings, sizeof(postings), one, 2));
+				query_object->mark_term(1, 1, postings, codex.encode(postings, sizeof(postings), two, 1));
+				query_object->apply_constraint();
+				JASS_assert(query_object->remove_unmatched(matching, 3) == 1 && matching[0] == 1);
+
+				/*
+					The next boolean query must not see the documents matched by the previous one: "one OR two" with "two" in document 2
+				*/
+				DOCID_TYPE two_again[] = {2};
+				DOCID_TYPE all[] = {1, 2, 3};
+				query_object->rewind();
+				query_object->parse(std::string("one OR two"));
+				query_object->mark_term(1, 1, postings, codex.encode(postings, sizeof(postings), two_again, 1));
+				query_object->apply_constraint();
+				JASS_assert(query_object->remove_unmatched(all, 3) == 1 && all[0] == 2);
+
 				puts("query_heap::PASSED");
 				}
 		};
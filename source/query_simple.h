@@ -30,10 +30,11 @@ namespace JASS
 	class query_simple : public query
 		{
 		private:
+			typedef DEFAULT_ACCUMULATOR_TYPE ELEMENT;								///< The type of an accumulator
 
 		private:
-			ACCUMULATOR_TYPE accumulator[MAX_DOCUMENTS];							///< The accumulators, one per document in the collection
-			ACCUMULATOR_TYPE *accumulator_pointer[MAX_DOCUMENTS];				///< Array of pointers to the accumulators
+			std::vector<ELEMENT> accumulator;											///< The accumulators, one per document in the collection
+			std::vector<ELEMENT *> accumulator_pointer;								///< Array of pointers to the accumulators
 			bool sorted;																	///< Has accumulator_pointer been sorted (false after rewind() true after sort())
 			docid_rsv_pair next_result;												///< A single result, used but get_first() and get_next()
 			DOCID_TYPE next_result_location;											///< Used by get_first() and get_next() to determine which result is next
@@ -77,6 +78,8 @@ namespace JASS
 			*/
 			virtual void init(const std::vector<std::string> &primary_keys, DOCID_TYPE documents = 1024, DOCID_TYPE top_k = 10, size_t width = 7)
 				{
+				accumulator.resize(documents);
+				accumulator_pointer.resize(documents);
 				query::init(primary_keys, documents, top_k);
 
 				for (DOCID_TYPE which = 0; which < documents; which++)
@@ -111,7 +114,7 @@ namespace JASS
 				if (next_result_location >= top_k)
 					return NULL;
 
-				size_t id = accumulator_pointer[next_result_location] - accumulator;
+				size_t id = accumulator_pointer[next_result_location] - accumulator.data();
 				next_result.document_id = id;
 				next_result.primary_key = &((*primary_keys)[id]);
 				next_result.rsv = accumulator[id];
@@ -132,7 +135,20 @@ namespace JASS
 				{
 				sorted = false;
 				query::rewind(largest_possible_rsv);
-				::memset(accumulator, 0, documents * sizeof(*accumulator));
+				::memset(accumulator.data(), 0, documents * sizeof(accumulator[0]));
+				}
+
+			/*
+				QUERY_SIMPLE::GET_MAX_RSV()
+				---------------------------
+			*/
+			/*!
+				@brief Return the largest rsv an accumulator can hold before it overflows.
+				@return The largest value that can be stored in an accumulator.
+			*/
+			virtual ACCUMULATOR_TYPE get_max_rsv(void) const
+				{
+				return (std::numeric_limits<ELEMENT>::max)();
 				}
 
 			/*
@@ -145,8 +161,8 @@ namespace JASS
 			virtual void sort(void)
 				{
 				if (!sorted)
-					std::partial_sort(accumulator_pointer, accumulator_pointer + top_k, accumulator_pointer + documents,
-						[](ACCUMULATOR_TYPE *a, ACCUMULATOR_TYPE *b) -> bool
+					std::partial_sort(accumulator_pointer.begin(), accumulator_pointer.begin() + top_k, accumulator_pointer.begin() + documents,
+						[](ELEMENT *a, ELEMENT *b) -> bool
 						{
 						if (*a > *b)
 							return true;
@@ -191,6 +207,7 @@ namespace JASS
 					D1-decode inplace with SIMD instructions then process one at a time
 				*/
 				simd::cumulative_sum_256(buffer, integers);
+				integers = remove_unmatched(buffer, integers);
 
 				/*
 					Process the d1-decoded postings list.  We ask the compiler to unroll the loop as it
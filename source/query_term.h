@@ -39,6 +39,8 @@ namespace JASS
 		private:
 			slice term;						///< The term.  Note that the memory is kept elsewhere
 			size_t query_frequency;			///< Number of times the term occurs in the query
+			bool prohibited;					///< True if every occurrence of the term is negated (-term) and so the term must not contribute to the score
+			double query_weight;				///< The impact score multiplier for this term (the sum of the weights of each occurrence, each being 1 unless given)
 
 		public:
 			/*
@@ -49,7 +51,9 @@ namespace JASS
 				@brief Constructor for an empty object.
 			*/
 			query_term() :
-				query_frequency(0)
+				query_frequency(0),
+				prohibited(false),
+				query_weight(0)
 				{
 				/* Nothing */
 				}
@@ -64,7 +68,9 @@ namespace JASS
 			*/
 			query_term(const query_term &original) :
 				term(original.term),
-				query_frequency(original.query_frequency)
+				query_frequency(original.query_frequency),
+				prohibited(original.prohibited),
+				query_weight(original.query_weight)
 				{
 				/* Nothing */
 				}
@@ -77,14 +83,17 @@ namespace JASS
 				@brief Constructor.
 				@param term [in] Term that this object reprrsents.
 				@param query_frequency [in] the number of times the term occurs in the query.
+				@param prohibited [in] true if the term is negated in the query (and so must not contribute to the score).
 				
 				@details Create a new query term object from a string and a frequency.  Node that the term slice is copied and that
 				the term is nod duplicated.  That is, the memory containing the query term belongs to the caller and not to this object.
 				This isn't a problem because ll memory associated with processing a query should be in a single allocator object.
 			*/
-			query_term(const slice &term, size_t query_frequency = 1) :
+			query_term(const slice &term, size_t query_frequency = 1, bool prohibited = false) :
 				term(term),
-				query_frequency(query_frequency)
+				query_frequency(query_frequency),
+				prohibited(prohibited),
+				query_weight(query_frequency)
 				{
 				/* Nothing */
 				}
@@ -131,6 +140,35 @@ namespace JASS
 				return query_frequency;
 				}
 
+			/*
+				QUERY_TERM::WEIGHT()
+				--------------------
+			*/
+			/*!
+				@brief Return the weight of this term in this query, which is used to multiply the impact scores.
+				@details Each occurrence of the term has a weight of 1 unless given (for example, term^1.5), and the weight of the term is the sum of these.
+				So for queries without weights this is the same as frequency().
+				@return The weight
+			*/
+			double weight() const
+				{
+				return query_weight;
+				}
+
+			/*
+				QUERY_TERM::IS_PROHIBITED()
+				---------------------------
+			*/
+			/*!
+				@brief Is this term only ever negated in the query (for example, "-term")?
+				@details Prohibited terms are used to eliminate documents and must not contribute to the score of a document.
+				@return true if the term is prohibited, else false
+			*/
+			bool is_prohibited() const
+				{
+				return prohibited;
+				}
+
 			/*
 				QUERY_TERM::OPERATOR<()
 				-----------------------
@@ -164,6 +202,9 @@ namespace JASS
 				JASS_assert(second.term.size() == 6);
 				JASS_assert(third.query_frequency == second.query_frequency);
 				JASS_assert(third.term.address() == second.term.address());
+				JASS_assert(!third.is_prohibited());
+				JASS_assert(third.weight() == 2);
+				JASS_assert(query_term(text, 1, true).is_prohibited());
 
 				JASS_assert(static_cast<std::string>(second) == std::string("(string,2)"));
 
@@ -13,6 +13,8 @@
 #pragma once
 
 #include <array>
+#include <string>
+#include <vector>
 #include <algorithm>
 
 #include "query_term.h"
@@ -32,12 +34,46 @@ namespace JASS
 		{
 		friend std::ostream &operator<<(std::ostream &stream, const query_term_list &object);
 
+		public:
+			static const size_t max_boolean_terms = 64;				///< A boolean query can have at most this many unique terms (one bit each in a uint64_t), a query with more is rejected (see has_too_many_boolean_terms()).
+
+			/*!
+				@enum boolean_operator
+				@brief The type of a node in a boolean query expression.
+			*/
+			enum boolean_operator
+				{
+				boolean_term,								///< A query term (a leaf of the query tree).
+				boolean_and,								///< Both of the top two operands must be true.
+				boolean_or,									///< Either of the top two operands must be true.
+				boolean_not									///< The top operand must be false.
+				};
+
+			/*
+				CLASS QUERY_TERM_LIST::BOOLEAN_NODE
+				-----------------------------------
+			*/
+			/*!
+				@brief A node in the boolean query expression, which is stored in postfix (reverse Polish) order.
+			*/
+			class boolean_node
+				{
+				public:
+					boolean_operator operation;			///< The operator (or boolean_term for a leaf).
+					slice term;									///< If a leaf then the term.
+					size_t term_number;						///< If a leaf then the position of the term in the (sort_unique()ed) term list.
+				};
+
 		private:
 			static const size_t max_query_terms = 0xFFFF;			///< We allow up-to this numnber of (not necessarily unique) terms in a query.
 
 		private:
 			size_t terms_in_query;								///< The numner of terms in this query.
 			query_term terms[max_query_terms];				///< The quey terms themselves.
+			std::vector<boolean_node> expression;			///< If this is a boolean query then the query tree in postfix order, else empty.
+			mutable std::vector<uint8_t> stack;				///< The evaluation stack used by matches().
+			std::vector<std::vector<slice>> phrases;		///< The phrases (quoted sequences of terms) in the query.
+			bool too_many_boolean_terms;						///< true if this is a boolean query with more than max_boolean_terms unique terms (and so it has been rejected).
 
 		public:
 			/*
@@ -48,7 +84,8 @@ namespace JASS
 				@brief Constructort
 			*/
 			query_term_list() :
-				terms_in_query(0)
+				terms_in_query(0),
+				too_many_boolean_terms(false)
 				{
 				/* Nothing */
 				}
@@ -120,17 +157,131 @@ namespace JASS
 			/*!
 				@brief Add a query term to the list.  This does not increase the term count if the term
 				has been seen before - call sort_unique() to do that.
+				@param term [in] The term to add.
+				@param prohibited [in] true if the term is negated in the query (see query_term::is_prohibited()).
+				@param weight [in] The weight of this occurrence of the term (see query_term::weight()).
 			*/
-			void push_back(const slice &term)
+			void push_back(const slice &term, bool prohibited = false, double weight = 1)
 				{
 				if (terms_in_query < max_query_terms)
 					{
 					terms[terms_in_query].term = term;
 					terms[terms_in_query].query_frequency = 1;
+					terms[terms_in_query].prohibited = prohibited;
+					terms[terms_in_query].query_weight = weight;
 					terms_in_query++;
 					}
 				}
 
+			/*
+				QUERY_TERM_LIST::SET_BOOLEAN_EXPRESSION()
+				-----------------------------------------
+			*/
+			/*!
+				@brief Make this a boolean query by giving it a query tree (in postfix order).  The term_number of each leaf is set by sort_unique().
+				@param postfix [in] The query tree in postfix order (the leaves must also have been added with push_back()).
+			*/
+			void set_boolean_expression(const std::vector<boolean_node> &postfix)
+				{
+				expression = postfix;
+				}
+
+			/*
+				QUERY_TERM_LIST::ADD_PHRASE()
+				-----------------------------
+			*/
+			/*!
+				@brief Add a phrase (a sequence of terms that must be adjacent in the document).  The terms must also have been added with push_back().
+				@param phrase [in] The terms of the phrase, in order.
+			*/
+			void add_phrase(const std::vector<slice> &phrase)
+				{
+				phrases.push_back(phrase);
+				}
+
+			/*
+				QUERY_TERM_LIST::GET_PHRASES()
+				------------------------------
+			*/
+			/*!
+				@brief Return the phrases in the query.
+				@return The phrases, each as the sequence of its terms.
+			*/
+			const std::vector<std::vector<slice>> &get_phrases(void) const
+				{
+				return phrases;
+				}
+
+			/*
+				QUERY_TERM_LIST::IS_BOOLEAN()
+				-----------------------------
+			*/
+			/*!
+				@brief Is this a boolean query (one with +, -, AND, OR, or brackets), or is it a bag of words?
+				@return true if the query has a boolean expression that a document must satisfy.
+			*/
+			bool is_boolean(void) const
+				{
+				return !expression.empty();
+				}
+
+			/*
+				QUERY_TERM_LIST::HAS_TOO_MANY_BOOLEAN_TERMS()
+				---------------------------------------------
+			*/
+			/*!
+				@brief Was this query rejected by sort_unique() because it is a boolean query with more than max_boolean_terms unique terms?
+				@details A rejected query has no terms (and so finds nothing), the caller should report it to the user.
+				@return true if the query was rejected, else false.
+			*/
+			bool has_too_many_boolean_terms(void) const
+				{
+				return too_many_boolean_terms;
+				}
+
+			/*
+				QUERY_TERM_LIST::MATCHES()
+				--------------------------
+			*/
+			/*!
+				@brief Does a document satisfy the boolean query?
+				@param terms_present [in] Bit n is set if the document contains the n-th term in the (sort_unique()ed) term list.
+				@return true if the document satisfies the query (always true if this is not a boolean query).
+			*/
+			bool matches(uint64_t terms_present) const
+				{
+				if (expression.empty())
+					return true;
+
+				stack.clear();
+				for (const auto &node : expression)
+					switch (node.operation)
+						{
+						case boolean_term:
+							stack.push_back((terms_present >> node.term_number) & 1);
+							break;
+						case boolean_and:
+							{
+							uint8_t right = stack.back();
+							stack.pop_back();
+							stack.back() &= right;
+							break;
+							}
+						case boolean_or:
+							{
+							uint8_t right = stack.back();
+							stack.pop_back();
+							stack.back() |= right;
+							break;
+							}
+						case boolean_not:
+							stack.back() ^= 1;
+							break;
+						}
+
+				return stack.back() != 0;
+				}
+
 			/*
 				QUERY_TERM_LIST::SORT_UNIQUE()
 				------------------------------
@@ -153,7 +304,11 @@ namespace JASS
 				while (from < terms_in_query)
 					{
 					if (terms[from].term == terms[to].term)
+						{
 						terms[to].query_frequency++;
+						terms[to].prohibited &= terms[from].prohibited;
+						terms[to].query_weight += terms[from].query_weight;
+						}
 					else
 						{
 						to++;
@@ -164,6 +319,22 @@ namespace JASS
 					}
 
 				terms_in_query = to + 1;
+
+				/*
+					If this is a boolean query then each leaf in the query tree needs to know where its term is in the list.  If there are
+					too many terms to fit into a bitmask then the query is rejected (it is emptied so that it finds nothing).
+				*/
+				if (!expression.empty() && terms_in_query > max_boolean_terms)
+					{
+					too_many_boolean_terms = true;
+					terms_in_query = 0;
+					expression.clear();
+					phrases.clear();
+					return;
+					}
+				for (auto &node : expression)
+					if (node.operation == boolean_term)
+						node.term_number = std::lower_bound(begin(), end(), query_term(node.term)) - begin();
 				}
 
 			/*
@@ -272,7 +443,86 @@ namespace JASS
 				JASS_assert(into.str() == "(a,2)(b,2)");
 				delete terms;
 				}
-				
+
+				/*
+					Weighted terms
+				*/
+				{
+				query_term_list *terms = new query_term_list;
+
+				terms->push_back("a", false, 1.5);
+				terms->push_back("b", false, 12);
+				terms->push_back("a");
+
+				terms->sort_unique();
+				JASS_assert(terms->begin()[0].frequency() == 2 && terms->begin()[0].weight() == 2.5);
+				JASS_assert(terms->begin()[1].frequency() == 1 && terms->begin()[1].weight() == 12);
+				delete terms;
+				}
+
+				/*
+					Boolean query: b AND NOT (a OR c)
+				*/
+				{
+				query_term_list *terms = new query_term_list;
+
+				terms->push_back("b");
+				terms->push_back("a", true);
+				terms->push_back("c", true);
+				terms->set_boolean_expression
+					({
+					{boolean_term, "b", 0},
+					{boolean_term, "a", 0},
+					{boolean_term, "c", 0},
+					{boolean_or, slice(), 0},
+					{boolean_not, slice(), 0},
+					{boolean_and, slice(), 0}
+					});
+				terms->sort_unique();
+				JASS_assert(terms->is_boolean());
+				JASS_assert(terms->begin()[0].is_prohibited());
+				JASS_assert(!terms->begin()[1].is_prohibited());
+				JASS_assert(terms->matches(0b010));
+				JASS_assert(!terms->matches(0b011));
+				JASS_assert(!terms->matches(0b110));
+				JASS_assert(!terms->matches(0b000));
+				JASS_assert(!terms->has_too_many_boolean_terms());
+				delete terms;
+				}
+
+				/*
+					A boolean query with too many terms is rejected, but a bag of words query with as many terms is not
+				*/
+				{
+				std::vector<std::string> words;
+				for (size_t word = 0; word <= max_boolean_terms; word++)
+					words.push_back("t" + std::to_string(word));
+
+				query_term_list *terms = new query_term_list;
+				std::vector<boolean_node> postfix;
+				for (const auto &word : words)
+					{
+					terms->push_back(slice(word.c_str()));
+					postfix.push_back({boolean_term, slice(word.c_str()), 0});
+					if (postfix.size() > 1)
+						postfix.push_back({boolean_and, slice(), 0});
+					}
+				terms->set_boolean_expression(postfix);
+				terms->sort_unique();
+				JASS_assert(terms->has_too_many_boolean_terms());
+				JASS_assert(!terms->is_boolean());
+				JASS_assert(terms->size() == 0);
+				delete terms;
+
+				terms = new query_term_list;
+				for (const auto &word : words)
+					terms->push_back(slice(word.c_str()));
+				terms->sort_unique();
+				JASS_assert(!terms->has_too_many_boolean_terms());
+				JASS_assert(terms->size() == max_boolean_terms + 1);
+				delete terms;
+				}
+
 				puts("query_term_list::PASSED");
 				}
 		};
@@ -19,6 +19,7 @@
 
 #include <vector>
 
+#include "slice.h"
 #include "forceinline.h"
 #include "compress_integer.h"
 #include "index_postings_impact.h"
@@ -85,6 +86,20 @@ namespace JASS
 				/* Nothing */
 				}
 
+			/*
+				RANKING_FUNCTION_ATIRE_BM25::COMPUTE_TERM_COMPONENT()
+				-----------------------------------------------------
+			*/
+			/*!
+				@brief Called once per term before compute_idf_component().  Not needed by this ranking function.
+				@param term [in] The term.
+				@param collection_frequency [in] The number of times the term occurs in the collection.
+			*/
+			forceinline void compute_term_component(const slice &term, uint64_t collection_frequency)
+				{
+				/* Nothing */
+				}
+
 			/*
 				RANKING_FUNCTION_ATIRE_BM25::COMPUTE_IDF_COMPONENT()
 				----------------------------------------------------
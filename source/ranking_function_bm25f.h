@@ -0,0 +1,288 @@
+/*
+	RANKING_FUNCTION_BM25F.H
+	------------------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief The BM25F ranking function (BM25 over weighted fields)
+	@details Robertson, Zaragoza, Taylor (2004) Simple BM25 Extension to Multiple Weighted Fields, CIKM 2004, pp. 42-49.  Each field (and the
+	body, the part of the document in no field) has its own length normalisation, and the normalised term frequencies are weighted and summed
+	before the BM25 saturation is applied.
+	@author Andrew Trotman
+	@copyright 2021 Andrew Trotman
+*/
+#pragma once
+
+#include <math.h>
+#include <stdio.h>
+#include <stdint.h>
+#include <string.h>
+
+#include <vector>
+
+#include "maths.h"
+#include "asserts.h"
+#include "forceinline.h"
+#include "index_manager.h"
+#include "compress_integer.h"
+#include "index_postings_impact.h"
+
+namespace JASS
+	{
+	/*
+		CLASS RANKING_FUNCTION_BM25F
+		----------------------------
+	*/
+	/*!
+		@brief The BM25F ranking function.
+		@details The per-field term frequencies and lengths come from the index_manager that was used to index the collection (see
+		index_manager::set_fields()), so this ranker must be used before that object is destroyed.  A field restricted term (e.g. title:jaguar)
+		is scored with BM25 using the length of that field.
+	*/
+	class ranking_function_bm25f
+		{
+		private:
+			index_manager &index;										///< The index (used to get the term frequencies in each field).
+			double idf;														///< the IDF of the term being processed
+			double k1;														///< The BM25 k1 parameter
+			std::vector<double> weight;								///< The weight of each field, weight[0] is the body.
+			std::vector<std::vector<float>> length_correction;	///< For each field (0 is the body) and document, (1 - b) + b * length / mean_length
+			std::vector<std::vector<index_postings_impact::impact_type>> field_frequency;	///< For each field (from 1) the term frequency of the current term in each document.
+			std::vector<compress_integer::integer> touched;		///< The documents that have a non-zero field_frequency (so they can be cleared).
+			size_t restricted_field;									///< If the current term is a field restricted term then the field, else 0.
+
+		private:
+			/*
+				RANKING_FUNCTION_BM25F::COMPUTE_LENGTH_CORRECTION()
+				---------------------------------------------------
+			*/
+			/*!
+				@brief Compute the length normalisation of each document for a field.
+				@param into [out] The length normalisation of each document.
+				@param lengths [in] The length of the field in each document (document 0 is not used).
+				@param b [in] The BM25 b parameter.
+			*/
+			static void compute_length_correction(std::vector<float> &into, const std::vector<compress_integer::integer> &lengths, double b)
+				{
+				uint64_t sum = 0;
+				for (auto length : lengths)
+					sum += length;
+				double mean_length = lengths.size() <= 1 ? 0 : static_cast<double>(sum) / static_cast<double>(lengths.size() - 1);			// -1 because ID 0 is not used (and should be 0)
+
+				into.resize(lengths.size());
+				for (size_t document = 0; document < lengths.size(); document++)
+					into[document] = mean_length == 0 ? 1.0 : (1.0 - b) + b * static_cast<double>(lengths[document]) / mean_length;
+				}
+
+		public:
+			/*
+				RANKING_FUNCTION_BM25F::RANKING_FUNCTION_BM25F()
+				------------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param k1 [in] the BM25 k1 parameter, 0.9 is a good value.
+				@param b [in] the BM25 b parameter, 0.4 is a good value.
+				@param index [in] the index (which must have been built with fields, see index_manager::set_fields()).
+				@param field_weights [in] the weight of each field (field_weights[n - 1] is the weight of field n), the body has a weight of 1.
+			*/
+			ranking_function_bm25f(double k1, double b, index_manager &index, const std::vector<double> &field_weights):
+				index(index),
+				idf(0),
+				k1(k1),
+				weight(1, 1.0),
+				length_correction(field_weights.size() + 1),
+				field_frequency(field_weights.size() + 1),
+				restricted_field(0)
+				{
+				weight.insert(weight.end(), field_weights.begin(), field_weights.end());
+
+				/*
+					The body is the part of the document not in any field
+				*/
+				std::vector<compress_integer::integer> body_lengths = index.get_document_length_vector();
+				for (size_t field = 1; field <= field_weights.size(); field++)
+					{
+					const auto &lengths = index.get_field_length_vector(field);
+					for (size_t document = 0; document < body_lengths.size(); document++)
+						body_lengths[document] -= maths::minimum(body_lengths[document], lengths[document]);
+
+					compute_length_correction(length_correction[field], lengths, b);
+					field_frequency[field].resize(lengths.size());
+					}
+				compute_length_correction(length_correction[0], body_lengths, b);
+				}
+
+			/*
+				RANKING_FUNCTION_BM25F::COMPUTE_TERM_COMPONENT()
+				------------------------------------------------
+			*/
+			/*!
+				@brief Called once per term.  Gets the term frequency of the term in each field of each document.
+				@param term [in] The term.
+				@param collection_frequency [in] The number of times the term occurs in the collection (not used).
+			*/
+			void compute_term_component(const slice &term, uint64_t collection_frequency)
+				{
+				/*
+					Forget the last term
+				*/
+				for (size_t field = 1; field < field_frequency.size(); field++)
+					for (auto document : touched)
+						field_frequency[field][document] = 0;
+				touched.clear();
+
+				/*
+					Is this a field restricted term?  If so then it is in one field only.
+				*/
+				restricted_field = 0;
+				const auto &names = index.get_fields();
+				for (size_t field = 1; field <= names.size(); field++)
+					if (term.size() > names[field - 1].size() && term[names[field - 1].size()] == index_manager::FIELD_SEPARATOR && memcmp(term.address(), names[field - 1].c_str(), names[field - 1].size()) == 0)
+						{
+						restricted_field = field;
+						return;
+						}
+
+				/*
+					Get the term frequencies in each field
+				*/
+				for (size_t field = 1; field < field_frequency.size(); field++)
+					{
+					compress_integer::integer *document_ids;
+					index_postings_impact::impact_type *term_frequencies;
+					compress_integer::integer document_frequency = index.get_field_postings(term, field, document_ids, term_frequencies);
+					for (compress_integer::integer which = 0; which < document_frequency; which++)
+						{
+						field_frequency[field][document_ids[which]] = term_frequencies[which];
+						touched.push_back(document_ids[which]);
+						}
+					}
+				}
+
+			/*
+				RANKING_FUNCTION_BM25F::COMPUTE_IDF_COMPONENT()
+				-----------------------------------------------
+			*/
+			/*!
+				@brief Called once per term.  Computes the IDF component of the ranking function and stores it internally
+				@param document_frequency [in] the number of documents that contain this term.
+				@param documents_in_collection [in] the number of documents in the collection.
+			*/
+			forceinline void compute_idf_component(compress_integer::integer document_frequency, compress_integer::integer documents_in_collection)
+				{
+				idf = log((double)documents_in_collection / (double)document_frequency);
+				}
+
+			/*
+				RANKING_FUNCTION_BM25F::COMPUTE_TF_COMPONENT()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Not used by BM25F as the term frequency is split across the fields (see compute_score()).
+				@param term_frequency [in] The number of times the term occurs in the document.
+			*/
+			forceinline void compute_tf_component(index_postings_impact::impact_type term_frequency)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_BM25F::COMPUTE_SCORE()
+				---------------------------------------
+			*/
+			/*!
+				@brief Compute BM25F from the given document, assuming compute_term_component() and compute_idf_component() have been called.
+				@param document_id [in] The ID of the document
+				@param term_frequency [in] The number of times the term occurs in the document.
+				@return The score
+			*/
+			forceinline double compute_score(compress_integer::integer document_id, index_postings_impact::impact_type term_frequency)
+				{
+				/*
+										 tf'                       tf(td, f)
+					rsv = ---------- * IDF     tf' = sum w(f) * -----------------------------
+							 k1 + tf'                    f           (1 - b + b * len(d, f) / av_len(f))
+
+					Where the body (the part of the document in no field) is field 0.
+				*/
+				double tf;
+				if (restricted_field != 0)
+					tf = weight[restricted_field] * term_frequency / length_correction[restricted_field][document_id];
+				else
+					{
+					double body_frequency = term_frequency;
+					tf = 0;
+					for (size_t field = 1; field < field_frequency.size(); field++)
+						{
+						double frequency = field_frequency[field][document_id];
+						body_frequency -= frequency;
+						tf += weight[field] * frequency / length_correction[field][document_id];
+						}
+					tf += weight[0] * body_frequency / length_correction[0][document_id];
+					}
+
+				return idf * (tf * (k1 + 1.0)) / (k1 + tf);
+				}
+
+			/*
+				RANKING_FUNCTION_BM25F::UNITTEST()
+				----------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void)
+				{
+				/*
+					Two documents each of length 4 with one field (the title) of length 2.  "jaguar" is in the title of the first and the body of the second.
+				*/
+				class index_test : public index_manager
+					{
+					public:
+						virtual compress_integer::integer get_field_postings(const slice &term, size_t field, compress_integer::integer *&document_ids, index_postings_impact::impact_type *&term_frequencies)
+							{
+							static compress_integer::integer ids[] = {1};
+							static index_postings_impact::impact_type frequencies[] = {1};
+							document_ids = ids;
+							term_frequencies = frequencies;
+							return term == slice("jaguar") ? 1 : 0;
+							}
+					};
+				index_test index;
+				index.set_fields({"title"});
+				for (const char *key : {"one", "two"})
+					{
+					index.begin_document(slice(key));
+					index.set_field(1);
+					index.count_field_term();
+					index.count_field_term();
+					index.end_document(4);
+					}
+
+				ranking_function_bm25f ranker(0.9, 0.4, index, {3.0});			// k1=0.9, b=0.4, title weight=3
+
+				/*
+					All lengths are average so tf' = 3 in the first and 1 in the second.  IDF = log(3/2).
+				*/
+				ranker.compute_term_component(slice("jaguar"), 2);
+				ranker.compute_idf_component(2, 3);
+				double in_title = ranker.compute_score(1, 1);
+				double in_body = ranker.compute_score(2, 1);
+				JASS_assert(fabs(in_title - log(1.5) * 3 * 1.9 / 3.9) < 0.0001);
+				JASS_assert(fabs(in_body - log(1.5) * 1 * 1.9 / 1.9) < 0.0001);
+				JASS_assert(in_title > in_body);
+
+				/*
+					A field restricted term is scored only in its field
+				*/
+				ranker.compute_term_component(slice("title:jaguar"), 1);
+				ranker.compute_idf_component(1, 3);
+				JASS_assert(fabs(ranker.compute_score(1, 1) - log(3.0) * 3 * 1.9 / 3.9) < 0.0001);
+
+				puts("ranking_function_bm25f::PASSED");
+				}
+		};
+	}
@@ -0,0 +1,164 @@
+/*
+	RANKING_FUNCTION_DPH.H
+	----------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief The DPH (Divergence From Randomness) ranking function
+	@details See: G. Amati, E. Ambrosi, M. Bianchi, C. Gaibisso, G. Gambosi (2007) FUB, IASI-CNR and University of Tor Vergata at TREC 2007 Blog Track.
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <math.h>
+#include <stdio.h>
+#include <stdint.h>
+
+#include <vector>
+
+#include "slice.h"
+#include "asserts.h"
+#include "forceinline.h"
+#include "compress_integer.h"
+#include "index_postings_impact.h"
+
+namespace JASS
+	{
+	/*
+		CLASS RANKING_FUNCTION_DPH
+		--------------------------
+	*/
+	/*!
+		@brief The parameter-free DPH ranking function, as computed by Terrier
+	*/
+	class ranking_function_dph
+		{
+		private:
+			double mean_document_length;							///< the mean of the document lengths
+			double collection_frequency;							///< the number of times the term being processed occurs in the collection
+			double documents_in_collection;						///< the number of documents in the collection
+			std::vector<compress_integer::integer> &document_lengths;	///< the length of each document
+
+		public:
+			/*
+				RANKING_FUNCTION_DPH::RANKING_FUNCTION_DPH()
+				--------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param document_lengths [in] a vector holding the length of each document in the collection.
+			*/
+			ranking_function_dph(std::vector<compress_integer::integer> &document_lengths):
+				mean_document_length(0),
+				collection_frequency(0),
+				documents_in_collection(0),
+				document_lengths(document_lengths)
+				{
+				uint64_t sum = 0;
+				for (auto length : document_lengths)
+					sum += length;
+
+				mean_document_length = static_cast<double>(sum) / static_cast<double>(document_lengths.size() - 1);			// -1 because ID 0 is not used (and should be 0)
+				}
+
+			/*
+				RANKING_FUNCTION_DPH::COMPUTE_TERM_COMPONENT()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Called once per term.  Stores the collection frequency of the term
+				@param term [in] The term.
+				@param collection_frequency [in] The number of times the term occurs in the collection.
+			*/
+			forceinline void compute_term_component(const slice &term, uint64_t collection_frequency)
+				{
+				this->collection_frequency = static_cast<double>(collection_frequency);
+				}
+
+			/*
+				RANKING_FUNCTION_DPH::COMPUTE_IDF_COMPONENT()
+				---------------------------------------------
+			*/
+			/*!
+				@brief Called once per term.  Stores the number of documents in the collection
+				@param document_frequency [in] the number of documents that contain this term.
+				@param documents_in_collection [in] the number of documents in the collection.
+			*/
+			forceinline void compute_idf_component(compress_integer::integer document_frequency, compress_integer::integer documents_in_collection)
+				{
+				this->documents_in_collection = static_cast<double>(documents_in_collection);
+				}
+
+			/*
+				RANKING_FUNCTION_DPH::COMPUTE_TF_COMPONENT()
+				--------------------------------------------
+			*/
+			/*!
+				@brief Not needed by this ranking function (see compute_score()).
+				@param term_frequency [in] The number of times the term occurs in the document.
+			*/
+			forceinline void compute_tf_component(index_postings_impact::impact_type term_frequency)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_DPH::COMPUTE_SCORE()
+				-------------------------------------
+			*/
+			/*!
+				@brief Compute DPH for the given document, assuming compute_term_component() and compute_idf_component() have already been called.
+				@param document_id [in] The ID of the document (used to look up the length)
+				@param term_frequency [in] The number of times the term occurs in the document.
+				@return The score
+			*/
+			forceinline double compute_score(compress_integer::integer document_id, index_postings_impact::impact_type term_frequency)
+				{
+				/*
+					f = tf / len(d)
+
+							  (1 - f)^2                 tf * av_len_d      N
+					rsv = --------- * (tf * log2(------------- * ---) + 0.5 * log2(2 * pi * tf * (1 - f)))
+								tf + 1                       len(d)       F
+				*/
+				double tf = term_frequency;
+				double length = document_lengths[document_id];
+				double f = tf / length;
+
+				if (f >= 1.0)
+					return 0;				// the document is only this term, which DPH can't score
+
+				double normalisation = (1.0 - f) * (1.0 - f) / (tf + 1.0);
+				double rsv = normalisation * (tf * log2((tf * mean_document_length / length) * (documents_in_collection / collection_frequency)) + 0.5 * log2(2.0 * M_PI * tf * (1.0 - f)));
+
+				return rsv > 0 ? rsv : 0;
+				}
+
+			/*
+				RANKING_FUNCTION_DPH::UNITTEST()
+				--------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void)
+				{
+				std::vector<compress_integer::integer> lengths{30, 40, 50, 60, 70};			// the lengths of the documents in this pseudo-index
+				ranking_function_dph ranker(lengths);
+
+				ranker.compute_term_component(slice("term"), 20);						// this term occurs 20 times in the collection
+				ranker.compute_idf_component(2, static_cast<uint32_t>(lengths.size()));			// this term occurs in 2 of 5 documents
+				ranker.compute_tf_component(12);									// it occurs in this document 12 times
+				double rsv = ranker.compute_score(1, 12);						// it occurs in document 1 a total of 12 times;
+				JASS_assert(fabs(rsv - 1.115947) < 0.0001);
+
+				rsv = ranker.compute_score(0, 30);									// a document that is nothing but the term
+				JASS_assert(rsv == 0);
+
+				puts("ranking_function_dph::PASSED");
+				}
+		};
+	}
@@ -0,0 +1,158 @@
+/*
+	RANKING_FUNCTION_LM_DIRICHLET.H
+	-------------------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Language Model ranking with Dirichlet smoothing
+	@details See: C. Zhai, J. Lafferty (2004) A Study of Smoothing Methods for Language Models Applied to Information Retrieval, ACM TOIS 22(2):179-214.
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <math.h>
+#include <stdio.h>
+#include <stdint.h>
+
+#include <vector>
+
+#include "slice.h"
+#include "asserts.h"
+#include "forceinline.h"
+#include "compress_integer.h"
+#include "index_postings_impact.h"
+
+namespace JASS
+	{
+	/*
+		CLASS RANKING_FUNCTION_LM_DIRICHLET
+		-----------------------------------
+	*/
+	/*!
+		@brief Language Model with Dirichlet smoothing, as computed by Lucene's LMDirichletSimilarity
+		@details Scores that would be negative are clipped at 0 (as Lucene does) because an impact-ordered index cannot store negative impacts.
+	*/
+	class ranking_function_lm_dirichlet
+		{
+		private:
+			double mu;												///< the Dirichlet smoothing parameter
+			double collection_length;								///< the number of terms in the collection
+			double mu_times_collection_probability;				///< mu * P(t|C) for the term being processed
+			std::vector<float> length_correction;					///< log(mu / (length + mu)) for each document
+
+		public:
+			/*
+				RANKING_FUNCTION_LM_DIRICHLET::RANKING_FUNCTION_LM_DIRICHLET()
+				--------------------------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param mu [in] the Dirichlet smoothing parameter, 1000 is a good value.
+				@param document_lengths [in] a vector holding the length of each document in the collection.
+			*/
+			ranking_function_lm_dirichlet(double mu, std::vector<compress_integer::integer> &document_lengths):
+				mu(mu),
+				collection_length(0),
+				mu_times_collection_probability(0),
+				length_correction(document_lengths.size())
+				{
+				uint64_t sum = 0;
+				for (auto length : document_lengths)
+					sum += length;
+				collection_length = static_cast<double>(sum);
+
+				auto correction = &length_correction[0];			// recall that we count from 1, not from 0
+				for (auto length : document_lengths)
+					*correction++ = log(mu / (static_cast<double>(length) + mu));
+				}
+
+			/*
+				RANKING_FUNCTION_LM_DIRICHLET::COMPUTE_TERM_COMPONENT()
+				-------------------------------------------------------
+			*/
+			/*!
+				@brief Called once per term.  Computes the collection probability of the term and stores it internally
+				@param term [in] The term.
+				@param collection_frequency [in] The number of times the term occurs in the collection.
+			*/
+			forceinline void compute_term_component(const slice &term, uint64_t collection_frequency)
+				{
+				mu_times_collection_probability = mu * static_cast<double>(collection_frequency) / collection_length;
+				}
+
+			/*
+				RANKING_FUNCTION_LM_DIRICHLET::COMPUTE_IDF_COMPONENT()
+				------------------------------------------------------
+			*/
+			/*!
+				@brief Not needed by this ranking function.
+				@param document_frequency [in] the number of documents that contain this term.
+				@param documents_in_collection [in] the number of documents in the collection.
+			*/
+			forceinline void compute_idf_component(compress_integer::integer document_frequency, compress_integer::integer documents_in_collection)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_LM_DIRICHLET::COMPUTE_TF_COMPONENT()
+				-----------------------------------------------------
+			*/
+			/*!
+				@brief Not needed by this ranking function (see compute_score()).
+				@param term_frequency [in] The number of times the term occurs in the document.
+			*/
+			forceinline void compute_tf_component(index_postings_impact::impact_type term_frequency)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_LM_DIRICHLET::COMPUTE_SCORE()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Compute the score of the given document, assuming compute_term_component() has already been called.
+				@param document_id [in] The ID of the document (used to look up the length)
+				@param term_frequency [in] The number of times the term occurs in the document.
+				@return The score
+			*/
+			forceinline double compute_score(compress_integer::integer document_id, index_postings_impact::impact_type term_frequency)
+				{
+				/*
+										  tf(td)                mu
+					rsv = log(1 + ------------) + log(-------------)
+									  mu * P(t|C)       len(d) + mu
+				*/
+				double rsv = log(1.0 + term_frequency / mu_times_collection_probability) + length_correction[document_id];
+				return rsv > 0 ? rsv : 0;
+				}
+
+			/*
+				RANKING_FUNCTION_LM_DIRICHLET::UNITTEST()
+				-----------------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void)
+				{
+				std::vector<compress_integer::integer> lengths{30, 40, 50, 60, 70};			// the lengths of the documents in this pseudo-index
+				ranking_function_lm_dirichlet ranker(1000, lengths);						// mu=1000
+
+				ranker.compute_term_component(slice("term"), 20);						// this term occurs 20 times in the collection
+				ranker.compute_idf_component(2, static_cast<uint32_t>(lengths.size()));			// this term occurs in 2 of 5 documents
+				ranker.compute_tf_component(12);									// it occurs in this document 12 times
+				double rsv = ranker.compute_score(1, 12);						// it occurs in document 1 a total of 12 times;
+				JASS_assert(fabs(rsv - 0.100541) < 0.0001);
+
+				rsv = ranker.compute_score(1, 1);									// and negative scores are clipped at 0
+				JASS_assert(rsv == 0);
+
+				puts("ranking_function_lm_dirichlet::PASSED");
+				}
+		};
+	}
@@ -0,0 +1,162 @@
+/*
+	RANKING_FUNCTION_LUCENE_BM25.H
+	------------------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief The Lucene version of the BM25 ranking function
+	@details See: C. Kamphuis, A. de Vries, L. Boytsov, J. Lin (2020) Which BM25 Do You Mean? A Large-Scale Reproducibility Study of Scoring Variants, ECIR 2020.
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <math.h>
+#include <stdio.h>
+#include <stdint.h>
+
+#include <vector>
+
+#include "slice.h"
+#include "asserts.h"
+#include "forceinline.h"
+#include "compress_integer.h"
+#include "index_postings_impact.h"
+
+namespace JASS
+	{
+	/*
+		CLASS RANKING_FUNCTION_LUCENE_BM25
+		----------------------------------
+	*/
+	/*!
+		@brief The Lucene (version 8 onwards) version of BM25, as used by Anserini
+		@details This differs from ranking_function_atire_bm25 in the IDF, and in that the (k1 + 1) in the top row is dropped (it does not change the ordering).
+	*/
+	class ranking_function_lucene_bm25
+		{
+		private:
+			double idf;												///< the IDF of the term being processed
+			double mean_document_length;							///< the mean of the document lengths
+			std::vector<float> length_correction;					///< the bottom row of BM25 less the term frequency (k1 * ((1 - b) + b * length / mean_document_length))
+
+		public:
+			/*
+				RANKING_FUNCTION_LUCENE_BM25::RANKING_FUNCTION_LUCENE_BM25()
+				------------------------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param k1 [in] the BM25 k1 parameter, 0.9 is a good value.
+				@param b [in] the BM25 b parameter, 0.4 is a good value.
+				@param document_lengths [in] a vector holding the length of each document in the collection.
+			*/
+			ranking_function_lucene_bm25(double k1, double b, std::vector<compress_integer::integer> &document_lengths):
+				idf(0),
+				mean_document_length(0),
+				length_correction(document_lengths.size())
+				{
+				uint64_t sum = 0;
+				for (auto length : document_lengths)
+					sum += length;
+
+				mean_document_length = static_cast<double>(sum) / static_cast<double>(document_lengths.size() - 1);			// -1 because ID 0 is not used (and should be 0)
+
+				auto correction = &length_correction[0];			// recall that we count from 1, not from 0
+				for (auto length : document_lengths)
+					*correction++ = k1 * ((1.0 - b) + b * static_cast<double>(length) / mean_document_length);
+				}
+
+			/*
+				RANKING_FUNCTION_LUCENE_BM25::COMPUTE_TERM_COMPONENT()
+				------------------------------------------------------
+			*/
+			/*!
+				@brief Called once per term before compute_idf_component().  Not needed by this ranking function.
+				@param term [in] The term.
+				@param collection_frequency [in] The number of times the term occurs in the collection.
+			*/
+			forceinline void compute_term_component(const slice &term, uint64_t collection_frequency)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_LUCENE_BM25::COMPUTE_IDF_COMPONENT()
+				-----------------------------------------------------
+			*/
+			/*!
+				@brief Called once per term.  Computes the IDF component of the ranking function and stores it internally
+				@param document_frequency [in] the number of documents that contain this term.
+				@param documents_in_collection [in] the number of documents in the collection.
+			*/
+			forceinline void compute_idf_component(compress_integer::integer document_frequency, compress_integer::integer documents_in_collection)
+				{
+				/*
+										  N - n + 0.5
+					IDF = log(1 + -----------)
+											n + 0.5
+				*/
+				idf = log(1.0 + ((double)documents_in_collection - (double)document_frequency + 0.5) / ((double)document_frequency + 0.5));
+				}
+
+			/*
+				RANKING_FUNCTION_LUCENE_BM25::COMPUTE_TF_COMPONENT()
+				----------------------------------------------------
+			*/
+			/*!
+				@brief Not needed by this ranking function (see compute_score()).
+				@param term_frequency [in] The number of times the term occurs in the document.
+			*/
+			forceinline void compute_tf_component(index_postings_impact::impact_type term_frequency)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_LUCENE_BM25::COMPUTE_SCORE()
+				---------------------------------------------
+			*/
+			/*!
+				@brief Compute BM25 from the given document, assuming compute_idf_component() has already been called.
+				@param document_id [in] The ID of the document (used to look up the length)
+				@param term_frequency [in] The number of times the term occurs in the document.
+				@return The score
+			*/
+			forceinline double compute_score(compress_integer::integer document_id, index_postings_impact::impact_type term_frequency)
+				{
+				/*
+											 tf(td)
+					rsv = ----------------------------------- * IDF
+																 len(d)
+							tf(td) + k1 * (1 - b + b * --------)
+																av_len_d
+				*/
+				double tf = term_frequency;
+				return idf * (tf / (tf + length_correction[document_id]));
+				}
+
+			/*
+				RANKING_FUNCTION_LUCENE_BM25::UNITTEST()
+				----------------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void)
+				{
+				std::vector<compress_integer::integer> lengths{30, 40, 50, 60, 70};			// the lengths of the documents in this pseudo-index
+				ranking_function_lucene_bm25 ranker(0.9, 0.4, lengths);						// k1=0.9, b=0.4
+
+				ranker.compute_term_component(slice("term"), 20);
+				ranker.compute_idf_component(2, static_cast<uint32_t>(lengths.size()));			// this term occurs in 2 of 5 documents
+				ranker.compute_tf_component(12);									// it occurs in this document 12 times
+				double rsv = ranker.compute_score(1, 12);						// it occurs in document 1 a total of 12 times;
+
+				JASS_assert(fabs(rsv - 0.822654) < 0.0001);
+				puts("ranking_function_lucene_bm25::PASSED");
+				}
+		};
+	}
@@ -0,0 +1,148 @@
+/*
+	RANKING_FUNCTION_TFIDF.H
+	------------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief The TF.IDF ranking function
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <math.h>
+#include <stdio.h>
+#include <stdint.h>
+
+#include <vector>
+
+#include "slice.h"
+#include "asserts.h"
+#include "forceinline.h"
+#include "compress_integer.h"
+#include "index_postings_impact.h"
+
+namespace JASS
+	{
+	/*
+		CLASS RANKING_FUNCTION_TFIDF
+		----------------------------
+	*/
+	/*!
+		@brief TF.IDF with length normalisation, as computed by Lucene's ClassicSimilarity (without the query normalisation, which does not change the ordering)
+	*/
+	class ranking_function_tfidf
+		{
+		private:
+			double idf;												///< the IDF of the term being processed
+			std::vector<float> length_correction;					///< 1 / sqrt(length) for each document
+
+		public:
+			/*
+				RANKING_FUNCTION_TFIDF::RANKING_FUNCTION_TFIDF()
+				------------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param document_lengths [in] a vector holding the length of each document in the collection.
+			*/
+			ranking_function_tfidf(std::vector<compress_integer::integer> &document_lengths):
+				idf(0),
+				length_correction(document_lengths.size())
+				{
+				auto correction = &length_correction[0];			// recall that we count from 1, not from 0
+				for (auto length : document_lengths)
+					*correction++ = length == 0 ? 0 : 1.0 / sqrt(static_cast<double>(length));
+				}
+
+			/*
+				RANKING_FUNCTION_TFIDF::COMPUTE_TERM_COMPONENT()
+				------------------------------------------------
+			*/
+			/*!
+				@brief Called once per term before compute_idf_component().  Not needed by this ranking function.
+				@param term [in] The term.
+				@param collection_frequency [in] The number of times the term occurs in the collection.
+			*/
+			forceinline void compute_term_component(const slice &term, uint64_t collection_frequency)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_TFIDF::COMPUTE_IDF_COMPONENT()
+				-----------------------------------------------
+			*/
+			/*!
+				@brief Called once per term.  Computes the IDF component of the ranking function and stores it internally
+				@param document_frequency [in] the number of documents that contain this term.
+				@param documents_in_collection [in] the number of documents in the collection.
+			*/
+			forceinline void compute_idf_component(compress_integer::integer document_frequency, compress_integer::integer documents_in_collection)
+				{
+				/*
+									  N
+					IDF = 1 + ln(-----)
+									n + 1
+				*/
+				idf = 1.0 + log((double)documents_in_collection / ((double)document_frequency + 1.0));
+				}
+
+			/*
+				RANKING_FUNCTION_TFIDF::COMPUTE_TF_COMPONENT()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Not needed by this ranking function (see compute_score()).
+				@param term_frequency [in] The number of times the term occurs in the document.
+			*/
+			forceinline void compute_tf_component(index_postings_impact::impact_type term_frequency)
+				{
+				/* Nothing */
+				}
+
+			/*
+				RANKING_FUNCTION_TFIDF::COMPUTE_SCORE()
+				---------------------------------------
+			*/
+			/*!
+				@brief Compute TF.IDF for the given document, assuming compute_idf_component() has already been called.
+				@param document_id [in] The ID of the document (used to look up the length)
+				@param term_frequency [in] The number of times the term occurs in the document.
+				@return The score
+			*/
+			forceinline double compute_score(compress_integer::integer document_id, index_postings_impact::impact_type term_frequency)
+				{
+				/*
+							  sqrt(tf(td)) * IDF
+					rsv = ------------------
+								 sqrt(len(d))
+				*/
+				double rsv = sqrt(static_cast<double>(term_frequency)) * idf * length_correction[document_id];
+				return rsv > 0 ? rsv : 0;
+				}
+
+			/*
+				RANKING_FUNCTION_TFIDF::UNITTEST()
+				----------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void)
+				{
+				std::vector<compress_integer::integer> lengths{30, 40, 50, 60, 70};			// the lengths of the documents in this pseudo-index
+				ranking_function_tfidf ranker(lengths);
+
+				ranker.compute_term_component(slice("term"), 20);
+				ranker.compute_idf_component(2, static_cast<uint32_t>(lengths.size()));			// this term occurs in 2 of 5 documents
+				ranker.compute_tf_component(12);									// it occurs in this document 12 times
+				double rsv = ranker.compute_score(1, 12);						// it occurs in document 1 a total of 12 times;
+
+				JASS_assert(fabs(rsv - 0.827513) < 0.0001);
+				puts("ranking_function_tfidf::PASSED");
+				}
+		};
+	}
@@ -0,0 +1,323 @@
+/*
+	SERIALISE_CIFF.CPP
+	------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <stdio.h>
+
+#include <numeric>
+#include <algorithm>
+#include <filesystem>
+
+#include "file.h"
+#include "asserts.h"
+#include "protobuf.h"
+#include "unittest_data.h"
+#include "serialise_ciff.h"
+#include "index_manager_sequential.h"
+
+namespace JASS
+	{
+	/*
+		SERIALISE_CIFF::SERIALISE_CIFF()
+		--------------------------------
+	*/
+	serialise_ciff::serialise_ciff(size_t documents_in_collection, std::vector<compress_integer::integer> &document_lengths, const std::string &filename) :
+		index_manager::delegate(documents_in_collection),
+		filename(filename),
+		document_lengths(document_lengths),
+		postings_lists(0),
+		document_records(0),
+		postings_filename(filename + ".postings.tmp"),
+		documents_filename(filename + ".documents.tmp"),
+		postings_messages(std::make_unique<file>(postings_filename, "w+b")),
+		document_messages(std::make_unique<file>(documents_filename, "w+b")),
+		postings_size(0)
+		{
+		/* Nothing */
+		}
+
+	/*
+		SERIALISE_CIFF::OPERATOR()()
+		----------------------------
+	*/
+	void serialise_ciff::operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+		{
+		/*
+			PostingsList {string term = 1; int64 df = 2; int64 cf = 3; repeated Posting postings = 4;}
+		*/
+		message.clear();
+		protobuf::put_type_and_field(message, protobuf::BLOB, 1);
+		protobuf::put_blob(message, term.address(), term.size());
+		protobuf::put_type_and_field(message, protobuf::VARINT, 2);
+		protobuf::put_uint64_t(message, document_frequency);
+		protobuf::put_type_and_field(message, protobuf::VARINT, 3);
+		protobuf::put_uint64_t(message, std::accumulate(term_frequencies, term_frequencies + document_frequency, static_cast<uint64_t>(0)));
+
+		/*
+			Posting {int32 docid = 1; int32 tf = 2;} where the docid is d-gap encoded and counts from 0
+		*/
+		compress_integer::integer previous = 1;
+		for (compress_integer::integer which = 0; which < document_frequency; which++)
+			{
+			posting.clear();
+			protobuf::put_type_and_field(posting, protobuf::VARINT, 1);
+			protobuf::put_uint64_t(posting, document_ids[which] - previous);
+			protobuf::put_type_and_field(posting, protobuf::VARINT, 2);
+			protobuf::put_uint64_t(posting, term_frequencies[which]);
+
+			protobuf::put_type_and_field(message, protobuf::BLOB, 4);
+			protobuf::put_blob(message, posting.data(), posting.size());
+			previous = document_ids[which];
+			}
+
+		/*
+			Write the message now but remember where it is so that it can be written in term order by finish()
+		*/
+		postings_messages->write(message.data(), message.size());
+		records.push_back(record{std::string(reinterpret_cast<const char *>(term.address()), term.size()), postings_size, message.size()});
+		postings_size += message.size();
+		postings_lists++;
+		}
+
+	/*
+		SERIALISE_CIFF::OPERATOR()()
+		----------------------------
+	*/
+	void serialise_ciff::operator()(size_t document_id, const slice &primary_key)
+		{
+		if (document_id == 0)
+			return;				// document 0 is the dud document "-" and is not in CIFF
+
+		/*
+			DocRecord {int32 docid = 1; string collection_docid = 2; int32 doclength = 3;}
+		*/
+		message.clear();
+		protobuf::put_type_and_field(message, protobuf::VARINT, 1);
+		protobuf::put_uint64_t(message, document_id - 1);
+		protobuf::put_type_and_field(message, protobuf::BLOB, 2);
+		protobuf::put_blob(message, primary_key.address(), primary_key.size());
+		protobuf::put_type_and_field(message, protobuf::VARINT, 3);
+		protobuf::put_uint64_t(message, document_id < document_lengths.size() ? document_lengths[document_id] : 0);
+
+		posting.clear();
+		protobuf::put_blob(posting, message.data(), message.size());
+		document_messages->write(posting.data(), posting.size());
+		document_records++;
+		}
+
+	/*
+		SERIALISE_CIFF::FINISH()
+		------------------------
+	*/
+	void serialise_ciff::finish(void)
+		{
+		/*
+			Header {int32 version = 1; int32 num_postings_lists = 2; int32 num_docs = 3; int32 total_postings_lists = 4; int32 total_docs = 5;
+			int64 total_terms_in_collection = 6; double average_doclength = 7; string description = 8;}
+		*/
+		uint64_t total_terms = std::accumulate(document_lengths.begin(), document_lengths.end(), static_cast<uint64_t>(0));
+		std::string description = "JASSv2 index, the term frequencies are impacts";
+
+		message.clear();
+		protobuf::put_type_and_field(message, protobuf::VARINT, 1);
+		protobuf::put_uint64_t(message, 1);
+		protobuf::put_type_and_field(message, protobuf::VARINT, 2);
+		protobuf::put_uint64_t(message, postings_lists);
+		protobuf::put_type_and_field(message, protobuf::VARINT, 3);
+		protobuf::put_uint64_t(message, document_records);
+		protobuf::put_type_and_field(message, protobuf::VARINT, 4);
+		protobuf::put_uint64_t(message, postings_lists);
+		protobuf::put_type_and_field(message, protobuf::VARINT, 5);
+		protobuf::put_uint64_t(message, documents);
+		protobuf::put_type_and_field(message, protobuf::VARINT, 6);
+		protobuf::put_uint64_t(message, total_terms);
+		protobuf::put_type_and_field(message, protobuf::SIXTY_FOUR_BIT, 7);
+		protobuf::put_double(message, documents == 0 ? 0.0 : static_cast<double>(total_terms) / documents);
+		protobuf::put_type_and_field(message, protobuf::BLOB, 8);
+		protobuf::put_blob(message, description.data(), description.size());
+
+		std::string delimited;
+		protobuf::put_blob(delimited, message.data(), message.size());
+
+		file outfile(filename, "w+b");
+		outfile.write(delimited.data(), delimited.size());
+
+		/*
+			Close the temporary files so that they can be read back
+		*/
+		postings_messages.reset();
+		document_messages.reset();
+
+		/*
+			The PostingsList messages, in term order (std::string compares as unsigned bytes)
+		*/
+		std::sort(records.begin(), records.end(), [](const record &first, const record &second){ return first.term < second.term; });
+
+		file::file_read_only postings_file;
+		const uint8_t *postings = nullptr;
+		if (file::read_entire_file(postings_filename, postings_file) != 0)
+			postings_file.read_entire_file(postings);
+		for (const auto &current : records)
+			{
+			delimited.clear();
+			protobuf::put_uint64_t(delimited, current.length);
+			outfile.write(delimited.data(), delimited.size());
+			outfile.write(postings + current.offset, current.length);
+			}
+
+		/*
+			The DocRecord messages (already in document order and length prefixed)
+		*/
+		file::file_read_only documents_file;
+		const uint8_t *documents = nullptr;
+		size_t documents_size = file::read_entire_file(documents_filename, documents_file);
+		if (documents_size != 0)
+			{
+			documents_file.read_entire_file(documents);
+			outfile.write(documents, documents_size);
+			}
+
+		/*
+			Clean up the temporary files
+		*/
+		std::error_code error;
+		std::filesystem::remove(postings_filename, error);
+		std::filesystem::remove(documents_filename, error);
+		}
+
+	/*
+		SERIALISE_CIFF::UNITTEST()
+		--------------------------
+	*/
+	void serialise_ciff::unittest(void)
+		{
+		const std::string filename = "serialise_ciff_unittest.ciff";
+
+		/*
+			Build an index of the standard 10 documents and serialise it
+		*/
+		index_manager_sequential index;
+		index_manager_sequential::unittest_build_index(index, unittest_data::ten_documents);
+		{
+		serialise_ciff serialiser(index.get_highest_document_id(), index.get_document_length_vector(), filename);
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+
+		std::string contents;
+		file::read_entire_file(filename, contents);
+		std::filesystem::remove(filename);
+		const uint8_t *stream = reinterpret_cast<const uint8_t *>(contents.data());
+		const uint8_t *end = stream + contents.size();
+
+		/*
+			The header
+		*/
+		protobuf::wire_type type;
+		uint64_t values[9] = {};
+		double average_length = 0;
+		uint64_t length = protobuf::get_uint64_t(stream);
+		const uint8_t *message_end = stream + length;
+		while (stream < message_end)
+			{
+			uint8_t field = protobuf::get_type_and_field(type, stream);
+			if (type == protobuf::VARINT)
+				values[field] = protobuf::get_uint64_t(stream);
+			else if (type == protobuf::SIXTY_FOUR_BIT)
+				average_length = protobuf::get_double(stream);
+			else
+				protobuf::get_blob(stream);
+			}
+		JASS_assert(values[1] == 1);				// version
+		JASS_assert(values[2] == 20);				// postings lists (the DOCNOs are indexed too)
+		JASS_assert(values[3] == 10);				// document records
+		JASS_assert(values[5] == 10);				// documents
+		JASS_assert(values[6] == 75);				// terms in the collection
+		JASS_assert(average_length == 7.5);
+
+		/*
+			The postings lists, check that the docids are in order and the document frequencies add up
+		*/
+		uint64_t postings = 0;
+		bool seen_one = false;
+		std::string previous_term;
+		for (size_t list = 0; list < values[2]; list++)
+			{
+			length = protobuf::get_uint64_t(stream);
+			const uint8_t *list_end = stream + length;
+			slice term;
+			uint64_t document_frequency = 0;
+			std::vector<std::pair<uint64_t, uint64_t>> pairs;
+			uint64_t docid = 0;
+			while (stream < list_end)
+				{
+				uint8_t field = protobuf::get_type_and_field(type, stream);
+				if (type == protobuf::VARINT)
+					{
+					uint64_t value = protobuf::get_uint64_t(stream);
+					if (field == 2)
+						document_frequency = value;
+					}
+				else if (field == 1)
+					term = protobuf::get_blob(stream);
+				else
+					{
+					slice blob = protobuf::get_blob(stream);
+					const uint8_t *here = reinterpret_cast<const uint8_t *>(blob.address());
+					protobuf::get_type_and_field(type, here);
+					docid += protobuf::get_uint64_t(here);
+					protobuf::get_type_and_field(type, here);
+					pairs.push_back(std::make_pair(docid, protobuf::get_uint64_t(here)));
+					}
+				}
+			JASS_assert(pairs.size() == document_frequency);
+			postings += document_frequency;
+
+			std::string current_term(reinterpret_cast<const char *>(term.address()), term.size());
+			JASS_assert(list == 0 || previous_term < current_term);
+			previous_term = current_term;
+
+			if (term == slice("one"))
+				{
+				seen_one = true;
+				JASS_assert((pairs == std::vector<std::pair<uint64_t, uint64_t>>{{9, 1}}));			// in JASS document 10 once
+				}
+			if (term == slice("ten"))
+				JASS_assert(pairs.size() == 10 && pairs[0].first == 0 && pairs[9].first == 9);
+			}
+		JASS_assert(seen_one);
+		JASS_assert(postings == 65);
+
+		/*
+			The document records
+		*/
+		for (size_t record = 0; record < values[3]; record++)
+			{
+			length = protobuf::get_uint64_t(stream);
+			const uint8_t *record_end = stream + length;
+			uint64_t fields[4] = {};
+			slice key;
+			while (stream < record_end)
+				{
+				uint8_t field = protobuf::get_type_and_field(type, stream);
+				if (type == protobuf::VARINT)
+					fields[field] = protobuf::get_uint64_t(stream);
+				else
+					key = protobuf::get_blob(stream);
+				}
+			JASS_assert(fields[1] == record);
+			JASS_assert(fields[3] == index.get_document_length_vector()[record + 1]);
+			JASS_assert(key == slice(std::to_string(record + 1).c_str()));
+			}
+		JASS_assert(stream == end);
+
+		/*
+			The temporary files have gone
+		*/
+		JASS_assert(!std::filesystem::exists(filename + ".postings.tmp") && !std::filesystem::exists(filename + ".documents.tmp"));
+
+		puts("serialise_ciff::PASSED");
+		}
+	}
@@ -0,0 +1,140 @@
+/*
+	SERIALISE_CIFF.H
+	----------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Serialise an index in the Common Index File Format (CIFF)
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+#include <vector>
+#include <memory>
+
+#include "file.h"
+#include "index_manager.h"
+
+namespace JASS
+	{
+	/*
+		CLASS SERIALISE_CIFF
+		--------------------
+	*/
+	/*!
+		@brief Serialise an index in the Common Index File Format (CIFF) so that it can be loaded into other search engines (such as PISA and Anserini).
+		@details The file is a Header message then a PostingsList message for each term then a DocRecord message for each document (see
+		https://github.com/osirrc/ciff), each written with its length first (as by writeDelimitedTo()).  The postings lists are in term order, the
+		postings are in document id order, and CIFF document ids count from 0, so JASS document id n is CIFF document id n - 1.  When this class is
+		given a quantized index (see quantize::serialise_index()) the term frequencies in the file are the impacts.  The file is written by finish(),
+		until then the messages are written to temporary files (and only the term and location of each PostingsList is held in memory) because the
+		Header (which comes first) includes the number of postings lists, and the postings lists arrive in hash table order.
+	*/
+	class serialise_ciff : public index_manager::delegate
+		{
+		public:
+			static constexpr const char *FILENAME = "index.ciff";			///< The name of the file this class writes.
+
+		private:
+			/*
+				CLASS SERIALISE_CIFF::RECORD
+				----------------------------
+			*/
+			/*!
+				@brief Where a PostingsList message is in the temporary file of PostingsList messages.
+			*/
+			class record
+				{
+				public:
+					std::string term;						///< The term (the records are written in term order).
+					uint64_t offset;						///< Where the message starts in the temporary file.
+					uint64_t length;						///< The length of the message (in bytes).
+				};
+
+		private:
+			std::string filename;												///< The name of the file to write.
+			std::vector<compress_integer::integer> &document_lengths;	///< The length of each document (element 0 is unused).
+			size_t postings_lists;												///< The number of postings lists written so far.
+			size_t document_records;											///< The number of document records written so far.
+			std::string postings_filename;									///< The name of the temporary file of PostingsList messages.
+			std::string documents_filename;									///< The name of the temporary file of (length prefixed) DocRecord messages.
+			std::unique_ptr<file> postings_messages;						///< The PostingsList messages (in the order they are given to this class).
+			std::unique_ptr<file> document_messages;						///< The DocRecord messages.
+			uint64_t postings_size;												///< The number of bytes written to postings_messages.
+			std::vector<record> records;										///< The term and location of each PostingsList message.
+			std::string message;													///< Buffer used to build a message.
+			std::string posting;													///< Buffer used to build a Posting (which is inside a PostingsList message) or a length prefixed DocRecord.
+
+		public:
+			/*
+				SERIALISE_CIFF::SERIALISE_CIFF()
+				--------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents_in_collection [in] The number of documents in the collection.
+				@param document_lengths [in] The length of each document (element 0 is unused).
+				@param filename [in] The name of the file to write.
+			*/
+			serialise_ciff(size_t documents_in_collection, std::vector<compress_integer::integer> &document_lengths, const std::string &filename = FILENAME);
+
+			/*
+				SERIALISE_CIFF::~SERIALISE_CIFF()
+				---------------------------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~serialise_ciff()
+				{
+				/* Nothing */
+				}
+
+			/*
+				SERIALISE_CIFF::OPERATOR()()
+				----------------------------
+			*/
+			/*!
+				@brief The callback function to serialise the postings (given the term) is operator().
+				@param term [in] The term name.
+				@param postings [in] The postings lists.
+				@param document_frequency [in] The document frequency of the term
+				@param document_ids [in] An array (of length document_frequency) of document ids.
+				@param term_frequencies [in] An array (of length document_frequency) of term frequencies (corresponding to document_ids).
+			*/
+			virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies);
+
+			/*
+				SERIALISE_CIFF::OPERATOR()()
+				----------------------------
+			*/
+			/*!
+				@brief The callback function to serialise the primary keys (external document ids) is operator().
+				@param document_id [in] The internal document identfier.
+				@param primary_key [in] This document's primary key (external document identifier).
+			*/
+			virtual void operator()(size_t document_id, const slice &primary_key);
+
+			/*
+				SERIALISE_CIFF::FINISH()
+				------------------------
+			*/
+			/*!
+				@brief Write the file (the Header, then the PostingsList messages sorted on term, then the DocRecord messages).
+			*/
+			virtual void finish(void);
+
+			/*
+				SERIALISE_CIFF::UNITTEST()
+				--------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
@@ -4,6 +4,8 @@
 	Copyright (c) 2016 Andrew Trotman
 	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
 */
+#include <memory>
+#include <vector>
 #include <algorithm>
 
 #include "reverse.h"
@@ -30,6 +32,30 @@ namespace JASS
 			Serialise the primary key offsets and the number of documents in the collection.
 		*/
 		serialise_primary_keys();
+
+		/*
+			Checksum each postings list (CIchecksums.bin) and each of the index files.
+		*/
+		serialise_checksums();
+
+		/*
+			Describe the index so that the search engine can check what it is loading (CImanifest.txt).
+		*/
+		complete_manifest();
+		file::write_entire_file(index_manifest::FILENAME, manifest.serialise());
+		}
+
+	/*
+		SERIALISE_JASS_V1::COMPLETE_MANIFEST()
+		--------------------------------------
+	*/
+	void serialise_jass_v1::complete_manifest(void)
+		{
+		manifest.codex = compressor_name;
+		manifest.d_ness = compressor_d_ness;
+		manifest.documents = primary_key_offsets.size() - 1;
+		manifest.terms = index_key.size();
+		manifest.set_build_time();
 		}
 
 	/*
@@ -69,6 +95,59 @@ namespace JASS
 		primary_keys.write(&document_count, sizeof(document_count));
 		}
 
+	/*
+		SERIALISE_JASS_V1::CHECKSUM_POSTINGS()
+		--------------------------------------
+	*/
+	std::string serialise_jass_v1::checksum_postings(void)
+		{
+		/*
+			Everything must be on disk before it can be checksummed.
+		*/
+		if (!vocabulary_strings.sync() || !vocabulary.sync() || !postings.sync() || !primary_keys.sync())
+			{
+			std::cout << "Failed to write the index\n";
+			exit(1);
+			}
+
+		/*
+			The length and checksum of each postings list were computed as it was written (see write_to_postings())
+		*/
+		std::string checksums;
+		for (const auto &line : index_key)
+			{
+			checksums.append(reinterpret_cast<const char *>(&line.length), sizeof(line.length));
+			checksums.append(reinterpret_cast<const char *>(&line.checksum), sizeof(line.checksum));
+			}
+
+		return checksums;
+		}
+
+	/*
+		SERIALISE_JASS_V1::SERIALISE_CHECKSUMS()
+		----------------------------------------
+	*/
+	void serialise_jass_v1::serialise_checksums(void)
+		{
+		file::write_entire_file(CHECKSUMS_FILENAME, checksum_postings());
+
+		/*
+			Now the checksum of each file.
+		*/
+		manifest.files.clear();
+		manifest.add_file("CIvocab_terms.bin");
+		manifest.add_file("CIvocab.bin");
+		manifest.add_file("CIpostings.bin");
+		manifest.add_file("CIdoclist.bin");
+		manifest.add_file(CHECKSUMS_FILENAME);
+		for (const auto &filename : auxiliary_files)
+			if (!manifest.add_file(filename))
+				{
+				std::cout << "Cannot checksum " << filename << " (which is part of the index)\n";
+				exit(1);
+				}
+		}
+
 	/*
 		SERIALISE_JASS_V1::WRITE_POSTINGS()
 		-----------------------------------
@@ -79,6 +158,8 @@ namespace JASS
 			Keep a track of where the postings are stored on disk.
 		*/
 		size_t postings_location = postings.tell();
+		postings_length = 0;
+		postings_checksum = 0;
 
 		/*
 			Impact order the postings list.
@@ -97,7 +178,7 @@ namespace JASS
 		uint64_t impact_header_size = sizeof(uint16_t) + sizeof(uint64_t) + sizeof(uint64_t) + sizeof(uint32_t);
 		for (size_t which = 0; which < number_of_impacts; which++)
 			{
-			postings.write(&offset, sizeof(offset));
+			write_to_postings(&offset, sizeof(offset));
 			offset += impact_header_size;
 			}
 
@@ -117,14 +198,14 @@ namespace JASS
 				Impact score (uint16_t).
 			*/
 			uint16_t score = static_cast<uint16_t>(header.impact_score);
-			postings.write(&score, sizeof(score));
+			write_to_postings(&score, sizeof(score));
 
 			/*
 				Start loction on disk (uint64_t).
 			*/
 			uint64_t start_location = start_of_postings;
 
-			postings.write(&start_location, sizeof(start_location));
+			write_to_postings(&start_location, sizeof(start_location));
 
 			/*
 				This is where compression happens.
@@ -158,13 +239,13 @@ namespace JASS
 				End location on disk (uint64_t).
 			*/
 			uint64_t finish_location = start_of_postings + took;
-			postings.write(&finish_location, sizeof(finish_location));
+			write_to_postings(&finish_location, sizeof(finish_location));
 
 			/*
 				The number of document ids with this impact score (length of the impact segment measured in doc_ids).
 			*/
 			uint32_t frequency = static_cast<uint32_t>(header.size());
-			postings.write(&frequency, sizeof(frequency));
+			write_to_postings(&frequency, sizeof(frequency));
 
 			start_of_postings = finish_location + padding;
 			}
@@ -173,18 +254,18 @@ namespace JASS
 			Write out a "blank" impact header
 		*/
 		uint8_t zero[] = {0, 0,  0, 0, 0, 0, 0, 0, 0, 0,   0, 0, 0, 0, 0, 0, 0, 0,   0, 0, 0, 0};
-		postings.write(&zero, sizeof(zero));
+		write_to_postings(&zero, sizeof(zero));
 
 		/*
 			Pad so that the postings are on a word boundary
 		*/
-		postings.write(zero, wastage);			
+		write_to_postings(zero, wastage);			
 
 		/*
 			Write out each postings list segment.
 		*/
 		for (auto &header : compressed_segments)
-			postings.write(header.address(), header.size());
+			write_to_postings(header.address(), header.size());
 
 		/*
 			Return the location of the postings list on disk
@@ -220,7 +301,7 @@ namespace JASS
 		/*
 			Keep a copy of the term and the detals of the postings list for later sorting and writing to CIvocab.bin
 		*/
-		index_key.push_back(vocab_tripple(term, term_offset, postings_location, number_of_impact_scores));
+		index_key.push_back(vocab_tripple(term, term_offset, postings_location, number_of_impact_scores, postings_length, postings_checksum));
 		}
 
 	/*
@@ -267,6 +348,72 @@ namespace JASS
 		return compress_integer_all::get_by_name(name);
 		}
 
+	/*
+		SERIALISE_JASS_V1::GET_COMPRESSOR()
+		-----------------------------------
+	*/
+	compress_integer *serialise_jass_v1::get_compressor(const std::string &codex_shortname, std::string &name, int32_t &d_ness)
+		{
+		if (!compress_integer_all::is_known(codex_shortname))
+			exit(printf("Unknown index format\n"));
+
+		d_ness = 1;
+		name = compress_integer_all::name_of(codex_shortname);
+
+		return compress_integer_all::replicate(codex_shortname);
+		}
+
+	/*
+		SERIALISE_JASS_V1::CAN_ENCODE_POSTINGS()
+		----------------------------------------
+	*/
+	bool serialise_jass_v1::can_encode_postings(const std::string &codex_shortname)
+		{
+		if (!compress_integer_all::is_known(codex_shortname))
+			return false;
+
+		std::unique_ptr<compress_integer> codex(compress_integer_all::replicate(codex_shortname));
+		std::vector<compress_integer::integer> d_gaps = {0, 1, 2, 1, 5, 100, 1, 70000, 3, 1, 1, 1, 1000, 2};
+		std::vector<compress_integer::integer> decoded;
+		std::vector<uint8_t> encoded;
+
+		for (size_t length = 1; length <= d_gaps.size(); length++)
+			{
+			encoded.assign(1024, 0);
+			decoded.assign(length + 256, 0);			// some codexes decode more integers than asked for
+			size_t bytes = codex->encode(encoded.data(), encoded.size(), d_gaps.data(), length);
+			if (bytes == 0)
+				return false;
+			codex->decode(decoded.data(), length, encoded.data(), bytes);
+			if (!std::equal(d_gaps.begin(), d_gaps.begin() + length, decoded.begin()))
+				return false;
+			}
+
+		return true;
+		}
+
+	/*
+		SERIALISE_JASS_V1::CODEX_SHORTNAME()
+		------------------------------------
+	*/
+	bool serialise_jass_v1::codex_shortname(const std::string &codex_name, jass_v1_codex default_codex, std::string &codex_shortname)
+		{
+		std::string default_name;
+		int32_t d_ness;
+		std::unique_ptr<compress_integer> default_compressor(get_compressor(default_codex, default_name, d_ness));
+
+		if (codex_name == default_name)
+			codex_shortname = "";
+		else
+			{
+			codex_shortname = compress_integer_all::shortname_of(codex_name);
+			if (codex_shortname == "")
+				return false;
+			}
+
+		return true;
+		}
+
 	/*
 		SERIALISE_JASS_V1::UNITTEST()
 		-----------------------------
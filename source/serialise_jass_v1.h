@@ -14,9 +14,11 @@
 
 #include "file.h"
 #include "slice.h"
+#include "checksum.h"
 #include "allocator_cpp.h"
 #include "index_postings.h"
 #include "index_manager.h"
+#include "index_manifest.h"
 #include "compress_integer_qmx_jass_v1.h"
 #include "compress_integer_elias_gamma_simd.h"
 
@@ -40,7 +42,8 @@ namespace JASS
 		The Open-Source IR Reproducibility Challenge, Proceedings of the European Conference on Information Retrieval
 		(ECIR 2016), pp. 408-420.
 
-		The JASS version 1 index in made up of 4 files: CIvocab_terms.bin, CIvocab.bin, CIpostings.bin, and CIdoclist.bin
+		The JASS version 1 index in made up of 4 files: CIvocab_terms.bin, CIvocab.bin, CIpostings.bin, and CIdoclist.bin.  JASSv2 also writes
+		CIchecksums.bin (the length and checksum of each postings list) and CImanifest.txt (see index_manifest), neither of which JASS v1 needs.
 
 		CIdoclist.bin: The list of document identifiers (each '\0' terminated). Then an index to each of the doclents 
 		(stored as a table of uint64_t). The final 8 bytes of the file is an uin64_t storing the total numbner of unique 
@@ -58,7 +61,8 @@ namespace JASS
 
 		CIpostings.bin: This file contains all the postings lists compressed using the same codex. This is different from 
 		ATIRE which allows each postings list to be encoded using a different codex. The first byte of this file specifies 
-		the codex where s=uncompressed, c=VarByte, 8=Simple8, q=QMX, Q=QMX4D, R=QMX0D. This is followed by the postings lists.
+		the codex where s=uncompressed, c=VarByte, 8=Simple8, q=QMX, Q=QMX4D, R=QMX0D, and A=any codex in
+		compress_integer_all (in which case the codex's short command line name, e.g. "-cV", follows as a '\0' terminated string). This is followed by the postings lists.
 		A postings list is: a list of 64-bit pointer to headers. Each header is (uint16_t impact_score, uint64_t start,
 		uint64_t end, uint32_t impact_frequency) where impact_score is the impact value, start and end are pointers to the
 		compressed docids, and impact_frequency is the number of dociment_ids in the list. The header is terminated with a 
@@ -84,6 +88,8 @@ namespace JASS
 					uint64_t term;				///< The pointer to the \0 terminated string in the CI_vovab_terms.bin file.
 					uint64_t offset;			///< The pointer to the postings stored in the CIpostings.bin file.
 					uint64_t impacts;			///< The number of impacts that exist for this term.
+					uint64_t length;			///< The length (in bytes) of the postings list in the CIpostings.bin file.
+					uint16_t checksum;		///< The Fletcher 16-bit checksum of the postings list.
 
 				public:
 					/*
@@ -96,12 +102,16 @@ namespace JASS
 						@param term [in] The location of this term in CIvocab_terms.bin.
 						@param offset [in] The offset of the postings list in CIpostings.bin.
 						@param impacts [in] The number of impacts in the postings list.
+						@param length [in] The length (in bytes) of the postings list in CIpostings.bin.
+						@param checksum [in] The Fletcher 16-bit checksum of the postings list.
 					*/
-					vocab_tripple(const slice &string, uint64_t term, uint64_t offset, uint64_t impacts) :
+					vocab_tripple(const slice &string, uint64_t term, uint64_t offset, uint64_t impacts, uint64_t length, uint16_t checksum) :
 						token(string),
 						term(term),
 						offset(offset),
-						impacts(impacts)
+						impacts(impacts),
+						length(length),
+						checksum(checksum)
 						{
 						/* Nothing */
 						}
@@ -122,6 +132,8 @@ namespace JASS
 				};
 
 		public:
+			static constexpr const char *CHECKSUMS_FILENAME = "CIchecksums.bin";		///< The name of the file holding the length and checksum of each postings list.
+
 			/*
 				ENUM JASS_V1_CODEX
 				------------------
@@ -139,10 +151,12 @@ namespace JASS
 				qmx_d0 = 'R',						///< Postings are compressed using QMX without delta encoding.
 				elias_gamma_simd = 'G',			///< Postings are compressed using Elias gamma SIMD encoding.
 				elias_gamma_simd_vb = 'g',		///< Postings are compressed using Elias gamma SIMD encoding with variable byte endings.
-				elias_delta_simd = 'D'			///< Postings are compressed using Elias delta SIMD encoding.
+				elias_delta_simd = 'D',			///< Postings are compressed using Elias delta SIMD encoding.
+				any_codex = 'A'					///< Postings are compressed using the compress_integer_all codex whose short name (e.g. "-cE") follows as a '\0' terminated string.
 				};
 
 		protected:
+			std::string filename_prefix;						///< Prepended to the name of each file written.
 			file vocabulary_strings;							///< The concatination of UTS-8 encoded unique tokens in the collection.
 			file vocabulary;										///< Details about the term (including a pointer to the term, a pointer to the postings, and the quantum count.
 			file postings;											///< The postings lists.
@@ -158,8 +172,29 @@ namespace JASS
 			std::vector<uint8_t, allocator_cpp<uint8_t>> compressed_buffer;		///< The buffer used to compress postings into.
 			std::vector<slice, allocator_cpp<slice>> compressed_segments;			///< vector of pointers (and lengths) to the compressed postings.
 			uint8_t alignment;									///< Postings lists are padded to this alignment (used for codexes that require word alignment).
+			uint64_t postings_length;							///< The number of bytes of the current postings list written so far (see write_to_postings()).
+			uint16_t postings_checksum;						///< The Fletcher 16-bit checksum of the current postings list so far (see write_to_postings()).
+			index_manifest manifest;							///< The description of the index written to CImanifest.txt by finish().
+			std::vector<std::string> auxiliary_files;		///< Other files that are part of the index (the stemmer, stop words, etc.) to record in the manifest.
 
 		protected:
+			/*
+				SERIALISE_JASS_V1::WRITE_TO_POSTINGS()
+				--------------------------------------
+			*/
+			/*!
+				@brief Write part of the current postings list to CIpostings.bin, keeping its length and checksum (for CIchecksums.bin) as it goes.
+				@details write_postings() starts each postings list with postings_length and postings_checksum set to 0.
+				@param data [in] The bytes to write.
+				@param length [in] The number of bytes to write.
+			*/
+			void write_to_postings(const void *data, size_t length)
+				{
+				postings.write(data, length);
+				postings_length += length;
+				postings_checksum = checksum::fletcher_16_continue(postings_checksum, data, length);
+				}
+
 			/*
 				SERIALISE_JASS_V1::WRITE_POSTINGS()
 				-----------------------------------
@@ -175,7 +210,6 @@ namespace JASS
 			*/
 			virtual size_t write_postings(const index_postings &postings, size_t &number_of_impacts, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies);
 
-		public:
 			/*
 				SERIALISE_JASS_V1::SERIALISE_JASS_V1()
 				--------------------------------------
@@ -183,22 +217,27 @@ namespace JASS
 			/*!
 				@brief Constructor
 				@param documents [in] The number of documents in the collection (used to allocate re-usable buffers).
-				@param encoder [in] An shared pointer to a codex responsible for performing the compression of postings lists (default = compress_integer_QMX_jass_v1()).
-				@param alignment [in] The start address of a postings list is padded to start on these boundaries (needed for compress_integer_QMX_jass_v1 (use 16), and others).  Default = 0.
+				@param codex [in] The codex used to compress the postings lists.
+				@param codex_shortname [in] If codex is any_codex, the short name (e.g. "-cE") of the compress_integer_all codex to use, else ignored.
+				@param alignment [in] The start address of a postings list is padded to start on these boundaries.
+				@param filename_prefix [in] Prepended to the name of each file written.
 			*/
-			serialise_jass_v1(size_t documents, jass_v1_codex codex = jass_v1_codex::elias_gamma_simd, int8_t alignment = 1) :
+			serialise_jass_v1(size_t documents, jass_v1_codex codex, const std::string &codex_shortname, int8_t alignment, const std::string &filename_prefix) :
 				index_manager::delegate(documents),
-				vocabulary_strings("CIvocab_terms.bin", "w+b"),
-				vocabulary("CIvocab.bin", "w+b"),
-				postings("CIpostings.bin", "w+b"),
-				primary_keys("CIdoclist.bin", "w+b"),
+				filename_prefix(filename_prefix),
+				vocabulary_strings(filename_prefix + "CIvocab_terms.bin", "w+b"),
+				vocabulary(filename_prefix + "CIvocab.bin", "w+b"),
+				postings(filename_prefix + "CIpostings.bin", "w+b"),
+				primary_keys(filename_prefix + "CIdoclist.bin", "w+b"),
 				memory(1024 * 1024),								///< The allocation block size is currently 1MB, big enough for most postings lists (but it'll grow for larger ones).
 				impact_ordered(documents, memory),
-				encoder(get_compressor(codex, compressor_name, compressor_d_ness)),
+				encoder(codex == any_codex ? get_compressor(codex_shortname, compressor_name, compressor_d_ness) : get_compressor(codex, compressor_name, compressor_d_ness)),
 				allocator(memory),
 				compressed_buffer(allocator),
 				compressed_segments(allocator),
-				alignment(alignment)
+				alignment(alignment),
+				postings_length(0),
+				postings_checksum(0)
 				{
 				/*
 					Allocate space for storing the compressed postings.  But, allocate too much space as some
@@ -208,25 +247,63 @@ namespace JASS
 					We store the:
 						postings, each docid is 8 bytes and there are |documents| of those
 						The impact header consisting of an impact (2 bytes) + start_pointer (8 bytes) + length (8 bytes) + frequency (4 bytes)
-						There are index_postings_impact::largest_impact of those.
+						There are index_postings_impact::largest_storable_impact of those.
 					To make things worse, each of these are stored compressed, and so might be bigger than the
 					raw size by 8/7 (assuming variable byte), so the raw storage is:
-						 8/7 *(documents * 8 + 22 * index_postings_impact::largest_impact)
+						 8/7 *(documents * 8 + 22 * index_postings_impact::largest_storable_impact)
 					but, each of these two things is stored in a vector as a slice and each slice takes
-					8 bytes for the address and 8 bytes for the size giving an additional 2 * 16 * index_postings_impact::largest_impact
+					8 bytes for the address and 8 bytes for the size giving an additional 2 * 16 * index_postings_impact::largest_storable_impact
 					giving a total of
-						8/7 *(documents * 8 + (22 + 2 * 16) * index_postings_impact::largest_impact)
+						8/7 *(documents * 8 + (22 + 2 * 16) * index_postings_impact::largest_storable_impact)
 					and now lets add a bit for reasons we can't predict (the std::vector has house-keeping)
 						1 MB
 					and make sure integer rounding doesn't get this wrong:
-						8 * (documents * 8 + (22 + 2 * 16) * index_postings_impact::largest_impact) / 7 + 1024 * 1024
+						8 * (documents * 8 + (22 + 2 * 16) * index_postings_impact::largest_storable_impact) / 7 + 1024 * 1024
 				*/
-				compressed_buffer.resize(8 * (documents * 8 + (22 + 2 * 16) * index_postings_impact::largest_impact) / 7 + 1024 * 1024);
-				compressed_segments.reserve(index_postings_impact::largest_impact);
+				compressed_buffer.resize(8 * (documents * 8 + (22 + 2 * 16) * index_postings_impact::largest_storable_impact) / 7 + 1024 * 1024);
+				compressed_segments.reserve(index_postings_impact::largest_storable_impact);
 
 // std::cout << compressor_name << "-D" << compressor_d_ness << "\n";
 
 				postings.write(&codex, 1);
+				if (codex == any_codex)
+					postings.write(codex_shortname.c_str(), codex_shortname.size() + 1);
+				manifest.version = 1;
+				}
+
+		public:
+			/*
+				SERIALISE_JASS_V1::SERIALISE_JASS_V1()
+				--------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents [in] The number of documents in the collection (used to allocate re-usable buffers).
+				@param codex [in] The codex used to compress the postings lists (default = elias_gamma_simd).
+				@param alignment [in] The start address of a postings list is padded to start on these boundaries (needed for compress_integer_QMX_jass_v1 (use 16), and others).  Default = 1.
+				@param filename_prefix [in] Prepended to the name of each file written (used by serialise_jass_v3 for its temporary files).  Default = "".
+			*/
+			serialise_jass_v1(size_t documents, jass_v1_codex codex = jass_v1_codex::elias_gamma_simd, int8_t alignment = 1, const std::string &filename_prefix = "") :
+				serialise_jass_v1(documents, codex, "", alignment, filename_prefix)
+				{
+				/* Nothing */
+				}
+
+			/*
+				SERIALISE_JASS_V1::SERIALISE_JASS_V1()
+				--------------------------------------
+			*/
+			/*!
+				@brief Constructor for an index compressed with any of the codexes in compress_integer_all (recorded in the index as any_codex)
+				@param documents [in] The number of documents in the collection (used to allocate re-usable buffers).
+				@param codex_shortname [in] The short command line name of the codex (e.g. "-cE", see compress_integer_all).
+				@param alignment [in] The start address of a postings list is padded to start on these boundaries (needed for compress_integer_QMX_jass_v1 (use 16), and others).  Default = 1.
+				@param filename_prefix [in] Prepended to the name of each file written (used by serialise_jass_v3 for its temporary files).  Default = "".
+			*/
+			serialise_jass_v1(size_t documents, const std::string &codex_shortname, int8_t alignment = 1, const std::string &filename_prefix = "") :
+				serialise_jass_v1(documents, any_codex, codex_shortname, alignment, filename_prefix)
+				{
+				/* Nothing */
 				}
 
 			/*
@@ -250,6 +327,34 @@ namespace JASS
 			*/
 			virtual void finish(void);
 
+			/*
+				SERIALISE_JASS_V1::GET_MANIFEST()
+				---------------------------------
+			*/
+			/*!
+				@brief Return the manifest so that the caller can describe how the index was built (the ranking function, quantization, and stemmer).
+				@details The format, codex, and counts are filled in by finish(), which then writes the manifest.
+				@return A reference to the manifest.
+			*/
+			index_manifest &get_manifest(void)
+				{
+				return manifest;
+				}
+
+			/*
+				SERIALISE_JASS_V1::ADD_AUXILIARY_FILE()
+				---------------------------------------
+			*/
+			/*!
+				@brief Record that a file written by the caller (such as CIstemmer.txt or CIpositions.bin) is part of the index so that finish() adds its size and checksum to the manifest.
+				@details The file must be complete before finish() is called.
+				@param filename [in] The name of the file (in the current directory).
+			*/
+			void add_auxiliary_file(const std::string &filename)
+				{
+				auxiliary_files.push_back(filename);
+				}
+
 			/*
 				 SERIALISE_JASS_V1::SERIALISE_VOCABULARY_POINTERS()
 				--------------------------------------------------
@@ -268,6 +373,36 @@ namespace JASS
 			*/
 			virtual void serialise_primary_keys(void);
 
+			/*
+				 SERIALISE_JASS_V1::CHECKSUM_POSTINGS()
+				---------------------------------------
+			*/
+			/*!
+				@brief Flush each of the files to disk then return the length and checksum of each postings list (computed as each was written).
+				@details The result is, for each term in the same order as CIvocab.bin, the length (uint64_t) of the postings list in CIpostings.bin
+				then its Fletcher 16-bit checksum (uint16_t).
+				@return The checksums (the contents of CIchecksums.bin).
+			*/
+			std::string checksum_postings(void);
+
+			/*
+				 SERIALISE_JASS_V1::SERIALISE_CHECKSUMS()
+				-----------------------------------------
+			*/
+			/*!
+				@brief Serialise the length and checksum of each postings list (CIchecksums.bin) and record the checksum of each index file in the manifest.
+			*/
+			void serialise_checksums(void);
+
+			/*
+				 SERIALISE_JASS_V1::COMPLETE_MANIFEST()
+				---------------------------------------
+			*/
+			/*!
+				@brief Fill in the parts of the manifest that are known once the index has been serialised (the codex, the counts, and the build time).
+			*/
+			void complete_manifest(void);
+
 			/*
 				SERIALISE_JASS_V1::DELEGATE::OPERATOR()()
 				-----------------------------------------
@@ -307,6 +442,44 @@ namespace JASS
 			*/
 			static compress_integer *get_compressor(jass_v1_codex codex, std::string &name, int32_t &d_ness);
 
+			/*
+				SERIALISE_JASS_V1::GET_COMPRESSOR()
+				-----------------------------------
+			*/
+			/*!
+				@brief Return a reference to a compressor/decompressor for an any_codex index
+				@param codex_shortname [in] The short command line name of the codex (e.g. "-cE", see compress_integer_all)
+				@param name [out] The name of the compression codex
+				@param d_ness [out] Whether the codex requires D0, D1, etc decoding (always 1 as the postings are D1 encoded before they are compressed)
+				@return A reference to a compress_integer that can decode the given codex
+			*/
+			static compress_integer *get_compressor(const std::string &codex_shortname, std::string &name, int32_t &d_ness);
+
+			/*
+				SERIALISE_JASS_V1::CAN_ENCODE_POSTINGS()
+				----------------------------------------
+			*/
+			/*!
+				@brief Check that a codex can compress a postings list by encoding and decoding some d-gaps.
+				@details Not every codex in compress_integer_all can be used in an index.  The first d-gap of a postings list can be 0 (the document id 0), which some codexes (e.g. Elias gamma) cannot encode, and others fail on short lists.
+				@param codex_shortname [in] The short command line name of the codex (e.g. "-cE", see compress_integer_all).
+				@return true if the d-gaps decode to what was encoded, else false.
+			*/
+			static bool can_encode_postings(const std::string &codex_shortname);
+
+			/*
+				SERIALISE_JASS_V1::CODEX_SHORTNAME()
+				------------------------------------
+			*/
+			/*!
+				@brief Given the name of the codex of an index (see index_manifest::codex), return the codex to pass to a serialiser to write an index with the same codex.
+				@param codex_name [in] The name of the codex.
+				@param default_codex [in] The codex the serialiser uses by default.
+				@param codex_shortname [out] "" if the codex is default_codex, else the short command line name of the codex (e.g. "-cE", see compress_integer_all).
+				@return true on success, false if the codex is not known.
+			*/
+			static bool codex_shortname(const std::string &codex_name, jass_v1_codex default_codex, std::string &codex_shortname);
+
 			/*
 				SERIALISE_JASS_V1::UNITTEST()
 				-----------------------------
@@ -32,6 +32,8 @@ namespace JASS
 			Keep a track of where the postings are stored on disk (we return this to the caller).
 		*/
 		size_t postings_location = postings.tell();
+		postings_length = 0;
+		postings_checksum = 0;
 
 		/*
 			Impact order the postings list.
@@ -116,13 +118,13 @@ namespace JASS
 			Write out each postings list header.
 		*/
 		for (const auto &header : reverse(compressed_headers))
-			postings.write(header.address(), header.size());
+			write_to_postings(header.address(), header.size());
 
 		/*
 			Write out each postings list segment.
 		*/
 		for (const auto &segment : compressed_segments)
-			postings.write(segment.address(), segment.size());
+			write_to_postings(segment.address(), segment.size());
 
 		/*
 			Return the location of the postings list on disk
@@ -159,7 +161,7 @@ namespace JASS
 		/*
 			Keep a copy of the term and the detals of the postings list for later sorting and writing to CIvocab.bin
 		*/
-		index_key.push_back(vocab_tripple(term, term_offset, postings_location, number_of_impact_scores));
+		index_key.push_back(vocab_tripple(term, term_offset, postings_location, number_of_impact_scores, postings_length, postings_checksum));
 		}
 
 	/*
@@ -55,12 +55,31 @@ namespace JASS
 				@param documents [in] The number of documents in the collection (used to allocate re-usable buffers).
 				@param encoder [in] An shared pointer to a codex responsible for performing the compression of postings lists (default = compress_integer_QMX_jass_v1()).
 				@param alignment [in] The start address of a postings list is padded to start on these boundaries (needed for compress_integer_QMX_jass_v1 (use 16), and others).  Default = 0.
+				@param filename_prefix [in] Prepended to the name of each file written (used by serialise_jass_v3 for its temporary files).  Default = "".
 			*/
-			serialise_jass_v2(size_t documents, jass_v1_codex codex = jass_v1_codex::elias_gamma_simd_vb, int8_t alignment = 1) :
-				serialise_jass_v1(documents, codex, alignment),
+			serialise_jass_v2(size_t documents, jass_v1_codex codex = jass_v1_codex::elias_gamma_simd_vb, int8_t alignment = 1, const std::string &filename_prefix = "") :
+				serialise_jass_v1(documents, codex, alignment, filename_prefix),
 				compressed_headers(allocator)
 				{
-				/* Nothing */
+				manifest.version = 2;
+				}
+
+			/*
+				SERIALISE_JASS_V2::SERIALISE_JASS_V2()
+				--------------------------------------
+			*/
+			/*!
+				@brief Constructor for an index compressed with any of the codexes in compress_integer_all
+				@param documents [in] The number of documents in the collection (used to allocate re-usable buffers).
+				@param codex_shortname [in] The short command line name of the codex (e.g. "-cE", see compress_integer_all).
+				@param alignment [in] The start address of a postings list is padded to start on these boundaries.  Default = 1.
+				@param filename_prefix [in] Prepended to the name of each file written.  Default = "".
+			*/
+			serialise_jass_v2(size_t documents, const std::string &codex_shortname, int8_t alignment = 1, const std::string &filename_prefix = "") :
+				serialise_jass_v1(documents, codex_shortname, alignment, filename_prefix),
+				compressed_headers(allocator)
+				{
+				manifest.version = 2;
 				}
 
 			/*
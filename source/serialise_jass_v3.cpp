@@ -0,0 +1,311 @@
+/*
+	SERIALISE_JASS_V3.CPP
+	---------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <stdlib.h>
+#include <string.h>
+
+#include <memory>
+#include <iostream>
+#include <filesystem>
+
+#include "asserts.h"
+#include "checksum.h"
+#include "allocator.h"
+#include "unittest_data.h"
+#include "serialise_jass_v3.h"
+#include "compress_integer_all.h"
+#include "deserialised_jass_v2.h"
+#include "deserialised_jass_v3.h"
+#include "index_manager_sequential.h"
+
+namespace JASS
+	{
+	/*
+		SERIALISE_JASS_V3::FINISH()
+		---------------------------
+	*/
+	void serialise_jass_v3::finish(void)
+		{
+		/*
+			Finish the JASS v2 files (which are temporary files) and describe the index
+		*/
+		serialise_vocabulary_pointers();
+		serialise_primary_keys();
+		std::string checksums = checksum_postings();
+		complete_manifest();
+		std::string metadata = manifest.serialise();
+
+		/*
+			Map each of the temporary files into memory (an empty file has no memory)
+		*/
+		file::file_read_only primary_key_file;
+		file::file_read_only vocabulary_file;
+		file::file_read_only terms_file;
+		file::file_read_only postings_file;
+		const char *temporary_filenames[] = {"CIdoclist.bin", "CIvocab.bin", "CIvocab_terms.bin", "CIpostings.bin"};
+		file::file_read_only *temporary_files[] = {&primary_key_file, &vocabulary_file, &terms_file, &postings_file};
+		section_type temporary_types[] = {PRIMARY_KEYS, VOCABULARY, TERMS, POSTINGS};
+
+		std::vector<section> table;
+		std::vector<const uint8_t *> contents;
+		for (size_t which = 0; which < 4; which++)
+			{
+			const uint8_t *memory = nullptr;
+			size_t length = file::read_entire_file(filename_prefix + temporary_filenames[which], *temporary_files[which]);
+			if (length != 0)
+				temporary_files[which]->read_entire_file(memory);
+			table.push_back(section{temporary_types[which], 0, length, 0});
+			contents.push_back(memory);
+			}
+		table.push_back(section{METADATA, 0, metadata.size(), 0});
+		contents.push_back(reinterpret_cast<const uint8_t *>(metadata.data()));
+		table.push_back(section{CHECKSUMS, 0, checksums.size(), 0});
+		contents.push_back(reinterpret_cast<const uint8_t *>(checksums.data()));
+
+		/*
+			Add the other sections (the stemmer, stop words, and so on) so that the index is self contained
+		*/
+		for (const auto &[type, data] : extra_sections)
+			{
+			table.push_back(section{type, 0, data.size(), 0});
+			contents.push_back(reinterpret_cast<const uint8_t *>(data.data()));
+			}
+
+		std::vector<std::unique_ptr<file::file_read_only>> extra_files;
+		for (const auto &[type, filename] : extra_section_files)
+			{
+			extra_files.push_back(std::make_unique<file::file_read_only>());
+			const uint8_t *memory = nullptr;
+			size_t length = std::filesystem::exists(filename) ? file::read_entire_file(filename, *extra_files.back()) : 0;
+			if (length == 0)
+				continue;
+			extra_files.back()->read_entire_file(memory);
+			table.push_back(section{type, 0, length, 0});
+			contents.push_back(memory);
+			}
+
+		/*
+			Lay out the sections, each on a page boundary after the header and the section table
+		*/
+		header head;
+		memcpy(head.magic, MAGIC, sizeof(head.magic));
+		head.version = 3;
+		head.sections = table.size();
+
+		uint64_t offset = sizeof(head) + table.size() * sizeof(section);
+		for (size_t which = 0; which < table.size(); which++)
+			{
+			offset += allocator::realign(offset, PAGE_SIZE);
+			table[which].offset = offset;
+			table[which].checksum = table[which].length == 0 ? 0 : checksum::fletcher_16(contents[which], table[which].length);
+			offset += table[which].length;
+			}
+
+		/*
+			Write the index to a temporary file then rename it so that anyone reading the index sees either the old one or the new one.  The
+			temporary file is on the disk before the rename and the rename is on the disk before returning so that a crash leaves one or the other.
+		*/
+		std::string temporary_filename = std::string(FILENAME) + ".tmp";
+		bool written;
+		{
+		file out(temporary_filename, "w+b");
+		static const uint8_t zeros[PAGE_SIZE] = {};
+
+		out.write(&head, sizeof(head));
+		out.write(table.data(), table.size() * sizeof(section));
+		for (size_t which = 0; which < table.size(); which++)
+			{
+			out.write(zeros, table[which].offset - out.tell());
+			if (table[which].length != 0)
+				out.write(contents[which], table[which].length);
+			}
+		written = out.sync();
+		}
+
+		/*
+			Clean up the temporary files (and on failure the partly written index, leaving any previous index as it was)
+		*/
+		std::error_code error;
+		for (const auto &filename : temporary_filenames)
+			std::filesystem::remove(filename_prefix + filename, error);
+
+		if (!written)
+			{
+			std::filesystem::remove(temporary_filename, error);
+			std::cout << "Failed to write the index (" << temporary_filename << ")\n";
+			exit(1);
+			}
+
+		std::filesystem::rename(temporary_filename, FILENAME, error);
+		if (error)
+			{
+			std::filesystem::remove(temporary_filename, error);
+			std::cout << "Failed to replace the index (" << FILENAME << ") with " << temporary_filename << "\n";
+			exit(1);
+			}
+		file::sync_directory(".");
+		}
+
+	/*
+		SERIALISE_JASS_V3::UNITTEST()
+		-----------------------------
+	*/
+	void serialise_jass_v3::unittest(void)
+		{
+		/*
+			Build an index and serialise it as both JASS v2 and JASS v3
+		*/
+		index_manager_sequential index;
+		index_manager_sequential::unittest_build_index(index, unittest_data::ten_documents);
+
+		{
+		serialise_jass_v2 serialiser(index.get_highest_document_id());
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		{
+		serialise_jass_v3 serialiser(index.get_highest_document_id());
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+
+		/*
+			The temporary files have gone
+		*/
+		JASS_assert(!std::filesystem::exists(std::string(FILENAME) + ".tmp"));
+		JASS_assert(!std::filesystem::exists(std::string(FILENAME) + ".tmp.CIpostings.bin"));
+
+		/*
+			Check the header and section table
+		*/
+		std::string index_file;
+		file::read_entire_file(FILENAME, index_file);
+		JASS_assert(index_file.size() > sizeof(header));
+
+		header head;
+		memcpy(&head, index_file.data(), sizeof(head));
+		JASS_assert(strcmp(head.magic, MAGIC) == 0);
+		JASS_assert(head.version == 3);
+		JASS_assert(head.sections == 6);
+
+		/*
+			Each section is page aligned, has the right checksum, and holds what the JASS v2 file holds
+		*/
+		const char *same_as[] = {nullptr, "CIdoclist.bin", "CIvocab.bin", "CIvocab_terms.bin", "CIpostings.bin", nullptr, CHECKSUMS_FILENAME};
+		for (size_t which = 0; which < head.sections; which++)
+			{
+			section current;
+			memcpy(&current, index_file.data() + sizeof(head) + which * sizeof(section), sizeof(current));
+
+			JASS_assert(current.offset % PAGE_SIZE == 0);
+			JASS_assert(current.offset + current.length <= index_file.size());
+			JASS_assert(current.type >= PRIMARY_KEYS && current.type <= CHECKSUMS);
+
+			std::string contents = index_file.substr(current.offset, current.length);
+			JASS_assert(checksum::fletcher_16(contents) == current.checksum);
+
+			if (current.type == METADATA)
+				{
+				index_manifest manifest;
+				JASS_assert(manifest.deserialise(contents));
+				JASS_assert(manifest.version == 3 && manifest.documents == 10 && manifest.terms == 20 && manifest.files.size() == 0);
+				}
+			else
+				{
+				std::string original;
+				file::read_entire_file(same_as[current.type], original);
+				JASS_assert(contents == original);
+				}
+			}
+
+		/*
+			Which codexes can compress an index, and how to write an index with the same codex as another
+		*/
+		JASS_assert(can_encode_postings("-cV"));
+		JASS_assert(can_encode_postings("-c256"));
+		JASS_assert(!can_encode_postings("-cUnknown"));
+
+		std::string shortname;
+		JASS_assert(codex_shortname("Group Elias Gamma SIMD with Variable Byte", jass_v1_codex::elias_gamma_simd_vb, shortname) && shortname == "");
+		JASS_assert(codex_shortname("Group Elias Gamma SIMD", jass_v1_codex::elias_gamma_simd, shortname) && shortname == "");
+		JASS_assert(codex_shortname("Group Elias Gamma SIMD", jass_v1_codex::elias_gamma_simd_vb, shortname) && shortname == "-cE");
+		JASS_assert(codex_shortname(compress_integer_all::name_of("-c256"), jass_v1_codex::elias_gamma_simd_vb, shortname) && shortname == "-c256");
+		JASS_assert(!codex_shortname("Unknown", jass_v1_codex::elias_gamma_simd_vb, shortname));
+
+		/*
+			An index compressed with any of the compress_integer_all codexes decodes to the same postings as one using the default codex
+		*/
+		auto decode_all = [](std::string &codex_name)
+			{
+			deserialised_jass_v2 loaded;
+			JASS_assert(loaded.read_index() != 0);
+
+			int32_t d_ness;
+			std::unique_ptr<compress_integer> decoder(loaded.codex(codex_name, d_ness));
+			JASS_assert(decoder != nullptr && d_ness == 1);
+			index_manifest manifest;
+			JASS_assert(manifest.read() && manifest.codex == codex_name);
+
+			std::vector<std::vector<compress_integer::integer>> everything;
+			std::vector<compress_integer::integer> decoded(loaded.document_count() + 1024);		// some codexes decode more integers than asked for
+			for (auto &term : loaded)
+				{
+				std::vector<deserialised_jass_v1::segment_header> segments(term.impacts);
+				uint32_t smallest;
+				uint32_t largest;
+				query::DOCID_TYPE document_frequency;
+				loaded.get_segment_list(segments.data(), term, 1, smallest, largest, document_frequency);
+				for (const auto &segment : segments)
+					{
+					decoder->decode(decoded.data(), segment.segment_frequency, loaded.postings() + segment.offset, segment.end - segment.offset);
+					everything.push_back(std::vector<compress_integer::integer>(1, segment.impact));
+					compress_integer::integer document_id = 0;
+					for (size_t which = 0; which < segment.segment_frequency; which++)
+						everything.back().push_back(document_id += decoded[which]);
+					}
+				}
+			return everything;
+			};
+
+		{
+		serialise_jass_v2 serialiser(index.get_highest_document_id());
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		std::string codex_name;
+		auto expected = decode_all(codex_name);
+
+		for (const char *codex : {"-cV", "-c256", "-cs"})
+			{
+			{
+			serialise_jass_v2 serialiser(index.get_highest_document_id(), codex);
+			index.iterate(serialiser);
+			serialiser.finish();
+			}
+			JASS_assert(decode_all(codex_name) == expected);
+			JASS_assert(codex_name == compress_integer_all::name_of(codex));
+			}
+
+		/*
+			A JASS v3 index records its codex in the manifest it holds, and is loaded with it
+		*/
+		{
+		serialise_jass_v3 serialiser(index.get_highest_document_id(), "-c256");
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+		{
+		deserialised_jass_v3 loaded;
+		JASS_assert(loaded.read_index("", true) != 0);
+
+		int32_t d_ness;
+		std::unique_ptr<compress_integer> decoder(loaded.codex(codex_name, d_ness));
+		JASS_assert(decoder != nullptr && codex_name == compress_integer_all::name_of("-c256") && loaded.get_manifest().codex == codex_name);
+		}
+
+		puts("serialise_jass_v3::PASSED");
+		}
+	}
@@ -0,0 +1,190 @@
+/*
+	SERIALISE_JASS_V3.H
+	-------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Serialise an index in the format used by JASS version 3 (a JASS v2 index in a single file).
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+#include <vector>
+#include <utility>
+
+#include "serialise_jass_v2.h"
+
+namespace JASS
+	{
+	/*
+		CLASS SERIALISE_JASS_V3
+		-----------------------
+	*/
+	/*!
+		@brief Serialise an index in the format used by JASS version 3 (a JASS v2 index in a single file).
+		@details A JASS v3 index is a single file (CIindex.bin) so that it can be deployed, versioned, and swapped as one.  The file starts with
+		a header (the magic number, the version, and the number of sections) followed by a table of sections, one per section, each of which is
+		(type, offset, length, checksum).  Each section starts on a page boundary (so the whole file can be memory mapped and each section used in
+		place) and holds exactly what the JASS v2 file of the same purpose would hold (see serialise_jass_v1 and serialise_jass_v2), so offsets
+		into the postings are relative to the start of the postings section.  The metadata section is the manifest (see index_manifest) and the
+		checksums section holds the length and checksum of each postings list (see serialise_jass_v1::checksum_postings()).  The checksum of each
+		section is its Fletcher 16-bit checksum.  The index is written to a temporary file which is then renamed so that replacing an index is atomic.
+		The stemmer, stop words, quantization, and positions are (optional) sections holding what the JASS v2 files would (see add_section()).
+	*/
+	class serialise_jass_v3 : public serialise_jass_v2
+		{
+		public:
+			static constexpr const char *FILENAME = "CIindex.bin";		///< The name of the index file.
+			static constexpr const char *MAGIC = "JASS v3";					///< The first 8 bytes of the file (including the '\0').
+			static constexpr size_t PAGE_SIZE = 4096;						///< Each section starts on a boundary of this many bytes.
+
+			/*!
+				@enum section_type
+				@brief The kinds of section in a JASS v3 index.
+			*/
+			enum section_type : uint64_t
+				{
+				PRIMARY_KEYS = 1,			///< The primary keys (as in CIdoclist.bin)
+				VOCABULARY = 2,			///< The vocabulary pointers (as in CIvocab.bin)
+				TERMS = 3,					///< The vocabulary strings (as in CIvocab_terms.bin)
+				POSTINGS = 4,				///< The postings (as in CIpostings.bin)
+				METADATA = 5,				///< The manifest (as in CImanifest.txt)
+				CHECKSUMS = 6,				///< The length and checksum of each postings list (as in CIchecksums.bin)
+				STEMMER = 7,				///< The name of the stemmer (as in CIstemmer.txt)
+				STOPWORDS = 8,				///< The stop words (as in CIstopwords.txt)
+				QUANTIZATION = 9,			///< The quantization scheme (as in CIquantization.txt)
+				POSITIONS = 10,			///< The positions of each term in each document (as in CIpositions.bin)
+				LAST_SECTION = POSITIONS	///< The largest section_type
+				};
+
+			/*
+				CLASS SERIALISE_JASS_V3::HEADER
+				-------------------------------
+			*/
+			/*!
+				@brief The start of a JASS v3 index file, it is followed by the section table.
+			*/
+			class header
+				{
+				public:
+					char magic[8];					///< MAGIC
+					uint64_t version;				///< The version of the format (3).
+					uint64_t sections;			///< The number of sections in the section table.
+				};
+
+			/*
+				CLASS SERIALISE_JASS_V3::SECTION
+				--------------------------------
+			*/
+			/*!
+				@brief An entry in the section table.
+			*/
+			class section
+				{
+				public:
+					uint64_t type;					///< The kind of section (see section_type).
+					uint64_t offset;				///< The offset of the section from the start of the file (a multiple of PAGE_SIZE).
+					uint64_t length;				///< The length of the section in bytes.
+					uint64_t checksum;			///< The Fletcher 16-bit checksum of the section.
+				};
+
+		private:
+			std::vector<std::pair<section_type, std::string>> extra_sections;				///< The sections (other than those of a JASS v2 index) and their contents (see add_section()).
+			std::vector<std::pair<section_type, std::string>> extra_section_files;		///< The sections (other than those of a JASS v2 index) and the name of the file holding their contents (see add_section_file()).
+
+		public:
+			/*
+				SERIALISE_JASS_V3::SERIALISE_JASS_V3()
+				--------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents [in] The number of documents in the collection (used to allocate re-usable buffers).
+				@param codex [in] The codex used to compress the postings lists.
+				@param alignment [in] The start address of a postings list is padded to start on these boundaries.  Default = 1.
+			*/
+			serialise_jass_v3(size_t documents, jass_v1_codex codex = jass_v1_codex::elias_gamma_simd_vb, int8_t alignment = 1) :
+				serialise_jass_v2(documents, codex, alignment, std::string(FILENAME) + ".tmp.")
+				{
+				manifest.version = 3;
+				}
+
+			/*
+				SERIALISE_JASS_V3::SERIALISE_JASS_V3()
+				--------------------------------------
+			*/
+			/*!
+				@brief Constructor for an index compressed with any of the codexes in compress_integer_all
+				@param documents [in] The number of documents in the collection (used to allocate re-usable buffers).
+				@param codex_shortname [in] The short command line name of the codex (e.g. "-cE", see compress_integer_all).
+				@param alignment [in] The start address of a postings list is padded to start on these boundaries.  Default = 1.
+			*/
+			serialise_jass_v3(size_t documents, const std::string &codex_shortname, int8_t alignment = 1) :
+				serialise_jass_v2(documents, codex_shortname, alignment, std::string(FILENAME) + ".tmp.")
+				{
+				manifest.version = 3;
+				}
+
+			/*
+				SERIALISE_JASS_V3::~SERIALISE_JASS_V3()
+				---------------------------------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~serialise_jass_v3()
+				{
+				/* Nothing */
+				}
+
+			/*
+				SERIALISE_JASS_V3::ADD_SECTION()
+				--------------------------------
+			*/
+			/*!
+				@brief Add a section (such as the STEMMER or STOPWORDS) to the index.  Must be called before finish().
+				@param type [in] The kind of section.
+				@param contents [in] The contents of the section (what the JASS v2 file of the same purpose would hold).
+			*/
+			void add_section(section_type type, const std::string &contents)
+				{
+				extra_sections.push_back(std::make_pair(type, contents));
+				}
+
+			/*
+				SERIALISE_JASS_V3::ADD_SECTION_FILE()
+				-------------------------------------
+			*/
+			/*!
+				@brief Add a section (such as the POSITIONS) to the index from a file, which is read when the index is written.  Must be called before finish().
+				@param type [in] The kind of section.
+				@param filename [in] The name of the file (what the JASS v2 file of the same purpose would hold), if it is missing then the section is not added.
+			*/
+			void add_section_file(section_type type, const std::string &filename)
+				{
+				extra_section_files.push_back(std::make_pair(type, filename));
+				}
+
+			/*
+				SERIALISE_JASS_V3::FINISH()
+				---------------------------
+			*/
+			/*!
+				@brief Finish serialising the index by writing the single index file (through a temporary file that is then renamed).
+			*/
+			virtual void finish(void);
+
+			/*
+				SERIALISE_JASS_V3::UNITTEST()
+				-----------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
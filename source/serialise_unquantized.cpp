@@ -0,0 +1,270 @@
+/*
+	SERIALISE_UNQUANTIZED.CPP
+	-------------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <string.h>
+
+#include <limits>
+#include <sstream>
+#include <iostream>
+#include <filesystem>
+
+#include "posting.h"
+#include "unittest_data.h"
+#include "serialise_unquantized.h"
+#include "index_manager_sequential.h"
+
+namespace JASS
+	{
+	/*
+		READ_INTEGER()
+		--------------
+	*/
+	/*!
+		@brief Read an integer from the buffer, and move past it.
+		@param current [in / out] The place to read from.
+		@param end [in] The end of the buffer.
+		@param value [out] The value.
+		@return false if there are not enough bytes left in the buffer, else true.
+	*/
+	template <typename TYPE>
+	static bool read_integer(const char *&current, const char *end, TYPE &value)
+		{
+		if (end - current < static_cast<ptrdiff_t>(sizeof(value)))
+			return false;
+
+		memcpy(&value, current, sizeof(value));
+		current += sizeof(value);
+		return true;
+		}
+
+	/*
+		SERIALISE_UNQUANTIZED::SERIALISE_UNQUANTIZED()
+		----------------------------------------------
+	*/
+	serialise_unquantized::serialise_unquantized(size_t documents_in_collection, std::vector<compress_integer::integer> &document_lengths, const std::string &filename) :
+		index_manager::delegate(documents_in_collection),
+		unquantized_file(filename, "w+b"),
+		document_lengths(document_lengths),
+		writing_terms(true)
+		{
+		unquantized_file.write(MAGIC, strlen(MAGIC));
+		}
+
+	/*
+		SERIALISE_UNQUANTIZED::END_TERMS()
+		----------------------------------
+	*/
+	void serialise_unquantized::end_terms(void)
+		{
+		if (writing_terms)
+			{
+			uint32_t end_marker = 0;
+			unquantized_file.write(&end_marker, sizeof(end_marker));
+			writing_terms = false;
+			}
+		}
+
+	/*
+		SERIALISE_UNQUANTIZED::OPERATOR()()
+		-----------------------------------
+	*/
+	void serialise_unquantized::operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies)
+		{
+		if (term.size() == 0 || document_frequency == 0)
+			return;				// an empty term would look like the end of the terms
+
+		uint32_t length = static_cast<uint32_t>(term.size());
+		unquantized_file.write(&length, sizeof(length));
+		unquantized_file.write(term.address(), term.size());
+
+		uint32_t frequency = static_cast<uint32_t>(document_frequency);
+		unquantized_file.write(&frequency, sizeof(frequency));
+
+		static_assert(sizeof(*document_ids) == sizeof(uint32_t) && sizeof(*term_frequencies) == sizeof(uint16_t));
+		unquantized_file.write(document_ids, document_frequency * sizeof(*document_ids));
+		unquantized_file.write(term_frequencies, document_frequency * sizeof(*term_frequencies));
+		}
+
+	/*
+		SERIALISE_UNQUANTIZED::OPERATOR()()
+		-----------------------------------
+	*/
+	void serialise_unquantized::operator()(size_t document_id, const slice &primary_key)
+		{
+		end_terms();
+
+		if (document_id == 0)
+			return;				// document 0 is the dud document "-" and is not stored
+
+		uint32_t length = static_cast<uint32_t>(primary_key.size());
+		unquantized_file.write(&length, sizeof(length));
+		unquantized_file.write(primary_key.address(), primary_key.size());
+		}
+
+	/*
+		SERIALISE_UNQUANTIZED::FINISH()
+		-------------------------------
+	*/
+	void serialise_unquantized::finish(void)
+		{
+		end_terms();
+
+		/*
+			The end of the primary keys is marked by the count of document lengths, which has its high bit set so that it can't be mistaken for a key length
+		*/
+		uint32_t count = static_cast<uint32_t>(document_lengths.size()) | 0x80000000;
+		unquantized_file.write(&count, sizeof(count));
+
+		for (uint32_t length : document_lengths)
+			unquantized_file.write(&length, sizeof(length));
+		}
+
+	/*
+		SERIALISE_UNQUANTIZED::LOAD()
+		-----------------------------
+	*/
+	bool serialise_unquantized::load(const std::string &filename, index_manager_sequential &index, std::string &buffer)
+		{
+		if (!std::filesystem::exists(filename) || file::read_entire_file(filename, buffer) == 0)
+			return false;
+
+		const char *current = buffer.data();
+		const char *end = buffer.data() + buffer.size();
+
+		if (buffer.size() < strlen(MAGIC) || memcmp(current, MAGIC, strlen(MAGIC)) != 0)
+			return false;
+		current += strlen(MAGIC);
+
+		/*
+			The postings, which are given to the index as D1-encoded postings lists
+		*/
+		std::vector<posting> postings_list;
+		uint32_t length;
+		while (true)
+			{
+			if (!read_integer(current, end, length))
+				return false;
+			if (length == 0)
+				break;
+
+			if (end - current < length)
+				return false;
+			slice term(const_cast<char *>(current), length);
+			current += length;
+
+			uint32_t document_frequency;
+			if (!read_integer(current, end, document_frequency) || static_cast<size_t>(end - current) < static_cast<size_t>(document_frequency) * (sizeof(uint32_t) + sizeof(uint16_t)))
+				return false;
+
+			const char *term_frequencies = current + document_frequency * sizeof(uint32_t);
+			postings_list.resize(document_frequency);
+			uint32_t previous = 0;
+			for (auto &posting : postings_list)
+				{
+				uint32_t document_id = 0;
+				uint16_t term_frequency = 0;
+
+				read_integer(current, end, document_id);
+				read_integer(term_frequencies, end, term_frequency);
+				posting.docid = document_id - previous;
+				posting.term_frequency = term_frequency;
+				previous = document_id;
+				}
+			current = term_frequencies;
+
+			parser::token token;
+			token.set(term);
+			index.term(token, postings_list);
+			}
+
+		/*
+			The primary keys (which end at the count of document lengths)
+		*/
+		std::vector<slice> primary_keys;
+		while (true)
+			{
+			if (!read_integer(current, end, length))
+				return false;
+			if (length & 0x80000000)
+				break;
+
+			if (end - current < length)
+				return false;
+			primary_keys.push_back(slice(const_cast<char *>(current), length));
+			current += length;
+			}
+
+		/*
+			The document lengths
+		*/
+		std::vector<compress_integer::integer> document_lengths(length & ~0x80000000);
+		for (auto &document_length : document_lengths)
+			if (!read_integer(current, end, document_length))
+				return false;
+
+		if (document_lengths.size() != primary_keys.size() + 1)
+			return false;				// there is one more length than key because document 0 has a length but no key
+
+		index.set_primary_keys(primary_keys);
+		index.set_document_length_vector(document_lengths);
+
+		return true;
+		}
+
+	/*
+		SERIALISE_UNQUANTIZED::UNITTEST()
+		---------------------------------
+	*/
+	void serialise_unquantized::unittest(void)
+		{
+		const std::string filename = "serialise_unquantized_unittest.bin";
+
+		/*
+			Build an index of the standard 10 documents and serialise it
+		*/
+		index_manager_sequential index;
+		index_manager_sequential::unittest_build_index(index, unittest_data::ten_documents);
+		{
+		serialise_unquantized serialiser(index.get_highest_document_id(), index.get_document_length_vector(), filename);
+		index.iterate(serialiser);
+		serialiser.finish();
+		}
+
+		/*
+			Load it back and check that it's the same index
+		*/
+		index_manager_sequential reloaded;
+		std::string buffer;
+		JASS_assert(load(filename, reloaded, buffer));
+
+		std::ostringstream original_postings;
+		std::ostringstream original_keys;
+		index_manager_sequential::delegate original(index.get_highest_document_id(), original_postings, original_keys);
+		index.iterate_sorted(original);
+
+		std::ostringstream reloaded_postings;
+		std::ostringstream reloaded_keys;
+		index_manager_sequential::delegate reloaded_callback(reloaded.get_highest_document_id(), reloaded_postings, reloaded_keys);
+		reloaded.iterate_sorted(reloaded_callback);
+
+		JASS_assert(reloaded.get_highest_document_id() == index.get_highest_document_id());
+		JASS_assert(reloaded.get_document_length_vector() == index.get_document_length_vector());
+		JASS_assert(reloaded_postings.str() == original_postings.str());
+		JASS_assert(reloaded_keys.str() == original_keys.str());
+
+		/*
+			A damaged file is rejected
+		*/
+		file::write_entire_file(filename, buffer.substr(0, buffer.size() - 1));
+		index_manager_sequential damaged;
+		std::string damaged_buffer;
+		JASS_assert(!load(filename, damaged, damaged_buffer));
+		JASS_assert(!load("serialise_unquantized_missing.bin", damaged, damaged_buffer));
+		std::filesystem::remove(filename);
+
+		puts("serialise_unquantized::PASSED");
+		}
+	}
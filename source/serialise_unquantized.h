@@ -0,0 +1,141 @@
+/*
+	SERIALISE_UNQUANTIZED.H
+	-----------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Serialise the unquantized (term frequency) index so that it can later be re-quantized without re-parsing the collection
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+#include <vector>
+
+#include "file.h"
+#include "index_manager.h"
+
+namespace JASS
+	{
+	class index_manager_sequential;
+
+	/*
+		CLASS SERIALISE_UNQUANTIZED
+		---------------------------
+	*/
+	/*!
+		@brief Serialise the unquantized (term frequency) index so that it can later be re-quantized without re-parsing the collection
+		@details This serialiser must be given the index before quantization (that is, index_manager::iterate() rather than quantize::serialise_index()).
+		The file holds everything a ranking function needs and is read back with load().  The format (all integers in Intel byte order) is:
+		\<"JASSunq1"\>
+		then for each term \<uint32_t term length\>\<term\>\<uint32_t document frequency\>\<uint32_t docid\>...\<uint32_t docid\>\<uint16_t tf\>...\<uint16_t tf\>
+		then \<uint32_t 0\>
+		then for each document (from 1) \<uint32_t primary key length\>\<primary key\>
+		then \<uint32_t number of document lengths with the high bit set\>\<uint32_t length\>...\<uint32_t length\> (from document 0, which is unused).
+	*/
+	class serialise_unquantized : public index_manager::delegate
+		{
+		public:
+			static constexpr const char *FILENAME = "CIunquantized.bin";			///< The name of the file this class writes.
+			static constexpr const char *MAGIC = "JASSunq1";						///< The first 8 bytes of the file.
+
+		private:
+			file unquantized_file;											///< The file being written.
+			std::vector<compress_integer::integer> &document_lengths;		///< The length of each document.
+			bool writing_terms;												///< Are we still writing postings (rather than primary keys)?
+
+		private:
+			/*
+				SERIALISE_UNQUANTIZED::END_TERMS()
+				----------------------------------
+			*/
+			/*!
+				@brief Write the marker that ends the postings (if it has not already been written).
+			*/
+			void end_terms(void);
+
+		public:
+			/*
+				SERIALISE_UNQUANTIZED::SERIALISE_UNQUANTIZED()
+				----------------------------------------------
+			*/
+			/*!
+				@brief Constructor
+				@param documents_in_collection [in] The number of documents in the collection.
+				@param document_lengths [in] The length of each document (element 0 is unused), which is written by finish().
+				@param filename [in] The name of the file to write.
+			*/
+			serialise_unquantized(size_t documents_in_collection, std::vector<compress_integer::integer> &document_lengths, const std::string &filename = FILENAME);
+
+			/*
+				SERIALISE_UNQUANTIZED::~SERIALISE_UNQUANTIZED()
+				-----------------------------------------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~serialise_unquantized()
+				{
+				/* Nothing */
+				}
+
+			/*
+				SERIALISE_UNQUANTIZED::OPERATOR()()
+				-----------------------------------
+			*/
+			/*!
+				@brief The callback function to serialise the postings (given the term) is operator().
+				@param term [in] The term name.
+				@param postings [in] The postings lists.
+				@param document_frequency [in] The document frequency of the term
+				@param document_ids [in] An array (of length document_frequency) of document ids.
+				@param term_frequencies [in] An array (of length document_frequency) of term frequencies (corresponding to document_ids).
+			*/
+			virtual void operator()(const slice &term, const index_postings &postings, compress_integer::integer document_frequency, compress_integer::integer *document_ids, index_postings_impact::impact_type *term_frequencies);
+
+			/*
+				SERIALISE_UNQUANTIZED::OPERATOR()()
+				-----------------------------------
+			*/
+			/*!
+				@brief The callback function to serialise the primary keys (external document ids) is operator().
+				@param document_id [in] The internal document identfier.
+				@param primary_key [in] This document's primary key (external document identifier).
+			*/
+			virtual void operator()(size_t document_id, const slice &primary_key);
+
+			/*
+				SERIALISE_UNQUANTIZED::FINISH()
+				-------------------------------
+			*/
+			/*!
+				@brief Write the document lengths.
+			*/
+			virtual void finish(void);
+
+			/*
+				SERIALISE_UNQUANTIZED::LOAD()
+				-----------------------------
+			*/
+			/*!
+				@brief Read a file written by this class into an index (which can then be quantized).
+				@param filename [in] The name of the file.
+				@param index [out] The index to load into (which should be empty).
+				@param buffer [out] The contents of the file, which must not be destroyed before index (the primary keys point into it).
+				@return false if the file cannot be read or is not in the right format, else true.
+			*/
+			static bool load(const std::string &filename, index_manager_sequential &index, std::string &buffer);
+
+			/*
+				SERIALISE_UNQUANTIZED::UNITTEST()
+				---------------------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
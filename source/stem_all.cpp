@@ -0,0 +1,76 @@
+/*
+	STEM_ALL.CPP
+	------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <memory>
+
+#include "asserts.h"
+#include "stem_all.h"
+#include "stem_s.h"
+#include "stem_porter.h"
+#include "stem_krovetz.h"
+#include "stem_porter2.h"
+
+namespace JASS
+	{
+	/*
+		STEM_ALL::GET_BY_NAME()
+		-----------------------
+	*/
+	stem *stem_all::get_by_name(const std::string &name)
+		{
+		if (name == "Porter")
+			return new stem_porter;
+		if (name == "Porter2")
+			return new stem_porter2;
+		if (name == "Krovetz")
+			return new stem_krovetz;
+		if (name == "S")
+			return new stem_s;
+
+		return nullptr;
+		}
+
+	/*
+		STEM_ALL::IS_KNOWN()
+		--------------------
+	*/
+	bool stem_all::is_known(const std::string &name)
+		{
+		if (name == NO_STEMMER)
+			return true;
+
+		std::unique_ptr<stem> stemmer(get_by_name(name));
+		return stemmer != nullptr;
+		}
+
+	/*
+		STEM_ALL::UNITTEST()
+		--------------------
+	*/
+	void stem_all::unittest(void)
+		{
+		/*
+			Each stemmer must be able to find itself by name
+		*/
+		for (const char *name : {"Porter", "Porter2", "Krovetz", "S"})
+			{
+			std::unique_ptr<stem> stemmer(get_by_name(name));
+			JASS_assert(stemmer != nullptr);
+			JASS_assert(stemmer->name() == name);
+			JASS_assert(is_known(stemmer->name()));
+			}
+
+		/*
+			No stemmer and unknown stemmers
+		*/
+		JASS_assert(get_by_name(NO_STEMMER) == nullptr);
+		JASS_assert(is_known(NO_STEMMER));
+		JASS_assert(get_by_name("Unknown") == nullptr);
+		JASS_assert(!is_known("Unknown"));
+
+		puts("stem_all::PASSED");
+		}
+	}
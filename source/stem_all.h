@@ -0,0 +1,67 @@
+/*
+	STEM_ALL.H
+	----------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief A container holding all the stemming algorithms known by JASS.
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+
+#include "stem.h"
+
+namespace JASS
+	{
+	/*
+		CLASS STEM_ALL
+		--------------
+	*/
+	/*!
+		@brief A container holding all the stemming algorithms known by JASS
+		@details Stemmers are identified by the value returned by their name() method.  That name is recorded in the index
+		(by the indexer) so that the search engine can stem query terms the same way the documents were stemmed.
+	*/
+	class stem_all
+		{
+		public:
+			static constexpr const char *NO_STEMMER = "None";			///< The name used to record that no stemming was done.
+
+		public:
+			/*
+				STEM_ALL::GET_BY_NAME()
+				-----------------------
+			*/
+			/*!
+				@brief Given the name of a stemmer, return a new object that is that kind of stemmer.
+				@param name [in] The name of the stemmer (as returned by stem::name()).
+				@return A pointer to a stemmer (caller to free), or nullptr if name is NO_STEMMER or is not a known stemmer.
+			*/
+			static stem *get_by_name(const std::string &name);
+
+			/*
+				STEM_ALL::IS_KNOWN()
+				--------------------
+			*/
+			/*!
+				@brief Is the given name either a known stemmer or NO_STEMMER?
+				@param name [in] The name of the stemmer.
+				@return true if get_by_name() understands this name, else false.
+			*/
+			static bool is_known(const std::string &name);
+
+			/*
+				STEM_ALL::UNITTEST()
+				--------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
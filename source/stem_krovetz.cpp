@@ -0,0 +1,1354 @@
+/*
+	STEM_KROVETZ.CPP
+	----------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <string.h>
+
+#include <string>
+#include <vector>
+
+#include "asserts.h"
+#include "stem_krovetz.h"
+
+namespace JASS
+	{
+	/*
+		STEM_KROVETZ::EXCEPTION_WORDS
+		-----------------------------
+	*/
+	const char *const stem_krovetz::exception_words[] =
+		{
+		"aide", "bathe", "caste", "cute", "dame", "dime", "doge", "done", "dune", "envelope", "gage", "grille", "grippe", "lobe", "mane", "mare",
+		"nape", "node", "pane", "pate", "plane", "pope", "programme", "quite", "ripe", "rote", "rune", "sage", "severe", "shoppe", "sine",
+		"slime", "snipe", "steppe", "suite", "swinge", "tare", "tine", "tope", "tripe", "twine",
+		nullptr
+		};
+
+	/*
+		STEM_KROVETZ::DIRECT_CONFLATIONS
+		--------------------------------
+	*/
+	const char *const stem_krovetz::direct_conflations[][2] =
+		{
+		{"aging", "age"}, {"going", "go"}, {"goes", "go"}, {"lying", "lie"}, {"using", "use"}, {"owing", "owe"}, {"suing", "sue"},
+		{"dying", "die"}, {"tying", "tie"}, {"vying", "vie"}, {"aged", "age"}, {"used", "use"}, {"vied", "vie"}, {"cued", "cue"},
+		{"died", "die"}, {"eyed", "eye"}, {"hued", "hue"}, {"iced", "ice"}, {"lied", "lie"}, {"owed", "owe"}, {"sued", "sue"}, {"toed", "toe"},
+		{"tied", "tie"}, {"does", "do"}, {"doing", "do"}, {"aeronautical", "aeronautics"}, {"mathematical", "mathematics"},
+		{"political", "politics"}, {"metaphysical", "metaphysics"}, {"cylindrical", "cylinder"}, {"nazism", "nazi"},
+		{"ambiguity", "ambiguous"}, {"barbarity", "barbarous"}, {"credulity", "credulous"}, {"generosity", "generous"},
+		{"spontaneity", "spontaneous"}, {"unanimity", "unanimous"}, {"voracity", "voracious"}, {"fled", "flee"}, {"miscarriage", "miscarry"},
+		{nullptr, nullptr}
+		};
+
+	/*
+		STEM_KROVETZ::COUNTRY_NATIONALITY
+		---------------------------------
+	*/
+	const char *const stem_krovetz::country_nationality[][2] =
+		{
+		{"afghan", "afghanistan"}, {"african", "africa"}, {"albanian", "albania"}, {"algerian", "algeria"}, {"american", "america"},
+		{"andorran", "andorra"}, {"angolan", "angola"}, {"arabian", "arabia"}, {"argentine", "argentina"}, {"armenian", "armenia"},
+		{"asian", "asia"}, {"australian", "australia"}, {"austrian", "austria"}, {"azerbaijani", "azerbaijan"}, {"azeri", "azerbaijan"},
+		{"bangladeshi", "bangladesh"}, {"belgian", "belgium"}, {"bermudan", "bermuda"}, {"bolivian", "bolivia"}, {"bosnian", "bosnia"},
+		{"botswanan", "botswana"}, {"brazilian", "brazil"}, {"british", "britain"}, {"bulgarian", "bulgaria"}, {"burmese", "burma"},
+		{"californian", "california"}, {"cambodian", "cambodia"}, {"canadian", "canada"}, {"chadian", "chad"}, {"chilean", "chile"},
+		{"chinese", "china"}, {"colombian", "colombia"}, {"croat", "croatia"}, {"croatian", "croatia"}, {"cuban", "cuba"},
+		{"cypriot", "cyprus"}, {"czechoslovakian", "czechoslovakia"}, {"danish", "denmark"}, {"egyptian", "egypt"},
+		{"equadorian", "equador"}, {"eritrean", "eritrea"}, {"estonian", "estonia"}, {"ethiopian", "ethiopia"}, {"european", "europe"},
+		{"fijian", "fiji"}, {"filipino", "philippines"}, {"finnish", "finland"}, {"french", "france"}, {"gambian", "gambia"},
+		{"georgian", "georgia"}, {"german", "germany"}, {"ghanian", "ghana"}, {"greek", "greece"}, {"grenadan", "grenada"},
+		{"guamian", "guam"}, {"guatemalan", "guatemala"}, {"guinean", "guinea"}, {"guyanan", "guyana"}, {"haitian", "haiti"},
+		{"hawaiian", "hawaii"}, {"holland", "dutch"}, {"honduran", "honduras"}, {"hungarian", "hungary"}, {"icelandic", "iceland"},
+		{"indonesian", "indonesia"}, {"iranian", "iran"}, {"iraqi", "iraq"}, {"iraqui", "iraq"}, {"irish", "ireland"}, {"israeli", "israel"},
+		{"italian", "italy"}, {"jamaican", "jamaica"}, {"japanese", "japan"}, {"jordanian", "jordan"}, {"kampuchean", "cambodia"},
+		{"kenyan", "kenya"}, {"korean", "korea"}, {"kuwaiti", "kuwait"}, {"lankan", "lanka"}, {"laotian", "laos"}, {"latvian", "latvia"},
+		{"lebanese", "lebanon"}, {"liberian", "liberia"}, {"libyan", "libya"}, {"lithuanian", "lithuania"}, {"macedonian", "macedonia"},
+		{"madagascan", "madagascar"}, {"malaysian", "malaysia"}, {"maltese", "malta"}, {"mauritanian", "mauritania"}, {"mexican", "mexico"},
+		{"micronesian", "micronesia"}, {"moldovan", "moldova"}, {"monacan", "monaco"}, {"mongolian", "mongolia"},
+		{"montenegran", "montenegro"}, {"moroccan", "morocco"}, {"myanmar", "burma"}, {"namibian", "namibia"}, {"nepalese", "nepal"},
+		{"nicaraguan", "nicaragua"}, {"nigerian", "nigeria"}, {"norwegian", "norway"}, {"omani", "oman"}, {"pakistani", "pakistan"},
+		{"panamanian", "panama"}, {"papuan", "papua"}, {"paraguayan", "paraguay"}, {"peruvian", "peru"}, {"portuguese", "portugal"},
+		{"romanian", "romania"}, {"rumania", "romania"}, {"rumanian", "romania"}, {"russian", "russia"}, {"rwandan", "rwanda"},
+		{"samoan", "samoa"}, {"scottish", "scotland"}, {"serb", "serbia"}, {"serbian", "serbia"}, {"siam", "thailand"},
+		{"siamese", "thailand"}, {"slovakia", "slovak"}, {"slovakian", "slovak"}, {"slovenian", "slovenia"}, {"somali", "somalia"},
+		{"somalian", "somalia"}, {"spanish", "spain"}, {"swedish", "sweden"}, {"swiss", "switzerland"}, {"syrian", "syria"},
+		{"taiwanese", "taiwan"}, {"tanzanian", "tanzania"}, {"texan", "texas"}, {"thai", "thailand"}, {"tunisian", "tunisia"},
+		{"turkish", "turkey"}, {"ugandan", "uganda"}, {"ukrainian", "ukraine"}, {"uruguayan", "uruguay"}, {"uzbek", "uzbekistan"},
+		{"venezuelan", "venezuela"}, {"vietnamese", "viet"}, {"virginian", "virginia"}, {"yemeni", "yemen"}, {"yugoslav", "yugoslavia"},
+		{"yugoslavian", "yugoslavia"}, {"zambian", "zambia"}, {"zealander", "zealand"}, {"zimbabwean", "zimbabwe"},
+		{nullptr, nullptr}
+		};
+
+	/*
+		STEM_KROVETZ::SUPPLEMENT_WORDS
+		------------------------------
+	*/
+	const char *const stem_krovetz::supplement_words[] =
+		{
+		"aids", "applicator", "capacitor", "digitize", "electromagnet", "ellipsoid", "exosphere", "extensible", "ferromagnet", "graphics",
+		"hydromagnet", "polygraph", "toroid", "superconduct", "backscatter", "connectionism",
+		nullptr
+		};
+
+	/*
+		STEM_KROVETZ::PROPER_NOUNS
+		--------------------------
+	*/
+	const char *const stem_krovetz::proper_nouns[] =
+		{
+		"abrams", "achilles", "acropolis", "adams", "agnes", "aires", "alexander", "alexis", "alfred", "algiers", "alps", "amadeus", "ames",
+		"amos", "andes", "angeles", "annapolis", "antilles", "aquarius", "archimedes", "arkansas", "asher", "ashly", "athens", "atkins",
+		"atlantis", "avis", "bahamas", "bangor", "barbados", "barger", "bering", "brahms", "brandeis", "brussels", "bruxelles", "cairns",
+		"camoros", "camus", "carlos", "celts", "chalker", "charles", "cheops", "ching", "christmas", "cocos", "collins", "columbus",
+		"confucius", "conners", "connolly", "copernicus", "cramer", "cyclops", "cygnus", "cyprus", "dallas", "damascus", "daniels", "davies",
+		"davis", "decker", "denning", "dennis", "descartes", "dickens", "doris", "douglas", "downs", "dreyfus", "dukakis", "dulles",
+		"dumfries", "ecclesiastes", "edwards", "emily", "erasmus", "euphrates", "evans", "everglades", "fairbanks", "federales", "fisher",
+		"fitzsimmons", "fleming", "forbes", "fowler", "france", "francis", "goering", "goodling", "goths", "grenadines", "guiness", "hades",
+		"harding", "harris", "hastings", "hawkes", "hawking", "hayes", "heights", "hercules", "himalayas", "hippocrates", "hobbs", "holmes",
+		"honduras", "hopkins", "hughes", "humphreys", "illinois", "indianapolis", "inverness", "iris", "iroquois", "irving", "isaacs",
+		"italy", "james", "jarvis", "jeffreys", "jesus", "jones", "josephus", "judas", "julius", "kansas", "keynes", "kipling", "kiwanis",
+		"lansing", "laos", "leeds", "levis", "leviticus", "lewis", "louis", "maccabees", "madras", "maimonides", "maldive", "massachusetts",
+		"matthews", "mauritius", "memphis", "mercedes", "midas", "mingus", "minneapolis", "mohammed", "moines", "morris", "moses", "myers",
+		"myknos", "nablus", "nanjing", "nantes", "naples", "neal", "netherlands", "nevis", "nostradamus", "oedipus", "olympus", "orleans",
+		"orly", "papas", "paris", "parker", "pauling", "peking", "pershing", "peter", "peters", "philippines", "phineas", "pisces", "pryor",
+		"pythagoras", "queens", "rabelais", "ramses", "reynolds", "rhesus", "rhodes", "richards", "robins", "rodgers", "rogers", "rubens",
+		"sagittarius", "seychelles", "socrates", "texas", "thames", "thomas", "tiberias", "tunis", "venus", "vilnius", "wales", "warner",
+		"wilkins", "williams", "wyoming", "xmas", "yonkers", "zeus", "frances", "aarhus", "adonis", "andrews", "angus", "antares", "aquinas",
+		"arcturus", "ares", "artemis", "augustus", "ayers", "barnabas", "barnes", "becker", "bejing", "biggs", "billings", "boeing", "boris",
+		"borroughs", "briggs", "buenos", "calais", "caracas", "cassius", "cerberus", "ceres", "cervantes", "chantilly", "chartres", "chester",
+		"connally", "conner", "coors", "cummings", "curtis", "daedalus", "dionysus", "dobbs", "dolores", "edmonds",
+		nullptr
+		};
+
+	/*
+		STEM_KROVETZ::GET_DICTIONARY()
+		------------------------------
+	*/
+	const stem_krovetz::dictionary_type &stem_krovetz::get_dictionary(void)
+		{
+		/*
+			The dictionary is built once (the first time a stemmer is constructed) and shared between all stemmers (and threads).  The
+			exceptions and direct mappings are added first so that they take precedence over the head words.
+		*/
+		static const dictionary_type dictionary = []()
+			{
+			dictionary_type into;
+
+			for (const char *const *current = exception_words; *current != nullptr; current++)
+				into.emplace(*current, dictionary_entry{*current, true});
+
+			for (const auto *current = direct_conflations; (*current)[0] != nullptr; current++)
+				into.emplace((*current)[0], dictionary_entry{(*current)[1], false});
+
+			for (const auto *current = country_nationality; (*current)[0] != nullptr; current++)
+				into.emplace((*current)[0], dictionary_entry{(*current)[1], false});
+
+			for (const char *const *list : {head_words, supplement_words, proper_nouns})
+				for (const char *const *current = list; *current != nullptr; current++)
+					into.emplace(*current, dictionary_entry{nullptr, false});
+
+			return into;
+			}();
+
+		return dictionary;
+		}
+
+	/*
+		STEM_KROVETZ::IS_CONSONANT()
+		----------------------------
+	*/
+	bool stem_krovetz::is_consonant(long index) const
+		{
+		switch (word[index])
+			{
+			case 'a':
+			case 'e':
+			case 'i':
+			case 'o':
+			case 'u':
+				return false;
+			case 'y':
+				return index == 0 || !is_consonant(index - 1);
+			default:
+				return true;
+			}
+		}
+
+	/*
+		STEM_KROVETZ::ENDS_IN()
+		-----------------------
+	*/
+	bool stem_krovetz::ends_in(const char *suffix)
+		{
+		long suffix_length = static_cast<long>(strlen(suffix));
+
+		if (suffix_length > k)
+			return false;
+
+		long start = length - suffix_length;
+		if (memcmp(word + start, suffix, suffix_length) != 0)
+			return false;
+
+		j = start - 1;
+		return true;
+		}
+
+	/*
+		STEM_KROVETZ::DOUBLE_CONSONANT()
+		--------------------------------
+	*/
+	bool stem_krovetz::double_consonant(long index) const
+		{
+		if (index < 1 || word[index] != word[index - 1])
+			return false;
+
+		return is_consonant(index);
+		}
+
+	/*
+		STEM_KROVETZ::VOWEL_IN_STEM()
+		-----------------------------
+	*/
+	bool stem_krovetz::vowel_in_stem(void) const
+		{
+		for (long index = 0; index <= j; index++)
+			if (!is_consonant(index))
+				return true;
+
+		return false;
+		}
+
+	/*
+		STEM_KROVETZ::LOOKUP()
+		----------------------
+	*/
+	bool stem_krovetz::lookup(void)
+		{
+		auto found = dictionary.find(std::string_view(word, length));
+		matched_entry = found == dictionary.end() ? nullptr : &found->second;
+
+		return matched_entry != nullptr;
+		}
+
+	/*
+		STEM_KROVETZ::WORD_IN_DICTIONARY()
+		----------------------------------
+	*/
+	const stem_krovetz::dictionary_entry *stem_krovetz::word_in_dictionary(void)
+		{
+		if (matched_entry != nullptr)
+			return matched_entry;
+
+		auto found = dictionary.find(std::string_view(word, length));
+		if (found == dictionary.end())
+			return nullptr;
+
+		if (!found->second.exception)
+			matched_entry = &found->second;			// only remember it if it is not an exception
+
+		return &found->second;
+		}
+
+	/*
+		STEM_KROVETZ::PLURAL()
+		----------------------
+	*/
+	void stem_krovetz::plural(void)
+		{
+		if (word[k] != 's')
+			return;
+
+		if (ends_in("ies"))
+			{
+			set_length(j + 3);
+			k--;
+			if (lookup())
+				return;											// calories -> calorie
+			k++;
+			append('s');
+			set_length(j + 1);
+			append('y');
+			k = j + 1;
+			lookup();											// ponies -> pony
+			}
+		else if (ends_in("es"))
+			{
+			/*
+				Try removing just the "s" (but not for a double s as crosses -> crosse is rare)
+			*/
+			set_length(j + 2);
+			k--;
+			bool try_e = j > 0 && !(word[j] == 's' && word[j - 1] == 's');
+			if (try_e && lookup())
+				return;
+
+			/*
+				Try removing the "es"
+			*/
+			set_length(j + 1);
+			k--;
+			if (lookup())
+				return;
+
+			/*
+				The default is to keep the "e"
+			*/
+			append('e');
+			k++;
+
+			if (!try_e)
+				lookup();
+			}
+		else if (length > 3 && word[k - 1] != 's' && !ends_in("ous"))
+			{
+			/*
+				Unless the word ends in "ous" or a double "s", remove the final "s"
+			*/
+			set_length(k);
+			k--;
+			lookup();
+			}
+		}
+
+	/*
+		STEM_KROVETZ::PAST_TENSE()
+		--------------------------
+	*/
+	void stem_krovetz::past_tense(void)
+		{
+		/*
+			Words of 4 or fewer letters are handled by direct mapping (this prevents fled -> fl)
+		*/
+		if (length <= 4)
+			return;
+
+		if (ends_in("ied"))
+			{
+			set_length(j + 3);
+			k--;
+			if (lookup())
+				return;											// died -> die
+			k++;
+			append('d');
+			set_length(j + 1);
+			append('y');
+			k = j + 1;
+			lookup();											// carried -> carry
+			return;
+			}
+
+		/*
+			Check for a vowel in the stem so that acronyms are not stemmed
+		*/
+		if (ends_in("ed") && vowel_in_stem())
+			{
+			/*
+				Does the root end in e?
+			*/
+			set_length(j + 2);
+			k = j + 1;
+
+			const dictionary_entry *entry = word_in_dictionary();
+			if (entry != nullptr && !entry->exception)
+				return;
+
+			/*
+				Try removing the "ed"
+			*/
+			set_length(j + 1);
+			k = j;
+			if (lookup())
+				return;
+
+			/*
+				Try removing a doubled consonant, but if the root isn't in the dictionary then leave it doubled (backfilled -> backfill)
+			*/
+			if (double_consonant(k))
+				{
+				set_length(k);
+				k--;
+				if (lookup())
+					return;
+				append(word[k]);
+				k++;
+				lookup();
+				return;
+				}
+
+			/*
+				Leave words that start un- alone
+			*/
+			if (word[0] == 'u' && word[1] == 'n')
+				{
+				append('e');
+				append('d');
+				k += 2;
+				return;
+				}
+
+			/*
+				The root was not found, so prefer to end with an e (microcoded -> microcode)
+			*/
+			set_length(j + 1);
+			append('e');
+			k = j + 1;
+			}
+		}
+
+	/*
+		STEM_KROVETZ::ASPECT()
+		----------------------
+	*/
+	void stem_krovetz::aspect(void)
+		{
+		/*
+			Short words are handled by direct mapping (this prevents thing -> the)
+		*/
+		if (length <= 5)
+			return;
+
+		/*
+			Check for a vowel in the stem so that acronyms are not stemmed
+		*/
+		if (ends_in("ing") && vowel_in_stem())
+			{
+			/*
+				Try adding an e to the stem
+			*/
+			word[j + 1] = 'e';
+			set_length(j + 2);
+			k = j + 1;
+
+			const dictionary_entry *entry = word_in_dictionary();
+			if (entry != nullptr && !entry->exception)
+				return;
+
+			/*
+				Adding an e didn't work, so remove it
+			*/
+			set_length(k);
+			k--;
+			if (lookup())
+				return;
+
+			/*
+				Try removing a doubled consonant, but if the root isn't in the dictionary then leave it doubled (fingerspelling -> fingerspell)
+			*/
+			if (double_consonant(k))
+				{
+				k--;
+				set_length(k + 1);
+				if (lookup())
+					return;
+				append(word[k]);
+				k++;
+				lookup();
+				return;
+				}
+
+			/*
+				The root was not found, so add an e unless the stem ends in two consonants (microcoding -> microcode, footstamping -> footstamp)
+			*/
+			if (j > 0 && is_consonant(j) && is_consonant(j - 1))
+				{
+				k = j;
+				set_length(k + 1);
+				return;
+				}
+
+			set_length(j + 1);
+			append('e');
+			k = j + 1;
+			}
+		}
+
+	/*
+		STEM_KROVETZ::ITY()
+		-------------------
+	*/
+	void stem_krovetz::ity(void)
+		{
+		if (!ends_in("ity"))
+			return;
+
+		/*
+			Try removing -ity, then removing -ity and adding -e
+		*/
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+		append('e');
+		k++;
+		if (lookup())
+			return;
+		word[j + 1] = 'i';
+		append("ty");
+		k = j + 3;
+
+		/*
+			The -ability and -ibility endings are very productive, so accept them (as -ble)
+		*/
+		if (j > 0 && word[j - 1] == 'i' && word[j] == 'l')
+			{
+			set_length(j - 1);
+			append("le");
+			k = j;
+			lookup();
+			return;
+			}
+
+		/*
+			The same for -ivity (as -ive)
+		*/
+		if (j > 0 && word[j - 1] == 'i' && word[j] == 'v')
+			{
+			set_length(j + 1);
+			append('e');
+			k = j + 1;
+			lookup();
+			return;
+			}
+
+		/*
+			The same for -ality (as -al)
+		*/
+		if (j > 0 && word[j - 1] == 'a' && word[j] == 'l')
+			{
+			set_length(j + 1);
+			k = j;
+			lookup();
+			return;
+			}
+
+		/*
+			If the word with -ity is in the dictionary then keep it (capacity), else remove -ity as the default
+		*/
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		k = j;
+		}
+
+	/*
+		STEM_KROVETZ::NCY()
+		-------------------
+	*/
+	void stem_krovetz::ncy(void)
+		{
+		if (!ends_in("ncy"))
+			return;
+
+		if (word[j] != 'e' && word[j] != 'a')
+			return;
+
+		/*
+			Try -ncy to -nt, then default to -nce
+		*/
+		word[j + 2] = 't';
+		set_length(j + 3);
+		k = j + 2;
+		if (lookup())
+			return;
+
+		word[j + 2] = 'c';
+		append('e');
+		k = j + 3;
+		lookup();
+		}
+
+	/*
+		STEM_KROVETZ::NCE()
+		-------------------
+	*/
+	void stem_krovetz::nce(void)
+		{
+		if (!ends_in("nce"))
+			return;
+
+		if (word[j] != 'e' && word[j] != 'a')
+			return;
+
+		/*
+			Try -ance to -e (adherance -> adhere), then removing -ance (disappearance -> disappear)
+		*/
+		char ending = word[j];
+		set_length(j);
+		append('e');
+		k = j;
+		if (lookup())
+			return;
+
+		set_length(j);
+		k = j - 1;
+		if (lookup())
+			return;
+
+		append(ending);
+		append("nce");
+		k = j + 3;
+		}
+
+	/*
+		STEM_KROVETZ::NESS()
+		--------------------
+	*/
+	void stem_krovetz::ness(void)
+		{
+		if (!ends_in("ness"))
+			return;
+
+		set_length(j + 1);
+		k = j;
+		if (word[j] == 'i')
+			word[j] = 'y';									// happiness -> happy
+		lookup();
+		}
+
+	/*
+		STEM_KROVETZ::ISM()
+		-------------------
+	*/
+	void stem_krovetz::ism(void)
+		{
+		if (!ends_in("ism"))
+			return;
+
+		set_length(j + 1);
+		k = j;
+		lookup();
+		}
+
+	/*
+		STEM_KROVETZ::MENT()
+		--------------------
+	*/
+	void stem_krovetz::ment(void)
+		{
+		if (!ends_in("ment"))
+			return;
+
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+
+		/*
+			The default is to leave it alone
+		*/
+		append("ment");
+		k = j + 4;
+		}
+
+	/*
+		STEM_KROVETZ::IZE()
+		-------------------
+	*/
+	void stem_krovetz::ize(void)
+		{
+		if (!ends_in("ize"))
+			return;
+
+		/*
+			Try removing -ize
+		*/
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+		append('i');
+
+		/*
+			Allow for a doubled consonant
+		*/
+		if (double_consonant(j))
+			{
+			set_length(j);
+			k = j - 1;
+			if (lookup())
+				return;
+			append(word[j - 1]);
+			}
+
+		/*
+			Try replacing -ize with -e
+		*/
+		set_length(j + 1);
+		append('e');
+		k = j + 1;
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		append("ize");
+		k = j + 3;
+		}
+
+	/*
+		STEM_KROVETZ::BLE()
+		-------------------
+	*/
+	void stem_krovetz::ble(void)
+		{
+		if (!ends_in("ble"))
+			return;
+
+		if (word[j] != 'a' && word[j] != 'i')
+			return;
+
+		/*
+			Try removing -able
+		*/
+		char vowel = word[j];
+		set_length(j);
+		k = j - 1;
+		if (lookup())
+			return;
+
+		/*
+			Allow for a doubled consonant
+		*/
+		if (double_consonant(k))
+			{
+			set_length(k);
+			k--;
+			if (lookup())
+				return;
+			k++;
+			append(word[k - 1]);
+			}
+
+		/*
+			Try replacing -able with -e then with -ate (compensable -> compensate)
+		*/
+		set_length(j);
+		append('e');
+		k = j;
+		if (lookup())
+			return;
+
+		set_length(j);
+		append("ate");
+		k = j + 2;
+		if (lookup())
+			return;
+
+		set_length(j);
+		append(vowel);
+		append("ble");
+		k = j + 3;
+		}
+
+	/*
+		STEM_KROVETZ::IC()
+		------------------
+	*/
+	void stem_krovetz::ic(void)
+		{
+		if (!ends_in("ic"))
+			return;
+
+		/*
+			Try -ic to -ical (canonic -> canonical), -y, and -e, then try removing it
+		*/
+		set_length(j + 3);
+		append("al");
+		k = j + 4;
+		if (lookup())
+			return;
+
+		word[j + 1] = 'y';
+		set_length(j + 2);
+		k = j + 1;
+		if (lookup())
+			return;
+
+		word[j + 1] = 'e';
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+
+		append("ic");
+		k = j + 2;
+		}
+
+	/*
+		STEM_KROVETZ::ION()
+		-------------------
+	*/
+	void stem_krovetz::ion(void)
+		{
+		long old_k = k;
+
+		if (!ends_in("ion"))
+			return;
+
+		/*
+			The -ize ending is very productive, so accept -ization as -ize
+		*/
+		if (ends_in("ization"))
+			{
+			set_length(j + 3);
+			append('e');
+			k = j + 3;
+			lookup();
+			return;
+			}
+
+		if (ends_in("ition"))
+			{
+			/*
+				Try replacing -ition with -e (definition -> define, opposition -> oppose)
+			*/
+			set_length(j + 1);
+			append('e');
+			k = j + 1;
+			if (lookup())
+				return;
+
+			set_length(j + 1);
+			append("ition");
+			k = old_k;
+			}
+		else if (ends_in("ation"))
+			{
+			/*
+				Try replacing -ion with -e (elimination -> eliminate), -ation with -e, and removing -ation (resignation -> resign)
+			*/
+			set_length(j + 3);
+			append('e');
+			k = j + 3;
+			if (lookup())
+				return;
+
+			set_length(j + 1);
+			append('e');
+			k = j + 1;
+			if (lookup())
+				return;
+
+			set_length(j + 1);
+			k = j;
+			if (lookup())
+				return;
+
+			set_length(j + 1);
+			append("ation");
+			k = old_k;
+			}
+
+		/*
+			Try -ication after -ation (so complication -> complicate rather than comply)
+		*/
+		if (ends_in("ication"))
+			{
+			/*
+				Try replacing -ication with -y (amplification -> amplify)
+			*/
+			set_length(j + 1);
+			append('y');
+			k = j + 1;
+			if (lookup())
+				return;
+
+			set_length(j + 1);
+			append("ication");
+			k = old_k;
+			}
+
+		/*
+			Try replacing -ion with -e, and then removing -ion
+		*/
+		j = k - 3;
+		set_length(j + 1);
+		append('e');
+		k = j + 1;
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		append("ion");
+		k = old_k;
+		}
+
+	/*
+		STEM_KROVETZ::ER()
+		------------------
+	*/
+	void stem_krovetz::er(void)
+		{
+		long old_k = k;
+
+		if (word[k] != 'r')
+			return;
+
+		/*
+			The -ize ending is very productive, so accept -izer as -ize
+		*/
+		if (ends_in("izer"))
+			{
+			set_length(j + 4);
+			k = j + 3;
+			lookup();
+			return;
+			}
+
+		if (ends_in("er") || ends_in("or"))
+			{
+			char vowel = word[j + 1];
+
+			/*
+				Try removing a doubled consonant (runner -> run)
+			*/
+			if (double_consonant(j))
+				{
+				set_length(j);
+				k = j - 1;
+				if (lookup())
+					return;
+				append(word[j - 1]);
+				}
+
+			/*
+				Try -ier to -y (happier -> happy)
+			*/
+			if (word[j] == 'i')
+				{
+				word[j] = 'y';
+				set_length(j + 1);
+				k = j;
+				if (lookup())
+					return;
+				word[j] = 'i';
+				append('e');
+				}
+
+			/*
+				Try removing -eer
+			*/
+			if (word[j] == 'e')
+				{
+				set_length(j);
+				k = j - 1;
+				if (lookup())
+					return;
+				append('e');
+				}
+
+			/*
+				Try removing -r, removing -er, and -or to -e
+			*/
+			set_length(j + 2);
+			k = j + 1;
+			if (lookup())
+				return;
+
+			set_length(j + 1);
+			k = j;
+			if (lookup())
+				return;
+
+			append('e');
+			k = j + 1;
+			if (lookup())
+				return;
+
+			set_length(j + 1);
+			append(vowel);
+			append('r');
+			k = old_k;
+			}
+		}
+
+	/*
+		STEM_KROVETZ::LY()
+		------------------
+	*/
+	void stem_krovetz::ly(void)
+		{
+		long old_k = k;
+
+		if (!ends_in("ly"))
+			return;
+
+		/*
+			Try -ly to -le (gently -> gentle) then removing -ly
+		*/
+		word[j + 2] = 'e';
+		if (lookup())
+			return;
+		word[j + 2] = 'y';
+
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+
+		/*
+			Always convert -ally to -al (which al() might then remove)
+		*/
+		if (j > 0 && word[j - 1] == 'a' && word[j] == 'l')
+			return;
+
+		append("ly");
+		k = old_k;
+
+		/*
+			Always convert -ably to -able
+		*/
+		if (j > 0 && word[j - 1] == 'a' && word[j] == 'b')
+			{
+			word[j + 2] = 'e';
+			k = j + 2;
+			return;
+			}
+
+		/*
+			Try -ily to -y (militarily -> military)
+		*/
+		if (word[j] == 'i')
+			{
+			set_length(j);
+			append('y');
+			k = j;
+			if (lookup())
+				return;
+			set_length(j);
+			append("ily");
+			k = old_k;
+			}
+
+		/*
+			The default is to remove -ly
+		*/
+		set_length(j + 1);
+		k = j;
+		}
+
+	/*
+		STEM_KROVETZ::AL()
+		------------------
+	*/
+	void stem_krovetz::al(void)
+		{
+		long old_k = k;
+
+		if (length < 4 || !ends_in("al"))
+			return;
+
+		/*
+			Try removing -al
+		*/
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+
+		/*
+			Allow for a doubled consonant
+		*/
+		if (double_consonant(j))
+			{
+			set_length(j);
+			k = j - 1;
+			if (lookup())
+				return;
+			append(word[j - 1]);
+			}
+
+		/*
+			Try -al to -e and to -um (optimal -> optimum)
+		*/
+		set_length(j + 1);
+		append('e');
+		k = j + 1;
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		append("um");
+		k = j + 2;
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		append("al");
+		k = old_k;
+
+		if (j > 0 && word[j - 1] == 'i' && word[j] == 'c')
+			{
+			/*
+				Try removing -ical, then -ical to -y (bibliographical -> bibliography), then default to -ic
+			*/
+			set_length(j - 1);
+			k = j - 2;
+			if (lookup())
+				return;
+
+			set_length(j - 1);
+			append('y');
+			k = j - 1;
+			if (lookup())
+				return;
+
+			set_length(j - 1);
+			append("ic");
+			k = j;
+			lookup();
+			return;
+			}
+
+		if (word[j] == 'i')
+			{
+			/*
+				Try removing -ial
+			*/
+			set_length(j);
+			k = j - 1;
+			if (lookup())
+				return;
+			append("ial");
+			k = old_k;
+			lookup();
+			}
+		}
+
+	/*
+		STEM_KROVETZ::IVE()
+		-------------------
+	*/
+	void stem_krovetz::ive(void)
+		{
+		long old_k = k;
+
+		if (!ends_in("ive"))
+			return;
+
+		/*
+			Try removing -ive then -ive to -e
+		*/
+		set_length(j + 1);
+		k = j;
+		if (lookup())
+			return;
+
+		append('e');
+		k = j + 1;
+		if (lookup())
+			return;
+
+		set_length(j + 1);
+		append("ive");
+
+		if (j > 0 && word[j - 1] == 'a' && word[j] == 't')
+			{
+			/*
+				Try -ative to -e (determinative -> determine) then removing -ative
+			*/
+			word[j - 1] = 'e';
+			set_length(j);
+			k = j - 1;
+			if (lookup())
+				return;
+
+			set_length(j - 1);
+			if (lookup())
+				return;
+
+			append("ative");
+			k = old_k;
+			}
+
+		/*
+			Try -ive to -ion (injunctive -> injunction)
+		*/
+		word[j + 2] = 'o';
+		word[j + 3] = 'n';
+		if (lookup())
+			return;
+
+		word[j + 2] = 'v';
+		word[j + 3] = 'e';
+		k = old_k;
+		}
+
+	/*
+		STEM_KROVETZ::KSTEM()
+		---------------------
+	*/
+	std::string_view stem_krovetz::kstem(std::string_view term)
+		{
+		/*
+			Very short and very long words are not stemmed
+		*/
+		if (term.size() <= 2 || term.size() >= max_word_length)
+			return term;
+
+		/*
+			Words in the dictionary are not stemmed, but some map directly to a root
+		*/
+		auto found = dictionary.find(term);
+		if (found != dictionary.end())
+			return found->second.root == nullptr ? term : found->second.root;
+
+		/*
+			Only lower case English words are stemmed
+		*/
+		for (char character : term)
+			if (character < 'a' || character > 'z')
+				return term;
+
+		memcpy(word, term.data(), term.size());
+		length = static_cast<long>(term.size());
+		k = length - 1;
+		matched_entry = nullptr;
+
+		/*
+			Try each rule in turn until the word is in the dictionary
+		*/
+		void (stem_krovetz::*const rules_before_ive[])(void) = {&stem_krovetz::plural, &stem_krovetz::past_tense, &stem_krovetz::aspect, &stem_krovetz::ity, &stem_krovetz::ness, &stem_krovetz::ion, &stem_krovetz::er, &stem_krovetz::ly, &stem_krovetz::al};
+		void (stem_krovetz::*const rules_from_ive[])(void) = {&stem_krovetz::ive, &stem_krovetz::ize, &stem_krovetz::ment, &stem_krovetz::ble, &stem_krovetz::ism, &stem_krovetz::ic, &stem_krovetz::ncy, &stem_krovetz::nce};
+
+		for (auto rule : rules_before_ive)
+			{
+			(this->*rule)();
+			if (matched_entry != nullptr)
+				break;
+			}
+
+		if (matched_entry == nullptr)
+			{
+			word_in_dictionary();
+			for (auto rule : rules_from_ive)
+				{
+				(this->*rule)();
+				if (matched_entry != nullptr)
+					break;
+				}
+			}
+
+		/*
+			A direct mapping (such as italians -> italian -> italy) is used as the root
+		*/
+		if (matched_entry != nullptr && matched_entry->root != nullptr)
+			return matched_entry->root;
+
+		return std::string_view(word, length);
+		}
+
+	/*
+		STEM_KROVETZ::TOSTEM()
+		----------------------
+	*/
+	size_t stem_krovetz::tostem(char *destination, const char *source, size_t source_length)
+		{
+		std::string_view result = kstem(std::string_view(source, source_length));
+
+		memmove(destination, result.data(), result.size());
+		destination[result.size()] = '\0';
+
+		return result.size();
+		}
+
+	/*
+		STEM_KROVETZ::UNITTEST()
+		------------------------
+	*/
+	void stem_krovetz::unittest(void)
+		{
+		/*
+			The examples from Krovetz's paper and the comments in the KStem source code, along with plurals, tenses, and the direct mappings
+		*/
+		std::vector<std::pair<std::string, std::string>> test_data =
+			{
+			{"calories", "calorie"},
+			{"ponies", "pony"},
+			{"aides", "aide"},
+			{"horses", "horse"},
+			{"crosses", "cross"},
+			{"boxes", "box"},
+			{"cats", "cat"},
+			{"glass", "glass"},
+			{"famous", "famous"},
+			{"carried", "carry"},
+			{"died", "die"},
+			{"hoped", "hope"},
+			{"jumped", "jump"},
+			{"stopped", "stop"},
+			{"backfilled", "backfill"},
+			{"microcoded", "microcode"},
+			{"hoping", "hope"},
+			{"jumping", "jump"},
+			{"stopping", "stop"},
+			{"fingerspelling", "fingerspell"},
+			{"microcoding", "microcode"},
+			{"footstamping", "footstamp"},
+			{"immunities", "immunity"},
+			{"capacity", "capacity"},
+			{"readability", "readable"},
+			{"happiness", "happy"},
+			{"preachiness", "preachy"},
+			{"drinkable", "drink"},
+			{"brutalism", "brutal"},
+			{"bibliographical", "bibliography"},
+			{"heroically", "heroic"},
+			{"definition", "definition"},
+			{"defining", "define"},
+			{"eliminator", "eliminate"},
+			{"defrostation", "defrost"},
+			{"summarization", "summarize"},
+			{"amplifying", "amplify"},
+			{"immunization", "immunize"},
+			{"teachers", "teacher"},
+			{"teaching", "teach"},
+			{"sunnier", "sunny"},
+			{"quickly", "quick"},
+			{"gently", "gentle"},
+			{"militarily", "military"},
+			{"optimally", "optimal"},
+			{"determinative", "determine"},
+			{"injunctive", "injunction"},
+			{"preachment", "preach"},
+			{"compensable", "compensate"},
+			{"resistancy", "resistant"},
+			{"canonic", "canonical"},
+			{"does", "do"},
+			{"italian", "italy"},
+			{"italians", "italy"},
+			{"texas", "texas"},
+			{"as", "as"},
+			{"iraq1", "iraq1"},
+			{"PONIES", "PONIES"}
+			};
+
+		stem_krovetz stemmer;
+		char result[1024];
+
+		for (const auto &example : test_data)
+			{
+			stemmer.tostem(result, example.first.c_str(), example.first.size());
+			JASS_assert(result == example.second);
+			}
+
+		/*
+			Stemming in place
+		*/
+		strcpy(result, "ponies");
+		JASS_assert(stemmer.tostem(result, result, strlen(result)) == 4);
+		JASS_assert(strcmp(result, "pony") == 0);
+
+		puts("stem_krovetz::PASSED");
+		}
+	}
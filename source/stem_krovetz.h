@@ -0,0 +1,424 @@
+/*
+	STEM_KROVETZ.H
+	--------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Krovetz's dictionary based stemmer (KStem)
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+#include <string_view>
+#include <unordered_map>
+
+#include "stem.h"
+
+namespace JASS
+	{
+	/*
+		CLASS STEM_KROVETZ
+		------------------
+	*/
+	/*!
+		@brief Generate the stem of a word using Krovetz's stemmer (KStem).
+		@details See: R. Krovetz, Viewing morphology as an inference process, SIGIR 1993, pp 191-202.  Each suffix rule (plurals, past tense,
+		-ing, -ity, -ness, -ion, -er, -ly, -al, -ive, -ize, -ment, -able, -ism, -ic, -ency, and -ence) suggests candidate roots which are checked
+		against a dictionary of English head words, so the stem is (almost always) a word.  Words in the dictionary are not stemmed, and a short
+		list of exceptions (such as "does" -> "do" and "italian" -> "italy") map directly to their root.  This is a port of the version in Lucene
+		(org.apache.lucene.analysis.en.KStemmer) and it uses the same exception, direct conflation, nationality, supplementary and proper noun
+		lists.  The head word list (see stem_krovetz_dictionary.cpp) is in the same form as the KStem dictionary, but is not Krovetz's original list.
+		Terms must already be in lower case, and terms of fewer than 3 or more than 49 characters, or that contain characters other than 'a'
+		to 'z' are not stemmed.
+	*/
+	class stem_krovetz : public stem
+		{
+		private:
+			/*
+				CLASS STEM_KROVETZ::DICTIONARY_ENTRY
+				------------------------------------
+			*/
+			/*!
+				@brief An entry in the KStem dictionary.
+			*/
+			class dictionary_entry
+				{
+				public:
+					const char *root;			///< The root of the word (if it maps directly to a root) or nullptr if the word is its own root.
+					bool exception;			///< true if this word is an exception (a word that ends in -e but is not the root of the -ed and -ing forms).
+				};
+
+			/*!
+				@typedef dictionary_type
+				@brief The KStem dictionary, a map from a word to its dictionary_entry.
+			*/
+			typedef std::unordered_map<std::string_view, dictionary_entry> dictionary_type;
+
+		private:
+			static constexpr size_t max_word_length = 50;				///< Words of this length (less 1) or longer are not stemmed.
+
+			static const char *const head_words[];							///< The dictionary of English head words (nullptr terminated).
+			static const char *const exception_words[];					///< Words that end in -e that should not be the root of -ed and -ing words (nullptr terminated).
+			static const char *const direct_conflations[][2];			///< Pairs of {word, root} that are stemmed directly (nullptr terminated).
+			static const char *const country_nationality[][2];			///< Pairs of {nationality, country} that are stemmed directly (nullptr terminated).
+			static const char *const supplement_words[];					///< Words added to the dictionary (nullptr terminated).
+			static const char *const proper_nouns[];						///< Proper nouns that end in s (nullptr terminated), added to the dictionary.
+
+		private:
+			const dictionary_type &dictionary;								///< The KStem dictionary (shared by all instances).
+			char word[max_word_length + 10];									///< The word being stemmed (with space for a longer suffix to be tried).
+			long length;															///< The length of word (which might be shorter than the characters set in word).
+			long j;																	///< The index of the last character of the stem (before the suffix).
+			long k;																	///< The index of the last character of word.
+			const dictionary_entry *matched_entry;							///< The entry for the last successful dictionary look-up, or nullptr.
+
+		private:
+			/*
+				STEM_KROVETZ::GET_DICTIONARY()
+				------------------------------
+			*/
+			/*!
+				@brief Return the KStem dictionary, building it on first use.
+				@return A reference to the dictionary.
+			*/
+			static const dictionary_type &get_dictionary(void);
+
+			/*
+				STEM_KROVETZ::SET_LENGTH()
+				--------------------------
+			*/
+			/*!
+				@brief Set the length of the word.  The characters beyond the end of the word are not changed so a suffix can be restored by growing the word again.
+				@param new_length [in] The new length of the word.
+			*/
+			void set_length(long new_length)
+				{
+				length = new_length;
+				}
+
+			/*
+				STEM_KROVETZ::APPEND()
+				----------------------
+			*/
+			/*!
+				@brief Append a character to the end of the word.
+				@param character [in] The character to append.
+			*/
+			void append(char character)
+				{
+				word[length++] = character;
+				}
+
+			/*
+				STEM_KROVETZ::APPEND()
+				----------------------
+			*/
+			/*!
+				@brief Append a string to the end of the word.
+				@param suffix [in] The string to append.
+			*/
+			void append(const char *suffix)
+				{
+				while (*suffix != '\0')
+					word[length++] = *suffix++;
+				}
+
+			/*
+				STEM_KROVETZ::IS_CONSONANT()
+				----------------------------
+			*/
+			/*!
+				@brief Is the character at the given position a consonant (a y is a consonant if it is the first letter or follows a vowel)?
+				@param index [in] The position of the character in word.
+				@return true if a consonant, else false.
+			*/
+			bool is_consonant(long index) const;
+
+			/*
+				STEM_KROVETZ::ENDS_IN()
+				-----------------------
+			*/
+			/*!
+				@brief Does the word end with the given suffix, and if so set j to the index of the character before the suffix.
+				@param suffix [in] The suffix.
+				@return true if the word ends with suffix (and is longer than it), else false.
+			*/
+			bool ends_in(const char *suffix);
+
+			/*
+				STEM_KROVETZ::DOUBLE_CONSONANT()
+				--------------------------------
+			*/
+			/*!
+				@brief Does the word end with a double consonant at the given position?
+				@param index [in] The position of the second of the two characters.
+				@return true if word[index - 1] and word[index] are the same consonant, else false.
+			*/
+			bool double_consonant(long index) const;
+
+			/*
+				STEM_KROVETZ::VOWEL_IN_STEM()
+				-----------------------------
+			*/
+			/*!
+				@brief Is there a vowel in the stem (the characters up to and including j)?
+				@return true if there is a vowel in the stem, else false.
+			*/
+			bool vowel_in_stem(void) const;
+
+			/*
+				STEM_KROVETZ::LOOKUP()
+				----------------------
+			*/
+			/*!
+				@brief Look the word up in the dictionary and remember the entry (even if it is an exception) in matched_entry.
+				@return true if the word is in the dictionary, else false.
+			*/
+			bool lookup(void);
+
+			/*
+				STEM_KROVETZ::WORD_IN_DICTIONARY()
+				----------------------------------
+			*/
+			/*!
+				@brief Look the word up in the dictionary, but only remember the entry in matched_entry if it is not an exception.
+				@return The dictionary entry, or nullptr if the word is not in the dictionary.
+			*/
+			const dictionary_entry *word_in_dictionary(void);
+
+			/*
+				STEM_KROVETZ::PLURAL()
+				----------------------
+			*/
+			/*!
+				@brief Convert plurals to the singular (-ies to -y or -ie, -es to -e or removed, and -s removed).
+			*/
+			void plural(void);
+
+			/*
+				STEM_KROVETZ::PAST_TENSE()
+				--------------------------
+			*/
+			/*!
+				@brief Convert the past tense (-ed) to the present (-ied to -y or -ie).
+			*/
+			void past_tense(void);
+
+			/*
+				STEM_KROVETZ::ASPECT()
+				----------------------
+			*/
+			/*!
+				@brief Remove -ing.
+			*/
+			void aspect(void);
+
+			/*
+				STEM_KROVETZ::ITY()
+				-------------------
+			*/
+			/*!
+				@brief Remove -ity (-ability and -ibility become -ble, -ivity becomes -ive, and -ality becomes -al).
+			*/
+			void ity(void);
+
+			/*
+				STEM_KROVETZ::NESS()
+				--------------------
+			*/
+			/*!
+				@brief Remove -ness (-iness becomes -y).
+			*/
+			void ness(void);
+
+			/*
+				STEM_KROVETZ::ION()
+				-------------------
+			*/
+			/*!
+				@brief Remove -ion, -ition, -ation, -ization (which always becomes -ize), and -ication.
+			*/
+			void ion(void);
+
+			/*
+				STEM_KROVETZ::ER()
+				------------------
+			*/
+			/*!
+				@brief Remove -er, -or, -ier, and -eer (-izer always becomes -ize).
+			*/
+			void er(void);
+
+			/*
+				STEM_KROVETZ::LY()
+				------------------
+			*/
+			/*!
+				@brief Remove -ly (-ally always becomes -al and -ably always becomes -able).
+			*/
+			void ly(void);
+
+			/*
+				STEM_KROVETZ::AL()
+				------------------
+			*/
+			/*!
+				@brief Remove -al, -ical, and -ial.
+			*/
+			void al(void);
+
+			/*
+				STEM_KROVETZ::IVE()
+				-------------------
+			*/
+			/*!
+				@brief Remove -ive and -ative, or map -ive to -ion.
+			*/
+			void ive(void);
+
+			/*
+				STEM_KROVETZ::IZE()
+				-------------------
+			*/
+			/*!
+				@brief Remove -ize.
+			*/
+			void ize(void);
+
+			/*
+				STEM_KROVETZ::MENT()
+				--------------------
+			*/
+			/*!
+				@brief Remove -ment.
+			*/
+			void ment(void);
+
+			/*
+				STEM_KROVETZ::BLE()
+				-------------------
+			*/
+			/*!
+				@brief Remove -able and -ible.
+			*/
+			void ble(void);
+
+			/*
+				STEM_KROVETZ::ISM()
+				-------------------
+			*/
+			/*!
+				@brief Remove -ism.
+			*/
+			void ism(void);
+
+			/*
+				STEM_KROVETZ::IC()
+				------------------
+			*/
+			/*!
+				@brief Remove -ic, or map it to -ical, -y, or -e.
+			*/
+			void ic(void);
+
+			/*
+				STEM_KROVETZ::NCY()
+				-------------------
+			*/
+			/*!
+				@brief Map -ency and -ancy to -ent and -ant or to -ence and -ance.
+			*/
+			void ncy(void);
+
+			/*
+				STEM_KROVETZ::NCE()
+				-------------------
+			*/
+			/*!
+				@brief Remove -ence and -ance.
+			*/
+			void nce(void);
+
+			/*
+				STEM_KROVETZ::KSTEM()
+				---------------------
+			*/
+			/*!
+				@brief Stem the given term.
+				@param term [in] The term to stem.
+				@return The stem (which might be term itself, a string in the dictionary, or word).
+			*/
+			std::string_view kstem(std::string_view term);
+
+		public:
+			/*
+				STEM_KROVETZ::STEM_KROVETZ()
+				----------------------------
+			*/
+			/*!
+				@brief Constructor
+			*/
+			stem_krovetz() :
+				dictionary(get_dictionary()),
+				length(0),
+				j(0),
+				k(0),
+				matched_entry(nullptr)
+				{
+				/* Nothing */
+				}
+
+			/*
+				STEM_KROVETZ::~STEM_KROVETZ()
+				-----------------------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~stem_krovetz()
+				{
+				/* Nothing */
+				}
+
+			/*
+				STEM_KROVETZ::NAME()
+				--------------------
+			*/
+			/*!
+				@brief Return the name of the stemming algorithm
+				@return The name of the stemmer
+			*/
+			virtual std::string name(void)
+				{
+				return "Krovetz";
+				}
+
+			/*
+				STEM_KROVETZ::TOSTEM()
+				----------------------
+			*/
+			/*!
+				@brief Stem from source into destination
+				@param destination [out] the result of the steming process (the stem)
+				@param source [in] the term to stem
+				@param source_length [in] the length of the string to stem
+				@details source and destination can be the same.
+				@return the length of the stem
+			*/
+			using stem::tostem;
+			virtual size_t tostem(char *destination, const char *source, size_t source_length);
+
+			/*
+				STEM_KROVETZ::UNITTEST()
+				------------------------
+			*/
+			/*!
+				@brief Unit test this class.
+			*/
+			static void unittest(void);
+		};
+	}
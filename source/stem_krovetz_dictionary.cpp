@@ -0,0 +1,1462 @@
+/*
+	STEM_KROVETZ_DICTIONARY.CPP
+	---------------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include "stem_krovetz.h"
+
+namespace JASS
+	{
+	/*
+		STEM_KROVETZ::HEAD_WORDS
+		------------------------
+		English head words (and their common derived forms that are words in their own right) in the form of the KStem dictionary.  This is
+		not Krovetz's original dictionary (which is larger) so a few words that KStem leaves alone are stemmed, and vice versa.
+	*/
+	const char *const stem_krovetz::head_words[] =
+		{
+		"a", "aback", "abacus", "abandon", "abandonment", "abase", "abash", "abate", "abatement", "abattoir", "abbess", "abbey", "abbot",
+		"abbreviate", "abbreviation", "abdicate", "abdication", "abdomen", "abdominal", "abduct", "abduction", "aberrant", "aberration", "abet",
+		"abeyance", "abhor", "abhorrence", "abhorrent", "abide", "ability", "abject", "abjure", "ablaze", "able", "ablution", "abnegation",
+		"abnormal", "abnormality", "aboard", "abode", "abolish", "abolition", "abolitionist", "abominable", "abominate", "abomination",
+		"aboriginal", "aborigine", "abort", "abortion", "abortive", "abound", "about", "above", "aboveboard", "abrasion", "abrasive", "abreast",
+		"abridge", "abridgement", "abroad", "abrogate", "abrupt", "abscess", "abscond", "absence", "absent", "absentee", "absenteeism",
+		"absolute", "absolution", "absolutism", "absolve", "absorb", "absorbent", "absorption", "abstain", "abstention", "abstinence",
+		"abstract", "abstraction", "abstruse", "absurd", "absurdity", "abundance", "abundant", "abuse", "abusive", "abut", "abysmal", "abyss",
+		"acacia", "academe", "academic", "academician", "academy", "accede", "accelerate", "acceleration", "accelerator", "accent", "accentuate",
+		"accept", "acceptable", "acceptance", "access", "accessible", "accession", "accessory", "accident", "accidental", "acclaim",
+		"acclamation", "acclimate", "acclimatize", "accolade", "accommodate", "accommodation", "accompaniment", "accompanist", "accompany",
+		"accomplice", "accomplish", "accomplishment", "accord", "accordance", "accordingly", "accordion", "accost", "account", "accountable",
+		"accountancy", "accountant", "accredit", "accreditation", "accretion", "accrue", "accumulate", "accumulation", "accumulator", "accuracy",
+		"accurate", "accursed", "accusation", "accusative", "accuse", "accustom", "ace", "acerbic", "acetate", "acetic", "acetone", "acetylene",
+		"ache", "achieve", "achievement", "acid", "acidic", "acidity", "acknowledge", "acknowledgement", "acme", "acne", "acolyte", "acorn",
+		"acoustic", "acoustics", "acquaint", "acquaintance", "acquiesce", "acquiescence", "acquire", "acquisition", "acquisitive", "acquit",
+		"acquittal", "acre", "acreage", "acrid", "acrimonious", "acrimony", "acrobat", "acrobatic", "acrobatics", "acronym", "across", "acrylic",
+		"act", "action", "activate", "activation", "active", "activism", "activist", "activity", "actor", "actress", "actual", "actuality",
+		"actuary", "actuate", "acuity", "acumen", "acupuncture", "acute", "ad", "adage", "adamant", "adapt", "adaptable", "adaptation",
+		"adapter", "adaptor", "add", "addendum", "adder", "addict", "addiction", "addictive", "addition", "additional", "additive", "addle",
+		"address", "addressee", "adept", "adequacy", "adequate", "adhere", "adherence", "adherent", "adhesion", "adhesive", "adjacent",
+		"adjective", "adjoin", "adjourn", "adjournment", "adjudicate", "adjunct", "adjust", "adjustable", "adjustment", "adjutant", "administer",
+		"administrate", "administration", "administrative", "administrator", "admirable", "admiral", "admiralty", "admiration", "admire",
+		"admirer", "admissible", "admission", "admit", "admittance", "admonish", "admonition", "ado", "adobe", "adolescence", "adolescent",
+		"adopt", "adoption", "adoptive", "adorable", "adoration", "adore", "adorn", "adornment", "adrenalin", "adrenaline", "adrift", "adroit",
+		"adulation", "adult", "adulterate", "adulterer", "adultery", "adulthood", "advance", "advancement", "advantage", "advantageous",
+		"advent", "adventure", "adventurer", "adventurous", "adverb", "adverbial", "adversary", "adverse", "adversity", "advert", "advertise",
+		"advertisement", "advertiser", "advice", "advisable", "advise", "adviser", "advisor", "advisory", "advocacy", "advocate", "aegis",
+		"aerate", "aerial", "aerobic", "aerobics", "aerodrome", "aerodynamic", "aerodynamics", "aeronautics", "aeroplane", "aerosol",
+		"aerospace", "aesthete", "aesthetic", "aesthetics", "afar", "affable", "affair", "affect", "affectation", "affection", "affectionate",
+		"affidavit", "affiliate", "affiliation", "affinity", "affirm", "affirmation", "affirmative", "affix", "afflict", "affliction",
+		"affluence", "affluent", "afford", "affordable", "afforestation", "affray", "affront", "afield", "aflame", "afloat", "afoot",
+		"aforementioned", "aforesaid", "afraid", "afresh", "aft", "after", "aftermath", "afternoon", "aftershave", "aftertaste", "afterthought",
+		"afterward", "afterwards", "again", "against", "agape", "agate", "age", "agency", "agenda", "agent", "agglomeration", "aggrandize",
+		"aggrandizement", "aggravate", "aggravation", "aggregate", "aggression", "aggressive", "aggressor", "aggrieved", "aghast", "agile",
+		"agility", "agitate", "agitation", "agitator", "aglow", "agnostic", "agnosticism", "ago", "agog", "agonize", "agony", "agrarian",
+		"agree", "agreeable", "agreement", "agricultural", "agriculture", "agronomy", "aground", "ah", "ahead", "ahoy", "aid", "aide", "ail",
+		"aileron", "ailment", "aim", "aimless", "air", "airborne", "aircraft", "airfield", "airline", "airliner", "airmail", "airman",
+		"airplane", "airport", "airship", "airspace", "airstrip", "airtight", "airwaves", "airway", "airworthy", "airy", "aisle", "ajar", "akin",
+		"alabaster", "alacrity", "alarm", "alarmist", "alas", "albatross", "albeit", "albino", "album", "albumen", "alchemist", "alchemy",
+		"alcohol", "alcoholic", "alcoholism", "alcove", "alder", "alderman", "ale", "alert", "alfalfa", "alga", "algae", "algebra", "algebraic",
+		"algorithm", "alias", "alibi", "alien", "alienate", "alienation", "alight", "align", "alignment", "alike", "alimony", "alive", "alkali",
+		"alkaline", "all", "allay", "allegation", "allege", "allegiance", "allegorical", "allegory", "allergic", "allergy", "alleviate",
+		"alleviation", "alley", "alliance", "alligator", "alliteration", "allocate", "allocation", "allot", "allotment", "allow", "allowance",
+		"alloy", "allude", "allure", "allusion", "alluvial", "ally", "almanac", "almighty", "almond", "almost", "alms", "aloft", "alone",
+		"along", "alongside", "aloof", "aloud", "alpaca", "alpha", "alphabet", "alphabetical", "alpine", "already", "also", "altar", "alter",
+		"alteration", "altercation", "alternate", "alternation", "alternative", "alternator", "although", "altimeter", "altitude", "alto",
+		"altogether", "altruism", "altruistic", "aluminium", "aluminum", "alumnus", "always", "amalgam", "amalgamate", "amalgamation", "amass",
+		"amateur", "amateurish", "amaze", "amazement", "ambassador", "amber", "ambidextrous", "ambience", "ambient", "ambiguous", "ambit",
+		"ambition", "ambitious", "ambivalence", "ambivalent", "amble", "ambulance", "ambush", "ameliorate", "amelioration", "amen", "amenable",
+		"amend", "amendment", "amenity", "amethyst", "amiable", "amicable", "amid", "amidst", "amino", "amiss", "ammeter", "ammonia",
+		"ammunition", "amnesia", "amnesty", "amoeba", "amok", "among", "amongst", "amoral", "amorous", "amorphous", "amortize", "amount", "amp",
+		"amperage", "ampere", "ampersand", "amphetamine", "amphibian", "amphibious", "amphitheater", "amphitheatre", "ample", "amplification",
+		"amplifier", "amplify", "amplitude", "amputate", "amputation", "amulet", "amuse", "amusement", "anachronism", "anachronistic", "anaemia",
+		"anaemic", "anaesthesia", "anaesthetic", "anaesthetist", "anagram", "anal", "analgesic", "analog", "analogous", "analogue", "analogy",
+		"analyse", "analysis", "analyst", "analytic", "analytical", "analyze", "anarchic", "anarchism", "anarchist", "anarchy", "anathema",
+		"anatomical", "anatomy", "ancestor", "ancestral", "ancestry", "anchor", "anchorage", "anchovy", "ancient", "ancillary", "and",
+		"anecdotal", "anecdote", "anemia", "anemic", "anemone", "anesthesia", "anesthetic", "anew", "angel", "angelic", "anger", "angina",
+		"angle", "angler", "angling", "angora", "angry", "angst", "anguish", "angular", "animal", "animate", "animation", "animator",
+		"animosity", "anise", "ankle", "annals", "annex", "annexation", "annexe", "annihilate", "annihilation", "anniversary", "annotate",
+		"annotation", "announce", "announcement", "announcer", "annoy", "annoyance", "annual", "annuity", "annul", "annulment", "anode",
+		"anoint", "anomalous", "anomaly", "anonymity", "anonymous", "anorak", "anorexia", "anorexic", "another", "answer", "answerable", "ant",
+		"antagonism", "antagonist", "antagonistic", "antagonize", "antarctic", "ante", "anteater", "antecedent", "antechamber", "antelope",
+		"antenatal", "antenna", "anthem", "anthill", "anthology", "anthracite", "anthrax", "anthropological", "anthropologist", "anthropology",
+		"anti", "antibiotic", "antibody", "antic", "anticipate", "anticipation", "anticlimax", "anticlockwise", "antics", "antidote",
+		"antifreeze", "antigen", "antihistamine", "antimony", "antipathy", "antiquarian", "antiquated", "antique", "antiquity", "antiseptic",
+		"antisocial", "antithesis", "antler", "antonym", "anus", "anvil", "anxiety", "anxious", "any", "anybody", "anyhow", "anyone", "anyplace",
+		"anything", "anyway", "anywhere", "aorta", "apace", "apart", "apartheid", "apartment", "apathetic", "apathy", "ape", "aperitif",
+		"aperture", "apex", "aphid", "aphorism", "aphrodisiac", "apiary", "apiece", "aplomb", "apocalypse", "apocalyptic", "apologetic",
+		"apologist", "apologize", "apology", "apoplectic", "apoplexy", "apostle", "apostolic", "apostrophe", "apothecary", "appal", "appall",
+		"apparatus", "apparel", "apparent", "apparition", "appeal", "appear", "appearance", "appease", "appeasement", "appellant", "appellate",
+		"append", "appendage", "appendicitis", "appendix", "appetite", "appetizer", "applaud", "applause", "apple", "appliance", "applicable",
+		"applicant", "application", "applicator", "apply", "appoint", "appointee", "appointment", "apportion", "apposite", "appraisal",
+		"appraise", "appreciable", "appreciate", "appreciation", "appreciative", "apprehend", "apprehension", "apprehensive", "apprentice",
+		"apprenticeship", "apprise", "approach", "approachable", "approbation", "appropriate", "appropriation", "approval", "approve",
+		"approximate", "approximation", "apricot", "april", "apron", "apt", "aptitude", "aqualung", "aquamarine", "aquarium", "aquatic",
+		"aqueduct", "aqueous", "aquiline", "arabesque", "arable", "arbiter", "arbitrage", "arbitrary", "arbitrate", "arbitration", "arbitrator",
+		"arbor", "arboreal", "arbour", "arc", "arcade", "arcane", "arch", "archaeological", "archaeologist", "archaeology", "archaic",
+		"archangel", "archbishop", "archdeacon", "archeology", "archer", "archery", "archetypal", "archetype", "archipelago", "architect",
+		"architectural", "architecture", "archive", "archway", "arctic", "ardent", "ardor", "ardour", "arduous", "are", "area", "arena", "argon",
+		"argot", "arguable", "argue", "argument", "argumentative", "aria", "arid", "aridity", "aright", "arise", "aristocracy", "aristocrat",
+		"aristocratic", "arithmetic", "arithmetical", "ark", "arm", "armada", "armadillo", "armament", "armband", "armchair", "armful",
+		"armhole", "armistice", "armor", "armory", "armour", "armoury", "armpit", "army", "aroma", "aromatic", "around", "arousal", "arouse",
+		"arraign", "arraignment", "arrange", "arrangement", "arrant", "array", "arrears", "arrest", "arrival", "arrive", "arrogance", "arrogant",
+		"arrow", "arrowhead", "arsenal", "arsenic", "arson", "arsonist", "art", "artefact", "artery", "artful", "arthritic", "arthritis",
+		"artichoke", "article", "articulate", "articulation", "artifact", "artifice", "artificial", "artillery", "artisan", "artist", "artiste",
+		"artistic", "artistry", "artless", "arty", "as", "asbestos", "ascend", "ascendancy", "ascendant", "ascension", "ascent", "ascertain",
+		"ascetic", "asceticism", "ascribe", "aseptic", "asexual", "ash", "ashamed", "ashen", "ashore", "ashtray", "aside", "asinine", "ask",
+		"askance", "askew", "asleep", "asp", "asparagus", "aspect", "aspen", "aspersion", "asphalt", "asphyxiate", "asphyxiation", "aspic",
+		"aspirant", "aspirate", "aspiration", "aspire", "aspirin", "ass", "assail", "assailant", "assassin", "assassinate", "assassination",
+		"assault", "assay", "assemblage", "assemble", "assembly", "assent", "assert", "assertion", "assertive", "assess", "assessment",
+		"assessor", "asset", "assiduous", "assign", "assignation", "assignment", "assimilate", "assimilation", "assist", "assistance",
+		"assistant", "assize", "associate", "association", "assonance", "assortment", "assuage", "assume", "assumption", "assurance", "assure",
+		"asterisk", "astern", "asteroid", "asthma", "asthmatic", "astigmatism", "astonish", "astonishment", "astound", "astral", "astray",
+		"astride", "astringent", "astrologer", "astrology", "astronaut", "astronomer", "astronomical", "astronomy", "astrophysics", "astute",
+		"asunder", "asylum", "asymmetric", "asymmetry", "at", "atavistic", "atheism", "atheist", "athlete", "athletic", "athletics", "atlas",
+		"atmosphere", "atmospheric", "atoll", "atom", "atomic", "atone", "atonement", "atrocious", "atrocity", "atrophy", "attach", "attache",
+		"attachment", "attack", "attacker", "attain", "attainment", "attempt", "attend", "attendance", "attendant", "attention", "attentive",
+		"attenuate", "attest", "attestation", "attic", "attire", "attitude", "attorney", "attract", "attraction", "attractive", "attributable",
+		"attribute", "attribution", "attrition", "attune", "atypical", "aubergine", "auburn", "auction", "auctioneer", "audacious", "audacity",
+		"audible", "audience", "audio", "audiovisual", "audit", "audition", "auditor", "auditorium", "auditory", "augment", "augmentation",
+		"augur", "august", "aunt", "aura", "aural", "auspices", "auspicious", "austere", "austerity", "authentic", "authenticate",
+		"authenticity", "author", "authoress", "authorial", "authoritarian", "authoritative", "authority", "authorization", "authorize",
+		"authorship", "autism", "autistic", "auto", "autobiographical", "autobiography", "autocracy", "autocrat", "autocratic", "autograph",
+		"automate", "automatic", "automation", "automaton", "automobile", "automotive", "autonomous", "autonomy", "autopsy", "autumn",
+		"autumnal", "auxiliary", "avail", "available", "avalanche", "avarice", "avaricious", "avenge", "avenue", "aver", "average", "averse",
+		"aversion", "avert", "aviary", "aviation", "aviator", "avid", "avocado", "avoid", "avoidable", "avoidance", "avow", "avowal",
+		"avuncular", "await", "awake", "awaken", "award", "aware", "awareness", "awash", "away", "awe", "awesome", "awful", "awhile", "awkward",
+		"awl", "awning", "awry", "axe", "axiom", "axiomatic", "axis", "axle", "ay", "aye", "azalea", "azure", "baa", "babble", "babe", "baboon",
+		"baby", "babyhood", "babyish", "babysit", "babysitter", "baccalaureate", "bachelor", "bacillus", "back", "backache", "backbench",
+		"backbencher", "backbite", "backbone", "backbreaking", "backcloth", "backdate", "backdrop", "backer", "backfire", "backgammon",
+		"background", "backhand", "backhanded", "backlash", "backlog", "backpack", "backpacker", "backside", "backslash", "backslide",
+		"backstage", "backstroke", "backtrack", "backup", "backward", "backwards", "backwater", "backwoods", "backyard", "bacon", "bacteria",
+		"bacterial", "bacteriology", "bacterium", "bad", "badge", "badger", "badinage", "badminton", "baffle", "bag", "bagel", "baggage",
+		"baggy", "bagpipe", "bail", "bailiff", "bait", "bake", "baker", "bakery", "balaclava", "balance", "balcony", "bald", "balderdash",
+		"bale", "baleful", "balk", "ball", "ballad", "ballast", "ballerina", "ballet", "ballistic", "ballistics", "balloon", "ballot",
+		"ballpoint", "ballroom", "balm", "balmy", "baloney", "balsa", "balustrade", "bamboo", "bamboozle", "ban", "banal", "banality", "banana",
+		"band", "bandage", "bandanna", "bandit", "bandstand", "bandwagon", "bandwidth", "bandy", "bane", "bang", "bangle", "banish",
+		"banishment", "banister", "banjo", "bank", "banker", "banknote", "bankrupt", "bankruptcy", "banner", "banquet", "bantam", "banter",
+		"baptism", "baptize", "bar", "barb", "barbarian", "barbaric", "barbarism", "barbarous", "barbecue", "barbed", "barber", "barbiturate",
+		"bard", "bare", "barefoot", "barely", "bargain", "barge", "baritone", "barium", "bark", "barley", "barmaid", "barman", "barn",
+		"barnacle", "barnyard", "barometer", "barometric", "baron", "baroness", "baronet", "baroque", "barrack", "barracks", "barrage", "barrel",
+		"barren", "barricade", "barrier", "barrister", "barrow", "bartender", "barter", "basal", "basalt", "base", "baseball", "baseboard",
+		"baseless", "baseline", "basement", "bash", "bashful", "basic", "basically", "basil", "basilica", "basin", "basis", "bask", "basket",
+		"basketball", "bass", "bassoon", "bastard", "bastion", "bat", "batch", "bath", "bathe", "bather", "bathroom", "bathtub", "baton",
+		"battalion", "batten", "batter", "battery", "battle", "battlefield", "battlement", "battleship", "bauble", "baulk", "bawdy", "bawl",
+		"bay", "bayonet", "bazaar", "bazooka", "be", "beach", "beacon", "bead", "beady", "beagle", "beak", "beaker", "beam", "bean", "bear",
+		"bearable", "beard", "bearer", "bearing", "beast", "beastly", "beat", "beater", "beatific", "beatify", "beau", "beautician", "beautiful",
+		"beautify", "beauty", "beaver", "becalmed", "because", "beck", "beckon", "become", "bed", "bedbug", "bedclothes", "bedding", "bedevil",
+		"bedlam", "bedraggled", "bedridden", "bedrock", "bedroom", "bedside", "bedsit", "bedspread", "bedstead", "bedtime", "bee", "beech",
+		"beef", "beefburger", "beefy", "beehive", "beeline", "beer", "beeswax", "beet", "beetle", "beetroot", "befall", "befit", "before",
+		"beforehand", "befriend", "befuddle", "beg", "beget", "beggar", "begin", "beginner", "beginning", "begonia", "begrudge", "beguile",
+		"behalf", "behave", "behavior", "behavioral", "behaviour", "behavioural", "behead", "behest", "behind", "behold", "beholden", "beige",
+		"being", "belabor", "belabour", "belated", "belch", "beleaguer", "belfry", "belie", "belief", "believable", "believe", "believer",
+		"belittle", "bell", "belle", "bellicose", "belligerence", "belligerent", "bellow", "belly", "belong", "belongings", "beloved", "below",
+		"belt", "bemoan", "bemuse", "bench", "benchmark", "bend", "beneath", "benediction", "benefactor", "beneficent", "beneficial",
+		"beneficiary", "benefit", "benevolence", "benevolent", "benign", "bent", "bequeath", "bequest", "berate", "bereave", "bereavement",
+		"bereft", "beret", "berry", "berserk", "berth", "beseech", "beset", "beside", "besides", "besiege", "besotted", "bespectacled", "best",
+		"bestial", "bestiality", "bestow", "bestseller", "bet", "beta", "betide", "betray", "betrayal", "betroth", "betrothal", "better",
+		"between", "bevel", "beverage", "bevy", "bewail", "beware", "bewilder", "bewilderment", "bewitch", "beyond", "bias", "bib", "bible",
+		"biblical", "bibliography", "bicentenary", "bicentennial", "biceps", "bicker", "bicycle", "bid", "bidder", "bide", "biennial",
+		"bifocals", "big", "bigamist", "bigamy", "bigot", "bigotry", "bike", "bikini", "bilateral", "bile", "bilge", "bilingual", "bilious",
+		"bill", "billboard", "billet", "billiards", "billion", "billionaire", "billow", "bin", "binary", "bind", "binder", "binding", "binge",
+		"bingo", "binoculars", "biochemical", "biochemist", "biochemistry", "biodegradable", "biographer", "biographical", "biography",
+		"biological", "biologist", "biology", "biopsy", "biosphere", "biotechnology", "bipartisan", "biped", "biplane", "birch", "bird",
+		"birdie", "birth", "birthday", "birthmark", "birthplace", "birthright", "biscuit", "bisect", "bisexual", "bishop", "bison", "bistro",
+		"bit", "bitch", "bite", "bitter", "bitterness", "bitumen", "bivouac", "bizarre", "blab", "black", "blackberry", "blackbird",
+		"blackboard", "blacken", "blackguard", "blackhead", "blackleg", "blacklist", "blackmail", "blackmailer", "blackout", "blacksmith",
+		"bladder", "blade", "blame", "blameless", "blanch", "bland", "blandishment", "blank", "blanket", "blare", "blaspheme", "blasphemous",
+		"blasphemy", "blast", "blatant", "blaze", "blazer", "bleach", "bleak", "bleary", "bleat", "bleed", "bleep", "blemish", "blend",
+		"blender", "bless", "blessing", "blight", "blimp", "blind", "blindfold", "blink", "blip", "bliss", "blissful", "blister", "blithe",
+		"blitz", "blizzard", "bloat", "blob", "bloc", "block", "blockade", "blockage", "blockbuster", "bloke", "blond", "blonde", "blood",
+		"bloodbath", "bloodhound", "bloodless", "bloodshed", "bloodshot", "bloodstain", "bloodstream", "bloodthirsty", "bloody", "bloom",
+		"blossom", "blot", "blotch", "blotter", "blouse", "blow", "blowlamp", "blowout", "blowpipe", "blowtorch", "blubber", "bludgeon", "blue",
+		"bluebell", "blueberry", "bluebottle", "blueprint", "bluff", "blunder", "blunt", "blur", "blurb", "blurt", "blush", "bluster", "boa",
+		"boar", "board", "boarder", "boardroom", "boast", "boastful", "boat", "boatman", "bob", "bobbin", "bobsleigh", "bode", "bodice",
+		"bodily", "body", "bodyguard", "bodywork", "bog", "bogey", "boggle", "bogus", "boil", "boiler", "boisterous", "bold", "bole", "bollard",
+		"bolster", "bolt", "bomb", "bombard", "bombardment", "bombastic", "bomber", "bombshell", "bona", "bonanza", "bond", "bondage", "bone",
+		"bonfire", "bonnet", "bonny", "bonus", "bony", "boo", "boob", "booby", "book", "bookcase", "bookie", "bookkeeper", "bookkeeping",
+		"booklet", "bookmaker", "bookmark", "bookseller", "bookshelf", "bookshop", "bookstore", "bookworm", "boom", "boomerang", "boon", "boor",
+		"boorish", "boost", "booster", "boot", "booth", "bootleg", "booty", "booze", "border", "borderline", "bore", "boredom", "born",
+		"borough", "borrow", "borrower", "bosom", "boss", "bossy", "botanical", "botanist", "botany", "botch", "both", "bother", "bothersome",
+		"bottle", "bottleneck", "bottom", "bottomless", "bough", "boulder", "boulevard", "bounce", "bouncer", "bound", "boundary", "boundless",
+		"bounteous", "bountiful", "bounty", "bouquet", "bourbon", "bourgeois", "bourgeoisie", "bout", "boutique", "bovine", "bow", "bowel",
+		"bower", "bowl", "bowler", "bowling", "box", "boxer", "boxing", "boy", "boycott", "boyfriend", "boyhood", "boyish", "bra", "brace",
+		"bracelet", "bracing", "bracken", "bracket", "brackish", "brag", "braggart", "braid", "braille", "brain", "brainchild", "brainless",
+		"brainstorm", "brainwash", "brainwave", "brainy", "braise", "brake", "bramble", "bran", "branch", "brand", "brandish", "brandy", "brash",
+		"brass", "brassiere", "brassy", "brat", "bravado", "brave", "bravery", "bravo", "brawl", "brawn", "brawny", "bray", "brazen", "brazier",
+		"breach", "bread", "breadth", "breadwinner", "break", "breakable", "breakage", "breakdown", "breakfast", "breakneck", "breakthrough",
+		"breakwater", "breast", "breaststroke", "breath", "breathalyser", "breathe", "breather", "breathless", "breathtaking", "breed",
+		"breeder", "breeze", "breezy", "brethren", "brevity", "brew", "brewer", "brewery", "bribe", "bribery", "brick", "bricklayer",
+		"brickwork", "bridal", "bride", "bridegroom", "bridesmaid", "bridge", "bridle", "brief", "briefcase", "brigade", "brigadier", "brigand",
+		"bright", "brighten", "brilliance", "brilliant", "brim", "brimstone", "brine", "bring", "brink", "brinkmanship", "brisk", "bristle",
+		"brittle", "broach", "broad", "broadcast", "broadcaster", "broaden", "broadside", "brocade", "broccoli", "brochure", "brogue", "broil",
+		"broiler", "broke", "broken", "broker", "brokerage", "bromide", "bronchial", "bronchitis", "bronco", "bronze", "brooch", "brood",
+		"broody", "brook", "broom", "broth", "brothel", "brother", "brotherhood", "brotherly", "brow", "browbeat", "brown", "browse", "browser",
+		"bruise", "brunch", "brunette", "brunt", "brush", "brusque", "brutal", "brutality", "brutalize", "brute", "brutish", "bubble", "bubbly",
+		"buccaneer", "buck", "bucket", "buckle", "bud", "buddy", "budge", "budgerigar", "budget", "buff", "buffalo", "buffer", "buffet",
+		"buffoon", "bug", "bugbear", "buggy", "bugle", "build", "builder", "building", "bulb", "bulbous", "bulge", "bulk", "bulky", "bull",
+		"bulldog", "bulldoze", "bulldozer", "bullet", "bulletin", "bulletproof", "bullfight", "bullion", "bullock", "bully", "bulwark", "bum",
+		"bumble", "bump", "bumper", "bumpy", "bun", "bunch", "bundle", "bung", "bungalow", "bungle", "bunion", "bunk", "bunker", "bunny", "buoy",
+		"buoyancy", "buoyant", "burble", "burden", "burdensome", "bureau", "bureaucracy", "bureaucrat", "bureaucratic", "burgeon", "burger",
+		"burglar", "burglary", "burgle", "burial", "burlesque", "burly", "burn", "burner", "burnish", "burp", "burrow", "bursar", "bursary",
+		"burst", "bury", "bus", "bush", "bushel", "bushy", "business", "businesslike", "businessman", "businesswoman", "bust", "bustle", "busy",
+		"busybody", "but", "butane", "butch", "butcher", "butchery", "butler", "butt", "butter", "buttercup", "butterfly", "buttermilk",
+		"butterscotch", "buttock", "button", "buttonhole", "buttress", "buxom", "buy", "buyer", "buzz", "buzzard", "buzzer", "by", "bye",
+		"bygone", "bylaw", "bypass", "byproduct", "bystander", "byte", "byword", "cab", "cabaret", "cabbage", "cabin", "cabinet", "cable",
+		"cache", "cackle", "cacophony", "cactus", "cad", "cadaver", "caddie", "caddy", "cadence", "cadet", "cadge", "cadmium", "cafe",
+		"cafeteria", "caffeine", "cage", "cagey", "cajole", "cake", "calamitous", "calamity", "calcify", "calcium", "calculable", "calculate",
+		"calculation", "calculator", "calculus", "calendar", "calf", "caliber", "calibrate", "calibration", "calibre", "calico", "caliph",
+		"call", "caller", "calligraphy", "calling", "callous", "callow", "callus", "calm", "calorie", "calorific", "calve", "calypso",
+		"camaraderie", "camber", "camcorder", "camel", "cameo", "camera", "cameraman", "camouflage", "camp", "campaign", "campaigner", "camper",
+		"campfire", "campsite", "campus", "camshaft", "can", "canal", "canary", "cancel", "cancellation", "cancer", "cancerous", "candelabra",
+		"candid", "candidacy", "candidate", "candle", "candlelight", "candlestick", "candor", "candour", "candy", "cane", "canine", "canister",
+		"canker", "cannabis", "cannery", "cannibal", "cannibalism", "cannon", "cannonball", "canny", "canoe", "canon", "canonical", "canopy",
+		"cant", "cantankerous", "canteen", "canter", "cantilever", "canton", "canvas", "canvass", "canyon", "cap", "capability", "capable",
+		"capacious", "capacitance", "capacitor", "capacity", "cape", "caper", "capillary", "capital", "capitalism", "capitalist", "capitalize",
+		"capitulate", "capitulation", "caprice", "capricious", "capsize", "capstan", "capsule", "captain", "caption", "captivate", "captive",
+		"captivity", "captor", "capture", "car", "carafe", "caramel", "carat", "caravan", "carbohydrate", "carbon", "carbonate", "carburetor",
+		"carburettor", "carcass", "carcinogen", "carcinogenic", "card", "cardboard", "cardiac", "cardigan", "cardinal", "cardiology", "care",
+		"careen", "career", "carefree", "careful", "careless", "caress", "caretaker", "cargo", "caribou", "caricature", "caries", "carnage",
+		"carnal", "carnation", "carnival", "carnivore", "carnivorous", "carol", "carouse", "carousel", "carp", "carpenter", "carpentry",
+		"carpet", "carriage", "carriageway", "carrier", "carrion", "carrot", "carry", "cart", "cartel", "cartilage", "cartographer",
+		"cartography", "carton", "cartoon", "cartoonist", "cartridge", "cartwheel", "carve", "carving", "cascade", "case", "casement", "cash",
+		"cashew", "cashier", "cashmere", "casino", "cask", "casket", "casserole", "cassette", "cassock", "cast", "castaway", "caste",
+		"castigate", "castle", "castor", "castrate", "castration", "casual", "casualty", "cat", "cataclysm", "cataclysmic", "catacomb",
+		"catalog", "catalogue", "catalyse", "catalyst", "catalytic", "catapult", "cataract", "catarrh", "catastrophe", "catastrophic", "catch",
+		"catchment", "catchphrase", "catchy", "catechism", "categorical", "categorize", "category", "cater", "caterer", "caterpillar", "catfish",
+		"catharsis", "cathartic", "cathedral", "catheter", "cathode", "catholic", "catkin", "cattle", "catwalk", "caucus", "cauldron",
+		"cauliflower", "causal", "causality", "causation", "causative", "cause", "causeway", "caustic", "cauterize", "caution", "cautionary",
+		"cautious", "cavalcade", "cavalier", "cavalry", "cave", "caveat", "caveman", "cavern", "cavernous", "caviar", "cavity", "cavort",
+		"cease", "ceasefire", "ceaseless", "cedar", "cede", "ceiling", "celebrant", "celebrate", "celebration", "celebratory", "celebrity",
+		"celery", "celestial", "celibacy", "celibate", "cell", "cellar", "cellist", "cello", "cellophane", "cellular", "celluloid", "cellulose",
+		"cement", "cemetery", "cenotaph", "censor", "censorious", "censorship", "censure", "census", "cent", "centaur", "centenarian",
+		"centenary", "centennial", "center", "centigrade", "centimeter", "centimetre", "centipede", "central", "centralize", "centre",
+		"centrifugal", "centrifuge", "centurion", "century", "ceramic", "ceramics", "cereal", "cerebral", "ceremonial", "ceremonious",
+		"ceremony", "certain", "certainty", "certificate", "certify", "certitude", "cervical", "cervix", "cessation", "cesspit", "cesspool",
+		"chafe", "chaff", "chagrin", "chain", "chainsaw", "chair", "chairman", "chairmanship", "chairperson", "chairwoman", "chalet", "chalice",
+		"chalk", "chalky", "challenge", "challenger", "chamber", "chamberlain", "chambermaid", "chameleon", "champ", "champagne", "champion",
+		"championship", "chance", "chancel", "chancellor", "chancery", "chancy", "chandelier", "change", "changeable", "changeover", "channel",
+		"chant", "chaos", "chaotic", "chap", "chapel", "chaperone", "chaplain", "chapter", "char", "character", "characteristic",
+		"characterization", "characterize", "charade", "charcoal", "charge", "charger", "chariot", "charioteer", "charisma", "charismatic",
+		"charitable", "charity", "charlatan", "charm", "charmer", "chart", "charter", "chary", "chase", "chaser", "chasm", "chassis", "chaste",
+		"chasten", "chastise", "chastisement", "chastity", "chat", "chateau", "chatter", "chatterbox", "chatty", "chauffeur", "chauvinism",
+		"chauvinist", "cheap", "cheapen", "cheat", "check", "checkbook", "checker", "checklist", "checkmate", "checkout", "checkpoint",
+		"checkup", "cheek", "cheeky", "cheep", "cheer", "cheerful", "cheerio", "cheerless", "cheery", "cheese", "cheesecake", "cheetah", "chef",
+		"chemical", "chemist", "chemistry", "chemotherapy", "cheque", "chequebook", "cherish", "cheroot", "cherry", "cherub", "chess",
+		"chessboard", "chest", "chestnut", "chevron", "chew", "chic", "chicanery", "chick", "chicken", "chickpea", "chicory", "chide", "chief",
+		"chieftain", "chiffon", "chilblain", "child", "childbirth", "childhood", "childish", "childless", "childlike", "chill", "chilli",
+		"chilly", "chime", "chimney", "chimpanzee", "chin", "china", "chink", "chip", "chipmunk", "chiropodist", "chiropody", "chiropractor",
+		"chirp", "chisel", "chit", "chivalrous", "chivalry", "chive", "chlorinate", "chlorine", "chloroform", "chlorophyll", "chock",
+		"chocolate", "choice", "choir", "choirboy", "choke", "cholera", "cholesterol", "choose", "choosy", "chop", "chopper", "choppy",
+		"chopstick", "choral", "chord", "chore", "choreographer", "choreography", "chorister", "chortle", "chorus", "chowder", "christen",
+		"christening", "chrome", "chromium", "chromosome", "chronic", "chronicle", "chronicler", "chronological", "chronology", "chronometer",
+		"chrysalis", "chrysanthemum", "chubby", "chuck", "chuckle", "chug", "chum", "chunk", "chunky", "church", "churchyard", "churlish",
+		"churn", "chute", "chutney", "cicada", "cider", "cigar", "cigarette", "cinch", "cinder", "cinema", "cinematic", "cinematography",
+		"cinnamon", "cipher", "circa", "circle", "circuit", "circuitous", "circular", "circulate", "circulation", "circulatory", "circumcise",
+		"circumcision", "circumference", "circumflex", "circumlocution", "circumnavigate", "circumscribe", "circumspect", "circumstance",
+		"circumstantial", "circumvent", "circus", "cirrhosis", "cistern", "citadel", "citation", "cite", "citizen", "citizenry", "citizenship",
+		"citric", "citrus", "city", "civic", "civics", "civil", "civilian", "civility", "civilization", "civilize", "clad", "claim", "claimant",
+		"clairvoyance", "clairvoyant", "clam", "clamber", "clammy", "clamor", "clamour", "clamp", "clampdown", "clan", "clandestine", "clang",
+		"clank", "clap", "clapper", "claptrap", "claret", "clarification", "clarify", "clarinet", "clarinettist", "clarion", "clarity", "clash",
+		"clasp", "class", "classic", "classical", "classicism", "classicist", "classification", "classify", "classmate", "classroom", "classy",
+		"clatter", "clause", "claustrophobia", "claustrophobic", "claw", "clay", "clean", "cleaner", "cleanliness", "cleanly", "cleanse",
+		"cleanser", "clear", "clearance", "clearing", "cleavage", "cleave", "cleaver", "clef", "cleft", "clemency", "clement", "clench",
+		"clergy", "clergyman", "cleric", "clerical", "clerk", "clever", "cliche", "click", "client", "clientele", "cliff", "climactic",
+		"climate", "climatic", "climax", "climb", "climber", "clinch", "cling", "clinic", "clinical", "clinician", "clink", "clip", "clipboard",
+		"clipper", "clipping", "clique", "cloak", "cloakroom", "clobber", "clock", "clockwise", "clockwork", "clod", "clog", "cloister", "clone",
+		"close", "closet", "closure", "clot", "cloth", "clothe", "clothes", "clothing", "cloud", "cloudburst", "cloudless", "cloudy", "clout",
+		"clove", "clover", "clown", "clownish", "club", "clubhouse", "cluck", "clue", "clueless", "clump", "clumsy", "cluster", "clutch",
+		"clutter", "coach", "coachman", "coagulate", "coal", "coalesce", "coalface", "coalfield", "coalition", "coarse", "coarsen", "coast",
+		"coastal", "coaster", "coastguard", "coastline", "coat", "coating", "coax", "cob", "cobalt", "cobble", "cobbler", "cobra", "cobweb",
+		"cocaine", "cock", "cockerel", "cockney", "cockpit", "cockroach", "cocktail", "cocky", "cocoa", "coconut", "cocoon", "cod", "coddle",
+		"code", "codify", "coerce", "coercion", "coercive", "coexist", "coexistence", "coffee", "coffer", "coffin", "cog", "cogent", "cogitate",
+		"cognac", "cognition", "cognitive", "cognizant", "cohabit", "cohere", "coherence", "coherent", "cohesion", "cohesive", "cohort", "coil",
+		"coin", "coinage", "coincide", "coincidence", "coincident", "coincidental", "coke", "colander", "cold", "coleslaw", "colic",
+		"collaborate", "collaboration", "collaborative", "collaborator", "collage", "collapse", "collapsible", "collar", "collarbone", "collate",
+		"collateral", "colleague", "collect", "collectable", "collection", "collective", "collectivism", "collector", "college", "collegiate",
+		"collide", "collie", "colliery", "collision", "colloquial", "colloquialism", "collusion", "cologne", "colon", "colonel", "colonial",
+		"colonialism", "colonist", "colonize", "colonnade", "colony", "color", "colossal", "colossus", "colour", "colourful", "colt", "column",
+		"columnist", "coma", "comatose", "comb", "combat", "combatant", "combative", "combination", "combine", "combustible", "combustion",
+		"come", "comeback", "comedian", "comedienne", "comedown", "comedy", "comely", "comet", "comfort", "comfortable", "comforter", "comic",
+		"comical", "comma", "command", "commandant", "commandeer", "commander", "commandment", "commando", "commemorate", "commemoration",
+		"commence", "commencement", "commend", "commendable", "commendation", "commensurate", "comment", "commentary", "commentate",
+		"commentator", "commerce", "commercial", "commercialism", "commercialize", "commiserate", "commiseration", "commissar", "commission",
+		"commissionaire", "commissioner", "commit", "commitment", "committee", "commode", "commodity", "commodore", "common", "commoner",
+		"commonplace", "commonwealth", "commotion", "communal", "commune", "communicable", "communicant", "communicate", "communication",
+		"communicative", "communion", "communique", "communism", "communist", "community", "commute", "commuter", "compact", "compaction",
+		"companion", "companionable", "companionship", "company", "comparable", "comparative", "compare", "comparison", "compartment", "compass",
+		"compassion", "compassionate", "compatibility", "compatible", "compatriot", "compel", "compendium", "compensate", "compensation",
+		"compensatory", "compere", "compete", "competence", "competent", "competition", "competitive", "competitor", "compilation", "compile",
+		"compiler", "complacency", "complacent", "complain", "complainant", "complaint", "complement", "complementary", "complete", "completion",
+		"complex", "complexion", "complexity", "compliance", "compliant", "complicate", "complication", "complicity", "compliment",
+		"complimentary", "comply", "component", "comport", "compose", "composer", "composite", "composition", "compositor", "compost",
+		"composure", "compound", "comprehend", "comprehensible", "comprehension", "comprehensive", "compress", "compression", "compressor",
+		"comprise", "compromise", "compulsion", "compulsive", "compulsory", "compunction", "computation", "compute", "computer", "computerize",
+		"comrade", "comradeship", "con", "concave", "conceal", "concealment", "concede", "conceit", "conceited", "conceivable", "conceive",
+		"concentrate", "concentration", "concentric", "concept", "conception", "conceptual", "concern", "concert", "concerted", "concertina",
+		"concerto", "concession", "conch", "conciliate", "conciliation", "conciliatory", "concise", "conclave", "conclude", "conclusion",
+		"conclusive", "concoct", "concoction", "concomitant", "concord", "concordance", "concourse", "concrete", "concubine", "concur",
+		"concurrence", "concurrent", "concussion", "condemn", "condemnation", "condensation", "condense", "condescend", "condescension",
+		"condiment", "condition", "conditional", "conditioner", "condolence", "condom", "condominium", "condone", "condor", "conducive",
+		"conduct", "conduction", "conductive", "conductivity", "conductor", "conduit", "cone", "confection", "confectioner", "confectionery",
+		"confederacy", "confederate", "confederation", "confer", "conference", "confess", "confession", "confessional", "confessor", "confetti",
+		"confidant", "confide", "confidence", "confident", "confidential", "configuration", "configure", "confine", "confinement", "confirm",
+		"confirmation", "confiscate", "confiscation", "conflagration", "conflate", "conflict", "confluence", "conform", "conformist",
+		"conformity", "confound", "confront", "confrontation", "confrontational", "confuse", "confusion", "congeal", "congenial", "congenital",
+		"congested", "congestion", "conglomerate", "conglomeration", "congratulate", "congratulation", "congratulatory", "congregate",
+		"congregation", "congress", "congressional", "congressman", "conical", "conifer", "coniferous", "conjecture", "conjugal", "conjugate",
+		"conjugation", "conjunction", "conjure", "conjurer", "conk", "connect", "connection", "connective", "connector", "connoisseur",
+		"connotation", "connote", "conquer", "conqueror", "conquest", "conscience", "conscientious", "conscious", "consciousness", "conscript",
+		"conscription", "consecrate", "consecration", "consecutive", "consensus", "consent", "consequence", "consequent", "consequential",
+		"conservation", "conservationist", "conservatism", "conservative", "conservatory", "conserve", "consider", "considerable", "considerate",
+		"consideration", "consign", "consignment", "consist", "consistency", "consistent", "consolation", "console", "consolidate",
+		"consolidation", "consonant", "consort", "consortium", "conspicuous", "conspiracy", "conspirator", "conspiratorial", "conspire",
+		"constable", "constabulary", "constancy", "constant", "constellation", "consternation", "constipate", "constipation", "constituency",
+		"constituent", "constitute", "constitution", "constitutional", "constrain", "constraint", "constrict", "constriction", "construct",
+		"construction", "constructive", "constructor", "construe", "consul", "consular", "consulate", "consult", "consultancy", "consultant",
+		"consultation", "consultative", "consume", "consumer", "consumerism", "consummate", "consummation", "consumption", "contact",
+		"contagion", "contagious", "contain", "container", "containment", "contaminant", "contaminate", "contamination", "contemplate",
+		"contemplation", "contemplative", "contemporary", "contempt", "contemptible", "contemptuous", "contend", "contender", "content",
+		"contention", "contentious", "contentment", "contest", "contestant", "context", "contextual", "contiguous", "continence", "continent",
+		"continental", "contingency", "contingent", "continual", "continuance", "continuation", "continue", "continuity", "continuous",
+		"continuum", "contort", "contortion", "contour", "contraband", "contraception", "contraceptive", "contract", "contraction", "contractor",
+		"contractual", "contradict", "contradiction", "contradictory", "contraption", "contrary", "contrast", "contravene", "contravention",
+		"contribute", "contribution", "contributor", "contributory", "contrite", "contrition", "contrivance", "contrive", "control",
+		"controller", "controversial", "controversy", "conundrum", "conurbation", "convalesce", "convalescence", "convalescent", "convection",
+		"convene", "convener", "convenience", "convenient", "convent", "convention", "conventional", "converge", "convergence", "convergent",
+		"conversant", "conversation", "conversational", "converse", "conversion", "convert", "converter", "convertible", "convex", "convey",
+		"conveyance", "conveyor", "convict", "conviction", "convince", "convivial", "convocation", "convoluted", "convolution", "convoy",
+		"convulse", "convulsion", "coo", "cook", "cooker", "cookery", "cookie", "cool", "coolant", "cooler", "coop", "cooperate", "cooperation",
+		"cooperative", "coordinate", "coordination", "coordinator", "cop", "cope", "copier", "copious", "copper", "coppice", "copse", "copulate",
+		"copulation", "copy", "copyright", "coral", "cord", "cordial", "cordon", "corduroy", "core", "coriander", "cork", "corkscrew", "corn",
+		"cornea", "corner", "cornerstone", "cornet", "cornflakes", "cornflour", "cornice", "cornucopia", "corny", "corollary", "corona",
+		"coronary", "coronation", "coroner", "coronet", "corporal", "corporate", "corporation", "corporeal", "corps", "corpse", "corpulent",
+		"corpus", "corpuscle", "corral", "correct", "correction", "corrective", "correlate", "correlation", "correspond", "correspondence",
+		"correspondent", "corridor", "corroborate", "corroboration", "corrode", "corrosion", "corrosive", "corrugated", "corrupt", "corruption",
+		"corset", "cortex", "cosmetic", "cosmic", "cosmology", "cosmonaut", "cosmopolitan", "cosmos", "cost", "costly", "costume", "cosy", "cot",
+		"cottage", "cotton", "couch", "cougar", "cough", "could", "council", "councillor", "councilor", "counsel", "counselling", "counsellor",
+		"counselor", "count", "countdown", "countenance", "counter", "counteract", "counterattack", "counterbalance", "counterfeit",
+		"counterfoil", "countermand", "counterpart", "counterpoint", "countersign", "countess", "countless", "country", "countryman",
+		"countryside", "county", "coup", "couple", "couplet", "coupon", "courage", "courageous", "courgette", "courier", "course", "court",
+		"courteous", "courtesan", "courtesy", "courthouse", "courtier", "courtly", "courtroom", "courtship", "courtyard", "cousin", "cove",
+		"covenant", "cover", "coverage", "covert", "covet", "cow", "coward", "cowardice", "cowardly", "cowboy", "cower", "cowl", "coy", "coyote",
+		"crab", "crack", "crackdown", "cracker", "crackle", "crackpot", "cradle", "craft", "craftsman", "craftsmanship", "crafty", "crag",
+		"cram", "cramp", "crampon", "cranberry", "crane", "cranium", "crank", "cranky", "cranny", "crap", "crash", "crass", "crate", "crater",
+		"cravat", "crave", "craving", "crawl", "crayfish", "crayon", "craze", "crazy", "creak", "cream", "creamy", "crease", "create",
+		"creation", "creative", "creativity", "creator", "creature", "creche", "credence", "credentials", "credibility", "credible", "credit",
+		"creditable", "creditor", "credo", "credulous", "creed", "creek", "creep", "creepy", "cremate", "cremation", "crematorium", "creole",
+		"creosote", "crepe", "crescendo", "crescent", "cress", "crest", "crestfallen", "cretin", "crevasse", "crevice", "crew", "crib", "crick",
+		"cricket", "cricketer", "crime", "criminal", "criminology", "crimson", "cringe", "crinkle", "cripple", "crisis", "crisp", "crispy",
+		"crisscross", "criterion", "critic", "critical", "criticism", "criticize", "critique", "croak", "crochet", "crock", "crockery",
+		"crocodile", "crocus", "croissant", "crone", "crony", "crook", "crooked", "croon", "crop", "croquet", "cross", "crossbar", "crossbow",
+		"crossfire", "crossroads", "crossword", "crotch", "crouch", "croupier", "crow", "crowbar", "crowd", "crown", "crucial", "crucible",
+		"crucifix", "crucifixion", "crucify", "crude", "cruel", "cruelty", "cruet", "cruise", "cruiser", "crumb", "crumble", "crumple", "crunch",
+		"crusade", "crusader", "crush", "crust", "crustacean", "crusty", "crutch", "crux", "cry", "cryptic", "crystal", "crystalline",
+		"crystallize", "cub", "cube", "cubic", "cubicle", "cuckoo", "cucumber", "cuddle", "cudgel", "cue", "cuff", "cuisine", "culinary", "cull",
+		"culminate", "culmination", "culpable", "culprit", "cult", "cultivate", "cultivation", "cultural", "culture", "cumbersome", "cumulative",
+		"cunning", "cup", "cupboard", "cupola", "curable", "curate", "curative", "curator", "curb", "curd", "curdle", "cure", "curfew", "curio",
+		"curiosity", "curious", "curl", "curler", "curly", "currant", "currency", "current", "curriculum", "curry", "curse", "cursor", "cursory",
+		"curt", "curtail", "curtain", "curtsy", "curvature", "curve", "cushion", "cusp", "custard", "custodial", "custodian", "custody",
+		"custom", "customary", "customer", "customize", "cut", "cutback", "cute", "cuticle", "cutlery", "cutlet", "cutter", "cutthroat",
+		"cutting", "cyanide", "cybernetics", "cycle", "cyclic", "cyclical", "cyclist", "cyclone", "cygnet", "cylinder", "cymbal", "cynic",
+		"cynical", "cynicism", "cypress", "cyst", "cystitis", "czar", "dab", "dabble", "dachshund", "dad", "daddy", "daffodil", "daft", "dagger",
+		"dahlia", "daily", "dainty", "dairy", "dais", "daisy", "dale", "dally", "dam", "damage", "damask", "dame", "damn", "damnation", "damp",
+		"dampen", "damper", "damsel", "damson", "dance", "dancer", "dandelion", "dandruff", "dandy", "danger", "dangerous", "dangle", "dank",
+		"dapper", "dappled", "dare", "daredevil", "dark", "darken", "darkness", "darkroom", "darling", "darn", "dart", "dartboard", "dash",
+		"dashboard", "dastardly", "data", "database", "date", "dateline", "dative", "daub", "daughter", "daunt", "dauntless", "dawdle", "dawn",
+		"day", "daybreak", "daydream", "daylight", "daytime", "daze", "dazzle", "deacon", "dead", "deaden", "deadline", "deadlock", "deadly",
+		"deaf", "deafen", "deal", "dealer", "dealership", "dean", "dear", "dearth", "death", "deathbed", "deathly", "debacle", "debar", "debase",
+		"debatable", "debate", "debauch", "debauchery", "debenture", "debilitate", "debility", "debit", "debonair", "debrief", "debris", "debt",
+		"debtor", "debug", "debunk", "debut", "decade", "decadence", "decadent", "decaffeinated", "decamp", "decant", "decanter", "decapitate",
+		"decathlon", "decay", "decease", "deceased", "deceit", "deceitful", "deceive", "decelerate", "deceleration", "december", "decency",
+		"decent", "decentralize", "deception", "deceptive", "decibel", "decide", "decidedly", "deciduous", "decimal", "decimate", "decipher",
+		"decision", "decisive", "deck", "deckchair", "declaim", "declamation", "declaration", "declare", "declassify", "declension", "decline",
+		"decode", "decompose", "decomposition", "decompress", "decongestant", "decontaminate", "decor", "decorate", "decoration", "decorative",
+		"decorator", "decorous", "decorum", "decoy", "decrease", "decree", "decrepit", "decriminalize", "decry", "dedicate", "dedication",
+		"deduce", "deduct", "deductible", "deduction", "deductive", "deed", "deem", "deep", "deepen", "deer", "deface", "defamation",
+		"defamatory", "defame", "default", "defeat", "defeatism", "defeatist", "defecate", "defect", "defection", "defective", "defector",
+		"defence", "defenceless", "defend", "defendant", "defender", "defense", "defenseless", "defensible", "defensive", "defer", "deference",
+		"deferential", "deferment", "defiance", "defiant", "deficiency", "deficient", "deficit", "defile", "define", "definite", "definition",
+		"definitive", "deflate", "deflation", "deflationary", "deflect", "deflection", "deforestation", "deform", "deformation", "deformity",
+		"defraud", "defray", "defrost", "deft", "defunct", "defuse", "defy", "degenerate", "degeneration", "degradation", "degrade", "degree",
+		"dehydrate", "dehydration", "deify", "deign", "deity", "deject", "dejection", "delay", "delectable", "delegate", "delegation", "delete",
+		"deleterious", "deletion", "deliberate", "deliberation", "delicacy", "delicate", "delicatessen", "delicious", "delight", "delightful",
+		"delimit", "delineate", "delinquency", "delinquent", "delirious", "delirium", "deliver", "deliverance", "delivery", "dell", "delta",
+		"delude", "deluge", "delusion", "deluxe", "delve", "demagogue", "demand", "demarcate", "demarcation", "demean", "demeanor", "demeanour",
+		"demented", "dementia", "demerit", "demise", "demo", "demobilize", "democracy", "democrat", "democratic", "democratize", "demographic",
+		"demography", "demolish", "demolition", "demon", "demonic", "demonstrable", "demonstrate", "demonstration", "demonstrative",
+		"demonstrator", "demoralize", "demote", "demotion", "demur", "demure", "den", "denial", "denigrate", "denim", "denizen", "denomination",
+		"denominational", "denominator", "denote", "denouement", "denounce", "dense", "density", "dent", "dental", "dentist", "dentistry",
+		"denture", "denude", "denunciation", "deny", "deodorant", "depart", "department", "departmental", "departure", "depend", "dependable",
+		"dependant", "dependence", "dependency", "dependent", "depict", "depiction", "deplete", "depletion", "deplorable", "deplore", "deploy",
+		"deployment", "depopulate", "deport", "deportation", "deportment", "depose", "deposit", "deposition", "depositor", "depot", "deprave",
+		"depravity", "deprecate", "depreciate", "depreciation", "depredation", "depress", "depressant", "depression", "depressive",
+		"deprivation", "deprive", "depth", "deputation", "depute", "deputize", "deputy", "derail", "derailment", "derange", "derby",
+		"deregulate", "deregulation", "derelict", "dereliction", "deride", "derision", "derisive", "derisory", "derivation", "derivative",
+		"derive", "dermatitis", "dermatologist", "dermatology", "derogatory", "derrick", "descend", "descendant", "descent", "describe",
+		"description", "descriptive", "desecrate", "desecration", "desegregate", "desert", "deserter", "desertion", "deserve", "design",
+		"designate", "designation", "designer", "desirable", "desire", "desirous", "desist", "desk", "desktop", "desolate", "desolation",
+		"despair", "despatch", "desperado", "desperate", "desperation", "despicable", "despise", "despite", "despondent", "despot", "despotic",
+		"despotism", "dessert", "destabilize", "destination", "destined", "destiny", "destitute", "destitution", "destroy", "destroyer",
+		"destruction", "destructive", "desultory", "detach", "detachable", "detachment", "detail", "detain", "detainee", "detect", "detection",
+		"detective", "detector", "detention", "deter", "detergent", "deteriorate", "deterioration", "determinant", "determination", "determine",
+		"determiner", "determinism", "deterrence", "deterrent", "detest", "detestable", "dethrone", "detonate", "detonation", "detonator",
+		"detour", "detract", "detractor", "detriment", "detrimental", "deuce", "devaluation", "devalue", "devastate", "devastation", "develop",
+		"developer", "development", "deviant", "deviate", "deviation", "device", "devil", "devilish", "devious", "devise", "devoid",
+		"devolution", "devolve", "devote", "devotee", "devotion", "devour", "devout", "dew", "dexterity", "dexterous", "diabetes", "diabetic",
+		"diabolical", "diagnose", "diagnosis", "diagnostic", "diagonal", "diagram", "dial", "dialect", "dialectic", "dialog", "dialogue",
+		"dialysis", "diameter", "diametrically", "diamond", "diaper", "diaphragm", "diarrhea", "diarrhoea", "diary", "diatribe", "dice", "dicey",
+		"dichotomy", "dictate", "dictation", "dictator", "dictatorial", "dictatorship", "diction", "dictionary", "dictum", "didactic", "die",
+		"diesel", "diet", "dietary", "dietician", "differ", "difference", "different", "differential", "differentiate", "differentiation",
+		"difficult", "difficulty", "diffidence", "diffident", "diffuse", "diffusion", "dig", "digest", "digestible", "digestion", "digestive",
+		"digger", "digit", "digital", "dignitary", "dignity", "digress", "digression", "dike", "dilapidated", "dilapidation", "dilate",
+		"dilation", "dilemma", "diligence", "diligent", "dill", "dilute", "dilution", "dim", "dime", "dimension", "dimensional", "diminish",
+		"diminution", "diminutive", "dimple", "din", "dine", "diner", "dinghy", "dingy", "dinner", "dinosaur", "diocese", "dioxide", "dip",
+		"diphtheria", "diphthong", "diploma", "diplomacy", "diplomat", "diplomatic", "dire", "direct", "direction", "directive", "director",
+		"directorate", "directory", "dirge", "dirt", "dirty", "disability", "disable", "disabuse", "disadvantage", "disadvantageous",
+		"disaffect", "disaffection", "disagree", "disagreeable", "disagreement", "disallow", "disappear", "disappearance", "disappoint",
+		"disappointment", "disapproval", "disapprove", "disarm", "disarmament", "disarray", "disassociate", "disaster", "disastrous", "disavow",
+		"disband", "disbelief", "disbelieve", "disburse", "disbursement", "disc", "discard", "discern", "discernible", "discerning", "discharge",
+		"disciple", "disciplinarian", "disciplinary", "discipline", "disclaim", "disclaimer", "disclose", "disclosure", "disco", "discolor",
+		"discolour", "discomfort", "disconcert", "disconnect", "disconsolate", "discontent", "discontinue", "discontinuity", "discord",
+		"discordant", "discotheque", "discount", "discourage", "discouragement", "discourse", "discourteous", "discover", "discoverer",
+		"discovery", "discredit", "discreet", "discrepancy", "discrete", "discretion", "discretionary", "discriminate", "discrimination",
+		"discriminatory", "discursive", "discus", "discuss", "discussion", "disdain", "disdainful", "disease", "disembark", "disembodied",
+		"disenchant", "disenchantment", "disengage", "disentangle", "disfavor", "disfavour", "disfigure", "disgorge", "disgrace", "disgraceful",
+		"disgruntled", "disguise", "disgust", "dish", "disharmony", "dishearten", "dishevelled", "dishonest", "dishonesty", "dishonor",
+		"dishonour", "dishonourable", "dishwasher", "disillusion", "disillusionment", "disincentive", "disinclination", "disinclined",
+		"disinfect", "disinfectant", "disingenuous", "disinherit", "disintegrate", "disintegration", "disinterest", "disinterested",
+		"disjointed", "disk", "dislike", "dislocate", "dislocation", "dislodge", "disloyal", "disloyalty", "dismal", "dismantle", "dismay",
+		"dismember", "dismiss", "dismissal", "dismissive", "dismount", "disobedience", "disobedient", "disobey", "disorder", "disorderly",
+		"disorganize", "disorient", "disorientate", "disown", "disparage", "disparate", "disparity", "dispassionate", "dispatch", "dispel",
+		"dispensable", "dispensary", "dispensation", "dispense", "dispenser", "dispersal", "disperse", "dispersion", "dispirit", "displace",
+		"displacement", "display", "displease", "displeasure", "disposable", "disposal", "dispose", "disposition", "dispossess", "disproportion",
+		"disproportionate", "disprove", "disputable", "dispute", "disqualification", "disqualify", "disquiet", "disregard", "disrepair",
+		"disreputable", "disrepute", "disrespect", "disrespectful", "disrupt", "disruption", "disruptive", "dissatisfaction", "dissatisfy",
+		"dissect", "dissection", "disseminate", "dissemination", "dissension", "dissent", "dissenter", "dissertation", "disservice", "dissident",
+		"dissimilar", "dissipate", "dissipation", "dissociate", "dissolute", "dissolution", "dissolve", "dissonance", "dissuade", "distance",
+		"distant", "distaste", "distasteful", "distend", "distil", "distill", "distillation", "distiller", "distillery", "distinct",
+		"distinction", "distinctive", "distinguish", "distinguishable", "distort", "distortion", "distract", "distraction", "distraught",
+		"distress", "distribute", "distribution", "distributor", "district", "distrust", "distrustful", "disturb", "disturbance", "disunity",
+		"disuse", "ditch", "dither", "ditto", "ditty", "diurnal", "divan", "dive", "diver", "diverge", "divergence", "divergent", "diverse",
+		"diversify", "diversion", "diversity", "divert", "divest", "divide", "dividend", "divine", "divinity", "divisible", "division",
+		"divisive", "divorce", "divorcee", "divulge", "dizzy", "do", "docile", "dock", "docker", "docket", "dockyard", "doctor", "doctoral",
+		"doctorate", "doctrinaire", "doctrinal", "doctrine", "document", "documentary", "documentation", "dodge", "dodgy", "doe", "doer", "dog",
+		"dogged", "doggerel", "dogma", "dogmatic", "dogmatism", "dole", "doleful", "doll", "dollar", "dollop", "dolphin", "domain", "dome",
+		"domestic", "domesticate", "domesticity", "domicile", "dominance", "dominant", "dominate", "domination", "domineer", "dominion",
+		"domino", "don", "donate", "donation", "donkey", "donor", "doodle", "doom", "door", "doorbell", "doorknob", "doorman", "doormat",
+		"doorstep", "doorway", "dope", "dopey", "dormant", "dormitory", "dormouse", "dosage", "dose", "dossier", "dot", "dote", "double",
+		"doubt", "doubtful", "doubtless", "dough", "doughnut", "dour", "douse", "dove", "dovetail", "dowager", "dowdy", "down", "downcast",
+		"downfall", "downgrade", "downhearted", "downhill", "download", "downpour", "downright", "downside", "downstairs", "downstream",
+		"downtown", "downtrodden", "downturn", "downward", "downwards", "dowry", "doze", "dozen", "drab", "draft", "draftsman", "drafty", "drag",
+		"dragon", "dragonfly", "dragoon", "drain", "drainage", "drainpipe", "drake", "drama", "dramatic", "dramatics", "dramatist", "dramatize",
+		"drape", "draper", "drapery", "drastic", "draught", "draughtsman", "draughty", "draw", "drawback", "drawbridge", "drawer", "drawing",
+		"drawl", "dread", "dreadful", "dream", "dreamer", "dreamy", "dreary", "dredge", "dregs", "drench", "dress", "dresser", "dressing",
+		"dressmaker", "dribble", "drier", "drift", "drifter", "driftwood", "drill", "drink", "drinker", "drip", "drive", "drivel", "driver",
+		"driveway", "drizzle", "droll", "drone", "drool", "droop", "drop", "droplet", "dropout", "dropper", "droppings", "drought", "drove",
+		"drown", "drowse", "drowsy", "drudge", "drudgery", "drug", "druggist", "drugstore", "drum", "drummer", "drumstick", "drunk", "drunkard",
+		"drunken", "dry", "dryer", "dual", "dualism", "dub", "dubious", "duchess", "duchy", "duck", "duckling", "duct", "dud", "dude", "due",
+		"duel", "duet", "duffel", "dug", "dugout", "duke", "dull", "duly", "dumb", "dumbfound", "dummy", "dump", "dumpling", "dumpy", "dun",
+		"dunce", "dune", "dung", "dungarees", "dungeon", "dunk", "duo", "dupe", "duplex", "duplicate", "duplication", "duplicity", "durability",
+		"durable", "duration", "duress", "during", "dusk", "dusky", "dust", "dustbin", "duster", "dustman", "dustpan", "dusty", "dutiful",
+		"duty", "duvet", "dwarf", "dwell", "dweller", "dwelling", "dwindle", "dye", "dyke", "dynamic", "dynamics", "dynamism", "dynamite",
+		"dynamo", "dynastic", "dynasty", "dysentery", "dyslexia", "dyslexic", "each", "eager", "eagle", "ear", "earache", "eardrum", "earl",
+		"earldom", "early", "earmark", "earn", "earnest", "earnings", "earphone", "earring", "earshot", "earth", "earthen", "earthenware",
+		"earthly", "earthquake", "earthworm", "earthy", "earwig", "ease", "easel", "east", "easterly", "eastern", "eastward", "easy",
+		"easygoing", "eat", "eater", "eatery", "eaves", "eavesdrop", "ebb", "ebony", "ebullient", "eccentric", "eccentricity", "ecclesiastic",
+		"ecclesiastical", "echelon", "echo", "eclectic", "eclipse", "ecological", "ecologist", "ecology", "economic", "economical", "economics",
+		"economist", "economize", "economy", "ecosystem", "ecstasy", "ecstatic", "ecumenical", "eczema", "eddy", "edge", "edgeways", "edgy",
+		"edible", "edict", "edifice", "edify", "edit", "edition", "editor", "editorial", "educate", "education", "educational", "educationalist",
+		"educator", "eel", "eerie", "efface", "effect", "effective", "effectual", "effeminate", "effervescent", "efficacious", "efficacy",
+		"efficiency", "efficient", "effigy", "effluent", "effort", "effortless", "effrontery", "effusive", "egalitarian", "egg", "eggplant",
+		"eggshell", "ego", "egocentric", "egoism", "egoist", "egotism", "egotist", "egregious", "egress", "eh", "eiderdown", "eight", "eighteen",
+		"eighth", "eighty", "either", "ejaculate", "ejaculation", "eject", "ejection", "eke", "elaborate", "elaboration", "elapse", "elastic",
+		"elasticity", "elate", "elation", "elbow", "elder", "elderly", "eldest", "elect", "election", "electoral", "electorate", "electric",
+		"electrical", "electrician", "electricity", "electrify", "electrocute", "electrode", "electrolysis", "electrolyte", "electromagnet",
+		"electromagnetic", "electron", "electronic", "electronics", "elegance", "elegant", "elegy", "element", "elemental", "elementary",
+		"elephant", "elephantine", "elevate", "elevation", "elevator", "eleven", "eleventh", "elf", "elicit", "eligibility", "eligible",
+		"eliminate", "elimination", "elite", "elitism", "elitist", "elixir", "elk", "ellipse", "ellipsis", "ellipsoid", "elliptical", "elm",
+		"elocution", "elongate", "elope", "eloquence", "eloquent", "else", "elsewhere", "elucidate", "elude", "elusive", "emaciate",
+		"emaciation", "email", "emanate", "emancipate", "emancipation", "emasculate", "embalm", "embankment", "embargo", "embark", "embarkation",
+		"embarrass", "embarrassment", "embassy", "embattled", "embed", "embellish", "embellishment", "ember", "embezzle", "embezzlement",
+		"embitter", "emblazon", "emblem", "emblematic", "embodiment", "embody", "emboss", "embrace", "embroider", "embroidery", "embroil",
+		"embryo", "embryonic", "emerald", "emerge", "emergence", "emergency", "emergent", "emeritus", "emigrant", "emigrate", "emigration",
+		"eminence", "eminent", "emir", "emirate", "emissary", "emission", "emit", "emotion", "emotional", "emotive", "empathize", "empathy",
+		"emperor", "emphasis", "emphasize", "emphatic", "empire", "empirical", "empiricism", "employ", "employee", "employer", "employment",
+		"emporium", "empower", "empress", "empty", "emu", "emulate", "emulation", "emulsion", "enable", "enact", "enactment", "enamel", "enamor",
+		"enamour", "encamp", "encampment", "encapsulate", "encase", "enchant", "enchantment", "encircle", "enclave", "enclose", "enclosure",
+		"encode", "encompass", "encore", "encounter", "encourage", "encouragement", "encroach", "encroachment", "encrust", "encrypt",
+		"encryption", "encumber", "encumbrance", "encyclopaedia", "encyclopedia", "encyclopedic", "end", "endanger", "endear", "endearment",
+		"endeavor", "endeavour", "endemic", "ending", "endless", "endorse", "endorsement", "endow", "endowment", "endurance", "endure", "enema",
+		"enemy", "energetic", "energize", "energy", "enervate", "enfeeble", "enforce", "enforcement", "enfranchise", "enfranchisement", "engage",
+		"engagement", "engender", "engine", "engineer", "engineering", "engrave", "engraving", "engross", "engulf", "enhance", "enhancement",
+		"enigma", "enigmatic", "enjoin", "enjoy", "enjoyable", "enjoyment", "enlarge", "enlargement", "enlighten", "enlightenment", "enlist",
+		"enlistment", "enliven", "enmity", "ennoble", "enormity", "enormous", "enough", "enquire", "enquiry", "enrage", "enrapture", "enrich",
+		"enrichment", "enrol", "enroll", "enrollment", "enrolment", "ensconce", "ensemble", "enshrine", "ensign", "enslave", "ensnare", "ensue",
+		"ensure", "entail", "entangle", "entanglement", "enter", "enterprise", "entertain", "entertainer", "entertainment", "enthral",
+		"enthrall", "enthrone", "enthuse", "enthusiasm", "enthusiast", "enthusiastic", "entice", "enticement", "entire", "entirety", "entitle",
+		"entitlement", "entity", "entomb", "entomology", "entourage", "entrails", "entrance", "entrant", "entrap", "entreat", "entreaty",
+		"entree", "entrench", "entrenchment", "entrepreneur", "entrepreneurial", "entrust", "entry", "entwine", "enumerate", "enumeration",
+		"enunciate", "envelop", "envelope", "enviable", "envious", "environment", "environmental", "environmentalist", "envisage", "envision",
+		"envoy", "envy", "enzyme", "ephemeral", "epic", "epicenter", "epicentre", "epidemic", "epidemiology", "epidermis", "epigram", "epilepsy",
+		"epileptic", "epilogue", "episcopal", "episode", "episodic", "epistle", "epitaph", "epithet", "epitome", "epitomize", "epoch", "equable",
+		"equal", "equality", "equalize", "equanimity", "equate", "equation", "equator", "equatorial", "equestrian", "equidistant", "equilateral",
+		"equilibrium", "equine", "equinox", "equip", "equipment", "equitable", "equity", "equivalence", "equivalent", "equivocal", "era",
+		"eradicate", "eradication", "erase", "eraser", "erasure", "erect", "erection", "ergonomic", "ergonomics", "ermine", "erode", "erogenous",
+		"erosion", "erotic", "eroticism", "err", "errand", "errant", "erratic", "erroneous", "error", "erstwhile", "erudite", "erudition",
+		"erupt", "eruption", "escalate", "escalation", "escalator", "escapade", "escape", "escapism", "escapist", "escarpment", "eschew",
+		"escort", "esoteric", "especial", "especially", "espionage", "espouse", "espresso", "essay", "essayist", "essence", "essential",
+		"establish", "establishment", "estate", "esteem", "estimable", "estimate", "estimation", "estrange", "estrangement", "estuary",
+		"etcetera", "etch", "etching", "eternal", "eternity", "ether", "ethereal", "ethic", "ethical", "ethics", "ethnic", "ethnicity", "ethos",
+		"etiquette", "etymology", "eucalyptus", "euphemism", "euphemistic", "euphoria", "euphoric", "euthanasia", "evacuate", "evacuation",
+		"evacuee", "evade", "evaluate", "evaluation", "evangelical", "evangelism", "evangelist", "evaporate", "evaporation", "evasion",
+		"evasive", "eve", "even", "evening", "evenly", "event", "eventful", "eventual", "eventuality", "ever", "evergreen", "everlasting",
+		"evermore", "every", "everybody", "everyday", "everyone", "everyplace", "everything", "everywhere", "evict", "eviction", "evidence",
+		"evident", "evil", "evince", "evocation", "evocative", "evoke", "evolution", "evolutionary", "evolve", "ewe", "exacerbate", "exact",
+		"exacting", "exaggerate", "exaggeration", "exalt", "exaltation", "exam", "examination", "examine", "examiner", "example", "exasperate",
+		"exasperation", "excavate", "excavation", "excavator", "exceed", "exceedingly", "excel", "excellence", "excellency", "excellent",
+		"except", "exception", "exceptional", "excerpt", "excess", "excessive", "exchange", "exchequer", "excise", "excitable", "excite",
+		"excitement", "exclaim", "exclamation", "exclude", "exclusion", "exclusive", "excommunicate", "excommunication", "excrement", "excrete",
+		"excretion", "excruciating", "excursion", "excusable", "excuse", "execute", "execution", "executioner", "executive", "executor",
+		"exemplary", "exemplify", "exempt", "exemption", "exercise", "exert", "exertion", "exhale", "exhaust", "exhaustion", "exhaustive",
+		"exhibit", "exhibition", "exhibitionism", "exhibitionist", "exhibitor", "exhilarate", "exhilaration", "exhort", "exhortation", "exhume",
+		"exigency", "exile", "exist", "existence", "existent", "existential", "existentialism", "exit", "exodus", "exonerate", "exorbitant",
+		"exorcism", "exorcist", "exorcize", "exotic", "expand", "expanse", "expansion", "expansionism", "expansive", "expatriate", "expect",
+		"expectancy", "expectant", "expectation", "expedience", "expediency", "expedient", "expedite", "expedition", "expeditionary", "expel",
+		"expend", "expendable", "expenditure", "expense", "expensive", "experience", "experiment", "experimental", "experimentation", "expert",
+		"expertise", "expiate", "expiration", "expire", "expiry", "explain", "explanation", "explanatory", "expletive", "explicable", "explicit",
+		"explode", "exploit", "exploitation", "exploration", "exploratory", "explore", "explorer", "explosion", "explosive", "exponent",
+		"exponential", "export", "exporter", "expose", "exposition", "exposure", "expound", "express", "expression", "expressionism",
+		"expressive", "expressway", "expropriate", "expulsion", "expunge", "exquisite", "extant", "extemporize", "extend", "extension",
+		"extensive", "extent", "extenuate", "exterior", "exterminate", "extermination", "external", "externalize", "extinct", "extinction",
+		"extinguish", "extinguisher", "extol", "extort", "extortion", "extortionate", "extra", "extract", "extraction", "extractor",
+		"extracurricular", "extradite", "extradition", "extramarital", "extraneous", "extraordinary", "extrapolate", "extrapolation",
+		"extraterrestrial", "extravagance", "extravagant", "extravaganza", "extreme", "extremism", "extremist", "extremity", "extricate",
+		"extrovert", "exuberance", "exuberant", "exude", "exult", "exultant", "exultation", "eye", "eyeball", "eyebrow", "eyeful", "eyelash",
+		"eyelet", "eyelid", "eyepiece", "eyesight", "eyesore", "eyewitness", "fable", "fabric", "fabricate", "fabrication", "fabulous", "facade",
+		"face", "faceless", "facet", "facetious", "facial", "facile", "facilitate", "facilitator", "facility", "facsimile", "fact", "faction",
+		"factional", "factor", "factorial", "factory", "factual", "faculty", "fad", "fade", "faeces", "fag", "fail", "failure", "faint", "fair",
+		"fairground", "fairway", "fairy", "fairyland", "faith", "faithful", "faithless", "fake", "falcon", "fall", "fallacious", "fallacy",
+		"fallible", "fallout", "fallow", "false", "falsehood", "falsetto", "falsify", "falsity", "falter", "fame", "familiar", "familiarity",
+		"familiarize", "family", "famine", "famish", "famous", "fan", "fanatic", "fanatical", "fanaticism", "fancier", "fanciful", "fancy",
+		"fanfare", "fang", "fantasize", "fantastic", "fantasy", "far", "faraway", "farce", "farcical", "fare", "farewell", "farm", "farmer",
+		"farmhouse", "farmland", "farmyard", "farther", "farthest", "fascinate", "fascination", "fascism", "fascist", "fashion", "fashionable",
+		"fast", "fasten", "fastener", "fastidious", "fat", "fatal", "fatalism", "fatalistic", "fatality", "fate", "fateful", "father",
+		"fatherhood", "fatherland", "fatherly", "fathom", "fatigue", "fatten", "fatty", "fatuous", "faucet", "fault", "faultless", "faulty",
+		"fauna", "favor", "favorable", "favorite", "favoritism", "favour", "favourable", "favourite", "favouritism", "fawn", "fax", "fear",
+		"fearful", "fearless", "fearsome", "feasibility", "feasible", "feast", "feat", "feather", "feathery", "feature", "february", "fecund",
+		"federal", "federalism", "federalist", "federate", "federation", "fee", "feeble", "feed", "feedback", "feeder", "feel", "feeler",
+		"feeling", "feign", "feint", "feisty", "felicitous", "felicity", "feline", "fell", "fellow", "fellowship", "felon", "felony", "felt",
+		"female", "feminine", "femininity", "feminism", "feminist", "femur", "fen", "fence", "fencing", "fend", "fender", "fennel", "ferment",
+		"fermentation", "fern", "ferocious", "ferocity", "ferret", "ferromagnet", "ferry", "fertile", "fertility", "fertilization", "fertilize",
+		"fertilizer", "fervent", "fervor", "fervour", "fester", "festival", "festive", "festivity", "festoon", "fetal", "fetch", "fete", "fetid",
+		"fetish", "fetter", "fetus", "feud", "feudal", "feudalism", "fever", "feverish", "few", "fiance", "fiancee", "fiasco", "fiat", "fib",
+		"fiber", "fiberglass", "fibre", "fibreglass", "fibrous", "fickle", "fiction", "fictional", "fictitious", "fiddle", "fiddler", "fidelity",
+		"fidget", "fidgety", "field", "fieldwork", "fiend", "fiendish", "fierce", "fiery", "fiesta", "fife", "fifteen", "fifth", "fifty", "fig",
+		"fight", "fighter", "figment", "figurative", "figure", "figurehead", "filament", "filch", "file", "filial", "filibuster", "filigree",
+		"fill", "fillet", "filling", "filly", "film", "filter", "filth", "filthy", "filtrate", "filtration", "fin", "final", "finale",
+		"finalist", "finality", "finalize", "finance", "financial", "financier", "finch", "find", "finder", "finding", "fine", "finery",
+		"finesse", "finger", "fingernail", "fingerprint", "fingertip", "finicky", "finish", "finite", "fir", "fire", "firearm", "firebrand",
+		"firefighter", "firefly", "fireman", "fireplace", "fireproof", "fireside", "firewood", "firework", "firm", "firmament", "first",
+		"firsthand", "fiscal", "fish", "fisherman", "fishery", "fishmonger", "fishy", "fission", "fissure", "fist", "fit", "fitful", "fitness",
+		"fitter", "fitting", "five", "fix", "fixation", "fixative", "fixture", "fizz", "fizzle", "fizzy", "flabbergast", "flabby", "flaccid",
+		"flag", "flagrant", "flagship", "flagstone", "flair", "flak", "flake", "flaky", "flamboyance", "flamboyant", "flame", "flamingo",
+		"flammable", "flan", "flank", "flannel", "flap", "flare", "flash", "flashback", "flashlight", "flashy", "flask", "flat", "flatten",
+		"flatter", "flattery", "flatulence", "flaunt", "flautist", "flavor", "flavour", "flaw", "flawless", "flax", "flay", "flea", "fleck",
+		"fledge", "fledgling", "flee", "fleece", "fleet", "fleeting", "flesh", "fleshy", "flex", "flexibility", "flexible", "flick", "flicker",
+		"flier", "flight", "flimsy", "flinch", "fling", "flint", "flip", "flippant", "flipper", "flirt", "flirtation", "flirtatious", "flit",
+		"float", "flock", "flog", "flood", "floodlight", "floor", "floorboard", "flop", "floppy", "flora", "floral", "florid", "florist",
+		"floss", "flotation", "flotilla", "flounce", "flounder", "flour", "flourish", "flout", "flow", "flower", "flowery", "flu", "fluctuate",
+		"fluctuation", "flue", "fluency", "fluent", "fluff", "fluffy", "fluid", "fluke", "fluorescence", "fluorescent", "fluoride", "flurry",
+		"flush", "fluster", "flute", "flutter", "flux", "fly", "flyer", "flyover", "foal", "foam", "fob", "focal", "focus", "fodder", "foe",
+		"foetal", "foetus", "fog", "foggy", "foible", "foil", "foist", "fold", "folder", "foliage", "folk", "folklore", "follicle", "follow",
+		"follower", "folly", "foment", "fond", "fondle", "fondness", "font", "food", "foodstuff", "fool", "foolhardy", "foolish", "foolproof",
+		"foot", "footage", "football", "footballer", "foothill", "foothold", "footing", "footlights", "footnote", "footpath", "footprint",
+		"footstep", "footwear", "for", "forage", "foray", "forbear", "forbearance", "forbid", "forbidding", "force", "forceful", "forceps",
+		"forcible", "ford", "fore", "forearm", "forebear", "foreboding", "forecast", "forecaster", "forecourt", "forefather", "forefinger",
+		"forefront", "forego", "foregone", "foreground", "forehand", "forehead", "foreign", "foreigner", "foreman", "foremost", "forensic",
+		"forerunner", "foresee", "foreseeable", "foreshadow", "foresight", "foreskin", "forest", "forestall", "forestry", "foretaste",
+		"foretell", "forethought", "forever", "forewarn", "foreword", "forfeit", "forge", "forgery", "forget", "forgetful", "forgive",
+		"forgiveness", "forgo", "fork", "forlorn", "form", "formal", "formality", "formalize", "format", "formation", "formative", "former",
+		"formerly", "formidable", "formula", "formulaic", "formulate", "formulation", "fornicate", "fornication", "forsake", "fort", "forte",
+		"forth", "forthcoming", "forthright", "forthwith", "fortieth", "fortification", "fortify", "fortitude", "fortnight", "fortnightly",
+		"fortress", "fortuitous", "fortunate", "fortune", "forty", "forum", "forward", "forwards", "fossil", "fossilize", "foster", "foul",
+		"found", "foundation", "founder", "foundry", "fount", "fountain", "four", "fourteen", "fourth", "fowl", "fox", "foyer", "fraction",
+		"fractional", "fractious", "fracture", "fragile", "fragility", "fragment", "fragmentary", "fragmentation", "fragrance", "fragrant",
+		"frail", "frailty", "frame", "framework", "franchise", "frank", "frantic", "fraternal", "fraternity", "fraternize", "fraud",
+		"fraudulent", "fraught", "fray", "freak", "freakish", "freckle", "free", "freedom", "freehold", "freelance", "freeway", "freeze",
+		"freezer", "freight", "freighter", "frenetic", "frenzy", "frequency", "frequent", "fresco", "fresh", "freshen", "freshman", "freshwater",
+		"fret", "fretful", "friar", "friction", "friday", "fridge", "friend", "friendly", "friendship", "frieze", "frigate", "fright",
+		"frighten", "frightful", "frigid", "frill", "fringe", "frisk", "frisky", "fritter", "frivolity", "frivolous", "frizzy", "frock", "frog",
+		"frolic", "from", "frond", "front", "frontage", "frontal", "frontier", "frost", "frostbite", "frosty", "froth", "frothy", "frown",
+		"frugal", "fruit", "fruitful", "fruition", "fruitless", "fruity", "frustrate", "frustration", "fry", "fuddle", "fudge", "fuel",
+		"fugitive", "fulcrum", "fulfil", "fulfill", "fulfillment", "fulfilment", "full", "fulsome", "fumble", "fume", "fumigate", "fun",
+		"function", "functional", "functionary", "fund", "fundamental", "fundamentalism", "fundamentalist", "funeral", "funereal", "fungal",
+		"fungus", "funk", "funky", "funnel", "funny", "fur", "furious", "furl", "furlong", "furnace", "furnish", "furniture", "furor", "furore",
+		"furrow", "furry", "further", "furthermore", "furthest", "furtive", "fury", "fuse", "fuselage", "fusion", "fuss", "fussy", "futile",
+		"futility", "future", "futuristic", "fuzz", "fuzzy", "gab", "gabble", "gable", "gadget", "gaffe", "gag", "gaga", "gage", "gaggle",
+		"gaiety", "gain", "gainful", "gainsay", "gait", "gala", "galactic", "galaxy", "gale", "gall", "gallant", "gallantry", "galleon",
+		"gallery", "galley", "gallon", "gallop", "gallows", "gallstone", "galore", "galvanize", "gambit", "gamble", "gambler", "game",
+		"gamekeeper", "gamut", "gander", "gang", "gangland", "gangly", "gangplank", "gangrene", "gangster", "gangway", "gaol", "gap", "gape",
+		"garage", "garb", "garbage", "garble", "garden", "gardener", "gargantuan", "gargle", "gargoyle", "garish", "garland", "garlic",
+		"garment", "garner", "garnet", "garnish", "garret", "garrison", "garrulous", "garter", "gas", "gaseous", "gash", "gasket", "gasoline",
+		"gasp", "gastric", "gastronomic", "gastronomy", "gate", "gatecrash", "gateway", "gather", "gathering", "gauche", "gaudy", "gauge",
+		"gaunt", "gauntlet", "gauze", "gavel", "gawky", "gay", "gaze", "gazelle", "gazette", "gear", "gearbox", "gel", "gelatin", "gelatine",
+		"gem", "gender", "gene", "genealogical", "genealogy", "general", "generalization", "generalize", "generate", "generation", "generator",
+		"generic", "generous", "genesis", "genetic", "genetics", "genial", "genie", "genital", "genitals", "genius", "genocide", "genre", "gent",
+		"genteel", "gentile", "gentility", "gentle", "gentleman", "gentry", "genuine", "genus", "geographer", "geographic", "geographical",
+		"geography", "geological", "geologist", "geology", "geometric", "geometrical", "geometry", "geriatric", "germ", "germane", "germinate",
+		"germination", "gerund", "gestation", "gesticulate", "gesture", "get", "getaway", "geyser", "ghastly", "gherkin", "ghetto", "ghost",
+		"ghostly", "ghoul", "ghoulish", "giant", "gibber", "gibberish", "gibe", "giddy", "gift", "gig", "gigantic", "giggle", "gild", "gill",
+		"gilt", "gimmick", "gin", "ginger", "gingerbread", "gingerly", "giraffe", "gird", "girder", "girdle", "girl", "girlfriend", "girlhood",
+		"girlish", "girth", "gist", "give", "given", "glacial", "glacier", "glad", "gladden", "glade", "gladiator", "glamor", "glamorous",
+		"glamour", "glance", "gland", "glandular", "glare", "glass", "glasses", "glassy", "glaze", "glazier", "gleam", "glean", "glee",
+		"gleeful", "glen", "glib", "glide", "glider", "glimmer", "glimpse", "glint", "glisten", "glitter", "gloat", "global", "globe",
+		"globular", "gloom", "gloomy", "glorify", "glorious", "glory", "gloss", "glossary", "glossy", "glove", "glow", "glower", "glucose",
+		"glue", "glum", "glut", "glutton", "gluttony", "glycerine", "gnarled", "gnash", "gnat", "gnaw", "gnome", "gnu", "go", "goad", "goal",
+		"goalkeeper", "goalpost", "goat", "gobble", "goblet", "goblin", "god", "goddess", "godfather", "godless", "godly", "godmother",
+		"godparent", "godsend", "goggle", "goggles", "gold", "golden", "goldfish", "golf", "golfer", "gondola", "gong", "good", "goodbye",
+		"goodness", "goodwill", "goody", "goose", "gooseberry", "gore", "gorge", "gorgeous", "gorilla", "gory", "gosh", "gospel", "gossamer",
+		"gossip", "gouge", "goulash", "gourd", "gourmet", "gout", "govern", "governess", "government", "governmental", "governor", "gown",
+		"grab", "grace", "graceful", "graceless", "gracious", "gradation", "grade", "gradient", "gradual", "graduate", "graduation", "graffiti",
+		"graft", "grain", "gram", "grammar", "grammatical", "gramme", "gramophone", "granary", "grand", "grandchild", "granddaughter",
+		"grandeur", "grandfather", "grandiose", "grandmother", "grandparent", "grandson", "grandstand", "granite", "granny", "grant", "granular",
+		"granule", "grape", "grapefruit", "grapevine", "graph", "graphic", "graphics", "graphite", "grapple", "grasp", "grass", "grasshopper",
+		"grassland", "grassy", "grate", "grateful", "grater", "gratification", "gratify", "grating", "gratitude", "gratuitous", "gratuity",
+		"grave", "gravel", "gravestone", "graveyard", "gravitate", "gravitation", "gravitational", "gravity", "gravy", "gray", "graze", "grease",
+		"greasy", "great", "greed", "greedy", "green", "greenery", "greengrocer", "greenhouse", "greet", "greeting", "gregarious", "grenade",
+		"grey", "greyhound", "grid", "griddle", "gridiron", "gridlock", "grief", "grievance", "grieve", "grievous", "grill", "grille", "grim",
+		"grimace", "grime", "grimy", "grin", "grind", "grinder", "grip", "gripe", "grisly", "grit", "gritty", "grizzly", "groan", "grocer",
+		"grocery", "groggy", "groin", "groom", "groove", "grope", "gross", "grotesque", "grotto", "grouch", "ground", "groundless", "groundwork",
+		"group", "grouse", "grove", "grovel", "grow", "grower", "growl", "grown", "grownup", "growth", "grub", "grubby", "grudge", "gruel",
+		"grueling", "gruelling", "gruesome", "gruff", "grumble", "grumpy", "grunt", "guarantee", "guarantor", "guard", "guardian",
+		"guardianship", "guerrilla", "guess", "guesswork", "guest", "guffaw", "guidance", "guide", "guidebook", "guideline", "guild", "guile",
+		"guillotine", "guilt", "guilty", "guinea", "guise", "guitar", "guitarist", "gulf", "gull", "gullet", "gullible", "gully", "gulp", "gum",
+		"gumption", "gun", "gunboat", "gunfire", "gunman", "gunner", "gunpoint", "gunpowder", "gunshot", "gurgle", "guru", "gush", "gust",
+		"gusto", "gut", "gutter", "guttural", "guy", "guzzle", "gym", "gymnasium", "gymnast", "gymnastic", "gymnastics", "gynaecologist",
+		"gynaecology", "gynecologist", "gynecology", "gypsy", "gyrate", "gyroscope", "ha", "habit", "habitable", "habitat", "habitation",
+		"habitual", "hack", "hacker", "hackles", "hackney", "hacksaw", "haddock", "haemoglobin", "haemophilia", "haemorrhage", "haemorrhoids",
+		"hag", "haggard", "haggle", "hail", "hailstone", "hair", "haircut", "hairdo", "hairdresser", "hairline", "hairpin", "hairstyle", "hairy",
+		"hale", "half", "halfway", "halibut", "hall", "hallmark", "hallowed", "hallucinate", "hallucination", "hallway", "halo", "halt",
+		"halter", "halve", "ham", "hamburger", "hamlet", "hammer", "hammock", "hamper", "hamster", "hamstring", "hand", "handbag", "handbook",
+		"handbrake", "handcuff", "handful", "handgun", "handicap", "handicraft", "handiwork", "handkerchief", "handle", "handlebar", "handler",
+		"handmade", "handout", "handrail", "handset", "handshake", "handsome", "handwriting", "handy", "hang", "hangar", "hanger", "hangover",
+		"hanker", "haphazard", "hapless", "happen", "happening", "happy", "harangue", "harass", "harassment", "harbinger", "harbor", "harbour",
+		"hard", "hardback", "harden", "hardline", "hardly", "hardship", "hardware", "hardwood", "hardy", "hare", "harem", "hark", "harlot",
+		"harm", "harmful", "harmless", "harmonic", "harmonica", "harmonious", "harmonium", "harmonize", "harmony", "harness", "harp", "harpoon",
+		"harpsichord", "harrow", "harrowing", "harsh", "harvest", "harvester", "hash", "hashish", "hassle", "haste", "hasten", "hasty", "hat",
+		"hatch", "hatchback", "hatchet", "hate", "hateful", "hatred", "haughty", "haul", "haulage", "haunch", "haunt", "have", "haven",
+		"haversack", "havoc", "hawk", "hawker", "hay", "haystack", "haywire", "hazard", "hazardous", "haze", "hazel", "hazy", "he", "head",
+		"headache", "headband", "headdress", "header", "headgear", "heading", "headland", "headlight", "headline", "headlong", "headmaster",
+		"headmistress", "headphones", "headquarters", "headroom", "headstone", "headstrong", "headway", "heady", "heal", "healer", "health",
+		"healthy", "heap", "hear", "hearing", "hearsay", "hearse", "heart", "heartache", "heartbeat", "heartbreak", "heartbroken", "heartburn",
+		"hearten", "heartfelt", "hearth", "heartless", "hearty", "heat", "heater", "heath", "heathen", "heather", "heatwave", "heave", "heaven",
+		"heavenly", "heavy", "heavyweight", "heckle", "hectare", "hectic", "hedge", "hedgehog", "hedgerow", "hedonism", "hedonist", "heed",
+		"heedless", "heel", "hefty", "hegemony", "heifer", "height", "heighten", "heinous", "heir", "heiress", "heirloom", "helicopter",
+		"helium", "hell", "hellish", "hello", "helm", "helmet", "helmsman", "help", "helper", "helpful", "helpless", "hem", "hemisphere",
+		"hemline", "hemlock", "hemoglobin", "hemophilia", "hemorrhage", "hemp", "hen", "hence", "henceforth", "henchman", "hepatitis",
+		"heptagon", "her", "herald", "heraldic", "heraldry", "herb", "herbaceous", "herbal", "herbalist", "herbivore", "herd", "herdsman",
+		"here", "hereabouts", "hereafter", "hereby", "hereditary", "heredity", "herein", "heresy", "heretic", "heretical", "herewith",
+		"heritage", "hermaphrodite", "hermetic", "hermit", "hernia", "hero", "heroic", "heroics", "heroin", "heroine", "heroism", "heron",
+		"herpes", "herring", "herringbone", "hesitancy", "hesitant", "hesitate", "hesitation", "heterogeneous", "heterosexual", "heuristic",
+		"hew", "hexagon", "hexagonal", "heyday", "hi", "hiatus", "hibernate", "hibernation", "hiccup", "hide", "hideous", "hideout",
+		"hierarchical", "hierarchy", "hieroglyphics", "high", "highbrow", "highland", "highlight", "highway", "hijack", "hijacker", "hike",
+		"hiker", "hilarious", "hilarity", "hill", "hillock", "hillside", "hilly", "hilt", "hind", "hinder", "hindrance", "hindsight", "hinge",
+		"hint", "hinterland", "hip", "hippie", "hippo", "hippopotamus", "hire", "hireling", "hiss", "historian", "historic", "historical",
+		"history", "histrionic", "hit", "hitch", "hitchhike", "hither", "hitherto", "hive", "hoard", "hoarding", "hoarse", "hoax", "hob",
+		"hobble", "hobby", "hobgoblin", "hock", "hockey", "hoe", "hog", "hoist", "hold", "holdall", "holder", "holding", "holdup", "hole",
+		"holiday", "holiness", "hollow", "holly", "holocaust", "hologram", "holster", "holy", "homage", "home", "homecoming", "homeland",
+		"homeless", "homely", "homemade", "homeopathy", "homesick", "homestead", "homeward", "homework", "homicidal", "homicide", "homily",
+		"homogeneous", "homogenize", "homonym", "homosexual", "homosexuality", "hone", "honest", "honesty", "honey", "honeycomb", "honeymoon",
+		"honk", "honor", "honorable", "honorary", "honour", "honourable", "hood", "hoodwink", "hoof", "hook", "hooligan", "hoop", "hoot",
+		"hooter", "hop", "hope", "hopeful", "hopeless", "hopper", "horde", "horizon", "horizontal", "hormonal", "hormone", "horn", "hornet",
+		"horoscope", "horrendous", "horrible", "horrid", "horrific", "horrify", "horror", "horse", "horseback", "horseman", "horsepower",
+		"horseshoe", "horticultural", "horticulture", "hose", "hosiery", "hospice", "hospitable", "hospital", "hospitality", "hospitalize",
+		"host", "hostage", "hostel", "hostess", "hostile", "hostility", "hot", "hotbed", "hotel", "hotelier", "hothead", "hothouse", "hound",
+		"hour", "hourly", "house", "houseboat", "housebound", "household", "householder", "housekeeper", "housekeeping", "housewife",
+		"housework", "housing", "hovel", "hover", "hovercraft", "how", "however", "howl", "hub", "hubbub", "huddle", "hue", "huff", "hug",
+		"huge", "hulk", "hull", "hullabaloo", "hum", "human", "humane", "humanism", "humanist", "humanitarian", "humanity", "humanize", "humble",
+		"humbug", "humdrum", "humid", "humidity", "humiliate", "humiliation", "humility", "hummingbird", "humor", "humorist", "humorous",
+		"humour", "hump", "hunch", "hunchback", "hundred", "hundredth", "hunger", "hungry", "hunk", "hunt", "hunter", "hurdle", "hurl", "hurrah",
+		"hurray", "hurricane", "hurry", "hurt", "hurtful", "hurtle", "husband", "husbandry", "hush", "husk", "husky", "hustle", "hut", "hutch",
+		"hyacinth", "hybrid", "hydrant", "hydraulic", "hydraulics", "hydrocarbon", "hydroelectric", "hydrofoil", "hydrogen", "hydromagnet",
+		"hyena", "hygiene", "hygienic", "hymn", "hype", "hyperactive", "hyperbole", "hypermarket", "hypertension", "hyphen", "hyphenate",
+		"hypnosis", "hypnotic", "hypnotism", "hypnotist", "hypnotize", "hypochondria", "hypochondriac", "hypocrisy", "hypocrite", "hypocritical",
+		"hypodermic", "hypothermia", "hypothesis", "hypothesize", "hypothetical", "hysteria", "hysterical", "hysterics", "ice", "iceberg",
+		"icebox", "icicle", "icing", "icon", "iconoclast", "icy", "idea", "ideal", "idealism", "idealist", "idealistic", "idealize", "identical",
+		"identification", "identify", "identity", "ideological", "ideology", "idiocy", "idiom", "idiomatic", "idiosyncrasy", "idiosyncratic",
+		"idiot", "idiotic", "idle", "idol", "idolatry", "idolize", "idyll", "idyllic", "if", "igloo", "ignite", "ignition", "ignoble",
+		"ignominious", "ignominy", "ignoramus", "ignorance", "ignorant", "ignore", "ill", "illegal", "illegality", "illegible", "illegitimacy",
+		"illegitimate", "illicit", "illiteracy", "illiterate", "illness", "illogical", "illuminate", "illumination", "illusion", "illusory",
+		"illustrate", "illustration", "illustrative", "illustrator", "illustrious", "image", "imagery", "imaginable", "imaginary", "imagination",
+		"imaginative", "imagine", "imbalance", "imbecile", "imbibe", "imbue", "imitate", "imitation", "imitative", "imitator", "immaculate",
+		"immaterial", "immature", "immaturity", "immeasurable", "immediacy", "immediate", "immemorial", "immense", "immensity", "immerse",
+		"immersion", "immigrant", "immigrate", "immigration", "imminent", "immobile", "immobilize", "immoderate", "immodest", "immoral",
+		"immorality", "immortal", "immortality", "immortalize", "immovable", "immune", "immunity", "immunize", "immutable", "imp", "impact",
+		"impair", "impairment", "impale", "impart", "impartial", "impartiality", "impassable", "impasse", "impassioned", "impassive",
+		"impatience", "impatient", "impeach", "impeachment", "impeccable", "impede", "impediment", "impel", "impending", "impenetrable",
+		"imperative", "imperceptible", "imperfect", "imperfection", "imperial", "imperialism", "imperialist", "imperil", "imperious",
+		"impersonal", "impersonate", "impersonation", "impertinence", "impertinent", "impervious", "impetuous", "impetus", "impinge", "impish",
+		"implacable", "implant", "implausible", "implement", "implementation", "implicate", "implication", "implicit", "implore", "imply",
+		"impolite", "import", "importance", "important", "importation", "importer", "impose", "imposition", "impossibility", "impossible",
+		"impostor", "impotence", "impotent", "impound", "impoverish", "impracticable", "impractical", "imprecise", "impregnable", "impregnate",
+		"impress", "impression", "impressionable", "impressionism", "impressionist", "impressive", "imprint", "imprison", "imprisonment",
+		"improbable", "impromptu", "improper", "impropriety", "improve", "improvement", "improvisation", "improvise", "imprudent", "impudence",
+		"impudent", "impulse", "impulsive", "impunity", "impure", "impurity", "impute", "in", "inability", "inaccessible", "inaccuracy",
+		"inaccurate", "inaction", "inactive", "inactivity", "inadequacy", "inadequate", "inadmissible", "inadvertent", "inadvisable",
+		"inalienable", "inane", "inanimate", "inapplicable", "inappropriate", "inarticulate", "inasmuch", "inattention", "inattentive",
+		"inaudible", "inaugural", "inaugurate", "inauguration", "inauspicious", "inborn", "inbred", "incalculable", "incandescent",
+		"incantation", "incapable", "incapacitate", "incapacity", "incarcerate", "incarceration", "incarnate", "incarnation", "incendiary",
+		"incense", "incentive", "inception", "incessant", "incest", "incestuous", "inch", "incidence", "incident", "incidental", "incinerate",
+		"incinerator", "incipient", "incision", "incisive", "incisor", "incite", "incitement", "inclement", "inclination", "incline", "include",
+		"inclusion", "inclusive", "incognito", "incoherence", "incoherent", "income", "incoming", "incomparable", "incompatibility",
+		"incompatible", "incompetence", "incompetent", "incomplete", "incomprehensible", "inconceivable", "inconclusive", "incongruity",
+		"incongruous", "inconsequential", "inconsiderable", "inconsiderate", "inconsistency", "inconsistent", "inconsolable", "inconspicuous",
+		"incontinence", "incontinent", "incontrovertible", "inconvenience", "inconvenient", "incorporate", "incorporation", "incorrect",
+		"incorrigible", "incorruptible", "increase", "incredible", "incredulity", "incredulous", "increment", "incremental", "incriminate",
+		"incubate", "incubation", "incubator", "inculcate", "incumbent", "incur", "incurable", "incursion", "indebted", "indecency", "indecent",
+		"indecipherable", "indecision", "indecisive", "indeed", "indefatigable", "indefensible", "indefinable", "indefinite", "indelible",
+		"indelicate", "indemnify", "indemnity", "indent", "indentation", "independence", "independent", "indescribable", "indestructible",
+		"indeterminate", "index", "indicate", "indication", "indicative", "indicator", "indict", "indictment", "indifference", "indifferent",
+		"indigenous", "indigestion", "indignant", "indignation", "indignity", "indigo", "indirect", "indiscernible", "indiscreet",
+		"indiscretion", "indiscriminate", "indispensable", "indisposed", "indisputable", "indistinct", "indistinguishable", "individual",
+		"individualism", "individualist", "individuality", "indivisible", "indoctrinate", "indoctrination", "indolence", "indolent",
+		"indomitable", "indoor", "indoors", "induce", "inducement", "induct", "induction", "inductive", "indulge", "indulgence", "indulgent",
+		"industrial", "industrialism", "industrialist", "industrialize", "industrious", "industry", "inebriate", "inedible", "ineffable",
+		"ineffective", "ineffectual", "inefficiency", "inefficient", "inelegant", "ineligible", "inept", "ineptitude", "inequality",
+		"inequitable", "inequity", "inert", "inertia", "inescapable", "inessential", "inestimable", "inevitable", "inexact", "inexcusable",
+		"inexhaustible", "inexorable", "inexpensive", "inexperience", "inexplicable", "inextricable", "infallible", "infamous", "infamy",
+		"infancy", "infant", "infantile", "infantry", "infatuate", "infatuation", "infect", "infection", "infectious", "infer", "inference",
+		"inferior", "inferiority", "infernal", "inferno", "infertile", "infertility", "infest", "infestation", "infidel", "infidelity",
+		"infighting", "infiltrate", "infiltration", "infinite", "infinitesimal", "infinitive", "infinity", "infirm", "infirmary", "infirmity",
+		"inflame", "inflammable", "inflammation", "inflammatory", "inflatable", "inflate", "inflation", "inflationary", "inflect", "inflection",
+		"inflexible", "inflict", "infliction", "influence", "influential", "influenza", "influx", "inform", "informal", "informality",
+		"informant", "information", "informative", "informer", "infrared", "infrastructure", "infrequent", "infringe", "infringement",
+		"infuriate", "infuse", "infusion", "ingenious", "ingenuity", "ingenuous", "ingest", "ingot", "ingrained", "ingratiate", "ingratitude",
+		"ingredient", "inhabit", "inhabitant", "inhale", "inhaler", "inherent", "inherit", "inheritance", "inhibit", "inhibition",
+		"inhospitable", "inhuman", "inhumane", "inhumanity", "inimitable", "iniquitous", "iniquity", "initial", "initiate", "initiation",
+		"initiative", "inject", "injection", "injunction", "injure", "injurious", "injury", "injustice", "ink", "inkling", "inland", "inlay",
+		"inlet", "inmate", "inmost", "inn", "innate", "inner", "innermost", "innings", "innkeeper", "innocence", "innocent", "innocuous",
+		"innovate", "innovation", "innovative", "innovator", "innuendo", "innumerable", "inoculate", "inoculation", "inoffensive", "inoperable",
+		"inopportune", "inordinate", "inorganic", "input", "inquest", "inquire", "inquiry", "inquisition", "inquisitive", "inroad", "insane",
+		"insanitary", "insanity", "insatiable", "inscribe", "inscription", "inscrutable", "insect", "insecticide", "insecure", "insecurity",
+		"inseminate", "insemination", "insensitive", "insensitivity", "inseparable", "insert", "insertion", "inset", "inshore", "inside",
+		"insider", "insidious", "insight", "insignia", "insignificance", "insignificant", "insincere", "insincerity", "insinuate", "insinuation",
+		"insipid", "insist", "insistence", "insistent", "insofar", "insole", "insolence", "insolent", "insoluble", "insolvency", "insolvent",
+		"insomnia", "insomniac", "inspect", "inspection", "inspector", "inspectorate", "inspiration", "inspire", "instability", "install",
+		"installation", "installment", "instalment", "instance", "instant", "instantaneous", "instead", "instep", "instigate", "instigation",
+		"instigator", "instil", "instill", "instinct", "instinctive", "institute", "institution", "institutional", "instruct", "instruction",
+		"instructive", "instructor", "instrument", "instrumental", "instrumentalist", "insubordinate", "insubordination", "insubstantial",
+		"insufferable", "insufficient", "insular", "insularity", "insulate", "insulation", "insulator", "insulin", "insult", "insuperable",
+		"insurance", "insure", "insurer", "insurgency", "insurgent", "insurmountable", "insurrection", "intact", "intake", "intangible",
+		"integer", "integral", "integrate", "integration", "integrity", "intellect", "intellectual", "intelligence", "intelligent",
+		"intelligentsia", "intelligible", "intemperate", "intend", "intense", "intensify", "intensity", "intensive", "intent", "intention",
+		"intentional", "inter", "interact", "interaction", "interactive", "intercede", "intercept", "interception", "interchange",
+		"interchangeable", "intercom", "interconnect", "intercontinental", "intercourse", "interdependence", "interdependent", "interest",
+		"interface", "interfere", "interference", "interim", "interior", "interject", "interjection", "interlock", "interloper", "interlude",
+		"intermarry", "intermediary", "intermediate", "interminable", "intermission", "intermittent", "intern", "internal", "international",
+		"internationalism", "internet", "internment", "interplay", "interpolate", "interpose", "interpret", "interpretation", "interpreter",
+		"interracial", "interrogate", "interrogation", "interrogative", "interrogator", "interrupt", "interruption", "intersect", "intersection",
+		"intersperse", "interstate", "intertwine", "interval", "intervene", "intervention", "interview", "interviewee", "interviewer",
+		"interweave", "intestate", "intestinal", "intestine", "intimacy", "intimate", "intimation", "intimidate", "intimidation", "into",
+		"intolerable", "intolerance", "intolerant", "intonation", "intoxicant", "intoxicate", "intoxication", "intractable", "intransigence",
+		"intransigent", "intransitive", "intravenous", "intrepid", "intricacy", "intricate", "intrigue", "intrinsic", "introduce",
+		"introduction", "introductory", "introspection", "introspective", "introvert", "intrude", "intruder", "intrusion", "intrusive",
+		"intuition", "intuitive", "inundate", "inundation", "inure", "invade", "invader", "invalid", "invalidate", "invaluable", "invariable",
+		"invasion", "invasive", "invective", "inveigle", "invent", "invention", "inventive", "inventor", "inventory", "inverse", "inversion",
+		"invert", "invertebrate", "invest", "investigate", "investigation", "investigative", "investigator", "investiture", "investment",
+		"investor", "inveterate", "invidious", "invigilate", "invigorate", "invincible", "inviolable", "inviolate", "invisible", "invitation",
+		"invite", "invocation", "invoice", "invoke", "involuntary", "involve", "involvement", "invulnerable", "inward", "inwards", "iodine",
+		"ion", "iota", "irate", "ire", "iridescent", "iris", "irk", "iron", "ironic", "ironical", "irony", "irradiate", "irrational",
+		"irreconcilable", "irrefutable", "irregular", "irregularity", "irrelevance", "irrelevant", "irreparable", "irreplaceable",
+		"irrepressible", "irreproachable", "irresistible", "irrespective", "irresponsible", "irretrievable", "irreverent", "irreversible",
+		"irrevocable", "irrigate", "irrigation", "irritable", "irritant", "irritate", "irritation", "is", "island", "islander", "isle",
+		"isolate", "isolation", "isotope", "issue", "isthmus", "it", "italic", "italics", "itch", "itchy", "item", "itemize", "itinerant",
+		"itinerary", "ivory", "ivy", "jab", "jabber", "jack", "jackal", "jackdaw", "jacket", "jackknife", "jackpot", "jade", "jaded", "jagged",
+		"jaguar", "jail", "jailer", "jam", "jamb", "jamboree", "jangle", "janitor", "january", "jar", "jargon", "jasmine", "jaundice", "jaunt",
+		"jaunty", "javelin", "jaw", "jawbone", "jay", "jazz", "jazzy", "jealous", "jealousy", "jeans", "jeep", "jeer", "jelly", "jellyfish",
+		"jeopardize", "jeopardy", "jerk", "jerky", "jersey", "jest", "jester", "jet", "jetty", "jewel", "jeweler", "jeweller", "jewellery",
+		"jewelry", "jib", "jibe", "jig", "jiggle", "jigsaw", "jilt", "jingle", "jinx", "jitters", "job", "jobless", "jockey", "jocular", "jog",
+		"jogger", "join", "joiner", "joinery", "joint", "joist", "joke", "joker", "jolly", "jolt", "jostle", "jot", "journal", "journalism",
+		"journalist", "journey", "jovial", "jowl", "joy", "joyful", "joyous", "joystick", "jubilant", "jubilation", "jubilee", "judge",
+		"judgement", "judgment", "judicial", "judiciary", "judicious", "judo", "jug", "juggernaut", "juggle", "juggler", "jugular", "juice",
+		"juicy", "july", "jumble", "jumbo", "jump", "jumper", "jumpy", "junction", "juncture", "june", "jungle", "junior", "juniper", "junk",
+		"junkie", "junta", "juridical", "jurisdiction", "jurisprudence", "jurist", "juror", "jury", "just", "justice", "justifiable",
+		"justification", "justify", "jut", "jute", "juvenile", "juxtapose", "juxtaposition", "kaleidoscope", "kangaroo", "karaoke", "karate",
+		"kayak", "kebab", "keel", "keen", "keep", "keeper", "keepsake", "keg", "kennel", "kerb", "kerbstone", "kernel", "kerosene", "kestrel",
+		"ketchup", "kettle", "key", "keyboard", "keyhole", "keynote", "keystone", "khaki", "kick", "kickback", "kickoff", "kid", "kidnap",
+		"kidnapper", "kidney", "kill", "killer", "killjoy", "kiln", "kilo", "kilobyte", "kilogram", "kilogramme", "kilometer", "kilometre",
+		"kilowatt", "kilt", "kin", "kind", "kindergarten", "kindle", "kindly", "kindness", "kindred", "kinetic", "king", "kingdom", "kingfisher",
+		"kingly", "kingpin", "kink", "kinky", "kinship", "kiosk", "kip", "kipper", "kiss", "kit", "kitchen", "kite", "kitten", "kitty", "kiwi",
+		"knack", "knapsack", "knave", "knead", "knee", "kneecap", "kneel", "knell", "knickers", "knife", "knight", "knighthood", "knit",
+		"knitwear", "knob", "knock", "knocker", "knockout", "knoll", "knot", "knotty", "know", "knowable", "knowledge", "knowledgeable",
+		"knuckle", "koala", "kosher", "kudos", "lab", "label", "labor", "laboratory", "laborer", "laborious", "labour", "labourer", "labyrinth",
+		"lace", "lacerate", "laceration", "lack", "lackey", "lackluster", "lacklustre", "laconic", "lacquer", "lacrosse", "lactate", "lactation",
+		"lad", "ladder", "laden", "ladle", "lady", "ladybird", "lag", "lager", "lagoon", "lair", "laity", "lake", "lamb", "lame", "lament",
+		"lamentable", "lamentation", "laminate", "lamp", "lampoon", "lamppost", "lampshade", "lance", "land", "landfall", "landfill", "landing",
+		"landlady", "landlocked", "landlord", "landmark", "landmass", "landowner", "landscape", "landslide", "lane", "language", "languid",
+		"languish", "lank", "lanky", "lantern", "lap", "lapel", "lapse", "laptop", "larceny", "larch", "lard", "larder", "large", "largesse",
+		"lark", "larva", "larynx", "lascivious", "laser", "lash", "lass", "lasso", "last", "latch", "late", "latecomer", "latency", "latent",
+		"lateral", "latex", "lath", "lathe", "lather", "latitude", "latrine", "latter", "lattice", "laud", "laudable", "laugh", "laughable",
+		"laughter", "launch", "launder", "launderette", "laundry", "laureate", "laurel", "lava", "lavatory", "lavender", "lavish", "law",
+		"lawful", "lawless", "lawn", "lawnmower", "lawsuit", "lawyer", "lax", "laxative", "laxity", "lay", "layabout", "layer", "layman",
+		"layout", "laze", "lazy", "lead", "leaden", "leader", "leadership", "leaf", "leaflet", "leafy", "league", "leak", "leakage", "leaky",
+		"lean", "leap", "learn", "learner", "lease", "leash", "least", "leather", "leathery", "leave", "lecher", "lecherous", "lectern",
+		"lecture", "lecturer", "ledge", "ledger", "lee", "leech", "leek", "leer", "leeway", "left", "leftover", "leg", "legacy", "legal",
+		"legality", "legalize", "legation", "legend", "legendary", "legible", "legion", "legislate", "legislation", "legislative", "legislator",
+		"legislature", "legitimacy", "legitimate", "legitimize", "leisure", "leisurely", "lemon", "lemonade", "lend", "lender", "length",
+		"lengthen", "lengthy", "lenience", "leniency", "lenient", "lens", "lentil", "leopard", "leotard", "leper", "leprosy", "lesbian",
+		"lesion", "less", "lessee", "lessen", "lesser", "lesson", "lessor", "lest", "let", "lethal", "lethargic", "lethargy", "letter",
+		"letterbox", "lettuce", "leukaemia", "leukemia", "levee", "level", "lever", "leverage", "levitate", "levity", "levy", "lewd", "lexical",
+		"lexicon", "liability", "liable", "liaise", "liaison", "liar", "libel", "libellous", "libelous", "liberal", "liberalism", "liberalize",
+		"liberate", "liberation", "liberator", "libertine", "liberty", "libido", "librarian", "library", "licence", "license", "licensee",
+		"licentious", "lichen", "lick", "lid", "lido", "lie", "lieu", "lieutenant", "life", "lifeblood", "lifeboat", "lifeguard", "lifeless",
+		"lifelike", "lifeline", "lifelong", "lifestyle", "lifetime", "lift", "ligament", "light", "lighten", "lighter", "lighthouse",
+		"lightning", "likable", "like", "likeable", "likelihood", "likely", "liken", "likeness", "likewise", "liking", "lilac", "lilt", "lily",
+		"limb", "limber", "limbo", "lime", "limelight", "limerick", "limestone", "limit", "limitation", "limousine", "limp", "limpid", "line",
+		"lineage", "linear", "linen", "liner", "linesman", "linger", "lingerie", "lingo", "linguist", "linguistic", "linguistics", "liniment",
+		"lining", "link", "linkage", "lino", "linoleum", "lintel", "lion", "lioness", "lip", "lipstick", "liquefy", "liqueur", "liquid",
+		"liquidate", "liquidation", "liquidity", "liquor", "lisp", "list", "listen", "listener", "listless", "litany", "liter", "literacy",
+		"literal", "literary", "literate", "literature", "lithe", "lithograph", "litigant", "litigate", "litigation", "litmus", "litre",
+		"litter", "little", "liturgical", "liturgy", "livable", "live", "livelihood", "lively", "liven", "liver", "livery", "livestock", "livid",
+		"living", "lizard", "llama", "load", "loaf", "loam", "loan", "loath", "loathe", "loathsome", "lob", "lobby", "lobbyist", "lobe",
+		"lobster", "local", "locale", "locality", "localize", "locate", "location", "loch", "lock", "locker", "locket", "locksmith",
+		"locomotion", "locomotive", "locust", "lodge", "lodger", "lodging", "loft", "lofty", "log", "logarithm", "logbook", "logic", "logical",
+		"logistic", "logistics", "logo", "loin", "loiter", "loll", "lollipop", "lolly", "lone", "lonely", "loner", "lonesome", "long",
+		"longevity", "longhand", "longing", "longitude", "longitudinal", "loo", "look", "lookout", "loom", "loony", "loop", "loophole", "loose",
+		"loosen", "loot", "lop", "lope", "lopsided", "loquacious", "lord", "lordly", "lordship", "lore", "lorry", "lose", "loser", "loss",
+		"lost", "lot", "lotion", "lottery", "lotus", "loud", "loudspeaker", "lounge", "louse", "lousy", "lout", "lovable", "love", "lovely",
+		"lover", "low", "lower", "lowland", "lowly", "loyal", "loyalist", "loyalty", "lozenge", "lubricant", "lubricate", "lubrication", "lucid",
+		"lucidity", "luck", "lucky", "lucrative", "ludicrous", "lug", "luggage", "lugubrious", "lukewarm", "lull", "lullaby", "lumbago",
+		"lumber", "lumberjack", "luminary", "luminous", "lump", "lumpy", "lunacy", "lunar", "lunatic", "lunch", "luncheon", "lunchtime", "lung",
+		"lunge", "lurch", "lure", "lurid", "lurk", "luscious", "lush", "lust", "luster", "lustful", "lustre", "lusty", "lute", "luxuriant",
+		"luxuriate", "luxurious", "luxury", "lynch", "lyre", "lyric", "lyrical", "lyricism", "lyricist", "ma", "mac", "macabre", "macaroni",
+		"mace", "machete", "machination", "machine", "machinery", "machinist", "macho", "mackerel", "mackintosh", "macro", "macrobiotic", "mad",
+		"madam", "madden", "madhouse", "madman", "madness", "maelstrom", "maestro", "mafia", "magazine", "maggot", "magic", "magical",
+		"magician", "magisterial", "magistrate", "magnanimity", "magnanimous", "magnate", "magnesium", "magnet", "magnetic", "magnetism",
+		"magnetize", "magnification", "magnificence", "magnificent", "magnify", "magnitude", "magnolia", "magpie", "mahogany", "maid", "maiden",
+		"mail", "mailbox", "mailman", "maim", "main", "mainframe", "mainland", "mainline", "mainspring", "mainstay", "mainstream", "maintain",
+		"maintenance", "maize", "majestic", "majesty", "major", "majority", "make", "maker", "makeshift", "makeup", "maladjusted",
+		"maladministration", "malady", "malaise", "malaria", "malcontent", "male", "malevolence", "malevolent", "malformation", "malfunction",
+		"malice", "malicious", "malign", "malignant", "malinger", "mall", "mallard", "malleable", "mallet", "malnourished", "malnutrition",
+		"malpractice", "malt", "maltreat", "maltreatment", "mammal", "mammalian", "mammoth", "man", "manacle", "manage", "manageable",
+		"management", "manager", "manageress", "managerial", "mandarin", "mandate", "mandatory", "mandolin", "mane", "maneuver", "manger",
+		"mangle", "mango", "mangrove", "manhandle", "manhole", "manhood", "mania", "maniac", "manic", "manicure", "manicurist", "manifest",
+		"manifestation", "manifesto", "manifold", "manipulate", "manipulation", "manipulative", "mankind", "manly", "manner", "mannerism",
+		"manoeuvre", "manor", "manpower", "manse", "mansion", "manslaughter", "mantelpiece", "mantle", "mantra", "manual", "manufacture",
+		"manufacturer", "manure", "manuscript", "many", "map", "maple", "mar", "marathon", "marauder", "marble", "march", "mare", "margarine",
+		"margin", "marginal", "marginalize", "marigold", "marijuana", "marina", "marinade", "marinate", "marine", "mariner", "marionette",
+		"marital", "maritime", "mark", "marker", "market", "marketable", "marketing", "marketplace", "marksman", "marmalade", "maroon",
+		"marquee", "marquis", "marriage", "marriageable", "marrow", "marry", "marsh", "marshal", "marshy", "marsupial", "mart", "martial",
+		"martyr", "martyrdom", "marvel", "marvellous", "marvelous", "marzipan", "mascara", "mascot", "masculine", "masculinity", "mash", "mask",
+		"masochism", "masochist", "mason", "masonry", "masquerade", "mass", "massacre", "massage", "massive", "mast", "master", "masterful",
+		"mastermind", "masterpiece", "mastery", "masturbate", "masturbation", "mat", "matador", "match", "matchbox", "matchstick", "mate",
+		"material", "materialism", "materialist", "materialistic", "materialize", "maternal", "maternity", "math", "mathematician",
+		"mathematics", "maths", "matinee", "matriarch", "matriarchal", "matriculate", "matriculation", "matrimonial", "matrimony", "matrix",
+		"matron", "matter", "mattress", "mature", "maturity", "maudlin", "maul", "mausoleum", "mauve", "maverick", "maxim", "maximal",
+		"maximize", "maximum", "may", "maybe", "mayhem", "mayonnaise", "mayor", "mayoress", "maze", "me", "meadow", "meager", "meagre", "meal",
+		"mealtime", "mean", "meander", "meaning", "meaningful", "meaningless", "means", "meantime", "meanwhile", "measles", "measly",
+		"measurable", "measure", "measurement", "meat", "meaty", "mecca", "mechanic", "mechanical", "mechanics", "mechanism", "mechanize",
+		"medal", "medalist", "medallion", "medallist", "meddle", "meddlesome", "media", "median", "mediate", "mediation", "mediator", "medic",
+		"medical", "medicate", "medication", "medicinal", "medicine", "medieval", "mediocre", "mediocrity", "meditate", "meditation",
+		"meditative", "medium", "medley", "meek", "meet", "meeting", "megabyte", "megalomania", "megalomaniac", "megaphone", "melancholic",
+		"melancholy", "melee", "mellow", "melodic", "melodious", "melodrama", "melodramatic", "melody", "melon", "melt", "member", "membership",
+		"membrane", "memento", "memo", "memoir", "memorabilia", "memorable", "memorandum", "memorial", "memorize", "memory", "menace",
+		"menagerie", "mend", "menial", "meningitis", "menopause", "menstrual", "menstruate", "menstruation", "mental", "mentality", "mention",
+		"mentor", "menu", "mercantile", "mercenary", "merchandise", "merchant", "merciful", "merciless", "mercurial", "mercury", "mercy", "mere",
+		"merge", "merger", "meridian", "meringue", "merit", "meritocracy", "meritorious", "mermaid", "merriment", "merry", "mesh", "mesmerize",
+		"mess", "message", "messenger", "messiah", "messy", "metabolic", "metabolism", "metal", "metallic", "metallurgy", "metamorphose",
+		"metamorphosis", "metaphor", "metaphorical", "metaphysics", "mete", "meteor", "meteoric", "meteorite", "meteorological", "meteorologist",
+		"meteorology", "meter", "methane", "method", "methodical", "methodology", "meticulous", "metre", "metric", "metrical", "metro",
+		"metropolis", "metropolitan", "mettle", "mew", "mezzanine", "miaow", "microbe", "microchip", "microcomputer", "microcosm", "microfilm",
+		"microphone", "microprocessor", "microscope", "microscopic", "microwave", "mid", "midday", "middle", "middleman", "midget", "midnight",
+		"midst", "midsummer", "midway", "midwife", "midwinter", "mien", "might", "mighty", "migraine", "migrant", "migrate", "migration",
+		"migratory", "mike", "mild", "mildew", "mile", "mileage", "milestone", "milieu", "militancy", "militant", "militarism", "militarist",
+		"military", "militate", "militia", "milk", "milkman", "milky", "mill", "millennium", "miller", "milligram", "millimeter", "millimetre",
+		"milliner", "millinery", "million", "millionaire", "millionth", "millstone", "mime", "mimic", "mimicry", "minaret", "mince", "mincemeat",
+		"mind", "mindful", "mindless", "mine", "minefield", "miner", "mineral", "mineralogy", "mingle", "miniature", "minibus", "minimal",
+		"minimize", "minimum", "minion", "miniskirt", "minister", "ministerial", "ministry", "mink", "minnow", "minor", "minority", "minstrel",
+		"mint", "minuet", "minus", "minuscule", "minute", "minutiae", "miracle", "miraculous", "mirage", "mire", "mirror", "mirth",
+		"misadventure", "misanthrope", "misanthropic", "misapprehension", "misappropriate", "misbehave", "misbehavior", "misbehaviour",
+		"miscalculate", "miscalculation", "miscarry", "miscellaneous", "miscellany", "mischief", "mischievous", "misconceive", "misconception",
+		"misconduct", "misconstrue", "misdeed", "misdemeanor", "misdemeanour", "miser", "miserable", "miserly", "misery", "misfire", "misfit",
+		"misfortune", "misgiving", "misguided", "mishandle", "mishap", "misinform", "misinformation", "misinterpret", "misinterpretation",
+		"misjudge", "mislay", "mislead", "mismanage", "mismanagement", "mismatch", "misnomer", "misogynist", "misogyny", "misplace", "misprint",
+		"mispronounce", "misquote", "misread", "misrepresent", "misrepresentation", "misrule", "miss", "missile", "mission", "missionary",
+		"missive", "misspell", "mist", "mistake", "mistaken", "mistress", "mistrust", "misty", "misunderstand", "misunderstanding", "misuse",
+		"mite", "mitigate", "mitigation", "mitre", "mitt", "mitten", "mix", "mixer", "mixture", "moan", "moat", "mob", "mobile", "mobility",
+		"mobilization", "mobilize", "moccasin", "mock", "mockery", "mode", "model", "modem", "moderate", "moderation", "moderator", "modern",
+		"modernism", "modernist", "modernity", "modernization", "modernize", "modest", "modesty", "modicum", "modification", "modify", "modular",
+		"modulate", "modulation", "module", "mogul", "moist", "moisten", "moisture", "moisturize", "molar", "molasses", "mold", "mole",
+		"molecular", "molecule", "molehill", "molest", "molestation", "mollify", "mollusc", "mollusk", "molten", "moment", "momentary",
+		"momentous", "momentum", "monarch", "monarchist", "monarchy", "monastery", "monastic", "monday", "monetarism", "monetarist", "monetary",
+		"money", "moneylender", "mongrel", "monitor", "monk", "monkey", "monochrome", "monocle", "monogamous", "monogamy", "monogram",
+		"monolith", "monolithic", "monologue", "monopolize", "monopoly", "monorail", "monosyllabic", "monosyllable", "monotone", "monotonous",
+		"monotony", "monsoon", "monster", "monstrosity", "monstrous", "montage", "month", "monthly", "monument", "monumental", "moo", "mood",
+		"moody", "moon", "moonbeam", "moonlight", "moor", "mooring", "moorland", "moose", "moot", "mop", "mope", "moped", "moral", "morale",
+		"moralist", "moralistic", "morality", "moralize", "morass", "moratorium", "morbid", "more", "moreover", "mores", "morgue", "moribund",
+		"morning", "moron", "moronic", "morose", "morphine", "morphology", "morrow", "morsel", "mortal", "mortality", "mortar", "mortgage",
+		"mortician", "mortify", "mortuary", "mosaic", "mosque", "mosquito", "moss", "most", "mostly", "motel", "moth", "mother", "motherhood",
+		"motherland", "motherly", "motif", "motion", "motionless", "motivate", "motivation", "motive", "motley", "motor", "motorbike",
+		"motorboat", "motorcade", "motorcar", "motorcycle", "motorcyclist", "motorist", "motorway", "mottled", "motto", "mould", "mouldy",
+		"moult", "mound", "mount", "mountain", "mountaineer", "mountaineering", "mountainous", "mourn", "mourner", "mournful", "mourning",
+		"mouse", "mousse", "moustache", "mouth", "mouthful", "mouthpiece", "movable", "move", "movement", "mover", "movie", "mow", "mower",
+		"much", "muck", "mucous", "mucus", "mud", "muddle", "muddy", "mudguard", "muffin", "muffle", "muffler", "mug", "mugger", "muggy",
+		"mulberry", "mule", "mull", "mullet", "multicultural", "multilateral", "multimedia", "multinational", "multiple", "multiplication",
+		"multiplicity", "multiply", "multitude", "mum", "mumble", "mummify", "mummy", "mumps", "munch", "mundane", "municipal", "municipality",
+		"munificent", "munitions", "mural", "murder", "murderer", "murderous", "murky", "murmur", "muscle", "muscular", "muse", "museum", "mush",
+		"mushroom", "mushy", "music", "musical", "musician", "musk", "musket", "musketeer", "muslin", "mussel", "must", "mustache", "mustard",
+		"muster", "musty", "mutant", "mutate", "mutation", "mute", "mutilate", "mutilation", "mutineer", "mutinous", "mutiny", "mutt", "mutter",
+		"mutton", "mutual", "muzzle", "my", "myopia", "myopic", "myriad", "myrrh", "myself", "mysterious", "mystery", "mystic", "mystical",
+		"mysticism", "mystify", "mystique", "myth", "mythical", "mythological", "mythology", "nab", "nadir", "nag", "nail", "naive", "naivety",
+		"naked", "name", "nameless", "namely", "namesake", "nanny", "nap", "napalm", "nape", "napkin", "nappy", "narcissism", "narcissistic",
+		"narcotic", "narrate", "narration", "narrative", "narrator", "narrow", "nasal", "nascent", "nasty", "nation", "national", "nationalism",
+		"nationalist", "nationalistic", "nationality", "nationalization", "nationalize", "nationwide", "native", "nativity", "natter", "natural",
+		"naturalism", "naturalist", "naturalistic", "naturalization", "naturalize", "nature", "naught", "naughty", "nausea", "nauseate",
+		"nauseous", "nautical", "naval", "nave", "navel", "navigable", "navigate", "navigation", "navigator", "navvy", "navy", "nay", "near",
+		"nearby", "nearly", "neat", "nebula", "nebulous", "necessary", "necessitate", "necessity", "neck", "necklace", "neckline", "necktie",
+		"necromancy", "nectar", "nectarine", "need", "needful", "needle", "needless", "needlework", "needy", "negate", "negation", "negative",
+		"neglect", "neglectful", "negligence", "negligent", "negligible", "negotiable", "negotiate", "negotiation", "negotiator", "neigh",
+		"neighbor", "neighborhood", "neighbour", "neighbourhood", "neighbourly", "neither", "nemesis", "neon", "nephew", "nepotism", "nerve",
+		"nervous", "nest", "nestle", "net", "netball", "nether", "netting", "nettle", "network", "neural", "neurological", "neurologist",
+		"neurology", "neurosis", "neurotic", "neuter", "neutral", "neutrality", "neutralize", "neutron", "never", "nevertheless", "new",
+		"newborn", "newcomer", "newfangled", "newly", "newlywed", "news", "newsagent", "newscast", "newscaster", "newsletter", "newspaper",
+		"newsprint", "newsreader", "newsreel", "newt", "next", "nib", "nibble", "nice", "nicety", "niche", "nick", "nickel", "nickname",
+		"nicotine", "niece", "nifty", "niggle", "nigh", "night", "nightclub", "nightdress", "nightfall", "nightgown", "nightingale", "nightlife",
+		"nightly", "nightmare", "nightmarish", "nighttime", "nihilism", "nil", "nimble", "nine", "nineteen", "ninety", "ninth", "nip", "nipple",
+		"nitrate", "nitrogen", "no", "nobility", "noble", "nobleman", "nobody", "nocturnal", "nod", "node", "noise", "noiseless", "noisy",
+		"nomad", "nomadic", "nomenclature", "nominal", "nominate", "nomination", "nominee", "nonchalance", "nonchalant", "noncommittal",
+		"nonconformist", "nondescript", "none", "nonentity", "nonetheless", "nonexistent", "nonplussed", "nonsense", "nonsensical", "nonstop",
+		"noodle", "nook", "noon", "noose", "nor", "norm", "normal", "normality", "normalize", "north", "northeast", "northerly", "northern",
+		"northward", "northwest", "nose", "nosebleed", "nosedive", "nostalgia", "nostalgic", "nostril", "nosy", "not", "notable", "notary",
+		"notation", "notch", "note", "notebook", "noteworthy", "nothing", "notice", "noticeable", "notification", "notify", "notion", "notional",
+		"notoriety", "notorious", "notwithstanding", "nought", "noun", "nourish", "nourishment", "novel", "novelist", "novelty", "november",
+		"novice", "now", "nowadays", "nowhere", "noxious", "nozzle", "nuance", "nubile", "nuclear", "nucleus", "nude", "nudge", "nudism",
+		"nudist", "nudity", "nugget", "nuisance", "null", "nullify", "numb", "number", "numeral", "numerate", "numerical", "numerous", "nun",
+		"nunnery", "nuptial", "nurse", "nursery", "nurture", "nut", "nutcracker", "nutmeg", "nutrient", "nutrition", "nutritional", "nutritious",
+		"nutshell", "nuzzle", "nylon", "nymph", "oak", "oar", "oasis", "oat", "oath", "oatmeal", "obedience", "obedient", "obelisk", "obese",
+		"obesity", "obey", "obituary", "object", "objection", "objectionable", "objective", "objectivity", "objector", "obligation",
+		"obligatory", "oblige", "obliging", "oblique", "obliterate", "obliteration", "oblivion", "oblivious", "oblong", "obnoxious", "oboe",
+		"obscene", "obscenity", "obscure", "obscurity", "obsequious", "observable", "observance", "observant", "observation", "observatory",
+		"observe", "observer", "obsess", "obsession", "obsessive", "obsolescence", "obsolescent", "obsolete", "obstacle", "obstetric",
+		"obstetrician", "obstetrics", "obstinacy", "obstinate", "obstruct", "obstruction", "obstructive", "obtain", "obtainable", "obtrusive",
+		"obtuse", "obverse", "obviate", "obvious", "occasion", "occasional", "occult", "occupancy", "occupant", "occupation", "occupational",
+		"occupier", "occupy", "occur", "occurrence", "ocean", "oceanic", "oceanography", "ochre", "octagon", "octagonal", "octane", "octave",
+		"october", "octopus", "ocular", "odd", "oddity", "oddment", "odds", "ode", "odious", "odor", "odour", "odyssey", "of", "off", "offal",
+		"offence", "offend", "offender", "offense", "offensive", "offer", "offering", "offhand", "office", "officer", "official", "officialdom",
+		"officiate", "officious", "offing", "offset", "offshoot", "offshore", "offside", "offspring", "often", "ogre", "oh", "oil", "oilfield",
+		"oilskin", "oily", "ointment", "okay", "old", "olfactory", "oligarchy", "olive", "omelet", "omelette", "omen", "ominous", "omission",
+		"omit", "omnibus", "omnipotence", "omnipotent", "omnipresent", "omniscient", "omnivorous", "on", "once", "oncoming", "one", "onerous",
+		"oneself", "ongoing", "onion", "onlooker", "only", "onset", "onshore", "onslaught", "onto", "onus", "onward", "onwards", "ooze",
+		"opacity", "opal", "opaque", "open", "opener", "opening", "openness", "opera", "operable", "operate", "operatic", "operation",
+		"operational", "operative", "operator", "operetta", "ophthalmic", "opiate", "opine", "opinion", "opinionated", "opium", "opponent",
+		"opportune", "opportunism", "opportunist", "opportunistic", "opportunity", "oppose", "opposite", "opposition", "oppress", "oppression",
+		"oppressive", "oppressor", "opt", "optic", "optical", "optician", "optics", "optimal", "optimism", "optimist", "optimistic", "optimize",
+		"optimum", "option", "optional", "opulence", "opulent", "opus", "or", "oracle", "oral", "orange", "orangutan", "oration", "orator",
+		"oratorio", "oratory", "orb", "orbit", "orbital", "orchard", "orchestra", "orchestral", "orchestrate", "orchestration", "orchid",
+		"ordain", "ordeal", "order", "orderly", "ordinal", "ordinance", "ordinary", "ordination", "ore", "organ", "organic", "organism",
+		"organist", "organization", "organizational", "organize", "organizer", "orgasm", "orgy", "orient", "oriental", "orientate",
+		"orientation", "orifice", "origin", "original", "originality", "originate", "originator", "ornament", "ornamental", "ornate",
+		"ornithology", "orphan", "orphanage", "orthodox", "orthodoxy", "orthography", "orthopaedic", "orthopedic", "oscillate", "oscillation",
+		"ostensible", "ostentation", "ostentatious", "osteopath", "ostracism", "ostracize", "ostrich", "other", "otherwise", "otter", "ought",
+		"ounce", "our", "ourselves", "oust", "out", "outback", "outbid", "outboard", "outbreak", "outbuilding", "outburst", "outcast",
+		"outclass", "outcome", "outcrop", "outcry", "outdated", "outdo", "outdoor", "outdoors", "outer", "outermost", "outfit", "outflank",
+		"outflow", "outgoing", "outgrow", "outhouse", "outing", "outlandish", "outlast", "outlaw", "outlay", "outlet", "outline", "outlive",
+		"outlook", "outlying", "outmanoeuvre", "outmoded", "outnumber", "outpatient", "outperform", "outpost", "outpouring", "output", "outrage",
+		"outrageous", "outright", "outrun", "outset", "outshine", "outside", "outsider", "outskirts", "outsmart", "outspoken", "outstanding",
+		"outstay", "outstretched", "outstrip", "outward", "outwards", "outweigh", "outwit", "oval", "ovary", "ovation", "oven", "over",
+		"overact", "overall", "overawe", "overbalance", "overbearing", "overboard", "overburden", "overcast", "overcharge", "overcoat",
+		"overcome", "overcrowd", "overdo", "overdose", "overdraft", "overdraw", "overdrive", "overdue", "overestimate", "overflow", "overgrown",
+		"overhang", "overhaul", "overhead", "overhear", "overheat", "overjoyed", "overkill", "overland", "overlap", "overlay", "overleaf",
+		"overload", "overlook", "overlord", "overnight", "overpass", "overpower", "overrate", "overreach", "overreact", "override", "overrule",
+		"overrun", "overseas", "oversee", "overseer", "overshadow", "overshoot", "oversight", "oversimplify", "oversleep", "overspend",
+		"overstate", "overstep", "overt", "overtake", "overthrow", "overtime", "overtone", "overture", "overturn", "overview", "overweight",
+		"overwhelm", "overwork", "overwrought", "ovulate", "ovulation", "owe", "owl", "own", "owner", "ownership", "ox", "oxidation", "oxide",
+		"oxidize", "oxygen", "oyster", "ozone", "pace", "pacemaker", "pacifier", "pacifism", "pacifist", "pacify", "pack", "package", "packet",
+		"pact", "pad", "padding", "paddle", "paddock", "padlock", "paediatrician", "paediatrics", "pagan", "paganism", "page", "pageant",
+		"pageantry", "pager", "pagoda", "pail", "pain", "painful", "painkiller", "painless", "painstaking", "paint", "paintbrush", "painter",
+		"painting", "pair", "pal", "palace", "palatable", "palate", "palatial", "pale", "palette", "pall", "pallbearer", "pallet", "palliative",
+		"pallid", "pallor", "palm", "palmistry", "palpable", "palpitate", "palpitation", "paltry", "pamper", "pamphlet", "pan", "panacea",
+		"panache", "pancake", "pancreas", "panda", "pandemonium", "pander", "pane", "panel", "pang", "panic", "panorama", "panoramic", "pansy",
+		"pant", "panther", "panties", "pantomime", "pantry", "pants", "papa", "papacy", "papal", "paper", "paperback", "paperweight",
+		"paperwork", "paprika", "papyrus", "par", "parable", "parachute", "parachutist", "parade", "paradigm", "paradise", "paradox",
+		"paradoxical", "paraffin", "paragon", "paragraph", "parakeet", "parallel", "parallelogram", "paralyse", "paralysis", "paralytic",
+		"paralyze", "paramedic", "parameter", "paramilitary", "paramount", "paranoia", "paranoid", "paranormal", "parapet", "paraphernalia",
+		"paraphrase", "paraplegic", "parasite", "parasitic", "parasol", "paratrooper", "parcel", "parch", "parchment", "pardon", "pardonable",
+		"pare", "parent", "parentage", "parental", "parenthesis", "parenthetical", "parenthood", "pariah", "parish", "parishioner", "parity",
+		"park", "parka", "parliament", "parliamentarian", "parliamentary", "parlor", "parlour", "parochial", "parody", "parole", "paroxysm",
+		"parquet", "parrot", "parry", "parse", "parsimonious", "parsley", "parsnip", "parson", "parsonage", "part", "partake", "partial",
+		"partiality", "participant", "participate", "participation", "participle", "particle", "particular", "particularly", "parting",
+		"partisan", "partition", "partly", "partner", "partnership", "partridge", "party", "pass", "passable", "passage", "passageway",
+		"passenger", "passer", "passion", "passionate", "passive", "passivity", "passport", "password", "past", "pasta", "paste", "pastel",
+		"pasteurize", "pastiche", "pastime", "pastor", "pastoral", "pastry", "pasture", "pasty", "pat", "patch", "patchwork", "patchy", "pate",
+		"patent", "paternal", "paternalism", "paternity", "path", "pathetic", "pathological", "pathologist", "pathology", "pathos", "pathway",
+		"patience", "patient", "patio", "patriarch", "patriarchal", "patriot", "patriotic", "patriotism", "patrol", "patrolman", "patron",
+		"patronage", "patronize", "patter", "pattern", "paucity", "paunch", "pauper", "pause", "pave", "pavement", "pavilion", "paw", "pawn",
+		"pawnbroker", "pay", "payable", "payee", "payer", "payment", "payoff", "payroll", "pea", "peace", "peaceable", "peaceful",
+		"peacekeeping", "peacetime", "peach", "peacock", "peak", "peal", "peanut", "pear", "pearl", "peasant", "peasantry", "peat", "pebble",
+		"pecan", "peck", "peculiar", "peculiarity", "pecuniary", "pedagogic", "pedagogy", "pedal", "pedant", "pedantic", "peddle", "peddler",
+		"pedestal", "pedestrian", "pediatrician", "pediatrics", "pedigree", "pedlar", "pee", "peek", "peel", "peep", "peer", "peerage",
+		"peerless", "peevish", "peg", "pejorative", "pelican", "pellet", "pelt", "pelvic", "pelvis", "pen", "penal", "penalize", "penalty",
+		"penance", "penchant", "pencil", "pendant", "pending", "pendulum", "penetrate", "penetration", "penguin", "penicillin", "peninsula",
+		"penis", "penitence", "penitent", "penitentiary", "penknife", "pennant", "penniless", "penny", "pension", "pensioner", "pensive",
+		"pentagon", "penthouse", "penultimate", "penury", "people", "pep", "pepper", "peppermint", "per", "perceive", "percent", "percentage",
+		"perceptible", "perception", "perceptive", "perch", "percolate", "percolator", "percussion", "peremptory", "perennial", "perfect",
+		"perfection", "perfectionist", "perforate", "perforation", "perform", "performance", "performer", "perfume", "perfunctory", "perhaps",
+		"peril", "perilous", "perimeter", "period", "periodic", "periodical", "peripheral", "periphery", "periscope", "perish", "perishable",
+		"perjure", "perjury", "perk", "perky", "perm", "permanence", "permanent", "permeable", "permeate", "permissible", "permission",
+		"permissive", "permit", "permutation", "pernicious", "perpendicular", "perpetrate", "perpetrator", "perpetual", "perpetuate", "perplex",
+		"perplexity", "persecute", "persecution", "persecutor", "perseverance", "persevere", "persist", "persistence", "persistent", "person",
+		"persona", "personable", "personage", "personal", "personality", "personalize", "personification", "personify", "personnel",
+		"perspective", "perspiration", "perspire", "persuade", "persuasion", "persuasive", "pert", "pertain", "pertinent", "perturb", "perusal",
+		"peruse", "pervade", "pervasive", "perverse", "perversion", "perversity", "pervert", "pessimism", "pessimist", "pessimistic", "pest",
+		"pester", "pesticide", "pestle", "pet", "petal", "peter", "petite", "petition", "petrify", "petrol", "petroleum", "petticoat", "petty",
+		"petulant", "pew", "pewter", "phantom", "pharaoh", "pharmaceutical", "pharmacist", "pharmacology", "pharmacy", "phase", "pheasant",
+		"phenomenal", "phenomenon", "phial", "philanthropic", "philanthropist", "philanthropy", "philately", "philosopher", "philosophical",
+		"philosophize", "philosophy", "phlegm", "phlegmatic", "phobia", "phoenix", "phone", "phonetic", "phonetics", "phoney", "phony",
+		"phosphate", "phosphorus", "photo", "photocopier", "photocopy", "photogenic", "photograph", "photographer", "photographic",
+		"photography", "phrase", "phraseology", "physical", "physician", "physicist", "physics", "physiological", "physiology",
+		"physiotherapist", "physiotherapy", "physique", "pianist", "piano", "piccolo", "pick", "picket", "pickle", "pickpocket", "picnic",
+		"pictorial", "picture", "picturesque", "pie", "piece", "piecemeal", "pier", "pierce", "piety", "pig", "pigeon", "piggyback", "pigheaded",
+		"piglet", "pigment", "pigsty", "pike", "pile", "pilfer", "pilgrim", "pilgrimage", "pill", "pillage", "pillar", "pillion", "pillow",
+		"pillowcase", "pilot", "pimp", "pimple", "pin", "pinafore", "pincer", "pinch", "pine", "pineapple", "ping", "pink", "pinnacle",
+		"pinpoint", "pint", "pioneer", "pious", "pip", "pipe", "pipeline", "piper", "piping", "piquant", "pique", "piracy", "piranha", "pirate",
+		"pirouette", "pistachio", "pistol", "piston", "pit", "pitch", "pitcher", "pitchfork", "piteous", "pitfall", "pith", "pitiable",
+		"pitiful", "pitiless", "pittance", "pity", "pivot", "pivotal", "pixel", "pixie", "pizza", "placard", "placate", "place", "placement",
+		"placid", "plagiarism", "plagiarize", "plague", "plaice", "plaid", "plain", "plaintiff", "plaintive", "plait", "plan", "plane", "planet",
+		"planetarium", "planetary", "plank", "plankton", "planner", "plant", "plantation", "planter", "plaque", "plasma", "plaster",
+		"plasterboard", "plasterer", "plastic", "plate", "plateau", "platform", "platinum", "platitude", "platonic", "platoon", "platter",
+		"plaudits", "plausible", "play", "playback", "playboy", "player", "playful", "playground", "playmate", "playpen", "plaything",
+		"playwright", "plaza", "plea", "plead", "pleasant", "pleasantry", "please", "pleasurable", "pleasure", "pleat", "pleb", "plebeian",
+		"plebiscite", "pledge", "plenary", "plentiful", "plenty", "plethora", "pliable", "pliant", "pliers", "plight", "plinth", "plod", "plonk",
+		"plop", "plot", "plough", "plow", "ploy", "pluck", "plucky", "plug", "plum", "plumage", "plumb", "plumber", "plumbing", "plume",
+		"plummet", "plump", "plunder", "plunge", "plural", "pluralism", "plurality", "plus", "plush", "plutonium", "ply", "plywood", "pneumatic",
+		"pneumonia", "poach", "poacher", "pocket", "pocketbook", "pod", "podgy", "podium", "poem", "poet", "poetic", "poetry", "poignancy",
+		"poignant", "point", "pointer", "pointless", "poise", "poison", "poisonous", "poke", "poker", "polar", "polarity", "polarization",
+		"polarize", "pole", "polemic", "polemical", "police", "policeman", "policewoman", "policy", "polio", "polish", "polite", "politic",
+		"politician", "politics", "polity", "polka", "poll", "pollen", "pollinate", "pollination", "pollster", "pollutant", "pollute",
+		"pollution", "polo", "polyester", "polygamy", "polygon", "polygraph", "polystyrene", "polytechnic", "polythene", "pomegranate", "pomp",
+		"pompous", "pond", "ponder", "ponderous", "pony", "ponytail", "poodle", "pool", "poor", "poorly", "pop", "popcorn", "pope", "poplar",
+		"poppy", "populace", "popular", "popularity", "popularize", "populate", "population", "populist", "populous", "porcelain", "porch",
+		"porcupine", "pore", "pork", "pornographic", "pornography", "porous", "porpoise", "porridge", "port", "portable", "portal", "portend",
+		"portent", "portentous", "porter", "portfolio", "porthole", "portion", "portly", "portrait", "portraiture", "portray", "portrayal",
+		"pose", "poser", "posh", "position", "positive", "possess", "possession", "possessive", "possessor", "possibility", "possible",
+		"possibly", "post", "postage", "postal", "postbox", "postcard", "postcode", "poster", "posterior", "posterity", "postgraduate",
+		"posthumous", "postman", "postmark", "postmaster", "postmortem", "postpone", "postponement", "postscript", "postulate", "posture",
+		"postwar", "posy", "pot", "potash", "potassium", "potato", "potency", "potent", "potentate", "potential", "potion", "potter", "pottery",
+		"potty", "pouch", "poultice", "poultry", "pounce", "pound", "pour", "pout", "poverty", "powder", "powdery", "power", "powerful",
+		"powerhouse", "powerless", "practicable", "practical", "practicality", "practice", "practise", "practitioner", "pragmatic", "pragmatism",
+		"pragmatist", "prairie", "praise", "praiseworthy", "pram", "prance", "prank", "prattle", "prawn", "pray", "prayer", "preach", "preacher",
+		"preamble", "precarious", "precaution", "precautionary", "precede", "precedence", "precedent", "precept", "precinct", "precious",
+		"precipice", "precipitate", "precipitation", "precipitous", "precis", "precise", "precision", "preclude", "precocious", "preconceived",
+		"preconception", "precondition", "precursor", "predate", "predator", "predatory", "predecessor", "predestination", "predetermine",
+		"predicament", "predicate", "predict", "predictable", "prediction", "predilection", "predispose", "predisposition", "predominance",
+		"predominant", "predominate", "preeminent", "preempt", "preen", "prefab", "prefabricate", "preface", "prefect", "prefer", "preferable",
+		"preference", "preferential", "preferment", "prefix", "pregnancy", "pregnant", "prehistoric", "prehistory", "prejudice", "prejudicial",
+		"prelate", "preliminary", "prelude", "premarital", "premature", "premeditate", "premeditation", "premier", "premiere", "premise",
+		"premises", "premium", "premonition", "preoccupation", "preoccupy", "preparation", "preparatory", "prepare", "preponderance",
+		"preposition", "preposterous", "prerequisite", "prerogative", "presage", "prescribe", "prescription", "prescriptive", "presence",
+		"present", "presentable", "presentation", "presenter", "presently", "preservation", "preservative", "preserve", "preside", "presidency",
+		"president", "presidential", "press", "pressure", "pressurize", "prestige", "prestigious", "presumable", "presume", "presumption",
+		"presumptuous", "presuppose", "presupposition", "pretence", "pretend", "pretender", "pretense", "pretension", "pretentious", "pretext",
+		"pretty", "prevail", "prevalence", "prevalent", "prevaricate", "prevent", "preventable", "prevention", "preventive", "preview",
+		"previous", "prey", "price", "priceless", "pricey", "prick", "prickle", "prickly", "pride", "priest", "priesthood", "prig", "prim",
+		"primacy", "primal", "primary", "primate", "prime", "primer", "primeval", "primitive", "primrose", "prince", "princely", "princess",
+		"principal", "principality", "principle", "print", "printer", "printout", "prior", "priority", "priory", "prise", "prism", "prison",
+		"prisoner", "pristine", "privacy", "private", "privation", "privatization", "privatize", "privilege", "privy", "prize", "pro",
+		"proactive", "probability", "probable", "probate", "probation", "probationary", "probe", "probity", "problem", "problematic",
+		"procedural", "procedure", "proceed", "proceeding", "proceeds", "process", "procession", "processor", "proclaim", "proclamation",
+		"proclivity", "procrastinate", "procrastination", "procreate", "procreation", "procure", "procurement", "prod", "prodigal", "prodigious",
+		"prodigy", "produce", "producer", "product", "production", "productive", "productivity", "profane", "profanity", "profess", "profession",
+		"professional", "professionalism", "professor", "proffer", "proficiency", "proficient", "profile", "profit", "profitable", "profiteer",
+		"profligate", "profound", "profundity", "profuse", "profusion", "progeny", "prognosis", "program", "programme", "programmer", "progress",
+		"progression", "progressive", "prohibit", "prohibition", "prohibitive", "project", "projectile", "projection", "projector",
+		"proletarian", "proletariat", "proliferate", "proliferation", "prolific", "prologue", "prolong", "promenade", "prominence", "prominent",
+		"promiscuity", "promiscuous", "promise", "promissory", "promontory", "promote", "promoter", "promotion", "promotional", "prompt",
+		"promulgate", "prone", "prong", "pronoun", "pronounce", "pronouncement", "pronunciation", "proof", "proofread", "prop", "propaganda",
+		"propagandist", "propagate", "propagation", "propane", "propel", "propellant", "propeller", "propensity", "proper", "property",
+		"prophecy", "prophesy", "prophet", "prophetic", "proponent", "proportion", "proportional", "proportionate", "proposal", "propose",
+		"proposition", "proprietary", "proprietor", "propriety", "propulsion", "prosaic", "proscribe", "prose", "prosecute", "prosecution",
+		"prosecutor", "prospect", "prospective", "prospector", "prospectus", "prosper", "prosperity", "prosperous", "prostate", "prostitute",
+		"prostitution", "prostrate", "protagonist", "protect", "protection", "protectionism", "protective", "protector", "protectorate",
+		"protege", "protein", "protest", "protestation", "protester", "protocol", "proton", "prototype", "protract", "protractor", "protrude",
+		"protrusion", "proud", "prove", "proverb", "proverbial", "provide", "providence", "provident", "providential", "provider", "province",
+		"provincial", "provision", "provisional", "proviso", "provocation", "provocative", "provoke", "prow", "prowess", "prowl", "proximity",
+		"proxy", "prude", "prudence", "prudent", "prudish", "prune", "pry", "psalm", "pseudonym", "psyche", "psychiatric", "psychiatrist",
+		"psychiatry", "psychic", "psychoanalysis", "psychoanalyst", "psychological", "psychologist", "psychology", "psychopath", "psychosis",
+		"psychotherapist", "psychotherapy", "psychotic", "pub", "puberty", "pubic", "public", "publican", "publication", "publicity",
+		"publicize", "publish", "publisher", "pudding", "puddle", "puff", "puffin", "puffy", "pull", "pulley", "pullover", "pulp", "pulpit",
+		"pulsate", "pulse", "pulverize", "puma", "pump", "pumpkin", "pun", "punch", "punctilious", "punctual", "punctuality", "punctuate",
+		"punctuation", "puncture", "pundit", "pungent", "punish", "punishment", "punitive", "punk", "punt", "puny", "pup", "pupil", "puppet",
+		"puppy", "purchase", "purchaser", "pure", "puree", "purgatory", "purge", "purification", "purify", "purist", "puritan", "puritanical",
+		"purity", "purple", "purport", "purpose", "purposeful", "purr", "purse", "pursue", "pursuer", "pursuit", "purvey", "purveyor", "pus",
+		"push", "pushchair", "pusher", "pushy", "puss", "pussy", "put", "putrid", "putt", "putty", "puzzle", "pygmy", "pyjamas", "pylon",
+		"pyramid", "pyre", "python", "quack", "quadrangle", "quadrant", "quadrilateral", "quadruped", "quadruple", "quagmire", "quail", "quaint",
+		"quake", "qualification", "qualify", "qualitative", "quality", "qualm", "quandary", "quantify", "quantitative", "quantity", "quantum",
+		"quarantine", "quarrel", "quarrelsome", "quarry", "quart", "quarter", "quarterback", "quarterfinal", "quarterly", "quartet", "quartz",
+		"quash", "quasi", "quaver", "quay", "queasy", "queen", "queer", "quell", "quench", "query", "quest", "question", "questionable",
+		"questionnaire", "queue", "quibble", "quiche", "quick", "quicken", "quicksand", "quid", "quiet", "quieten", "quill", "quilt",
+		"quintessence", "quintessential", "quintet", "quip", "quirk", "quirky", "quit", "quite", "quits", "quiver", "quiz", "quizzical",
+		"quorum", "quota", "quotation", "quote", "quotient", "rabbi", "rabbit", "rabble", "rabid", "rabies", "raccoon", "race", "racecourse",
+		"racehorse", "racer", "racetrack", "racial", "racialism", "racism", "racist", "rack", "racket", "racketeer", "racy", "radar", "radial",
+		"radiance", "radiant", "radiate", "radiation", "radiator", "radical", "radicalism", "radio", "radioactive", "radioactivity",
+		"radiographer", "radiography", "radiology", "radiotherapy", "radish", "radium", "radius", "raffle", "raft", "rafter", "rag", "rage",
+		"ragged", "raid", "raider", "rail", "railing", "railroad", "railway", "rain", "rainbow", "raincoat", "raindrop", "rainfall",
+		"rainforest", "rainstorm", "rainwater", "rainy", "raise", "raisin", "rake", "rally", "ram", "ramble", "rambler", "ramification", "ramp",
+		"rampage", "rampant", "rampart", "ramshackle", "ranch", "rancher", "rancid", "rancor", "rancour", "random", "randy", "range", "ranger",
+		"rank", "rankle", "ransack", "ransom", "rant", "rap", "rape", "rapid", "rapidity", "rapist", "rapport", "rapt", "rapture", "rapturous",
+		"rare", "rarity", "rascal", "rash", "rasher", "rasp", "raspberry", "rat", "rate", "rather", "ratification", "ratify", "rating", "ratio",
+		"ration", "rational", "rationale", "rationalism", "rationalist", "rationality", "rationalization", "rationalize", "rattle",
+		"rattlesnake", "raucous", "ravage", "rave", "raven", "ravenous", "ravine", "ravish", "ravishing", "raw", "ray", "rayon", "raze", "razor",
+		"reach", "react", "reaction", "reactionary", "reactivate", "reactor", "read", "readable", "reader", "readership", "readily", "readiness",
+		"reading", "readjust", "ready", "reaffirm", "real", "realism", "realist", "realistic", "reality", "realization", "realize", "realm",
+		"ream", "reap", "reaper", "reappear", "reappraisal", "rear", "rearguard", "rearm", "rearmament", "rearrange", "reason", "reasonable",
+		"reasoning", "reassemble", "reassess", "reassessment", "reassurance", "reassure", "rebate", "rebel", "rebellion", "rebellious",
+		"rebirth", "reboot", "rebound", "rebuff", "rebuild", "rebuke", "rebut", "rebuttal", "recalcitrant", "recall", "recant", "recap",
+		"recapitulate", "recapture", "recede", "receipt", "receive", "receiver", "receivership", "recent", "receptacle", "reception",
+		"receptionist", "receptive", "recess", "recession", "recharge", "recipe", "recipient", "reciprocal", "reciprocate", "reciprocity",
+		"recital", "recitation", "recite", "reckless", "reckon", "reckoning", "reclaim", "reclamation", "recline", "recluse", "reclusive",
+		"recognition", "recognizable", "recognize", "recoil", "recollect", "recollection", "recommend", "recommendation", "recompense",
+		"reconcile", "reconciliation", "recondite", "recondition", "reconnaissance", "reconnoiter", "reconnoitre", "reconsider",
+		"reconsideration", "reconstitute", "reconstruct", "reconstruction", "record", "recorder", "recount", "recoup", "recourse", "recover",
+		"recovery", "recreate", "recreation", "recreational", "recrimination", "recruit", "recruitment", "rectangle", "rectangular", "rectify",
+		"rectitude", "rector", "rectory", "rectum", "recuperate", "recuperation", "recur", "recurrence", "recurrent", "recycle", "red", "redden",
+		"redeem", "redeemer", "redemption", "redeploy", "redevelop", "redevelopment", "redhead", "redirect", "redistribute", "redistribution",
+		"redness", "redouble", "redoubtable", "redress", "reduce", "reduction", "redundancy", "redundant", "reed", "reef", "reek", "reel",
+		"reelect", "refectory", "refer", "referee", "reference", "referendum", "refill", "refine", "refinement", "refinery", "refit", "reflect",
+		"reflection", "reflective", "reflector", "reflex", "reflexive", "reform", "reformation", "reformer", "refract", "refraction", "refrain",
+		"refresh", "refresher", "refreshment", "refrigerate", "refrigeration", "refrigerator", "refuel", "refuge", "refugee", "refund",
+		"refurbish", "refurbishment", "refusal", "refuse", "refutation", "refute", "regain", "regal", "regale", "regalia", "regard", "regarding",
+		"regardless", "regatta", "regency", "regenerate", "regeneration", "regent", "reggae", "regime", "regiment", "regimental", "region",
+		"regional", "register", "registrar", "registration", "registry", "regress", "regression", "regressive", "regret", "regretful",
+		"regrettable", "regroup", "regular", "regularity", "regulate", "regulation", "regulator", "regulatory", "regurgitate", "rehabilitate",
+		"rehabilitation", "rehash", "rehearsal", "rehearse", "reign", "reimburse", "reimbursement", "rein", "reincarnation", "reindeer",
+		"reinforce", "reinforcement", "reinstate", "reinstatement", "reinvest", "reissue", "reiterate", "reiteration", "reject", "rejection",
+		"rejoice", "rejoin", "rejoinder", "rejuvenate", "rekindle", "relapse", "relate", "relation", "relationship", "relative", "relativity",
+		"relax", "relaxation", "relay", "release", "relegate", "relegation", "relent", "relentless", "relevance", "relevant", "reliability",
+		"reliable", "reliance", "reliant", "relic", "relief", "relieve", "religion", "religious", "relinquish", "relish", "relive", "relocate",
+		"relocation", "reluctance", "reluctant", "rely", "remain", "remainder", "remains", "remake", "remand", "remark", "remarkable", "remarry",
+		"remedial", "remedy", "remember", "remembrance", "remind", "reminder", "reminisce", "reminiscence", "reminiscent", "remiss", "remission",
+		"remit", "remittance", "remnant", "remodel", "remonstrate", "remorse", "remorseful", "remorseless", "remote", "removable", "removal",
+		"remove", "remunerate", "remuneration", "renaissance", "renal", "rename", "rend", "render", "rendezvous", "rendition", "renegade",
+		"renege", "renew", "renewable", "renewal", "renounce", "renovate", "renovation", "renown", "rent", "rental", "renunciation", "reopen",
+		"reorganization", "reorganize", "rep", "repaid", "repair", "repairman", "reparation", "repartee", "repatriate", "repatriation", "repay",
+		"repayment", "repeal", "repeat", "repeatedly", "repel", "repellent", "repent", "repentance", "repentant", "repercussion", "repertoire",
+		"repertory", "repetition", "repetitious", "repetitive", "rephrase", "replace", "replacement", "replay", "replenish", "replete",
+		"replica", "replicate", "replication", "reply", "report", "reportage", "reporter", "repose", "repository", "repossess", "reprehensible",
+		"represent", "representation", "representative", "repress", "repression", "repressive", "reprieve", "reprimand", "reprint", "reprisal",
+		"reproach", "reproachful", "reprobate", "reproduce", "reproduction", "reproductive", "reproof", "reprove", "reptile", "reptilian",
+		"republic", "republican", "repudiate", "repudiation", "repugnance", "repugnant", "repulse", "repulsion", "repulsive", "reputable",
+		"reputation", "repute", "request", "requiem", "require", "requirement", "requisite", "requisition", "rescind", "rescue", "rescuer",
+		"research", "researcher", "resemblance", "resemble", "resent", "resentful", "resentment", "reservation", "reserve", "reservoir",
+		"reshuffle", "reside", "residence", "resident", "residential", "residual", "residue", "resign", "resignation", "resilience", "resilient",
+		"resin", "resist", "resistance", "resistant", "resistor", "resolute", "resolution", "resolve", "resonance", "resonant", "resonate",
+		"resort", "resound", "resource", "resourceful", "respect", "respectability", "respectable", "respectful", "respective", "respiration",
+		"respirator", "respiratory", "respite", "resplendent", "respond", "respondent", "response", "responsibility", "responsible",
+		"responsive", "rest", "restaurant", "restaurateur", "restful", "restitution", "restive", "restless", "restoration", "restorative",
+		"restore", "restrain", "restraint", "restrict", "restriction", "restrictive", "restructure", "result", "resultant", "resume",
+		"resumption", "resurface", "resurgence", "resurgent", "resurrect", "resurrection", "resuscitate", "resuscitation", "retail", "retailer",
+		"retain", "retainer", "retake", "retaliate", "retaliation", "retaliatory", "retard", "retch", "retention", "retentive", "rethink",
+		"reticence", "reticent", "retina", "retinue", "retire", "retiree", "retirement", "retort", "retouch", "retrace", "retract", "retraction",
+		"retreat", "retrench", "retrenchment", "retrial", "retribution", "retrieval", "retrieve", "retriever", "retroactive", "retrograde",
+		"retrospect", "retrospective", "return", "reunification", "reunion", "reunite", "reuse", "rev", "revamp", "reveal", "revel",
+		"revelation", "reveler", "reveller", "revelry", "revenge", "revenue", "reverberate", "reverberation", "revere", "reverence", "reverend",
+		"reverent", "reverie", "reversal", "reverse", "reversible", "revert", "review", "reviewer", "revile", "revise", "revision",
+		"revisionism", "revitalize", "revival", "revive", "revoke", "revolt", "revolution", "revolutionary", "revolutionize", "revolve",
+		"revolver", "revue", "revulsion", "reward", "rewind", "rewrite", "rhapsody", "rhetoric", "rhetorical", "rheumatic", "rheumatism",
+		"rhino", "rhinoceros", "rhododendron", "rhombus", "rhubarb", "rhyme", "rhythm", "rhythmic", "rib", "ribald", "ribbon", "rice", "rich",
+		"riches", "rickety", "rickshaw", "ricochet", "rid", "riddance", "riddle", "ride", "rider", "ridge", "ridicule", "ridiculous", "rife",
+		"rifle", "rift", "rig", "rigging", "right", "righteous", "rightful", "rigid", "rigidity", "rigmarole", "rigor", "rigorous", "rigour",
+		"rile", "rim", "rind", "ring", "ringleader", "ringlet", "rink", "rinse", "riot", "rioter", "riotous", "rip", "ripe", "ripen", "ripple",
+		"rise", "risk", "risky", "risotto", "rite", "ritual", "ritualistic", "rival", "rivalry", "river", "riverside", "rivet", "road",
+		"roadblock", "roadside", "roadway", "roam", "roar", "roast", "rob", "robber", "robbery", "robe", "robin", "robot", "robotic", "robotics",
+		"robust", "rock", "rocker", "rocket", "rocky", "rod", "rodent", "rodeo", "roe", "rogue", "roguish", "role", "roll", "roller", "romance",
+		"romantic", "romanticism", "romanticize", "romp", "roof", "rook", "rookie", "room", "roommate", "roomy", "roost", "rooster", "root",
+		"rope", "rosary", "rose", "rosemary", "rosette", "roster", "rostrum", "rosy", "rot", "rota", "rotary", "rotate", "rotation", "rote",
+		"rotor", "rotten", "rotund", "rouge", "rough", "roughage", "roughen", "roulette", "round", "roundabout", "rounders", "rouse", "rout",
+		"route", "routine", "rove", "rover", "row", "rowdy", "rowing", "royal", "royalist", "royalty", "rub", "rubber", "rubbish", "rubble",
+		"rubric", "ruby", "rucksack", "rudder", "ruddy", "rude", "rudiment", "rudimentary", "rue", "rueful", "ruff", "ruffian", "ruffle", "rug",
+		"rugby", "rugged", "ruin", "ruinous", "rule", "ruler", "ruling", "rum", "rumble", "ruminate", "rummage", "rumor", "rumour", "rump",
+		"rumple", "run", "runaway", "rung", "runner", "runway", "rupture", "rural", "ruse", "rush", "rust", "rustic", "rustle", "rusty", "rut",
+		"ruthless", "rye", "sabbath", "sabbatical", "saber", "sabotage", "saboteur", "sabre", "sac", "saccharin", "sachet", "sack", "sacrament",
+		"sacred", "sacrifice", "sacrificial", "sacrilege", "sacrilegious", "sad", "sadden", "saddle", "sadism", "sadist", "sadistic", "safari",
+		"safe", "safeguard", "safety", "saffron", "sag", "saga", "sagacious", "sagacity", "sage", "sail", "sailboat", "sailor", "saint",
+		"saintly", "sake", "salad", "salami", "salary", "sale", "saleable", "salesman", "salesperson", "saleswoman", "salient", "saline",
+		"saliva", "salivate", "sallow", "sally", "salmon", "salon", "saloon", "salt", "salty", "salutary", "salutation", "salute", "salvage",
+		"salvation", "salve", "same", "sample", "sanatorium", "sanctify", "sanctimonious", "sanction", "sanctity", "sanctuary", "sand", "sandal",
+		"sandbag", "sandpaper", "sandstone", "sandwich", "sandy", "sane", "sanitary", "sanitation", "sanity", "sap", "sapling", "sapphire",
+		"sarcasm", "sarcastic", "sardine", "sardonic", "sari", "sash", "satanic", "satchel", "satellite", "satin", "satire", "satirical",
+		"satirist", "satirize", "satisfaction", "satisfactory", "satisfy", "saturate", "saturation", "saturday", "sauce", "saucepan", "saucer",
+		"saucy", "sauna", "saunter", "sausage", "savage", "savagery", "savannah", "save", "saver", "saving", "savings", "savior", "saviour",
+		"savor", "savory", "savour", "savoury", "saw", "sawdust", "saxophone", "say", "saying", "scab", "scaffold", "scaffolding", "scald",
+		"scale", "scallop", "scalp", "scalpel", "scamper", "scan", "scandal", "scandalize", "scandalous", "scanner", "scant", "scanty",
+		"scapegoat", "scar", "scarce", "scarcity", "scare", "scarecrow", "scarf", "scarlet", "scary", "scathing", "scatter", "scavenge",
+		"scavenger", "scenario", "scene", "scenery", "scenic", "scent", "sceptic", "sceptical", "scepticism", "sceptre", "schedule", "schema",
+		"schematic", "scheme", "schism", "schizophrenia", "schizophrenic", "scholar", "scholarly", "scholarship", "scholastic", "school",
+		"schoolboy", "schoolchild", "schoolgirl", "schooling", "schoolmaster", "schoolteacher", "schooner", "science", "scientific", "scientist",
+		"scintillating", "scissors", "scoff", "scold", "scone", "scoop", "scooter", "scope", "scorch", "score", "scoreboard", "scorer", "scorn",
+		"scornful", "scorpion", "scoundrel", "scour", "scourge", "scout", "scowl", "scrabble", "scramble", "scrap", "scrapbook", "scrape",
+		"scratch", "scrawl", "scrawny", "scream", "screech", "screen", "screenplay", "screw", "screwdriver", "scribble", "scribe", "script",
+		"scripture", "scroll", "scrounge", "scrub", "scruff", "scruffy", "scruple", "scrupulous", "scrutinize", "scrutiny", "scuba", "scuff",
+		"scuffle", "sculpt", "sculptor", "sculpture", "scum", "scurrilous", "scurry", "scuttle", "scythe", "sea", "seabed", "seafaring",
+		"seafood", "seagull", "seal", "seam", "seaman", "seamless", "seance", "sear", "search", "searchlight", "seashell", "seashore", "seasick",
+		"seaside", "season", "seasonal", "seasoning", "seat", "seaweed", "secede", "secession", "secluded", "seclusion", "second", "secondary",
+		"secrecy", "secret", "secretarial", "secretariat", "secretary", "secrete", "secretion", "secretive", "sect", "sectarian", "section",
+		"sector", "secular", "secularism", "secure", "security", "sedan", "sedate", "sedation", "sedative", "sedentary", "sediment",
+		"sedimentary", "sedition", "seditious", "seduce", "seduction", "seductive", "see", "seed", "seedling", "seedy", "seek", "seem",
+		"seemingly", "seep", "seesaw", "seethe", "segment", "segregate", "segregation", "seismic", "seize", "seizure", "seldom", "select",
+		"selection", "selective", "self", "selfish", "selfless", "sell", "seller", "semantic", "semantics", "semblance", "semen", "semester",
+		"semicircle", "semicolon", "semiconductor", "semifinal", "seminar", "seminary", "senate", "senator", "send", "senile", "senility",
+		"senior", "seniority", "sensation", "sensational", "sensationalism", "sense", "senseless", "sensibility", "sensible", "sensitive",
+		"sensitivity", "sensor", "sensory", "sensual", "sensuality", "sensuous", "sentence", "sentiment", "sentimental", "sentimentality",
+		"sentinel", "sentry", "separable", "separate", "separation", "separatism", "separatist", "september", "septic", "sequel", "sequence",
+		"sequential", "sequin", "serenade", "serene", "serenity", "serf", "serge", "sergeant", "serial", "serialize", "series", "serious",
+		"sermon", "serpent", "serrated", "serum", "servant", "serve", "server", "service", "serviceable", "serviceman", "serviette", "servile",
+		"servitude", "session", "set", "setback", "settee", "setting", "settle", "settlement", "settler", "seven", "seventeen", "seventh",
+		"seventy", "sever", "several", "severance", "severe", "severity", "sew", "sewage", "sewer", "sex", "sexism", "sexist", "sextet",
+		"sexual", "sexuality", "sexy", "shabby", "shack", "shackle", "shade", "shadow", "shadowy", "shady", "shaft", "shaggy", "shake", "shaky",
+		"shall", "shallow", "sham", "shambles", "shame", "shameful", "shameless", "shampoo", "shamrock", "shandy", "shanty", "shape",
+		"shapeless", "shapely", "share", "shareholder", "shark", "sharp", "sharpen", "sharpener", "shatter", "shave", "shaver", "shawl", "she",
+		"sheaf", "shear", "shears", "sheath", "shed", "sheen", "sheep", "sheepish", "sheer", "sheet", "sheikh", "shelf", "shell", "shellfish",
+		"shelter", "shelve", "shepherd", "sherbet", "sheriff", "sherry", "shield", "shift", "shifty", "shilling", "shimmer", "shin", "shine",
+		"shingle", "shiny", "ship", "shipbuilding", "shipment", "shipping", "shipwreck", "shipyard", "shirk", "shirt", "shiver", "shoal",
+		"shock", "shoddy", "shoe", "shoelace", "shoot", "shop", "shopkeeper", "shoplift", "shoplifter", "shopper", "shopping", "shore", "short",
+		"shortage", "shortbread", "shortcoming", "shortcut", "shorten", "shortfall", "shorthand", "shortlist", "shorts", "shot", "shotgun",
+		"should", "shoulder", "shout", "shove", "shovel", "show", "showcase", "showdown", "shower", "showman", "showroom", "showy", "shrapnel",
+		"shred", "shrew", "shrewd", "shriek", "shrill", "shrimp", "shrine", "shrink", "shrivel", "shroud", "shrub", "shrubbery", "shrug",
+		"shudder", "shuffle", "shun", "shunt", "shut", "shutter", "shuttle", "shy", "sibling", "sick", "sicken", "sickle", "sickly", "sickness",
+		"side", "sideboard", "sideline", "sidelong", "sideshow", "sidestep", "sidetrack", "sidewalk", "sideways", "siding", "sidle", "siege",
+		"siesta", "sieve", "sift", "sigh", "sight", "sightseeing", "sign", "signal", "signatory", "signature", "significance", "significant",
+		"signify", "signpost", "silence", "silencer", "silent", "silhouette", "silicon", "silk", "silken", "silkworm", "silky", "sill", "silly",
+		"silt", "silver", "silversmith", "silverware", "silvery", "similar", "similarity", "simile", "simmer", "simple", "simplicity",
+		"simplification", "simplify", "simplistic", "simply", "simulate", "simulation", "simulator", "simultaneous", "sin", "since", "sincere",
+		"sincerity", "sinew", "sinful", "sing", "singe", "singer", "single", "singular", "singularity", "sinister", "sink", "sinner", "sinuous",
+		"sinus", "sip", "siphon", "sir", "siren", "sirloin", "sissy", "sister", "sisterhood", "sit", "site", "sitter", "situate", "situation",
+		"six", "sixteen", "sixth", "sixty", "sizable", "size", "sizeable", "sizzle", "skate", "skateboard", "skater", "skeletal", "skeleton",
+		"sketch", "sketchy", "skew", "skewer", "ski", "skid", "skier", "skilful", "skill", "skillet", "skillful", "skim", "skimp", "skin",
+		"skinny", "skip", "skipper", "skirmish", "skirt", "skit", "skittle", "skull", "skunk", "sky", "skylight", "skyline", "skyscraper",
+		"slab", "slack", "slacken", "slacks", "slag", "slam", "slander", "slanderous", "slang", "slant", "slap", "slapdash", "slapstick",
+		"slash", "slat", "slate", "slaughter", "slaughterhouse", "slave", "slavery", "slavish", "slay", "sleazy", "sled", "sledge",
+		"sledgehammer", "sleek", "sleep", "sleeper", "sleepless", "sleepy", "sleet", "sleeve", "sleigh", "slender", "sleuth", "slice", "slick",
+		"slide", "slight", "slim", "slime", "slimy", "sling", "slink", "slip", "slipper", "slippery", "slit", "slither", "sliver", "slob",
+		"slog", "slogan", "slop", "slope", "sloppy", "slot", "sloth", "slouch", "slovenly", "slow", "slowdown", "sludge", "slug", "sluggish",
+		"sluice", "slum", "slumber", "slump", "slur", "slush", "sly", "smack", "small", "smallpox", "smart", "smarten", "smash", "smattering",
+		"smear", "smell", "smelly", "smile", "smirk", "smith", "smock", "smog", "smoke", "smoker", "smokescreen", "smoky", "smolder", "smooth",
+		"smother", "smoulder", "smudge", "smug", "smuggle", "smuggler", "snack", "snag", "snail", "snake", "snap", "snapshot", "snare", "snarl",
+		"snatch", "sneak", "sneaker", "sneer", "sneeze", "snide", "sniff", "sniffle", "snigger", "snip", "snipe", "sniper", "snippet", "snob",
+		"snobbery", "snobbish", "snooker", "snoop", "snooze", "snore", "snorkel", "snort", "snout", "snow", "snowball", "snowdrift", "snowfall",
+		"snowflake", "snowman", "snowstorm", "snowy", "snub", "snuff", "snug", "snuggle", "so", "soak", "soap", "soapy", "soar", "sob", "sober",
+		"sobriety", "soccer", "sociable", "social", "socialism", "socialist", "socialite", "socialize", "society", "sociological", "sociologist",
+		"sociology", "sock", "socket", "sod", "soda", "sodden", "sodium", "sofa", "soft", "soften", "software", "soggy", "soil", "solace",
+		"solar", "solder", "soldier", "sole", "solemn", "solemnity", "solicit", "solicitor", "solicitous", "solid", "solidarity", "solidify",
+		"solidity", "soliloquy", "solitary", "solitude", "solo", "soloist", "solstice", "soluble", "solution", "solve", "solvent", "somber",
+		"sombre", "some", "somebody", "someday", "somehow", "someone", "someplace", "somersault", "something", "sometime", "sometimes",
+		"somewhat", "somewhere", "son", "sonar", "sonata", "song", "songwriter", "sonic", "sonnet", "soon", "soot", "soothe", "sophisticate",
+		"sophisticated", "sophistication", "soporific", "soprano", "sorcerer", "sorcery", "sordid", "sore", "sorrow", "sorrowful", "sorry",
+		"sort", "soul", "soulful", "sound", "soundproof", "soundtrack", "soup", "sour", "source", "south", "southeast", "southerly", "southern",
+		"southward", "southwest", "souvenir", "sovereign", "sovereignty", "sow", "soy", "soya", "spa", "space", "spacecraft", "spaceship",
+		"spacious", "spade", "spaghetti", "span", "spaniel", "spank", "spanner", "spar", "spare", "spark", "sparkle", "sparrow", "sparse",
+		"spartan", "spasm", "spasmodic", "spate", "spatial", "spatter", "spawn", "speak", "speaker", "spear", "spearhead", "special",
+		"specialist", "speciality", "specialization", "specialize", "specialty", "species", "specific", "specification", "specify", "specimen",
+		"specious", "speck", "speckle", "spectacle", "spectacular", "spectator", "specter", "spectre", "spectrum", "speculate", "speculation",
+		"speculative", "speculator", "speech", "speechless", "speed", "speedboat", "speedometer", "speedy", "spell", "spellbound", "spelling",
+		"spend", "spender", "spendthrift", "sperm", "spew", "sphere", "spherical", "sphinx", "spice", "spicy", "spider", "spike", "spiky",
+		"spill", "spin", "spinach", "spinal", "spindle", "spine", "spineless", "spinster", "spiral", "spire", "spirit", "spiritual",
+		"spiritualism", "spit", "spite", "spiteful", "splash", "splatter", "spleen", "splendid", "splendor", "splendour", "splice", "splint",
+		"splinter", "split", "splutter", "spoil", "spoke", "spokesman", "spokesperson", "spokeswoman", "sponge", "sponsor", "sponsorship",
+		"spontaneous", "spoof", "spooky", "spool", "spoon", "spoonful", "sporadic", "spore", "sport", "sporting", "sportsman", "sportsmanship",
+		"sporty", "spot", "spotless", "spotlight", "spotty", "spouse", "spout", "sprain", "sprawl", "spray", "spread", "spreadsheet", "spree",
+		"sprig", "sprightly", "spring", "springboard", "springtime", "sprinkle", "sprinkler", "sprint", "sprinter", "sprout", "spruce", "spry",
+		"spur", "spurious", "spurn", "spurt", "sputter", "spy", "squabble", "squad", "squadron", "squalid", "squall", "squalor", "squander",
+		"square", "squash", "squat", "squatter", "squawk", "squeak", "squeal", "squeamish", "squeeze", "squelch", "squid", "squiggle", "squint",
+		"squire", "squirm", "squirrel", "squirt", "stab", "stability", "stabilize", "stable", "stack", "stadium", "staff", "stag", "stage",
+		"stagger", "stagnant", "stagnate", "stagnation", "staid", "stain", "stainless", "stair", "staircase", "stairway", "stake", "stale",
+		"stalemate", "stalk", "stall", "stallion", "stalwart", "stamina", "stammer", "stamp", "stampede", "stance", "stand", "standard",
+		"standardize", "standby", "standpoint", "standstill", "stanza", "staple", "stapler", "star", "starboard", "starch", "stardom", "stare",
+		"stark", "starlight", "starling", "starry", "start", "starter", "startle", "starvation", "starve", "stash", "state", "stateless",
+		"stately", "statement", "statesman", "statesmanship", "static", "station", "stationary", "stationer", "stationery", "statistic",
+		"statistical", "statistician", "statistics", "statue", "statuesque", "stature", "status", "statute", "statutory", "staunch", "stave",
+		"stay", "stead", "steadfast", "steady", "steak", "steal", "stealth", "stealthy", "steam", "steamer", "steamroller", "steamship",
+		"steamy", "steel", "steep", "steeple", "steer", "stellar", "stem", "stench", "stencil", "step", "stepbrother", "stepchild",
+		"stepdaughter", "stepfather", "stepmother", "stepparent", "stepsister", "stepson", "stereo", "stereotype", "sterile", "sterility",
+		"sterilize", "sterling", "stern", "steroid", "stethoscope", "stew", "steward", "stewardess", "stick", "sticker", "sticky", "stiff",
+		"stiffen", "stifle", "stigma", "stigmatize", "stile", "stiletto", "still", "stillborn", "stilt", "stilted", "stimulant", "stimulate",
+		"stimulation", "stimulus", "sting", "stingy", "stink", "stint", "stipend", "stipulate", "stipulation", "stir", "stirrup", "stitch",
+		"stoat", "stock", "stockbroker", "stockholder", "stocking", "stockpile", "stocky", "stodgy", "stoic", "stoical", "stoicism", "stoke",
+		"stole", "stolid", "stomach", "stomp", "stone", "stony", "stool", "stoop", "stop", "stopgap", "stoppage", "stopper", "stopwatch",
+		"storage", "store", "storeroom", "storey", "stork", "storm", "stormy", "story", "stout", "stove", "stow", "stowaway", "straddle",
+		"straggle", "straggler", "straight", "straighten", "straightforward", "strain", "strainer", "strait", "straitjacket", "strand",
+		"strange", "stranger", "strangle", "stranglehold", "strangulation", "strap", "strapping", "strata", "stratagem", "strategic",
+		"strategist", "strategy", "stratify", "stratosphere", "stratum", "straw", "strawberry", "stray", "streak", "stream", "streamer",
+		"streamline", "street", "strength", "strengthen", "strenuous", "stress", "stressful", "stretch", "stretcher", "strew", "stricken",
+		"strict", "stricture", "stride", "strident", "strife", "strike", "striker", "string", "stringent", "strip", "stripe", "stripper",
+		"strive", "stroke", "stroll", "stroller", "strong", "stronghold", "structural", "structure", "struggle", "strum", "strut", "stub",
+		"stubble", "stubborn", "stucco", "stud", "student", "studio", "studious", "study", "stuff", "stuffing", "stuffy", "stumble", "stump",
+		"stun", "stunt", "stupefy", "stupendous", "stupid", "stupidity", "stupor", "sturdy", "stutter", "sty", "style", "stylish", "stylist",
+		"stylistic", "stylize", "stylus", "suave", "subconscious", "subcontinent", "subcontract", "subculture", "subdivide", "subdivision",
+		"subdue", "subject", "subjection", "subjective", "subjugate", "subjugation", "subjunctive", "sublet", "sublime", "subliminal",
+		"submarine", "submerge", "submission", "submissive", "submit", "subordinate", "subordination", "subpoena", "subscribe", "subscriber",
+		"subscription", "subsequent", "subservient", "subside", "subsidence", "subsidiary", "subsidize", "subsidy", "subsist", "subsistence",
+		"substance", "substantial", "substantiate", "substantive", "substitute", "substitution", "subsume", "subterfuge", "subterranean",
+		"subtitle", "subtle", "subtlety", "subtract", "subtraction", "suburb", "suburban", "suburbia", "subversion", "subversive", "subvert",
+		"subway", "succeed", "success", "successful", "succession", "successive", "successor", "succinct", "succor", "succour", "succulent",
+		"succumb", "such", "suck", "sucker", "suckle", "suction", "sudden", "suds", "sue", "suede", "suffer", "sufferance", "sufferer",
+		"suffering", "suffice", "sufficiency", "sufficient", "suffix", "suffocate", "suffocation", "suffrage", "suffuse", "sugar", "suggest",
+		"suggestion", "suggestive", "suicidal", "suicide", "suit", "suitable", "suitcase", "suite", "suitor", "sulfur", "sulk", "sulky",
+		"sullen", "sully", "sulphur", "sultan", "sultry", "sum", "summarize", "summary", "summation", "summer", "summertime", "summit", "summon",
+		"summons", "sumptuous", "sun", "sunbathe", "sunbeam", "sunburn", "sunday", "sundial", "sundry", "sunflower", "sunglasses", "sunken",
+		"sunlight", "sunny", "sunrise", "sunset", "sunshine", "sunstroke", "suntan", "super", "superb", "supercilious", "superconduct",
+		"superficial", "superfluous", "superhuman", "superimpose", "superintend", "superintendent", "superior", "superiority", "superlative",
+		"superman", "supermarket", "supernatural", "superpower", "supersede", "supersonic", "superstar", "superstition", "superstitious",
+		"superstructure", "supervise", "supervision", "supervisor", "supervisory", "supper", "supplant", "supple", "supplement", "supplementary",
+		"supplier", "supply", "support", "supporter", "supportive", "suppose", "supposedly", "supposition", "suppress", "suppression",
+		"supremacy", "supreme", "surcharge", "sure", "surety", "surf", "surface", "surfboard", "surfeit", "surfer", "surge", "surgeon",
+		"surgery", "surgical", "surly", "surmise", "surmount", "surname", "surpass", "surplus", "surprise", "surreal", "surrealism", "surrender",
+		"surreptitious", "surrogate", "surround", "surroundings", "surveillance", "survey", "surveyor", "survival", "survive", "survivor",
+		"susceptible", "suspect", "suspend", "suspender", "suspense", "suspension", "suspicion", "suspicious", "sustain", "sustainable",
+		"sustenance", "swab", "swagger", "swallow", "swamp", "swan", "swap", "swarm", "swarthy", "swat", "sway", "swear", "sweat", "sweater",
+		"sweatshirt", "sweaty", "sweep", "sweeper", "sweet", "sweeten", "sweetener", "sweetheart", "swell", "swelter", "swerve", "swift", "swig",
+		"swill", "swim", "swimmer", "swimsuit", "swindle", "swindler", "swine", "swing", "swipe", "swirl", "swish", "switch", "switchboard",
+		"swivel", "swoon", "swoop", "sword", "swordfish", "swot", "sycamore", "sycophant", "syllable", "syllabus", "symbol", "symbolic",
+		"symbolism", "symbolize", "symmetrical", "symmetry", "sympathetic", "sympathize", "sympathizer", "sympathy", "symphony", "symposium",
+		"symptom", "symptomatic", "synagogue", "synchronize", "syndicate", "syndrome", "synonym", "synonymous", "synopsis", "syntactic",
+		"syntax", "synthesis", "synthesize", "synthesizer", "synthetic", "syphilis", "syringe", "syrup", "system", "systematic", "tab", "tabby",
+		"table", "tableau", "tablecloth", "tablespoon", "tablet", "tabloid", "taboo", "tabulate", "tacit", "taciturn", "tack", "tackle", "tacky",
+		"tact", "tactful", "tactic", "tactical", "tactician", "tactics", "tactile", "tactless", "tadpole", "tag", "tail", "tailback", "tailor",
+		"taint", "take", "takeaway", "takeoff", "takeover", "taker", "talc", "tale", "talent", "talisman", "talk", "talkative", "tall", "tallow",
+		"tally", "talon", "tambourine", "tame", "tamper", "tampon", "tan", "tandem", "tang", "tangent", "tangerine", "tangible", "tangle",
+		"tango", "tank", "tankard", "tanker", "tannery", "tannin", "tantalize", "tantamount", "tantrum", "tap", "tape", "taper", "tapestry",
+		"tapioca", "tar", "tardy", "target", "tariff", "tarmac", "tarnish", "tarpaulin", "tarry", "tart", "tartan", "task", "tassel", "taste",
+		"tasteful", "tasteless", "tasty", "tattoo", "taunt", "taut", "tavern", "tawdry", "tawny", "tax", "taxable", "taxation", "taxi",
+		"taxidermy", "taxpayer", "tea", "teach", "teacher", "teak", "team", "teammate", "teamwork", "teapot", "tear", "tearful", "tease",
+		"teaspoon", "teat", "technical", "technicality", "technician", "technique", "technological", "technology", "teddy", "tedious", "tedium",
+		"teem", "teenage", "teenager", "teeter", "teetotal", "telecommunications", "telegram", "telegraph", "telepathic", "telepathy",
+		"telephone", "telescope", "televise", "television", "tell", "teller", "telltale", "temerity", "temper", "temperament", "temperamental",
+		"temperance", "temperate", "temperature", "tempest", "tempestuous", "template", "temple", "tempo", "temporal", "temporary", "tempt",
+		"temptation", "ten", "tenable", "tenacious", "tenacity", "tenancy", "tenant", "tend", "tendency", "tender", "tendon", "tendril",
+		"tenement", "tenet", "tennis", "tenor", "tense", "tension", "tent", "tentacle", "tentative", "tenth", "tenuous", "tenure", "tepid",
+		"term", "terminal", "terminate", "termination", "terminology", "terminus", "termite", "terrace", "terrain", "terrestrial", "terrible",
+		"terrier", "terrific", "terrify", "territorial", "territory", "terror", "terrorism", "terrorist", "terrorize", "terse", "tertiary",
+		"test", "testament", "testicle", "testify", "testimonial", "testimony", "tether", "text", "textbook", "textile", "textual", "texture",
+		"than", "thank", "thankful", "thankless", "thanksgiving", "that", "thatch", "thaw", "the", "theater", "theatre", "theatrical", "thee",
+		"theft", "their", "theirs", "them", "theme", "themselves", "then", "thence", "theologian", "theological", "theology", "theorem",
+		"theoretical", "theorist", "theorize", "theory", "therapeutic", "therapist", "therapy", "there", "thereabouts", "thereafter", "thereby",
+		"therefore", "therein", "thereof", "thereupon", "thermal", "thermometer", "thermostat", "thesaurus", "these", "thesis", "they", "thick",
+		"thicken", "thicket", "thief", "thigh", "thimble", "thin", "thing", "think", "thinker", "third", "thirst", "thirsty", "thirteen",
+		"thirty", "this", "thistle", "thorn", "thorny", "thorough", "thoroughbred", "thoroughfare", "those", "thou", "though", "thought",
+		"thoughtful", "thoughtless", "thousand", "thousandth", "thrash", "thread", "threadbare", "threat", "threaten", "three", "threshold",
+		"thrice", "thrift", "thrifty", "thrill", "thriller", "thrive", "throat", "throb", "throes", "throne", "throng", "throttle", "through",
+		"throughout", "throw", "throwback", "thrush", "thrust", "thud", "thug", "thumb", "thump", "thunder", "thunderbolt", "thunderous",
+		"thunderstorm", "thursday", "thus", "thwart", "thy", "thyme", "thyroid", "tiara", "tick", "ticket", "tickle", "ticklish", "tidal",
+		"tide", "tidy", "tie", "tier", "tiger", "tight", "tighten", "tightrope", "tights", "tile", "till", "tilt", "timber", "time", "timeless",
+		"timely", "timer", "timetable", "timid", "timidity", "tin", "tinder", "tinge", "tingle", "tinker", "tinkle", "tinsel", "tint", "tiny",
+		"tip", "tipple", "tipsy", "tiptoe", "tirade", "tire", "tireless", "tiresome", "tissue", "tit", "titanic", "titbit", "tithe", "titillate",
+		"title", "titter", "toad", "toadstool", "toast", "toaster", "tobacco", "tobacconist", "toboggan", "today", "toddle", "toddler", "toe",
+		"toenail", "toffee", "together", "toil", "toilet", "toiletries", "token", "tolerable", "tolerance", "tolerant", "tolerate", "toleration",
+		"toll", "tomato", "tomb", "tombstone", "tomorrow", "ton", "tonal", "tone", "tongs", "tongue", "tonic", "tonight", "tonnage", "tonne",
+		"tonsil", "tonsillitis", "too", "tool", "tooth", "toothache", "toothbrush", "toothpaste", "top", "topaz", "topic", "topical",
+		"topography", "topple", "torch", "torment", "tormentor", "tornado", "torpedo", "torrent", "torrential", "torrid", "torso", "tortoise",
+		"tortuous", "torture", "torturer", "toss", "tot", "total", "totalitarian", "totalitarianism", "totality", "tote", "totem", "totter",
+		"touch", "touchdown", "touchstone", "touchy", "tough", "toughen", "toupee", "tour", "tourism", "tourist", "tournament", "tousle", "tout",
+		"tow", "toward", "towards", "towel", "tower", "town", "township", "toxic", "toxin", "toy", "trace", "traceable", "track", "tract",
+		"traction", "tractor", "trade", "trademark", "trader", "tradesman", "tradition", "traditional", "traditionalist", "traffic",
+		"trafficker", "tragedy", "tragic", "trail", "trailer", "train", "trainee", "trainer", "training", "trait", "traitor", "trajectory",
+		"tram", "tramp", "trample", "trampoline", "trance", "tranquil", "tranquility", "tranquilizer", "tranquillity", "tranquillizer",
+		"transact", "transaction", "transatlantic", "transcend", "transcendence", "transcendent", "transcribe", "transcript", "transcription",
+		"transfer", "transferable", "transfigure", "transform", "transformation", "transformer", "transfusion", "transgress", "transgression",
+		"transient", "transistor", "transit", "transition", "transitional", "transitive", "transitory", "translate", "translation", "translator",
+		"translucent", "transmission", "transmit", "transmitter", "transparency", "transparent", "transpire", "transplant", "transport",
+		"transportation", "transpose", "transverse", "transvestite", "trap", "trapdoor", "trapeze", "trappings", "trash", "trauma", "traumatic",
+		"travel", "traveler", "traveller", "traverse", "travesty", "trawl", "trawler", "tray", "treacherous", "treachery", "treacle", "tread",
+		"treadmill", "treason", "treasonable", "treasure", "treasurer", "treasury", "treat", "treatise", "treatment", "treaty", "treble", "tree",
+		"trek", "trellis", "tremble", "tremendous", "tremor", "trench", "trenchant", "trend", "trendy", "trepidation", "trespass", "trespasser",
+		"trestle", "trial", "triangle", "triangular", "tribal", "tribe", "tribulation", "tribunal", "tributary", "tribute", "trick", "trickery",
+		"trickle", "tricky", "tricycle", "trident", "trifle", "trigger", "trigonometry", "trill", "trillion", "trilogy", "trim", "trinity",
+		"trinket", "trio", "trip", "tripe", "triple", "triplet", "tripod", "trite", "triumph", "triumphal", "triumphant", "trivia", "trivial",
+		"triviality", "trolley", "trombone", "troop", "trooper", "trophy", "tropic", "tropical", "trot", "trouble", "troublemaker",
+		"troublesome", "trough", "trounce", "troupe", "trousers", "trout", "trowel", "truancy", "truant", "truce", "truck", "trudge", "true",
+		"truffle", "truism", "truly", "trump", "trumpet", "truncate", "truncheon", "trundle", "trunk", "truss", "trust", "trustee",
+		"trustworthy", "truth", "truthful", "try", "tub", "tuba", "tube", "tuberculosis", "tubular", "tuck", "tuesday", "tuft", "tug", "tuition",
+		"tulip", "tumble", "tumbler", "tummy", "tumor", "tumour", "tumult", "tumultuous", "tuna", "tune", "tuneful", "tunic", "tunnel", "turban",
+		"turbine", "turbulence", "turbulent", "turf", "turkey", "turmoil", "turn", "turnip", "turnout", "turnover", "turnpike", "turnstile",
+		"turntable", "turpentine", "turquoise", "turret", "turtle", "tusk", "tussle", "tutor", "tutorial", "tuxedo", "twang", "tweak", "tweed",
+		"tweezers", "twelfth", "twelve", "twentieth", "twenty", "twice", "twiddle", "twig", "twilight", "twin", "twine", "twinge", "twinkle",
+		"twirl", "twist", "twit", "twitch", "twitter", "two", "tycoon", "type", "typewriter", "typhoid", "typhoon", "typhus", "typical",
+		"typify", "typist", "tyrannical", "tyrannize", "tyranny", "tyrant", "tyre", "ubiquitous", "udder", "ugly", "ulcer", "ulterior",
+		"ultimate", "ultimatum", "ultrasonic", "ultraviolet", "umbilical", "umbrella", "umpire", "unable", "unanimous", "unarmed", "unassuming",
+		"unaware", "unawares", "unbearable", "unbeatable", "unbelievable", "unbridled", "uncanny", "uncertain", "uncertainty", "uncle",
+		"unclean", "uncomfortable", "uncommon", "unconditional", "unconscious", "uncouth", "uncover", "unction", "unctuous", "undecided",
+		"undeniable", "under", "underage", "undercarriage", "underclothes", "undercover", "undercurrent", "undercut", "underdeveloped",
+		"underdog", "underestimate", "underfoot", "undergo", "undergraduate", "underground", "undergrowth", "underhand", "underline",
+		"underling", "underlying", "undermine", "underneath", "underpants", "underpass", "underprivileged", "underrate", "underscore",
+		"undersecretary", "underside", "understand", "understandable", "understanding", "understate", "understatement", "understudy",
+		"undertake", "undertaker", "undertaking", "undertone", "underwater", "underwear", "underweight", "underworld", "underwrite",
+		"underwriter", "undo", "undoubted", "undress", "undue", "undulate", "unduly", "unearth", "unearthly", "unease", "uneasy", "unemployment",
+		"unending", "unerring", "uneven", "unfair", "unfold", "unforeseen", "unfortunate", "unfounded", "unfurl", "ungainly", "ungodly",
+		"unguarded", "unhappy", "unhealthy", "unicorn", "unification", "uniform", "uniformity", "unify", "unilateral", "unimpeachable",
+		"uninterested", "union", "unionist", "unique", "unisex", "unison", "unit", "unite", "unity", "universal", "universe", "university",
+		"unkempt", "unkind", "unknown", "unleash", "unless", "unlike", "unlikely", "unload", "unlock", "unlucky", "unmistakable", "unnatural",
+		"unnecessary", "unnerve", "unpack", "unprecedented", "unravel", "unreal", "unrest", "unruly", "unscathed", "unscrupulous", "unseat",
+		"unseemly", "unsettle", "unsightly", "unsound", "unspeakable", "unstable", "unsung", "until", "untimely", "unto", "untold", "untoward",
+		"unusual", "unveil", "unwieldy", "unwitting", "unwonted", "up", "upbeat", "upbraid", "upbringing", "upcoming", "update", "upgrade",
+		"upheaval", "uphill", "uphold", "upholster", "upholstery", "upkeep", "uplift", "upon", "upper", "uppermost", "upright", "uprising",
+		"uproar", "uproot", "upset", "upshot", "upside", "upstairs", "upstart", "upstream", "upsurge", "uptake", "uptight", "upturn", "upward",
+		"upwards", "uranium", "urban", "urbane", "urchin", "urge", "urgency", "urgent", "urinal", "urinate", "urine", "urn", "us", "usable",
+		"usage", "use", "useful", "useless", "user", "usher", "usual", "usurp", "usury", "utensil", "uterus", "utilitarian", "utility",
+		"utilize", "utmost", "utopia", "utopian", "utter", "utterance", "vacancy", "vacant", "vacate", "vacation", "vaccinate", "vaccination",
+		"vaccine", "vacillate", "vacuous", "vacuum", "vagabond", "vagina", "vaginal", "vagrancy", "vagrant", "vague", "vain", "valiant", "valid",
+		"validate", "validity", "valley", "valor", "valour", "valuable", "valuation", "value", "valve", "vampire", "van", "vandal", "vandalism",
+		"vandalize", "vane", "vanguard", "vanilla", "vanish", "vanity", "vanquish", "vantage", "vapor", "vapour", "variable", "variance",
+		"variant", "variation", "varicose", "variety", "various", "varnish", "vary", "vascular", "vase", "vasectomy", "vast", "vat", "vault",
+		"veal", "veer", "vegan", "vegetable", "vegetarian", "vegetate", "vegetation", "vehement", "vehicle", "vehicular", "veil", "vein",
+		"velocity", "velvet", "vendetta", "vendor", "veneer", "venerable", "venerate", "veneration", "vengeance", "vengeful", "venison", "venom",
+		"venomous", "vent", "ventilate", "ventilation", "ventilator", "ventricle", "ventriloquist", "venture", "venue", "veracity", "veranda",
+		"verandah", "verb", "verbal", "verbatim", "verbose", "verdict", "verge", "verification", "verify", "veritable", "vermin", "vernacular",
+		"versatile", "versatility", "verse", "version", "versus", "vertebra", "vertebrate", "vertical", "vertigo", "verve", "very", "vessel",
+		"vest", "vestibule", "vestige", "vestry", "vet", "veteran", "veterinary", "veto", "vex", "vexation", "via", "viable", "viaduct", "vial",
+		"vibrant", "vibrate", "vibration", "vicar", "vicarage", "vicarious", "vice", "viceroy", "vicinity", "vicious", "vicissitude", "victim",
+		"victimize", "victor", "victorious", "victory", "video", "videotape", "vie", "view", "viewer", "viewpoint", "vigil", "vigilance",
+		"vigilant", "vigilante", "vigor", "vigorous", "vigour", "vile", "vilify", "villa", "village", "villager", "villain", "villainous",
+		"vindicate", "vindication", "vindictive", "vine", "vinegar", "vineyard", "vintage", "vinyl", "viola", "violate", "violation", "violence",
+		"violent", "violet", "violin", "violinist", "viper", "viral", "virgin", "virginity", "virile", "virility", "virtual", "virtue",
+		"virtuoso", "virtuous", "virulent", "virus", "visa", "visage", "viscount", "viscous", "vise", "visibility", "visible", "vision",
+		"visionary", "visit", "visitation", "visitor", "visor", "vista", "visual", "visualize", "vital", "vitality", "vitamin", "vitriolic",
+		"vivacious", "vivacity", "vivid", "vixen", "vocabulary", "vocal", "vocalist", "vocation", "vocational", "vociferous", "vodka", "vogue",
+		"voice", "void", "volatile", "volatility", "volcanic", "volcano", "vole", "volition", "volley", "volleyball", "volt", "voltage",
+		"voluble", "volume", "voluminous", "voluntary", "volunteer", "voluptuous", "vomit", "voracious", "vortex", "vote", "voter", "vouch",
+		"voucher", "vow", "vowel", "voyage", "voyager", "vulgar", "vulgarity", "vulnerability", "vulnerable", "vulture", "wad", "waddle", "wade",
+		"wafer", "waffle", "waft", "wag", "wage", "wager", "waggle", "wagon", "waif", "wail", "waist", "waistcoat", "waistline", "wait",
+		"waiter", "waitress", "waive", "waiver", "wake", "waken", "walk", "walker", "walkout", "wall", "wallet", "wallop", "wallow", "wallpaper",
+		"walnut", "walrus", "waltz", "wan", "wand", "wander", "wanderer", "wane", "wangle", "want", "wanton", "war", "warble", "ward", "warden",
+		"warder", "wardrobe", "warehouse", "wares", "warfare", "warhead", "warlike", "warlord", "warm", "warmth", "warn", "warning", "warp",
+		"warrant", "warranty", "warren", "warrior", "warship", "wart", "wary", "wash", "washable", "washbasin", "washer", "washroom", "wasp",
+		"wastage", "waste", "wasteful", "wasteland", "watch", "watchdog", "watchful", "watchman", "water", "waterfall", "waterfront",
+		"watermelon", "waterproof", "watershed", "waterway", "watery", "watt", "wave", "waveband", "wavelength", "waver", "wavy", "wax",
+		"waxwork", "way", "wayside", "wayward", "we", "weak", "weaken", "weakling", "weakness", "wealth", "wealthy", "wean", "weapon",
+		"weaponry", "wear", "weary", "weasel", "weather", "weave", "weaver", "web", "wed", "wedding", "wedge", "wedlock", "wednesday", "wee",
+		"weed", "weedy", "week", "weekday", "weekend", "weekly", "weep", "weigh", "weight", "weighty", "weir", "weird", "welcome", "weld",
+		"welder", "welfare", "well", "wellbeing", "welt", "wench", "west", "westerly", "western", "westward", "wet", "whack", "whale", "whaler",
+		"wharf", "what", "whatever", "whatsoever", "wheat", "wheedle", "wheel", "wheelbarrow", "wheelchair", "wheeze", "when", "whence",
+		"whenever", "where", "whereabouts", "whereas", "whereby", "wherein", "whereupon", "wherever", "whet", "whether", "whey", "which",
+		"whichever", "whiff", "while", "whim", "whimper", "whimsical", "whine", "whinny", "whip", "whirl", "whirlpool", "whirlwind", "whirr",
+		"whisk", "whisker", "whiskey", "whisky", "whisper", "whistle", "white", "whiten", "whitewash", "whittle", "whiz", "who", "whoever",
+		"whole", "wholehearted", "wholesale", "wholesaler", "wholesome", "wholly", "whom", "whoop", "whore", "whose", "why", "wick", "wicked",
+		"wicker", "wicket", "wide", "widen", "widespread", "widow", "widower", "width", "wield", "wife", "wig", "wiggle", "wigwam", "wild",
+		"wilderness", "wildlife", "wile", "wilful", "will", "willful", "willing", "willow", "willpower", "wilt", "wily", "wimp", "win", "wince",
+		"winch", "wind", "windfall", "windmill", "window", "windowsill", "windpipe", "windscreen", "windshield", "windsurfing", "windswept",
+		"windy", "wine", "wing", "wink", "winner", "winter", "wintry", "wipe", "wire", "wireless", "wiry", "wisdom", "wise", "wish", "wishful",
+		"wisp", "wistful", "wit", "witch", "witchcraft", "with", "withdraw", "withdrawal", "wither", "withhold", "within", "without",
+		"withstand", "witness", "witty", "wizard", "wobble", "woe", "woeful", "wolf", "woman", "womanhood", "womb", "wonder", "wonderful",
+		"wont", "woo", "wood", "woodcutter", "wooden", "woodland", "woodpecker", "woodwind", "woodwork", "woody", "wool", "woolen", "woollen",
+		"woolly", "word", "wording", "wordy", "work", "workable", "workaholic", "worker", "workforce", "workload", "workman", "workmanship",
+		"workplace", "workshop", "world", "worldly", "worldwide", "worm", "worry", "worse", "worsen", "worship", "worshipper", "worst", "worth",
+		"worthless", "worthwhile", "worthy", "would", "wound", "wrangle", "wrap", "wrapper", "wrath", "wreak", "wreath", "wreck", "wreckage",
+		"wren", "wrench", "wrest", "wrestle", "wrestler", "wretch", "wretched", "wriggle", "wring", "wrinkle", "wrist", "wristwatch", "writ",
+		"write", "writer", "writhe", "writing", "wrong", "wrongful", "wry", "xenophobia", "xerox", "xylophone", "yacht", "yachtsman", "yak",
+		"yam", "yank", "yap", "yard", "yardstick", "yarn", "yawn", "yeah", "year", "yearbook", "yearly", "yearn", "yeast", "yell", "yellow",
+		"yelp", "yen", "yes", "yesterday", "yet", "yew", "yield", "yoga", "yoghurt", "yogurt", "yoke", "yolk", "yonder", "you", "young",
+		"youngster", "your", "yours", "yourself", "youth", "youthful", "yummy", "zany", "zeal", "zealot", "zealous", "zebra", "zenith", "zero",
+		"zest", "zigzag", "zinc", "zip", "zipper", "zodiac", "zombie", "zone", "zoo", "zoological", "zoologist", "zoology", "zoom",
+		nullptr
+		};
+	}
@@ -0,0 +1,514 @@
+/*
+	STEM_PORTER2.CPP
+	----------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <string.h>
+
+#include <vector>
+
+#include "asserts.h"
+#include "stem_porter2.h"
+#include "unittest_data.h"
+
+namespace JASS
+	{
+	/*
+		STEM_PORTER2::HAS_VOWEL()
+		-------------------------
+	*/
+	bool stem_porter2::has_vowel(size_t length) const
+		{
+		for (size_t current = 0; current < length; current++)
+			if (isvowel(word[current]))
+				return true;
+
+		return false;
+		}
+
+	/*
+		STEM_PORTER2::REGION_AFTER()
+		----------------------------
+	*/
+	size_t stem_porter2::region_after(size_t start) const
+		{
+		for (size_t current = start + 1; current < word.size(); current++)
+			if (isvowel(word[current - 1]) && !isvowel(word[current]))
+				return current + 1;
+
+		return word.size();
+		}
+
+	/*
+		STEM_PORTER2::ENDS_IN_SHORT_SYLLABLE()
+		--------------------------------------
+	*/
+	bool stem_porter2::ends_in_short_syllable(size_t length) const
+		{
+		/*
+			A vowel at the beginning of the word followed by a non-vowel
+		*/
+		if (length == 2)
+			return isvowel(word[0]) && !isvowel(word[1]);
+
+		/*
+			A vowel followed by a non-vowel other than w, x or Y and preceded by a non-vowel
+		*/
+		if (length >= 3)
+			return !isvowel(word[length - 3]) && isvowel(word[length - 2]) && !isvowel(word[length - 1]) && strchr("wxY", word[length - 1]) == nullptr;
+
+		return false;
+		}
+
+	/*
+		STEM_PORTER2::LONGEST_SUFFIX()
+		------------------------------
+	*/
+	size_t stem_porter2::longest_suffix(const char *const suffixes[][2], size_t count) const
+		{
+		size_t longest = count;
+		size_t longest_length = 0;
+
+		for (size_t which = 0; which < count; which++)
+			{
+			size_t length = strlen(suffixes[which][0]);
+			if (length > longest_length && ends_with(suffixes[which][0]))
+				{
+				longest = which;
+				longest_length = length;
+				}
+			}
+
+		return longest;
+		}
+
+	/*
+		STEM_PORTER2::EXCEPTION()
+		-------------------------
+	*/
+	bool stem_porter2::exception(void)
+		{
+		static const char *const exceptions[][2] =
+			{
+			{"skis", "ski"}, {"skies", "sky"}, {"dying", "die"}, {"lying", "lie"}, {"tying", "tie"}, {"idly", "idl"}, {"gently", "gentl"},
+			{"ugly", "ugli"}, {"early", "earli"}, {"only", "onli"}, {"singly", "singl"}, {"sky", "sky"}, {"news", "news"}, {"howe", "howe"},
+			{"atlas", "atlas"}, {"cosmos", "cosmos"}, {"bias", "bias"}, {"andes", "andes"}
+			};
+
+		for (const auto &[from, to] : exceptions)
+			if (word == from)
+				{
+				word = to;
+				return true;
+				}
+
+		return false;
+		}
+
+	/*
+		STEM_PORTER2::STEP_0()
+		----------------------
+	*/
+	void stem_porter2::step_0(void)
+		{
+		if (ends_with("'s'"))
+			word.resize(word.size() - 3);
+		else if (ends_with("'s"))
+			word.resize(word.size() - 2);
+		else if (ends_with("'"))
+			word.resize(word.size() - 1);
+		}
+
+	/*
+		STEM_PORTER2::STEP_1A()
+		-----------------------
+	*/
+	void stem_porter2::step_1a(void)
+		{
+		if (ends_with("sses"))
+			word.resize(word.size() - 2);
+		else if (ends_with("ied") || ends_with("ies"))
+			word.replace(word.size() - 3, 3, word.size() > 4 ? "i" : "ie");
+		else if (ends_with("us") || ends_with("ss"))
+			{ /* do nothing */ }
+		else if (ends_with("s") && has_vowel(word.size() - 2))
+			word.resize(word.size() - 1);
+		}
+
+	/*
+		STEM_PORTER2::STEP_1B()
+		-----------------------
+	*/
+	void stem_porter2::step_1b(void)
+		{
+		static const char *const suffixes[][2] = {{"eed", "ee"}, {"eedly", "ee"}, {"ed", ""}, {"edly", ""}, {"ing", ""}, {"ingly", ""}};
+		const size_t count = sizeof(suffixes) / sizeof(*suffixes);
+
+		size_t found = longest_suffix(suffixes, count);
+		if (found == count)
+			return;
+
+		size_t start = word.size() - strlen(suffixes[found][0]);
+		if (found <= 1)
+			{
+			/*
+				eed and eedly
+			*/
+			if (start >= r1)
+				word.replace(start, std::string::npos, suffixes[found][1]);
+			return;
+			}
+
+		/*
+			ed, edly, ing, and ingly
+		*/
+		if (!has_vowel(start))
+			return;
+		word.resize(start);
+
+		if (ends_with("at") || ends_with("bl") || ends_with("iz"))
+			word += 'e';
+		else if (word.size() >= 2 && word[word.size() - 1] == word[word.size() - 2] && strchr("bdfgmnprt", word.back()) != nullptr)
+			word.pop_back();
+		else if (r1 >= word.size() && ends_in_short_syllable(word.size()))
+			word += 'e';
+		}
+
+	/*
+		STEM_PORTER2::STEP_1C()
+		-----------------------
+	*/
+	void stem_porter2::step_1c(void)
+		{
+		if (word.size() > 2 && (word.back() == 'y' || word.back() == 'Y') && !isvowel(word[word.size() - 2]))
+			word.back() = 'i';
+		}
+
+	/*
+		STEM_PORTER2::STEP_2()
+		----------------------
+	*/
+	void stem_porter2::step_2(void)
+		{
+		static const char *const suffixes[][2] =
+			{
+			{"tional", "tion"}, {"enci", "ence"}, {"anci", "ance"}, {"abli", "able"}, {"entli", "ent"}, {"izer", "ize"}, {"ization", "ize"},
+			{"ational", "ate"}, {"ation", "ate"}, {"ator", "ate"}, {"alism", "al"}, {"aliti", "al"}, {"alli", "al"}, {"fulness", "ful"},
+			{"ousli", "ous"}, {"ousness", "ous"}, {"iveness", "ive"}, {"iviti", "ive"}, {"biliti", "ble"}, {"bli", "ble"}, {"ogi", "og"},
+			{"fulli", "ful"}, {"lessli", "less"}, {"li", ""}
+			};
+		const size_t count = sizeof(suffixes) / sizeof(*suffixes);
+
+		size_t found = longest_suffix(suffixes, count);
+		if (found == count)
+			return;
+
+		size_t start = word.size() - strlen(suffixes[found][0]);
+		if (start < r1)
+			return;
+
+		/*
+			ogi must be preceded by l, and li by a valid li-ending
+		*/
+		if (strcmp(suffixes[found][0], "ogi") == 0 && (start == 0 || word[start - 1] != 'l'))
+			return;
+		if (strcmp(suffixes[found][0], "li") == 0 && (start == 0 || strchr("cdeghkmnrt", word[start - 1]) == nullptr))
+			return;
+
+		word.replace(start, std::string::npos, suffixes[found][1]);
+		}
+
+	/*
+		STEM_PORTER2::STEP_3()
+		----------------------
+	*/
+	void stem_porter2::step_3(void)
+		{
+		static const char *const suffixes[][2] =
+			{
+			{"tional", "tion"}, {"ational", "ate"}, {"alize", "al"}, {"icate", "ic"}, {"iciti", "ic"}, {"ical", "ic"}, {"ful", ""}, {"ness", ""},
+			{"ative", ""}
+			};
+		const size_t count = sizeof(suffixes) / sizeof(*suffixes);
+
+		size_t found = longest_suffix(suffixes, count);
+		if (found == count)
+			return;
+
+		size_t start = word.size() - strlen(suffixes[found][0]);
+		if (start < r1)
+			return;
+
+		/*
+			ative must also be in R2
+		*/
+		if (strcmp(suffixes[found][0], "ative") == 0 && start < r2)
+			return;
+
+		word.replace(start, std::string::npos, suffixes[found][1]);
+		}
+
+	/*
+		STEM_PORTER2::STEP_4()
+		----------------------
+	*/
+	void stem_porter2::step_4(void)
+		{
+		static const char *const suffixes[][2] =
+			{
+			{"al", ""}, {"ance", ""}, {"ence", ""}, {"er", ""}, {"ic", ""}, {"able", ""}, {"ible", ""}, {"ant", ""}, {"ement", ""}, {"ment", ""},
+			{"ent", ""}, {"ism", ""}, {"ate", ""}, {"iti", ""}, {"ous", ""}, {"ive", ""}, {"ize", ""}, {"ion", ""}
+			};
+		const size_t count = sizeof(suffixes) / sizeof(*suffixes);
+
+		size_t found = longest_suffix(suffixes, count);
+		if (found == count)
+			return;
+
+		size_t start = word.size() - strlen(suffixes[found][0]);
+		if (start < r2)
+			return;
+
+		/*
+			ion must be preceded by s or t
+		*/
+		if (strcmp(suffixes[found][0], "ion") == 0 && (start == 0 || (word[start - 1] != 's' && word[start - 1] != 't')))
+			return;
+
+		word.resize(start);
+		}
+
+	/*
+		STEM_PORTER2::STEP_5()
+		----------------------
+	*/
+	void stem_porter2::step_5(void)
+		{
+		size_t last = word.size() - 1;
+
+		if (word.back() == 'e')
+			{
+			if (last >= r2 || (last >= r1 && !ends_in_short_syllable(last)))
+				word.pop_back();
+			}
+		else if (word.back() == 'l')
+			{
+			if (last >= r2 && last > 0 && word[last - 1] == 'l')
+				word.pop_back();
+			}
+		}
+
+	/*
+		STEM_PORTER2::TOSTEM()
+		----------------------
+	*/
+	size_t stem_porter2::tostem(char *destination, const char *source, size_t source_length)
+		{
+		word.assign(source, source_length);
+
+		/*
+			Words of one or two letters are not stemmed
+		*/
+		if (!exception() && word.size() > 2)
+			{
+			if (word[0] == '\'')
+				word.erase(0, 1);
+
+			/*
+				Mark the y's that are consonants (at the start of the word or after a vowel)
+			*/
+			if (word[0] == 'y')
+				word[0] = 'Y';
+			for (size_t current = 1; current < word.size(); current++)
+				if (word[current] == 'y' && isvowel(word[current - 1]))
+					word[current] = 'Y';
+
+			/*
+				Compute the regions, the words starting gener, commun, and arsen are special cases
+			*/
+			if (word.compare(0, 5, "gener") == 0 || word.compare(0, 5, "arsen") == 0)
+				r1 = 5;
+			else if (word.compare(0, 6, "commun") == 0)
+				r1 = 6;
+			else
+				r1 = region_after(0);
+			r2 = region_after(r1);
+
+			step_0();
+			step_1a();
+
+			/*
+				The second list of exceptions are left as they are after Step 1a
+			*/
+			static const char *const invariant[] = {"inning", "outing", "canning", "herring", "earring", "proceed", "exceed", "succeed"};
+			bool is_invariant = false;
+			for (const char *current : invariant)
+				if (word == current)
+					is_invariant = true;
+
+			if (!is_invariant)
+				{
+				step_1b();
+				step_1c();
+				step_2();
+				step_3();
+				step_4();
+				step_5();
+				}
+
+			for (auto &character : word)
+				if (character == 'Y')
+					character = 'y';
+			}
+
+		memcpy(destination, word.c_str(), word.size());
+		destination[word.size()] = '\0';
+
+		return word.size();
+		}
+
+	/*
+		STEM_PORTER2::UNITTEST()
+		------------------------
+	*/
+	void stem_porter2::unittest(void)
+		{
+		/*
+			From the Snowball English sample vocabulary (voc.txt and output.txt)
+		*/
+		std::vector<std::pair<std::string, std::string>> test_data =
+			{
+			{"consign", "consign"},
+			{"consigned", "consign"},
+			{"consigning", "consign"},
+			{"consignment", "consign"},
+			{"consist", "consist"},
+			{"consisted", "consist"},
+			{"consistency", "consist"},
+			{"consistent", "consist"},
+			{"consistently", "consist"},
+			{"consisting", "consist"},
+			{"consists", "consist"},
+			{"consolation", "consol"},
+			{"consolations", "consol"},
+			{"consolatory", "consolatori"},
+			{"console", "consol"},
+			{"consoled", "consol"},
+			{"consoles", "consol"},
+			{"consolidate", "consolid"},
+			{"consolidated", "consolid"},
+			{"consolidating", "consolid"},
+			{"consoling", "consol"},
+			{"consolingly", "consol"},
+			{"consols", "consol"},
+			{"consonant", "conson"},
+			{"consort", "consort"},
+			{"consorted", "consort"},
+			{"consorting", "consort"},
+			{"conspicuous", "conspicu"},
+			{"conspicuously", "conspicu"},
+			{"conspiracy", "conspiraci"},
+			{"conspirator", "conspir"},
+			{"conspirators", "conspir"},
+			{"conspire", "conspir"},
+			{"conspired", "conspir"},
+			{"conspiring", "conspir"},
+			{"constable", "constabl"},
+			{"constables", "constabl"},
+			{"constance", "constanc"},
+			{"constancy", "constanc"},
+			{"constant", "constant"},
+			{"knack", "knack"},
+			{"knackeries", "knackeri"},
+			{"knacks", "knack"},
+			{"knag", "knag"},
+			{"knave", "knave"},
+			{"knaves", "knave"},
+			{"knavish", "knavish"},
+			{"kneaded", "knead"},
+			{"kneading", "knead"},
+			{"knee", "knee"},
+			{"kneel", "kneel"},
+			{"kneeled", "kneel"},
+			{"kneeling", "kneel"},
+			{"kneels", "kneel"},
+			{"knees", "knee"},
+			{"knell", "knell"},
+			{"knelt", "knelt"},
+			{"knew", "knew"},
+			{"knick", "knick"},
+			{"knif", "knif"},
+			{"knife", "knife"},
+			{"knight", "knight"},
+			{"knightly", "knight"},
+			{"knights", "knight"},
+			{"knit", "knit"},
+			{"knits", "knit"},
+			{"knitted", "knit"},
+			{"knitting", "knit"},
+			{"knives", "knive"},
+			{"knob", "knob"},
+			{"knobs", "knob"},
+			{"knock", "knock"},
+			{"knocked", "knock"},
+			{"knocker", "knocker"},
+			{"knockers", "knocker"},
+			{"knocking", "knock"},
+			{"knocks", "knock"},
+			{"knopp", "knopp"},
+			{"knot", "knot"},
+			{"knots", "knot"},
+
+			/*
+				The exceptions and the examples from the description of the algorithm
+			*/
+			{"skies", "sky"},
+			{"dying", "die"},
+			{"news", "news"},
+			{"innings", "inning"},
+			{"proceed", "proceed"},
+			{"generously", "generous"},
+			{"caresses", "caress"},
+			{"ties", "tie"},
+			{"cries", "cri"},
+			{"gas", "gas"},
+			{"gaps", "gap"},
+			{"kiwis", "kiwi"},
+			{"agreed", "agre"},
+			{"hopping", "hop"},
+			{"hoping", "hope"},
+			{"cry", "cri"},
+			{"by", "by"},
+			{"say", "say"},
+			{"youth", "youth"}
+			};
+
+		stem_porter2 stemmer;
+		char result[1024];
+
+		for (const auto &example : test_data)
+			{
+			stemmer.tostem(result, example.first.c_str(), example.first.size());
+			JASS_assert(result == example.second);
+			}
+
+		/*
+			A larger vocabulary stemmed by the reference (Snowball) implementation
+		*/
+		for (const auto *example = unittest_data::snowball_english; (*example)[0] != nullptr; example++)
+			{
+			stemmer.tostem(result, (*example)[0], strlen((*example)[0]));
+			JASS_assert(strcmp(result, (*example)[1]) == 0);
+			}
+
+		/*
+			Stemming in place
+		*/
+		char in_place[] = "generalizations";
+		size_t length = stemmer.tostem(in_place, in_place, strlen(in_place));
+		JASS_assert(std::string(in_place, length) == "general");
+
+		puts("stem_porter2::PASSED");
+		}
+	}
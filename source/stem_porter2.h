@@ -0,0 +1,257 @@
+/*
+	STEM_PORTER2.H
+	--------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Porter's English stemmer version 2 (the Snowball English stemmer)
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+
+#include "stem.h"
+
+namespace JASS
+	{
+	/*
+		CLASS STEM_PORTER2
+		------------------
+	*/
+	/*!
+		@brief Generate the stem of a word using Porter's English stemmer version 2 (the Snowball English stemmer).
+		@details See: https://snowballstem.org/algorithms/english/stemmer.html.  This is the stemmer used by Lucene's SnowballFilter (English) and
+		so by Anserini, it is not the same as Porter's original algorithm (see stem_porter).
+	*/
+	class stem_porter2 : public stem
+		{
+		private:
+			std::string word;			///< The word being stemmed (a y that is a consonant is stored as Y).
+			size_t r1;					///< The start of region R1 (word.size() if R1 is empty).
+			size_t r2;					///< The start of region R2 (word.size() if R2 is empty).
+
+		private:
+			/*
+				STEM_PORTER2::ISVOWEL()
+				-----------------------
+			*/
+			/*!
+				@brief Is the character a vowel (a, e, i, o, u, or y but not Y)?
+				@param character [in] The character to check.
+				@return true if a vowel, else false.
+			*/
+			static bool isvowel(char character)
+				{
+				return character == 'a' || character == 'e' || character == 'i' || character == 'o' || character == 'u' || character == 'y';
+				}
+
+			/*
+				STEM_PORTER2::ENDS_WITH()
+				-------------------------
+			*/
+			/*!
+				@brief Does the word end with the given suffix?
+				@param suffix [in] The suffix.
+				@return true if the word ends with suffix, else false.
+			*/
+			bool ends_with(const std::string &suffix) const
+				{
+				return word.size() >= suffix.size() && word.compare(word.size() - suffix.size(), suffix.size(), suffix) == 0;
+				}
+
+			/*
+				STEM_PORTER2::HAS_VOWEL()
+				-------------------------
+			*/
+			/*!
+				@brief Is there a vowel in the first length characters of the word?
+				@param length [in] The number of characters to check.
+				@return true if there is a vowel, else false.
+			*/
+			bool has_vowel(size_t length) const;
+
+			/*
+				STEM_PORTER2::REGION_AFTER()
+				----------------------------
+			*/
+			/*!
+				@brief Return the start of the region after the first non-vowel following a vowel at or after start.
+				@param start [in] Where to start looking.
+				@return The start of the region (word.size() if the region is empty).
+			*/
+			size_t region_after(size_t start) const;
+
+			/*
+				STEM_PORTER2::ENDS_IN_SHORT_SYLLABLE()
+				--------------------------------------
+			*/
+			/*!
+				@brief Do the first length characters of the word end in a short syllable?
+				@param length [in] The length of the prefix of the word to check.
+				@return true if the prefix ends in a short syllable, else false.
+			*/
+			bool ends_in_short_syllable(size_t length) const;
+
+			/*
+				STEM_PORTER2::LONGEST_SUFFIX()
+				------------------------------
+			*/
+			/*!
+				@brief Find the longest of a list of suffixes that the word ends with.
+				@param suffixes [in] The suffixes and their replacements.
+				@param count [in] The number of suffixes.
+				@return The index of the longest suffix, or count if the word ends with none of them.
+			*/
+			size_t longest_suffix(const char *const suffixes[][2], size_t count) const;
+
+			/*
+				STEM_PORTER2::EXCEPTION()
+				-------------------------
+			*/
+			/*!
+				@brief Stem the words that are exceptions to the rules.
+				@return true if the word is an exception (and so has been stemmed), else false.
+			*/
+			bool exception(void);
+
+			/*
+				STEM_PORTER2::STEP_0()
+				----------------------
+			*/
+			/*!
+				@brief Remove the possessive (Snowball Step 0).
+			*/
+			void step_0(void);
+
+			/*
+				STEM_PORTER2::STEP_1A()
+				-----------------------
+			*/
+			/*!
+				@brief Remove plurals (Snowball Step 1a).
+			*/
+			void step_1a(void);
+
+			/*
+				STEM_PORTER2::STEP_1B()
+				-----------------------
+			*/
+			/*!
+				@brief Remove the past tense and progressive suffixes (Snowball Step 1b).
+			*/
+			void step_1b(void);
+
+			/*
+				STEM_PORTER2::STEP_1C()
+				-----------------------
+			*/
+			/*!
+				@brief Turn a final y into i (Snowball Step 1c).
+			*/
+			void step_1c(void);
+
+			/*
+				STEM_PORTER2::STEP_2()
+				----------------------
+			*/
+			/*!
+				@brief Map double suffixes to single ones (Snowball Step 2).
+			*/
+			void step_2(void);
+
+			/*
+				STEM_PORTER2::STEP_3()
+				----------------------
+			*/
+			/*!
+				@brief Deal with -ic-, -full, -ness etc. (Snowball Step 3).
+			*/
+			void step_3(void);
+
+			/*
+				STEM_PORTER2::STEP_4()
+				----------------------
+			*/
+			/*!
+				@brief Remove derivational suffixes (Snowball Step 4).
+			*/
+			void step_4(void);
+
+			/*
+				STEM_PORTER2::STEP_5()
+				----------------------
+			*/
+			/*!
+				@brief Remove a final e or the second l of ll (Snowball Step 5).
+			*/
+			void step_5(void);
+
+		public:
+			/*
+				STEM_PORTER2::STEM_PORTER2()
+				----------------------------
+			*/
+			/*!
+				@brief Constructor
+			*/
+			stem_porter2() :
+				r1(0),
+				r2(0)
+				{
+				/* Nothing */
+				}
+
+			/*
+				STEM_PORTER2::~STEM_PORTER2()
+				-----------------------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~stem_porter2()
+				{
+				/* Nothing */
+				}
+
+			/*
+				STEM_PORTER2::NAME()
+				--------------------
+			*/
+			/*!
+				@brief Return the name of the stemming algorithm
+				@return The name of the stemmer
+			*/
+			virtual std::string name(void)
+				{
+				return "Porter2";
+				}
+
+			/*
+				STEM_PORTER2::TOSTEM()
+				----------------------
+			*/
+			/*!
+				@brief Stem from source into destination
+				@param destination [out] the result of the steming process (the stem)
+				@param source [in] the term to stem
+				@param source_length [in] the length of the string to stem
+				@details source and destination can be the same.
+				@return the length of the stem
+			*/
+			using stem::tostem;
+			virtual size_t tostem(char *destination, const char *source, size_t source_length);
+
+			/*
+				STEM_PORTER2::UNITTEST()
+				------------------------
+			*/
+			/*!
+				@brief Unit test this class.
+			*/
+			static void unittest(void);
+		};
+	}
@@ -0,0 +1,99 @@
+/*
+	STEM_S.CPP
+	----------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <string.h>
+
+#include <vector>
+
+#include "asserts.h"
+#include "stem_s.h"
+
+namespace JASS
+	{
+	/*
+		STEM_S::TOSTEM()
+		----------------
+	*/
+	size_t stem_s::tostem(char *destination, const char *source, size_t source_length)
+		{
+		size_t length = source_length;
+
+		memmove(destination, source, source_length);
+
+		if (length >= 3 && destination[length - 1] == 's')
+			switch (destination[length - 2])
+				{
+				case 'u':
+				case 's':
+					break;									// -us and -ss
+				case 'e':
+					if (length > 3 && destination[length - 3] == 'i' && destination[length - 4] != 'a' && destination[length - 4] != 'e')
+						{
+						destination[length - 3] = 'y';	// -ies
+						length -= 2;
+						}
+					else if (strchr("iaoe", destination[length - 3]) == nullptr)
+						length--;							// -es
+					break;
+				default:
+					length--;								// -s
+					break;
+				}
+
+		destination[length] = '\0';
+
+		return length;
+		}
+
+	/*
+		STEM_S::UNITTEST()
+		------------------
+	*/
+	void stem_s::unittest(void)
+		{
+		/*
+			Examples of each of Harman's rules and its exceptions, and Lucene's EnglishMinimalStemmer tests
+		*/
+		std::vector<std::pair<std::string, std::string>> test_data =
+			{
+			{"queries", "query"},
+			{"phrases", "phrase"},
+			{"corpus", "corpus"},
+			{"stress", "stress"},
+			{"kings", "king"},
+			{"panels", "panel"},
+			{"aerodynamics", "aerodynamic"},
+			{"congress", "congress"},
+			{"serious", "serious"},
+			{"ponies", "pony"},
+			{"cookies", "cooky"},
+			{"aies", "aies"},
+			{"eies", "eies"},
+			{"horses", "horse"},
+			{"does", "does"},
+			{"trees", "trees"},
+			{"toes", "toes"},
+			{"cats", "cat"},
+			{"glass", "glass"},
+			{"virus", "virus"},
+			{"is", "is"},
+			{"gas", "ga"},
+			{"cat", "cat"},
+			{"s", "s"}
+			};
+
+		stem_s stemmer;
+		char result[1024];
+
+		for (const auto &example : test_data)
+			{
+			stemmer.tostem(result, example.first.c_str(), example.first.size());
+			JASS_assert(result == example.second);
+			}
+
+		puts("stem_s::PASSED");
+		}
+	}
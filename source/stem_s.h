@@ -0,0 +1,83 @@
+/*
+	STEM_S.H
+	--------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief The S-stemmer (which removes English plurals)
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <string>
+
+#include "stem.h"
+
+namespace JASS
+	{
+	/*
+		CLASS STEM_S
+		------------
+	*/
+	/*!
+		@brief Generate the stem of a word using the S-stemmer, which removes English plurals.
+		@details See: D. Harman, How effective is suffixing?, JASIS 42(1):7-15, 1991.  Only the first rule whose suffix matches is considered:
+		"ies" becomes "y" (but not "aies" or "eies"), "es" becomes "e" (but not "aes", "ees", "oes", or the "aies" and "eies" above), and "s" is
+		removed (but not "us" or "ss").  Words shorter than 3 characters are not stemmed.  This is the same as Lucene's EnglishMinimalStemmer.
+	*/
+	class stem_s : public stem
+		{
+		public:
+			/*
+				STEM_S::~STEM_S()
+				-----------------
+			*/
+			/*!
+				@brief Destructor
+			*/
+			virtual ~stem_s()
+				{
+				/* Nothing */
+				}
+
+			/*
+				STEM_S::NAME()
+				--------------
+			*/
+			/*!
+				@brief Return the name of the stemming algorithm
+				@return The name of the stemmer
+			*/
+			virtual std::string name(void)
+				{
+				return "S";
+				}
+
+			/*
+				STEM_S::TOSTEM()
+				----------------
+			*/
+			/*!
+				@brief Stem from source into destination
+				@param destination [out] the result of the steming process (the stem)
+				@param source [in] the term to stem
+				@param source_length [in] the length of the string to stem
+				@details source and destination can be the same.
+				@return the length of the stem
+			*/
+			using stem::tostem;
+			virtual size_t tostem(char *destination, const char *source, size_t source_length);
+
+			/*
+				STEM_S::UNITTEST()
+				------------------
+			*/
+			/*!
+				@brief Unit test this class.
+			*/
+			static void unittest(void);
+		};
+	}
@@ -0,0 +1,152 @@
+/*
+	STOP_WORDS.CPP
+	--------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include <ctype.h>
+
+#include <sstream>
+#include <filesystem>
+
+#include "file.h"
+#include "asserts.h"
+#include "stop_words.h"
+
+namespace JASS
+	{
+	/*
+		STOP_WORDS::ENGLISH
+		-------------------
+	*/
+	const char *const stop_words::english[] =
+		{
+		"a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it", "no", "not", "of", "on", "or", "such",
+		"that", "the", "their", "then", "there", "these", "they", "this", "to", "was", "will", "with",
+		nullptr
+		};
+
+	/*
+		STOP_WORDS::ADD()
+		-----------------
+	*/
+	void stop_words::add(const std::string &word)
+		{
+		std::string lower = word;
+		for (auto &character : lower)
+			character = static_cast<char>(tolower(static_cast<unsigned char>(character)));
+
+		if (lower.size() != 0)
+			words.insert(lower);
+		}
+
+	/*
+		STOP_WORDS::ADD_ENGLISH()
+		-------------------------
+	*/
+	void stop_words::add_english(void)
+		{
+		for (const char *const *word = english; *word != nullptr; word++)
+			words.insert(*word);
+		}
+
+	/*
+		STOP_WORDS::ADD_FILE()
+		----------------------
+	*/
+	bool stop_words::add_file(const std::string &filename)
+		{
+		std::string contents;
+
+		if (!std::filesystem::exists(filename))
+			return false;
+		file::read_entire_file(filename, contents);
+		add_string(contents);
+
+		return true;
+		}
+
+	/*
+		STOP_WORDS::ADD_STRING()
+		------------------------
+	*/
+	void stop_words::add_string(const std::string &contents)
+		{
+		std::istringstream stream(contents);
+		std::string word;
+		while (stream >> word)
+			add(word);
+		}
+
+	/*
+		STOP_WORDS::SERIALISE()
+		-----------------------
+	*/
+	std::string stop_words::serialise(void) const
+		{
+		std::string result;
+
+		for (const auto &word : words)
+			{
+			result += word;
+			result += '\n';
+			}
+
+		return result;
+		}
+
+	/*
+		STOP_WORDS::UNITTEST()
+		----------------------
+	*/
+	void stop_words::unittest(void)
+		{
+		const std::string filename = "stop_words_unittest.txt";
+		stop_words stopper;
+
+		/*
+			An empty list stops nothing
+		*/
+		JASS_assert(stopper.size() == 0);
+		JASS_assert(!stopper.is_stop_word(slice("the")));
+
+		/*
+			The built-in English list
+		*/
+		stopper.add_english();
+		JASS_assert(stopper.size() == 33);
+		JASS_assert(stopper.is_stop_word(slice("the")));
+		JASS_assert(stopper.is_stop_word(slice("a")));
+		JASS_assert(!stopper.is_stop_word(slice("th")));
+		JASS_assert(!stopper.is_stop_word(slice("them")));
+		JASS_assert(!stopper.is_stop_word(slice("jaguar")));
+
+		/*
+			Words from a file are case folded and merged with those already there, and the list can be written and read back
+		*/
+		file::write_entire_file(filename, "Jaguar\n  cars the\n\tWITH\n");
+		JASS_assert(stopper.add_file(filename));
+		JASS_assert(stopper.size() == 35);
+		JASS_assert(stopper.is_stop_word(slice("jaguar")));
+		JASS_assert(stopper.is_stop_word(slice("cars")));
+
+		file::write_entire_file(filename, stopper.serialise());
+		stop_words reread;
+		JASS_assert(reread.add_file(filename));
+		stop_words from_string;
+		from_string.add_string(stopper.serialise());
+		JASS_assert(from_string.serialise() == stopper.serialise());
+		JASS_assert(reread.serialise() == stopper.serialise());
+		JASS_assert(reread.serialise().substr(0, 8) == "a\nan\nand");
+		std::filesystem::remove(filename);
+
+		/*
+			A missing file can't be read
+		*/
+		JASS_assert(!reread.add_file(filename));
+		reread.clear();
+		JASS_assert(reread.size() == 0);
+
+		puts("stop_words::PASSED");
+		}
+	}
@@ -0,0 +1,146 @@
+/*
+	STOP_WORDS.H
+	------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief A list of stop words (terms that are neither indexed nor searched for).
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+*/
+#pragma once
+
+#include <set>
+#include <string>
+#include <string_view>
+
+#include "slice.h"
+
+namespace JASS
+	{
+	/*
+		CLASS STOP_WORDS
+		----------------
+	*/
+	/*!
+		@brief A list of stop words (terms that are neither indexed nor searched for).
+		@details Very frequent terms (such as "the") have very long postings lists which take much of the anytime postings budget while adding
+		little to the ranking.  The indexer drops the stop words from each document and records the list in the index (see serialise()) so that
+		the search engine can drop the same terms from each query.  Stop words are in lower case (as the parser case folds terms) and are checked
+		before stemming.
+	*/
+	class stop_words
+		{
+		private:
+			static const char *const english[];										///< The built-in English stop word list (nullptr terminated).
+
+		private:
+			std::set<std::string, std::less<>> words;								///< The stop words.
+
+		public:
+			/*
+				STOP_WORDS::ADD()
+				-----------------
+			*/
+			/*!
+				@brief Add a single word to the list of stop words.
+				@param word [in] The word (which is converted to lower case).
+			*/
+			void add(const std::string &word);
+
+			/*
+				STOP_WORDS::ADD_ENGLISH()
+				-------------------------
+			*/
+			/*!
+				@brief Add the built-in English stop word list (the list used by Lucene's EnglishAnalyzer).
+			*/
+			void add_english(void);
+
+			/*
+				STOP_WORDS::ADD_FILE()
+				----------------------
+			*/
+			/*!
+				@brief Add each of the words in a file to the list of stop words.
+				@details The words are separated by whitespace.  This is the format written by serialise().
+				@param filename [in] The name of the file.
+				@return false if the file cannot be read, else true.
+			*/
+			bool add_file(const std::string &filename);
+
+			/*
+				STOP_WORDS::ADD_STRING()
+				------------------------
+			*/
+			/*!
+				@brief Add each of the words in a string to the list of stop words.
+				@details The words are separated by whitespace.  This is the format written by serialise().
+				@param contents [in] The words.
+			*/
+			void add_string(const std::string &contents);
+
+			/*
+				STOP_WORDS::CLEAR()
+				-------------------
+			*/
+			/*!
+				@brief Remove all the words from the list.
+			*/
+			void clear(void)
+				{
+				words.clear();
+				}
+
+			/*
+				STOP_WORDS::SIZE()
+				------------------
+			*/
+			/*!
+				@brief Return the number of stop words.
+				@return The number of stop words (0 if there is no stopping).
+			*/
+			size_t size(void) const
+				{
+				return words.size();
+				}
+
+			/*
+				STOP_WORDS::IS_STOP_WORD()
+				--------------------------
+			*/
+			/*!
+				@brief Is the given term a stop word?
+				@param term [in] The term (which is assumed to be case folded).
+				@return true if term is a stop word, else false.
+			*/
+			bool is_stop_word(const slice &term) const
+				{
+				if (words.empty())
+					return false;
+
+				return words.find(std::string_view(reinterpret_cast<const char *>(term.address()), term.size())) != words.end();
+				}
+
+			/*
+				STOP_WORDS::SERIALISE()
+				-----------------------
+			*/
+			/*!
+				@brief Return the list of stop words, one per line in sorted order (which can be read back with add_file() or add_string()).
+				@return The stop words.
+			*/
+			std::string serialise(void) const;
+
+			/*
+				STOP_WORDS::UNITTEST()
+				----------------------
+			*/
+			/*!
+				@brief Unit test this class
+			*/
+			static void unittest(void);
+		};
+	}
@@ -51,5 +51,7 @@ namespace JASS
 			static const std::string five_trec_assessments; 			///< Five example TREC assessments.
 			static const std::string ten_price_assessments_prices;	///< The prices of ten example items.
 			static const std::string ten_price_assessments;				///< The assessments of ten example items.
+
+			static const char *const snowball_english[][2];				///< Pairs of {word, stem} generated by the Snowball English (Porter2) stemmer (nullptr terminated, see unittest_data_snowball.cpp).
 		};
 	}
@@ -0,0 +1,4222 @@
+/*
+	UNITTEST_DATA_SNOWBALL.CPP
+	--------------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+#include "unittest_data.h"
+
+namespace JASS
+	{
+	/*
+		UNITTEST_DATA::SNOWBALL_ENGLISH
+		-------------------------------
+		The stems of 22,168 English words (including their inflected and derived forms) produced by the reference implementation of the
+		Snowball English (Porter2) stemmer (the one compiled into PostgreSQL's dict_snowball).  Used to unittest stem_porter2.
+	*/
+	const char *const unittest_data::snowball_english[][2] =
+		{
+		{"a", "a"}, {"aback", "aback"}, {"abacus", "abacus"}, {"abandon", "abandon"}, {"abandoned", "abandon"}, {"abandoning", "abandon"},
+		{"abandonment", "abandon"}, {"abandons", "abandon"}, {"abase", "abas"}, {"abash", "abash"}, {"abate", "abat"}, {"abatement", "abat"},
+		{"abattoir", "abattoir"}, {"abbess", "abbess"}, {"abbey", "abbey"}, {"abbot", "abbot"}, {"abbreviate", "abbrevi"},
+		{"abbreviated", "abbrevi"}, {"abbreviates", "abbrevi"}, {"abbreviating", "abbrevi"}, {"abbreviation", "abbrevi"},
+		{"abbreviations", "abbrevi"}, {"abdicate", "abdic"}, {"abdication", "abdic"}, {"abdomen", "abdomen"}, {"abdominal", "abdomin"},
+		{"abduct", "abduct"}, {"abduction", "abduct"}, {"aberrant", "aberr"}, {"aberration", "aberr"}, {"abet", "abet"}, {"abeyance", "abey"},
+		{"abhor", "abhor"}, {"abhorrence", "abhorr"}, {"abhorrent", "abhorr"}, {"abide", "abid"}, {"abiding", "abid"}, {"abilities", "abil"},
+		{"ability", "abil"}, {"abject", "abject"}, {"abjure", "abjur"}, {"ablaze", "ablaz"}, {"able", "abl"}, {"ablution", "ablut"},
+		{"abnegation", "abneg"}, {"abnormal", "abnorm"}, {"abnormality", "abnorm"}, {"abnormally", "abnorm"}, {"aboard", "aboard"},
+		{"abode", "abod"}, {"abolish", "abolish"}, {"abolition", "abolit"}, {"abolitionist", "abolitionist"}, {"abominable", "abomin"},
+		{"abominate", "abomin"}, {"abomination", "abomin"}, {"aboriginal", "aborigin"}, {"aborigine", "aborigin"}, {"abort", "abort"},
+		{"abortable", "abort"}, {"aborted", "abort"}, {"aborting", "abort"}, {"abortion", "abort"}, {"abortive", "abort"}, {"aborts", "abort"},
+		{"abound", "abound"}, {"about", "about"}, {"above", "abov"}, {"aboveboard", "aboveboard"}, {"abrasion", "abras"}, {"abrasive", "abras"},
+		{"abreast", "abreast"}, {"abridge", "abridg"}, {"abridged", "abridg"}, {"abridgement", "abridg"}, {"abroad", "abroad"},
+		{"abrogate", "abrog"}, {"abrupt", "abrupt"}, {"abruptly", "abrupt"}, {"abscess", "abscess"}, {"abscond", "abscond"},
+		{"absence", "absenc"}, {"absent", "absent"}, {"absentee", "absente"}, {"absenteeism", "absente"}, {"absolute", "absolut"},
+		{"absolutely", "absolut"}, {"absolution", "absolut"}, {"absolutism", "absolut"}, {"absolve", "absolv"}, {"absorb", "absorb"},
+		{"absorbed", "absorb"}, {"absorbent", "absorb"}, {"absorption", "absorpt"}, {"abstain", "abstain"}, {"abstention", "abstent"},
+		{"abstinence", "abstin"}, {"abstract", "abstract"}, {"abstracting", "abstract"}, {"abstraction", "abstract"},
+		{"abstractions", "abstract"}, {"abstracts", "abstract"}, {"abstruse", "abstrus"}, {"absurd", "absurd"}, {"absurdity", "absurd"},
+		{"abundance", "abund"}, {"abundant", "abund"}, {"abuse", "abus"}, {"abused", "abus"}, {"abusing", "abus"}, {"abusive", "abus"},
+		{"abut", "abut"}, {"abysmal", "abysm"}, {"abyss", "abyss"}, {"acacia", "acacia"}, {"academe", "academ"}, {"academic", "academ"},
+		{"academician", "academician"}, {"academy", "academi"}, {"accede", "acced"}, {"accelerate", "acceler"}, {"accelerated", "acceler"},
+		{"acceleration", "acceler"}, {"accelerator", "acceler"}, {"accelerators", "acceler"}, {"accent", "accent"}, {"accented", "accent"},
+		{"accentuate", "accentu"}, {"accept", "accept"}, {"acceptable", "accept"}, {"acceptance", "accept"}, {"accepted", "accept"},
+		{"accepting", "accept"}, {"accepts", "accept"}, {"access", "access"}, {"accessed", "access"}, {"accesses", "access"},
+		{"accessibility", "access"}, {"accessible", "access"}, {"accessing", "access"}, {"accession", "access"}, {"accessory", "accessori"},
+		{"accident", "accid"}, {"accidental", "accident"}, {"accidentally", "accident"}, {"accidents", "accid"}, {"acclaim", "acclaim"},
+		{"acclamation", "acclam"}, {"acclimate", "acclim"}, {"acclimatize", "acclimat"}, {"accolade", "accolad"}, {"accommodate", "accommod"},
+		{"accommodates", "accommod"}, {"accommodation", "accommod"}, {"accompanied", "accompani"}, {"accompanies", "accompani"},
+		{"accompaniment", "accompani"}, {"accompanist", "accompanist"}, {"accompany", "accompani"}, {"accompanying", "accompani"},
+		{"accomplice", "accomplic"}, {"accomplish", "accomplish"}, {"accomplished", "accomplish"}, {"accomplishes", "accomplish"},
+		{"accomplishing", "accomplish"}, {"accomplishment", "accomplish"}, {"accord", "accord"}, {"accordance", "accord"},
+		{"according", "accord"}, {"accordingly", "accord"}, {"accordion", "accordion"}, {"accost", "accost"}, {"account", "account"},
+		{"accountable", "account"}, {"accountancy", "account"}, {"accountant", "account"}, {"accounted", "account"}, {"accounting", "account"},
+		{"accounts", "account"}, {"accredit", "accredit"}, {"accreditation", "accredit"}, {"accretion", "accret"}, {"accrue", "accru"},
+		{"accrued", "accru"}, {"accrues", "accru"}, {"accumulate", "accumul"}, {"accumulated", "accumul"}, {"accumulates", "accumul"},
+		{"accumulating", "accumul"}, {"accumulation", "accumul"}, {"accumulator", "accumul"}, {"accuracy", "accuraci"}, {"accurate", "accur"},
+		{"accurately", "accur"}, {"accursed", "accurs"}, {"accusation", "accus"}, {"accusative", "accus"}, {"accuse", "accus"},
+		{"accused", "accus"}, {"accustom", "accustom"}, {"accustomed", "accustom"}, {"ace", "ace"}, {"aced", "ace"}, {"acee", "ace"},
+		{"aceed", "ace"}, {"acerbic", "acerb"}, {"acetate", "acet"}, {"acetic", "acet"}, {"acetone", "aceton"}, {"acetylene", "acetylen"},
+		{"ache", "ach"}, {"achieve", "achiev"}, {"achieved", "achiev"}, {"achievement", "achiev"}, {"achieves", "achiev"},
+		{"achieving", "achiev"}, {"acid", "acid"}, {"acidic", "acid"}, {"acidity", "acid"}, {"acknowledge", "acknowledg"},
+		{"acknowledged", "acknowledg"}, {"acknowledgement", "acknowledg"}, {"acknowledgements", "acknowledg"}, {"acknowledges", "acknowledg"},
+		{"acknowledging", "acknowledg"}, {"acknowledgment", "acknowledg"}, {"acknowledgments", "acknowledg"}, {"acm", "acm"}, {"acme", "acm"},
+		{"acne", "acn"}, {"acolyte", "acolyt"}, {"acorn", "acorn"}, {"acoustic", "acoust"}, {"acoustics", "acoust"}, {"acquaint", "acquaint"},
+		{"acquaintance", "acquaint"}, {"acquiesce", "acquiesc"}, {"acquiescence", "acquiesc"}, {"acquire", "acquir"}, {"acquired", "acquir"},
+		{"acquires", "acquir"}, {"acquiring", "acquir"}, {"acquisition", "acquisit"}, {"acquisitions", "acquisit"}, {"acquisitive", "acquisit"},
+		{"acquit", "acquit"}, {"acquittal", "acquitt"}, {"acre", "acr"}, {"acreage", "acreag"}, {"acrid", "acrid"}, {"acrimonious", "acrimoni"},
+		{"acrimony", "acrimoni"}, {"acrobat", "acrobat"}, {"acrobatic", "acrobat"}, {"acrobatics", "acrobat"}, {"acronym", "acronym"},
+		{"acronyms", "acronym"}, {"across", "across"}, {"acrylic", "acryl"}, {"act", "act"}, {"acted", "act"}, {"acting", "act"},
+		{"action", "action"}, {"actionable", "action"}, {"actions", "action"}, {"activate", "activ"}, {"activated", "activ"},
+		{"activates", "activ"}, {"activating", "activ"}, {"activation", "activ"}, {"activations", "activ"}, {"active", "activ"},
+		{"actively", "activ"}, {"activism", "activ"}, {"activist", "activist"}, {"activities", "activ"}, {"activity", "activ"},
+		{"actor", "actor"}, {"actors", "actor"}, {"actress", "actress"}, {"acts", "act"}, {"actual", "actual"}, {"actuality", "actual"},
+		{"actually", "actual"}, {"actuary", "actuari"}, {"actuate", "actuat"}, {"actuated", "actuat"}, {"actuation", "actuat"},
+		{"acuity", "acuiti"}, {"acumen", "acumen"}, {"acupuncture", "acupunctur"}, {"acute", "acut"}, {"ad", "ad"}, {"adage", "adag"},
+		{"adam", "adam"}, {"adamant", "adam"}, {"adamic", "adam"}, {"adams", "adam"}, {"adapt", "adapt"}, {"adaptable", "adapt"},
+		{"adaptation", "adapt"}, {"adapted", "adapt"}, {"adapter", "adapt"}, {"adapters", "adapt"}, {"adapting", "adapt"}, {"adaptive", "adapt"},
+		{"adaptively", "adapt"}, {"adaptor", "adaptor"}, {"adaptors", "adaptor"}, {"adapts", "adapt"}, {"add", "add"}, {"adde", "add"},
+		{"added", "ad"}, {"addendum", "addendum"}, {"adder", "adder"}, {"addict", "addict"}, {"addiction", "addict"}, {"addictive", "addict"},
+		{"adding", "ad"}, {"addition", "addit"}, {"additional", "addit"}, {"additionally", "addit"}, {"additions", "addit"},
+		{"additive", "addit"}, {"addl", "addl"}, {"addle", "addl"}, {"address", "address"}, {"addressable", "address"}, {"addressed", "address"},
+		{"addressee", "addresse"}, {"addresses", "address"}, {"addressing", "address"}, {"adds", "add"}, {"adept", "adept"},
+		{"adequacy", "adequaci"}, {"adequate", "adequ"}, {"adequately", "adequ"}, {"adhere", "adher"}, {"adhered", "adher"},
+		{"adherence", "adher"}, {"adherent", "adher"}, {"adheres", "adher"}, {"adhering", "adher"}, {"adhesion", "adhes"}, {"adhesive", "adhes"},
+		{"adjacent", "adjac"}, {"adjective", "adject"}, {"adjoin", "adjoin"}, {"adjourn", "adjourn"}, {"adjournment", "adjourn"},
+		{"adjudicate", "adjud"}, {"adjunct", "adjunct"}, {"adjust", "adjust"}, {"adjustable", "adjust"}, {"adjusted", "adjust"},
+		{"adjuster", "adjust"}, {"adjusting", "adjust"}, {"adjustment", "adjust"}, {"adjustments", "adjust"}, {"adjusts", "adjust"},
+		{"adjutant", "adjut"}, {"administer", "administ"}, {"administrate", "administr"}, {"administration", "administr"},
+		{"administrative", "administr"}, {"administratively", "administr"}, {"administrator", "administr"}, {"administrators", "administr"},
+		{"admirable", "admir"}, {"admiral", "admir"}, {"admiralty", "admiralti"}, {"admiration", "admir"}, {"admire", "admir"},
+		{"admirer", "admir"}, {"admissible", "admiss"}, {"admission", "admiss"}, {"admit", "admit"}, {"admittance", "admitt"},
+		{"admitted", "admit"}, {"admittedly", "admit"}, {"admonish", "admonish"}, {"admonition", "admonit"}, {"ado", "ado"}, {"adobe", "adob"},
+		{"adolescence", "adolesc"}, {"adolescent", "adolesc"}, {"adopt", "adopt"}, {"adopted", "adopt"}, {"adopting", "adopt"},
+		{"adoption", "adopt"}, {"adoptive", "adopt"}, {"adopts", "adopt"}, {"adorable", "ador"}, {"adoration", "ador"}, {"adore", "ador"},
+		{"adorn", "adorn"}, {"adornment", "adorn"}, {"adrenalin", "adrenalin"}, {"adrenaline", "adrenalin"}, {"adrift", "adrift"},
+		{"adroit", "adroit"}, {"adulation", "adul"}, {"adult", "adult"}, {"adulterate", "adulter"}, {"adulterer", "adulter"},
+		{"adultery", "adulteri"}, {"adulthood", "adulthood"}, {"advance", "advanc"}, {"advanced", "advanc"}, {"advancement", "advanc"},
+		{"advancements", "advanc"}, {"advances", "advanc"}, {"advancing", "advanc"}, {"advantage", "advantag"}, {"advantageous", "advantag"},
+		{"advantages", "advantag"}, {"advent", "advent"}, {"adventure", "adventur"}, {"adventurer", "adventur"}, {"adventurous", "adventur"},
+		{"adverb", "adverb"}, {"adverbial", "adverbi"}, {"adversaries", "adversari"}, {"adversary", "adversari"}, {"adverse", "advers"},
+		{"adversely", "advers"}, {"adversity", "advers"}, {"advert", "advert"}, {"advertise", "advertis"}, {"advertised", "advertis"},
+		{"advertisement", "advertis"}, {"advertisements", "advertis"}, {"advertiser", "advertis"}, {"advertises", "advertis"},
+		{"advertising", "advertis"}, {"advice", "advic"}, {"advisable", "advis"}, {"advise", "advis"}, {"advised", "advis"},
+		{"adviser", "advis"}, {"advises", "advis"}, {"advisor", "advisor"}, {"advisories", "advisori"}, {"advisory", "advisori"},
+		{"advocacy", "advocaci"}, {"advocate", "advoc"}, {"advocates", "advoc"}, {"advocating", "advoc"}, {"aed", "a"}, {"aegis", "aegi"},
+		{"aerate", "aerat"}, {"aerial", "aerial"}, {"aerobic", "aerob"}, {"aerobics", "aerob"}, {"aerodrome", "aerodrom"},
+		{"aerodynamic", "aerodynam"}, {"aerodynamics", "aerodynam"}, {"aeronautics", "aeronaut"}, {"aeroplane", "aeroplan"},
+		{"aerosol", "aerosol"}, {"aerospace", "aerospac"}, {"aesthete", "aesthet"}, {"aesthetic", "aesthet"}, {"aesthetics", "aesthet"},
+		{"afar", "afar"}, {"affable", "affabl"}, {"affair", "affair"}, {"affairs", "affair"}, {"affect", "affect"}, {"affectation", "affect"},
+		{"affected", "affect"}, {"affecting", "affect"}, {"affection", "affect"}, {"affectionate", "affection"}, {"affects", "affect"},
+		{"affidavit", "affidavit"}, {"affiliate", "affili"}, {"affiliated", "affili"}, {"affiliation", "affili"}, {"affine", "affin"},
+		{"affinities", "affin"}, {"affinity", "affin"}, {"affirm", "affirm"}, {"affirmation", "affirm"}, {"affirmative", "affirm"},
+		{"affirmatively", "affirm"}, {"affirmed", "affirm"}, {"affirmer", "affirm"}, {"affirms", "affirm"}, {"affix", "affix"},
+		{"afflict", "afflict"}, {"affliction", "afflict"}, {"affluence", "affluenc"}, {"affluent", "affluent"}, {"afford", "afford"},
+		{"affordable", "afford"}, {"afforded", "afford"}, {"afforestation", "afforest"}, {"affray", "affray"}, {"affront", "affront"},
+		{"afield", "afield"}, {"aflame", "aflam"}, {"afloat", "afloat"}, {"afoot", "afoot"}, {"aforementioned", "aforement"},
+		{"aforesaid", "aforesaid"}, {"afraid", "afraid"}, {"afresh", "afresh"}, {"aft", "aft"}, {"after", "after"}, {"aftermath", "aftermath"},
+		{"afternoon", "afternoon"}, {"aftershave", "aftershav"}, {"aftertaste", "aftertast"}, {"afterthought", "afterthought"},
+		{"afterward", "afterward"}, {"afterwards", "afterward"}, {"again", "again"}, {"against", "against"}, {"agape", "agap"},
+		{"agate", "agat"}, {"age", "age"}, {"ageing", "age"}, {"agency", "agenc"}, {"agenda", "agenda"}, {"agent", "agent"}, {"agents", "agent"},
+		{"ages", "age"}, {"agglomeration", "agglomer"}, {"aggrandize", "aggrand"}, {"aggrandizement", "aggrandiz"}, {"aggravate", "aggrav"},
+		{"aggravation", "aggrav"}, {"aggregate", "aggreg"}, {"aggregated", "aggreg"}, {"aggregates", "aggreg"}, {"aggregating", "aggreg"},
+		{"aggregation", "aggreg"}, {"aggregator", "aggreg"}, {"aggression", "aggress"}, {"aggressive", "aggress"}, {"aggressively", "aggress"},
+		{"aggressiveness", "aggress"}, {"aggressor", "aggressor"}, {"aggrieved", "aggriev"}, {"aghast", "aghast"}, {"agile", "agil"},
+		{"agility", "agil"}, {"aging", "age"}, {"agit", "agit"}, {"agitate", "agit"}, {"agitation", "agit"}, {"agitator", "agit"},
+		{"aglow", "aglow"}, {"agnostic", "agnost"}, {"agnosticism", "agnostic"}, {"ago", "ago"}, {"agog", "agog"}, {"agonize", "agon"},
+		{"agony", "agoni"}, {"agrarian", "agrarian"}, {"agree", "agre"}, {"agreeable", "agreeabl"}, {"agreed", "agre"},
+		{"agreement", "agreement"}, {"agreements", "agreement"}, {"agrees", "agre"}, {"agricultural", "agricultur"},
+		{"agriculture", "agricultur"}, {"agronomy", "agronomi"}, {"aground", "aground"}, {"ah", "ah"}, {"ahead", "ahead"}, {"ahoy", "ahoy"},
+		{"aid", "aid"}, {"aide", "aid"}, {"aids", "aid"}, {"ail", "ail"}, {"aileron", "aileron"}, {"ailment", "ailment"}, {"aim", "aim"},
+		{"aimed", "aim"}, {"aimless", "aimless"}, {"aims", "aim"}, {"air", "air"}, {"airborne", "airborn"}, {"aircraft", "aircraft"},
+		{"airfield", "airfield"}, {"airing", "air"}, {"airline", "airlin"}, {"airliner", "airlin"}, {"airmail", "airmail"}, {"airman", "airman"},
+		{"airplane", "airplan"}, {"airport", "airport"}, {"airship", "airship"}, {"airspace", "airspac"}, {"airstrip", "airstrip"},
+		{"airtight", "airtight"}, {"airwaves", "airwav"}, {"airway", "airway"}, {"airworthy", "airworthi"}, {"airy", "airi"}, {"aisle", "aisl"},
+		{"ajar", "ajar"}, {"akin", "akin"}, {"ala", "ala"}, {"alabaster", "alabast"}, {"alacrity", "alacr"}, {"alarm", "alarm"},
+		{"alarming", "alarm"}, {"alarmist", "alarmist"}, {"alarms", "alarm"}, {"alas", "ala"}, {"albatross", "albatross"}, {"albeit", "albeit"},
+		{"albino", "albino"}, {"album", "album"}, {"albumen", "albumen"}, {"alchemist", "alchemist"}, {"alchemy", "alchemi"},
+		{"alcohol", "alcohol"}, {"alcoholic", "alcohol"}, {"alcoholism", "alcohol"}, {"alcove", "alcov"}, {"alder", "alder"},
+		{"alderman", "alderman"}, {"ale", "ale"}, {"alert", "alert"}, {"alerting", "alert"}, {"alerts", "alert"}, {"alfalfa", "alfalfa"},
+		{"alga", "alga"}, {"algae", "alga"}, {"algebra", "algebra"}, {"algebraic", "algebra"}, {"algebraically", "algebra"},
+		{"algorithm", "algorithm"}, {"algorithmic", "algorithm"}, {"algorithmically", "algorithm"}, {"algorithms", "algorithm"},
+		{"alias", "alia"}, {"alibi", "alibi"}, {"alien", "alien"}, {"alienate", "alien"}, {"alienation", "alien"}, {"aliens", "alien"},
+		{"alight", "alight"}, {"align", "align"}, {"aligned", "align"}, {"aligning", "align"}, {"alignment", "align"}, {"alignments", "align"},
+		{"aligns", "align"}, {"alike", "alik"}, {"alimony", "alimoni"}, {"alive", "aliv"}, {"alkali", "alkali"}, {"alkaline", "alkalin"},
+		{"all", "all"}, {"allay", "allay"}, {"allegation", "alleg"}, {"allege", "alleg"}, {"alleged", "alleg"}, {"allegiance", "allegi"},
+		{"alleging", "alleg"}, {"allegorical", "allegor"}, {"allegory", "allegori"}, {"allergic", "allerg"}, {"allergy", "allergi"},
+		{"alleviate", "allevi"}, {"alleviation", "allevi"}, {"alley", "alley"}, {"alliance", "allianc"}, {"alligator", "allig"},
+		{"alliteration", "alliter"}, {"alloc", "alloc"}, {"allocate", "alloc"}, {"allocated", "alloc"}, {"allocates", "alloc"},
+		{"allocating", "alloc"}, {"allocation", "alloc"}, {"allocations", "alloc"}, {"allocator", "alloc"}, {"allocators", "alloc"},
+		{"allocs", "alloc"}, {"allot", "allot"}, {"allotment", "allot"}, {"allotted", "allot"}, {"allow", "allow"}, {"allowable", "allow"},
+		{"allowance", "allow"}, {"allowances", "allow"}, {"allowed", "allow"}, {"allowing", "allow"}, {"allows", "allow"}, {"alloy", "alloy"},
+		{"allude", "allud"}, {"allure", "allur"}, {"allusers", "allus"}, {"allusion", "allus"}, {"alluvial", "alluvi"}, {"ally", "alli"},
+		{"almanac", "almanac"}, {"almighty", "almighti"}, {"almond", "almond"}, {"almost", "almost"}, {"alms", "alm"}, {"aloft", "aloft"},
+		{"alone", "alon"}, {"along", "along"}, {"alongside", "alongsid"}, {"aloof", "aloof"}, {"aloud", "aloud"}, {"alpaca", "alpaca"},
+		{"alpha", "alpha"}, {"alphabet", "alphabet"}, {"alphabetic", "alphabet"}, {"alphabetical", "alphabet"}, {"alphabetically", "alphabet"},
+		{"alphabetics", "alphabet"}, {"alphabetize", "alphabet"}, {"alphabets", "alphabet"}, {"alpine", "alpin"}, {"already", "alreadi"},
+		{"also", "also"}, {"altar", "altar"}, {"alter", "alter"}, {"alterable", "alter"}, {"alteration", "alter"}, {"alterations", "alter"},
+		{"altercation", "alterc"}, {"altered", "alter"}, {"altering", "alter"}, {"alternate", "altern"}, {"alternately", "altern"},
+		{"alternates", "altern"}, {"alternating", "altern"}, {"alternatingly", "altern"}, {"alternation", "altern"}, {"alternative", "altern"},
+		{"alternatively", "altern"}, {"alternatives", "altern"}, {"alternator", "altern"}, {"alters", "alter"}, {"although", "although"},
+		{"altimeter", "altimet"}, {"altitude", "altitud"}, {"alto", "alto"}, {"altogether", "altogeth"}, {"altruism", "altruism"},
+		{"altruistic", "altruist"}, {"aluminium", "aluminium"}, {"aluminum", "aluminum"}, {"alumnus", "alumnus"}, {"always", "alway"},
+		{"amalgam", "amalgam"}, {"amalgamate", "amalgam"}, {"amalgamation", "amalgam"}, {"amass", "amass"}, {"amateur", "amateur"},
+		{"amateurish", "amateurish"}, {"amaze", "amaz"}, {"amazement", "amaz"}, {"ambassador", "ambassador"}, {"ambassadors", "ambassador"},
+		{"amber", "amber"}, {"ambidextrous", "ambidextr"}, {"ambience", "ambienc"}, {"ambient", "ambient"}, {"ambiguities", "ambigu"},
+		{"ambiguity", "ambigu"}, {"ambiguous", "ambigu"}, {"ambiguously", "ambigu"}, {"ambit", "ambit"}, {"ambition", "ambit"},
+		{"ambitious", "ambiti"}, {"ambivalence", "ambival"}, {"ambivalent", "ambival"}, {"amble", "ambl"}, {"ambulance", "ambul"},
+		{"ambush", "ambush"}, {"ameliorate", "amelior"}, {"ameliorates", "amelior"}, {"amelioration", "amelior"}, {"amen", "amen"},
+		{"amenable", "amen"}, {"amend", "amend"}, {"amended", "amend"}, {"amending", "amend"}, {"amendment", "amend"}, {"amendments", "amend"},
+		{"amends", "amend"}, {"amenity", "amen"}, {"amethyst", "amethyst"}, {"amiable", "amiabl"}, {"amicable", "amic"}, {"amid", "amid"},
+		{"amidst", "amidst"}, {"amino", "amino"}, {"amiss", "amiss"}, {"ammeter", "ammet"}, {"ammonia", "ammonia"}, {"ammunition", "ammunit"},
+		{"amnesia", "amnesia"}, {"amnesty", "amnesti"}, {"amoeba", "amoeba"}, {"amok", "amok"}, {"among", "among"}, {"amongst", "amongst"},
+		{"amoral", "amor"}, {"amorous", "amor"}, {"amorphous", "amorph"}, {"amortize", "amort"}, {"amount", "amount"}, {"amounts", "amount"},
+		{"amp", "amp"}, {"amperage", "amperag"}, {"ampere", "amper"}, {"ampersand", "ampersand"}, {"ampersands", "ampersand"},
+		{"amphetamine", "amphetamin"}, {"amphibian", "amphibian"}, {"amphibious", "amphibi"}, {"amphitheater", "amphitheat"},
+		{"amphitheatre", "amphitheatr"}, {"ample", "ampl"}, {"amplification", "amplif"}, {"amplifier", "amplifi"}, {"amplify", "amplifi"},
+		{"amplitude", "amplitud"}, {"amputate", "amput"}, {"amputation", "amput"}, {"amulet", "amulet"}, {"amuse", "amus"},
+		{"amusement", "amus"}, {"anachronism", "anachron"}, {"anachronistic", "anachronist"}, {"anaemia", "anaemia"}, {"anaemic", "anaem"},
+		{"anaesthesia", "anaesthesia"}, {"anaesthetic", "anaesthet"}, {"anaesthetist", "anaesthetist"}, {"anagram", "anagram"}, {"anal", "anal"},
+		{"analgesic", "analges"}, {"analog", "analog"}, {"analogous", "analog"}, {"analogously", "analog"}, {"analogs", "analog"},
+		{"analogue", "analogu"}, {"analogues", "analogu"}, {"analogy", "analog"}, {"analyse", "analys"}, {"analysed", "analys"},
+		{"analyses", "analys"}, {"analysis", "analysi"}, {"analyst", "analyst"}, {"analytic", "analyt"}, {"analytical", "analyt"},
+		{"analytics", "analyt"}, {"analyze", "analyz"}, {"analyzed", "analyz"}, {"analyzer", "analyz"}, {"analyzers", "analyz"},
+		{"analyzes", "analyz"}, {"analyzing", "analyz"}, {"anarchic", "anarch"}, {"anarchism", "anarch"}, {"anarchist", "anarchist"},
+		{"anarchy", "anarchi"}, {"anathema", "anathema"}, {"anatomical", "anatom"}, {"anatomy", "anatomi"}, {"ancestor", "ancestor"},
+		{"ancestors", "ancestor"}, {"ancestral", "ancestr"}, {"ancestry", "ancestri"}, {"anchor", "anchor"}, {"anchorage", "anchorag"},
+		{"anchored", "anchor"}, {"anchoring", "anchor"}, {"anchors", "anchor"}, {"anchovy", "anchovi"}, {"ancient", "ancient"},
+		{"ancillary", "ancillari"}, {"and", "and"}, {"anded", "and"}, {"anding", "and"}, {"ands", "and"}, {"anecdotal", "anecdot"},
+		{"anecdote", "anecdot"}, {"anemia", "anemia"}, {"anemic", "anem"}, {"anemone", "anemon"}, {"anesthesia", "anesthesia"},
+		{"anesthetic", "anesthet"}, {"anew", "anew"}, {"angel", "angel"}, {"angeles", "angel"}, {"angelic", "angel"}, {"anger", "anger"},
+		{"angina", "angina"}, {"angle", "angl"}, {"angled", "angl"}, {"angler", "angler"}, {"angles", "angl"}, {"angling", "angl"},
+		{"angora", "angora"}, {"angry", "angri"}, {"angst", "angst"}, {"anguish", "anguish"}, {"angular", "angular"}, {"animal", "anim"},
+		{"animals", "anim"}, {"animate", "anim"}, {"animated", "anim"}, {"animation", "anim"}, {"animator", "anim"}, {"anime", "anim"},
+		{"animosity", "animos"}, {"anise", "anis"}, {"ankle", "ankl"}, {"annals", "annal"}, {"annex", "annex"}, {"annexation", "annex"},
+		{"annexe", "annex"}, {"annihilate", "annihil"}, {"annihilation", "annihil"}, {"anniversary", "anniversari"}, {"annotate", "annot"},
+		{"annotated", "annot"}, {"annotates", "annot"}, {"annotating", "annot"}, {"annotation", "annot"}, {"annotations", "annot"},
+		{"announce", "announc"}, {"announced", "announc"}, {"announcement", "announc"}, {"announcements", "announc"}, {"announcer", "announc"},
+		{"announces", "announc"}, {"annoy", "annoy"}, {"annoyance", "annoy"}, {"annoying", "annoy"}, {"annual", "annual"},
+		{"annuity", "annuiti"}, {"annul", "annul"}, {"annulment", "annul"}, {"anode", "anod"}, {"anoint", "anoint"}, {"anomalies", "anomali"},
+		{"anomalous", "anomal"}, {"anomaly", "anomali"}, {"anonymity", "anonym"}, {"anonymization", "anonym"}, {"anonymize", "anonym"},
+		{"anonymized", "anonym"}, {"anonymizing", "anonym"}, {"anonymous", "anonym"}, {"anonymously", "anonym"}, {"anorak", "anorak"},
+		{"anorexia", "anorexia"}, {"anorexic", "anorex"}, {"anoth", "anoth"}, {"another", "anoth"}, {"answer", "answer"},
+		{"answerable", "answer"}, {"answered", "answer"}, {"answering", "answer"}, {"answers", "answer"}, {"ant", "ant"},
+		{"antagonism", "antagon"}, {"antagonist", "antagonist"}, {"antagonistic", "antagonist"}, {"antagonize", "antagon"},
+		{"antarctic", "antarct"}, {"ante", "ant"}, {"anteater", "anteat"}, {"antecedent", "anteced"}, {"antechamber", "antechamb"},
+		{"antelope", "antelop"}, {"antenatal", "antenat"}, {"antenna", "antenna"}, {"anthem", "anthem"}, {"anthill", "anthil"},
+		{"anthology", "antholog"}, {"anthracite", "anthracit"}, {"anthrax", "anthrax"}, {"anthropological", "anthropolog"},
+		{"anthropologist", "anthropologist"}, {"anthropology", "anthropolog"}, {"anti", "anti"}, {"antibiotic", "antibiot"},
+		{"antibody", "antibodi"}, {"antic", "antic"}, {"anticipate", "anticip"}, {"anticipated", "anticip"}, {"anticipation", "anticip"},
+		{"anticlimax", "anticlimax"}, {"anticlockwise", "anticlockwis"}, {"antics", "antic"}, {"antidote", "antidot"},
+		{"antifreeze", "antifreez"}, {"antigen", "antigen"}, {"antihistamine", "antihistamin"}, {"antimony", "antimoni"},
+		{"antipathy", "antipathi"}, {"antiquarian", "antiquarian"}, {"antiquated", "antiqu"}, {"antique", "antiqu"}, {"antiquity", "antiqu"},
+		{"antiseptic", "antisept"}, {"antisocial", "antisoci"}, {"antithesis", "antithesi"}, {"antler", "antler"}, {"antonym", "antonym"},
+		{"anus", "anus"}, {"anvil", "anvil"}, {"anxiety", "anxieti"}, {"anxious", "anxious"}, {"any", "ani"}, {"anybody", "anybodi"},
+		{"anyhow", "anyhow"}, {"anyone", "anyon"}, {"anyplace", "anyplac"}, {"anything", "anyth"}, {"anyway", "anyway"}, {"anywhere", "anywher"},
+		{"aorta", "aorta"}, {"apace", "apac"}, {"apart", "apart"}, {"apartheid", "apartheid"}, {"apartment", "apart"}, {"apathetic", "apathet"},
+		{"apathy", "apathi"}, {"ape", "ape"}, {"aperitif", "aperitif"}, {"aperture", "apertur"}, {"apex", "apex"}, {"aphid", "aphid"},
+		{"aphorism", "aphor"}, {"aphrodisiac", "aphrodisiac"}, {"apiary", "apiari"}, {"apiece", "apiec"}, {"aplomb", "aplomb"},
+		{"apocalypse", "apocalyps"}, {"apocalyptic", "apocalypt"}, {"apologetic", "apologet"}, {"apologist", "apologist"},
+		{"apologize", "apolog"}, {"apology", "apolog"}, {"apoplectic", "apoplect"}, {"apoplexy", "apoplexi"}, {"apostle", "apostl"},
+		{"apostolic", "apostol"}, {"apostrophe", "apostroph"}, {"apostrophes", "apostroph"}, {"apothecary", "apothecari"}, {"appal", "appal"},
+		{"appall", "appal"}, {"appalling", "appal"}, {"apparatus", "apparatus"}, {"apparel", "apparel"}, {"apparent", "appar"},
+		{"apparently", "appar"}, {"apparition", "apparit"}, {"appeal", "appeal"}, {"appear", "appear"}, {"appearance", "appear"},
+		{"appearances", "appear"}, {"appeared", "appear"}, {"appearing", "appear"}, {"appears", "appear"}, {"appease", "appeas"},
+		{"appeasement", "appeas"}, {"appellant", "appel"}, {"appellate", "appel"}, {"append", "append"}, {"appendage", "appendag"},
+		{"appended", "append"}, {"appendicitis", "append"}, {"appending", "append"}, {"appendix", "appendix"}, {"appends", "append"},
+		{"appetite", "appetit"}, {"appetizer", "appet"}, {"appetizing", "appet"}, {"applaud", "applaud"}, {"applause", "applaus"},
+		{"apple", "appl"}, {"apples", "appl"}, {"appliance", "applianc"}, {"appliances", "applianc"}, {"applicability", "applic"},
+		{"applicable", "applic"}, {"applicant", "applic"}, {"application", "applic"}, {"applications", "applic"}, {"applicator", "applic"},
+		{"applied", "appli"}, {"applies", "appli"}, {"apply", "appli"}, {"applying", "appli"}, {"appoint", "appoint"}, {"appointee", "appointe"},
+		{"appointment", "appoint"}, {"apportion", "apport"}, {"apposite", "apposit"}, {"appraisal", "apprais"}, {"appraise", "apprais"},
+		{"appreciable", "appreci"}, {"appreciate", "appreci"}, {"appreciated", "appreci"}, {"appreciation", "appreci"},
+		{"appreciative", "appreci"}, {"apprehend", "apprehend"}, {"apprehension", "apprehens"}, {"apprehensive", "apprehens"},
+		{"apprentice", "apprentic"}, {"apprenticeship", "apprenticeship"}, {"apprise", "appris"}, {"approach", "approach"},
+		{"approachability", "approach"}, {"approachable", "approach"}, {"approaches", "approach"}, {"approaching", "approach"},
+		{"approbation", "approb"}, {"appropriate", "appropri"}, {"appropriately", "appropri"}, {"appropriation", "appropri"},
+		{"approvable", "approv"}, {"approval", "approv"}, {"approvals", "approv"}, {"approve", "approv"}, {"approved", "approv"},
+		{"approver", "approv"}, {"approves", "approv"}, {"approving", "approv"}, {"approximate", "approxim"}, {"approximated", "approxim"},
+		{"approximately", "approxim"}, {"approximates", "approxim"}, {"approximating", "approxim"}, {"approximation", "approxim"},
+		{"approximations", "approxim"}, {"apricot", "apricot"}, {"april", "april"}, {"apron", "apron"}, {"apt", "apt"}, {"aptitude", "aptitud"},
+		{"aqualung", "aqualung"}, {"aquamarine", "aquamarin"}, {"aquarium", "aquarium"}, {"aquatic", "aquat"}, {"aqueduct", "aqueduct"},
+		{"aqueous", "aqueous"}, {"aquiline", "aquilin"}, {"arabesque", "arabesqu"}, {"arable", "arabl"}, {"arbiter", "arbit"},
+		{"arbiters", "arbit"}, {"arbitrage", "arbitrag"}, {"arbitrary", "arbitrari"}, {"arbitrate", "arbitr"}, {"arbitrated", "arbitr"},
+		{"arbitrates", "arbitr"}, {"arbitration", "arbitr"}, {"arbitrator", "arbitr"}, {"arbor", "arbor"}, {"arboreal", "arbor"},
+		{"arbour", "arbour"}, {"arc", "arc"}, {"arcade", "arcad"}, {"arcane", "arcan"}, {"arch", "arch"}, {"archaeological", "archaeolog"},
+		{"archaeologist", "archaeologist"}, {"archaeology", "archaeolog"}, {"archaic", "archaic"}, {"archangel", "archangel"},
+		{"archbishop", "archbishop"}, {"archdeacon", "archdeacon"}, {"archeology", "archeolog"}, {"archer", "archer"}, {"archery", "archeri"},
+		{"archetypal", "archetyp"}, {"archetype", "archetyp"}, {"archipelago", "archipelago"}, {"architect", "architect"},
+		{"architectural", "architectur"}, {"architecturally", "architectur"}, {"architecture", "architectur"}, {"architectures", "architectur"},
+		{"archival", "archiv"}, {"archive", "archiv"}, {"archived", "archiv"}, {"archiver", "archiv"}, {"archivers", "archiv"},
+		{"archives", "archiv"}, {"archiving", "archiv"}, {"archs", "arch"}, {"archway", "archway"}, {"arcs", "arc"}, {"arctic", "arctic"},
+		{"ardent", "ardent"}, {"ardor", "ardor"}, {"ardour", "ardour"}, {"arduous", "arduous"}, {"are", "are"}, {"area", "area"},
+		{"areas", "area"}, {"arena", "arena"}, {"arenas", "arena"}, {"ares", "are"}, {"argon", "argon"}, {"argot", "argot"},
+		{"arguable", "arguabl"}, {"arguably", "arguabl"}, {"argue", "argu"}, {"argued", "argu"}, {"argument", "argument"},
+		{"argumentative", "argument"}, {"arguments", "argument"}, {"aria", "aria"}, {"arid", "arid"}, {"aridity", "arid"}, {"aright", "aright"},
+		{"aring", "are"}, {"arise", "aris"}, {"arises", "aris"}, {"arising", "aris"}, {"aristocracy", "aristocraci"},
+		{"aristocrat", "aristocrat"}, {"aristocratic", "aristocrat"}, {"arithmetic", "arithmet"}, {"arithmetical", "arithmet"}, {"ark", "ark"},
+		{"arm", "arm"}, {"armada", "armada"}, {"armadillo", "armadillo"}, {"armament", "armament"}, {"armband", "armband"},
+		{"armchair", "armchair"}, {"armed", "arm"}, {"armful", "arm"}, {"armhole", "armhol"}, {"arming", "arm"}, {"armistice", "armistic"},
+		{"armor", "armor"}, {"armored", "armor"}, {"armory", "armori"}, {"armour", "armour"}, {"armoury", "armouri"}, {"armpit", "armpit"},
+		{"arms", "arm"}, {"army", "armi"}, {"aroma", "aroma"}, {"aromatic", "aromat"}, {"around", "around"}, {"arounds", "around"},
+		{"arousal", "arous"}, {"arouse", "arous"}, {"arraign", "arraign"}, {"arraignment", "arraign"}, {"arrange", "arrang"},
+		{"arranged", "arrang"}, {"arrangement", "arrang"}, {"arrangements", "arrang"}, {"arranges", "arrang"}, {"arrant", "arrant"},
+		{"array", "array"}, {"arrays", "array"}, {"arrears", "arrear"}, {"arrest", "arrest"}, {"arrival", "arriv"}, {"arrive", "arriv"},
+		{"arrived", "arriv"}, {"arrives", "arriv"}, {"arriving", "arriv"}, {"arrogance", "arrog"}, {"arrogant", "arrog"}, {"arrow", "arrow"},
+		{"arrowhead", "arrowhead"}, {"arrows", "arrow"}, {"arsenal", "arsenal"}, {"arsenic", "arsenic"}, {"arson", "arson"},
+		{"arsonist", "arsonist"}, {"art", "art"}, {"artefact", "artefact"}, {"artefacts", "artefact"}, {"artery", "arteri"}, {"artful", "art"},
+		{"arthritic", "arthrit"}, {"arthritis", "arthriti"}, {"artichoke", "artichok"}, {"article", "articl"}, {"articles", "articl"},
+		{"articulate", "articul"}, {"articulation", "articul"}, {"artifact", "artifact"}, {"artifacts", "artifact"}, {"artifice", "artific"},
+		{"artificial", "artifici"}, {"artificially", "artifici"}, {"artillery", "artilleri"}, {"artisan", "artisan"}, {"artist", "artist"},
+		{"artiste", "artist"}, {"artistic", "artist"}, {"artistry", "artistri"}, {"artless", "artless"}, {"arts", "art"}, {"arty", "arti"},
+		{"as", "as"}, {"asbestos", "asbesto"}, {"ascend", "ascend"}, {"ascendancy", "ascend"}, {"ascendant", "ascend"}, {"ascending", "ascend"},
+		{"ascension", "ascens"}, {"ascent", "ascent"}, {"ascertain", "ascertain"}, {"ascertained", "ascertain"}, {"ascetic", "ascet"},
+		{"asceticism", "ascetic"}, {"ascribe", "ascrib"}, {"aseptic", "asept"}, {"asexual", "asexu"}, {"ash", "ash"}, {"ashamed", "asham"},
+		{"ashen", "ashen"}, {"ashore", "ashor"}, {"ashtray", "ashtray"}, {"aside", "asid"}, {"asinine", "asinin"}, {"ask", "ask"},
+		{"askance", "askanc"}, {"asked", "ask"}, {"askew", "askew"}, {"asking", "ask"}, {"asks", "ask"}, {"asleep", "asleep"}, {"asp", "asp"},
+		{"asparagus", "asparagus"}, {"aspect", "aspect"}, {"aspects", "aspect"}, {"aspen", "aspen"}, {"aspersion", "aspers"},
+		{"asphalt", "asphalt"}, {"asphyxiate", "asphyxi"}, {"asphyxiation", "asphyxi"}, {"aspic", "aspic"}, {"aspirant", "aspir"},
+		{"aspirate", "aspir"}, {"aspiration", "aspir"}, {"aspire", "aspir"}, {"aspires", "aspir"}, {"aspirin", "aspirin"}, {"aspiring", "aspir"},
+		{"ass", "ass"}, {"assail", "assail"}, {"assailant", "assail"}, {"assassin", "assassin"}, {"assassinate", "assassin"},
+		{"assassination", "assassin"}, {"assault", "assault"}, {"assay", "assay"}, {"assemblage", "assemblag"}, {"assemble", "assembl"},
+		{"assembled", "assembl"}, {"assembler", "assembl"}, {"assemblers", "assembl"}, {"assembles", "assembl"}, {"assembling", "assembl"},
+		{"assembly", "assembl"}, {"assent", "assent"}, {"assert", "assert"}, {"asserted", "assert"}, {"asserting", "assert"},
+		{"assertion", "assert"}, {"assertions", "assert"}, {"assertive", "assert"}, {"asserts", "assert"}, {"assess", "assess"},
+		{"assessed", "assess"}, {"assesses", "assess"}, {"assessing", "assess"}, {"assessment", "assess"}, {"assessments", "assess"},
+		{"assessor", "assessor"}, {"asset", "asset"}, {"assets", "asset"}, {"assiduous", "assidu"}, {"assign", "assign"},
+		{"assignation", "assign"}, {"assigned", "assign"}, {"assigning", "assign"}, {"assignment", "assign"}, {"assignments", "assign"},
+		{"assigns", "assign"}, {"assimilate", "assimil"}, {"assimilation", "assimil"}, {"assist", "assist"}, {"assistance", "assist"},
+		{"assistant", "assist"}, {"assisted", "assist"}, {"assists", "assist"}, {"assize", "assiz"}, {"associate", "associ"},
+		{"associated", "associ"}, {"associates", "associ"}, {"associating", "associ"}, {"association", "associ"}, {"associations", "associ"},
+		{"associative", "associ"}, {"associativity", "associ"}, {"assonance", "asson"}, {"assorted", "assort"}, {"assortment", "assort"},
+		{"assuage", "assuag"}, {"assume", "assum"}, {"assumed", "assum"}, {"assumes", "assum"}, {"assuming", "assum"}, {"assumption", "assumpt"},
+		{"assumptions", "assumpt"}, {"assurance", "assur"}, {"assurances", "assur"}, {"assure", "assur"}, {"assured", "assur"},
+		{"assures", "assur"}, {"assuring", "assur"}, {"asterisk", "asterisk"}, {"asterisks", "asterisk"}, {"astern", "astern"},
+		{"asteroid", "asteroid"}, {"asthma", "asthma"}, {"asthmatic", "asthmat"}, {"astigmatism", "astigmat"}, {"astonish", "astonish"},
+		{"astonishment", "astonish"}, {"astound", "astound"}, {"astounding", "astound"}, {"astral", "astral"}, {"astray", "astray"},
+		{"astride", "astrid"}, {"astringent", "astring"}, {"astrologer", "astrolog"}, {"astrology", "astrolog"}, {"astronaut", "astronaut"},
+		{"astronomer", "astronom"}, {"astronomical", "astronom"}, {"astronomy", "astronomi"}, {"astrophysics", "astrophys"}, {"astute", "astut"},
+		{"asunder", "asund"}, {"asylum", "asylum"}, {"asymmetric", "asymmetr"}, {"asymmetry", "asymmetri"}, {"at", "at"},
+		{"atavistic", "atavist"}, {"atheism", "atheism"}, {"atheist", "atheist"}, {"athlete", "athlet"}, {"athletic", "athlet"},
+		{"athletics", "athlet"}, {"atlas", "atlas"}, {"atmosphere", "atmospher"}, {"atmospheric", "atmospher"}, {"atol", "atol"},
+		{"atoll", "atol"}, {"atom", "atom"}, {"atomic", "atom"}, {"atomically", "atom"}, {"atomicity", "atom"}, {"atomics", "atom"},
+		{"atoms", "atom"}, {"aton", "aton"}, {"atone", "aton"}, {"atonement", "aton"}, {"atrocious", "atroci"}, {"atrocity", "atroc"},
+		{"atrophy", "atrophi"}, {"attach", "attach"}, {"attache", "attach"}, {"attached", "attach"}, {"attachements", "attach"},
+		{"attacher", "attach"}, {"attaches", "attach"}, {"attaching", "attach"}, {"attachment", "attach"}, {"attachments", "attach"},
+		{"attack", "attack"}, {"attacked", "attack"}, {"attacker", "attack"}, {"attackers", "attack"}, {"attacks", "attack"},
+		{"attain", "attain"}, {"attainment", "attain"}, {"attempt", "attempt"}, {"attempted", "attempt"}, {"attempting", "attempt"},
+		{"attempts", "attempt"}, {"attend", "attend"}, {"attendance", "attend"}, {"attendant", "attend"}, {"attention", "attent"},
+		{"attentive", "attent"}, {"attenuate", "attenu"}, {"attenuated", "attenu"}, {"attest", "attest"}, {"attestation", "attest"},
+		{"attestations", "attest"}, {"attested", "attest"}, {"attesting", "attest"}, {"attic", "attic"}, {"attire", "attir"},
+		{"attitude", "attitud"}, {"attorney", "attorney"}, {"attract", "attract"}, {"attraction", "attract"}, {"attractive", "attract"},
+		{"attributable", "attribut"}, {"attribute", "attribut"}, {"attributed", "attribut"}, {"attributes", "attribut"},
+		{"attributing", "attribut"}, {"attribution", "attribut"}, {"attributions", "attribut"}, {"attrition", "attrit"}, {"attune", "attun"},
+		{"atypical", "atyp"}, {"aubergine", "aubergin"}, {"auburn", "auburn"}, {"auction", "auction"}, {"auctioneer", "auction"},
+		{"audacious", "audaci"}, {"audacity", "audac"}, {"audible", "audibl"}, {"audience", "audienc"}, {"audiences", "audienc"},
+		{"audio", "audio"}, {"audiovisual", "audiovisu"}, {"audit", "audit"}, {"audited", "audit"}, {"auditing", "audit"}, {"audition", "audit"},
+		{"auditor", "auditor"}, {"auditorium", "auditorium"}, {"auditors", "auditor"}, {"auditory", "auditori"}, {"augment", "augment"},
+		{"augmentation", "augment"}, {"augmented", "augment"}, {"augmenting", "augment"}, {"augments", "augment"}, {"augur", "augur"},
+		{"august", "august"}, {"aunt", "aunt"}, {"aura", "aura"}, {"aural", "aural"}, {"auspices", "auspic"}, {"auspicious", "auspici"},
+		{"austere", "auster"}, {"austerity", "auster"}, {"authentic", "authent"}, {"authenticate", "authent"}, {"authenticated", "authent"},
+		{"authenticates", "authent"}, {"authenticating", "authent"}, {"authentication", "authent"}, {"authentications", "authent"},
+		{"authenticator", "authent"}, {"authenticators", "authent"}, {"authenticity", "authent"}, {"author", "author"}, {"authored", "author"},
+		{"authoress", "authoress"}, {"authorial", "authori"}, {"authoring", "author"}, {"authoritarian", "authoritarian"},
+		{"authoritative", "authorit"}, {"authorities", "author"}, {"authority", "author"}, {"authorization", "author"},
+		{"authorizations", "author"}, {"authorize", "author"}, {"authorized", "author"}, {"authorizer", "author"}, {"authorizes", "author"},
+		{"authorizing", "author"}, {"authors", "author"}, {"authorship", "authorship"}, {"autism", "autism"}, {"autistic", "autist"},
+		{"auto", "auto"}, {"autobiographical", "autobiograph"}, {"autobiography", "autobiographi"}, {"autocracy", "autocraci"},
+		{"autocrat", "autocrat"}, {"autocratic", "autocrat"}, {"autograph", "autograph"}, {"automate", "autom"}, {"automated", "autom"},
+		{"automates", "autom"}, {"automatic", "automat"}, {"automatically", "automat"}, {"automating", "autom"}, {"automation", "autom"},
+		{"automations", "autom"}, {"automaton", "automaton"}, {"automator", "autom"}, {"automobile", "automobil"}, {"automotive", "automot"},
+		{"autonomous", "autonom"}, {"autonomy", "autonomi"}, {"autopsy", "autopsi"}, {"autumn", "autumn"}, {"autumnal", "autumn"},
+		{"auxiliary", "auxiliari"}, {"avail", "avail"}, {"availability", "avail"}, {"available", "avail"}, {"availables", "avail"},
+		{"avalanche", "avalanch"}, {"avarice", "avaric"}, {"avaricious", "avarici"}, {"avenge", "aveng"}, {"avenue", "avenu"}, {"aver", "aver"},
+		{"average", "averag"}, {"averaged", "averag"}, {"averages", "averag"}, {"averaging", "averag"}, {"averse", "avers"},
+		{"aversion", "avers"}, {"avert", "avert"}, {"aviary", "aviari"}, {"aviation", "aviat"}, {"aviator", "aviat"}, {"avid", "avid"},
+		{"avocado", "avocado"}, {"avoid", "avoid"}, {"avoidable", "avoid"}, {"avoidance", "avoid"}, {"avoided", "avoid"}, {"avoiding", "avoid"},
+		{"avoids", "avoid"}, {"avow", "avow"}, {"avowal", "avow"}, {"avuncular", "avuncular"}, {"aw", "aw"}, {"await", "await"},
+		{"awaitable", "await"}, {"awaited", "await"}, {"awaiting", "await"}, {"awaits", "await"}, {"awake", "awak"}, {"awaken", "awaken"},
+		{"awakened", "awaken"}, {"awakening", "awaken"}, {"award", "award"}, {"awards", "award"}, {"aware", "awar"}, {"awareness", "awar"},
+		{"awash", "awash"}, {"away", "away"}, {"awe", "awe"}, {"awesome", "awesom"}, {"awful", "aw"}, {"awhile", "awhil"},
+		{"awkward", "awkward"}, {"awl", "awl"}, {"awning", "awn"}, {"awry", "awri"}, {"aws", "aw"}, {"axe", "axe"}, {"axiom", "axiom"},
+		{"axiomatic", "axiomat"}, {"axis", "axi"}, {"axle", "axl"}, {"ay", "ay"}, {"aye", "aye"}, {"azalea", "azalea"}, {"azure", "azur"},
+		{"baa", "baa"}, {"baaed", "baa"}, {"babble", "babbl"}, {"babe", "babe"}, {"baboon", "baboon"}, {"baby", "babi"},
+		{"babyhood", "babyhood"}, {"babyish", "babyish"}, {"babysit", "babysit"}, {"babysitter", "babysitt"}, {"baccalaureate", "baccalaur"},
+		{"bachelor", "bachelor"}, {"bacillus", "bacillus"}, {"back", "back"}, {"backache", "backach"}, {"backbench", "backbench"},
+		{"backbencher", "backbench"}, {"backbite", "backbit"}, {"backbone", "backbon"}, {"backbreaking", "backbreak"},
+		{"backcloth", "backcloth"}, {"backdate", "backdat"}, {"backdrop", "backdrop"}, {"backed", "back"}, {"backer", "backer"},
+		{"backes", "back"}, {"backfire", "backfir"}, {"backgammon", "backgammon"}, {"background", "background"}, {"backgrounded", "background"},
+		{"backgrounds", "background"}, {"backhand", "backhand"}, {"backhanded", "backhand"}, {"backing", "back"}, {"backlash", "backlash"},
+		{"backlog", "backlog"}, {"backlogged", "backlog"}, {"backpack", "backpack"}, {"backpacker", "backpack"}, {"backs", "back"},
+		{"backside", "backsid"}, {"backslash", "backslash"}, {"backslashed", "backslash"}, {"backslashes", "backslash"},
+		{"backslide", "backslid"}, {"backstage", "backstag"}, {"backstroke", "backstrok"}, {"backtrack", "backtrack"}, {"backup", "backup"},
+		{"backups", "backup"}, {"backward", "backward"}, {"backwardly", "backward"}, {"backwards", "backward"}, {"backwater", "backwat"},
+		{"backwoods", "backwood"}, {"backyard", "backyard"}, {"bacon", "bacon"}, {"bacteria", "bacteria"}, {"bacterial", "bacteri"},
+		{"bacteriology", "bacteriolog"}, {"bacterium", "bacterium"}, {"bad", "bad"}, {"badge", "badg"}, {"badger", "badger"},
+		{"badinage", "badinag"}, {"badly", "bad"}, {"badminton", "badminton"}, {"badness", "bad"}, {"baffle", "baffl"}, {"bag", "bag"},
+		{"bagel", "bagel"}, {"baggage", "baggag"}, {"baggy", "baggi"}, {"bagpipe", "bagpip"}, {"bail", "bail"}, {"bailiff", "bailiff"},
+		{"bails", "bail"}, {"bait", "bait"}, {"bake", "bake"}, {"baker", "baker"}, {"bakery", "bakeri"}, {"baking", "bake"},
+		{"balaclava", "balaclava"}, {"balance", "balanc"}, {"balanced", "balanc"}, {"balancer", "balanc"}, {"balancers", "balanc"},
+		{"balances", "balanc"}, {"balancing", "balanc"}, {"balcony", "balconi"}, {"bald", "bald"}, {"balderdash", "balderdash"},
+		{"bale", "bale"}, {"baleful", "bale"}, {"balk", "balk"}, {"ball", "ball"}, {"ballad", "ballad"}, {"ballast", "ballast"},
+		{"ballerina", "ballerina"}, {"ballet", "ballet"}, {"ballistic", "ballist"}, {"ballistics", "ballist"}, {"balloon", "balloon"},
+		{"ballot", "ballot"}, {"ballpoint", "ballpoint"}, {"ballroom", "ballroom"}, {"balm", "balm"}, {"balmy", "balmi"}, {"baloney", "baloney"},
+		{"balsa", "balsa"}, {"balustrade", "balustrad"}, {"bamboo", "bamboo"}, {"bamboozle", "bamboozl"}, {"ban", "ban"}, {"banal", "banal"},
+		{"banality", "banal"}, {"banana", "banana"}, {"band", "band"}, {"bandage", "bandag"}, {"bandanna", "bandanna"}, {"bandit", "bandit"},
+		{"bands", "band"}, {"bandstand", "bandstand"}, {"bandwagon", "bandwagon"}, {"bandwidth", "bandwidth"}, {"bandwidths", "bandwidth"},
+		{"bandy", "bandi"}, {"bane", "bane"}, {"bang", "bang"}, {"bangle", "bangl"}, {"banish", "banish"}, {"banishment", "banish"},
+		{"banister", "banist"}, {"banjo", "banjo"}, {"bank", "bank"}, {"banker", "banker"}, {"banknote", "banknot"}, {"bankrupt", "bankrupt"},
+		{"bankruptcy", "bankruptci"}, {"banks", "bank"}, {"banned", "ban"}, {"banner", "banner"}, {"banners", "banner"}, {"banning", "ban"},
+		{"banquet", "banquet"}, {"bantam", "bantam"}, {"banter", "banter"}, {"baptism", "baptism"}, {"baptize", "baptiz"}, {"bar", "bar"},
+		{"barb", "barb"}, {"barbarian", "barbarian"}, {"barbaric", "barbar"}, {"barbarism", "barbar"}, {"barbarous", "barbar"},
+		{"barbecue", "barbecu"}, {"barbed", "barb"}, {"barber", "barber"}, {"barbiturate", "barbitur"}, {"bard", "bard"}, {"bare", "bare"},
+		{"barefoot", "barefoot"}, {"barely", "bare"}, {"bargain", "bargain"}, {"barge", "barg"}, {"baritone", "bariton"}, {"barium", "barium"},
+		{"bark", "bark"}, {"barley", "barley"}, {"barmaid", "barmaid"}, {"barman", "barman"}, {"barn", "barn"}, {"barnacle", "barnacl"},
+		{"barnyard", "barnyard"}, {"barometer", "baromet"}, {"barometric", "barometr"}, {"baron", "baron"}, {"baroness", "baro"},
+		{"baronet", "baronet"}, {"baroque", "baroqu"}, {"barrack", "barrack"}, {"barracks", "barrack"}, {"barrage", "barrag"},
+		{"barrel", "barrel"}, {"barren", "barren"}, {"barricade", "barricad"}, {"barrier", "barrier"}, {"barriers", "barrier"},
+		{"barrister", "barrist"}, {"barrow", "barrow"}, {"bars", "bar"}, {"bartender", "bartend"}, {"barter", "barter"}, {"basal", "basal"},
+		{"basalt", "basalt"}, {"base", "base"}, {"baseball", "basebal"}, {"baseboard", "baseboard"}, {"based", "base"}, {"baseless", "baseless"},
+		{"baseline", "baselin"}, {"basement", "basement"}, {"bases", "base"}, {"bash", "bash"}, {"bashful", "bash"}, {"basic", "basic"},
+		{"basically", "basic"}, {"basics", "basic"}, {"basil", "basil"}, {"basilica", "basilica"}, {"basin", "basin"}, {"basis", "basi"},
+		{"bask", "bask"}, {"basket", "basket"}, {"basketball", "basketbal"}, {"bass", "bass"}, {"bassoon", "bassoon"}, {"bastard", "bastard"},
+		{"bastion", "bastion"}, {"bat", "bat"}, {"batch", "batch"}, {"batched", "batch"}, {"batches", "batch"}, {"batching", "batch"},
+		{"bath", "bath"}, {"bathe", "bath"}, {"bather", "bather"}, {"bathroom", "bathroom"}, {"bathtub", "bathtub"}, {"baton", "baton"},
+		{"battalion", "battalion"}, {"batten", "batten"}, {"batter", "batter"}, {"battery", "batteri"}, {"battle", "battl"},
+		{"battlefield", "battlefield"}, {"battlement", "battlement"}, {"battleship", "battleship"}, {"bauble", "baubl"}, {"baulk", "baulk"},
+		{"bawdy", "bawdi"}, {"bawl", "bawl"}, {"bay", "bay"}, {"bayonet", "bayonet"}, {"bazaar", "bazaar"}, {"bazooka", "bazooka"}, {"be", "be"},
+		{"beach", "beach"}, {"beacon", "beacon"}, {"bead", "bead"}, {"beady", "beadi"}, {"beagle", "beagl"}, {"beak", "beak"},
+		{"beaker", "beaker"}, {"beam", "beam"}, {"bean", "bean"}, {"beans", "bean"}, {"bear", "bear"}, {"bearable", "bearabl"},
+		{"beard", "beard"}, {"bearer", "bearer"}, {"bearers", "bearer"}, {"bearing", "bear"}, {"bears", "bear"}, {"beast", "beast"},
+		{"beastly", "beast"}, {"beat", "beat"}, {"beater", "beater"}, {"beatific", "beatif"}, {"beatify", "beatifi"}, {"beau", "beau"},
+		{"beautician", "beautician"}, {"beautiful", "beauti"}, {"beautify", "beautifi"}, {"beauty", "beauti"}, {"beaver", "beaver"},
+		{"becalmed", "becalm"}, {"because", "becaus"}, {"beck", "beck"}, {"beckon", "beckon"}, {"become", "becom"}, {"becomes", "becom"},
+		{"becoming", "becom"}, {"bed", "bed"}, {"bedbug", "bedbug"}, {"bedclothes", "bedcloth"}, {"bedding", "bed"}, {"bedevil", "bedevil"},
+		{"bedlam", "bedlam"}, {"bedraggled", "bedraggl"}, {"bedridden", "bedridden"}, {"bedrock", "bedrock"}, {"bedroom", "bedroom"},
+		{"bedside", "bedsid"}, {"bedsit", "bedsit"}, {"bedspread", "bedspread"}, {"bedstead", "bedstead"}, {"bedtime", "bedtim"}, {"bee", "bee"},
+		{"beech", "beech"}, {"beef", "beef"}, {"beefburger", "beefburg"}, {"beefy", "beefi"}, {"beehive", "beehiv"}, {"beeline", "beelin"},
+		{"beer", "beer"}, {"beeswax", "beeswax"}, {"beet", "beet"}, {"beetle", "beetl"}, {"beetroot", "beetroot"}, {"befall", "befal"},
+		{"befit", "befit"}, {"before", "befor"}, {"beforehand", "beforehand"}, {"befriend", "befriend"}, {"befuddle", "befuddl"}, {"beg", "beg"},
+		{"beget", "beget"}, {"beggar", "beggar"}, {"begin", "begin"}, {"beginner", "beginn"}, {"beginners", "beginn"}, {"beginning", "begin"},
+		{"begins", "begin"}, {"begonia", "begonia"}, {"begrudge", "begrudg"}, {"beguile", "beguil"}, {"behalf", "behalf"}, {"behave", "behav"},
+		{"behaved", "behav"}, {"behaves", "behav"}, {"behaving", "behav"}, {"behavior", "behavior"}, {"behavioral", "behavior"},
+		{"behaviors", "behavior"}, {"behaviour", "behaviour"}, {"behavioural", "behaviour"}, {"behaviours", "behaviour"}, {"behead", "behead"},
+		{"behest", "behest"}, {"behind", "behind"}, {"behold", "behold"}, {"beholden", "beholden"}, {"beige", "beig"}, {"being", "be"},
+		{"belabor", "belabor"}, {"belabour", "belabour"}, {"belated", "belat"}, {"belatedly", "belat"}, {"belch", "belch"},
+		{"beleaguer", "beleagu"}, {"belfry", "belfri"}, {"belie", "beli"}, {"belief", "belief"}, {"believable", "believ"}, {"believe", "believ"},
+		{"believed", "believ"}, {"believer", "believ"}, {"believes", "believ"}, {"belittle", "belittl"}, {"bell", "bell"}, {"belle", "bell"},
+		{"bellicose", "bellicos"}, {"belligerence", "belliger"}, {"belligerent", "belliger"}, {"bellow", "bellow"}, {"bells", "bell"},
+		{"belly", "belli"}, {"belong", "belong"}, {"belonged", "belong"}, {"belonging", "belong"}, {"belongings", "belong"},
+		{"belongs", "belong"}, {"beloved", "belov"}, {"below", "below"}, {"belt", "belt"}, {"bemoan", "bemoan"}, {"bemuse", "bemus"},
+		{"bench", "bench"}, {"benches", "bench"}, {"benchmark", "benchmark"}, {"benchmarked", "benchmark"}, {"benchmarker", "benchmark"},
+		{"benchmarkers", "benchmark"}, {"benchmarking", "benchmark"}, {"benchmarks", "benchmark"}, {"benchs", "bench"}, {"bend", "bend"},
+		{"beneath", "beneath"}, {"benediction", "benedict"}, {"benefactor", "benefactor"}, {"beneficent", "benefic"}, {"beneficial", "benefici"},
+		{"beneficiary", "beneficiari"}, {"benefit", "benefit"}, {"benefits", "benefit"}, {"benevolence", "benevol"}, {"benevolent", "benevol"},
+		{"benign", "benign"}, {"bent", "bent"}, {"bequeath", "bequeath"}, {"bequest", "bequest"}, {"berate", "berat"}, {"bereave", "bereav"},
+		{"bereavement", "bereav"}, {"bereft", "bereft"}, {"beret", "beret"}, {"berry", "berri"}, {"berserk", "berserk"}, {"berth", "berth"},
+		{"beseech", "beseech"}, {"beset", "beset"}, {"beside", "besid"}, {"besides", "besid"}, {"besiege", "besieg"}, {"besotted", "besot"},
+		{"bespectacled", "bespectacl"}, {"best", "best"}, {"bestial", "bestial"}, {"bestiality", "bestial"}, {"bestow", "bestow"},
+		{"bestseller", "bestsel"}, {"bet", "bet"}, {"beta", "beta"}, {"betide", "betid"}, {"betray", "betray"}, {"betrayal", "betray"},
+		{"betroth", "betroth"}, {"betrothal", "betroth"}, {"better", "better"}, {"between", "between"}, {"bevel", "bevel"},
+		{"beverage", "beverag"}, {"beverages", "beverag"}, {"bevy", "bevi"}, {"bewail", "bewail"}, {"beware", "bewar"}, {"bewilder", "bewild"},
+		{"bewilderment", "bewilder"}, {"bewitch", "bewitch"}, {"beyond", "beyond"}, {"bias", "bias"}, {"biased", "bias"}, {"bib", "bib"},
+		{"bible", "bibl"}, {"biblical", "biblic"}, {"bibliography", "bibliographi"}, {"bicentenary", "bicentenari"},
+		{"bicentennial", "bicentenni"}, {"biceps", "bicep"}, {"bicker", "bicker"}, {"bicycle", "bicycl"}, {"bid", "bid"}, {"bidder", "bidder"},
+		{"bide", "bide"}, {"biennial", "biennial"}, {"bifocals", "bifoc"}, {"big", "big"}, {"bigamist", "bigamist"}, {"bigamy", "bigami"},
+		{"bigot", "bigot"}, {"bigotry", "bigotri"}, {"bike", "bike"}, {"bikini", "bikini"}, {"bilateral", "bilater"}, {"bile", "bile"},
+		{"bilge", "bilg"}, {"bilingual", "bilingu"}, {"bilious", "bilious"}, {"bill", "bill"}, {"billboard", "billboard"},
+		{"billboards", "billboard"}, {"billed", "bill"}, {"billet", "billet"}, {"billiards", "billiard"}, {"billing", "bill"},
+		{"billion", "billion"}, {"billionaire", "billionair"}, {"billow", "billow"}, {"bills", "bill"}, {"bin", "bin"}, {"binaries", "binari"},
+		{"binary", "binari"}, {"bind", "bind"}, {"binded", "bind"}, {"binder", "binder"}, {"binding", "bind"}, {"bindings", "bind"},
+		{"binds", "bind"}, {"binge", "bing"}, {"bingo", "bingo"}, {"binoculars", "binocular"}, {"bins", "bin"}, {"biochemical", "biochem"},
+		{"biochemist", "biochemist"}, {"biochemistry", "biochemistri"}, {"biodegradable", "biodegrad"}, {"biographer", "biograph"},
+		{"biographical", "biograph"}, {"biography", "biographi"}, {"biological", "biolog"}, {"biologist", "biologist"}, {"biology", "biolog"},
+		{"biopsy", "biopsi"}, {"biosphere", "biospher"}, {"biotechnology", "biotechnolog"}, {"bipartisan", "bipartisan"}, {"biped", "bipe"},
+		{"biplane", "biplan"}, {"birch", "birch"}, {"bird", "bird"}, {"birdie", "birdi"}, {"birds", "bird"}, {"birth", "birth"},
+		{"birthday", "birthday"}, {"birthmark", "birthmark"}, {"birthplace", "birthplac"}, {"birthright", "birthright"}, {"biscuit", "biscuit"},
+		{"bisect", "bisect"}, {"bisecting", "bisect"}, {"bisection", "bisect"}, {"bisexual", "bisexu"}, {"bishop", "bishop"}, {"bison", "bison"},
+		{"bistro", "bistro"}, {"bit", "bit"}, {"bitch", "bitch"}, {"bite", "bite"}, {"bits", "bit"}, {"bitter", "bitter"},
+		{"bitterness", "bitter"}, {"bitumen", "bitumen"}, {"bivouac", "bivouac"}, {"bizarre", "bizarr"}, {"bizarrely", "bizarr"},
+		{"blab", "blab"}, {"black", "black"}, {"blackberry", "blackberri"}, {"blackbird", "blackbird"}, {"blackboard", "blackboard"},
+		{"blacken", "blacken"}, {"blackguard", "blackguard"}, {"blackhead", "blackhead"}, {"blackleg", "blackleg"}, {"blacklist", "blacklist"},
+		{"blacklisted", "blacklist"}, {"blackmail", "blackmail"}, {"blackmailer", "blackmail"}, {"blackout", "blackout"},
+		{"blacksmith", "blacksmith"}, {"bladder", "bladder"}, {"blade", "blade"}, {"blame", "blame"}, {"blamed", "blame"},
+		{"blameless", "blameless"}, {"blames", "blame"}, {"blaming", "blame"}, {"blanch", "blanch"}, {"blanche", "blanch"}, {"bland", "bland"},
+		{"blandishment", "blandish"}, {"blank", "blank"}, {"blanked", "blank"}, {"blanket", "blanket"}, {"blanking", "blank"},
+		{"blanks", "blank"}, {"blare", "blare"}, {"blaspheme", "blasphem"}, {"blasphemous", "blasphem"}, {"blasphemy", "blasphemi"},
+		{"blast", "blast"}, {"blatant", "blatant"}, {"blaze", "blaze"}, {"blazer", "blazer"}, {"bleach", "bleach"}, {"bleak", "bleak"},
+		{"bleary", "bleari"}, {"bleat", "bleat"}, {"bleed", "bleed"}, {"bleeding", "bleed"}, {"bleep", "bleep"}, {"blemish", "blemish"},
+		{"blend", "blend"}, {"blender", "blender"}, {"bless", "bless"}, {"blessed", "bless"}, {"blessing", "bless"}, {"blight", "blight"},
+		{"blimp", "blimp"}, {"blind", "blind"}, {"blindfold", "blindfold"}, {"blinding", "blind"}, {"blindly", "blind"}, {"blink", "blink"},
+		{"blinking", "blink"}, {"blip", "blip"}, {"bliss", "bliss"}, {"blissful", "bliss"}, {"blister", "blister"}, {"blithe", "blith"},
+		{"blitz", "blitz"}, {"blizzard", "blizzard"}, {"bloat", "bloat"}, {"bloated", "bloat"}, {"bloating", "bloat"}, {"blob", "blob"},
+		{"blobs", "blob"}, {"bloc", "bloc"}, {"block", "block"}, {"blockade", "blockad"}, {"blockage", "blockag"}, {"blockbuster", "blockbust"},
+		{"blocked", "block"}, {"blocking", "block"}, {"blockingly", "block"}, {"blocks", "block"}, {"bloke", "bloke"}, {"blond", "blond"},
+		{"blonde", "blond"}, {"blood", "blood"}, {"bloodbath", "bloodbath"}, {"bloodhound", "bloodhound"}, {"bloodless", "bloodless"},
+		{"bloodshed", "bloodsh"}, {"bloodshot", "bloodshot"}, {"bloodstain", "bloodstain"}, {"bloodstream", "bloodstream"},
+		{"bloodthirsty", "bloodthirsti"}, {"bloody", "bloodi"}, {"bloom", "bloom"}, {"blossom", "blossom"}, {"blot", "blot"},
+		{"blotch", "blotch"}, {"blotter", "blotter"}, {"blouse", "blous"}, {"blow", "blow"}, {"blowing", "blow"}, {"blowlamp", "blowlamp"},
+		{"blowout", "blowout"}, {"blowpipe", "blowpip"}, {"blowtorch", "blowtorch"}, {"blubber", "blubber"}, {"bludgeon", "bludgeon"},
+		{"blue", "blue"}, {"bluebell", "bluebel"}, {"blueberry", "blueberri"}, {"bluebottle", "bluebottl"}, {"blueprint", "blueprint"},
+		{"bluff", "bluff"}, {"blunder", "blunder"}, {"blunt", "blunt"}, {"blur", "blur"}, {"blurb", "blurb"}, {"blurbs", "blurb"},
+		{"blurt", "blurt"}, {"blush", "blush"}, {"bluster", "bluster"}, {"boa", "boa"}, {"boar", "boar"}, {"board", "board"},
+		{"boarder", "boarder"}, {"boarding", "board"}, {"boardroom", "boardroom"}, {"boards", "board"}, {"boast", "boast"},
+		{"boastful", "boast"}, {"boasts", "boast"}, {"boat", "boat"}, {"boatman", "boatman"}, {"bob", "bob"}, {"bobbin", "bobbin"},
+		{"bobsleigh", "bobsleigh"}, {"bode", "bode"}, {"bodice", "bodic"}, {"bodies", "bodi"}, {"bodily", "bodili"}, {"body", "bodi"},
+		{"bodyguard", "bodyguard"}, {"bodywork", "bodywork"}, {"bog", "bog"}, {"bogey", "bogey"}, {"boggle", "boggl"}, {"bogus", "bogus"},
+		{"boil", "boil"}, {"boiler", "boiler"}, {"boisterous", "boister"}, {"bold", "bold"}, {"bole", "bole"}, {"bollard", "bollard"},
+		{"bolster", "bolster"}, {"bolt", "bolt"}, {"bomb", "bomb"}, {"bombard", "bombard"}, {"bombardment", "bombard"}, {"bombastic", "bombast"},
+		{"bomber", "bomber"}, {"bombshell", "bombshel"}, {"bona", "bona"}, {"bonanza", "bonanza"}, {"bond", "bond"}, {"bondage", "bondag"},
+		{"bonded", "bond"}, {"bonding", "bond"}, {"bone", "bone"}, {"bones", "bone"}, {"bonfire", "bonfir"}, {"bonnet", "bonnet"},
+		{"bonny", "bonni"}, {"bonus", "bonus"}, {"bony", "boni"}, {"boo", "boo"}, {"boob", "boob"}, {"booby", "boobi"}, {"book", "book"},
+		{"bookcase", "bookcas"}, {"booke", "book"}, {"bookie", "booki"}, {"bookkeeper", "bookkeep"}, {"bookkeeping", "bookkeep"},
+		{"booklet", "booklet"}, {"bookmaker", "bookmak"}, {"bookmark", "bookmark"}, {"bookmarks", "bookmark"}, {"books", "book"},
+		{"bookseller", "booksel"}, {"bookshelf", "bookshelf"}, {"bookshop", "bookshop"}, {"bookstore", "bookstor"}, {"bookworm", "bookworm"},
+		{"boom", "boom"}, {"boomerang", "boomerang"}, {"boon", "boon"}, {"boor", "boor"}, {"boorish", "boorish"}, {"boost", "boost"},
+		{"boosted", "boost"}, {"booster", "booster"}, {"boosting", "boost"}, {"boot", "boot"}, {"booted", "boot"}, {"booth", "booth"},
+		{"booting", "boot"}, {"bootleg", "bootleg"}, {"boots", "boot"}, {"booty", "booti"}, {"booze", "booz"}, {"border", "border"},
+		{"bordering", "border"}, {"borderline", "borderlin"}, {"borders", "border"}, {"bore", "bore"}, {"boredom", "boredom"},
+		{"boring", "bore"}, {"born", "born"}, {"borough", "borough"}, {"borrow", "borrow"}, {"borrowed", "borrow"}, {"borrower", "borrow"},
+		{"borrowing", "borrow"}, {"borrows", "borrow"}, {"bosom", "bosom"}, {"boss", "boss"}, {"bossy", "bossi"}, {"botanical", "botan"},
+		{"botanist", "botanist"}, {"botany", "botani"}, {"botch", "botch"}, {"botched", "botch"}, {"both", "both"}, {"bother", "bother"},
+		{"bothered", "bother"}, {"bothers", "bother"}, {"bothersome", "bothersom"}, {"bottle", "bottl"}, {"bottleneck", "bottleneck"},
+		{"bottlenecks", "bottleneck"}, {"bottom", "bottom"}, {"bottomless", "bottomless"}, {"bough", "bough"}, {"boulder", "boulder"},
+		{"boulevard", "boulevard"}, {"bounce", "bounc"}, {"bouncer", "bouncer"}, {"bouncing", "bounc"}, {"bound", "bound"},
+		{"boundaries", "boundari"}, {"boundary", "boundari"}, {"bounded", "bound"}, {"bounding", "bound"}, {"boundless", "boundless"},
+		{"bounds", "bound"}, {"bounteous", "bounteous"}, {"bountiful", "bounti"}, {"bounty", "bounti"}, {"bouquet", "bouquet"},
+		{"bourbon", "bourbon"}, {"bourgeois", "bourgeoi"}, {"bourgeoisie", "bourgeoisi"}, {"bout", "bout"}, {"boutique", "boutiqu"},
+		{"bovine", "bovin"}, {"bow", "bow"}, {"bowel", "bowel"}, {"bower", "bower"}, {"bowl", "bowl"}, {"bowler", "bowler"}, {"bowling", "bowl"},
+		{"box", "box"}, {"boxed", "box"}, {"boxer", "boxer"}, {"boxes", "box"}, {"boxing", "box"}, {"boy", "boy"}, {"boycott", "boycott"},
+		{"boyfriend", "boyfriend"}, {"boyhood", "boyhood"}, {"boyish", "boyish"}, {"bra", "bra"}, {"brace", "brace"}, {"bracelet", "bracelet"},
+		{"braces", "brace"}, {"bracing", "brace"}, {"bracken", "bracken"}, {"bracket", "bracket"}, {"bracketed", "bracket"},
+		{"brackets", "bracket"}, {"brackish", "brackish"}, {"brag", "brag"}, {"braggart", "braggart"}, {"braid", "braid"}, {"braille", "braill"},
+		{"brain", "brain"}, {"brainchild", "brainchild"}, {"brainless", "brainless"}, {"brainstorm", "brainstorm"}, {"brainwash", "brainwash"},
+		{"brainwave", "brainwav"}, {"brainy", "braini"}, {"braise", "brais"}, {"brake", "brake"}, {"bramble", "brambl"}, {"bran", "bran"},
+		{"branch", "branch"}, {"branched", "branch"}, {"branches", "branch"}, {"branching", "branch"}, {"brand", "brand"}, {"branded", "brand"},
+		{"branding", "brand"}, {"brandish", "brandish"}, {"brands", "brand"}, {"brandy", "brandi"}, {"brash", "brash"}, {"brass", "brass"},
+		{"brassiere", "brassier"}, {"brassy", "brassi"}, {"brat", "brat"}, {"bravado", "bravado"}, {"brave", "brave"}, {"bravery", "braveri"},
+		{"bravo", "bravo"}, {"brawl", "brawl"}, {"brawn", "brawn"}, {"brawny", "brawni"}, {"bray", "bray"}, {"brazen", "brazen"},
+		{"brazier", "brazier"}, {"breach", "breach"}, {"bread", "bread"}, {"breadth", "breadth"}, {"breadwinner", "breadwinn"},
+		{"break", "break"}, {"breakable", "breakabl"}, {"breakage", "breakag"}, {"breakages", "breakag"}, {"breakdown", "breakdown"},
+		{"breakdowns", "breakdown"}, {"breakfast", "breakfast"}, {"breaking", "break"}, {"breakneck", "breakneck"}, {"breaks", "break"},
+		{"breakthrough", "breakthrough"}, {"breakwater", "breakwat"}, {"breast", "breast"}, {"breaststroke", "breaststrok"},
+		{"breath", "breath"}, {"breathalyser", "breathalys"}, {"breathe", "breath"}, {"breather", "breather"}, {"breathless", "breathless"},
+		{"breathtaking", "breathtak"}, {"breed", "breed"}, {"breeder", "breeder"}, {"breeze", "breez"}, {"breezy", "breezi"},
+		{"brethren", "brethren"}, {"brevity", "breviti"}, {"brew", "brew"}, {"brewer", "brewer"}, {"brewery", "breweri"}, {"bribe", "bribe"},
+		{"bribery", "briberi"}, {"brick", "brick"}, {"bricklayer", "bricklay"}, {"brickwork", "brickwork"}, {"bridal", "bridal"},
+		{"bride", "bride"}, {"bridegroom", "bridegroom"}, {"bridesmaid", "bridesmaid"}, {"bridge", "bridg"}, {"bridged", "bridg"},
+		{"bridges", "bridg"}, {"bridging", "bridg"}, {"bridle", "bridl"}, {"brief", "brief"}, {"briefcase", "briefcas"}, {"brigade", "brigad"},
+		{"brigadier", "brigadi"}, {"brigand", "brigand"}, {"bright", "bright"}, {"brighten", "brighten"}, {"brightness", "bright"},
+		{"brilliance", "brillianc"}, {"brilliant", "brilliant"}, {"brim", "brim"}, {"brimstone", "brimston"}, {"brine", "brine"},
+		{"bring", "bring"}, {"bringing", "bring"}, {"brings", "bring"}, {"brink", "brink"}, {"brinkmanship", "brinkmanship"}, {"brisk", "brisk"},
+		{"bristle", "bristl"}, {"brittle", "brittl"}, {"broach", "broach"}, {"broad", "broad"}, {"broadcast", "broadcast"},
+		{"broadcaster", "broadcast"}, {"broadcasting", "broadcast"}, {"broadcasts", "broadcast"}, {"broaden", "broaden"}, {"broadly", "broad"},
+		{"broadside", "broadsid"}, {"brocade", "brocad"}, {"broccoli", "broccoli"}, {"brochure", "brochur"}, {"brogue", "brogu"},
+		{"broil", "broil"}, {"broiler", "broiler"}, {"broke", "broke"}, {"broken", "broken"}, {"broker", "broker"}, {"brokerage", "brokerag"},
+		{"brokers", "broker"}, {"bromide", "bromid"}, {"bronchial", "bronchial"}, {"bronchitis", "bronchiti"}, {"bronco", "bronco"},
+		{"bronze", "bronz"}, {"brooch", "brooch"}, {"brood", "brood"}, {"broody", "broodi"}, {"brook", "brook"}, {"broom", "broom"},
+		{"broth", "broth"}, {"brothel", "brothel"}, {"brother", "brother"}, {"brotherhood", "brotherhood"}, {"brotherly", "brother"},
+		{"brow", "brow"}, {"browbeat", "browbeat"}, {"brown", "brown"}, {"browse", "brows"}, {"browsed", "brows"}, {"browser", "browser"},
+		{"browsers", "browser"}, {"browsing", "brows"}, {"bruise", "bruis"}, {"brunch", "brunch"}, {"brunette", "brunett"}, {"brunt", "brunt"},
+		{"brush", "brush"}, {"brusque", "brusqu"}, {"brutal", "brutal"}, {"brutality", "brutal"}, {"brutalize", "brutal"}, {"brute", "brute"},
+		{"brutish", "brutish"}, {"bubble", "bubbl"}, {"bubbles", "bubbl"}, {"bubbly", "bubbl"}, {"buccaneer", "buccan"}, {"buck", "buck"},
+		{"bucket", "bucket"}, {"bucketized", "bucket"}, {"buckets", "bucket"}, {"buckle", "buckl"}, {"bud", "bud"}, {"buddy", "buddi"},
+		{"budge", "budg"}, {"budgerigar", "budgerigar"}, {"budget", "budget"}, {"budgets", "budget"}, {"buff", "buff"}, {"buffalo", "buffalo"},
+		{"buffe", "buff"}, {"buffer", "buffer"}, {"buffered", "buffer"}, {"buffering", "buffer"}, {"buffers", "buffer"}, {"buffet", "buffet"},
+		{"buffoon", "buffoon"}, {"bug", "bug"}, {"bugbear", "bugbear"}, {"buggy", "buggi"}, {"bugle", "bugl"}, {"bugs", "bug"},
+		{"build", "build"}, {"builder", "builder"}, {"builders", "builder"}, {"building", "build"}, {"builds", "build"}, {"bulb", "bulb"},
+		{"bulbous", "bulbous"}, {"bulge", "bulg"}, {"bulk", "bulk"}, {"bulky", "bulki"}, {"bull", "bull"}, {"bulldog", "bulldog"},
+		{"bulldoze", "bulldoz"}, {"bulldozer", "bulldoz"}, {"bullet", "bullet"}, {"bulleted", "bullet"}, {"bulletin", "bulletin"},
+		{"bulletins", "bulletin"}, {"bulletproof", "bulletproof"}, {"bullfight", "bullfight"}, {"bullion", "bullion"}, {"bullock", "bullock"},
+		{"bully", "bulli"}, {"bulwark", "bulwark"}, {"bum", "bum"}, {"bumble", "bumbl"}, {"bump", "bump"}, {"bumped", "bump"},
+		{"bumper", "bumper"}, {"bumping", "bump"}, {"bumps", "bump"}, {"bumpy", "bumpi"}, {"bun", "bun"}, {"bunch", "bunch"},
+		{"bundle", "bundl"}, {"bundled", "bundl"}, {"bundles", "bundl"}, {"bundling", "bundl"}, {"bung", "bung"}, {"bungalow", "bungalow"},
+		{"bungle", "bungl"}, {"bunion", "bunion"}, {"bunk", "bunk"}, {"bunker", "bunker"}, {"bunny", "bunni"}, {"buoy", "buoy"},
+		{"buoyancy", "buoyanc"}, {"buoyant", "buoyant"}, {"burble", "burbl"}, {"burden", "burden"}, {"burdensome", "burdensom"},
+		{"bureau", "bureau"}, {"bureaucracy", "bureaucraci"}, {"bureaucrat", "bureaucrat"}, {"bureaucratic", "bureaucrat"},
+		{"burgeon", "burgeon"}, {"burger", "burger"}, {"burglar", "burglar"}, {"burglary", "burglari"}, {"burgle", "burgl"},
+		{"burial", "burial"}, {"buried", "buri"}, {"burlesque", "burlesqu"}, {"burly", "bur"}, {"burn", "burn"}, {"burner", "burner"},
+		{"burning", "burn"}, {"burnish", "burnish"}, {"burns", "burn"}, {"burp", "burp"}, {"burrow", "burrow"}, {"burrows", "burrow"},
+		{"bursar", "bursar"}, {"bursary", "bursari"}, {"burst", "burst"}, {"bursts", "burst"}, {"bury", "buri"}, {"bus", "bus"},
+		{"bush", "bush"}, {"bushe", "bush"}, {"bushel", "bushel"}, {"bushy", "bushi"}, {"business", "busi"}, {"businesslike", "businesslik"},
+		{"businessman", "businessman"}, {"businesswoman", "businesswoman"}, {"bust", "bust"}, {"busted", "bust"}, {"bustle", "bustl"},
+		{"busy", "busi"}, {"busybody", "busybodi"}, {"but", "but"}, {"butane", "butan"}, {"butch", "butch"}, {"butcher", "butcher"},
+		{"butchery", "butcheri"}, {"butler", "butler"}, {"butt", "butt"}, {"butter", "butter"}, {"buttercup", "buttercup"},
+		{"butterfly", "butterfli"}, {"buttermilk", "buttermilk"}, {"butterscotch", "butterscotch"}, {"buttock", "buttock"}, {"button", "button"},
+		{"buttonhole", "buttonhol"}, {"buttons", "button"}, {"buttress", "buttress"}, {"buxom", "buxom"}, {"buy", "buy"}, {"buyer", "buyer"},
+		{"buzz", "buzz"}, {"buzzard", "buzzard"}, {"buzzer", "buzzer"}, {"by", "by"}, {"bye", "bye"}, {"bygone", "bygon"}, {"bylaw", "bylaw"},
+		{"bypass", "bypass"}, {"bypassed", "bypass"}, {"bypasses", "bypass"}, {"bypassing", "bypass"}, {"byproduct", "byproduct"},
+		{"bystander", "bystand"}, {"byte", "byte"}, {"bytes", "byte"}, {"byword", "byword"}, {"cab", "cab"}, {"cabaret", "cabaret"},
+		{"cabbage", "cabbag"}, {"cabin", "cabin"}, {"cabinet", "cabinet"}, {"cable", "cabl"}, {"cabs", "cab"}, {"cach", "cach"},
+		{"cache", "cach"}, {"cached", "cach"}, {"caches", "cach"}, {"caching", "cach"}, {"cackle", "cackl"}, {"cacophony", "cacophoni"},
+		{"cactus", "cactus"}, {"cad", "cad"}, {"cadaver", "cadav"}, {"caddie", "caddi"}, {"caddy", "caddi"}, {"cadence", "cadenc"},
+		{"cadet", "cadet"}, {"cadge", "cadg"}, {"cadmium", "cadmium"}, {"cafe", "cafe"}, {"cafeteria", "cafeteria"}, {"caffeine", "caffein"},
+		{"cage", "cage"}, {"cagey", "cagey"}, {"cajole", "cajol"}, {"cake", "cake"}, {"calamitous", "calamit"}, {"calamity", "calam"},
+		{"calcify", "calcifi"}, {"calcium", "calcium"}, {"calculable", "calcul"}, {"calculate", "calcul"}, {"calculated", "calcul"},
+		{"calculates", "calcul"}, {"calculating", "calcul"}, {"calculation", "calcul"}, {"calculations", "calcul"}, {"calculator", "calcul"},
+		{"calculus", "calculus"}, {"calendar", "calendar"}, {"calf", "calf"}, {"caliber", "calib"}, {"calibrate", "calibr"},
+		{"calibrated", "calibr"}, {"calibration", "calibr"}, {"calibre", "calibr"}, {"calico", "calico"}, {"caliph", "caliph"}, {"call", "call"},
+		{"called", "call"}, {"caller", "caller"}, {"callers", "caller"}, {"calligraphy", "calligraphi"}, {"calling", "call"},
+		{"callous", "callous"}, {"callow", "callow"}, {"calls", "call"}, {"callus", "callus"}, {"calm", "calm"}, {"calorie", "calori"},
+		{"calorific", "calorif"}, {"calve", "calv"}, {"calypso", "calypso"}, {"camaraderie", "camaraderi"}, {"camber", "camber"},
+		{"camcorder", "camcord"}, {"camel", "camel"}, {"cameo", "cameo"}, {"camera", "camera"}, {"cameraman", "cameraman"},
+		{"camouflage", "camouflag"}, {"camp", "camp"}, {"campaign", "campaign"}, {"campaigner", "campaign"}, {"camper", "camper"},
+		{"campfire", "campfir"}, {"campsite", "campsit"}, {"campus", "campus"}, {"camshaft", "camshaft"}, {"can", "can"}, {"canal", "canal"},
+		{"canary", "canari"}, {"cancel", "cancel"}, {"cancelability", "cancel"}, {"cancelable", "cancel"}, {"cancelation", "cancel"},
+		{"canceled", "cancel"}, {"canceling", "cancel"}, {"cancellation", "cancel"}, {"cancellations", "cancel"}, {"cancelled", "cancel"},
+		{"cancelling", "cancel"}, {"cancels", "cancel"}, {"cancer", "cancer"}, {"cancerous", "cancer"}, {"candelabra", "candelabra"},
+		{"candid", "candid"}, {"candidacy", "candidaci"}, {"candidate", "candid"}, {"candidates", "candid"}, {"candies", "candi"},
+		{"candle", "candl"}, {"candlelight", "candlelight"}, {"candlestick", "candlestick"}, {"candor", "candor"}, {"candour", "candour"},
+		{"candy", "candi"}, {"cane", "cane"}, {"canine", "canin"}, {"canister", "canist"}, {"canker", "canker"}, {"cannabis", "cannabi"},
+		{"canned", "can"}, {"cannery", "canneri"}, {"cannibal", "cannib"}, {"cannibalism", "cannib"}, {"cannon", "cannon"},
+		{"cannonball", "cannonbal"}, {"canny", "canni"}, {"canoe", "cano"}, {"canon", "canon"}, {"canonical", "canon"}, {"canopy", "canopi"},
+		{"cant", "cant"}, {"cantankerous", "cantanker"}, {"canteen", "canteen"}, {"canter", "canter"}, {"cantilever", "cantilev"},
+		{"canton", "canton"}, {"canvas", "canva"}, {"canvass", "canvass"}, {"canyon", "canyon"}, {"cap", "cap"}, {"capabilities", "capabl"},
+		{"capability", "capabl"}, {"capable", "capabl"}, {"capacious", "capaci"}, {"capacitance", "capacit"}, {"capacities", "capac"},
+		{"capacitor", "capacitor"}, {"capacity", "capac"}, {"cape", "cape"}, {"caper", "caper"}, {"capillary", "capillari"},
+		{"capital", "capit"}, {"capitalism", "capit"}, {"capitalist", "capitalist"}, {"capitalization", "capit"}, {"capitalize", "capit"},
+		{"capitalized", "capit"}, {"capitalizing", "capit"}, {"capitals", "capit"}, {"capitulate", "capitul"}, {"capitulation", "capitul"},
+		{"capped", "cap"}, {"caprice", "capric"}, {"capricious", "caprici"}, {"caps", "cap"}, {"capsize", "capsiz"}, {"capstan", "capstan"},
+		{"capsule", "capsul"}, {"captain", "captain"}, {"caption", "caption"}, {"captions", "caption"}, {"captivate", "captiv"},
+		{"captive", "captiv"}, {"captivity", "captiv"}, {"captor", "captor"}, {"capture", "captur"}, {"captured", "captur"},
+		{"captures", "captur"}, {"capturing", "captur"}, {"car", "car"}, {"carafe", "caraf"}, {"caramel", "caramel"}, {"carat", "carat"},
+		{"caravan", "caravan"}, {"carbohydrate", "carbohydr"}, {"carbon", "carbon"}, {"carbonate", "carbon"}, {"carburetor", "carburetor"},
+		{"carburettor", "carburettor"}, {"carcass", "carcass"}, {"carcinogen", "carcinogen"}, {"carcinogenic", "carcinogen"}, {"card", "card"},
+		{"cardboard", "cardboard"}, {"cardiac", "cardiac"}, {"cardigan", "cardigan"}, {"cardinal", "cardin"}, {"cardinality", "cardin"},
+		{"cardiology", "cardiolog"}, {"cards", "card"}, {"care", "care"}, {"careen", "careen"}, {"career", "career"}, {"carefree", "carefre"},
+		{"careful", "care"}, {"carefully", "care"}, {"careless", "careless"}, {"carelessly", "careless"}, {"cares", "care"},
+		{"caress", "caress"}, {"caretaker", "caretak"}, {"cargo", "cargo"}, {"caribou", "caribou"}, {"caricature", "caricatur"},
+		{"caries", "cari"}, {"carnage", "carnag"}, {"carnal", "carnal"}, {"carnation", "carnat"}, {"carnival", "carniv"},
+		{"carnivore", "carnivor"}, {"carnivorous", "carnivor"}, {"carol", "carol"}, {"carouse", "carous"}, {"carousel", "carousel"},
+		{"carp", "carp"}, {"carpenter", "carpent"}, {"carpentry", "carpentri"}, {"carpet", "carpet"}, {"carriage", "carriag"},
+		{"carriageway", "carriageway"}, {"carrie", "carri"}, {"carried", "carri"}, {"carrier", "carrier"}, {"carries", "carri"},
+		{"carrion", "carrion"}, {"carrot", "carrot"}, {"carry", "carri"}, {"carrying", "carri"}, {"cart", "cart"}, {"cartel", "cartel"},
+		{"cartilage", "cartilag"}, {"cartographer", "cartograph"}, {"cartography", "cartographi"}, {"carton", "carton"}, {"cartoon", "cartoon"},
+		{"cartoonist", "cartoonist"}, {"cartoons", "cartoon"}, {"cartridge", "cartridg"}, {"cartwheel", "cartwheel"}, {"carve", "carv"},
+		{"carving", "carv"}, {"cascadable", "cascad"}, {"cascade", "cascad"}, {"cascaded", "cascad"}, {"cascading", "cascad"}, {"case", "case"},
+		{"cased", "case"}, {"casement", "casement"}, {"cases", "case"}, {"cash", "cash"}, {"cashew", "cashew"}, {"cashier", "cashier"},
+		{"cashmere", "cashmer"}, {"casing", "case"}, {"casino", "casino"}, {"cask", "cask"}, {"casket", "casket"}, {"casserole", "casserol"},
+		{"cassette", "cassett"}, {"cassock", "cassock"}, {"cast", "cast"}, {"castaway", "castaway"}, {"caste", "cast"}, {"castigate", "castig"},
+		{"casting", "cast"}, {"castle", "castl"}, {"castor", "castor"}, {"castore", "castor"}, {"castrate", "castrat"},
+		{"castration", "castrat"}, {"casts", "cast"}, {"casual", "casual"}, {"casualty", "casualti"}, {"cat", "cat"}, {"cataclysm", "cataclysm"},
+		{"cataclysmic", "cataclysm"}, {"catacomb", "catacomb"}, {"catalog", "catalog"}, {"catalogs", "catalog"}, {"catalogue", "catalogu"},
+		{"catalyse", "catalys"}, {"catalyst", "catalyst"}, {"catalytic", "catalyt"}, {"catapult", "catapult"}, {"cataract", "cataract"},
+		{"catarrh", "catarrh"}, {"catastrophe", "catastroph"}, {"catastrophic", "catastroph"}, {"catch", "catch"}, {"catches", "catch"},
+		{"catching", "catch"}, {"catchment", "catchment"}, {"catchphrase", "catchphras"}, {"catchy", "catchi"}, {"catechism", "catech"},
+		{"categorical", "categor"}, {"categories", "categori"}, {"categorization", "categor"}, {"categorize", "categor"},
+		{"categorized", "categor"}, {"category", "categori"}, {"cater", "cater"}, {"caterer", "cater"}, {"caterpillar", "caterpillar"},
+		{"catfish", "catfish"}, {"catharsis", "catharsi"}, {"cathartic", "cathart"}, {"cathedral", "cathedr"}, {"catheter", "cathet"},
+		{"cathode", "cathod"}, {"catholic", "cathol"}, {"catkin", "catkin"}, {"cats", "cat"}, {"cattle", "cattl"}, {"catwalk", "catwalk"},
+		{"caucus", "caucus"}, {"cauldron", "cauldron"}, {"cauliflower", "cauliflow"}, {"causal", "causal"}, {"causality", "causal"},
+		{"causation", "causat"}, {"causative", "causat"}, {"cause", "caus"}, {"caused", "caus"}, {"causes", "caus"}, {"causeway", "causeway"},
+		{"causing", "caus"}, {"caustic", "caustic"}, {"cauterize", "cauter"}, {"caution", "caution"}, {"cautionary", "cautionari"},
+		{"cautions", "caution"}, {"cautious", "cautious"}, {"cautiously", "cautious"}, {"cavalcade", "cavalcad"}, {"cavalier", "cavali"},
+		{"cavalry", "cavalri"}, {"cave", "cave"}, {"caveat", "caveat"}, {"caveats", "caveat"}, {"caveman", "caveman"}, {"cavern", "cavern"},
+		{"cavernous", "cavern"}, {"caviar", "caviar"}, {"cavity", "caviti"}, {"cavort", "cavort"}, {"cease", "ceas"}, {"ceased", "ceas"},
+		{"ceasefire", "ceasefir"}, {"ceaseless", "ceaseless"}, {"ceases", "ceas"}, {"cedar", "cedar"}, {"cede", "cede"}, {"ceil", "ceil"},
+		{"ceiling", "ceil"}, {"ceilings", "ceil"}, {"celebrant", "celebr"}, {"celebrate", "celebr"}, {"celebration", "celebr"},
+		{"celebratory", "celebratori"}, {"celebrity", "celebr"}, {"celery", "celeri"}, {"celestial", "celesti"}, {"celibacy", "celibaci"},
+		{"celibate", "celib"}, {"cell", "cell"}, {"cellar", "cellar"}, {"cellist", "cellist"}, {"cello", "cello"}, {"cellophane", "cellophan"},
+		{"cells", "cell"}, {"cellular", "cellular"}, {"celluloid", "celluloid"}, {"cellulose", "cellulos"}, {"cement", "cement"},
+		{"cemetery", "cemeteri"}, {"cenotaph", "cenotaph"}, {"censor", "censor"}, {"censorious", "censori"}, {"censorship", "censorship"},
+		{"censure", "censur"}, {"census", "census"}, {"cent", "cent"}, {"centaur", "centaur"}, {"centenarian", "centenarian"},
+		{"centenary", "centenari"}, {"centennial", "centenni"}, {"center", "center"}, {"centered", "center"}, {"centigrade", "centigrad"},
+		{"centimeter", "centimet"}, {"centimetre", "centimetr"}, {"centipede", "centiped"}, {"central", "central"}, {"centralize", "central"},
+		{"centralized", "central"}, {"centralizing", "central"}, {"centrally", "central"}, {"centre", "centr"}, {"centrifugal", "centrifug"},
+		{"centrifuge", "centrifug"}, {"centurion", "centurion"}, {"century", "centuri"}, {"ceramic", "ceram"}, {"ceramics", "ceram"},
+		{"cereal", "cereal"}, {"cerebral", "cerebr"}, {"ceremonial", "ceremoni"}, {"ceremonious", "ceremoni"}, {"ceremony", "ceremoni"},
+		{"certain", "certain"}, {"certainly", "certain"}, {"certainty", "certainti"}, {"certifi", "certifi"}, {"certificate", "certif"},
+		{"certificates", "certif"}, {"certification", "certif"}, {"certifications", "certif"}, {"certified", "certifi"},
+		{"certifies", "certifi"}, {"certify", "certifi"}, {"certitude", "certitud"}, {"cervical", "cervic"}, {"cervix", "cervix"},
+		{"cessation", "cessat"}, {"cesspit", "cesspit"}, {"cesspool", "cesspool"}, {"chafe", "chafe"}, {"chaff", "chaff"},
+		{"chagrin", "chagrin"}, {"chain", "chain"}, {"chained", "chain"}, {"chaining", "chain"}, {"chains", "chain"}, {"chainsaw", "chainsaw"},
+		{"chair", "chair"}, {"chairman", "chairman"}, {"chairmanship", "chairmanship"}, {"chairperson", "chairperson"},
+		{"chairwoman", "chairwoman"}, {"chalet", "chalet"}, {"chalice", "chalic"}, {"chalk", "chalk"}, {"chalky", "chalki"},
+		{"challenge", "challeng"}, {"challenger", "challeng"}, {"challenges", "challeng"}, {"challenging", "challeng"}, {"chamber", "chamber"},
+		{"chamberlain", "chamberlain"}, {"chambermaid", "chambermaid"}, {"chameleon", "chameleon"}, {"champ", "champ"},
+		{"champagne", "champagn"}, {"champion", "champion"}, {"championship", "championship"}, {"chance", "chanc"}, {"chancel", "chancel"},
+		{"chancellor", "chancellor"}, {"chancery", "chanceri"}, {"chances", "chanc"}, {"chancy", "chanci"}, {"chandelier", "chandeli"},
+		{"chang", "chang"}, {"change", "chang"}, {"changeable", "changeabl"}, {"changed", "chang"}, {"changeover", "changeov"},
+		{"changeovers", "changeov"}, {"changes", "chang"}, {"changing", "chang"}, {"channel", "channel"}, {"channels", "channel"},
+		{"chant", "chant"}, {"chaos", "chao"}, {"chaotic", "chaotic"}, {"chap", "chap"}, {"chapel", "chapel"}, {"chaperone", "chaperon"},
+		{"chaplain", "chaplain"}, {"chapter", "chapter"}, {"chapters", "chapter"}, {"char", "char"}, {"character", "charact"},
+		{"characteristic", "characterist"}, {"characteristics", "characterist"}, {"characterization", "character"},
+		{"characterize", "character"}, {"characterized", "character"}, {"characters", "charact"}, {"charade", "charad"},
+		{"charcoal", "charcoal"}, {"charge", "charg"}, {"charged", "charg"}, {"charger", "charger"}, {"charges", "charg"}, {"chari", "chari"},
+		{"chariot", "chariot"}, {"charioteer", "chariot"}, {"charisma", "charisma"}, {"charismatic", "charismat"}, {"charitable", "charit"},
+		{"charity", "chariti"}, {"charlatan", "charlatan"}, {"charm", "charm"}, {"charmer", "charmer"}, {"chars", "char"}, {"chart", "chart"},
+		{"charter", "charter"}, {"chary", "chari"}, {"chase", "chase"}, {"chaser", "chaser"}, {"chasing", "chase"}, {"chasm", "chasm"},
+		{"chassis", "chassi"}, {"chaste", "chast"}, {"chasten", "chasten"}, {"chastise", "chastis"}, {"chastisement", "chastis"},
+		{"chastity", "chastiti"}, {"chat", "chat"}, {"chateau", "chateau"}, {"chatter", "chatter"}, {"chatterbox", "chatterbox"},
+		{"chatty", "chatti"}, {"chauffeur", "chauffeur"}, {"chauvinism", "chauvin"}, {"chauvinist", "chauvinist"}, {"cheap", "cheap"},
+		{"cheapen", "cheapen"}, {"cheat", "cheat"}, {"check", "check"}, {"checkbook", "checkbook"}, {"checked", "check"}, {"checker", "checker"},
+		{"checkers", "checker"}, {"checking", "check"}, {"checklist", "checklist"}, {"checkmate", "checkmat"}, {"checkout", "checkout"},
+		{"checkouts", "checkout"}, {"checkpoint", "checkpoint"}, {"checkpointing", "checkpoint"}, {"checkpoints", "checkpoint"},
+		{"checks", "check"}, {"checkup", "checkup"}, {"cheek", "cheek"}, {"cheeky", "cheeki"}, {"cheep", "cheep"}, {"cheer", "cheer"},
+		{"cheerful", "cheer"}, {"cheerio", "cheerio"}, {"cheerless", "cheerless"}, {"cheery", "cheeri"}, {"cheese", "chees"},
+		{"cheesecake", "cheesecak"}, {"cheetah", "cheetah"}, {"chef", "chef"}, {"chemical", "chemic"}, {"chemist", "chemist"},
+		{"chemistry", "chemistri"}, {"chemotherapy", "chemotherapi"}, {"cheque", "chequ"}, {"chequebook", "chequebook"}, {"cherish", "cherish"},
+		{"cheroot", "cheroot"}, {"cherry", "cherri"}, {"cherub", "cherub"}, {"chess", "chess"}, {"chessboard", "chessboard"}, {"chest", "chest"},
+		{"chestnut", "chestnut"}, {"chevron", "chevron"}, {"chew", "chew"}, {"chic", "chic"}, {"chicanery", "chicaneri"}, {"chick", "chick"},
+		{"chicken", "chicken"}, {"chickpea", "chickpea"}, {"chicory", "chicori"}, {"chide", "chide"}, {"chief", "chief"},
+		{"chieftain", "chieftain"}, {"chiffon", "chiffon"}, {"chilblain", "chilblain"}, {"child", "child"}, {"childbirth", "childbirth"},
+		{"childhood", "childhood"}, {"childish", "childish"}, {"childless", "childless"}, {"childlike", "childlik"}, {"childs", "child"},
+		{"chill", "chill"}, {"chilli", "chilli"}, {"chilly", "chilli"}, {"chime", "chime"}, {"chimney", "chimney"}, {"chimpanzee", "chimpanze"},
+		{"chin", "chin"}, {"china", "china"}, {"chink", "chink"}, {"chip", "chip"}, {"chipmunk", "chipmunk"}, {"chips", "chip"},
+		{"chiropodist", "chiropodist"}, {"chiropody", "chiropodi"}, {"chiropractor", "chiropractor"}, {"chirp", "chirp"}, {"chisel", "chisel"},
+		{"chit", "chit"}, {"chivalrous", "chivalr"}, {"chivalry", "chivalri"}, {"chive", "chive"}, {"chlorinate", "chlorin"},
+		{"chlorine", "chlorin"}, {"chloroform", "chloroform"}, {"chlorophyll", "chlorophyl"}, {"chock", "chock"}, {"chocolate", "chocol"},
+		{"choice", "choic"}, {"choices", "choic"}, {"choir", "choir"}, {"choirboy", "choirboy"}, {"choke", "choke"}, {"choked", "choke"},
+		{"cholera", "cholera"}, {"cholesterol", "cholesterol"}, {"choose", "choos"}, {"chooses", "choos"}, {"choosing", "choos"},
+		{"choosy", "choosi"}, {"chop", "chop"}, {"chopped", "chop"}, {"chopper", "chopper"}, {"chopping", "chop"}, {"choppy", "choppi"},
+		{"chopstick", "chopstick"}, {"choral", "choral"}, {"chord", "chord"}, {"chore", "chore"}, {"choreographer", "choreograph"},
+		{"choreography", "choreographi"}, {"chorister", "chorist"}, {"chortle", "chortl"}, {"chorus", "chorus"}, {"chowder", "chowder"},
+		{"christen", "christen"}, {"christening", "christen"}, {"chrome", "chrome"}, {"chromium", "chromium"}, {"chromosome", "chromosom"},
+		{"chronic", "chronic"}, {"chronicle", "chronicl"}, {"chronicler", "chronicl"}, {"chronological", "chronolog"},
+		{"chronologically", "chronolog"}, {"chronology", "chronolog"}, {"chronometer", "chronomet"}, {"chrysalis", "chrysali"},
+		{"chrysanthemum", "chrysanthemum"}, {"chubby", "chubbi"}, {"chuck", "chuck"}, {"chuckle", "chuckl"}, {"chug", "chug"}, {"chum", "chum"},
+		{"chunk", "chunk"}, {"chunked", "chunk"}, {"chunking", "chunk"}, {"chunks", "chunk"}, {"chunky", "chunki"}, {"church", "church"},
+		{"churchyard", "churchyard"}, {"churlish", "churlish"}, {"churn", "churn"}, {"chute", "chute"}, {"chutney", "chutney"},
+		{"cicada", "cicada"}, {"cider", "cider"}, {"cigar", "cigar"}, {"cigarette", "cigarett"}, {"cinch", "cinch"}, {"cinder", "cinder"},
+		{"cinema", "cinema"}, {"cinemas", "cinema"}, {"cinematic", "cinemat"}, {"cinematography", "cinematographi"}, {"cinnamon", "cinnamon"},
+		{"cipher", "cipher"}, {"ciphers", "cipher"}, {"circa", "circa"}, {"circle", "circl"}, {"circles", "circl"}, {"circling", "circl"},
+		{"circuit", "circuit"}, {"circuitous", "circuit"}, {"circuits", "circuit"}, {"circular", "circular"}, {"circulate", "circul"},
+		{"circulation", "circul"}, {"circulatory", "circulatori"}, {"circumcise", "circumcis"}, {"circumcision", "circumcis"},
+		{"circumference", "circumfer"}, {"circumflex", "circumflex"}, {"circumlocution", "circumlocut"}, {"circumnavigate", "circumnavig"},
+		{"circumscribe", "circumscrib"}, {"circumspect", "circumspect"}, {"circumstance", "circumst"}, {"circumstances", "circumst"},
+		{"circumstantial", "circumstanti"}, {"circumvent", "circumv"}, {"circumvented", "circumv"}, {"circus", "circus"},
+		{"cirrhosis", "cirrhosi"}, {"cistern", "cistern"}, {"citadel", "citadel"}, {"citation", "citat"}, {"cite", "cite"}, {"cited", "cite"},
+		{"cities", "citi"}, {"citing", "cite"}, {"citizen", "citizen"}, {"citizenry", "citizenri"}, {"citizenship", "citizenship"},
+		{"citric", "citric"}, {"citrus", "citrus"}, {"city", "citi"}, {"civic", "civic"}, {"civics", "civic"}, {"civil", "civil"},
+		{"civilian", "civilian"}, {"civility", "civil"}, {"civilization", "civil"}, {"civilize", "civil"}, {"clad", "clad"}, {"claim", "claim"},
+		{"claimant", "claimant"}, {"claimed", "claim"}, {"claiming", "claim"}, {"claims", "claim"}, {"clairvoyance", "clairvoy"},
+		{"clairvoyant", "clairvoy"}, {"clam", "clam"}, {"clamber", "clamber"}, {"clammy", "clammi"}, {"clamor", "clamor"},
+		{"clamour", "clamour"}, {"clamp", "clamp"}, {"clampdown", "clampdown"}, {"clamped", "clamp"}, {"clamping", "clamp"}, {"clan", "clan"},
+		{"clandestine", "clandestin"}, {"clang", "clang"}, {"clank", "clank"}, {"clap", "clap"}, {"clapper", "clapper"},
+		{"claptrap", "claptrap"}, {"claret", "claret"}, {"clarification", "clarif"}, {"clarifications", "clarif"}, {"clarified", "clarifi"},
+		{"clarifies", "clarifi"}, {"clarify", "clarifi"}, {"clarifying", "clarifi"}, {"clarinet", "clarinet"}, {"clarinettist", "clarinettist"},
+		{"clarion", "clarion"}, {"clarity", "clariti"}, {"clash", "clash"}, {"clashes", "clash"}, {"clashing", "clash"}, {"clasp", "clasp"},
+		{"class", "class"}, {"classes", "class"}, {"classful", "class"}, {"classic", "classic"}, {"classical", "classic"},
+		{"classicism", "classic"}, {"classicist", "classicist"}, {"classification", "classif"}, {"classifications", "classif"},
+		{"classified", "classifi"}, {"classifier", "classifi"}, {"classifiers", "classifi"}, {"classifies", "classifi"},
+		{"classify", "classifi"}, {"classifying", "classifi"}, {"classmate", "classmat"}, {"classroom", "classroom"}, {"classy", "classi"},
+		{"clatter", "clatter"}, {"clause", "claus"}, {"clauses", "claus"}, {"claustrophobia", "claustrophobia"},
+		{"claustrophobic", "claustrophob"}, {"claw", "claw"}, {"clay", "clay"}, {"clean", "clean"}, {"cleaned", "clean"}, {"cleaner", "cleaner"},
+		{"cleaning", "clean"}, {"cleanliness", "cleanli"}, {"cleanly", "clean"}, {"cleans", "clean"}, {"cleanse", "cleans"},
+		{"cleanser", "cleanser"}, {"clear", "clear"}, {"clearance", "clearanc"}, {"cleared", "clear"}, {"clearing", "clear"},
+		{"clearly", "clear"}, {"clears", "clear"}, {"cleavage", "cleavag"}, {"cleave", "cleav"}, {"cleaver", "cleaver"}, {"clef", "clef"},
+		{"cleft", "cleft"}, {"clemency", "clemenc"}, {"clement", "clement"}, {"clench", "clench"}, {"clergy", "clergi"},
+		{"clergyman", "clergyman"}, {"cleric", "cleric"}, {"clerical", "cleric"}, {"clerk", "clerk"}, {"clever", "clever"}, {"cliche", "clich"},
+		{"click", "click"}, {"clicked", "click"}, {"clicking", "click"}, {"clicks", "click"}, {"client", "client"}, {"clientele", "clientel"},
+		{"clients", "client"}, {"cliff", "cliff"}, {"climactic", "climact"}, {"climate", "climat"}, {"climatic", "climat"}, {"climax", "climax"},
+		{"climb", "climb"}, {"climber", "climber"}, {"climbs", "climb"}, {"clinch", "clinch"}, {"cling", "cling"}, {"clinic", "clinic"},
+		{"clinical", "clinic"}, {"clinician", "clinician"}, {"clink", "clink"}, {"clip", "clip"}, {"clipboard", "clipboard"},
+		{"clipped", "clip"}, {"clipper", "clipper"}, {"clipping", "clip"}, {"clique", "cliqu"}, {"cloak", "cloak"}, {"cloakroom", "cloakroom"},
+		{"clobber", "clobber"}, {"clobbered", "clobber"}, {"clobbering", "clobber"}, {"clobbers", "clobber"}, {"clock", "clock"},
+		{"clocks", "clock"}, {"clockwise", "clockwis"}, {"clockwork", "clockwork"}, {"clod", "clod"}, {"clog", "clog"}, {"cloister", "cloister"},
+		{"clone", "clone"}, {"cloned", "clone"}, {"clones", "clone"}, {"cloning", "clone"}, {"close", "close"}, {"closed", "close"},
+		{"closely", "close"}, {"closes", "close"}, {"closet", "closet"}, {"closing", "close"}, {"closure", "closur"}, {"clot", "clot"},
+		{"cloth", "cloth"}, {"clothe", "cloth"}, {"clothes", "cloth"}, {"clothing", "cloth"}, {"cloud", "cloud"}, {"cloudburst", "cloudburst"},
+		{"cloudless", "cloudless"}, {"clouds", "cloud"}, {"cloudy", "cloudi"}, {"clout", "clout"}, {"clove", "clove"}, {"clover", "clover"},
+		{"clown", "clown"}, {"clownish", "clownish"}, {"club", "club"}, {"clubbed", "club"}, {"clubhouse", "clubhous"}, {"cluck", "cluck"},
+		{"clue", "clue"}, {"clueless", "clueless"}, {"clump", "clump"}, {"clumsy", "clumsi"}, {"cluster", "cluster"}, {"clustered", "cluster"},
+		{"clustering", "cluster"}, {"clusters", "cluster"}, {"clutch", "clutch"}, {"clutter", "clutter"}, {"cluttered", "clutter"},
+		{"cluttering", "clutter"}, {"coach", "coach"}, {"coachman", "coachman"}, {"coagulate", "coagul"}, {"coal", "coal"},
+		{"coalesce", "coalesc"}, {"coalesced", "coalesc"}, {"coalescing", "coalesc"}, {"coalface", "coalfac"}, {"coalfield", "coalfield"},
+		{"coalition", "coalit"}, {"coarse", "coars"}, {"coarsen", "coarsen"}, {"coast", "coast"}, {"coastal", "coastal"}, {"coaster", "coaster"},
+		{"coastguard", "coastguard"}, {"coastline", "coastlin"}, {"coat", "coat"}, {"coating", "coat"}, {"coax", "coax"}, {"cob", "cob"},
+		{"cobalt", "cobalt"}, {"cobble", "cobbl"}, {"cobbler", "cobbler"}, {"cobra", "cobra"}, {"cobweb", "cobweb"}, {"cocaine", "cocain"},
+		{"cock", "cock"}, {"cockerel", "cockerel"}, {"cockney", "cockney"}, {"cockpit", "cockpit"}, {"cockroach", "cockroach"},
+		{"cocktail", "cocktail"}, {"cocky", "cocki"}, {"cocoa", "cocoa"}, {"coconut", "coconut"}, {"cocoon", "cocoon"}, {"cod", "cod"},
+		{"coddle", "coddl"}, {"code", "code"}, {"coded", "code"}, {"codes", "code"}, {"codify", "codifi"}, {"coding", "code"},
+		{"codings", "code"}, {"coerce", "coerc"}, {"coerced", "coerc"}, {"coerces", "coerc"}, {"coercing", "coerc"}, {"coercion", "coercion"},
+		{"coercive", "coerciv"}, {"coexist", "coexist"}, {"coexistence", "coexist"}, {"coffee", "coffe"}, {"coffer", "coffer"},
+		{"coffin", "coffin"}, {"cog", "cog"}, {"cogent", "cogent"}, {"cogitate", "cogit"}, {"cognac", "cognac"}, {"cognition", "cognit"},
+		{"cognitive", "cognit"}, {"cognizant", "cogniz"}, {"cohabit", "cohabit"}, {"cohere", "coher"}, {"coherence", "coher"},
+		{"coherency", "coher"}, {"coherent", "coher"}, {"cohesion", "cohes"}, {"cohesive", "cohes"}, {"cohort", "cohort"}, {"coil", "coil"},
+		{"coin", "coin"}, {"coinage", "coinag"}, {"coincide", "coincid"}, {"coincidence", "coincid"}, {"coincident", "coincid"},
+		{"coincidental", "coincident"}, {"coined", "coin"}, {"coke", "coke"}, {"colander", "coland"}, {"cold", "cold"}, {"coleslaw", "coleslaw"},
+		{"colic", "colic"}, {"collaborate", "collabor"}, {"collaboration", "collabor"}, {"collaborative", "collabor"},
+		{"collaborator", "collabor"}, {"collaborators", "collabor"}, {"collage", "collag"}, {"collapse", "collaps"}, {"collapsed", "collaps"},
+		{"collapses", "collaps"}, {"collapsible", "collaps"}, {"collapsing", "collaps"}, {"collar", "collar"}, {"collarbone", "collarbon"},
+		{"collatable", "collat"}, {"collate", "collat"}, {"collateral", "collater"}, {"collates", "collat"}, {"collating", "collat"},
+		{"collation", "collat"}, {"collations", "collat"}, {"colleague", "colleagu"}, {"colleagues", "colleagu"}, {"collect", "collect"},
+		{"collectable", "collect"}, {"collected", "collect"}, {"collectible", "collect"}, {"collecting", "collect"}, {"collection", "collect"},
+		{"collections", "collect"}, {"collective", "collect"}, {"collectively", "collect"}, {"collectivism", "collectiv"},
+		{"collector", "collector"}, {"collectors", "collector"}, {"collects", "collect"}, {"college", "colleg"}, {"collegiate", "collegi"},
+		{"collide", "collid"}, {"collides", "collid"}, {"colliding", "collid"}, {"collie", "colli"}, {"colliery", "collieri"},
+		{"collision", "collis"}, {"collisions", "collis"}, {"colloquial", "colloqui"}, {"colloquialism", "colloqui"}, {"collusion", "collus"},
+		{"cologne", "cologn"}, {"colon", "colon"}, {"colonel", "colonel"}, {"colonial", "coloni"}, {"colonialism", "coloni"},
+		{"colonist", "colonist"}, {"colonize", "colon"}, {"colonnade", "colonnad"}, {"colons", "colon"}, {"colony", "coloni"},
+		{"color", "color"}, {"coloration", "color"}, {"colored", "color"}, {"coloring", "color"}, {"colorization", "color"},
+		{"colorize", "color"}, {"colorized", "color"}, {"colorizes", "color"}, {"colors", "color"}, {"colossal", "coloss"},
+		{"colossus", "colossus"}, {"colour", "colour"}, {"coloured", "colour"}, {"colourful", "colour"}, {"colouring", "colour"},
+		{"colours", "colour"}, {"colt", "colt"}, {"column", "column"}, {"columnation", "column"}, {"columnist", "columnist"},
+		{"columns", "column"}, {"coma", "coma"}, {"comatose", "comatos"}, {"comb", "comb"}, {"combat", "combat"}, {"combatant", "combat"},
+		{"combative", "combat"}, {"combination", "combin"}, {"combinations", "combin"}, {"combine", "combin"}, {"combined", "combin"},
+		{"combiner", "combin"}, {"combines", "combin"}, {"combining", "combin"}, {"combs", "comb"}, {"combustible", "combust"},
+		{"combustion", "combust"}, {"come", "come"}, {"comeback", "comeback"}, {"comedian", "comedian"}, {"comedienne", "comedienn"},
+		{"comedies", "comedi"}, {"comedown", "comedown"}, {"comedy", "comedi"}, {"comely", "come"}, {"comes", "come"}, {"comet", "comet"},
+		{"comfort", "comfort"}, {"comfortable", "comfort"}, {"comfortably", "comfort"}, {"comforter", "comfort"}, {"comic", "comic"},
+		{"comical", "comic"}, {"coming", "come"}, {"comma", "comma"}, {"command", "command"}, {"commandant", "command"},
+		{"commandeer", "command"}, {"commander", "command"}, {"commandment", "command"}, {"commando", "commando"}, {"commands", "command"},
+		{"commas", "comma"}, {"commemorate", "commemor"}, {"commemoration", "commemor"}, {"commence", "commenc"}, {"commenced", "commenc"},
+		{"commencement", "commenc"}, {"commences", "commenc"}, {"commend", "commend"}, {"commendable", "commend"}, {"commendation", "commend"},
+		{"commensurate", "commensur"}, {"comment", "comment"}, {"commentary", "commentari"}, {"commentate", "comment"},
+		{"commentator", "comment"}, {"commented", "comment"}, {"commenter", "comment"}, {"commenting", "comment"}, {"comments", "comment"},
+		{"commerce", "commerc"}, {"commercial", "commerci"}, {"commercialism", "commerci"}, {"commercialize", "commerci"},
+		{"commercially", "commerci"}, {"commiserate", "commiser"}, {"commiseration", "commiser"}, {"commissar", "commissar"},
+		{"commission", "commiss"}, {"commissionaire", "commissionair"}, {"commissioner", "commission"}, {"commit", "commit"},
+		{"commitment", "commit"}, {"commitments", "commit"}, {"commits", "commit"}, {"committed", "commit"}, {"committee", "committe"},
+		{"committees", "committe"}, {"committing", "commit"}, {"commode", "commod"}, {"commodity", "commod"}, {"commodore", "commodor"},
+		{"common", "common"}, {"commoner", "common"}, {"commonly", "common"}, {"commonplace", "commonplac"}, {"commons", "common"},
+		{"commonwealth", "commonwealth"}, {"commotion", "commot"}, {"communal", "communal"}, {"commune", "commune"},
+		{"communicable", "communic"}, {"communicant", "communic"}, {"communicate", "communic"}, {"communicated", "communic"},
+		{"communicates", "communic"}, {"communicating", "communic"}, {"communication", "communic"}, {"communications", "communic"},
+		{"communicative", "communic"}, {"communion", "communion"}, {"communique", "communiqu"}, {"communism", "communism"},
+		{"communist", "communist"}, {"communities", "communiti"}, {"community", "communiti"}, {"commutative", "commut"},
+		{"commutativity", "commut"}, {"commutator", "commut"}, {"commute", "commut"}, {"commuter", "commut"}, {"compact", "compact"},
+		{"compacted", "compact"}, {"compaction", "compact"}, {"compactly", "compact"}, {"compacts", "compact"}, {"companies", "compani"},
+		{"companion", "companion"}, {"companionable", "companion"}, {"companionship", "companionship"}, {"company", "compani"},
+		{"compar", "compar"}, {"comparable", "compar"}, {"comparably", "compar"}, {"comparative", "compar"}, {"comparatively", "compar"},
+		{"compare", "compar"}, {"compared", "compar"}, {"compares", "compar"}, {"comparing", "compar"}, {"comparison", "comparison"},
+		{"comparisons", "comparison"}, {"compartment", "compart"}, {"compass", "compass"}, {"compassion", "compass"},
+		{"compassionate", "compassion"}, {"compat", "compat"}, {"compatibility", "compat"}, {"compatible", "compat"}, {"compatibly", "compat"},
+		{"compatriot", "compatriot"}, {"compel", "compel"}, {"compelled", "compel"}, {"compendium", "compendium"}, {"compensate", "compens"},
+		{"compensated", "compens"}, {"compensates", "compens"}, {"compensation", "compens"}, {"compensatory", "compensatori"},
+		{"compere", "comper"}, {"compete", "compet"}, {"competence", "compet"}, {"competent", "compet"}, {"competes", "compet"},
+		{"competing", "compet"}, {"competition", "competit"}, {"competitive", "competit"}, {"competitor", "competitor"},
+		{"competitors", "competitor"}, {"compilable", "compil"}, {"compilation", "compil"}, {"compilations", "compil"}, {"compile", "compil"},
+		{"compiled", "compil"}, {"compiler", "compil"}, {"compilers", "compil"}, {"compiles", "compil"}, {"compiling", "compil"},
+		{"complacency", "complac"}, {"complacent", "complac"}, {"complain", "complain"}, {"complainant", "complain"}, {"complained", "complain"},
+		{"complaining", "complain"}, {"complains", "complain"}, {"complaint", "complaint"}, {"complaints", "complaint"},
+		{"complement", "complement"}, {"complementary", "complementari"}, {"complemented", "complement"}, {"complements", "complement"},
+		{"complete", "complet"}, {"completed", "complet"}, {"completely", "complet"}, {"completeness", "complet"}, {"completer", "complet"},
+		{"completes", "complet"}, {"completing", "complet"}, {"completion", "complet"}, {"completions", "complet"}, {"complex", "complex"},
+		{"complexion", "complexion"}, {"complexity", "complex"}, {"compliance", "complianc"}, {"compliances", "complianc"},
+		{"compliant", "compliant"}, {"complicate", "complic"}, {"complicated", "complic"}, {"complicates", "complic"},
+		{"complicating", "complic"}, {"complication", "complic"}, {"complications", "complic"}, {"complicity", "complic"},
+		{"complies", "compli"}, {"compliment", "compliment"}, {"complimentary", "complimentari"}, {"comply", "compli"}, {"component", "compon"},
+		{"components", "compon"}, {"comport", "comport"}, {"compose", "compos"}, {"composed", "compos"}, {"composer", "compos"},
+		{"composes", "compos"}, {"composing", "compos"}, {"composite", "composit"}, {"composited", "composit"}, {"compositing", "composit"},
+		{"composition", "composit"}, {"compositor", "compositor"}, {"compost", "compost"}, {"composure", "composur"}, {"compound", "compound"},
+		{"comprehend", "comprehend"}, {"comprehensible", "comprehens"}, {"comprehension", "comprehens"}, {"comprehensions", "comprehens"},
+		{"comprehensive", "comprehens"}, {"compress", "compress"}, {"compressed", "compress"}, {"compresses", "compress"},
+		{"compressible", "compress"}, {"compressing", "compress"}, {"compression", "compress"}, {"compressor", "compressor"},
+		{"compressors", "compressor"}, {"comprise", "compris"}, {"comprised", "compris"}, {"comprises", "compris"}, {"comprising", "compris"},
+		{"compromise", "compromis"}, {"compromised", "compromis"}, {"compromising", "compromis"}, {"compulsion", "compuls"},
+		{"compulsive", "compuls"}, {"compulsory", "compulsori"}, {"compunction", "compunct"}, {"computation", "comput"},
+		{"computational", "comput"}, {"computationally", "comput"}, {"computations", "comput"}, {"compute", "comput"}, {"computed", "comput"},
+		{"computer", "comput"}, {"computerize", "computer"}, {"computers", "comput"}, {"computes", "comput"}, {"computing", "comput"},
+		{"comrade", "comrad"}, {"comradeship", "comradeship"}, {"con", "con"}, {"concave", "concav"}, {"conceal", "conceal"},
+		{"concealment", "conceal"}, {"concede", "conced"}, {"conceit", "conceit"}, {"conceited", "conceit"}, {"conceivable", "conceiv"},
+		{"conceivably", "conceiv"}, {"conceive", "conceiv"}, {"concentrate", "concentr"}, {"concentrated", "concentr"},
+		{"concentration", "concentr"}, {"concentrators", "concentr"}, {"concentric", "concentr"}, {"concept", "concept"},
+		{"conception", "concept"}, {"concepts", "concept"}, {"conceptual", "conceptu"}, {"conceptually", "conceptu"}, {"concern", "concern"},
+		{"concerned", "concern"}, {"concerning", "concern"}, {"concerns", "concern"}, {"concert", "concert"}, {"concerted", "concert"},
+		{"concertina", "concertina"}, {"concerto", "concerto"}, {"concession", "concess"}, {"conch", "conch"}, {"conciliate", "concili"},
+		{"conciliation", "concili"}, {"conciliatory", "conciliatori"}, {"concise", "concis"}, {"concisely", "concis"}, {"concision", "concis"},
+		{"conclave", "conclav"}, {"conclude", "conclud"}, {"concluded", "conclud"}, {"concluding", "conclud"}, {"conclusion", "conclus"},
+		{"conclusions", "conclus"}, {"conclusive", "conclus"}, {"concoct", "concoct"}, {"concoction", "concoct"}, {"concomitant", "concomit"},
+		{"concord", "concord"}, {"concordance", "concord"}, {"concourse", "concours"}, {"concrete", "concret"}, {"concubine", "concubin"},
+		{"concur", "concur"}, {"concurent", "concur"}, {"concurrence", "concurr"}, {"concurrency", "concurr"}, {"concurrent", "concurr"},
+		{"concurrently", "concurr"}, {"concussion", "concuss"}, {"condemn", "condemn"}, {"condemnation", "condemn"}, {"condensation", "condens"},
+		{"condense", "condens"}, {"condensed", "condens"}, {"condescend", "condescend"}, {"condescension", "condescens"},
+		{"condiment", "condiment"}, {"condition", "condit"}, {"conditional", "condit"}, {"conditionalize", "condition"},
+		{"conditionalizing", "condition"}, {"conditionally", "condit"}, {"conditionals", "condit"}, {"conditioned", "condit"},
+		{"conditioner", "condition"}, {"conditions", "condit"}, {"condolence", "condol"}, {"condom", "condom"}, {"condominium", "condominium"},
+		{"condone", "condon"}, {"condor", "condor"}, {"conducive", "conduc"}, {"conduct", "conduct"}, {"conducted", "conduct"},
+		{"conducting", "conduct"}, {"conduction", "conduct"}, {"conductive", "conduct"}, {"conductivity", "conduct"}, {"conductor", "conductor"},
+		{"conducts", "conduct"}, {"conduit", "conduit"}, {"cone", "cone"}, {"confection", "confect"}, {"confectioner", "confection"},
+		{"confectionery", "confectioneri"}, {"confederacy", "confederaci"}, {"confederate", "confeder"}, {"confederation", "confeder"},
+		{"confer", "confer"}, {"conference", "confer"}, {"conferred", "confer"}, {"confers", "confer"}, {"confess", "confess"},
+		{"confession", "confess"}, {"confessional", "confession"}, {"confessor", "confessor"}, {"confetti", "confetti"}, {"confidant", "confid"},
+		{"confide", "confid"}, {"confidence", "confid"}, {"confident", "confid"}, {"confidential", "confidenti"},
+		{"confidentiality", "confidenti"}, {"configurability", "configur"}, {"configurable", "configur"}, {"configuration", "configur"},
+		{"configurations", "configur"}, {"configure", "configur"}, {"configured", "configur"}, {"configures", "configur"},
+		{"configuring", "configur"}, {"confine", "confin"}, {"confined", "confin"}, {"confinement", "confin"}, {"confirm", "confirm"},
+		{"confirmation", "confirm"}, {"confirmations", "confirm"}, {"confirmed", "confirm"}, {"confirming", "confirm"}, {"confirms", "confirm"},
+		{"confiscate", "confisc"}, {"confiscation", "confisc"}, {"conflagration", "conflagr"}, {"conflate", "conflat"}, {"conflated", "conflat"},
+		{"conflict", "conflict"}, {"conflicted", "conflict"}, {"conflicting", "conflict"}, {"conflicts", "conflict"},
+		{"confluence", "confluenc"}, {"conform", "conform"}, {"conformance", "conform"}, {"conformant", "conform"}, {"conforming", "conform"},
+		{"conformist", "conformist"}, {"conformity", "conform"}, {"conforms", "conform"}, {"confound", "confound"}, {"confront", "confront"},
+		{"confrontation", "confront"}, {"confrontational", "confront"}, {"confusable", "confus"}, {"confuse", "confus"}, {"confused", "confus"},
+		{"confuses", "confus"}, {"confusing", "confus"}, {"confusingly", "confus"}, {"confusion", "confus"}, {"congeal", "congeal"},
+		{"congenial", "congeni"}, {"congenital", "congenit"}, {"congested", "congest"}, {"congestion", "congest"}, {"conglomerate", "conglomer"},
+		{"conglomeration", "conglomer"}, {"congratulate", "congratul"}, {"congratulation", "congratul"}, {"congratulations", "congratul"},
+		{"congratulatory", "congratulatori"}, {"congregate", "congreg"}, {"congregation", "congreg"}, {"congress", "congress"},
+		{"congressional", "congression"}, {"congressman", "congressman"}, {"conical", "conic"}, {"conifer", "conif"}, {"coniferous", "conifer"},
+		{"conjecture", "conjectur"}, {"conjugal", "conjug"}, {"conjugate", "conjug"}, {"conjugation", "conjug"}, {"conjunction", "conjunct"},
+		{"conjure", "conjur"}, {"conjurer", "conjur"}, {"conk", "conk"}, {"connect", "connect"}, {"connected", "connect"},
+		{"connecting", "connect"}, {"connection", "connect"}, {"connections", "connect"}, {"connective", "connect"}, {"connectivity", "connect"},
+		{"connector", "connector"}, {"connectors", "connector"}, {"connects", "connect"}, {"connoisseur", "connoisseur"},
+		{"connotation", "connot"}, {"connote", "connot"}, {"conquer", "conquer"}, {"conqueror", "conqueror"}, {"conquest", "conquest"},
+		{"cons", "con"}, {"conscience", "conscienc"}, {"conscientious", "conscienti"}, {"conscious", "conscious"},
+		{"consciousness", "conscious"}, {"conscript", "conscript"}, {"conscription", "conscript"}, {"consecrate", "consecr"},
+		{"consecration", "consecr"}, {"consecutive", "consecut"}, {"consensus", "consensus"}, {"consent", "consent"}, {"consented", "consent"},
+		{"consents", "consent"}, {"consequence", "consequ"}, {"consequences", "consequ"}, {"consequent", "consequ"},
+		{"consequential", "consequenti"}, {"consequently", "consequ"}, {"conservation", "conserv"}, {"conservationist", "conservationist"},
+		{"conservatism", "conservat"}, {"conservative", "conserv"}, {"conservatively", "conserv"}, {"conservatory", "conservatori"},
+		{"conserve", "conserv"}, {"conserving", "conserv"}, {"consider", "consid"}, {"considerable", "consider"}, {"considerably", "consider"},
+		{"considerate", "consider"}, {"consideration", "consider"}, {"considerations", "consider"}, {"considered", "consid"},
+		{"considering", "consid"}, {"considers", "consid"}, {"consign", "consign"}, {"consignment", "consign"}, {"consist", "consist"},
+		{"consisted", "consist"}, {"consistency", "consist"}, {"consistent", "consist"}, {"consistently", "consist"}, {"consisting", "consist"},
+		{"consists", "consist"}, {"consolation", "consol"}, {"console", "consol"}, {"consoles", "consol"}, {"consolidate", "consolid"},
+		{"consolidated", "consolid"}, {"consolidates", "consolid"}, {"consolidation", "consolid"}, {"consolidator", "consolid"},
+		{"consonant", "conson"}, {"consort", "consort"}, {"consortium", "consortium"}, {"conspicuous", "conspicu"},
+		{"conspicuously", "conspicu"}, {"conspiracy", "conspiraci"}, {"conspirator", "conspir"}, {"conspiratorial", "conspiratori"},
+		{"conspire", "conspir"}, {"constable", "constabl"}, {"constabulary", "constabulari"}, {"constancy", "constanc"},
+		{"constant", "constant"}, {"constantly", "constant"}, {"constants", "constant"}, {"constellation", "constel"},
+		{"consternation", "constern"}, {"constipate", "constip"}, {"constipation", "constip"}, {"constituencies", "constitu"},
+		{"constituency", "constitu"}, {"constituent", "constitu"}, {"constitute", "constitut"}, {"constitutes", "constitut"},
+		{"constitution", "constitut"}, {"constitutional", "constitut"}, {"constrain", "constrain"}, {"constrained", "constrain"},
+		{"constrains", "constrain"}, {"constraint", "constraint"}, {"constraints", "constraint"}, {"constrict", "constrict"},
+		{"constriction", "constrict"}, {"construct", "construct"}, {"constructed", "construct"}, {"constructing", "construct"},
+		{"construction", "construct"}, {"constructions", "construct"}, {"constructive", "construct"}, {"constructor", "constructor"},
+		{"constructors", "constructor"}, {"constructs", "construct"}, {"construe", "constru"}, {"construed", "constru"}, {"consul", "consul"},
+		{"consular", "consular"}, {"consulate", "consul"}, {"consult", "consult"}, {"consultancy", "consult"}, {"consultant", "consult"},
+		{"consultation", "consult"}, {"consultative", "consult"}, {"consulted", "consult"}, {"consulting", "consult"}, {"consults", "consult"},
+		{"consumable", "consum"}, {"consume", "consum"}, {"consumed", "consum"}, {"consumer", "consum"}, {"consumerism", "consumer"},
+		{"consumers", "consum"}, {"consumes", "consum"}, {"consuming", "consum"}, {"consummate", "consumm"}, {"consummation", "consumm"},
+		{"consumption", "consumpt"}, {"contact", "contact"}, {"contacted", "contact"}, {"contacting", "contact"}, {"contacts", "contact"},
+		{"contagion", "contagion"}, {"contagious", "contagi"}, {"contain", "contain"}, {"contained", "contain"}, {"container", "contain"},
+		{"containers", "contain"}, {"containing", "contain"}, {"containment", "contain"}, {"contains", "contain"}, {"contaminant", "contamin"},
+		{"contaminate", "contamin"}, {"contaminated", "contamin"}, {"contamination", "contamin"}, {"contemplate", "contempl"},
+		{"contemplation", "contempl"}, {"contemplative", "contempl"}, {"contemporary", "contemporari"}, {"contempt", "contempt"},
+		{"contemptible", "contempt"}, {"contemptuous", "contemptu"}, {"contend", "contend"}, {"contended", "contend"}, {"contender", "contend"},
+		{"content", "content"}, {"contention", "content"}, {"contentious", "contenti"}, {"contentment", "content"}, {"contents", "content"},
+		{"contest", "contest"}, {"contestant", "contest"}, {"context", "context"}, {"contexts", "context"}, {"contextual", "contextu"},
+		{"contiguous", "contigu"}, {"contiguously", "contigu"}, {"continence", "contin"}, {"continent", "contin"}, {"continental", "continent"},
+		{"contingency", "conting"}, {"contingent", "conting"}, {"continual", "continu"}, {"continually", "continu"}, {"continuance", "continu"},
+		{"continuation", "continu"}, {"continuations", "continu"}, {"continue", "continu"}, {"continued", "continu"}, {"continues", "continu"},
+		{"continuing", "continu"}, {"continuity", "continu"}, {"continuous", "continu"}, {"continuously", "continu"}, {"continuum", "continuum"},
+		{"contort", "contort"}, {"contortion", "contort"}, {"contour", "contour"}, {"contraband", "contraband"}, {"contraception", "contracept"},
+		{"contraceptive", "contracept"}, {"contract", "contract"}, {"contraction", "contract"}, {"contractions", "contract"},
+		{"contractor", "contractor"}, {"contractual", "contractu"}, {"contradict", "contradict"}, {"contradicting", "contradict"},
+		{"contradiction", "contradict"}, {"contradictory", "contradictori"}, {"contradicts", "contradict"}, {"contraption", "contrapt"},
+		{"contrary", "contrari"}, {"contrast", "contrast"}, {"contrasts", "contrast"}, {"contravene", "contraven"},
+		{"contravention", "contravent"}, {"contribute", "contribut"}, {"contributed", "contribut"}, {"contributes", "contribut"},
+		{"contributing", "contribut"}, {"contribution", "contribut"}, {"contributions", "contribut"}, {"contributor", "contributor"},
+		{"contributors", "contributor"}, {"contributory", "contributori"}, {"contrite", "contrit"}, {"contrition", "contrit"},
+		{"contrivance", "contriv"}, {"contrive", "contriv"}, {"contrived", "contriv"}, {"control", "control"}, {"controle", "control"},
+		{"controllable", "control"}, {"controlled", "control"}, {"controller", "control"}, {"controllers", "control"},
+		{"controlling", "control"}, {"controls", "control"}, {"controversial", "controversi"}, {"controversy", "controversi"},
+		{"conundrum", "conundrum"}, {"conurbation", "conurb"}, {"convalesce", "convalesc"}, {"convalescence", "convalesc"},
+		{"convalescent", "convalesc"}, {"convection", "convect"}, {"convene", "conven"}, {"convener", "conven"}, {"convenience", "conveni"},
+		{"convenient", "conveni"}, {"conveniently", "conveni"}, {"convent", "convent"}, {"convention", "convent"}, {"conventional", "convent"},
+		{"conventionally", "convent"}, {"conventions", "convent"}, {"converge", "converg"}, {"converged", "converg"}, {"convergence", "converg"},
+		{"convergent", "converg"}, {"conversant", "convers"}, {"conversation", "convers"}, {"conversational", "convers"},
+		{"conversations", "convers"}, {"converse", "convers"}, {"conversely", "convers"}, {"conversion", "convers"}, {"conversions", "convers"},
+		{"convert", "convert"}, {"converted", "convert"}, {"converter", "convert"}, {"converters", "convert"}, {"convertible", "convert"},
+		{"converting", "convert"}, {"converts", "convert"}, {"convex", "convex"}, {"convey", "convey"}, {"conveyance", "convey"},
+		{"conveyed", "convey"}, {"conveying", "convey"}, {"conveyor", "conveyor"}, {"conveys", "convey"}, {"convict", "convict"},
+		{"conviction", "convict"}, {"convince", "convinc"}, {"convinced", "convinc"}, {"convivial", "convivi"}, {"convocation", "convoc"},
+		{"convoluted", "convolut"}, {"convolution", "convolut"}, {"convoy", "convoy"}, {"convulse", "convuls"}, {"convulsion", "convuls"},
+		{"coo", "coo"}, {"cook", "cook"}, {"cooked", "cook"}, {"cooker", "cooker"}, {"cookery", "cookeri"}, {"cookie", "cooki"},
+		{"cookies", "cooki"}, {"cooking", "cook"}, {"cool", "cool"}, {"coolant", "coolant"}, {"cooler", "cooler"}, {"cooling", "cool"},
+		{"coop", "coop"}, {"cooper", "cooper"}, {"cooperate", "cooper"}, {"cooperating", "cooper"}, {"cooperation", "cooper"},
+		{"cooperative", "cooper"}, {"coordinate", "coordin"}, {"coordinated", "coordin"}, {"coordinates", "coordin"},
+		{"coordinating", "coordin"}, {"coordination", "coordin"}, {"coordinator", "coordin"}, {"cop", "cop"}, {"cope", "cope"},
+		{"copied", "copi"}, {"copier", "copier"}, {"copies", "copi"}, {"copious", "copious"}, {"copper", "copper"}, {"coppice", "coppic"},
+		{"copse", "cops"}, {"copulate", "copul"}, {"copulation", "copul"}, {"copy", "copi"}, {"copying", "copi"}, {"copyright", "copyright"},
+		{"copyrightable", "copyright"}, {"copyrighted", "copyright"}, {"coral", "coral"}, {"cord", "cord"}, {"cordial", "cordial"},
+		{"cordon", "cordon"}, {"corduroy", "corduroy"}, {"core", "core"}, {"cores", "core"}, {"coriander", "coriand"}, {"cork", "cork"},
+		{"corked", "cork"}, {"corkscrew", "corkscrew"}, {"corn", "corn"}, {"cornea", "cornea"}, {"corner", "corner"}, {"corners", "corner"},
+		{"cornerstone", "cornerston"}, {"cornet", "cornet"}, {"cornflakes", "cornflak"}, {"cornflour", "cornflour"}, {"cornice", "cornic"},
+		{"cornucopia", "cornucopia"}, {"corny", "corni"}, {"corollary", "corollari"}, {"corona", "corona"}, {"coronary", "coronari"},
+		{"coronation", "coron"}, {"coroner", "coron"}, {"coronet", "coronet"}, {"corp", "corp"}, {"corporal", "corpor"}, {"corporate", "corpor"},
+		{"corporation", "corpor"}, {"corporeal", "corpor"}, {"corps", "corp"}, {"corpse", "corps"}, {"corpulent", "corpul"},
+		{"corpus", "corpus"}, {"corpuscle", "corpuscl"}, {"corral", "corral"}, {"correct", "correct"}, {"corrected", "correct"},
+		{"correcting", "correct"}, {"correction", "correct"}, {"corrections", "correct"}, {"corrective", "correct"}, {"correctly", "correct"},
+		{"correctness", "correct"}, {"corrects", "correct"}, {"correlate", "correl"}, {"correlated", "correl"}, {"correlates", "correl"},
+		{"correlation", "correl"}, {"correspond", "correspond"}, {"corresponded", "correspond"}, {"correspondence", "correspond"},
+		{"correspondent", "correspond"}, {"correspondents", "correspond"}, {"corresponding", "correspond"}, {"correspondingly", "correspond"},
+		{"corresponds", "correspond"}, {"corridor", "corridor"}, {"corroborate", "corrobor"}, {"corroboration", "corrobor"},
+		{"corrode", "corrod"}, {"corrosion", "corros"}, {"corrosive", "corros"}, {"corrugated", "corrug"}, {"corrupt", "corrupt"},
+		{"corrupted", "corrupt"}, {"corrupting", "corrupt"}, {"corruption", "corrupt"}, {"corruptions", "corrupt"}, {"corrupts", "corrupt"},
+		{"corset", "corset"}, {"cortex", "cortex"}, {"cosmetic", "cosmet"}, {"cosmic", "cosmic"}, {"cosmology", "cosmolog"},
+		{"cosmonaut", "cosmonaut"}, {"cosmopolitan", "cosmopolitan"}, {"cosmos", "cosmos"}, {"cost", "cost"}, {"costly", "cost"},
+		{"costs", "cost"}, {"costume", "costum"}, {"cosy", "cosi"}, {"cot", "cot"}, {"cots", "cot"}, {"cottage", "cottag"}, {"cotton", "cotton"},
+		{"couch", "couch"}, {"cougar", "cougar"}, {"cough", "cough"}, {"could", "could"}, {"council", "council"}, {"councillor", "councillor"},
+		{"councilor", "councilor"}, {"counsel", "counsel"}, {"counselling", "counsel"}, {"counsellor", "counsellor"}, {"counselor", "counselor"},
+		{"count", "count"}, {"countdown", "countdown"}, {"counted", "count"}, {"countenance", "counten"}, {"counter", "counter"},
+		{"counteract", "counteract"}, {"counterattack", "counterattack"}, {"counterbalance", "counterbal"}, {"counterfeit", "counterfeit"},
+		{"counterfoil", "counterfoil"}, {"countermand", "countermand"}, {"countermanded", "countermand"}, {"counterpart", "counterpart"},
+		{"counterparts", "counterpart"}, {"counterpoint", "counterpoint"}, {"counters", "counter"}, {"countersign", "countersign"},
+		{"countess", "countess"}, {"counting", "count"}, {"countless", "countless"}, {"countries", "countri"}, {"country", "countri"},
+		{"countryman", "countryman"}, {"countryside", "countrysid"}, {"counts", "count"}, {"county", "counti"}, {"coup", "coup"},
+		{"couple", "coupl"}, {"coupled", "coupl"}, {"couples", "coupl"}, {"couplet", "couplet"}, {"coupling", "coupl"}, {"coupon", "coupon"},
+		{"courage", "courag"}, {"courageous", "courag"}, {"courgette", "courgett"}, {"courier", "courier"}, {"course", "cours"},
+		{"court", "court"}, {"courteous", "courteous"}, {"courtesan", "courtesan"}, {"courtesy", "courtesi"}, {"courthouse", "courthous"},
+		{"courtier", "courtier"}, {"courtly", "court"}, {"courtroom", "courtroom"}, {"courts", "court"}, {"courtship", "courtship"},
+		{"courtyard", "courtyard"}, {"cousin", "cousin"}, {"cousins", "cousin"}, {"cove", "cove"}, {"covenant", "coven"}, {"cover", "cover"},
+		{"coverage", "coverag"}, {"covered", "cover"}, {"covering", "cover"}, {"coverity", "cover"}, {"covers", "cover"}, {"covert", "covert"},
+		{"covet", "covet"}, {"cow", "cow"}, {"coward", "coward"}, {"cowardice", "cowardic"}, {"cowardly", "coward"}, {"cowboy", "cowboy"},
+		{"cower", "cower"}, {"cowl", "cowl"}, {"coy", "coy"}, {"coyote", "coyot"}, {"crab", "crab"}, {"crack", "crack"},
+		{"crackdown", "crackdown"}, {"cracker", "cracker"}, {"crackle", "crackl"}, {"crackpot", "crackpot"}, {"cradle", "cradl"},
+		{"craft", "craft"}, {"crafted", "craft"}, {"crafting", "craft"}, {"craftsman", "craftsman"}, {"craftsmanship", "craftsmanship"},
+		{"crafty", "crafti"}, {"crag", "crag"}, {"cram", "cram"}, {"cramp", "cramp"}, {"crampon", "crampon"}, {"cranberry", "cranberri"},
+		{"crane", "crane"}, {"cranium", "cranium"}, {"crank", "crank"}, {"cranky", "cranki"}, {"cranny", "cranni"}, {"crap", "crap"},
+		{"crash", "crash"}, {"crashed", "crash"}, {"crashes", "crash"}, {"crashing", "crash"}, {"crass", "crass"}, {"crate", "crate"},
+		{"crater", "crater"}, {"cravat", "cravat"}, {"crave", "crave"}, {"craving", "crave"}, {"crawl", "crawl"}, {"crawled", "crawl"},
+		{"crawling", "crawl"}, {"crayfish", "crayfish"}, {"crayon", "crayon"}, {"craze", "craze"}, {"crazy", "crazi"}, {"creak", "creak"},
+		{"cream", "cream"}, {"creamy", "creami"}, {"crease", "creas"}, {"creat", "creat"}, {"create", "creat"}, {"created", "creat"},
+		{"creates", "creat"}, {"creating", "creat"}, {"creation", "creation"}, {"creations", "creation"}, {"creative", "creativ"},
+		{"creativity", "creativ"}, {"creator", "creator"}, {"creature", "creatur"}, {"creche", "crech"}, {"credence", "credenc"},
+		{"credential", "credenti"}, {"credentialed", "credenti"}, {"credentials", "credenti"}, {"credibility", "credibl"},
+		{"credible", "credibl"}, {"credit", "credit"}, {"creditable", "credit"}, {"credited", "credit"}, {"crediting", "credit"},
+		{"creditor", "creditor"}, {"credits", "credit"}, {"credo", "credo"}, {"credulity", "credul"}, {"credulous", "credul"},
+		{"creed", "creed"}, {"creek", "creek"}, {"creep", "creep"}, {"creepy", "creepi"}, {"cremate", "cremat"}, {"cremation", "cremat"},
+		{"crematorium", "crematorium"}, {"creole", "creol"}, {"creosote", "creosot"}, {"crepe", "crepe"}, {"crescendo", "crescendo"},
+		{"crescent", "crescent"}, {"cress", "cress"}, {"crest", "crest"}, {"crestfallen", "crestfallen"}, {"cretin", "cretin"},
+		{"crevasse", "crevass"}, {"crevice", "crevic"}, {"crew", "crew"}, {"crib", "crib"}, {"crick", "crick"}, {"cricket", "cricket"},
+		{"cricketer", "cricket"}, {"crime", "crime"}, {"criminal", "crimin"}, {"criminology", "criminolog"}, {"crimson", "crimson"},
+		{"cringe", "cring"}, {"crinkle", "crinkl"}, {"cripple", "crippl"}, {"crippling", "crippl"}, {"crisis", "crisi"}, {"crisp", "crisp"},
+		{"crispy", "crispi"}, {"crisscross", "crisscross"}, {"criterion", "criterion"}, {"critic", "critic"}, {"critical", "critic"},
+		{"criticality", "critic"}, {"criticism", "critic"}, {"criticize", "critic"}, {"critique", "critiqu"}, {"croak", "croak"},
+		{"croaking", "croak"}, {"croaks", "croak"}, {"crochet", "crochet"}, {"crock", "crock"}, {"crockery", "crockeri"},
+		{"crocodile", "crocodil"}, {"crocus", "crocus"}, {"croissant", "croissant"}, {"crone", "crone"}, {"crony", "croni"}, {"crook", "crook"},
+		{"crooked", "crook"}, {"croon", "croon"}, {"crop", "crop"}, {"cropped", "crop"}, {"croquet", "croquet"}, {"cross", "cross"},
+		{"crossbar", "crossbar"}, {"crossbow", "crossbow"}, {"crossed", "cross"}, {"crosses", "cross"}, {"crossfire", "crossfir"},
+		{"crossing", "cross"}, {"crossroads", "crossroad"}, {"crossword", "crossword"}, {"crotch", "crotch"}, {"crouch", "crouch"},
+		{"croupier", "croupier"}, {"crow", "crow"}, {"crowbar", "crowbar"}, {"crowd", "crowd"}, {"crown", "crown"}, {"crucial", "crucial"},
+		{"crucible", "crucibl"}, {"crucifix", "crucifix"}, {"crucifixion", "crucifixion"}, {"crucify", "crucifi"}, {"crude", "crude"},
+		{"cruel", "cruel"}, {"cruelty", "cruelti"}, {"cruet", "cruet"}, {"cruise", "cruis"}, {"cruiser", "cruiser"}, {"crumb", "crumb"},
+		{"crumble", "crumbl"}, {"crumple", "crumpl"}, {"crunch", "crunch"}, {"crusade", "crusad"}, {"crusader", "crusad"}, {"crush", "crush"},
+		{"crust", "crust"}, {"crustacean", "crustacean"}, {"crusty", "crusti"}, {"crutch", "crutch"}, {"crux", "crux"}, {"cry", "cri"},
+		{"cryptic", "cryptic"}, {"crystal", "crystal"}, {"crystalline", "crystallin"}, {"crystallize", "crystal"}, {"cub", "cub"},
+		{"cube", "cube"}, {"cubic", "cubic"}, {"cubicle", "cubicl"}, {"cuckoo", "cuckoo"}, {"cucumber", "cucumb"}, {"cuddle", "cuddl"},
+		{"cudgel", "cudgel"}, {"cue", "cue"}, {"cues", "cue"}, {"cuff", "cuff"}, {"cuisine", "cuisin"}, {"culinary", "culinari"},
+		{"cull", "cull"}, {"culminate", "culmin"}, {"culmination", "culmin"}, {"culpable", "culpabl"}, {"culprit", "culprit"}, {"cult", "cult"},
+		{"cultivate", "cultiv"}, {"cultivation", "cultiv"}, {"cultural", "cultur"}, {"culture", "cultur"}, {"cumbersome", "cumbersom"},
+		{"cumulative", "cumul"}, {"cumulatively", "cumul"}, {"cunning", "cun"}, {"cup", "cup"}, {"cupboard", "cupboard"}, {"cupola", "cupola"},
+		{"cups", "cup"}, {"cur", "cur"}, {"curable", "curabl"}, {"curate", "curat"}, {"curated", "curat"}, {"curative", "curat"},
+		{"curator", "curat"}, {"curb", "curb"}, {"curd", "curd"}, {"curdle", "curdl"}, {"cure", "cure"}, {"curfew", "curfew"},
+		{"curio", "curio"}, {"curiosity", "curios"}, {"curious", "curious"}, {"curl", "curl"}, {"curler", "curler"}, {"curly", "cur"},
+		{"currant", "currant"}, {"currency", "currenc"}, {"current", "current"}, {"currently", "current"}, {"curriculum", "curriculum"},
+		{"curry", "curri"}, {"curse", "curs"}, {"curses", "curs"}, {"cursor", "cursor"}, {"cursors", "cursor"}, {"cursory", "cursori"},
+		{"curt", "curt"}, {"curtail", "curtail"}, {"curtain", "curtain"}, {"curtsy", "curtsi"}, {"curvature", "curvatur"}, {"curve", "curv"},
+		{"curves", "curv"}, {"cushion", "cushion"}, {"cusp", "cusp"}, {"custard", "custard"}, {"custodial", "custodi"},
+		{"custodian", "custodian"}, {"custody", "custodi"}, {"custom", "custom"}, {"customary", "customari"}, {"customer", "custom"},
+		{"customers", "custom"}, {"customization", "custom"}, {"customizations", "custom"}, {"customize", "custom"}, {"customized", "custom"},
+		{"customizes", "custom"}, {"customizing", "custom"}, {"cut", "cut"}, {"cutback", "cutback"}, {"cute", "cute"}, {"cuticle", "cuticl"},
+		{"cutlery", "cutleri"}, {"cutlet", "cutlet"}, {"cutter", "cutter"}, {"cutthroat", "cutthroat"}, {"cutting", "cut"},
+		{"cyanide", "cyanid"}, {"cybernetics", "cybernet"}, {"cycle", "cycl"}, {"cycled", "cycl"}, {"cycles", "cycl"}, {"cyclic", "cyclic"},
+		{"cyclical", "cyclic"}, {"cyclically", "cyclic"}, {"cycling", "cycl"}, {"cyclist", "cyclist"}, {"cyclone", "cyclon"},
+		{"cygnet", "cygnet"}, {"cylinder", "cylind"}, {"cylinders", "cylind"}, {"cylindrical", "cylindr"}, {"cymbal", "cymbal"},
+		{"cynic", "cynic"}, {"cynical", "cynic"}, {"cynicism", "cynic"}, {"cypress", "cypress"}, {"cyst", "cyst"}, {"cystitis", "cystiti"},
+		{"czar", "czar"}, {"dab", "dab"}, {"dabble", "dabbl"}, {"dachshund", "dachshund"}, {"dad", "dad"}, {"daddy", "daddi"},
+		{"daffodil", "daffodil"}, {"daft", "daft"}, {"dagger", "dagger"}, {"dahlia", "dahlia"}, {"dai", "dai"}, {"daily", "daili"},
+		{"dainty", "dainti"}, {"dairy", "dairi"}, {"dais", "dai"}, {"daisy", "daisi"}, {"dale", "dale"}, {"dally", "dalli"}, {"dam", "dam"},
+		{"damage", "damag"}, {"damaged", "damag"}, {"damages", "damag"}, {"damaging", "damag"}, {"damask", "damask"}, {"dame", "dame"},
+		{"damn", "damn"}, {"damnation", "damnat"}, {"damp", "damp"}, {"dampen", "dampen"}, {"dampened", "dampen"}, {"damper", "damper"},
+		{"damsel", "damsel"}, {"damson", "damson"}, {"dance", "danc"}, {"dancer", "dancer"}, {"dandelion", "dandelion"},
+		{"dandruff", "dandruff"}, {"dandy", "dandi"}, {"danger", "danger"}, {"dangerous", "danger"}, {"dangerously", "danger"},
+		{"dangers", "danger"}, {"dangle", "dangl"}, {"dangling", "dangl"}, {"dank", "dank"}, {"dapper", "dapper"}, {"dappled", "dappl"},
+		{"dare", "dare"}, {"daredevil", "daredevil"}, {"daring", "dare"}, {"dark", "dark"}, {"darken", "darken"}, {"darkness", "dark"},
+		{"darkroom", "darkroom"}, {"darl", "darl"}, {"darling", "darl"}, {"darn", "darn"}, {"dart", "dart"}, {"dartboard", "dartboard"},
+		{"dash", "dash"}, {"dashboard", "dashboard"}, {"dashboards", "dashboard"}, {"dashed", "dash"}, {"dashes", "dash"},
+		{"dastardly", "dastard"}, {"data", "data"}, {"database", "databas"}, {"databases", "databas"}, {"datas", "data"}, {"date", "date"},
+		{"dated", "date"}, {"dateline", "datelin"}, {"dateness", "date"}, {"dates", "date"}, {"dative", "dativ"}, {"daub", "daub"},
+		{"daughter", "daughter"}, {"daunt", "daunt"}, {"daunting", "daunt"}, {"dauntless", "dauntless"}, {"dawdle", "dawdl"}, {"dawn", "dawn"},
+		{"day", "day"}, {"daybreak", "daybreak"}, {"daydream", "daydream"}, {"daylight", "daylight"}, {"days", "day"}, {"daytime", "daytim"},
+		{"daze", "daze"}, {"dazzle", "dazzl"}, {"deacon", "deacon"}, {"dead", "dead"}, {"deade", "dead"}, {"deaden", "deaden"},
+		{"deadline", "deadlin"}, {"deadlock", "deadlock"}, {"deadlocked", "deadlock"}, {"deadlocking", "deadlock"}, {"deadlocks", "deadlock"},
+		{"deadly", "dead"}, {"deaf", "deaf"}, {"deafen", "deafen"}, {"deal", "deal"}, {"dealer", "dealer"}, {"dealership", "dealership"},
+		{"dealing", "deal"}, {"dealings", "deal"}, {"deals", "deal"}, {"dean", "dean"}, {"dear", "dear"}, {"dearth", "dearth"},
+		{"death", "death"}, {"deathbed", "deathb"}, {"deathly", "death"}, {"debacle", "debacl"}, {"debar", "debar"}, {"debase", "debas"},
+		{"debatable", "debat"}, {"debate", "debat"}, {"debauch", "debauch"}, {"debauchery", "debaucheri"}, {"debenture", "debentur"},
+		{"debilitate", "debilit"}, {"debility", "debil"}, {"debit", "debit"}, {"debonair", "debonair"}, {"debrief", "debrief"},
+		{"debris", "debri"}, {"debt", "debt"}, {"debtor", "debtor"}, {"debug", "debug"}, {"debugged", "debug"}, {"debugging", "debug"},
+		{"debunk", "debunk"}, {"debut", "debut"}, {"decade", "decad"}, {"decadence", "decad"}, {"decadent", "decad"},
+		{"decaffeinated", "decaffein"}, {"decamp", "decamp"}, {"decant", "decant"}, {"decanter", "decant"}, {"decapitalize", "decapit"},
+		{"decapitate", "decapit"}, {"decathlon", "decathlon"}, {"decay", "decay"}, {"decease", "deceas"}, {"deceased", "deceas"},
+		{"deceit", "deceit"}, {"deceitful", "deceit"}, {"deceive", "deceiv"}, {"decelerate", "deceler"}, {"deceleration", "deceler"},
+		{"december", "decemb"}, {"decency", "decenc"}, {"decent", "decent"}, {"decentralize", "decentr"}, {"deception", "decept"},
+		{"deceptive", "decept"}, {"decibel", "decibel"}, {"decide", "decid"}, {"decided", "decid"}, {"decidedly", "decid"}, {"decides", "decid"},
+		{"deciding", "decid"}, {"deciduous", "decidu"}, {"decimal", "decim"}, {"decimals", "decim"}, {"decimate", "decim"},
+		{"decipher", "deciph"}, {"deciphered", "deciph"}, {"decision", "decis"}, {"decisions", "decis"}, {"decisive", "decis"}, {"deck", "deck"},
+		{"deckchair", "deckchair"}, {"declaim", "declaim"}, {"declamation", "declam"}, {"declaration", "declar"}, {"declarations", "declar"},
+		{"declarative", "declar"}, {"declare", "declar"}, {"declared", "declar"}, {"declares", "declar"}, {"declaring", "declar"},
+		{"declassify", "declassifi"}, {"declension", "declens"}, {"decline", "declin"}, {"declines", "declin"}, {"declining", "declin"},
+		{"decode", "decod"}, {"decoded", "decod"}, {"decoder", "decod"}, {"decoders", "decod"}, {"decodes", "decod"}, {"decoding", "decod"},
+		{"decompose", "decompos"}, {"decomposed", "decompos"}, {"decomposition", "decomposit"}, {"decompress", "decompress"},
+		{"decompressed", "decompress"}, {"decompresses", "decompress"}, {"decompressible", "decompress"}, {"decompressing", "decompress"},
+		{"decompression", "decompress"}, {"decongestant", "decongest"}, {"decontaminate", "decontamin"}, {"decor", "decor"},
+		{"decorate", "decor"}, {"decorated", "decor"}, {"decorating", "decor"}, {"decoration", "decor"}, {"decorations", "decor"},
+		{"decorative", "decor"}, {"decorator", "decor"}, {"decorators", "decor"}, {"decorous", "decor"}, {"decorum", "decorum"},
+		{"decoy", "decoy"}, {"decrease", "decreas"}, {"decreased", "decreas"}, {"decreases", "decreas"}, {"decreasing", "decreas"},
+		{"decree", "decre"}, {"decrepit", "decrepit"}, {"decriminalize", "decrimin"}, {"decry", "decri"}, {"dedicate", "dedic"},
+		{"dedicated", "dedic"}, {"dedicates", "dedic"}, {"dedicating", "dedic"}, {"dedication", "dedic"}, {"dedications", "dedic"},
+		{"deduce", "deduc"}, {"deduced", "deduc"}, {"deduces", "deduc"}, {"deducible", "deduc"}, {"deduct", "deduct"}, {"deducted", "deduct"},
+		{"deductible", "deduct"}, {"deduction", "deduct"}, {"deductive", "deduct"}, {"deed", "deed"}, {"deem", "deem"}, {"deemed", "deem"},
+		{"deems", "deem"}, {"deep", "deep"}, {"deepen", "deepen"}, {"deepened", "deepen"}, {"deepens", "deepen"}, {"deer", "deer"},
+		{"deface", "defac"}, {"defamation", "defam"}, {"defamatory", "defamatori"}, {"defame", "defam"}, {"default", "default"},
+		{"defaulted", "default"}, {"defaulting", "default"}, {"defaults", "default"}, {"defeat", "defeat"}, {"defeated", "defeat"},
+		{"defeating", "defeat"}, {"defeatism", "defeat"}, {"defeatist", "defeatist"}, {"defeats", "defeat"}, {"defecate", "defec"},
+		{"defect", "defect"}, {"defection", "defect"}, {"defective", "defect"}, {"defector", "defector"}, {"defects", "defect"},
+		{"defence", "defenc"}, {"defenceless", "defenceless"}, {"defend", "defend"}, {"defendant", "defend"}, {"defender", "defend"},
+		{"defense", "defens"}, {"defenseless", "defenseless"}, {"defenses", "defens"}, {"defensible", "defens"}, {"defensive", "defens"},
+		{"defer", "defer"}, {"deference", "defer"}, {"deferential", "deferenti"}, {"deferment", "defer"}, {"deferred", "defer"},
+		{"deferring", "defer"}, {"defers", "defer"}, {"defiance", "defianc"}, {"defiant", "defiant"}, {"deficiencies", "defici"},
+		{"deficiency", "defici"}, {"deficient", "defici"}, {"deficit", "deficit"}, {"defile", "defil"}, {"definable", "defin"},
+		{"define", "defin"}, {"defined", "defin"}, {"definer", "defin"}, {"defines", "defin"}, {"defining", "defin"}, {"definite", "definit"},
+		{"definitely", "definit"}, {"definition", "definit"}, {"definitions", "definit"}, {"definitive", "definit"}, {"deflate", "deflat"},
+		{"deflated", "deflat"}, {"deflating", "deflat"}, {"deflation", "deflat"}, {"deflationary", "deflationari"}, {"deflect", "deflect"},
+		{"deflection", "deflect"}, {"deforestation", "deforest"}, {"deform", "deform"}, {"deformation", "deform"}, {"deformity", "deform"},
+		{"defraud", "defraud"}, {"defray", "defray"}, {"defrost", "defrost"}, {"deft", "deft"}, {"defunct", "defunct"}, {"defuse", "defus"},
+		{"defy", "defi"}, {"degenerate", "degener"}, {"degenerates", "degener"}, {"degeneration", "degener"}, {"degradation", "degrad"},
+		{"degradations", "degrad"}, {"degrade", "degrad"}, {"degraded", "degrad"}, {"degrades", "degrad"}, {"degree", "degre"},
+		{"dehydrate", "dehydr"}, {"dehydration", "dehydr"}, {"deify", "deifi"}, {"deign", "deign"}, {"deity", "deiti"}, {"deject", "deject"},
+		{"dejection", "deject"}, {"delay", "delay"}, {"delayed", "delay"}, {"delaying", "delay"}, {"delays", "delay"}, {"delectable", "delect"},
+		{"delegate", "deleg"}, {"delegated", "deleg"}, {"delegates", "deleg"}, {"delegating", "deleg"}, {"delegation", "deleg"},
+		{"deletable", "delet"}, {"delete", "delet"}, {"deleted", "delet"}, {"deleter", "delet"}, {"deleterious", "deleteri"},
+		{"deletes", "delet"}, {"deleting", "delet"}, {"deletion", "delet"}, {"deletions", "delet"}, {"deliberate", "deliber"},
+		{"deliberately", "deliber"}, {"deliberation", "deliber"}, {"delicacy", "delicaci"}, {"delicate", "delic"},
+		{"delicatessen", "delicatessen"}, {"delicious", "delici"}, {"delight", "delight"}, {"delightful", "delight"}, {"delimit", "delimit"},
+		{"delimited", "delimit"}, {"delimiter", "delimit"}, {"delimiters", "delimit"}, {"delimiting", "delimit"}, {"delimits", "delimit"},
+		{"delineate", "delin"}, {"delineator", "delin"}, {"delinquency", "delinqu"}, {"delinquent", "delinqu"}, {"delirious", "deliri"},
+		{"delirium", "delirium"}, {"deliver", "deliv"}, {"deliverance", "deliver"}, {"delivered", "deliv"}, {"deliveries", "deliveri"},
+		{"delivering", "deliv"}, {"delivers", "deliv"}, {"delivery", "deliveri"}, {"dell", "dell"}, {"delta", "delta"}, {"deltas", "delta"},
+		{"delude", "delud"}, {"deluge", "delug"}, {"deluser", "delus"}, {"delusion", "delus"}, {"deluxe", "delux"}, {"delve", "delv"},
+		{"demagogue", "demagogu"}, {"demand", "demand"}, {"demanding", "demand"}, {"demands", "demand"}, {"demarcate", "demarc"},
+		{"demarcation", "demarc"}, {"demean", "demean"}, {"demeanor", "demeanor"}, {"demeanour", "demeanour"}, {"demented", "dement"},
+		{"dementia", "dementia"}, {"demerit", "demerit"}, {"demise", "demis"}, {"demo", "demo"}, {"demobilize", "demobil"},
+		{"democracy", "democraci"}, {"democrat", "democrat"}, {"democratic", "democrat"}, {"democratize", "democrat"},
+		{"demographic", "demograph"}, {"demography", "demographi"}, {"demolish", "demolish"}, {"demolition", "demolit"}, {"demon", "demon"},
+		{"demonic", "demon"}, {"demonstrable", "demonstr"}, {"demonstrate", "demonstr"}, {"demonstrated", "demonstr"},
+		{"demonstrates", "demonstr"}, {"demonstrating", "demonstr"}, {"demonstration", "demonstr"}, {"demonstrative", "demonstr"},
+		{"demonstrator", "demonstr"}, {"demoralize", "demor"}, {"demote", "demot"}, {"demoted", "demot"}, {"demotion", "demot"},
+		{"demur", "demur"}, {"demure", "demur"}, {"den", "den"}, {"denial", "denial"}, {"denied", "deni"}, {"denies", "deni"},
+		{"denigrate", "denigr"}, {"denim", "denim"}, {"denis", "deni"}, {"denizen", "denizen"}, {"denominated", "denomin"},
+		{"denomination", "denomin"}, {"denominational", "denomin"}, {"denominator", "denomin"}, {"denote", "denot"}, {"denoted", "denot"},
+		{"denotes", "denot"}, {"denoting", "denot"}, {"denouement", "denouement"}, {"denounce", "denounc"}, {"dense", "dens"},
+		{"densely", "dens"}, {"density", "densiti"}, {"dent", "dent"}, {"dental", "dental"}, {"dentist", "dentist"}, {"dentistry", "dentistri"},
+		{"denture", "dentur"}, {"denude", "denud"}, {"denunciation", "denunci"}, {"deny", "deni"}, {"denying", "deni"}, {"denys", "deni"},
+		{"deodorant", "deodor"}, {"depart", "depart"}, {"department", "depart"}, {"departmental", "department"}, {"departments", "depart"},
+		{"departs", "depart"}, {"departure", "departur"}, {"depend", "depend"}, {"dependable", "depend"}, {"dependant", "depend"},
+		{"depended", "depend"}, {"dependence", "depend"}, {"dependences", "depend"}, {"dependencies", "depend"}, {"dependency", "depend"},
+		{"dependent", "depend"}, {"dependents", "depend"}, {"depending", "depend"}, {"depends", "depend"}, {"depict", "depict"},
+		{"depicted", "depict"}, {"depiction", "depict"}, {"deplete", "deplet"}, {"depletion", "deplet"}, {"deplorable", "deplor"},
+		{"deplore", "deplor"}, {"deploy", "deploy"}, {"deployable", "deploy"}, {"deployables", "deploy"}, {"deployed", "deploy"},
+		{"deploying", "deploy"}, {"deployment", "deploy"}, {"deployments", "deploy"}, {"deploys", "deploy"}, {"depopulate", "depopul"},
+		{"deport", "deport"}, {"deportation", "deport"}, {"deportment", "deport"}, {"depose", "depos"}, {"deposit", "deposit"},
+		{"deposition", "deposit"}, {"depositor", "depositor"}, {"depot", "depot"}, {"deprave", "deprav"}, {"depravity", "deprav"},
+		{"deprecate", "deprec"}, {"deprecated", "deprec"}, {"deprecates", "deprec"}, {"deprecating", "deprec"}, {"deprecation", "deprec"},
+		{"deprecations", "deprec"}, {"depreciate", "depreci"}, {"depreciation", "depreci"}, {"depredation", "depred"}, {"depress", "depress"},
+		{"depressant", "depress"}, {"depression", "depress"}, {"depressive", "depress"}, {"deprivation", "depriv"}, {"deprive", "depriv"},
+		{"depriving", "depriv"}, {"depth", "depth"}, {"depths", "depth"}, {"deputation", "deput"}, {"depute", "deput"}, {"deputize", "deput"},
+		{"deputy", "deputi"}, {"derail", "derail"}, {"derailment", "derail"}, {"derange", "derang"}, {"derby", "derbi"},
+		{"deregulate", "deregul"}, {"deregulation", "deregul"}, {"derelict", "derelict"}, {"dereliction", "derelict"}, {"deride", "derid"},
+		{"derision", "deris"}, {"derisive", "deris"}, {"derisory", "derisori"}, {"derivation", "deriv"}, {"derivative", "deriv"},
+		{"derivatives", "deriv"}, {"derive", "deriv"}, {"derived", "deriv"}, {"derives", "deriv"}, {"deriving", "deriv"},
+		{"dermatitis", "dermat"}, {"dermatologist", "dermatologist"}, {"dermatology", "dermatolog"}, {"derogatory", "derogatori"},
+		{"derrick", "derrick"}, {"descend", "descend"}, {"descendant", "descend"}, {"descendants", "descend"}, {"descended", "descend"},
+		{"descendent", "descend"}, {"descending", "descend"}, {"descends", "descend"}, {"descent", "descent"}, {"describe", "describ"},
+		{"described", "describ"}, {"describes", "describ"}, {"describing", "describ"}, {"description", "descript"}, {"descriptions", "descript"},
+		{"descriptive", "descript"}, {"descriptiveness", "descript"}, {"desecrate", "desecr"}, {"desecration", "desecr"},
+		{"desegregate", "desegreg"}, {"desert", "desert"}, {"deserter", "desert"}, {"desertion", "desert"}, {"deserve", "deserv"},
+		{"design", "design"}, {"designate", "design"}, {"designated", "design"}, {"designates", "design"}, {"designating", "design"},
+		{"designation", "design"}, {"designations", "design"}, {"designator", "design"}, {"designators", "design"}, {"designed", "design"},
+		{"designer", "design"}, {"designing", "design"}, {"desirable", "desir"}, {"desire", "desir"}, {"desired", "desir"}, {"desires", "desir"},
+		{"desiring", "desir"}, {"desirous", "desir"}, {"desist", "desist"}, {"desk", "desk"}, {"desktop", "desktop"}, {"desolate", "desol"},
+		{"desolation", "desol"}, {"despair", "despair"}, {"despatch", "despatch"}, {"desperado", "desperado"}, {"desperate", "desper"},
+		{"desperation", "desper"}, {"despicable", "despic"}, {"despise", "despis"}, {"despite", "despit"}, {"despondent", "despond"},
+		{"despot", "despot"}, {"despotic", "despot"}, {"despotism", "despot"}, {"dessert", "dessert"}, {"destabilize", "destabil"},
+		{"destination", "destin"}, {"destinations", "destin"}, {"destined", "destin"}, {"destiny", "destini"}, {"destitute", "destitut"},
+		{"destitution", "destitut"}, {"destroy", "destroy"}, {"destroyed", "destroy"}, {"destroyer", "destroy"}, {"destroying", "destroy"},
+		{"destroys", "destroy"}, {"destruct", "destruct"}, {"destructing", "destruct"}, {"destruction", "destruct"}, {"destructive", "destruct"},
+		{"destructively", "destruct"}, {"desultory", "desultori"}, {"detach", "detach"}, {"detachable", "detach"}, {"detached", "detach"},
+		{"detaches", "detach"}, {"detaching", "detach"}, {"detachment", "detach"}, {"detail", "detail"}, {"detailed", "detail"},
+		{"detailing", "detail"}, {"detailled", "detail"}, {"details", "detail"}, {"detain", "detain"}, {"detainee", "detaine"},
+		{"detect", "detect"}, {"detectable", "detect"}, {"detected", "detect"}, {"detecting", "detect"}, {"detection", "detect"},
+		{"detections", "detect"}, {"detective", "detect"}, {"detector", "detector"}, {"detectors", "detector"}, {"detects", "detect"},
+		{"detention", "detent"}, {"deter", "deter"}, {"detergent", "deterg"}, {"deteriorate", "deterior"}, {"deterioration", "deterior"},
+		{"determinable", "determin"}, {"determinant", "determin"}, {"determination", "determin"}, {"determine", "determin"},
+		{"determined", "determin"}, {"determiner", "determin"}, {"determines", "determin"}, {"determining", "determin"},
+		{"determinism", "determin"}, {"deterrence", "deterr"}, {"deterrent", "deterr"}, {"detest", "detest"}, {"detestable", "detest"},
+		{"dethrone", "dethron"}, {"detonate", "deton"}, {"detonation", "deton"}, {"detonator", "deton"}, {"detour", "detour"},
+		{"detract", "detract"}, {"detractor", "detractor"}, {"detriment", "detriment"}, {"detrimental", "detriment"}, {"deuce", "deuc"},
+		{"devaluation", "devalu"}, {"devalue", "devalu"}, {"devastate", "devast"}, {"devastation", "devast"}, {"develop", "develop"},
+		{"developed", "develop"}, {"developer", "develop"}, {"developers", "develop"}, {"developing", "develop"}, {"development", "develop"},
+		{"developments", "develop"}, {"develops", "develop"}, {"deviant", "deviant"}, {"deviate", "deviat"}, {"deviates", "deviat"},
+		{"deviation", "deviat"}, {"deviations", "deviat"}, {"device", "devic"}, {"devices", "devic"}, {"devil", "devil"},
+		{"devilish", "devilish"}, {"devious", "devious"}, {"devise", "devis"}, {"devoid", "devoid"}, {"devolution", "devolut"},
+		{"devolve", "devolv"}, {"devote", "devot"}, {"devoted", "devot"}, {"devotee", "devote"}, {"devotion", "devot"}, {"devour", "devour"},
+		{"devout", "devout"}, {"dew", "dew"}, {"dexter", "dexter"}, {"dexterity", "dexter"}, {"dexterous", "dexter"}, {"diabetes", "diabet"},
+		{"diabetic", "diabet"}, {"diabolical", "diabol"}, {"diagnose", "diagnos"}, {"diagnosed", "diagnos"}, {"diagnoses", "diagnos"},
+		{"diagnosing", "diagnos"}, {"diagnosis", "diagnosi"}, {"diagnostic", "diagnost"}, {"diagnostics", "diagnost"}, {"diagonal", "diagon"},
+		{"diagram", "diagram"}, {"dial", "dial"}, {"dialect", "dialect"}, {"dialectic", "dialect"}, {"dialects", "dialect"}, {"dialing", "dial"},
+		{"dialog", "dialog"}, {"dialogs", "dialog"}, {"dialogue", "dialogu"}, {"dialysis", "dialysi"}, {"diameter", "diamet"},
+		{"diametrically", "diametr"}, {"diamond", "diamond"}, {"diaper", "diaper"}, {"diaphragm", "diaphragm"}, {"diarrhea", "diarrhea"},
+		{"diarrhoea", "diarrhoea"}, {"diary", "diari"}, {"diatribe", "diatrib"}, {"dice", "dice"}, {"dicey", "dicey"},
+		{"dichotomy", "dichotomi"}, {"dictate", "dictat"}, {"dictated", "dictat"}, {"dictates", "dictat"}, {"dictation", "dictat"},
+		{"dictator", "dictat"}, {"dictatorial", "dictatori"}, {"dictatorship", "dictatorship"}, {"diction", "diction"},
+		{"dictionaries", "dictionari"}, {"dictionary", "dictionari"}, {"dictum", "dictum"}, {"didactic", "didact"}, {"die", "die"},
+		{"died", "die"}, {"dies", "die"}, {"diesel", "diesel"}, {"diet", "diet"}, {"dietary", "dietari"}, {"dietician", "dietician"},
+		{"differ", "differ"}, {"difference", "differ"}, {"differences", "differ"}, {"different", "differ"}, {"differential", "differenti"},
+		{"differentiate", "differenti"}, {"differentiated", "differenti"}, {"differentiates", "differenti"}, {"differentiating", "differenti"},
+		{"differentiation", "differenti"}, {"differently", "differ"}, {"differing", "differ"}, {"differs", "differ"}, {"difficult", "difficult"},
+		{"difficulties", "difficulti"}, {"difficulty", "difficulti"}, {"diffidence", "diffid"}, {"diffident", "diffid"}, {"diffuse", "diffus"},
+		{"diffusion", "diffus"}, {"dig", "dig"}, {"digest", "digest"}, {"digested", "digest"}, {"digestible", "digest"}, {"digesting", "digest"},
+		{"digestion", "digest"}, {"digestive", "digest"}, {"digests", "digest"}, {"digger", "digger"}, {"digging", "dig"}, {"digit", "digit"},
+		{"digital", "digit"}, {"digitally", "digit"}, {"digits", "digit"}, {"dignified", "dignifi"}, {"dignitary", "dignitari"},
+		{"dignity", "digniti"}, {"digress", "digress"}, {"digression", "digress"}, {"digs", "dig"}, {"dike", "dike"}, {"dilapidated", "dilapid"},
+		{"dilapidation", "dilapid"}, {"dilate", "dilat"}, {"dilation", "dilat"}, {"dilemma", "dilemma"}, {"diligence", "dilig"},
+		{"diligent", "dilig"}, {"dill", "dill"}, {"dilute", "dilut"}, {"dilution", "dilut"}, {"dim", "dim"}, {"dime", "dime"},
+		{"dimension", "dimens"}, {"dimensional", "dimension"}, {"dimensioned", "dimens"}, {"dimensions", "dimens"}, {"diminish", "diminish"},
+		{"diminishing", "diminish"}, {"diminution", "diminut"}, {"diminutive", "diminut"}, {"dimmed", "dim"}, {"dimming", "dim"},
+		{"dimple", "dimpl"}, {"din", "din"}, {"dine", "dine"}, {"diner", "diner"}, {"dinghy", "dinghi"}, {"dingy", "dingi"},
+		{"dinner", "dinner"}, {"dinosaur", "dinosaur"}, {"diocese", "dioces"}, {"dioxide", "dioxid"}, {"dip", "dip"},
+		{"diphtheria", "diphtheria"}, {"diphthong", "diphthong"}, {"diploma", "diploma"}, {"diplomacy", "diplomaci"}, {"diplomat", "diplomat"},
+		{"diplomatic", "diplomat"}, {"dire", "dire"}, {"direct", "direct"}, {"directed", "direct"}, {"directing", "direct"},
+		{"direction", "direct"}, {"directional", "direct"}, {"directions", "direct"}, {"directive", "direct"}, {"directives", "direct"},
+		{"directly", "direct"}, {"director", "director"}, {"directorate", "director"}, {"directories", "directori"}, {"directors", "director"},
+		{"directory", "directori"}, {"directs", "direct"}, {"dired", "dire"}, {"dirge", "dirg"}, {"dirt", "dirt"}, {"dirtied", "dirti"},
+		{"dirtiness", "dirti"}, {"dirty", "dirti"}, {"dirtying", "dirti"}, {"disability", "disabl"}, {"disable", "disabl"},
+		{"disabled", "disabl"}, {"disablement", "disabl"}, {"disables", "disabl"}, {"disabling", "disabl"}, {"disabuse", "disabus"},
+		{"disadvantage", "disadvantag"}, {"disadvantageous", "disadvantag"}, {"disadvantages", "disadvantag"}, {"disaffect", "disaffect"},
+		{"disaffection", "disaffect"}, {"disagree", "disagre"}, {"disagreeable", "disagre"}, {"disagreement", "disagr"},
+		{"disagreements", "disagr"}, {"disallow", "disallow"}, {"disallowed", "disallow"}, {"disallowing", "disallow"},
+		{"disallows", "disallow"}, {"disappear", "disappear"}, {"disappearance", "disappear"}, {"disappeared", "disappear"},
+		{"disappears", "disappear"}, {"disappoint", "disappoint"}, {"disappointment", "disappoint"}, {"disapproval", "disapprov"},
+		{"disapprove", "disapprov"}, {"disarm", "disarm"}, {"disarmament", "disarma"}, {"disarmed", "disarm"}, {"disarms", "disarm"},
+		{"disarray", "disarray"}, {"disassociate", "disassoci"}, {"disassociated", "disassoci"}, {"disassociates", "disassoci"},
+		{"disaster", "disast"}, {"disastrous", "disastr"}, {"disavow", "disavow"}, {"disband", "disband"}, {"disbelief", "disbelief"},
+		{"disbelieve", "disbeliev"}, {"disburse", "disburs"}, {"disbursement", "disburs"}, {"disc", "disc"}, {"discard", "discard"},
+		{"discardable", "discard"}, {"discarded", "discard"}, {"discarding", "discard"}, {"discards", "discard"}, {"discern", "discern"},
+		{"discernible", "discern"}, {"discerning", "discern"}, {"discharge", "discharg"}, {"discharging", "discharg"}, {"disciple", "discipl"},
+		{"disciplinarian", "disciplinarian"}, {"disciplinary", "disciplinari"}, {"discipline", "disciplin"}, {"disciplines", "disciplin"},
+		{"disclaim", "disclaim"}, {"disclaimed", "disclaim"}, {"disclaimer", "disclaim"}, {"disclaimers", "disclaim"},
+		{"disclaiming", "disclaim"}, {"disclaims", "disclaim"}, {"disclose", "disclos"}, {"disclosed", "disclos"}, {"disclosure", "disclosur"},
+		{"disclosures", "disclosur"}, {"disco", "disco"}, {"discolor", "discolor"}, {"discolour", "discolour"}, {"discomfort", "discomfort"},
+		{"disconcert", "disconcert"}, {"disconnect", "disconnect"}, {"disconnected", "disconnect"}, {"disconnecting", "disconnect"},
+		{"disconnection", "disconnect"}, {"disconnects", "disconnect"}, {"disconsolate", "disconsol"}, {"discontent", "discont"},
+		{"discontinue", "discontinu"}, {"discontinued", "discontinu"}, {"discontinuities", "discontinu"}, {"discontinuity", "discontinu"},
+		{"discontinuous", "discontinu"}, {"discord", "discord"}, {"discordant", "discord"}, {"discotheque", "discothequ"},
+		{"discount", "discount"}, {"discounted", "discount"}, {"discounting", "discount"}, {"discounts", "discount"},
+		{"discourage", "discourag"}, {"discouraged", "discourag"}, {"discouragement", "discourag"}, {"discourse", "discours"},
+		{"discourteous", "discourt"}, {"discover", "discov"}, {"discoverability", "discover"}, {"discoverable", "discover"},
+		{"discovered", "discov"}, {"discoverer", "discover"}, {"discoveries", "discoveri"}, {"discovering", "discov"}, {"discovers", "discov"},
+		{"discovery", "discoveri"}, {"discredit", "discredit"}, {"discreet", "discreet"}, {"discrepancies", "discrep"},
+		{"discrepancy", "discrep"}, {"discrete", "discret"}, {"discretion", "discret"}, {"discretionary", "discretionari"},
+		{"discriminant", "discrimin"}, {"discriminate", "discrimin"}, {"discriminated", "discrimin"}, {"discrimination", "discrimin"},
+		{"discriminator", "discrimin"}, {"discriminators", "discrimin"}, {"discriminatory", "discriminatori"}, {"discs", "disc"},
+		{"discursive", "discurs"}, {"discus", "discus"}, {"discuss", "discuss"}, {"discussed", "discuss"}, {"discusses", "discuss"},
+		{"discussing", "discuss"}, {"discussion", "discuss"}, {"discussions", "discuss"}, {"disdain", "disdain"}, {"disdainful", "disdain"},
+		{"disease", "diseas"}, {"disembark", "disembark"}, {"disembodied", "disembodi"}, {"disenchant", "disench"},
+		{"disenchantment", "disenchant"}, {"disengage", "disengag"}, {"disentangle", "disentangl"}, {"disfavor", "disfavor"},
+		{"disfavored", "disfavor"}, {"disfavour", "disfavour"}, {"disfigure", "disfigur"}, {"disgorge", "disgorg"}, {"disgrace", "disgrac"},
+		{"disgraceful", "disgrac"}, {"disgruntled", "disgruntl"}, {"disguise", "disguis"}, {"disgust", "disgust"}, {"dish", "dish"},
+		{"disharmony", "disharmoni"}, {"dishearten", "dishearten"}, {"dishes", "dish"}, {"dishevelled", "dishevel"}, {"dishonest", "dishonest"},
+		{"dishonesty", "dishonesti"}, {"dishonor", "dishonor"}, {"dishonour", "dishonour"}, {"dishonourable", "dishonour"},
+		{"dishwasher", "dishwash"}, {"disillusion", "disillus"}, {"disillusionment", "disillusion"}, {"disincentive", "disincent"},
+		{"disinclination", "disinclin"}, {"disinclined", "disinclin"}, {"disinfect", "disinfect"}, {"disinfectant", "disinfect"},
+		{"disingenuous", "disingenu"}, {"disinherit", "disinherit"}, {"disintegrate", "disintegr"}, {"disintegration", "disintegr"},
+		{"disinterest", "disinterest"}, {"disinterested", "disinterest"}, {"disjoint", "disjoint"}, {"disjointed", "disjoint"}, {"disk", "disk"},
+		{"disks", "disk"}, {"dislike", "dislik"}, {"dislikes", "dislik"}, {"dislocate", "disloc"}, {"dislocation", "disloc"},
+		{"dislodge", "dislodg"}, {"disloyal", "disloy"}, {"disloyalty", "disloyalti"}, {"dismal", "dismal"}, {"dismantle", "dismantl"},
+		{"dismay", "dismay"}, {"dismember", "dismemb"}, {"dismiss", "dismiss"}, {"dismissal", "dismiss"}, {"dismissed", "dismiss"},
+		{"dismissive", "dismiss"}, {"dismount", "dismount"}, {"dismounted", "dismount"}, {"disobedience", "disobedi"},
+		{"disobedient", "disobedi"}, {"disobey", "disobey"}, {"disorder", "disord"}, {"disorderly", "disord"}, {"disorganize", "disorgan"},
+		{"disorient", "disori"}, {"disorientate", "disorient"}, {"disown", "disown"}, {"disparage", "disparag"}, {"disparate", "dispar"},
+		{"disparity", "dispar"}, {"dispassionate", "dispassion"}, {"dispatch", "dispatch"}, {"dispatchable", "dispatch"},
+		{"dispatched", "dispatch"}, {"dispatcher", "dispatch"}, {"dispatches", "dispatch"}, {"dispatching", "dispatch"}, {"dispel", "dispel"},
+		{"dispensable", "dispens"}, {"dispensary", "dispensari"}, {"dispensation", "dispens"}, {"dispense", "dispens"}, {"dispenser", "dispens"},
+		{"dispersal", "dispers"}, {"disperse", "dispers"}, {"dispersion", "dispers"}, {"dispirit", "dispirit"}, {"displace", "displac"},
+		{"displaced", "displac"}, {"displacement", "displac"}, {"displacements", "displac"}, {"display", "display"}, {"displayable", "display"},
+		{"displayed", "display"}, {"displayer", "display"}, {"displaying", "display"}, {"displays", "display"}, {"displease", "displeas"},
+		{"displeasure", "displeasur"}, {"disposable", "dispos"}, {"disposal", "dispos"}, {"dispose", "dispos"}, {"disposed", "dispos"},
+		{"disposing", "dispos"}, {"disposition", "disposit"}, {"dispositions", "disposit"}, {"dispossess", "dispossess"},
+		{"disproportion", "disproport"}, {"disproportionate", "disproportion"}, {"disproportionately", "disproportion"}, {"disprove", "disprov"},
+		{"disputable", "disput"}, {"dispute", "disput"}, {"disqualification", "disqualif"}, {"disqualify", "disqualifi"},
+		{"disquiet", "disquiet"}, {"disregard", "disregard"}, {"disregarded", "disregard"}, {"disregarding", "disregard"},
+		{"disrepair", "disrepair"}, {"disreputable", "disreput"}, {"disrepute", "disreput"}, {"disrespect", "disrespect"},
+		{"disrespectful", "disrespect"}, {"disrespecting", "disrespect"}, {"disrupt", "disrupt"}, {"disrupted", "disrupt"},
+		{"disrupting", "disrupt"}, {"disruption", "disrupt"}, {"disruptions", "disrupt"}, {"disruptive", "disrupt"}, {"disrupts", "disrupt"},
+		{"dissatisfaction", "dissatisfact"}, {"dissatisfy", "dissatisfi"}, {"dissect", "dissect"}, {"dissecting", "dissect"},
+		{"dissection", "dissect"}, {"disseminate", "dissemin"}, {"dissemination", "dissemin"}, {"dissension", "dissens"}, {"dissent", "dissent"},
+		{"dissenter", "dissent"}, {"dissertation", "dissert"}, {"disservice", "disservic"}, {"dissident", "dissid"},
+		{"dissimilar", "dissimilar"}, {"dissimilarity", "dissimilar"}, {"dissipate", "dissip"}, {"dissipation", "dissip"},
+		{"dissociate", "dissoci"}, {"dissociates", "dissoci"}, {"dissolute", "dissolut"}, {"dissolution", "dissolut"}, {"dissolve", "dissolv"},
+		{"dissonance", "disson"}, {"dissuade", "dissuad"}, {"distance", "distanc"}, {"distances", "distanc"}, {"distant", "distant"},
+		{"distaste", "distast"}, {"distasteful", "distast"}, {"distend", "distend"}, {"distil", "distil"}, {"distill", "distil"},
+		{"distillation", "distil"}, {"distiller", "distil"}, {"distillery", "distilleri"}, {"distinct", "distinct"}, {"distinction", "distinct"},
+		{"distinctions", "distinct"}, {"distinctive", "distinct"}, {"distinguish", "distinguish"}, {"distinguishable", "distinguish"},
+		{"distinguished", "distinguish"}, {"distinguishes", "distinguish"}, {"distinguishing", "distinguish"},
+		{"distinguishment", "distinguish"}, {"distort", "distort"}, {"distortion", "distort"}, {"distract", "distract"},
+		{"distracting", "distract"}, {"distraction", "distract"}, {"distraught", "distraught"}, {"distress", "distress"},
+		{"distribute", "distribut"}, {"distributed", "distribut"}, {"distributes", "distribut"}, {"distributing", "distribut"},
+		{"distribution", "distribut"}, {"distributions", "distribut"}, {"distributor", "distributor"}, {"distributors", "distributor"},
+		{"district", "district"}, {"distrust", "distrust"}, {"distrustful", "distrust"}, {"disturb", "disturb"}, {"disturbance", "disturb"},
+		{"disturbing", "disturb"}, {"disunity", "disun"}, {"disuse", "disus"}, {"disused", "disus"}, {"ditch", "ditch"}, {"dither", "dither"},
+		{"ditto", "ditto"}, {"ditty", "ditti"}, {"diurnal", "diurnal"}, {"divan", "divan"}, {"dive", "dive"}, {"diver", "diver"},
+		{"diverge", "diverg"}, {"diverged", "diverg"}, {"divergence", "diverg"}, {"divergent", "diverg"}, {"diverges", "diverg"},
+		{"diverging", "diverg"}, {"diverse", "divers"}, {"diversify", "diversifi"}, {"diversion", "divers"}, {"diversions", "divers"},
+		{"diversity", "divers"}, {"divert", "divert"}, {"diverted", "divert"}, {"diverting", "divert"}, {"diverts", "divert"},
+		{"divest", "divest"}, {"divested", "divest"}, {"dividable", "divid"}, {"divide", "divid"}, {"divided", "divid"},
+		{"dividend", "dividend"}, {"divider", "divid"}, {"divides", "divid"}, {"dividing", "divid"}, {"divine", "divin"}, {"divining", "divin"},
+		{"divinity", "divin"}, {"divisible", "divis"}, {"division", "divis"}, {"divisive", "divis"}, {"divorce", "divorc"},
+		{"divorcee", "divorce"}, {"divulge", "divulg"}, {"dizzy", "dizzi"}, {"do", "do"}, {"docile", "docil"}, {"dock", "dock"},
+		{"docked", "dock"}, {"docker", "docker"}, {"docket", "docket"}, {"docking", "dock"}, {"docks", "dock"}, {"dockyard", "dockyard"},
+		{"doctor", "doctor"}, {"doctoral", "doctor"}, {"doctorate", "doctor"}, {"doctored", "doctor"}, {"doctrinaire", "doctrinair"},
+		{"doctrinal", "doctrin"}, {"doctrine", "doctrin"}, {"document", "document"}, {"documentary", "documentari"},
+		{"documentation", "document"}, {"documentations", "document"}, {"documented", "document"}, {"documenting", "document"},
+		{"documents", "document"}, {"dodge", "dodg"}, {"dodgy", "dodgi"}, {"doe", "doe"}, {"doer", "doer"}, {"does", "doe"}, {"dog", "dog"},
+		{"dogged", "dog"}, {"doggerel", "doggerel"}, {"dogma", "dogma"}, {"dogmatic", "dogmat"}, {"dogmatism", "dogmat"}, {"dogs", "dog"},
+		{"doing", "do"}, {"dole", "dole"}, {"doleful", "dole"}, {"doll", "doll"}, {"dollar", "dollar"}, {"dollars", "dollar"},
+		{"dollop", "dollop"}, {"dolphin", "dolphin"}, {"domain", "domain"}, {"domains", "domain"}, {"dome", "dome"}, {"domestic", "domest"},
+		{"domesticate", "domest"}, {"domesticity", "domest"}, {"domicile", "domicil"}, {"dominance", "domin"}, {"dominant", "domin"},
+		{"dominate", "domin"}, {"dominated", "domin"}, {"dominating", "domin"}, {"domination", "domin"}, {"domineer", "domin"},
+		{"dominic", "domin"}, {"dominion", "dominion"}, {"domino", "domino"}, {"don", "don"}, {"donate", "donat"}, {"donating", "donat"},
+		{"donation", "donat"}, {"donkey", "donkey"}, {"donor", "donor"}, {"doodle", "doodl"}, {"doom", "doom"}, {"door", "door"},
+		{"doorbell", "doorbel"}, {"doorknob", "doorknob"}, {"doorman", "doorman"}, {"doormat", "doormat"}, {"doors", "door"},
+		{"doorstep", "doorstep"}, {"doorway", "doorway"}, {"dope", "dope"}, {"dopey", "dopey"}, {"dormant", "dormant"},
+		{"dormitory", "dormitori"}, {"dormouse", "dormous"}, {"dosage", "dosag"}, {"dose", "dose"}, {"dossier", "dossier"}, {"dot", "dot"},
+		{"dote", "dote"}, {"dots", "dot"}, {"dotted", "dot"}, {"double", "doubl"}, {"doubled", "doubl"}, {"doubles", "doubl"},
+		{"doubling", "doubl"}, {"doublings", "doubl"}, {"doubt", "doubt"}, {"doubtful", "doubt"}, {"doubtless", "doubtless"}, {"dough", "dough"},
+		{"doughnut", "doughnut"}, {"dour", "dour"}, {"douse", "dous"}, {"dove", "dove"}, {"dovetail", "dovetail"}, {"dowager", "dowag"},
+		{"dowdy", "dowdi"}, {"down", "down"}, {"downcast", "downcast"}, {"downfall", "downfal"}, {"downgrade", "downgrad"},
+		{"downgraded", "downgrad"}, {"downgrades", "downgrad"}, {"downgrading", "downgrad"}, {"downhearted", "downheart"},
+		{"downhill", "downhil"}, {"download", "download"}, {"downloaded", "download"}, {"downloader", "download"}, {"downloading", "download"},
+		{"downloads", "download"}, {"downpour", "downpour"}, {"downright", "downright"}, {"downs", "down"}, {"downside", "downsid"},
+		{"downstairs", "downstair"}, {"downstream", "downstream"}, {"downtown", "downtown"}, {"downtrodden", "downtrodden"},
+		{"downturn", "downturn"}, {"downward", "downward"}, {"downwards", "downward"}, {"dowry", "dowri"}, {"doze", "doze"}, {"dozen", "dozen"},
+		{"dozens", "dozen"}, {"drab", "drab"}, {"draft", "draft"}, {"drafts", "draft"}, {"draftsman", "draftsman"}, {"drafty", "drafti"},
+		{"drag", "drag"}, {"dragon", "dragon"}, {"dragonfly", "dragonfli"}, {"dragoon", "dragoon"}, {"drain", "drain"}, {"drainage", "drainag"},
+		{"drained", "drain"}, {"draining", "drain"}, {"drainpipe", "drainpip"}, {"drains", "drain"}, {"drake", "drake"}, {"drama", "drama"},
+		{"dramatic", "dramat"}, {"dramatically", "dramat"}, {"dramatics", "dramat"}, {"dramatist", "dramatist"}, {"dramatize", "dramat"},
+		{"drape", "drape"}, {"draper", "draper"}, {"drapery", "draperi"}, {"drastic", "drastic"}, {"drastically", "drastic"},
+		{"draught", "draught"}, {"draughtsman", "draughtsman"}, {"draughty", "draughti"}, {"draw", "draw"}, {"drawback", "drawback"},
+		{"drawbacks", "drawback"}, {"drawbridge", "drawbridg"}, {"drawer", "drawer"}, {"drawing", "draw"}, {"drawings", "draw"},
+		{"drawl", "drawl"}, {"draws", "draw"}, {"dread", "dread"}, {"dreadful", "dread"}, {"dream", "dream"}, {"dreamer", "dreamer"},
+		{"dreamy", "dreami"}, {"dreary", "dreari"}, {"dredge", "dredg"}, {"dregs", "dreg"}, {"drench", "drench"}, {"dress", "dress"},
+		{"dresser", "dresser"}, {"dressing", "dress"}, {"dressmaker", "dressmak"}, {"dribble", "dribbl"}, {"drier", "drier"}, {"dries", "dri"},
+		{"drift", "drift"}, {"drifter", "drifter"}, {"drifting", "drift"}, {"driftwood", "driftwood"}, {"drill", "drill"}, {"drink", "drink"},
+		{"drinker", "drinker"}, {"drip", "drip"}, {"drive", "drive"}, {"drivel", "drivel"}, {"driver", "driver"}, {"drivers", "driver"},
+		{"drives", "drive"}, {"driveway", "driveway"}, {"driving", "drive"}, {"drizzle", "drizzl"}, {"droll", "droll"}, {"drone", "drone"},
+		{"drool", "drool"}, {"droop", "droop"}, {"drop", "drop"}, {"droplet", "droplet"}, {"dropout", "dropout"}, {"dropped", "drop"},
+		{"dropper", "dropper"}, {"dropping", "drop"}, {"droppings", "drop"}, {"drops", "drop"}, {"drought", "drought"}, {"drove", "drove"},
+		{"drown", "drown"}, {"drowse", "drows"}, {"drowsy", "drowsi"}, {"drudge", "drudg"}, {"drudgery", "drudgeri"}, {"drug", "drug"},
+		{"druggist", "druggist"}, {"drugstore", "drugstor"}, {"drum", "drum"}, {"drummer", "drummer"}, {"drumstick", "drumstick"},
+		{"drunk", "drunk"}, {"drunkard", "drunkard"}, {"drunken", "drunken"}, {"dry", "dri"}, {"dryer", "dryer"}, {"dual", "dual"},
+		{"dualism", "dualism"}, {"dub", "dub"}, {"dubious", "dubious"}, {"duchess", "duchess"}, {"duchy", "duchi"}, {"duck", "duck"},
+		{"duckling", "duckl"}, {"duct", "duct"}, {"dud", "dud"}, {"dude", "dude"}, {"due", "due"}, {"duel", "duel"}, {"duet", "duet"},
+		{"duffel", "duffel"}, {"dug", "dug"}, {"dugout", "dugout"}, {"duke", "duke"}, {"dull", "dull"}, {"duly", "duli"}, {"dumb", "dumb"},
+		{"dumbfound", "dumbfound"}, {"dummy", "dummi"}, {"dump", "dump"}, {"dumpe", "dump"}, {"dumped", "dump"}, {"dumping", "dump"},
+		{"dumpling", "dumpl"}, {"dumps", "dump"}, {"dumpy", "dumpi"}, {"dun", "dun"}, {"dunce", "dunc"}, {"dune", "dune"}, {"dung", "dung"},
+		{"dungarees", "dungare"}, {"dungeon", "dungeon"}, {"dunk", "dunk"}, {"duo", "duo"}, {"dupe", "dupe"}, {"duplex", "duplex"},
+		{"duplicate", "duplic"}, {"duplicated", "duplic"}, {"duplicates", "duplic"}, {"duplicating", "duplic"}, {"duplication", "duplic"},
+		{"duplicity", "duplic"}, {"durability", "durabl"}, {"durable", "durabl"}, {"duration", "durat"}, {"durations", "durat"},
+		{"duress", "duress"}, {"during", "dure"}, {"dusk", "dusk"}, {"dusky", "duski"}, {"dust", "dust"}, {"dustbin", "dustbin"},
+		{"duster", "duster"}, {"dustman", "dustman"}, {"dustpan", "dustpan"}, {"dusty", "dusti"}, {"duties", "duti"}, {"dutiful", "duti"},
+		{"duty", "duti"}, {"duvet", "duvet"}, {"dwarf", "dwarf"}, {"dwell", "dwell"}, {"dweller", "dweller"}, {"dwelling", "dwell"},
+		{"dwindle", "dwindl"}, {"dye", "dye"}, {"dying", "die"}, {"dyke", "dyke"}, {"dynamic", "dynam"}, {"dynamically", "dynam"},
+		{"dynamics", "dynam"}, {"dynamism", "dynam"}, {"dynamite", "dynamit"}, {"dynamo", "dynamo"}, {"dynastic", "dynast"},
+		{"dynasty", "dynasti"}, {"dysentery", "dysenteri"}, {"dyslexia", "dyslexia"}, {"dyslexic", "dyslex"}, {"each", "each"},
+		{"eager", "eager"}, {"eagerly", "eager"}, {"eagle", "eagl"}, {"eagles", "eagl"}, {"ear", "ear"}, {"earache", "earach"},
+		{"eardrum", "eardrum"}, {"earl", "earl"}, {"earldom", "earldom"}, {"early", "earli"}, {"earmark", "earmark"}, {"earn", "earn"},
+		{"earnest", "earnest"}, {"earnings", "earn"}, {"earphone", "earphon"}, {"earring", "earring"}, {"earshot", "earshot"},
+		{"earth", "earth"}, {"earthen", "earthen"}, {"earthenware", "earthenwar"}, {"earthly", "earth"}, {"earthquake", "earthquak"},
+		{"earthworm", "earthworm"}, {"earthy", "earthi"}, {"earwig", "earwig"}, {"ease", "eas"}, {"easel", "easel"}, {"eases", "eas"},
+		{"east", "east"}, {"easterly", "easter"}, {"eastern", "eastern"}, {"eastward", "eastward"}, {"easy", "easi"}, {"easygoing", "easygo"},
+		{"eat", "eat"}, {"eater", "eater"}, {"eatery", "eateri"}, {"eaves", "eav"}, {"eavesdrop", "eavesdrop"}, {"eavesdropping", "eavesdrop"},
+		{"ebb", "ebb"}, {"ebbe", "ebb"}, {"ebony", "eboni"}, {"ebullient", "ebulli"}, {"eccentric", "eccentr"}, {"eccentricity", "eccentr"},
+		{"ecclesiastic", "ecclesiast"}, {"ecclesiastical", "ecclesiast"}, {"echelon", "echelon"}, {"echo", "echo"}, {"echoe", "echo"},
+		{"echoed", "echo"}, {"echoes", "echo"}, {"echoing", "echo"}, {"echos", "echo"}, {"eclectic", "eclect"}, {"eclipse", "eclips"},
+		{"ecological", "ecolog"}, {"ecologist", "ecologist"}, {"ecology", "ecolog"}, {"economic", "econom"}, {"economical", "econom"},
+		{"economics", "econom"}, {"economist", "economist"}, {"economize", "econom"}, {"economy", "economi"}, {"ecosystem", "ecosystem"},
+		{"ecstasy", "ecstasi"}, {"ecstatic", "ecstat"}, {"ecumenical", "ecumen"}, {"eczema", "eczema"}, {"eddie", "eddi"}, {"eddy", "eddi"},
+		{"edg", "edg"}, {"edge", "edg"}, {"edges", "edg"}, {"edgeways", "edgeway"}, {"edgy", "edgi"}, {"edible", "edibl"}, {"edict", "edict"},
+		{"edifice", "edific"}, {"edify", "edifi"}, {"edit", "edit"}, {"editable", "edit"}, {"edited", "edit"}, {"editing", "edit"},
+		{"edition", "edit"}, {"editions", "edit"}, {"editor", "editor"}, {"editorial", "editori"}, {"editors", "editor"}, {"edits", "edit"},
+		{"educate", "educ"}, {"education", "educ"}, {"educational", "educ"}, {"educationalist", "educationalist"}, {"educator", "educ"},
+		{"eel", "eel"}, {"eerie", "eeri"}, {"efface", "effac"}, {"effect", "effect"}, {"effected", "effect"}, {"effective", "effect"},
+		{"effectively", "effect"}, {"effects", "effect"}, {"effectual", "effectu"}, {"effeminate", "effemin"}, {"effervescent", "effervesc"},
+		{"efficacious", "efficaci"}, {"efficacy", "efficaci"}, {"efficiency", "effici"}, {"efficient", "effici"}, {"efficiently", "effici"},
+		{"effigy", "effigi"}, {"effluent", "effluent"}, {"effort", "effort"}, {"effortless", "effortless"}, {"efforts", "effort"},
+		{"effrontery", "effronteri"}, {"effusive", "effus"}, {"egalitarian", "egalitarian"}, {"egg", "egg"}, {"eggplant", "eggplant"},
+		{"eggs", "egg"}, {"eggshell", "eggshel"}, {"ego", "ego"}, {"egocentric", "egocentr"}, {"egoism", "egoism"}, {"egoist", "egoist"},
+		{"egotism", "egot"}, {"egotist", "egotist"}, {"egregious", "egregi"}, {"egress", "egress"}, {"eh", "eh"}, {"eiderdown", "eiderdown"},
+		{"eight", "eight"}, {"eighteen", "eighteen"}, {"eighth", "eighth"}, {"eighty", "eighti"}, {"either", "either"}, {"ejaculate", "ejacul"},
+		{"ejaculation", "ejacul"}, {"eject", "eject"}, {"ejecting", "eject"}, {"ejection", "eject"}, {"eke", "eke"}, {"elaborate", "elabor"},
+		{"elaborated", "elabor"}, {"elaboration", "elabor"}, {"elapsation", "elaps"}, {"elapse", "elaps"}, {"elapsed", "elaps"},
+		{"elapses", "elaps"}, {"elapsing", "elaps"}, {"elastic", "elast"}, {"elasticity", "elast"}, {"elate", "elat"}, {"elation", "elat"},
+		{"elbow", "elbow"}, {"elder", "elder"}, {"elderly", "elder"}, {"eldest", "eldest"}, {"elect", "elect"}, {"elected", "elect"},
+		{"election", "elect"}, {"electoral", "elector"}, {"electorate", "elector"}, {"electric", "electr"}, {"electrical", "electr"},
+		{"electrician", "electrician"}, {"electricity", "electr"}, {"electrify", "electrifi"}, {"electrocute", "electrocut"},
+		{"electrode", "electrod"}, {"electrolysis", "electrolysi"}, {"electrolyte", "electrolyt"}, {"electromagnet", "electromagnet"},
+		{"electromagnetic", "electromagnet"}, {"electron", "electron"}, {"electronic", "electron"}, {"electronics", "electron"},
+		{"elects", "elect"}, {"elegance", "eleg"}, {"elegant", "eleg"}, {"elegy", "elegi"}, {"element", "element"}, {"elemental", "element"},
+		{"elementary", "elementari"}, {"elements", "element"}, {"elephant", "eleph"}, {"elephantine", "elephantin"}, {"elevate", "elev"},
+		{"elevated", "elev"}, {"elevating", "elev"}, {"elevation", "elev"}, {"elevator", "elev"}, {"eleven", "eleven"}, {"eleventh", "eleventh"},
+		{"elf", "elf"}, {"elicit", "elicit"}, {"elicits", "elicit"}, {"eligibility", "elig"}, {"eligible", "elig"}, {"eliminate", "elimin"},
+		{"eliminated", "elimin"}, {"eliminates", "elimin"}, {"eliminating", "elimin"}, {"elimination", "elimin"}, {"elit", "elit"},
+		{"elite", "elit"}, {"elitism", "elit"}, {"elitist", "elitist"}, {"elixir", "elixir"}, {"elk", "elk"}, {"ellipse", "ellips"},
+		{"ellipses", "ellips"}, {"ellipsis", "ellipsi"}, {"ellipsize", "ellips"}, {"ellipsoid", "ellipsoid"}, {"elliptic", "ellipt"},
+		{"elliptical", "ellipt"}, {"elm", "elm"}, {"elocution", "elocut"}, {"elongate", "elong"}, {"elope", "elop"}, {"eloquence", "eloqu"},
+		{"eloquent", "eloqu"}, {"else", "els"}, {"elsewhere", "elsewher"}, {"elucidate", "elucid"}, {"elude", "elud"}, {"elusive", "elus"},
+		{"emaciate", "emaci"}, {"emaciation", "emaci"}, {"email", "email"}, {"emailed", "email"}, {"emailing", "email"}, {"emails", "email"},
+		{"emanate", "eman"}, {"emancipate", "emancip"}, {"emancipation", "emancip"}, {"emasculate", "emascul"}, {"emb", "emb"},
+		{"embalm", "embalm"}, {"embankment", "embank"}, {"embargo", "embargo"}, {"embargoed", "embargo"}, {"embark", "embark"},
+		{"embarkation", "embark"}, {"embarrass", "embarrass"}, {"embarrassing", "embarrass"}, {"embarrassment", "embarrass"},
+		{"embassy", "embassi"}, {"embattled", "embattl"}, {"embed", "emb"}, {"embeds", "emb"}, {"embellish", "embellish"},
+		{"embellishment", "embellish"}, {"ember", "ember"}, {"embezzle", "embezzl"}, {"embezzlement", "embezzl"}, {"embitter", "embitt"},
+		{"emblazon", "emblazon"}, {"emblem", "emblem"}, {"emblematic", "emblemat"}, {"embodied", "embodi"}, {"embodiment", "embodi"},
+		{"embody", "embodi"}, {"emboss", "emboss"}, {"embrace", "embrac"}, {"embroider", "embroid"}, {"embroidery", "embroideri"},
+		{"embroil", "embroil"}, {"embryo", "embryo"}, {"embryonic", "embryon"}, {"emerald", "emerald"}, {"emerg", "emerg"}, {"emerge", "emerg"},
+		{"emerged", "emerg"}, {"emergence", "emerg"}, {"emergency", "emerg"}, {"emergent", "emerg"}, {"emeritus", "emeritus"},
+		{"emigrant", "emigr"}, {"emigrate", "emigr"}, {"emigration", "emigr"}, {"eminence", "emin"}, {"eminent", "emin"}, {"emir", "emir"},
+		{"emirate", "emir"}, {"emissary", "emissari"}, {"emission", "emiss"}, {"emit", "emit"}, {"emits", "emit"}, {"emitted", "emit"},
+		{"emitting", "emit"}, {"emotion", "emot"}, {"emotional", "emot"}, {"emotive", "emot"}, {"empathize", "empath"}, {"empathy", "empathi"},
+		{"emperor", "emperor"}, {"emphasis", "emphasi"}, {"emphasize", "emphas"}, {"emphasized", "emphas"}, {"emphatic", "emphat"},
+		{"empire", "empir"}, {"empirical", "empir"}, {"empiricism", "empiric"}, {"employ", "employ"}, {"employed", "employ"},
+		{"employee", "employe"}, {"employees", "employe"}, {"employer", "employ"}, {"employing", "employ"}, {"employment", "employ"},
+		{"employs", "employ"}, {"emporium", "emporium"}, {"empower", "empow"}, {"empress", "empress"}, {"emptied", "empti"},
+		{"empties", "empti"}, {"empty", "empti"}, {"emptying", "empti"}, {"emu", "emu"}, {"emul", "emul"}, {"emulate", "emul"},
+		{"emulated", "emul"}, {"emulates", "emul"}, {"emulating", "emul"}, {"emulation", "emul"}, {"emulations", "emul"}, {"emulator", "emul"},
+		{"emulators", "emul"}, {"emulsion", "emuls"}, {"enable", "enabl"}, {"enabled", "enabl"}, {"enablement", "enabl"}, {"enables", "enabl"},
+		{"enabling", "enabl"}, {"enact", "enact"}, {"enactment", "enact"}, {"enamel", "enamel"}, {"enamor", "enamor"}, {"enamour", "enamour"},
+		{"encamp", "encamp"}, {"encampment", "encamp"}, {"encapsulate", "encapsul"}, {"encapsulated", "encapsul"}, {"encapsulates", "encapsul"},
+		{"encapsulating", "encapsul"}, {"encapsulation", "encapsul"}, {"encase", "encas"}, {"enchant", "enchant"}, {"enchantment", "enchant"},
+		{"encircle", "encircl"}, {"enclave", "enclav"}, {"enclose", "enclos"}, {"enclosed", "enclos"}, {"encloses", "enclos"},
+		{"enclosing", "enclos"}, {"enclosure", "enclosur"}, {"encode", "encod"}, {"encoded", "encod"}, {"encoder", "encod"},
+		{"encoders", "encod"}, {"encodes", "encod"}, {"encoding", "encod"}, {"encodings", "encod"}, {"encompass", "encompass"},
+		{"encompasses", "encompass"}, {"encompassing", "encompass"}, {"encore", "encor"}, {"encounted", "encount"}, {"encounter", "encount"},
+		{"encountered", "encount"}, {"encountering", "encount"}, {"encounters", "encount"}, {"encourage", "encourag"},
+		{"encouraged", "encourag"}, {"encouragement", "encourag"}, {"encourages", "encourag"}, {"encouraging", "encourag"},
+		{"encroach", "encroach"}, {"encroachment", "encroach"}, {"encrust", "encrust"}, {"encrypt", "encrypt"}, {"encrypted", "encrypt"},
+		{"encrypter", "encrypt"}, {"encrypting", "encrypt"}, {"encryption", "encrypt"}, {"encrypts", "encrypt"}, {"encumber", "encumb"},
+		{"encumbered", "encumb"}, {"encumbrance", "encumbr"}, {"encyclopaedia", "encyclopaedia"}, {"encyclopedia", "encyclopedia"},
+		{"encyclopedic", "encycloped"}, {"end", "end"}, {"endanger", "endang"}, {"endear", "endear"}, {"endearment", "endear"},
+		{"endeavor", "endeavor"}, {"endeavour", "endeavour"}, {"ended", "end"}, {"endemic", "endem"}, {"ending", "end"}, {"endings", "end"},
+		{"endless", "endless"}, {"endorse", "endors"}, {"endorsed", "endors"}, {"endorsement", "endors"}, {"endorsements", "endors"},
+		{"endow", "endow"}, {"endowed", "endow"}, {"endowment", "endow"}, {"ends", "end"}, {"endurance", "endur"}, {"endure", "endur"},
+		{"enema", "enema"}, {"enemy", "enemi"}, {"energetic", "energet"}, {"energize", "energ"}, {"energy", "energi"}, {"enervate", "enerv"},
+		{"enfeeble", "enfeebl"}, {"enforce", "enforc"}, {"enforceable", "enforc"}, {"enforced", "enforc"}, {"enforcement", "enforc"},
+		{"enforcer", "enforc"}, {"enforces", "enforc"}, {"enforcing", "enforc"}, {"enfranchise", "enfranchis"},
+		{"enfranchisement", "enfranchis"}, {"engage", "engag"}, {"engaged", "engag"}, {"engagement", "engag"}, {"engender", "engend"},
+		{"engine", "engin"}, {"engineer", "engin"}, {"engineered", "engin"}, {"engineering", "engin"}, {"engineers", "engin"},
+		{"engines", "engin"}, {"engrave", "engrav"}, {"engraving", "engrav"}, {"engross", "engross"}, {"engulf", "engulf"},
+		{"enhance", "enhanc"}, {"enhanced", "enhanc"}, {"enhancement", "enhanc"}, {"enhancements", "enhanc"}, {"enhances", "enhanc"},
+		{"enhancing", "enhanc"}, {"enigma", "enigma"}, {"enigmatic", "enigmat"}, {"enjoin", "enjoin"}, {"enjoy", "enjoy"},
+		{"enjoyable", "enjoy"}, {"enjoyed", "enjoy"}, {"enjoyment", "enjoy"}, {"enlarge", "enlarg"}, {"enlarged", "enlarg"},
+		{"enlargement", "enlarg"}, {"enlighten", "enlighten"}, {"enlightened", "enlighten"}, {"enlightenment", "enlighten"},
+		{"enlist", "enlist"}, {"enlistment", "enlist"}, {"enlistments", "enlist"}, {"enliven", "enliven"}, {"enmity", "enmiti"},
+		{"ennoble", "ennobl"}, {"enormity", "enorm"}, {"enormous", "enorm"}, {"enough", "enough"}, {"enquire", "enquir"}, {"enquiry", "enquiri"},
+		{"enrage", "enrag"}, {"enrapture", "enraptur"}, {"enrich", "enrich"}, {"enrichment", "enrich"}, {"enrol", "enrol"}, {"enroll", "enrol"},
+		{"enrolled", "enrol"}, {"enrolling", "enrol"}, {"enrollment", "enrol"}, {"enrollments", "enrol"}, {"enrolls", "enrol"},
+		{"enrolment", "enrol"}, {"ensconce", "ensconc"}, {"ensemble", "ensembl"}, {"enshrine", "enshrin"}, {"ensign", "ensign"},
+		{"enslave", "enslav"}, {"enslaved", "enslav"}, {"enslaves", "enslav"}, {"enslaving", "enslav"}, {"ensnare", "ensnar"}, {"ensue", "ensu"},
+		{"ensure", "ensur"}, {"ensured", "ensur"}, {"ensures", "ensur"}, {"ensuring", "ensur"}, {"entail", "entail"}, {"entails", "entail"},
+		{"entangle", "entangl"}, {"entanglement", "entangl"}, {"enter", "enter"}, {"entered", "enter"}, {"entering", "enter"},
+		{"enterprise", "enterpris"}, {"enterprises", "enterpris"}, {"enters", "enter"}, {"entertain", "entertain"}, {"entertainer", "entertain"},
+		{"entertainment", "entertain"}, {"enthral", "enthral"}, {"enthrall", "enthral"}, {"enthrone", "enthron"}, {"enthuse", "enthus"},
+		{"enthusiasm", "enthusiasm"}, {"enthusiast", "enthusiast"}, {"enthusiastic", "enthusiast"}, {"entice", "entic"}, {"enticement", "entic"},
+		{"entire", "entir"}, {"entirely", "entir"}, {"entirety", "entireti"}, {"entities", "entiti"}, {"entitle", "entitl"},
+		{"entitled", "entitl"}, {"entitlement", "entitl"}, {"entitlements", "entitl"}, {"entity", "entiti"}, {"entomb", "entomb"},
+		{"entomology", "entomolog"}, {"entourage", "entourag"}, {"entrails", "entrail"}, {"entrance", "entranc"}, {"entrancy", "entranc"},
+		{"entrant", "entrant"}, {"entrap", "entrap"}, {"entreat", "entreat"}, {"entreaty", "entreati"}, {"entree", "entre"},
+		{"entrench", "entrench"}, {"entrenchment", "entrench"}, {"entrepreneur", "entrepreneur"}, {"entrepreneurial", "entrepreneuri"},
+		{"entries", "entri"}, {"entrust", "entrust"}, {"entry", "entri"}, {"entwine", "entwin"}, {"enumerability", "enumer"},
+		{"enumerable", "enumer"}, {"enumerate", "enumer"}, {"enumerated", "enumer"}, {"enumerates", "enumer"}, {"enumerating", "enumer"},
+		{"enumeration", "enumer"}, {"enumerator", "enumer"}, {"enumerators", "enumer"}, {"enunciate", "enunci"}, {"envelop", "envelop"},
+		{"envelope", "envelop"}, {"enveloped", "envelop"}, {"enviable", "enviabl"}, {"envious", "envious"}, {"environ", "environ"},
+		{"environment", "environ"}, {"environmental", "environment"}, {"environmentalist", "environmentalist"}, {"environments", "environ"},
+		{"envisage", "envisag"}, {"envision", "envis"}, {"envisioned", "envis"}, {"envoy", "envoy"}, {"envy", "envi"}, {"enzyme", "enzym"},
+		{"ephemeral", "ephemer"}, {"epic", "epic"}, {"epicenter", "epicent"}, {"epicentre", "epicentr"}, {"epidemic", "epidem"},
+		{"epidemiology", "epidemiolog"}, {"epidermis", "epidermi"}, {"epigram", "epigram"}, {"epilepsy", "epilepsi"}, {"epileptic", "epilept"},
+		{"epilogue", "epilogu"}, {"episcopal", "episcop"}, {"episode", "episod"}, {"episodic", "episod"}, {"epistle", "epistl"},
+		{"epitaph", "epitaph"}, {"epithet", "epithet"}, {"epitome", "epitom"}, {"epitomize", "epitom"}, {"epoch", "epoch"}, {"epochs", "epoch"},
+		{"equable", "equabl"}, {"equal", "equal"}, {"equality", "equal"}, {"equalize", "equal"}, {"equalizer", "equal"}, {"equalizers", "equal"},
+		{"equally", "equal"}, {"equals", "equal"}, {"equanimity", "equanim"}, {"equate", "equat"}, {"equates", "equat"}, {"equation", "equat"},
+		{"equator", "equat"}, {"equatorial", "equatori"}, {"equestrian", "equestrian"}, {"equidistant", "equidist"},
+		{"equilateral", "equilater"}, {"equilibrium", "equilibrium"}, {"equine", "equin"}, {"equinox", "equinox"}, {"equip", "equip"},
+		{"equipment", "equip"}, {"equipped", "equip"}, {"equitable", "equit"}, {"equity", "equiti"}, {"equivalence", "equival"},
+		{"equivalent", "equival"}, {"equivalently", "equival"}, {"equivalents", "equival"}, {"equivocal", "equivoc"}, {"era", "era"},
+		{"eradicate", "erad"}, {"eradication", "erad"}, {"erase", "eras"}, {"erased", "eras"}, {"eraser", "eras"}, {"erases", "eras"},
+		{"erasing", "eras"}, {"erasure", "erasur"}, {"erect", "erect"}, {"erection", "erect"}, {"ergonomic", "ergonom"},
+		{"ergonomically", "ergonom"}, {"ergonomics", "ergonom"}, {"ermine", "ermin"}, {"erode", "erod"}, {"erogenous", "erogen"},
+		{"erosion", "eros"}, {"erotic", "erot"}, {"eroticism", "erotic"}, {"err", "err"}, {"errand", "errand"}, {"errant", "errant"},
+		{"erratic", "errat"}, {"erroneous", "erron"}, {"erroneously", "erron"}, {"error", "error"}, {"errored", "error"}, {"erroring", "error"},
+		{"errors", "error"}, {"errs", "err"}, {"erstwhile", "erstwhil"}, {"erudite", "erudit"}, {"erudition", "erudit"}, {"erupt", "erupt"},
+		{"eruption", "erupt"}, {"escalate", "escal"}, {"escalation", "escal"}, {"escalator", "escal"}, {"escapable", "escap"},
+		{"escapade", "escapad"}, {"escape", "escap"}, {"escaped", "escap"}, {"escapes", "escap"}, {"escaping", "escap"}, {"escapism", "escap"},
+		{"escapist", "escapist"}, {"escarpment", "escarp"}, {"eschew", "eschew"}, {"escort", "escort"}, {"esoteric", "esoter"},
+		{"especial", "especi"}, {"especially", "especi"}, {"espionage", "espionag"}, {"espouse", "espous"}, {"espresso", "espresso"},
+		{"essay", "essay"}, {"essayist", "essayist"}, {"essence", "essenc"}, {"essential", "essenti"}, {"essentially", "essenti"},
+		{"essentials", "essenti"}, {"establish", "establish"}, {"established", "establish"}, {"establishes", "establish"},
+		{"establishing", "establish"}, {"establishment", "establish"}, {"estate", "estat"}, {"esteem", "esteem"}, {"estimable", "estim"},
+		{"estimate", "estim"}, {"estimated", "estim"}, {"estimates", "estim"}, {"estimating", "estim"}, {"estimation", "estim"},
+		{"estimations", "estim"}, {"estimator", "estim"}, {"estrange", "estrang"}, {"estrangement", "estrang"}, {"estuary", "estuari"},
+		{"etcetera", "etcetera"}, {"etch", "etch"}, {"etching", "etch"}, {"eternal", "etern"}, {"eternity", "etern"}, {"ether", "ether"},
+		{"ethereal", "ether"}, {"ethers", "ether"}, {"ethic", "ethic"}, {"ethical", "ethic"}, {"ethics", "ethic"}, {"ethnic", "ethnic"},
+		{"ethnicity", "ethnic"}, {"ethos", "etho"}, {"etiquette", "etiquett"}, {"etymology", "etymolog"}, {"eucalyptus", "eucalyptus"},
+		{"euphemism", "euphem"}, {"euphemistic", "euphemist"}, {"euphoria", "euphoria"}, {"euphoric", "euphor"}, {"euthanasia", "euthanasia"},
+		{"evacuate", "evacu"}, {"evacuation", "evacu"}, {"evacuee", "evacue"}, {"evade", "evad"}, {"evaluate", "evalu"}, {"evaluated", "evalu"},
+		{"evaluates", "evalu"}, {"evaluating", "evalu"}, {"evaluation", "evalu"}, {"evaluations", "evalu"}, {"evaluator", "evalu"},
+		{"evaluators", "evalu"}, {"evangelical", "evangel"}, {"evangelism", "evangel"}, {"evangelist", "evangelist"}, {"evaporate", "evapor"},
+		{"evaporation", "evapor"}, {"evasion", "evas"}, {"evasive", "evas"}, {"eve", "eve"}, {"even", "even"}, {"evening", "even"},
+		{"evenly", "even"}, {"event", "event"}, {"eventful", "event"}, {"events", "event"}, {"eventual", "eventu"}, {"eventuality", "eventu"},
+		{"eventually", "eventu"}, {"ever", "ever"}, {"evergreen", "evergreen"}, {"everlasting", "everlast"}, {"evermore", "evermor"},
+		{"every", "everi"}, {"everybody", "everybodi"}, {"everyday", "everyday"}, {"everyone", "everyon"}, {"everyplace", "everyplac"},
+		{"everything", "everyth"}, {"everywhere", "everywher"}, {"evict", "evict"}, {"evictable", "evict"}, {"evicted", "evict"},
+		{"eviction", "evict"}, {"evictions", "evict"}, {"evidence", "evid"}, {"evident", "evid"}, {"evil", "evil"}, {"evince", "evinc"},
+		{"evocation", "evoc"}, {"evocative", "evoc"}, {"evoke", "evok"}, {"evolution", "evolut"}, {"evolutionary", "evolutionari"},
+		{"evolve", "evolv"}, {"evolved", "evolv"}, {"evolves", "evolv"}, {"evolving", "evolv"}, {"ewe", "ewe"}, {"exacerbate", "exacerb"},
+		{"exacerbated", "exacerb"}, {"exacerbating", "exacerb"}, {"exact", "exact"}, {"exacting", "exact"}, {"exactly", "exact"},
+		{"exaggerate", "exagger"}, {"exaggeration", "exagger"}, {"exalt", "exalt"}, {"exaltation", "exalt"}, {"exam", "exam"},
+		{"examination", "examin"}, {"examine", "examin"}, {"examined", "examin"}, {"examiner", "examin"}, {"examines", "examin"},
+		{"examining", "examin"}, {"exampl", "exampl"}, {"example", "exampl"}, {"examples", "exampl"}, {"exasperate", "exasper"},
+		{"exasperation", "exasper"}, {"excavate", "excav"}, {"excavation", "excav"}, {"excavator", "excav"}, {"exceed", "exceed"},
+		{"exceeded", "exceed"}, {"exceeding", "exceed"}, {"exceedingly", "exceed"}, {"exceeds", "exceed"}, {"excel", "excel"},
+		{"excellence", "excel"}, {"excellency", "excel"}, {"excellent", "excel"}, {"except", "except"}, {"excepted", "except"},
+		{"excepting", "except"}, {"exception", "except"}, {"exceptional", "except"}, {"exceptionally", "except"}, {"exceptions", "except"},
+		{"excerpt", "excerpt"}, {"excerpts", "excerpt"}, {"excess", "excess"}, {"excessive", "excess"}, {"excessively", "excess"},
+		{"exchange", "exchang"}, {"exchanged", "exchang"}, {"exchanges", "exchang"}, {"exchanging", "exchang"}, {"exchequer", "exchequ"},
+		{"excise", "excis"}, {"excitable", "excit"}, {"excite", "excit"}, {"excited", "excit"}, {"excitement", "excit"}, {"exciting", "excit"},
+		{"exclaim", "exclaim"}, {"exclamation", "exclam"}, {"exclude", "exclud"}, {"excluded", "exclud"}, {"excludes", "exclud"},
+		{"excluding", "exclud"}, {"exclusion", "exclus"}, {"exclusions", "exclus"}, {"exclusive", "exclus"}, {"exclusively", "exclus"},
+		{"exclusivity", "exclus"}, {"excommunicate", "excommun"}, {"excommunication", "excommun"}, {"excrement", "excrement"},
+		{"excrete", "excret"}, {"excretion", "excret"}, {"excruciating", "excruci"}, {"excursion", "excurs"}, {"excusable", "excus"},
+		{"excuse", "excus"}, {"excuses", "excus"}, {"executable", "execut"}, {"executables", "execut"}, {"execute", "execut"},
+		{"executed", "execut"}, {"executes", "execut"}, {"executing", "execut"}, {"execution", "execut"}, {"executioner", "execution"},
+		{"executions", "execut"}, {"executive", "execut"}, {"executor", "executor"}, {"executors", "executor"}, {"exemplary", "exemplari"},
+		{"exemplify", "exemplifi"}, {"exempt", "exempt"}, {"exemptable", "exempt"}, {"exempted", "exempt"}, {"exempting", "exempt"},
+		{"exemption", "exempt"}, {"exemptions", "exempt"}, {"exempts", "exempt"}, {"exercise", "exercis"}, {"exercised", "exercis"},
+		{"exercises", "exercis"}, {"exercising", "exercis"}, {"exert", "exert"}, {"exertion", "exert"}, {"exhale", "exhal"},
+		{"exhaust", "exhaust"}, {"exhausted", "exhaust"}, {"exhausting", "exhaust"}, {"exhaustion", "exhaust"}, {"exhaustive", "exhaust"},
+		{"exhibit", "exhibit"}, {"exhibited", "exhibit"}, {"exhibiting", "exhibit"}, {"exhibition", "exhibit"}, {"exhibitionism", "exhibition"},
+		{"exhibitionist", "exhibitionist"}, {"exhibitor", "exhibitor"}, {"exhibits", "exhibit"}, {"exhilarate", "exhilar"},
+		{"exhilaration", "exhilar"}, {"exhort", "exhort"}, {"exhortation", "exhort"}, {"exhume", "exhum"}, {"exigency", "exig"},
+		{"exile", "exil"}, {"exist", "exist"}, {"existance", "exist"}, {"existant", "exist"}, {"existed", "exist"}, {"existence", "exist"},
+		{"existent", "exist"}, {"existential", "existenti"}, {"existentialism", "existenti"}, {"existing", "exist"}, {"exists", "exist"},
+		{"exit", "exit"}, {"exited", "exit"}, {"exiting", "exit"}, {"exits", "exit"}, {"exodus", "exodus"}, {"exonerate", "exoner"},
+		{"exorbitant", "exorbit"}, {"exorcism", "exorc"}, {"exorcist", "exorcist"}, {"exorcize", "exorc"}, {"exotic", "exot"},
+		{"expand", "expand"}, {"expandable", "expand"}, {"expanded", "expand"}, {"expanding", "expand"}, {"expands", "expand"},
+		{"expanse", "expans"}, {"expansion", "expans"}, {"expansionism", "expansion"}, {"expansions", "expans"}, {"expansive", "expans"},
+		{"expatriate", "expatri"}, {"expect", "expect"}, {"expectancy", "expect"}, {"expectant", "expect"}, {"expectation", "expect"},
+		{"expectations", "expect"}, {"expected", "expect"}, {"expecting", "expect"}, {"expects", "expect"}, {"expedience", "expedi"},
+		{"expediency", "expedi"}, {"expedient", "expedi"}, {"expedite", "expedit"}, {"expedited", "expedit"}, {"expedition", "expedit"},
+		{"expeditionary", "expeditionari"}, {"expel", "expel"}, {"expend", "expend"}, {"expendable", "expend"}, {"expenditure", "expenditur"},
+		{"expense", "expens"}, {"expenses", "expens"}, {"expensive", "expens"}, {"experience", "experi"}, {"experiences", "experi"},
+		{"experiment", "experi"}, {"experimental", "experiment"}, {"experimentation", "experiment"}, {"experimenting", "experi"},
+		{"experiments", "experi"}, {"expert", "expert"}, {"expertise", "expertis"}, {"experts", "expert"}, {"expiate", "expiat"},
+		{"expiration", "expir"}, {"expirations", "expir"}, {"expire", "expir"}, {"expired", "expir"}, {"expires", "expir"},
+		{"expiring", "expir"}, {"expiry", "expiri"}, {"explain", "explain"}, {"explaination", "explain"}, {"explained", "explain"},
+		{"explainer", "explain"}, {"explaining", "explain"}, {"explains", "explain"}, {"explanation", "explan"}, {"explanations", "explan"},
+		{"explanatory", "explanatori"}, {"expletive", "explet"}, {"explicable", "explic"}, {"explicit", "explicit"}, {"explicitely", "explicit"},
+		{"explicitly", "explicit"}, {"explicity", "explic"}, {"explode", "explod"}, {"exploded", "explod"}, {"exploit", "exploit"},
+		{"exploitable", "exploit"}, {"exploitation", "exploit"}, {"exploited", "exploit"}, {"exploiting", "exploit"}, {"exploits", "exploit"},
+		{"exploration", "explor"}, {"exploratory", "exploratori"}, {"explore", "explor"}, {"explored", "explor"}, {"explorer", "explor"},
+		{"exploring", "explor"}, {"explosion", "explos"}, {"explosive", "explos"}, {"exponent", "expon"}, {"exponential", "exponenti"},
+		{"exponentially", "exponenti"}, {"exponentiation", "exponenti"}, {"exponents", "expon"}, {"export", "export"}, {"exportable", "export"},
+		{"exported", "export"}, {"exporter", "export"}, {"exporting", "export"}, {"exports", "export"}, {"expose", "expos"},
+		{"exposed", "expos"}, {"exposes", "expos"}, {"exposing", "expos"}, {"exposition", "exposit"}, {"exposure", "exposur"},
+		{"exposures", "exposur"}, {"expound", "expound"}, {"express", "express"}, {"expressed", "express"}, {"expresses", "express"},
+		{"expressing", "express"}, {"expression", "express"}, {"expressionism", "expression"}, {"expressions", "express"},
+		{"expressive", "express"}, {"expressively", "express"}, {"expressway", "expressway"}, {"expropriate", "expropri"},
+		{"expulsion", "expuls"}, {"expunge", "expung"}, {"expunged", "expung"}, {"exquisite", "exquisit"}, {"extant", "extant"},
+		{"extemporize", "extempor"}, {"extend", "extend"}, {"extendability", "extend"}, {"extendable", "extend"}, {"extended", "extend"},
+		{"extending", "extend"}, {"extends", "extend"}, {"extensibility", "extens"}, {"extensible", "extens"}, {"extension", "extens"},
+		{"extensioned", "extens"}, {"extensions", "extens"}, {"extensive", "extens"}, {"extensively", "extens"}, {"extent", "extent"},
+		{"extention", "extent"}, {"extentions", "extent"}, {"extents", "extent"}, {"extenuate", "extenu"}, {"exterior", "exterior"},
+		{"exterminate", "extermin"}, {"extermination", "extermin"}, {"extern", "extern"}, {"external", "extern"}, {"externalize", "extern"},
+		{"externalized", "extern"}, {"externally", "extern"}, {"externals", "extern"}, {"extinct", "extinct"}, {"extinction", "extinct"},
+		{"extinguish", "extinguish"}, {"extinguisher", "extinguish"}, {"extol", "extol"}, {"extort", "extort"}, {"extortion", "extort"},
+		{"extortionate", "extortion"}, {"extra", "extra"}, {"extract", "extract"}, {"extractable", "extract"}, {"extracted", "extract"},
+		{"extracting", "extract"}, {"extraction", "extract"}, {"extractor", "extractor"}, {"extracts", "extract"},
+		{"extracurricular", "extracurricular"}, {"extradite", "extradit"}, {"extradition", "extradit"}, {"extramarital", "extramarit"},
+		{"extraneous", "extran"}, {"extraordinary", "extraordinari"}, {"extrapolate", "extrapol"}, {"extrapolation", "extrapol"},
+		{"extras", "extra"}, {"extraterrestrial", "extraterrestri"}, {"extravagance", "extravag"}, {"extravagant", "extravag"},
+		{"extravaganza", "extravaganza"}, {"extreme", "extrem"}, {"extremely", "extrem"}, {"extremism", "extrem"}, {"extremist", "extremist"},
+		{"extremity", "extrem"}, {"extricate", "extric"}, {"extrovert", "extrovert"}, {"exuberance", "exuber"}, {"exuberant", "exuber"},
+		{"exude", "exud"}, {"exult", "exult"}, {"exultant", "exult"}, {"exultation", "exult"}, {"eye", "eye"}, {"eyeball", "eyebal"},
+		{"eyeballing", "eyebal"}, {"eyeballs", "eyebal"}, {"eyebrow", "eyebrow"}, {"eyeful", "eye"}, {"eyelash", "eyelash"},
+		{"eyelet", "eyelet"}, {"eyelid", "eyelid"}, {"eyepiece", "eyepiec"}, {"eyes", "eye"}, {"eyesight", "eyesight"}, {"eyesore", "eyesor"},
+		{"eyewitness", "eyewit"}, {"fable", "fabl"}, {"fabric", "fabric"}, {"fabricate", "fabric"}, {"fabricated", "fabric"},
+		{"fabrication", "fabric"}, {"fabrice", "fabric"}, {"fabulous", "fabul"}, {"facad", "facad"}, {"facade", "facad"}, {"facades", "facad"},
+		{"face", "face"}, {"faced", "face"}, {"faceless", "faceless"}, {"faces", "face"}, {"facet", "facet"}, {"facetious", "faceti"},
+		{"facial", "facial"}, {"facile", "facil"}, {"facilitate", "facilit"}, {"facilitates", "facilit"}, {"facilitator", "facilit"},
+		{"facilities", "facil"}, {"facility", "facil"}, {"facing", "face"}, {"facsimile", "facsimil"}, {"fact", "fact"}, {"faction", "faction"},
+		{"factional", "faction"}, {"factor", "factor"}, {"factorial", "factori"}, {"factors", "factor"}, {"factory", "factori"},
+		{"facts", "fact"}, {"factual", "factual"}, {"faculty", "faculti"}, {"fad", "fad"}, {"fade", "fade"}, {"faec", "faec"},
+		{"faeces", "faec"}, {"fag", "fag"}, {"fail", "fail"}, {"failed", "fail"}, {"failing", "fail"}, {"fails", "fail"}, {"failure", "failur"},
+		{"failures", "failur"}, {"faint", "faint"}, {"fair", "fair"}, {"fairground", "fairground"}, {"fairly", "fair"}, {"fairness", "fair"},
+		{"fairway", "fairway"}, {"fairy", "fairi"}, {"fairyland", "fairyland"}, {"faith", "faith"}, {"faithful", "faith"},
+		{"faithfully", "faith"}, {"faithless", "faithless"}, {"fake", "fake"}, {"faked", "fake"}, {"fakeing", "fake"}, {"faking", "fake"},
+		{"falcon", "falcon"}, {"fall", "fall"}, {"fallacious", "fallaci"}, {"fallacy", "fallaci"}, {"fallible", "fallibl"}, {"falling", "fall"},
+		{"fallout", "fallout"}, {"fallow", "fallow"}, {"falls", "fall"}, {"false", "fals"}, {"falsehood", "falsehood"}, {"falsely", "fals"},
+		{"falsetto", "falsetto"}, {"falsify", "falsifi"}, {"falsity", "falsiti"}, {"falter", "falter"}, {"fame", "fame"},
+		{"familiar", "familiar"}, {"familiarity", "familiar"}, {"familiarize", "familiar"}, {"families", "famili"}, {"family", "famili"},
+		{"famine", "famin"}, {"famish", "famish"}, {"famous", "famous"}, {"fan", "fan"}, {"fanatic", "fanat"}, {"fanatical", "fanat"},
+		{"fanaticism", "fanatic"}, {"fancier", "fancier"}, {"fanciful", "fanci"}, {"fancy", "fanci"}, {"fanfare", "fanfar"}, {"fang", "fang"},
+		{"fantasize", "fantas"}, {"fantastic", "fantast"}, {"fantasy", "fantasi"}, {"far", "far"}, {"faraway", "faraway"}, {"farce", "farc"},
+		{"farcical", "farcic"}, {"fare", "fare"}, {"farewell", "farewel"}, {"farm", "farm"}, {"farmer", "farmer"}, {"farmhouse", "farmhous"},
+		{"farmland", "farmland"}, {"farmyard", "farmyard"}, {"farther", "farther"}, {"farthest", "farthest"}, {"fascinate", "fascin"},
+		{"fascination", "fascin"}, {"fascism", "fascism"}, {"fascist", "fascist"}, {"fashion", "fashion"}, {"fashionable", "fashion"},
+		{"fashioned", "fashion"}, {"fast", "fast"}, {"fasten", "fasten"}, {"fastener", "fasten"}, {"fastidious", "fastidi"}, {"fastly", "fast"},
+		{"fat", "fat"}, {"fatal", "fatal"}, {"fatalism", "fatal"}, {"fatalistic", "fatalist"}, {"fatality", "fatal"}, {"fatally", "fatal"},
+		{"fate", "fate"}, {"fateful", "fate"}, {"father", "father"}, {"fatherhood", "fatherhood"}, {"fatherland", "fatherland"},
+		{"fatherly", "father"}, {"fathom", "fathom"}, {"fatigue", "fatigu"}, {"fatten", "fatten"}, {"fatty", "fatti"}, {"fatuous", "fatuous"},
+		{"faucet", "faucet"}, {"fault", "fault"}, {"faulted", "fault"}, {"faulting", "fault"}, {"faultless", "faultless"}, {"faults", "fault"},
+		{"faulty", "faulti"}, {"fauna", "fauna"}, {"favor", "favor"}, {"favorable", "favor"}, {"favorably", "favor"}, {"favored", "favor"},
+		{"favoring", "favor"}, {"favorite", "favorit"}, {"favoritism", "favorit"}, {"favors", "favor"}, {"favour", "favour"},
+		{"favourable", "favour"}, {"favoured", "favour"}, {"favouring", "favour"}, {"favourite", "favourit"}, {"favouritism", "favourit"},
+		{"fawn", "fawn"}, {"fax", "fax"}, {"fear", "fear"}, {"fearful", "fear"}, {"fearing", "fear"}, {"fearless", "fearless"},
+		{"fearsome", "fearsom"}, {"feasibility", "feasibl"}, {"feasible", "feasibl"}, {"feast", "feast"}, {"feat", "feat"},
+		{"feather", "feather"}, {"feathery", "featheri"}, {"feature", "featur"}, {"featured", "featur"}, {"features", "featur"},
+		{"featuring", "featur"}, {"february", "februari"}, {"fecund", "fecund"}, {"federal", "feder"}, {"federalism", "feder"},
+		{"federalist", "federalist"}, {"federate", "feder"}, {"federated", "feder"}, {"federation", "feder"}, {"federations", "feder"},
+		{"fee", "fee"}, {"feeble", "feebl"}, {"feed", "feed"}, {"feedback", "feedback"}, {"feeder", "feeder"}, {"feeding", "feed"},
+		{"feeds", "feed"}, {"feel", "feel"}, {"feeler", "feeler"}, {"feeling", "feel"}, {"feels", "feel"}, {"fees", "fee"}, {"feign", "feign"},
+		{"feint", "feint"}, {"feisty", "feisti"}, {"felicitous", "felicit"}, {"felicity", "felic"}, {"feline", "felin"}, {"fell", "fell"},
+		{"fellow", "fellow"}, {"fellowship", "fellowship"}, {"felon", "felon"}, {"felony", "feloni"}, {"felt", "felt"}, {"female", "femal"},
+		{"feminine", "feminin"}, {"femininity", "feminin"}, {"feminism", "femin"}, {"feminist", "feminist"}, {"femur", "femur"}, {"fen", "fen"},
+		{"fence", "fenc"}, {"fenced", "fenc"}, {"fences", "fenc"}, {"fencing", "fenc"}, {"fend", "fend"}, {"fender", "fender"},
+		{"fennel", "fennel"}, {"ferment", "ferment"}, {"fermentation", "ferment"}, {"fern", "fern"}, {"ferocious", "feroci"},
+		{"ferocity", "feroc"}, {"ferret", "ferret"}, {"ferromagnet", "ferromagnet"}, {"ferry", "ferri"}, {"fertile", "fertil"},
+		{"fertility", "fertil"}, {"fertilization", "fertil"}, {"fertilize", "fertil"}, {"fertilizer", "fertil"}, {"fervent", "fervent"},
+		{"fervor", "fervor"}, {"fervour", "fervour"}, {"fester", "fester"}, {"festival", "festiv"}, {"festive", "festiv"},
+		{"festivity", "festiv"}, {"festoon", "festoon"}, {"fetal", "fetal"}, {"fetch", "fetch"}, {"fetched", "fetch"}, {"fetches", "fetch"},
+		{"fetching", "fetch"}, {"fete", "fete"}, {"fetid", "fetid"}, {"fetish", "fetish"}, {"fetter", "fetter"}, {"fetus", "fetus"},
+		{"feud", "feud"}, {"feudal", "feudal"}, {"feudalism", "feudal"}, {"fever", "fever"}, {"feverish", "feverish"}, {"few", "few"},
+		{"fiance", "fianc"}, {"fiancee", "fiance"}, {"fiasco", "fiasco"}, {"fiat", "fiat"}, {"fib", "fib"}, {"fiber", "fiber"},
+		{"fiberglass", "fiberglass"}, {"fibre", "fibr"}, {"fibreglass", "fibreglass"}, {"fibrous", "fibrous"}, {"fickle", "fickl"},
+		{"fiction", "fiction"}, {"fictional", "fiction"}, {"fictitious", "fictiti"}, {"fiddle", "fiddl"}, {"fiddler", "fiddler"},
+		{"fidelity", "fidel"}, {"fidget", "fidget"}, {"fidgety", "fidgeti"}, {"field", "field"}, {"fields", "field"}, {"fieldwork", "fieldwork"},
+		{"fiend", "fiend"}, {"fiendish", "fiendish"}, {"fierce", "fierc"}, {"fiery", "fieri"}, {"fiesta", "fiesta"}, {"fife", "fife"},
+		{"fifteen", "fifteen"}, {"fifth", "fifth"}, {"fifty", "fifti"}, {"fig", "fig"}, {"fight", "fight"}, {"fighter", "fighter"},
+		{"fighting", "fight"}, {"figment", "figment"}, {"figurative", "figur"}, {"figure", "figur"}, {"figurehead", "figurehead"},
+		{"figures", "figur"}, {"figuring", "figur"}, {"filament", "filament"}, {"filch", "filch"}, {"file", "file"}, {"filed", "file"},
+		{"files", "file"}, {"filial", "filial"}, {"filibuster", "filibust"}, {"filigree", "filigre"}, {"filing", "file"}, {"fill", "fill"},
+		{"filled", "fill"}, {"fillet", "fillet"}, {"filling", "fill"}, {"fills", "fill"}, {"filly", "filli"}, {"film", "film"},
+		{"films", "film"}, {"filter", "filter"}, {"filtered", "filter"}, {"filtering", "filter"}, {"filters", "filter"}, {"filth", "filth"},
+		{"filthy", "filthi"}, {"filtrate", "filtrat"}, {"filtration", "filtrat"}, {"fin", "fin"}, {"final", "final"}, {"finale", "final"},
+		{"finaled", "final"}, {"finalist", "finalist"}, {"finality", "final"}, {"finalization", "final"}, {"finalize", "final"},
+		{"finalized", "final"}, {"finalizer", "final"}, {"finalizers", "final"}, {"finalizes", "final"}, {"finalizing", "final"},
+		{"finally", "final"}, {"finance", "financ"}, {"financial", "financi"}, {"financier", "financi"}, {"finch", "finch"}, {"find", "find"},
+		{"finder", "finder"}, {"finders", "finder"}, {"finding", "find"}, {"findings", "find"}, {"finds", "find"}, {"fine", "fine"},
+		{"finely", "fine"}, {"finery", "fineri"}, {"finesse", "finess"}, {"finger", "finger"}, {"fingernail", "fingernail"},
+		{"fingerprint", "fingerprint"}, {"fingerprints", "fingerprint"}, {"fingers", "finger"}, {"fingertip", "fingertip"},
+		{"finicky", "finicki"}, {"finish", "finish"}, {"finished", "finish"}, {"finishes", "finish"}, {"finishing", "finish"},
+		{"finit", "finit"}, {"finite", "finit"}, {"fir", "fir"}, {"fire", "fire"}, {"firearm", "firearm"}, {"firebrand", "firebrand"},
+		{"fired", "fire"}, {"firefighter", "firefight"}, {"firefly", "firefli"}, {"fireman", "fireman"}, {"fireplace", "fireplac"},
+		{"fireproof", "fireproof"}, {"fires", "fire"}, {"fireside", "firesid"}, {"firewood", "firewood"}, {"firework", "firework"},
+		{"firing", "fire"}, {"firings", "fire"}, {"firm", "firm"}, {"firmament", "firmament"}, {"firmly", "firm"}, {"first", "first"},
+		{"firsthand", "firsthand"}, {"firstly", "first"}, {"fiscal", "fiscal"}, {"fish", "fish"}, {"fisherman", "fisherman"},
+		{"fishery", "fisheri"}, {"fishmonger", "fishmong"}, {"fishy", "fishi"}, {"fission", "fission"}, {"fissure", "fissur"}, {"fist", "fist"},
+		{"fit", "fit"}, {"fitful", "fit"}, {"fitfully", "fit"}, {"fitness", "fit"}, {"fits", "fit"}, {"fitter", "fitter"}, {"fitting", "fit"},
+		{"five", "five"}, {"fix", "fix"}, {"fixate", "fixat"}, {"fixation", "fixat"}, {"fixative", "fixat"}, {"fixe", "fix"}, {"fixed", "fix"},
+		{"fixes", "fix"}, {"fixing", "fix"}, {"fixture", "fixtur"}, {"fixtures", "fixtur"}, {"fizz", "fizz"}, {"fizzle", "fizzl"},
+		{"fizzy", "fizzi"}, {"flabbergast", "flabbergast"}, {"flabby", "flabbi"}, {"flaccid", "flaccid"}, {"flag", "flag"}, {"flagged", "flag"},
+		{"flagging", "flag"}, {"flagrant", "flagrant"}, {"flags", "flag"}, {"flagship", "flagship"}, {"flagstone", "flagston"},
+		{"flair", "flair"}, {"flak", "flak"}, {"flake", "flake"}, {"flakes", "flake"}, {"flakiness", "flaki"}, {"flaky", "flaki"},
+		{"flamboyance", "flamboy"}, {"flamboyant", "flamboy"}, {"flame", "flame"}, {"flaming", "flame"}, {"flamingo", "flamingo"},
+		{"flammable", "flammabl"}, {"flan", "flan"}, {"flank", "flank"}, {"flannel", "flannel"}, {"flap", "flap"}, {"flare", "flare"},
+		{"flash", "flash"}, {"flashback", "flashback"}, {"flashed", "flash"}, {"flashes", "flash"}, {"flashing", "flash"},
+		{"flashlight", "flashlight"}, {"flashy", "flashi"}, {"flask", "flask"}, {"flat", "flat"}, {"flatten", "flatten"},
+		{"flattened", "flatten"}, {"flattening", "flatten"}, {"flattens", "flatten"}, {"flatter", "flatter"}, {"flattery", "flatteri"},
+		{"flatulence", "flatul"}, {"flaunt", "flaunt"}, {"flautist", "flautist"}, {"flavor", "flavor"}, {"flavors", "flavor"},
+		{"flavour", "flavour"}, {"flavours", "flavour"}, {"flaw", "flaw"}, {"flawed", "flaw"}, {"flawless", "flawless"}, {"flaws", "flaw"},
+		{"flax", "flax"}, {"flay", "flay"}, {"flea", "flea"}, {"fleck", "fleck"}, {"fledge", "fledg"}, {"fledged", "fledg"},
+		{"fledgling", "fledgl"}, {"flee", "flee"}, {"fleece", "fleec"}, {"fleet", "fleet"}, {"fleeting", "fleet"}, {"fleets", "fleet"},
+		{"flesh", "flesh"}, {"fleshy", "fleshi"}, {"flex", "flex"}, {"flexibility", "flexibl"}, {"flexible", "flexibl"}, {"flick", "flick"},
+		{"flicker", "flicker"}, {"flier", "flier"}, {"flight", "flight"}, {"flimsy", "flimsi"}, {"flinch", "flinch"}, {"fling", "fling"},
+		{"flint", "flint"}, {"flip", "flip"}, {"flippant", "flippant"}, {"flipper", "flipper"}, {"flips", "flip"}, {"flirt", "flirt"},
+		{"flirtation", "flirtat"}, {"flirtatious", "flirtati"}, {"flit", "flit"}, {"float", "float"}, {"floating", "float"}, {"floats", "float"},
+		{"flock", "flock"}, {"flog", "flog"}, {"flood", "flood"}, {"flooded", "flood"}, {"flooding", "flood"}, {"floodlight", "floodlight"},
+		{"floor", "floor"}, {"floorboard", "floorboard"}, {"flop", "flop"}, {"floppies", "floppi"}, {"floppy", "floppi"}, {"flora", "flora"},
+		{"floral", "floral"}, {"florid", "florid"}, {"florist", "florist"}, {"floss", "floss"}, {"flotation", "flotat"},
+		{"flotilla", "flotilla"}, {"flounce", "flounc"}, {"flounder", "flounder"}, {"flour", "flour"}, {"flourish", "flourish"},
+		{"flout", "flout"}, {"flow", "flow"}, {"flowed", "flow"}, {"flower", "flower"}, {"flowery", "floweri"}, {"flowing", "flow"},
+		{"flows", "flow"}, {"flu", "flu"}, {"fluctuate", "fluctuat"}, {"fluctuation", "fluctuat"}, {"flue", "flue"}, {"fluency", "fluenci"},
+		{"fluent", "fluent"}, {"fluff", "fluff"}, {"fluffy", "fluffi"}, {"fluid", "fluid"}, {"fluke", "fluke"}, {"fluorescence", "fluoresc"},
+		{"fluorescent", "fluoresc"}, {"fluoride", "fluorid"}, {"flurry", "flurri"}, {"flush", "flush"}, {"flushed", "flush"},
+		{"flushes", "flush"}, {"flushing", "flush"}, {"fluster", "fluster"}, {"flute", "flute"}, {"flutter", "flutter"}, {"flux", "flux"},
+		{"fly", "fli"}, {"flyer", "flyer"}, {"flyover", "flyover"}, {"foal", "foal"}, {"foam", "foam"}, {"fob", "fob"}, {"focal", "focal"},
+		{"focus", "focus"}, {"focused", "focus"}, {"focuses", "focus"}, {"focusing", "focus"}, {"fodder", "fodder"}, {"foe", "foe"},
+		{"foetal", "foetal"}, {"foetus", "foetus"}, {"fog", "fog"}, {"foggy", "foggi"}, {"foible", "foibl"}, {"foil", "foil"},
+		{"foist", "foist"}, {"fold", "fold"}, {"folded", "fold"}, {"folder", "folder"}, {"folders", "folder"}, {"folding", "fold"},
+		{"folds", "fold"}, {"foliage", "foliag"}, {"folk", "folk"}, {"folklore", "folklor"}, {"folks", "folk"}, {"follicle", "follicl"},
+		{"follow", "follow"}, {"followed", "follow"}, {"follower", "follow"}, {"following", "follow"}, {"follows", "follow"}, {"folly", "folli"},
+		{"foment", "foment"}, {"fond", "fond"}, {"fondle", "fondl"}, {"fondness", "fond"}, {"font", "font"}, {"fonts", "font"}, {"food", "food"},
+		{"foodstuff", "foodstuff"}, {"fool", "fool"}, {"fooled", "fool"}, {"foolhardy", "foolhardi"}, {"foolish", "foolish"},
+		{"foolishly", "foolish"}, {"foolproof", "foolproof"}, {"foot", "foot"}, {"footage", "footag"}, {"football", "footbal"},
+		{"footballer", "footbal"}, {"foothill", "foothil"}, {"foothold", "foothold"}, {"footing", "foot"}, {"footlights", "footlight"},
+		{"footnote", "footnot"}, {"footnotes", "footnot"}, {"footpath", "footpath"}, {"footprint", "footprint"}, {"footprints", "footprint"},
+		{"footstep", "footstep"}, {"footwear", "footwear"}, {"for", "for"}, {"forage", "forag"}, {"foray", "foray"}, {"forbear", "forbear"},
+		{"forbearance", "forbear"}, {"forbid", "forbid"}, {"forbidding", "forbid"}, {"forbids", "forbid"}, {"force", "forc"}, {"forced", "forc"},
+		{"forceful", "forc"}, {"forcefully", "forc"}, {"forceps", "forcep"}, {"forces", "forc"}, {"forcible", "forcibl"},
+		{"forcibly", "forcibl"}, {"forcing", "forc"}, {"ford", "ford"}, {"fore", "fore"}, {"forearm", "forearm"}, {"forebear", "forebear"},
+		{"foreboding", "forebod"}, {"forecast", "forecast"}, {"forecasted", "forecast"}, {"forecaster", "forecast"}, {"forecourt", "forecourt"},
+		{"forefather", "forefath"}, {"forefinger", "forefing"}, {"forefront", "forefront"}, {"forego", "forego"}, {"foregone", "foregon"},
+		{"foreground", "foreground"}, {"forehand", "forehand"}, {"forehead", "forehead"}, {"foreign", "foreign"}, {"foreigner", "foreign"},
+		{"foreman", "foreman"}, {"foremost", "foremost"}, {"forensic", "forens"}, {"forensics", "forens"}, {"forerunner", "forerunn"},
+		{"foresee", "forese"}, {"foreseeable", "forese"}, {"foreshadow", "foreshadow"}, {"foresight", "foresight"}, {"foreskin", "foreskin"},
+		{"forest", "forest"}, {"forestall", "forestal"}, {"forestry", "forestri"}, {"forests", "forest"}, {"foretaste", "foretast"},
+		{"foretell", "foretel"}, {"forethought", "forethought"}, {"forever", "forev"}, {"forewarn", "forewarn"}, {"foreword", "foreword"},
+		{"forfeit", "forfeit"}, {"forge", "forg"}, {"forgery", "forgeri"}, {"forget", "forget"}, {"forgetful", "forget"},
+		{"forgetting", "forget"}, {"forgive", "forgiv"}, {"forgiveness", "forgiv"}, {"forgo", "forgo"}, {"fork", "fork"}, {"forked", "fork"},
+		{"forking", "fork"}, {"forks", "fork"}, {"forlorn", "forlorn"}, {"form", "form"}, {"formal", "formal"}, {"formality", "formal"},
+		{"formalize", "formal"}, {"formally", "formal"}, {"format", "format"}, {"formating", "format"}, {"formation", "format"},
+		{"formative", "format"}, {"formats", "format"}, {"formatted", "format"}, {"formatting", "format"}, {"formed", "form"},
+		{"former", "former"}, {"formerly", "former"}, {"formidable", "formid"}, {"forming", "form"}, {"forms", "form"}, {"formula", "formula"},
+		{"formulaic", "formula"}, {"formulas", "formula"}, {"formulate", "formul"}, {"formulating", "formul"}, {"formulation", "formul"},
+		{"fornicate", "fornic"}, {"fornication", "fornic"}, {"forsake", "forsak"}, {"fort", "fort"}, {"forte", "fort"}, {"forth", "forth"},
+		{"forthcoming", "forthcom"}, {"forthright", "forthright"}, {"forthwith", "forthwith"}, {"fortieth", "fortieth"},
+		{"fortification", "fortif"}, {"fortified", "fortifi"}, {"fortify", "fortifi"}, {"fortitude", "fortitud"}, {"fortnight", "fortnight"},
+		{"fortnightly", "fortnight"}, {"fortress", "fortress"}, {"fortuitous", "fortuit"}, {"fortunate", "fortun"}, {"fortunately", "fortun"},
+		{"fortune", "fortun"}, {"forty", "forti"}, {"forum", "forum"}, {"forums", "forum"}, {"forward", "forward"}, {"forwarded", "forward"},
+		{"forwarding", "forward"}, {"forwardings", "forward"}, {"forwards", "forward"}, {"fossil", "fossil"}, {"fossilize", "fossil"},
+		{"foster", "foster"}, {"foul", "foul"}, {"found", "found"}, {"foundation", "foundat"}, {"founder", "founder"}, {"foundry", "foundri"},
+		{"fount", "fount"}, {"fountain", "fountain"}, {"four", "four"}, {"fourteen", "fourteen"}, {"fourth", "fourth"}, {"fowl", "fowl"},
+		{"fox", "fox"}, {"foyer", "foyer"}, {"fraction", "fraction"}, {"fractional", "fraction"}, {"fractions", "fraction"},
+		{"fractious", "fractious"}, {"fracture", "fractur"}, {"fragile", "fragil"}, {"fragility", "fragil"}, {"fragment", "fragment"},
+		{"fragmentary", "fragmentari"}, {"fragmentation", "fragment"}, {"fragmented", "fragment"}, {"fragmenting", "fragment"},
+		{"fragments", "fragment"}, {"fragrance", "fragranc"}, {"fragrant", "fragrant"}, {"frail", "frail"}, {"frailty", "frailti"},
+		{"frame", "frame"}, {"framed", "frame"}, {"frames", "frame"}, {"framework", "framework"}, {"frameworks", "framework"},
+		{"framing", "frame"}, {"franchise", "franchis"}, {"frank", "frank"}, {"frantic", "frantic"}, {"fraternal", "fratern"},
+		{"fraternity", "fratern"}, {"fraternize", "fratern"}, {"fraud", "fraud"}, {"fraudulent", "fraudul"}, {"fraught", "fraught"},
+		{"fray", "fray"}, {"freak", "freak"}, {"freakish", "freakish"}, {"freckle", "freckl"}, {"free", "free"}, {"freedom", "freedom"},
+		{"freedoms", "freedom"}, {"freehold", "freehold"}, {"freeing", "free"}, {"freelance", "freelanc"}, {"frees", "free"},
+		{"freeway", "freeway"}, {"freeze", "freez"}, {"freezer", "freezer"}, {"freezes", "freez"}, {"freezing", "freez"}, {"freight", "freight"},
+		{"freighter", "freighter"}, {"frenetic", "frenet"}, {"frenzy", "frenzi"}, {"frequencies", "frequenc"}, {"frequency", "frequenc"},
+		{"frequent", "frequent"}, {"frequently", "frequent"}, {"fresco", "fresco"}, {"fresh", "fresh"}, {"freshen", "freshen"},
+		{"freshening", "freshen"}, {"freshly", "fresh"}, {"freshman", "freshman"}, {"freshness", "fresh"}, {"freshwater", "freshwat"},
+		{"fret", "fret"}, {"fretful", "fret"}, {"fri", "fri"}, {"friar", "friar"}, {"friction", "friction"}, {"friday", "friday"},
+		{"fridge", "fridg"}, {"friedly", "fri"}, {"friend", "friend"}, {"friendly", "friend"}, {"friends", "friend"},
+		{"friendship", "friendship"}, {"frieze", "friez"}, {"frigate", "frigat"}, {"fright", "fright"}, {"frighten", "frighten"},
+		{"frightful", "fright"}, {"frigid", "frigid"}, {"frill", "frill"}, {"frills", "frill"}, {"fringe", "fring"}, {"frisk", "frisk"},
+		{"frisky", "friski"}, {"fritter", "fritter"}, {"frivolity", "frivol"}, {"frivolous", "frivol"}, {"frizzy", "frizzi"}, {"frock", "frock"},
+		{"frog", "frog"}, {"frolic", "frolic"}, {"from", "from"}, {"frond", "frond"}, {"front", "front"}, {"frontage", "frontag"},
+		{"frontal", "frontal"}, {"frontier", "frontier"}, {"frost", "frost"}, {"frostbite", "frostbit"}, {"frosty", "frosti"},
+		{"froth", "froth"}, {"frothy", "frothi"}, {"frown", "frown"}, {"frowned", "frown"}, {"frugal", "frugal"}, {"fruit", "fruit"},
+		{"fruitful", "fruit"}, {"fruition", "fruition"}, {"fruitless", "fruitless"}, {"fruity", "fruiti"}, {"frustrate", "frustrat"},
+		{"frustration", "frustrat"}, {"fry", "fri"}, {"fuddle", "fuddl"}, {"fudge", "fudg"}, {"fuel", "fuel"}, {"fugitive", "fugit"},
+		{"fulcrum", "fulcrum"}, {"fulfil", "fulfil"}, {"fulfill", "fulfil"}, {"fulfilled", "fulfil"}, {"fulfilling", "fulfil"},
+		{"fulfillment", "fulfil"}, {"fulfills", "fulfil"}, {"fulfilment", "fulfil"}, {"fulfils", "fulfil"}, {"full", "full"},
+		{"fulsome", "fulsom"}, {"fumble", "fumbl"}, {"fume", "fume"}, {"fumigate", "fumig"}, {"fun", "fun"}, {"function", "function"},
+		{"functional", "function"}, {"functionalities", "function"}, {"functionality", "function"}, {"functionally", "function"},
+		{"functionary", "functionari"}, {"functioning", "function"}, {"functions", "function"}, {"fund", "fund"}, {"fundamental", "fundament"},
+		{"fundamentalism", "fundament"}, {"fundamentalist", "fundamentalist"}, {"fundamentally", "fundament"}, {"fundamentals", "fundament"},
+		{"funds", "fund"}, {"funeral", "funer"}, {"funereal", "funer"}, {"fungal", "fungal"}, {"fungus", "fungus"}, {"funk", "funk"},
+		{"funky", "funki"}, {"funnel", "funnel"}, {"funny", "funni"}, {"fur", "fur"}, {"furious", "furious"}, {"furl", "furl"},
+		{"furlong", "furlong"}, {"furnace", "furnac"}, {"furnish", "furnish"}, {"furniture", "furnitur"}, {"furor", "furor"},
+		{"furore", "furor"}, {"furrow", "furrow"}, {"furry", "furri"}, {"further", "further"}, {"furthermore", "furthermor"},
+		{"furthest", "furthest"}, {"furtive", "furtiv"}, {"fury", "furi"}, {"fuse", "fuse"}, {"fused", "fuse"}, {"fuselage", "fuselag"},
+		{"fusing", "fuse"}, {"fusion", "fusion"}, {"fuss", "fuss"}, {"fussy", "fussi"}, {"futile", "futil"}, {"futility", "futil"},
+		{"future", "futur"}, {"futuristic", "futurist"}, {"fuzz", "fuzz"}, {"fuzzy", "fuzzi"}, {"gab", "gab"}, {"gabble", "gabbl"},
+		{"gable", "gabl"}, {"gadget", "gadget"}, {"gaffe", "gaff"}, {"gag", "gag"}, {"gaga", "gaga"}, {"gage", "gage"}, {"gaggle", "gaggl"},
+		{"gaiety", "gaieti"}, {"gain", "gain"}, {"gained", "gain"}, {"gainful", "gain"}, {"gaining", "gain"}, {"gains", "gain"},
+		{"gainsay", "gainsay"}, {"gait", "gait"}, {"gala", "gala"}, {"galactic", "galact"}, {"galaxy", "galaxi"}, {"gale", "gale"},
+		{"gall", "gall"}, {"gallant", "gallant"}, {"gallantry", "gallantri"}, {"galleon", "galleon"}, {"gallery", "galleri"},
+		{"galley", "galley"}, {"gallon", "gallon"}, {"gallop", "gallop"}, {"gallows", "gallow"}, {"gallstone", "gallston"}, {"galore", "galor"},
+		{"galvanize", "galvan"}, {"gambit", "gambit"}, {"gamble", "gambl"}, {"gambler", "gambler"}, {"game", "game"}, {"gamekeeper", "gamekeep"},
+		{"games", "game"}, {"gaming", "game"}, {"gamut", "gamut"}, {"gander", "gander"}, {"gang", "gang"}, {"gangland", "gangland"},
+		{"gangly", "gang"}, {"gangplank", "gangplank"}, {"gangrene", "gangren"}, {"gangster", "gangster"}, {"gangway", "gangway"},
+		{"gaol", "gaol"}, {"gap", "gap"}, {"gape", "gape"}, {"gaps", "gap"}, {"garage", "garag"}, {"garb", "garb"}, {"garbage", "garbag"},
+		{"garble", "garbl"}, {"garbled", "garbl"}, {"garden", "garden"}, {"gardener", "garden"}, {"gargantuan", "gargantuan"},
+		{"gargle", "gargl"}, {"gargoyle", "gargoyl"}, {"garish", "garish"}, {"garland", "garland"}, {"garlic", "garlic"}, {"garment", "garment"},
+		{"garner", "garner"}, {"garnet", "garnet"}, {"garnish", "garnish"}, {"garret", "garret"}, {"garrison", "garrison"},
+		{"garrulous", "garrul"}, {"garter", "garter"}, {"gas", "gas"}, {"gaseous", "gaseous"}, {"gash", "gash"}, {"gasket", "gasket"},
+		{"gasoline", "gasolin"}, {"gasp", "gasp"}, {"gastric", "gastric"}, {"gastronomic", "gastronom"}, {"gastronomy", "gastronomi"},
+		{"gate", "gate"}, {"gatecrash", "gatecrash"}, {"gated", "gate"}, {"gates", "gate"}, {"gateway", "gateway"}, {"gatewayed", "gateway"},
+		{"gateways", "gateway"}, {"gather", "gather"}, {"gathered", "gather"}, {"gathering", "gather"}, {"gathers", "gather"},
+		{"gauche", "gauch"}, {"gaudy", "gaudi"}, {"gauge", "gaug"}, {"gaunt", "gaunt"}, {"gauntlet", "gauntlet"}, {"gauze", "gauz"},
+		{"gavel", "gavel"}, {"gawky", "gawki"}, {"gay", "gay"}, {"gaze", "gaze"}, {"gazelle", "gazell"}, {"gazette", "gazett"}, {"gear", "gear"},
+		{"gearbox", "gearbox"}, {"geared", "gear"}, {"gel", "gel"}, {"gelatin", "gelatin"}, {"gelatine", "gelatin"}, {"gem", "gem"},
+		{"gender", "gender"}, {"gene", "gene"}, {"genealogical", "genealog"}, {"genealogy", "genealog"}, {"general", "general"},
+		{"generalization", "general"}, {"generalize", "general"}, {"generalized", "general"}, {"generalizes", "general"},
+		{"generalizing", "general"}, {"generally", "general"}, {"generate", "generat"}, {"generated", "generat"}, {"generates", "generat"},
+		{"generating", "generat"}, {"generation", "generat"}, {"generational", "generat"}, {"generations", "generat"}, {"generator", "generat"},
+		{"generators", "generat"}, {"generic", "generic"}, {"generically", "generic"}, {"generics", "generic"}, {"generosity", "generos"},
+		{"generous", "generous"}, {"genesis", "genesi"}, {"genetic", "genet"}, {"genetics", "genet"}, {"genial", "genial"}, {"genie", "geni"},
+		{"genital", "genit"}, {"genitals", "genit"}, {"genius", "genius"}, {"genocide", "genocid"}, {"genre", "genr"}, {"gent", "gent"},
+		{"genteel", "genteel"}, {"gentes", "gent"}, {"gentile", "gentil"}, {"gentility", "gentil"}, {"gentle", "gentl"},
+		{"gentleman", "gentleman"}, {"gently", "gentl"}, {"gentry", "gentri"}, {"genuine", "genuin"}, {"genus", "genus"},
+		{"geographer", "geograph"}, {"geographic", "geograph"}, {"geographical", "geograph"}, {"geography", "geographi"},
+		{"geological", "geolog"}, {"geologist", "geologist"}, {"geology", "geolog"}, {"geometric", "geometr"}, {"geometrical", "geometr"},
+		{"geometry", "geometri"}, {"geriatric", "geriatr"}, {"germ", "germ"}, {"german", "german"}, {"germane", "german"},
+		{"germinate", "germin"}, {"germination", "germin"}, {"gerund", "gerund"}, {"gestation", "gestat"}, {"gesticulate", "gesticul"},
+		{"gesture", "gestur"}, {"get", "get"}, {"getaway", "getaway"}, {"gets", "get"}, {"getting", "get"}, {"geyser", "geyser"},
+		{"ghastly", "ghast"}, {"gherkin", "gherkin"}, {"ghetto", "ghetto"}, {"ghost", "ghost"}, {"ghostly", "ghost"}, {"ghoul", "ghoul"},
+		{"ghoulish", "ghoulish"}, {"giant", "giant"}, {"gibber", "gibber"}, {"gibberish", "gibberish"}, {"gibe", "gibe"}, {"giddy", "giddi"},
+		{"gift", "gift"}, {"gig", "gig"}, {"gigantic", "gigant"}, {"giggle", "giggl"}, {"gild", "gild"}, {"gill", "gill"}, {"gilles", "gill"},
+		{"gilt", "gilt"}, {"gimmick", "gimmick"}, {"gin", "gin"}, {"ginger", "ginger"}, {"gingerbread", "gingerbread"}, {"gingerly", "ginger"},
+		{"giraffe", "giraff"}, {"gird", "gird"}, {"girder", "girder"}, {"girdle", "girdl"}, {"girl", "girl"}, {"girlfriend", "girlfriend"},
+		{"girlhood", "girlhood"}, {"girlish", "girlish"}, {"girth", "girth"}, {"gist", "gist"}, {"give", "give"}, {"given", "given"},
+		{"gives", "give"}, {"giving", "give"}, {"glacial", "glacial"}, {"glacier", "glacier"}, {"glad", "glad"}, {"gladden", "gladden"},
+		{"glade", "glade"}, {"gladiator", "gladiat"}, {"glamor", "glamor"}, {"glamorous", "glamor"}, {"glamour", "glamour"}, {"glance", "glanc"},
+		{"glancing", "glanc"}, {"gland", "gland"}, {"glandular", "glandular"}, {"glare", "glare"}, {"glass", "glass"}, {"glasses", "glass"},
+		{"glassy", "glassi"}, {"glaze", "glaze"}, {"glazier", "glazier"}, {"gleam", "gleam"}, {"glean", "glean"}, {"gleaned", "glean"},
+		{"glee", "glee"}, {"gleeful", "gleeful"}, {"glen", "glen"}, {"glib", "glib"}, {"glide", "glide"}, {"glider", "glider"},
+		{"glimmer", "glimmer"}, {"glimpse", "glimps"}, {"glint", "glint"}, {"glisten", "glisten"}, {"glitter", "glitter"}, {"gloat", "gloat"},
+		{"global", "global"}, {"globalization", "global"}, {"globalize", "global"}, {"globally", "global"}, {"globals", "global"},
+		{"globe", "globe"}, {"globular", "globular"}, {"gloom", "gloom"}, {"gloomy", "gloomi"}, {"glorify", "glorifi"}, {"glorious", "glorious"},
+		{"glory", "glori"}, {"gloss", "gloss"}, {"glossaries", "glossari"}, {"glossary", "glossari"}, {"glossy", "glossi"}, {"glove", "glove"},
+		{"glow", "glow"}, {"glower", "glower"}, {"glucose", "glucos"}, {"glue", "glue"}, {"glum", "glum"}, {"glut", "glut"},
+		{"glutton", "glutton"}, {"gluttony", "gluttoni"}, {"glycerine", "glycerin"}, {"gnarled", "gnarl"}, {"gnash", "gnash"}, {"gnat", "gnat"},
+		{"gnaw", "gnaw"}, {"gnome", "gnome"}, {"gnu", "gnu"}, {"go", "go"}, {"goad", "goad"}, {"goal", "goal"}, {"goalkeeper", "goalkeep"},
+		{"goalpost", "goalpost"}, {"goals", "goal"}, {"goat", "goat"}, {"gobble", "gobbl"}, {"goblet", "goblet"}, {"goblin", "goblin"},
+		{"god", "god"}, {"goddess", "goddess"}, {"godfather", "godfath"}, {"godless", "godless"}, {"godly", "god"}, {"godmother", "godmoth"},
+		{"godparent", "godpar"}, {"godsend", "godsend"}, {"goggle", "goggl"}, {"goggles", "goggl"}, {"going", "go"}, {"gold", "gold"},
+		{"golden", "golden"}, {"goldfish", "goldfish"}, {"golf", "golf"}, {"golfer", "golfer"}, {"gondola", "gondola"}, {"gong", "gong"},
+		{"good", "good"}, {"goodbye", "goodby"}, {"goodies", "goodi"}, {"goodness", "good"}, {"goods", "good"}, {"goodwill", "goodwil"},
+		{"goody", "goodi"}, {"goose", "goos"}, {"gooseberry", "gooseberri"}, {"gore", "gore"}, {"gorge", "gorg"}, {"gorgeous", "gorgeous"},
+		{"gorilla", "gorilla"}, {"gory", "gori"}, {"gosh", "gosh"}, {"gospel", "gospel"}, {"gossamer", "gossam"}, {"gossip", "gossip"},
+		{"gouge", "goug"}, {"goulash", "goulash"}, {"gourd", "gourd"}, {"gourmet", "gourmet"}, {"gout", "gout"}, {"govern", "govern"},
+		{"governance", "govern"}, {"governed", "govern"}, {"governess", "gover"}, {"governing", "govern"}, {"government", "govern"},
+		{"governmental", "government"}, {"governor", "governor"}, {"governors", "governor"}, {"governs", "govern"}, {"gown", "gown"},
+		{"grab", "grab"}, {"grabbed", "grab"}, {"grabbing", "grab"}, {"grabs", "grab"}, {"grace", "grace"}, {"graceful", "grace"},
+		{"gracefully", "grace"}, {"graceless", "graceless"}, {"gracious", "gracious"}, {"gradation", "gradat"}, {"grade", "grade"},
+		{"gradient", "gradient"}, {"gradients", "gradient"}, {"gradual", "gradual"}, {"gradually", "gradual"}, {"graduate", "graduat"},
+		{"graduates", "graduat"}, {"graduation", "graduat"}, {"graffiti", "graffiti"}, {"graft", "graft"}, {"grafted", "graft"},
+		{"grafts", "graft"}, {"grain", "grain"}, {"grained", "grain"}, {"gram", "gram"}, {"grammar", "grammar"}, {"grammatical", "grammat"},
+		{"gramme", "gramm"}, {"gramophone", "gramophon"}, {"granary", "granari"}, {"grand", "grand"}, {"grandchild", "grandchild"},
+		{"granddaughter", "granddaught"}, {"grandeur", "grandeur"}, {"grandfather", "grandfath"}, {"grandiose", "grandios"},
+		{"grandmother", "grandmoth"}, {"grandparent", "grandpar"}, {"grandson", "grandson"}, {"grandstand", "grandstand"}, {"granite", "granit"},
+		{"granny", "granni"}, {"grant", "grant"}, {"granted", "grant"}, {"granting", "grant"}, {"grants", "grant"}, {"granular", "granular"},
+		{"granularity", "granular"}, {"granule", "granul"}, {"grape", "grape"}, {"grapefruit", "grapefruit"}, {"grapevine", "grapevin"},
+		{"graph", "graph"}, {"graphic", "graphic"}, {"graphical", "graphic"}, {"graphically", "graphic"}, {"graphics", "graphic"},
+		{"graphite", "graphit"}, {"graphs", "graph"}, {"grapple", "grappl"}, {"grasp", "grasp"}, {"grass", "grass"},
+		{"grasshopper", "grasshopp"}, {"grassland", "grassland"}, {"grassy", "grassi"}, {"grate", "grate"}, {"grateful", "grate"},
+		{"grater", "grater"}, {"gratification", "gratif"}, {"gratify", "gratifi"}, {"grating", "grate"}, {"gratitude", "gratitud"},
+		{"gratuitous", "gratuit"}, {"gratuity", "gratuiti"}, {"grave", "grave"}, {"gravel", "gravel"}, {"gravestone", "graveston"},
+		{"graveyard", "graveyard"}, {"gravitate", "gravit"}, {"gravitation", "gravit"}, {"gravitational", "gravit"}, {"gravity", "graviti"},
+		{"gravy", "gravi"}, {"gray", "gray"}, {"graze", "graze"}, {"grease", "greas"}, {"greasy", "greasi"}, {"great", "great"},
+		{"greatly", "great"}, {"greed", "greed"}, {"greedy", "greedi"}, {"green", "green"}, {"greene", "green"}, {"greenery", "greeneri"},
+		{"greengrocer", "greengroc"}, {"greenhouse", "greenhous"}, {"greet", "greet"}, {"greeting", "greet"}, {"greetings", "greet"},
+		{"gregarious", "gregari"}, {"grenade", "grenad"}, {"grey", "grey"}, {"greyhound", "greyhound"}, {"greying", "grey"}, {"grid", "grid"},
+		{"griddle", "griddl"}, {"gridiron", "gridiron"}, {"gridlock", "gridlock"}, {"grief", "grief"}, {"grievance", "grievanc"},
+		{"grieve", "griev"}, {"grievous", "grievous"}, {"grill", "grill"}, {"grille", "grill"}, {"grim", "grim"}, {"grimace", "grimac"},
+		{"grime", "grime"}, {"grimy", "grimi"}, {"grin", "grin"}, {"grind", "grind"}, {"grinder", "grinder"}, {"grip", "grip"},
+		{"gripe", "gripe"}, {"grisly", "grisli"}, {"grit", "grit"}, {"gritty", "gritti"}, {"grizzly", "grizzli"}, {"groan", "groan"},
+		{"grocer", "grocer"}, {"grocery", "groceri"}, {"groggy", "groggi"}, {"groin", "groin"}, {"groom", "groom"}, {"groove", "groov"},
+		{"grope", "grope"}, {"gross", "gross"}, {"grotesque", "grotesqu"}, {"grotto", "grotto"}, {"grouch", "grouch"}, {"ground", "ground"},
+		{"grounding", "ground"}, {"groundless", "groundless"}, {"grounds", "ground"}, {"groundwork", "groundwork"}, {"group", "group"},
+		{"grouped", "group"}, {"grouping", "group"}, {"groupings", "group"}, {"groups", "group"}, {"grouse", "grous"}, {"grove", "grove"},
+		{"grovel", "grovel"}, {"grow", "grow"}, {"grower", "grower"}, {"growing", "grow"}, {"growl", "growl"}, {"grown", "grown"},
+		{"grownup", "grownup"}, {"grows", "grow"}, {"growth", "growth"}, {"grub", "grub"}, {"grubby", "grubbi"}, {"grudge", "grudg"},
+		{"gruel", "gruel"}, {"grueling", "gruel"}, {"gruelling", "gruell"}, {"gruesome", "gruesom"}, {"gruff", "gruff"}, {"grumble", "grumbl"},
+		{"grumpy", "grumpi"}, {"grunt", "grunt"}, {"guarantee", "guarante"}, {"guaranteed", "guarante"}, {"guaranteeing", "guarante"},
+		{"guarantees", "guarante"}, {"guarantor", "guarantor"}, {"guard", "guard"}, {"guarded", "guard"}, {"guardian", "guardian"},
+		{"guardianship", "guardianship"}, {"guarding", "guard"}, {"guards", "guard"}, {"guerrilla", "guerrilla"}, {"guess", "guess"},
+		{"guessed", "guess"}, {"guesses", "guess"}, {"guessing", "guess"}, {"guesswork", "guesswork"}, {"guest", "guest"}, {"guests", "guest"},
+		{"guffaw", "guffaw"}, {"guid", "guid"}, {"guidance", "guidanc"}, {"guide", "guid"}, {"guidebook", "guidebook"}, {"guided", "guid"},
+		{"guideline", "guidelin"}, {"guidelines", "guidelin"}, {"guides", "guid"}, {"guids", "guid"}, {"guild", "guild"}, {"guile", "guil"},
+		{"guillotine", "guillotin"}, {"guilt", "guilt"}, {"guilty", "guilti"}, {"guinea", "guinea"}, {"guise", "guis"}, {"guitar", "guitar"},
+		{"guitarist", "guitarist"}, {"gulf", "gulf"}, {"gull", "gull"}, {"gullet", "gullet"}, {"gullible", "gullibl"}, {"gully", "gulli"},
+		{"gulp", "gulp"}, {"gum", "gum"}, {"gumption", "gumption"}, {"gun", "gun"}, {"gunboat", "gunboat"}, {"gunfire", "gunfir"},
+		{"gunman", "gunman"}, {"gunner", "gunner"}, {"gunpoint", "gunpoint"}, {"gunpowder", "gunpowd"}, {"gunshot", "gunshot"},
+		{"gurgle", "gurgl"}, {"guru", "guru"}, {"gush", "gush"}, {"gust", "gust"}, {"gusto", "gusto"}, {"gut", "gut"}, {"guts", "gut"},
+		{"gutter", "gutter"}, {"guttural", "guttur"}, {"guy", "guy"}, {"guzzle", "guzzl"}, {"gym", "gym"}, {"gymnasium", "gymnasium"},
+		{"gymnast", "gymnast"}, {"gymnastic", "gymnast"}, {"gymnastics", "gymnast"}, {"gynaecologist", "gynaecologist"},
+		{"gynaecology", "gynaecolog"}, {"gynecologist", "gynecologist"}, {"gynecology", "gynecolog"}, {"gypsy", "gypsi"}, {"gyrate", "gyrat"},
+		{"gyration", "gyrat"}, {"gyroscope", "gyroscop"}, {"ha", "ha"}, {"habit", "habit"}, {"habitable", "habit"}, {"habitat", "habitat"},
+		{"habitation", "habit"}, {"habits", "habit"}, {"habitual", "habitu"}, {"habitually", "habitu"}, {"hack", "hack"}, {"hacker", "hacker"},
+		{"hackers", "hacker"}, {"hacking", "hack"}, {"hackles", "hackl"}, {"hackney", "hackney"}, {"hacks", "hack"}, {"hacksaw", "hacksaw"},
+		{"haddock", "haddock"}, {"haemoglobin", "haemoglobin"}, {"haemophilia", "haemophilia"}, {"haemorrhage", "haemorrhag"},
+		{"haemorrhoids", "haemorrhoid"}, {"hag", "hag"}, {"haggard", "haggard"}, {"haggle", "haggl"}, {"hail", "hail"},
+		{"hailstone", "hailston"}, {"hair", "hair"}, {"haircut", "haircut"}, {"hairdo", "hairdo"}, {"hairdresser", "hairdress"},
+		{"hairline", "hairlin"}, {"hairpin", "hairpin"}, {"hairstyle", "hairstyl"}, {"hairy", "hairi"}, {"hale", "hale"}, {"half", "half"},
+		{"halfway", "halfway"}, {"halibut", "halibut"}, {"hall", "hall"}, {"hallmark", "hallmark"}, {"hallowed", "hallow"}, {"halls", "hall"},
+		{"hallucinate", "hallucin"}, {"hallucination", "hallucin"}, {"hallway", "hallway"}, {"halo", "halo"}, {"halos", "halo"},
+		{"halt", "halt"}, {"halted", "halt"}, {"halter", "halter"}, {"halting", "halt"}, {"halts", "halt"}, {"halve", "halv"},
+		{"halved", "halv"}, {"halves", "halv"}, {"ham", "ham"}, {"hamburger", "hamburg"}, {"hamlet", "hamlet"}, {"hammer", "hammer"},
+		{"hammock", "hammock"}, {"hamper", "hamper"}, {"hamster", "hamster"}, {"hamstring", "hamstr"}, {"hand", "hand"}, {"handbag", "handbag"},
+		{"handbook", "handbook"}, {"handbrake", "handbrak"}, {"handcuff", "handcuff"}, {"handed", "hand"}, {"handful", "hand"},
+		{"handgun", "handgun"}, {"handicap", "handicap"}, {"handicraft", "handicraft"}, {"handing", "hand"}, {"handiwork", "handiwork"},
+		{"handkerchief", "handkerchief"}, {"handle", "handl"}, {"handlebar", "handlebar"}, {"handled", "handl"}, {"handler", "handler"},
+		{"handlers", "handler"}, {"handles", "handl"}, {"handling", "handl"}, {"handlings", "handl"}, {"handmade", "handmad"},
+		{"handout", "handout"}, {"handrail", "handrail"}, {"hands", "hand"}, {"handset", "handset"}, {"handshake", "handshak"},
+		{"handshakes", "handshak"}, {"handshaking", "handshak"}, {"handsome", "handsom"}, {"handwriting", "handwrit"}, {"handy", "handi"},
+		{"hang", "hang"}, {"hangar", "hangar"}, {"hanger", "hanger"}, {"hanging", "hang"}, {"hangover", "hangov"}, {"hangs", "hang"},
+		{"hanker", "hanker"}, {"haphazard", "haphazard"}, {"hapless", "hapless"}, {"happen", "happen"}, {"happened", "happen"},
+		{"happening", "happen"}, {"happens", "happen"}, {"happy", "happi"}, {"harangue", "harangu"}, {"harass", "harass"},
+		{"harassing", "harass"}, {"harassment", "harass"}, {"harbinger", "harbing"}, {"harbor", "harbor"}, {"harbour", "harbour"},
+		{"hard", "hard"}, {"hardback", "hardback"}, {"harden", "harden"}, {"hardened", "harden"}, {"hardening", "harden"}, {"hardens", "harden"},
+		{"hardline", "hardlin"}, {"hardly", "hard"}, {"hardship", "hardship"}, {"hardware", "hardwar"}, {"hardwood", "hardwood"},
+		{"hardy", "hardi"}, {"hare", "hare"}, {"harem", "harem"}, {"hark", "hark"}, {"harlot", "harlot"}, {"harm", "harm"}, {"harmful", "harm"},
+		{"harming", "harm"}, {"harmless", "harmless"}, {"harmonic", "harmon"}, {"harmonica", "harmonica"}, {"harmonious", "harmoni"},
+		{"harmonium", "harmonium"}, {"harmonize", "harmon"}, {"harmony", "harmoni"}, {"harness", "har"}, {"harp", "harp"},
+		{"harpoon", "harpoon"}, {"harpsichord", "harpsichord"}, {"harrow", "harrow"}, {"harrowing", "harrow"}, {"harsh", "harsh"},
+		{"harvest", "harvest"}, {"harvester", "harvest"}, {"hash", "hash"}, {"hashed", "hash"}, {"hashes", "hash"}, {"hashing", "hash"},
+		{"hashish", "hashish"}, {"hassle", "hassl"}, {"hast", "hast"}, {"haste", "hast"}, {"hasten", "hasten"}, {"hasty", "hasti"},
+		{"hat", "hat"}, {"hatch", "hatch"}, {"hatchback", "hatchback"}, {"hatchet", "hatchet"}, {"hate", "hate"}, {"hateful", "hate"},
+		{"hatred", "hatr"}, {"haughty", "haughti"}, {"haul", "haul"}, {"haulage", "haulag"}, {"haunch", "haunch"}, {"haunt", "haunt"},
+		{"have", "have"}, {"haven", "haven"}, {"haversack", "haversack"}, {"having", "have"}, {"havoc", "havoc"}, {"hawk", "hawk"},
+		{"hawker", "hawker"}, {"hay", "hay"}, {"hayes", "hay"}, {"haystack", "haystack"}, {"haywire", "haywir"}, {"hazard", "hazard"},
+		{"hazardous", "hazard"}, {"hazards", "hazard"}, {"haze", "haze"}, {"hazel", "hazel"}, {"hazy", "hazi"}, {"he", "he"}, {"head", "head"},
+		{"headache", "headach"}, {"headband", "headband"}, {"headdress", "headdress"}, {"headed", "head"}, {"header", "header"},
+		{"headers", "header"}, {"headgear", "headgear"}, {"heading", "head"}, {"headings", "head"}, {"headland", "headland"},
+		{"headlight", "headlight"}, {"headline", "headlin"}, {"headlong", "headlong"}, {"headmaster", "headmast"},
+		{"headmistress", "headmistress"}, {"headphones", "headphon"}, {"headquarters", "headquart"}, {"headroom", "headroom"}, {"heads", "head"},
+		{"headstone", "headston"}, {"headstrong", "headstrong"}, {"headway", "headway"}, {"heady", "headi"}, {"heal", "heal"},
+		{"healer", "healer"}, {"healing", "heal"}, {"health", "health"}, {"healthy", "healthi"}, {"heap", "heap"}, {"heaps", "heap"},
+		{"hear", "hear"}, {"hearing", "hear"}, {"hearings", "hear"}, {"hearsay", "hearsay"}, {"hearse", "hears"}, {"heart", "heart"},
+		{"heartache", "heartach"}, {"heartbeat", "heartbeat"}, {"heartbreak", "heartbreak"}, {"heartbroken", "heartbroken"},
+		{"heartburn", "heartburn"}, {"hearten", "hearten"}, {"heartfelt", "heartfelt"}, {"hearth", "hearth"}, {"heartless", "heartless"},
+		{"hearty", "hearti"}, {"heat", "heat"}, {"heater", "heater"}, {"heath", "heath"}, {"heathen", "heathen"}, {"heather", "heather"},
+		{"heatwave", "heatwav"}, {"heave", "heav"}, {"heaven", "heaven"}, {"heavenly", "heaven"}, {"heavy", "heavi"},
+		{"heavyweight", "heavyweight"}, {"heckle", "heckl"}, {"hectare", "hectar"}, {"hectic", "hectic"}, {"hedge", "hedg"},
+		{"hedgehog", "hedgehog"}, {"hedgerow", "hedgerow"}, {"hedging", "hedg"}, {"hedonism", "hedon"}, {"hedonist", "hedonist"},
+		{"heed", "heed"}, {"heedless", "heedless"}, {"heel", "heel"}, {"hefty", "hefti"}, {"hegemony", "hegemoni"}, {"heifer", "heifer"},
+		{"height", "height"}, {"heighten", "heighten"}, {"heinous", "heinous"}, {"heir", "heir"}, {"heiress", "heiress"},
+		{"heirloom", "heirloom"}, {"helicopter", "helicopt"}, {"helium", "helium"}, {"hell", "hell"}, {"hellish", "hellish"}, {"hello", "hello"},
+		{"helm", "helm"}, {"helmet", "helmet"}, {"helmsman", "helmsman"}, {"help", "help"}, {"helped", "help"}, {"helper", "helper"},
+		{"helpers", "helper"}, {"helpful", "help"}, {"helping", "help"}, {"helpless", "helpless"}, {"helps", "help"}, {"hem", "hem"},
+		{"hemisphere", "hemispher"}, {"hemline", "hemlin"}, {"hemlock", "hemlock"}, {"hemoglobin", "hemoglobin"}, {"hemophilia", "hemophilia"},
+		{"hemorrhage", "hemorrhag"}, {"hemp", "hemp"}, {"hen", "hen"}, {"hence", "henc"}, {"henceforth", "henceforth"}, {"henchman", "henchman"},
+		{"hepatitis", "hepat"}, {"heptagon", "heptagon"}, {"her", "her"}, {"herald", "herald"}, {"heraldic", "herald"}, {"heraldry", "heraldri"},
+		{"herb", "herb"}, {"herbaceous", "herbac"}, {"herbal", "herbal"}, {"herbalist", "herbalist"}, {"herbivore", "herbivor"},
+		{"herd", "herd"}, {"herdsman", "herdsman"}, {"here", "here"}, {"hereabouts", "hereabout"}, {"hereafter", "hereaft"},
+		{"hereby", "herebi"}, {"hereditary", "hereditari"}, {"heredity", "hered"}, {"herein", "herein"}, {"heresy", "heresi"},
+		{"heretic", "heret"}, {"heretical", "heret"}, {"herewith", "herewith"}, {"heritage", "heritag"}, {"hermaphrodite", "hermaphrodit"},
+		{"hermetic", "hermet"}, {"hermit", "hermit"}, {"hernia", "hernia"}, {"hero", "hero"}, {"heroic", "heroic"}, {"heroics", "heroic"},
+		{"heroin", "heroin"}, {"heroine", "heroin"}, {"heroism", "heroism"}, {"heron", "heron"}, {"herpes", "herp"}, {"herring", "herring"},
+		{"herringbone", "herringbon"}, {"hesitancy", "hesit"}, {"hesitant", "hesit"}, {"hesitate", "hesit"}, {"hesitation", "hesit"},
+		{"heterogeneous", "heterogen"}, {"heterosexual", "heterosexu"}, {"heuristic", "heurist"}, {"heuristically", "heurist"},
+		{"heuristics", "heurist"}, {"hew", "hew"}, {"hexagon", "hexagon"}, {"hexagonal", "hexagon"}, {"heyday", "heyday"}, {"hi", "hi"},
+		{"hiatus", "hiatus"}, {"hibernate", "hibern"}, {"hibernated", "hibern"}, {"hibernating", "hibern"}, {"hibernation", "hibern"},
+		{"hiccup", "hiccup"}, {"hide", "hide"}, {"hideous", "hideous"}, {"hideout", "hideout"}, {"hides", "hide"}, {"hiding", "hide"},
+		{"hierarchical", "hierarch"}, {"hierarchically", "hierarch"}, {"hierarchies", "hierarchi"}, {"hierarchy", "hierarchi"},
+		{"hieroglyphics", "hieroglyph"}, {"high", "high"}, {"highbrow", "highbrow"}, {"highland", "highland"}, {"highlight", "highlight"},
+		{"highlighted", "highlight"}, {"highlighting", "highlight"}, {"highlights", "highlight"}, {"highly", "high"}, {"highway", "highway"},
+		{"hijack", "hijack"}, {"hijacker", "hijack"}, {"hijacking", "hijack"}, {"hike", "hike"}, {"hiker", "hiker"}, {"hilarious", "hilari"},
+		{"hilarity", "hilar"}, {"hill", "hill"}, {"hillock", "hillock"}, {"hillside", "hillsid"}, {"hilly", "hilli"}, {"hilt", "hilt"},
+		{"hind", "hind"}, {"hinder", "hinder"}, {"hindrance", "hindranc"}, {"hinds", "hind"}, {"hindsight", "hindsight"}, {"hinge", "hing"},
+		{"hint", "hint"}, {"hinted", "hint"}, {"hinterland", "hinterland"}, {"hinting", "hint"}, {"hints", "hint"}, {"hip", "hip"},
+		{"hippie", "hippi"}, {"hippo", "hippo"}, {"hippopotamus", "hippopotamus"}, {"hire", "hire"}, {"hireling", "hirel"}, {"hiss", "hiss"},
+		{"historian", "historian"}, {"historic", "histor"}, {"historical", "histor"}, {"historically", "histor"}, {"histories", "histori"},
+		{"history", "histori"}, {"histrionic", "histrion"}, {"hit", "hit"}, {"hitch", "hitch"}, {"hitchhike", "hitchhik"},
+		{"hitchhiker", "hitchhik"}, {"hither", "hither"}, {"hitherto", "hitherto"}, {"hits", "hit"}, {"hitting", "hit"}, {"hive", "hive"},
+		{"hoard", "hoard"}, {"hoarding", "hoard"}, {"hoarse", "hoars"}, {"hoax", "hoax"}, {"hob", "hob"}, {"hobble", "hobbl"},
+		{"hobby", "hobbi"}, {"hobgoblin", "hobgoblin"}, {"hock", "hock"}, {"hockey", "hockey"}, {"hoe", "hoe"}, {"hog", "hog"},
+		{"hogging", "hog"}, {"hoist", "hoist"}, {"hoisted", "hoist"}, {"hoisting", "hoist"}, {"hold", "hold"}, {"holdall", "holdal"},
+		{"holder", "holder"}, {"holders", "holder"}, {"holding", "hold"}, {"holds", "hold"}, {"holdup", "holdup"}, {"hole", "hole"},
+		{"holes", "hole"}, {"holiday", "holiday"}, {"holidays", "holiday"}, {"holiness", "holi"}, {"hollow", "hollow"}, {"holly", "holli"},
+		{"holocaust", "holocaust"}, {"hologram", "hologram"}, {"holster", "holster"}, {"holy", "holi"}, {"homage", "homag"}, {"home", "home"},
+		{"homecoming", "homecom"}, {"homed", "home"}, {"homeland", "homeland"}, {"homeless", "homeless"}, {"homely", "home"},
+		{"homemade", "homemad"}, {"homeopathy", "homeopathi"}, {"homes", "home"}, {"homesick", "homesick"}, {"homestead", "homestead"},
+		{"homeward", "homeward"}, {"homework", "homework"}, {"homicidal", "homicid"}, {"homicide", "homicid"}, {"homily", "homili"},
+		{"homogeneous", "homogen"}, {"homogenize", "homogen"}, {"homonym", "homonym"}, {"homosexual", "homosexu"}, {"homosexuality", "homosexu"},
+		{"hone", "hone"}, {"honest", "honest"}, {"honesty", "honesti"}, {"honey", "honey"}, {"honeycomb", "honeycomb"},
+		{"honeymoon", "honeymoon"}, {"honk", "honk"}, {"honor", "honor"}, {"honorable", "honor"}, {"honorary", "honorari"}, {"honored", "honor"},
+		{"honoring", "honor"}, {"honors", "honor"}, {"honour", "honour"}, {"honourable", "honour"}, {"honoured", "honour"},
+		{"honours", "honour"}, {"hood", "hood"}, {"hoodwink", "hoodwink"}, {"hoof", "hoof"}, {"hook", "hook"}, {"hooking", "hook"},
+		{"hooks", "hook"}, {"hooligan", "hooligan"}, {"hoop", "hoop"}, {"hoot", "hoot"}, {"hooter", "hooter"}, {"hop", "hop"}, {"hope", "hope"},
+		{"hoped", "hope"}, {"hopeful", "hope"}, {"hopefully", "hope"}, {"hopeless", "hopeless"}, {"hopes", "hope"}, {"hopper", "hopper"},
+		{"hops", "hop"}, {"horde", "hord"}, {"horizon", "horizon"}, {"horizontal", "horizont"}, {"horizontally", "horizont"},
+		{"hormonal", "hormon"}, {"hormone", "hormon"}, {"horn", "horn"}, {"hornet", "hornet"}, {"horoscope", "horoscop"},
+		{"horrendous", "horrend"}, {"horrible", "horribl"}, {"horribly", "horribl"}, {"horrid", "horrid"}, {"horrific", "horrif"},
+		{"horrify", "horrifi"}, {"horror", "horror"}, {"horrors", "horror"}, {"horse", "hors"}, {"horseback", "horseback"},
+		{"horseman", "horseman"}, {"horsepower", "horsepow"}, {"horseshoe", "horsesho"}, {"horticultural", "horticultur"},
+		{"horticulture", "horticultur"}, {"hose", "hose"}, {"hosiery", "hosieri"}, {"hospice", "hospic"}, {"hospitable", "hospit"},
+		{"hospital", "hospit"}, {"hospitality", "hospit"}, {"hospitalize", "hospit"}, {"host", "host"}, {"hostage", "hostag"},
+		{"hosted", "host"}, {"hostel", "hostel"}, {"hostess", "hostess"}, {"hostile", "hostil"}, {"hostility", "hostil"}, {"hosting", "host"},
+		{"hosts", "host"}, {"hot", "hot"}, {"hotbed", "hotb"}, {"hotel", "hotel"}, {"hotelier", "hoteli"}, {"hothead", "hothead"},
+		{"hothouse", "hothous"}, {"hound", "hound"}, {"hour", "hour"}, {"hourly", "hour"}, {"hours", "hour"}, {"house", "hous"},
+		{"houseboat", "houseboat"}, {"housebound", "housebound"}, {"household", "household"}, {"householder", "household"},
+		{"housekeeper", "housekeep"}, {"housekeeping", "housekeep"}, {"houses", "hous"}, {"housewife", "housewif"}, {"housework", "housework"},
+		{"housing", "hous"}, {"hovel", "hovel"}, {"hover", "hover"}, {"hovercraft", "hovercraft"}, {"hovered", "hover"}, {"how", "how"},
+		{"however", "howev"}, {"howl", "howl"}, {"hub", "hub"}, {"hubbub", "hubbub"}, {"hubs", "hub"}, {"huddle", "huddl"}, {"hue", "hue"},
+		{"huff", "huff"}, {"hug", "hug"}, {"huge", "huge"}, {"huges", "huge"}, {"hugging", "hug"}, {"hulk", "hulk"}, {"hull", "hull"},
+		{"hullabaloo", "hullabaloo"}, {"hum", "hum"}, {"human", "human"}, {"humane", "human"}, {"humanism", "human"}, {"humanist", "humanist"},
+		{"humanitarian", "humanitarian"}, {"humanity", "human"}, {"humanize", "human"}, {"humanly", "human"}, {"humans", "human"},
+		{"humble", "humbl"}, {"humbug", "humbug"}, {"humdrum", "humdrum"}, {"humid", "humid"}, {"humidity", "humid"}, {"humiliate", "humili"},
+		{"humiliation", "humili"}, {"humility", "humil"}, {"hummingbird", "hummingbird"}, {"humor", "humor"}, {"humorist", "humorist"},
+		{"humorous", "humor"}, {"humour", "humour"}, {"hump", "hump"}, {"hunch", "hunch"}, {"hunchback", "hunchback"}, {"hundred", "hundr"},
+		{"hundreds", "hundr"}, {"hundredth", "hundredth"}, {"hundredths", "hundredth"}, {"hunger", "hunger"}, {"hungry", "hungri"},
+		{"hunk", "hunk"}, {"hunks", "hunk"}, {"hunt", "hunt"}, {"hunter", "hunter"}, {"hurdle", "hurdl"}, {"hurl", "hurl"}, {"hurrah", "hurrah"},
+		{"hurray", "hurray"}, {"hurricane", "hurrican"}, {"hurry", "hurri"}, {"hurt", "hurt"}, {"hurtful", "hurt"}, {"hurting", "hurt"},
+		{"hurtle", "hurtl"}, {"hurts", "hurt"}, {"husband", "husband"}, {"husbandry", "husbandri"}, {"hush", "hush"}, {"hushed", "hush"},
+		{"husk", "husk"}, {"husky", "huski"}, {"hustle", "hustl"}, {"hut", "hut"}, {"hutch", "hutch"}, {"hyacinth", "hyacinth"},
+		{"hybrid", "hybrid"}, {"hybridized", "hybrid"}, {"hydrant", "hydrant"}, {"hydraulic", "hydraul"}, {"hydraulics", "hydraul"},
+		{"hydrocarbon", "hydrocarbon"}, {"hydroelectric", "hydroelectr"}, {"hydrofoil", "hydrofoil"}, {"hydrogen", "hydrogen"},
+		{"hydromagnet", "hydromagnet"}, {"hyena", "hyena"}, {"hygiene", "hygien"}, {"hygienic", "hygien"}, {"hymn", "hymn"}, {"hype", "hype"},
+		{"hyperactive", "hyperact"}, {"hyperbole", "hyperbol"}, {"hyperbolic", "hyperbol"}, {"hypermarket", "hypermarket"},
+		{"hypertension", "hypertens"}, {"hyphen", "hyphen"}, {"hyphenate", "hyphen"}, {"hyphenated", "hyphen"}, {"hyphenation", "hyphen"},
+		{"hyphens", "hyphen"}, {"hypnosis", "hypnosi"}, {"hypnotic", "hypnot"}, {"hypnotism", "hypnot"}, {"hypnotist", "hypnotist"},
+		{"hypnotize", "hypnot"}, {"hypochondria", "hypochondria"}, {"hypochondriac", "hypochondriac"}, {"hypocrisy", "hypocrisi"},
+		{"hypocrite", "hypocrit"}, {"hypocritical", "hypocrit"}, {"hypodermic", "hypoderm"}, {"hypothermia", "hypothermia"},
+		{"hypotheses", "hypothes"}, {"hypothesis", "hypothesi"}, {"hypothesize", "hypothes"}, {"hypothetical", "hypothet"},
+		{"hysteria", "hysteria"}, {"hysterical", "hyster"}, {"hysterics", "hyster"}, {"ice", "ice"}, {"iceberg", "iceberg"},
+		{"icebox", "icebox"}, {"icicle", "icicl"}, {"icing", "ice"}, {"icon", "icon"}, {"iconoclast", "iconoclast"}, {"icons", "icon"},
+		{"icy", "ici"}, {"idea", "idea"}, {"ideal", "ideal"}, {"idealism", "ideal"}, {"idealist", "idealist"}, {"idealistic", "idealist"},
+		{"idealize", "ideal"}, {"ideally", "ideal"}, {"ideas", "idea"}, {"ident", "ident"}, {"identical", "ident"}, {"identically", "ident"},
+		{"identifiable", "identifi"}, {"identification", "identif"}, {"identifications", "identif"}, {"identified", "identifi"},
+		{"identifier", "identifi"}, {"identifiers", "identifi"}, {"identifies", "identifi"}, {"identify", "identifi"},
+		{"identifying", "identifi"}, {"identities", "ident"}, {"identity", "ident"}, {"ideological", "ideolog"}, {"ideology", "ideolog"},
+		{"idiocy", "idioci"}, {"idiom", "idiom"}, {"idiomatic", "idiomat"}, {"idioms", "idiom"}, {"idiosyncrasy", "idiosyncrasi"},
+		{"idiosyncratic", "idiosyncrat"}, {"idiot", "idiot"}, {"idiotic", "idiot"}, {"idl", "idl"}, {"idle", "idl"}, {"idleness", "idl"},
+		{"idling", "idl"}, {"idol", "idol"}, {"idolatry", "idolatri"}, {"idolize", "idol"}, {"idyll", "idyl"}, {"idyllic", "idyl"}, {"if", "if"},
+		{"ifs", "if"}, {"igloo", "igloo"}, {"ignite", "ignit"}, {"ignition", "ignit"}, {"ignoble", "ignobl"}, {"ignominious", "ignomini"},
+		{"ignominy", "ignomini"}, {"ignorable", "ignor"}, {"ignoramus", "ignoramus"}, {"ignorance", "ignor"}, {"ignorant", "ignor"},
+		{"ignore", "ignor"}, {"ignored", "ignor"}, {"ignores", "ignor"}, {"ignoring", "ignor"}, {"ill", "ill"}, {"illegal", "illeg"},
+		{"illegality", "illeg"}, {"illegible", "illeg"}, {"illegitimacy", "illegitimaci"}, {"illegitimate", "illegitim"}, {"illicit", "illicit"},
+		{"illiteracy", "illiteraci"}, {"illiterate", "illiter"}, {"illness", "ill"}, {"illogical", "illog"}, {"illuminate", "illumin"},
+		{"illumination", "illumin"}, {"illusion", "illus"}, {"illusory", "illusori"}, {"illustrate", "illustr"}, {"illustrated", "illustr"},
+		{"illustrates", "illustr"}, {"illustrating", "illustr"}, {"illustration", "illustr"}, {"illustrative", "illustr"},
+		{"illustrator", "illustr"}, {"illustrious", "illustri"}, {"imag", "imag"}, {"image", "imag"}, {"imagery", "imageri"}, {"images", "imag"},
+		{"imagic", "imag"}, {"imaginable", "imagin"}, {"imaginary", "imaginari"}, {"imagination", "imagin"}, {"imaginative", "imagin"},
+		{"imagine", "imagin"}, {"imbalance", "imbal"}, {"imbecile", "imbecil"}, {"imbibe", "imbib"}, {"imbue", "imbu"}, {"imit", "imit"},
+		{"imitate", "imit"}, {"imitates", "imit"}, {"imitating", "imit"}, {"imitation", "imit"}, {"imitative", "imit"}, {"imitator", "imit"},
+		{"immaculate", "immacul"}, {"immaterial", "immateri"}, {"immature", "immatur"}, {"immaturity", "immatur"}, {"immeasurable", "immeasur"},
+		{"immediacy", "immediaci"}, {"immediate", "immedi"}, {"immediately", "immedi"}, {"immediates", "immedi"}, {"immemorial", "immemori"},
+		{"immense", "immens"}, {"immensity", "immens"}, {"immerse", "immers"}, {"immersion", "immers"}, {"immersive", "immers"},
+		{"immigrant", "immigr"}, {"immigrate", "immigr"}, {"immigration", "immigr"}, {"imminent", "immin"}, {"immobile", "immobil"},
+		{"immobilize", "immobil"}, {"immoderate", "immoder"}, {"immodest", "immodest"}, {"immoral", "immor"}, {"immorality", "immor"},
+		{"immortal", "immort"}, {"immortality", "immort"}, {"immortalize", "immort"}, {"immovable", "immov"}, {"immune", "immun"},
+		{"immunity", "immun"}, {"immunize", "immun"}, {"immutability", "immut"}, {"immutable", "immut"}, {"immutably", "immut"}, {"imp", "imp"},
+		{"impact", "impact"}, {"impacted", "impact"}, {"impacting", "impact"}, {"impacts", "impact"}, {"impair", "impair"},
+		{"impairment", "impair"}, {"impale", "impal"}, {"impart", "impart"}, {"impartial", "imparti"}, {"impartiality", "imparti"},
+		{"impassable", "impass"}, {"impasse", "impass"}, {"impassioned", "impass"}, {"impassive", "impass"}, {"impatience", "impati"},
+		{"impatient", "impati"}, {"impeach", "impeach"}, {"impeachment", "impeach"}, {"impeccable", "impecc"}, {"impede", "imped"},
+		{"impediment", "impedi"}, {"impel", "impel"}, {"impending", "impend"}, {"impenetrable", "impenetr"}, {"imperative", "imper"},
+		{"imperceptible", "impercept"}, {"imperfect", "imperfect"}, {"imperfection", "imperfect"}, {"imperial", "imperi"},
+		{"imperialism", "imperi"}, {"imperialist", "imperialist"}, {"imperil", "imperil"}, {"imperious", "imperi"}, {"impersonal", "imperson"},
+		{"impersonate", "imperson"}, {"impersonated", "imperson"}, {"impersonating", "imperson"}, {"impersonation", "imperson"},
+		{"impersonator", "imperson"}, {"impertinence", "impertin"}, {"impertinent", "impertin"}, {"impervious", "impervi"},
+		{"impetuous", "impetu"}, {"impetus", "impetus"}, {"impinge", "imping"}, {"impish", "impish"}, {"implacable", "implac"},
+		{"implant", "implant"}, {"implausible", "implaus"}, {"implement", "implement"}, {"implementation", "implement"},
+		{"implementations", "implement"}, {"implemented", "implement"}, {"implementers", "implement"}, {"implementing", "implement"},
+		{"implements", "implement"}, {"implicate", "implic"}, {"implicates", "implic"}, {"implication", "implic"}, {"implications", "implic"},
+		{"implicit", "implicit"}, {"implicitly", "implicit"}, {"implied", "impli"}, {"implies", "impli"}, {"implore", "implor"},
+		{"imply", "impli"}, {"implying", "impli"}, {"impolite", "impolit"}, {"import", "import"}, {"importable", "import"},
+		{"importance", "import"}, {"important", "import"}, {"importantly", "import"}, {"importation", "import"}, {"imported", "import"},
+		{"importer", "import"}, {"importers", "import"}, {"importing", "import"}, {"imports", "import"}, {"impose", "impos"},
+		{"imposed", "impos"}, {"imposes", "impos"}, {"imposing", "impos"}, {"imposition", "imposit"}, {"impossibility", "imposs"},
+		{"impossible", "imposs"}, {"impostor", "impostor"}, {"impotence", "impot"}, {"impotent", "impot"}, {"impound", "impound"},
+		{"impoverish", "impoverish"}, {"impracticable", "impractic"}, {"impractical", "impract"}, {"imprecise", "imprecis"},
+		{"impregnable", "impregn"}, {"impregnate", "impregn"}, {"impress", "impress"}, {"impression", "impress"},
+		{"impressionable", "impression"}, {"impressionism", "impression"}, {"impressionist", "impressionist"}, {"impressive", "impress"},
+		{"imprint", "imprint"}, {"imprison", "imprison"}, {"imprisonment", "imprison"}, {"improbable", "improb"}, {"impromptu", "impromptu"},
+		{"improper", "improp"}, {"improperly", "improp"}, {"impropriety", "improprieti"}, {"improve", "improv"}, {"improved", "improv"},
+		{"improvement", "improv"}, {"improvements", "improv"}, {"improves", "improv"}, {"improving", "improv"}, {"improvisation", "improvis"},
+		{"improvise", "improvis"}, {"imprudent", "imprud"}, {"imprudently", "imprud"}, {"impudence", "impud"}, {"impudent", "impud"},
+		{"impulse", "impuls"}, {"impulsive", "impuls"}, {"impunity", "impun"}, {"impure", "impur"}, {"impurity", "impur"}, {"impute", "imput"},
+		{"in", "in"}, {"inability", "inabl"}, {"inaccessible", "inaccess"}, {"inaccuracies", "inaccuraci"}, {"inaccuracy", "inaccuraci"},
+		{"inaccurate", "inaccur"}, {"inact", "inact"}, {"inaction", "inact"}, {"inactive", "inact"}, {"inactivity", "inact"},
+		{"inadequacy", "inadequaci"}, {"inadequate", "inadequ"}, {"inadmissible", "inadmiss"}, {"inadvertent", "inadvert"},
+		{"inadvertently", "inadvert"}, {"inadvisable", "inadvis"}, {"inalienable", "inalien"}, {"inane", "inan"}, {"inanimate", "inanim"},
+		{"inapplicable", "inapplic"}, {"inappropriate", "inappropri"}, {"inappropriately", "inappropri"}, {"inarticulate", "inarticul"},
+		{"inasmuch", "inasmuch"}, {"inattention", "inattent"}, {"inattentive", "inattent"}, {"inaudible", "inaud"}, {"inaugural", "inaugur"},
+		{"inaugurate", "inaugur"}, {"inauguration", "inaugur"}, {"inauspicious", "inauspici"}, {"inborn", "inborn"}, {"inbred", "inbr"},
+		{"incalculable", "incalcul"}, {"incandescent", "incandesc"}, {"incantation", "incant"}, {"incapable", "incap"},
+		{"incapacitate", "incapacit"}, {"incapacity", "incapac"}, {"incarcerate", "incarcer"}, {"incarceration", "incarcer"},
+		{"incarnate", "incarn"}, {"incarnation", "incarn"}, {"incendiary", "incendiari"}, {"incense", "incens"}, {"incentive", "incent"},
+		{"incept", "incept"}, {"inception", "incept"}, {"incessant", "incess"}, {"incest", "incest"}, {"incestuous", "incestu"},
+		{"inch", "inch"}, {"inches", "inch"}, {"incidence", "incid"}, {"incident", "incid"}, {"incidental", "incident"},
+		{"incidentally", "incident"}, {"incidents", "incid"}, {"incinerate", "inciner"}, {"incinerator", "inciner"}, {"incipient", "incipi"},
+		{"incision", "incis"}, {"incisive", "incis"}, {"incisor", "incisor"}, {"incite", "incit"}, {"incitement", "incit"},
+		{"inclement", "inclement"}, {"inclination", "inclin"}, {"incline", "inclin"}, {"include", "includ"}, {"included", "includ"},
+		{"includes", "includ"}, {"including", "includ"}, {"inclusion", "inclus"}, {"inclusions", "inclus"}, {"inclusive", "inclus"},
+		{"inclusively", "inclus"}, {"incognito", "incognito"}, {"incoherence", "incoher"}, {"incoherent", "incoher"}, {"income", "incom"},
+		{"incoming", "incom"}, {"incomparable", "incompar"}, {"incompatibilities", "incompat"}, {"incompatibility", "incompat"},
+		{"incompatible", "incompat"}, {"incompetence", "incompet"}, {"incompetent", "incompet"}, {"incomplete", "incomplet"},
+		{"incompletely", "incomplet"}, {"incomprehensible", "incomprehens"}, {"inconceivable", "inconceiv"}, {"inconclusive", "inconclus"},
+		{"incongruity", "incongru"}, {"incongruous", "incongru"}, {"inconsequential", "inconsequenti"}, {"inconsiderable", "inconsider"},
+		{"inconsiderate", "inconsider"}, {"inconsistencies", "inconsist"}, {"inconsistency", "inconsist"}, {"inconsistent", "inconsist"},
+		{"inconsistently", "inconsist"}, {"inconsolable", "inconsol"}, {"inconspicuous", "inconspicu"}, {"incontinence", "incontin"},
+		{"incontinent", "incontin"}, {"incontrovertible", "incontrovert"}, {"inconvenience", "inconveni"}, {"inconvenient", "inconveni"},
+		{"incorporate", "incorpor"}, {"incorporated", "incorpor"}, {"incorporates", "incorpor"}, {"incorporating", "incorpor"},
+		{"incorporation", "incorpor"}, {"incorrect", "incorrect"}, {"incorrectly", "incorrect"}, {"incorrigible", "incorrig"},
+		{"incorruptible", "incorrupt"}, {"increase", "increas"}, {"increased", "increas"}, {"increases", "increas"}, {"increasing", "increas"},
+		{"increasingly", "increas"}, {"incredible", "incred"}, {"incredulity", "incredul"}, {"incredulous", "incredul"},
+		{"increment", "increment"}, {"incremental", "increment"}, {"incrementally", "increment"}, {"incremented", "increment"},
+		{"incrementing", "increment"}, {"increments", "increment"}, {"incriminate", "incrimin"}, {"incubate", "incub"}, {"incubation", "incub"},
+		{"incubator", "incub"}, {"inculcate", "inculc"}, {"incumbent", "incumb"}, {"incur", "incur"}, {"incurable", "incur"},
+		{"incurred", "incur"}, {"incurring", "incur"}, {"incurs", "incur"}, {"incursion", "incurs"}, {"indebted", "indebt"},
+		{"indecency", "indec"}, {"indecent", "indec"}, {"indecipherable", "indecipher"}, {"indecision", "indecis"}, {"indecisive", "indecis"},
+		{"indeed", "inde"}, {"indefatigable", "indefatig"}, {"indefensible", "indefens"}, {"indefinable", "indefin"},
+		{"indefinite", "indefinit"}, {"indefinitely", "indefinit"}, {"indelible", "indel"}, {"indelicate", "indel"}, {"indemnify", "indemnifi"},
+		{"indemnity", "indemn"}, {"indent", "indent"}, {"indentation", "indent"}, {"indentations", "indent"}, {"indented", "indent"},
+		{"indenting", "indent"}, {"indention", "indent"}, {"indents", "indent"}, {"independence", "independ"}, {"independent", "independ"},
+		{"independently", "independ"}, {"indescribable", "indescrib"}, {"indestructible", "indestruct"}, {"indeterminate", "indetermin"},
+		{"index", "index"}, {"indexed", "index"}, {"indexer", "index"}, {"indexes", "index"}, {"indexing", "index"}, {"indic", "indic"},
+		{"indicate", "indic"}, {"indicated", "indic"}, {"indicates", "indic"}, {"indicating", "indic"}, {"indication", "indic"},
+		{"indications", "indic"}, {"indicative", "indic"}, {"indicator", "indic"}, {"indicators", "indic"}, {"indices", "indic"},
+		{"indict", "indict"}, {"indictment", "indict"}, {"indifference", "indiffer"}, {"indifferent", "indiffer"}, {"indigenous", "indigen"},
+		{"indigestion", "indigest"}, {"indignant", "indign"}, {"indignation", "indign"}, {"indignity", "indign"}, {"indigo", "indigo"},
+		{"indirect", "indirect"}, {"indirection", "indirect"}, {"indirectly", "indirect"}, {"indiscernible", "indiscern"},
+		{"indiscreet", "indiscreet"}, {"indiscretion", "indiscret"}, {"indiscriminate", "indiscrimin"}, {"indiscriminately", "indiscrimin"},
+		{"indispensable", "indispens"}, {"indisposed", "indispos"}, {"indisputable", "indisput"}, {"indistinct", "indistinct"},
+		{"indistinguishable", "indistinguish"}, {"individual", "individu"}, {"individualism", "individu"}, {"individualist", "individualist"},
+		{"individuality", "individu"}, {"individually", "individu"}, {"individuals", "individu"}, {"indivisible", "indivis"},
+		{"indoctrinate", "indoctrin"}, {"indoctrination", "indoctrin"}, {"indolence", "indol"}, {"indolent", "indol"},
+		{"indomitable", "indomit"}, {"indoor", "indoor"}, {"indoors", "indoor"}, {"induce", "induc"}, {"induced", "induc"},
+		{"inducement", "induc"}, {"induct", "induct"}, {"induction", "induct"}, {"inductive", "induct"}, {"indulge", "indulg"},
+		{"indulgence", "indulg"}, {"indulgent", "indulg"}, {"industrial", "industri"}, {"industrialism", "industri"},
+		{"industrialist", "industrialist"}, {"industrialize", "industri"}, {"industrious", "industri"}, {"industry", "industri"},
+		{"inebriate", "inebri"}, {"inedible", "ined"}, {"ineffable", "ineff"}, {"ineffective", "ineffect"}, {"ineffectual", "ineffectu"},
+		{"inefficiency", "ineffici"}, {"inefficient", "ineffici"}, {"inefficiently", "ineffici"}, {"inelegant", "ineleg"},
+		{"ineligible", "inelig"}, {"inept", "inept"}, {"ineptitude", "ineptitud"}, {"inequality", "inequ"}, {"inequitable", "inequit"},
+		{"inequity", "inequ"}, {"inert", "inert"}, {"inertia", "inertia"}, {"inescapable", "inescap"}, {"inessential", "inessenti"},
+		{"inestimable", "inestim"}, {"inevitable", "inevit"}, {"inevitably", "inevit"}, {"inexact", "inexact"}, {"inexcusable", "inexcus"},
+		{"inexhaustible", "inexhaust"}, {"inexorable", "inexor"}, {"inexpensive", "inexpens"}, {"inexpensively", "inexpens"},
+		{"inexperience", "inexperi"}, {"inexplicable", "inexplic"}, {"inextricable", "inextric"}, {"infallible", "infal"}, {"infamous", "infam"},
+		{"infamy", "infami"}, {"infancy", "infanc"}, {"infant", "infant"}, {"infantile", "infantil"}, {"infantry", "infantri"},
+		{"infatuate", "infatu"}, {"infatuation", "infatu"}, {"infect", "infect"}, {"infection", "infect"}, {"infectious", "infecti"},
+		{"infer", "infer"}, {"inference", "infer"}, {"inferior", "inferior"}, {"inferiority", "inferior"}, {"inferiors", "inferior"},
+		{"infernal", "infern"}, {"inferno", "inferno"}, {"inferred", "infer"}, {"infers", "infer"}, {"infertile", "infertil"},
+		{"infertility", "infertil"}, {"infest", "infest"}, {"infestation", "infest"}, {"infidel", "infidel"}, {"infidelity", "infidel"},
+		{"infighting", "infight"}, {"infiltrate", "infiltr"}, {"infiltration", "infiltr"}, {"infinite", "infinit"},
+		{"infinitesimal", "infinitesim"}, {"infinities", "infin"}, {"infinitive", "infinit"}, {"infinity", "infin"}, {"infirm", "infirm"},
+		{"infirmary", "infirmari"}, {"infirmity", "infirm"}, {"inflame", "inflam"}, {"inflammable", "inflamm"}, {"inflammation", "inflamm"},
+		{"inflammatory", "inflammatori"}, {"inflatable", "inflat"}, {"inflate", "inflat"}, {"inflated", "inflat"}, {"inflating", "inflat"},
+		{"inflation", "inflat"}, {"inflationary", "inflationari"}, {"inflect", "inflect"}, {"inflection", "inflect"}, {"inflexible", "inflex"},
+		{"inflict", "inflict"}, {"infliction", "inflict"}, {"influence", "influenc"}, {"influenced", "influenc"}, {"influences", "influenc"},
+		{"influencing", "influenc"}, {"influential", "influenti"}, {"influenza", "influenza"}, {"influx", "influx"}, {"inform", "inform"},
+		{"informal", "inform"}, {"informality", "inform"}, {"informally", "inform"}, {"informant", "inform"}, {"information", "inform"},
+		{"informational", "inform"}, {"informations", "inform"}, {"informative", "inform"}, {"informed", "inform"}, {"informer", "inform"},
+		{"informing", "inform"}, {"informs", "inform"}, {"infrared", "infrar"}, {"infrastructure", "infrastructur"},
+		{"infrastructures", "infrastructur"}, {"infrequent", "infrequ"}, {"infrequently", "infrequ"}, {"infringe", "infring"},
+		{"infringed", "infring"}, {"infringement", "infring"}, {"infringements", "infring"}, {"infringes", "infring"}, {"infringing", "infring"},
+		{"infuriate", "infuri"}, {"infuse", "infus"}, {"infusion", "infus"}, {"ingenious", "ingeni"}, {"ingenuity", "ingenu"},
+		{"ingenuous", "ingenu"}, {"ingest", "ingest"}, {"ingested", "ingest"}, {"ingestion", "ingest"}, {"ingot", "ingot"},
+		{"ingrained", "ingrain"}, {"ingratiate", "ingrati"}, {"ingratitude", "ingratitud"}, {"ingredient", "ingredi"}, {"inhabit", "inhabit"},
+		{"inhabitant", "inhabit"}, {"inhale", "inhal"}, {"inhaler", "inhal"}, {"inherent", "inher"}, {"inherently", "inher"},
+		{"inherit", "inherit"}, {"inheritable", "inherit"}, {"inheritance", "inherit"}, {"inherited", "inherit"}, {"inheriting", "inherit"},
+		{"inherits", "inherit"}, {"inhibit", "inhibit"}, {"inhibited", "inhibit"}, {"inhibiting", "inhibit"}, {"inhibition", "inhibit"},
+		{"inhibits", "inhibit"}, {"inhospitable", "inhospit"}, {"inhuman", "inhuman"}, {"inhumane", "inhuman"}, {"inhumanity", "inhuman"},
+		{"inimitable", "inimit"}, {"iniquitous", "iniquit"}, {"iniquity", "iniqu"}, {"initial", "initi"}, {"initialization", "initi"},
+		{"initializations", "initi"}, {"initialize", "initi"}, {"initialized", "initi"}, {"initializer", "initi"}, {"initializers", "initi"},
+		{"initializes", "initi"}, {"initializing", "initi"}, {"initially", "initi"}, {"initials", "initi"}, {"initiate", "initi"},
+		{"initiated", "initi"}, {"initiates", "initi"}, {"initiating", "initi"}, {"initiation", "initi"}, {"initiative", "initi"},
+		{"initiatives", "initi"}, {"initiator", "initi"}, {"initiators", "initi"}, {"inject", "inject"}, {"injected", "inject"},
+		{"injecting", "inject"}, {"injection", "inject"}, {"injects", "inject"}, {"injunction", "injunct"}, {"injure", "injur"},
+		{"injurious", "injuri"}, {"injury", "injuri"}, {"injustice", "injustic"}, {"ink", "ink"}, {"inkling", "inkl"}, {"inland", "inland"},
+		{"inlay", "inlay"}, {"inlet", "inlet"}, {"inmate", "inmat"}, {"inmost", "inmost"}, {"inn", "inn"}, {"innate", "innat"},
+		{"inner", "inner"}, {"innermost", "innermost"}, {"innings", "inning"}, {"innkeeper", "innkeep"}, {"innocence", "innoc"},
+		{"innocent", "innoc"}, {"innocuous", "innocu"}, {"innovate", "innov"}, {"innovation", "innov"}, {"innovative", "innov"},
+		{"innovator", "innov"}, {"innuendo", "innuendo"}, {"innumerable", "innumer"}, {"inoculate", "inocul"}, {"inoculation", "inocul"},
+		{"inoffensive", "inoffens"}, {"inoperable", "inoper"}, {"inopportune", "inopportun"}, {"inordinate", "inordin"},
+		{"inorganic", "inorgan"}, {"input", "input"}, {"inputs", "input"}, {"inputting", "input"}, {"inquest", "inquest"}, {"inquire", "inquir"},
+		{"inquiries", "inquiri"}, {"inquiry", "inquiri"}, {"inquisition", "inquisit"}, {"inquisitive", "inquisit"}, {"inroad", "inroad"},
+		{"ins", "in"}, {"insane", "insan"}, {"insanely", "insan"}, {"insanitary", "insanitari"}, {"insanity", "insan"}, {"insatiable", "insati"},
+		{"inscribe", "inscrib"}, {"inscript", "inscript"}, {"inscription", "inscript"}, {"inscrutable", "inscrut"}, {"insect", "insect"},
+		{"insecticide", "insecticid"}, {"insecure", "insecur"}, {"insecurity", "insecur"}, {"inseminate", "insemin"},
+		{"insemination", "insemin"}, {"insensitive", "insensit"}, {"insensitively", "insensit"}, {"insensitivity", "insensit"},
+		{"inseparable", "insepar"}, {"insert", "insert"}, {"inserted", "insert"}, {"inserting", "insert"}, {"insertion", "insert"},
+		{"insertions", "insert"}, {"inserts", "insert"}, {"inset", "inset"}, {"inshore", "inshor"}, {"inside", "insid"}, {"insider", "insid"},
+		{"insidious", "insidi"}, {"insight", "insight"}, {"insights", "insight"}, {"insignia", "insignia"}, {"insignificance", "insignific"},
+		{"insignificant", "insignific"}, {"insincere", "insincer"}, {"insincerity", "insincer"}, {"insinuate", "insinu"},
+		{"insinuation", "insinu"}, {"insipid", "insipid"}, {"insist", "insist"}, {"insisted", "insist"}, {"insistence", "insist"},
+		{"insistent", "insist"}, {"insists", "insist"}, {"insofar", "insofar"}, {"insole", "insol"}, {"insolence", "insol"},
+		{"insolent", "insol"}, {"insoluble", "insolubl"}, {"insolvency", "insolv"}, {"insolvent", "insolv"}, {"insomnia", "insomnia"},
+		{"insomniac", "insomniac"}, {"inspect", "inspect"}, {"inspected", "inspect"}, {"inspecting", "inspect"}, {"inspection", "inspect"},
+		{"inspector", "inspector"}, {"inspectorate", "inspector"}, {"inspects", "inspect"}, {"inspiration", "inspir"}, {"inspire", "inspir"},
+		{"inspired", "inspir"}, {"instability", "instabl"}, {"install", "instal"}, {"installable", "instal"}, {"installation", "instal"},
+		{"installations", "instal"}, {"installed", "instal"}, {"installer", "instal"}, {"installers", "instal"}, {"installing", "instal"},
+		{"installment", "instal"}, {"installs", "instal"}, {"instalment", "instal"}, {"instance", "instanc"}, {"instances", "instanc"},
+		{"instant", "instant"}, {"instantaneous", "instantan"}, {"instantaneously", "instantan"}, {"instantly", "instant"},
+		{"instants", "instant"}, {"instead", "instead"}, {"instep", "instep"}, {"instigate", "instig"}, {"instigated", "instig"},
+		{"instigation", "instig"}, {"instigator", "instig"}, {"instil", "instil"}, {"instill", "instil"}, {"instinct", "instinct"},
+		{"instinctive", "instinct"}, {"institute", "institut"}, {"institution", "institut"}, {"institutional", "institut"},
+		{"institutions", "institut"}, {"instruct", "instruct"}, {"instructed", "instruct"}, {"instructing", "instruct"},
+		{"instruction", "instruct"}, {"instructions", "instruct"}, {"instructive", "instruct"}, {"instructor", "instructor"},
+		{"instructs", "instruct"}, {"instrument", "instrument"}, {"instrumental", "instrument"}, {"instrumentalist", "instrumentalist"},
+		{"instrumentation", "instrument"}, {"instrumented", "instrument"}, {"insubordinate", "insubordin"}, {"insubordination", "insubordin"},
+		{"insubstantial", "insubstanti"}, {"insufferable", "insuffer"}, {"insufficient", "insuffici"}, {"insufficiently", "insuffici"},
+		{"insular", "insular"}, {"insularity", "insular"}, {"insulate", "insul"}, {"insulation", "insul"}, {"insulator", "insul"},
+		{"insulin", "insulin"}, {"insult", "insult"}, {"insuperable", "insuper"}, {"insurance", "insur"}, {"insure", "insur"},
+		{"insurer", "insur"}, {"insurgency", "insurg"}, {"insurgent", "insurg"}, {"insurmountable", "insurmount"}, {"insurrection", "insurrect"},
+		{"intact", "intact"}, {"intake", "intak"}, {"intangible", "intang"}, {"integ", "integ"}, {"integer", "integ"}, {"integers", "integ"},
+		{"integral", "integr"}, {"integrate", "integr"}, {"integrated", "integr"}, {"integrates", "integr"}, {"integrating", "integr"},
+		{"integration", "integr"}, {"integrations", "integr"}, {"integrator", "integr"}, {"integrators", "integr"}, {"integrity", "integr"},
+		{"intellect", "intellect"}, {"intellectual", "intellectu"}, {"intelligence", "intellig"}, {"intelligent", "intellig"},
+		{"intelligently", "intellig"}, {"intelligentsia", "intelligentsia"}, {"intelligible", "intellig"}, {"intemperate", "intemper"},
+		{"intend", "intend"}, {"intended", "intend"}, {"intending", "intend"}, {"intends", "intend"}, {"intense", "intens"},
+		{"intensify", "intensifi"}, {"intensity", "intens"}, {"intensive", "intens"}, {"intent", "intent"}, {"intention", "intent"},
+		{"intentional", "intent"}, {"intentionally", "intent"}, {"intentioned", "intent"}, {"intentions", "intent"}, {"intents", "intent"},
+		{"inter", "inter"}, {"interact", "interact"}, {"interacting", "interact"}, {"interaction", "interact"}, {"interactions", "interact"},
+		{"interactive", "interact"}, {"interactively", "interact"}, {"interactivity", "interact"}, {"interacts", "interact"},
+		{"interal", "inter"}, {"intercede", "interced"}, {"intercept", "intercept"}, {"intercepted", "intercept"}, {"intercepting", "intercept"},
+		{"interception", "intercept"}, {"intercepts", "intercept"}, {"interchange", "interchang"}, {"interchangeable", "interchang"},
+		{"interchangeably", "interchang"}, {"interchanged", "interchang"}, {"intercom", "intercom"}, {"interconnect", "interconnect"},
+		{"interconnected", "interconnect"}, {"interconnection", "interconnect"}, {"interconnects", "interconnect"},
+		{"intercontinental", "intercontinent"}, {"intercourse", "intercours"}, {"interdependence", "interdepend"},
+		{"interdependencies", "interdepend"}, {"interdependent", "interdepend"}, {"interest", "interest"}, {"interested", "interest"},
+		{"interesting", "interest"}, {"interests", "interest"}, {"interface", "interfac"}, {"interfaces", "interfac"},
+		{"interfacing", "interfac"}, {"interfere", "interfer"}, {"interference", "interfer"}, {"interferences", "interfer"},
+		{"interferes", "interfer"}, {"interim", "interim"}, {"interior", "interior"}, {"interject", "interject"}, {"interjection", "interject"},
+		{"interlock", "interlock"}, {"interloper", "interlop"}, {"interlude", "interlud"}, {"intermarry", "intermarri"},
+		{"intermediary", "intermediari"}, {"intermediate", "intermedi"}, {"intermediates", "intermedi"}, {"interminable", "intermin"},
+		{"intermission", "intermiss"}, {"intermittent", "intermitt"}, {"intern", "intern"}, {"internal", "intern"}, {"internalize", "intern"},
+		{"internally", "intern"}, {"internals", "intern"}, {"international", "intern"}, {"internationalism", "intern"},
+		{"internationally", "intern"}, {"interned", "intern"}, {"internet", "internet"}, {"internment", "intern"}, {"interplay", "interplay"},
+		{"interpolate", "interpol"}, {"interpolated", "interpol"}, {"interpolates", "interpol"}, {"interpolating", "interpol"},
+		{"interpolation", "interpol"}, {"interpolations", "interpol"}, {"interpose", "interpos"}, {"interposed", "interpos"},
+		{"interpret", "interpret"}, {"interpretable", "interpret"}, {"interpretation", "interpret"}, {"interpretations", "interpret"},
+		{"interpretative", "interpret"}, {"interpreted", "interpret"}, {"interpreter", "interpret"}, {"interpreters", "interpret"},
+		{"interpreting", "interpret"}, {"interprets", "interpret"}, {"interracial", "interraci"}, {"interrogate", "interrog"},
+		{"interrogated", "interrog"}, {"interrogation", "interrog"}, {"interrogative", "interrog"}, {"interrogator", "interrog"},
+		{"interrupt", "interrupt"}, {"interrupted", "interrupt"}, {"interruptible", "interrupt"}, {"interrupting", "interrupt"},
+		{"interruption", "interrupt"}, {"interruptions", "interrupt"}, {"interrupts", "interrupt"}, {"intersect", "intersect"},
+		{"intersection", "intersect"}, {"intersperse", "interspers"}, {"interspersed", "interspers"}, {"interstate", "interst"},
+		{"intertwine", "intertwin"}, {"interval", "interv"}, {"intervals", "interv"}, {"intervene", "interven"}, {"intervening", "interven"},
+		{"intervention", "intervent"}, {"interview", "interview"}, {"interviewee", "interviewe"}, {"interviewer", "interview"},
+		{"interweave", "interweav"}, {"intestate", "intest"}, {"intestinal", "intestin"}, {"intestine", "intestin"}, {"intimacy", "intimaci"},
+		{"intimate", "intim"}, {"intimation", "intim"}, {"intimidate", "intimid"}, {"intimidation", "intimid"}, {"into", "into"},
+		{"intolerable", "intoler"}, {"intolerance", "intoler"}, {"intolerant", "intoler"}, {"intonation", "inton"}, {"intoxicant", "intoxic"},
+		{"intoxicate", "intox"}, {"intoxication", "intox"}, {"intractable", "intract"}, {"intransigence", "intransig"},
+		{"intransigent", "intransig"}, {"intransitive", "intransit"}, {"intravenous", "intraven"}, {"intrepid", "intrepid"},
+		{"intricacy", "intricaci"}, {"intricate", "intric"}, {"intrigue", "intrigu"}, {"intrinsic", "intrins"}, {"intrinsics", "intrins"},
+		{"introduce", "introduc"}, {"introduced", "introduc"}, {"introduces", "introduc"}, {"introducing", "introduc"},
+		{"introduction", "introduct"}, {"introductory", "introductori"}, {"introspect", "introspect"}, {"introspectable", "introspect"},
+		{"introspected", "introspect"}, {"introspecting", "introspect"}, {"introspection", "introspect"}, {"introspective", "introspect"},
+		{"introvert", "introvert"}, {"intrude", "intrud"}, {"intruder", "intrud"}, {"intrusion", "intrus"}, {"intrusive", "intrus"},
+		{"intuit", "intuit"}, {"intuition", "intuit"}, {"intuitive", "intuit"}, {"intuitively", "intuit"}, {"inundate", "inund"},
+		{"inundation", "inund"}, {"inure", "inur"}, {"invade", "invad"}, {"invader", "invad"}, {"invalid", "invalid"}, {"invalidate", "invalid"},
+		{"invalidated", "invalid"}, {"invalidates", "invalid"}, {"invalidating", "invalid"}, {"invalidation", "invalid"},
+		{"invalidations", "invalid"}, {"invaluable", "invalu"}, {"invariable", "invari"}, {"invariant", "invari"}, {"invariants", "invari"},
+		{"invasion", "invas"}, {"invasive", "invas"}, {"invective", "invect"}, {"inveigle", "inveigl"}, {"invent", "invent"},
+		{"invented", "invent"}, {"inventing", "invent"}, {"invention", "invent"}, {"inventions", "invent"}, {"inventive", "invent"},
+		{"inventor", "inventor"}, {"inventories", "inventori"}, {"inventory", "inventori"}, {"inverse", "invers"}, {"inversed", "invers"},
+		{"inversely", "invers"}, {"inverses", "invers"}, {"inversion", "invers"}, {"invert", "invert"}, {"invertebrate", "invertebr"},
+		{"inverted", "invert"}, {"inverting", "invert"}, {"inverts", "invert"}, {"invest", "invest"}, {"investigate", "investig"},
+		{"investigated", "investig"}, {"investigates", "investig"}, {"investigating", "investig"}, {"investigation", "investig"},
+		{"investigations", "investig"}, {"investigative", "investig"}, {"investigator", "investig"}, {"investiture", "investitur"},
+		{"investment", "invest"}, {"investor", "investor"}, {"inveterate", "inveter"}, {"invidious", "invidi"}, {"invigilate", "invigil"},
+		{"invigorate", "invigor"}, {"invincible", "invinc"}, {"inviolable", "inviol"}, {"inviolate", "inviol"}, {"invisible", "invis"},
+		{"invitation", "invit"}, {"invite", "invit"}, {"invited", "invit"}, {"inviting", "invit"}, {"invocation", "invoc"},
+		{"invocations", "invoc"}, {"invoice", "invoic"}, {"invokable", "invok"}, {"invoke", "invok"}, {"invoked", "invok"}, {"invoker", "invok"},
+		{"invokes", "invok"}, {"invoking", "invok"}, {"involuntary", "involuntari"}, {"involve", "involv"}, {"involved", "involv"},
+		{"involvement", "involv"}, {"involves", "involv"}, {"involving", "involv"}, {"invulnerable", "invulner"}, {"inward", "inward"},
+		{"inwards", "inward"}, {"iodine", "iodin"}, {"ion", "ion"}, {"iota", "iota"}, {"irate", "irat"}, {"ire", "ire"}, {"iri", "iri"},
+		{"iridescent", "iridesc"}, {"iris", "iri"}, {"irk", "irk"}, {"iron", "iron"}, {"ironic", "iron"}, {"ironical", "iron"},
+		{"ironically", "iron"}, {"irony", "ironi"}, {"irradiate", "irradi"}, {"irrational", "irrat"}, {"irreconcilable", "irreconcil"},
+		{"irrefutable", "irrefut"}, {"irregular", "irregular"}, {"irregularity", "irregular"}, {"irrelevance", "irrelev"},
+		{"irrelevant", "irrelev"}, {"irreparable", "irrepar"}, {"irreplaceable", "irreplac"}, {"irrepressible", "irrepress"},
+		{"irreproachable", "irreproach"}, {"irresistible", "irresist"}, {"irrespective", "irrespect"}, {"irresponsible", "irrespons"},
+		{"irretrievable", "irretriev"}, {"irreverent", "irrever"}, {"irreversible", "irrevers"}, {"irreversibly", "irrevers"},
+		{"irrevocable", "irrevoc"}, {"irrigate", "irrig"}, {"irrigation", "irrig"}, {"irritable", "irrit"}, {"irritant", "irrit"},
+		{"irritate", "irrit"}, {"irritating", "irrit"}, {"irritation", "irrit"}, {"is", "is"}, {"isl", "isl"}, {"island", "island"},
+		{"islander", "island"}, {"islands", "island"}, {"isle", "isl"}, {"isolate", "isol"}, {"isolated", "isol"}, {"isolates", "isol"},
+		{"isolating", "isol"}, {"isolation", "isol"}, {"isotope", "isotop"}, {"issue", "issu"}, {"issued", "issu"}, {"issues", "issu"},
+		{"issuing", "issu"}, {"isthmus", "isthmus"}, {"it", "it"}, {"italic", "ital"}, {"italics", "ital"}, {"itch", "itch"}, {"itchy", "itchi"},
+		{"item", "item"}, {"itemize", "item"}, {"items", "item"}, {"itinerant", "itiner"}, {"itinerary", "itinerari"}, {"its", "it"},
+		{"ivory", "ivori"}, {"ivy", "ivi"}, {"jab", "jab"}, {"jabber", "jabber"}, {"jack", "jack"}, {"jackal", "jackal"}, {"jackdaw", "jackdaw"},
+		{"jacket", "jacket"}, {"jackknife", "jackknif"}, {"jackpot", "jackpot"}, {"jade", "jade"}, {"jaded", "jade"}, {"jagged", "jag"},
+		{"jaguar", "jaguar"}, {"jail", "jail"}, {"jailer", "jailer"}, {"jails", "jail"}, {"jam", "jam"}, {"jamb", "jamb"},
+		{"jamboree", "jambore"}, {"jangle", "jangl"}, {"janitor", "janitor"}, {"january", "januari"}, {"jar", "jar"}, {"jargon", "jargon"},
+		{"jars", "jar"}, {"jasmine", "jasmin"}, {"jaundice", "jaundic"}, {"jaunt", "jaunt"}, {"jaunty", "jaunti"}, {"javelin", "javelin"},
+		{"jaw", "jaw"}, {"jawbone", "jawbon"}, {"jay", "jay"}, {"jazz", "jazz"}, {"jazzy", "jazzi"}, {"jealous", "jealous"},
+		{"jealousy", "jealousi"}, {"jean", "jean"}, {"jeans", "jean"}, {"jeep", "jeep"}, {"jeer", "jeer"}, {"jelly", "jelli"},
+		{"jellyfish", "jellyfish"}, {"jeopardize", "jeopard"}, {"jeopardy", "jeopardi"}, {"jerk", "jerk"}, {"jerky", "jerki"},
+		{"jersey", "jersey"}, {"jest", "jest"}, {"jester", "jester"}, {"jet", "jet"}, {"jetty", "jetti"}, {"jewel", "jewel"},
+		{"jeweler", "jewel"}, {"jeweller", "jewel"}, {"jewellery", "jewelleri"}, {"jewelry", "jewelri"}, {"jib", "jib"}, {"jibe", "jibe"},
+		{"jig", "jig"}, {"jiggle", "jiggl"}, {"jigsaw", "jigsaw"}, {"jilt", "jilt"}, {"jingle", "jingl"}, {"jinx", "jinx"}, {"jitter", "jitter"},
+		{"jitters", "jitter"}, {"job", "job"}, {"jobless", "jobless"}, {"jobs", "job"}, {"jockey", "jockey"}, {"jocular", "jocular"},
+		{"jog", "jog"}, {"jogger", "jogger"}, {"join", "join"}, {"joined", "join"}, {"joiner", "joiner"}, {"joinery", "joineri"},
+		{"joining", "join"}, {"joins", "join"}, {"joint", "joint"}, {"jointly", "joint"}, {"joist", "joist"}, {"joke", "joke"},
+		{"joker", "joker"}, {"jolly", "jolli"}, {"jolt", "jolt"}, {"jostle", "jostl"}, {"jot", "jot"}, {"journal", "journal"},
+		{"journaled", "journal"}, {"journaling", "journal"}, {"journalism", "journal"}, {"journalist", "journalist"}, {"journals", "journal"},
+		{"journey", "journey"}, {"jovial", "jovial"}, {"jowl", "jowl"}, {"joy", "joy"}, {"joyful", "joy"}, {"joyous", "joyous"},
+		{"joystick", "joystick"}, {"jubilant", "jubil"}, {"jubilation", "jubil"}, {"jubilee", "jubile"}, {"judge", "judg"}, {"judged", "judg"},
+		{"judgement", "judgement"}, {"judgment", "judgment"}, {"judicial", "judici"}, {"judiciary", "judiciari"}, {"judicious", "judici"},
+		{"judo", "judo"}, {"jug", "jug"}, {"juggernaut", "juggernaut"}, {"juggle", "juggl"}, {"juggler", "juggler"}, {"jugular", "jugular"},
+		{"juice", "juic"}, {"juicy", "juici"}, {"juli", "juli"}, {"julie", "juli"}, {"july", "juli"}, {"jumble", "jumbl"}, {"jumbo", "jumbo"},
+		{"jump", "jump"}, {"jumped", "jump"}, {"jumper", "jumper"}, {"jumping", "jump"}, {"jumps", "jump"}, {"jumpy", "jumpi"},
+		{"junction", "junction"}, {"junctions", "junction"}, {"juncture", "junctur"}, {"june", "june"}, {"jungle", "jungl"},
+		{"junior", "junior"}, {"juniper", "junip"}, {"junk", "junk"}, {"junkie", "junki"}, {"junta", "junta"}, {"juridical", "jurid"},
+		{"jurisdiction", "jurisdict"}, {"jurisdictions", "jurisdict"}, {"jurisprudence", "jurisprud"}, {"jurist", "jurist"}, {"juror", "juror"},
+		{"jury", "juri"}, {"just", "just"}, {"justice", "justic"}, {"justifiable", "justifi"}, {"justification", "justif"},
+		{"justifications", "justif"}, {"justified", "justifi"}, {"justify", "justifi"}, {"jut", "jut"}, {"jute", "jute"},
+		{"juvenile", "juvenil"}, {"juxtapose", "juxtapos"}, {"juxtaposition", "juxtaposit"}, {"kaleidoscope", "kaleidoscop"},
+		{"kangaroo", "kangaroo"}, {"karaoke", "karaok"}, {"karate", "karat"}, {"kayak", "kayak"}, {"kebab", "kebab"}, {"keel", "keel"},
+		{"keen", "keen"}, {"keep", "keep"}, {"keeper", "keeper"}, {"keeping", "keep"}, {"keeps", "keep"}, {"keepsake", "keepsak"},
+		{"keg", "keg"}, {"kennel", "kennel"}, {"kerb", "kerb"}, {"kerbstone", "kerbston"}, {"kernel", "kernel"}, {"kernels", "kernel"},
+		{"kerosene", "kerosen"}, {"kestrel", "kestrel"}, {"ketchup", "ketchup"}, {"kettle", "kettl"}, {"key", "key"}, {"keyboard", "keyboard"},
+		{"keyboards", "keyboard"}, {"keyed", "key"}, {"keyhole", "keyhol"}, {"keying", "key"}, {"keynote", "keynot"}, {"keys", "key"},
+		{"keystone", "keyston"}, {"khaki", "khaki"}, {"kick", "kick"}, {"kickback", "kickback"}, {"kicked", "kick"}, {"kicking", "kick"},
+		{"kickoff", "kickoff"}, {"kicks", "kick"}, {"kid", "kid"}, {"kidnap", "kidnap"}, {"kidnapper", "kidnapp"}, {"kidney", "kidney"},
+		{"kill", "kill"}, {"killed", "kill"}, {"killer", "killer"}, {"killers", "killer"}, {"killing", "kill"}, {"killjoy", "killjoy"},
+		{"kills", "kill"}, {"kiln", "kiln"}, {"kilo", "kilo"}, {"kilobyte", "kilobyt"}, {"kilobytes", "kilobyt"}, {"kilogram", "kilogram"},
+		{"kilogramme", "kilogramm"}, {"kilometer", "kilomet"}, {"kilometre", "kilometr"}, {"kilowatt", "kilowatt"}, {"kilt", "kilt"},
+		{"kin", "kin"}, {"kind", "kind"}, {"kindergarten", "kindergarten"}, {"kindle", "kindl"}, {"kindly", "kind"}, {"kindness", "kind"},
+		{"kindred", "kindr"}, {"kinds", "kind"}, {"kinetic", "kinet"}, {"king", "king"}, {"kingdom", "kingdom"}, {"kingfisher", "kingfish"},
+		{"kingly", "king"}, {"kingpin", "kingpin"}, {"kink", "kink"}, {"kinky", "kinki"}, {"kinship", "kinship"}, {"kiosk", "kiosk"},
+		{"kip", "kip"}, {"kipper", "kipper"}, {"kiss", "kiss"}, {"kit", "kit"}, {"kitchen", "kitchen"}, {"kite", "kite"}, {"kitten", "kitten"},
+		{"kitty", "kitti"}, {"kiwi", "kiwi"}, {"knack", "knack"}, {"knapsack", "knapsack"}, {"knave", "knave"}, {"knead", "knead"},
+		{"knee", "knee"}, {"kneecap", "kneecap"}, {"kneel", "kneel"}, {"knell", "knell"}, {"knickers", "knicker"}, {"knife", "knife"},
+		{"knight", "knight"}, {"knighthood", "knighthood"}, {"knights", "knight"}, {"knit", "knit"}, {"knitwear", "knitwear"}, {"knob", "knob"},
+		{"knobs", "knob"}, {"knock", "knock"}, {"knocker", "knocker"}, {"knockout", "knockout"}, {"knoll", "knoll"}, {"knot", "knot"},
+		{"knotty", "knotti"}, {"know", "know"}, {"knowable", "knowabl"}, {"knowing", "know"}, {"knowingly", "know"}, {"knowledge", "knowledg"},
+		{"knowledgeable", "knowledg"}, {"knows", "know"}, {"knuckle", "knuckl"}, {"koala", "koala"}, {"kosher", "kosher"}, {"kudos", "kudo"},
+		{"lab", "lab"}, {"label", "label"}, {"labeled", "label"}, {"labeler", "label"}, {"labeling", "label"}, {"labelling", "label"},
+		{"labels", "label"}, {"labor", "labor"}, {"laboratory", "laboratori"}, {"labore", "labor"}, {"laborer", "labor"},
+		{"laborious", "labori"}, {"labour", "labour"}, {"labourer", "labour"}, {"labs", "lab"}, {"labyrinth", "labyrinth"}, {"lace", "lace"},
+		{"lacerate", "lacer"}, {"laceration", "lacer"}, {"lack", "lack"}, {"lacked", "lack"}, {"lackey", "lackey"}, {"lacking", "lack"},
+		{"lackluster", "lacklust"}, {"lacklustre", "lacklustr"}, {"lacks", "lack"}, {"laconic", "lacon"}, {"lacquer", "lacquer"},
+		{"lacrosse", "lacross"}, {"lactate", "lactat"}, {"lactation", "lactat"}, {"lad", "lad"}, {"ladder", "ladder"}, {"laden", "laden"},
+		{"ladle", "ladl"}, {"lady", "ladi"}, {"ladybird", "ladybird"}, {"lag", "lag"}, {"lager", "lager"}, {"lagoon", "lagoon"},
+		{"lair", "lair"}, {"laity", "laiti"}, {"lake", "lake"}, {"lakes", "lake"}, {"lamb", "lamb"}, {"lame", "lame"}, {"lamed", "lame"},
+		{"lamely", "lame"}, {"lament", "lament"}, {"lamentable", "lament"}, {"lamentation", "lament"}, {"laminate", "lamin"}, {"lamp", "lamp"},
+		{"lampoon", "lampoon"}, {"lamppost", "lamppost"}, {"lampshade", "lampshad"}, {"lance", "lanc"}, {"land", "land"}, {"landed", "land"},
+		{"landfall", "landfal"}, {"landfill", "landfil"}, {"landing", "land"}, {"landlady", "landladi"}, {"landlock", "landlock"},
+		{"landlocked", "landlock"}, {"landlord", "landlord"}, {"landmark", "landmark"}, {"landmarks", "landmark"}, {"landmass", "landmass"},
+		{"landowner", "landown"}, {"lands", "land"}, {"landscape", "landscap"}, {"landslide", "landslid"}, {"lane", "lane"},
+		{"language", "languag"}, {"languages", "languag"}, {"languid", "languid"}, {"languish", "languish"}, {"lank", "lank"},
+		{"lanky", "lanki"}, {"lantern", "lantern"}, {"lap", "lap"}, {"lapel", "lapel"}, {"lapse", "laps"}, {"laptop", "laptop"},
+		{"laptops", "laptop"}, {"larceny", "larceni"}, {"larch", "larch"}, {"lard", "lard"}, {"larder", "larder"}, {"large", "larg"},
+		{"largely", "larg"}, {"largesse", "largess"}, {"lark", "lark"}, {"larva", "larva"}, {"larynx", "larynx"}, {"lascivious", "lascivi"},
+		{"laser", "laser"}, {"lash", "lash"}, {"lass", "lass"}, {"lasso", "lasso"}, {"last", "last"}, {"lasting", "last"}, {"lastly", "last"},
+		{"lasts", "last"}, {"latch", "latch"}, {"late", "late"}, {"latecomer", "latecom"}, {"latencies", "latenc"}, {"latency", "latenc"},
+		{"latent", "latent"}, {"later", "later"}, {"lateral", "later"}, {"latex", "latex"}, {"lath", "lath"}, {"lathe", "lath"},
+		{"lather", "lather"}, {"latitude", "latitud"}, {"latrine", "latrin"}, {"latter", "latter"}, {"lattice", "lattic"}, {"laud", "laud"},
+		{"laudable", "laudabl"}, {"laugh", "laugh"}, {"laughable", "laughabl"}, {"laughter", "laughter"}, {"launch", "launch"},
+		{"launched", "launch"}, {"launches", "launch"}, {"launching", "launch"}, {"launder", "launder"}, {"launderette", "launderett"},
+		{"laundry", "laundri"}, {"laureate", "laureat"}, {"laurel", "laurel"}, {"lava", "lava"}, {"lavatory", "lavatori"},
+		{"lavender", "lavend"}, {"lavish", "lavish"}, {"law", "law"}, {"lawful", "law"}, {"lawless", "lawless"}, {"lawn", "lawn"},
+		{"lawnmower", "lawnmow"}, {"laws", "law"}, {"lawsuit", "lawsuit"}, {"lawyer", "lawyer"}, {"lax", "lax"}, {"laxative", "laxat"},
+		{"laxity", "laxiti"}, {"lay", "lay"}, {"layabout", "layabout"}, {"layer", "layer"}, {"layered", "layer"}, {"layers", "layer"},
+		{"laying", "lay"}, {"layman", "layman"}, {"layout", "layout"}, {"layouts", "layout"}, {"lays", "lay"}, {"laze", "laze"},
+		{"laziness", "lazi"}, {"lazy", "lazi"}, {"lead", "lead"}, {"leaden", "leaden"}, {"leader", "leader"}, {"leaders", "leader"},
+		{"leadership", "leadership"}, {"leading", "lead"}, {"leads", "lead"}, {"leaf", "leaf"}, {"leaflet", "leaflet"}, {"leafs", "leaf"},
+		{"leafy", "leafi"}, {"league", "leagu"}, {"leak", "leak"}, {"leakage", "leakag"}, {"leaked", "leak"}, {"leaking", "leak"},
+		{"leaks", "leak"}, {"leaky", "leaki"}, {"lean", "lean"}, {"leaning", "lean"}, {"leap", "leap"}, {"leaps", "leap"}, {"learn", "learn"},
+		{"learned", "learn"}, {"learner", "learner"}, {"learning", "learn"}, {"learns", "learn"}, {"lease", "leas"}, {"leased", "leas"},
+		{"leases", "leas"}, {"leash", "leash"}, {"least", "least"}, {"leather", "leather"}, {"leathery", "leatheri"}, {"leave", "leav"},
+		{"leaves", "leav"}, {"leaving", "leav"}, {"lecher", "lecher"}, {"lecherous", "lecher"}, {"lectern", "lectern"}, {"lecture", "lectur"},
+		{"lecturer", "lectur"}, {"lecturing", "lectur"}, {"ledge", "ledg"}, {"ledger", "ledger"}, {"lee", "lee"}, {"leech", "leech"},
+		{"leek", "leek"}, {"leer", "leer"}, {"leeway", "leeway"}, {"left", "left"}, {"leftness", "left"}, {"leftover", "leftov"},
+		{"leftovers", "leftov"}, {"leg", "leg"}, {"legacy", "legaci"}, {"legal", "legal"}, {"legality", "legal"}, {"legalize", "legal"},
+		{"legalizer", "legal"}, {"legally", "legal"}, {"legation", "legat"}, {"legend", "legend"}, {"legendary", "legendari"},
+		{"legible", "legibl"}, {"legibly", "legibl"}, {"legion", "legion"}, {"legislate", "legisl"}, {"legislation", "legisl"},
+		{"legislative", "legisl"}, {"legislator", "legisl"}, {"legislature", "legislatur"}, {"legitimacy", "legitimaci"},
+		{"legitimate", "legitim"}, {"legitimately", "legitim"}, {"legitimize", "legitim"}, {"leisure", "leisur"}, {"leisurely", "leisur"},
+		{"lemon", "lemon"}, {"lemonade", "lemonad"}, {"len", "len"}, {"lend", "lend"}, {"lender", "lender"}, {"length", "length"},
+		{"lengthen", "lengthen"}, {"lengthened", "lengthen"}, {"lengthening", "lengthen"}, {"lengths", "length"}, {"lengthy", "lengthi"},
+		{"lenience", "lenienc"}, {"leniency", "lenienc"}, {"lenient", "lenient"}, {"lens", "len"}, {"lentil", "lentil"}, {"leopard", "leopard"},
+		{"leotard", "leotard"}, {"leper", "leper"}, {"leprosy", "leprosi"}, {"lesbian", "lesbian"}, {"lesion", "lesion"}, {"less", "less"},
+		{"lessee", "lesse"}, {"lessen", "lessen"}, {"lesser", "lesser"}, {"lesson", "lesson"}, {"lessor", "lessor"}, {"lest", "lest"},
+		{"let", "let"}, {"lethal", "lethal"}, {"lethargic", "letharg"}, {"lethargy", "lethargi"}, {"lets", "let"}, {"letter", "letter"},
+		{"letterbox", "letterbox"}, {"letters", "letter"}, {"letting", "let"}, {"lettuce", "lettuc"}, {"leukaemia", "leukaemia"},
+		{"leukemia", "leukemia"}, {"levee", "leve"}, {"level", "level"}, {"levels", "level"}, {"lever", "lever"}, {"leverage", "leverag"},
+		{"leverages", "leverag"}, {"leveraging", "leverag"}, {"levi", "levi"}, {"levitate", "levit"}, {"levity", "leviti"}, {"levy", "levi"},
+		{"lewd", "lewd"}, {"lexical", "lexic"}, {"lexically", "lexic"}, {"lexicon", "lexicon"}, {"liability", "liabil"}, {"liable", "liabl"},
+		{"liaise", "liais"}, {"liaison", "liaison"}, {"liar", "liar"}, {"libel", "libel"}, {"libellous", "libel"}, {"libelous", "libel"},
+		{"liberal", "liber"}, {"liberalism", "liber"}, {"liberalize", "liber"}, {"liberate", "liber"}, {"liberation", "liber"},
+		{"liberator", "liber"}, {"libertine", "libertin"}, {"liberty", "liberti"}, {"libido", "libido"}, {"librarian", "librarian"},
+		{"libraries", "librari"}, {"library", "librari"}, {"licence", "licenc"}, {"licensable", "licens"}, {"license", "licens"},
+		{"licensed", "licens"}, {"licensee", "license"}, {"licensees", "license"}, {"licenses", "licens"}, {"licensing", "licens"},
+		{"licentious", "licenti"}, {"lichen", "lichen"}, {"lick", "lick"}, {"lid", "lid"}, {"lido", "lido"}, {"lie", "lie"}, {"lies", "lie"},
+		{"lieu", "lieu"}, {"lieutenant", "lieuten"}, {"life", "life"}, {"lifeblood", "lifeblood"}, {"lifeboat", "lifeboat"},
+		{"lifeguard", "lifeguard"}, {"lifeless", "lifeless"}, {"lifelike", "lifelik"}, {"lifeline", "lifelin"}, {"lifelong", "lifelong"},
+		{"lifestyle", "lifestyl"}, {"lifetime", "lifetim"}, {"lifetimes", "lifetim"}, {"lift", "lift"}, {"lifted", "lift"}, {"lifting", "lift"},
+		{"lifts", "lift"}, {"ligament", "ligament"}, {"light", "light"}, {"lighten", "lighten"}, {"lighter", "lighter"},
+		{"lighthouse", "lighthous"}, {"lightly", "light"}, {"lightness", "light"}, {"lightning", "lightn"}, {"lights", "light"},
+		{"likable", "likabl"}, {"like", "like"}, {"likeable", "likeabl"}, {"likelihood", "likelihood"}, {"likely", "like"}, {"liken", "liken"},
+		{"likeness", "like"}, {"likes", "like"}, {"likewise", "likewis"}, {"liking", "like"}, {"lilac", "lilac"}, {"lilt", "lilt"},
+		{"lily", "lili"}, {"limb", "limb"}, {"limber", "limber"}, {"limbo", "limbo"}, {"lime", "lime"}, {"limelight", "limelight"},
+		{"limerick", "limerick"}, {"limestone", "limeston"}, {"limit", "limit"}, {"limitation", "limit"}, {"limitations", "limit"},
+		{"limited", "limit"}, {"limiter", "limit"}, {"limiters", "limit"}, {"limiting", "limit"}, {"limits", "limit"}, {"limousine", "limousin"},
+		{"limp", "limp"}, {"limpid", "limpid"}, {"line", "line"}, {"lineage", "lineag"}, {"linear", "linear"}, {"linearly", "linear"},
+		{"linen", "linen"}, {"liner", "liner"}, {"liners", "liner"}, {"lines", "line"}, {"linesman", "linesman"}, {"linger", "linger"},
+		{"lingerie", "lingeri"}, {"lingering", "linger"}, {"lingo", "lingo"}, {"linguist", "linguist"}, {"linguistic", "linguist"},
+		{"linguistics", "linguist"}, {"liniment", "liniment"}, {"lining", "line"}, {"link", "link"}, {"linkage", "linkag"}, {"linked", "link"},
+		{"linking", "link"}, {"links", "link"}, {"lino", "lino"}, {"linoleum", "linoleum"}, {"lintel", "lintel"}, {"lion", "lion"},
+		{"lioness", "lioness"}, {"lip", "lip"}, {"lipstick", "lipstick"}, {"liquefy", "liquefi"}, {"liqueur", "liqueur"}, {"liquid", "liquid"},
+		{"liquidate", "liquid"}, {"liquidation", "liquid"}, {"liquidity", "liquid"}, {"liquor", "liquor"}, {"lisp", "lisp"}, {"list", "list"},
+		{"listed", "list"}, {"listen", "listen"}, {"listened", "listen"}, {"listener", "listen"}, {"listeners", "listen"},
+		{"listening", "listen"}, {"listens", "listen"}, {"listing", "list"}, {"listings", "list"}, {"listless", "listless"}, {"lists", "list"},
+		{"litany", "litani"}, {"liter", "liter"}, {"literacy", "literaci"}, {"literal", "liter"}, {"literalization", "liter"},
+		{"literally", "liter"}, {"literals", "liter"}, {"literary", "literari"}, {"literate", "liter"}, {"literature", "literatur"},
+		{"lithe", "lith"}, {"lithograph", "lithograph"}, {"litigant", "litig"}, {"litigate", "litig"}, {"litigation", "litig"},
+		{"litmus", "litmus"}, {"litre", "litr"}, {"litter", "litter"}, {"little", "littl"}, {"liturgical", "liturg"}, {"liturgy", "liturgi"},
+		{"livable", "livabl"}, {"live", "live"}, {"lived", "live"}, {"livelihood", "livelihood"}, {"lively", "live"}, {"liven", "liven"},
+		{"liveness", "live"}, {"liver", "liver"}, {"livery", "liveri"}, {"lives", "live"}, {"livestock", "livestock"}, {"livid", "livid"},
+		{"living", "live"}, {"lizard", "lizard"}, {"llama", "llama"}, {"load", "load"}, {"loaded", "load"}, {"loading", "load"},
+		{"loads", "load"}, {"loaf", "loaf"}, {"loam", "loam"}, {"loan", "loan"}, {"loath", "loath"}, {"loathe", "loath"},
+		{"loathsome", "loathsom"}, {"lob", "lob"}, {"lobby", "lobbi"}, {"lobbyist", "lobbyist"}, {"lobe", "lobe"}, {"lobster", "lobster"},
+		{"local", "local"}, {"locale", "local"}, {"localed", "local"}, {"locales", "local"}, {"locality", "local"}, {"localization", "local"},
+		{"localize", "local"}, {"localized", "local"}, {"localizing", "local"}, {"locally", "local"}, {"locals", "local"}, {"locate", "locat"},
+		{"located", "locat"}, {"locates", "locat"}, {"locating", "locat"}, {"location", "locat"}, {"locations", "locat"}, {"locator", "locat"},
+		{"locators", "locat"}, {"loch", "loch"}, {"lock", "lock"}, {"locke", "lock"}, {"locked", "lock"}, {"locker", "locker"},
+		{"locket", "locket"}, {"locking", "lock"}, {"locks", "lock"}, {"locksmith", "locksmith"}, {"locomotion", "locomot"},
+		{"locomotive", "locomot"}, {"locust", "locust"}, {"lodge", "lodg"}, {"lodger", "lodger"}, {"lodging", "lodg"}, {"loft", "loft"},
+		{"lofty", "lofti"}, {"log", "log"}, {"logarithm", "logarithm"}, {"logarithmic", "logarithm"}, {"logarithms", "logarithm"},
+		{"logbook", "logbook"}, {"logged", "log"}, {"logging", "log"}, {"logic", "logic"}, {"logical", "logic"}, {"logically", "logic"},
+		{"logistic", "logist"}, {"logistics", "logist"}, {"logo", "logo"}, {"logos", "logo"}, {"logs", "log"}, {"loin", "loin"},
+		{"loiter", "loiter"}, {"loll", "loll"}, {"lollipop", "lollipop"}, {"lolly", "lolli"}, {"lone", "lone"}, {"lonely", "lone"},
+		{"loner", "loner"}, {"lonesome", "lonesom"}, {"long", "long"}, {"longevity", "longev"}, {"longhand", "longhand"}, {"longing", "long"},
+		{"longitude", "longitud"}, {"longitudinal", "longitudin"}, {"longs", "long"}, {"loo", "loo"}, {"look", "look"}, {"looked", "look"},
+		{"looking", "look"}, {"lookout", "lookout"}, {"looks", "look"}, {"loom", "loom"}, {"loony", "looni"}, {"loop", "loop"},
+		{"looped", "loop"}, {"loophole", "loophol"}, {"looping", "loop"}, {"loops", "loop"}, {"loose", "loos"}, {"loosely", "loos"},
+		{"loosen", "loosen"}, {"loosened", "loosen"}, {"loosening", "loosen"}, {"loosens", "loosen"}, {"loot", "loot"}, {"lop", "lop"},
+		{"lope", "lope"}, {"lopsided", "lopsid"}, {"loquacious", "loquaci"}, {"lord", "lord"}, {"lordly", "lord"}, {"lordship", "lordship"},
+		{"lore", "lore"}, {"loring", "lore"}, {"lorry", "lorri"}, {"lose", "lose"}, {"loser", "loser"}, {"loses", "lose"}, {"losing", "lose"},
+		{"loss", "loss"}, {"losses", "loss"}, {"lost", "lost"}, {"lot", "lot"}, {"lotion", "lotion"}, {"lots", "lot"}, {"lottery", "lotteri"},
+		{"lotus", "lotus"}, {"loud", "loud"}, {"loudly", "loud"}, {"loudspeaker", "loudspeak"}, {"lounge", "loung"}, {"louse", "lous"},
+		{"lousy", "lousi"}, {"lout", "lout"}, {"lovable", "lovabl"}, {"love", "love"}, {"lovely", "love"}, {"lover", "lover"},
+		{"loving", "love"}, {"low", "low"}, {"lowe", "low"}, {"lower", "lower"}, {"lowered", "lower"}, {"lowering", "lower"},
+		{"lowers", "lower"}, {"lowland", "lowland"}, {"lowly", "lowli"}, {"loyal", "loyal"}, {"loyalist", "loyalist"}, {"loyalty", "loyalti"},
+		{"lozenge", "lozeng"}, {"lubricant", "lubric"}, {"lubricate", "lubric"}, {"lubrication", "lubric"}, {"lucid", "lucid"},
+		{"lucidity", "lucid"}, {"luck", "luck"}, {"lucky", "lucki"}, {"lucrative", "lucrat"}, {"ludicrous", "ludicr"}, {"lug", "lug"},
+		{"luggage", "luggag"}, {"lugubrious", "lugubri"}, {"lukewarm", "lukewarm"}, {"lull", "lull"}, {"lullaby", "lullabi"},
+		{"lumbago", "lumbago"}, {"lumber", "lumber"}, {"lumberjack", "lumberjack"}, {"luminance", "lumin"}, {"luminary", "luminari"},
+		{"luminous", "lumin"}, {"lump", "lump"}, {"lumpy", "lumpi"}, {"lunacy", "lunaci"}, {"lunar", "lunar"}, {"lunatic", "lunat"},
+		{"lunch", "lunch"}, {"luncheon", "luncheon"}, {"lunchtime", "lunchtim"}, {"lung", "lung"}, {"lunge", "lung"}, {"lurch", "lurch"},
+		{"lure", "lure"}, {"lurid", "lurid"}, {"lurk", "lurk"}, {"luscious", "luscious"}, {"lush", "lush"}, {"lust", "lust"},
+		{"luster", "luster"}, {"lustful", "lust"}, {"lustre", "lustr"}, {"lusty", "lusti"}, {"lute", "lute"}, {"luxuriant", "luxuri"},
+		{"luxuriate", "luxuri"}, {"luxurious", "luxuri"}, {"luxury", "luxuri"}, {"lying", "lie"}, {"lynch", "lynch"}, {"lyre", "lyre"},
+		{"lyric", "lyric"}, {"lyrical", "lyric"}, {"lyricism", "lyric"}, {"lyricist", "lyricist"}, {"ma", "ma"}, {"mac", "mac"},
+		{"macabre", "macabr"}, {"macaroni", "macaroni"}, {"mace", "mace"}, {"machete", "machet"}, {"machin", "machin"},
+		{"machination", "machin"}, {"machine", "machin"}, {"machined", "machin"}, {"machinery", "machineri"}, {"machines", "machin"},
+		{"machinist", "machinist"}, {"macho", "macho"}, {"mackerel", "mackerel"}, {"mackintosh", "mackintosh"}, {"macro", "macro"},
+		{"macrobiotic", "macrobiot"}, {"macros", "macro"}, {"macs", "mac"}, {"mad", "mad"}, {"madam", "madam"}, {"madden", "madden"},
+		{"madhouse", "madhous"}, {"madman", "madman"}, {"madness", "mad"}, {"maelstrom", "maelstrom"}, {"maestro", "maestro"},
+		{"mafia", "mafia"}, {"magazine", "magazin"}, {"maggot", "maggot"}, {"magic", "magic"}, {"magical", "magic"}, {"magically", "magic"},
+		{"magician", "magician"}, {"magisterial", "magisteri"}, {"magistrate", "magistr"}, {"magnanimity", "magnanim"},
+		{"magnanimous", "magnanim"}, {"magnate", "magnat"}, {"magnesium", "magnesium"}, {"magnet", "magnet"}, {"magnetic", "magnet"},
+		{"magnetism", "magnet"}, {"magnetize", "magnet"}, {"magnification", "magnif"}, {"magnificence", "magnific"}, {"magnificent", "magnific"},
+		{"magnifies", "magnifi"}, {"magnify", "magnifi"}, {"magnitude", "magnitud"}, {"magnolia", "magnolia"}, {"magpie", "magpi"},
+		{"mahogany", "mahogani"}, {"maid", "maid"}, {"maiden", "maiden"}, {"mail", "mail"}, {"mailbox", "mailbox"}, {"mailboxes", "mailbox"},
+		{"mailed", "mail"}, {"mailing", "mail"}, {"mailman", "mailman"}, {"mails", "mail"}, {"maim", "maim"}, {"main", "main"},
+		{"mainframe", "mainfram"}, {"mainframes", "mainfram"}, {"mainland", "mainland"}, {"mainline", "mainlin"}, {"mainly", "main"},
+		{"mainspring", "mainspr"}, {"mainstay", "mainstay"}, {"mainstream", "mainstream"}, {"maintain", "maintain"},
+		{"maintainability", "maintain"}, {"maintained", "maintain"}, {"maintainer", "maintain"}, {"maintainers", "maintain"},
+		{"maintaining", "maintain"}, {"maintains", "maintain"}, {"maintenance", "mainten"}, {"maize", "maiz"}, {"majestic", "majest"},
+		{"majesty", "majesti"}, {"major", "major"}, {"majority", "major"}, {"majors", "major"}, {"make", "make"}, {"maker", "maker"},
+		{"makes", "make"}, {"makeshift", "makeshift"}, {"makeup", "makeup"}, {"making", "make"}, {"maladjusted", "maladjust"},
+		{"maladministration", "maladministr"}, {"malady", "maladi"}, {"malaise", "malais"}, {"malaria", "malaria"}, {"malcontent", "malcont"},
+		{"male", "male"}, {"malevolence", "malevol"}, {"malevolent", "malevol"}, {"malformation", "malform"}, {"malformed", "malform"},
+		{"malfunction", "malfunct"}, {"malfunctioning", "malfunct"}, {"malice", "malic"}, {"malicious", "malici"}, {"malign", "malign"},
+		{"malignant", "malign"}, {"malinger", "maling"}, {"mall", "mall"}, {"mallard", "mallard"}, {"malleable", "malleabl"},
+		{"mallet", "mallet"}, {"malnourished", "malnourish"}, {"malnutrition", "malnutrit"}, {"malpractice", "malpractic"}, {"malt", "malt"},
+		{"malte", "malt"}, {"maltreat", "maltreat"}, {"maltreatment", "maltreat"}, {"mammal", "mammal"}, {"mammalian", "mammalian"},
+		{"mammoth", "mammoth"}, {"man", "man"}, {"manacle", "manacl"}, {"manage", "manag"}, {"manageable", "manag"}, {"managed", "manag"},
+		{"management", "manag"}, {"manager", "manag"}, {"manageress", "manageress"}, {"managerial", "manageri"}, {"managers", "manag"},
+		{"manages", "manag"}, {"managing", "manag"}, {"mandarin", "mandarin"}, {"mandate", "mandat"}, {"mandated", "mandat"},
+		{"mandates", "mandat"}, {"mandatory", "mandatori"}, {"mandolin", "mandolin"}, {"mane", "mane"}, {"maneuver", "maneuv"},
+		{"manger", "manger"}, {"mangle", "mangl"}, {"mangled", "mangl"}, {"mangles", "mangl"}, {"mangling", "mangl"}, {"manglings", "mangl"},
+		{"mango", "mango"}, {"mangrove", "mangrov"}, {"manhandle", "manhandl"}, {"manhole", "manhol"}, {"manhood", "manhood"},
+		{"mania", "mania"}, {"maniac", "maniac"}, {"manic", "manic"}, {"manicure", "manicur"}, {"manicurist", "manicurist"},
+		{"manifest", "manifest"}, {"manifestation", "manifest"}, {"manifesto", "manifesto"}, {"manifests", "manifest"}, {"manifold", "manifold"},
+		{"manipulate", "manipul"}, {"manipulated", "manipul"}, {"manipulates", "manipul"}, {"manipulating", "manipul"},
+		{"manipulation", "manipul"}, {"manipulations", "manipul"}, {"manipulative", "manipul"}, {"mankind", "mankind"}, {"manly", "man"},
+		{"manner", "manner"}, {"mannerism", "manner"}, {"manners", "manner"}, {"manoeuvre", "manoeuvr"}, {"manor", "manor"},
+		{"manpower", "manpow"}, {"manse", "mans"}, {"mansion", "mansion"}, {"manslaughter", "manslaught"}, {"mantelpiece", "mantelpiec"},
+		{"mantle", "mantl"}, {"mantra", "mantra"}, {"manual", "manual"}, {"manually", "manual"}, {"manuals", "manual"},
+		{"manufacture", "manufactur"}, {"manufacturer", "manufactur"}, {"manufacturers", "manufactur"}, {"manure", "manur"},
+		{"manuscript", "manuscript"}, {"many", "mani"}, {"map", "map"}, {"maple", "mapl"}, {"mapped", "map"}, {"mapping", "map"},
+		{"mappings", "map"}, {"maps", "map"}, {"mar", "mar"}, {"marathon", "marathon"}, {"marauder", "maraud"}, {"marble", "marbl"},
+		{"march", "march"}, {"mare", "mare"}, {"margarine", "margarin"}, {"margin", "margin"}, {"marginal", "margin"}, {"marginalize", "margin"},
+		{"marginally", "margin"}, {"margins", "margin"}, {"marigold", "marigold"}, {"marijuana", "marijuana"}, {"marina", "marina"},
+		{"marinade", "marinad"}, {"marinate", "marin"}, {"marine", "marin"}, {"mariner", "marin"}, {"maring", "mare"},
+		{"marionette", "marionett"}, {"marital", "marit"}, {"maritime", "maritim"}, {"mark", "mark"}, {"marked", "mark"}, {"marker", "marker"},
+		{"markers", "marker"}, {"market", "market"}, {"marketable", "market"}, {"marketing", "market"}, {"marketplace", "marketplac"},
+		{"marking", "mark"}, {"markings", "mark"}, {"marks", "mark"}, {"marksman", "marksman"}, {"marmalade", "marmalad"}, {"maroon", "maroon"},
+		{"marquee", "marque"}, {"marquis", "marqui"}, {"marriage", "marriag"}, {"marriageable", "marriag"}, {"marrow", "marrow"},
+		{"marry", "marri"}, {"mars", "mar"}, {"marsh", "marsh"}, {"marshal", "marshal"}, {"marshall", "marshal"}, {"marshy", "marshi"},
+		{"marsupial", "marsupi"}, {"mart", "mart"}, {"martial", "martial"}, {"martyr", "martyr"}, {"martyrdom", "martyrdom"},
+		{"marvel", "marvel"}, {"marvellous", "marvel"}, {"marvelous", "marvel"}, {"marzipan", "marzipan"}, {"mascara", "mascara"},
+		{"mascot", "mascot"}, {"masculine", "masculin"}, {"masculinity", "masculin"}, {"mash", "mash"}, {"mask", "mask"}, {"masked", "mask"},
+		{"masking", "mask"}, {"masks", "mask"}, {"masochism", "masoch"}, {"masochist", "masochist"}, {"masochistic", "masochist"},
+		{"mason", "mason"}, {"masonry", "masonri"}, {"masquerade", "masquerad"}, {"masqueraded", "masquerad"}, {"masquerading", "masquerad"},
+		{"mass", "mass"}, {"massacre", "massacr"}, {"massage", "massag"}, {"massaged", "massag"}, {"massaging", "massag"}, {"massive", "massiv"},
+		{"massively", "massiv"}, {"mast", "mast"}, {"master", "master"}, {"masterful", "master"}, {"mastermind", "mastermind"},
+		{"masterpiece", "masterpiec"}, {"masters", "master"}, {"mastery", "masteri"}, {"masturbate", "masturb"}, {"masturbation", "masturb"},
+		{"mat", "mat"}, {"matador", "matador"}, {"match", "match"}, {"matchbox", "matchbox"}, {"matched", "match"}, {"matches", "match"},
+		{"matching", "match"}, {"matchstick", "matchstick"}, {"mate", "mate"}, {"material", "materi"}, {"materialism", "materi"},
+		{"materialist", "materialist"}, {"materialistic", "materialist"}, {"materialize", "materi"}, {"materialized", "materi"},
+		{"materializing", "materi"}, {"materially", "materi"}, {"materials", "materi"}, {"maternal", "matern"}, {"maternity", "matern"},
+		{"math", "math"}, {"mathematical", "mathemat"}, {"mathematically", "mathemat"}, {"mathematician", "mathematician"},
+		{"mathematics", "mathemat"}, {"maths", "math"}, {"matinee", "matine"}, {"matriarch", "matriarch"}, {"matriarchal", "matriarch"},
+		{"matriculate", "matricul"}, {"matriculation", "matricul"}, {"matrimonial", "matrimoni"}, {"matrimony", "matrimoni"},
+		{"matrix", "matrix"}, {"matron", "matron"}, {"matter", "matter"}, {"matters", "matter"}, {"mattress", "mattress"}, {"mature", "matur"},
+		{"maturity", "matur"}, {"maudlin", "maudlin"}, {"maul", "maul"}, {"mausoleum", "mausoleum"}, {"mauve", "mauv"}, {"maverick", "maverick"},
+		{"maxim", "maxim"}, {"maximal", "maxim"}, {"maxime", "maxim"}, {"maximize", "maxim"}, {"maximizes", "maxim"}, {"maximum", "maximum"},
+		{"maximums", "maximum"}, {"may", "may"}, {"maybe", "mayb"}, {"maybes", "mayb"}, {"mayhem", "mayhem"}, {"mayonnaise", "mayonnais"},
+		{"mayor", "mayor"}, {"mayoress", "mayoress"}, {"mays", "may"}, {"maze", "maze"}, {"me", "me"}, {"meadow", "meadow"},
+		{"meager", "meager"}, {"meagre", "meagr"}, {"meal", "meal"}, {"mealtime", "mealtim"}, {"mean", "mean"}, {"meander", "meander"},
+		{"meaning", "mean"}, {"meaningful", "meaning"}, {"meaningfully", "meaning"}, {"meaningless", "meaningless"}, {"meanings", "mean"},
+		{"means", "mean"}, {"meantime", "meantim"}, {"meanwhile", "meanwhil"}, {"measles", "measl"}, {"measly", "measli"},
+		{"measurable", "measur"}, {"measurably", "measur"}, {"measure", "measur"}, {"measured", "measur"}, {"measurement", "measur"},
+		{"measurements", "measur"}, {"measures", "measur"}, {"measuring", "measur"}, {"meat", "meat"}, {"meaty", "meati"}, {"mecca", "mecca"},
+		{"mechanic", "mechan"}, {"mechanical", "mechan"}, {"mechanically", "mechan"}, {"mechanics", "mechan"}, {"mechanism", "mechan"},
+		{"mechanisms", "mechan"}, {"mechanize", "mechan"}, {"medal", "medal"}, {"medalist", "medalist"}, {"medallion", "medallion"},
+		{"medallist", "medallist"}, {"meddle", "meddl"}, {"meddlesome", "meddlesom"}, {"media", "media"}, {"median", "median"},
+		{"mediate", "mediat"}, {"mediation", "mediat"}, {"mediations", "mediat"}, {"mediator", "mediat"}, {"medic", "medic"},
+		{"medical", "medic"}, {"medicate", "medic"}, {"medication", "medic"}, {"medicinal", "medicin"}, {"medicine", "medicin"},
+		{"medieval", "mediev"}, {"mediocre", "mediocr"}, {"mediocrity", "mediocr"}, {"meditate", "medit"}, {"meditation", "medit"},
+		{"meditative", "medit"}, {"medium", "medium"}, {"medley", "medley"}, {"meek", "meek"}, {"meet", "meet"}, {"meeting", "meet"},
+		{"meetings", "meet"}, {"meets", "meet"}, {"megabyte", "megabyt"}, {"megabytes", "megabyt"}, {"megalomania", "megalomania"},
+		{"megalomaniac", "megalomaniac"}, {"megaphone", "megaphon"}, {"melancholic", "melanchol"}, {"melancholy", "melancholi"},
+		{"melee", "mele"}, {"mellow", "mellow"}, {"melodic", "melod"}, {"melodious", "melodi"}, {"melodrama", "melodrama"},
+		{"melodramatic", "melodramat"}, {"melody", "melodi"}, {"melon", "melon"}, {"melt", "melt"}, {"member", "member"}, {"members", "member"},
+		{"membership", "membership"}, {"memberships", "membership"}, {"membrane", "membran"}, {"memento", "memento"}, {"memo", "memo"},
+		{"memoir", "memoir"}, {"memorabilia", "memorabilia"}, {"memorable", "memor"}, {"memorandum", "memorandum"}, {"memorial", "memori"},
+		{"memories", "memori"}, {"memorize", "memor"}, {"memory", "memori"}, {"menace", "menac"}, {"menagerie", "menageri"}, {"mend", "mend"},
+		{"menial", "menial"}, {"meningitis", "mening"}, {"menopause", "menopaus"}, {"menstrual", "menstrual"}, {"menstruate", "menstruat"},
+		{"menstruation", "menstruat"}, {"mental", "mental"}, {"mentality", "mental"}, {"mentally", "mental"}, {"mention", "mention"},
+		{"mentioned", "mention"}, {"mentioning", "mention"}, {"mentions", "mention"}, {"mentor", "mentor"}, {"mentoring", "mentor"},
+		{"menu", "menu"}, {"mercantile", "mercantil"}, {"mercenary", "mercenari"}, {"merchandise", "merchandis"}, {"merchant", "merchant"},
+		{"merchantability", "merchant"}, {"merchantable", "merchant"}, {"merciful", "merci"}, {"merciless", "merciless"},
+		{"mercurial", "mercuri"}, {"mercury", "mercuri"}, {"mercy", "merci"}, {"mere", "mere"}, {"merely", "mere"}, {"merge", "merg"},
+		{"merged", "merg"}, {"merger", "merger"}, {"merges", "merg"}, {"merging", "merg"}, {"meridian", "meridian"}, {"meringue", "meringu"},
+		{"merit", "merit"}, {"meritocracy", "meritocraci"}, {"meritorious", "meritori"}, {"mermaid", "mermaid"}, {"merriment", "merriment"},
+		{"merry", "merri"}, {"mesh", "mesh"}, {"meshes", "mesh"}, {"mesmerize", "mesmer"}, {"mess", "mess"}, {"message", "messag"},
+		{"messages", "messag"}, {"messaging", "messag"}, {"messed", "mess"}, {"messenger", "messeng"}, {"messes", "mess"},
+		{"messiah", "messiah"}, {"messing", "mess"}, {"messy", "messi"}, {"metabolic", "metabol"}, {"metabolism", "metabol"}, {"metal", "metal"},
+		{"metallic", "metal"}, {"metallurgy", "metallurgi"}, {"metamorphose", "metamorphos"}, {"metamorphosis", "metamorphosi"},
+		{"metaphor", "metaphor"}, {"metaphorical", "metaphor"}, {"metaphysical", "metaphys"}, {"metaphysics", "metaphys"}, {"mete", "mete"},
+		{"meteor", "meteor"}, {"meteoric", "meteor"}, {"meteorite", "meteorit"}, {"meteorological", "meteorolog"},
+		{"meteorologist", "meteorologist"}, {"meteorology", "meteorolog"}, {"meter", "meter"}, {"metered", "meter"}, {"metering", "meter"},
+		{"meters", "meter"}, {"methane", "methan"}, {"method", "method"}, {"methodical", "method"}, {"methodology", "methodolog"},
+		{"methods", "method"}, {"meticulous", "meticul"}, {"metre", "metr"}, {"metric", "metric"}, {"metrical", "metric"}, {"metrics", "metric"},
+		{"metro", "metro"}, {"metropolis", "metropoli"}, {"metropolitan", "metropolitan"}, {"metros", "metro"}, {"mettle", "mettl"},
+		{"mew", "mew"}, {"mezzanine", "mezzanin"}, {"miaow", "miaow"}, {"microbe", "microb"}, {"microchip", "microchip"},
+		{"microcomputer", "microcomput"}, {"microcosm", "microcosm"}, {"microfilm", "microfilm"}, {"microphone", "microphon"},
+		{"microprocessor", "microprocessor"}, {"microscope", "microscop"}, {"microscopic", "microscop"}, {"microwave", "microwav"},
+		{"mid", "mid"}, {"midday", "midday"}, {"middle", "middl"}, {"middleman", "middleman"}, {"midget", "midget"}, {"midnight", "midnight"},
+		{"midst", "midst"}, {"midsummer", "midsumm"}, {"midway", "midway"}, {"midwife", "midwif"}, {"midwinter", "midwint"}, {"mien", "mien"},
+		{"might", "might"}, {"mighty", "mighti"}, {"migraine", "migrain"}, {"migrant", "migrant"}, {"migratable", "migrat"},
+		{"migrate", "migrat"}, {"migrated", "migrat"}, {"migrates", "migrat"}, {"migrating", "migrat"}, {"migration", "migrat"},
+		{"migrations", "migrat"}, {"migratory", "migratori"}, {"mike", "mike"}, {"mild", "mild"}, {"mildew", "mildew"}, {"mildly", "mild"},
+		{"mile", "mile"}, {"mileage", "mileag"}, {"miles", "mile"}, {"milestone", "mileston"}, {"milestones", "mileston"}, {"milieu", "milieu"},
+		{"militancy", "milit"}, {"militant", "milit"}, {"militarism", "militar"}, {"militarist", "militarist"}, {"military", "militari"},
+		{"militate", "milit"}, {"militia", "militia"}, {"milk", "milk"}, {"milkman", "milkman"}, {"milky", "milki"}, {"mill", "mill"},
+		{"mille", "mill"}, {"millennium", "millennium"}, {"miller", "miller"}, {"milligram", "milligram"}, {"millimeter", "millimet"},
+		{"millimeters", "millimet"}, {"millimetre", "millimetr"}, {"milliner", "millin"}, {"millinery", "millineri"}, {"million", "million"},
+		{"millionaire", "millionair"}, {"millions", "million"}, {"millionth", "millionth"}, {"mills", "mill"}, {"millstone", "millston"},
+		{"mime", "mime"}, {"mimic", "mimic"}, {"mimicry", "mimicri"}, {"mimics", "mimic"}, {"minaret", "minaret"}, {"mince", "minc"},
+		{"mincemeat", "mincemeat"}, {"mind", "mind"}, {"minded", "mind"}, {"mindful", "mind"}, {"mindless", "mindless"}, {"mine", "mine"},
+		{"minefield", "minefield"}, {"miner", "miner"}, {"mineral", "miner"}, {"mineralogy", "mineralog"}, {"mines", "mine"},
+		{"mingle", "mingl"}, {"miniature", "miniatur"}, {"minibus", "minibus"}, {"minimal", "minim"}, {"minimally", "minim"},
+		{"minimize", "minim"}, {"minimized", "minim"}, {"minimizes", "minim"}, {"minimizing", "minim"}, {"minimum", "minimum"},
+		{"mining", "mine"}, {"minion", "minion"}, {"miniskirt", "miniskirt"}, {"minister", "minist"}, {"ministerial", "ministeri"},
+		{"ministry", "ministri"}, {"mink", "mink"}, {"minnow", "minnow"}, {"minor", "minor"}, {"minority", "minor"}, {"minors", "minor"},
+		{"minstrel", "minstrel"}, {"mint", "mint"}, {"minted", "mint"}, {"minuet", "minuet"}, {"minus", "minus"}, {"minuscule", "minuscul"},
+		{"minuses", "minus"}, {"minute", "minut"}, {"minutely", "minut"}, {"minutes", "minut"}, {"minutia", "minutia"}, {"minutiae", "minutia"},
+		{"miracle", "miracl"}, {"miraculous", "miracul"}, {"mirage", "mirag"}, {"mire", "mire"}, {"mirror", "mirror"}, {"mirrored", "mirror"},
+		{"mirroring", "mirror"}, {"mirrorings", "mirror"}, {"mirrors", "mirror"}, {"mirth", "mirth"}, {"misadventure", "misadventur"},
+		{"misanthrope", "misanthrop"}, {"misanthropic", "misanthrop"}, {"misapprehension", "misapprehens"}, {"misappropriate", "misappropri"},
+		{"misbehave", "misbehav"}, {"misbehaved", "misbehav"}, {"misbehaving", "misbehav"}, {"misbehavior", "misbehavior"},
+		{"misbehaviour", "misbehaviour"}, {"miscalculate", "miscalcul"}, {"miscalculation", "miscalcul"}, {"miscarriage", "miscarriag"},
+		{"miscarry", "miscarri"}, {"miscellaneous", "miscellan"}, {"miscellany", "miscellani"}, {"mischief", "mischief"},
+		{"mischievous", "mischiev"}, {"misconceive", "misconc"}, {"misconception", "misconcept"}, {"misconduct", "misconduct"},
+		{"misconstrue", "misconstru"}, {"misdeed", "misde"}, {"misdemeanor", "misdemeanor"}, {"misdemeanour", "misdemeanour"},
+		{"miser", "miser"}, {"miserable", "miser"}, {"miserly", "miser"}, {"misery", "miseri"}, {"misfire", "misfir"}, {"misfit", "misfit"},
+		{"misfortune", "misfortun"}, {"misgiving", "misgiv"}, {"misguided", "misguid"}, {"mishandle", "mishandl"}, {"mishandled", "mishandl"},
+		{"mishandles", "mishandl"}, {"mishandling", "mishandl"}, {"mishap", "mishap"}, {"misinform", "misinform"},
+		{"misinformation", "misinform"}, {"misinterpret", "misinterpret"}, {"misinterpretation", "misinterpret"},
+		{"misinterpreted", "misinterpret"}, {"misinterpreting", "misinterpret"}, {"misjudge", "misjudg"}, {"mislay", "mislay"},
+		{"mislead", "mislead"}, {"misleading", "mislead"}, {"misleadingly", "mislead"}, {"mismanage", "mismanag"}, {"mismanagement", "mismanag"},
+		{"mismatch", "mismatch"}, {"mismatched", "mismatch"}, {"mismatches", "mismatch"}, {"mismatching", "mismatch"}, {"misnomer", "misnom"},
+		{"misogynist", "misogynist"}, {"misogyny", "misogyni"}, {"misplace", "misplac"}, {"misplaced", "misplac"}, {"misprint", "misprint"},
+		{"mispronounce", "mispronounc"}, {"misquote", "misquot"}, {"misread", "misread"}, {"misrepresent", "misrepres"},
+		{"misrepresentation", "misrepresent"}, {"misrepresented", "misrepres"}, {"misrule", "misrul"}, {"miss", "miss"}, {"missed", "miss"},
+		{"misses", "miss"}, {"missile", "missil"}, {"missing", "miss"}, {"mission", "mission"}, {"missionary", "missionari"},
+		{"missive", "missiv"}, {"misspell", "misspel"}, {"misspelled", "misspel"}, {"misspelling", "misspel"}, {"misspellings", "misspel"},
+		{"mist", "mist"}, {"mistake", "mistak"}, {"mistaken", "mistaken"}, {"mistakenly", "mistaken"}, {"mistakes", "mistak"},
+		{"mistaking", "mistak"}, {"mistress", "mistress"}, {"mistrust", "mistrust"}, {"misty", "misti"}, {"misunderstand", "misunderstand"},
+		{"misunderstanding", "misunderstand"}, {"misuse", "misus"}, {"misused", "misus"}, {"misuses", "misus"}, {"mite", "mite"},
+		{"mitigate", "mitig"}, {"mitigated", "mitig"}, {"mitigating", "mitig"}, {"mitigation", "mitig"}, {"mitigations", "mitig"},
+		{"mitr", "mitr"}, {"mitre", "mitr"}, {"mitt", "mitt"}, {"mitten", "mitten"}, {"mix", "mix"}, {"mixed", "mix"}, {"mixer", "mixer"},
+		{"mixing", "mix"}, {"mixture", "mixtur"}, {"mixtures", "mixtur"}, {"moan", "moan"}, {"moat", "moat"}, {"mob", "mob"},
+		{"mobile", "mobil"}, {"mobility", "mobil"}, {"mobilization", "mobil"}, {"mobilize", "mobil"}, {"moccasin", "moccasin"}, {"mock", "mock"},
+		{"mocked", "mock"}, {"mockery", "mockeri"}, {"mocking", "mock"}, {"mocks", "mock"}, {"mode", "mode"}, {"model", "model"},
+		{"modeled", "model"}, {"modeling", "model"}, {"modelled", "model"}, {"models", "model"}, {"modem", "modem"}, {"modems", "modem"},
+		{"moderate", "moder"}, {"moderated", "moder"}, {"moderately", "moder"}, {"moderation", "moder"}, {"moderator", "moder"},
+		{"modern", "modern"}, {"modernism", "modern"}, {"modernist", "modernist"}, {"modernity", "modern"}, {"modernization", "modern"},
+		{"modernize", "modern"}, {"modernized", "modern"}, {"modernizing", "modern"}, {"modes", "mode"}, {"modest", "modest"},
+		{"modesty", "modesti"}, {"modicum", "modicum"}, {"modifiable", "modifi"}, {"modification", "modif"}, {"modifications", "modif"},
+		{"modified", "modifi"}, {"modifier", "modifi"}, {"modifiers", "modifi"}, {"modifies", "modifi"}, {"modify", "modifi"},
+		{"modifying", "modifi"}, {"modular", "modular"}, {"modulate", "modul"}, {"modulation", "modul"}, {"module", "modul"},
+		{"modules", "modul"}, {"mogul", "mogul"}, {"moist", "moist"}, {"moisten", "moisten"}, {"moisture", "moistur"}, {"moisturize", "moistur"},
+		{"molar", "molar"}, {"molasses", "molass"}, {"mold", "mold"}, {"mole", "mole"}, {"molecular", "molecular"}, {"molecule", "molecul"},
+		{"molehill", "molehil"}, {"molest", "molest"}, {"molestation", "molest"}, {"mollify", "mollifi"}, {"mollusc", "mollusc"},
+		{"mollusk", "mollusk"}, {"molten", "molten"}, {"moment", "moment"}, {"momentary", "momentari"}, {"momentous", "moment"},
+		{"moments", "moment"}, {"momentum", "momentum"}, {"monarch", "monarch"}, {"monarchist", "monarchist"}, {"monarchy", "monarchi"},
+		{"monastery", "monasteri"}, {"monastic", "monast"}, {"monday", "monday"}, {"monetarism", "monetar"}, {"monetarist", "monetarist"},
+		{"monetary", "monetari"}, {"money", "money"}, {"moneylender", "moneylend"}, {"mongrel", "mongrel"}, {"monitor", "monitor"},
+		{"monitored", "monitor"}, {"monitoring", "monitor"}, {"monitors", "monitor"}, {"monk", "monk"}, {"monkey", "monkey"}, {"monks", "monk"},
+		{"monochrome", "monochrom"}, {"monocle", "monocl"}, {"monogamous", "monogam"}, {"monogamy", "monogami"}, {"monogram", "monogram"},
+		{"monolith", "monolith"}, {"monolithic", "monolith"}, {"monologue", "monologu"}, {"monopolize", "monopol"}, {"monopoly", "monopoli"},
+		{"monorail", "monorail"}, {"monosyllabic", "monosyllab"}, {"monosyllable", "monosyl"}, {"monotone", "monoton"}, {"monotonic", "monoton"},
+		{"monotonically", "monoton"}, {"monotonicity", "monoton"}, {"monotonous", "monoton"}, {"monotony", "monotoni"}, {"monsoon", "monsoon"},
+		{"monster", "monster"}, {"monstrosity", "monstros"}, {"monstrous", "monstrous"}, {"montage", "montag"}, {"month", "month"},
+		{"monthly", "month"}, {"months", "month"}, {"monument", "monument"}, {"monumental", "monument"}, {"moo", "moo"}, {"mood", "mood"},
+		{"moody", "moodi"}, {"moon", "moon"}, {"moonbeam", "moonbeam"}, {"moonlight", "moonlight"}, {"moor", "moor"}, {"moore", "moor"},
+		{"mooring", "moor"}, {"moorland", "moorland"}, {"moose", "moos"}, {"moot", "moot"}, {"mop", "mop"}, {"mope", "mope"}, {"moped", "mope"},
+		{"moral", "moral"}, {"morale", "moral"}, {"morales", "moral"}, {"moralist", "moralist"}, {"moralistic", "moralist"},
+		{"morality", "moral"}, {"moralize", "moral"}, {"morass", "morass"}, {"moratorium", "moratorium"}, {"morbid", "morbid"}, {"more", "more"},
+		{"moreover", "moreov"}, {"mores", "more"}, {"morgue", "morgu"}, {"moribund", "moribund"}, {"morning", "morn"}, {"moron", "moron"},
+		{"moronic", "moron"}, {"morose", "moros"}, {"morphine", "morphin"}, {"morphology", "morpholog"}, {"morrow", "morrow"},
+		{"morsel", "morsel"}, {"mortal", "mortal"}, {"mortality", "mortal"}, {"mortar", "mortar"}, {"mortgage", "mortgag"},
+		{"mortician", "mortician"}, {"mortify", "mortifi"}, {"mortuary", "mortuari"}, {"mosaic", "mosaic"}, {"mosque", "mosqu"},
+		{"mosquito", "mosquito"}, {"moss", "moss"}, {"most", "most"}, {"mostly", "most"}, {"motel", "motel"}, {"moth", "moth"},
+		{"mother", "mother"}, {"motherhood", "motherhood"}, {"motherland", "motherland"}, {"motherly", "mother"}, {"motif", "motif"},
+		{"motion", "motion"}, {"motionless", "motionless"}, {"motions", "motion"}, {"motivate", "motiv"}, {"motivated", "motiv"},
+		{"motivation", "motiv"}, {"motivations", "motiv"}, {"motive", "motiv"}, {"motley", "motley"}, {"motor", "motor"},
+		{"motorbike", "motorbik"}, {"motorboat", "motorboat"}, {"motorcade", "motorcad"}, {"motorcar", "motorcar"}, {"motorcycle", "motorcycl"},
+		{"motorcyclist", "motorcyclist"}, {"motorist", "motorist"}, {"motorway", "motorway"}, {"mottled", "mottl"}, {"motto", "motto"},
+		{"mould", "mould"}, {"mouldy", "mouldi"}, {"moult", "moult"}, {"mound", "mound"}, {"mount", "mount"}, {"mountain", "mountain"},
+		{"mountaineer", "mountain"}, {"mountaineering", "mountain"}, {"mountainous", "mountain"}, {"mounted", "mount"}, {"mounting", "mount"},
+		{"mounts", "mount"}, {"mourn", "mourn"}, {"mourner", "mourner"}, {"mournful", "mourn"}, {"mourning", "mourn"}, {"mouse", "mous"},
+		{"mousse", "mouss"}, {"moustache", "moustach"}, {"mouth", "mouth"}, {"mouthful", "mouth"}, {"mouthpiece", "mouthpiec"},
+		{"movable", "movabl"}, {"move", "move"}, {"moved", "move"}, {"movement", "movement"}, {"movements", "movement"}, {"mover", "mover"},
+		{"moves", "move"}, {"movie", "movi"}, {"moving", "move"}, {"mow", "mow"}, {"mower", "mower"}, {"much", "much"}, {"muck", "muck"},
+		{"mucous", "mucous"}, {"mucus", "mucus"}, {"mud", "mud"}, {"muddle", "muddl"}, {"muddy", "muddi"}, {"mudguard", "mudguard"},
+		{"muffin", "muffin"}, {"muffle", "muffl"}, {"muffler", "muffler"}, {"mug", "mug"}, {"mugger", "mugger"}, {"muggy", "muggi"},
+		{"mulberry", "mulberri"}, {"mule", "mule"}, {"mull", "mull"}, {"mullet", "mullet"}, {"multicultural", "multicultur"},
+		{"multilateral", "multilater"}, {"multimedia", "multimedia"}, {"multinational", "multin"}, {"multiple", "multipl"},
+		{"multiples", "multipl"}, {"multiplication", "multipl"}, {"multiplications", "multipl"}, {"multiplicative", "multipl"},
+		{"multiplicity", "multipl"}, {"multiplied", "multipli"}, {"multiplier", "multipli"}, {"multiplies", "multipli"},
+		{"multiply", "multipli"}, {"multiplying", "multipli"}, {"multitude", "multitud"}, {"mum", "mum"}, {"mumble", "mumbl"},
+		{"mummify", "mummifi"}, {"mummy", "mummi"}, {"mumps", "mump"}, {"munch", "munch"}, {"mundane", "mundan"}, {"municipal", "municip"},
+		{"municipality", "municip"}, {"munificent", "munific"}, {"munitions", "munit"}, {"mural", "mural"}, {"murder", "murder"},
+		{"murderer", "murder"}, {"murderous", "murder"}, {"murky", "murki"}, {"murmur", "murmur"}, {"muscle", "muscl"}, {"muscular", "muscular"},
+		{"muse", "muse"}, {"museum", "museum"}, {"mush", "mush"}, {"mushroom", "mushroom"}, {"mushy", "mushi"}, {"music", "music"},
+		{"musical", "music"}, {"musician", "musician"}, {"musk", "musk"}, {"musket", "musket"}, {"musketeer", "musket"}, {"muslin", "muslin"},
+		{"mussel", "mussel"}, {"must", "must"}, {"mustache", "mustach"}, {"mustard", "mustard"}, {"muster", "muster"}, {"musty", "musti"},
+		{"mutant", "mutant"}, {"mutate", "mutat"}, {"mutated", "mutat"}, {"mutating", "mutat"}, {"mutation", "mutat"}, {"mutations", "mutat"},
+		{"mutators", "mutat"}, {"mute", "mute"}, {"muted", "mute"}, {"mutilate", "mutil"}, {"mutilation", "mutil"}, {"mutineer", "mutin"},
+		{"mutinous", "mutin"}, {"mutiny", "mutini"}, {"mutt", "mutt"}, {"mutter", "mutter"}, {"mutton", "mutton"}, {"mutual", "mutual"},
+		{"mutually", "mutual"}, {"muzzle", "muzzl"}, {"my", "my"}, {"myopia", "myopia"}, {"myopic", "myopic"}, {"myriad", "myriad"},
+		{"myrrh", "myrrh"}, {"myself", "myself"}, {"mysterious", "mysteri"}, {"mystery", "mysteri"}, {"mystic", "mystic"},
+		{"mystical", "mystic"}, {"mysticism", "mystic"}, {"mystify", "mystifi"}, {"mystique", "mystiqu"}, {"myth", "myth"},
+		{"mythical", "mythic"}, {"mything", "myth"}, {"mythological", "mytholog"}, {"mythology", "mytholog"}, {"nab", "nab"}, {"nadir", "nadir"},
+		{"nag", "nag"}, {"nail", "nail"}, {"naive", "naiv"}, {"naively", "naiv"}, {"naivety", "naiveti"}, {"naked", "nake"}, {"name", "name"},
+		{"named", "name"}, {"nameless", "nameless"}, {"namely", "name"}, {"names", "name"}, {"namesake", "namesak"}, {"naming", "name"},
+		{"nanny", "nanni"}, {"nap", "nap"}, {"napalm", "napalm"}, {"nape", "nape"}, {"napkin", "napkin"}, {"nappy", "nappi"},
+		{"narcissism", "narciss"}, {"narcissistic", "narcissist"}, {"narcotic", "narcot"}, {"narrate", "narrat"}, {"narration", "narrat"},
+		{"narrative", "narrat"}, {"narrator", "narrat"}, {"narrow", "narrow"}, {"narrowed", "narrow"}, {"narrower", "narrow"},
+		{"narrowing", "narrow"}, {"narrows", "narrow"}, {"nasal", "nasal"}, {"nascent", "nascent"}, {"nasty", "nasti"}, {"nation", "nation"},
+		{"national", "nation"}, {"nationalism", "nation"}, {"nationalist", "nationalist"}, {"nationalistic", "nationalist"},
+		{"nationality", "nation"}, {"nationalization", "nation"}, {"nationalize", "nation"}, {"nationwide", "nationwid"}, {"native", "nativ"},
+		{"natively", "nativ"}, {"natives", "nativ"}, {"nativity", "nativ"}, {"natter", "natter"}, {"natural", "natur"}, {"naturalism", "natur"},
+		{"naturalist", "naturalist"}, {"naturalistic", "naturalist"}, {"naturalization", "natur"}, {"naturalize", "natur"},
+		{"naturally", "natur"}, {"nature", "natur"}, {"naught", "naught"}, {"naughty", "naughti"}, {"nausea", "nausea"}, {"nauseate", "nauseat"},
+		{"nauseous", "nauseous"}, {"nautical", "nautic"}, {"naval", "naval"}, {"nave", "nave"}, {"navel", "navel"}, {"navigability", "navig"},
+		{"navigable", "navig"}, {"navigate", "navig"}, {"navigated", "navig"}, {"navigating", "navig"}, {"navigation", "navig"},
+		{"navigator", "navig"}, {"navvy", "navvi"}, {"navy", "navi"}, {"nay", "nay"}, {"near", "near"}, {"nearby", "nearbi"},
+		{"nearing", "near"}, {"nearly", "near"}, {"neat", "neat"}, {"nebula", "nebula"}, {"nebulous", "nebul"}, {"necessary", "necessari"},
+		{"necessitate", "necessit"}, {"necessitates", "necessit"}, {"necessitating", "necessit"}, {"necessity", "necess"}, {"neck", "neck"},
+		{"necklace", "necklac"}, {"neckline", "necklin"}, {"necktie", "neckti"}, {"necromancy", "necrom"}, {"nectar", "nectar"},
+		{"nectarine", "nectarin"}, {"need", "need"}, {"needed", "need"}, {"needful", "need"}, {"needing", "need"}, {"needle", "needl"},
+		{"needless", "needless"}, {"needlessly", "needless"}, {"needlework", "needlework"}, {"needs", "need"}, {"needy", "needi"},
+		{"negate", "negat"}, {"negated", "negat"}, {"negates", "negat"}, {"negating", "negat"}, {"negation", "negat"}, {"negations", "negat"},
+		{"negative", "negat"}, {"negatively", "negat"}, {"negatives", "negat"}, {"negator", "negat"}, {"neglect", "neglect"},
+		{"neglectful", "neglect"}, {"negligence", "neglig"}, {"negligent", "neglig"}, {"negligible", "neglig"}, {"negotiable", "negoti"},
+		{"negotiate", "negoti"}, {"negotiated", "negoti"}, {"negotiates", "negoti"}, {"negotiating", "negoti"}, {"negotiation", "negoti"},
+		{"negotiations", "negoti"}, {"negotiator", "negoti"}, {"neigh", "neigh"}, {"neighbor", "neighbor"}, {"neighborhood", "neighborhood"},
+		{"neighboring", "neighbor"}, {"neighbors", "neighbor"}, {"neighbour", "neighbour"}, {"neighbourhood", "neighbourhood"},
+		{"neighbourly", "neighbour"}, {"neighbours", "neighbour"}, {"neither", "neither"}, {"nemesis", "nemesi"}, {"neon", "neon"},
+		{"nephew", "nephew"}, {"nepotism", "nepot"}, {"nerve", "nerv"}, {"nervous", "nervous"}, {"nest", "nest"}, {"nested", "nest"},
+		{"nesting", "nest"}, {"nestle", "nestl"}, {"nests", "nest"}, {"net", "net"}, {"netball", "netbal"}, {"nether", "nether"},
+		{"nets", "net"}, {"netting", "net"}, {"nettle", "nettl"}, {"network", "network"}, {"networked", "network"}, {"networking", "network"},
+		{"networks", "network"}, {"neural", "neural"}, {"neurological", "neurolog"}, {"neurologist", "neurologist"}, {"neurology", "neurolog"},
+		{"neurosis", "neurosi"}, {"neurotic", "neurot"}, {"neuter", "neuter"}, {"neutral", "neutral"}, {"neutrality", "neutral"},
+		{"neutralize", "neutral"}, {"neutron", "neutron"}, {"never", "never"}, {"nevertheless", "nevertheless"}, {"new", "new"},
+		{"newborn", "newborn"}, {"newcomer", "newcom"}, {"newcomers", "newcom"}, {"newed", "new"}, {"newfangled", "newfangl"},
+		{"newly", "newli"}, {"newlywed", "newlyw"}, {"news", "news"}, {"newsagent", "newsag"}, {"newscast", "newscast"},
+		{"newscaster", "newscast"}, {"newsletter", "newslett"}, {"newsletters", "newslett"}, {"newspaper", "newspap"}, {"newspapers", "newspap"},
+		{"newsprint", "newsprint"}, {"newsreader", "newsread"}, {"newsreel", "newsreel"}, {"newt", "newt"}, {"next", "next"}, {"nib", "nib"},
+		{"nibble", "nibbl"}, {"nibbles", "nibbl"}, {"nice", "nice"}, {"niced", "nice"}, {"nicely", "nice"}, {"niceness", "nice"},
+		{"nicety", "niceti"}, {"niche", "nich"}, {"nicing", "nice"}, {"nick", "nick"}, {"nickel", "nickel"}, {"nickname", "nicknam"},
+		{"nicotine", "nicotin"}, {"niece", "niec"}, {"nifty", "nifti"}, {"niggle", "niggl"}, {"nigh", "nigh"}, {"night", "night"},
+		{"nightclub", "nightclub"}, {"nightdress", "nightdress"}, {"nightfall", "nightfal"}, {"nightgown", "nightgown"},
+		{"nightingale", "nightingal"}, {"nightlies", "night"}, {"nightlife", "nightlif"}, {"nightly", "night"}, {"nightmare", "nightmar"},
+		{"nightmarish", "nightmarish"}, {"nights", "night"}, {"nighttime", "nighttim"}, {"nihilism", "nihil"}, {"nil", "nil"}, {"nils", "nil"},
+		{"nimble", "nimbl"}, {"nine", "nine"}, {"nineteen", "nineteen"}, {"ninety", "nineti"}, {"ninth", "ninth"}, {"nip", "nip"},
+		{"nipple", "nippl"}, {"nitrate", "nitrat"}, {"nitrogen", "nitrogen"}, {"no", "no"}, {"nobility", "nobil"}, {"noble", "nobl"},
+		{"nobleman", "nobleman"}, {"nobody", "nobodi"}, {"nocturnal", "nocturn"}, {"nod", "nod"}, {"node", "node"}, {"nodes", "node"},
+		{"noise", "nois"}, {"noiseless", "noiseless"}, {"noisy", "noisi"}, {"nomad", "nomad"}, {"nomadic", "nomad"},
+		{"nomenclature", "nomenclatur"}, {"nominal", "nomin"}, {"nominally", "nomin"}, {"nominate", "nomin"}, {"nominated", "nomin"},
+		{"nominating", "nomin"}, {"nomination", "nomin"}, {"nominations", "nomin"}, {"nominee", "nomine"}, {"nominees", "nomine"},
+		{"nonchalance", "nonchal"}, {"nonchalant", "nonchal"}, {"noncommittal", "noncommitt"}, {"nonconformist", "nonconformist"},
+		{"nondescript", "nondescript"}, {"none", "none"}, {"nonentity", "nonent"}, {"nonetheless", "nonetheless"}, {"nonexistant", "nonexist"},
+		{"nonexistent", "nonexist"}, {"nonplussed", "nonpluss"}, {"nonsense", "nonsens"}, {"nonsensical", "nonsens"}, {"nonstop", "nonstop"},
+		{"noodle", "noodl"}, {"nook", "nook"}, {"noon", "noon"}, {"noose", "noos"}, {"nor", "nor"}, {"norm", "norm"}, {"normal", "normal"},
+		{"normality", "normal"}, {"normalization", "normal"}, {"normalize", "normal"}, {"normalized", "normal"}, {"normalizes", "normal"},
+		{"normalizing", "normal"}, {"normally", "normal"}, {"north", "north"}, {"northeast", "northeast"}, {"northerly", "norther"},
+		{"northern", "northern"}, {"northward", "northward"}, {"northwest", "northwest"}, {"nose", "nose"}, {"nosebleed", "noseble"},
+		{"nosedive", "nosed"}, {"nostalgia", "nostalgia"}, {"nostalgic", "nostalg"}, {"nostril", "nostril"}, {"nosy", "nosi"}, {"not", "not"},
+		{"notable", "notabl"}, {"notables", "notabl"}, {"notably", "notabl"}, {"notary", "notari"}, {"notation", "notat"},
+		{"notational", "notat"}, {"notations", "notat"}, {"notch", "notch"}, {"note", "note"}, {"notebook", "notebook"},
+		{"notebooks", "notebook"}, {"noted", "note"}, {"notes", "note"}, {"noteworthy", "noteworthi"}, {"nothing", "noth"}, {"notice", "notic"},
+		{"noticeable", "notic"}, {"noticeably", "notic"}, {"noticed", "notic"}, {"notices", "notic"}, {"noticing", "notic"}, {"notif", "notif"},
+		{"notification", "notif"}, {"notifications", "notif"}, {"notified", "notifi"}, {"notifier", "notifi"}, {"notifiers", "notifi"},
+		{"notifies", "notifi"}, {"notify", "notifi"}, {"notifying", "notifi"}, {"noting", "note"}, {"notion", "notion"}, {"notional", "notion"},
+		{"notionally", "notion"}, {"notions", "notion"}, {"notoriety", "notorieti"}, {"notorious", "notori"},
+		{"notwithstanding", "notwithstand"}, {"nought", "nought"}, {"noun", "noun"}, {"nouns", "noun"}, {"nourish", "nourish"},
+		{"nourishment", "nourish"}, {"novel", "novel"}, {"novelist", "novelist"}, {"novell", "novel"}, {"novelty", "novelti"},
+		{"november", "novemb"}, {"novice", "novic"}, {"now", "now"}, {"nowadays", "nowaday"}, {"nowhere", "nowher"}, {"noxious", "noxious"},
+		{"nozzle", "nozzl"}, {"nuance", "nuanc"}, {"nuances", "nuanc"}, {"nubile", "nubil"}, {"nuclear", "nuclear"}, {"nucleus", "nucleus"},
+		{"nude", "nude"}, {"nudge", "nudg"}, {"nudism", "nudism"}, {"nudist", "nudist"}, {"nudity", "nuditi"}, {"nugget", "nugget"},
+		{"nuisance", "nuisanc"}, {"null", "null"}, {"nulled", "null"}, {"nullify", "nullifi"}, {"nulls", "null"}, {"numb", "numb"},
+		{"number", "number"}, {"numbered", "number"}, {"numbering", "number"}, {"numbers", "number"}, {"numeral", "numer"},
+		{"numerals", "numer"}, {"numerate", "numer"}, {"numerator", "numer"}, {"numeric", "numer"}, {"numerical", "numer"},
+		{"numerically", "numer"}, {"numerics", "numer"}, {"numerous", "numer"}, {"nun", "nun"}, {"nunnery", "nunneri"}, {"nuptial", "nuptial"},
+		{"nurse", "nurs"}, {"nursery", "nurseri"}, {"nurture", "nurtur"}, {"nut", "nut"}, {"nutcracker", "nutcrack"}, {"nutmeg", "nutmeg"},
+		{"nutrient", "nutrient"}, {"nutrition", "nutrit"}, {"nutritional", "nutrit"}, {"nutritious", "nutriti"}, {"nutshell", "nutshel"},
+		{"nuzzle", "nuzzl"}, {"nylon", "nylon"}, {"nymph", "nymph"}, {"oak", "oak"}, {"oar", "oar"}, {"oasis", "oasi"}, {"oasys", "oasi"},
+		{"oat", "oat"}, {"oath", "oath"}, {"oatmeal", "oatmeal"}, {"obedience", "obedi"}, {"obedient", "obedi"}, {"obelisk", "obelisk"},
+		{"obese", "obes"}, {"obesity", "obes"}, {"obey", "obey"}, {"obeyed", "obey"}, {"obeying", "obey"}, {"obeys", "obey"},
+		{"obituary", "obituari"}, {"object", "object"}, {"objection", "object"}, {"objectionable", "objection"}, {"objections", "object"},
+		{"objective", "object"}, {"objectivity", "object"}, {"objector", "objector"}, {"objects", "object"}, {"obligate", "oblig"},
+		{"obligated", "oblig"}, {"obligation", "oblig"}, {"obligations", "oblig"}, {"obligatory", "obligatori"}, {"oblige", "oblig"},
+		{"obliging", "oblig"}, {"oblique", "obliqu"}, {"obliterate", "obliter"}, {"obliteration", "obliter"}, {"oblivion", "oblivion"},
+		{"oblivious", "oblivi"}, {"oblong", "oblong"}, {"obnoxious", "obnoxi"}, {"obo", "obo"}, {"oboe", "obo"}, {"obscene", "obscen"},
+		{"obscenity", "obscen"}, {"obscure", "obscur"}, {"obscured", "obscur"}, {"obscures", "obscur"}, {"obscurity", "obscur"},
+		{"obsequious", "obsequi"}, {"observability", "observ"}, {"observable", "observ"}, {"observance", "observ"}, {"observant", "observ"},
+		{"observation", "observ"}, {"observatory", "observatori"}, {"observe", "observ"}, {"observed", "observ"}, {"observer", "observ"},
+		{"observers", "observ"}, {"observes", "observ"}, {"observing", "observ"}, {"obsess", "obsess"}, {"obsession", "obsess"},
+		{"obsessive", "obsess"}, {"obsolescence", "obsolesc"}, {"obsolescent", "obsolesc"}, {"obsolete", "obsolet"}, {"obsoleted", "obsolet"},
+		{"obsoletes", "obsolet"}, {"obstacle", "obstacl"}, {"obstetric", "obstetr"}, {"obstetrician", "obstetrician"}, {"obstetrics", "obstetr"},
+		{"obstinacy", "obstinaci"}, {"obstinate", "obstin"}, {"obstruct", "obstruct"}, {"obstruction", "obstruct"}, {"obstructive", "obstruct"},
+		{"obtain", "obtain"}, {"obtainability", "obtain"}, {"obtainable", "obtain"}, {"obtained", "obtain"}, {"obtaining", "obtain"},
+		{"obtains", "obtain"}, {"obtrusive", "obtrus"}, {"obtuse", "obtus"}, {"obverse", "obvers"}, {"obviate", "obviat"},
+		{"obviates", "obviat"}, {"obvious", "obvious"}, {"obviously", "obvious"}, {"occasion", "occas"}, {"occasional", "occasion"},
+		{"occasionally", "occasion"}, {"occasions", "occas"}, {"occult", "occult"}, {"occupancy", "occup"}, {"occupant", "occup"},
+		{"occupation", "occup"}, {"occupational", "occup"}, {"occupied", "occupi"}, {"occupier", "occupi"}, {"occupies", "occupi"},
+		{"occupy", "occupi"}, {"occupying", "occupi"}, {"occur", "occur"}, {"occurences", "occur"}, {"occuring", "occur"}, {"occurred", "occur"},
+		{"occurrence", "occurr"}, {"occurrences", "occurr"}, {"occurring", "occur"}, {"occurs", "occur"}, {"ocean", "ocean"},
+		{"oceanic", "ocean"}, {"oceanography", "oceanographi"}, {"ochre", "ochr"}, {"octagon", "octagon"}, {"octagonal", "octagon"},
+		{"octane", "octan"}, {"octave", "octav"}, {"october", "octob"}, {"octopus", "octopus"}, {"ocular", "ocular"}, {"odd", "odd"},
+		{"oddity", "odditi"}, {"oddly", "odd"}, {"oddment", "oddment"}, {"odds", "odd"}, {"ode", "ode"}, {"odious", "odious"}, {"odor", "odor"},
+		{"odour", "odour"}, {"odyssey", "odyssey"}, {"of", "of"}, {"off", "off"}, {"offal", "offal"}, {"offence", "offenc"},
+		{"offend", "offend"}, {"offender", "offend"}, {"offending", "offend"}, {"offense", "offens"}, {"offensive", "offens"},
+		{"offer", "offer"}, {"offered", "offer"}, {"offering", "offer"}, {"offerings", "offer"}, {"offers", "offer"}, {"offhand", "offhand"},
+		{"office", "offic"}, {"officer", "offic"}, {"official", "offici"}, {"officialdom", "officialdom"}, {"officially", "offici"},
+		{"officiate", "offici"}, {"officious", "offici"}, {"offing", "of"}, {"offs", "off"}, {"offset", "offset"}, {"offsets", "offset"},
+		{"offshoot", "offshoot"}, {"offshore", "offshor"}, {"offside", "offsid"}, {"offspring", "offspr"}, {"ofs", "of"}, {"often", "often"},
+		{"ogre", "ogr"}, {"oh", "oh"}, {"oil", "oil"}, {"oilfield", "oilfield"}, {"oilskin", "oilskin"}, {"oily", "oili"},
+		{"ointment", "ointment"}, {"okay", "okay"}, {"old", "old"}, {"olfactory", "olfactori"}, {"oligarchy", "oligarchi"}, {"olive", "oliv"},
+		{"oliver", "oliv"}, {"omelet", "omelet"}, {"omelette", "omelett"}, {"omen", "omen"}, {"ominous", "omin"}, {"omission", "omiss"},
+		{"omit", "omit"}, {"omits", "omit"}, {"omitted", "omit"}, {"omitting", "omit"}, {"omnibus", "omnibus"}, {"omnipotence", "omnipot"},
+		{"omnipotent", "omnipot"}, {"omnipresent", "omnipres"}, {"omniscient", "omnisci"}, {"omnivorous", "omnivor"}, {"on", "on"},
+		{"onc", "onc"}, {"once", "onc"}, {"oncoming", "oncom"}, {"one", "one"}, {"onerous", "oner"}, {"ones", "one"}, {"oneself", "oneself"},
+		{"ongoing", "ongo"}, {"onion", "onion"}, {"onlooker", "onlook"}, {"only", "onli"}, {"ons", "on"}, {"onset", "onset"},
+		{"onshore", "onshor"}, {"onslaught", "onslaught"}, {"onto", "onto"}, {"onus", "onus"}, {"onward", "onward"}, {"onwards", "onward"},
+		{"ooze", "ooz"}, {"opacity", "opac"}, {"opal", "opal"}, {"opaque", "opaqu"}, {"open", "open"}, {"opened", "open"}, {"opener", "open"},
+		{"opening", "open"}, {"openings", "open"}, {"openly", "open"}, {"openness", "open"}, {"opens", "open"}, {"opera", "opera"},
+		{"operable", "oper"}, {"operate", "oper"}, {"operated", "oper"}, {"operates", "oper"}, {"operatic", "operat"}, {"operating", "oper"},
+		{"operation", "oper"}, {"operational", "oper"}, {"operations", "oper"}, {"operative", "oper"}, {"operator", "oper"},
+		{"operators", "oper"}, {"operetta", "operetta"}, {"ophthalmic", "ophthalm"}, {"opiate", "opiat"}, {"opine", "opin"},
+		{"opinion", "opinion"}, {"opinionated", "opinion"}, {"opinions", "opinion"}, {"opium", "opium"}, {"opponent", "oppon"},
+		{"opportune", "opportun"}, {"opportunism", "opportun"}, {"opportunist", "opportunist"}, {"opportunistic", "opportunist"},
+		{"opportunistically", "opportunist"}, {"opportunities", "opportun"}, {"opportunity", "opportun"}, {"oppose", "oppos"},
+		{"opposed", "oppos"}, {"opposite", "opposit"}, {"opposition", "opposit"}, {"oppress", "oppress"}, {"oppression", "oppress"},
+		{"oppressive", "oppress"}, {"oppressor", "oppressor"}, {"opt", "opt"}, {"opted", "opt"}, {"optic", "optic"}, {"optical", "optic"},
+		{"optician", "optician"}, {"optics", "optic"}, {"optim", "optim"}, {"optimal", "optim"}, {"optimally", "optim"}, {"optimism", "optim"},
+		{"optimist", "optimist"}, {"optimistic", "optimist"}, {"optimistically", "optimist"}, {"optimization", "optim"},
+		{"optimizations", "optim"}, {"optimize", "optim"}, {"optimized", "optim"}, {"optimizer", "optim"}, {"optimizers", "optim"},
+		{"optimizes", "optim"}, {"optimizing", "optim"}, {"optimum", "optimum"}, {"opting", "opt"}, {"option", "option"}, {"optional", "option"},
+		{"optionality", "option"}, {"optionally", "option"}, {"options", "option"}, {"opts", "opt"}, {"opulence", "opul"}, {"opulent", "opul"},
+		{"opus", "opus"}, {"or", "or"}, {"oracle", "oracl"}, {"oral", "oral"}, {"orange", "orang"}, {"oranges", "orang"},
+		{"orangutan", "orangutan"}, {"oration", "orat"}, {"orator", "orat"}, {"oratorio", "oratorio"}, {"oratory", "oratori"}, {"orb", "orb"},
+		{"orbit", "orbit"}, {"orbital", "orbit"}, {"orchard", "orchard"}, {"orchestra", "orchestra"}, {"orchestral", "orchestr"},
+		{"orchestrate", "orchestr"}, {"orchestration", "orchestr"}, {"orchestrator", "orchestr"}, {"orchestrators", "orchestr"},
+		{"orchid", "orchid"}, {"ordain", "ordain"}, {"ordeal", "ordeal"}, {"order", "order"}, {"ordered", "order"}, {"ordering", "order"},
+		{"orderings", "order"}, {"orderly", "order"}, {"orders", "order"}, {"ordinal", "ordin"}, {"ordinality", "ordin"}, {"ordinals", "ordin"},
+		{"ordinance", "ordin"}, {"ordinary", "ordinari"}, {"ordinate", "ordin"}, {"ordination", "ordin"}, {"ore", "ore"}, {"ored", "ore"},
+		{"organ", "organ"}, {"organic", "organ"}, {"organism", "organ"}, {"organist", "organist"}, {"organization", "organ"},
+		{"organizational", "organiz"}, {"organizations", "organ"}, {"organize", "organ"}, {"organized", "organ"}, {"organizer", "organ"},
+		{"organizing", "organ"}, {"orgasm", "orgasm"}, {"orgy", "orgi"}, {"orient", "orient"}, {"oriental", "orient"}, {"orientate", "orient"},
+		{"orientation", "orient"}, {"orientations", "orient"}, {"oriented", "orient"}, {"orifice", "orific"}, {"origin", "origin"},
+		{"original", "origin"}, {"originality", "origin"}, {"originally", "origin"}, {"originals", "origin"}, {"originate", "origin"},
+		{"originated", "origin"}, {"originates", "origin"}, {"originating", "origin"}, {"originator", "origin"}, {"origins", "origin"},
+		{"oring", "ore"}, {"ornament", "ornament"}, {"ornamental", "ornament"}, {"ornate", "ornat"}, {"ornithology", "ornitholog"},
+		{"orphan", "orphan"}, {"orphanage", "orphanag"}, {"orphaned", "orphan"}, {"ors", "or"}, {"orthodox", "orthodox"},
+		{"orthodoxy", "orthodoxi"}, {"orthographies", "orthographi"}, {"orthography", "orthographi"}, {"orthopaedic", "orthopaed"},
+		{"orthopedic", "orthoped"}, {"oscillate", "oscil"}, {"oscillation", "oscil"}, {"ostensible", "ostens"}, {"ostensibly", "ostens"},
+		{"ostentation", "ostent"}, {"ostentatious", "ostentati"}, {"osteopath", "osteopath"}, {"ostracism", "ostrac"}, {"ostracize", "ostrac"},
+		{"ostrich", "ostrich"}, {"other", "other"}, {"others", "other"}, {"otherwise", "otherwis"}, {"otter", "otter"}, {"ought", "ought"},
+		{"ounce", "ounc"}, {"our", "our"}, {"ours", "our"}, {"ourselves", "ourselv"}, {"oust", "oust"}, {"out", "out"}, {"outback", "outback"},
+		{"outbid", "outbid"}, {"outboard", "outboard"}, {"outbreak", "outbreak"}, {"outbuilding", "outbuild"}, {"outburst", "outburst"},
+		{"outcast", "outcast"}, {"outclass", "outclass"}, {"outcome", "outcom"}, {"outcomes", "outcom"}, {"outcrop", "outcrop"},
+		{"outcry", "outcri"}, {"outdate", "outdat"}, {"outdated", "outdat"}, {"outdo", "outdo"}, {"outdoor", "outdoor"}, {"outdoors", "outdoor"},
+		{"outer", "outer"}, {"outermost", "outermost"}, {"outfit", "outfit"}, {"outflank", "outflank"}, {"outflow", "outflow"},
+		{"outgoing", "outgo"}, {"outgrow", "outgrow"}, {"outhouse", "outhous"}, {"outing", "outing"}, {"outlandish", "outlandish"},
+		{"outlast", "outlast"}, {"outlaw", "outlaw"}, {"outlay", "outlay"}, {"outlet", "outlet"}, {"outline", "outlin"}, {"outlined", "outlin"},
+		{"outlines", "outlin"}, {"outlining", "outlin"}, {"outlive", "outliv"}, {"outlived", "outliv"}, {"outlives", "outliv"},
+		{"outliving", "outliv"}, {"outlook", "outlook"}, {"outlying", "out"}, {"outmanoeuvre", "outmanoeuvr"}, {"outmoded", "outmod"},
+		{"outnumber", "outnumb"}, {"outpatient", "outpati"}, {"outperform", "outperform"}, {"outpost", "outpost"}, {"outpouring", "outpour"},
+		{"output", "output"}, {"outputing", "output"}, {"outputs", "output"}, {"outputted", "output"}, {"outputting", "output"},
+		{"outrage", "outrag"}, {"outrageous", "outrag"}, {"outright", "outright"}, {"outrun", "outrun"}, {"outs", "out"}, {"outset", "outset"},
+		{"outshine", "outshin"}, {"outside", "outsid"}, {"outsider", "outsid"}, {"outskirts", "outskirt"}, {"outsmart", "outsmart"},
+		{"outspoken", "outspoken"}, {"outstanding", "outstand"}, {"outstay", "outstay"}, {"outstretched", "outstretch"},
+		{"outstrip", "outstrip"}, {"outward", "outward"}, {"outwards", "outward"}, {"outweigh", "outweigh"}, {"outwit", "outwit"},
+		{"oval", "oval"}, {"ovary", "ovari"}, {"ovation", "ovat"}, {"oven", "oven"}, {"over", "over"}, {"overact", "overact"},
+		{"overall", "overal"}, {"overawe", "overaw"}, {"overbalance", "overbal"}, {"overbearing", "overbear"}, {"overboard", "overboard"},
+		{"overburden", "overburden"}, {"overcast", "overcast"}, {"overcharge", "overcharg"}, {"overcoat", "overcoat"}, {"overcome", "overcom"},
+		{"overcrowd", "overcrowd"}, {"overdo", "overdo"}, {"overdose", "overdos"}, {"overdraft", "overdraft"}, {"overdraw", "overdraw"},
+		{"overdrive", "overdr"}, {"overdue", "overdu"}, {"overestimate", "overestim"}, {"overflow", "overflow"}, {"overflowed", "overflow"},
+		{"overflowing", "overflow"}, {"overflows", "overflow"}, {"overgrown", "overgrown"}, {"overhang", "overhang"}, {"overhaul", "overhaul"},
+		{"overhead", "overhead"}, {"overheads", "overhead"}, {"overhear", "overhear"}, {"overheat", "overheat"}, {"overjoyed", "overjoy"},
+		{"overkill", "overkil"}, {"overland", "overland"}, {"overlap", "overlap"}, {"overlapped", "overlap"}, {"overlapping", "overlap"},
+		{"overlaps", "overlap"}, {"overlay", "overlay"}, {"overlaying", "overlay"}, {"overlays", "overlay"}, {"overleaf", "overleaf"},
+		{"overload", "overload"}, {"overloaded", "overload"}, {"overloading", "overload"}, {"overloads", "overload"}, {"overlook", "overlook"},
+		{"overlooked", "overlook"}, {"overlord", "overlord"}, {"overly", "over"}, {"overnight", "overnight"}, {"overpass", "overpass"},
+		{"overpower", "overpow"}, {"overrate", "overr"}, {"overreach", "overreach"}, {"overreact", "overreact"}, {"overridable", "overrid"},
+		{"override", "overrid"}, {"overrides", "overrid"}, {"overriding", "overrid"}, {"overrule", "overrul"}, {"overruled", "overrul"},
+		{"overrules", "overrul"}, {"overrun", "overrun"}, {"overrunning", "overrun"}, {"overruns", "overrun"}, {"overseas", "oversea"},
+		{"oversee", "overse"}, {"overseer", "overs"}, {"overshadow", "overshadow"}, {"overshoot", "overshoot"}, {"oversight", "oversight"},
+		{"oversimplify", "oversimplifi"}, {"oversize", "overs"}, {"oversleep", "oversleep"}, {"overspend", "overspend"}, {"overstate", "overst"},
+		{"overstep", "overstep"}, {"overt", "overt"}, {"overtake", "overtak"}, {"overthrow", "overthrow"}, {"overtime", "overtim"},
+		{"overtone", "overton"}, {"overture", "overtur"}, {"overturn", "overturn"}, {"overview", "overview"}, {"overviews", "overview"},
+		{"overweight", "overweight"}, {"overwhelm", "overwhelm"}, {"overwhelming", "overwhelm"}, {"overwhelmingly", "overwhelm"},
+		{"overwork", "overwork"}, {"overwrought", "overwrought"}, {"ovulate", "ovul"}, {"ovulation", "ovul"}, {"owe", "owe"}, {"owl", "owl"},
+		{"own", "own"}, {"owned", "own"}, {"owner", "owner"}, {"owners", "owner"}, {"ownership", "ownership"}, {"ownerships", "ownership"},
+		{"owning", "own"}, {"owns", "own"}, {"ox", "ox"}, {"oxidation", "oxid"}, {"oxide", "oxid"}, {"oxidize", "oxid"}, {"oxygen", "oxygen"},
+		{"oyster", "oyster"}, {"ozone", "ozon"}, {"pace", "pace"}, {"pacemaker", "pacemak"}, {"pacific", "pacif"}, {"pacifier", "pacifi"},
+		{"pacifism", "pacif"}, {"pacifist", "pacifist"}, {"pacify", "pacifi"}, {"pacing", "pace"}, {"pack", "pack"}, {"packag", "packag"},
+		{"package", "packag"}, {"packaged", "packag"}, {"packager", "packag"}, {"packages", "packag"}, {"packaging", "packag"},
+		{"packed", "pack"}, {"packet", "packet"}, {"packetization", "packet"}, {"packets", "packet"}, {"packing", "pack"}, {"packs", "pack"},
+		{"pact", "pact"}, {"pad", "pad"}, {"padded", "pad"}, {"padding", "pad"}, {"paddle", "paddl"}, {"paddock", "paddock"},
+		{"padlock", "padlock"}, {"pads", "pad"}, {"paediatrician", "paediatrician"}, {"paediatrics", "paediatr"}, {"pagan", "pagan"},
+		{"paganism", "pagan"}, {"page", "page"}, {"pageant", "pageant"}, {"pageantry", "pageantri"}, {"paged", "page"}, {"pager", "pager"},
+		{"pagers", "pager"}, {"pages", "page"}, {"paging", "page"}, {"pagoda", "pagoda"}, {"pail", "pail"}, {"pain", "pain"},
+		{"painful", "pain"}, {"painkiller", "painkil"}, {"painless", "painless"}, {"painstaking", "painstak"}, {"paint", "paint"},
+		{"paintbrush", "paintbrush"}, {"painted", "paint"}, {"painter", "painter"}, {"painting", "paint"}, {"paints", "paint"}, {"pair", "pair"},
+		{"paired", "pair"}, {"pairing", "pair"}, {"pairs", "pair"}, {"pal", "pal"}, {"palace", "palac"}, {"palatable", "palat"},
+		{"palate", "palat"}, {"palatial", "palati"}, {"pale", "pale"}, {"palette", "palett"}, {"palettes", "palett"}, {"pall", "pall"},
+		{"pallbearer", "pallbear"}, {"pallet", "pallet"}, {"palliative", "palliat"}, {"pallid", "pallid"}, {"pallor", "pallor"},
+		{"palm", "palm"}, {"palmistry", "palmistri"}, {"palpable", "palpabl"}, {"palpitate", "palpit"}, {"palpitation", "palpit"},
+		{"paltry", "paltri"}, {"pamper", "pamper"}, {"pamphlet", "pamphlet"}, {"pan", "pan"}, {"panacea", "panacea"}, {"panache", "panach"},
+		{"pancake", "pancak"}, {"pancreas", "pancrea"}, {"panda", "panda"}, {"pandas", "panda"}, {"pandemonium", "pandemonium"},
+		{"pander", "pander"}, {"pane", "pane"}, {"paned", "pane"}, {"panel", "panel"}, {"panes", "pane"}, {"pang", "pang"}, {"panic", "panic"},
+		{"panics", "panic"}, {"panorama", "panorama"}, {"panoramic", "panoram"}, {"pansy", "pansi"}, {"pant", "pant"}, {"panther", "panther"},
+		{"panties", "panti"}, {"pantomime", "pantomim"}, {"pantry", "pantri"}, {"pants", "pant"}, {"papa", "papa"}, {"papacy", "papaci"},
+		{"papal", "papal"}, {"paper", "paper"}, {"paperback", "paperback"}, {"papered", "paper"}, {"papers", "paper"},
+		{"paperweight", "paperweight"}, {"paperwork", "paperwork"}, {"paprika", "paprika"}, {"papyrus", "papyrus"}, {"par", "par"},
+		{"parable", "parabl"}, {"parachute", "parachut"}, {"parachutist", "parachutist"}, {"parade", "parad"}, {"paradigm", "paradigm"},
+		{"paradigms", "paradigm"}, {"paradise", "paradis"}, {"paradox", "paradox"}, {"paradoxical", "paradox"}, {"paraffin", "paraffin"},
+		{"paragon", "paragon"}, {"paragraph", "paragraph"}, {"paragraphs", "paragraph"}, {"parakeet", "parakeet"}, {"parallel", "parallel"},
+		{"parallelism", "parallel"}, {"parallelization", "parallel"}, {"parallelize", "parallel"}, {"parallelized", "parallel"},
+		{"parallelizes", "parallel"}, {"parallelogram", "parallelogram"}, {"parallels", "parallel"}, {"paralyse", "paralys"},
+		{"paralysis", "paralysi"}, {"paralytic", "paralyt"}, {"paralyze", "paralyz"}, {"paramedic", "paramed"}, {"parameter", "paramet"},
+		{"parameters", "paramet"}, {"paramilitary", "paramilitari"}, {"paramount", "paramount"}, {"paranoia", "paranoia"},
+		{"paranoid", "paranoid"}, {"paranormal", "paranorm"}, {"parapet", "parapet"}, {"paraphernalia", "paraphernalia"},
+		{"paraphrase", "paraphras"}, {"paraplegic", "parapleg"}, {"parasite", "parasit"}, {"parasitic", "parasit"}, {"parasol", "parasol"},
+		{"paratrooper", "paratroop"}, {"parcel", "parcel"}, {"parch", "parch"}, {"parchment", "parchment"}, {"pardon", "pardon"},
+		{"pardonable", "pardon"}, {"pare", "pare"}, {"parent", "parent"}, {"parentage", "parentag"}, {"parental", "parent"},
+		{"parented", "parent"}, {"parenthesis", "parenthesi"}, {"parenthetical", "parenthet"}, {"parenthood", "parenthood"},
+		{"parents", "parent"}, {"pariah", "pariah"}, {"parish", "parish"}, {"parishioner", "parishion"}, {"parity", "pariti"}, {"park", "park"},
+		{"parka", "parka"}, {"parked", "park"}, {"parliament", "parliament"}, {"parliamentarian", "parliamentarian"},
+		{"parliamentary", "parliamentari"}, {"parlor", "parlor"}, {"parlour", "parlour"}, {"parochial", "parochi"}, {"parody", "parodi"},
+		{"parole", "parol"}, {"paroxysm", "paroxysm"}, {"parquet", "parquet"}, {"parrot", "parrot"}, {"parrotting", "parrot"},
+		{"parry", "parri"}, {"parse", "pars"}, {"parsed", "pars"}, {"parses", "pars"}, {"parsimonious", "parsimoni"}, {"parsing", "pars"},
+		{"parsley", "parsley"}, {"parsnip", "parsnip"}, {"parson", "parson"}, {"parsonage", "parsonag"}, {"part", "part"}, {"partake", "partak"},
+		{"parted", "part"}, {"partial", "partial"}, {"partiality", "partial"}, {"partially", "partial"}, {"participant", "particip"},
+		{"participants", "particip"}, {"participate", "particip"}, {"participates", "particip"}, {"participating", "particip"},
+		{"participation", "particip"}, {"participle", "participl"}, {"particle", "particl"}, {"particles", "particl"},
+		{"particular", "particular"}, {"particularly", "particular"}, {"particulars", "particular"}, {"parties", "parti"}, {"parting", "part"},
+		{"partisan", "partisan"}, {"partition", "partit"}, {"partitioned", "partit"}, {"partitioning", "partit"}, {"partitions", "partit"},
+		{"partly", "part"}, {"partner", "partner"}, {"partners", "partner"}, {"partnership", "partnership"}, {"partridge", "partridg"},
+		{"parts", "part"}, {"party", "parti"}, {"pass", "pass"}, {"passable", "passabl"}, {"passage", "passag"}, {"passages", "passag"},
+		{"passageway", "passageway"}, {"passed", "pass"}, {"passenger", "passeng"}, {"passer", "passer"}, {"passes", "pass"},
+		{"passing", "pass"}, {"passion", "passion"}, {"passionate", "passion"}, {"passive", "passiv"}, {"passively", "passiv"},
+		{"passivity", "passiv"}, {"passport", "passport"}, {"password", "password"}, {"passwords", "password"}, {"past", "past"},
+		{"pasta", "pasta"}, {"paste", "past"}, {"pasted", "past"}, {"pastel", "pastel"}, {"pasteurize", "pasteur"}, {"pastiche", "pastich"},
+		{"pastime", "pastim"}, {"pasting", "past"}, {"pastor", "pastor"}, {"pastoral", "pastor"}, {"pastry", "pastri"}, {"pasture", "pastur"},
+		{"pasty", "pasti"}, {"pat", "pat"}, {"patch", "patch"}, {"patched", "patch"}, {"patches", "patch"}, {"patching", "patch"},
+		{"patchwork", "patchwork"}, {"patchy", "patchi"}, {"pate", "pate"}, {"patent", "patent"}, {"patents", "patent"}, {"paternal", "patern"},
+		{"paternalism", "patern"}, {"paternity", "patern"}, {"path", "path"}, {"pathetic", "pathet"}, {"pathological", "patholog"},
+		{"pathologically", "patholog"}, {"pathologist", "pathologist"}, {"pathology", "patholog"}, {"pathos", "patho"}, {"paths", "path"},
+		{"pathway", "pathway"}, {"pathways", "pathway"}, {"patience", "patienc"}, {"patient", "patient"}, {"patio", "patio"},
+		{"patriarch", "patriarch"}, {"patriarchal", "patriarch"}, {"patriot", "patriot"}, {"patriotic", "patriot"}, {"patriotism", "patriot"},
+		{"patrol", "patrol"}, {"patrolman", "patrolman"}, {"patron", "patron"}, {"patronage", "patronag"}, {"patronize", "patron"},
+		{"patter", "patter"}, {"pattern", "pattern"}, {"patterns", "pattern"}, {"paucity", "pauciti"}, {"paunch", "paunch"},
+		{"pauper", "pauper"}, {"pause", "paus"}, {"paused", "paus"}, {"pauses", "paus"}, {"pausing", "paus"}, {"pave", "pave"},
+		{"pavement", "pavement"}, {"paves", "pave"}, {"pavilion", "pavilion"}, {"paw", "paw"}, {"pawn", "pawn"}, {"pawnbroker", "pawnbrok"},
+		{"paws", "paw"}, {"pay", "pay"}, {"payable", "payabl"}, {"payee", "paye"}, {"payer", "payer"}, {"paying", "pay"}, {"payment", "payment"},
+		{"payoff", "payoff"}, {"payroll", "payrol"}, {"pays", "pay"}, {"pea", "pea"}, {"peace", "peac"}, {"peaceable", "peaceabl"},
+		{"peaceful", "peac"}, {"peacekeeping", "peacekeep"}, {"peacetime", "peacetim"}, {"peach", "peach"}, {"peacock", "peacock"},
+		{"peak", "peak"}, {"peal", "peal"}, {"peanut", "peanut"}, {"pear", "pear"}, {"pearl", "pearl"}, {"peasant", "peasant"},
+		{"peasantry", "peasantri"}, {"peat", "peat"}, {"pebble", "pebbl"}, {"pecan", "pecan"}, {"peck", "peck"}, {"peculiar", "peculiar"},
+		{"peculiarity", "peculiar"}, {"pecuniary", "pecuniari"}, {"pedagogic", "pedagog"}, {"pedagogy", "pedagogi"}, {"pedal", "pedal"},
+		{"pedant", "pedant"}, {"pedantic", "pedant"}, {"peddle", "peddl"}, {"peddler", "peddler"}, {"pedestal", "pedest"},
+		{"pedestrian", "pedestrian"}, {"pediatrician", "pediatrician"}, {"pediatrics", "pediatr"}, {"pedigree", "pedigre"}, {"pedlar", "pedlar"},
+		{"pee", "pee"}, {"peek", "peek"}, {"peeked", "peek"}, {"peeking", "peek"}, {"peeks", "peek"}, {"peel", "peel"}, {"peeled", "peel"},
+		{"peeling", "peel"}, {"peep", "peep"}, {"peer", "peer"}, {"peerage", "peerag"}, {"peered", "peer"}, {"peering", "peer"},
+		{"peerings", "peer"}, {"peerless", "peerless"}, {"peers", "peer"}, {"peevish", "peevish"}, {"peg", "peg"}, {"pejorative", "pejor"},
+		{"pelican", "pelican"}, {"pellet", "pellet"}, {"pelt", "pelt"}, {"pelvic", "pelvic"}, {"pelvis", "pelvi"}, {"pen", "pen"},
+		{"penal", "penal"}, {"penalize", "penal"}, {"penalized", "penal"}, {"penalties", "penalti"}, {"penalty", "penalti"},
+		{"penance", "penanc"}, {"penchant", "penchant"}, {"pencil", "pencil"}, {"pendant", "pendant"}, {"pending", "pend"},
+		{"pendulum", "pendulum"}, {"penetrate", "penetr"}, {"penetration", "penetr"}, {"penguin", "penguin"}, {"penicillin", "penicillin"},
+		{"peninsula", "peninsula"}, {"penis", "peni"}, {"penitence", "penit"}, {"penitent", "penit"}, {"penitentiary", "penitentiari"},
+		{"penknife", "penknif"}, {"pennant", "pennant"}, {"penniless", "penniless"}, {"penny", "penni"}, {"pension", "pension"},
+		{"pensioner", "pension"}, {"pensive", "pensiv"}, {"pentagon", "pentagon"}, {"penthouse", "penthous"}, {"penultimate", "penultim"},
+		{"penury", "penuri"}, {"people", "peopl"}, {"peoples", "peopl"}, {"pep", "pep"}, {"pepper", "pepper"}, {"peppermint", "peppermint"},
+		{"per", "per"}, {"perceive", "perceiv"}, {"perceived", "perceiv"}, {"percent", "percent"}, {"percentage", "percentag"},
+		{"percentages", "percentag"}, {"perceptible", "percept"}, {"perception", "percept"}, {"perceptive", "percept"}, {"perch", "perch"},
+		{"percolate", "percol"}, {"percolator", "percol"}, {"percussion", "percuss"}, {"peremptory", "peremptori"}, {"perennial", "perenni"},
+		{"perfect", "perfect"}, {"perfection", "perfect"}, {"perfectionist", "perfectionist"}, {"perfectly", "perfect"}, {"perforate", "perfor"},
+		{"perforation", "perfor"}, {"perform", "perform"}, {"performance", "perform"}, {"performant", "perform"}, {"performed", "perform"},
+		{"performer", "perform"}, {"performing", "perform"}, {"performs", "perform"}, {"perfume", "perfum"}, {"perfunctory", "perfunctori"},
+		{"perhaps", "perhap"}, {"peril", "peril"}, {"perilous", "peril"}, {"perimeter", "perimet"}, {"perimeters", "perimet"},
+		{"period", "period"}, {"periodic", "period"}, {"periodical", "period"}, {"periodically", "period"}, {"periods", "period"},
+		{"peripheral", "peripher"}, {"periphery", "peripheri"}, {"periscope", "periscop"}, {"perish", "perish"}, {"perishable", "perish"},
+		{"perjure", "perjur"}, {"perjury", "perjuri"}, {"perk", "perk"}, {"perky", "perki"}, {"perm", "perm"}, {"permanence", "perman"},
+		{"permanent", "perman"}, {"permanently", "perman"}, {"permeable", "permeabl"}, {"permeate", "permeat"}, {"permissible", "permiss"},
+		{"permission", "permiss"}, {"permissions", "permiss"}, {"permissive", "permiss"}, {"permit", "permit"}, {"permits", "permit"},
+		{"permitted", "permit"}, {"permitting", "permit"}, {"perms", "perm"}, {"permutation", "permut"}, {"permutations", "permut"},
+		{"permute", "permut"}, {"permuted", "permut"}, {"permutes", "permut"}, {"pernicious", "pernici"}, {"perpendicular", "perpendicular"},
+		{"perpetrate", "perpetr"}, {"perpetrator", "perpetr"}, {"perpetual", "perpetu"}, {"perpetuate", "perpetu"}, {"perpetuity", "perpetu"},
+		{"perplex", "perplex"}, {"perplexity", "perplex"}, {"persecute", "persecut"}, {"persecution", "persecut"}, {"persecutor", "persecutor"},
+		{"perseverance", "persever"}, {"persevere", "persever"}, {"persist", "persist"}, {"persisted", "persist"}, {"persistence", "persist"},
+		{"persistent", "persist"}, {"persistently", "persist"}, {"persisting", "persist"}, {"persists", "persist"}, {"person", "person"},
+		{"persona", "persona"}, {"personable", "person"}, {"personage", "personag"}, {"personal", "person"}, {"personalities", "person"},
+		{"personality", "person"}, {"personalization", "person"}, {"personalize", "person"}, {"personalized", "person"},
+		{"personally", "person"}, {"personification", "personif"}, {"personify", "personifi"}, {"personnel", "personnel"}, {"persons", "person"},
+		{"perspective", "perspect"}, {"perspiration", "perspir"}, {"perspire", "perspir"}, {"persuade", "persuad"}, {"persuasion", "persuas"},
+		{"persuasive", "persuas"}, {"pert", "pert"}, {"pertain", "pertain"}, {"pertaining", "pertain"}, {"pertains", "pertain"},
+		{"pertinent", "pertin"}, {"perturb", "perturb"}, {"perturbation", "perturb"}, {"perusal", "perus"}, {"peruse", "perus"},
+		{"pervade", "pervad"}, {"pervasive", "pervas"}, {"perverse", "pervers"}, {"perversion", "pervers"}, {"perversity", "pervers"},
+		{"pervert", "pervert"}, {"pessimism", "pessim"}, {"pessimist", "pessimist"}, {"pessimistic", "pessimist"}, {"pest", "pest"},
+		{"pester", "pester"}, {"pesticide", "pesticid"}, {"pestle", "pestl"}, {"pet", "pet"}, {"petal", "petal"}, {"peter", "peter"},
+		{"peters", "peter"}, {"petite", "petit"}, {"petition", "petit"}, {"petrify", "petrifi"}, {"petrol", "petrol"},
+		{"petroleum", "petroleum"}, {"petticoat", "petticoat"}, {"petty", "petti"}, {"petulant", "petul"}, {"pew", "pew"}, {"pewter", "pewter"},
+		{"phantom", "phantom"}, {"pharaoh", "pharaoh"}, {"pharmaceutical", "pharmaceut"}, {"pharmacist", "pharmacist"},
+		{"pharmacology", "pharmacolog"}, {"pharmacy", "pharmaci"}, {"phase", "phase"}, {"phased", "phase"}, {"phases", "phase"},
+		{"pheasant", "pheasant"}, {"phenomenal", "phenomen"}, {"phenomenon", "phenomenon"}, {"phial", "phial"}, {"philanthropic", "philanthrop"},
+		{"philanthropist", "philanthropist"}, {"philanthropy", "philanthropi"}, {"philately", "philat"}, {"philosopher", "philosoph"},
+		{"philosophical", "philosoph"}, {"philosophize", "philosoph"}, {"philosophy", "philosophi"}, {"phlegm", "phlegm"},
+		{"phlegmatic", "phlegmat"}, {"phobia", "phobia"}, {"phoenix", "phoenix"}, {"phone", "phone"}, {"phonetic", "phonet"},
+		{"phonetics", "phonet"}, {"phoney", "phoney"}, {"phony", "phoni"}, {"phosphate", "phosphat"}, {"phosphorus", "phosphorus"},
+		{"photo", "photo"}, {"photocopier", "photocopi"}, {"photocopy", "photocopi"}, {"photogenic", "photogen"}, {"photograph", "photograph"},
+		{"photographer", "photograph"}, {"photographic", "photograph"}, {"photography", "photographi"}, {"photos", "photo"},
+		{"phrase", "phrase"}, {"phrased", "phrase"}, {"phraseology", "phraseolog"}, {"phrases", "phrase"}, {"phrasing", "phrase"},
+		{"physical", "physic"}, {"physically", "physic"}, {"physician", "physician"}, {"physicist", "physicist"}, {"physics", "physic"},
+		{"physiological", "physiolog"}, {"physiology", "physiolog"}, {"physiotherapist", "physiotherapist"}, {"physiotherapy", "physiotherapi"},
+		{"physique", "physiqu"}, {"pianist", "pianist"}, {"piano", "piano"}, {"piccolo", "piccolo"}, {"pick", "pick"}, {"picked", "pick"},
+		{"picket", "picket"}, {"picking", "pick"}, {"pickle", "pickl"}, {"pickpocket", "pickpocket"}, {"picks", "pick"}, {"picnic", "picnic"},
+		{"pictorial", "pictori"}, {"picture", "pictur"}, {"pictures", "pictur"}, {"picturesque", "picturesqu"}, {"pie", "pie"},
+		{"piece", "piec"}, {"piecemeal", "piecem"}, {"pieces", "piec"}, {"pier", "pier"}, {"pierce", "pierc"}, {"pies", "pie"},
+		{"piety", "pieti"}, {"pig", "pig"}, {"pigeon", "pigeon"}, {"piggyback", "piggyback"}, {"pigheaded", "pighead"}, {"piglet", "piglet"},
+		{"pigment", "pigment"}, {"pigsty", "pigsti"}, {"pike", "pike"}, {"pile", "pile"}, {"pilfer", "pilfer"}, {"pilgrim", "pilgrim"},
+		{"pilgrimage", "pilgrimag"}, {"pill", "pill"}, {"pillage", "pillag"}, {"pillar", "pillar"}, {"pillion", "pillion"}, {"pillow", "pillow"},
+		{"pillowcase", "pillowcas"}, {"pilot", "pilot"}, {"pimp", "pimp"}, {"pimple", "pimpl"}, {"pin", "pin"}, {"pinafore", "pinafor"},
+		{"pincer", "pincer"}, {"pinch", "pinch"}, {"pine", "pine"}, {"pineapple", "pineappl"}, {"ping", "ping"}, {"pinged", "ping"},
+		{"pinging", "ping"}, {"pings", "ping"}, {"pink", "pink"}, {"pinnacle", "pinnacl"}, {"pinned", "pin"}, {"pinning", "pin"},
+		{"pinpoint", "pinpoint"}, {"pins", "pin"}, {"pint", "pint"}, {"pioneer", "pioneer"}, {"pious", "pious"}, {"pip", "pip"},
+		{"pipe", "pipe"}, {"piped", "pipe"}, {"pipeline", "pipelin"}, {"pipelined", "pipelin"}, {"pipelines", "pipelin"},
+		{"pipelining", "pipelin"}, {"piper", "piper"}, {"pipes", "pipe"}, {"piping", "pipe"}, {"piquant", "piquant"}, {"pique", "piqu"},
+		{"piracy", "piraci"}, {"piranha", "piranha"}, {"pirate", "pirat"}, {"pirouette", "pirouett"}, {"pistachio", "pistachio"},
+		{"pistol", "pistol"}, {"piston", "piston"}, {"pit", "pit"}, {"pitch", "pitch"}, {"pitcher", "pitcher"}, {"pitchfork", "pitchfork"},
+		{"piteous", "piteous"}, {"pitfall", "pitfal"}, {"pitfalls", "pitfal"}, {"pith", "pith"}, {"pitiable", "pitiabl"}, {"pitiful", "piti"},
+		{"pitiless", "pitiless"}, {"pittance", "pittanc"}, {"pity", "piti"}, {"pivot", "pivot"}, {"pivotal", "pivot"}, {"pixel", "pixel"},
+		{"pixels", "pixel"}, {"pixie", "pixi"}, {"pizza", "pizza"}, {"placard", "placard"}, {"placate", "placat"}, {"place", "place"},
+		{"placed", "place"}, {"placement", "placement"}, {"placements", "placement"}, {"places", "place"}, {"placid", "placid"},
+		{"placing", "place"}, {"plagiarism", "plagiar"}, {"plagiarize", "plagiar"}, {"plagiarized", "plagiar"}, {"plague", "plagu"},
+		{"plaice", "plaic"}, {"plaid", "plaid"}, {"plain", "plain"}, {"plainly", "plain"}, {"plaintiff", "plaintiff"}, {"plaintive", "plaintiv"},
+		{"plait", "plait"}, {"plan", "plan"}, {"plane", "plane"}, {"planes", "plane"}, {"planet", "planet"}, {"planetarium", "planetarium"},
+		{"planetary", "planetari"}, {"plank", "plank"}, {"plankton", "plankton"}, {"planned", "plan"}, {"planner", "planner"},
+		{"planning", "plan"}, {"plans", "plan"}, {"plant", "plant"}, {"plantation", "plantat"}, {"planter", "planter"}, {"planting", "plant"},
+		{"plaque", "plaqu"}, {"plasma", "plasma"}, {"plaster", "plaster"}, {"plasterboard", "plasterboard"}, {"plasterer", "plaster"},
+		{"plastic", "plastic"}, {"plate", "plate"}, {"plateau", "plateau"}, {"platform", "platform"}, {"platforms", "platform"},
+		{"platinum", "platinum"}, {"platitude", "platitud"}, {"platonic", "platon"}, {"platoon", "platoon"}, {"platter", "platter"},
+		{"plaudits", "plaudit"}, {"plausible", "plausibl"}, {"play", "play"}, {"playback", "playback"}, {"playboy", "playboy"},
+		{"player", "player"}, {"players", "player"}, {"playful", "play"}, {"playground", "playground"}, {"playing", "play"},
+		{"playmate", "playmat"}, {"playpen", "playpen"}, {"plays", "play"}, {"plaything", "playth"}, {"playwright", "playwright"},
+		{"plaza", "plaza"}, {"plea", "plea"}, {"plead", "plead"}, {"pleasant", "pleasant"}, {"pleasantry", "pleasantri"}, {"please", "pleas"},
+		{"pleasurable", "pleasur"}, {"pleasure", "pleasur"}, {"pleat", "pleat"}, {"pleb", "pleb"}, {"plebeian", "plebeian"},
+		{"plebiscite", "plebiscit"}, {"pledge", "pledg"}, {"plenary", "plenari"}, {"plentiful", "plenti"}, {"plenty", "plenti"},
+		{"plethora", "plethora"}, {"pliable", "pliabl"}, {"pliant", "pliant"}, {"pliers", "plier"}, {"plight", "plight"}, {"plinth", "plinth"},
+		{"plod", "plod"}, {"plonk", "plonk"}, {"plop", "plop"}, {"plot", "plot"}, {"plots", "plot"}, {"plough", "plough"}, {"plow", "plow"},
+		{"ploy", "ploy"}, {"pluck", "pluck"}, {"plucky", "plucki"}, {"plug", "plug"}, {"plugged", "plug"}, {"plugging", "plug"},
+		{"plum", "plum"}, {"plumage", "plumag"}, {"plumb", "plumb"}, {"plumber", "plumber"}, {"plumbing", "plumb"}, {"plume", "plume"},
+		{"plummet", "plummet"}, {"plump", "plump"}, {"plunder", "plunder"}, {"plunge", "plung"}, {"plural", "plural"}, {"pluralism", "plural"},
+		{"plurality", "plural"}, {"plus", "plus"}, {"plush", "plush"}, {"plutonium", "plutonium"}, {"ply", "pli"}, {"plywood", "plywood"},
+		{"pneumatic", "pneumat"}, {"pneumonia", "pneumonia"}, {"poach", "poach"}, {"poacher", "poacher"}, {"pocket", "pocket"},
+		{"pocketbook", "pocketbook"}, {"pod", "pod"}, {"podgy", "podgi"}, {"podium", "podium"}, {"pods", "pod"}, {"poem", "poem"},
+		{"poet", "poet"}, {"poetic", "poetic"}, {"poetry", "poetri"}, {"poets", "poet"}, {"poignancy", "poignanc"}, {"poignant", "poignant"},
+		{"point", "point"}, {"pointed", "point"}, {"pointer", "pointer"}, {"pointers", "pointer"}, {"pointing", "point"},
+		{"pointless", "pointless"}, {"pointlessly", "pointless"}, {"points", "point"}, {"poise", "pois"}, {"poison", "poison"},
+		{"poisoned", "poison"}, {"poisoning", "poison"}, {"poisonous", "poison"}, {"poke", "poke"}, {"poker", "poker"}, {"polar", "polar"},
+		{"polarity", "polar"}, {"polarization", "polar"}, {"polarize", "polar"}, {"pole", "pole"}, {"polemic", "polem"}, {"polemical", "polem"},
+		{"poles", "pole"}, {"police", "polic"}, {"policeman", "policeman"}, {"policer", "polic"}, {"policewoman", "policewoman"},
+		{"policie", "polici"}, {"policies", "polici"}, {"policing", "polic"}, {"policy", "polici"}, {"polio", "polio"}, {"polish", "polish"},
+		{"polished", "polish"}, {"polite", "polit"}, {"politic", "polit"}, {"political", "polit"}, {"politician", "politician"},
+		{"politics", "polit"}, {"polity", "politi"}, {"polka", "polka"}, {"poll", "poll"}, {"polled", "poll"}, {"pollen", "pollen"},
+		{"pollin", "pollin"}, {"pollinate", "pollin"}, {"pollination", "pollin"}, {"polling", "poll"}, {"polls", "poll"},
+		{"pollster", "pollster"}, {"pollutant", "pollut"}, {"pollute", "pollut"}, {"polluting", "pollut"}, {"pollution", "pollut"},
+		{"polo", "polo"}, {"polyester", "polyest"}, {"polygamy", "polygami"}, {"polygon", "polygon"}, {"polygons", "polygon"},
+		{"polygraph", "polygraph"}, {"polystyrene", "polystyren"}, {"polytechnic", "polytechn"}, {"polythene", "polythen"},
+		{"pomegranate", "pomegran"}, {"pomp", "pomp"}, {"pompous", "pompous"}, {"pond", "pond"}, {"ponder", "ponder"}, {"ponderous", "ponder"},
+		{"pony", "poni"}, {"ponytail", "ponytail"}, {"poodle", "poodl"}, {"pool", "pool"}, {"poole", "pool"}, {"pooled", "pool"},
+		{"pooling", "pool"}, {"pools", "pool"}, {"poor", "poor"}, {"poorly", "poor"}, {"pop", "pop"}, {"popcorn", "popcorn"}, {"pope", "pope"},
+		{"poplar", "poplar"}, {"popped", "pop"}, {"popping", "pop"}, {"poppy", "poppi"}, {"pops", "pop"}, {"populace", "populac"},
+		{"popular", "popular"}, {"popularity", "popular"}, {"popularize", "popular"}, {"populate", "popul"}, {"populated", "popul"},
+		{"populates", "popul"}, {"populating", "popul"}, {"population", "popul"}, {"populist", "populist"}, {"populous", "popul"},
+		{"porcelain", "porcelain"}, {"porcelains", "porcelain"}, {"porch", "porch"}, {"porcupine", "porcupin"}, {"pore", "pore"},
+		{"pork", "pork"}, {"pornographic", "pornograph"}, {"pornography", "pornographi"}, {"porous", "porous"}, {"porpoise", "porpois"},
+		{"porridge", "porridg"}, {"port", "port"}, {"portability", "portabl"}, {"portable", "portabl"}, {"portabled", "portabl"},
+		{"portables", "portabl"}, {"portably", "portabl"}, {"portal", "portal"}, {"ported", "port"}, {"portend", "portend"},
+		{"portent", "portent"}, {"portentous", "portent"}, {"porter", "porter"}, {"porters", "porter"}, {"portfolio", "portfolio"},
+		{"porthole", "porthol"}, {"porting", "port"}, {"portion", "portion"}, {"portions", "portion"}, {"portly", "port"},
+		{"portrait", "portrait"}, {"portraiture", "portraitur"}, {"portray", "portray"}, {"portrayal", "portray"}, {"ports", "port"},
+		{"pose", "pose"}, {"poser", "poser"}, {"poses", "pose"}, {"posh", "posh"}, {"posing", "pose"}, {"position", "posit"},
+		{"positional", "posit"}, {"positionals", "posit"}, {"positioned", "posit"}, {"positioning", "posit"}, {"positions", "posit"},
+		{"positive", "posit"}, {"positively", "posit"}, {"positives", "posit"}, {"possess", "possess"}, {"possessed", "possess"},
+		{"possesses", "possess"}, {"possessing", "possess"}, {"possession", "possess"}, {"possessive", "possess"}, {"possessives", "possess"},
+		{"possessor", "possessor"}, {"possibilities", "possibl"}, {"possibility", "possibl"}, {"possible", "possibl"}, {"possibly", "possibl"},
+		{"post", "post"}, {"postage", "postag"}, {"postal", "postal"}, {"postbox", "postbox"}, {"postcard", "postcard"}, {"postcode", "postcod"},
+		{"posted", "post"}, {"poster", "poster"}, {"posterior", "posterior"}, {"posterity", "poster"}, {"postgraduate", "postgradu"},
+		{"posthumous", "posthum"}, {"posting", "post"}, {"postman", "postman"}, {"postmark", "postmark"}, {"postmaster", "postmast"},
+		{"postmasters", "postmast"}, {"postmortem", "postmortem"}, {"postpone", "postpon"}, {"postponed", "postpon"},
+		{"postponement", "postpon"}, {"postponing", "postpon"}, {"posts", "post"}, {"postscript", "postscript"}, {"postulate", "postul"},
+		{"posture", "postur"}, {"postures", "postur"}, {"postwar", "postwar"}, {"posy", "posi"}, {"pot", "pot"}, {"potash", "potash"},
+		{"potassium", "potassium"}, {"potato", "potato"}, {"potency", "potenc"}, {"potent", "potent"}, {"potentate", "potent"},
+		{"potential", "potenti"}, {"potentially", "potenti"}, {"potion", "potion"}, {"potter", "potter"}, {"pottery", "potteri"},
+		{"potty", "potti"}, {"pouch", "pouch"}, {"poultice", "poultic"}, {"poultry", "poultri"}, {"pounce", "pounc"}, {"pound", "pound"},
+		{"pour", "pour"}, {"pout", "pout"}, {"poverty", "poverti"}, {"powder", "powder"}, {"powdery", "powderi"}, {"power", "power"},
+		{"powered", "power"}, {"powerful", "power"}, {"powerhouse", "powerhous"}, {"powering", "power"}, {"powerless", "powerless"},
+		{"powers", "power"}, {"practicable", "practic"}, {"practical", "practic"}, {"practicality", "practic"}, {"practically", "practic"},
+		{"practice", "practic"}, {"practices", "practic"}, {"practise", "practis"}, {"practises", "practis"}, {"practitioner", "practition"},
+		{"pragmatic", "pragmat"}, {"pragmatism", "pragmat"}, {"pragmatist", "pragmatist"}, {"prairie", "prairi"}, {"praise", "prais"},
+		{"praiseworthy", "praiseworthi"}, {"pram", "pram"}, {"prance", "pranc"}, {"prank", "prank"}, {"prattle", "prattl"}, {"prawn", "prawn"},
+		{"pray", "pray"}, {"prayer", "prayer"}, {"preach", "preach"}, {"preacher", "preacher"}, {"preamble", "preambl"},
+		{"precarious", "precari"}, {"precaution", "precaut"}, {"precautionary", "precautionari"}, {"precautions", "precaut"},
+		{"precede", "preced"}, {"preceded", "preced"}, {"precedence", "preced"}, {"precedent", "preced"}, {"precedes", "preced"},
+		{"preceding", "preced"}, {"precept", "precept"}, {"precinct", "precinct"}, {"precious", "precious"}, {"precipice", "precipic"},
+		{"precipitate", "precipit"}, {"precipitation", "precipit"}, {"precipitous", "precipit"}, {"precis", "preci"}, {"precise", "precis"},
+		{"precisely", "precis"}, {"precision", "precis"}, {"preclude", "preclud"}, {"precocious", "precoci"}, {"preconceived", "preconceiv"},
+		{"preconception", "preconcept"}, {"precondition", "precondit"}, {"preconditions", "precondit"}, {"precursor", "precursor"},
+		{"predate", "predat"}, {"predated", "predat"}, {"predates", "predat"}, {"predator", "predat"}, {"predatory", "predatori"},
+		{"predecessor", "predecessor"}, {"predestination", "predestin"}, {"predetermine", "predetermin"}, {"predetermined", "predetermin"},
+		{"predicable", "predic"}, {"predicament", "predica"}, {"predicate", "predic"}, {"predicates", "predic"}, {"predication", "predic"},
+		{"predict", "predict"}, {"predictable", "predict"}, {"predicted", "predict"}, {"predicting", "predict"}, {"prediction", "predict"},
+		{"predictions", "predict"}, {"predictive", "predict"}, {"predicts", "predict"}, {"predilection", "predilect"},
+		{"predispose", "predispos"}, {"predisposition", "predisposit"}, {"predominance", "predomin"}, {"predominant", "predomin"},
+		{"predominate", "predomin"}, {"preeminent", "preemin"}, {"preempt", "preempt"}, {"preempted", "preempt"}, {"preen", "preen"},
+		{"prefab", "prefab"}, {"prefabricate", "prefabr"}, {"preface", "prefac"}, {"prefaced", "prefac"}, {"prefacing", "prefac"},
+		{"prefect", "prefect"}, {"prefer", "prefer"}, {"preferable", "prefer"}, {"preferably", "prefer"}, {"preference", "prefer"},
+		{"preferences", "prefer"}, {"preferential", "preferenti"}, {"preferentially", "preferenti"}, {"prefering", "prefer"},
+		{"preferment", "prefer"}, {"preferred", "prefer"}, {"preferring", "prefer"}, {"prefers", "prefer"}, {"prefix", "prefix"},
+		{"prefixed", "prefix"}, {"prefixes", "prefix"}, {"prefixing", "prefix"}, {"pregnancy", "pregnanc"}, {"pregnant", "pregnant"},
+		{"prehistoric", "prehistor"}, {"prehistory", "prehistori"}, {"prejudice", "prejudic"}, {"prejudicial", "prejudici"},
+		{"prelate", "prelat"}, {"preliminary", "preliminari"}, {"prelude", "prelud"}, {"premarital", "premarit"}, {"premature", "prematur"},
+		{"prematurely", "prematur"}, {"premeditate", "premedit"}, {"premeditation", "premedit"}, {"premier", "premier"}, {"premiere", "premier"},
+		{"premise", "premis"}, {"premises", "premis"}, {"premium", "premium"}, {"premonition", "premonit"}, {"preoccupation", "preoccup"},
+		{"preoccupy", "preoccupi"}, {"preparation", "prepar"}, {"preparations", "prepar"}, {"preparatory", "preparatori"}, {"prepare", "prepar"},
+		{"prepared", "prepar"}, {"prepares", "prepar"}, {"preparing", "prepar"}, {"preponderance", "preponder"}, {"preposition", "preposit"},
+		{"preposterous", "preposter"}, {"prerequisite", "prerequisit"}, {"prerequisites", "prerequisit"}, {"prerogative", "prerog"},
+		{"presage", "presag"}, {"prescribe", "prescrib"}, {"prescribed", "prescrib"}, {"prescribes", "prescrib"}, {"prescription", "prescript"},
+		{"prescriptive", "prescript"}, {"presence", "presenc"}, {"present", "present"}, {"presentable", "present"}, {"presentation", "present"},
+		{"presented", "present"}, {"presenter", "present"}, {"presenting", "present"}, {"presently", "present"}, {"presents", "present"},
+		{"preservation", "preserv"}, {"preservative", "preserv"}, {"preserve", "preserv"}, {"preserved", "preserv"}, {"preserves", "preserv"},
+		{"preserving", "preserv"}, {"preside", "presid"}, {"presidency", "presid"}, {"president", "presid"}, {"presidential", "presidenti"},
+		{"press", "press"}, {"pressed", "press"}, {"presses", "press"}, {"pressing", "press"}, {"pressure", "pressur"},
+		{"pressurize", "pressur"}, {"prestige", "prestig"}, {"prestigious", "prestigi"}, {"presumable", "presum"}, {"presumably", "presum"},
+		{"presume", "presum"}, {"presumed", "presum"}, {"presuming", "presum"}, {"presumption", "presumpt"}, {"presumptuous", "presumptu"},
+		{"presuppose", "presuppos"}, {"presupposition", "presupposit"}, {"pretence", "pretenc"}, {"pretend", "pretend"},
+		{"pretended", "pretend"}, {"pretender", "pretend"}, {"pretending", "pretend"}, {"pretends", "pretend"}, {"pretense", "pretens"},
+		{"pretension", "pretens"}, {"pretentious", "pretenti"}, {"pretext", "pretext"}, {"pretty", "pretti"}, {"prevail", "prevail"},
+		{"prevailing", "prevail"}, {"prevalence", "preval"}, {"prevalent", "preval"}, {"prevaricate", "prevar"}, {"prevent", "prevent"},
+		{"preventable", "prevent"}, {"prevented", "prevent"}, {"preventing", "prevent"}, {"prevention", "prevent"}, {"preventive", "prevent"},
+		{"prevents", "prevent"}, {"preview", "preview"}, {"previewed", "preview"}, {"previews", "preview"}, {"previous", "previous"},
+		{"previously", "previous"}, {"prey", "prey"}, {"pri", "pri"}, {"price", "price"}, {"priceless", "priceless"}, {"prices", "price"},
+		{"pricey", "pricey"}, {"pricing", "price"}, {"prick", "prick"}, {"prickle", "prickl"}, {"prickly", "prick"}, {"pride", "pride"},
+		{"priest", "priest"}, {"priesthood", "priesthood"}, {"prig", "prig"}, {"prim", "prim"}, {"primacy", "primaci"}, {"primal", "primal"},
+		{"primality", "primal"}, {"primaries", "primari"}, {"primary", "primari"}, {"primate", "primat"}, {"prime", "prime"},
+		{"primed", "prime"}, {"primer", "primer"}, {"primes", "prime"}, {"primeval", "primev"}, {"priming", "prime"}, {"primitive", "primit"},
+		{"primitives", "primit"}, {"primrose", "primros"}, {"prims", "prim"}, {"prince", "princ"}, {"princely", "princ"},
+		{"princess", "princess"}, {"principal", "princip"}, {"principality", "princip"}, {"principally", "princip"}, {"principals", "princip"},
+		{"principe", "princip"}, {"principle", "principl"}, {"principles", "principl"}, {"print", "print"}, {"printed", "print"},
+		{"printer", "printer"}, {"printers", "printer"}, {"printing", "print"}, {"printout", "printout"}, {"printouts", "printout"},
+		{"prints", "print"}, {"prior", "prior"}, {"priori", "priori"}, {"priorities", "prioriti"}, {"priority", "prioriti"},
+		{"priory", "priori"}, {"prise", "prise"}, {"prism", "prism"}, {"prison", "prison"}, {"prisoner", "prison"}, {"pristine", "pristin"},
+		{"privacy", "privaci"}, {"private", "privat"}, {"privately", "privat"}, {"privation", "privat"}, {"privatization", "privat"},
+		{"privatize", "privat"}, {"privilege", "privileg"}, {"privileged", "privileg"}, {"privileges", "privileg"}, {"privy", "privi"},
+		{"prize", "prize"}, {"pro", "pro"}, {"proactive", "proactiv"}, {"proactively", "proactiv"}, {"probabilities", "probabl"},
+		{"probability", "probabl"}, {"probable", "probabl"}, {"probably", "probabl"}, {"probate", "probat"}, {"probation", "probat"},
+		{"probationary", "probationari"}, {"probe", "probe"}, {"probed", "probe"}, {"probes", "probe"}, {"probing", "probe"},
+		{"probity", "probiti"}, {"problem", "problem"}, {"problematic", "problemat"}, {"problems", "problem"}, {"procedural", "procedur"},
+		{"procedure", "procedur"}, {"procedures", "procedur"}, {"proceed", "proceed"}, {"proceeding", "proceed"}, {"proceedings", "proceed"},
+		{"proceeds", "proceed"}, {"process", "process"}, {"processed", "process"}, {"processes", "process"}, {"processing", "process"},
+		{"procession", "process"}, {"processor", "processor"}, {"processors", "processor"}, {"proclaim", "proclaim"},
+		{"proclamation", "proclam"}, {"proclivity", "procliv"}, {"procrastinate", "procrastin"}, {"procrastination", "procrastin"},
+		{"procreate", "procreat"}, {"procreation", "procreat"}, {"procure", "procur"}, {"procurement", "procur"}, {"procuring", "procur"},
+		{"prod", "prod"}, {"prodigal", "prodig"}, {"prodigious", "prodigi"}, {"prodigy", "prodigi"}, {"produce", "produc"},
+		{"produced", "produc"}, {"producer", "produc"}, {"producers", "produc"}, {"produces", "produc"}, {"producing", "produc"},
+		{"product", "product"}, {"production", "product"}, {"productive", "product"}, {"productivity", "product"}, {"productized", "product"},
+		{"products", "product"}, {"profane", "profan"}, {"profanities", "profan"}, {"profanity", "profan"}, {"profess", "profess"},
+		{"profession", "profess"}, {"professional", "profession"}, {"professionalism", "profession"}, {"professionally", "profession"},
+		{"professor", "professor"}, {"proffer", "proffer"}, {"proficiency", "profici"}, {"proficient", "profici"}, {"profil", "profil"},
+		{"profile", "profil"}, {"profiled", "profil"}, {"profiler", "profil"}, {"profilers", "profil"}, {"profiles", "profil"},
+		{"profiling", "profil"}, {"profit", "profit"}, {"profitable", "profit"}, {"profiteer", "profit"}, {"profits", "profit"},
+		{"profligate", "proflig"}, {"profound", "profound"}, {"profundity", "profund"}, {"profuse", "profus"}, {"profusion", "profus"},
+		{"progeny", "progeni"}, {"prognosis", "prognosi"}, {"program", "program"}, {"programmable", "programm"}, {"programme", "programm"},
+		{"programmed", "program"}, {"programmer", "programm"}, {"programmers", "programm"}, {"programming", "program"}, {"programs", "program"},
+		{"progress", "progress"}, {"progresses", "progress"}, {"progression", "progress"}, {"progressive", "progress"},
+		{"progressively", "progress"}, {"prohibit", "prohibit"}, {"prohibited", "prohibit"}, {"prohibiting", "prohibit"},
+		{"prohibition", "prohibit"}, {"prohibitive", "prohibit"}, {"prohibitively", "prohibit"}, {"prohibits", "prohibit"},
+		{"project", "project"}, {"projected", "project"}, {"projectile", "projectil"}, {"projecting", "project"}, {"projection", "project"},
+		{"projections", "project"}, {"projector", "projector"}, {"projects", "project"}, {"proletarian", "proletarian"},
+		{"proletariat", "proletariat"}, {"proliferate", "prolifer"}, {"proliferation", "prolifer"}, {"prolific", "prolif"},
+		{"prologue", "prologu"}, {"prolong", "prolong"}, {"prolonged", "prolong"}, {"promenade", "promenad"}, {"prominence", "promin"},
+		{"prominent", "promin"}, {"prominently", "promin"}, {"promiscuity", "promiscu"}, {"promiscuous", "promiscu"},
+		{"promiscuously", "promiscu"}, {"promise", "promis"}, {"promised", "promis"}, {"promises", "promis"}, {"promissory", "promissori"},
+		{"promontory", "promontori"}, {"promotable", "promot"}, {"promote", "promot"}, {"promoted", "promot"}, {"promoter", "promot"},
+		{"promotes", "promot"}, {"promoting", "promot"}, {"promotion", "promot"}, {"promotional", "promot"}, {"promotions", "promot"},
+		{"prompt", "prompt"}, {"prompted", "prompt"}, {"prompting", "prompt"}, {"promptly", "prompt"}, {"prompts", "prompt"},
+		{"promulgate", "promulg"}, {"prone", "prone"}, {"prong", "prong"}, {"pronoun", "pronoun"}, {"pronounce", "pronounc"},
+		{"pronounced", "pronounc"}, {"pronouncement", "pronounc"}, {"pronouns", "pronoun"}, {"pronunciation", "pronunci"}, {"proof", "proof"},
+		{"proofing", "proof"}, {"proofread", "proofread"}, {"proofs", "proof"}, {"prop", "prop"}, {"propaganda", "propaganda"},
+		{"propagandist", "propagandist"}, {"propagate", "propag"}, {"propagated", "propag"}, {"propagates", "propag"}, {"propagating", "propag"},
+		{"propagation", "propag"}, {"propane", "propan"}, {"propel", "propel"}, {"propellant", "propel"}, {"propeller", "propel"},
+		{"propensity", "propens"}, {"proper", "proper"}, {"properly", "proper"}, {"properties", "properti"}, {"property", "properti"},
+		{"prophecy", "propheci"}, {"prophesy", "prophesi"}, {"prophet", "prophet"}, {"prophetic", "prophet"}, {"proponent", "propon"},
+		{"proportion", "proport"}, {"proportional", "proport"}, {"proportionally", "proport"}, {"proportionate", "proportion"},
+		{"proposal", "propos"}, {"proposals", "propos"}, {"propose", "propos"}, {"proposed", "propos"}, {"proposing", "propos"},
+		{"proposition", "proposit"}, {"proprietary", "proprietari"}, {"proprietor", "proprietor"}, {"propriety", "proprieti"}, {"props", "prop"},
+		{"propulsion", "propuls"}, {"prosaic", "prosaic"}, {"proscribe", "proscrib"}, {"prose", "prose"}, {"prosecute", "prosecut"},
+		{"prosecution", "prosecut"}, {"prosecutor", "prosecutor"}, {"prospect", "prospect"}, {"prospective", "prospect"},
+		{"prospector", "prospector"}, {"prospectus", "prospectus"}, {"prosper", "prosper"}, {"prosperity", "prosper"}, {"prosperous", "prosper"},
+		{"prostate", "prostat"}, {"prostitute", "prostitut"}, {"prostitution", "prostitut"}, {"prostrate", "prostrat"},
+		{"protagonist", "protagonist"}, {"protect", "protect"}, {"protected", "protect"}, {"protecting", "protect"}, {"protection", "protect"},
+		{"protectionism", "protection"}, {"protections", "protect"}, {"protective", "protect"}, {"protector", "protector"},
+		{"protectorate", "protector"}, {"protects", "protect"}, {"protege", "proteg"}, {"protein", "protein"}, {"protest", "protest"},
+		{"protestation", "protest"}, {"protester", "protest"}, {"protocol", "protocol"}, {"protocols", "protocol"}, {"proton", "proton"},
+		{"prototype", "prototyp"}, {"prototyped", "prototyp"}, {"prototypes", "prototyp"}, {"prototypically", "prototyp"},
+		{"prototyping", "prototyp"}, {"protract", "protract"}, {"protractor", "protractor"}, {"protrude", "protrud"}, {"protrusion", "protrus"},
+		{"proud", "proud"}, {"prove", "prove"}, {"proved", "prove"}, {"proverb", "proverb"}, {"proverbial", "proverbi"}, {"proves", "prove"},
+		{"provide", "provid"}, {"provided", "provid"}, {"providence", "provid"}, {"provident", "provid"}, {"providential", "providenti"},
+		{"provider", "provid"}, {"providers", "provid"}, {"provides", "provid"}, {"providing", "provid"}, {"province", "provinc"},
+		{"provincial", "provinci"}, {"provision", "provis"}, {"provisional", "provision"}, {"provisionally", "provision"},
+		{"provisioned", "provis"}, {"provisioning", "provis"}, {"provisions", "provis"}, {"proviso", "proviso"}, {"provocation", "provoc"},
+		{"provocative", "provoc"}, {"provoke", "provok"}, {"prow", "prow"}, {"prowess", "prowess"}, {"prowl", "prowl"}, {"proxied", "proxi"},
+		{"proxies", "proxi"}, {"proximal", "proxim"}, {"proximity", "proxim"}, {"proxy", "proxi"}, {"proxying", "proxi"}, {"prude", "prude"},
+		{"prudence", "prudenc"}, {"prudent", "prudent"}, {"prudish", "prudish"}, {"prune", "prune"}, {"pruned", "prune"}, {"prunes", "prune"},
+		{"pruning", "prune"}, {"pry", "pri"}, {"psalm", "psalm"}, {"pseudonym", "pseudonym"}, {"psyche", "psych"}, {"psychiatric", "psychiatr"},
+		{"psychiatrist", "psychiatrist"}, {"psychiatry", "psychiatri"}, {"psychic", "psychic"}, {"psychoanalysis", "psychoanalysi"},
+		{"psychoanalyst", "psychoanalyst"}, {"psychological", "psycholog"}, {"psychologist", "psychologist"}, {"psychology", "psycholog"},
+		{"psychopath", "psychopath"}, {"psychosis", "psychosi"}, {"psychotherapist", "psychotherapist"}, {"psychotherapy", "psychotherapi"},
+		{"psychotic", "psychot"}, {"pub", "pub"}, {"puberty", "puberti"}, {"pubic", "pubic"}, {"public", "public"}, {"publican", "publican"},
+		{"publication", "public"}, {"publications", "public"}, {"publicity", "public"}, {"publicize", "public"}, {"publicized", "public"},
+		{"publicizing", "public"}, {"publicly", "public"}, {"publics", "public"}, {"publish", "publish"}, {"published", "publish"},
+		{"publisher", "publish"}, {"publishers", "publish"}, {"publishes", "publish"}, {"publishing", "publish"}, {"pubs", "pub"},
+		{"pudding", "pud"}, {"puddle", "puddl"}, {"puff", "puff"}, {"puffin", "puffin"}, {"puffy", "puffi"}, {"pull", "pull"},
+		{"pulled", "pull"}, {"pulley", "pulley"}, {"pulling", "pull"}, {"pullover", "pullov"}, {"pulls", "pull"}, {"pulp", "pulp"},
+		{"pulpit", "pulpit"}, {"pulsate", "pulsat"}, {"pulse", "puls"}, {"pulses", "puls"}, {"pulsing", "puls"}, {"pulverize", "pulver"},
+		{"puma", "puma"}, {"pump", "pump"}, {"pumpkin", "pumpkin"}, {"pun", "pun"}, {"punch", "punch"}, {"punching", "punch"},
+		{"punctilious", "punctili"}, {"punctual", "punctual"}, {"punctuality", "punctual"}, {"punctuate", "punctuat"},
+		{"punctuation", "punctuat"}, {"puncture", "punctur"}, {"pundit", "pundit"}, {"pungent", "pungent"}, {"punish", "punish"},
+		{"punishment", "punish"}, {"punit", "punit"}, {"punitive", "punit"}, {"punk", "punk"}, {"punt", "punt"}, {"puny", "puni"},
+		{"pup", "pup"}, {"pupil", "pupil"}, {"puppet", "puppet"}, {"puppies", "puppi"}, {"puppy", "puppi"}, {"purchase", "purchas"},
+		{"purchased", "purchas"}, {"purchaser", "purchas"}, {"purchasing", "purchas"}, {"pure", "pure"}, {"puree", "pure"}, {"purely", "pure"},
+		{"purgatory", "purgatori"}, {"purge", "purg"}, {"purged", "purg"}, {"purges", "purg"}, {"purging", "purg"}, {"purification", "purif"},
+		{"purify", "purifi"}, {"purist", "purist"}, {"puritan", "puritan"}, {"puritanical", "puritan"}, {"purity", "puriti"},
+		{"purple", "purpl"}, {"purport", "purport"}, {"purpose", "purpos"}, {"purposed", "purpos"}, {"purposeful", "purpos"},
+		{"purposes", "purpos"}, {"purr", "purr"}, {"purse", "purs"}, {"pursue", "pursu"}, {"pursued", "pursu"}, {"pursuer", "pursuer"},
+		{"pursuit", "pursuit"}, {"purvey", "purvey"}, {"purveyor", "purveyor"}, {"pus", "pus"}, {"push", "push"}, {"pushchair", "pushchair"},
+		{"pushed", "push"}, {"pusher", "pusher"}, {"pushes", "push"}, {"pushing", "push"}, {"pushy", "pushi"}, {"puss", "puss"},
+		{"pussy", "pussi"}, {"put", "put"}, {"putrid", "putrid"}, {"puts", "put"}, {"putt", "putt"}, {"putting", "put"}, {"putty", "putti"},
+		{"puzzle", "puzzl"}, {"pygmy", "pygmi"}, {"pyjamas", "pyjama"}, {"pylon", "pylon"}, {"pyramid", "pyramid"}, {"pyre", "pyre"},
+		{"python", "python"}, {"pythonic", "python"}, {"quack", "quack"}, {"quadrangle", "quadrangl"}, {"quadrant", "quadrant"},
+		{"quadrilateral", "quadrilater"}, {"quadruped", "quadrup"}, {"quadruple", "quadrupl"}, {"quagmire", "quagmir"}, {"quail", "quail"},
+		{"quaint", "quaint"}, {"quake", "quak"}, {"qualification", "qualif"}, {"qualified", "qualifi"}, {"qualifier", "qualifi"},
+		{"qualifiers", "qualifi"}, {"qualifies", "qualifi"}, {"qualify", "qualifi"}, {"qualifying", "qualifi"}, {"qualitative", "qualit"},
+		{"qualities", "qualiti"}, {"quality", "qualiti"}, {"qualm", "qualm"}, {"quandary", "quandari"}, {"quantify", "quantifi"},
+		{"quantitative", "quantit"}, {"quantities", "quantiti"}, {"quantity", "quantiti"}, {"quantum", "quantum"}, {"quarantine", "quarantin"},
+		{"quarantined", "quarantin"}, {"quarrel", "quarrel"}, {"quarrelsome", "quarrelsom"}, {"quarry", "quarri"}, {"quart", "quart"},
+		{"quarter", "quarter"}, {"quarterback", "quarterback"}, {"quarterfinal", "quarterfin"}, {"quarterly", "quarter"},
+		{"quarters", "quarter"}, {"quartet", "quartet"}, {"quartz", "quartz"}, {"quash", "quash"}, {"quasi", "quasi"}, {"quaver", "quaver"},
+		{"quay", "quay"}, {"queasy", "queasi"}, {"queen", "queen"}, {"queens", "queen"}, {"queer", "queer"}, {"quell", "quell"},
+		{"quench", "quench"}, {"queried", "queri"}, {"queries", "queri"}, {"query", "queri"}, {"querying", "queri"}, {"quest", "quest"},
+		{"question", "question"}, {"questionable", "question"}, {"questionnaire", "questionnair"}, {"questions", "question"}, {"queue", "queue"},
+		{"queueing", "queue"}, {"queues", "queue"}, {"quibble", "quibbl"}, {"quiche", "quich"}, {"quick", "quick"}, {"quicken", "quicken"},
+		{"quickly", "quick"}, {"quicksand", "quicksand"}, {"quid", "quid"}, {"quiet", "quiet"}, {"quieten", "quieten"}, {"quietly", "quiet"},
+		{"quietness", "quiet"}, {"quill", "quill"}, {"quilt", "quilt"}, {"quintessence", "quintess"}, {"quintessential", "quintessenti"},
+		{"quintet", "quintet"}, {"quip", "quip"}, {"quirk", "quirk"}, {"quirks", "quirk"}, {"quirky", "quirki"}, {"quit", "quit"},
+		{"quite", "quit"}, {"quits", "quit"}, {"quitting", "quit"}, {"quiver", "quiver"}, {"quiz", "quiz"}, {"quizzical", "quizzic"},
+		{"quorum", "quorum"}, {"quot", "quot"}, {"quota", "quota"}, {"quotas", "quota"}, {"quotation", "quotat"}, {"quotations", "quotat"},
+		{"quote", "quot"}, {"quoted", "quot"}, {"quotes", "quot"}, {"quotient", "quotient"}, {"quoting", "quot"}, {"rabbi", "rabbi"},
+		{"rabbit", "rabbit"}, {"rabble", "rabbl"}, {"rabid", "rabid"}, {"rabies", "rabi"}, {"raccoon", "raccoon"}, {"race", "race"},
+		{"racecourse", "racecours"}, {"racehorse", "racehors"}, {"racer", "racer"}, {"races", "race"}, {"racetrack", "racetrack"},
+		{"racial", "racial"}, {"racialism", "racial"}, {"racing", "race"}, {"racism", "racism"}, {"racist", "racist"}, {"rack", "rack"},
+		{"racket", "racket"}, {"racketeer", "racket"}, {"racy", "raci"}, {"radar", "radar"}, {"radial", "radial"}, {"radiance", "radianc"},
+		{"radiant", "radiant"}, {"radiate", "radiat"}, {"radiation", "radiat"}, {"radiator", "radiat"}, {"radical", "radic"},
+		{"radicalism", "radic"}, {"radices", "radic"}, {"radio", "radio"}, {"radioactive", "radioact"}, {"radioactivity", "radioact"},
+		{"radiographer", "radiograph"}, {"radiography", "radiographi"}, {"radiology", "radiolog"}, {"radiotherapy", "radiotherapi"},
+		{"radish", "radish"}, {"radium", "radium"}, {"radius", "radius"}, {"raffle", "raffl"}, {"raft", "raft"}, {"rafter", "rafter"},
+		{"rag", "rag"}, {"rage", "rage"}, {"ragged", "rag"}, {"raid", "raid"}, {"raider", "raider"}, {"raids", "raid"}, {"rail", "rail"},
+		{"railing", "rail"}, {"railroad", "railroad"}, {"rails", "rail"}, {"railway", "railway"}, {"rain", "rain"}, {"rainbow", "rainbow"},
+		{"raincoat", "raincoat"}, {"raindrop", "raindrop"}, {"rainfall", "rainfal"}, {"rainforest", "rainforest"}, {"raining", "rain"},
+		{"rainstorm", "rainstorm"}, {"rainwater", "rainwat"}, {"rainy", "raini"}, {"raise", "rais"}, {"raised", "rais"}, {"raises", "rais"},
+		{"raisin", "raisin"}, {"raising", "rais"}, {"rake", "rake"}, {"rally", "ralli"}, {"ram", "ram"}, {"ramble", "rambl"},
+		{"rambler", "rambler"}, {"ramification", "ramif"}, {"ramp", "ramp"}, {"rampage", "rampag"}, {"rampant", "rampant"},
+		{"rampart", "rampart"}, {"ramshackle", "ramshackl"}, {"ranch", "ranch"}, {"rancher", "rancher"}, {"rancid", "rancid"},
+		{"rancor", "rancor"}, {"rancour", "rancour"}, {"random", "random"}, {"randomization", "random"}, {"randomize", "random"},
+		{"randomized", "random"}, {"randomly", "random"}, {"randomness", "random"}, {"randy", "randi"}, {"range", "rang"}, {"ranger", "ranger"},
+		{"rangers", "ranger"}, {"ranges", "rang"}, {"ranging", "rang"}, {"rank", "rank"}, {"ranked", "rank"}, {"rankle", "rankl"},
+		{"ranks", "rank"}, {"ransack", "ransack"}, {"ransom", "ransom"}, {"rant", "rant"}, {"rap", "rap"}, {"rape", "rape"}, {"rapid", "rapid"},
+		{"rapidity", "rapid"}, {"rapidly", "rapid"}, {"rapist", "rapist"}, {"rapport", "rapport"}, {"rapt", "rapt"}, {"rapture", "raptur"},
+		{"rapturous", "raptur"}, {"rare", "rare"}, {"rarely", "rare"}, {"rarity", "rariti"}, {"rascal", "rascal"}, {"rash", "rash"},
+		{"rasher", "rasher"}, {"rasp", "rasp"}, {"raspberry", "raspberri"}, {"rat", "rat"}, {"rate", "rate"}, {"rates", "rate"},
+		{"rather", "rather"}, {"ratification", "ratif"}, {"ratified", "ratifi"}, {"ratify", "ratifi"}, {"rating", "rate"}, {"ratings", "rate"},
+		{"ratio", "ratio"}, {"ration", "ration"}, {"rational", "ration"}, {"rationale", "rational"}, {"rationalism", "ration"},
+		{"rationalist", "rationalist"}, {"rationality", "ration"}, {"rationalization", "ration"}, {"rationalize", "ration"}, {"ratios", "ratio"},
+		{"rattle", "rattl"}, {"rattlesnake", "rattlesnak"}, {"raucous", "raucous"}, {"ravage", "ravag"}, {"rave", "rave"}, {"raven", "raven"},
+		{"ravenous", "raven"}, {"ravine", "ravin"}, {"ravish", "ravish"}, {"ravishing", "ravish"}, {"raw", "raw"}, {"raws", "raw"},
+		{"ray", "ray"}, {"rayon", "rayon"}, {"raze", "raze"}, {"razor", "razor"}, {"reach", "reach"}, {"reached", "reach"}, {"reaches", "reach"},
+		{"reaching", "reach"}, {"react", "react"}, {"reacting", "react"}, {"reaction", "reaction"}, {"reactionary", "reactionari"},
+		{"reactivate", "reactiv"}, {"reactivated", "reactiv"}, {"reactive", "reactiv"}, {"reactor", "reactor"}, {"reacts", "react"},
+		{"read", "read"}, {"readability", "readabl"}, {"readable", "readabl"}, {"reader", "reader"}, {"readers", "reader"},
+		{"readership", "readership"}, {"readily", "readili"}, {"readiness", "readi"}, {"reading", "read"}, {"readjust", "readjust"},
+		{"reads", "read"}, {"ready", "readi"}, {"reaffirm", "reaffirm"}, {"real", "real"}, {"realism", "realism"}, {"realist", "realist"},
+		{"realistic", "realist"}, {"realistically", "realist"}, {"reality", "realiti"}, {"realization", "realiz"}, {"realize", "realiz"},
+		{"realized", "realiz"}, {"realizes", "realiz"}, {"realizing", "realiz"}, {"realm", "realm"}, {"realms", "realm"}, {"ream", "ream"},
+		{"reap", "reap"}, {"reaped", "reap"}, {"reaper", "reaper"}, {"reappear", "reappear"}, {"reappears", "reappear"},
+		{"reappraisal", "reapprais"}, {"rear", "rear"}, {"rearguard", "rearguard"}, {"rearm", "rearm"}, {"rearmament", "rearmament"},
+		{"rearrange", "rearrang"}, {"rearranged", "rearrang"}, {"rearrangement", "rearrang"}, {"rearranging", "rearrang"}, {"reason", "reason"},
+		{"reasonable", "reason"}, {"reasonably", "reason"}, {"reasoning", "reason"}, {"reasons", "reason"}, {"reassemble", "reassembl"},
+		{"reassembled", "reassembl"}, {"reassembles", "reassembl"}, {"reassembling", "reassembl"}, {"reassembly", "reassembl"},
+		{"reassess", "reassess"}, {"reassessment", "reassess"}, {"reassurance", "reassur"}, {"reassure", "reassur"}, {"rebate", "rebat"},
+		{"rebel", "rebel"}, {"rebellion", "rebellion"}, {"rebellious", "rebelli"}, {"rebirth", "rebirth"}, {"reboot", "reboot"},
+		{"rebooted", "reboot"}, {"rebooting", "reboot"}, {"reboots", "reboot"}, {"rebound", "rebound"}, {"rebuff", "rebuff"},
+		{"rebuild", "rebuild"}, {"rebuilding", "rebuild"}, {"rebuilds", "rebuild"}, {"rebuke", "rebuk"}, {"rebut", "rebut"},
+		{"rebuttal", "rebutt"}, {"recalcitrant", "recalcitr"}, {"recall", "recal"}, {"recant", "recant"}, {"recap", "recap"},
+		{"recapitulate", "recapitul"}, {"recapture", "recaptur"}, {"recede", "reced"}, {"receipt", "receipt"}, {"receipts", "receipt"},
+		{"receive", "receiv"}, {"received", "receiv"}, {"receiver", "receiv"}, {"receivers", "receiv"}, {"receivership", "receivership"},
+		{"receives", "receiv"}, {"receiving", "receiv"}, {"recent", "recent"}, {"recently", "recent"}, {"receptacle", "receptacl"},
+		{"reception", "recept"}, {"receptionist", "receptionist"}, {"receptions", "recept"}, {"receptive", "recept"}, {"recess", "recess"},
+		{"recession", "recess"}, {"recharge", "recharg"}, {"recip", "recip"}, {"recipe", "recip"}, {"recipes", "recip"}, {"recipient", "recipi"},
+		{"recipients", "recipi"}, {"reciprocal", "reciproc"}, {"reciprocate", "reciproc"}, {"reciprocity", "reciproc"}, {"recital", "recit"},
+		{"recitation", "recit"}, {"recite", "recit"}, {"reckless", "reckless"}, {"reckon", "reckon"}, {"reckoning", "reckon"},
+		{"reclaim", "reclaim"}, {"reclaimable", "reclaim"}, {"reclaimed", "reclaim"}, {"reclaims", "reclaim"}, {"reclamation", "reclam"},
+		{"recline", "reclin"}, {"recluse", "reclus"}, {"reclusive", "reclus"}, {"recognition", "recognit"}, {"recognizable", "recogniz"},
+		{"recognize", "recogn"}, {"recognized", "recogn"}, {"recognizer", "recogn"}, {"recognizers", "recogn"}, {"recognizes", "recogn"},
+		{"recognizing", "recogn"}, {"recoil", "recoil"}, {"recollect", "recollect"}, {"recollection", "recollect"}, {"recommend", "recommend"},
+		{"recommendable", "recommend"}, {"recommendation", "recommend"}, {"recommendations", "recommend"}, {"recommended", "recommend"},
+		{"recommender", "recommend"}, {"recommenders", "recommend"}, {"recommending", "recommend"}, {"recommends", "recommend"},
+		{"recompense", "recompens"}, {"reconcile", "reconcil"}, {"reconciles", "reconcil"}, {"reconciliation", "reconcili"},
+		{"reconciling", "reconcil"}, {"recondite", "recondit"}, {"recondition", "recondit"}, {"reconnaissance", "reconnaiss"},
+		{"reconnoiter", "reconnoit"}, {"reconnoitre", "reconnoitr"}, {"reconsider", "reconsid"}, {"reconsideration", "reconsider"},
+		{"reconstitute", "reconstitut"}, {"reconstruct", "reconstruct"}, {"reconstructed", "reconstruct"}, {"reconstructing", "reconstruct"},
+		{"reconstruction", "reconstruct"}, {"record", "record"}, {"recorded", "record"}, {"recorder", "record"}, {"recording", "record"},
+		{"recordings", "record"}, {"records", "record"}, {"recount", "recount"}, {"recoup", "recoup"}, {"recourse", "recours"},
+		{"recover", "recov"}, {"recovered", "recov"}, {"recoveries", "recoveri"}, {"recovering", "recov"}, {"recovers", "recov"},
+		{"recovery", "recoveri"}, {"recreate", "recreat"}, {"recreated", "recreat"}, {"recreates", "recreat"}, {"recreating", "recreat"},
+		{"recreation", "recreat"}, {"recreational", "recreat"}, {"recreations", "recreat"}, {"recrimination", "recrimin"},
+		{"recruit", "recruit"}, {"recruitment", "recruit"}, {"rectangle", "rectangl"}, {"rectangles", "rectangl"},
+		{"rectangular", "rectangular"}, {"rectified", "rectifi"}, {"rectify", "rectifi"}, {"rectitude", "rectitud"}, {"rector", "rector"},
+		{"rectory", "rectori"}, {"rectum", "rectum"}, {"recuperate", "recuper"}, {"recuperation", "recuper"}, {"recur", "recur"},
+		{"recurrence", "recurr"}, {"recurrences", "recurr"}, {"recurrent", "recurr"}, {"recurring", "recur"}, {"recurs", "recur"},
+		{"recycle", "recycl"}, {"recycled", "recycl"}, {"recycling", "recycl"}, {"red", "red"}, {"redden", "redden"}, {"redeem", "redeem"},
+		{"redeemer", "redeem"}, {"redemption", "redempt"}, {"redeploy", "redeploy"}, {"redevelop", "redevelop"}, {"redevelopment", "redevelop"},
+		{"redhead", "redhead"}, {"redirect", "redirect"}, {"redirected", "redirect"}, {"redirecting", "redirect"}, {"redirection", "redirect"},
+		{"redirections", "redirect"}, {"redirects", "redirect"}, {"redistribute", "redistribut"}, {"redistributed", "redistribut"},
+		{"redistributes", "redistribut"}, {"redistributing", "redistribut"}, {"redistribution", "redistribut"},
+		{"redistributions", "redistribut"}, {"redness", "red"}, {"redouble", "redoubl"}, {"redoubtable", "redoubt"}, {"redress", "redress"},
+		{"reduce", "reduc"}, {"reduced", "reduc"}, {"reducer", "reduc"}, {"reduces", "reduc"}, {"reducing", "reduc"}, {"reduction", "reduct"},
+		{"reductions", "reduct"}, {"redundancy", "redund"}, {"redundant", "redund"}, {"reed", "reed"}, {"reef", "reef"}, {"reek", "reek"},
+		{"reel", "reel"}, {"reelect", "reelect"}, {"refectory", "refectori"}, {"refer", "refer"}, {"referee", "refere"}, {"reference", "refer"},
+		{"references", "refer"}, {"referendum", "referendum"}, {"referent", "refer"}, {"referer", "refer"}, {"refererring", "refer"},
+		{"referred", "refer"}, {"referring", "refer"}, {"refers", "refer"}, {"refill", "refil"}, {"refine", "refin"}, {"refined", "refin"},
+		{"refinement", "refin"}, {"refinements", "refin"}, {"refinery", "refineri"}, {"refit", "refit"}, {"reflect", "reflect"},
+		{"reflected", "reflect"}, {"reflecting", "reflect"}, {"reflection", "reflect"}, {"reflective", "reflect"}, {"reflector", "reflector"},
+		{"reflects", "reflect"}, {"reflex", "reflex"}, {"reflexive", "reflex"}, {"reflexively", "reflex"}, {"reflexivity", "reflex"},
+		{"reform", "reform"}, {"reformation", "reform"}, {"reformed", "reform"}, {"reformer", "reform"}, {"refract", "refract"},
+		{"refraction", "refract"}, {"refrain", "refrain"}, {"refrains", "refrain"}, {"refresh", "refresh"}, {"refreshed", "refresh"},
+		{"refresher", "refresh"}, {"refreshes", "refresh"}, {"refreshing", "refresh"}, {"refreshment", "refresh"}, {"refrigerate", "refriger"},
+		{"refrigeration", "refriger"}, {"refrigerator", "refriger"}, {"refuel", "refuel"}, {"refuge", "refug"}, {"refugee", "refuge"},
+		{"refund", "refund"}, {"refurbish", "refurbish"}, {"refurbishment", "refurbish"}, {"refusal", "refus"}, {"refuse", "refus"},
+		{"refused", "refus"}, {"refuses", "refus"}, {"refusing", "refus"}, {"refutation", "refut"}, {"refute", "refut"}, {"regain", "regain"},
+		{"regained", "regain"}, {"regaining", "regain"}, {"regal", "regal"}, {"regale", "regal"}, {"regalia", "regalia"}, {"regard", "regard"},
+		{"regarded", "regard"}, {"regarding", "regard"}, {"regardless", "regardless"}, {"regards", "regard"}, {"regatta", "regatta"},
+		{"regency", "regenc"}, {"regenerate", "regener"}, {"regenerated", "regener"}, {"regenerates", "regener"}, {"regenerating", "regener"},
+		{"regeneration", "regener"}, {"regent", "regent"}, {"regents", "regent"}, {"reggae", "regga"}, {"regime", "regim"},
+		{"regiment", "regiment"}, {"regimental", "regiment"}, {"region", "region"}, {"regional", "region"}, {"regions", "region"},
+		{"register", "regist"}, {"registered", "regist"}, {"registering", "regist"}, {"registers", "regist"}, {"registrant", "registr"},
+		{"registrar", "registrar"}, {"registration", "registr"}, {"registrations", "registr"}, {"registries", "registri"},
+		{"registry", "registri"}, {"regress", "regress"}, {"regressed", "regress"}, {"regression", "regress"}, {"regressions", "regress"},
+		{"regressive", "regress"}, {"regret", "regret"}, {"regretful", "regret"}, {"regrettable", "regrett"}, {"regroup", "regroup"},
+		{"regular", "regular"}, {"regularities", "regular"}, {"regularity", "regular"}, {"regularly", "regular"}, {"regulate", "regul"},
+		{"regulation", "regul"}, {"regulator", "regul"}, {"regulatory", "regulatori"}, {"regurgitate", "regurgit"},
+		{"rehabilitate", "rehabilit"}, {"rehabilitated", "rehabilit"}, {"rehabilitation", "rehabilit"}, {"rehash", "rehash"},
+		{"rehashed", "rehash"}, {"rehashing", "rehash"}, {"rehearsal", "rehears"}, {"rehearse", "rehears"}, {"reign", "reign"},
+		{"reimburse", "reimburs"}, {"reimbursement", "reimburs"}, {"rein", "rein"}, {"reincarnation", "reincarn"}, {"reindeer", "reindeer"},
+		{"reinforce", "reinforc"}, {"reinforcement", "reinforc"}, {"reinstate", "reinstat"}, {"reinstated", "reinstat"},
+		{"reinstatement", "reinstat"}, {"reinvest", "reinvest"}, {"reissue", "reissu"}, {"reiterate", "reiter"}, {"reiteration", "reiter"},
+		{"reject", "reject"}, {"rejected", "reject"}, {"rejecting", "reject"}, {"rejection", "reject"}, {"rejections", "reject"},
+		{"rejects", "reject"}, {"rejoice", "rejoic"}, {"rejoin", "rejoin"}, {"rejoinder", "rejoind"}, {"rejuvenate", "rejuven"},
+		{"rekindle", "rekindl"}, {"relapse", "relaps"}, {"relate", "relat"}, {"related", "relat"}, {"relates", "relat"}, {"relating", "relat"},
+		{"relation", "relat"}, {"relational", "relat"}, {"relations", "relat"}, {"relationship", "relationship"},
+		{"relationships", "relationship"}, {"relative", "relat"}, {"relatively", "relat"}, {"relativity", "relat"}, {"relax", "relax"},
+		{"relaxation", "relax"}, {"relaxations", "relax"}, {"relaxed", "relax"}, {"relaxes", "relax"}, {"relaxing", "relax"}, {"relay", "relay"},
+		{"relaying", "relay"}, {"relays", "relay"}, {"releasable", "releas"}, {"release", "releas"}, {"released", "releas"},
+		{"releaser", "releas"}, {"releasers", "releas"}, {"releases", "releas"}, {"releasing", "releas"}, {"relegate", "releg"},
+		{"relegation", "releg"}, {"relent", "relent"}, {"relentless", "relentless"}, {"relevance", "relev"}, {"relevant", "relev"},
+		{"reliability", "reliabl"}, {"reliable", "reliabl"}, {"reliably", "reliabl"}, {"reliance", "relianc"}, {"reliant", "reliant"},
+		{"relic", "relic"}, {"relied", "reli"}, {"relief", "relief"}, {"relies", "reli"}, {"relieve", "reliev"}, {"relieves", "reliev"},
+		{"religion", "religion"}, {"religious", "religi"}, {"relinquish", "relinquish"}, {"relinquished", "relinquish"}, {"relish", "relish"},
+		{"relive", "reliv"}, {"reloc", "reloc"}, {"relocate", "reloc"}, {"relocated", "reloc"}, {"relocates", "reloc"}, {"relocating", "reloc"},
+		{"relocation", "reloc"}, {"relocations", "reloc"}, {"relocs", "reloc"}, {"reluctance", "reluct"}, {"reluctant", "reluct"},
+		{"rely", "reli"}, {"relying", "reli"}, {"remain", "remain"}, {"remainder", "remaind"}, {"remained", "remain"}, {"remaining", "remain"},
+		{"remains", "remain"}, {"remake", "remak"}, {"remaking", "remak"}, {"remand", "remand"}, {"remark", "remark"}, {"remarkable", "remark"},
+		{"remarks", "remark"}, {"remarry", "remarri"}, {"remedial", "remedi"}, {"remediate", "remedi"}, {"remediated", "remedi"},
+		{"remediation", "remedi"}, {"remedy", "remedi"}, {"remember", "rememb"}, {"remembered", "rememb"}, {"remembering", "rememb"},
+		{"remembers", "rememb"}, {"remembrance", "remembr"}, {"remind", "remind"}, {"reminder", "remind"}, {"reminds", "remind"},
+		{"reminisce", "reminisc"}, {"reminiscence", "reminisc"}, {"reminiscent", "reminisc"}, {"remiss", "remiss"}, {"remission", "remiss"},
+		{"remit", "remit"}, {"remittance", "remitt"}, {"remnant", "remnant"}, {"remnants", "remnant"}, {"remodel", "remodel"},
+		{"remonstrate", "remonstr"}, {"remorse", "remors"}, {"remorseful", "remors"}, {"remorseless", "remorseless"}, {"remote", "remot"},
+		{"remotely", "remot"}, {"remotes", "remot"}, {"remotly", "remot"}, {"removable", "remov"}, {"removal", "remov"}, {"removals", "remov"},
+		{"remove", "remov"}, {"removed", "remov"}, {"removes", "remov"}, {"removing", "remov"}, {"remunerate", "remuner"},
+		{"remuneration", "remuner"}, {"renaissance", "renaiss"}, {"renal", "renal"}, {"rename", "renam"}, {"renamed", "renam"},
+		{"renames", "renam"}, {"renaming", "renam"}, {"rend", "rend"}, {"render", "render"}, {"rendered", "render"}, {"rendering", "render"},
+		{"renders", "render"}, {"rendezvous", "rendezv"}, {"rendition", "rendit"}, {"reneg", "reneg"}, {"renegade", "renegad"},
+		{"renege", "reneg"}, {"renew", "renew"}, {"renewable", "renew"}, {"renewal", "renew"}, {"renewals", "renew"}, {"renewed", "renew"},
+		{"renewing", "renew"}, {"renounce", "renounc"}, {"renovate", "renov"}, {"renovation", "renov"}, {"renown", "renown"}, {"rent", "rent"},
+		{"rental", "rental"}, {"renunciation", "renunci"}, {"reopen", "reopen"}, {"reopened", "reopen"}, {"reopening", "reopen"},
+		{"reopens", "reopen"}, {"reorganization", "reorgan"}, {"reorganize", "reorgan"}, {"reorganized", "reorgan"}, {"rep", "rep"},
+		{"repaid", "repaid"}, {"repair", "repair"}, {"repaired", "repair"}, {"repairing", "repair"}, {"repairman", "repairman"},
+		{"repairs", "repair"}, {"reparation", "repar"}, {"reparented", "repar"}, {"reparenting", "repar"}, {"repartee", "reparte"},
+		{"repatriate", "repatri"}, {"repatriation", "repatri"}, {"repay", "repay"}, {"repayment", "repay"}, {"repeal", "repeal"},
+		{"repeat", "repeat"}, {"repeatability", "repeat"}, {"repeatable", "repeat"}, {"repeated", "repeat"}, {"repeatedly", "repeat"},
+		{"repeating", "repeat"}, {"repeats", "repeat"}, {"repel", "repel"}, {"repellent", "repel"}, {"repent", "repent"},
+		{"repentance", "repent"}, {"repentant", "repent"}, {"repercussion", "repercuss"}, {"repertoire", "repertoir"},
+		{"repertory", "repertori"}, {"repetition", "repetit"}, {"repetitions", "repetit"}, {"repetitious", "repetiti"},
+		{"repetitive", "repetit"}, {"repetitively", "repetit"}, {"repetitiveness", "repetit"}, {"rephrase", "rephras"}, {"replace", "replac"},
+		{"replaceable", "replac"}, {"replaced", "replac"}, {"replacement", "replac"}, {"replacements", "replac"}, {"replaces", "replac"},
+		{"replacing", "replac"}, {"replay", "replay"}, {"replayed", "replay"}, {"replaying", "replay"}, {"replays", "replay"},
+		{"replenish", "replenish"}, {"replenishes", "replenish"}, {"replete", "replet"}, {"replica", "replica"}, {"replicas", "replica"},
+		{"replicate", "replic"}, {"replicated", "replic"}, {"replicates", "replic"}, {"replicating", "replic"}, {"replication", "replic"},
+		{"replications", "replic"}, {"replies", "repli"}, {"reply", "repli"}, {"replying", "repli"}, {"report", "report"},
+		{"reportage", "reportag"}, {"reported", "report"}, {"reportedly", "report"}, {"reporter", "report"}, {"reporters", "report"},
+		{"reporting", "report"}, {"reports", "report"}, {"repose", "repos"}, {"repositories", "repositori"}, {"repository", "repositori"},
+		{"repossess", "repossess"}, {"reprehensible", "reprehens"}, {"represent", "repres"}, {"representable", "represent"},
+		{"representation", "represent"}, {"representations", "represent"}, {"representative", "repres"}, {"represented", "repres"},
+		{"representing", "repres"}, {"represents", "repres"}, {"repress", "repress"}, {"repression", "repress"}, {"repressive", "repress"},
+		{"reprieve", "repriev"}, {"reprimand", "reprimand"}, {"reprint", "reprint"}, {"reprinted", "reprint"}, {"reprisal", "repris"},
+		{"reproach", "reproach"}, {"reproachful", "reproach"}, {"reprobate", "reprob"}, {"reprobe", "reprob"}, {"reproduce", "reproduc"},
+		{"reproduced", "reproduc"}, {"reproducer", "reproduc"}, {"reproduces", "reproduc"}, {"reproducible", "reproduc"},
+		{"reproducibly", "reproduc"}, {"reproducing", "reproduc"}, {"reproduction", "reproduct"}, {"reproductions", "reproduct"},
+		{"reproductive", "reproduct"}, {"reproof", "reproof"}, {"reprove", "reprov"}, {"reptile", "reptil"}, {"reptilian", "reptilian"},
+		{"republic", "republ"}, {"republican", "republican"}, {"repudiate", "repudi"}, {"repudiation", "repudi"}, {"repugnance", "repugn"},
+		{"repugnant", "repugn"}, {"repulse", "repuls"}, {"repulsion", "repuls"}, {"repulsive", "repuls"}, {"reputable", "reput"},
+		{"reputation", "reput"}, {"reputations", "reput"}, {"repute", "reput"}, {"request", "request"}, {"requested", "request"},
+		{"requester", "request"}, {"requesters", "request"}, {"requesting", "request"}, {"requests", "request"}, {"requiem", "requiem"},
+		{"require", "requir"}, {"required", "requir"}, {"requirement", "requir"}, {"requirements", "requir"}, {"requires", "requir"},
+		{"requiring", "requir"}, {"requisite", "requisit"}, {"requisites", "requisit"}, {"requisition", "requisit"}, {"rescind", "rescind"},
+		{"rescue", "rescu"}, {"rescuer", "rescuer"}, {"research", "research"}, {"researcher", "research"}, {"resemblance", "resembl"},
+		{"resemble", "resembl"}, {"resembles", "resembl"}, {"resembling", "resembl"}, {"resent", "resent"}, {"resentful", "resent"},
+		{"resentment", "resent"}, {"reservation", "reserv"}, {"reservations", "reserv"}, {"reserve", "reserv"}, {"reserved", "reserv"},
+		{"reserves", "reserv"}, {"reserving", "reserv"}, {"reservoir", "reservoir"}, {"reshuffle", "reshuffl"}, {"resid", "resid"},
+		{"reside", "resid"}, {"residence", "resid"}, {"residency", "resid"}, {"resident", "resid"}, {"residential", "residenti"},
+		{"resides", "resid"}, {"residing", "resid"}, {"residual", "residu"}, {"residue", "residu"}, {"resign", "resign"},
+		{"resignation", "resign"}, {"resigning", "resign"}, {"resilience", "resili"}, {"resilient", "resili"}, {"resin", "resin"},
+		{"resist", "resist"}, {"resistance", "resist"}, {"resistant", "resist"}, {"resistor", "resistor"}, {"resolute", "resolut"},
+		{"resolution", "resolut"}, {"resolutions", "resolut"}, {"resolv", "resolv"}, {"resolvability", "resolv"}, {"resolvable", "resolv"},
+		{"resolve", "resolv"}, {"resolved", "resolv"}, {"resolver", "resolv"}, {"resolvers", "resolv"}, {"resolves", "resolv"},
+		{"resolving", "resolv"}, {"resonance", "reson"}, {"resonant", "reson"}, {"resonate", "reson"}, {"resort", "resort"},
+		{"resorting", "resort"}, {"resound", "resound"}, {"resource", "resourc"}, {"resourced", "resourc"}, {"resourceful", "resourc"},
+		{"resources", "resourc"}, {"respect", "respect"}, {"respectability", "respect"}, {"respectable", "respect"}, {"respected", "respect"},
+		{"respectful", "respect"}, {"respecting", "respect"}, {"respective", "respect"}, {"respectively", "respect"}, {"respects", "respect"},
+		{"respiration", "respir"}, {"respirator", "respir"}, {"respiratory", "respiratori"}, {"respite", "respit"}, {"resplendent", "resplend"},
+		{"respond", "respond"}, {"responded", "respond"}, {"respondent", "respond"}, {"responder", "respond"}, {"responders", "respond"},
+		{"responding", "respond"}, {"responds", "respond"}, {"response", "respons"}, {"responses", "respons"}, {"responsibilities", "respons"},
+		{"responsibility", "respons"}, {"responsible", "respons"}, {"responsive", "respons"}, {"responsiveness", "respons"}, {"rest", "rest"},
+		{"restaurant", "restaur"}, {"restaurateur", "restaurateur"}, {"restful", "rest"}, {"restitution", "restitut"}, {"restive", "restiv"},
+		{"restless", "restless"}, {"restoration", "restor"}, {"restorative", "restor"}, {"restore", "restor"}, {"restored", "restor"},
+		{"restorer", "restor"}, {"restores", "restor"}, {"restoring", "restor"}, {"restrain", "restrain"}, {"restraint", "restraint"},
+		{"restrict", "restrict"}, {"restricted", "restrict"}, {"restricting", "restrict"}, {"restriction", "restrict"},
+		{"restrictions", "restrict"}, {"restrictive", "restrict"}, {"restricts", "restrict"}, {"restructure", "restructur"},
+		{"restructured", "restructur"}, {"restructuring", "restructur"}, {"result", "result"}, {"resultant", "result"}, {"resulted", "result"},
+		{"resulting", "result"}, {"results", "result"}, {"resumable", "resum"}, {"resume", "resum"}, {"resumed", "resum"}, {"resumes", "resum"},
+		{"resuming", "resum"}, {"resumption", "resumpt"}, {"resurface", "resurfac"}, {"resurgence", "resurg"}, {"resurgent", "resurg"},
+		{"resurrect", "resurrect"}, {"resurrected", "resurrect"}, {"resurrection", "resurrect"}, {"resuscitate", "resuscit"},
+		{"resuscitation", "resuscit"}, {"retail", "retail"}, {"retailer", "retail"}, {"retailers", "retail"}, {"retain", "retain"},
+		{"retained", "retain"}, {"retainer", "retain"}, {"retaining", "retain"}, {"retains", "retain"}, {"retake", "retak"},
+		{"retaliate", "retali"}, {"retaliation", "retali"}, {"retaliatory", "retaliatori"}, {"retard", "retard"}, {"retch", "retch"},
+		{"retention", "retent"}, {"retentive", "retent"}, {"rethink", "rethink"}, {"reticence", "retic"}, {"reticent", "retic"},
+		{"retina", "retina"}, {"retinue", "retinu"}, {"retire", "retir"}, {"retired", "retir"}, {"retiree", "retire"}, {"retirement", "retir"},
+		{"retires", "retir"}, {"retiring", "retir"}, {"retort", "retort"}, {"retouch", "retouch"}, {"retrace", "retrac"}, {"retract", "retract"},
+		{"retraction", "retract"}, {"retreat", "retreat"}, {"retrench", "retrench"}, {"retrenchment", "retrench"}, {"retrial", "retrial"},
+		{"retribution", "retribut"}, {"retrievable", "retriev"}, {"retrieval", "retriev"}, {"retrievals", "retriev"}, {"retrieve", "retriev"},
+		{"retrieved", "retriev"}, {"retriever", "retriev"}, {"retrieves", "retriev"}, {"retrieving", "retriev"}, {"retroactive", "retroact"},
+		{"retroactively", "retroact"}, {"retrograde", "retrograd"}, {"retrospect", "retrospect"}, {"retrospective", "retrospect"},
+		{"return", "return"}, {"returned", "return"}, {"returning", "return"}, {"returns", "return"}, {"reunification", "reunif"},
+		{"reunion", "reunion"}, {"reunite", "reunit"}, {"reuse", "reus"}, {"reused", "reus"}, {"reuses", "reus"}, {"reusing", "reus"},
+		{"rev", "rev"}, {"revamp", "revamp"}, {"revamped", "revamp"}, {"reveal", "reveal"}, {"revealed", "reveal"}, {"revealing", "reveal"},
+		{"reveals", "reveal"}, {"revel", "revel"}, {"revelation", "revel"}, {"reveler", "revel"}, {"reveller", "revel"}, {"revelry", "revelri"},
+		{"revenge", "reveng"}, {"revenue", "revenu"}, {"reverberate", "reverber"}, {"reverberation", "reverber"}, {"revere", "rever"},
+		{"reverence", "rever"}, {"reverend", "reverend"}, {"reverent", "rever"}, {"reverie", "reveri"}, {"reversal", "revers"},
+		{"reverse", "revers"}, {"reversed", "revers"}, {"reversely", "revers"}, {"reverses", "revers"}, {"reversible", "revers"},
+		{"reversing", "revers"}, {"reversion", "revers"}, {"reversions", "revers"}, {"revert", "revert"}, {"reverted", "revert"},
+		{"reverting", "revert"}, {"reverts", "revert"}, {"review", "review"}, {"reviewed", "review"}, {"reviewer", "review"},
+		{"reviewers", "review"}, {"reviewing", "review"}, {"reviews", "review"}, {"revile", "revil"}, {"revise", "revis"}, {"revised", "revis"},
+		{"revising", "revis"}, {"revision", "revis"}, {"revisionism", "revision"}, {"revisions", "revis"}, {"revitalize", "revit"},
+		{"revival", "reviv"}, {"revive", "reviv"}, {"revoke", "revok"}, {"revoked", "revok"}, {"revoker", "revok"}, {"revokes", "revok"},
+		{"revoking", "revok"}, {"revolt", "revolt"}, {"revolution", "revolut"}, {"revolutionary", "revolutionari"},
+		{"revolutionize", "revolution"}, {"revolve", "revolv"}, {"revolver", "revolv"}, {"revs", "rev"}, {"revue", "revu"},
+		{"revulsion", "revuls"}, {"reward", "reward"}, {"rewards", "reward"}, {"rewind", "rewind"}, {"rewinding", "rewind"},
+		{"rewinds", "rewind"}, {"rewrite", "rewrit"}, {"rewrites", "rewrit"}, {"rewriting", "rewrit"}, {"rhapsody", "rhapsodi"},
+		{"rhetoric", "rhetor"}, {"rhetorical", "rhetor"}, {"rheumatic", "rheumat"}, {"rheumatism", "rheumat"}, {"rhino", "rhino"},
+		{"rhinoceros", "rhinocero"}, {"rhododendron", "rhododendron"}, {"rhombus", "rhombus"}, {"rhubarb", "rhubarb"}, {"rhyme", "rhyme"},
+		{"rhythm", "rhythm"}, {"rhythmic", "rhythmic"}, {"rib", "rib"}, {"ribald", "ribald"}, {"ribbon", "ribbon"}, {"rice", "rice"},
+		{"rich", "rich"}, {"riches", "rich"}, {"rickety", "ricketi"}, {"rickshaw", "rickshaw"}, {"ricochet", "ricochet"}, {"rid", "rid"},
+		{"riddance", "riddanc"}, {"riddle", "riddl"}, {"ride", "ride"}, {"rider", "rider"}, {"ridge", "ridg"}, {"ridicule", "ridicul"},
+		{"ridiculous", "ridicul"}, {"rife", "rife"}, {"rifle", "rifl"}, {"rift", "rift"}, {"rig", "rig"}, {"rigging", "rig"}, {"right", "right"},
+		{"righteous", "righteous"}, {"rightful", "right"}, {"rights", "right"}, {"rigid", "rigid"}, {"rigidity", "rigid"}, {"rigidly", "rigid"},
+		{"rigmarole", "rigmarol"}, {"rigor", "rigor"}, {"rigorous", "rigor"}, {"rigour", "rigour"}, {"rile", "rile"}, {"rim", "rim"},
+		{"rind", "rind"}, {"ring", "ring"}, {"ringing", "ring"}, {"ringleader", "ringlead"}, {"ringlet", "ringlet"}, {"rings", "ring"},
+		{"rink", "rink"}, {"rinse", "rins"}, {"riot", "riot"}, {"rioter", "rioter"}, {"riotous", "riotous"}, {"rip", "rip"}, {"ripe", "ripe"},
+		{"ripen", "ripen"}, {"ripple", "rippl"}, {"rise", "rise"}, {"rises", "rise"}, {"risk", "risk"}, {"risking", "risk"}, {"risks", "risk"},
+		{"risky", "riski"}, {"risotto", "risotto"}, {"rite", "rite"}, {"ritual", "ritual"}, {"ritualistic", "ritualist"}, {"rival", "rival"},
+		{"rivalry", "rivalri"}, {"river", "river"}, {"riverside", "riversid"}, {"rivet", "rivet"}, {"road", "road"}, {"roadblock", "roadblock"},
+		{"roadside", "roadsid"}, {"roadway", "roadway"}, {"roam", "roam"}, {"roar", "roar"}, {"roast", "roast"}, {"rob", "rob"},
+		{"robber", "robber"}, {"robbery", "robberi"}, {"robe", "robe"}, {"robin", "robin"}, {"robot", "robot"}, {"robotic", "robot"},
+		{"robotics", "robot"}, {"robots", "robot"}, {"robust", "robust"}, {"robustly", "robust"}, {"robustness", "robust"}, {"rock", "rock"},
+		{"rocker", "rocker"}, {"rocket", "rocket"}, {"rocky", "rocki"}, {"rod", "rod"}, {"rodent", "rodent"}, {"rodeo", "rodeo"}, {"roe", "roe"},
+		{"rogue", "rogu"}, {"roguish", "roguish"}, {"role", "role"}, {"roles", "role"}, {"roll", "roll"}, {"rolled", "roll"},
+		{"roller", "roller"}, {"rolling", "roll"}, {"rolls", "roll"}, {"romance", "romanc"}, {"romantic", "romant"}, {"romanticism", "romantic"},
+		{"romanticize", "romantic"}, {"romp", "romp"}, {"roof", "roof"}, {"rook", "rook"}, {"rookie", "rooki"}, {"room", "room"},
+		{"roommate", "roommat"}, {"rooms", "room"}, {"roomy", "roomi"}, {"roost", "roost"}, {"rooster", "rooster"}, {"root", "root"},
+		{"rooted", "root"}, {"roots", "root"}, {"rope", "rope"}, {"rosary", "rosari"}, {"rose", "rose"}, {"rosemary", "rosemari"},
+		{"rosette", "rosett"}, {"roster", "roster"}, {"rostrum", "rostrum"}, {"rosy", "rosi"}, {"rot", "rot"}, {"rota", "rota"},
+		{"rotary", "rotari"}, {"rotate", "rotat"}, {"rotated", "rotat"}, {"rotates", "rotat"}, {"rotating", "rotat"}, {"rotation", "rotat"},
+		{"rotations", "rotat"}, {"rote", "rote"}, {"rotor", "rotor"}, {"rotten", "rotten"}, {"rotund", "rotund"}, {"rouge", "roug"},
+		{"rough", "rough"}, {"roughage", "roughag"}, {"roughen", "roughen"}, {"roughly", "rough"}, {"roulette", "roulett"}, {"round", "round"},
+		{"roundabout", "roundabout"}, {"rounded", "round"}, {"rounders", "rounder"}, {"rounding", "round"}, {"rounds", "round"},
+		{"rouse", "rous"}, {"rout", "rout"}, {"route", "rout"}, {"routed", "rout"}, {"routes", "rout"}, {"routine", "routin"},
+		{"routines", "routin"}, {"routing", "rout"}, {"rove", "rove"}, {"rover", "rover"}, {"row", "row"}, {"rowdy", "rowdi"}, {"rowing", "row"},
+		{"rows", "row"}, {"royal", "royal"}, {"royalist", "royalist"}, {"royalty", "royalti"}, {"rub", "rub"}, {"rubber", "rubber"},
+		{"rubbish", "rubbish"}, {"rubble", "rubbl"}, {"rubric", "rubric"}, {"ruby", "rubi"}, {"rubys", "rubi"}, {"rucksack", "rucksack"},
+		{"rudder", "rudder"}, {"ruddy", "ruddi"}, {"rude", "rude"}, {"rudiment", "rudiment"}, {"rudimentary", "rudimentari"}, {"rue", "rue"},
+		{"rueful", "rueful"}, {"ruff", "ruff"}, {"ruffian", "ruffian"}, {"ruffle", "ruffl"}, {"rug", "rug"}, {"rugby", "rugbi"},
+		{"rugged", "rug"}, {"ruin", "ruin"}, {"ruinous", "ruinous"}, {"rule", "rule"}, {"ruler", "ruler"}, {"rules", "rule"}, {"ruling", "rule"},
+		{"rum", "rum"}, {"rumble", "rumbl"}, {"ruminate", "rumin"}, {"rummage", "rummag"}, {"rumor", "rumor"}, {"rumour", "rumour"},
+		{"rumoured", "rumour"}, {"rump", "rump"}, {"rumple", "rumpl"}, {"run", "run"}, {"runaway", "runaway"}, {"rung", "rung"},
+		{"runge", "rung"}, {"runner", "runner"}, {"running", "run"}, {"runs", "run"}, {"runway", "runway"}, {"rupture", "ruptur"},
+		{"rural", "rural"}, {"ruse", "ruse"}, {"rush", "rush"}, {"rust", "rust"}, {"rustic", "rustic"}, {"rustle", "rustl"}, {"rusty", "rusti"},
+		{"rut", "rut"}, {"ruthless", "ruthless"}, {"rye", "rye"}, {"sabbath", "sabbath"}, {"sabbatical", "sabbat"}, {"saber", "saber"},
+		{"sabotage", "sabotag"}, {"saboteur", "saboteur"}, {"sabre", "sabr"}, {"sac", "sac"}, {"saccharin", "saccharin"}, {"sachet", "sachet"},
+		{"sack", "sack"}, {"sacrament", "sacrament"}, {"sacred", "sacr"}, {"sacrifice", "sacrific"}, {"sacrifices", "sacrific"},
+		{"sacrificial", "sacrifici"}, {"sacrilege", "sacrileg"}, {"sacrilegious", "sacrilegi"}, {"sad", "sad"}, {"sadden", "sadden"},
+		{"saddle", "saddl"}, {"sadism", "sadism"}, {"sadist", "sadist"}, {"sadistic", "sadist"}, {"sadness", "sad"}, {"safari", "safari"},
+		{"safe", "safe"}, {"safeguard", "safeguard"}, {"safeguards", "safeguard"}, {"safely", "safe"}, {"safes", "safe"}, {"safety", "safeti"},
+		{"saffron", "saffron"}, {"sag", "sag"}, {"saga", "saga"}, {"sagacious", "sagaci"}, {"sagacity", "sagac"}, {"sage", "sage"},
+		{"sail", "sail"}, {"sailboat", "sailboat"}, {"sailor", "sailor"}, {"saint", "saint"}, {"saintly", "saint"}, {"sake", "sake"},
+		{"salad", "salad"}, {"salami", "salami"}, {"salaries", "salari"}, {"salary", "salari"}, {"sale", "sale"}, {"saleable", "saleabl"},
+		{"sales", "sale"}, {"salesman", "salesman"}, {"salesperson", "salesperson"}, {"saleswoman", "saleswoman"}, {"salient", "salient"},
+		{"saline", "salin"}, {"saliva", "saliva"}, {"salivate", "saliv"}, {"sallow", "sallow"}, {"sally", "salli"}, {"salmon", "salmon"},
+		{"salon", "salon"}, {"saloon", "saloon"}, {"salt", "salt"}, {"salted", "salt"}, {"salts", "salt"}, {"salty", "salti"},
+		{"salut", "salut"}, {"salutary", "salutari"}, {"salutation", "salut"}, {"salutations", "salut"}, {"salute", "salut"},
+		{"salvage", "salvag"}, {"salvageable", "salvag"}, {"salvaged", "salvag"}, {"salvation", "salvat"}, {"salve", "salv"}, {"same", "same"},
+		{"sample", "sampl"}, {"sampled", "sampl"}, {"samples", "sampl"}, {"sampling", "sampl"}, {"sanatorium", "sanatorium"},
+		{"sanctify", "sanctifi"}, {"sanctimonious", "sanctimoni"}, {"sanction", "sanction"}, {"sanctity", "sanctiti"},
+		{"sanctuary", "sanctuari"}, {"sand", "sand"}, {"sandal", "sandal"}, {"sandbag", "sandbag"}, {"sande", "sand"}, {"sandpaper", "sandpap"},
+		{"sandstone", "sandston"}, {"sandwich", "sandwich"}, {"sandy", "sandi"}, {"sane", "sane"}, {"sanitary", "sanitari"},
+		{"sanitation", "sanit"}, {"sanitization", "sanit"}, {"sanitize", "sanit"}, {"sanitized", "sanit"}, {"sanitizer", "sanit"},
+		{"sanitizers", "sanit"}, {"sanitizes", "sanit"}, {"sanity", "saniti"}, {"sap", "sap"}, {"sapling", "sapl"}, {"sapphire", "sapphir"},
+		{"sarcasm", "sarcasm"}, {"sarcastic", "sarcast"}, {"sardine", "sardin"}, {"sardonic", "sardon"}, {"sari", "sari"}, {"sash", "sash"},
+		{"satanic", "satan"}, {"satchel", "satchel"}, {"satellite", "satellit"}, {"satin", "satin"}, {"satire", "satir"}, {"satirical", "satir"},
+		{"satirist", "satirist"}, {"satirize", "satir"}, {"satisfaction", "satisfact"}, {"satisfactory", "satisfactori"},
+		{"satisfied", "satisfi"}, {"satisfies", "satisfi"}, {"satisfy", "satisfi"}, {"satisfying", "satisfi"}, {"saturate", "satur"},
+		{"saturation", "satur"}, {"saturday", "saturday"}, {"sauce", "sauc"}, {"saucepan", "saucepan"}, {"saucer", "saucer"}, {"saucy", "sauci"},
+		{"sauna", "sauna"}, {"saunter", "saunter"}, {"sausage", "sausag"}, {"savage", "savag"}, {"savagery", "savageri"},
+		{"savannah", "savannah"}, {"save", "save"}, {"saved", "save"}, {"saver", "saver"}, {"savers", "saver"}, {"saves", "save"},
+		{"saving", "save"}, {"savings", "save"}, {"savior", "savior"}, {"saviour", "saviour"}, {"savor", "savor"}, {"savory", "savori"},
+		{"savour", "savour"}, {"savoury", "savouri"}, {"saw", "saw"}, {"sawdust", "sawdust"}, {"saxophone", "saxophon"}, {"say", "say"},
+		{"saying", "say"}, {"says", "say"}, {"scab", "scab"}, {"scaffold", "scaffold"}, {"scaffolding", "scaffold"}, {"scald", "scald"},
+		{"scale", "scale"}, {"scaled", "scale"}, {"scales", "scale"}, {"scaling", "scale"}, {"scallop", "scallop"}, {"scalp", "scalp"},
+		{"scalpel", "scalpel"}, {"scamper", "scamper"}, {"scan", "scan"}, {"scandal", "scandal"}, {"scandalize", "scandal"},
+		{"scandalous", "scandal"}, {"scanned", "scan"}, {"scanner", "scanner"}, {"scanners", "scanner"}, {"scanning", "scan"}, {"scans", "scan"},
+		{"scant", "scant"}, {"scanty", "scanti"}, {"scapegoat", "scapegoat"}, {"scar", "scar"}, {"scarce", "scarc"}, {"scarcity", "scarciti"},
+		{"scare", "scare"}, {"scarecrow", "scarecrow"}, {"scarf", "scarf"}, {"scarlet", "scarlet"}, {"scary", "scari"}, {"scathing", "scath"},
+		{"scatter", "scatter"}, {"scattered", "scatter"}, {"scavenge", "scaveng"}, {"scavenger", "scaveng"}, {"scenario", "scenario"},
+		{"scenarios", "scenario"}, {"scene", "scene"}, {"scenery", "sceneri"}, {"scenes", "scene"}, {"scenic", "scenic"}, {"scent", "scent"},
+		{"sceptic", "sceptic"}, {"sceptical", "sceptic"}, {"scepticism", "sceptic"}, {"sceptre", "sceptr"}, {"schedulable", "schedul"},
+		{"schedule", "schedul"}, {"scheduled", "schedul"}, {"scheduler", "schedul"}, {"schedulers", "schedul"}, {"schedules", "schedul"},
+		{"scheduling", "schedul"}, {"schema", "schema"}, {"schemas", "schema"}, {"schematic", "schemat"}, {"schematized", "schemat"},
+		{"scheme", "scheme"}, {"schemes", "scheme"}, {"schism", "schism"}, {"schizophrenia", "schizophrenia"}, {"schizophrenic", "schizophren"},
+		{"scholar", "scholar"}, {"scholarly", "scholar"}, {"scholarship", "scholarship"}, {"scholastic", "scholast"}, {"school", "school"},
+		{"schoolboy", "schoolboy"}, {"schoolchild", "schoolchild"}, {"schoolgirl", "schoolgirl"}, {"schooling", "school"},
+		{"schoolmaster", "schoolmast"}, {"schoolteacher", "schoolteach"}, {"schooner", "schooner"}, {"science", "scienc"},
+		{"sciences", "scienc"}, {"scientific", "scientif"}, {"scientist", "scientist"}, {"scientists", "scientist"},
+		{"scintillating", "scintil"}, {"scissors", "scissor"}, {"scoff", "scoff"}, {"scold", "scold"}, {"scone", "scone"}, {"scoop", "scoop"},
+		{"scooter", "scooter"}, {"scope", "scope"}, {"scoped", "scope"}, {"scopes", "scope"}, {"scoping", "scope"}, {"scorch", "scorch"},
+		{"score", "score"}, {"scoreboard", "scoreboard"}, {"scorer", "scorer"}, {"scoring", "score"}, {"scorn", "scorn"}, {"scornful", "scorn"},
+		{"scorpion", "scorpion"}, {"scoundrel", "scoundrel"}, {"scour", "scour"}, {"scourge", "scourg"}, {"scout", "scout"}, {"scowl", "scowl"},
+		{"scrabble", "scrabbl"}, {"scramble", "scrambl"}, {"scrap", "scrap"}, {"scrapbook", "scrapbook"}, {"scrape", "scrape"},
+		{"scratch", "scratch"}, {"scratches", "scratch"}, {"scrawl", "scrawl"}, {"scrawny", "scrawni"}, {"scream", "scream"},
+		{"screaming", "scream"}, {"screech", "screech"}, {"screen", "screen"}, {"screened", "screen"}, {"screenful", "screen"},
+		{"screenfuls", "screen"}, {"screening", "screen"}, {"screenplay", "screenplay"}, {"screens", "screen"}, {"screw", "screw"},
+		{"screwdriver", "screwdriv"}, {"screwed", "screw"}, {"scribble", "scribbl"}, {"scribe", "scribe"}, {"script", "script"},
+		{"scripted", "script"}, {"scripting", "script"}, {"scripts", "script"}, {"scripture", "scriptur"}, {"scroll", "scroll"},
+		{"scrolled", "scroll"}, {"scrolling", "scroll"}, {"scrolls", "scroll"}, {"scrounge", "scroung"}, {"scrub", "scrub"},
+		{"scrubbed", "scrub"}, {"scruff", "scruff"}, {"scruffy", "scruffi"}, {"scruple", "scrupl"}, {"scrupulous", "scrupul"},
+		{"scrutinize", "scrutin"}, {"scrutiny", "scrutini"}, {"scuba", "scuba"}, {"scuff", "scuff"}, {"scuffle", "scuffl"}, {"sculpt", "sculpt"},
+		{"sculptor", "sculptor"}, {"sculpture", "sculptur"}, {"scum", "scum"}, {"scurrilous", "scurril"}, {"scurry", "scurri"},
+		{"scuttle", "scuttl"}, {"scythe", "scyth"}, {"sea", "sea"}, {"seabed", "seab"}, {"seafaring", "seafar"}, {"seafood", "seafood"},
+		{"seagull", "seagul"}, {"seal", "seal"}, {"sealed", "seal"}, {"sealing", "seal"}, {"seals", "seal"}, {"seam", "seam"},
+		{"seaman", "seaman"}, {"seamless", "seamless"}, {"seamlessly", "seamless"}, {"seance", "seanc"}, {"sear", "sear"}, {"search", "search"},
+		{"searched", "search"}, {"searches", "search"}, {"searching", "search"}, {"searchlight", "searchlight"}, {"seashell", "seashel"},
+		{"seashore", "seashor"}, {"seasick", "seasick"}, {"seaside", "seasid"}, {"season", "season"}, {"seasonal", "season"},
+		{"seasoning", "season"}, {"seat", "seat"}, {"seats", "seat"}, {"seaweed", "seawe"}, {"secede", "seced"}, {"secession", "secess"},
+		{"secluded", "seclud"}, {"seclusion", "seclus"}, {"second", "second"}, {"secondaries", "secondari"}, {"secondary", "secondari"},
+		{"secondly", "second"}, {"seconds", "second"}, {"secrecy", "secreci"}, {"secret", "secret"}, {"secretarial", "secretari"},
+		{"secretariat", "secretariat"}, {"secretary", "secretari"}, {"secrete", "secret"}, {"secretion", "secret"}, {"secretive", "secret"},
+		{"secrets", "secret"}, {"sect", "sect"}, {"sectarian", "sectarian"}, {"section", "section"}, {"sections", "section"},
+		{"sector", "sector"}, {"sectors", "sector"}, {"secular", "secular"}, {"secularism", "secular"}, {"secure", "secur"},
+		{"secured", "secur"}, {"securely", "secur"}, {"securing", "secur"}, {"security", "secur"}, {"sedan", "sedan"}, {"sedate", "sedat"},
+		{"sedation", "sedat"}, {"sedative", "sedat"}, {"sedentary", "sedentari"}, {"sediment", "sediment"}, {"sedimentary", "sedimentari"},
+		{"sedition", "sedit"}, {"seditious", "sediti"}, {"seduce", "seduc"}, {"seduction", "seduct"}, {"seductive", "seduct"}, {"see", "see"},
+		{"seed", "seed"}, {"seeded", "seed"}, {"seeding", "seed"}, {"seedling", "seedl"}, {"seeds", "seed"}, {"seedy", "seedi"},
+		{"seeing", "see"}, {"seek", "seek"}, {"seeked", "seek"}, {"seeking", "seek"}, {"seeks", "seek"}, {"seem", "seem"}, {"seemingly", "seem"},
+		{"seems", "seem"}, {"seep", "seep"}, {"sees", "see"}, {"seesaw", "seesaw"}, {"seethe", "seeth"}, {"segment", "segment"},
+		{"segmentation", "segment"}, {"segmented", "segment"}, {"segmenter", "segment"}, {"segments", "segment"}, {"segregate", "segreg"},
+		{"segregation", "segreg"}, {"seismic", "seismic"}, {"seize", "seiz"}, {"seizure", "seizur"}, {"seldom", "seldom"}, {"select", "select"},
+		{"selectable", "select"}, {"selected", "select"}, {"selecting", "select"}, {"selection", "select"}, {"selections", "select"},
+		{"selective", "select"}, {"selectively", "select"}, {"selectivities", "select"}, {"selectivity", "select"}, {"selects", "select"},
+		{"self", "self"}, {"selfish", "selfish"}, {"selfless", "selfless"}, {"sell", "sell"}, {"seller", "seller"}, {"sellers", "seller"},
+		{"selling", "sell"}, {"semantic", "semant"}, {"semantically", "semant"}, {"semantics", "semant"}, {"semblance", "semblanc"},
+		{"semen", "semen"}, {"semester", "semest"}, {"semicircle", "semicircl"}, {"semicolon", "semicolon"}, {"semicolons", "semicolon"},
+		{"semiconductor", "semiconductor"}, {"semifinal", "semifin"}, {"seminar", "seminar"}, {"seminary", "seminari"}, {"senate", "senat"},
+		{"senator", "senat"}, {"send", "send"}, {"sending", "send"}, {"sends", "send"}, {"senile", "senil"}, {"senility", "senil"},
+		{"senior", "senior"}, {"seniority", "senior"}, {"sensation", "sensat"}, {"sensational", "sensat"}, {"sensationalism", "sensat"},
+		{"sense", "sens"}, {"senseless", "senseless"}, {"senses", "sens"}, {"sensibility", "sensibl"}, {"sensible", "sensibl"},
+		{"sensibly", "sensibl"}, {"sensitive", "sensit"}, {"sensitively", "sensit"}, {"sensitivity", "sensit"}, {"sensor", "sensor"},
+		{"sensors", "sensor"}, {"sensory", "sensori"}, {"sensual", "sensual"}, {"sensuality", "sensual"}, {"sensuous", "sensuous"},
+		{"sentence", "sentenc"}, {"sentences", "sentenc"}, {"sentiment", "sentiment"}, {"sentimental", "sentiment"},
+		{"sentimentality", "sentiment"}, {"sentiments", "sentiment"}, {"sentinel", "sentinel"}, {"sentry", "sentri"}, {"separable", "separ"},
+		{"separate", "separ"}, {"separated", "separ"}, {"separately", "separ"}, {"separates", "separ"}, {"separating", "separ"},
+		{"separation", "separ"}, {"separatism", "separat"}, {"separatist", "separatist"}, {"separator", "separ"}, {"separators", "separ"},
+		{"september", "septemb"}, {"septic", "septic"}, {"sequel", "sequel"}, {"sequence", "sequenc"}, {"sequenced", "sequenc"},
+		{"sequencer", "sequenc"}, {"sequences", "sequenc"}, {"sequencing", "sequenc"}, {"sequential", "sequenti"}, {"sequentially", "sequenti"},
+		{"sequin", "sequin"}, {"serenade", "serenad"}, {"serene", "seren"}, {"serenity", "seren"}, {"serf", "serf"}, {"serge", "serg"},
+		{"sergeant", "sergeant"}, {"serial", "serial"}, {"serialization", "serial"}, {"serializations", "serial"}, {"serialize", "serial"},
+		{"serialized", "serial"}, {"serializer", "serial"}, {"serializes", "serial"}, {"serializing", "serial"}, {"serially", "serial"},
+		{"series", "seri"}, {"serious", "serious"}, {"seriously", "serious"}, {"sermon", "sermon"}, {"serpent", "serpent"},
+		{"serrated", "serrat"}, {"serum", "serum"}, {"servant", "servant"}, {"serve", "serv"}, {"served", "serv"}, {"server", "server"},
+		{"servers", "server"}, {"serves", "serv"}, {"service", "servic"}, {"serviceable", "servic"}, {"serviced", "servic"},
+		{"serviceman", "serviceman"}, {"services", "servic"}, {"servicing", "servic"}, {"serviette", "serviett"}, {"servile", "servil"},
+		{"serving", "serv"}, {"servitude", "servitud"}, {"session", "session"}, {"sessions", "session"}, {"set", "set"}, {"setback", "setback"},
+		{"sets", "set"}, {"settee", "sette"}, {"setting", "set"}, {"settings", "set"}, {"settle", "settl"}, {"settled", "settl"},
+		{"settlement", "settlement"}, {"settler", "settler"}, {"settles", "settl"}, {"settls", "settl"}, {"seven", "seven"},
+		{"seventeen", "seventeen"}, {"seventh", "seventh"}, {"seventy", "seventi"}, {"sever", "sever"}, {"several", "sever"},
+		{"severance", "sever"}, {"severe", "sever"}, {"severed", "sever"}, {"severely", "sever"}, {"severities", "sever"}, {"severity", "sever"},
+		{"sew", "sew"}, {"sewage", "sewag"}, {"sewer", "sewer"}, {"sex", "sex"}, {"sexism", "sexism"}, {"sexist", "sexist"},
+		{"sextet", "sextet"}, {"sexual", "sexual"}, {"sexuality", "sexual"}, {"sexy", "sexi"}, {"shabby", "shabbi"}, {"shack", "shack"},
+		{"shackle", "shackl"}, {"shade", "shade"}, {"shadow", "shadow"}, {"shadowed", "shadow"}, {"shadowing", "shadow"}, {"shadows", "shadow"},
+		{"shadowy", "shadowi"}, {"shady", "shadi"}, {"shaft", "shaft"}, {"shaggy", "shaggi"}, {"shake", "shake"}, {"shaky", "shaki"},
+		{"shall", "shall"}, {"shallow", "shallow"}, {"sham", "sham"}, {"shambles", "shambl"}, {"shame", "shame"}, {"shameful", "shame"},
+		{"shameless", "shameless"}, {"shamelessly", "shameless"}, {"shampoo", "shampoo"}, {"shamrock", "shamrock"}, {"shandy", "shandi"},
+		{"shanty", "shanti"}, {"shape", "shape"}, {"shaped", "shape"}, {"shapeless", "shapeless"}, {"shapely", "shape"}, {"shapes", "shape"},
+		{"shaping", "shape"}, {"share", "share"}, {"shared", "share"}, {"shareholder", "sharehold"}, {"shares", "share"}, {"sharing", "share"},
+		{"shark", "shark"}, {"sharp", "sharp"}, {"sharpen", "sharpen"}, {"sharpener", "sharpen"}, {"shatter", "shatter"}, {"shave", "shave"},
+		{"shaver", "shaver"}, {"shawl", "shawl"}, {"she", "she"}, {"sheaf", "sheaf"}, {"shear", "shear"}, {"shears", "shear"},
+		{"sheath", "sheath"}, {"shed", "shed"}, {"sheen", "sheen"}, {"sheep", "sheep"}, {"sheepish", "sheepish"}, {"sheer", "sheer"},
+		{"sheet", "sheet"}, {"sheets", "sheet"}, {"sheikh", "sheikh"}, {"shelf", "shelf"}, {"shell", "shell"}, {"shellfish", "shellfish"},
+		{"shells", "shell"}, {"shelter", "shelter"}, {"shelve", "shelv"}, {"shelved", "shelv"}, {"shepherd", "shepherd"}, {"sherbet", "sherbet"},
+		{"sheriff", "sheriff"}, {"sherry", "sherri"}, {"shi", "shi"}, {"shield", "shield"}, {"shielded", "shield"}, {"shielding", "shield"},
+		{"shields", "shield"}, {"shift", "shift"}, {"shifted", "shift"}, {"shifting", "shift"}, {"shifts", "shift"}, {"shifty", "shifti"},
+		{"shilling", "shill"}, {"shimmer", "shimmer"}, {"shin", "shin"}, {"shine", "shine"}, {"shingle", "shingl"}, {"shingled", "shingl"},
+		{"shiny", "shini"}, {"ship", "ship"}, {"shipbuilding", "shipbuild"}, {"shipment", "shipment"}, {"shipped", "ship"}, {"shipping", "ship"},
+		{"ships", "ship"}, {"shipwreck", "shipwreck"}, {"shipyard", "shipyard"}, {"shirk", "shirk"}, {"shirt", "shirt"}, {"shiver", "shiver"},
+		{"shoal", "shoal"}, {"shock", "shock"}, {"shoddy", "shoddi"}, {"shoe", "shoe"}, {"shoelace", "shoelac"}, {"shoot", "shoot"},
+		{"shop", "shop"}, {"shopkeeper", "shopkeep"}, {"shoplift", "shoplift"}, {"shoplifter", "shoplift"}, {"shopper", "shopper"},
+		{"shopping", "shop"}, {"shore", "shore"}, {"short", "short"}, {"shortage", "shortag"}, {"shortbread", "shortbread"},
+		{"shortcoming", "shortcom"}, {"shortcomings", "shortcom"}, {"shortcut", "shortcut"}, {"shortcuts", "shortcut"}, {"shorten", "shorten"},
+		{"shortened", "shorten"}, {"shortening", "shorten"}, {"shortens", "shorten"}, {"shortfall", "shortfal"}, {"shorthand", "shorthand"},
+		{"shorthands", "shorthand"}, {"shortlist", "shortlist"}, {"shortly", "short"}, {"shorts", "short"}, {"shot", "shot"},
+		{"shotgun", "shotgun"}, {"shots", "shot"}, {"should", "should"}, {"shoulder", "shoulder"}, {"shout", "shout"}, {"shove", "shove"},
+		{"shovel", "shovel"}, {"show", "show"}, {"showcase", "showcas"}, {"showcases", "showcas"}, {"showdown", "showdown"}, {"showed", "show"},
+		{"shower", "shower"}, {"showing", "show"}, {"showman", "showman"}, {"showroom", "showroom"}, {"shows", "show"}, {"showy", "showi"},
+		{"shrapnel", "shrapnel"}, {"shred", "shred"}, {"shrew", "shrew"}, {"shrewd", "shrewd"}, {"shriek", "shriek"}, {"shrill", "shrill"},
+		{"shrimp", "shrimp"}, {"shrine", "shrine"}, {"shrink", "shrink"}, {"shrinking", "shrink"}, {"shrinks", "shrink"}, {"shrivel", "shrivel"},
+		{"shroud", "shroud"}, {"shrub", "shrub"}, {"shrubbery", "shrubberi"}, {"shrug", "shrug"}, {"shudder", "shudder"}, {"shuffle", "shuffl"},
+		{"shuffled", "shuffl"}, {"shuffles", "shuffl"}, {"shuffling", "shuffl"}, {"shun", "shun"}, {"shunt", "shunt"}, {"shut", "shut"},
+		{"shuts", "shut"}, {"shutter", "shutter"}, {"shutting", "shut"}, {"shuttle", "shuttl"}, {"shy", "shi"}, {"sibling", "sibl"},
+		{"siblings", "sibl"}, {"sick", "sick"}, {"sicken", "sicken"}, {"sickle", "sickl"}, {"sickly", "sick"}, {"sickness", "sick"},
+		{"side", "side"}, {"sideboard", "sideboard"}, {"sideline", "sidelin"}, {"sidelong", "sidelong"}, {"sides", "side"},
+		{"sideshow", "sideshow"}, {"sidestep", "sidestep"}, {"sidetrack", "sidetrack"}, {"sidewalk", "sidewalk"}, {"sideways", "sideway"},
+		{"siding", "side"}, {"sidle", "sidl"}, {"siege", "sieg"}, {"siesta", "siesta"}, {"sieve", "siev"}, {"sift", "sift"}, {"sigh", "sigh"},
+		{"sight", "sight"}, {"sightseeing", "sightse"}, {"sign", "sign"}, {"signal", "signal"}, {"signaled", "signal"}, {"signaling", "signal"},
+		{"signalled", "signal"}, {"signalling", "signal"}, {"signals", "signal"}, {"signatory", "signatori"}, {"signature", "signatur"},
+		{"signatures", "signatur"}, {"signed", "sign"}, {"significance", "signific"}, {"significant", "signific"}, {"significantly", "signific"},
+		{"signified", "signifi"}, {"signifies", "signifi"}, {"signify", "signifi"}, {"signifying", "signifi"}, {"signing", "sign"},
+		{"signpost", "signpost"}, {"signs", "sign"}, {"silence", "silenc"}, {"silenced", "silenc"}, {"silencer", "silenc"},
+		{"silences", "silenc"}, {"silencing", "silenc"}, {"silent", "silent"}, {"silently", "silent"}, {"silhouette", "silhouett"},
+		{"silicon", "silicon"}, {"silk", "silk"}, {"silken", "silken"}, {"silkworm", "silkworm"}, {"silky", "silki"}, {"sill", "sill"},
+		{"silly", "silli"}, {"silt", "silt"}, {"silver", "silver"}, {"silversmith", "silversmith"}, {"silverware", "silverwar"},
+		{"silvery", "silveri"}, {"similar", "similar"}, {"similarities", "similar"}, {"similarity", "similar"}, {"similarly", "similar"},
+		{"simile", "simil"}, {"simmer", "simmer"}, {"simple", "simpl"}, {"simplicity", "simplic"}, {"simplification", "simplif"},
+		{"simplifications", "simplif"}, {"simplified", "simplifi"}, {"simplifies", "simplifi"}, {"simplify", "simplifi"},
+		{"simplifying", "simplifi"}, {"simplistic", "simplist"}, {"simply", "simpli"}, {"simulate", "simul"}, {"simulated", "simul"},
+		{"simulates", "simul"}, {"simulating", "simul"}, {"simulation", "simul"}, {"simulations", "simul"}, {"simulator", "simul"},
+		{"simultaneous", "simultan"}, {"simultaneously", "simultan"}, {"sin", "sin"}, {"since", "sinc"}, {"sincere", "sincer"},
+		{"sincerity", "sincer"}, {"sinew", "sinew"}, {"sinful", "sin"}, {"sing", "sing"}, {"singe", "sing"}, {"singer", "singer"},
+		{"singers", "singer"}, {"single", "singl"}, {"singly", "singl"}, {"singular", "singular"}, {"singularity", "singular"},
+		{"sinister", "sinist"}, {"sink", "sink"}, {"sinking", "sink"}, {"sinks", "sink"}, {"sinner", "sinner"}, {"sinuous", "sinuous"},
+		{"sinus", "sinus"}, {"sip", "sip"}, {"siphon", "siphon"}, {"sir", "sir"}, {"siren", "siren"}, {"sirloin", "sirloin"}, {"sissy", "sissi"},
+		{"sister", "sister"}, {"sisterhood", "sisterhood"}, {"sit", "sit"}, {"site", "site"}, {"sites", "site"}, {"sits", "sit"},
+		{"sitter", "sitter"}, {"sitting", "sit"}, {"situate", "situat"}, {"situation", "situat"}, {"situations", "situat"}, {"six", "six"},
+		{"sixteen", "sixteen"}, {"sixth", "sixth"}, {"sixty", "sixti"}, {"sizable", "sizabl"}, {"size", "size"}, {"sizeable", "sizeabl"},
+		{"sized", "size"}, {"sizes", "size"}, {"sizing", "size"}, {"sizzle", "sizzl"}, {"skate", "skate"}, {"skateboard", "skateboard"},
+		{"skater", "skater"}, {"skeletal", "skelet"}, {"skeleton", "skeleton"}, {"sketch", "sketch"}, {"sketchy", "sketchi"}, {"skew", "skew"},
+		{"skewed", "skew"}, {"skewer", "skewer"}, {"skews", "skew"}, {"ski", "ski"}, {"skid", "skid"}, {"skier", "skier"}, {"skil", "skil"},
+		{"skilful", "skil"}, {"skill", "skill"}, {"skillet", "skillet"}, {"skillful", "skill"}, {"skim", "skim"}, {"skimp", "skimp"},
+		{"skin", "skin"}, {"skinny", "skinni"}, {"skip", "skip"}, {"skipped", "skip"}, {"skipper", "skipper"}, {"skipping", "skip"},
+		{"skips", "skip"}, {"skirmish", "skirmish"}, {"skirt", "skirt"}, {"skit", "skit"}, {"skittle", "skittl"}, {"skull", "skull"},
+		{"skunk", "skunk"}, {"sky", "sky"}, {"skylight", "skylight"}, {"skyline", "skylin"}, {"skyscraper", "skyscrap"}, {"slab", "slab"},
+		{"slabs", "slab"}, {"slack", "slack"}, {"slacken", "slacken"}, {"slacks", "slack"}, {"slag", "slag"}, {"slam", "slam"},
+		{"slander", "slander"}, {"slanderous", "slander"}, {"slang", "slang"}, {"slant", "slant"}, {"slap", "slap"}, {"slapdash", "slapdash"},
+		{"slapstick", "slapstick"}, {"slash", "slash"}, {"slashed", "slash"}, {"slashes", "slash"}, {"slat", "slat"}, {"slate", "slate"},
+		{"slaughter", "slaughter"}, {"slaughterhouse", "slaughterhous"}, {"slave", "slave"}, {"slavery", "slaveri"}, {"slaves", "slave"},
+		{"slaving", "slave"}, {"slavish", "slavish"}, {"slay", "slay"}, {"sleazy", "sleazi"}, {"sled", "sled"}, {"sledge", "sledg"},
+		{"sledgehammer", "sledgehamm"}, {"sleek", "sleek"}, {"sleep", "sleep"}, {"sleeper", "sleeper"}, {"sleeping", "sleep"},
+		{"sleepless", "sleepless"}, {"sleeps", "sleep"}, {"sleepy", "sleepi"}, {"sleet", "sleet"}, {"sleeve", "sleev"}, {"sleigh", "sleigh"},
+		{"slender", "slender"}, {"sleuth", "sleuth"}, {"sli", "sli"}, {"slice", "slice"}, {"sliced", "slice"}, {"slices", "slice"},
+		{"slicing", "slice"}, {"slicings", "slice"}, {"slick", "slick"}, {"slide", "slide"}, {"sliding", "slide"}, {"slight", "slight"},
+		{"slightly", "slight"}, {"slim", "slim"}, {"slime", "slime"}, {"slimmed", "slim"}, {"slimy", "slimi"}, {"sling", "sling"},
+		{"slink", "slink"}, {"slip", "slip"}, {"slipper", "slipper"}, {"slippery", "slipperi"}, {"slit", "slit"}, {"slither", "slither"},
+		{"sliver", "sliver"}, {"slob", "slob"}, {"slog", "slog"}, {"slogan", "slogan"}, {"slop", "slop"}, {"slope", "slope"},
+		{"sloppy", "sloppi"}, {"slot", "slot"}, {"sloth", "sloth"}, {"slots", "slot"}, {"slotted", "slot"}, {"slotting", "slot"},
+		{"slouch", "slouch"}, {"slovenly", "sloven"}, {"slow", "slow"}, {"slowdown", "slowdown"}, {"slowed", "slow"}, {"slowing", "slow"},
+		{"slows", "slow"}, {"sludge", "sludg"}, {"slug", "slug"}, {"sluggish", "sluggish"}, {"sluggishness", "sluggish"}, {"sluice", "sluic"},
+		{"slum", "slum"}, {"slumber", "slumber"}, {"slump", "slump"}, {"slur", "slur"}, {"slush", "slush"}, {"sly", "sli"}, {"smack", "smack"},
+		{"small", "small"}, {"smallpox", "smallpox"}, {"smart", "smart"}, {"smarten", "smarten"}, {"smash", "smash"}, {"smashing", "smash"},
+		{"smattering", "smatter"}, {"smear", "smear"}, {"smell", "smell"}, {"smelly", "smelli"}, {"smile", "smile"}, {"smirk", "smirk"},
+		{"smith", "smith"}, {"smock", "smock"}, {"smog", "smog"}, {"smoke", "smoke"}, {"smoker", "smoker"}, {"smokescreen", "smokescreen"},
+		{"smoky", "smoki"}, {"smolder", "smolder"}, {"smooth", "smooth"}, {"smoothing", "smooth"}, {"smoothly", "smooth"}, {"smooths", "smooth"},
+		{"smother", "smother"}, {"smoulder", "smoulder"}, {"smudge", "smudg"}, {"smudged", "smudg"}, {"smug", "smug"}, {"smuggle", "smuggl"},
+		{"smuggler", "smuggler"}, {"smuggling", "smuggl"}, {"snack", "snack"}, {"snag", "snag"}, {"snail", "snail"}, {"snake", "snake"},
+		{"snap", "snap"}, {"snapshot", "snapshot"}, {"snapshotable", "snapshot"}, {"snapshots", "snapshot"}, {"snapshotted", "snapshot"},
+		{"snapshotting", "snapshot"}, {"snare", "snare"}, {"snarl", "snarl"}, {"snatch", "snatch"}, {"sneak", "sneak"}, {"sneaker", "sneaker"},
+		{"sneer", "sneer"}, {"sneeze", "sneez"}, {"snide", "snide"}, {"sniff", "sniff"}, {"sniffle", "sniffl"}, {"snigger", "snigger"},
+		{"snip", "snip"}, {"snipe", "snipe"}, {"sniper", "sniper"}, {"snippet", "snippet"}, {"snippets", "snippet"}, {"snob", "snob"},
+		{"snobbery", "snobberi"}, {"snobbish", "snobbish"}, {"snooker", "snooker"}, {"snoop", "snoop"}, {"snooping", "snoop"},
+		{"snooze", "snooz"}, {"snoozes", "snooz"}, {"snore", "snore"}, {"snorkel", "snorkel"}, {"snort", "snort"}, {"snout", "snout"},
+		{"snow", "snow"}, {"snowball", "snowbal"}, {"snowdrift", "snowdrift"}, {"snowfall", "snowfal"}, {"snowflake", "snowflak"},
+		{"snowman", "snowman"}, {"snowstorm", "snowstorm"}, {"snowy", "snowi"}, {"snub", "snub"}, {"snuff", "snuff"}, {"snug", "snug"},
+		{"snuggle", "snuggl"}, {"so", "so"}, {"soak", "soak"}, {"soaking", "soak"}, {"soap", "soap"}, {"soapy", "soapi"}, {"soar", "soar"},
+		{"sob", "sob"}, {"sober", "sober"}, {"sobriety", "sobrieti"}, {"soccer", "soccer"}, {"sociable", "sociabl"}, {"social", "social"},
+		{"socialism", "social"}, {"socialist", "socialist"}, {"socialite", "socialit"}, {"socialize", "social"}, {"society", "societi"},
+		{"sociological", "sociolog"}, {"sociologist", "sociologist"}, {"sociology", "sociolog"}, {"sock", "sock"}, {"socket", "socket"},
+		{"sockets", "socket"}, {"socks", "sock"}, {"sod", "sod"}, {"soda", "soda"}, {"sodden", "sodden"}, {"sodium", "sodium"}, {"sofa", "sofa"},
+		{"soft", "soft"}, {"soften", "soften"}, {"software", "softwar"}, {"soggy", "soggi"}, {"soil", "soil"}, {"solace", "solac"},
+		{"solar", "solar"}, {"solder", "solder"}, {"soldier", "soldier"}, {"sole", "sole"}, {"solely", "sole"}, {"solemn", "solemn"},
+		{"solemnity", "solemn"}, {"solicit", "solicit"}, {"solicitation", "solicit"}, {"solicitations", "solicit"}, {"solicitor", "solicitor"},
+		{"solicitous", "solicit"}, {"solid", "solid"}, {"solidarity", "solidar"}, {"solidify", "solidifi"}, {"solidity", "solid"},
+		{"soliloquy", "soliloquy"}, {"solitary", "solitari"}, {"solitude", "solitud"}, {"solo", "solo"}, {"soloist", "soloist"},
+		{"solstice", "solstic"}, {"soluble", "solubl"}, {"solution", "solut"}, {"solutions", "solut"}, {"solve", "solv"}, {"solved", "solv"},
+		{"solvent", "solvent"}, {"solves", "solv"}, {"solving", "solv"}, {"somber", "somber"}, {"sombre", "sombr"}, {"some", "some"},
+		{"somebody", "somebodi"}, {"someday", "someday"}, {"somehow", "somehow"}, {"someone", "someon"}, {"someplace", "someplac"},
+		{"somersault", "somersault"}, {"something", "someth"}, {"sometime", "sometim"}, {"sometimes", "sometim"}, {"somewhat", "somewhat"},
+		{"somewhere", "somewher"}, {"son", "son"}, {"sonar", "sonar"}, {"sonata", "sonata"}, {"song", "song"}, {"songwriter", "songwrit"},
+		{"sonic", "sonic"}, {"sonnet", "sonnet"}, {"soon", "soon"}, {"soot", "soot"}, {"soothe", "sooth"}, {"sophisticate", "sophist"},
+		{"sophisticated", "sophist"}, {"sophistication", "sophist"}, {"soporific", "soporif"}, {"soprano", "soprano"}, {"sorcerer", "sorcer"},
+		{"sorcery", "sorceri"}, {"sordid", "sordid"}, {"sore", "sore"}, {"sorrow", "sorrow"}, {"sorrowful", "sorrow"}, {"sorry", "sorri"},
+		{"sort", "sort"}, {"sorted", "sort"}, {"sorting", "sort"}, {"sorts", "sort"}, {"soul", "soul"}, {"soulful", "soul"}, {"sound", "sound"},
+		{"soundproof", "soundproof"}, {"sounds", "sound"}, {"soundtrack", "soundtrack"}, {"soup", "soup"}, {"sour", "sour"}, {"source", "sourc"},
+		{"sourced", "sourc"}, {"sources", "sourc"}, {"sourcing", "sourc"}, {"south", "south"}, {"southeast", "southeast"},
+		{"southerly", "souther"}, {"southern", "southern"}, {"southward", "southward"}, {"southwest", "southwest"}, {"souvenir", "souvenir"},
+		{"sovereign", "sovereign"}, {"sovereignty", "sovereignti"}, {"sow", "sow"}, {"soy", "soy"}, {"soya", "soya"}, {"spa", "spa"},
+		{"space", "space"}, {"spacecraft", "spacecraft"}, {"spaced", "space"}, {"spaces", "space"}, {"spaceship", "spaceship"},
+		{"spacing", "space"}, {"spacious", "spacious"}, {"spade", "spade"}, {"spaghetti", "spaghetti"}, {"span", "span"}, {"spaniel", "spaniel"},
+		{"spank", "spank"}, {"spanned", "span"}, {"spanner", "spanner"}, {"spanning", "span"}, {"spans", "span"}, {"spar", "spar"},
+		{"spare", "spare"}, {"sparingly", "spare"}, {"spark", "spark"}, {"sparkle", "sparkl"}, {"sparrow", "sparrow"}, {"sparse", "spars"},
+		{"sparsely", "spars"}, {"sparseness", "spars"}, {"spartan", "spartan"}, {"spasm", "spasm"}, {"spasmodic", "spasmod"}, {"spate", "spate"},
+		{"spatial", "spatial"}, {"spatter", "spatter"}, {"spawn", "spawn"}, {"spawned", "spawn"}, {"spawning", "spawn"}, {"spawns", "spawn"},
+		{"speak", "speak"}, {"speaker", "speaker"}, {"speakers", "speaker"}, {"speaking", "speak"}, {"speaks", "speak"}, {"spear", "spear"},
+		{"spearhead", "spearhead"}, {"special", "special"}, {"specialist", "specialist"}, {"speciality", "special"},
+		{"specialization", "special"}, {"specialize", "special"}, {"specialized", "special"}, {"specially", "special"},
+		{"specialty", "specialti"}, {"species", "speci"}, {"specifed", "specif"}, {"specific", "specif"}, {"specifically", "specif"},
+		{"specification", "specif"}, {"specifications", "specif"}, {"specificed", "specif"}, {"specificity", "specif"}, {"specifics", "specif"},
+		{"specified", "specifi"}, {"specifier", "specifi"}, {"specifiers", "specifi"}, {"specifies", "specifi"}, {"specify", "specifi"},
+		{"specifying", "specifi"}, {"specimen", "specimen"}, {"specious", "specious"}, {"speck", "speck"}, {"speckle", "speckl"},
+		{"spectacle", "spectacl"}, {"spectacular", "spectacular"}, {"spectator", "spectat"}, {"specter", "specter"}, {"spectre", "spectr"},
+		{"spectrum", "spectrum"}, {"speculate", "specul"}, {"speculation", "specul"}, {"speculative", "specul"}, {"speculator", "specul"},
+		{"speech", "speech"}, {"speechless", "speechless"}, {"speed", "speed"}, {"speedboat", "speedboat"}, {"speeding", "speed"},
+		{"speedometer", "speedomet"}, {"speeds", "speed"}, {"speedy", "speedi"}, {"spell", "spell"}, {"spellbound", "spellbound"},
+		{"spelled", "spell"}, {"spelling", "spell"}, {"spellings", "spell"}, {"spend", "spend"}, {"spender", "spender"}, {"spending", "spend"},
+		{"spends", "spend"}, {"spendthrift", "spendthrift"}, {"sperm", "sperm"}, {"spew", "spew"}, {"spewed", "spew"}, {"spewing", "spew"},
+		{"sphere", "sphere"}, {"spherical", "spheric"}, {"sphinx", "sphinx"}, {"spi", "spi"}, {"spice", "spice"}, {"spicy", "spici"},
+		{"spider", "spider"}, {"spies", "spi"}, {"spike", "spike"}, {"spikes", "spike"}, {"spiky", "spiki"}, {"spill", "spill"},
+		{"spilling", "spill"}, {"spin", "spin"}, {"spinach", "spinach"}, {"spinal", "spinal"}, {"spindle", "spindl"}, {"spine", "spine"},
+		{"spineless", "spineless"}, {"spinning", "spin"}, {"spins", "spin"}, {"spinster", "spinster"}, {"spiral", "spiral"}, {"spire", "spire"},
+		{"spirit", "spirit"}, {"spiritual", "spiritu"}, {"spiritualism", "spiritu"}, {"spit", "spit"}, {"spite", "spite"}, {"spiteful", "spite"},
+		{"splash", "splash"}, {"splatter", "splatter"}, {"spleen", "spleen"}, {"splendid", "splendid"}, {"splendor", "splendor"},
+		{"splendour", "splendour"}, {"splice", "splice"}, {"spliced", "splice"}, {"splices", "splice"}, {"splicing", "splice"},
+		{"splint", "splint"}, {"splinter", "splinter"}, {"split", "split"}, {"splits", "split"}, {"splitting", "split"},
+		{"splutter", "splutter"}, {"spoil", "spoil"}, {"spoke", "spoke"}, {"spokes", "spoke"}, {"spokesman", "spokesman"},
+		{"spokesperson", "spokesperson"}, {"spokeswoman", "spokeswoman"}, {"sponge", "spong"}, {"sponsor", "sponsor"}, {"sponsors", "sponsor"},
+		{"sponsorship", "sponsorship"}, {"spontaneity", "spontan"}, {"spontaneous", "spontan"}, {"spontaneously", "spontan"}, {"spoof", "spoof"},
+		{"spoofed", "spoof"}, {"spoofing", "spoof"}, {"spoofs", "spoof"}, {"spooky", "spooki"}, {"spool", "spool"}, {"spooled", "spool"},
+		{"spoon", "spoon"}, {"spoonful", "spoon"}, {"sporadic", "sporad"}, {"sporadically", "sporad"}, {"spore", "spore"}, {"sport", "sport"},
+		{"sporting", "sport"}, {"sportsman", "sportsman"}, {"sportsmanship", "sportsmanship"}, {"sporty", "sporti"}, {"spot", "spot"},
+		{"spotless", "spotless"}, {"spotlight", "spotlight"}, {"spotted", "spot"}, {"spotty", "spotti"}, {"spouse", "spous"}, {"spout", "spout"},
+		{"sprain", "sprain"}, {"sprawl", "sprawl"}, {"spray", "spray"}, {"spread", "spread"}, {"spreading", "spread"}, {"spreads", "spread"},
+		{"spreadsheet", "spreadsheet"}, {"spree", "spree"}, {"sprig", "sprig"}, {"sprightly", "spright"}, {"spring", "spring"},
+		{"springboard", "springboard"}, {"springtime", "springtim"}, {"sprinkle", "sprinkl"}, {"sprinkler", "sprinkler"}, {"sprint", "sprint"},
+		{"sprinter", "sprinter"}, {"sprout", "sprout"}, {"spruce", "spruce"}, {"spry", "spri"}, {"spur", "spur"}, {"spurious", "spurious"},
+		{"spuriously", "spurious"}, {"spurn", "spurn"}, {"spurt", "spurt"}, {"sputter", "sputter"}, {"spy", "spi"}, {"squabble", "squabbl"},
+		{"squad", "squad"}, {"squadron", "squadron"}, {"squalid", "squalid"}, {"squall", "squall"}, {"squalor", "squalor"},
+		{"squander", "squander"}, {"square", "squar"}, {"squared", "squar"}, {"squares", "squar"}, {"squash", "squash"}, {"squashed", "squash"},
+		{"squashes", "squash"}, {"squashing", "squash"}, {"squat", "squat"}, {"squatter", "squatter"}, {"squawk", "squawk"},
+		{"squawkings", "squawk"}, {"squeak", "squeak"}, {"squeal", "squeal"}, {"squeamish", "squeamish"}, {"squeeze", "squeez"},
+		{"squeezed", "squeez"}, {"squelch", "squelch"}, {"squelched", "squelch"}, {"squid", "squid"}, {"squiggle", "squiggl"},
+		{"squint", "squint"}, {"squire", "squir"}, {"squirm", "squirm"}, {"squirrel", "squirrel"}, {"squirt", "squirt"}, {"stab", "stab"},
+		{"stabil", "stabil"}, {"stability", "stabil"}, {"stabilization", "stabil"}, {"stabilize", "stabil"}, {"stable", "stabl"},
+		{"stabs", "stab"}, {"stack", "stack"}, {"stacked", "stack"}, {"stacking", "stack"}, {"stacks", "stack"}, {"stadium", "stadium"},
+		{"staff", "staff"}, {"stag", "stag"}, {"stage", "stage"}, {"staged", "stage"}, {"stages", "stage"}, {"stagger", "stagger"},
+		{"staging", "stage"}, {"stagnant", "stagnant"}, {"stagnate", "stagnat"}, {"stagnation", "stagnat"}, {"staid", "staid"},
+		{"stain", "stain"}, {"stainless", "stainless"}, {"stair", "stair"}, {"staircase", "staircas"}, {"stairway", "stairway"},
+		{"stake", "stake"}, {"stale", "stale"}, {"stalemate", "stalem"}, {"stalk", "stalk"}, {"stall", "stall"}, {"stalled", "stall"},
+		{"stalling", "stall"}, {"stallion", "stallion"}, {"stalls", "stall"}, {"stalwart", "stalwart"}, {"stamina", "stamina"},
+		{"stammer", "stammer"}, {"stamp", "stamp"}, {"stamped", "stamp"}, {"stampede", "stamped"}, {"stamping", "stamp"}, {"stamps", "stamp"},
+		{"stance", "stanc"}, {"stand", "stand"}, {"standard", "standard"}, {"standardization", "standard"}, {"standardize", "standard"},
+		{"standardized", "standard"}, {"standardizing", "standard"}, {"standards", "standard"}, {"standby", "standbi"}, {"standbys", "standbi"},
+		{"standing", "stand"}, {"standpoint", "standpoint"}, {"stands", "stand"}, {"standstill", "standstil"}, {"stanza", "stanza"},
+		{"stanzas", "stanza"}, {"staple", "stapl"}, {"stapler", "stapler"}, {"stapling", "stapl"}, {"star", "star"}, {"starboard", "starboard"},
+		{"starch", "starch"}, {"stardom", "stardom"}, {"stare", "stare"}, {"stark", "stark"}, {"starlight", "starlight"}, {"starling", "starl"},
+		{"starred", "star"}, {"starry", "starri"}, {"stars", "star"}, {"start", "start"}, {"started", "start"}, {"starter", "starter"},
+		{"starting", "start"}, {"startle", "startl"}, {"starts", "start"}, {"starvation", "starvat"}, {"starve", "starv"}, {"starved", "starv"},
+		{"stash", "stash"}, {"stashed", "stash"}, {"stashes", "stash"}, {"stashing", "stash"}, {"state", "state"}, {"stated", "state"},
+		{"stateful", "state"}, {"stateless", "stateless"}, {"stately", "state"}, {"statement", "statement"}, {"statements", "statement"},
+		{"states", "state"}, {"statesman", "statesman"}, {"statesmanship", "statesmanship"}, {"static", "static"}, {"statically", "static"},
+		{"statics", "static"}, {"stating", "state"}, {"station", "station"}, {"stationary", "stationari"}, {"stationer", "station"},
+		{"stationery", "stationeri"}, {"statistic", "statist"}, {"statistical", "statist"}, {"statistically", "statist"},
+		{"statistician", "statistician"}, {"statistics", "statist"}, {"statue", "statu"}, {"statuesque", "statuesqu"}, {"stature", "statur"},
+		{"status", "status"}, {"statuses", "status"}, {"statute", "statut"}, {"statutory", "statutori"}, {"staunch", "staunch"},
+		{"stave", "stave"}, {"stay", "stay"}, {"staying", "stay"}, {"stays", "stay"}, {"stead", "stead"}, {"steadfast", "steadfast"},
+		{"steady", "steadi"}, {"steak", "steak"}, {"steal", "steal"}, {"stealing", "steal"}, {"stealth", "stealth"}, {"stealthy", "stealthi"},
+		{"steam", "steam"}, {"steamer", "steamer"}, {"steamroller", "steamrol"}, {"steamship", "steamship"}, {"steamy", "steami"},
+		{"steel", "steel"}, {"steep", "steep"}, {"steeple", "steepl"}, {"steer", "steer"}, {"steering", "steer"}, {"stellar", "stellar"},
+		{"stem", "stem"}, {"stemming", "stem"}, {"stems", "stem"}, {"stench", "stench"}, {"stencil", "stencil"}, {"step", "step"},
+		{"stepbrother", "stepbroth"}, {"stepchild", "stepchild"}, {"stepdaughter", "stepdaught"}, {"stepfather", "stepfath"},
+		{"stepmother", "stepmoth"}, {"stepparent", "steppar"}, {"stepped", "step"}, {"stepping", "step"}, {"steps", "step"},
+		{"stepsister", "stepsist"}, {"stepson", "stepson"}, {"stereo", "stereo"}, {"stereotype", "stereotyp"}, {"sterile", "steril"},
+		{"sterility", "steril"}, {"sterilize", "steril"}, {"sterling", "sterl"}, {"stern", "stern"}, {"steroid", "steroid"},
+		{"stethoscope", "stethoscop"}, {"stew", "stew"}, {"steward", "steward"}, {"stewardess", "stewardess"}, {"stewards", "steward"},
+		{"sti", "sti"}, {"stick", "stick"}, {"sticker", "sticker"}, {"sticking", "stick"}, {"sticks", "stick"}, {"sticky", "sticki"},
+		{"stiff", "stiff"}, {"stiffen", "stiffen"}, {"stifle", "stifl"}, {"stifled", "stifl"}, {"stigma", "stigma"}, {"stigmatize", "stigmat"},
+		{"stile", "stile"}, {"stiletto", "stiletto"}, {"still", "still"}, {"stillborn", "stillborn"}, {"stilt", "stilt"}, {"stilted", "stilt"},
+		{"stimulant", "stimul"}, {"stimulate", "stimul"}, {"stimulation", "stimul"}, {"stimulus", "stimulus"}, {"sting", "sting"},
+		{"stingy", "stingi"}, {"stink", "stink"}, {"stint", "stint"}, {"stipend", "stipend"}, {"stipulate", "stipul"}, {"stipulates", "stipul"},
+		{"stipulation", "stipul"}, {"stir", "stir"}, {"stirrup", "stirrup"}, {"stitch", "stitch"}, {"stoat", "stoat"}, {"stock", "stock"},
+		{"stockbroker", "stockbrok"}, {"stockholder", "stockhold"}, {"stocking", "stock"}, {"stockpile", "stockpil"}, {"stocky", "stocki"},
+		{"stodgy", "stodgi"}, {"stoic", "stoic"}, {"stoical", "stoical"}, {"stoicism", "stoicism"}, {"stoke", "stoke"}, {"stole", "stole"},
+		{"stolid", "stolid"}, {"stomach", "stomach"}, {"stomp", "stomp"}, {"stone", "stone"}, {"stony", "stoni"}, {"stool", "stool"},
+		{"stoop", "stoop"}, {"stop", "stop"}, {"stopgap", "stopgap"}, {"stoppage", "stoppag"}, {"stopped", "stop"}, {"stopper", "stopper"},
+		{"stopping", "stop"}, {"stops", "stop"}, {"stopwatch", "stopwatch"}, {"storage", "storag"}, {"storages", "storag"}, {"store", "store"},
+		{"stored", "store"}, {"storeroom", "storeroom"}, {"stores", "store"}, {"storey", "storey"}, {"stories", "stori"}, {"storing", "store"},
+		{"stork", "stork"}, {"storm", "storm"}, {"stormy", "stormi"}, {"story", "stori"}, {"stout", "stout"}, {"stove", "stove"},
+		{"stow", "stow"}, {"stowaway", "stowaway"}, {"straddle", "straddl"}, {"straggle", "straggl"}, {"straggler", "straggler"},
+		{"straight", "straight"}, {"straighten", "straighten"}, {"straightened", "straighten"}, {"straightforward", "straightforward"},
+		{"straightforwardly", "straightforward"}, {"strain", "strain"}, {"strainer", "strainer"}, {"strait", "strait"},
+		{"straitjacket", "straitjacket"}, {"strand", "strand"}, {"strange", "strang"}, {"strangely", "strang"}, {"strangeness", "strang"},
+		{"stranger", "stranger"}, {"strangle", "strangl"}, {"stranglehold", "stranglehold"}, {"strangulation", "strangul"}, {"strap", "strap"},
+		{"strapping", "strap"}, {"strata", "strata"}, {"stratagem", "stratagem"}, {"strategic", "strateg"}, {"strategies", "strategi"},
+		{"strategist", "strategist"}, {"strategy", "strategi"}, {"stratify", "stratifi"}, {"stratosphere", "stratospher"},
+		{"stratum", "stratum"}, {"straw", "straw"}, {"strawberry", "strawberri"}, {"stray", "stray"}, {"streak", "streak"}, {"stream", "stream"},
+		{"streamed", "stream"}, {"streamer", "streamer"}, {"streaming", "stream"}, {"streamline", "streamlin"}, {"streamlined", "streamlin"},
+		{"streams", "stream"}, {"street", "street"}, {"strength", "strength"}, {"strengthen", "strengthen"}, {"strenuous", "strenuous"},
+		{"stress", "stress"}, {"stressful", "stress"}, {"stretch", "stretch"}, {"stretched", "stretch"}, {"stretcher", "stretcher"},
+		{"stretches", "stretch"}, {"strew", "strew"}, {"stricken", "stricken"}, {"strict", "strict"}, {"strictly", "strict"},
+		{"strictness", "strict"}, {"stricture", "strictur"}, {"stride", "stride"}, {"strident", "strident"}, {"strife", "strife"},
+		{"strike", "strike"}, {"striker", "striker"}, {"striking", "strike"}, {"string", "string"}, {"stringent", "stringent"},
+		{"strings", "string"}, {"strip", "strip"}, {"stripe", "stripe"}, {"striped", "stripe"}, {"stripes", "stripe"}, {"stripped", "strip"},
+		{"stripper", "stripper"}, {"stripping", "strip"}, {"strips", "strip"}, {"strive", "strive"}, {"strives", "strive"}, {"stroke", "stroke"},
+		{"strokes", "stroke"}, {"stroll", "stroll"}, {"stroller", "stroller"}, {"strong", "strong"}, {"stronghold", "stronghold"},
+		{"strongly", "strong"}, {"structural", "structur"}, {"structure", "structur"}, {"structured", "structur"}, {"structures", "structur"},
+		{"structuring", "structur"}, {"structurize", "structur"}, {"struggle", "struggl"}, {"strum", "strum"}, {"strut", "strut"},
+		{"stub", "stub"}, {"stubble", "stubbl"}, {"stubborn", "stubborn"}, {"stubs", "stub"}, {"stucco", "stucco"}, {"stud", "stud"},
+		{"student", "student"}, {"studies", "studi"}, {"studio", "studio"}, {"studious", "studious"}, {"study", "studi"}, {"studying", "studi"},
+		{"stuff", "stuff"}, {"stuffing", "stuf"}, {"stuffy", "stuffi"}, {"stumble", "stumbl"}, {"stump", "stump"}, {"stun", "stun"},
+		{"stunt", "stunt"}, {"stupefy", "stupefi"}, {"stupendous", "stupend"}, {"stupid", "stupid"}, {"stupidity", "stupid"},
+		{"stupor", "stupor"}, {"sturdy", "sturdi"}, {"stutter", "stutter"}, {"sty", "sti"}, {"style", "style"}, {"styled", "style"},
+		{"styles", "style"}, {"styling", "style"}, {"stylish", "stylish"}, {"stylist", "stylist"}, {"stylistic", "stylist"},
+		{"stylize", "styliz"}, {"stylized", "styliz"}, {"stylus", "stylus"}, {"suave", "suav"}, {"subconscious", "subconsci"},
+		{"subcontinent", "subcontin"}, {"subcontract", "subcontract"}, {"subculture", "subcultur"}, {"subdivide", "subdivid"},
+		{"subdivided", "subdivid"}, {"subdividing", "subdivid"}, {"subdivision", "subdivis"}, {"subdue", "subdu"}, {"subject", "subject"},
+		{"subjected", "subject"}, {"subjection", "subject"}, {"subjective", "subject"}, {"subjects", "subject"}, {"subjugate", "subjug"},
+		{"subjugation", "subjug"}, {"subjunctive", "subjunct"}, {"sublet", "sublet"}, {"sublime", "sublim"}, {"subliminal", "sublimin"},
+		{"submarine", "submarin"}, {"submerge", "submerg"}, {"submission", "submiss"}, {"submissive", "submiss"}, {"submit", "submit"},
+		{"submits", "submit"}, {"submitted", "submit"}, {"submitting", "submit"}, {"subordinate", "subordin"}, {"subordinates", "subordin"},
+		{"subordination", "subordin"}, {"subpoena", "subpoena"}, {"subscribe", "subscrib"}, {"subscribed", "subscrib"},
+		{"subscriber", "subscrib"}, {"subscribers", "subscrib"}, {"subscribes", "subscrib"}, {"subscribing", "subscrib"},
+		{"subscript", "subscript"}, {"subscriptable", "subscript"}, {"subscripted", "subscript"}, {"subscripting", "subscript"},
+		{"subscription", "subscript"}, {"subscriptions", "subscript"}, {"subscripts", "subscript"}, {"subseque", "subsequ"},
+		{"subsequence", "subsequ"}, {"subsequences", "subsequ"}, {"subsequent", "subsequ"}, {"subsequently", "subsequ"},
+		{"subservient", "subservi"}, {"subside", "subsid"}, {"subsidence", "subsid"}, {"subsidiary", "subsidiari"}, {"subsidize", "subsid"},
+		{"subsidy", "subsidi"}, {"subsist", "subsist"}, {"subsistence", "subsist"}, {"substance", "substanc"}, {"substantial", "substanti"},
+		{"substantially", "substanti"}, {"substantiate", "substanti"}, {"substantive", "substant"}, {"substitute", "substitut"},
+		{"substituted", "substitut"}, {"substitutes", "substitut"}, {"substituting", "substitut"}, {"substitution", "substitut"},
+		{"substitutions", "substitut"}, {"subsume", "subsum"}, {"subsumed", "subsum"}, {"subterfuge", "subterfug"},
+		{"subterranean", "subterranean"}, {"subtitle", "subtitl"}, {"subtle", "subtl"}, {"subtleties", "subtleti"}, {"subtlety", "subtleti"},
+		{"subtract", "subtract"}, {"subtracted", "subtract"}, {"subtracting", "subtract"}, {"subtraction", "subtract"},
+		{"subtracts", "subtract"}, {"suburb", "suburb"}, {"suburban", "suburban"}, {"suburbia", "suburbia"}, {"subversion", "subvers"},
+		{"subversive", "subvers"}, {"subvert", "subvert"}, {"subway", "subway"}, {"succeed", "succeed"}, {"succeeded", "succeed"},
+		{"succeeding", "succeed"}, {"succeeds", "succeed"}, {"success", "success"}, {"successful", "success"}, {"successfully", "success"},
+		{"succession", "success"}, {"successive", "success"}, {"successively", "success"}, {"successor", "successor"},
+		{"successors", "successor"}, {"succinct", "succinct"}, {"succinctly", "succinct"}, {"succor", "succor"}, {"succour", "succour"},
+		{"succulent", "succul"}, {"succumb", "succumb"}, {"such", "such"}, {"suck", "suck"}, {"sucker", "sucker"}, {"suckle", "suckl"},
+		{"suction", "suction"}, {"sudden", "sudden"}, {"suddenly", "sudden"}, {"suds", "sud"}, {"sue", "sue"}, {"suede", "sued"},
+		{"suffer", "suffer"}, {"sufferance", "suffer"}, {"suffered", "suffer"}, {"sufferer", "suffer"}, {"suffering", "suffer"},
+		{"suffers", "suffer"}, {"suffice", "suffic"}, {"suffices", "suffic"}, {"sufficiency", "suffici"}, {"sufficient", "suffici"},
+		{"sufficiently", "suffici"}, {"sufficing", "suffic"}, {"suffix", "suffix"}, {"suffixed", "suffix"}, {"suffixes", "suffix"},
+		{"suffocate", "suffoc"}, {"suffocation", "suffoc"}, {"suffrage", "suffrag"}, {"suffuse", "suffus"}, {"sugar", "sugar"},
+		{"suggest", "suggest"}, {"suggested", "suggest"}, {"suggesting", "suggest"}, {"suggestion", "suggest"}, {"suggestions", "suggest"},
+		{"suggestive", "suggest"}, {"suggests", "suggest"}, {"suicidal", "suicid"}, {"suicide", "suicid"}, {"suit", "suit"},
+		{"suitability", "suitabl"}, {"suitable", "suitabl"}, {"suitably", "suitabl"}, {"suitcase", "suitcas"}, {"suite", "suit"},
+		{"suited", "suit"}, {"suites", "suit"}, {"suitor", "suitor"}, {"suits", "suit"}, {"sulfur", "sulfur"}, {"sulk", "sulk"},
+		{"sulky", "sulki"}, {"sullen", "sullen"}, {"sully", "sulli"}, {"sulphur", "sulphur"}, {"sultan", "sultan"}, {"sultry", "sultri"},
+		{"sum", "sum"}, {"summaries", "summari"}, {"summarize", "summar"}, {"summarized", "summar"}, {"summarizes", "summar"},
+		{"summarizing", "summar"}, {"summary", "summari"}, {"summation", "summat"}, {"summed", "sum"}, {"summer", "summer"},
+		{"summertime", "summertim"}, {"summing", "sum"}, {"summit", "summit"}, {"summon", "summon"}, {"summons", "summon"},
+		{"sumptuous", "sumptuous"}, {"sums", "sum"}, {"sun", "sun"}, {"sunbathe", "sunbath"}, {"sunbeam", "sunbeam"}, {"sunburn", "sunburn"},
+		{"sunday", "sunday"}, {"sundial", "sundial"}, {"sundry", "sundri"}, {"sunflower", "sunflow"}, {"sunglasses", "sunglass"},
+		{"sunken", "sunken"}, {"sunlight", "sunlight"}, {"sunny", "sunni"}, {"sunrise", "sunris"}, {"sunset", "sunset"}, {"sunshine", "sunshin"},
+		{"sunstroke", "sunstrok"}, {"suntan", "suntan"}, {"super", "super"}, {"superb", "superb"}, {"supercilious", "supercili"},
+		{"superconduct", "superconduct"}, {"superficial", "superfici"}, {"superfluous", "superflu"}, {"superhuman", "superhuman"},
+		{"superimpose", "superimpos"}, {"superintend", "superintend"}, {"superintendent", "superintend"}, {"superior", "superior"},
+		{"superiority", "superior"}, {"superiorly", "superior"}, {"superlative", "superl"}, {"superman", "superman"},
+		{"supermarket", "supermarket"}, {"supernatural", "supernatur"}, {"superpower", "superpow"}, {"supersede", "supersed"},
+		{"superseded", "supersed"}, {"supersedes", "supersed"}, {"superseding", "supersed"}, {"supersonic", "superson"},
+		{"superstar", "superstar"}, {"superstition", "superstit"}, {"superstitious", "superstiti"}, {"superstructure", "superstructur"},
+		{"supervise", "supervis"}, {"supervised", "supervis"}, {"supervises", "supervis"}, {"supervision", "supervis"},
+		{"supervisor", "supervisor"}, {"supervisory", "supervisori"}, {"supper", "supper"}, {"supplant", "supplant"}, {"supple", "suppl"},
+		{"supplement", "supplement"}, {"supplemental", "supplement"}, {"supplementary", "supplementari"}, {"supplemented", "supplement"},
+		{"supplementing", "supplement"}, {"supplied", "suppli"}, {"supplier", "supplier"}, {"suppliers", "supplier"}, {"supplies", "suppli"},
+		{"supply", "suppli"}, {"supplying", "suppli"}, {"support", "support"}, {"supported", "support"}, {"supporter", "support"},
+		{"supporting", "support"}, {"supportive", "support"}, {"supports", "support"}, {"suppose", "suppos"}, {"supposed", "suppos"},
+		{"supposedly", "suppos"}, {"supposing", "suppos"}, {"supposition", "supposit"}, {"suppress", "suppress"}, {"suppressed", "suppress"},
+		{"suppresses", "suppress"}, {"suppressing", "suppress"}, {"suppression", "suppress"}, {"suppressions", "suppress"},
+		{"supremacy", "supremaci"}, {"supreme", "suprem"}, {"surcharge", "surcharg"}, {"sure", "sure"}, {"surety", "sureti"}, {"surf", "surf"},
+		{"surface", "surfac"}, {"surfboard", "surfboard"}, {"surfeit", "surfeit"}, {"surfer", "surfer"}, {"surge", "surg"},
+		{"surgeon", "surgeon"}, {"surgery", "surgeri"}, {"surgical", "surgic"}, {"surly", "sur"}, {"surmise", "surmis"},
+		{"surmount", "surmount"}, {"surname", "surnam"}, {"surpass", "surpass"}, {"surplus", "surplus"}, {"surprise", "surpris"},
+		{"surprised", "surpris"}, {"surprises", "surpris"}, {"surprising", "surpris"}, {"surprisingly", "surpris"}, {"surreal", "surreal"},
+		{"surrealism", "surreal"}, {"surrender", "surrend"}, {"surrenders", "surrend"}, {"surreptitious", "surreptiti"}, {"surrogate", "surrog"},
+		{"surrogates", "surrog"}, {"surround", "surround"}, {"surrounded", "surround"}, {"surrounding", "surround"},
+		{"surroundings", "surround"}, {"surrounds", "surround"}, {"surveillance", "surveil"}, {"survey", "survey"}, {"surveyor", "surveyor"},
+		{"surveys", "survey"}, {"survival", "surviv"}, {"survive", "surviv"}, {"survived", "surviv"}, {"survives", "surviv"},
+		{"surviving", "surviv"}, {"survivor", "survivor"}, {"susceptible", "suscept"}, {"suspect", "suspect"}, {"suspected", "suspect"},
+		{"suspects", "suspect"}, {"suspend", "suspend"}, {"suspended", "suspend"}, {"suspender", "suspend"}, {"suspending", "suspend"},
+		{"suspends", "suspend"}, {"suspense", "suspens"}, {"suspension", "suspens"}, {"suspicion", "suspicion"}, {"suspicious", "suspici"},
+		{"sustain", "sustain"}, {"sustainable", "sustain"}, {"sustained", "sustain"}, {"sustenance", "susten"}, {"swab", "swab"},
+		{"swagger", "swagger"}, {"swallow", "swallow"}, {"swallowed", "swallow"}, {"swallowing", "swallow"}, {"swamp", "swamp"},
+		{"swan", "swan"}, {"swap", "swap"}, {"swapped", "swap"}, {"swapping", "swap"}, {"swaps", "swap"}, {"swarm", "swarm"},
+		{"swarthy", "swarthi"}, {"swat", "swat"}, {"sway", "sway"}, {"swear", "swear"}, {"sweat", "sweat"}, {"sweater", "sweater"},
+		{"sweatshirt", "sweatshirt"}, {"sweaty", "sweati"}, {"sweep", "sweep"}, {"sweeper", "sweeper"}, {"sweet", "sweet"},
+		{"sweeten", "sweeten"}, {"sweetener", "sweeten"}, {"sweetheart", "sweetheart"}, {"swell", "swell"}, {"swelter", "swelter"},
+		{"swerve", "swerv"}, {"swift", "swift"}, {"swig", "swig"}, {"swill", "swill"}, {"swim", "swim"}, {"swimmer", "swimmer"},
+		{"swimsuit", "swimsuit"}, {"swindle", "swindl"}, {"swindler", "swindler"}, {"swine", "swine"}, {"swing", "swing"}, {"swipe", "swipe"},
+		{"swirl", "swirl"}, {"swish", "swish"}, {"switch", "switch"}, {"switchboard", "switchboard"}, {"switchboards", "switchboard"},
+		{"switched", "switch"}, {"switches", "switch"}, {"switching", "switch"}, {"swivel", "swivel"}, {"swoon", "swoon"}, {"swoop", "swoop"},
+		{"sword", "sword"}, {"swordfish", "swordfish"}, {"swot", "swot"}, {"sycamore", "sycamor"}, {"sycophant", "sycoph"},
+		{"syllable", "syllabl"}, {"syllabus", "syllabus"}, {"symbol", "symbol"}, {"symbolic", "symbol"}, {"symbolical", "symbol"},
+		{"symbolically", "symbol"}, {"symbolism", "symbol"}, {"symbolization", "symbol"}, {"symbolize", "symbol"}, {"symbolized", "symbol"},
+		{"symbolizer", "symbol"}, {"symbols", "symbol"}, {"symmetric", "symmetr"}, {"symmetrical", "symmetr"}, {"symmetrically", "symmetr"},
+		{"symmetry", "symmetri"}, {"sympathetic", "sympathet"}, {"sympathize", "sympath"}, {"sympathizer", "sympath"}, {"sympathy", "sympathi"},
+		{"symphony", "symphoni"}, {"symposium", "symposium"}, {"symptom", "symptom"}, {"symptomatic", "symptomat"}, {"synagogue", "synagogu"},
+		{"synchronization", "synchron"}, {"synchronize", "synchron"}, {"synchronized", "synchron"}, {"synchronizes", "synchron"},
+		{"synchronizing", "synchron"}, {"synchronous", "synchron"}, {"synchronously", "synchron"}, {"syndicate", "syndic"},
+		{"syndrome", "syndrom"}, {"synonym", "synonym"}, {"synonymous", "synonym"}, {"synonymously", "synonym"}, {"synonyms", "synonym"},
+		{"synopsis", "synopsi"}, {"syntactic", "syntact"}, {"syntactical", "syntact"}, {"syntactically", "syntact"}, {"syntax", "syntax"},
+		{"syntaxes", "syntax"}, {"synthesis", "synthesi"}, {"synthesize", "synthes"}, {"synthesized", "synthes"}, {"synthesizer", "synthes"},
+		{"synthesizes", "synthes"}, {"synthesizing", "synthes"}, {"synthetic", "synthet"}, {"syphilis", "syphili"}, {"syringe", "syring"},
+		{"syrup", "syrup"}, {"system", "system"}, {"systematic", "systemat"}, {"systematically", "systemat"}, {"systems", "system"},
+		{"tab", "tab"}, {"tabby", "tabbi"}, {"table", "tabl"}, {"tableau", "tableau"}, {"tablecloth", "tablecloth"}, {"tables", "tabl"},
+		{"tablespoon", "tablespoon"}, {"tablet", "tablet"}, {"tablets", "tablet"}, {"tabloid", "tabloid"}, {"taboo", "taboo"}, {"tabs", "tab"},
+		{"tabulate", "tabul"}, {"tabulation", "tabul"}, {"tabulator", "tabul"}, {"tacit", "tacit"}, {"taciturn", "taciturn"}, {"tack", "tack"},
+		{"tackle", "tackl"}, {"tacky", "tacki"}, {"tact", "tact"}, {"tactful", "tact"}, {"tactic", "tactic"}, {"tactical", "tactic"},
+		{"tactician", "tactician"}, {"tactics", "tactic"}, {"tactile", "tactil"}, {"tactless", "tactless"}, {"tadpole", "tadpol"},
+		{"tag", "tag"}, {"tagged", "tag"}, {"tagging", "tag"}, {"tags", "tag"}, {"tail", "tail"}, {"tailback", "tailback"}, {"tailing", "tail"},
+		{"tailor", "tailor"}, {"tailored", "tailor"}, {"tails", "tail"}, {"taint", "taint"}, {"tainted", "taint"}, {"tainting", "taint"},
+		{"taints", "taint"}, {"take", "take"}, {"takeaway", "takeaway"}, {"takeoff", "takeoff"}, {"takeover", "takeov"}, {"taker", "taker"},
+		{"takes", "take"}, {"taking", "take"}, {"talc", "talc"}, {"tale", "tale"}, {"talent", "talent"}, {"talisman", "talisman"},
+		{"talk", "talk"}, {"talkative", "talkat"}, {"talked", "talk"}, {"talking", "talk"}, {"talks", "talk"}, {"tall", "tall"},
+		{"tallied", "talli"}, {"tallow", "tallow"}, {"tally", "talli"}, {"talon", "talon"}, {"tambourine", "tambourin"}, {"tame", "tame"},
+		{"tamper", "tamper"}, {"tampered", "tamper"}, {"tampering", "tamper"}, {"tampon", "tampon"}, {"tan", "tan"}, {"tandem", "tandem"},
+		{"tang", "tang"}, {"tangent", "tangent"}, {"tangents", "tangent"}, {"tangerine", "tangerin"}, {"tangible", "tangibl"},
+		{"tangle", "tangl"}, {"tango", "tango"}, {"tank", "tank"}, {"tankard", "tankard"}, {"tanker", "tanker"}, {"tannery", "tanneri"},
+		{"tannin", "tannin"}, {"tantalize", "tantal"}, {"tantamount", "tantamount"}, {"tantrum", "tantrum"}, {"tap", "tap"}, {"tape", "tape"},
+		{"taper", "taper"}, {"tapes", "tape"}, {"tapestry", "tapestri"}, {"tapioca", "tapioca"}, {"tapped", "tap"}, {"tar", "tar"},
+		{"tardy", "tardi"}, {"target", "target"}, {"targeted", "target"}, {"targeting", "target"}, {"targets", "target"},
+		{"targetted", "target"}, {"tariff", "tariff"}, {"tarmac", "tarmac"}, {"tarnish", "tarnish"}, {"tarpaulin", "tarpaulin"},
+		{"tarred", "tar"}, {"tarry", "tarri"}, {"tars", "tar"}, {"tart", "tart"}, {"tartan", "tartan"}, {"task", "task"}, {"tasks", "task"},
+		{"tassel", "tassel"}, {"taste", "tast"}, {"tasteful", "tast"}, {"tasteless", "tasteless"}, {"tasty", "tasti"}, {"tattoo", "tattoo"},
+		{"taunt", "taunt"}, {"taut", "taut"}, {"tavern", "tavern"}, {"tawdry", "tawdri"}, {"tawny", "tawni"}, {"tax", "tax"},
+		{"taxable", "taxabl"}, {"taxation", "taxat"}, {"taxi", "taxi"}, {"taxidermy", "taxidermi"}, {"taxpayer", "taxpay"}, {"tea", "tea"},
+		{"teach", "teach"}, {"teacher", "teacher"}, {"teaches", "teach"}, {"teak", "teak"}, {"team", "team"}, {"teammate", "teammat"},
+		{"teams", "team"}, {"teamwork", "teamwork"}, {"teapot", "teapot"}, {"tear", "tear"}, {"tearful", "tear"}, {"tearing", "tear"},
+		{"tease", "teas"}, {"teaspoon", "teaspoon"}, {"teat", "teat"}, {"technic", "technic"}, {"technical", "technic"},
+		{"technicality", "technic"}, {"technically", "technic"}, {"technician", "technician"}, {"technique", "techniqu"},
+		{"techniques", "techniqu"}, {"technological", "technolog"}, {"technologies", "technolog"}, {"technology", "technolog"},
+		{"teddy", "teddi"}, {"tedious", "tedious"}, {"tedium", "tedium"}, {"teem", "teem"}, {"teenage", "teenag"}, {"teenager", "teenag"},
+		{"teeter", "teeter"}, {"teetotal", "teetot"}, {"telecommunication", "telecommun"}, {"telecommunications", "telecommun"},
+		{"telegram", "telegram"}, {"telegraph", "telegraph"}, {"telepathic", "telepath"}, {"telepathy", "telepathi"}, {"telephone", "telephon"},
+		{"telescope", "telescop"}, {"televise", "televis"}, {"television", "televis"}, {"tell", "tell"}, {"teller", "teller"},
+		{"tellers", "teller"}, {"telling", "tell"}, {"tells", "tell"}, {"telltale", "telltal"}, {"temerity", "temer"}, {"temper", "temper"},
+		{"temperament", "tempera"}, {"temperamental", "temperament"}, {"temperance", "temper"}, {"temperate", "temper"},
+		{"temperature", "temperatur"}, {"tempering", "temper"}, {"tempest", "tempest"}, {"tempestuous", "tempestu"}, {"templ", "templ"},
+		{"template", "templat"}, {"templated", "templat"}, {"templates", "templat"}, {"temple", "templ"}, {"tempo", "tempo"},
+		{"temporal", "tempor"}, {"temporaries", "temporari"}, {"temporary", "temporari"}, {"tempt", "tempt"}, {"temptation", "temptat"},
+		{"tempted", "tempt"}, {"tempting", "tempt"}, {"ten", "ten"}, {"tenable", "tenabl"}, {"tenacious", "tenaci"}, {"tenacity", "tenac"},
+		{"tenancy", "tenanc"}, {"tenant", "tenant"}, {"tenants", "tenant"}, {"tend", "tend"}, {"tended", "tend"}, {"tendency", "tendenc"},
+		{"tender", "tender"}, {"tendon", "tendon"}, {"tendril", "tendril"}, {"tends", "tend"}, {"tenement", "tenement"}, {"tenet", "tenet"},
+		{"tennis", "tenni"}, {"tenor", "tenor"}, {"tens", "ten"}, {"tense", "tens"}, {"tension", "tension"}, {"tent", "tent"},
+		{"tentacle", "tentacl"}, {"tentative", "tentat"}, {"tentatively", "tentat"}, {"tentativeness", "tentat"}, {"tenth", "tenth"},
+		{"tenths", "tenth"}, {"tenuous", "tenuous"}, {"tenure", "tenur"}, {"tenuring", "tenur"}, {"tepid", "tepid"}, {"term", "term"},
+		{"termed", "term"}, {"terminal", "termin"}, {"terminals", "termin"}, {"terminate", "termin"}, {"terminated", "termin"},
+		{"terminates", "termin"}, {"terminating", "termin"}, {"termination", "termin"}, {"terminations", "termin"}, {"terminator", "termin"},
+		{"terminators", "termin"}, {"terminology", "terminolog"}, {"terminus", "terminus"}, {"termite", "termit"}, {"terms", "term"},
+		{"terrace", "terrac"}, {"terrain", "terrain"}, {"terrestrial", "terrestri"}, {"terrible", "terribl"}, {"terrier", "terrier"},
+		{"terrific", "terrif"}, {"terrify", "terrifi"}, {"territorial", "territori"}, {"territories", "territori"}, {"territory", "territori"},
+		{"terror", "terror"}, {"terrorism", "terror"}, {"terrorist", "terrorist"}, {"terrorize", "terror"}, {"terse", "ters"},
+		{"tertiary", "tertiari"}, {"test", "test"}, {"testament", "testament"}, {"tested", "test"}, {"testicle", "testicl"},
+		{"testify", "testifi"}, {"testimonial", "testimoni"}, {"testimonials", "testimoni"}, {"testimony", "testimoni"}, {"testing", "test"},
+		{"tests", "test"}, {"tether", "tether"}, {"text", "text"}, {"textbook", "textbook"}, {"textile", "textil"}, {"texts", "text"},
+		{"textual", "textual"}, {"textually", "textual"}, {"texture", "textur"}, {"than", "than"}, {"thank", "thank"}, {"thankful", "thank"},
+		{"thankless", "thankless"}, {"thanks", "thank"}, {"thanksgiving", "thanksgiv"}, {"that", "that"}, {"thatch", "thatch"}, {"thaw", "thaw"},
+		{"thawed", "thaw"}, {"the", "the"}, {"theater", "theater"}, {"theatre", "theatr"}, {"theatrical", "theatric"}, {"thee", "thee"},
+		{"theft", "theft"}, {"their", "their"}, {"theirs", "their"}, {"them", "them"}, {"theme", "theme"}, {"themes", "theme"},
+		{"themselves", "themselv"}, {"then", "then"}, {"thence", "thenc"}, {"theologian", "theologian"}, {"theological", "theolog"},
+		{"theology", "theolog"}, {"theorem", "theorem"}, {"theoretic", "theoret"}, {"theoretical", "theoret"}, {"theoretically", "theoret"},
+		{"theorist", "theorist"}, {"theorize", "theoriz"}, {"theory", "theori"}, {"therapeutic", "therapeut"}, {"therapist", "therapist"},
+		{"therapy", "therapi"}, {"there", "there"}, {"thereabouts", "thereabout"}, {"thereafter", "thereaft"}, {"thereby", "therebi"},
+		{"therefore", "therefor"}, {"therein", "therein"}, {"thereof", "thereof"}, {"thereupon", "thereupon"}, {"thermal", "thermal"},
+		{"thermometer", "thermomet"}, {"thermostat", "thermostat"}, {"thesaurus", "thesaurus"}, {"these", "these"}, {"theses", "these"},
+		{"thesis", "thesi"}, {"they", "they"}, {"thick", "thick"}, {"thicken", "thicken"}, {"thicket", "thicket"}, {"thief", "thief"},
+		{"thigh", "thigh"}, {"thimble", "thimbl"}, {"thin", "thin"}, {"thing", "thing"}, {"things", "thing"}, {"think", "think"},
+		{"thinker", "thinker"}, {"thinking", "think"}, {"thinks", "think"}, {"thinly", "thin"}, {"third", "third"}, {"thirst", "thirst"},
+		{"thirsty", "thirsti"}, {"thirteen", "thirteen"}, {"thirty", "thirti"}, {"this", "this"}, {"thistle", "thistl"}, {"thorn", "thorn"},
+		{"thorny", "thorni"}, {"thorough", "thorough"}, {"thoroughbred", "thoroughbr"}, {"thoroughfare", "thoroughfar"},
+		{"thoroughly", "thorough"}, {"those", "those"}, {"thou", "thou"}, {"though", "though"}, {"thought", "thought"},
+		{"thoughtful", "thought"}, {"thoughtless", "thoughtless"}, {"thousand", "thousand"}, {"thousands", "thousand"},
+		{"thousandth", "thousandth"}, {"thousandths", "thousandth"}, {"thrash", "thrash"}, {"thrashing", "thrash"}, {"thread", "thread"},
+		{"threadbare", "threadbar"}, {"threaded", "thread"}, {"threading", "thread"}, {"threads", "thread"}, {"threat", "threat"},
+		{"threaten", "threaten"}, {"threatened", "threaten"}, {"threats", "threat"}, {"three", "three"}, {"threshold", "threshold"},
+		{"thresholds", "threshold"}, {"thrice", "thrice"}, {"thrift", "thrift"}, {"thrifty", "thrifti"}, {"thrill", "thrill"},
+		{"thriller", "thriller"}, {"thrive", "thrive"}, {"throat", "throat"}, {"throb", "throb"}, {"throes", "throe"}, {"throne", "throne"},
+		{"throng", "throng"}, {"throttle", "throttl"}, {"throttled", "throttl"}, {"throttles", "throttl"}, {"throttling", "throttl"},
+		{"through", "through"}, {"throughout", "throughout"}, {"throw", "throw"}, {"throwback", "throwback"}, {"throwed", "throw"},
+		{"throwing", "throw"}, {"throws", "throw"}, {"thrush", "thrush"}, {"thrust", "thrust"}, {"thud", "thud"}, {"thug", "thug"},
+		{"thumb", "thumb"}, {"thump", "thump"}, {"thunder", "thunder"}, {"thunderbolt", "thunderbolt"}, {"thundering", "thunder"},
+		{"thunderous", "thunder"}, {"thunderstorm", "thunderstorm"}, {"thursday", "thursday"}, {"thus", "thus"}, {"thwart", "thwart"},
+		{"thy", "thi"}, {"thyme", "thyme"}, {"thyroid", "thyroid"}, {"tiara", "tiara"}, {"tick", "tick"}, {"ticket", "ticket"},
+		{"tickets", "ticket"}, {"ticking", "tick"}, {"tickle", "tickl"}, {"ticklish", "ticklish"}, {"ticks", "tick"}, {"tidal", "tidal"},
+		{"tide", "tide"}, {"tidy", "tidi"}, {"tidying", "tidi"}, {"tie", "tie"}, {"tied", "tie"}, {"tier", "tier"}, {"tiered", "tier"},
+		{"tiering", "tier"}, {"tiers", "tier"}, {"ties", "tie"}, {"tiger", "tiger"}, {"tight", "tight"}, {"tighten", "tighten"},
+		{"tightened", "tighten"}, {"tightening", "tighten"}, {"tightens", "tighten"}, {"tightly", "tight"}, {"tightrope", "tightrop"},
+		{"tights", "tight"}, {"tile", "tile"}, {"tiled", "tile"}, {"tiling", "tile"}, {"till", "till"}, {"tilt", "tilt"}, {"timber", "timber"},
+		{"time", "time"}, {"timed", "time"}, {"timeless", "timeless"}, {"timely", "time"}, {"timer", "timer"}, {"timers", "timer"},
+		{"times", "time"}, {"timetable", "timet"}, {"timid", "timid"}, {"timidity", "timid"}, {"timing", "time"}, {"timings", "time"},
+		{"tin", "tin"}, {"tinder", "tinder"}, {"tinge", "ting"}, {"tingle", "tingl"}, {"tinker", "tinker"}, {"tinkle", "tinkl"}, {"tins", "tin"},
+		{"tinsel", "tinsel"}, {"tint", "tint"}, {"tiny", "tini"}, {"tip", "tip"}, {"tipple", "tippl"}, {"tips", "tip"}, {"tipsy", "tipsi"},
+		{"tiptoe", "tipto"}, {"tirade", "tirad"}, {"tire", "tire"}, {"tired", "tire"}, {"tireless", "tireless"}, {"tiresome", "tiresom"},
+		{"tissue", "tissu"}, {"tit", "tit"}, {"titan", "titan"}, {"titanic", "titan"}, {"titbit", "titbit"}, {"tithe", "tith"},
+		{"titillate", "titil"}, {"titl", "titl"}, {"title", "titl"}, {"titled", "titl"}, {"titles", "titl"}, {"titter", "titter"},
+		{"toad", "toad"}, {"toadstool", "toadstool"}, {"toast", "toast"}, {"toasted", "toast"}, {"toaster", "toaster"}, {"tobacco", "tobacco"},
+		{"tobacconist", "tobacconist"}, {"toboggan", "toboggan"}, {"today", "today"}, {"toddle", "toddl"}, {"toddler", "toddler"},
+		{"toe", "toe"}, {"toenail", "toenail"}, {"toffee", "toffe"}, {"together", "togeth"}, {"toil", "toil"}, {"toilet", "toilet"},
+		{"toiletries", "toiletri"}, {"token", "token"}, {"tokenization", "token"}, {"tokenize", "token"}, {"tokenized", "token"},
+		{"tokenizing", "token"}, {"tokens", "token"}, {"tolerable", "toler"}, {"tolerance", "toler"}, {"tolerant", "toler"},
+		{"tolerate", "toler"}, {"tolerated", "toler"}, {"tolerates", "toler"}, {"toleration", "toler"}, {"tolerations", "toler"},
+		{"toll", "toll"}, {"tomato", "tomato"}, {"tomb", "tomb"}, {"tombstone", "tombston"}, {"tomorrow", "tomorrow"}, {"ton", "ton"},
+		{"tonal", "tonal"}, {"tone", "tone"}, {"tongs", "tong"}, {"tongue", "tongu"}, {"tonic", "tonic"}, {"tonight", "tonight"},
+		{"tonnage", "tonnag"}, {"tonne", "tonn"}, {"tons", "ton"}, {"tonsil", "tonsil"}, {"tonsillitis", "tonsil"}, {"too", "too"},
+		{"tool", "tool"}, {"tooling", "tool"}, {"tools", "tool"}, {"tooth", "tooth"}, {"toothache", "toothach"}, {"toothbrush", "toothbrush"},
+		{"toothpaste", "toothpast"}, {"top", "top"}, {"topaz", "topaz"}, {"topic", "topic"}, {"topical", "topic"}, {"topics", "topic"},
+		{"topography", "topographi"}, {"topple", "toppl"}, {"torch", "torch"}, {"torment", "torment"}, {"tormentor", "tormentor"},
+		{"tornado", "tornado"}, {"torpedo", "torpedo"}, {"torrent", "torrent"}, {"torrential", "torrenti"}, {"torrid", "torrid"},
+		{"torso", "torso"}, {"tortoise", "tortois"}, {"tortuous", "tortuous"}, {"torture", "tortur"}, {"torturer", "tortur"}, {"toss", "toss"},
+		{"tot", "tot"}, {"total", "total"}, {"totalitarian", "totalitarian"}, {"totalitarianism", "totalitarian"}, {"totality", "total"},
+		{"totalling", "total"}, {"totally", "total"}, {"totals", "total"}, {"tote", "tote"}, {"totem", "totem"}, {"totter", "totter"},
+		{"touch", "touch"}, {"touchdown", "touchdown"}, {"touched", "touch"}, {"touches", "touch"}, {"touching", "touch"},
+		{"touchstone", "touchston"}, {"touchy", "touchi"}, {"tough", "tough"}, {"toughen", "toughen"}, {"toupee", "toupe"}, {"tour", "tour"},
+		{"tourism", "tourism"}, {"tourist", "tourist"}, {"tournament", "tournament"}, {"tousle", "tousl"}, {"tout", "tout"}, {"tow", "tow"},
+		{"toward", "toward"}, {"towards", "toward"}, {"towel", "towel"}, {"tower", "tower"}, {"towers", "tower"}, {"town", "town"},
+		{"township", "township"}, {"toxic", "toxic"}, {"toxin", "toxin"}, {"toy", "toy"}, {"toys", "toy"}, {"trace", "trace"},
+		{"traceable", "traceabl"}, {"traced", "trace"}, {"tracee", "trace"}, {"tracees", "trace"}, {"traces", "trace"}, {"tracing", "trace"},
+		{"track", "track"}, {"tracked", "track"}, {"tracking", "track"}, {"tracks", "track"}, {"tract", "tract"}, {"traction", "traction"},
+		{"tractor", "tractor"}, {"trade", "trade"}, {"trademark", "trademark"}, {"trademarks", "trademark"}, {"trader", "trader"},
+		{"trades", "trade"}, {"tradesman", "tradesman"}, {"tradition", "tradit"}, {"traditional", "tradit"},
+		{"traditionalist", "traditionalist"}, {"traditionally", "tradit"}, {"traffic", "traffic"}, {"trafficker", "traffick"},
+		{"tragedy", "tragedi"}, {"tragic", "tragic"}, {"trail", "trail"}, {"trailer", "trailer"}, {"trailers", "trailer"}, {"trailing", "trail"},
+		{"trails", "trail"}, {"train", "train"}, {"trained", "train"}, {"trainee", "traine"}, {"trainer", "trainer"}, {"training", "train"},
+		{"trait", "trait"}, {"traitor", "traitor"}, {"traits", "trait"}, {"trajectory", "trajectori"}, {"tram", "tram"}, {"tramp", "tramp"},
+		{"trample", "trampl"}, {"trampoline", "trampolin"}, {"trampolines", "trampolin"}, {"trance", "tranc"}, {"tranquil", "tranquil"},
+		{"tranquility", "tranquil"}, {"tranquilizer", "tranquil"}, {"tranquillity", "tranquil"}, {"tranquillizer", "tranquil"},
+		{"transact", "transact"}, {"transaction", "transact"}, {"transactional", "transact"}, {"transactions", "transact"},
+		{"transatlantic", "transatlant"}, {"transcend", "transcend"}, {"transcendence", "transcend"}, {"transcendent", "transcend"},
+		{"transcribe", "transcrib"}, {"transcript", "transcript"}, {"transcription", "transcript"}, {"transcripts", "transcript"},
+		{"transfer", "transfer"}, {"transferable", "transfer"}, {"transferred", "transfer"}, {"transferring", "transfer"},
+		{"transfers", "transfer"}, {"transfigure", "transfigur"}, {"transform", "transform"}, {"transformation", "transform"},
+		{"transformations", "transform"}, {"transformed", "transform"}, {"transformer", "transform"}, {"transforming", "transform"},
+		{"transforms", "transform"}, {"transfusion", "transfus"}, {"transgress", "transgress"}, {"transgression", "transgress"},
+		{"transient", "transient"}, {"transiently", "transient"}, {"transistor", "transistor"}, {"transit", "transit"},
+		{"transition", "transit"}, {"transitional", "transit"}, {"transitioned", "transit"}, {"transitioning", "transit"},
+		{"transitions", "transit"}, {"transitive", "transit"}, {"transitively", "transit"}, {"transitivity", "transit"},
+		{"transitory", "transitori"}, {"translatable", "translat"}, {"translate", "translat"}, {"translated", "translat"},
+		{"translates", "translat"}, {"translating", "translat"}, {"translation", "translat"}, {"translations", "translat"},
+		{"translator", "translat"}, {"translators", "translat"}, {"translucent", "transluc"}, {"transmission", "transmiss"},
+		{"transmissions", "transmiss"}, {"transmit", "transmit"}, {"transmits", "transmit"}, {"transmitted", "transmit"},
+		{"transmitter", "transmitt"}, {"transmitters", "transmitt"}, {"transmitting", "transmit"}, {"transparency", "transpar"},
+		{"transparent", "transpar"}, {"transparently", "transpar"}, {"transpire", "transpir"}, {"transplant", "transplant"},
+		{"transport", "transport"}, {"transportation", "transport"}, {"transporting", "transport"}, {"transports", "transport"},
+		{"transpose", "transpos"}, {"transposes", "transpos"}, {"transverse", "transvers"}, {"transverses", "transvers"},
+		{"transvestite", "transvestit"}, {"trap", "trap"}, {"trapdoor", "trapdoor"}, {"trapeze", "trapez"}, {"trapped", "trap"},
+		{"trapping", "trap"}, {"trappings", "trap"}, {"traps", "trap"}, {"trash", "trash"}, {"trashed", "trash"}, {"trauma", "trauma"},
+		{"traumatic", "traumat"}, {"travel", "travel"}, {"traveler", "travel"}, {"traveller", "travel"}, {"traversal", "travers"},
+		{"traversals", "travers"}, {"traverse", "travers"}, {"traversed", "travers"}, {"traverses", "travers"}, {"traversing", "travers"},
+		{"travesty", "travesti"}, {"trawl", "trawl"}, {"trawler", "trawler"}, {"tray", "tray"}, {"treacherous", "treacher"},
+		{"treachery", "treacheri"}, {"treacle", "treacl"}, {"tread", "tread"}, {"treadmill", "treadmil"}, {"treason", "treason"},
+		{"treasonable", "treason"}, {"treasure", "treasur"}, {"treasurer", "treasur"}, {"treasury", "treasuri"}, {"treat", "treat"},
+		{"treated", "treat"}, {"treating", "treat"}, {"treatise", "treatis"}, {"treatment", "treatment"}, {"treats", "treat"},
+		{"treaty", "treati"}, {"treble", "trebl"}, {"tree", "tree"}, {"trees", "tree"}, {"trek", "trek"}, {"trellis", "trelli"},
+		{"tremble", "trembl"}, {"tremendous", "tremend"}, {"tremendously", "tremend"}, {"tremor", "tremor"}, {"trench", "trench"},
+		{"trenchant", "trenchant"}, {"trend", "trend"}, {"trendy", "trendi"}, {"trepidation", "trepid"}, {"trespass", "trespass"},
+		{"trespasser", "trespass"}, {"trestle", "trestl"}, {"tri", "tri"}, {"trial", "trial"}, {"trials", "trial"}, {"triangle", "triangl"},
+		{"triangular", "triangular"}, {"tribal", "tribal"}, {"tribe", "tribe"}, {"tribulation", "tribul"}, {"tribunal", "tribun"},
+		{"tributary", "tributari"}, {"tribute", "tribut"}, {"trick", "trick"}, {"tricked", "trick"}, {"trickery", "trickeri"},
+		{"trickle", "trickl"}, {"tricks", "trick"}, {"tricky", "tricki"}, {"tricycle", "tricycl"}, {"trident", "trident"}, {"tried", "tri"},
+		{"tries", "tri"}, {"trifle", "trifl"}, {"trigger", "trigger"}, {"triggered", "trigger"}, {"triggerer", "trigger"},
+		{"triggerers", "trigger"}, {"triggering", "trigger"}, {"triggers", "trigger"}, {"trigonometry", "trigonometri"}, {"trill", "trill"},
+		{"trillion", "trillion"}, {"trilogy", "trilog"}, {"trim", "trim"}, {"trimmed", "trim"}, {"trimming", "trim"}, {"trims", "trim"},
+		{"trinity", "triniti"}, {"trinket", "trinket"}, {"trio", "trio"}, {"trip", "trip"}, {"tripe", "tripe"}, {"triple", "tripl"},
+		{"triples", "tripl"}, {"triplet", "triplet"}, {"triplets", "triplet"}, {"tripod", "tripod"}, {"tripping", "trip"}, {"trips", "trip"},
+		{"trite", "trite"}, {"triumph", "triumph"}, {"triumphal", "triumphal"}, {"triumphant", "triumphant"}, {"trivia", "trivia"},
+		{"trivial", "trivial"}, {"triviality", "trivial"}, {"trivially", "trivial"}, {"trolley", "trolley"}, {"trombone", "trombon"},
+		{"troop", "troop"}, {"trooper", "trooper"}, {"trophy", "trophi"}, {"tropic", "tropic"}, {"tropical", "tropic"}, {"trot", "trot"},
+		{"trouble", "troubl"}, {"troublemaker", "troublemak"}, {"troubles", "troubl"}, {"troublesome", "troublesom"}, {"trough", "trough"},
+		{"trounce", "trounc"}, {"troupe", "troup"}, {"trousers", "trouser"}, {"trout", "trout"}, {"trowel", "trowel"}, {"truancy", "truanci"},
+		{"truant", "truant"}, {"truce", "truce"}, {"truck", "truck"}, {"trudge", "trudg"}, {"true", "true"}, {"truffle", "truffl"},
+		{"truism", "truism"}, {"truly", "truli"}, {"trump", "trump"}, {"trumpet", "trumpet"}, {"truncate", "truncat"}, {"truncated", "truncat"},
+		{"truncates", "truncat"}, {"truncating", "truncat"}, {"truncation", "truncat"}, {"truncheon", "truncheon"}, {"trundle", "trundl"},
+		{"trunk", "trunk"}, {"truss", "truss"}, {"trust", "trust"}, {"trusted", "trust"}, {"trustee", "truste"}, {"trusting", "trust"},
+		{"trusts", "trust"}, {"trustworthy", "trustworthi"}, {"truth", "truth"}, {"truthful", "truth"}, {"try", "tri"}, {"trying", "tri"},
+		{"tub", "tub"}, {"tuba", "tuba"}, {"tube", "tube"}, {"tuberculosis", "tuberculosi"}, {"tubular", "tubular"}, {"tuck", "tuck"},
+		{"tuesday", "tuesday"}, {"tuft", "tuft"}, {"tug", "tug"}, {"tuition", "tuition"}, {"tulip", "tulip"}, {"tumble", "tumbl"},
+		{"tumbler", "tumbler"}, {"tummy", "tummi"}, {"tumor", "tumor"}, {"tumour", "tumour"}, {"tumult", "tumult"}, {"tumultuous", "tumultu"},
+		{"tuna", "tuna"}, {"tune", "tune"}, {"tuned", "tune"}, {"tuneful", "tune"}, {"tunes", "tune"}, {"tunic", "tunic"}, {"tuning", "tune"},
+		{"tunings", "tune"}, {"tunnel", "tunnel"}, {"tunneled", "tunnel"}, {"tunneling", "tunnel"}, {"tunnelling", "tunnel"},
+		{"tunnels", "tunnel"}, {"turban", "turban"}, {"turbine", "turbin"}, {"turbulence", "turbul"}, {"turbulent", "turbul"}, {"turf", "turf"},
+		{"turkey", "turkey"}, {"turmoil", "turmoil"}, {"turn", "turn"}, {"turned", "turn"}, {"turning", "turn"}, {"turnip", "turnip"},
+		{"turnout", "turnout"}, {"turnover", "turnov"}, {"turnpike", "turnpik"}, {"turns", "turn"}, {"turnstile", "turnstil"},
+		{"turntable", "turntabl"}, {"turpentine", "turpentin"}, {"turquoise", "turquois"}, {"turret", "turret"}, {"turtle", "turtl"},
+		{"tusk", "tusk"}, {"tussle", "tussl"}, {"tutor", "tutor"}, {"tutorial", "tutori"}, {"tutorials", "tutori"}, {"tuxedo", "tuxedo"},
+		{"twang", "twang"}, {"tweak", "tweak"}, {"tweaked", "tweak"}, {"tweaking", "tweak"}, {"tweaks", "tweak"}, {"tweed", "tweed"},
+		{"tweezers", "tweezer"}, {"twelfth", "twelfth"}, {"twelve", "twelv"}, {"twentieth", "twentieth"}, {"twenty", "twenti"},
+		{"twice", "twice"}, {"twiddle", "twiddl"}, {"twiddling", "twiddl"}, {"twig", "twig"}, {"twilight", "twilight"}, {"twin", "twin"},
+		{"twine", "twine"}, {"twinge", "twing"}, {"twinkle", "twinkl"}, {"twirl", "twirl"}, {"twist", "twist"}, {"twisted", "twist"},
+		{"twit", "twit"}, {"twitch", "twitch"}, {"twitter", "twitter"}, {"two", "two"}, {"tycoon", "tycoon"}, {"tying", "tie"}, {"type", "type"},
+		{"typed", "type"}, {"types", "type"}, {"typewriter", "typewrit"}, {"typhoid", "typhoid"}, {"typhoon", "typhoon"}, {"typhus", "typhus"},
+		{"typical", "typic"}, {"typically", "typic"}, {"typify", "typifi"}, {"typing", "type"}, {"typings", "type"}, {"typist", "typist"},
+		{"tyrannical", "tyrann"}, {"tyrannize", "tyrann"}, {"tyranny", "tyranni"}, {"tyrant", "tyrant"}, {"tyre", "tyre"},
+		{"ubiquitous", "ubiquit"}, {"udder", "udder"}, {"ugly", "ugli"}, {"ulcer", "ulcer"}, {"ulterior", "ulterior"}, {"ultimate", "ultim"},
+		{"ultimately", "ultim"}, {"ultimatum", "ultimatum"}, {"ultrasonic", "ultrason"}, {"ultraviolet", "ultraviolet"}, {"umbilical", "umbil"},
+		{"umbrella", "umbrella"}, {"umpire", "umpir"}, {"unable", "unabl"}, {"unanimity", "unanim"}, {"unanimous", "unanim"},
+		{"unarmed", "unarm"}, {"unassuming", "unassum"}, {"unaware", "unawar"}, {"unawares", "unawar"}, {"unbearable", "unbear"},
+		{"unbeatable", "unbeat"}, {"unbelievable", "unbeliev"}, {"unbridled", "unbridl"}, {"uncanny", "uncanni"}, {"uncertain", "uncertain"},
+		{"uncertainty", "uncertainti"}, {"uncle", "uncl"}, {"unclean", "unclean"}, {"uncleanly", "unclean"}, {"uncomfortable", "uncomfort"},
+		{"uncommon", "uncommon"}, {"unconditional", "uncondit"}, {"unconditionally", "uncondit"}, {"unconscious", "unconsci"},
+		{"uncouth", "uncouth"}, {"uncover", "uncov"}, {"uncovered", "uncov"}, {"unction", "unction"}, {"unctuous", "unctuous"},
+		{"undecided", "undecid"}, {"undeniable", "undeni"}, {"under", "under"}, {"underage", "underag"}, {"undercarriage", "undercarriag"},
+		{"underclothes", "undercloth"}, {"undercover", "undercov"}, {"undercurrent", "undercurr"}, {"undercut", "undercut"},
+		{"underdeveloped", "underdevelop"}, {"underdog", "underdog"}, {"underestimate", "underestim"}, {"underestimated", "underestim"},
+		{"underfoot", "underfoot"}, {"undergo", "undergo"}, {"undergoes", "undergo"}, {"undergoing", "undergo"}, {"undergraduate", "undergradu"},
+		{"underground", "underground"}, {"undergrowth", "undergrowth"}, {"underhand", "underhand"}, {"underlies", "under"},
+		{"underline", "underlin"}, {"underlined", "underlin"}, {"underling", "underl"}, {"underlining", "underlin"}, {"underlying", "under"},
+		{"undermine", "undermin"}, {"underneath", "underneath"}, {"underpants", "underp"}, {"underpass", "underpass"},
+		{"underprivileged", "underprivileg"}, {"underrate", "underr"}, {"unders", "under"}, {"underscore", "underscor"},
+		{"underscores", "underscor"}, {"undersecretary", "undersecretari"}, {"underside", "undersid"}, {"understand", "understand"},
+		{"understandable", "understand"}, {"understanding", "understand"}, {"understands", "understand"}, {"understate", "underst"},
+		{"understatement", "understat"}, {"understudy", "understudi"}, {"undertake", "undertak"}, {"undertaker", "undertak"},
+		{"undertaking", "undertak"}, {"undertone", "underton"}, {"underwater", "underwat"}, {"underwear", "underwear"},
+		{"underweight", "underweight"}, {"underworld", "underworld"}, {"underwrite", "underwrit"}, {"underwriter", "underwrit"},
+		{"undo", "undo"}, {"undoes", "undo"}, {"undoing", "undo"}, {"undoubted", "undoubt"}, {"undress", "undress"}, {"undue", "undu"},
+		{"undulate", "undul"}, {"unduly", "unduli"}, {"unearth", "unearth"}, {"unearthly", "unearth"}, {"unease", "uneas"}, {"uneasy", "uneasi"},
+		{"unemployment", "unemploy"}, {"unending", "unend"}, {"unerring", "uner"}, {"uneven", "uneven"}, {"unfair", "unfair"},
+		{"unfold", "unfold"}, {"unfolds", "unfold"}, {"unforeseen", "unforeseen"}, {"unfortunate", "unfortun"}, {"unfortunately", "unfortun"},
+		{"unfounded", "unfound"}, {"unfurl", "unfurl"}, {"ungainly", "ungain"}, {"ungodly", "ungod"}, {"unguarded", "unguard"},
+		{"unhappy", "unhappi"}, {"unhealthy", "unhealthi"}, {"unicorn", "unicorn"}, {"unification", "unif"}, {"unified", "unifi"},
+		{"uniform", "uniform"}, {"uniformity", "uniform"}, {"uniformly", "uniform"}, {"unify", "unifi"}, {"unilateral", "unilater"},
+		{"unimpeachable", "unimpeach"}, {"uninterested", "uninterest"}, {"uninteresting", "uninterest"}, {"union", "union"},
+		{"unionist", "unionist"}, {"unions", "union"}, {"unique", "uniqu"}, {"uniquely", "uniqu"}, {"uniqueness", "uniqu"},
+		{"uniquing", "uniqu"}, {"unisex", "unisex"}, {"unison", "unison"}, {"unit", "unit"}, {"unite", "unit"}, {"united", "unit"},
+		{"unitializing", "uniti"}, {"units", "unit"}, {"unity", "uniti"}, {"universal", "univers"}, {"universally", "univers"},
+		{"universe", "univers"}, {"university", "univers"}, {"unkempt", "unkempt"}, {"unkind", "unkind"}, {"unknown", "unknown"},
+		{"unknowns", "unknown"}, {"unleash", "unleash"}, {"unless", "unless"}, {"unlike", "unlik"}, {"unlikely", "unlik"}, {"unload", "unload"},
+		{"unloaded", "unload"}, {"unloading", "unload"}, {"unloads", "unload"}, {"unlock", "unlock"}, {"unlockable", "unlock"},
+		{"unlocked", "unlock"}, {"unlocking", "unlock"}, {"unlocks", "unlock"}, {"unlucky", "unlucki"}, {"unmistakable", "unmistak"},
+		{"unnatural", "unnatur"}, {"unnecessary", "unnecessari"}, {"unnerve", "unnerv"}, {"unpack", "unpack"}, {"unpacked", "unpack"},
+		{"unpacker", "unpack"}, {"unpacking", "unpack"}, {"unpackings", "unpack"}, {"unpacks", "unpack"}, {"unprecedented", "unpreced"},
+		{"unravel", "unravel"}, {"unreal", "unreal"}, {"unrealize", "unreal"}, {"unrealized", "unreal"}, {"unrest", "unrest"},
+		{"unruly", "unruli"}, {"unscathed", "unscath"}, {"unscrupulous", "unscrupul"}, {"unseat", "unseat"}, {"unseemly", "unseem"},
+		{"unsettle", "unsettl"}, {"unsightly", "unsight"}, {"unsound", "unsound"}, {"unspeakable", "unspeak"}, {"unstable", "unstabl"},
+		{"unsung", "unsung"}, {"until", "until"}, {"untimely", "untim"}, {"unto", "unto"}, {"untold", "untold"}, {"untoward", "untoward"},
+		{"unusual", "unusu"}, {"unusually", "unusu"}, {"unveil", "unveil"}, {"unwieldy", "unwieldi"}, {"unwitting", "unwit"},
+		{"unwittingly", "unwit"}, {"unwonted", "unwont"}, {"up", "up"}, {"upbeat", "upbeat"}, {"upbraid", "upbraid"}, {"upbringing", "upbring"},
+		{"upcoming", "upcom"}, {"updatable", "updat"}, {"update", "updat"}, {"updated", "updat"}, {"updater", "updat"}, {"updaters", "updat"},
+		{"updates", "updat"}, {"updating", "updat"}, {"updation", "updat"}, {"upgradability", "upgrad"}, {"upgrade", "upgrad"},
+		{"upgradeable", "upgrad"}, {"upgraded", "upgrad"}, {"upgrades", "upgrad"}, {"upgrading", "upgrad"}, {"upheaval", "upheav"},
+		{"uphill", "uphil"}, {"uphold", "uphold"}, {"upholds", "uphold"}, {"upholster", "upholst"}, {"upholstery", "upholsteri"},
+		{"upkeep", "upkeep"}, {"uplift", "uplift"}, {"upon", "upon"}, {"upper", "upper"}, {"uppermost", "uppermost"}, {"upright", "upright"},
+		{"uprising", "upris"}, {"uproar", "uproar"}, {"uproot", "uproot"}, {"ups", "up"}, {"upset", "upset"}, {"upshot", "upshot"},
+		{"upside", "upsid"}, {"upstairs", "upstair"}, {"upstart", "upstart"}, {"upstream", "upstream"}, {"upstreamed", "upstream"},
+		{"upstreams", "upstream"}, {"upsurge", "upsurg"}, {"uptake", "uptak"}, {"uptight", "uptight"}, {"upturn", "upturn"},
+		{"upward", "upward"}, {"upwardly", "upward"}, {"upwards", "upward"}, {"uranium", "uranium"}, {"urban", "urban"}, {"urbane", "urban"},
+		{"urchin", "urchin"}, {"urge", "urg"}, {"urged", "urg"}, {"urgency", "urgenc"}, {"urgent", "urgent"}, {"urinal", "urin"},
+		{"urinate", "urin"}, {"urine", "urin"}, {"urn", "urn"}, {"urns", "urn"}, {"us", "us"}, {"usability", "usabl"}, {"usable", "usabl"},
+		{"usage", "usag"}, {"usages", "usag"}, {"use", "use"}, {"used", "use"}, {"useful", "use"}, {"usefully", "use"}, {"usefulness", "use"},
+		{"useless", "useless"}, {"user", "user"}, {"users", "user"}, {"uses", "use"}, {"usher", "usher"}, {"using", "use"}, {"usual", "usual"},
+		{"usually", "usual"}, {"usurp", "usurp"}, {"usury", "usuri"}, {"utensil", "utensil"}, {"uterus", "uterus"}, {"util", "util"},
+		{"utilitarian", "utilitarian"}, {"utilities", "util"}, {"utility", "util"}, {"utilization", "util"}, {"utilize", "util"},
+		{"utilized", "util"}, {"utilizes", "util"}, {"utilizing", "util"}, {"utils", "util"}, {"utmost", "utmost"}, {"utopia", "utopia"},
+		{"utopian", "utopian"}, {"utter", "utter"}, {"utterance", "utter"}, {"utterances", "utter"}, {"utterly", "utter"}, {"vacancy", "vacanc"},
+		{"vacant", "vacant"}, {"vacate", "vacat"}, {"vacation", "vacat"}, {"vaccinate", "vaccin"}, {"vaccination", "vaccin"},
+		{"vaccine", "vaccin"}, {"vacillate", "vacil"}, {"vacuous", "vacuous"}, {"vacuum", "vacuum"}, {"vacuumed", "vacuum"},
+		{"vacuuming", "vacuum"}, {"vagabond", "vagabond"}, {"vagina", "vagina"}, {"vaginal", "vagin"}, {"vagrancy", "vagranc"},
+		{"vagrant", "vagrant"}, {"vague", "vagu"}, {"vaguely", "vagu"}, {"vain", "vain"}, {"valiant", "valiant"}, {"valid", "valid"},
+		{"validate", "valid"}, {"validated", "valid"}, {"validates", "valid"}, {"validating", "valid"}, {"validation", "valid"},
+		{"validations", "valid"}, {"validator", "valid"}, {"validators", "valid"}, {"validity", "valid"}, {"validly", "valid"},
+		{"valley", "valley"}, {"valor", "valor"}, {"valour", "valour"}, {"valuable", "valuabl"}, {"valuation", "valuat"}, {"value", "valu"},
+		{"valued", "valu"}, {"values", "valu"}, {"valve", "valv"}, {"vampire", "vampir"}, {"van", "van"}, {"vandal", "vandal"},
+		{"vandalism", "vandal"}, {"vandalize", "vandal"}, {"vane", "vane"}, {"vanguard", "vanguard"}, {"vanilla", "vanilla"},
+		{"vanish", "vanish"}, {"vanished", "vanish"}, {"vanishes", "vanish"}, {"vanity", "vaniti"}, {"vanquish", "vanquish"},
+		{"vantage", "vantag"}, {"vapor", "vapor"}, {"vapour", "vapour"}, {"variability", "variabl"}, {"variable", "variabl"},
+		{"variables", "variabl"}, {"variance", "varianc"}, {"variant", "variant"}, {"variants", "variant"}, {"variation", "variat"},
+		{"variations", "variat"}, {"varicose", "varicos"}, {"varied", "vari"}, {"varies", "vari"}, {"varieties", "varieti"},
+		{"variety", "varieti"}, {"various", "various"}, {"variously", "various"}, {"varnish", "varnish"}, {"vary", "vari"}, {"varying", "vari"},
+		{"vascular", "vascular"}, {"vase", "vase"}, {"vasectomy", "vasectomi"}, {"vast", "vast"}, {"vastly", "vast"}, {"vat", "vat"},
+		{"vault", "vault"}, {"vaults", "vault"}, {"veal", "veal"}, {"veer", "veer"}, {"vegan", "vegan"}, {"vegetable", "veget"},
+		{"vegetarian", "vegetarian"}, {"vegetate", "veget"}, {"vegetation", "veget"}, {"vehement", "vehement"}, {"vehicle", "vehicl"},
+		{"vehicular", "vehicular"}, {"veil", "veil"}, {"vein", "vein"}, {"velocity", "veloc"}, {"velvet", "velvet"}, {"vendetta", "vendetta"},
+		{"vendor", "vendor"}, {"vendored", "vendor"}, {"vendors", "vendor"}, {"veneer", "veneer"}, {"veneers", "veneer"}, {"venerable", "vener"},
+		{"venerate", "vener"}, {"veneration", "vener"}, {"vengeance", "vengeanc"}, {"vengeful", "veng"}, {"venison", "venison"},
+		{"venom", "venom"}, {"venomous", "venom"}, {"vent", "vent"}, {"ventilate", "ventil"}, {"ventilation", "ventil"},
+		{"ventilator", "ventil"}, {"ventricle", "ventricl"}, {"ventriloquist", "ventriloquist"}, {"venture", "ventur"}, {"venue", "venu"},
+		{"veracity", "verac"}, {"veranda", "veranda"}, {"verandah", "verandah"}, {"verb", "verb"}, {"verbal", "verbal"},
+		{"verbatim", "verbatim"}, {"verbose", "verbos"}, {"verbosely", "verbos"}, {"verbosity", "verbos"}, {"verbs", "verb"},
+		{"verdict", "verdict"}, {"verdicts", "verdict"}, {"verge", "verg"}, {"verifiable", "verifi"}, {"verification", "verif"},
+		{"verifications", "verif"}, {"verified", "verifi"}, {"verifier", "verifi"}, {"verifies", "verifi"}, {"verify", "verifi"},
+		{"verifying", "verifi"}, {"veritable", "verit"}, {"vermin", "vermin"}, {"vernacular", "vernacular"}, {"versatile", "versatil"},
+		{"versatility", "versatil"}, {"verse", "vers"}, {"version", "version"}, {"versioned", "version"}, {"versioning", "version"},
+		{"versions", "version"}, {"versus", "versus"}, {"vertebra", "vertebra"}, {"vertebrate", "vertebr"}, {"vertical", "vertic"},
+		{"vertically", "vertic"}, {"vertices", "vertic"}, {"vertigo", "vertigo"}, {"verve", "verv"}, {"very", "veri"}, {"vessel", "vessel"},
+		{"vest", "vest"}, {"vestibule", "vestibul"}, {"vestige", "vestig"}, {"vestiges", "vestig"}, {"vestry", "vestri"}, {"vet", "vet"},
+		{"veteran", "veteran"}, {"veterinary", "veterinari"}, {"veto", "veto"}, {"vetted", "vet"}, {"vex", "vex"}, {"vexation", "vexat"},
+		{"via", "via"}, {"viable", "viabl"}, {"viaduct", "viaduct"}, {"vial", "vial"}, {"vibrant", "vibrant"}, {"vibrate", "vibrat"},
+		{"vibration", "vibrat"}, {"vicar", "vicar"}, {"vicarage", "vicarag"}, {"vicarious", "vicari"}, {"vice", "vice"}, {"viceroy", "viceroy"},
+		{"vicinity", "vicin"}, {"vicious", "vicious"}, {"vicissitude", "vicissitud"}, {"victim", "victim"}, {"victimize", "victim"},
+		{"victor", "victor"}, {"victorious", "victori"}, {"victory", "victori"}, {"video", "video"}, {"videos", "video"},
+		{"videotape", "videotap"}, {"vie", "vie"}, {"view", "view"}, {"viewed", "view"}, {"viewer", "viewer"}, {"viewers", "viewer"},
+		{"viewing", "view"}, {"viewpoint", "viewpoint"}, {"views", "view"}, {"vigil", "vigil"}, {"vigilance", "vigil"}, {"vigilant", "vigil"},
+		{"vigilante", "vigilant"}, {"vigor", "vigor"}, {"vigorous", "vigor"}, {"vigour", "vigour"}, {"vile", "vile"}, {"vilify", "vilifi"},
+		{"villa", "villa"}, {"village", "villag"}, {"villager", "villag"}, {"villain", "villain"}, {"villainous", "villain"},
+		{"vindicate", "vindic"}, {"vindication", "vindic"}, {"vindictive", "vindict"}, {"vine", "vine"}, {"vinegar", "vinegar"},
+		{"vineyard", "vineyard"}, {"vintage", "vintag"}, {"vintages", "vintag"}, {"vinyl", "vinyl"}, {"viola", "viola"}, {"violate", "violat"},
+		{"violated", "violat"}, {"violates", "violat"}, {"violating", "violat"}, {"violation", "violat"}, {"violations", "violat"},
+		{"violence", "violenc"}, {"violent", "violent"}, {"violet", "violet"}, {"violin", "violin"}, {"violinist", "violinist"},
+		{"viper", "viper"}, {"viral", "viral"}, {"virgin", "virgin"}, {"virginity", "virgin"}, {"virile", "viril"}, {"virility", "viril"},
+		{"virtual", "virtual"}, {"virtualization", "virtual"}, {"virtualize", "virtual"}, {"virtualized", "virtual"}, {"virtually", "virtual"},
+		{"virtue", "virtu"}, {"virtues", "virtu"}, {"virtuoso", "virtuoso"}, {"virtuous", "virtuous"}, {"virulent", "virul"}, {"virus", "virus"},
+		{"visa", "visa"}, {"visage", "visag"}, {"viscount", "viscount"}, {"viscous", "viscous"}, {"vise", "vise"}, {"visibility", "visibl"},
+		{"visible", "visibl"}, {"vision", "vision"}, {"visionary", "visionari"}, {"visit", "visit"}, {"visitation", "visit"},
+		{"visited", "visit"}, {"visiting", "visit"}, {"visitor", "visitor"}, {"visits", "visit"}, {"visor", "visor"}, {"vista", "vista"},
+		{"visual", "visual"}, {"visualize", "visual"}, {"visually", "visual"}, {"visuals", "visual"}, {"vital", "vital"}, {"vitality", "vital"},
+		{"vitamin", "vitamin"}, {"vitriolic", "vitriol"}, {"vivacious", "vivaci"}, {"vivacity", "vivac"}, {"vivid", "vivid"}, {"vixen", "vixen"},
+		{"vocabulary", "vocabulari"}, {"vocal", "vocal"}, {"vocalist", "vocalist"}, {"vocation", "vocat"}, {"vocational", "vocat"},
+		{"vociferous", "vocifer"}, {"vodka", "vodka"}, {"vogue", "vogu"}, {"voice", "voic"}, {"void", "void"}, {"voided", "void"},
+		{"volatile", "volatil"}, {"volatility", "volatil"}, {"volcanic", "volcan"}, {"volcano", "volcano"}, {"vole", "vole"},
+		{"volition", "volit"}, {"volley", "volley"}, {"volleyball", "volleybal"}, {"volt", "volt"}, {"voltage", "voltag"}, {"voluble", "volubl"},
+		{"volume", "volum"}, {"volumes", "volum"}, {"voluminous", "volumin"}, {"voluntary", "voluntari"}, {"volunteer", "volunt"},
+		{"volunteered", "volunt"}, {"volunteering", "volunt"}, {"volunteers", "volunt"}, {"voluptuous", "voluptu"}, {"vomit", "vomit"},
+		{"voracious", "voraci"}, {"voracity", "vorac"}, {"vortex", "vortex"}, {"vote", "vote"}, {"voter", "voter"}, {"voting", "vote"},
+		{"vouch", "vouch"}, {"vouched", "vouch"}, {"voucher", "voucher"}, {"vow", "vow"}, {"vowel", "vowel"}, {"vowels", "vowel"},
+		{"voyage", "voyag"}, {"voyager", "voyag"}, {"vulgar", "vulgar"}, {"vulgarity", "vulgar"}, {"vulnerabilities", "vulner"},
+		{"vulnerability", "vulner"}, {"vulnerable", "vulner"}, {"vulture", "vultur"}, {"wad", "wad"}, {"waddle", "waddl"}, {"wade", "wade"},
+		{"wafer", "wafer"}, {"waffle", "waffl"}, {"waft", "waft"}, {"wag", "wag"}, {"wage", "wage"}, {"wager", "wager"}, {"waggle", "waggl"},
+		{"wagon", "wagon"}, {"waif", "waif"}, {"wail", "wail"}, {"waist", "waist"}, {"waistcoat", "waistcoat"}, {"waistline", "waistlin"},
+		{"wait", "wait"}, {"waited", "wait"}, {"waiter", "waiter"}, {"waiters", "waiter"}, {"waiting", "wait"}, {"waitress", "waitress"},
+		{"waits", "wait"}, {"waive", "waiv"}, {"waived", "waiv"}, {"waiver", "waiver"}, {"wake", "wake"}, {"waken", "waken"}, {"wakes", "wake"},
+		{"waking", "wake"}, {"walk", "walk"}, {"walked", "walk"}, {"walker", "walker"}, {"walkers", "walker"}, {"walking", "walk"},
+		{"walkout", "walkout"}, {"walks", "walk"}, {"wall", "wall"}, {"wallet", "wallet"}, {"wallop", "wallop"}, {"wallow", "wallow"},
+		{"wallpaper", "wallpap"}, {"walnut", "walnut"}, {"walrus", "walrus"}, {"waltz", "waltz"}, {"wan", "wan"}, {"wand", "wand"},
+		{"wander", "wander"}, {"wanderer", "wander"}, {"wane", "wane"}, {"wangle", "wangl"}, {"want", "want"}, {"wanted", "want"},
+		{"wanting", "want"}, {"wanton", "wanton"}, {"wants", "want"}, {"war", "war"}, {"warble", "warbl"}, {"ward", "ward"},
+		{"warden", "warden"}, {"warder", "warder"}, {"wardrobe", "wardrob"}, {"ware", "ware"}, {"warehouse", "warehous"}, {"wares", "ware"},
+		{"warfare", "warfar"}, {"warhead", "warhead"}, {"warlike", "warlik"}, {"warlord", "warlord"}, {"warm", "warm"}, {"warmed", "warm"},
+		{"warmth", "warmth"}, {"warn", "warn"}, {"warned", "warn"}, {"warning", "warn"}, {"warnings", "warn"}, {"warns", "warn"},
+		{"warp", "warp"}, {"warrant", "warrant"}, {"warranties", "warranti"}, {"warranty", "warranti"}, {"warren", "warren"},
+		{"warrior", "warrior"}, {"warship", "warship"}, {"wart", "wart"}, {"warts", "wart"}, {"wary", "wari"}, {"wash", "wash"},
+		{"washable", "washabl"}, {"washbasin", "washbasin"}, {"washed", "wash"}, {"washer", "washer"}, {"washroom", "washroom"},
+		{"wasp", "wasp"}, {"wastage", "wastag"}, {"waste", "wast"}, {"wasted", "wast"}, {"wasteful", "wast"}, {"wasteland", "wasteland"},
+		{"wasting", "wast"}, {"watch", "watch"}, {"watchdog", "watchdog"}, {"watchdogs", "watchdog"}, {"watched", "watch"}, {"watches", "watch"},
+		{"watchful", "watch"}, {"watching", "watch"}, {"watchman", "watchman"}, {"water", "water"}, {"waterfall", "waterfal"},
+		{"waterfront", "waterfront"}, {"watermelon", "watermelon"}, {"waterproof", "waterproof"}, {"watershed", "watersh"},
+		{"waterway", "waterway"}, {"watery", "wateri"}, {"watt", "watt"}, {"wave", "wave"}, {"waveband", "waveband"},
+		{"wavelength", "wavelength"}, {"waver", "waver"}, {"wavy", "wavi"}, {"wax", "wax"}, {"waxwork", "waxwork"}, {"way", "way"},
+		{"ways", "way"}, {"wayside", "waysid"}, {"wayward", "wayward"}, {"we", "we"}, {"weak", "weak"}, {"weaken", "weaken"},
+		{"weakling", "weakl"}, {"weakly", "weak"}, {"weakness", "weak"}, {"wealth", "wealth"}, {"wealthy", "wealthi"}, {"wean", "wean"},
+		{"weaning", "wean"}, {"weapon", "weapon"}, {"weaponry", "weaponri"}, {"wear", "wear"}, {"weary", "weari"}, {"weasel", "weasel"},
+		{"weather", "weather"}, {"weave", "weav"}, {"weaver", "weaver"}, {"web", "web"}, {"wed", "wed"}, {"wedding", "wed"}, {"wedge", "wedg"},
+		{"wedlock", "wedlock"}, {"wednesday", "wednesday"}, {"wee", "wee"}, {"weed", "weed"}, {"weedy", "weedi"}, {"week", "week"},
+		{"weekday", "weekday"}, {"weekdays", "weekday"}, {"weekend", "weekend"}, {"weekly", "week"}, {"weeks", "week"}, {"weep", "weep"},
+		{"weigh", "weigh"}, {"weighed", "weigh"}, {"weight", "weight"}, {"weighted", "weight"}, {"weighting", "weight"}, {"weights", "weight"},
+		{"weighty", "weighti"}, {"weir", "weir"}, {"weird", "weird"}, {"weirdness", "weird"}, {"welcome", "welcom"}, {"welcomed", "welcom"},
+		{"welcomes", "welcom"}, {"welcoming", "welcom"}, {"weld", "weld"}, {"welder", "welder"}, {"welfare", "welfar"}, {"well", "well"},
+		{"wellbeing", "wellb"}, {"welt", "welt"}, {"welte", "welt"}, {"wench", "wench"}, {"west", "west"}, {"westerly", "wester"},
+		{"western", "western"}, {"westward", "westward"}, {"wet", "wet"}, {"whack", "whack"}, {"whale", "whale"}, {"whaler", "whaler"},
+		{"wharf", "wharf"}, {"what", "what"}, {"whatever", "whatev"}, {"whats", "what"}, {"whatsoever", "whatsoev"}, {"wheat", "wheat"},
+		{"wheedle", "wheedl"}, {"wheel", "wheel"}, {"wheelbarrow", "wheelbarrow"}, {"wheelchair", "wheelchair"}, {"wheels", "wheel"},
+		{"wheeze", "wheez"}, {"when", "when"}, {"whence", "whenc"}, {"whenever", "whenev"}, {"where", "where"}, {"whereabouts", "whereabout"},
+		{"whereas", "wherea"}, {"whereby", "wherebi"}, {"wherein", "wherein"}, {"whereupon", "whereupon"}, {"wherever", "wherev"},
+		{"whet", "whet"}, {"whether", "whether"}, {"whey", "whey"}, {"which", "which"}, {"whichever", "whichev"}, {"whiff", "whiff"},
+		{"while", "while"}, {"whim", "whim"}, {"whimper", "whimper"}, {"whimsical", "whimsic"}, {"whine", "whine"}, {"whinny", "whinni"},
+		{"whip", "whip"}, {"whirl", "whirl"}, {"whirlpool", "whirlpool"}, {"whirlwind", "whirlwind"}, {"whirr", "whirr"}, {"whisk", "whisk"},
+		{"whisker", "whisker"}, {"whiskey", "whiskey"}, {"whisky", "whiski"}, {"whisper", "whisper"}, {"whistle", "whistl"},
+		{"whistles", "whistl"}, {"white", "white"}, {"whiten", "whiten"}, {"whitewash", "whitewash"}, {"whittle", "whittl"}, {"whiz", "whiz"},
+		{"who", "who"}, {"whoever", "whoever"}, {"whole", "whole"}, {"wholehearted", "wholeheart"}, {"wholesale", "wholesal"},
+		{"wholesaler", "wholesal"}, {"wholesome", "wholesom"}, {"wholly", "wholli"}, {"whom", "whom"}, {"whoop", "whoop"}, {"whoops", "whoop"},
+		{"whore", "whore"}, {"whose", "whose"}, {"why", "whi"}, {"wick", "wick"}, {"wicke", "wick"}, {"wicked", "wick"}, {"wicker", "wicker"},
+		{"wicket", "wicket"}, {"wide", "wide"}, {"widely", "wide"}, {"widen", "widen"}, {"widening", "widen"}, {"widespread", "widespread"},
+		{"widow", "widow"}, {"widower", "widow"}, {"width", "width"}, {"widths", "width"}, {"wield", "wield"}, {"wife", "wife"}, {"wig", "wig"},
+		{"wiggle", "wiggl"}, {"wigwam", "wigwam"}, {"wild", "wild"}, {"wilderness", "wilder"}, {"wildlife", "wildlif"}, {"wile", "wile"},
+		{"wilful", "wil"}, {"will", "will"}, {"willful", "will"}, {"willing", "will"}, {"willow", "willow"}, {"willpower", "willpow"},
+		{"wilt", "wilt"}, {"wily", "wili"}, {"wimp", "wimp"}, {"win", "win"}, {"wince", "winc"}, {"winch", "winch"}, {"wind", "wind"},
+		{"windfall", "windfal"}, {"windmill", "windmil"}, {"window", "window"}, {"windowed", "window"}, {"windowing", "window"},
+		{"windows", "window"}, {"windowsill", "windowsil"}, {"windpipe", "windpip"}, {"windscreen", "windscreen"}, {"windshield", "windshield"},
+		{"windsurfing", "windsurf"}, {"windswept", "windswept"}, {"windy", "windi"}, {"wine", "wine"}, {"wines", "wine"}, {"wing", "wing"},
+		{"wink", "wink"}, {"winner", "winner"}, {"winners", "winner"}, {"winning", "win"}, {"wins", "win"}, {"winter", "winter"},
+		{"wintry", "wintri"}, {"wipe", "wipe"}, {"wiped", "wipe"}, {"wipes", "wipe"}, {"wiping", "wipe"}, {"wire", "wire"}, {"wired", "wire"},
+		{"wireless", "wireless"}, {"wires", "wire"}, {"wiring", "wire"}, {"wiry", "wiri"}, {"wisdom", "wisdom"}, {"wise", "wise"},
+		{"wisely", "wise"}, {"wish", "wish"}, {"wished", "wish"}, {"wishes", "wish"}, {"wishful", "wish"}, {"wishing", "wish"}, {"wisp", "wisp"},
+		{"wistful", "wist"}, {"wit", "wit"}, {"witch", "witch"}, {"witchcraft", "witchcraft"}, {"with", "with"}, {"withdraw", "withdraw"},
+		{"withdrawal", "withdraw"}, {"wither", "wither"}, {"withhold", "withhold"}, {"within", "within"}, {"without", "without"},
+		{"withstand", "withstand"}, {"witness", "wit"}, {"witty", "witti"}, {"wizard", "wizard"}, {"wobble", "wobbl"}, {"woe", "woe"},
+		{"woeful", "woeful"}, {"wolf", "wolf"}, {"woman", "woman"}, {"womanhood", "womanhood"}, {"womb", "womb"}, {"wonder", "wonder"},
+		{"wonderful", "wonder"}, {"wondering", "wonder"}, {"wont", "wont"}, {"woo", "woo"}, {"wood", "wood"}, {"woodcutter", "woodcutt"},
+		{"wooden", "wooden"}, {"woodland", "woodland"}, {"woodpecker", "woodpeck"}, {"woods", "wood"}, {"woodwind", "woodwind"},
+		{"woodwork", "woodwork"}, {"woody", "woodi"}, {"wool", "wool"}, {"woolen", "woolen"}, {"woollen", "woollen"}, {"woolly", "woolli"},
+		{"word", "word"}, {"worded", "word"}, {"wording", "word"}, {"wordings", "word"}, {"words", "word"}, {"wordy", "wordi"}, {"work", "work"},
+		{"workable", "workabl"}, {"workaholic", "workahol"}, {"worked", "work"}, {"worker", "worker"}, {"workers", "worker"},
+		{"workforce", "workforc"}, {"working", "work"}, {"workings", "work"}, {"workload", "workload"}, {"workloads", "workload"},
+		{"workman", "workman"}, {"workmanship", "workmanship"}, {"workplace", "workplac"}, {"works", "work"}, {"workshop", "workshop"},
+		{"world", "world"}, {"worldly", "world"}, {"worldwide", "worldwid"}, {"worm", "worm"}, {"worry", "worri"}, {"worrying", "worri"},
+		{"worse", "wors"}, {"worsen", "worsen"}, {"worship", "worship"}, {"worshipper", "worshipp"}, {"worst", "worst"}, {"worth", "worth"},
+		{"worthless", "worthless"}, {"worthwhile", "worthwhil"}, {"worthy", "worthi"}, {"would", "would"}, {"wound", "wound"},
+		{"wrangle", "wrangl"}, {"wrangling", "wrangl"}, {"wrap", "wrap"}, {"wrapped", "wrap"}, {"wrapper", "wrapper"}, {"wrappers", "wrapper"},
+		{"wrapping", "wrap"}, {"wraps", "wrap"}, {"wrath", "wrath"}, {"wreak", "wreak"}, {"wreath", "wreath"}, {"wreck", "wreck"},
+		{"wreckage", "wreckag"}, {"wren", "wren"}, {"wrench", "wrench"}, {"wrest", "wrest"}, {"wrestle", "wrestl"}, {"wrestler", "wrestler"},
+		{"wretch", "wretch"}, {"wretched", "wretch"}, {"wriggle", "wriggl"}, {"wring", "wring"}, {"wrinkle", "wrinkl"}, {"wrist", "wrist"},
+		{"wristwatch", "wristwatch"}, {"writ", "writ"}, {"write", "write"}, {"writer", "writer"}, {"writers", "writer"}, {"writes", "write"},
+		{"writhe", "writh"}, {"writing", "write"}, {"wrong", "wrong"}, {"wrongful", "wrong"}, {"wrongly", "wrong"}, {"wry", "wri"},
+		{"xenophobia", "xenophobia"}, {"xerox", "xerox"}, {"xylophone", "xylophon"}, {"yacht", "yacht"}, {"yachtsman", "yachtsman"},
+		{"yak", "yak"}, {"yam", "yam"}, {"yank", "yank"}, {"yanked", "yank"}, {"yanking", "yank"}, {"yap", "yap"}, {"yard", "yard"},
+		{"yardstick", "yardstick"}, {"yarn", "yarn"}, {"yawn", "yawn"}, {"yeah", "yeah"}, {"year", "year"}, {"yearbook", "yearbook"},
+		{"yearly", "year"}, {"yearn", "yearn"}, {"years", "year"}, {"yeast", "yeast"}, {"yell", "yell"}, {"yellow", "yellow"}, {"yelp", "yelp"},
+		{"yen", "yen"}, {"yes", "yes"}, {"yesterday", "yesterday"}, {"yet", "yet"}, {"yew", "yew"}, {"yield", "yield"}, {"yielded", "yield"},
+		{"yielding", "yield"}, {"yields", "yield"}, {"yoga", "yoga"}, {"yoghurt", "yoghurt"}, {"yogurt", "yogurt"}, {"yoke", "yoke"},
+		{"yolk", "yolk"}, {"yonder", "yonder"}, {"you", "you"}, {"young", "young"}, {"youngster", "youngster"}, {"your", "your"},
+		{"yours", "your"}, {"yourself", "yourself"}, {"youth", "youth"}, {"youthful", "youth"}, {"yummy", "yummi"}, {"zany", "zani"},
+		{"zeal", "zeal"}, {"zealot", "zealot"}, {"zealous", "zealous"}, {"zebra", "zebra"}, {"zenith", "zenith"}, {"zero", "zero"},
+		{"zeroed", "zero"}, {"zeroes", "zero"}, {"zeroing", "zero"}, {"zeros", "zero"}, {"zest", "zest"}, {"zigzag", "zigzag"}, {"zinc", "zinc"},
+		{"zip", "zip"}, {"zipped", "zip"}, {"zipper", "zipper"}, {"zipping", "zip"}, {"zips", "zip"}, {"zodiac", "zodiac"}, {"zombie", "zombi"},
+		{"zombies", "zombi"}, {"zone", "zone"}, {"zoned", "zone"}, {"zones", "zone"}, {"zoo", "zoo"}, {"zoological", "zoolog"},
+		{"zoologist", "zoologist"}, {"zoology", "zoolog"}, {"zoom", "zoom"}, {"zoomed", "zoom"}, {"zooms", "zoom"},
+		{nullptr, nullptr}
+		};
+	}
@@ -0,0 +1,118 @@
+/*
+	JASS_FSCK.CPP
+	-------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Check a JASS index for damage.
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+
+	@details The checking is done by index_checker, which checks the files against the manifest and the postings lists against their checksums,
+	then walks every postings list checking the segment headers and the document ids.
+*/
+#include <iostream>
+
+#include "version.h"
+#include "commandline.h"
+#include "index_checker.h"
+
+/*
+	Declare the command line parameters
+*/
+std::string parameter_directory = ".";
+size_t parameter_report = 100;
+bool parameter_quiet = false;
+bool parameter_help = false;
+
+auto command_line_parameters = std::make_tuple
+	(
+	JASS::commandline::note("\nMISCELLANEOUS\n-------------"),
+	JASS::commandline::parameter("-q", "--nologo", "Suppress the banner.", parameter_quiet),
+	JASS::commandline::parameter("-?", "--help", "Print this help.", parameter_help),
+	JASS::commandline::parameter("-h", "--help", "Print this help.", parameter_help),
+	JASS::commandline::parameter("-H", "--help", "Print this help.", parameter_help),
+
+	JASS::commandline::note("\nFILE HANDLING\n-------------"),
+	JASS::commandline::parameter("-d", "--directory", "<directory> The directory holding the index [default = .]", parameter_directory),
+
+	JASS::commandline::note("\nREPORTING\n---------"),
+	JASS::commandline::parameter("-r", "--report", "<count> Print no more than this many problems (they are all counted) [default = 100]", parameter_report)
+	);
+
+/*
+	USAGE()
+	-------
+*/
+uint8_t usage(const std::string &exename)
+	{
+	std::cout << JASS::commandline::usage(exename, command_line_parameters) << "\n";
+	return 1;
+	}
+
+/*
+	MAIN()
+	------
+*/
+int main(int argc, const char *argv[])
+	{
+	/*
+		Do the command line parsing.
+	*/
+	std::string error;
+	auto success = JASS::commandline::parse(argc, argv, command_line_parameters, error);
+	if (!success)
+		{
+		std::cout << error;
+		exit(1);
+		}
+
+	if (parameter_help)
+		exit(usage(argv[0]));
+
+	if (!parameter_quiet)
+		std::cout << JASS::version::build() << "\n";
+
+	/*
+		Check the index
+	*/
+	JASS::index_checker checker(parameter_directory, parameter_report);
+	bool clean = checker.check();
+
+	for (const auto &note : checker.notes)
+		std::cout << note << "\n";
+
+	if (checker.version == 0)
+		{
+		std::cout << "There is no JASS index in " << parameter_directory << "\n";
+		return 1;
+		}
+	std::cout << "Index version: " << checker.version << "\n";
+
+	if (checker.documents != 0)
+		{
+		std::cout << "Documents: " << checker.documents << "\n";
+		std::cout << "Terms    : " << checker.terms << "\n";
+		std::cout << "Segments : " << checker.segments << "\n";
+		std::cout << "Postings : " << checker.postings << "\n";
+		}
+
+	/*
+		Report
+	*/
+	for (const auto &problem : checker.found.reported)
+		std::cout << problem << "\n";
+	if (checker.found.count > checker.found.reported.size())
+		std::cout << "... and " << checker.found.count - checker.found.reported.size() << " more\n";
+
+	if (clean)
+		{
+		std::cout << "The index is clean\n";
+		return 0;
+		}
+
+	std::cout << "The index is damaged (" << checker.found.count << " problem" << (checker.found.count == 1 ? "" : "s") << ")\n";
+	return 1;
+	}
@@ -13,30 +13,44 @@
 #include <string.h>
 
 #include <vector>
+#include <memory>
+#include <algorithm>
 #include <filesystem>
 
 #include "timer.h"
 #include "parser.h"
+#include "threads.h"
 #include "version.h"
+#include "stem_all.h"
 #include "quantize.h"
+#include "quantize_index.h"
+#include "stop_words.h"
 #include "commandline.h"
-#include "stem_porter.h"
 #include "parser_fasta.h"
 #include "serialise_ci.h"
-#include "quantize_none.h"
+#include "serialise_ciff.h"
 #include "instream_file.h"
 #include "instream_memory.h"
 #include "instream_deflate.h"
 #include "compress_integer.h"
+#include "compress_integer_all.h"
+#include "document_reorder.h"
 #include "serialise_jass_v1.h"
 #include "serialise_jass_v2.h"
+#include "serialise_jass_v3.h"
 #include "serialise_integers.h"
 #include "parser_unicoil_json.h"
+#include "index_manager_spill.h"
 #include "instream_document_trec.h"
+#include "instream_document_html.h"
+#include "instream_document_warc.h"
 #include "instream_document_fasta.h"
 #include "serialise_forward_index.h"
+#include "serialise_unquantized.h"
 #include "index_manager_sequential.h"
+#include "index_manager_positional.h"
 #include "ranking_function_atire_bm25.h"
+#include "ranking_function_bm25f.h"
 #include "instream_directory_iterator.h"
 #include "instream_document_unicoil_json.h"
 
@@ -45,20 +59,48 @@
 */
 bool parameter_jass_v1_index = false;
 bool parameter_jass_v2_index = false;
+bool parameter_jass_v3_index = false;
 bool parameter_compiled_index = false;
+bool parameter_ciff_index = false;
 bool parameter_uint32_index = false;
 bool parameter_forward_index = false;
+bool parameter_unquantized_index = false;
 std::string parameter_filename = "";
 bool parameter_quiet = false;
 bool parameter_help = false;
 size_t parameter_report_every_n = (std::numeric_limits<size_t>::max)();
 bool parameter_atire_similar = false;
 size_t parameter_fasta_kmer_length = 0;
+size_t parameter_threads = 1;
+size_t parameter_memory_budget = 0;
+bool parameter_positional = false;
 
 bool parameter_stem_porter = false;
+std::string parameter_stemmer = JASS::stem_all::NO_STEMMER;
+std::string parameter_fields = "";
+bool parameter_stop_english = false;
+std::string parameter_stop_filename = "";
+std::string parameter_ranking_function = "ATIRE_BM25";
+double parameter_bm25_k1 = 0.9;
+double parameter_bm25_b = 0.4;
+double parameter_dirichlet_mu = 1000;
+size_t parameter_quantization_bits = 0;
+std::string parameter_quantization_mapping = "linear";
+bool parameter_quantization_per_term = false;
+std::string parameter_reorder = "None";
+size_t parameter_reorder_iterations = 20;
+JASS::quantization_scheme::mapping_type quantization_mapping = JASS::quantization_scheme::LINEAR;		///< The mapping from scores to impacts (from parameter_quantization_mapping)
+
+std::vector<std::string> field_names;			///< The XML tags to index as fields (from parameter_fields), lower case
+std::vector<double> field_weights;				///< The BM25F weight of each field (from parameter_fields)
+JASS::stop_words stopwords;						///< The terms that are not indexed (from parameter_stop_english and parameter_stop_filename)
 
 bool parameter_document_format_trec = true;
 bool parameter_document_format_JSON_uniCOIL = false;
+bool parameter_document_format_warc = false;
+bool parameter_document_format_html = false;
+
+std::array<bool, JASS::compress_integer_all::compressors_size> parameter_codex = {};		///< Which compress_integer_all codex to compress the JASS indexes with (default is none of them, so use the serialiser's default)
 
 auto command_line_parameters = std::make_tuple
 	(
@@ -77,20 +119,49 @@ auto command_line_parameters = std::make_tuple
 	JASS::commandline::note("\nDOCUMENT FORMATS\n-------------"),
 	JASS::commandline::parameter("-dt",  "--document_TREC", "TREC format: <DOC><DOCNO></DOCNO></DOC> formatted documents (default)", parameter_document_format_trec),
 	JASS::commandline::parameter("-djc", "--document_JSON_uniCOIL", "JSON uniCOIL forward index format: {\"id\": \"0\", \"vector\": {\"term\": 94 }}", parameter_document_format_JSON_uniCOIL),
+	JASS::commandline::parameter("-dw",  "--document_WARC", "WARC format (ClueWeb, CommonCrawl), primary key from WARC-TREC-ID or else WARC-Target-URI (.gz files are decompressed)", parameter_document_format_warc),
+	JASS::commandline::parameter("-dh",  "--document_HTML", "HTML format: <html><title></title></html> formatted documents (.gz files are decompressed)", parameter_document_format_html),
 
 	JASS::commandline::note("\nCOMPATIBILITY\n-------------"),
 	JASS::commandline::parameter("-A", "--atire", "ATIRE-like parsing (errors and all)", parameter_atire_similar),
 
 	JASS::commandline::note("\nTERM PROCESSING\n---------------"),
-	JASS::commandline::parameter("-tp", "--term_steming_porter", "Term stemming with Porter v1 (JASS implementation)", parameter_stem_porter),
+	JASS::commandline::parameter("-tp", "--term_steming_porter", "Term stemming with Porter v1 (JASS implementation), the same as -ts Porter", parameter_stem_porter),
+	JASS::commandline::parameter("-ts", "--term_stemming", "<stemmer> Term stemming with the named stemmer (None|Porter|Porter2|Krovetz|S) [default = None]", parameter_stemmer),
+	JASS::commandline::parameter("-Sf", "--stopwords_file", "<filename> Do not index the (whitespace separated) words in this file (the stop words are recorded in the index and removed from queries).", parameter_stop_filename),
+	JASS::commandline::parameter("-S", "--stopwords", "Do not index the built-in English stop words (the stop words are recorded in the index and removed from queries).", parameter_stop_english),
+	JASS::commandline::parameter("-F", "--fields", "<tag[=weight],...> Index these XML tags as fields and rank with BM25F, e.g. -F title=3,headline [default weight = 2]", parameter_fields),
+
+	JASS::commandline::note("\nRANKING\n-------"),
+	JASS::commandline::parameter("-r", "--ranking_function", "<function> Quantize using this ranking function (ATIRE_BM25|Lucene_BM25|LM_Dirichlet|DPH|TF_IDF) [default = ATIRE_BM25]", parameter_ranking_function),
+	JASS::commandline::parameter("-k1", "--bm25_k1", "<k1> The k1 parameter of BM25 (and BM25F) [default = 0.9]", parameter_bm25_k1),
+	JASS::commandline::parameter("-b", "--bm25_b", "<b> The b parameter of BM25 (and BM25F) [default = 0.4]", parameter_bm25_b),
+	JASS::commandline::parameter("-mu", "--dirichlet_mu", "<mu> The mu parameter of LM_Dirichlet [default = 1000]", parameter_dirichlet_mu),
+
+	JASS::commandline::note("\nQUANTIZATION\n------------"),
+	JASS::commandline::parameter("-Qb", "--quantization_bits", "<bits> Quantize into impacts of this many bits (1 to 16), i.e. 1 to 2^bits - 1 (wide impacts need wide accumulators when searching) [default = 0, impacts 1 to 1024 (11 bits)]", parameter_quantization_bits),
+	JASS::commandline::parameter("-Qm", "--quantization_mapping", "<mapping> Map scores to impacts with equal width (linear), log-scaled (log), or equal-frequency (equal_frequency) buckets [default = linear]", parameter_quantization_mapping),
+	JASS::commandline::parameter("-Qt", "--quantization_per_term", "Quantize each term on its own range of scores rather than the collection-wide range", parameter_quantization_per_term),
+
+	JASS::commandline::note("\nDOCUMENT ORDER\n--------------"),
+	JASS::commandline::parameter("-Ri", "--reorder_iterations", "<n> The number of rounds of swapping at each split of -R BP [default = 20]", parameter_reorder_iterations),
+	JASS::commandline::parameter("-R", "--reorder", "<order> Renumber the documents so that the index is smaller, by recursive graph bisection (BP) or by primary key (URL) (None|URL|BP) [default = None]", parameter_reorder),
+
+	JASS::commandline::note("\nPERFORMANCE\n-----------"),
+	JASS::commandline::parameter("-t", "--threads", "<threads> Number of threads to use for parsing and indexing [default = -t1]", parameter_threads),
+	JASS::commandline::parameter("-M", "--memory", "<megabytes> Spill postings to disk (in the current directory) when they use more than this much memory [default = unlimited]", parameter_memory_budget),
 
 	JASS::commandline::note("\nINDEX GENERATION\n----------------"),
 	JASS::commandline::parameter("-I1", "--index_jass_v1", "Generate a JASS version 1 index.", parameter_jass_v1_index),
 	JASS::commandline::parameter("-I2", "--index_jass_v2", "Generate a JASS version 2 index.", parameter_jass_v2_index),
+	JASS::commandline::parameter("-I3", "--index_jass_v3", "Generate a JASS version 3 index (a single file, CIindex.bin).", parameter_jass_v3_index),
+	JASS::commandline::parameter("-IC", "--index_CIFF", "Generate a Common Index File Format (CIFF) index (index.ciff) with the quantized impacts as term frequencies.", parameter_ciff_index),
 	JASS::commandline::parameter("-Ib", "--index_binary", "Generate a binary dump of just the postings segments.", parameter_uint32_index),
 	JASS::commandline::parameter("-Ic", "--index_compiled", "Generate a JASS compiled index.", parameter_compiled_index),
 	JASS::commandline::parameter("-If", "--index_forward", "Generate a forward index.", parameter_forward_index),
-	JASS::commandline::parameter("-IF", "--index_FASTA", "<k> Generate a k-mer index from FASTA documents.", parameter_fasta_kmer_length)
+	JASS::commandline::parameter("-IF", "--index_FASTA", "<k> Generate a k-mer index from FASTA documents.", parameter_fasta_kmer_length),
+	JASS::commandline::parameter("-Iu", "--index_unquantized", "Also write the unquantized postings (CIunquantized.bin) so that JASS_requantize can re-rank without re-parsing.", parameter_unquantized_index),
+	JASS::commandline::parameter("-P", "--positional", "Also store the position of each term in each document (CIpositions.bin) so that phrases can be searched for.", parameter_positional)
 	);
 
 
@@ -103,16 +174,19 @@ enum document_format
 	NONE,
 	TREC,
 	K_MER,
-	JSON_uniCOIL
+	JSON_uniCOIL,
+	WARC,
+	HTML
 	};
 
 /*
 	USAGE()
 	-------
 */
-uint8_t usage(const std::string &exename)
+template <typename TYPE>
+uint8_t usage(const std::string &exename, TYPE &all_parameters)
 	{
-	std::cout << JASS::commandline::usage(exename, command_line_parameters) << "\n";
+	std::cout << JASS::commandline::usage(exename, all_parameters) << "\n";
 	return 1;
 	}
 
@@ -122,22 +196,342 @@ uint8_t usage(const std::string &exename)
 */
 document_format get_document_format()
 	{
-	if
-		(
-		parameter_fasta_kmer_length != 0 &&
-		parameter_document_format_JSON_uniCOIL
-		)
-		return document_format::NONE;
+	size_t formats_specified = (parameter_fasta_kmer_length != 0) + parameter_document_format_JSON_uniCOIL + parameter_document_format_warc + parameter_document_format_html;
 
+	if (formats_specified > 1)
+		return document_format::NONE;
 
 	if (parameter_fasta_kmer_length != 0)
 		return document_format::K_MER;
 	else if (parameter_document_format_JSON_uniCOIL)
 		return document_format::JSON_uniCOIL;
+	else if (parameter_document_format_warc)
+		return document_format::WARC;
+	else if (parameter_document_format_html)
+		return document_format::HTML;
 	else
 		return document_format::TREC;
 	}
 
+/*
+	MAKE_PARSER()
+	-------------
+*/
+/*!
+	@brief Construct a parser for the given document format.
+	@param format [in] The document format.
+	@return A new parser (which the caller must delete), or nullptr if the format is unknown.
+*/
+JASS::parser *make_parser(document_format format)
+	{
+	switch (format)
+		{
+		case TREC:
+		case WARC:
+		case HTML:
+			return new JASS::parser();
+		case K_MER:
+			return new JASS::parser_fasta(parameter_fasta_kmer_length);
+		case JSON_uniCOIL:
+			return new JASS::parser_unicoil_json();
+		default:
+			return nullptr;
+		}
+	}
+
+/*
+	PARSE_FIELDS()
+	--------------
+*/
+/*!
+	@brief Turn the -F parameter (e.g. "title=3,headline") into field_names and field_weights.
+	@param fields [in] The -F parameter.
+	@return true on success, false if a weight is not a positive number.
+*/
+bool parse_fields(const std::string &fields)
+	{
+	size_t start = 0;
+	while (start < fields.size())
+		{
+		size_t end = fields.find(',', start);
+		if (end == std::string::npos)
+			end = fields.size();
+		std::string field = fields.substr(start, end - start);
+		start = end + 1;
+		if (field.empty())
+			continue;
+
+		double weight = 2;
+		size_t equals = field.find('=');
+		if (equals != std::string::npos)
+			{
+			char *end_of_weight;
+			weight = strtod(field.c_str() + equals + 1, &end_of_weight);
+			if (*end_of_weight != '\0' || weight <= 0)
+				return false;
+			field = field.substr(0, equals);
+			}
+
+		std::transform(field.begin(), field.end(), field.begin(), [](unsigned char character){ return static_cast<char>(tolower(character)); });
+		field_names.push_back(field);
+		field_weights.push_back(weight);
+		}
+
+	return true;
+	}
+
+/*
+	FIELD_NUMBER()
+	--------------
+*/
+/*!
+	@brief Return the field number of an XML tag.
+	@param tag [in] The tag name (perhaps followed by whitespace), which is case insensitive.
+	@return The field number (counting from 1) or 0 if the tag is not a field.
+*/
+size_t field_number(const JASS::slice &tag)
+	{
+	size_t length = tag.size();
+	while (length > 0 && isspace(tag[length - 1]))
+		length--;
+
+	for (size_t field = 0; field < field_names.size(); field++)
+		if (field_names[field].size() == length && strncasecmp(field_names[field].c_str(), reinterpret_cast<char *>(tag.address()), length) == 0)
+			return field + 1;
+
+	return 0;
+	}
+
+/*
+	INDEX_DOCUMENT()
+	----------------
+*/
+/*!
+	@brief Parse a single document and add it to the index.
+	@param parser [in] The parser to use.
+	@param stem [in] The stemmer to use (or nullptr for no stemming).
+	@param index [in] The index to add the document to.
+	@param document [in] The document to index.
+	@param collection_length [in/out] The number of terms in the document is added to this.
+*/
+void index_document(JASS::parser &parser, JASS::stem *stem, JASS::index_manager &index, JASS::document &document, uint64_t &collection_length)
+	{
+	/*
+		parse the current document
+	*/
+	parser.set_document(document);
+	index.begin_document(document.primary_key);
+
+	/*
+		Process each token
+	*/
+	bool finished = false;
+	JASS::compress_integer::integer document_length = 0;				// measured in terms
+	std::vector<size_t> open_fields;											// the fields we are in, innermost last
+	do
+		{
+		auto &token = const_cast<JASS::parser::token &>(parser.get_next_token());
+//std::cout << "[" << token.lexeme << "," << token.count << "]\n";
+		switch (token.type)
+			{
+			case JASS::parser::token::eof:
+				finished = true;
+				break;
+			case JASS::parser::token::alpha:
+				if (stopwords.is_stop_word(token.lexeme))
+					break;
+				document_length++;
+				if (stem != nullptr && token.lexeme.size() > 2)
+					stem->tostem(token, token);
+				index.term(token);
+				break;
+			case JASS::parser::token::numeric:
+				if (stopwords.is_stop_word(token.lexeme))
+					break;
+				document_length++;
+				index.term(token);
+				break;
+			case JASS::parser::token::xml_start_tag:
+				if (field_names.size() != 0)
+					if (size_t field = field_number(token.lexeme); field != 0)
+						{
+						open_fields.push_back(field);
+						index.set_field(field);
+						}
+				break;
+			case JASS::parser::token::xml_end_tag:
+				if (field_names.size() != 0)
+					if (size_t field = field_number(token.lexeme); field != 0)
+						{
+						/*
+							Close the innermost open instance of the field (ignoring badly nested tags), the terms are then in the enclosing field (if any)
+						*/
+						auto found = std::find(open_fields.rbegin(), open_fields.rend(), field);
+						if (found != open_fields.rend())
+							open_fields.erase(std::next(found).base());
+						index.set_field(open_fields.empty() ? 0 : open_fields.back());
+						}
+				break;
+			default:
+				break;
+			}
+		}
+	while (!finished);
+	collection_length += document_length;
+
+	/*
+		ATIRE has a bug that results in the document length calculation being off by one (one too large in ATIRE)
+	*/
+	index.end_document(document_length + (parameter_atire_similar ? 1 : 0));
+	}
+
+/*
+	CLASS INDEXING_THREAD
+	---------------------
+*/
+/*!
+	@brief Everything a worker thread needs in order to index its share of the document collection.
+	@details Documents are handed to each thread a batch at a time.  Each batch is a run of consecutive documents, and the batches are handed
+	out round-robin, so a thread's documents are in increasing (global) document id order.  Each thread has two batches, one is being indexed
+	while the other is being filled.
+*/
+class indexing_thread
+	{
+	public:
+		static constexpr size_t BATCH_SIZE = 1024;			///< The number of documents handed to a thread at a time.
+
+	public:
+		std::unique_ptr<JASS::parser> parser;												///< This thread's parser.
+		std::unique_ptr<JASS::stem> stem;													///< This thread's stemmer (or nullptr for no stemming).
+		JASS::index_manager_sequential index;												///< The partial index built by this thread.
+		std::vector<JASS::compress_integer::integer> global_document_ids;			///< The global document id of each document in index (element 0 is unused).
+		std::vector<JASS::document> batch[2];												///< The two batches of documents.
+		size_t batch_length[2];																	///< The number of documents in each batch.
+		uint64_t collection_length;															///< The number of terms this thread has indexed.
+
+	public:
+		/*
+			INDEXING_THREAD::INDEXING_THREAD()
+			----------------------------------
+		*/
+		/*!
+			@brief Constructor
+			@param format [in] The format of the documents.
+		*/
+		explicit indexing_thread(document_format format) :
+			parser(make_parser(format)),
+			stem(JASS::stem_all::get_by_name(parameter_stemmer)),
+			global_document_ids(1, 0),
+			batch{std::vector<JASS::document>(BATCH_SIZE), std::vector<JASS::document>(BATCH_SIZE)},
+			batch_length{0, 0},
+			collection_length(0)
+			{
+			/* Nothing */
+			}
+
+		/*
+			INDEXING_THREAD::INDEX_BATCH()
+			------------------------------
+		*/
+		/*!
+			@brief Index each document in one of the batches (this is the thread's main function).
+			@param which [in] Which batch to index (0 or 1).
+		*/
+		void index_batch(size_t which)
+			{
+			for (size_t current = 0; current < batch_length[which]; current++)
+				index_document(*parser, stem.get(), index, batch[which][current], collection_length);
+			}
+	};
+
+/*
+	INDEX_WITH_THREADS()
+	--------------------
+*/
+/*!
+	@brief Index the documents in source using several threads, each building a partial index, then merge the partial indexes into index.
+	@details The resulting index is identical to one built by indexing each document in turn.
+	@param source [in] The source of the documents.
+	@param format [in] The format of the documents.
+	@param threads [in] The number of indexing threads to use.
+	@param index [out] The index (which must be empty).
+	@param collection_length [out] The number of terms in the collection.
+	@param timer [in] A stop watch started at the beginning of the run (used for reporting).
+	@return The number of documents indexed.
+*/
+size_t index_with_threads(JASS::instream &source, document_format format, size_t threads, JASS::index_manager_sequential &index, uint64_t &collection_length, const decltype(JASS::timer::start()) &timer)
+	{
+	std::vector<std::unique_ptr<indexing_thread>> workers;
+	for (size_t which = 0; which < threads; which++)
+		workers.push_back(std::make_unique<indexing_thread>(format));
+
+	size_t total_documents = 0;
+	size_t current = 0;
+	bool at_eof = false;
+	std::vector<JASS::thread> thread_pool;
+	while (!at_eof)
+		{
+		/*
+			Fill the current batch of each worker, allocating global document ids in the order we read the documents
+		*/
+		for (auto &worker : workers)
+			{
+			size_t length = 0;
+			while (length < indexing_thread::BATCH_SIZE && !at_eof)
+				{
+				JASS::document &document = worker->batch[current][length];
+				document.rewind();
+				source.read(document);
+				if (document.isempty())
+					at_eof = true;
+				else
+					{
+					length++;
+					total_documents++;
+					worker->global_document_ids.push_back(static_cast<JASS::compress_integer::integer>(total_documents));
+					if (total_documents % parameter_report_every_n == 0)
+						{
+						auto took = JASS::timer::stop(timer).nanoseconds();
+						std::cout << "Documents:" << total_documents << " in:" << took << " ns" << "\n";
+						}
+					}
+				}
+			worker->batch_length[current] = length;
+			}
+
+		/*
+			Wait for the workers to finish the previous batch then start them on this one
+		*/
+		for (auto &thread : thread_pool)
+			thread.join();
+		thread_pool.clear();
+
+		for (auto &worker : workers)
+			thread_pool.push_back(JASS::thread(&indexing_thread::index_batch, worker.get(), current));
+
+		current = 1 - current;
+		}
+
+	for (auto &thread : thread_pool)
+		thread.join();
+
+	/*
+		Merge the partial indexes
+	*/
+	std::vector<JASS::index_manager_sequential *> partials;
+	std::vector<std::vector<JASS::compress_integer::integer>> global_document_ids;
+	collection_length = 0;
+	for (auto &worker : workers)
+		{
+		partials.push_back(&worker->index);
+		global_document_ids.push_back(std::move(worker->global_document_ids));
+		collection_length += worker->collection_length;
+		}
+	index.merge(partials, global_document_ids);
+
+	return total_documents;
+	}
+
 /*
 	MAIN()
 	------
@@ -147,10 +541,16 @@ int main(int argc, const char *argv[])
 	auto timer = JASS::timer::start();			// elapsed time since start (excluding static initialisers)
 
 	/*
-		Do the command line parsing.
+		Do the command line parsing (the list of codexes is built here as it depends on compress_integer_all's static initialisation).
 	*/
+	auto all_parameters = std::tuple_cat
+		(
+		command_line_parameters,
+		std::make_tuple(JASS::commandline::note("\nCOMPRESSION (of JASS v1, v2, and v3 indexes)\n--------------------------------------------")),
+		JASS::compress_integer_all::parameterlist(parameter_codex)
+		);
 	std::string error;
-	auto success = JASS::commandline::parse(argc, argv, command_line_parameters, error);
+	auto success = JASS::commandline::parse(argc, argv, all_parameters, error);
 	if (!success)
 		{
 		std::cout << error;
@@ -168,7 +568,7 @@ int main(int argc, const char *argv[])
 		Provide help if needed.
 	*/
 	if (parameter_filename == "" || parameter_help)
-		exit(usage(argv[0]));
+		exit(usage(argv[0], all_parameters));
 
 	/*
 		If we're not in quiet mode then dump the copyright message
@@ -180,12 +580,127 @@ int main(int argc, const char *argv[])
 	/*
 		Check to make sure we'll actually be exporting the index
 	*/
-	if (!(parameter_jass_v2_index | parameter_jass_v1_index | parameter_uint32_index | parameter_compiled_index | parameter_forward_index | parameter_fasta_kmer_length))
+	if (!(parameter_jass_v3_index | parameter_jass_v2_index | parameter_jass_v1_index | parameter_uint32_index | parameter_compiled_index | parameter_ciff_index | parameter_forward_index | parameter_fasta_kmer_length | parameter_unquantized_index))
 		{
 		std::cout << "You must specify an index file format or else no index will be generated\n";
 		return 1;
 		}
 
+	/*
+		The JASS indexes can only be compressed with a codex that can encode every d-gap
+	*/
+	std::string codex_shortname = JASS::compress_integer_all::shortname(parameter_codex);
+	if (codex_shortname != "" && !JASS::serialise_jass_v1::can_encode_postings(codex_shortname))
+		{
+		std::cout << "The " << JASS::compress_integer_all::name_of(codex_shortname) << " codex (" << codex_shortname << ") cannot be used to compress an index\n";
+		return 1;
+		}
+
+	/*
+		The threads each build an in-memory index and then merge, so a memory budget can't be honoured
+	*/
+	if (parameter_threads > 1 && parameter_memory_budget != 0)
+		{
+		std::cout << "Multi-threaded indexing (-t) cannot be used with a memory budget (-M)\n";
+		return 1;
+		}
+
+	/*
+		The per-field statistics are not merged between threads or spilled to disk
+	*/
+	if (!parse_fields(parameter_fields))
+		{
+		std::cout << "Bad field list (-F), expected tag[=weight],... with positive weights\n";
+		return 1;
+		}
+	if (field_names.size() != 0 && (parameter_threads > 1 || parameter_memory_budget != 0))
+		{
+		std::cout << "Fields (-F) cannot be used with multiple threads (-t) or a memory budget (-M)\n";
+		return 1;
+		}
+
+	/*
+		The positions are held in memory and are recorded as each document is parsed in order
+	*/
+	if (parameter_positional && (parameter_threads > 1 || parameter_memory_budget != 0))
+		{
+		std::cout << "A positional index (-P) cannot be built with multiple threads (-t) or a memory budget (-M)\n";
+		return 1;
+		}
+
+	/*
+		The unquantized postings are term frequencies, which BM25F (per-field) and uniCOIL (pre-quantized) indexes do not have
+	*/
+	if (parameter_unquantized_index && (parameter_fields != "" || format == JSON_uniCOIL))
+		{
+		std::cout << "The unquantized postings (-Iu) cannot be written for fields (-F) or uniCOIL documents (-djc)\n";
+		return 1;
+		}
+
+	/*
+		Check the document order (the field lengths and positions are not renumbered)
+	*/
+	if (parameter_reorder != "None" && parameter_reorder != "URL" && parameter_reorder != "BP")
+		{
+		std::cout << "Unknown document order (-R):" << parameter_reorder << "\n";
+		return 1;
+		}
+	if (parameter_reorder != "None" && (parameter_fields != "" || parameter_positional))
+		{
+		std::cout << "The documents cannot be reordered (-R) in an index with fields (-F) or positions (-P)\n";
+		return 1;
+		}
+
+	/*
+		Check the stemmer
+	*/
+	if (parameter_stem_porter)
+		parameter_stemmer = "Porter";
+	if (!JASS::stem_all::is_known(parameter_stemmer))
+		{
+		std::cout << "Unknown stemmer (-ts):" << parameter_stemmer << "\n";
+		return 1;
+		}
+
+	/*
+		Check the ranking function (BM25F is used when there are fields)
+	*/
+	if (!JASS::quantize_index::is_known(parameter_ranking_function))
+		{
+		std::cout << "Unknown ranking function (-r):" << parameter_ranking_function << "\n";
+		return 1;
+		}
+	if (parameter_fields != "" && parameter_ranking_function != "ATIRE_BM25")
+		{
+		std::cout << "Fields (-F) are ranked with BM25F and cannot be used with another ranking function (-r)\n";
+		return 1;
+		}
+
+	/*
+		Check the quantization scheme
+	*/
+	if (parameter_quantization_bits > JASS::quantization_scheme::MAX_BITS)
+		{
+		std::cout << "Impacts (-Qb) can be no more than " << JASS::quantization_scheme::MAX_BITS << " bits\n";
+		return 1;
+		}
+	if (!JASS::quantization_scheme::mapping_by_name(parameter_quantization_mapping, quantization_mapping))
+		{
+		std::cout << "Unknown quantization mapping (-Qm):" << parameter_quantization_mapping << "\n";
+		return 1;
+		}
+
+	/*
+		Load the stop words
+	*/
+	if (parameter_stop_english)
+		stopwords.add_english();
+	if (parameter_stop_filename != "" && !stopwords.add_file(parameter_stop_filename))
+		{
+		std::cout << "Cannot read the stop word file (-Sf):" << parameter_stop_filename << "\n";
+		return 1;
+		}
+
 	/*
 		Decode the input filename
 	*/
@@ -195,22 +710,11 @@ int main(int argc, const char *argv[])
 	/*
 		Set up the parser
 	*/
-	JASS::parser *parser;
-	switch (format)
+	JASS::parser *parser = make_parser(format);
+	if (parser == nullptr)
 		{
-		case TREC:
-			parser = new JASS::parser();
-			break;
-		case K_MER:
-			parser = new JASS::parser_fasta(parameter_fasta_kmer_length);
-			break;
-		case JSON_uniCOIL:
-			parser = new JASS::parser_unicoil_json();
-			break;
-		default:
-			std::cout << "Unknown parser type";
-			exit(1);
-			break;
+		std::cout << "Unknown parser type";
+		exit(1);
 		}
 
 	/*
@@ -240,6 +744,23 @@ int main(int argc, const char *argv[])
 				}
 			break;
 			}
+		case WARC:
+		case HTML:
+			{
+			std::shared_ptr<JASS::instream> reader;
+			if (std::filesystem::is_directory(std::filesystem::path(parameter_filename)))
+				reader = std::shared_ptr<JASS::instream>(new JASS::instream_directory_iterator(parameter_filename));
+			else if (std::filesystem::path(parameter_filename).extension() == ".gz")
+				reader = std::shared_ptr<JASS::instream>(new JASS::instream_deflate(file));
+			else
+				reader = file;
+
+			if (format == WARC)
+				data_source = new JASS::instream_document_warc(reader);
+			else
+				data_source = new JASS::instream_document_html(reader);
+			break;
+			}
 		default:
 			std::cout << "Unknown parser type";
 			exit(1);
@@ -251,14 +772,19 @@ int main(int argc, const char *argv[])
 	/*
 		Set up the stemmer
 	*/
-	JASS::stem *stem = nullptr;
-	if (parameter_stem_porter)
-		stem = new JASS::stem_porter;
+	JASS::stem *stem = JASS::stem_all::get_by_name(parameter_stemmer);
 
 	/*
 		Now call JASS
 	*/
-	JASS::index_manager_sequential index;
+	std::unique_ptr<JASS::index_manager> index_manager;
+	if (parameter_memory_budget != 0)
+		index_manager = std::make_unique<JASS::index_manager_spill>(parameter_memory_budget * 1024 * 1024);
+	else if (parameter_positional)
+		index_manager = std::make_unique<JASS::index_manager_positional>();
+	else
+		index_manager = std::make_unique<JASS::index_manager_sequential>();
+	index_manager->set_fields(field_names);
 	JASS::document document;
 	size_t total_documents = 0;
 
@@ -268,134 +794,202 @@ int main(int argc, const char *argv[])
 		Parse the instream to get document (which are then indexed)
 	*/
 	uint64_t collection_length = 0;		// measured in terms
-	do
-		{
-		/*
-			Reuse memory from before
-		*/
-		document.rewind();
-
-		/*
-			get the next document
-		*/
-		source->read(document);
-		if (document.isempty())
-			break;
-
-		total_documents++;
-		if (total_documents % parameter_report_every_n == 0)
-			{
-			auto took = JASS::timer::stop(timer).nanoseconds();
-			std::cout << "Documents:" << total_documents << " in:" << took << " ns" << "\n";
-			}
-
-		/*
-			parse the current document
-		*/
-		parser->set_document(document);
-		index.begin_document(document.primary_key);
-
-		/*
-			Process each token
-		*/
-		bool finished = false;
-		JASS::compress_integer::integer document_length = 0;				// measured in terms
+	if (parameter_threads > 1)
+		total_documents = index_with_threads(*source, format, parameter_threads, static_cast<JASS::index_manager_sequential &>(*index_manager), collection_length, timer);		// -t and -M are mutually exclusive (checked above)
+	else
 		do
 			{
-			auto &token = const_cast<JASS::parser::token &>(parser->get_next_token());
-//std::cout << "[" << token.lexeme << "," << token.count << "]\n";
-			switch (token.type)
+			/*
+				Reuse memory from before
+			*/
+			document.rewind();
+
+			/*
+				get the next document
+			*/
+			source->read(document);
+			if (document.isempty())
+				break;
+
+			total_documents++;
+			if (total_documents % parameter_report_every_n == 0)
 				{
-				case JASS::parser::token::eof:
-					finished = true;
-					break;
-				case JASS::parser::token::alpha:
-					document_length++;
-					if (stem != nullptr && token.lexeme.size() > 2)
-						stem->tostem(token, token);
-					index.term(token);
-					break;
-				case JASS::parser::token::numeric:
-					document_length++;
-					index.term(token);
-					break;
-				case JASS::parser::token::xml_start_tag:
-					break;
-				case JASS::parser::token::xml_end_tag:
-					break;
-				default:
-					break;
+				auto took = JASS::timer::stop(timer).nanoseconds();
+				std::cout << "Documents:" << total_documents << " in:" << took << " ns" << "\n";
 				}
-			}
-		while (!finished);
-		collection_length += document_length;
 
-		/*
-			ATIRE has a bug that results in the document length calculation being off by one (one too large in ATIRE)
-		*/
-		index.end_document(document_length + (parameter_atire_similar ? 1 : 0));
-		}
-	while (!document.isempty());
+			index_document(*parser, stem, *index_manager, document, collection_length);
+			}
+		while (!document.isempty());
 
 	auto time_to_end_parse = JASS::timer::stop(timer).nanoseconds();
 
 	std::cout << "Documents:" << total_documents << '\n';
 	std::cout << "Terms    :" << collection_length << '\n';
+	if (parameter_memory_budget != 0)
+		std::cout << "Runs     :" << static_cast<JASS::index_manager_spill &>(*index_manager).runs() << '\n';
 
 	/*
-		quantize the index
+		Renumber the documents (into a new index, replacing the one we parsed into).
 	*/
-	std::shared_ptr<JASS::ranking_function_atire_bm25> ranker(new JASS::ranking_function_atire_bm25(0.9, 0.4, index.get_document_length_vector()));
-
-	JASS::quantize<JASS::ranking_function_atire_bm25> *quantizer;
-	if (format == JSON_uniCOIL)
-		quantizer = new JASS::quantize_none<JASS::ranking_function_atire_bm25>(total_documents, ranker);
-	else
+	if (parameter_reorder != "None")
 		{
-		quantizer = new JASS::quantize<JASS::ranking_function_atire_bm25>(total_documents, ranker);
-		index.iterate(*quantizer);
+		auto size_before = JASS::document_reorder::postings_size(*index_manager);
+		auto new_id = parameter_reorder == "BP" ? JASS::document_reorder::by_bisection(*index_manager, parameter_reorder_iterations) : JASS::document_reorder::by_primary_key(*index_manager);
+		auto reordered = std::make_unique<JASS::index_manager_sequential>();
+		JASS::document_reorder::renumber(*index_manager, new_id, *reordered);
+		index_manager = std::move(reordered);
+		auto size_after = JASS::document_reorder::postings_size(*index_manager);
+
+		std::cout << "Postings size before reordering:" << size_before << " bytes (variable byte encoded d-gaps)\n";
+		std::cout << "Postings size after reordering :" << size_after << " bytes (variable byte encoded d-gaps)\n";
 		}
+	JASS::index_manager &index = *index_manager;
+
+	auto time_to_end_reorder = JASS::timer::stop(timer).nanoseconds();
+
+	/*
+		How the scores will be mapped into impacts.
+	*/
+	JASS::quantization_scheme scheme(quantization_mapping, parameter_quantization_per_term ? JASS::quantization_scheme::PER_TERM : JASS::quantization_scheme::GLOBAL, parameter_quantization_bits);
+
+	/*
+		The JASS v1 and v2 indexes are several files, and JASS_requantize reads the stemmer, stop words, and positions of an unquantized
+		index (-Iu) from the loose files, but a JASS v3 index holds them so when it is the only one they are not left in the directory.
+	*/
+	bool loose_files = parameter_jass_v1_index || parameter_jass_v2_index || parameter_unquantized_index;
+
+	/*
+		Record the stemmer in the index so that the search engine can stem the queries the same way.  This and the other files that are part of
+		the index are written before the index itself so that their checksums can be recorded in its manifest.
+	*/
+	std::string stemmer_name = stem == nullptr ? std::string(JASS::stem_all::NO_STEMMER) : stem->name();
+	if (loose_files)
+		JASS::file::write_entire_file("CIstemmer.txt", stemmer_name + "\n");
+
+	/*
+		Record the stop words so that the search engine can stop the queries the same way (an empty list overwrites any left from a previous index).
+	*/
+	if (loose_files)
+		JASS::file::write_entire_file("CIstopwords.txt", stopwords.serialise());
+
+	/*
+		Write the positions so that the search engine can check phrases (the JASS v3 index copies them from the file, which is temporary if not needed).
+	*/
+	std::string positions_filename = loose_files ? JASS::index_manager_positional::POSITIONS_FILENAME : std::string(JASS::serialise_jass_v3::FILENAME) + ".tmp." + JASS::index_manager_positional::POSITIONS_FILENAME;
+	if (parameter_positional)
+		static_cast<JASS::index_manager_positional &>(index).serialise_positions(positions_filename);		// -P can't be used with -t or -M (checked above)
 
-	auto time_to_end_quantization = JASS::timer::stop(timer).nanoseconds();
+	/*
+		The quantization scheme (CIquantization.txt) is written by quantize_and_serialise() once its bounds are known.
+	*/
+	bool save_quantization = (parameter_jass_v1_index || parameter_jass_v2_index) && format != JSON_uniCOIL;
+	std::vector<std::string> auxiliary_files = {"CIstemmer.txt", "CIstopwords.txt"};
+	if (save_quantization)
+		auxiliary_files.push_back("CIquantization.txt");
+	if (parameter_positional)
+		auxiliary_files.push_back(JASS::index_manager_positional::POSITIONS_FILENAME);
+
+	/*
+		Record how the index is built in its manifest (JSON uniCOIL documents are already quantized, and BM25F is used when there are fields)
+	*/
+	std::string ranking_function = JASS::quantize_index::describe_ranking_function(format == JSON_uniCOIL ? "uniCOIL" : field_names.size() != 0 ? "BM25F" : parameter_ranking_function, parameter_bm25_k1, parameter_bm25_b, parameter_dirichlet_mu);
+	const JASS::quantization_scheme *quantization = format == JSON_uniCOIL ? nullptr : &scheme;
 
 	/*
 		Decode the export formats and encode into a vector
 	*/
+	int8_t codex_alignment = codex_shortname == "-cZ" ? 16 : 1;			// QMX JASS v1 needs its postings to start on 16-byte boundaries
 	std::vector<std::unique_ptr<JASS::index_manager::delegate>> exporters;
+	JASS::serialise_jass_v3 *jass_v3 = nullptr;
 	if (parameter_compiled_index)
 		exporters.push_back(std::make_unique<JASS::serialise_ci>(index.get_highest_document_id()));
 	if (parameter_jass_v1_index)
-		exporters.push_back(std::make_unique<JASS::serialise_jass_v1>(index.get_highest_document_id()));
+		{
+		auto serialiser = codex_shortname == "" ? std::make_unique<JASS::serialise_jass_v1>(index.get_highest_document_id()) : std::make_unique<JASS::serialise_jass_v1>(index.get_highest_document_id(), codex_shortname, codex_alignment);
+		JASS::quantize_index::describe(serialiser->get_manifest(), ranking_function, quantization, stemmer_name);
+		for (const auto &filename : auxiliary_files)
+			serialiser->add_auxiliary_file(filename);
+		exporters.push_back(std::move(serialiser));
+		}
 	if (parameter_jass_v2_index)
-		exporters.push_back(std::make_unique<JASS::serialise_jass_v2>(index.get_highest_document_id()));
+		{
+		auto serialiser = codex_shortname == "" ? std::make_unique<JASS::serialise_jass_v2>(index.get_highest_document_id()) : std::make_unique<JASS::serialise_jass_v2>(index.get_highest_document_id(), codex_shortname, codex_alignment);
+		JASS::quantize_index::describe(serialiser->get_manifest(), ranking_function, quantization, stemmer_name);
+		for (const auto &filename : auxiliary_files)
+			serialiser->add_auxiliary_file(filename);
+		exporters.push_back(std::move(serialiser));
+		}
+	if (parameter_jass_v3_index)
+		{
+		auto serialiser = codex_shortname == "" ? std::make_unique<JASS::serialise_jass_v3>(index.get_highest_document_id()) : std::make_unique<JASS::serialise_jass_v3>(index.get_highest_document_id(), codex_shortname, codex_alignment);
+		JASS::quantize_index::describe(serialiser->get_manifest(), ranking_function, quantization, stemmer_name);
+
+		/*
+			The JASS v3 index is a single file so it holds the stemmer, stop words, positions, and (see quantize_and_serialise()) the quantization
+		*/
+		serialiser->add_section(JASS::serialise_jass_v3::STEMMER, stemmer_name + "\n");
+		serialiser->add_section(JASS::serialise_jass_v3::STOPWORDS, stopwords.serialise());
+		if (parameter_positional)
+			serialiser->add_section_file(JASS::serialise_jass_v3::POSITIONS, positions_filename);
+
+		jass_v3 = serialiser.get();
+		exporters.push_back(std::move(serialiser));
+		}
 	if (parameter_uint32_index)
 		exporters.push_back(std::make_unique<JASS::serialise_integers>(index.get_highest_document_id()));
 	if (parameter_forward_index)
 		exporters.push_back(std::make_unique<JASS::serialise_forward_index>(index.get_highest_document_id()));
+	if (parameter_ciff_index)
+		exporters.push_back(std::make_unique<JASS::serialise_ciff>(index.get_highest_document_id(), index.get_document_length_vector()));
 
 	/*
-		Write out the index in the desired formats.
+		Write the unquantized postings (before they are quantized) so that the index can be re-quantized without re-parsing.
 	*/
-	if (exporters.size() != 0)
-		quantizer->serialise_index(index, exporters);
+	if (parameter_unquantized_index)
+		{
+		JASS::serialise_unquantized unquantized(index.get_highest_document_id(), index.get_document_length_vector());
+		index.iterate(unquantized);
+		unquantized.finish();
+		}
+
+	/*
+		Quantize the index (with BM25F if there are fields, else the chosen ranking function) then write it out in the desired formats.
+	*/
+	JASS::quantize_index::nanoseconds time_to_end_quantization;
+	auto &document_lengths = index.get_document_length_vector();
+	if (field_names.size() != 0)
+		JASS::quantize_index::quantize_and_serialise(std::make_shared<JASS::ranking_function_bm25f>(parameter_bm25_k1, parameter_bm25_b, index, field_weights), index, true, total_documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+	else if (format == JSON_uniCOIL)
+		JASS::quantize_index::quantize_and_serialise(std::make_shared<JASS::ranking_function_atire_bm25>(parameter_bm25_k1, parameter_bm25_b, document_lengths), index, false, total_documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);		// the documents are already impacts, so the ranking function is not used
+	else
+		JASS::quantize_index::quantize_and_serialise(parameter_ranking_function, parameter_bm25_k1, parameter_bm25_b, parameter_dirichlet_mu, index, total_documents, exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+
+	if (parameter_positional && !loose_files)
+		{
+		std::error_code error;
+		std::filesystem::remove(positions_filename, error);
+		}
 
 	/*
 		Dump the statistics to the console.
 	*/
 	auto time_to_end = JASS::timer::stop(timer).nanoseconds();
 	auto parse_time = time_to_end_parse - preamble_time;
-	auto quantization_time = time_to_end_quantization - time_to_end_parse;
+	auto reorder_time = time_to_end_reorder - time_to_end_parse;
+	auto quantization_time = time_to_end_quantization - time_to_end_reorder;
 	auto serialise_time = time_to_end - time_to_end_quantization;
 
 	std::cout << "Preamble time    :" << preamble_time << "ns (" << preamble_time / 1000000000 << " seconds)\n";
 	std::cout << "Parse time       :" << parse_time << "ns (" << parse_time / 1000000000 << " seconds)\n";
+	if (parameter_reorder != "None")
+		std::cout << "Reorder time     :" << reorder_time << "ns (" << reorder_time / 1000000000 << " seconds)\n";
 	std::cout << "Quantization time:" << quantization_time << "ns (" << quantization_time / 1000000000 << " seconds)\n";
 	std::cout << "Serialise time   :" << serialise_time << "ns (" << serialise_time / 1000000000 << " seconds)\n";
 	std::cout << "=================\n";
 	std::cout << "Total time       :" << time_to_end << "ns (" << time_to_end / 1000000000 << " seconds)\n";
 
 	delete parser;
-	delete quantizer;
 
 	/*
 		Done.
@@ -0,0 +1,368 @@
+/*
+	JASS_MERGE.CPP
+	--------------
+	Copyright (c) 2021 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Merge several JASS v2 indexes into a single JASS v2 index.
+	@author Andrew Trotman
+	@copyright 2021 Andrew Trotman
+
+	@details The indexes are merged in the order given on the command line.  Document ids are renumbered so that the
+	documents of the first index come first, then the documents of the second index, and so on.  If the indexes were
+	quantized using different score ranges then the impacts are re-quantized onto the range that covers all the indexes.
+	Indexes quantized in different ways (see JASS_index -Qb -Qm -Qt) cannot be merged, nor can indexes ranked with different ranking
+	functions or indexes that do not record their quantization bounds (their impacts might not be on the same scale) unless forced to
+	(in which case the impacts are merged unchanged).  Only JASS v2 indexes can be merged.  The merged index is written to the current directory.
+*/
+#include <stdio.h>
+#include <string.h>
+#include <stdint.h>
+
+#include <vector>
+#include <memory>
+#include <iostream>
+#include <algorithm>
+
+#include "file.h"
+#include "slice.h"
+#include "version.h"
+#include "stem_all.h"
+#include "index_manifest.h"
+#include "quantize_index.h"
+#include "allocator_pool.h"
+#include "index_postings.h"
+#include "serialise_jass_v2.h"
+#include "deserialised_jass_v2.h"
+#include "index_postings_impact.h"
+
+/*
+	CLASS SHARD
+	-----------
+*/
+/*!
+	@brief One of the indexes being merged, along with where we are in its vocabulary.
+*/
+class shard
+	{
+	public:
+		std::string directory;													///< The directory containing the index.
+		JASS::index_manifest manifest;										///< The manifest of the index (version 0 if it doesn't have one).
+		JASS::deserialised_jass_v2 index;									///< The index itself.
+		std::unique_ptr<JASS::compress_integer> codex;					///< The decoder for the postings segments.
+		int32_t d_ness;															///< Are the segments D1 encoded?
+		JASS::compress_integer::integer first_document_id;				///< The (global) document id of the first document in this index.
+		bool has_bounds;															///< Is the quantization scheme (and its bounds) known?
+		std::vector<JASS::deserialised_jass_v1::metadata>::const_iterator current_term;		///< The next term to merge.
+
+	public:
+		/*
+			SHARD::SHARD()
+			--------------
+		*/
+		/*!
+			@brief Constructor
+			@param directory [in] The directory containing the index.
+		*/
+		explicit shard(const std::string &directory) :
+			directory(directory),
+			d_ness(0),
+			first_document_id(0),
+			has_bounds(false)
+			{
+			/* Nothing */
+			}
+	};
+
+/*
+	USAGE()
+	-------
+*/
+/*!
+	@brief Explain how to use this tool.
+	@param exename [in] The name of this executable.
+	@return 1 (to be used as an exit code)
+*/
+uint8_t usage(const char *exename)
+	{
+	printf("Usage:%s [-F | --force] <index_directory> <index_directory> [<index_directory> ...]\n", exename);
+	printf("Merge several JASS v2 indexes into one JASS v2 index written to the current directory.\n");
+	printf("Documents are numbered in the order the indexes are given.  If the indexes were quantized\n");
+	printf("with different score ranges then the impacts are re-quantized onto a common range.\n");
+	printf("-F --force Merge indexes ranked with different ranking functions, and indexes that do not record\n");
+	printf("           their quantization bounds (the impacts of these are merged unchanged).\n");
+
+	return 1;
+	}
+
+/*
+	REQUANTIZE()
+	------------
+*/
+/*!
+	@brief Map an impact score from the range of one index onto the common range of the merged index.
+	@param impact [in] The impact score in the source index.
+	@param term [in] The term (for indexes quantized with a range per term).
+	@param from [in] How the source index was quantized.
+	@param to [in] How the merged index is quantized (the same method as from, with bounds that cover all the indexes).
+	@return The impact score in the merged index.
+*/
+JASS::index_postings_impact::impact_type requantize(JASS::index_postings_impact::impact_type impact, const JASS::slice &term, const JASS::quantization_scheme &from, const JASS::quantization_scheme &to)
+	{
+	/*
+		The impact is a bucket of scores, so take the middle of the bucket as the score it represents then quantize that.
+	*/
+	return to.quantize(from.dequantize(impact, from.get_bounds(term)), to.get_bounds(term));
+	}
+
+/*
+	MAIN()
+	------
+*/
+int main(int argc, const char *argv[])
+	{
+	bool force = false;
+	std::vector<std::string> directories;
+	for (int parameter = 1; parameter < argc; parameter++)
+		if (strcmp(argv[parameter], "-F") == 0 || strcmp(argv[parameter], "--force") == 0)
+			force = true;
+		else
+			directories.push_back(argv[parameter]);
+
+	if (directories.size() < 2)
+		exit(usage(argv[0]));
+
+	std::cout << JASS::version::build() << "\n";
+
+	/*
+		Load each of the indexes
+	*/
+	std::vector<std::unique_ptr<shard>> shards;
+	JASS::compress_integer::integer total_documents = 0;
+	for (const auto &directory : directories)
+		{
+		shards.push_back(std::make_unique<shard>(directory));
+		shard &source = *shards.back();
+
+		/*
+			Check the version before loading as loading an index of another version as JASS v2 fails badly
+		*/
+		size_t version = source.manifest.read(source.directory) ? source.manifest.version : JASS::index_manifest::legacy_version(source.directory);
+		if (version == 0)
+			exit(printf("Can't read JASS v2 index in directory:%s\n", directory.c_str()));
+		else if (version != 2)
+			exit(printf("Can't merge the JASS v%zu index in directory:%s (only JASS v2 indexes can be merged)\n", version, directory.c_str()));
+
+		std::cout << "Loading " << source.directory << "\n";
+		if (source.index.read_index(source.directory) == 0)
+			exit(printf("Can't read JASS v2 index in directory:%s\n", directory.c_str()));
+
+		std::string codex_name;
+		source.codex.reset(source.index.codex(codex_name, source.d_ness));
+		source.first_document_id = total_documents + 1;			// JASS indexes count from 1 (0 is the dud document "-")
+		double smallest;
+		double largest;
+		source.has_bounds = source.index.quantization_bounds(smallest, largest);
+		source.current_term = source.index.begin();
+		total_documents += source.index.document_count();
+		}
+
+	/*
+		The merged index can only be stemmed one way
+	*/
+	const std::string &stemmer = shards[0]->index.stemmer();
+	for (const auto &source : shards)
+		if (source->index.stemmer() != stemmer)
+			exit(printf("Can't merge indexes built with different stemmers (%s uses %s, %s uses %s)\n", shards[0]->directory.c_str(), stemmer.c_str(), source->directory.c_str(), source->index.stemmer().c_str()));
+
+	/*
+		Nor can it be stopped more than one way
+	*/
+	std::string stopwords = shards[0]->index.stopwords().serialise();
+	for (const auto &source : shards)
+		if (source->index.stopwords().serialise() != stopwords)
+			exit(printf("Can't merge indexes built with different stop words (%s and %s)\n", shards[0]->directory.c_str(), source->directory.c_str()));
+
+	/*
+		The impacts of indexes ranked with different ranking functions are not comparable (legacy indexes don't record theirs, so it is not known)
+	*/
+	std::string ranking_function;
+	bool ranking_function_known = true;
+	for (const auto &source : shards)
+		if (source->manifest.ranking_function.empty())
+			ranking_function_known = false;
+		else if (ranking_function.empty())
+			ranking_function = source->manifest.ranking_function;
+		else if (source->manifest.ranking_function != ranking_function)
+			{
+			if (!force)
+				exit(printf("Can't merge indexes ranked with different ranking functions (%s uses %s, an earlier index uses %s), use --force to merge them anyway\n", source->directory.c_str(), source->manifest.ranking_function.c_str(), ranking_function.c_str()));
+			ranking_function_known = false;
+			}
+	if (!ranking_function_known)
+		ranking_function = "";
+
+	/*
+		Work out whether we need to re-quantize, and if so then onto what range.
+	*/
+	bool all_have_bounds = true;
+	bool must_requantize = false;
+	JASS::quantization_scheme common = shards[0]->index.quantization();
+	std::string common_serialised = common.serialise();
+	for (const auto &source : shards)
+		{
+		const JASS::quantization_scheme &scheme = source->index.quantization();
+
+		if (!source->has_bounds)
+			all_have_bounds = false;
+		else if (!scheme.same_method(common))
+			exit(printf("Can't merge indexes quantized in different ways (%s and %s)\n", shards[0]->directory.c_str(), source->directory.c_str()));
+		else if (scheme.serialise() != common_serialised)
+			must_requantize = true;
+
+		common.widen(scheme);
+		}
+
+	if (!all_have_bounds && !force)
+		exit(printf("Can't merge indexes that do not record their quantization bounds (their impacts might not be on the same scale), use --force to merge their impacts unchanged\n"));
+	else if (!all_have_bounds)
+		{
+		std::cout << "WARNING: At least one index does not record its quantization bounds so the impacts are merged unchanged\n";
+		must_requantize = false;
+		}
+	else if (must_requantize && common.get_mapping() == JASS::quantization_scheme::EQUAL_FREQUENCY)
+		exit(printf("Can't merge equal-frequency quantized indexes unless they have the same boundaries\n"));
+	else if (must_requantize)
+		std::cout << "Re-quantizing onto the range [" << common.get_global_bounds().smallest << ", " << common.get_global_bounds().largest << "]\n";
+
+	/*
+		The merged index is written using the same codex as the first index.
+	*/
+	const uint8_t *first_postings = shards[0]->index.postings();
+	auto codex = static_cast<JASS::serialise_jass_v1::jass_v1_codex>(first_postings[0]);
+	std::unique_ptr<JASS::serialise_jass_v2> serialiser;
+	if (codex == JASS::serialise_jass_v1::jass_v1_codex::any_codex)
+		serialiser = std::make_unique<JASS::serialise_jass_v2>(total_documents, std::string(reinterpret_cast<const char *>(first_postings + 1)));		// codex() has already checked this is '\0' terminated
+	else
+		serialiser = std::make_unique<JASS::serialise_jass_v2>(total_documents, codex);
+	JASS::index_manager::delegate &writer = *serialiser;
+
+	/*
+		Describe the merged index in its manifest (if the impacts are merged unchanged then how they were quantized is not known).
+	*/
+	JASS::quantize_index::describe(serialiser->get_manifest(), ranking_function, &common, stemmer);
+	if (!all_have_bounds)
+		serialiser->get_manifest().quantization = "";
+
+	/*
+		Record the stemmer, the stop words, and the quantization bounds, just as JASS_index does (and before the index so that they are in its manifest).
+	*/
+	JASS::file::write_entire_file("CIstemmer.txt", stemmer + "\n");
+	serialiser->add_auxiliary_file("CIstemmer.txt");
+	JASS::file::write_entire_file("CIstopwords.txt", stopwords);
+	serialiser->add_auxiliary_file("CIstopwords.txt");
+	if (all_have_bounds)
+		{
+		JASS::file::write_entire_file("CIquantization.txt", common.serialise());
+		serialiser->add_auxiliary_file("CIquantization.txt");
+		}
+
+	/*
+		Merge the vocabularies (each is sorted) and for each term merge the postings lists.
+	*/
+	JASS::allocator_pool memory(1024);
+	JASS::index_postings unused_postings(memory);
+	std::vector<JASS::deserialised_jass_v1::segment_header> segments;
+	std::vector<JASS::compress_integer::integer> decompress_buffer;
+	std::vector<std::pair<JASS::compress_integer::integer, JASS::index_postings_impact::impact_type>> postings;
+	std::vector<JASS::compress_integer::integer> document_ids;
+	std::vector<JASS::index_postings_impact::impact_type> impacts;
+	size_t terms = 0;
+
+	while (true)
+		{
+		/*
+			Find the smallest term not yet merged
+		*/
+		const JASS::slice *term = nullptr;
+		for (const auto &source : shards)
+			if (source->current_term != source->index.end())
+				if (term == nullptr || JASS::slice::strict_weak_order_less_than(source->current_term->term, *term))
+					term = &source->current_term->term;
+
+		if (term == nullptr)
+			break;
+
+		JASS::slice merging = *term;
+
+		/*
+			Extract the postings from each index that has the term
+		*/
+		postings.clear();
+		for (auto &source : shards)
+			{
+			if (source->current_term == source->index.end() || !(source->current_term->term == merging))
+				continue;
+
+			JASS::deserialised_jass_v1::metadata metadata = *source->current_term;
+			uint32_t smallest_impact;
+			uint32_t largest_impact;
+			JASS::query::DOCID_TYPE document_frequency;
+
+			segments.resize(metadata.impacts);
+			source->index.get_segment_list(segments.data(), metadata, 1, smallest_impact, largest_impact, document_frequency);
+
+			for (const auto &segment : segments)
+				{
+				decompress_buffer.resize(segment.segment_frequency + 256);			// the decoders can overrun the end of the buffer
+				source->codex->decode(decompress_buffer.data(), segment.segment_frequency, source->index.postings() + segment.offset, segment.end - segment.offset);
+
+				auto impact = static_cast<JASS::index_postings_impact::impact_type>(segment.impact);
+				if (must_requantize)
+					impact = requantize(impact, merging, source->index.quantization(), common);
+
+				JASS::compress_integer::integer id = 0;
+				for (size_t which = 0; which < segment.segment_frequency; which++)
+					{
+					id = source->d_ness == 1 ? id + decompress_buffer[which] : decompress_buffer[which];
+					postings.push_back(std::make_pair(id + source->first_document_id, impact));
+					}
+				}
+
+			source->current_term++;
+			}
+
+		/*
+			Put the postings into document order and hand them to the serialiser
+		*/
+		std::sort(postings.begin(), postings.end());
+		document_ids.clear();
+		impacts.clear();
+		for (const auto &[document_id, impact] : postings)
+			{
+			document_ids.push_back(document_id);
+			impacts.push_back(impact);
+			}
+
+		writer(merging, unused_postings, static_cast<JASS::compress_integer::integer>(document_ids.size()), document_ids.data(), impacts.data());
+		terms++;
+		}
+
+	/*
+		Now the primary keys, which start with the dud document "-"
+	*/
+	size_t document_id = 0;
+	writer(document_id, JASS::slice("-"));
+	for (const auto &source : shards)
+		for (const auto &primary_key : source->index.primary_keys())
+			writer(++document_id, JASS::slice((void *)primary_key.c_str(), primary_key.size()));
+
+	writer.finish();
+
+	std::cout << "Documents:" << total_documents << '\n';
+	std::cout << "Terms    :" << terms << '\n';
+
+	return 0;
+	}
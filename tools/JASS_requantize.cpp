@@ -0,0 +1,314 @@
+/*
+	JASS_REQUANTIZE.CPP
+	-------------------
+	Copyright (c) 2026 Andrew Trotman
+	Released under the 2-clause BSD license (See:https://en.wikipedia.org/wiki/BSD_licenses)
+*/
+/*!
+	@file
+	@brief Re-quantize an index from its unquantized postings (see JASS_index -Iu) without re-parsing the collection.
+	@author Andrew Trotman
+	@copyright 2026 Andrew Trotman
+
+	@details The unquantized postings (CIunquantized.bin) are loaded from the given directory, ranked with the chosen ranking function, quantized
+	with the chosen quantization scheme, and written as a new index in the current directory.  Unless told otherwise the new index is in the
+	same format (JASS v1, v2, and / or v3) and compressed with the same codex as the original index (found from its manifest).  The stemmer,
+	stop words, and positions of the original index are copied across so that the new index is searched the same way.
+*/
+#include <vector>
+#include <iostream>
+#include <filesystem>
+
+#include "file.h"
+#include "timer.h"
+#include "version.h"
+#include "commandline.h"
+#include "index_manifest.h"
+#include "quantize_index.h"
+#include "serialise_jass_v1.h"
+#include "serialise_jass_v2.h"
+#include "serialise_jass_v3.h"
+#include "quantization_scheme.h"
+#include "compress_integer_all.h"
+#include "deserialised_jass_v3.h"
+#include "serialise_unquantized.h"
+#include "index_manager_sequential.h"
+
+/*
+	Declare the command line parameters
+*/
+std::string parameter_directory = ".";
+bool parameter_jass_v1_index = false;
+bool parameter_jass_v2_index = false;
+bool parameter_jass_v3_index = false;
+bool parameter_quiet = false;
+bool parameter_help = false;
+
+std::string parameter_ranking_function = "ATIRE_BM25";
+double parameter_bm25_k1 = 0.9;
+double parameter_bm25_b = 0.4;
+double parameter_dirichlet_mu = 1000;
+size_t parameter_quantization_bits = 0;
+std::string parameter_quantization_mapping = "linear";
+bool parameter_quantization_per_term = false;
+
+std::array<bool, JASS::compress_integer_all::compressors_size> parameter_codex = {};		///< Which compress_integer_all codex to compress the JASS indexes with (default is none of them, so use the codex of the original index)
+
+auto command_line_parameters = std::make_tuple
+	(
+	JASS::commandline::note("\nMISCELLANEOUS\n-------------"),
+	JASS::commandline::parameter("-q", "--nologo", "Suppress the banner.", parameter_quiet),
+	JASS::commandline::parameter("-?", "--help", "Print this help.", parameter_help),
+	JASS::commandline::parameter("-h", "--help", "Print this help.", parameter_help),
+	JASS::commandline::parameter("-H", "--help", "Print this help.", parameter_help),
+
+	JASS::commandline::note("\nFILE HANDLING\n-------------"),
+	JASS::commandline::parameter("-f", "--from", "<directory> The directory holding the unquantized postings written by JASS_index -Iu [default = .]", parameter_directory),
+
+	JASS::commandline::note("\nRANKING\n-------"),
+	JASS::commandline::parameter("-r", "--ranking_function", "<function> Quantize using this ranking function (ATIRE_BM25|Lucene_BM25|LM_Dirichlet|DPH|TF_IDF) [default = ATIRE_BM25]", parameter_ranking_function),
+	JASS::commandline::parameter("-k1", "--bm25_k1", "<k1> The k1 parameter of BM25 [default = 0.9]", parameter_bm25_k1),
+	JASS::commandline::parameter("-b", "--bm25_b", "<b> The b parameter of BM25 [default = 0.4]", parameter_bm25_b),
+	JASS::commandline::parameter("-mu", "--dirichlet_mu", "<mu> The mu parameter of LM_Dirichlet [default = 1000]", parameter_dirichlet_mu),
+
+	JASS::commandline::note("\nQUANTIZATION\n------------"),
+	JASS::commandline::parameter("-Qb", "--quantization_bits", "<bits> Quantize into impacts of this many bits (1 to 16), i.e. 1 to 2^bits - 1 (wide impacts need wide accumulators when searching) [default = 0, impacts 1 to 1024 (11 bits)]", parameter_quantization_bits),
+	JASS::commandline::parameter("-Qm", "--quantization_mapping", "<mapping> Map scores to impacts with equal width (linear), log-scaled (log), or equal-frequency (equal_frequency) buckets [default = linear]", parameter_quantization_mapping),
+	JASS::commandline::parameter("-Qt", "--quantization_per_term", "Quantize each term on its own range of scores rather than the collection-wide range", parameter_quantization_per_term),
+
+	JASS::commandline::note("\nINDEX GENERATION (default = the format(s) of the original index)\n---------------------------------------------------------------"),
+	JASS::commandline::parameter("-I1", "--index_jass_v1", "Generate a JASS version 1 index.", parameter_jass_v1_index),
+	JASS::commandline::parameter("-I2", "--index_jass_v2", "Generate a JASS version 2 index.", parameter_jass_v2_index),
+	JASS::commandline::parameter("-I3", "--index_jass_v3", "Generate a JASS version 3 index (a single file, CIindex.bin).", parameter_jass_v3_index)
+	);
+
+/*
+	USAGE()
+	-------
+*/
+template <typename TYPE>
+uint8_t usage(const std::string &exename, TYPE &all_parameters)
+	{
+	std::cout << JASS::commandline::usage(exename, all_parameters) << "\n";
+	return 1;
+	}
+
+/*
+	MAKE_SERIALISER()
+	-----------------
+*/
+/*!
+	@brief Make a serialiser that compresses the postings with the chosen codex (-c...), or else with the codex of the original index.
+	@param documents [in] The number of documents in the collection.
+	@param default_codex [in] The codex the serialiser uses by default.
+	@param codex_shortname [in] The codex chosen on the command line ("" if none was chosen).
+	@param source_codex [in] The name of the codex of the original index ("" if not known, in which case default_codex is used).
+	@return The serialiser.
+*/
+template <typename SERIALISER>
+std::unique_ptr<SERIALISER> make_serialiser(size_t documents, JASS::serialise_jass_v1::jass_v1_codex default_codex, const std::string &codex_shortname, const std::string &source_codex)
+	{
+	std::string shortname = codex_shortname;
+	if (shortname == "" && source_codex != "" && !JASS::serialise_jass_v1::codex_shortname(source_codex, default_codex, shortname))
+		{
+		std::cout << "The codex of the original index is not known:" << source_codex << "\n";
+		exit(1);
+		}
+
+	int8_t alignment = shortname == "-cZ" ? 16 : 1;			// QMX JASS v1 needs its postings to start on 16-byte boundaries
+	return shortname == "" ? std::make_unique<SERIALISER>(documents) : std::make_unique<SERIALISER>(documents, shortname, alignment);
+	}
+
+/*
+	MAIN()
+	------
+*/
+int main(int argc, const char *argv[])
+	{
+	auto timer = JASS::timer::start();
+
+	/*
+		Do the command line parsing (the list of codexes is built here as it depends on compress_integer_all's static initialisation).
+	*/
+	auto all_parameters = std::tuple_cat
+		(
+		command_line_parameters,
+		std::make_tuple(JASS::commandline::note("\nCOMPRESSION (default = the codex of the original index)\n-------------------------------------------------------")),
+		JASS::compress_integer_all::parameterlist(parameter_codex)
+		);
+	std::string error;
+	auto success = JASS::commandline::parse(argc, argv, all_parameters, error);
+	if (!success)
+		{
+		std::cout << error;
+		exit(1);
+		}
+
+	if (parameter_help)
+		exit(usage(argv[0], all_parameters));
+
+	if (!parameter_quiet)
+		std::cout << JASS::version::build() << "\n";
+
+	/*
+		Check the ranking function, quantization scheme, and codex
+	*/
+	if (!JASS::quantize_index::is_known(parameter_ranking_function))
+		{
+		std::cout << "Unknown ranking function (-r):" << parameter_ranking_function << "\n";
+		return 1;
+		}
+	if (parameter_quantization_bits > JASS::quantization_scheme::MAX_BITS)
+		{
+		std::cout << "Impacts (-Qb) can be no more than " << JASS::quantization_scheme::MAX_BITS << " bits\n";
+		return 1;
+		}
+	JASS::quantization_scheme::mapping_type quantization_mapping;
+	if (!JASS::quantization_scheme::mapping_by_name(parameter_quantization_mapping, quantization_mapping))
+		{
+		std::cout << "Unknown quantization mapping (-Qm):" << parameter_quantization_mapping << "\n";
+		return 1;
+		}
+	std::string codex_shortname = JASS::compress_integer_all::shortname(parameter_codex);
+	if (codex_shortname != "" && !JASS::serialise_jass_v1::can_encode_postings(codex_shortname))
+		{
+		std::cout << "The " << JASS::compress_integer_all::name_of(codex_shortname) << " codex (" << codex_shortname << ") cannot be used to compress an index\n";
+		return 1;
+		}
+
+	/*
+		Find the format(s) and codex of the original index from its manifest (a JASS v3 index holds its manifest, so it is loaded to get it).
+		An index without a manifest is compressed with the default codex of its format.
+	*/
+	std::filesystem::path source_directory(parameter_directory);
+	JASS::index_manifest source_manifest;
+	bool source_jass_v1 = false;
+	bool source_jass_v2 = false;
+	bool source_jass_v3 = std::filesystem::exists(source_directory / JASS::serialise_jass_v3::FILENAME);
+	if (source_manifest.read(parameter_directory))
+		{
+		source_jass_v1 = source_manifest.version == 1;
+		source_jass_v2 = source_manifest.version == 2;
+		}
+	else
+		{
+		size_t version = JASS::index_manifest::legacy_version(parameter_directory);
+		source_jass_v1 = version == 1;
+		source_jass_v2 = version == 2;
+		if (source_jass_v3)
+			{
+			JASS::deserialised_jass_v3 source_index;
+			if (source_index.read_index(parameter_directory) != 0)
+				source_manifest = source_index.get_manifest();
+			}
+		}
+
+	if (!(parameter_jass_v1_index || parameter_jass_v2_index || parameter_jass_v3_index))
+		{
+		parameter_jass_v1_index = source_jass_v1;
+		parameter_jass_v2_index = source_jass_v2;
+		parameter_jass_v3_index = source_jass_v3;
+		}
+	if (!(parameter_jass_v1_index || parameter_jass_v2_index || parameter_jass_v3_index))
+		{
+		std::cout << "There is no index in directory:" << parameter_directory << " so you must specify an index file format or else no index will be generated\n";
+		return 1;
+		}
+
+	/*
+		Load the unquantized postings
+	*/
+	JASS::index_manager_sequential index;
+	std::string buffer;			// the primary keys point into this so it must outlive index
+	if (!JASS::serialise_unquantized::load((source_directory / JASS::serialise_unquantized::FILENAME).string(), index, buffer))
+		{
+		std::cout << "Can't read the unquantized postings (" << JASS::serialise_unquantized::FILENAME << ") in directory:" << parameter_directory << "\n";
+		return 1;
+		}
+
+	auto time_to_end_load = JASS::timer::stop(timer).nanoseconds();
+	std::cout << "Documents:" << index.get_highest_document_id() << '\n';
+
+	/*
+		Quantize the index with the chosen ranking function then write it out in the desired formats.
+	*/
+	JASS::quantization_scheme scheme(quantization_mapping, parameter_quantization_per_term ? JASS::quantization_scheme::PER_TERM : JASS::quantization_scheme::GLOBAL, parameter_quantization_bits);
+
+	std::string stemmer = source_manifest.stemmer;
+	if (std::filesystem::exists(source_directory / "CIstemmer.txt"))
+		{
+		JASS::file::read_entire_file((source_directory / "CIstemmer.txt").string(), stemmer);
+		stemmer.erase(stemmer.find_last_not_of(" \t\r\n") + 1);
+		}
+	std::string stopwords;
+	if (std::filesystem::exists(source_directory / "CIstopwords.txt"))
+		JASS::file::read_entire_file((source_directory / "CIstopwords.txt").string(), stopwords);
+
+	/*
+		The stemmer, stop words, and positions are unchanged by re-quantization, so copy them from the original index (if it is elsewhere).  They
+		are part of a JASS v1 or v2 index so they are copied before it is written (so that their checksums can be recorded in its manifest).  A
+		JASS v3 index holds them itself.
+	*/
+	bool save_quantization = parameter_jass_v1_index || parameter_jass_v2_index;
+	std::vector<std::string> auxiliary_files = {"CIquantization.txt"};
+	std::error_code ignore;
+	bool elsewhere = !std::filesystem::equivalent(source_directory, std::filesystem::current_path(), ignore);
+	if (save_quantization)
+		for (const char *filename : {"CIstemmer.txt", "CIstopwords.txt", "CIpositions.bin"})
+			if (std::filesystem::exists(source_directory / filename))
+				{
+				if (elsewhere)
+					std::filesystem::copy_file(source_directory / filename, filename, std::filesystem::copy_options::overwrite_existing);
+				auxiliary_files.push_back(filename);
+				}
+
+	std::string ranking_function = JASS::quantize_index::describe_ranking_function(parameter_ranking_function, parameter_bm25_k1, parameter_bm25_b, parameter_dirichlet_mu);
+	std::vector<std::unique_ptr<JASS::index_manager::delegate>> exporters;
+	JASS::serialise_jass_v3 *jass_v3 = nullptr;
+	if (parameter_jass_v1_index)
+		{
+		auto serialiser = make_serialiser<JASS::serialise_jass_v1>(index.get_highest_document_id(), JASS::serialise_jass_v1::elias_gamma_simd, codex_shortname, source_manifest.codex);
+		JASS::quantize_index::describe(serialiser->get_manifest(), ranking_function, &scheme, stemmer);
+		for (const auto &filename : auxiliary_files)
+			serialiser->add_auxiliary_file(filename);
+		exporters.push_back(std::move(serialiser));
+		}
+	if (parameter_jass_v2_index)
+		{
+		auto serialiser = make_serialiser<JASS::serialise_jass_v2>(index.get_highest_document_id(), JASS::serialise_jass_v1::elias_gamma_simd_vb, codex_shortname, source_manifest.codex);
+		JASS::quantize_index::describe(serialiser->get_manifest(), ranking_function, &scheme, stemmer);
+		for (const auto &filename : auxiliary_files)
+			serialiser->add_auxiliary_file(filename);
+		exporters.push_back(std::move(serialiser));
+		}
+	if (parameter_jass_v3_index)
+		{
+		auto serialiser = make_serialiser<JASS::serialise_jass_v3>(index.get_highest_document_id(), JASS::serialise_jass_v1::elias_gamma_simd_vb, codex_shortname, source_manifest.codex);
+		JASS::quantize_index::describe(serialiser->get_manifest(), ranking_function, &scheme, stemmer);
+		if (stemmer != "")
+			serialiser->add_section(JASS::serialise_jass_v3::STEMMER, stemmer + "\n");
+		serialiser->add_section(JASS::serialise_jass_v3::STOPWORDS, stopwords);
+		if (std::filesystem::exists(source_directory / "CIpositions.bin"))
+			serialiser->add_section_file(JASS::serialise_jass_v3::POSITIONS, (source_directory / "CIpositions.bin").string());
+		jass_v3 = serialiser.get();
+		exporters.push_back(std::move(serialiser));
+		}
+
+	JASS::quantize_index::nanoseconds time_to_end_quantization;
+	JASS::quantize_index::quantize_and_serialise(parameter_ranking_function, parameter_bm25_k1, parameter_bm25_b, parameter_dirichlet_mu, index, index.get_highest_document_id(), exporters, jass_v3, save_quantization, scheme, timer, time_to_end_quantization);
+
+	/*
+		Dump the statistics to the console.
+	*/
+	auto time_to_end = JASS::timer::stop(timer).nanoseconds();
+	auto quantization_time = time_to_end_quantization - time_to_end_load;
+	auto serialise_time = time_to_end - time_to_end_quantization;
+
+	std::cout << "Load time        :" << time_to_end_load << "ns (" << time_to_end_load / 1000000000 << " seconds)\n";
+	std::cout << "Quantization time:" << quantization_time << "ns (" << quantization_time / 1000000000 << " seconds)\n";
+	std::cout << "Serialise time   :" << serialise_time << "ns (" << serialise_time / 1000000000 << " seconds)\n";
+	std::cout << "=================\n";
+	std::cout << "Total time       :" << time_to_end << "ns (" << time_to_end / 1000000000 << " seconds)\n";
+
+	return 0;
+	}
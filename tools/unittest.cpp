@@ -13,6 +13,7 @@
 #include "slice.h"
 #include "timer.h"
 #include "parser.h"
+#include "stem_s.h"
 #include "unicode.h"
 #include "version.h"
 #include "reverse.h"
@@ -20,6 +21,8 @@
 #include "evaluate.h"
 #include "checksum.h"
 #include "quantize.h"
+#include "quantize_index.h"
+#include "stem_all.h"
 #include "bitstream.h"
 #include "bitstring.h"
 #include "query_heap.h"
@@ -28,13 +31,17 @@
 #include "hash_table.h"
 #include "run_export.h"
 #include "top_k_heap.h"
+#include "stop_words.h"
 #include "stem_porter.h"
 #include "top_k_qsort.h"
 #include "binary_tree.h"
 #include "commandline.h"
 #include "pointer_box.h"
+#include "stem_krovetz.h"
+#include "stem_porter2.h"
 #include "evaluate_map.h"
 #include "serialise_ci.h"
+#include "serialise_ciff.h"
 #include "query_simple.h"
 #include "hash_pearson.h"
 #include "parser_query.h"
@@ -45,23 +52,30 @@
 #include "allocator_cpp.h"
 #include "instream_file.h"
 #include "index_manager.h"
+#include "index_manifest.h"
+#include "index_checker.h"
 #include "allocator_pool.h"
 #include "index_postings.h"
 #include "accumulator_2d.h"
 #include "channel_buffer.h"
 #include "instream_memory.h"
+#include "instream_deflate.h"
 #include "run_export_trec.h"
 #include "evaluate_recall.h"
 #include "query_block_max.h"
 #include "hardware_support.h"
+#include "document_reorder.h"
 #include "allocator_memory.h"
 #include "ranking_function.h"
 #include "serialise_jass_v1.h"
+#include "serialise_jass_v3.h"
 #include "accumulator_simple.h"
 #include "serialise_integers.h"
 #include "evaluate_precision.h"
 #include "instream_file_star.h"
+#include "quantization_scheme.h"
 #include "parser_unicoil_json.h"
+#include "index_manager_spill.h"
 #include "compress_integer_all.h"
 #include "evaluate_buying_power.h"
 #include "compress_integer_none.h"
@@ -74,7 +88,11 @@
 #include "evaluate_buying_power4k.h"
 #include "instream_document_fasta.h"
 #include "serialise_forward_index.h"
+#include "serialise_unquantized.h"
 #include "index_manager_sequential.h"
+#include "deserialised_positions.h"
+#include "deserialised_jass_v3.h"
+#include "index_manager_positional.h"
 #include "compress_integer_carry_8b.h"
 #include "compress_integer_simple_9.h"
 #include "evaluate_relevant_returned.h"
@@ -83,6 +101,11 @@
 #include "evaluate_cheapest_precision.h"
 #include "compress_integer_bitpack_64.h"
 #include "ranking_function_atire_bm25.h"
+#include "ranking_function_bm25f.h"
+#include "ranking_function_dph.h"
+#include "ranking_function_tfidf.h"
+#include "ranking_function_lucene_bm25.h"
+#include "ranking_function_lm_dirichlet.h"
 #include "instream_directory_iterator.h"
 #include "compress_integer_elias_gamma.h"
 #include "compress_integer_elias_delta.h"
@@ -208,6 +231,21 @@ int main(void)
 		puts("stem_porter");
 		JASS::stem_porter::unittest();
 
+		puts("stem_porter2");
+		JASS::stem_porter2::unittest();
+
+		puts("stem_krovetz");
+		JASS::stem_krovetz::unittest();
+
+		puts("stem_s");
+		JASS::stem_s::unittest();
+
+		puts("stem_all");
+		JASS::stem_all::unittest();
+
+		puts("stop_words");
+		JASS::stop_words::unittest();
+
 		puts("statistics");
 		JASS::statistics::unittest();
 		
@@ -217,6 +255,21 @@ int main(void)
 		puts("ranking_function_atire_bm25");
 		JASS::ranking_function_atire_bm25::unittest();
 
+		puts("ranking_function_bm25f");
+		JASS::ranking_function_bm25f::unittest();
+
+		puts("ranking_function_lucene_bm25");
+		JASS::ranking_function_lucene_bm25::unittest();
+
+		puts("ranking_function_lm_dirichlet");
+		JASS::ranking_function_lm_dirichlet::unittest();
+
+		puts("ranking_function_dph");
+		JASS::ranking_function_dph::unittest();
+
+		puts("ranking_function_tfidf");
+		JASS::ranking_function_tfidf::unittest();
+
 		puts("ranking_function");
 		JASS::ranking_function<JASS::ranking_function_atire_bm25>::unittest();
 
@@ -337,6 +390,9 @@ int main(void)
 		puts("instream_memory");
 		JASS::instream_memory::unittest();
 
+		puts("instream_deflate");
+		JASS::instream_deflate::unittest();
+
 		puts("instream_document_trec");
 		JASS::instream_document_trec::unittest();
 
@@ -393,18 +449,48 @@ int main(void)
 		puts("index_manager_sequential");
 		JASS::index_manager_sequential::unittest();
 
+		puts("index_manager_spill");
+		JASS::index_manager_spill::unittest();
+
+		puts("document_reorder");
+		JASS::document_reorder::unittest();
+
+		puts("index_manager_positional");
+		JASS::index_manager_positional::unittest();
+
+		puts("deserialised_positions");
+		JASS::deserialised_positions::unittest();
+
 		puts("serialise_ci");
 		JASS::serialise_ci::unittest();
 
+		puts("serialise_ciff");
+		JASS::serialise_ciff::unittest();
+
 		puts("serialise_jass_v1");
 		JASS::serialise_jass_v1::unittest();
 
+		puts("index_manifest");
+		JASS::index_manifest::unittest();
+
+		puts("index_checker");
+		JASS::index_checker::unittest();
+
+		puts("serialise_jass_v3");
+		JASS::serialise_jass_v3::unittest();
+
+		puts("deserialised_jass_v3");
+		JASS::deserialised_jass_v3::unittest();
+
 		puts("serialise_integers");
 		JASS::serialise_integers::unittest();
 
 		puts("serialise_forward_index");
 		JASS::serialise_forward_index::unittest();
 
+		puts("serialise_unquantized");
+		JASS::serialise_unquantized::unittest();
+
 		puts("compress_integer_elias_gamma_bitwise");
 		JASS::compress_integer_elias_gamma_bitwise::unittest();
 
@@ -466,13 +552,13 @@ int main(void)
 		JASS::compress_integer_bitpack_128::unittest();
 
 		puts("accumulator_2d");
-		JASS::accumulator_2d<uint32_t, 1>::unittest();
+		JASS::accumulator_2d<uint32_t>::unittest();
 
 		puts("accumulator_simple");
-		JASS::accumulator_simple<uint32_t, 1>::unittest();
+		JASS::accumulator_simple<uint32_t>::unittest();
 
 		puts("accumulator_block_max");
-		JASS::accumulator_block_max<uint32_t, 1>::unittest();
+		JASS::accumulator_block_max<uint32_t>::unittest();
 
 		puts("pointer_box");
 		JASS::pointer_box<int>::unittest();
@@ -484,7 +570,8 @@ int main(void)
 		JASS::top_k_heap<int>::unittest();
 
 		puts("query_heap");
-		JASS::query_heap<JASS::accumulator_2d<JASS::query::ACCUMULATOR_TYPE, JASS::query::MAX_DOCUMENTS>>::unittest();
+		JASS::query_heap<JASS::accumulator_2d<JASS::query::DEFAULT_ACCUMULATOR_TYPE>>::unittest();
+		JASS::query_heap<JASS::accumulator_2d<uint32_t>>::unittest();
 
 		puts("query_simple");
 		JASS::query_simple::unittest();
@@ -495,9 +582,15 @@ int main(void)
 		puts("run_export");
 		JASS::run_export::unittest();
 
+		puts("quantization_scheme");
+		JASS::quantization_scheme::unittest();
+
 		puts("quantize");
 		JASS::quantize<JASS::ranking_function_atire_bm25>::unittest();
 
+		puts("quantize_index");
+		JASS::quantize_index::unittest();
+
 		puts("compress_general_zlib");
 		JASS::compress_general_zlib::unittest();
 